@@ -1,9 +1,16 @@
 import {
+  type Capability,
   createRuntime,
+  type CreateRuntimeOptions,
   type Imports,
 } from "../example-protocol/bindings/ts-runtime/index.ts";
 
-export async function loadPlugin(path: string, imports: Imports) {
+export async function loadPlugin(
+  path: string,
+  imports: Imports,
+  capabilities: Capability[] = [],
+  options: CreateRuntimeOptions = {},
+) {
   // This uses the Deno API to load a local file, but you might want to use
   // `fetch()` here if you're targeting the browser.
   //
@@ -14,5 +21,5 @@ export async function loadPlugin(path: string, imports: Imports) {
   // ```
 
   const plugin = await Deno.readFile(path);
-  return createRuntime(plugin, imports);
+  return createRuntime(plugin, imports, capabilities, [], options);
 }
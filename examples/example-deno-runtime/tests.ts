@@ -1,11 +1,21 @@
 import {
   assert,
   assertAlmostEquals,
-  assertEquals, assertStrictEquals,
+  assertEquals, assertRejects, assertStrictEquals, assertThrows,
 } from "https://deno.land/std@0.135.0/testing/asserts.ts";
 import { loadPlugin } from "./loader.ts";
+import { checkFixtures } from "../example-protocol/bindings/fixtures/fixtures.ts";
+import {
+  createRuntime,
+  InvalidSignatureError,
+  PermissionDeniedError,
+  PluginError,
+} from "../example-protocol/bindings/ts-runtime/index.ts";
 import type { Exports, Imports } from "../example-protocol/bindings/ts-runtime/index.ts";
 import type {
+  ArbitraryPrecisionNumbers,
+  Collections,
+  EdgeValues,
   ExplicitBoundPoint,
   FpAdjacentlyTagged,
   FpFlatten,
@@ -14,6 +24,10 @@ import type {
   FpUntagged,
   FpVariantRenaming,
   HttpResult,
+  I128String,
+  IntegerTypes,
+  PluginConfig,
+  RateLimited,
   Request,
   SerdeAdjacentlyTagged,
   SerdeFlatten,
@@ -22,12 +36,18 @@ import type {
   SerdeUntagged,
   SerdeVariantRenaming,
   StructWithGenerics, StructWithOptions,
+  U128String,
+  ValidatedStruct,
 } from "../example-protocol/bindings/ts-runtime/types.ts";
 import {Result} from "../example-protocol/bindings/ts-runtime/types.ts";
 
 let voidFunctionCalled = false;
 
 const imports: Imports = {
+  importAsyncVoidFunction: async (message: string): Promise<void> => {
+    assertEquals(message, "Hello, runtime!");
+  },
+
   importExplicitBoundPoint: (arg: ExplicitBoundPoint<number>) => {
     assertEquals(arg.value, 123);
   },
@@ -103,6 +123,11 @@ const imports: Imports = {
     return { Ok: new TextEncoder().encode("hello") };
   },
 
+  importGetValue: async <T>(key: string): Promise<T | null> => {
+    assertEquals(key, "tabSize");
+    return 4 as T;
+  },
+
   importMultiplePrimitives: (arg1: number, arg2: string): bigint => {
     assertEquals(arg1, -8);
     assertEquals(arg2, "Hello, 🇳🇱!");
@@ -157,6 +182,14 @@ const imports: Imports = {
     assertEquals(arg, 64n);
     return 64n;
   },
+  importPrimitivePair: (arg: number): [number, number] => {
+    assertEquals(arg, 32);
+    return [32, -32];
+  },
+  importMultipleValues: (arg: number): [string, Array<number>, boolean] => {
+    assertEquals(arg, 3);
+    return ["three", [1, 2, 3], true];
+  },
 
   importPrimitiveU8: (arg: number): number => {
     assertEquals(arg, 8);
@@ -203,6 +236,14 @@ const imports: Imports = {
     return new Float64Array([1, 2, 3]);
   },
 
+  importRateLimited: (label: string): Result<string, RateLimited> => {
+    return { Ok: label };
+  },
+
+  importRenamedString: (arg: string): string => {
+    return `${arg} (renamed)`;
+  },
+
   importSerdeAdjacentlyTagged: (
     arg: SerdeAdjacentlyTagged,
   ): SerdeAdjacentlyTagged => {
@@ -242,6 +283,11 @@ const imports: Imports = {
     return { a: -8, b: 64 };
   },
 
+  importSetValue: <T>(key: string, value: T): void => {
+    assertEquals(key, "tabSize");
+    assertEquals(value, 4);
+  },
+
   importString: (arg: string): string => {
     assertEquals(arg, "Hello, world!");
     return "Hello, plugin!";
@@ -265,6 +311,10 @@ const imports: Imports = {
   importVoidFunctionEmptyReturn: (): void => {
   },
 
+  invokeCommand: (name: string, payload: string): Result<string, string> => {
+    return { Err: `Unknown command: ${name}` };
+  },
+
   log: (message: string): void => {
     console.log("Plugin log: " + message);
   },
@@ -295,6 +345,10 @@ const imports: Imports = {
     });
   },
 
+  recordMetric: (name: string, value: number): void => {
+    console.log(`Plugin metric: ${name} = ${value}`);
+  },
+
   importStructWithOptions: (arg: StructWithOptions): StructWithOptions => {
     assertStrictEquals(arg.filledString, "Hello!");
     assertStrictEquals(arg.emptyString, undefined);
@@ -303,20 +357,40 @@ const imports: Imports = {
     assertStrictEquals(arg.neverSkippedFilledOptionString, "Hello!");
     assertStrictEquals(arg.neverSkippedEmptyOptionString, null);
     return arg;
-  }
+  },
+
+  importIntegerTypes: (arg: IntegerTypes): IntegerTypes => {
+    return arg;
+  },
+
+  importArbitraryPrecisionNumbers: (
+    arg: ArbitraryPrecisionNumbers
+  ): ArbitraryPrecisionNumbers => {
+    return arg;
+  },
+
+  importValidatedStruct: (arg: ValidatedStruct): ValidatedStruct => {
+    assertEquals(arg.slug, "fp-bindgen");
+    return arg;
+  },
+
+  importCollections: (arg: Collections): Collections => {
+    assertEquals(arg.tags, new Set(["one", "two"]));
+    return arg;
+  },
 };
 
+const pluginConfig: PluginConfig = { logLevel: "debug" };
+
 let examplePlugin: Exports | null = null;
 async function loadExamplePlugin() {
   if (!examplePlugin) {
     examplePlugin = await loadPlugin(
       "../example-plugin/target/wasm32-unknown-unknown/debug/example_plugin.wasm",
       imports,
+      ["network"],
+      { init: pluginConfig },
     );
-
-    const { init } = examplePlugin;
-    assert(init);
-    init();
   }
 
   return examplePlugin;
@@ -335,6 +409,8 @@ Deno.test("primitives", async () => {
   assertEquals(plugin.exportPrimitiveI8?.(-8), -8);
   assertEquals(plugin.exportPrimitiveI16?.(-16), -16);
   assertEquals(plugin.exportPrimitiveI32?.(-32), -32);
+  assertEquals(plugin.exportPrimitivePair?.(32), [32, -32]);
+  assertEquals(plugin.exportMultipleValues?.(3), ["three", [1, 2, 3], true]);
   assertEquals(plugin.exportPrimitiveI64?.(-64n), -64n);
 
   assertEquals(plugin.exportMultiplePrimitives?.(-8, "Hello, 🇳🇱!"), -64n);
@@ -360,6 +436,37 @@ Deno.test("string", async () => {
   const plugin = await loadExamplePlugin();
 
   assertEquals(plugin.exportString?.("Hello, plugin!"), "Hello, world!");
+  assertEquals(plugin.exportRenamedString?.("Hello"), "Hello (renamed)");
+});
+
+Deno.test("cached export", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportCachedCallCount?.("a"), "a: 1");
+  assertEquals(plugin.exportCachedCallCount?.("a"), "a: 1");
+  assertEquals(plugin.exportCachedCallCount?.("b"), "b: 2");
+
+  plugin.clearExportCache();
+  assertEquals(plugin.exportCachedCallCount?.("a"), "a: 3");
+});
+
+Deno.test("rate-limited import", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const results = plugin.exportCallRateLimitedImport?.(3);
+  assertEquals(results?.slice(0, 2), [{ Ok: "call 0" }, { Ok: "call 1" }]);
+  const error = results?.[2];
+  assert(error && "Err" in error);
+  assertEquals(error.Err.function, "import_rate_limited");
+  assert(error.Err.retryAfterMs > 0);
+});
+
+Deno.test("string after memory growth", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const arg = "Hello, 🇳🇱!".repeat(1000);
+  assertEquals(plugin.exportStringAfterMemoryGrowth?.(arg), arg);
+  assertEquals(plugin.exportStringAfterMemoryGrowth?.(arg), arg);
 });
 
 Deno.test("timestamp", async () => {
@@ -368,6 +475,57 @@ Deno.test("timestamp", async () => {
   assertEquals(plugin.exportTimestamp?.("2022-04-12T19:10:00Z"), "2022-04-13T12:37:00Z");
 });
 
+Deno.test("timestamps as dates", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(
+    plugin.exportTimestamps?.({ createdAt: new Date("2022-04-12T19:10:00Z"), deletedAt: null }),
+    { createdAt: new Date("2022-04-12T19:10:00Z"), deletedAt: new Date("2022-04-13T12:37:00Z") }
+  );
+});
+
+Deno.test("edge values", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const cases: Array<EdgeValues> = [
+    {
+      character: "\0",
+      float: Math.fround(1.1754944e-38),
+      signed: "-170141183460469231731687303715884105728" as I128String,
+      unsigned: "0" as U128String,
+    },
+    {
+      character: "\u{10FFFF}",
+      float: Math.fround(3.4028235e38),
+      signed: "170141183460469231731687303715884105727" as I128String,
+      unsigned: "340282366920938463463374607431768211455" as U128String,
+    },
+    {
+      character: "🇳",
+      float: Infinity,
+      signed: "-1" as I128String,
+      unsigned: "1" as U128String,
+    },
+    {
+      character: "é",
+      float: -Infinity,
+      signed: "0" as I128String,
+      unsigned: "18446744073709551616" as U128String,
+    },
+  ];
+  for (const value of cases) {
+    assertEquals(plugin.exportEdgeValues?.(value), value);
+  }
+
+  const value = plugin.exportEdgeValues?.({
+    character: "a",
+    float: NaN,
+    signed: "0" as I128String,
+    unsigned: "0" as U128String,
+  });
+  assert(Number.isNaN(value?.float));
+});
+
 Deno.test("flattened structs", async () => {
   const plugin = await loadExamplePlugin();
 
@@ -428,6 +586,8 @@ Deno.test("generics", async () => {
     },
     optional_timestamp: "1970-01-01T00:00:00Z",
   });
+
+  assertEquals(plugin.exportTypedId?.({ id: 64 }), { id: 65 });
 });
 
 Deno.test("property renaming", async () => {
@@ -474,6 +634,35 @@ Deno.test("tagged enums", async () => {
   assertEquals(plugin.exportSerdeUntagged?.("Hello, plugin!"), { a: -8, b: 64 });
 });
 
+Deno.test("dynamic values", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(
+    plugin.exportDynamicValue?.({
+      extension: "markdown",
+      settings: { tables: true, tabSize: 4 },
+    }),
+    { enabled: true, features: ["tables"] },
+  );
+});
+
+Deno.test("results inside types", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(
+    plugin.exportBatchResponse?.({
+      results: [{ Ok: 1 }, { Err: "timeout" }, { Ok: 3 }],
+      summary: { Ok: "pending" },
+      retryAfter: null,
+    }),
+    {
+      results: [{ Ok: 1 }, { Err: "timeout" }, { Ok: 3 }],
+      summary: { Err: "1 operation(s) failed" },
+      retryAfter: { Ok: 30 },
+    },
+  );
+});
+
 Deno.test("async struct", async () => {
   const { exportAsyncStruct } = await loadExamplePlugin();
   assert(exportAsyncStruct);
@@ -499,6 +688,22 @@ Deno.test("fetch async data", async () => {
   });
 });
 
+Deno.test("fetch async data without network capability", async () => {
+  const { fetchData } = await loadPlugin(
+    "../example-plugin/target/wasm32-unknown-unknown/debug/example_plugin.wasm",
+    imports,
+    [],
+    { init: pluginConfig },
+  );
+  assert(fetchData);
+
+  await assertRejects(
+    async () => { await fetchData("sign-up"); },
+    PermissionDeniedError,
+    "make_http_request",
+  );
+});
+
 Deno.test("bytes", async () => {
   const { exportGetBytes, exportGetSerdeBytes } = await loadExamplePlugin();
   assert(exportGetBytes);
@@ -528,6 +733,137 @@ Deno.test("options", async () => {
   });
 });
 
+Deno.test("validation", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const value = {
+    percentage: 42,
+    slug: "fp-bindgen",
+    ratio: 0.5,
+    children: [{ name: "child" }],
+  };
+  assertEquals(plugin.exportValidatedStruct?.(value), value);
+});
+
+Deno.test("collections", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const value = {
+    tags: new Set(["one", "two"]),
+    optionalTags: null,
+    queue: [3, 1, 2],
+  };
+  assertEquals(plugin.exportCollections?.(value), value);
+});
+
+Deno.test("columnar samples", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const samples = {
+    timestampMs: new BigUint64Array([1000n, 2000n]),
+    value: new Float64Array([1.5, -2]),
+    quality: new Uint8Array([1, 0]),
+  };
+  assertEquals(plugin.exportSamples?.(samples), {
+    timestampMs: new BigUint64Array([2000n, 3000n]),
+    value: new Float64Array([3, -4]),
+    quality: new Uint8Array([1, 0]),
+  });
+});
+
+Deno.test("interned metrics", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const metrics = [
+    { name: "cpu.usage", value: 0.5 },
+    { name: "mem.usage", value: 1.5 },
+    { name: "cpu.usage", value: 0.25 },
+  ];
+  const expected = [
+    { name: "cpu.usage", value: 1 },
+    { name: "mem.usage", value: 3 },
+    { name: "cpu.usage", value: 0.5 },
+  ];
+  assertEquals(plugin.exportMetrics?.(metrics), expected);
+  // The second call reuses the handles that were registered by the first:
+  assertEquals(plugin.exportMetrics?.(metrics), expected);
+});
+
+Deno.test("lifecycle", async () => {
+  const plugin = await loadPlugin(
+    "../example-plugin/target/wasm32-unknown-unknown/debug/example_plugin.wasm",
+    imports,
+    [],
+    { init: pluginConfig },
+  );
+
+  assertEquals(plugin.exportPrimitiveU32?.(32), 32);
+  plugin.dispose();
+
+  const error = assertThrows(
+    () => plugin.exportString?.("Hello, plugin!"),
+    PluginError,
+  );
+  assertEquals((error as PluginError).kind, "disposed");
+
+  // Disposing twice is a no-op:
+  plugin.dispose();
+});
+
+Deno.test("missing exports", async () => {
+  const plugin = await loadExamplePlugin();
+
+  // Exports are checked when the plugin is loaded, so the exports that it
+  // omits are known up front:
+  assertEquals(plugin.missingExports(), [
+    "exportVoidFunction",
+    "reducerBridge",
+    "updateConfig",
+  ]);
+  assertEquals(plugin.exportVoidFunction, undefined);
+});
+
+Deno.test("signature verification", async () => {
+  const path = "../example-plugin/target/wasm32-unknown-unknown/debug/example_plugin.wasm";
+  const unsigned = await Deno.readFile(path);
+
+  // Appends an `fp-signature` section with a dummy signature:
+  const name = new TextEncoder().encode("fp-signature");
+  const signature = new Uint8Array(64).fill(7);
+  const signed = new Uint8Array([
+    ...unsigned,
+    0,
+    1 + name.length + signature.length,
+    name.length,
+    ...name,
+    ...signature,
+  ]);
+
+  const signatureVerifier = (signedBytes: Uint8Array, sig: Uint8Array) =>
+    signedBytes.length === unsigned.length && sig.every((byte) => byte === 7);
+
+  const plugin = await createRuntime(signed, imports, [], [], { signatureVerifier });
+  assertEquals(plugin.exportPrimitiveU32?.(32), 32);
+
+  await assertRejects(
+    () => createRuntime(unsigned, imports, [], [], { signatureVerifier }),
+    InvalidSignatureError,
+    "Plugin is not signed",
+  );
+  await assertRejects(
+    () => createRuntime(signed, imports, [], [], { signatureVerifier: () => false }),
+    InvalidSignatureError,
+    "Plugin signature is invalid",
+  );
+});
+
+Deno.test("fixtures", () => {
+  const failures = checkFixtures((file) =>
+    Deno.readFileSync(`../example-protocol/bindings/fixtures/fixtures/${file}`)
+  );
+  assertEquals(failures, []);
+});
+
 function isOk<T, E>(result: Result<T, E>): result is { Ok: T } {
   return "Ok" in result;
 }
@@ -1,8 +1,11 @@
 use bytes::{Bytes, BytesMut};
+use once_cell::sync::Lazy;
 use ::http::{Method, Uri};
 use example_bindings::*;
 use serde_bytes::ByteBuf;
+use serde_json::{json, Value};
 use std::collections::{BTreeMap};
+use std::marker::PhantomData;
 use std::panic;
 use time::{macros::datetime, OffsetDateTime};
 
@@ -23,7 +26,7 @@ fn init_panic_hook() {
     use std::sync::Once;
     static SET_HOOK: Once = Once::new();
     SET_HOOK.call_once(|| {
-        panic::set_hook(Box::new(|info| log(info.to_string())));
+        panic::set_hook(Box::new(|info| log(&info.to_string())));
     });
 }
 
@@ -94,6 +97,18 @@ fn export_primitive_u64(arg: u64) -> u64 {
     64
 }
 
+#[fp_export_impl(example_bindings)]
+fn export_primitive_pair(arg: u32) -> (u32, i32) {
+    assert_eq!(arg, 32);
+    (32, -32)
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_multiple_values(arg: u32) -> (String, Vec<u32>, bool) {
+    assert_eq!(arg, 3);
+    ("three".to_owned(), vec![1, 2, 3], true)
+}
+
 #[fp_export_impl(example_bindings)]
 fn export_array_u8(arg: [u8; 3]) -> [u8; 3] {
     assert_eq!(arg, [1u8, 2u8, 3u8]);
@@ -148,6 +163,44 @@ fn export_string(arg: String) -> String {
     "Hello, world!".to_owned()
 }
 
+#[fp_export_impl(example_bindings)]
+fn export_lazy_string(arg: String) -> String {
+    assert_eq!(arg, "Hello, plugin!");
+    "Hello, world!".to_owned()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_cached_call_count(label: String) -> String {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    static CALL_COUNT: AtomicU32 = AtomicU32::new(0);
+
+    let count = CALL_COUNT.fetch_add(1, Ordering::Relaxed) + 1;
+    format!("{label}: {count}")
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_call_rate_limited_import(calls: u32) -> Vec<Result<String, RateLimited>> {
+    (0..calls)
+        .map(|call| import_rate_limited(&format!("call {call}")))
+        .collect()
+}
+
+#[fp_export_impl(example_bindings, rename = "export_legacy_string")]
+fn export_renamed_string(arg: String) -> String {
+    import_renamed_string(&arg)
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_string_after_memory_growth(arg: String) -> String {
+    // Allocating a buffer that doesn't fit in the current memory forces it to
+    // grow, both before and after the import is called.
+    let before = vec![1u8; 4 << 20];
+    log(&format!("Allocated {} bytes", before.len()));
+    let after = vec![2u8; 4 << 20];
+    assert_eq!(before.len(), after.len());
+    arg
+}
+
 #[fp_export_impl(example_bindings)]
 fn export_multiple_primitives(arg1: i8, arg2: String) -> i64 {
     assert_eq!(arg1, -8);
@@ -161,6 +214,21 @@ fn export_timestamp(arg: MyDateTime) -> MyDateTime {
     MyDateTime(datetime!(2022-04-13 12:37 UTC))
 }
 
+#[fp_export_impl(example_bindings)]
+fn export_timestamps(arg: Timestamps) -> Timestamps {
+    assert_eq!(arg.created_at, datetime!(2022-04-12 19:10 UTC));
+    assert_eq!(arg.deleted_at, None);
+    Timestamps {
+        created_at: arg.created_at,
+        deleted_at: Some(datetime!(2022-04-13 12:37 UTC)),
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_edge_values(arg: EdgeValues) -> EdgeValues {
+    arg
+}
+
 #[fp_export_impl(example_bindings)]
 fn export_fp_flatten(arg: FpFlatten) -> FpFlatten {
     assert_eq!(
@@ -230,6 +298,14 @@ fn export_generics(arg: StructWithGenerics<u64>) -> StructWithGenerics<u64> {
     }
 }
 
+#[fp_export_impl(example_bindings)]
+fn export_typed_id(id: TypedId<Point<f64>>) -> TypedId<Point<f64>> {
+    TypedId {
+        id: id.id + 1,
+        resource: PhantomData,
+    }
+}
+
 #[fp_export_impl(example_bindings)]
 fn export_fp_struct(arg: FpPropertyRenaming) -> FpPropertyRenaming {
     assert_eq!(
@@ -247,13 +323,16 @@ fn export_fp_struct(arg: FpPropertyRenaming) -> FpPropertyRenaming {
     }
 }
 
+static FP_ENUM: Lazy<FpVariantRenaming> = Lazy::new(|| FpVariantRenaming::QuxBaz {
+    foo_bar: "foo_bar".to_owned(),
+    qux_baz: 64.0,
+});
+
+// Exports may return a borrowed value, which is serialized without cloning.
 #[fp_export_impl(example_bindings)]
-fn export_fp_enum(arg: FpVariantRenaming) -> FpVariantRenaming {
+fn export_fp_enum(arg: FpVariantRenaming) -> &'static FpVariantRenaming {
     assert_eq!(arg, FpVariantRenaming::FooBar);
-    FpVariantRenaming::QuxBaz {
-        foo_bar: "foo_bar".to_owned(),
-        qux_baz: 64.0,
-    }
+    &FP_ENUM
 }
 
 #[fp_export_impl(example_bindings)]
@@ -318,6 +397,32 @@ fn export_serde_untagged(arg: SerdeUntagged) -> SerdeUntagged {
     SerdeUntagged::Baz { a: -8, b: 64 }
 }
 
+#[cfg(feature = "experimental")]
+#[fp_export_impl(example_bindings)]
+fn export_experimental(arg: String) -> String {
+    import_experimental(&arg)
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_dynamic_value(arg: ExtensionSettings) -> Value {
+    assert_eq!(arg.extension, "markdown");
+    assert_eq!(arg.settings, json!({ "tables": true, "tabSize": 4 }));
+    json!({ "enabled": true, "features": ["tables"] })
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_batch_response(arg: BatchResponse) -> BatchResponse {
+    let failures = arg.results.iter().filter(|result| result.is_err()).count();
+    BatchResponse {
+        summary: match failures {
+            0 => Ok("all operations succeeded".to_owned()),
+            failures => Err(format!("{failures} operation(s) failed")),
+        },
+        retry_after: (failures > 0).then_some(Ok(30)),
+        results: arg.results,
+    }
+}
+
 #[fp_export_impl(example_bindings)]
 async fn export_async_struct(arg1: FpPropertyRenaming, arg2: u64) -> FpPropertyRenaming {
     assert_eq!(
@@ -329,6 +434,14 @@ async fn export_async_struct(arg1: FpPropertyRenaming, arg2: u64) -> FpPropertyR
         }
     );
     assert_eq!(arg2, 64);
+
+    // Resolves as soon as the call is handed to the runtime:
+    import_async_void_function("Hello, runtime!").await;
+
+    // The types of generic imports are picked by the plugin:
+    import_set_value("tabSize", 4u32);
+    assert_eq!(import_get_value::<u32>("tabSize").await, Some(4));
+
     FpPropertyRenaming {
         foo_bar: "fooBar".to_owned(),
         qux_baz: -64.0,
@@ -397,8 +510,54 @@ fn export_struct_with_options(arg: StructWithOptions) -> StructWithOptions {
 }
 
 #[fp_export_impl(example_bindings)]
-fn init() {
+fn export_validated_struct(arg: ValidatedStruct) -> ValidatedStruct {
+    let value = import_validated_struct(arg.clone());
+
+    assert_eq!(arg, value);
+
+    value
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_collections(arg: Collections) -> Collections {
+    let value = import_collections(arg.clone());
+
+    assert_eq!(arg, value);
+
+    value
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_samples(samples: SampleColumns) -> SampleColumns {
+    SampleColumns {
+        timestamp_ms: samples.timestamp_ms.iter().map(|ts| ts + 1000).collect(),
+        value: samples.value.iter().map(|value| value * 2.0).collect(),
+        quality: samples.quality,
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_metrics(metrics: Vec<Metric>) -> Vec<Metric> {
+    metrics
+        .into_iter()
+        .map(|metric| Metric {
+            name: metric.name,
+            value: metric.value * 2.0,
+        })
+        .collect()
+}
+
+#[fp_export_impl(example_bindings)]
+fn init(config: PluginConfig) {
     init_panic_hook();
     tracing_subscriber::init();
-    tracing::info!("Example plugin initialized");
+    tracing::info!(log_level = %config.log_level, "Example plugin initialized");
+    on_config_change(|config: &PluginConfig| {
+        tracing::info!(log_level = %config.log_level, "Example plugin reconfigured");
+    });
+}
+
+#[fp_export_impl(example_bindings)]
+fn shutdown() {
+    tracing::info!("Example plugin shutting down");
 }
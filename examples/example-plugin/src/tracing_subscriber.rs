@@ -38,7 +38,7 @@ impl Subscriber for ExampleSubscriber {
 
         let mut v = Visitor { message: None };
         event.record(&mut v);
-        crate::log(format!("{}{}", location, v.message.unwrap_or_else(|| "[Empty message]".to_owned())));
+        crate::log(&format!("{}{}", location, v.message.unwrap_or_else(|| "[Empty message]".to_owned())));
     }
 
     fn enter(&self, _span: &Id) {
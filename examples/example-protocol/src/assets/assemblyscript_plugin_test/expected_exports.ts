@@ -0,0 +1,323 @@
+// ============================================= //
+// Exports for AssemblyScript plugin             //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { fail, Some } from "./support";
+import {
+    BatchResponse,
+    Collections,
+    FpFlatten,
+    FpInternallyTagged,
+    FpPropertyRenaming,
+    FpVariantRenaming,
+    Metric,
+    PluginConfig,
+    Point,
+    RateLimited,
+    ReduxAction,
+    Result,
+    SerdeAdjacentlyTagged,
+    SerdeFlatten,
+    SerdeInternallyTagged,
+    SerdePropertyRenaming,
+    SerdeVariantRenaming,
+    StateUpdate,
+    StructWithOptions,
+    TypedId,
+    ValidatedStruct,
+} from "./types";
+
+// The following exports are not included, because they use types that cannot be
+// represented in AssemblyScript:
+// - export_dynamic_value
+// - export_edge_values
+// - export_fp_adjacently_tagged
+// - export_fp_untagged
+// - export_generics
+// - export_get_bytes
+// - export_get_serde_bytes
+// - export_multiple_values
+// - export_primitive_pair
+// - export_samples
+// - export_serde_untagged
+// - export_timestamp
+// - export_timestamps
+
+/**
+ * The exports of the plugin.
+ *
+ * Plugins extend this class to implement the exports, and pass an instance
+ * of their subclass to `registerExports()`. Exports that are not implemented
+ * report an error when they are called, except for the lifecycle exports,
+ * which do nothing by default.
+ */
+export class Exports {
+    exportArrayF32(arg: Array<f32>): Array<f32> {
+        fail("internal", "Export `export_array_f32` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportArrayF64(arg: Array<f64>): Array<f64> {
+        fail("internal", "Export `export_array_f64` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportArrayI16(arg: Array<i16>): Array<i16> {
+        fail("internal", "Export `export_array_i16` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportArrayI32(arg: Array<i32>): Array<i32> {
+        fail("internal", "Export `export_array_i32` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportArrayI8(arg: Array<i8>): Array<i8> {
+        fail("internal", "Export `export_array_i8` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportArrayU16(arg: Array<u16>): Array<u16> {
+        fail("internal", "Export `export_array_u16` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportArrayU32(arg: Array<u32>): Array<u32> {
+        fail("internal", "Export `export_array_u32` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportArrayU8(arg: Array<u8>): Array<u8> {
+        fail("internal", "Export `export_array_u8` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportAsyncStruct(arg1: FpPropertyRenaming, arg2: u64): FpPropertyRenaming {
+        fail("internal", "Export `export_async_struct` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportBatchResponse(arg: BatchResponse): BatchResponse {
+        fail("internal", "Export `export_batch_response` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportCachedCallCount(label: string): string {
+        fail("internal", "Export `export_cached_call_count` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportCallRateLimitedImport(calls: u32): Array<Result<string, RateLimited>> {
+        fail("internal", "Export `export_call_rate_limited_import` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportCollections(arg: Collections): Collections {
+        fail("internal", "Export `export_collections` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportExperimental(arg: string): string {
+        fail("internal", "Export `export_experimental` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportFpEnum(arg: FpVariantRenaming): FpVariantRenaming {
+        fail("internal", "Export `export_fp_enum` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportFpFlatten(arg: FpFlatten): FpFlatten {
+        fail("internal", "Export `export_fp_flatten` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportFpInternallyTagged(arg: FpInternallyTagged): FpInternallyTagged {
+        fail("internal", "Export `export_fp_internally_tagged` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportFpStruct(arg: FpPropertyRenaming): FpPropertyRenaming {
+        fail("internal", "Export `export_fp_struct` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportLazyString(arg: string): string {
+        fail("internal", "Export `export_lazy_string` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportMetrics(metrics: Array<Metric>): Array<Metric> {
+        fail("internal", "Export `export_metrics` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportMultiplePrimitives(arg1: i8, arg2: string): i64 {
+        fail("internal", "Export `export_multiple_primitives` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveBool(arg: bool): bool {
+        fail("internal", "Export `export_primitive_bool` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveF32(arg: f32): f32 {
+        fail("internal", "Export `export_primitive_f32` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveF64(arg: f64): f64 {
+        fail("internal", "Export `export_primitive_f64` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveI16(arg: i16): i16 {
+        fail("internal", "Export `export_primitive_i16` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveI32(arg: i32): i32 {
+        fail("internal", "Export `export_primitive_i32` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveI64(arg: i64): i64 {
+        fail("internal", "Export `export_primitive_i64` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveI8(arg: i8): i8 {
+        fail("internal", "Export `export_primitive_i8` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveU16(arg: u16): u16 {
+        fail("internal", "Export `export_primitive_u16` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveU32(arg: u32): u32 {
+        fail("internal", "Export `export_primitive_u32` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveU64(arg: u64): u64 {
+        fail("internal", "Export `export_primitive_u64` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportPrimitiveU8(arg: u8): u8 {
+        fail("internal", "Export `export_primitive_u8` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportRenamedString(arg: string): string {
+        fail("internal", "Export `export_renamed_string` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportSerdeAdjacentlyTagged(arg: SerdeAdjacentlyTagged): SerdeAdjacentlyTagged {
+        fail("internal", "Export `export_serde_adjacently_tagged` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportSerdeEnum(arg: SerdeVariantRenaming): SerdeVariantRenaming {
+        fail("internal", "Export `export_serde_enum` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportSerdeFlatten(arg: SerdeFlatten): SerdeFlatten {
+        fail("internal", "Export `export_serde_flatten` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportSerdeInternallyTagged(arg: SerdeInternallyTagged): SerdeInternallyTagged {
+        fail("internal", "Export `export_serde_internally_tagged` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportSerdeStruct(arg: SerdePropertyRenaming): SerdePropertyRenaming {
+        fail("internal", "Export `export_serde_struct` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportString(arg: string): string {
+        fail("internal", "Export `export_string` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportStringAfterMemoryGrowth(arg: string): string {
+        fail("internal", "Export `export_string_after_memory_growth` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportStructWithOptions(arg: StructWithOptions): StructWithOptions {
+        fail("internal", "Export `export_struct_with_options` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportTypedId(id: TypedId<Point<f64>>): TypedId<Point<f64>> {
+        fail("internal", "Export `export_typed_id` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportValidatedStruct(arg: ValidatedStruct): ValidatedStruct {
+        fail("internal", "Export `export_validated_struct` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    exportVoidFunction(): void {
+        fail("internal", "Export `export_void_function` is not implemented by the plugin");
+    }
+
+    /**
+     * Example how plugin could expose async data-fetching capabilities.
+     */
+    fetchData(type_: string): Result<string, string> {
+        fail("internal", "Export `fetch_data` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    /**
+     * Called on the plugin to give it a chance to initialize.
+     */
+    init(config: PluginConfig): void {}
+
+    /**
+     * Example how plugin could expose a reducer.
+     */
+    reducerBridge(action: ReduxAction): StateUpdate {
+        fail("internal", "Export `reducer_bridge` is not implemented by the plugin");
+        return unreachable();
+    }
+
+    /**
+     * Called on the plugin before it is discarded, to give it a chance to
+     * clean up.
+     */
+    shutdown(): void {}
+
+    /**
+     * Called on the plugin when the host changes its config.
+     */
+    updateConfig(config: PluginConfig): void {}
+}
+
+let current = new Exports();
+
+/**
+ * Registers the implementation of the exports.
+ */
+export function registerExports(implementation: Exports): void {
+    current = implementation;
+}
+
+/**
+ * Returns the implementation of the exports.
+ */
+export function getExports(): Exports {
+    return current;
+}
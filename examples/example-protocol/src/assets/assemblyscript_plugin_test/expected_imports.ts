@@ -0,0 +1,611 @@
+// ============================================= //
+// Imports for AssemblyScript plugin             //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { Decoder, Writer } from "@wapc/as-msgpack";
+import { deserialize, serialize, Some } from "./support";
+import {
+    Collections,
+    ExplicitBoundPoint,
+    FpFlatten,
+    FpInternallyTagged,
+    FpPropertyRenaming,
+    FpVariantRenaming,
+    Progress,
+    RateLimited,
+    Result,
+    SerdeAdjacentlyTagged,
+    SerdeFlatten,
+    SerdeInternallyTagged,
+    SerdePropertyRenaming,
+    SerdeVariantRenaming,
+    StructWithOptions,
+    ValidatedStruct,
+} from "./types";
+
+// The following imports are not included, because they use types that cannot be
+// represented in AssemblyScript or return their result asynchronously:
+// - import_arbitrary_precision_numbers
+// - import_fp_adjacently_tagged
+// - import_fp_untagged
+// - import_generics
+// - import_get_bytes
+// - import_get_serde_bytes
+// - import_get_value
+// - import_integer_types
+// - import_multiple_values
+// - import_primitive_pair
+// - import_serde_untagged
+// - import_set_value
+// - import_timestamp
+// - import_void_function_empty_result
+// - make_http_request
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_f32")
+declare function __fp_gen_import_array_f32(argPtr: u64): u64;
+
+export function importArrayF32(arg: Array<f32>): Array<f32> {
+    const argPtr = serialize<Array<f32>>(arg, (writer: Writer, value: Array<f32>): void => {
+        writer.writeArray<f32>(value, (writer: Writer, item: f32): void => {
+            writer.writeFloat32(item);
+        });
+    });
+    return deserialize<Array<f32>>(
+        __fp_gen_import_array_f32(argPtr),
+        (decoder: Decoder): Array<f32> => decoder.readArray<f32>((decoder: Decoder): f32 => decoder.readFloat32())
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_f64")
+declare function __fp_gen_import_array_f64(argPtr: u64): u64;
+
+export function importArrayF64(arg: Array<f64>): Array<f64> {
+    const argPtr = serialize<Array<f64>>(arg, (writer: Writer, value: Array<f64>): void => {
+        writer.writeArray<f64>(value, (writer: Writer, item: f64): void => {
+            writer.writeFloat64(item);
+        });
+    });
+    return deserialize<Array<f64>>(
+        __fp_gen_import_array_f64(argPtr),
+        (decoder: Decoder): Array<f64> => decoder.readArray<f64>((decoder: Decoder): f64 => decoder.readFloat64())
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_i16")
+declare function __fp_gen_import_array_i16(argPtr: u64): u64;
+
+export function importArrayI16(arg: Array<i16>): Array<i16> {
+    const argPtr = serialize<Array<i16>>(arg, (writer: Writer, value: Array<i16>): void => {
+        writer.writeArray<i16>(value, (writer: Writer, item: i16): void => {
+            writer.writeInt16(item);
+        });
+    });
+    return deserialize<Array<i16>>(
+        __fp_gen_import_array_i16(argPtr),
+        (decoder: Decoder): Array<i16> => decoder.readArray<i16>((decoder: Decoder): i16 => decoder.readInt16())
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_i32")
+declare function __fp_gen_import_array_i32(argPtr: u64): u64;
+
+export function importArrayI32(arg: Array<i32>): Array<i32> {
+    const argPtr = serialize<Array<i32>>(arg, (writer: Writer, value: Array<i32>): void => {
+        writer.writeArray<i32>(value, (writer: Writer, item: i32): void => {
+            writer.writeInt32(item);
+        });
+    });
+    return deserialize<Array<i32>>(
+        __fp_gen_import_array_i32(argPtr),
+        (decoder: Decoder): Array<i32> => decoder.readArray<i32>((decoder: Decoder): i32 => decoder.readInt32())
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_i8")
+declare function __fp_gen_import_array_i8(argPtr: u64): u64;
+
+export function importArrayI8(arg: Array<i8>): Array<i8> {
+    const argPtr = serialize<Array<i8>>(arg, (writer: Writer, value: Array<i8>): void => {
+        writer.writeArray<i8>(value, (writer: Writer, item: i8): void => {
+            writer.writeInt8(item);
+        });
+    });
+    return deserialize<Array<i8>>(
+        __fp_gen_import_array_i8(argPtr),
+        (decoder: Decoder): Array<i8> => decoder.readArray<i8>((decoder: Decoder): i8 => decoder.readInt8())
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_u16")
+declare function __fp_gen_import_array_u16(argPtr: u64): u64;
+
+export function importArrayU16(arg: Array<u16>): Array<u16> {
+    const argPtr = serialize<Array<u16>>(arg, (writer: Writer, value: Array<u16>): void => {
+        writer.writeArray<u16>(value, (writer: Writer, item: u16): void => {
+            writer.writeUInt16(item);
+        });
+    });
+    return deserialize<Array<u16>>(
+        __fp_gen_import_array_u16(argPtr),
+        (decoder: Decoder): Array<u16> => decoder.readArray<u16>((decoder: Decoder): u16 => decoder.readUInt16())
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_u32")
+declare function __fp_gen_import_array_u32(argPtr: u64): u64;
+
+export function importArrayU32(arg: Array<u32>): Array<u32> {
+    const argPtr = serialize<Array<u32>>(arg, (writer: Writer, value: Array<u32>): void => {
+        writer.writeArray<u32>(value, (writer: Writer, item: u32): void => {
+            writer.writeUInt32(item);
+        });
+    });
+    return deserialize<Array<u32>>(
+        __fp_gen_import_array_u32(argPtr),
+        (decoder: Decoder): Array<u32> => decoder.readArray<u32>((decoder: Decoder): u32 => decoder.readUInt32())
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_u8")
+declare function __fp_gen_import_array_u8(argPtr: u64): u64;
+
+export function importArrayU8(arg: Array<u8>): Array<u8> {
+    const argPtr = serialize<Array<u8>>(arg, (writer: Writer, value: Array<u8>): void => {
+        writer.writeArray<u8>(value, (writer: Writer, item: u8): void => {
+            writer.writeUInt8(item);
+        });
+    });
+    return deserialize<Array<u8>>(
+        __fp_gen_import_array_u8(argPtr),
+        (decoder: Decoder): Array<u8> => decoder.readArray<u8>((decoder: Decoder): u8 => decoder.readUInt8())
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_async_void_function")
+declare function __fp_gen_import_async_void_function(messagePtr: u64): void;
+
+export function importAsyncVoidFunction(message: string): void {
+    const messagePtr = serialize<string>(message, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    __fp_gen_import_async_void_function(messagePtr);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_collections")
+declare function __fp_gen_import_collections(argPtr: u64): u64;
+
+export function importCollections(arg: Collections): Collections {
+    const argPtr = serialize<Collections>(arg, (writer: Writer, value: Collections): void => {
+        value.encode(writer);
+    });
+    return deserialize<Collections>(
+        __fp_gen_import_collections(argPtr),
+        (decoder: Decoder): Collections => new Collections().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_experimental")
+declare function __fp_gen_import_experimental(argPtr: u64): u64;
+
+/**
+ * Example of an import behind a feature. Only plugins and runtimes that
+ * are compiled with the `experimental` feature know about it.
+ */
+export function importExperimental(arg: string): string {
+    const argPtr = serialize<string>(arg, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return deserialize<string>(
+        __fp_gen_import_experimental(argPtr),
+        (decoder: Decoder): string => decoder.readString()
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_explicit_bound_point")
+declare function __fp_gen_import_explicit_bound_point(argPtr: u64): void;
+
+export function importExplicitBoundPoint(arg: ExplicitBoundPoint<u64>): void {
+    const argPtr = serialize<ExplicitBoundPoint<u64>>(arg, (writer: Writer, value: ExplicitBoundPoint<u64>): void => {
+        value.encode(writer);
+    });
+    __fp_gen_import_explicit_bound_point(argPtr);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_enum")
+declare function __fp_gen_import_fp_enum(argPtr: u64): u64;
+
+export function importFpEnum(arg: FpVariantRenaming): FpVariantRenaming {
+    const argPtr = serialize<FpVariantRenaming>(arg, (writer: Writer, value: FpVariantRenaming): void => {
+        value.encode(writer);
+    });
+    return deserialize<FpVariantRenaming>(
+        __fp_gen_import_fp_enum(argPtr),
+        (decoder: Decoder): FpVariantRenaming => new FpVariantRenaming().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_flatten")
+declare function __fp_gen_import_fp_flatten(argPtr: u64): u64;
+
+export function importFpFlatten(arg: FpFlatten): FpFlatten {
+    const argPtr = serialize<FpFlatten>(arg, (writer: Writer, value: FpFlatten): void => {
+        value.encode(writer);
+    });
+    return deserialize<FpFlatten>(
+        __fp_gen_import_fp_flatten(argPtr),
+        (decoder: Decoder): FpFlatten => new FpFlatten().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_internally_tagged")
+declare function __fp_gen_import_fp_internally_tagged(argPtr: u64): u64;
+
+export function importFpInternallyTagged(arg: FpInternallyTagged): FpInternallyTagged {
+    const argPtr = serialize<FpInternallyTagged>(arg, (writer: Writer, value: FpInternallyTagged): void => {
+        value.encode(writer);
+    });
+    return deserialize<FpInternallyTagged>(
+        __fp_gen_import_fp_internally_tagged(argPtr),
+        (decoder: Decoder): FpInternallyTagged => new FpInternallyTagged().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_struct")
+declare function __fp_gen_import_fp_struct(argPtr: u64): u64;
+
+export function importFpStruct(arg: FpPropertyRenaming): FpPropertyRenaming {
+    const argPtr = serialize<FpPropertyRenaming>(arg, (writer: Writer, value: FpPropertyRenaming): void => {
+        value.encode(writer);
+    });
+    return deserialize<FpPropertyRenaming>(
+        __fp_gen_import_fp_struct(argPtr),
+        (decoder: Decoder): FpPropertyRenaming => new FpPropertyRenaming().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_multiple_primitives")
+declare function __fp_gen_import_multiple_primitives(arg1: i8, arg2Ptr: u64): i64;
+
+export function importMultiplePrimitives(arg1: i8, arg2: string): i64 {
+    const arg2Ptr = serialize<string>(arg2, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return __fp_gen_import_multiple_primitives(arg1, arg2Ptr);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_bool")
+declare function __fp_gen_import_primitive_bool(arg: bool): bool;
+
+export function importPrimitiveBool(arg: bool): bool {
+    return __fp_gen_import_primitive_bool(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f32")
+declare function __fp_gen_import_primitive_f32(arg: f32): f32;
+
+export function importPrimitiveF32(arg: f32): f32 {
+    return __fp_gen_import_primitive_f32(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f64")
+declare function __fp_gen_import_primitive_f64(arg: f64): f64;
+
+export function importPrimitiveF64(arg: f64): f64 {
+    return __fp_gen_import_primitive_f64(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i16")
+declare function __fp_gen_import_primitive_i16(arg: i16): i16;
+
+export function importPrimitiveI16(arg: i16): i16 {
+    return __fp_gen_import_primitive_i16(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i32")
+declare function __fp_gen_import_primitive_i32(arg: i32): i32;
+
+export function importPrimitiveI32(arg: i32): i32 {
+    return __fp_gen_import_primitive_i32(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i64")
+declare function __fp_gen_import_primitive_i64(arg: i64): i64;
+
+export function importPrimitiveI64(arg: i64): i64 {
+    return __fp_gen_import_primitive_i64(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i8")
+declare function __fp_gen_import_primitive_i8(arg: i8): i8;
+
+export function importPrimitiveI8(arg: i8): i8 {
+    return __fp_gen_import_primitive_i8(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u16")
+declare function __fp_gen_import_primitive_u16(arg: u16): u16;
+
+export function importPrimitiveU16(arg: u16): u16 {
+    return __fp_gen_import_primitive_u16(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u32")
+declare function __fp_gen_import_primitive_u32(arg: u32): u32;
+
+export function importPrimitiveU32(arg: u32): u32 {
+    return __fp_gen_import_primitive_u32(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u64")
+declare function __fp_gen_import_primitive_u64(arg: u64): u64;
+
+export function importPrimitiveU64(arg: u64): u64 {
+    return __fp_gen_import_primitive_u64(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u8")
+declare function __fp_gen_import_primitive_u8(arg: u8): u8;
+
+export function importPrimitiveU8(arg: u8): u8 {
+    return __fp_gen_import_primitive_u8(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_rate_limited")
+declare function __fp_gen_import_rate_limited(labelPtr: u64): u64;
+
+/**
+ * Example of a rate-limited import. Calls that exceed the limit return a
+ * `RateLimited` error to the plugin, instead of reaching the runtime.
+ */
+export function importRateLimited(label: string): Result<string, RateLimited> {
+    const labelPtr = serialize<string>(label, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return deserialize<Result<string, RateLimited>>(
+        __fp_gen_import_rate_limited(labelPtr),
+        (decoder: Decoder): Result<string, RateLimited> => new Result<string, RateLimited>().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_legacy_string")
+declare function __fp_gen_import_renamed_string(argPtr: u64): u64;
+
+/**
+ * Example of a renamed import. It is still exchanged under its old name,
+ * so plugins that were built against `import_legacy_string` keep working.
+ */
+export function importRenamedString(arg: string): string {
+    const argPtr = serialize<string>(arg, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return deserialize<string>(
+        __fp_gen_import_renamed_string(argPtr),
+        (decoder: Decoder): string => decoder.readString()
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_adjacently_tagged")
+declare function __fp_gen_import_serde_adjacently_tagged(argPtr: u64): u64;
+
+export function importSerdeAdjacentlyTagged(arg: SerdeAdjacentlyTagged): SerdeAdjacentlyTagged {
+    const argPtr = serialize<SerdeAdjacentlyTagged>(arg, (writer: Writer, value: SerdeAdjacentlyTagged): void => {
+        value.encode(writer);
+    });
+    return deserialize<SerdeAdjacentlyTagged>(
+        __fp_gen_import_serde_adjacently_tagged(argPtr),
+        (decoder: Decoder): SerdeAdjacentlyTagged => new SerdeAdjacentlyTagged().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_enum")
+declare function __fp_gen_import_serde_enum(argPtr: u64): u64;
+
+export function importSerdeEnum(arg: SerdeVariantRenaming): SerdeVariantRenaming {
+    const argPtr = serialize<SerdeVariantRenaming>(arg, (writer: Writer, value: SerdeVariantRenaming): void => {
+        value.encode(writer);
+    });
+    return deserialize<SerdeVariantRenaming>(
+        __fp_gen_import_serde_enum(argPtr),
+        (decoder: Decoder): SerdeVariantRenaming => new SerdeVariantRenaming().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_flatten")
+declare function __fp_gen_import_serde_flatten(argPtr: u64): u64;
+
+export function importSerdeFlatten(arg: SerdeFlatten): SerdeFlatten {
+    const argPtr = serialize<SerdeFlatten>(arg, (writer: Writer, value: SerdeFlatten): void => {
+        value.encode(writer);
+    });
+    return deserialize<SerdeFlatten>(
+        __fp_gen_import_serde_flatten(argPtr),
+        (decoder: Decoder): SerdeFlatten => new SerdeFlatten().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_internally_tagged")
+declare function __fp_gen_import_serde_internally_tagged(argPtr: u64): u64;
+
+export function importSerdeInternallyTagged(arg: SerdeInternallyTagged): SerdeInternallyTagged {
+    const argPtr = serialize<SerdeInternallyTagged>(arg, (writer: Writer, value: SerdeInternallyTagged): void => {
+        value.encode(writer);
+    });
+    return deserialize<SerdeInternallyTagged>(
+        __fp_gen_import_serde_internally_tagged(argPtr),
+        (decoder: Decoder): SerdeInternallyTagged => new SerdeInternallyTagged().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_struct")
+declare function __fp_gen_import_serde_struct(argPtr: u64): u64;
+
+export function importSerdeStruct(arg: SerdePropertyRenaming): SerdePropertyRenaming {
+    const argPtr = serialize<SerdePropertyRenaming>(arg, (writer: Writer, value: SerdePropertyRenaming): void => {
+        value.encode(writer);
+    });
+    return deserialize<SerdePropertyRenaming>(
+        __fp_gen_import_serde_struct(argPtr),
+        (decoder: Decoder): SerdePropertyRenaming => new SerdePropertyRenaming().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_string")
+declare function __fp_gen_import_string(argPtr: u64): u64;
+
+export function importString(arg: string): string {
+    const argPtr = serialize<string>(arg, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return deserialize<string>(
+        __fp_gen_import_string(argPtr),
+        (decoder: Decoder): string => decoder.readString()
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_struct_with_options")
+declare function __fp_gen_import_struct_with_options(argPtr: u64): u64;
+
+export function importStructWithOptions(arg: StructWithOptions): StructWithOptions {
+    const argPtr = serialize<StructWithOptions>(arg, (writer: Writer, value: StructWithOptions): void => {
+        value.encode(writer);
+    });
+    return deserialize<StructWithOptions>(
+        __fp_gen_import_struct_with_options(argPtr),
+        (decoder: Decoder): StructWithOptions => new StructWithOptions().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_validated_struct")
+declare function __fp_gen_import_validated_struct(argPtr: u64): u64;
+
+export function importValidatedStruct(arg: ValidatedStruct): ValidatedStruct {
+    const argPtr = serialize<ValidatedStruct>(arg, (writer: Writer, value: ValidatedStruct): void => {
+        value.encode(writer);
+    });
+    return deserialize<ValidatedStruct>(
+        __fp_gen_import_validated_struct(argPtr),
+        (decoder: Decoder): ValidatedStruct => new ValidatedStruct().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_void_function")
+declare function __fp_gen_import_void_function(): void;
+
+export function importVoidFunction(): void {
+    __fp_gen_import_void_function();
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_void_function_empty_return")
+declare function __fp_gen_import_void_function_empty_return(): void;
+
+export function importVoidFunctionEmptyReturn(): void {
+    __fp_gen_import_void_function_empty_return();
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_invoke_command")
+declare function __fp_gen_invoke_command(namePtr: u64, payloadPtr: u64): u64;
+
+/**
+ * Example of a dynamic import. The runtime can register handlers for it
+ * after instantiation, which are dispatched to by `name`. Calls for which
+ * no handler is registered fall back to the regular implementation.
+ */
+export function invokeCommand(name: string, payload: string): Result<string, string> {
+    const namePtr = serialize<string>(name, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    const payloadPtr = serialize<string>(payload, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return deserialize<Result<string, string>>(
+        __fp_gen_invoke_command(namePtr, payloadPtr),
+        (decoder: Decoder): Result<string, string> => new Result<string, string>().decode(decoder)
+    );
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_log")
+declare function __fp_gen_log(messagePtr: u64): void;
+
+/**
+ * Logs a message to the (development) console.
+ */
+export function log(message: string): void {
+    const messagePtr = serialize<string>(message, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    __fp_gen_log(messagePtr);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_record_metric")
+declare function __fp_gen_record_metric(namePtr: u64, value: f64): void;
+
+/**
+ * Example of a batchable import. Calls that are made inside `batch()` are
+ * queued by the plugin and sent to the runtime together.
+ */
+export function recordMetric(name: string, value: f64): void {
+    const namePtr = serialize<string>(name, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    __fp_gen_record_metric(namePtr, value);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_report_progress")
+declare function __fp_gen_report_progress(progressPtr: u64): void;
+
+/**
+ * Example of an event. The plugin can emit it at any time, after which
+ * the runtime passes it on to its subscribers.
+ */
+export function reportProgress(progress: Progress): void {
+    const progressPtr = serialize<Progress>(progress, (writer: Writer, value: Progress): void => {
+        value.encode(writer);
+    });
+    __fp_gen_report_progress(progressPtr);
+}
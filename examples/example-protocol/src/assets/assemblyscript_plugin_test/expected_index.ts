@@ -0,0 +1,526 @@
+// ============================================= //
+// Entry point for AssemblyScript plugin         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { Decoder, Writer } from "@wapc/as-msgpack";
+import { getExports } from "./exports";
+import { deserialize, resolveAsyncValue, serialize, Some } from "./support";
+import {
+    BatchResponse,
+    Collections,
+    FpFlatten,
+    FpInternallyTagged,
+    FpPropertyRenaming,
+    FpVariantRenaming,
+    Metric,
+    PluginConfig,
+    Point,
+    RateLimited,
+    ReduxAction,
+    Result,
+    SerdeAdjacentlyTagged,
+    SerdeFlatten,
+    SerdeInternallyTagged,
+    SerdePropertyRenaming,
+    SerdeVariantRenaming,
+    StateUpdate,
+    StructWithOptions,
+    TypedId,
+    ValidatedStruct,
+} from "./types";
+
+export {
+    __fp_free,
+    __fp_guest_resolve_async_value,
+    __fp_malloc,
+} from "./support";
+
+export function __fp_gen_export_array_f32(argPtr: u64): u64 {
+    const arg = deserialize<Array<f32>>(
+        argPtr,
+        (decoder: Decoder): Array<f32> => decoder.readArray<f32>((decoder: Decoder): f32 => decoder.readFloat32())
+    );
+    const result = getExports().exportArrayF32(arg);
+    const resultPtr = serialize<Array<f32>>(result, (writer: Writer, value: Array<f32>): void => {
+        writer.writeArray<f32>(value, (writer: Writer, item: f32): void => {
+            writer.writeFloat32(item);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_array_f64(argPtr: u64): u64 {
+    const arg = deserialize<Array<f64>>(
+        argPtr,
+        (decoder: Decoder): Array<f64> => decoder.readArray<f64>((decoder: Decoder): f64 => decoder.readFloat64())
+    );
+    const result = getExports().exportArrayF64(arg);
+    const resultPtr = serialize<Array<f64>>(result, (writer: Writer, value: Array<f64>): void => {
+        writer.writeArray<f64>(value, (writer: Writer, item: f64): void => {
+            writer.writeFloat64(item);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_array_i16(argPtr: u64): u64 {
+    const arg = deserialize<Array<i16>>(
+        argPtr,
+        (decoder: Decoder): Array<i16> => decoder.readArray<i16>((decoder: Decoder): i16 => decoder.readInt16())
+    );
+    const result = getExports().exportArrayI16(arg);
+    const resultPtr = serialize<Array<i16>>(result, (writer: Writer, value: Array<i16>): void => {
+        writer.writeArray<i16>(value, (writer: Writer, item: i16): void => {
+            writer.writeInt16(item);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_array_i32(argPtr: u64): u64 {
+    const arg = deserialize<Array<i32>>(
+        argPtr,
+        (decoder: Decoder): Array<i32> => decoder.readArray<i32>((decoder: Decoder): i32 => decoder.readInt32())
+    );
+    const result = getExports().exportArrayI32(arg);
+    const resultPtr = serialize<Array<i32>>(result, (writer: Writer, value: Array<i32>): void => {
+        writer.writeArray<i32>(value, (writer: Writer, item: i32): void => {
+            writer.writeInt32(item);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_array_i8(argPtr: u64): u64 {
+    const arg = deserialize<Array<i8>>(
+        argPtr,
+        (decoder: Decoder): Array<i8> => decoder.readArray<i8>((decoder: Decoder): i8 => decoder.readInt8())
+    );
+    const result = getExports().exportArrayI8(arg);
+    const resultPtr = serialize<Array<i8>>(result, (writer: Writer, value: Array<i8>): void => {
+        writer.writeArray<i8>(value, (writer: Writer, item: i8): void => {
+            writer.writeInt8(item);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_array_u16(argPtr: u64): u64 {
+    const arg = deserialize<Array<u16>>(
+        argPtr,
+        (decoder: Decoder): Array<u16> => decoder.readArray<u16>((decoder: Decoder): u16 => decoder.readUInt16())
+    );
+    const result = getExports().exportArrayU16(arg);
+    const resultPtr = serialize<Array<u16>>(result, (writer: Writer, value: Array<u16>): void => {
+        writer.writeArray<u16>(value, (writer: Writer, item: u16): void => {
+            writer.writeUInt16(item);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_array_u32(argPtr: u64): u64 {
+    const arg = deserialize<Array<u32>>(
+        argPtr,
+        (decoder: Decoder): Array<u32> => decoder.readArray<u32>((decoder: Decoder): u32 => decoder.readUInt32())
+    );
+    const result = getExports().exportArrayU32(arg);
+    const resultPtr = serialize<Array<u32>>(result, (writer: Writer, value: Array<u32>): void => {
+        writer.writeArray<u32>(value, (writer: Writer, item: u32): void => {
+            writer.writeUInt32(item);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_array_u8(argPtr: u64): u64 {
+    const arg = deserialize<Array<u8>>(
+        argPtr,
+        (decoder: Decoder): Array<u8> => decoder.readArray<u8>((decoder: Decoder): u8 => decoder.readUInt8())
+    );
+    const result = getExports().exportArrayU8(arg);
+    const resultPtr = serialize<Array<u8>>(result, (writer: Writer, value: Array<u8>): void => {
+        writer.writeArray<u8>(value, (writer: Writer, item: u8): void => {
+            writer.writeUInt8(item);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_async_struct(arg1Ptr: u64, arg2: u64): u64 {
+    const arg1 = deserialize<FpPropertyRenaming>(
+        arg1Ptr,
+        (decoder: Decoder): FpPropertyRenaming => new FpPropertyRenaming().decode(decoder)
+    );
+    const result = getExports().exportAsyncStruct(arg1, arg2);
+    const resultPtr = serialize<FpPropertyRenaming>(result, (writer: Writer, value: FpPropertyRenaming): void => {
+        value.encode(writer);
+    });
+    return resolveAsyncValue(resultPtr);
+}
+
+export function __fp_gen_export_batch_response(argPtr: u64): u64 {
+    const arg = deserialize<BatchResponse>(
+        argPtr,
+        (decoder: Decoder): BatchResponse => new BatchResponse().decode(decoder)
+    );
+    const result = getExports().exportBatchResponse(arg);
+    const resultPtr = serialize<BatchResponse>(result, (writer: Writer, value: BatchResponse): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_cached_call_count(labelPtr: u64): u64 {
+    const label = deserialize<string>(
+        labelPtr,
+        (decoder: Decoder): string => decoder.readString()
+    );
+    const result = getExports().exportCachedCallCount(label);
+    const resultPtr = serialize<string>(result, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_call_rate_limited_import(calls: u32): u64 {
+    const result = getExports().exportCallRateLimitedImport(calls);
+    const resultPtr = serialize<Array<Result<string, RateLimited>>>(result, (writer: Writer, value: Array<Result<string, RateLimited>>): void => {
+        writer.writeArray<Result<string, RateLimited>>(value, (writer: Writer, item: Result<string, RateLimited>): void => {
+            item.encode(writer);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_collections(argPtr: u64): u64 {
+    const arg = deserialize<Collections>(
+        argPtr,
+        (decoder: Decoder): Collections => new Collections().decode(decoder)
+    );
+    const result = getExports().exportCollections(arg);
+    const resultPtr = serialize<Collections>(result, (writer: Writer, value: Collections): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_experimental(argPtr: u64): u64 {
+    const arg = deserialize<string>(
+        argPtr,
+        (decoder: Decoder): string => decoder.readString()
+    );
+    const result = getExports().exportExperimental(arg);
+    const resultPtr = serialize<string>(result, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_fp_enum(argPtr: u64): u64 {
+    const arg = deserialize<FpVariantRenaming>(
+        argPtr,
+        (decoder: Decoder): FpVariantRenaming => new FpVariantRenaming().decode(decoder)
+    );
+    const result = getExports().exportFpEnum(arg);
+    const resultPtr = serialize<FpVariantRenaming>(result, (writer: Writer, value: FpVariantRenaming): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_fp_flatten(argPtr: u64): u64 {
+    const arg = deserialize<FpFlatten>(
+        argPtr,
+        (decoder: Decoder): FpFlatten => new FpFlatten().decode(decoder)
+    );
+    const result = getExports().exportFpFlatten(arg);
+    const resultPtr = serialize<FpFlatten>(result, (writer: Writer, value: FpFlatten): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_fp_internally_tagged(argPtr: u64): u64 {
+    const arg = deserialize<FpInternallyTagged>(
+        argPtr,
+        (decoder: Decoder): FpInternallyTagged => new FpInternallyTagged().decode(decoder)
+    );
+    const result = getExports().exportFpInternallyTagged(arg);
+    const resultPtr = serialize<FpInternallyTagged>(result, (writer: Writer, value: FpInternallyTagged): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_fp_struct(argPtr: u64): u64 {
+    const arg = deserialize<FpPropertyRenaming>(
+        argPtr,
+        (decoder: Decoder): FpPropertyRenaming => new FpPropertyRenaming().decode(decoder)
+    );
+    const result = getExports().exportFpStruct(arg);
+    const resultPtr = serialize<FpPropertyRenaming>(result, (writer: Writer, value: FpPropertyRenaming): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_lazy_string(argPtr: u64): u64 {
+    const arg = deserialize<string>(
+        argPtr,
+        (decoder: Decoder): string => decoder.readString()
+    );
+    const result = getExports().exportLazyString(arg);
+    const resultPtr = serialize<string>(result, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_metrics(metricsPtr: u64): u64 {
+    const metrics = deserialize<Array<Metric>>(
+        metricsPtr,
+        (decoder: Decoder): Array<Metric> => decoder.readArray<Metric>((decoder: Decoder): Metric => new Metric().decode(decoder))
+    );
+    const result = getExports().exportMetrics(metrics);
+    const resultPtr = serialize<Array<Metric>>(result, (writer: Writer, value: Array<Metric>): void => {
+        writer.writeArray<Metric>(value, (writer: Writer, item: Metric): void => {
+            item.encode(writer);
+        });
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_multiple_primitives(arg1: i8, arg2Ptr: u64): i64 {
+    const arg2 = deserialize<string>(
+        arg2Ptr,
+        (decoder: Decoder): string => decoder.readString()
+    );
+    return getExports().exportMultiplePrimitives(arg1, arg2);
+}
+
+export function __fp_gen_export_primitive_bool(arg: bool): bool {
+    return getExports().exportPrimitiveBool(arg);
+}
+
+export function __fp_gen_export_primitive_f32(arg: f32): f32 {
+    return getExports().exportPrimitiveF32(arg);
+}
+
+export function __fp_gen_export_primitive_f64(arg: f64): f64 {
+    return getExports().exportPrimitiveF64(arg);
+}
+
+export function __fp_gen_export_primitive_i16(arg: i16): i16 {
+    return getExports().exportPrimitiveI16(arg);
+}
+
+export function __fp_gen_export_primitive_i32(arg: i32): i32 {
+    return getExports().exportPrimitiveI32(arg);
+}
+
+export function __fp_gen_export_primitive_i64(arg: i64): i64 {
+    return getExports().exportPrimitiveI64(arg);
+}
+
+export function __fp_gen_export_primitive_i8(arg: i8): i8 {
+    return getExports().exportPrimitiveI8(arg);
+}
+
+export function __fp_gen_export_primitive_u16(arg: u16): u16 {
+    return getExports().exportPrimitiveU16(arg);
+}
+
+export function __fp_gen_export_primitive_u32(arg: u32): u32 {
+    return getExports().exportPrimitiveU32(arg);
+}
+
+export function __fp_gen_export_primitive_u64(arg: u64): u64 {
+    return getExports().exportPrimitiveU64(arg);
+}
+
+export function __fp_gen_export_primitive_u8(arg: u8): u8 {
+    return getExports().exportPrimitiveU8(arg);
+}
+
+export function __fp_gen_export_legacy_string(argPtr: u64): u64 {
+    const arg = deserialize<string>(
+        argPtr,
+        (decoder: Decoder): string => decoder.readString()
+    );
+    const result = getExports().exportRenamedString(arg);
+    const resultPtr = serialize<string>(result, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_serde_adjacently_tagged(argPtr: u64): u64 {
+    const arg = deserialize<SerdeAdjacentlyTagged>(
+        argPtr,
+        (decoder: Decoder): SerdeAdjacentlyTagged => new SerdeAdjacentlyTagged().decode(decoder)
+    );
+    const result = getExports().exportSerdeAdjacentlyTagged(arg);
+    const resultPtr = serialize<SerdeAdjacentlyTagged>(result, (writer: Writer, value: SerdeAdjacentlyTagged): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_serde_enum(argPtr: u64): u64 {
+    const arg = deserialize<SerdeVariantRenaming>(
+        argPtr,
+        (decoder: Decoder): SerdeVariantRenaming => new SerdeVariantRenaming().decode(decoder)
+    );
+    const result = getExports().exportSerdeEnum(arg);
+    const resultPtr = serialize<SerdeVariantRenaming>(result, (writer: Writer, value: SerdeVariantRenaming): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_serde_flatten(argPtr: u64): u64 {
+    const arg = deserialize<SerdeFlatten>(
+        argPtr,
+        (decoder: Decoder): SerdeFlatten => new SerdeFlatten().decode(decoder)
+    );
+    const result = getExports().exportSerdeFlatten(arg);
+    const resultPtr = serialize<SerdeFlatten>(result, (writer: Writer, value: SerdeFlatten): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_serde_internally_tagged(argPtr: u64): u64 {
+    const arg = deserialize<SerdeInternallyTagged>(
+        argPtr,
+        (decoder: Decoder): SerdeInternallyTagged => new SerdeInternallyTagged().decode(decoder)
+    );
+    const result = getExports().exportSerdeInternallyTagged(arg);
+    const resultPtr = serialize<SerdeInternallyTagged>(result, (writer: Writer, value: SerdeInternallyTagged): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_serde_struct(argPtr: u64): u64 {
+    const arg = deserialize<SerdePropertyRenaming>(
+        argPtr,
+        (decoder: Decoder): SerdePropertyRenaming => new SerdePropertyRenaming().decode(decoder)
+    );
+    const result = getExports().exportSerdeStruct(arg);
+    const resultPtr = serialize<SerdePropertyRenaming>(result, (writer: Writer, value: SerdePropertyRenaming): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_string(argPtr: u64): u64 {
+    const arg = deserialize<string>(
+        argPtr,
+        (decoder: Decoder): string => decoder.readString()
+    );
+    const result = getExports().exportString(arg);
+    const resultPtr = serialize<string>(result, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_string_after_memory_growth(argPtr: u64): u64 {
+    const arg = deserialize<string>(
+        argPtr,
+        (decoder: Decoder): string => decoder.readString()
+    );
+    const result = getExports().exportStringAfterMemoryGrowth(arg);
+    const resultPtr = serialize<string>(result, (writer: Writer, value: string): void => {
+        writer.writeString(value);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_struct_with_options(argPtr: u64): u64 {
+    const arg = deserialize<StructWithOptions>(
+        argPtr,
+        (decoder: Decoder): StructWithOptions => new StructWithOptions().decode(decoder)
+    );
+    const result = getExports().exportStructWithOptions(arg);
+    const resultPtr = serialize<StructWithOptions>(result, (writer: Writer, value: StructWithOptions): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_typed_id(idPtr: u64): u64 {
+    const id = deserialize<TypedId<Point<f64>>>(
+        idPtr,
+        (decoder: Decoder): TypedId<Point<f64>> => new TypedId<Point<f64>>().decode(decoder)
+    );
+    const result = getExports().exportTypedId(id);
+    const resultPtr = serialize<TypedId<Point<f64>>>(result, (writer: Writer, value: TypedId<Point<f64>>): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_validated_struct(argPtr: u64): u64 {
+    const arg = deserialize<ValidatedStruct>(
+        argPtr,
+        (decoder: Decoder): ValidatedStruct => new ValidatedStruct().decode(decoder)
+    );
+    const result = getExports().exportValidatedStruct(arg);
+    const resultPtr = serialize<ValidatedStruct>(result, (writer: Writer, value: ValidatedStruct): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_export_void_function(): void {
+    getExports().exportVoidFunction();
+}
+
+export function __fp_gen_fetch_data(typePtr: u64): u64 {
+    const type_ = deserialize<string>(
+        typePtr,
+        (decoder: Decoder): string => decoder.readString()
+    );
+    const result = getExports().fetchData(type_);
+    const resultPtr = serialize<Result<string, string>>(result, (writer: Writer, value: Result<string, string>): void => {
+        value.encode(writer);
+    });
+    return resolveAsyncValue(resultPtr);
+}
+
+export function __fp_gen_init(configPtr: u64): void {
+    const config = deserialize<PluginConfig>(
+        configPtr,
+        (decoder: Decoder): PluginConfig => new PluginConfig().decode(decoder)
+    );
+    getExports().init(config);
+}
+
+export function __fp_gen_reducer_bridge(actionPtr: u64): u64 {
+    const action = deserialize<ReduxAction>(
+        actionPtr,
+        (decoder: Decoder): ReduxAction => new ReduxAction().decode(decoder)
+    );
+    const result = getExports().reducerBridge(action);
+    const resultPtr = serialize<StateUpdate>(result, (writer: Writer, value: StateUpdate): void => {
+        value.encode(writer);
+    });
+    return resultPtr;
+}
+
+export function __fp_gen_shutdown(): void {
+    getExports().shutdown();
+}
+
+export function __fp_gen_update_config(configPtr: u64): void {
+    const config = deserialize<PluginConfig>(
+        configPtr,
+        (decoder: Decoder): PluginConfig => new PluginConfig().decode(decoder)
+    );
+    getExports().updateConfig(config);
+}
@@ -0,0 +1,215 @@
+// ============================================= //
+// Support for AssemblyScript plugin             //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { Decoder, Encoder, Sizer, Writer } from "@wapc/as-msgpack";
+
+// @ts-ignore: decorator
+@external("fp", "__fp_error")
+declare function __fp_error(report: u64): void;
+
+// @ts-ignore: decorator
+@external("fp", "__fp_host_resolve_async_value")
+declare function __fp_host_resolve_async_value(asyncValuePtr: u64, resultPtr: u64): void;
+
+/**
+ * Holds a value of a primitive type, so that it can be optional.
+ */
+export class Some<T> {
+    constructor(public value: T) {}
+}
+
+/**
+ * An error that is reported to the runtime.
+ */
+class GuestError {
+    constructor(public kind: string, public message: string) {}
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("kind");
+        writer.writeString(this.kind);
+        writer.writeString("message");
+        writer.writeString(this.message);
+    }
+}
+
+/**
+ * Reports an error of the given kind to the runtime, after which the plugin
+ * traps.
+ */
+export function fail(kind: string, message: string): void {
+    const error = new GuestError(kind, message);
+    __fp_error(
+        serialize<GuestError>(error, (writer: Writer, error: GuestError): void => {
+            error.encode(writer);
+        })
+    );
+    unreachable();
+}
+
+/**
+ * Reports aborts, such as failed assertions and thrown errors, to the runtime
+ * as panics.
+ *
+ * Plugins are compiled with `--use abort=<path to the bindings>/support/abort`,
+ * so that AssemblyScript doesn't import its own `abort()` from the runtime.
+ */
+export function abort(
+    message: string | null,
+    fileName: string | null,
+    line: u32,
+    column: u32
+): void {
+    fail(
+        "panic",
+        (message !== null ? message! : "abort") +
+            " at " +
+            (fileName !== null ? fileName! : "unknown") +
+            ":" +
+            line.toString() +
+            ":" +
+            column.toString()
+    );
+}
+
+export function __fp_malloc(len: u32): u64 {
+    return toFatPtr(heap.alloc(<usize>len), len);
+}
+
+export function __fp_free(fatPtr: u64): void {
+    heap.free(<usize>(fatPtr >> 32));
+}
+
+/**
+ * Resolves an async value that was returned to the runtime by an async import.
+ *
+ * The bindings don't support async imports with return values, but the
+ * runtimes expect plugins to export this nonetheless.
+ */
+export function __fp_guest_resolve_async_value(asyncValuePtr: u64, resultPtr: u64): void {
+    const ptr = <usize>(asyncValuePtr >> 32);
+    store<u32>(ptr, <u32>(resultPtr >> 32), 4);
+    store<u32>(ptr, <u32>(resultPtr & 0xffffffff), 8);
+    store<u32>(ptr, 1); // Ready.
+}
+
+/**
+ * Returns an async value that is already resolved with the given result, for
+ * async exports.
+ */
+export function resolveAsyncValue(resultPtr: u64): u64 {
+    const len: u32 = 12; // The size of an `AsyncValue`.
+    const ptr = heap.alloc(len);
+    memory.fill(ptr, 0, len);
+    const asyncValuePtr = toFatPtr(ptr, len);
+    __fp_host_resolve_async_value(asyncValuePtr, resultPtr);
+    return asyncValuePtr;
+}
+
+function toFatPtr(ptr: usize, len: u32): u64 {
+    return (<u64>ptr << 32) | <u64>len;
+}
+
+/**
+ * Serializes a value into memory that is handed over to the runtime, which
+ * frees it once it's done with it.
+ */
+export function serialize<T>(value: T, encode: (writer: Writer, value: T) => void): u64 {
+    const sizer = new Sizer();
+    encode(sizer, value);
+    const buffer = new ArrayBuffer(<i32>sizer.length);
+    encode(new Encoder(buffer), value);
+
+    const len = <u32>buffer.byteLength;
+    const ptr = heap.alloc(len);
+    memory.copy(ptr, changetype<usize>(buffer), len);
+    return toFatPtr(ptr, len);
+}
+
+/**
+ * Deserializes a value from memory that was handed over by the runtime, which
+ * is freed afterwards.
+ */
+export function deserialize<T>(fatPtr: u64, decode: (decoder: Decoder) => T): T {
+    const ptr = <usize>(fatPtr >> 32);
+    const len = <u32>(fatPtr & 0xffffffff);
+    if ((len & 0xff000000) != 0) {
+        fail("invalid_pointer", "Unknown extension bits in pointer");
+    }
+
+    const buffer = new ArrayBuffer(<i32>len);
+    memory.copy(changetype<usize>(buffer), ptr, len);
+    heap.free(ptr);
+    return decode(new Decoder(buffer));
+}
+
+/**
+ * Skips the given number of entries of a map.
+ */
+export function skipMapEntries(decoder: Decoder, count: u32): void {
+    for (let i: u32 = 0; i < count; i++) {
+        decoder.skip();
+        decoder.skip();
+    }
+}
+
+/**
+ * Encodes a value of a type parameter, which may be a primitive, a string or
+ * any of the generated types.
+ */
+export function encodeValue<T>(writer: Writer, value: T): void {
+    if (isBoolean<T>()) {
+        writer.writeBool(<bool>value);
+    } else if (isInteger<T>()) {
+        if (isSigned<T>()) {
+            writer.writeInt64(<i64>value);
+        } else {
+            writer.writeUInt64(<u64>value);
+        }
+    } else if (isFloat<T>()) {
+        if (sizeof<T>() == 4) {
+            writer.writeFloat32(<f32>value);
+        } else {
+            writer.writeFloat64(<f64>value);
+        }
+    } else if (isString<T>()) {
+        writer.writeString(changetype<string>(value));
+    } else if (changetype<usize>(value) == 0) {
+        writer.writeNil();
+    } else {
+        // @ts-ignore: generated types implement `encode()`
+        changetype<nonnull<T>>(value).encode(writer);
+    }
+}
+
+/**
+ * Decodes a value of a type parameter, which may be a primitive, a string or
+ * any of the generated types.
+ */
+export function decodeValue<T>(decoder: Decoder): T {
+    if (isBoolean<T>()) {
+        return <T>decoder.readBool();
+    } else if (isInteger<T>()) {
+        if (isSigned<T>()) {
+            return <T>decoder.readInt64();
+        } else {
+            return <T>decoder.readUInt64();
+        }
+    } else if (isFloat<T>()) {
+        if (sizeof<T>() == 4) {
+            return <T>decoder.readFloat32();
+        } else {
+            return <T>decoder.readFloat64();
+        }
+    } else if (isString<T>()) {
+        return changetype<T>(decoder.readString());
+    } else if (isNullable<T>() && decoder.isNextNil()) {
+        return changetype<T>(0);
+    } else {
+        // @ts-ignore: generated types implement `decode()`
+        return changetype<T>(instantiate<nonnull<T>>().decode(decoder));
+    }
+}
@@ -0,0 +1,1413 @@
+// ============================================= //
+// Types for AssemblyScript plugin               //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { Decoder, Writer } from "@wapc/as-msgpack";
+import { decodeValue, encodeValue, fail, skipMapEntries, Some } from "./support";
+
+/**
+ * The response to a batch of operations, some of which may have failed.
+ */
+export class BatchResponse {
+    results!: Array<Result<u64, string>>;
+    summary!: Result<string, string>;
+    retryAfter: Result<u32, string> | null = null;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(3);
+        writer.writeString("results");
+        writer.writeArray<Result<u64, string>>(this.results, (writer: Writer, item: Result<u64, string>): void => {
+            item.encode(writer);
+        });
+        writer.writeString("summary");
+        this.summary.encode(writer);
+        writer.writeString("retryAfter");
+        if (this.retryAfter === null) {
+            writer.writeNil();
+        } else {
+            this.retryAfter!.encode(writer);
+        }
+    }
+
+    decode(decoder: Decoder): BatchResponse {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "results") {
+                this.results = decoder.readArray<Result<u64, string>>((decoder: Decoder): Result<u64, string> => new Result<u64, string>().decode(decoder));
+            } else if (key == "summary") {
+                this.summary = new Result<string, string>().decode(decoder);
+            } else if (key == "retryAfter") {
+                this.retryAfter = decoder.isNextNil() ? null : new Result<u32, string>().decode(decoder);
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * Std collections that are encoded as lists.
+ *
+ * Sets are generated as `Set` types in TypeScript if `generate_set_types` is
+ * enabled, while other lists are always generated as arrays.
+ */
+export class Collections {
+    tags!: Array<string>;
+    optionalTags: Array<string> | null = null;
+    queue!: Array<u32>;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(3);
+        writer.writeString("tags");
+        writer.writeArray<string>(this.tags, (writer: Writer, item: string): void => {
+            writer.writeString(item);
+        });
+        writer.writeString("optionalTags");
+        if (this.optionalTags === null) {
+            writer.writeNil();
+        } else {
+            writer.writeArray<string>(this.optionalTags!, (writer: Writer, item: string): void => {
+                writer.writeString(item);
+            });
+        }
+        writer.writeString("queue");
+        writer.writeArray<u32>(this.queue, (writer: Writer, item: u32): void => {
+            writer.writeUInt32(item);
+        });
+    }
+
+    decode(decoder: Decoder): Collections {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "tags") {
+                this.tags = decoder.readArray<string>((decoder: Decoder): string => decoder.readString());
+            } else if (key == "optionalTags") {
+                this.optionalTags = decoder.isNextNil() ? null : decoder.readArray<string>((decoder: Decoder): string => decoder.readString());
+            } else if (key == "queue") {
+                this.queue = decoder.readArray<u32>((decoder: Decoder): u32 => decoder.readUInt32());
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * Fields and variants behind a `#[cfg(...)]` attribute are only included in
+ * the bindings if the protocol is compiled with the condition enabled.
+ *
+ * Using `#[fp(generators = "...")]`, fields and variants can also be limited
+ * to the bindings of specific generators.
+ */
+export class ConditionalFields {
+    name!: string;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("name");
+        writer.writeString(this.name);
+    }
+
+    decode(decoder: Decoder): ConditionalFields {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "name") {
+                this.name = decoder.readString();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class ConditionalVariants {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): ConditionalVariants {
+        if (decoder.isNextString()) {
+            const tag = decoder.readString();
+            if (tag == "Stable") return new ConditionalVariantsStable();
+            fail("deserialization", "Unknown variant `" + tag + "` for `ConditionalVariants`");
+        }
+        if (decoder.readMapSize() != 1) {
+            fail("deserialization", "Expected a single variant of `ConditionalVariants`");
+        }
+        const tag = decoder.readString();
+        fail("deserialization", "Unknown variant `" + tag + "` for `ConditionalVariants`");
+        return unreachable();
+    }
+}
+
+export class ConditionalVariantsStable extends ConditionalVariants {
+    encode(writer: Writer): void {
+        writer.writeString("Stable");
+    }
+}
+
+/**
+ * # This is an enum with doc comments.
+ */
+export class DocExampleEnum {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): DocExampleEnum {
+        if (decoder.isNextString()) {
+            const tag = decoder.readString();
+            fail("deserialization", "Unknown variant `" + tag + "` for `DocExampleEnum`");
+        }
+        if (decoder.readMapSize() != 1) {
+            fail("deserialization", "Expected a single variant of `DocExampleEnum`");
+        }
+        const tag = decoder.readString();
+        if (tag == "Variant1") return new DocExampleEnumVariant1(decoder.readString());
+        if (tag == "Variant2") return new DocExampleEnumVariant2().decodeFields(decoder, decoder.readMapSize());
+        fail("deserialization", "Unknown variant `" + tag + "` for `DocExampleEnum`");
+        return unreachable();
+    }
+}
+
+/**
+ * Multi-line doc comment with complex characters
+ * & " , \ ! '
+ */
+export class DocExampleEnumVariant1 extends DocExampleEnum {
+    constructor(public value: string) {
+        super();
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("Variant1");
+        writer.writeString(this.value);
+    }
+}
+
+/**
+ * Raw identifiers are supported too.
+ */
+export class DocExampleEnumVariant2 extends DocExampleEnum {
+    /**
+     * Variant property.
+     */
+    inner: i8;
+
+    decodeFields(decoder: Decoder, size: u32): DocExampleEnumVariant2 {
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "inner") {
+                this.inner = decoder.readInt8();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("Variant2");
+        writer.writeMapSize(1);
+        writer.writeString("inner");
+        writer.writeInt8(this.inner);
+    }
+}
+
+/**
+ * # This is a struct with doc comments.
+ */
+export class DocExampleStruct {
+    /**
+     * Multi-line doc comment with complex characters
+     * & " , \ ! '
+     */
+    multiLine!: string;
+    /**
+     * Raw identifiers are supported too.
+     */
+    type_!: string;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("multi_line");
+        writer.writeString(this.multiLine);
+        writer.writeString("type");
+        writer.writeString(this.type_);
+    }
+
+    decode(decoder: Decoder): DocExampleStruct {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "multi_line") {
+                this.multiLine = decoder.readString();
+            } else if (key == "type") {
+                this.type_ = decoder.readString();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * A point of an arbitrary type, with explicit trait bounds.
+ */
+export class ExplicitBoundPoint<T> {
+    value!: T;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("value");
+        encodeValue<T>(writer, this.value);
+    }
+
+    decode(decoder: Decoder): ExplicitBoundPoint<T> {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "value") {
+                this.value = decodeValue<T>(decoder);
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * This struct is also not referenced by any function or data structure, but
+ * it will show up because there is an explicit `use` statement for it in the
+ * `fp_import!` macro.
+ */
+export class ExplicitedlyImportedType {
+    youWillSeeThis: bool;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("you_will_see_this");
+        writer.writeBool(this.youWillSeeThis);
+    }
+
+    decode(decoder: Decoder): ExplicitedlyImportedType {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "you_will_see_this") {
+                this.youWillSeeThis = decoder.readBool();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class FlattenedStruct {
+    foo!: string;
+    bar: i64;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("foo");
+        writer.writeString(this.foo);
+        writer.writeString("bar");
+        writer.writeInt64(this.bar);
+    }
+
+    decode(decoder: Decoder): FlattenedStruct {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "foo") {
+                this.foo = decoder.readString();
+            } else if (key == "bar") {
+                this.bar = decoder.readInt64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class FpFlatten {
+    foo!: string;
+    bar: i64;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("foo");
+        writer.writeString(this.foo);
+        writer.writeString("bar");
+        writer.writeInt64(this.bar);
+    }
+
+    decode(decoder: Decoder): FpFlatten {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "foo") {
+                this.foo = decoder.readString();
+            } else if (key == "bar") {
+                this.bar = decoder.readInt64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class FpInternallyTagged {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): FpInternallyTagged {
+        const size = decoder.readMapSize();
+        if (size == 0 || decoder.readString() != "type") {
+            fail("deserialization", "Expected the tag of `FpInternallyTagged` to come first");
+        }
+        const tag = decoder.readString();
+        if (tag == "Foo") {
+            skipMapEntries(decoder, size - 1);
+            return new FpInternallyTaggedFoo();
+        }
+        if (tag == "Baz") return new FpInternallyTaggedBaz().decodeFields(decoder, size - 1);
+        fail("deserialization", "Unknown variant `" + tag + "` for `FpInternallyTagged`");
+        return unreachable();
+    }
+}
+
+export class FpInternallyTaggedFoo extends FpInternallyTagged {
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("type");
+        writer.writeString("Foo");
+    }
+}
+
+export class FpInternallyTaggedBaz extends FpInternallyTagged {
+    a: i8;
+    b: u64;
+
+    decodeFields(decoder: Decoder, size: u32): FpInternallyTaggedBaz {
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "a") {
+                this.a = decoder.readInt8();
+            } else if (key == "b") {
+                this.b = decoder.readUInt64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(3);
+        writer.writeString("type");
+        writer.writeString("Baz");
+        writer.writeString("a");
+        writer.writeInt8(this.a);
+        writer.writeString("b");
+        writer.writeUInt64(this.b);
+    }
+}
+
+export class FpPropertyRenaming {
+    fooBar!: string;
+    quxBaz: f64;
+    rawStruct: i32;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(3);
+        writer.writeString("fooBar");
+        writer.writeString(this.fooBar);
+        writer.writeString("QUX_BAZ");
+        writer.writeFloat64(this.quxBaz);
+        writer.writeString("rawStruct");
+        writer.writeInt32(this.rawStruct);
+    }
+
+    decode(decoder: Decoder): FpPropertyRenaming {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "fooBar") {
+                this.fooBar = decoder.readString();
+            } else if (key == "QUX_BAZ") {
+                this.quxBaz = decoder.readFloat64();
+            } else if (key == "rawStruct") {
+                this.rawStruct = decoder.readInt32();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class FpVariantRenaming {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): FpVariantRenaming {
+        if (decoder.isNextString()) {
+            const tag = decoder.readString();
+            if (tag == "foo_bar") return new FpVariantRenamingFooBar();
+            fail("deserialization", "Unknown variant `" + tag + "` for `FpVariantRenaming`");
+        }
+        if (decoder.readMapSize() != 1) {
+            fail("deserialization", "Expected a single variant of `FpVariantRenaming`");
+        }
+        const tag = decoder.readString();
+        if (tag == "QUX_BAZ") return new FpVariantRenamingQuxBaz().decodeFields(decoder, decoder.readMapSize());
+        fail("deserialization", "Unknown variant `" + tag + "` for `FpVariantRenaming`");
+        return unreachable();
+    }
+}
+
+export class FpVariantRenamingFooBar extends FpVariantRenaming {
+    encode(writer: Writer): void {
+        writer.writeString("foo_bar");
+    }
+}
+
+export class FpVariantRenamingQuxBaz extends FpVariantRenaming {
+    /**
+     * Will be renamed to "FOO_BAR" because of the `rename_all` on the
+     * variant.
+     */
+    fooBar!: string;
+    quxBaz: f64;
+
+    decodeFields(decoder: Decoder, size: u32): FpVariantRenamingQuxBaz {
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "FOO_BAR") {
+                this.fooBar = decoder.readString();
+            } else if (key == "qux_baz") {
+                this.quxBaz = decoder.readFloat64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("QUX_BAZ");
+        writer.writeMapSize(2);
+        writer.writeString("FOO_BAR");
+        writer.writeString(this.fooBar);
+        writer.writeString("qux_baz");
+        writer.writeFloat64(this.quxBaz);
+    }
+}
+
+export class GroupImportedType1 {
+    youWillSeeThis: bool;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("you_will_see_this");
+        writer.writeBool(this.youWillSeeThis);
+    }
+
+    decode(decoder: Decoder): GroupImportedType1 {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "you_will_see_this") {
+                this.youWillSeeThis = decoder.readBool();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class GroupImportedType2 {
+    youWillSeeThis: bool;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("you_will_see_this");
+        writer.writeBool(this.youWillSeeThis);
+    }
+
+    decode(decoder: Decoder): GroupImportedType2 {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "you_will_see_this") {
+                this.youWillSeeThis = decoder.readBool();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * A measurement that is reported by the plugin.
+ *
+ * Because the `name` field is annotated with `#[fp(interned)]`, the plugin
+ * registers every distinct name with the runtime once, and sends a `u32`
+ * handle in its place from then on. This keeps the payloads small when the
+ * same few names are reported over and over.
+ */
+export class Metric {
+    name!: string;
+    value: f64;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("name");
+        writer.writeString(this.name);
+        writer.writeString("value");
+        writer.writeFloat64(this.value);
+    }
+
+    decode(decoder: Decoder): Metric {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "name") {
+                this.name = decoder.readString();
+            } else if (key == "value") {
+                this.value = decoder.readFloat64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * Config that is passed to the `init` export when the plugin is instantiated.
+ *
+ * Every protocol has `init` and `shutdown` exports, but protocols can declare
+ * `init` themselves to have it take a config argument like this one.
+ */
+export class PluginConfig {
+    logLevel!: string;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("logLevel");
+        writer.writeString(this.logLevel);
+    }
+
+    decode(decoder: Decoder): PluginConfig {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "logLevel") {
+                this.logLevel = decoder.readString();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * A point of an arbitrary type.
+ */
+export class Point<T> {
+    value!: T;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("value");
+        encodeValue<T>(writer, this.value);
+    }
+
+    decode(decoder: Decoder): Point<T> {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "value") {
+                this.value = decodeValue<T>(decoder);
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class Priority {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): Priority {
+        if (decoder.isNextString()) {
+            const tag = decoder.readString();
+            if (tag == "low") return new PriorityLow();
+            if (tag == "normal") return new PriorityNormal();
+            if (tag == "urgent") return new PriorityHigh();
+            fail("deserialization", "Unknown variant `" + tag + "` for `Priority`");
+        }
+        if (decoder.readMapSize() != 1) {
+            fail("deserialization", "Expected a single variant of `Priority`");
+        }
+        const tag = decoder.readString();
+        fail("deserialization", "Unknown variant `" + tag + "` for `Priority`");
+        return unreachable();
+    }
+}
+
+/**
+ * Handled after everything else.
+ */
+export class PriorityLow extends Priority {
+    encode(writer: Writer): void {
+        writer.writeString("low");
+    }
+}
+
+export class PriorityNormal extends Priority {
+    encode(writer: Writer): void {
+        writer.writeString("normal");
+    }
+}
+
+export class PriorityHigh extends Priority {
+    encode(writer: Writer): void {
+        writer.writeString("urgent");
+    }
+}
+
+/**
+ * Emitted by the plugin while it works on a long-running task.
+ *
+ * See the `report_progress` event in `main.rs` for more info.
+ */
+export class Progress {
+    task!: string;
+    completedSteps: u32;
+    totalSteps: u32;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(3);
+        writer.writeString("task");
+        writer.writeString(this.task);
+        writer.writeString("completedSteps");
+        writer.writeUInt32(this.completedSteps);
+        writer.writeString("totalSteps");
+        writer.writeUInt32(this.totalSteps);
+    }
+
+    decode(decoder: Decoder): Progress {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "task") {
+                this.task = decoder.readString();
+            } else if (key == "completedSteps") {
+                this.completedSteps = decoder.readUInt32();
+            } else if (key == "totalSteps") {
+                this.totalSteps = decoder.readUInt32();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * Error that is returned to the plugin when it calls a rate-limited import
+ * more often than its limit allows.
+ */
+export class RateLimited {
+    /**
+     * The name of the import that was called.
+     */
+    function_!: string;
+    /**
+     * The number of milliseconds after which the import may be called again.
+     */
+    retryAfterMs: u32;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("function");
+        writer.writeString(this.function_);
+        writer.writeString("retryAfterMs");
+        writer.writeUInt32(this.retryAfterMs);
+    }
+
+    decode(decoder: Decoder): RateLimited {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "function") {
+                this.function_ = decoder.readString();
+            } else if (key == "retryAfterMs") {
+                this.retryAfterMs = decoder.readUInt32();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * Example for representing Redux actions.
+ */
+export class ReduxAction {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): ReduxAction {
+        const size = decoder.readMapSize();
+        if (size == 0 || decoder.readString() != "type") {
+            fail("deserialization", "Expected the tag of `ReduxAction` to come first");
+        }
+        const tag = decoder.readString();
+        const hasContent = size > 1 && decoder.readString() == "payload";
+        if (tag == "clear_title") {
+            if (hasContent) decoder.skip();
+            return new ReduxActionClearTitle();
+        }
+        if (hasContent && tag == "update_title") return new ReduxActionUpdateTitle().decodeFields(decoder, decoder.readMapSize());
+        fail("deserialization", "Unknown variant `" + tag + "` for `ReduxAction`");
+        return unreachable();
+    }
+}
+
+export class ReduxActionClearTitle extends ReduxAction {
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("type");
+        writer.writeString("clear_title");
+    }
+}
+
+export class ReduxActionUpdateTitle extends ReduxAction {
+    title!: string;
+
+    decodeFields(decoder: Decoder, size: u32): ReduxActionUpdateTitle {
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "title") {
+                this.title = decoder.readString();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("type");
+        writer.writeString("update_title");
+        writer.writeString("payload");
+        writer.writeMapSize(1);
+        writer.writeString("title");
+        writer.writeString(this.title);
+    }
+}
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+export class Result<T, E> {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): Result<T, E> {
+        if (decoder.isNextString()) {
+            const tag = decoder.readString();
+            fail("deserialization", "Unknown variant `" + tag + "` for `Result`");
+        }
+        if (decoder.readMapSize() != 1) {
+            fail("deserialization", "Expected a single variant of `Result`");
+        }
+        const tag = decoder.readString();
+        if (tag == "Ok") return new ResultOk<T, E>(decodeValue<T>(decoder));
+        if (tag == "Err") return new ResultErr<T, E>(decodeValue<E>(decoder));
+        fail("deserialization", "Unknown variant `" + tag + "` for `Result`");
+        return unreachable();
+    }
+}
+
+/**
+ * Represents a successful result.
+ */
+export class ResultOk<T, E> extends Result<T, E> {
+    constructor(public value: T) {
+        super();
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("Ok");
+        encodeValue<T>(writer, this.value);
+    }
+}
+
+/**
+ * Represents an error.
+ */
+export class ResultErr<T, E> extends Result<T, E> {
+    constructor(public value: E) {
+        super();
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("Err");
+        encodeValue<E>(writer, this.value);
+    }
+}
+
+/**
+ * A sample of a time series.
+ *
+ * Because the struct is annotated with `#[fp(columnar)]`, every `Vec<Sample>`
+ * in the protocol is replaced with a generated `SampleColumns` struct, which
+ * holds a column with the values of every field. In TypeScript, the columns
+ * are typed arrays, so large lists of samples can be passed without encoding
+ * an object for every item.
+ */
+export class Sample {
+    timestampMs: u64;
+    value: f64;
+    quality: u8;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(3);
+        writer.writeString("timestampMs");
+        writer.writeUInt64(this.timestampMs);
+        writer.writeString("value");
+        writer.writeFloat64(this.value);
+        writer.writeString("quality");
+        writer.writeUInt8(this.quality);
+    }
+
+    decode(decoder: Decoder): Sample {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "timestampMs") {
+                this.timestampMs = decoder.readUInt64();
+            } else if (key == "value") {
+                this.value = decoder.readFloat64();
+            } else if (key == "quality") {
+                this.quality = decoder.readUInt8();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class SerdeAdjacentlyTagged {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): SerdeAdjacentlyTagged {
+        const size = decoder.readMapSize();
+        if (size == 0 || decoder.readString() != "type") {
+            fail("deserialization", "Expected the tag of `SerdeAdjacentlyTagged` to come first");
+        }
+        const tag = decoder.readString();
+        const hasContent = size > 1 && decoder.readString() == "payload";
+        if (tag == "Foo") {
+            if (hasContent) decoder.skip();
+            return new SerdeAdjacentlyTaggedFoo();
+        }
+        if (hasContent && tag == "Bar") return new SerdeAdjacentlyTaggedBar(decoder.readString());
+        if (hasContent && tag == "Baz") return new SerdeAdjacentlyTaggedBaz().decodeFields(decoder, decoder.readMapSize());
+        fail("deserialization", "Unknown variant `" + tag + "` for `SerdeAdjacentlyTagged`");
+        return unreachable();
+    }
+}
+
+export class SerdeAdjacentlyTaggedFoo extends SerdeAdjacentlyTagged {
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("type");
+        writer.writeString("Foo");
+    }
+}
+
+export class SerdeAdjacentlyTaggedBar extends SerdeAdjacentlyTagged {
+    constructor(public value: string) {
+        super();
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("type");
+        writer.writeString("Bar");
+        writer.writeString("payload");
+        writer.writeString(this.value);
+    }
+}
+
+export class SerdeAdjacentlyTaggedBaz extends SerdeAdjacentlyTagged {
+    a: i8;
+    b: u64;
+
+    decodeFields(decoder: Decoder, size: u32): SerdeAdjacentlyTaggedBaz {
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "a") {
+                this.a = decoder.readInt8();
+            } else if (key == "b") {
+                this.b = decoder.readUInt64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("type");
+        writer.writeString("Baz");
+        writer.writeString("payload");
+        writer.writeMapSize(2);
+        writer.writeString("a");
+        writer.writeInt8(this.a);
+        writer.writeString("b");
+        writer.writeUInt64(this.b);
+    }
+}
+
+export class SerdeFlatten {
+    foo!: string;
+    bar: i64;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("foo");
+        writer.writeString(this.foo);
+        writer.writeString("bar");
+        writer.writeInt64(this.bar);
+    }
+
+    decode(decoder: Decoder): SerdeFlatten {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "foo") {
+                this.foo = decoder.readString();
+            } else if (key == "bar") {
+                this.bar = decoder.readInt64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class SerdeInternallyTagged {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): SerdeInternallyTagged {
+        const size = decoder.readMapSize();
+        if (size == 0 || decoder.readString() != "type") {
+            fail("deserialization", "Expected the tag of `SerdeInternallyTagged` to come first");
+        }
+        const tag = decoder.readString();
+        if (tag == "Foo") {
+            skipMapEntries(decoder, size - 1);
+            return new SerdeInternallyTaggedFoo();
+        }
+        if (tag == "Baz") return new SerdeInternallyTaggedBaz().decodeFields(decoder, size - 1);
+        fail("deserialization", "Unknown variant `" + tag + "` for `SerdeInternallyTagged`");
+        return unreachable();
+    }
+}
+
+export class SerdeInternallyTaggedFoo extends SerdeInternallyTagged {
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("type");
+        writer.writeString("Foo");
+    }
+}
+
+export class SerdeInternallyTaggedBaz extends SerdeInternallyTagged {
+    a: i8;
+    b: u64;
+
+    decodeFields(decoder: Decoder, size: u32): SerdeInternallyTaggedBaz {
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "a") {
+                this.a = decoder.readInt8();
+            } else if (key == "b") {
+                this.b = decoder.readUInt64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(3);
+        writer.writeString("type");
+        writer.writeString("Baz");
+        writer.writeString("a");
+        writer.writeInt8(this.a);
+        writer.writeString("b");
+        writer.writeUInt64(this.b);
+    }
+}
+
+export class SerdePropertyRenaming {
+    fooBar!: string;
+    quxBaz: f64;
+    rawStruct: i32;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(3);
+        writer.writeString("fooBar");
+        writer.writeString(this.fooBar);
+        writer.writeString("QUX_BAZ");
+        writer.writeFloat64(this.quxBaz);
+        writer.writeString("rawStruct");
+        writer.writeInt32(this.rawStruct);
+    }
+
+    decode(decoder: Decoder): SerdePropertyRenaming {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "fooBar") {
+                this.fooBar = decoder.readString();
+            } else if (key == "QUX_BAZ") {
+                this.quxBaz = decoder.readFloat64();
+            } else if (key == "rawStruct") {
+                this.rawStruct = decoder.readInt32();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class SerdeVariantRenaming {
+    encode(writer: Writer): void {
+        // Only the variants can be encoded.
+        unreachable();
+    }
+
+    decode(decoder: Decoder): SerdeVariantRenaming {
+        if (decoder.isNextString()) {
+            const tag = decoder.readString();
+            if (tag == "foo_bar") return new SerdeVariantRenamingFooBar();
+            fail("deserialization", "Unknown variant `" + tag + "` for `SerdeVariantRenaming`");
+        }
+        if (decoder.readMapSize() != 1) {
+            fail("deserialization", "Expected a single variant of `SerdeVariantRenaming`");
+        }
+        const tag = decoder.readString();
+        if (tag == "QUX_BAZ") return new SerdeVariantRenamingQuxBaz().decodeFields(decoder, decoder.readMapSize());
+        fail("deserialization", "Unknown variant `" + tag + "` for `SerdeVariantRenaming`");
+        return unreachable();
+    }
+}
+
+export class SerdeVariantRenamingFooBar extends SerdeVariantRenaming {
+    encode(writer: Writer): void {
+        writer.writeString("foo_bar");
+    }
+}
+
+export class SerdeVariantRenamingQuxBaz extends SerdeVariantRenaming {
+    /**
+     * Will be renamed to "FooBar" because of the `rename_all` on the
+     * variant.
+     */
+    fooBar!: string;
+    quxBaz: f64;
+
+    decodeFields(decoder: Decoder, size: u32): SerdeVariantRenamingQuxBaz {
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "FooBar") {
+                this.fooBar = decoder.readString();
+            } else if (key == "qux_baz") {
+                this.quxBaz = decoder.readFloat64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("QUX_BAZ");
+        writer.writeMapSize(2);
+        writer.writeString("FooBar");
+        writer.writeString(this.fooBar);
+        writer.writeString("qux_baz");
+        writer.writeFloat64(this.quxBaz);
+    }
+}
+
+/**
+ * A state update to communicate to the Redux host.
+ *
+ * Fields are wrapped in `Option`. If any field is `None` it means it hasn't
+ * changed.
+ */
+export class StateUpdate {
+    title: string | null = null;
+    revision: Some<u16> | null = null;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("title");
+        if (this.title === null) {
+            writer.writeNil();
+        } else {
+            writer.writeString(this.title!);
+        }
+        writer.writeString("revision");
+        if (this.revision === null) {
+            writer.writeNil();
+        } else {
+            writer.writeUInt16(this.revision!.value);
+        }
+    }
+
+    decode(decoder: Decoder): StateUpdate {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "title") {
+                this.title = decoder.isNextNil() ? null : decoder.readString();
+            } else if (key == "revision") {
+                this.revision = decoder.isNextNil() ? null : new Some<u16>(decoder.readUInt16());
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+export class StructWithOptions {
+    filledString!: string;
+    emptyString!: string;
+    filledOptionString: string | null = null;
+    emptyOptionString: string | null = null;
+    neverSkippedFilledOptionString: string | null = null;
+    neverSkippedEmptyOptionString: string | null = null;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(6);
+        writer.writeString("filledString");
+        writer.writeString(this.filledString);
+        writer.writeString("emptyString");
+        writer.writeString(this.emptyString);
+        writer.writeString("filledOptionString");
+        if (this.filledOptionString === null) {
+            writer.writeNil();
+        } else {
+            writer.writeString(this.filledOptionString!);
+        }
+        writer.writeString("emptyOptionString");
+        if (this.emptyOptionString === null) {
+            writer.writeNil();
+        } else {
+            writer.writeString(this.emptyOptionString!);
+        }
+        writer.writeString("neverSkippedFilledOptionString");
+        if (this.neverSkippedFilledOptionString === null) {
+            writer.writeNil();
+        } else {
+            writer.writeString(this.neverSkippedFilledOptionString!);
+        }
+        writer.writeString("neverSkippedEmptyOptionString");
+        if (this.neverSkippedEmptyOptionString === null) {
+            writer.writeNil();
+        } else {
+            writer.writeString(this.neverSkippedEmptyOptionString!);
+        }
+    }
+
+    decode(decoder: Decoder): StructWithOptions {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "filledString") {
+                this.filledString = decoder.readString();
+            } else if (key == "emptyString") {
+                this.emptyString = decoder.readString();
+            } else if (key == "filledOptionString") {
+                this.filledOptionString = decoder.isNextNil() ? null : decoder.readString();
+            } else if (key == "emptyOptionString") {
+                this.emptyOptionString = decoder.isNextNil() ? null : decoder.readString();
+            } else if (key == "neverSkippedFilledOptionString") {
+                this.neverSkippedFilledOptionString = decoder.isNextNil() ? null : decoder.readString();
+            } else if (key == "neverSkippedEmptyOptionString") {
+                this.neverSkippedEmptyOptionString = decoder.isNextNil() ? null : decoder.readString();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * An identifier of a resource of type `T`.
+ *
+ * `PhantomData` fields are not serialized, so they only show up in the Rust
+ * bindings, where they keep the type parameter in use. Other bindings only
+ * preserve the type parameter in the signature of the type.
+ */
+export class TypedId<T> {
+    id: u64;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("id");
+        writer.writeUInt64(this.id);
+    }
+
+    decode(decoder: Decoder): TypedId<T> {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "id") {
+                this.id = decoder.readUInt64();
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * Structs that contain validated structs get validated as a whole.
+ *
+ * Fields that are not part of this struct are rejected, rather than silently
+ * ignored.
+ */
+export class ValidatedChild {
+    name!: string;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(1);
+        writer.writeString("name");
+        writer.writeString(this.name);
+    }
+
+    decode(decoder: Decoder): ValidatedChild {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "name") {
+                this.name = decoder.readString();
+            } else {
+                fail("deserialization", "Unknown field `" + key + "` in `ValidatedChild`");
+            }
+        }
+        return this;
+    }
+}
+
+/**
+ * Fields with `#[fp(validate(...))]` attributes are validated when they are
+ * received from the other side, so invalid values are rejected right away.
+ */
+export class ValidatedStruct {
+    percentage: u8;
+    slug!: string;
+    ratio: Some<f64> | null = null;
+    children!: Array<ValidatedChild>;
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(4);
+        writer.writeString("percentage");
+        writer.writeUInt8(this.percentage);
+        writer.writeString("slug");
+        writer.writeString(this.slug);
+        writer.writeString("ratio");
+        if (this.ratio === null) {
+            writer.writeNil();
+        } else {
+            writer.writeFloat64(this.ratio!.value);
+        }
+        writer.writeString("children");
+        writer.writeArray<ValidatedChild>(this.children, (writer: Writer, item: ValidatedChild): void => {
+            item.encode(writer);
+        });
+    }
+
+    decode(decoder: Decoder): ValidatedStruct {
+        const size = decoder.readMapSize();
+        for (let i: u32 = 0; i < size; i++) {
+            const key = decoder.readString();
+            if (key == "percentage") {
+                this.percentage = decoder.readUInt8();
+            } else if (key == "slug") {
+                this.slug = decoder.readString();
+            } else if (key == "ratio") {
+                this.ratio = decoder.isNextNil() ? null : new Some<f64>(decoder.readFloat64());
+            } else if (key == "children") {
+                this.children = decoder.readArray<ValidatedChild>((decoder: Decoder): ValidatedChild => new ValidatedChild().decode(decoder));
+            } else {
+                decoder.skip();
+            }
+        }
+        return this;
+    }
+}
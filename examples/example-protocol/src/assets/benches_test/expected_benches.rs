@@ -0,0 +1,1714 @@
+// ============================================= //
+// Benchmarks for the Wasmer runtime             //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+use super::bindings::Runtime;
+use super::types::*;
+use criterion::{BatchSize, BenchmarkId, Criterion};
+use fp_bindgen_support::host::mem::{deserialize_from_slice, serialize_to_vec};
+
+/// The payload sizes to benchmark with. Strings, lists and maps in the payloads
+/// contain this many characters or items.
+pub const PAYLOAD_SIZES: [usize; 3] = [1, 16, 256];
+
+/// Benchmarks the costs of serializing arguments, calling the plugin and
+/// deserializing return values for every protocol function.
+///
+/// Calls are only benchmarked for exported functions, because imported
+/// functions can only be called by the plugin.
+pub fn bench_functions(c: &mut Criterion, wasm_module: &[u8]) {
+    let rt = Runtime::new(wasm_module).expect("Could not create runtime");
+    let async_runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Could not create async runtime");
+
+    bench_export_array_f32(c, &rt);
+    bench_export_array_f64(c, &rt);
+    bench_export_array_i16(c, &rt);
+    bench_export_array_i32(c, &rt);
+    bench_export_array_i8(c, &rt);
+    bench_export_array_u16(c, &rt);
+    bench_export_array_u32(c, &rt);
+    bench_export_array_u8(c, &rt);
+    bench_export_async_struct(c, &rt, &async_runtime);
+    bench_export_batch_response(c, &rt);
+    bench_export_cached_call_count(c, &rt);
+    bench_export_call_rate_limited_import(c, &rt);
+    // Skipped `export_collections`: list type `BTreeSet` is not supported.
+    // Skipped `export_dynamic_value`: custom type `Value` is not supported.
+    // Skipped `export_edge_values`: custom type `char` is not supported.
+    // Skipped `export_experimental`: it is behind the `experimental` feature.
+    bench_export_fp_adjacently_tagged(c, &rt);
+    bench_export_fp_enum(c, &rt);
+    bench_export_fp_flatten(c, &rt);
+    bench_export_fp_internally_tagged(c, &rt);
+    bench_export_fp_struct(c, &rt);
+    bench_export_fp_untagged(c, &rt);
+    // Skipped `export_generics`: custom type `OffsetDateTime` is not supported.
+    bench_export_get_bytes(c, &rt);
+    bench_export_get_serde_bytes(c, &rt);
+    bench_export_lazy_string(c, &rt);
+    bench_export_metrics(c, &rt);
+    bench_export_multiple_primitives(c, &rt);
+    // Skipped `export_multiple_values`: tuple `(String, Vec<u32>, bool)` is not supported.
+    bench_export_primitive_bool(c, &rt);
+    bench_export_primitive_f32(c, &rt);
+    bench_export_primitive_f64(c, &rt);
+    bench_export_primitive_i16(c, &rt);
+    bench_export_primitive_i32(c, &rt);
+    bench_export_primitive_i64(c, &rt);
+    bench_export_primitive_i8(c, &rt);
+    bench_export_primitive_pair(c, &rt);
+    bench_export_primitive_u16(c, &rt);
+    bench_export_primitive_u32(c, &rt);
+    bench_export_primitive_u64(c, &rt);
+    bench_export_primitive_u8(c, &rt);
+    bench_export_renamed_string(c, &rt);
+    // Skipped `export_samples`: field `timestamp_ms` of `SampleColumns` has a custom (de)serializer.
+    bench_export_serde_adjacently_tagged(c, &rt);
+    bench_export_serde_enum(c, &rt);
+    bench_export_serde_flatten(c, &rt);
+    bench_export_serde_internally_tagged(c, &rt);
+    bench_export_serde_struct(c, &rt);
+    bench_export_serde_untagged(c, &rt);
+    bench_export_string(c, &rt);
+    bench_export_string_after_memory_growth(c, &rt);
+    bench_export_struct_with_options(c, &rt);
+    // Skipped `export_timestamp`: custom type `OffsetDateTime` is not supported.
+    // Skipped `export_timestamps`: custom type `OffsetDateTime` is not supported.
+    // Skipped `export_typed_id`: field `resource` of `TypedId` is a `PhantomData` marker.
+    bench_export_validated_struct(c, &rt);
+    bench_export_void_function(c, &rt);
+    bench_fetch_data(c, &rt, &async_runtime);
+    bench_init(c, &rt);
+    bench_reducer_bridge(c, &rt);
+    bench_update_config(c, &rt);
+    // Skipped `import_arbitrary_precision_numbers`: custom type `Decimal` is not supported.
+    bench_import_array_f32(c);
+    bench_import_array_f64(c);
+    bench_import_array_i16(c);
+    bench_import_array_i32(c);
+    bench_import_array_i8(c);
+    bench_import_array_u16(c);
+    bench_import_array_u32(c);
+    bench_import_array_u8(c);
+    bench_import_async_void_function(c);
+    // Skipped `import_collections`: list type `BTreeSet` is not supported.
+    // Skipped `import_experimental`: it is behind the `experimental` feature.
+    bench_import_explicit_bound_point(c);
+    bench_import_fp_adjacently_tagged(c);
+    bench_import_fp_enum(c);
+    bench_import_fp_flatten(c);
+    bench_import_fp_internally_tagged(c);
+    bench_import_fp_struct(c);
+    bench_import_fp_untagged(c);
+    // Skipped `import_generics`: custom type `OffsetDateTime` is not supported.
+    bench_import_get_bytes(c);
+    bench_import_get_serde_bytes(c);
+    // Skipped `import_get_value`: it is generic.
+    // Skipped `import_integer_types`: custom type `NonZeroU32` is not supported.
+    bench_import_multiple_primitives(c);
+    // Skipped `import_multiple_values`: tuple `(String, Vec<u32>, bool)` is not supported.
+    bench_import_rate_limited(c);
+    bench_import_renamed_string(c);
+    bench_import_serde_adjacently_tagged(c);
+    bench_import_serde_enum(c);
+    bench_import_serde_flatten(c);
+    bench_import_serde_internally_tagged(c);
+    bench_import_serde_struct(c);
+    bench_import_serde_untagged(c);
+    // Skipped `import_set_value`: it is generic.
+    bench_import_string(c);
+    bench_import_struct_with_options(c);
+    // Skipped `import_timestamp`: custom type `OffsetDateTime` is not supported.
+    bench_import_validated_struct(c);
+    bench_import_void_function_empty_result(c);
+    bench_invoke_command(c);
+    bench_log(c);
+    // Skipped `make_http_request`: custom type `Uri` is not supported.
+    bench_record_metric(c);
+    bench_report_progress(c);
+}
+
+fn bench_export_array_f32(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_array_f32");
+    let arg: [f32; 3] = [1.5; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_array_f32(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: [f32; 3] = [1.5; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[f32; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_array_f64(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_array_f64");
+    let arg: [f64; 3] = [1.5; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_array_f64(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: [f64; 3] = [1.5; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[f64; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_array_i16(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_array_i16");
+    let arg: [i16; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_array_i16(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: [i16; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[i16; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_array_i32(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_array_i32");
+    let arg: [i32; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_array_i32(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: [i32; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[i32; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_array_i8(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_array_i8");
+    let arg: [i8; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(|| arg, |arg| rt.export_array_i8(arg), BatchSize::SmallInput)
+    });
+    let result: [i8; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[i8; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_array_u16(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_array_u16");
+    let arg: [u16; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_array_u16(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: [u16; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[u16; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_array_u32(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_array_u32");
+    let arg: [u32; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_array_u32(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: [u32; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[u32; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_array_u8(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_array_u8");
+    let arg: [u8; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(|| arg, |arg| rt.export_array_u8(arg), BatchSize::SmallInput)
+    });
+    let result: [u8; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[u8; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_async_struct(
+    c: &mut Criterion,
+    rt: &Runtime,
+    async_runtime: &tokio::runtime::Runtime,
+) {
+    let mut group = c.benchmark_group("export_async_struct");
+    for size in PAYLOAD_SIZES {
+        let arg1: FpPropertyRenaming = FpPropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        let arg2: u64 = 1;
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg1))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || (arg1.clone(), arg2),
+                |(arg1, arg2)| async_runtime.block_on(rt.export_async_struct(arg1, arg2)),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: FpPropertyRenaming = FpPropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<FpPropertyRenaming>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_batch_response(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_batch_response");
+    for size in PAYLOAD_SIZES {
+        let arg: BatchResponse = BatchResponse {
+            results: (0..size).map(|_| Result::Ok(1)).collect(),
+            summary: Result::Ok("x".repeat(size)),
+            retry_after: Some(Result::Ok(1)),
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_batch_response(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: BatchResponse = BatchResponse {
+            results: (0..size).map(|_| Result::Ok(1)).collect(),
+            summary: Result::Ok("x".repeat(size)),
+            retry_after: Some(Result::Ok(1)),
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<BatchResponse>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_cached_call_count(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_cached_call_count");
+    for size in PAYLOAD_SIZES {
+        let label: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&label))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || label.clone(),
+                |label| rt.export_cached_call_count(label),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: String = "x".repeat(size);
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<String>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_call_rate_limited_import(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_call_rate_limited_import");
+    for size in PAYLOAD_SIZES {
+        let calls: u32 = 1;
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || calls,
+                |calls| rt.export_call_rate_limited_import(calls),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: Vec<Result<String, RateLimited>> =
+            (0..size).map(|_| Result::Ok("x".repeat(size))).collect();
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<Vec<Result<String, RateLimited>>>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_fp_adjacently_tagged(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_fp_adjacently_tagged");
+    let arg: FpAdjacentlyTagged = FpAdjacentlyTagged::Foo;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg.clone(),
+            |arg| rt.export_fp_adjacently_tagged(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: FpAdjacentlyTagged = FpAdjacentlyTagged::Foo;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<FpAdjacentlyTagged>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_fp_enum(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_fp_enum");
+    let arg: FpVariantRenaming = FpVariantRenaming::FooBar;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg.clone(),
+            |arg| rt.export_fp_enum(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: FpVariantRenaming = FpVariantRenaming::FooBar;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<FpVariantRenaming>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_fp_flatten(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_fp_flatten");
+    for size in PAYLOAD_SIZES {
+        let arg: FpFlatten = FpFlatten {
+            flattened: FlattenedStruct {
+                foo: "x".repeat(size),
+                bar: 1,
+            },
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_fp_flatten(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: FpFlatten = FpFlatten {
+            flattened: FlattenedStruct {
+                foo: "x".repeat(size),
+                bar: 1,
+            },
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<FpFlatten>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_fp_internally_tagged(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_fp_internally_tagged");
+    let arg: FpInternallyTagged = FpInternallyTagged::Foo;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg.clone(),
+            |arg| rt.export_fp_internally_tagged(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: FpInternallyTagged = FpInternallyTagged::Foo;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<FpInternallyTagged>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_fp_struct(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_fp_struct");
+    for size in PAYLOAD_SIZES {
+        let arg: FpPropertyRenaming = FpPropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_fp_struct(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: FpPropertyRenaming = FpPropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<FpPropertyRenaming>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_fp_untagged(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_fp_untagged");
+    for size in PAYLOAD_SIZES {
+        let arg: FpUntagged = FpUntagged::Bar("x".repeat(size));
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_fp_untagged(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: FpUntagged = FpUntagged::Bar("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<FpUntagged>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_get_bytes(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_get_bytes");
+    for size in PAYLOAD_SIZES {
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(|| (), |()| rt.export_get_bytes(), BatchSize::SmallInput)
+        });
+        let result: Result<bytes::Bytes, String> = Result::Err("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<Result<bytes::Bytes, String>>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_get_serde_bytes(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_get_serde_bytes");
+    for size in PAYLOAD_SIZES {
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || (),
+                |()| rt.export_get_serde_bytes(),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: Result<serde_bytes::ByteBuf, String> = Result::Err("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<Result<serde_bytes::ByteBuf, String>>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_lazy_string(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_lazy_string");
+    for size in PAYLOAD_SIZES {
+        let arg: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_lazy_string(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: String = "x".repeat(size);
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<String>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_metrics(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_metrics");
+    for size in PAYLOAD_SIZES {
+        let metrics: Vec<Metric> = (0..size)
+            .map(|_| Metric {
+                name: "x".repeat(size),
+                value: 1.5,
+            })
+            .collect();
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&metrics))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || metrics.clone(),
+                |metrics| rt.export_metrics(metrics),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: Vec<Metric> = (0..size)
+            .map(|_| Metric {
+                name: "x".repeat(size),
+                value: 1.5,
+            })
+            .collect();
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<Vec<Metric>>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_multiple_primitives(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_multiple_primitives");
+    for size in PAYLOAD_SIZES {
+        let arg1: i8 = 1;
+        let arg2: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg2))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || (arg1, arg2.clone()),
+                |(arg1, arg2)| rt.export_multiple_primitives(arg1, arg2),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_primitive_bool(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_bool");
+    let arg: bool = true;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_bool(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_f32(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_f32");
+    let arg: f32 = 1.5;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_f32(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_f64(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_f64");
+    let arg: f64 = 1.5;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_f64(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_i16(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_i16");
+    let arg: i16 = 1;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_i16(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_i32(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_i32");
+    let arg: i32 = 1;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_i32(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_i64(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_i64");
+    let arg: i64 = 1;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_i64(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_i8(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_i8");
+    let arg: i8 = 1;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_i8(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_pair(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_pair");
+    let arg: u32 = 1;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_pair(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_u16(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_u16");
+    let arg: u16 = 1;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_u16(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_u32(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_u32");
+    let arg: u32 = 1;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_u32(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_u64(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_u64");
+    let arg: u64 = 1;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_u64(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_primitive_u8(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_primitive_u8");
+    let arg: u8 = 1;
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg,
+            |arg| rt.export_primitive_u8(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_export_renamed_string(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_renamed_string");
+    for size in PAYLOAD_SIZES {
+        let arg: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_renamed_string(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: String = "x".repeat(size);
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<String>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_serde_adjacently_tagged(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_serde_adjacently_tagged");
+    let arg: SerdeAdjacentlyTagged = SerdeAdjacentlyTagged::Foo;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg.clone(),
+            |arg| rt.export_serde_adjacently_tagged(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: SerdeAdjacentlyTagged = SerdeAdjacentlyTagged::Foo;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<SerdeAdjacentlyTagged>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_serde_enum(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_serde_enum");
+    let arg: SerdeVariantRenaming = SerdeVariantRenaming::FooBar;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg.clone(),
+            |arg| rt.export_serde_enum(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: SerdeVariantRenaming = SerdeVariantRenaming::FooBar;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<SerdeVariantRenaming>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_serde_flatten(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_serde_flatten");
+    for size in PAYLOAD_SIZES {
+        let arg: SerdeFlatten = SerdeFlatten {
+            flattened: FlattenedStruct {
+                foo: "x".repeat(size),
+                bar: 1,
+            },
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_serde_flatten(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: SerdeFlatten = SerdeFlatten {
+            flattened: FlattenedStruct {
+                foo: "x".repeat(size),
+                bar: 1,
+            },
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<SerdeFlatten>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_serde_internally_tagged(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_serde_internally_tagged");
+    let arg: SerdeInternallyTagged = SerdeInternallyTagged::Foo;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.bench_function("call", |b| {
+        b.iter_batched(
+            || arg.clone(),
+            |arg| rt.export_serde_internally_tagged(arg),
+            BatchSize::SmallInput,
+        )
+    });
+    let result: SerdeInternallyTagged = SerdeInternallyTagged::Foo;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<SerdeInternallyTagged>(&result))
+    });
+    group.finish();
+}
+
+fn bench_export_serde_struct(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_serde_struct");
+    for size in PAYLOAD_SIZES {
+        let arg: SerdePropertyRenaming = SerdePropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_serde_struct(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: SerdePropertyRenaming = SerdePropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<SerdePropertyRenaming>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_serde_untagged(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_serde_untagged");
+    for size in PAYLOAD_SIZES {
+        let arg: SerdeUntagged = SerdeUntagged::Bar("x".repeat(size));
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_serde_untagged(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: SerdeUntagged = SerdeUntagged::Bar("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<SerdeUntagged>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_string(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_string");
+    for size in PAYLOAD_SIZES {
+        let arg: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_string(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: String = "x".repeat(size);
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<String>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_string_after_memory_growth(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_string_after_memory_growth");
+    for size in PAYLOAD_SIZES {
+        let arg: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_string_after_memory_growth(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: String = "x".repeat(size);
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<String>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_struct_with_options(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_struct_with_options");
+    for size in PAYLOAD_SIZES {
+        let arg: StructWithOptions = StructWithOptions {
+            filled_string: "x".repeat(size),
+            empty_string: "x".repeat(size),
+            filled_option_string: Some("x".repeat(size)),
+            empty_option_string: Some("x".repeat(size)),
+            never_skipped_filled_option_string: Some("x".repeat(size)),
+            never_skipped_empty_option_string: Some("x".repeat(size)),
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_struct_with_options(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: StructWithOptions = StructWithOptions {
+            filled_string: "x".repeat(size),
+            empty_string: "x".repeat(size),
+            filled_option_string: Some("x".repeat(size)),
+            empty_option_string: Some("x".repeat(size)),
+            never_skipped_filled_option_string: Some("x".repeat(size)),
+            never_skipped_empty_option_string: Some("x".repeat(size)),
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<StructWithOptions>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_validated_struct(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_validated_struct");
+    for size in PAYLOAD_SIZES {
+        let arg: ValidatedStruct = ValidatedStruct {
+            percentage: 1,
+            slug: "x".repeat(size),
+            ratio: Some(1.5),
+            children: (0..size)
+                .map(|_| ValidatedChild {
+                    name: "x".repeat(size),
+                })
+                .collect(),
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || arg.clone(),
+                |arg| rt.export_validated_struct(arg),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: ValidatedStruct = ValidatedStruct {
+            percentage: 1,
+            slug: "x".repeat(size),
+            ratio: Some(1.5),
+            children: (0..size)
+                .map(|_| ValidatedChild {
+                    name: "x".repeat(size),
+                })
+                .collect(),
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<ValidatedStruct>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_export_void_function(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("export_void_function");
+    group.bench_function("call", |b| {
+        b.iter_batched(|| (), |()| rt.export_void_function(), BatchSize::SmallInput)
+    });
+    group.finish();
+}
+
+fn bench_fetch_data(c: &mut Criterion, rt: &Runtime, async_runtime: &tokio::runtime::Runtime) {
+    let mut group = c.benchmark_group("fetch_data");
+    for size in PAYLOAD_SIZES {
+        let r#type: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&r#type))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || r#type.clone(),
+                |r#type| async_runtime.block_on(rt.fetch_data(r#type)),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: Result<String, String> = Result::Ok("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<Result<String, String>>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_init(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("init");
+    for size in PAYLOAD_SIZES {
+        let config: PluginConfig = PluginConfig {
+            log_level: "x".repeat(size),
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&config))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || config.clone(),
+                |config| rt.init(config),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_reducer_bridge(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("reducer_bridge");
+    for size in PAYLOAD_SIZES {
+        let action: ReduxAction = ReduxAction::ClearTitle;
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&action))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || action.clone(),
+                |action| rt.reducer_bridge(action),
+                BatchSize::SmallInput,
+            )
+        });
+        let result: StateUpdate = StateUpdate {
+            title: Some(std::rc::Rc::new("x".repeat(size))),
+            revision: Some(1),
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<StateUpdate>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_update_config(c: &mut Criterion, rt: &Runtime) {
+    let mut group = c.benchmark_group("update_config");
+    for size in PAYLOAD_SIZES {
+        let config: PluginConfig = PluginConfig {
+            log_level: "x".repeat(size),
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&config))
+        });
+        group.bench_with_input(BenchmarkId::new("call", size), &size, |b, _| {
+            b.iter_batched(
+                || config.clone(),
+                |config| rt.update_config(config),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_array_f32(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_array_f32");
+    let arg: [f32; 3] = [1.5; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: [f32; 3] = [1.5; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[f32; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_array_f64(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_array_f64");
+    let arg: [f64; 3] = [1.5; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: [f64; 3] = [1.5; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[f64; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_array_i16(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_array_i16");
+    let arg: [i16; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: [i16; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[i16; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_array_i32(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_array_i32");
+    let arg: [i32; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: [i32; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[i32; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_array_i8(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_array_i8");
+    let arg: [i8; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: [i8; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[i8; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_array_u16(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_array_u16");
+    let arg: [u16; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: [u16; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[u16; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_array_u32(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_array_u32");
+    let arg: [u32; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: [u32; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[u32; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_array_u8(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_array_u8");
+    let arg: [u8; 3] = [1; 3];
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: [u8; 3] = [1; 3];
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<[u8; 3]>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_async_void_function(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_async_void_function");
+    for size in PAYLOAD_SIZES {
+        let message: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&message))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_explicit_bound_point(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_explicit_bound_point");
+    let arg: ExplicitBoundPoint<u64> = ExplicitBoundPoint { value: 1 };
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    group.finish();
+}
+
+fn bench_import_fp_adjacently_tagged(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_fp_adjacently_tagged");
+    let arg: FpAdjacentlyTagged = FpAdjacentlyTagged::Foo;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: FpAdjacentlyTagged = FpAdjacentlyTagged::Foo;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<FpAdjacentlyTagged>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_fp_enum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_fp_enum");
+    let arg: FpVariantRenaming = FpVariantRenaming::FooBar;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: FpVariantRenaming = FpVariantRenaming::FooBar;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<FpVariantRenaming>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_fp_flatten(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_fp_flatten");
+    for size in PAYLOAD_SIZES {
+        let arg: FpFlatten = FpFlatten {
+            flattened: FlattenedStruct {
+                foo: "x".repeat(size),
+                bar: 1,
+            },
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: FpFlatten = FpFlatten {
+            flattened: FlattenedStruct {
+                foo: "x".repeat(size),
+                bar: 1,
+            },
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<FpFlatten>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_fp_internally_tagged(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_fp_internally_tagged");
+    let arg: FpInternallyTagged = FpInternallyTagged::Foo;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: FpInternallyTagged = FpInternallyTagged::Foo;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<FpInternallyTagged>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_fp_struct(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_fp_struct");
+    for size in PAYLOAD_SIZES {
+        let arg: FpPropertyRenaming = FpPropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: FpPropertyRenaming = FpPropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<FpPropertyRenaming>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_fp_untagged(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_fp_untagged");
+    for size in PAYLOAD_SIZES {
+        let arg: FpUntagged = FpUntagged::Bar("x".repeat(size));
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: FpUntagged = FpUntagged::Bar("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<FpUntagged>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_get_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_get_bytes");
+    for size in PAYLOAD_SIZES {
+        let result: Result<bytes::Bytes, String> = Result::Err("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<Result<bytes::Bytes, String>>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_get_serde_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_get_serde_bytes");
+    for size in PAYLOAD_SIZES {
+        let result: Result<serde_bytes::ByteBuf, String> = Result::Err("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<Result<serde_bytes::ByteBuf, String>>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_multiple_primitives(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_multiple_primitives");
+    for size in PAYLOAD_SIZES {
+        let arg2: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg2))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_rate_limited(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_rate_limited");
+    for size in PAYLOAD_SIZES {
+        let label: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&label))
+        });
+        let result: Result<String, RateLimited> = Result::Ok("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<Result<String, RateLimited>>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_renamed_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_renamed_string");
+    for size in PAYLOAD_SIZES {
+        let arg: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: String = "x".repeat(size);
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<String>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_serde_adjacently_tagged(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_serde_adjacently_tagged");
+    let arg: SerdeAdjacentlyTagged = SerdeAdjacentlyTagged::Foo;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: SerdeAdjacentlyTagged = SerdeAdjacentlyTagged::Foo;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<SerdeAdjacentlyTagged>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_serde_enum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_serde_enum");
+    let arg: SerdeVariantRenaming = SerdeVariantRenaming::FooBar;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: SerdeVariantRenaming = SerdeVariantRenaming::FooBar;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<SerdeVariantRenaming>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_serde_flatten(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_serde_flatten");
+    for size in PAYLOAD_SIZES {
+        let arg: SerdeFlatten = SerdeFlatten {
+            flattened: FlattenedStruct {
+                foo: "x".repeat(size),
+                bar: 1,
+            },
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: SerdeFlatten = SerdeFlatten {
+            flattened: FlattenedStruct {
+                foo: "x".repeat(size),
+                bar: 1,
+            },
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<SerdeFlatten>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_serde_internally_tagged(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_serde_internally_tagged");
+    let arg: SerdeInternallyTagged = SerdeInternallyTagged::Foo;
+    group.bench_function("serialize", |b| b.iter(|| serialize_to_vec(&arg)));
+    let result: SerdeInternallyTagged = SerdeInternallyTagged::Foo;
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<SerdeInternallyTagged>(&result))
+    });
+    group.finish();
+}
+
+fn bench_import_serde_struct(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_serde_struct");
+    for size in PAYLOAD_SIZES {
+        let arg: SerdePropertyRenaming = SerdePropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: SerdePropertyRenaming = SerdePropertyRenaming {
+            foo_bar: "x".repeat(size),
+            qux_baz: 1.5,
+            r#raw_struct: 1,
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<SerdePropertyRenaming>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_serde_untagged(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_serde_untagged");
+    for size in PAYLOAD_SIZES {
+        let arg: SerdeUntagged = SerdeUntagged::Bar("x".repeat(size));
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: SerdeUntagged = SerdeUntagged::Bar("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<SerdeUntagged>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_string");
+    for size in PAYLOAD_SIZES {
+        let arg: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: String = "x".repeat(size);
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<String>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_struct_with_options(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_struct_with_options");
+    for size in PAYLOAD_SIZES {
+        let arg: StructWithOptions = StructWithOptions {
+            filled_string: "x".repeat(size),
+            empty_string: "x".repeat(size),
+            filled_option_string: Some("x".repeat(size)),
+            empty_option_string: Some("x".repeat(size)),
+            never_skipped_filled_option_string: Some("x".repeat(size)),
+            never_skipped_empty_option_string: Some("x".repeat(size)),
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: StructWithOptions = StructWithOptions {
+            filled_string: "x".repeat(size),
+            empty_string: "x".repeat(size),
+            filled_option_string: Some("x".repeat(size)),
+            empty_option_string: Some("x".repeat(size)),
+            never_skipped_filled_option_string: Some("x".repeat(size)),
+            never_skipped_empty_option_string: Some("x".repeat(size)),
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<StructWithOptions>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_validated_struct(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_validated_struct");
+    for size in PAYLOAD_SIZES {
+        let arg: ValidatedStruct = ValidatedStruct {
+            percentage: 1,
+            slug: "x".repeat(size),
+            ratio: Some(1.5),
+            children: (0..size)
+                .map(|_| ValidatedChild {
+                    name: "x".repeat(size),
+                })
+                .collect(),
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&arg))
+        });
+        let result: ValidatedStruct = ValidatedStruct {
+            percentage: 1,
+            slug: "x".repeat(size),
+            ratio: Some(1.5),
+            children: (0..size)
+                .map(|_| ValidatedChild {
+                    name: "x".repeat(size),
+                })
+                .collect(),
+        };
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<ValidatedStruct>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_import_void_function_empty_result(c: &mut Criterion) {
+    let mut group = c.benchmark_group("import_void_function_empty_result");
+    let result: Result<(), u32> = Result::Ok(());
+    let result = serialize_to_vec(&result);
+    group.bench_function("deserialize", |b| {
+        b.iter(|| deserialize_from_slice::<Result<(), u32>>(&result))
+    });
+    group.finish();
+}
+
+fn bench_invoke_command(c: &mut Criterion) {
+    let mut group = c.benchmark_group("invoke_command");
+    for size in PAYLOAD_SIZES {
+        let name: String = "x".repeat(size);
+        let payload: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| (serialize_to_vec(&name), serialize_to_vec(&payload)))
+        });
+        let result: Result<String, String> = Result::Ok("x".repeat(size));
+        let result = serialize_to_vec(&result);
+        group.bench_with_input(BenchmarkId::new("deserialize", size), &size, |b, _| {
+            b.iter(|| deserialize_from_slice::<Result<String, String>>(&result))
+        });
+    }
+    group.finish();
+}
+
+fn bench_log(c: &mut Criterion) {
+    let mut group = c.benchmark_group("log");
+    for size in PAYLOAD_SIZES {
+        let message: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&message))
+        });
+    }
+    group.finish();
+}
+
+fn bench_record_metric(c: &mut Criterion) {
+    let mut group = c.benchmark_group("record_metric");
+    for size in PAYLOAD_SIZES {
+        let name: String = "x".repeat(size);
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&name))
+        });
+    }
+    group.finish();
+}
+
+fn bench_report_progress(c: &mut Criterion) {
+    let mut group = c.benchmark_group("report_progress");
+    for size in PAYLOAD_SIZES {
+        let progress: Progress = Progress {
+            task: "x".repeat(size),
+            completed_steps: 1,
+            total_steps: 1,
+        };
+        group.bench_with_input(BenchmarkId::new("serialize", size), &size, |b, _| {
+            b.iter(|| serialize_to_vec(&progress))
+        });
+    }
+    group.finish();
+}
@@ -0,0 +1,1031 @@
+// ============================================= //
+// Benchmarks for the TypeScript runtime         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { Bench } from "tinybench";
+import { decode, encode } from "https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts";
+
+import { createRuntime } from "./index.ts";
+import type { Imports } from "./index.ts";
+import type * as types from "./types.ts";
+
+/**
+ * The payload sizes to benchmark with. Strings, lists and maps in the payloads
+ * contain this many characters or items.
+ */
+export const PAYLOAD_SIZES = [1, 16, 256];
+
+/**
+ * Creates benchmarks for the costs of serializing arguments, calling the
+ * plugin and deserializing return values for every protocol function.
+ *
+ * Calls are only benchmarked for exported functions, because imported
+ * functions can only be called by the plugin.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @returns A `Bench` instance on which the benchmarks can be run.
+ */
+export async function createBenches(
+    plugin: ArrayBuffer,
+    importFunctions: Imports
+): Promise<Bench> {
+    const runtime = await createRuntime(plugin, importFunctions);
+    const bench = new Bench();
+
+    {
+        const arg: Float32Array = new Float32Array(3).fill(1.5);
+        const result = encode(new Float32Array(3).fill(1.5));
+        bench.add("export_array_f32/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_array_f32/call", () => {
+            runtime.exportArrayF32?.(arg);
+        });
+        bench.add("export_array_f32/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Float64Array = new Float64Array(3).fill(1.5);
+        const result = encode(new Float64Array(3).fill(1.5));
+        bench.add("export_array_f64/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_array_f64/call", () => {
+            runtime.exportArrayF64?.(arg);
+        });
+        bench.add("export_array_f64/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Int16Array = new Int16Array(3).fill(1);
+        const result = encode(new Int16Array(3).fill(1));
+        bench.add("export_array_i16/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_array_i16/call", () => {
+            runtime.exportArrayI16?.(arg);
+        });
+        bench.add("export_array_i16/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Int32Array = new Int32Array(3).fill(1);
+        const result = encode(new Int32Array(3).fill(1));
+        bench.add("export_array_i32/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_array_i32/call", () => {
+            runtime.exportArrayI32?.(arg);
+        });
+        bench.add("export_array_i32/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Int8Array = new Int8Array(3).fill(1);
+        const result = encode(new Int8Array(3).fill(1));
+        bench.add("export_array_i8/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_array_i8/call", () => {
+            runtime.exportArrayI8?.(arg);
+        });
+        bench.add("export_array_i8/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Uint16Array = new Uint16Array(3).fill(1);
+        const result = encode(new Uint16Array(3).fill(1));
+        bench.add("export_array_u16/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_array_u16/call", () => {
+            runtime.exportArrayU16?.(arg);
+        });
+        bench.add("export_array_u16/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Uint32Array = new Uint32Array(3).fill(1);
+        const result = encode(new Uint32Array(3).fill(1));
+        bench.add("export_array_u32/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_array_u32/call", () => {
+            runtime.exportArrayU32?.(arg);
+        });
+        bench.add("export_array_u32/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Uint8Array = new Uint8Array(3).fill(1);
+        const result = encode(new Uint8Array(3).fill(1));
+        bench.add("export_array_u8/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_array_u8/call", () => {
+            runtime.exportArrayU8?.(arg);
+        });
+        bench.add("export_array_u8/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg1: types.FpPropertyRenaming = { fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 };
+        const arg2: bigint = 1n;
+        const result = encode({ fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 });
+        bench.add(`export_async_struct/serialize/${size}`, () => {
+            encode(arg1);
+        });
+        bench.add(`export_async_struct/call/${size}`, async () => {
+            await runtime.exportAsyncStruct?.(arg1, arg2);
+        });
+        bench.add(`export_async_struct/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.BatchResponse = { results: Array.from({ length: size }, () => ({ "Ok": 1 })), summary: { "Ok": "x".repeat(size) }, retryAfter: { "Ok": 1 } };
+        const result = encode({ results: Array.from({ length: size }, () => ({ "Ok": 1 })), summary: { "Ok": "x".repeat(size) }, retryAfter: { "Ok": 1 } });
+        bench.add(`export_batch_response/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_batch_response/call/${size}`, () => {
+            runtime.exportBatchResponse?.(arg);
+        });
+        bench.add(`export_batch_response/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const label: string = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`export_cached_call_count/serialize/${size}`, () => {
+            encode(label);
+        });
+        bench.add(`export_cached_call_count/call/${size}`, () => {
+            runtime.exportCachedCallCount?.(label);
+        });
+        bench.add(`export_cached_call_count/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const calls: number = 1;
+        const result = encode(Array.from({ length: size }, () => ({ "Ok": "x".repeat(size) })));
+        bench.add(`export_call_rate_limited_import/call/${size}`, () => {
+            runtime.exportCallRateLimitedImport?.(calls);
+        });
+        bench.add(`export_call_rate_limited_import/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    // Skipped `export_collections`: list type `BTreeSet` is not supported.
+
+    // Skipped `export_dynamic_value`: custom type `Value` is not supported.
+
+    // Skipped `export_edge_values`: custom type `char` is not supported.
+
+    // Skipped `export_experimental`: it is behind the `experimental` feature.
+
+    {
+        const arg: types.FpAdjacentlyTagged = { type: "Foo" };
+        const result = encode({ type: "Foo" });
+        bench.add("export_fp_adjacently_tagged/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_fp_adjacently_tagged/call", () => {
+            runtime.exportFpAdjacentlyTagged?.(arg);
+        });
+        bench.add("export_fp_adjacently_tagged/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: types.FpVariantRenaming = "foo_bar";
+        const result = encode("foo_bar");
+        bench.add("export_fp_enum/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_fp_enum/call", () => {
+            runtime.exportFpEnum?.(arg);
+        });
+        bench.add("export_fp_enum/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.FpFlatten = { foo: "x".repeat(size), bar: 1 };
+        const result = encode({ foo: "x".repeat(size), bar: 1 });
+        bench.add(`export_fp_flatten/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_fp_flatten/call/${size}`, () => {
+            runtime.exportFpFlatten?.(arg);
+        });
+        bench.add(`export_fp_flatten/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: types.FpInternallyTagged = { type: "Foo" };
+        const result = encode({ type: "Foo" });
+        bench.add("export_fp_internally_tagged/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_fp_internally_tagged/call", () => {
+            runtime.exportFpInternallyTagged?.(arg);
+        });
+        bench.add("export_fp_internally_tagged/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.FpPropertyRenaming = { fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 };
+        const result = encode({ fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 });
+        bench.add(`export_fp_struct/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_fp_struct/call/${size}`, () => {
+            runtime.exportFpStruct?.(arg);
+        });
+        bench.add(`export_fp_struct/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.FpUntagged = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`export_fp_untagged/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_fp_untagged/call/${size}`, () => {
+            runtime.exportFpUntagged?.(arg);
+        });
+        bench.add(`export_fp_untagged/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    // Skipped `export_generics`: custom type `OffsetDateTime` is not supported.
+
+    for (const size of PAYLOAD_SIZES) {
+        const result = encode({ "Err": "x".repeat(size) });
+        bench.add(`export_get_bytes/call/${size}`, () => {
+            runtime.exportGetBytes?.();
+        });
+        bench.add(`export_get_bytes/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const result = encode({ "Err": "x".repeat(size) });
+        bench.add(`export_get_serde_bytes/call/${size}`, () => {
+            runtime.exportGetSerdeBytes?.();
+        });
+        bench.add(`export_get_serde_bytes/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: string = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`export_lazy_string/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_lazy_string/call/${size}`, () => {
+            runtime.exportLazyString?.(arg);
+        });
+        bench.add(`export_lazy_string/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const metrics: Array<types.Metric> = Array.from({ length: size }, () => ({ name: "x".repeat(size), value: 1.5 }));
+        const result = encode(Array.from({ length: size }, () => ({ name: "x".repeat(size), value: 1.5 })));
+        bench.add(`export_metrics/serialize/${size}`, () => {
+            encode(metrics);
+        });
+        bench.add(`export_metrics/call/${size}`, () => {
+            runtime.exportMetrics?.(metrics);
+        });
+        bench.add(`export_metrics/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg1: number = 1;
+        const arg2: string = "x".repeat(size);
+        bench.add(`export_multiple_primitives/serialize/${size}`, () => {
+            encode(arg2);
+        });
+        bench.add(`export_multiple_primitives/call/${size}`, () => {
+            runtime.exportMultiplePrimitives?.(arg1, arg2);
+        });
+    }
+
+    // Skipped `export_multiple_values`: tuple `(String, Vec<u32>, bool)` is not supported.
+
+    {
+        const arg: boolean = true;
+        bench.add("export_primitive_bool/call", () => {
+            runtime.exportPrimitiveBool?.(arg);
+        });
+    }
+
+    {
+        const arg: number = 1.5;
+        bench.add("export_primitive_f32/call", () => {
+            runtime.exportPrimitiveF32?.(arg);
+        });
+    }
+
+    {
+        const arg: number = 1.5;
+        bench.add("export_primitive_f64/call", () => {
+            runtime.exportPrimitiveF64?.(arg);
+        });
+    }
+
+    {
+        const arg: number = 1;
+        bench.add("export_primitive_i16/call", () => {
+            runtime.exportPrimitiveI16?.(arg);
+        });
+    }
+
+    {
+        const arg: number = 1;
+        bench.add("export_primitive_i32/call", () => {
+            runtime.exportPrimitiveI32?.(arg);
+        });
+    }
+
+    {
+        const arg: bigint = 1n;
+        bench.add("export_primitive_i64/call", () => {
+            runtime.exportPrimitiveI64?.(arg);
+        });
+    }
+
+    {
+        const arg: number = 1;
+        bench.add("export_primitive_i8/call", () => {
+            runtime.exportPrimitiveI8?.(arg);
+        });
+    }
+
+    {
+        const arg: number = 1;
+        bench.add("export_primitive_pair/call", () => {
+            runtime.exportPrimitivePair?.(arg);
+        });
+    }
+
+    {
+        const arg: number = 1;
+        bench.add("export_primitive_u16/call", () => {
+            runtime.exportPrimitiveU16?.(arg);
+        });
+    }
+
+    {
+        const arg: number = 1;
+        bench.add("export_primitive_u32/call", () => {
+            runtime.exportPrimitiveU32?.(arg);
+        });
+    }
+
+    {
+        const arg: bigint = 1n;
+        bench.add("export_primitive_u64/call", () => {
+            runtime.exportPrimitiveU64?.(arg);
+        });
+    }
+
+    {
+        const arg: number = 1;
+        bench.add("export_primitive_u8/call", () => {
+            runtime.exportPrimitiveU8?.(arg);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: string = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`export_renamed_string/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_renamed_string/call/${size}`, () => {
+            runtime.exportRenamedString?.(arg);
+        });
+        bench.add(`export_renamed_string/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    // Skipped `export_samples`: field `timestamp_ms` of `SampleColumns` has a custom (de)serializer.
+
+    {
+        const arg: types.SerdeAdjacentlyTagged = { type: "Foo" };
+        const result = encode({ type: "Foo" });
+        bench.add("export_serde_adjacently_tagged/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_serde_adjacently_tagged/call", () => {
+            runtime.exportSerdeAdjacentlyTagged?.(arg);
+        });
+        bench.add("export_serde_adjacently_tagged/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: types.SerdeVariantRenaming = "foo_bar";
+        const result = encode("foo_bar");
+        bench.add("export_serde_enum/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_serde_enum/call", () => {
+            runtime.exportSerdeEnum?.(arg);
+        });
+        bench.add("export_serde_enum/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.SerdeFlatten = { foo: "x".repeat(size), bar: 1 };
+        const result = encode({ foo: "x".repeat(size), bar: 1 });
+        bench.add(`export_serde_flatten/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_serde_flatten/call/${size}`, () => {
+            runtime.exportSerdeFlatten?.(arg);
+        });
+        bench.add(`export_serde_flatten/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: types.SerdeInternallyTagged = { type: "Foo" };
+        const result = encode({ type: "Foo" });
+        bench.add("export_serde_internally_tagged/serialize", () => {
+            encode(arg);
+        });
+        bench.add("export_serde_internally_tagged/call", () => {
+            runtime.exportSerdeInternallyTagged?.(arg);
+        });
+        bench.add("export_serde_internally_tagged/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.SerdePropertyRenaming = { fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 };
+        const result = encode({ fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 });
+        bench.add(`export_serde_struct/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_serde_struct/call/${size}`, () => {
+            runtime.exportSerdeStruct?.(arg);
+        });
+        bench.add(`export_serde_struct/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.SerdeUntagged = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`export_serde_untagged/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_serde_untagged/call/${size}`, () => {
+            runtime.exportSerdeUntagged?.(arg);
+        });
+        bench.add(`export_serde_untagged/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: string = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`export_string/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_string/call/${size}`, () => {
+            runtime.exportString?.(arg);
+        });
+        bench.add(`export_string/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: string = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`export_string_after_memory_growth/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_string_after_memory_growth/call/${size}`, () => {
+            runtime.exportStringAfterMemoryGrowth?.(arg);
+        });
+        bench.add(`export_string_after_memory_growth/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.StructWithOptions = { filledString: "x".repeat(size), emptyString: "x".repeat(size), filledOptionString: "x".repeat(size), emptyOptionString: "x".repeat(size), neverSkippedFilledOptionString: "x".repeat(size), neverSkippedEmptyOptionString: "x".repeat(size) };
+        const result = encode({ filledString: "x".repeat(size), emptyString: "x".repeat(size), filledOptionString: "x".repeat(size), emptyOptionString: "x".repeat(size), neverSkippedFilledOptionString: "x".repeat(size), neverSkippedEmptyOptionString: "x".repeat(size) });
+        bench.add(`export_struct_with_options/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_struct_with_options/call/${size}`, () => {
+            runtime.exportStructWithOptions?.(arg);
+        });
+        bench.add(`export_struct_with_options/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    // Skipped `export_timestamp`: custom type `OffsetDateTime` is not supported.
+
+    // Skipped `export_timestamps`: custom type `OffsetDateTime` is not supported.
+
+    // Skipped `export_typed_id`: field `resource` of `TypedId` is a `PhantomData` marker.
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.ValidatedStruct = { percentage: 1, slug: "x".repeat(size), ratio: 1.5, children: Array.from({ length: size }, () => ({ name: "x".repeat(size) })) };
+        const result = encode({ percentage: 1, slug: "x".repeat(size), ratio: 1.5, children: Array.from({ length: size }, () => ({ name: "x".repeat(size) })) });
+        bench.add(`export_validated_struct/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`export_validated_struct/call/${size}`, () => {
+            runtime.exportValidatedStruct?.(arg);
+        });
+        bench.add(`export_validated_struct/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    {
+        bench.add("export_void_function/call", () => {
+            runtime.exportVoidFunction?.();
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const rType: string = "x".repeat(size);
+        const result = encode({ "Ok": "x".repeat(size) });
+        bench.add(`fetch_data/serialize/${size}`, () => {
+            encode(rType);
+        });
+        bench.add(`fetch_data/call/${size}`, async () => {
+            await runtime.fetchData?.(rType);
+        });
+        bench.add(`fetch_data/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const config: types.PluginConfig = { logLevel: "x".repeat(size) };
+        bench.add(`init/serialize/${size}`, () => {
+            encode(config);
+        });
+        bench.add(`init/call/${size}`, () => {
+            runtime.init?.(config);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const action: types.ReduxAction = { type: "clear_title" };
+        const result = encode({ title: "x".repeat(size), revision: 1 });
+        bench.add(`reducer_bridge/serialize/${size}`, () => {
+            encode(action);
+        });
+        bench.add(`reducer_bridge/call/${size}`, () => {
+            runtime.reducerBridge?.(action);
+        });
+        bench.add(`reducer_bridge/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const config: types.PluginConfig = { logLevel: "x".repeat(size) };
+        bench.add(`update_config/serialize/${size}`, () => {
+            encode(config);
+        });
+        bench.add(`update_config/call/${size}`, () => {
+            runtime.updateConfig?.(config);
+        });
+    }
+
+    // Skipped `import_arbitrary_precision_numbers`: custom type `Decimal` is not supported.
+
+    {
+        const arg: Float32Array = new Float32Array(3).fill(1.5);
+        const result = encode(new Float32Array(3).fill(1.5));
+        bench.add("import_array_f32/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_array_f32/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Float64Array = new Float64Array(3).fill(1.5);
+        const result = encode(new Float64Array(3).fill(1.5));
+        bench.add("import_array_f64/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_array_f64/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Int16Array = new Int16Array(3).fill(1);
+        const result = encode(new Int16Array(3).fill(1));
+        bench.add("import_array_i16/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_array_i16/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Int32Array = new Int32Array(3).fill(1);
+        const result = encode(new Int32Array(3).fill(1));
+        bench.add("import_array_i32/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_array_i32/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Int8Array = new Int8Array(3).fill(1);
+        const result = encode(new Int8Array(3).fill(1));
+        bench.add("import_array_i8/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_array_i8/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Uint16Array = new Uint16Array(3).fill(1);
+        const result = encode(new Uint16Array(3).fill(1));
+        bench.add("import_array_u16/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_array_u16/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Uint32Array = new Uint32Array(3).fill(1);
+        const result = encode(new Uint32Array(3).fill(1));
+        bench.add("import_array_u32/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_array_u32/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: Uint8Array = new Uint8Array(3).fill(1);
+        const result = encode(new Uint8Array(3).fill(1));
+        bench.add("import_array_u8/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_array_u8/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const message: string = "x".repeat(size);
+        bench.add(`import_async_void_function/serialize/${size}`, () => {
+            encode(message);
+        });
+    }
+
+    // Skipped `import_collections`: list type `BTreeSet` is not supported.
+
+    // Skipped `import_experimental`: it is behind the `experimental` feature.
+
+    {
+        const arg: types.ExplicitBoundPoint<number> = { value: 1 };
+        bench.add("import_explicit_bound_point/serialize", () => {
+            encode(arg);
+        });
+    }
+
+    {
+        const arg: types.FpAdjacentlyTagged = { type: "Foo" };
+        const result = encode({ type: "Foo" });
+        bench.add("import_fp_adjacently_tagged/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_fp_adjacently_tagged/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: types.FpVariantRenaming = "foo_bar";
+        const result = encode("foo_bar");
+        bench.add("import_fp_enum/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_fp_enum/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.FpFlatten = { foo: "x".repeat(size), bar: 1 };
+        const result = encode({ foo: "x".repeat(size), bar: 1 });
+        bench.add(`import_fp_flatten/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_fp_flatten/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: types.FpInternallyTagged = { type: "Foo" };
+        const result = encode({ type: "Foo" });
+        bench.add("import_fp_internally_tagged/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_fp_internally_tagged/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.FpPropertyRenaming = { fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 };
+        const result = encode({ fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 });
+        bench.add(`import_fp_struct/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_fp_struct/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.FpUntagged = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`import_fp_untagged/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_fp_untagged/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    // Skipped `import_generics`: custom type `OffsetDateTime` is not supported.
+
+    for (const size of PAYLOAD_SIZES) {
+        const result = encode({ "Err": "x".repeat(size) });
+        bench.add(`import_get_bytes/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const result = encode({ "Err": "x".repeat(size) });
+        bench.add(`import_get_serde_bytes/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    // Skipped `import_get_value`: it is generic.
+
+    // Skipped `import_integer_types`: custom type `NonZeroU32` is not supported.
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg2: string = "x".repeat(size);
+        bench.add(`import_multiple_primitives/serialize/${size}`, () => {
+            encode(arg2);
+        });
+    }
+
+    // Skipped `import_multiple_values`: tuple `(String, Vec<u32>, bool)` is not supported.
+
+    for (const size of PAYLOAD_SIZES) {
+        const label: string = "x".repeat(size);
+        const result = encode({ "Ok": "x".repeat(size) });
+        bench.add(`import_rate_limited/serialize/${size}`, () => {
+            encode(label);
+        });
+        bench.add(`import_rate_limited/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: string = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`import_renamed_string/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_renamed_string/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: types.SerdeAdjacentlyTagged = { type: "Foo" };
+        const result = encode({ type: "Foo" });
+        bench.add("import_serde_adjacently_tagged/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_serde_adjacently_tagged/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: types.SerdeVariantRenaming = "foo_bar";
+        const result = encode("foo_bar");
+        bench.add("import_serde_enum/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_serde_enum/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.SerdeFlatten = { foo: "x".repeat(size), bar: 1 };
+        const result = encode({ foo: "x".repeat(size), bar: 1 });
+        bench.add(`import_serde_flatten/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_serde_flatten/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    {
+        const arg: types.SerdeInternallyTagged = { type: "Foo" };
+        const result = encode({ type: "Foo" });
+        bench.add("import_serde_internally_tagged/serialize", () => {
+            encode(arg);
+        });
+        bench.add("import_serde_internally_tagged/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.SerdePropertyRenaming = { fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 };
+        const result = encode({ fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 });
+        bench.add(`import_serde_struct/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_serde_struct/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.SerdeUntagged = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`import_serde_untagged/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_serde_untagged/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    // Skipped `import_set_value`: it is generic.
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: string = "x".repeat(size);
+        const result = encode("x".repeat(size));
+        bench.add(`import_string/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_string/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.StructWithOptions = { filledString: "x".repeat(size), emptyString: "x".repeat(size), filledOptionString: "x".repeat(size), emptyOptionString: "x".repeat(size), neverSkippedFilledOptionString: "x".repeat(size), neverSkippedEmptyOptionString: "x".repeat(size) };
+        const result = encode({ filledString: "x".repeat(size), emptyString: "x".repeat(size), filledOptionString: "x".repeat(size), emptyOptionString: "x".repeat(size), neverSkippedFilledOptionString: "x".repeat(size), neverSkippedEmptyOptionString: "x".repeat(size) });
+        bench.add(`import_struct_with_options/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_struct_with_options/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    // Skipped `import_timestamp`: custom type `OffsetDateTime` is not supported.
+
+    for (const size of PAYLOAD_SIZES) {
+        const arg: types.ValidatedStruct = { percentage: 1, slug: "x".repeat(size), ratio: 1.5, children: Array.from({ length: size }, () => ({ name: "x".repeat(size) })) };
+        const result = encode({ percentage: 1, slug: "x".repeat(size), ratio: 1.5, children: Array.from({ length: size }, () => ({ name: "x".repeat(size) })) });
+        bench.add(`import_validated_struct/serialize/${size}`, () => {
+            encode(arg);
+        });
+        bench.add(`import_validated_struct/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    {
+        const result = encode({ "Ok": null });
+        bench.add("import_void_function_empty_result/deserialize", () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const name: string = "x".repeat(size);
+        const payload: string = "x".repeat(size);
+        const result = encode({ "Ok": "x".repeat(size) });
+        bench.add(`invoke_command/serialize/${size}`, () => {
+            encode(name);
+            encode(payload);
+        });
+        bench.add(`invoke_command/deserialize/${size}`, () => {
+            decode(result);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const message: string = "x".repeat(size);
+        bench.add(`log/serialize/${size}`, () => {
+            encode(message);
+        });
+    }
+
+    // Skipped `make_http_request`: custom type `Uri` is not supported.
+
+    for (const size of PAYLOAD_SIZES) {
+        const name: string = "x".repeat(size);
+        bench.add(`record_metric/serialize/${size}`, () => {
+            encode(name);
+        });
+    }
+
+    for (const size of PAYLOAD_SIZES) {
+        const progress: types.Progress = { task: "x".repeat(size), completedSteps: 1, totalSteps: 1 };
+        bench.add(`report_progress/serialize/${size}`, () => {
+            encode(progress);
+        });
+    }
+
+    return bench;
+}
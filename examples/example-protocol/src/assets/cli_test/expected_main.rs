@@ -0,0 +1,382 @@
+// ============================================= //
+// Command-line harness for plugins              //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+mod spec;
+
+use serde::{de::DeserializeOwned, Serialize};
+use spec::bindings::{PluginError, Runtime};
+
+const USAGE: &str = r"Usage: example-bindings-cli <PLUGIN> <EXPORT> [ARGS]...
+
+Calls an export of the given plugin with the given arguments, which are passed
+as JSON, and prints its result as JSON. Imports are not implemented, so exports
+that call them fail.
+
+Exports:
+    export_array_f32 <arg: [f32; 3]>
+    export_array_f64 <arg: [f64; 3]>
+    export_array_i16 <arg: [i16; 3]>
+    export_array_i32 <arg: [i32; 3]>
+    export_array_i8 <arg: [i8; 3]>
+    export_array_u16 <arg: [u16; 3]>
+    export_array_u32 <arg: [u32; 3]>
+    export_array_u8 <arg: [u8; 3]>
+    export_async_struct <arg1: FpPropertyRenaming> <arg2: u64>
+    export_batch_response <arg: BatchResponse>
+    export_cached_call_count <label: String>
+    export_call_rate_limited_import <calls: u32>
+    export_collections <arg: Collections>
+    export_dynamic_value <arg: ExtensionSettings>
+    export_edge_values <arg: EdgeValues>
+    export_experimental <arg: String>
+    export_fp_adjacently_tagged <arg: FpAdjacentlyTagged>
+    export_fp_enum <arg: FpVariantRenaming>
+    export_fp_flatten <arg: FpFlatten>
+    export_fp_internally_tagged <arg: FpInternallyTagged>
+    export_fp_struct <arg: FpPropertyRenaming>
+    export_fp_untagged <arg: FpUntagged>
+    export_generics <arg: StructWithGenerics<u64>>
+    export_get_bytes
+    export_get_serde_bytes
+    export_lazy_string <arg: String>
+    export_metrics <metrics: Vec<Metric>>
+    export_multiple_primitives <arg1: i8> <arg2: String>
+    export_multiple_values <arg: u32>
+    export_primitive_bool <arg: bool>
+    export_primitive_f32 <arg: f32>
+    export_primitive_f64 <arg: f64>
+    export_primitive_i16 <arg: i16>
+    export_primitive_i32 <arg: i32>
+    export_primitive_i64 <arg: i64>
+    export_primitive_i8 <arg: i8>
+    export_primitive_pair <arg: u32>
+    export_primitive_u16 <arg: u16>
+    export_primitive_u32 <arg: u32>
+    export_primitive_u64 <arg: u64>
+    export_primitive_u8 <arg: u8>
+    export_renamed_string <arg: String>
+    export_samples <samples: SampleColumns>
+    export_serde_adjacently_tagged <arg: SerdeAdjacentlyTagged>
+    export_serde_enum <arg: SerdeVariantRenaming>
+    export_serde_flatten <arg: SerdeFlatten>
+    export_serde_internally_tagged <arg: SerdeInternallyTagged>
+    export_serde_struct <arg: SerdePropertyRenaming>
+    export_serde_untagged <arg: SerdeUntagged>
+    export_string <arg: String>
+    export_string_after_memory_growth <arg: String>
+    export_struct_with_options <arg: StructWithOptions>
+    export_timestamp <arg: MyDateTime>
+    export_timestamps <arg: Timestamps>
+    export_typed_id <id: TypedId<Point<f64>>>
+    export_validated_struct <arg: ValidatedStruct>
+    export_void_function
+    fetch_data <type: String>
+    init <config: PluginConfig>
+    reducer_bridge <action: ReduxAction>
+    update_config <config: PluginConfig>";
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match run(&args).await {
+        Ok(result) => println!("{result}"),
+        Err(error) => {
+            eprintln!("{error}");
+            std::process::exit(1);
+        }
+    }
+}
+
+async fn run(args: &[String]) -> Result<String, String> {
+    let (plugin_path, export, args) = match args {
+        [plugin_path, export, args @ ..] => (plugin_path, export.as_str(), args),
+        _ => return Err(USAGE.to_owned()),
+    };
+
+    let wasm_module = std::fs::read(plugin_path)
+        .map_err(|error| format!("Could not read plugin `{plugin_path}`: {error}"))?;
+    let runtime = Runtime::new(wasm_module)
+        .map_err(|error| format!("Could not instantiate plugin: {error}"))?;
+
+    match export {
+        "export_array_f32" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_array_f32(parse_arg("arg", &args[0])?))
+        }
+        "export_array_f64" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_array_f64(parse_arg("arg", &args[0])?))
+        }
+        "export_array_i16" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_array_i16(parse_arg("arg", &args[0])?))
+        }
+        "export_array_i32" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_array_i32(parse_arg("arg", &args[0])?))
+        }
+        "export_array_i8" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_array_i8(parse_arg("arg", &args[0])?))
+        }
+        "export_array_u16" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_array_u16(parse_arg("arg", &args[0])?))
+        }
+        "export_array_u32" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_array_u32(parse_arg("arg", &args[0])?))
+        }
+        "export_array_u8" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_array_u8(parse_arg("arg", &args[0])?))
+        }
+        "export_async_struct" => {
+            check_arg_count(export, args, 2)?;
+            format_result(
+                runtime
+                    .export_async_struct(parse_arg("arg1", &args[0])?, parse_arg("arg2", &args[1])?)
+                    .await,
+            )
+        }
+        "export_batch_response" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_batch_response(parse_arg("arg", &args[0])?))
+        }
+        "export_cached_call_count" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_cached_call_count(parse_arg("label", &args[0])?))
+        }
+        "export_call_rate_limited_import" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_call_rate_limited_import(parse_arg("calls", &args[0])?))
+        }
+        "export_collections" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_collections(parse_arg("arg", &args[0])?))
+        }
+        "export_dynamic_value" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_dynamic_value(parse_arg("arg", &args[0])?))
+        }
+        "export_edge_values" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_edge_values(parse_arg("arg", &args[0])?))
+        }
+        #[cfg(feature = "experimental")]
+        "export_experimental" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_experimental(parse_arg("arg", &args[0])?))
+        }
+        "export_fp_adjacently_tagged" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_fp_adjacently_tagged(parse_arg("arg", &args[0])?))
+        }
+        "export_fp_enum" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_fp_enum(parse_arg("arg", &args[0])?))
+        }
+        "export_fp_flatten" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_fp_flatten(parse_arg("arg", &args[0])?))
+        }
+        "export_fp_internally_tagged" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_fp_internally_tagged(parse_arg("arg", &args[0])?))
+        }
+        "export_fp_struct" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_fp_struct(parse_arg("arg", &args[0])?))
+        }
+        "export_fp_untagged" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_fp_untagged(parse_arg("arg", &args[0])?))
+        }
+        "export_generics" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_generics(parse_arg("arg", &args[0])?))
+        }
+        "export_get_bytes" => {
+            check_arg_count(export, args, 0)?;
+            format_result(runtime.export_get_bytes())
+        }
+        "export_get_serde_bytes" => {
+            check_arg_count(export, args, 0)?;
+            format_result(runtime.export_get_serde_bytes())
+        }
+        "export_lazy_string" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_lazy_string(parse_arg("arg", &args[0])?))
+        }
+        "export_metrics" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_metrics(parse_arg("metrics", &args[0])?))
+        }
+        "export_multiple_primitives" => {
+            check_arg_count(export, args, 2)?;
+            format_result(runtime.export_multiple_primitives(
+                parse_arg("arg1", &args[0])?,
+                parse_arg("arg2", &args[1])?,
+            ))
+        }
+        "export_multiple_values" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_multiple_values(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_bool" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_bool(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_f32" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_f32(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_f64" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_f64(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_i16" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_i16(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_i32" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_i32(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_i64" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_i64(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_i8" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_i8(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_pair" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_pair(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_u16" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_u16(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_u32" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_u32(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_u64" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_u64(parse_arg("arg", &args[0])?))
+        }
+        "export_primitive_u8" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_primitive_u8(parse_arg("arg", &args[0])?))
+        }
+        "export_renamed_string" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_renamed_string(parse_arg("arg", &args[0])?))
+        }
+        "export_samples" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_samples(parse_arg("samples", &args[0])?))
+        }
+        "export_serde_adjacently_tagged" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_serde_adjacently_tagged(parse_arg("arg", &args[0])?))
+        }
+        "export_serde_enum" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_serde_enum(parse_arg("arg", &args[0])?))
+        }
+        "export_serde_flatten" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_serde_flatten(parse_arg("arg", &args[0])?))
+        }
+        "export_serde_internally_tagged" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_serde_internally_tagged(parse_arg("arg", &args[0])?))
+        }
+        "export_serde_struct" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_serde_struct(parse_arg("arg", &args[0])?))
+        }
+        "export_serde_untagged" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_serde_untagged(parse_arg("arg", &args[0])?))
+        }
+        "export_string" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_string(parse_arg("arg", &args[0])?))
+        }
+        "export_string_after_memory_growth" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_string_after_memory_growth(parse_arg("arg", &args[0])?))
+        }
+        "export_struct_with_options" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_struct_with_options(parse_arg("arg", &args[0])?))
+        }
+        "export_timestamp" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_timestamp(parse_arg("arg", &args[0])?))
+        }
+        "export_timestamps" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_timestamps(parse_arg("arg", &args[0])?))
+        }
+        "export_typed_id" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_typed_id(parse_arg("id", &args[0])?))
+        }
+        "export_validated_struct" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.export_validated_struct(parse_arg("arg", &args[0])?))
+        }
+        "export_void_function" => {
+            check_arg_count(export, args, 0)?;
+            format_result(runtime.export_void_function())
+        }
+        "fetch_data" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.fetch_data(parse_arg("type", &args[0])?).await)
+        }
+        "init" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.init(parse_arg("config", &args[0])?))
+        }
+        "reducer_bridge" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.reducer_bridge(parse_arg("action", &args[0])?))
+        }
+        "update_config" => {
+            check_arg_count(export, args, 1)?;
+            format_result(runtime.update_config(parse_arg("config", &args[0])?))
+        }
+        _ => Err(format!("Unknown export `{export}`\n\n{USAGE}")),
+    }
+}
+
+/// Checks that the given number of arguments was passed to an export.
+fn check_arg_count(export: &str, args: &[String], count: usize) -> Result<(), String> {
+    if args.len() == count {
+        Ok(())
+    } else {
+        Err(format!(
+            "Export `{export}` takes {count} argument(s), but {} were given",
+            args.len()
+        ))
+    }
+}
+
+/// Parses the JSON value of an argument.
+fn parse_arg<T: DeserializeOwned>(name: &str, value: &str) -> Result<T, String> {
+    serde_json::from_str(value).map_err(|error| format!("Invalid value for `{name}`: {error}"))
+}
+
+/// Formats the result of an export as JSON.
+fn format_result<T: Serialize>(result: Result<T, PluginError>) -> Result<String, String> {
+    let value = result.map_err(|error| format!("Invocation failed: {error}"))?;
+    serde_json::to_string_pretty(&value)
+        .map_err(|error| format!("Could not format result: {error}"))
+}
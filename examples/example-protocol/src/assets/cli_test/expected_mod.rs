@@ -0,0 +1,247 @@
+// ============================================= //
+// Command-line harness for plugins              //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+pub mod bindings;
+pub mod types;
+
+#[allow(unused_imports)]
+use types::*;
+
+fn import_arbitrary_precision_numbers(_: ArbitraryPrecisionNumbers) -> ArbitraryPrecisionNumbers {
+    unimplemented_import("import_arbitrary_precision_numbers")
+}
+
+fn import_array_f32(_: [f32; 3]) -> [f32; 3] {
+    unimplemented_import("import_array_f32")
+}
+
+fn import_array_f64(_: [f64; 3]) -> [f64; 3] {
+    unimplemented_import("import_array_f64")
+}
+
+fn import_array_i16(_: [i16; 3]) -> [i16; 3] {
+    unimplemented_import("import_array_i16")
+}
+
+fn import_array_i32(_: [i32; 3]) -> [i32; 3] {
+    unimplemented_import("import_array_i32")
+}
+
+fn import_array_i8(_: [i8; 3]) -> [i8; 3] {
+    unimplemented_import("import_array_i8")
+}
+
+fn import_array_u16(_: [u16; 3]) -> [u16; 3] {
+    unimplemented_import("import_array_u16")
+}
+
+fn import_array_u32(_: [u32; 3]) -> [u32; 3] {
+    unimplemented_import("import_array_u32")
+}
+
+fn import_array_u8(_: [u8; 3]) -> [u8; 3] {
+    unimplemented_import("import_array_u8")
+}
+
+fn import_async_void_function(_: String) -> std::future::Ready<()> {
+    unimplemented_import("import_async_void_function")
+}
+
+fn import_collections(_: Collections) -> Collections {
+    unimplemented_import("import_collections")
+}
+
+#[cfg(feature = "experimental")]
+fn import_experimental(_: String) -> String {
+    unimplemented_import("import_experimental")
+}
+
+fn import_explicit_bound_point(_: ExplicitBoundPoint<u64>) {
+    unimplemented_import("import_explicit_bound_point")
+}
+
+fn import_fp_adjacently_tagged(_: FpAdjacentlyTagged) -> FpAdjacentlyTagged {
+    unimplemented_import("import_fp_adjacently_tagged")
+}
+
+fn import_fp_enum(_: FpVariantRenaming) -> FpVariantRenaming {
+    unimplemented_import("import_fp_enum")
+}
+
+fn import_fp_flatten(_: FpFlatten) -> FpFlatten {
+    unimplemented_import("import_fp_flatten")
+}
+
+fn import_fp_internally_tagged(_: FpInternallyTagged) -> FpInternallyTagged {
+    unimplemented_import("import_fp_internally_tagged")
+}
+
+fn import_fp_struct(_: FpPropertyRenaming) -> FpPropertyRenaming {
+    unimplemented_import("import_fp_struct")
+}
+
+fn import_fp_untagged(_: FpUntagged) -> FpUntagged {
+    unimplemented_import("import_fp_untagged")
+}
+
+fn import_generics(_: StructWithGenerics<u64>) -> StructWithGenerics<u64> {
+    unimplemented_import("import_generics")
+}
+
+fn import_get_bytes() -> Result<bytes::Bytes, String> {
+    unimplemented_import("import_get_bytes")
+}
+
+fn import_get_serde_bytes() -> Result<serde_bytes::ByteBuf, String> {
+    unimplemented_import("import_get_serde_bytes")
+}
+
+fn import_get_value(_: String) -> std::future::Ready<Option<serde_json::Value>> {
+    unimplemented_import("import_get_value")
+}
+
+fn import_integer_types(_: IntegerTypes) -> IntegerTypes {
+    unimplemented_import("import_integer_types")
+}
+
+fn import_multiple_primitives(_: i8, _: String) -> i64 {
+    unimplemented_import("import_multiple_primitives")
+}
+
+fn import_multiple_values(_: u32) -> (String, Vec<u32>, bool) {
+    unimplemented_import("import_multiple_values")
+}
+
+fn import_primitive_bool(_: bool) -> bool {
+    unimplemented_import("import_primitive_bool")
+}
+
+fn import_primitive_f32(_: f32) -> f32 {
+    unimplemented_import("import_primitive_f32")
+}
+
+fn import_primitive_f64(_: f64) -> f64 {
+    unimplemented_import("import_primitive_f64")
+}
+
+fn import_primitive_i16(_: i16) -> i16 {
+    unimplemented_import("import_primitive_i16")
+}
+
+fn import_primitive_i32(_: i32) -> i32 {
+    unimplemented_import("import_primitive_i32")
+}
+
+fn import_primitive_i64(_: i64) -> i64 {
+    unimplemented_import("import_primitive_i64")
+}
+
+fn import_primitive_i8(_: i8) -> i8 {
+    unimplemented_import("import_primitive_i8")
+}
+
+fn import_primitive_pair(_: u32) -> (u32, i32) {
+    unimplemented_import("import_primitive_pair")
+}
+
+fn import_primitive_u16(_: u16) -> u16 {
+    unimplemented_import("import_primitive_u16")
+}
+
+fn import_primitive_u32(_: u32) -> u32 {
+    unimplemented_import("import_primitive_u32")
+}
+
+fn import_primitive_u64(_: u64) -> u64 {
+    unimplemented_import("import_primitive_u64")
+}
+
+fn import_primitive_u8(_: u8) -> u8 {
+    unimplemented_import("import_primitive_u8")
+}
+
+fn import_rate_limited(_: String) -> Result<String, RateLimited> {
+    unimplemented_import("import_rate_limited")
+}
+
+fn import_renamed_string(_: String) -> String {
+    unimplemented_import("import_renamed_string")
+}
+
+fn import_serde_adjacently_tagged(_: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged {
+    unimplemented_import("import_serde_adjacently_tagged")
+}
+
+fn import_serde_enum(_: SerdeVariantRenaming) -> SerdeVariantRenaming {
+    unimplemented_import("import_serde_enum")
+}
+
+fn import_serde_flatten(_: SerdeFlatten) -> SerdeFlatten {
+    unimplemented_import("import_serde_flatten")
+}
+
+fn import_serde_internally_tagged(_: SerdeInternallyTagged) -> SerdeInternallyTagged {
+    unimplemented_import("import_serde_internally_tagged")
+}
+
+fn import_serde_struct(_: SerdePropertyRenaming) -> SerdePropertyRenaming {
+    unimplemented_import("import_serde_struct")
+}
+
+fn import_serde_untagged(_: SerdeUntagged) -> SerdeUntagged {
+    unimplemented_import("import_serde_untagged")
+}
+
+fn import_set_value(_: String, _: serde_json::Value) {
+    unimplemented_import("import_set_value")
+}
+
+fn import_string(_: String) -> String {
+    unimplemented_import("import_string")
+}
+
+fn import_struct_with_options(_: StructWithOptions) -> StructWithOptions {
+    unimplemented_import("import_struct_with_options")
+}
+
+fn import_timestamp(_: MyDateTime) -> MyDateTime {
+    unimplemented_import("import_timestamp")
+}
+
+fn import_validated_struct(_: ValidatedStruct) -> ValidatedStruct {
+    unimplemented_import("import_validated_struct")
+}
+
+fn import_void_function() {
+    unimplemented_import("import_void_function")
+}
+
+fn import_void_function_empty_result() -> Result<(), u32> {
+    unimplemented_import("import_void_function_empty_result")
+}
+
+fn import_void_function_empty_return() {
+    unimplemented_import("import_void_function_empty_return")
+}
+
+fn invoke_command(_: String, _: String) -> Result<String, String> {
+    unimplemented_import("invoke_command")
+}
+
+fn log(_: String) {
+    unimplemented_import("log")
+}
+
+fn make_http_request(_: Request) -> std::future::Ready<HttpResult> {
+    unimplemented_import("make_http_request")
+}
+
+fn record_metric(_: String, _: f64) {
+    unimplemented_import("record_metric")
+}
+
+fn unimplemented_import(name: &str) -> ! {
+    panic!("Import `{name}` is not implemented by the CLI")
+}
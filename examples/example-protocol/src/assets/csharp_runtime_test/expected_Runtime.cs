@@ -0,0 +1,1564 @@
+// ============================================= //
+// WebAssembly runtime for C#                    //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+using System;
+using System.Collections.Generic;
+using System.Threading.Tasks;
+using MessagePack;
+using Wasmtime;
+
+namespace ExampleBindings;
+
+/// <summary>
+/// Statistics about the memory that is allocated by a plugin.
+/// </summary>
+[MessagePackObject]
+public sealed record MemoryStats
+{
+    [Key("allocatedBytes")]
+    public required ulong AllocatedBytes { get; init; }
+
+    [Key("peakAllocatedBytes")]
+    public required ulong PeakAllocatedBytes { get; init; }
+
+    [Key("allocationCount")]
+    public required ulong AllocationCount { get; init; }
+}
+
+/// <summary>
+/// The host functions that may be imported by the plugin.
+/// </summary>
+public interface IImports
+{
+    ArbitraryPrecisionNumbers ImportArbitraryPrecisionNumbers(ArbitraryPrecisionNumbers arg);
+
+    float[] ImportArrayF32(float[] arg);
+
+    double[] ImportArrayF64(double[] arg);
+
+    short[] ImportArrayI16(short[] arg);
+
+    int[] ImportArrayI32(int[] arg);
+
+    sbyte[] ImportArrayI8(sbyte[] arg);
+
+    ushort[] ImportArrayU16(ushort[] arg);
+
+    uint[] ImportArrayU32(uint[] arg);
+
+    List<byte> ImportArrayU8(List<byte> arg);
+
+    Task ImportAsyncVoidFunction(string message);
+
+    Collections ImportCollections(Collections arg);
+
+    /// <summary>
+    /// Example of an import behind a feature. Only plugins and runtimes that
+    /// are compiled with the `experimental` feature know about it.
+    /// </summary>
+    string ImportExperimental(string arg);
+
+    void ImportExplicitBoundPoint(ExplicitBoundPoint<ulong> arg);
+
+    FpAdjacentlyTagged ImportFpAdjacentlyTagged(FpAdjacentlyTagged arg);
+
+    FpVariantRenaming ImportFpEnum(FpVariantRenaming arg);
+
+    FpFlatten ImportFpFlatten(FpFlatten arg);
+
+    FpInternallyTagged ImportFpInternallyTagged(FpInternallyTagged arg);
+
+    FpPropertyRenaming ImportFpStruct(FpPropertyRenaming arg);
+
+    FpUntagged ImportFpUntagged(FpUntagged arg);
+
+    StructWithGenerics<ulong> ImportGenerics(StructWithGenerics<ulong> arg);
+
+    Result<byte[], string> ImportGetBytes();
+
+    Result<byte[], string> ImportGetSerdeBytes();
+
+    Task<object?> ImportGetValue(string key);
+
+    IntegerTypes ImportIntegerTypes(IntegerTypes arg);
+
+    long ImportMultiplePrimitives(sbyte arg1, string arg2);
+
+    (string, List<uint>, bool) ImportMultipleValues(uint arg);
+
+    bool ImportPrimitiveBool(bool arg);
+
+    float ImportPrimitiveF32(float arg);
+
+    double ImportPrimitiveF64(double arg);
+
+    short ImportPrimitiveI16(short arg);
+
+    int ImportPrimitiveI32(int arg);
+
+    long ImportPrimitiveI64(long arg);
+
+    sbyte ImportPrimitiveI8(sbyte arg);
+
+    (uint, int) ImportPrimitivePair(uint arg);
+
+    ushort ImportPrimitiveU16(ushort arg);
+
+    uint ImportPrimitiveU32(uint arg);
+
+    ulong ImportPrimitiveU64(ulong arg);
+
+    byte ImportPrimitiveU8(byte arg);
+
+    /// <summary>
+    /// Example of a rate-limited import. Calls that exceed the limit return a
+    /// `RateLimited` error to the plugin, instead of reaching the runtime.
+    /// </summary>
+    Result<string, RateLimited> ImportRateLimited(string label);
+
+    /// <summary>
+    /// Example of a renamed import. It is still exchanged under its old name,
+    /// so plugins that were built against `import_legacy_string` keep working.
+    /// </summary>
+    string ImportRenamedString(string arg);
+
+    SerdeAdjacentlyTagged ImportSerdeAdjacentlyTagged(SerdeAdjacentlyTagged arg);
+
+    SerdeVariantRenaming ImportSerdeEnum(SerdeVariantRenaming arg);
+
+    SerdeFlatten ImportSerdeFlatten(SerdeFlatten arg);
+
+    SerdeInternallyTagged ImportSerdeInternallyTagged(SerdeInternallyTagged arg);
+
+    SerdePropertyRenaming ImportSerdeStruct(SerdePropertyRenaming arg);
+
+    SerdeUntagged ImportSerdeUntagged(SerdeUntagged arg);
+
+    void ImportSetValue(string key, object value);
+
+    string ImportString(string arg);
+
+    StructWithOptions ImportStructWithOptions(StructWithOptions arg);
+
+    string ImportTimestamp(string arg);
+
+    ValidatedStruct ImportValidatedStruct(ValidatedStruct arg);
+
+    void ImportVoidFunction();
+
+    Result<Nil, uint> ImportVoidFunctionEmptyResult();
+
+    void ImportVoidFunctionEmptyReturn();
+
+    /// <summary>
+    /// Example of a dynamic import. The runtime can register handlers for it
+    /// after instantiation, which are dispatched to by `name`. Calls for which
+    /// no handler is registered fall back to the regular implementation.
+    /// </summary>
+    Result<string, string> InvokeCommand(string name, string payload);
+
+    /// <summary>
+    /// Logs a message to the (development) console.
+    /// </summary>
+    void Log(string message);
+
+    /// <summary>
+    /// Example how a runtime could expose a `Fetch`-like function to plugins.
+    ///
+    /// See `types/http.rs` for more info.
+    ///
+    /// Plugins may only call this if the runtime granted them the `network`
+    /// capability.
+    /// </summary>
+    Task<Result<Response, RequestError>> MakeHttpRequest(Request request);
+
+    /// <summary>
+    /// Example of a batchable import. Calls that are made inside `batch()` are
+    /// queued by the plugin and sent to the runtime together.
+    /// </summary>
+    void RecordMetric(string name, double value);
+}
+
+/// <summary>
+/// Represents an unrecoverable error in the FP runtime.
+///
+/// After this, your only recourse is to create a new runtime, probably with a
+/// different WASM plugin.
+/// </summary>
+public class FpRuntimeException : Exception
+{
+    public FpRuntimeException(string message) : base(message)
+    {
+    }
+}
+
+/// <summary>
+/// Thrown when the plugin reports a failure before it aborts, which plugins do
+/// if they are built with the `panic-abort` feature of `fp-bindgen-support`.
+/// </summary>
+public sealed class GuestErrorException : FpRuntimeException
+{
+    public GuestErrorException(string kind, string message)
+        : base($"Plugin failed with {kind} error: {message}")
+    {
+        Kind = kind;
+        GuestMessage = message;
+    }
+
+    /// <summary>
+    /// The kind of failure, such as <c>"deserialization"</c> or <c>"panic"</c>.
+    /// </summary>
+    public string Kind { get; }
+
+    /// <summary>
+    /// The message that was reported by the plugin.
+    /// </summary>
+    public string GuestMessage { get; }
+}
+
+/// <summary>
+/// Thrown when the plugin fails to allocate the memory for a value that is
+/// passed to it, which it reports by returning a null pointer from its
+/// allocator.
+/// </summary>
+public sealed class AllocationFailedException : FpRuntimeException
+{
+    public AllocationFailedException(int len)
+        : base($"Plugin could not allocate {len} bytes")
+    {
+        Len = len;
+    }
+
+    public int Len { get; }
+}
+
+[MessagePackObject]
+internal sealed record GuestErrorReport
+{
+    [Key("kind")]
+    public required string Kind { get; init; }
+
+    [Key("message")]
+    public required string Message { get; init; }
+}
+
+/// <summary>
+/// A capability that can be granted to the plugin. Imports that require a
+/// capability can only be called by the plugin if it was granted.
+/// </summary>
+public enum Capability
+{
+    Network,
+}
+
+/// <summary>
+/// Thrown when the plugin calls an import that requires a capability that was
+/// not granted to it. The plugin call that triggered the import fails as a
+/// result.
+/// </summary>
+public sealed class PermissionDeniedException : FpRuntimeException
+{
+    public PermissionDeniedException(string functionName, Capability capability)
+        : base($"Import \"{functionName}\" requires the \"{capability}\" capability, which was not granted")
+    {
+        FunctionName = functionName;
+        Capability = capability;
+    }
+
+    public string FunctionName { get; }
+
+    public Capability Capability { get; }
+}
+
+/// <summary>
+/// Runtime for executing a plugin.
+///
+/// Calls into the plugin are serialized, so a runtime may be shared between
+/// threads. Imports are invoked on the thread that calls into the plugin.
+/// </summary>
+public sealed class Runtime : IDisposable
+{
+    private static readonly MessagePackSerializerOptions SerializerOptions =
+        MessagePackSerializerOptions.Standard;
+
+    private readonly object _lock = new();
+    private readonly IImports _imports;
+    private readonly HashSet<Capability> _capabilities;
+    private readonly Engine _engine;
+    private readonly Store _store;
+    private readonly Instance _instance;
+    private readonly Memory _memory;
+    private readonly Func<int, long> _malloc;
+    private readonly Action<long> _free;
+    private readonly Action<long, long> _resolveFuture;
+    private readonly Dictionary<long, TaskCompletionSource<long>> _promises = new();
+    private readonly Dictionary<string, Func<string, Result<string, string>>> _invokeCommandHandlers = new();
+
+    /// <summary>
+    /// Creates a runtime for executing the given plugin.
+    /// </summary>
+    /// <param name="plugin">The raw WASM plugin.</param>
+    /// <param name="imports">The host functions that may be imported by the plugin.</param>
+    /// <param name="capabilities">The capabilities that are granted to the plugin.</param>
+    public Runtime(
+        byte[] plugin,
+        IImports imports,
+        IEnumerable<Capability>? capabilities = null)
+    {
+        _imports = imports;
+        _capabilities = new HashSet<Capability>(capabilities ?? Array.Empty<Capability>());
+        _engine = new Engine();
+        _store = new Store(_engine);
+
+        using var module = Module.FromBytes(_engine, "plugin", plugin);
+        using var linker = new Linker(_engine);
+        DefineImports(linker);
+
+        _instance = linker.Instantiate(_store, module);
+        _memory = _instance.GetMemory("memory") ?? throw MissingExport("memory");
+        _malloc = _instance.GetFunction<int, long>("__fp_malloc") ?? throw MissingExport("__fp_malloc");
+        _free = _instance.GetAction<long>("__fp_free") ?? throw MissingExport("__fp_free");
+        _resolveFuture = _instance.GetAction<long, long>("__fp_guest_resolve_async_value") ??
+            throw MissingExport("__fp_guest_resolve_async_value");
+    }
+
+    public float[] ExportArrayF32(float[] arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_array_f32") ??
+                throw MissingExport("__fp_gen_export_array_f32");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<float[]>(exportFn(argPtr));
+        }
+    }
+
+    public double[] ExportArrayF64(double[] arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_array_f64") ??
+                throw MissingExport("__fp_gen_export_array_f64");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<double[]>(exportFn(argPtr));
+        }
+    }
+
+    public short[] ExportArrayI16(short[] arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_array_i16") ??
+                throw MissingExport("__fp_gen_export_array_i16");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<short[]>(exportFn(argPtr));
+        }
+    }
+
+    public int[] ExportArrayI32(int[] arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_array_i32") ??
+                throw MissingExport("__fp_gen_export_array_i32");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<int[]>(exportFn(argPtr));
+        }
+    }
+
+    public sbyte[] ExportArrayI8(sbyte[] arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_array_i8") ??
+                throw MissingExport("__fp_gen_export_array_i8");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<sbyte[]>(exportFn(argPtr));
+        }
+    }
+
+    public ushort[] ExportArrayU16(ushort[] arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_array_u16") ??
+                throw MissingExport("__fp_gen_export_array_u16");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<ushort[]>(exportFn(argPtr));
+        }
+    }
+
+    public uint[] ExportArrayU32(uint[] arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_array_u32") ??
+                throw MissingExport("__fp_gen_export_array_u32");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<uint[]>(exportFn(argPtr));
+        }
+    }
+
+    public List<byte> ExportArrayU8(List<byte> arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_array_u8") ??
+                throw MissingExport("__fp_gen_export_array_u8");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<List<byte>>(exportFn(argPtr));
+        }
+    }
+
+    public async Task<FpPropertyRenaming> ExportAsyncStruct(FpPropertyRenaming arg1, ulong arg2)
+    {
+        Task<long> task;
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long, long>("__fp_gen_export_async_struct") ??
+                throw MissingExport("__fp_gen_export_async_struct");
+            var arg1Ptr = SerializeObject(arg1);
+            task = TaskFromPtr(exportFn(arg1Ptr, (long)arg2));
+        }
+
+        var resultPtr = await task;
+        lock (_lock)
+        {
+            return ParseObject<FpPropertyRenaming>(resultPtr);
+        }
+    }
+
+    public BatchResponse ExportBatchResponse(BatchResponse arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_batch_response") ??
+                throw MissingExport("__fp_gen_export_batch_response");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<BatchResponse>(exportFn(argPtr));
+        }
+    }
+
+    public string ExportCachedCallCount(string label)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_cached_call_count") ??
+                throw MissingExport("__fp_gen_export_cached_call_count");
+            var labelPtr = SerializeObject(label);
+            return ParseObject<string>(exportFn(labelPtr));
+        }
+    }
+
+    public List<Result<string, RateLimited>> ExportCallRateLimitedImport(uint calls)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, long>("__fp_gen_export_call_rate_limited_import") ??
+                throw MissingExport("__fp_gen_export_call_rate_limited_import");
+            return ParseObject<List<Result<string, RateLimited>>>(exportFn((int)calls));
+        }
+    }
+
+    public Collections ExportCollections(Collections arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_collections") ??
+                throw MissingExport("__fp_gen_export_collections");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<Collections>(exportFn(argPtr));
+        }
+    }
+
+    public object ExportDynamicValue(ExtensionSettings arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_dynamic_value") ??
+                throw MissingExport("__fp_gen_export_dynamic_value");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<object>(exportFn(argPtr));
+        }
+    }
+
+    public EdgeValues ExportEdgeValues(EdgeValues arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_edge_values") ??
+                throw MissingExport("__fp_gen_export_edge_values");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<EdgeValues>(exportFn(argPtr));
+        }
+    }
+
+    public string ExportExperimental(string arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_experimental") ??
+                throw MissingExport("__fp_gen_export_experimental");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<string>(exportFn(argPtr));
+        }
+    }
+
+    public FpAdjacentlyTagged ExportFpAdjacentlyTagged(FpAdjacentlyTagged arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_fp_adjacently_tagged") ??
+                throw MissingExport("__fp_gen_export_fp_adjacently_tagged");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<FpAdjacentlyTagged>(exportFn(argPtr));
+        }
+    }
+
+    public FpVariantRenaming ExportFpEnum(FpVariantRenaming arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_fp_enum") ??
+                throw MissingExport("__fp_gen_export_fp_enum");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<FpVariantRenaming>(exportFn(argPtr));
+        }
+    }
+
+    public FpFlatten ExportFpFlatten(FpFlatten arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_fp_flatten") ??
+                throw MissingExport("__fp_gen_export_fp_flatten");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<FpFlatten>(exportFn(argPtr));
+        }
+    }
+
+    public FpInternallyTagged ExportFpInternallyTagged(FpInternallyTagged arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_fp_internally_tagged") ??
+                throw MissingExport("__fp_gen_export_fp_internally_tagged");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<FpInternallyTagged>(exportFn(argPtr));
+        }
+    }
+
+    public FpPropertyRenaming ExportFpStruct(FpPropertyRenaming arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_fp_struct") ??
+                throw MissingExport("__fp_gen_export_fp_struct");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<FpPropertyRenaming>(exportFn(argPtr));
+        }
+    }
+
+    public FpUntagged ExportFpUntagged(FpUntagged arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_fp_untagged") ??
+                throw MissingExport("__fp_gen_export_fp_untagged");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<FpUntagged>(exportFn(argPtr));
+        }
+    }
+
+    public StructWithGenerics<ulong> ExportGenerics(StructWithGenerics<ulong> arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_generics") ??
+                throw MissingExport("__fp_gen_export_generics");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<StructWithGenerics<ulong>>(exportFn(argPtr));
+        }
+    }
+
+    public Result<byte[], string> ExportGetBytes()
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long>("__fp_gen_export_get_bytes") ??
+                throw MissingExport("__fp_gen_export_get_bytes");
+            return ParseObject<Result<byte[], string>>(exportFn());
+        }
+    }
+
+    public Result<byte[], string> ExportGetSerdeBytes()
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long>("__fp_gen_export_get_serde_bytes") ??
+                throw MissingExport("__fp_gen_export_get_serde_bytes");
+            return ParseObject<Result<byte[], string>>(exportFn());
+        }
+    }
+
+    public string ExportLazyString(string arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_lazy_string") ??
+                throw MissingExport("__fp_gen_export_lazy_string");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<string>(exportFn(argPtr));
+        }
+    }
+
+    public List<Metric> ExportMetrics(List<Metric> metrics)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_metrics") ??
+                throw MissingExport("__fp_gen_export_metrics");
+            var metricsPtr = SerializeObject(metrics);
+            return ParseObject<List<Metric>>(exportFn(metricsPtr));
+        }
+    }
+
+    public long ExportMultiplePrimitives(sbyte arg1, string arg2)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, long, long>("__fp_gen_export_multiple_primitives") ??
+                throw MissingExport("__fp_gen_export_multiple_primitives");
+            var arg2Ptr = SerializeObject(arg2);
+            return exportFn(arg1, arg2Ptr);
+        }
+    }
+
+    public (string, List<uint>, bool) ExportMultipleValues(uint arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, long>("__fp_gen_export_multiple_values") ??
+                throw MissingExport("__fp_gen_export_multiple_values");
+            return ParseObject<(string, List<uint>, bool)>(exportFn((int)arg));
+        }
+    }
+
+    public bool ExportPrimitiveBool(bool arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, int>("__fp_gen_export_primitive_bool") ??
+                throw MissingExport("__fp_gen_export_primitive_bool");
+            return exportFn(arg ? 1 : 0) != 0;
+        }
+    }
+
+    public float ExportPrimitiveF32(float arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<float, float>("__fp_gen_export_primitive_f32") ??
+                throw MissingExport("__fp_gen_export_primitive_f32");
+            return exportFn(arg);
+        }
+    }
+
+    public double ExportPrimitiveF64(double arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<double, double>("__fp_gen_export_primitive_f64") ??
+                throw MissingExport("__fp_gen_export_primitive_f64");
+            return exportFn(arg);
+        }
+    }
+
+    public short ExportPrimitiveI16(short arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, int>("__fp_gen_export_primitive_i16") ??
+                throw MissingExport("__fp_gen_export_primitive_i16");
+            return (short)exportFn(arg);
+        }
+    }
+
+    public int ExportPrimitiveI32(int arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, int>("__fp_gen_export_primitive_i32") ??
+                throw MissingExport("__fp_gen_export_primitive_i32");
+            return exportFn(arg);
+        }
+    }
+
+    public long ExportPrimitiveI64(long arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_primitive_i64") ??
+                throw MissingExport("__fp_gen_export_primitive_i64");
+            return exportFn(arg);
+        }
+    }
+
+    public sbyte ExportPrimitiveI8(sbyte arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, int>("__fp_gen_export_primitive_i8") ??
+                throw MissingExport("__fp_gen_export_primitive_i8");
+            return (sbyte)exportFn(arg);
+        }
+    }
+
+    public (uint, int) ExportPrimitivePair(uint arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, long>("__fp_gen_export_primitive_pair") ??
+                throw MissingExport("__fp_gen_export_primitive_pair");
+            var packed = (ulong)exportFn((int)arg);
+            return ((uint)(packed >> 32), (int)(uint)packed);
+        }
+    }
+
+    public ushort ExportPrimitiveU16(ushort arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, int>("__fp_gen_export_primitive_u16") ??
+                throw MissingExport("__fp_gen_export_primitive_u16");
+            return (ushort)exportFn(arg);
+        }
+    }
+
+    public uint ExportPrimitiveU32(uint arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, int>("__fp_gen_export_primitive_u32") ??
+                throw MissingExport("__fp_gen_export_primitive_u32");
+            return (uint)exportFn((int)arg);
+        }
+    }
+
+    public ulong ExportPrimitiveU64(ulong arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_primitive_u64") ??
+                throw MissingExport("__fp_gen_export_primitive_u64");
+            return (ulong)exportFn((long)arg);
+        }
+    }
+
+    public byte ExportPrimitiveU8(byte arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<int, int>("__fp_gen_export_primitive_u8") ??
+                throw MissingExport("__fp_gen_export_primitive_u8");
+            return (byte)exportFn(arg);
+        }
+    }
+
+    public string ExportRenamedString(string arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_legacy_string") ??
+                throw MissingExport("__fp_gen_export_legacy_string");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<string>(exportFn(argPtr));
+        }
+    }
+
+    public SampleColumns ExportSamples(SampleColumns samples)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_samples") ??
+                throw MissingExport("__fp_gen_export_samples");
+            var samplesPtr = SerializeObject(samples);
+            return ParseObject<SampleColumns>(exportFn(samplesPtr));
+        }
+    }
+
+    public SerdeAdjacentlyTagged ExportSerdeAdjacentlyTagged(SerdeAdjacentlyTagged arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_serde_adjacently_tagged") ??
+                throw MissingExport("__fp_gen_export_serde_adjacently_tagged");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<SerdeAdjacentlyTagged>(exportFn(argPtr));
+        }
+    }
+
+    public SerdeVariantRenaming ExportSerdeEnum(SerdeVariantRenaming arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_serde_enum") ??
+                throw MissingExport("__fp_gen_export_serde_enum");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<SerdeVariantRenaming>(exportFn(argPtr));
+        }
+    }
+
+    public SerdeFlatten ExportSerdeFlatten(SerdeFlatten arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_serde_flatten") ??
+                throw MissingExport("__fp_gen_export_serde_flatten");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<SerdeFlatten>(exportFn(argPtr));
+        }
+    }
+
+    public SerdeInternallyTagged ExportSerdeInternallyTagged(SerdeInternallyTagged arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_serde_internally_tagged") ??
+                throw MissingExport("__fp_gen_export_serde_internally_tagged");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<SerdeInternallyTagged>(exportFn(argPtr));
+        }
+    }
+
+    public SerdePropertyRenaming ExportSerdeStruct(SerdePropertyRenaming arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_serde_struct") ??
+                throw MissingExport("__fp_gen_export_serde_struct");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<SerdePropertyRenaming>(exportFn(argPtr));
+        }
+    }
+
+    public SerdeUntagged ExportSerdeUntagged(SerdeUntagged arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_serde_untagged") ??
+                throw MissingExport("__fp_gen_export_serde_untagged");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<SerdeUntagged>(exportFn(argPtr));
+        }
+    }
+
+    public string ExportString(string arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_string") ??
+                throw MissingExport("__fp_gen_export_string");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<string>(exportFn(argPtr));
+        }
+    }
+
+    public string ExportStringAfterMemoryGrowth(string arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_string_after_memory_growth") ??
+                throw MissingExport("__fp_gen_export_string_after_memory_growth");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<string>(exportFn(argPtr));
+        }
+    }
+
+    public StructWithOptions ExportStructWithOptions(StructWithOptions arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_struct_with_options") ??
+                throw MissingExport("__fp_gen_export_struct_with_options");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<StructWithOptions>(exportFn(argPtr));
+        }
+    }
+
+    public string ExportTimestamp(string arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_timestamp") ??
+                throw MissingExport("__fp_gen_export_timestamp");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<string>(exportFn(argPtr));
+        }
+    }
+
+    public Timestamps ExportTimestamps(Timestamps arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_timestamps") ??
+                throw MissingExport("__fp_gen_export_timestamps");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<Timestamps>(exportFn(argPtr));
+        }
+    }
+
+    public TypedId<Point<double>> ExportTypedId(TypedId<Point<double>> id)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_typed_id") ??
+                throw MissingExport("__fp_gen_export_typed_id");
+            var idPtr = SerializeObject(id);
+            return ParseObject<TypedId<Point<double>>>(exportFn(idPtr));
+        }
+    }
+
+    public ValidatedStruct ExportValidatedStruct(ValidatedStruct arg)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_export_validated_struct") ??
+                throw MissingExport("__fp_gen_export_validated_struct");
+            var argPtr = SerializeObject(arg);
+            return ParseObject<ValidatedStruct>(exportFn(argPtr));
+        }
+    }
+
+    public void ExportVoidFunction()
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetAction("__fp_gen_export_void_function") ??
+                throw MissingExport("__fp_gen_export_void_function");
+            exportFn();
+        }
+    }
+
+    /// <summary>
+    /// Example how plugin could expose async data-fetching capabilities.
+    /// </summary>
+    public async Task<Result<string, string>> FetchData(string type)
+    {
+        Task<long> task;
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_fetch_data") ??
+                throw MissingExport("__fp_gen_fetch_data");
+            var typePtr = SerializeObject(type);
+            task = TaskFromPtr(exportFn(typePtr));
+        }
+
+        var resultPtr = await task;
+        lock (_lock)
+        {
+            return ParseObject<Result<string, string>>(resultPtr);
+        }
+    }
+
+    /// <summary>
+    /// Called on the plugin to give it a chance to initialize.
+    /// </summary>
+    public void Init(PluginConfig config)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetAction<long>("__fp_gen_init") ??
+                throw MissingExport("__fp_gen_init");
+            var configPtr = SerializeObject(config);
+            exportFn(configPtr);
+        }
+    }
+
+    /// <summary>
+    /// Example how plugin could expose a reducer.
+    /// </summary>
+    public StateUpdate ReducerBridge(ReduxAction action)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long, long>("__fp_gen_reducer_bridge") ??
+                throw MissingExport("__fp_gen_reducer_bridge");
+            var actionPtr = SerializeObject(action);
+            return ParseObject<StateUpdate>(exportFn(actionPtr));
+        }
+    }
+
+    /// <summary>
+    /// Called on the plugin when the host changes its config.
+    /// </summary>
+    public void UpdateConfig(PluginConfig config)
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetAction<long>("__fp_gen_update_config") ??
+                throw MissingExport("__fp_gen_update_config");
+            var configPtr = SerializeObject(config);
+            exportFn(configPtr);
+        }
+    }
+
+    /// <summary>
+    /// Registers a handler for <see cref="IImports.InvokeCommand"/>, which is
+    /// called instead of the import when the plugin passes the given name.
+    /// </summary>
+    public void RegisterInvokeCommand(string name, Func<string, Result<string, string>> handler)
+    {
+        lock (_lock)
+        {
+            _invokeCommandHandlers[name] = handler;
+        }
+    }
+
+    /// <summary>
+    /// Unregisters the handler for <see cref="IImports.InvokeCommand"/> with the
+    /// given name. Returns whether a handler was registered.
+    /// </summary>
+    public bool UnregisterInvokeCommand(string name)
+    {
+        lock (_lock)
+        {
+            return _invokeCommandHandlers.Remove(name);
+        }
+    }
+
+    /// <summary>
+    /// Example of an event. The plugin can emit it at any time, after which
+    /// the runtime passes it on to its subscribers.
+    /// </summary>
+    public event Action<Progress>? ReportProgress;
+
+    /// <summary>
+    /// Returns statistics about the memory that is allocated by the plugin.
+    /// </summary>
+    public MemoryStats GetMemoryStats()
+    {
+        lock (_lock)
+        {
+            var exportFn = _instance.GetFunction<long>("__fp_memory_stats") ??
+                throw MissingExport("__fp_memory_stats");
+            return ParseObject<MemoryStats>(exportFn());
+        }
+    }
+
+    /// <summary>
+    /// Calls the <c>shutdown</c> export of the plugin, if it has one, and
+    /// releases the resources of the runtime.
+    /// </summary>
+    public void Dispose()
+    {
+        lock (_lock)
+        {
+            try
+            {
+                _instance.GetAction("__fp_gen_shutdown")?.Invoke();
+            }
+            finally
+            {
+                _store.Dispose();
+                _engine.Dispose();
+            }
+        }
+    }
+
+    private void DefineImports(Linker linker)
+    {
+        linker.DefineFunction("fp", "__fp_gen_import_arbitrary_precision_numbers", (long argPtr) =>
+        {
+            var arg = ParseObject<ArbitraryPrecisionNumbers>(argPtr);
+            return SerializeObject(_imports.ImportArbitraryPrecisionNumbers(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_array_f32", (long argPtr) =>
+        {
+            var arg = ParseObject<float[]>(argPtr);
+            return SerializeObject(_imports.ImportArrayF32(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_array_f64", (long argPtr) =>
+        {
+            var arg = ParseObject<double[]>(argPtr);
+            return SerializeObject(_imports.ImportArrayF64(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_array_i16", (long argPtr) =>
+        {
+            var arg = ParseObject<short[]>(argPtr);
+            return SerializeObject(_imports.ImportArrayI16(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_array_i32", (long argPtr) =>
+        {
+            var arg = ParseObject<int[]>(argPtr);
+            return SerializeObject(_imports.ImportArrayI32(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_array_i8", (long argPtr) =>
+        {
+            var arg = ParseObject<sbyte[]>(argPtr);
+            return SerializeObject(_imports.ImportArrayI8(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_array_u16", (long argPtr) =>
+        {
+            var arg = ParseObject<ushort[]>(argPtr);
+            return SerializeObject(_imports.ImportArrayU16(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_array_u32", (long argPtr) =>
+        {
+            var arg = ParseObject<uint[]>(argPtr);
+            return SerializeObject(_imports.ImportArrayU32(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_array_u8", (long argPtr) =>
+        {
+            var arg = ParseObject<List<byte>>(argPtr);
+            return SerializeObject(_imports.ImportArrayU8(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_async_void_function", (long messagePtr) =>
+        {
+            var message = ParseObject<string>(messagePtr);
+            ForgetTask("import_async_void_function", _imports.ImportAsyncVoidFunction(message));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_collections", (long argPtr) =>
+        {
+            var arg = ParseObject<Collections>(argPtr);
+            return SerializeObject(_imports.ImportCollections(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_experimental", (long argPtr) =>
+        {
+            var arg = ParseObject<string>(argPtr);
+            return SerializeObject(_imports.ImportExperimental(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_explicit_bound_point", (long argPtr) =>
+        {
+            var arg = ParseObject<ExplicitBoundPoint<ulong>>(argPtr);
+            _imports.ImportExplicitBoundPoint(arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_fp_adjacently_tagged", (long argPtr) =>
+        {
+            var arg = ParseObject<FpAdjacentlyTagged>(argPtr);
+            return SerializeObject(_imports.ImportFpAdjacentlyTagged(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_fp_enum", (long argPtr) =>
+        {
+            var arg = ParseObject<FpVariantRenaming>(argPtr);
+            return SerializeObject(_imports.ImportFpEnum(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_fp_flatten", (long argPtr) =>
+        {
+            var arg = ParseObject<FpFlatten>(argPtr);
+            return SerializeObject(_imports.ImportFpFlatten(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_fp_internally_tagged", (long argPtr) =>
+        {
+            var arg = ParseObject<FpInternallyTagged>(argPtr);
+            return SerializeObject(_imports.ImportFpInternallyTagged(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_fp_struct", (long argPtr) =>
+        {
+            var arg = ParseObject<FpPropertyRenaming>(argPtr);
+            return SerializeObject(_imports.ImportFpStruct(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_fp_untagged", (long argPtr) =>
+        {
+            var arg = ParseObject<FpUntagged>(argPtr);
+            return SerializeObject(_imports.ImportFpUntagged(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_generics", (long argPtr) =>
+        {
+            var arg = ParseObject<StructWithGenerics<ulong>>(argPtr);
+            return SerializeObject(_imports.ImportGenerics(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_get_bytes", () =>
+        {
+            return SerializeObject(_imports.ImportGetBytes());
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_get_serde_bytes", () =>
+        {
+            return SerializeObject(_imports.ImportGetSerdeBytes());
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_get_value", (long keyPtr) =>
+        {
+            var key = ParseObject<string>(keyPtr);
+            var asyncValuePtr = CreateAsyncValue();
+            var task = _imports.ImportGetValue(key);
+            ResolveFuture("import_get_value", asyncValuePtr, task, () => SerializeObject(task.Result));
+            return asyncValuePtr;
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_integer_types", (long argPtr) =>
+        {
+            var arg = ParseObject<IntegerTypes>(argPtr);
+            return SerializeObject(_imports.ImportIntegerTypes(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_multiple_primitives", (int arg1, long arg2Ptr) =>
+        {
+            var arg2 = ParseObject<string>(arg2Ptr);
+            return _imports.ImportMultiplePrimitives((sbyte)arg1, arg2);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_multiple_values", (int arg) =>
+        {
+            return SerializeObject(_imports.ImportMultipleValues((uint)arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_bool", (int arg) =>
+        {
+            return _imports.ImportPrimitiveBool(arg != 0) ? 1 : 0;
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_f32", (float arg) =>
+        {
+            return _imports.ImportPrimitiveF32(arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_f64", (double arg) =>
+        {
+            return _imports.ImportPrimitiveF64(arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_i16", (int arg) =>
+        {
+            return _imports.ImportPrimitiveI16((short)arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_i32", (int arg) =>
+        {
+            return _imports.ImportPrimitiveI32(arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_i64", (long arg) =>
+        {
+            return _imports.ImportPrimitiveI64(arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_i8", (int arg) =>
+        {
+            return _imports.ImportPrimitiveI8((sbyte)arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_pair", (int arg) =>
+        {
+            var result = _imports.ImportPrimitivePair((uint)arg);
+            return (long)(((ulong)result.Item1 << 32) | (uint)result.Item2);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_u16", (int arg) =>
+        {
+            return _imports.ImportPrimitiveU16((ushort)arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_u32", (int arg) =>
+        {
+            return (int)_imports.ImportPrimitiveU32((uint)arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_u64", (long arg) =>
+        {
+            return (long)_imports.ImportPrimitiveU64((ulong)arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_primitive_u8", (int arg) =>
+        {
+            return _imports.ImportPrimitiveU8((byte)arg);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_rate_limited", (long labelPtr) =>
+        {
+            var label = ParseObject<string>(labelPtr);
+            return SerializeObject(_imports.ImportRateLimited(label));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_legacy_string", (long argPtr) =>
+        {
+            var arg = ParseObject<string>(argPtr);
+            return SerializeObject(_imports.ImportRenamedString(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_serde_adjacently_tagged", (long argPtr) =>
+        {
+            var arg = ParseObject<SerdeAdjacentlyTagged>(argPtr);
+            return SerializeObject(_imports.ImportSerdeAdjacentlyTagged(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_serde_enum", (long argPtr) =>
+        {
+            var arg = ParseObject<SerdeVariantRenaming>(argPtr);
+            return SerializeObject(_imports.ImportSerdeEnum(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_serde_flatten", (long argPtr) =>
+        {
+            var arg = ParseObject<SerdeFlatten>(argPtr);
+            return SerializeObject(_imports.ImportSerdeFlatten(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_serde_internally_tagged", (long argPtr) =>
+        {
+            var arg = ParseObject<SerdeInternallyTagged>(argPtr);
+            return SerializeObject(_imports.ImportSerdeInternallyTagged(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_serde_struct", (long argPtr) =>
+        {
+            var arg = ParseObject<SerdePropertyRenaming>(argPtr);
+            return SerializeObject(_imports.ImportSerdeStruct(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_serde_untagged", (long argPtr) =>
+        {
+            var arg = ParseObject<SerdeUntagged>(argPtr);
+            return SerializeObject(_imports.ImportSerdeUntagged(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_set_value", (long keyPtr, long valuePtr) =>
+        {
+            var key = ParseObject<string>(keyPtr);
+            var value = ParseObject<object>(valuePtr);
+            _imports.ImportSetValue(key, value);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_string", (long argPtr) =>
+        {
+            var arg = ParseObject<string>(argPtr);
+            return SerializeObject(_imports.ImportString(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_struct_with_options", (long argPtr) =>
+        {
+            var arg = ParseObject<StructWithOptions>(argPtr);
+            return SerializeObject(_imports.ImportStructWithOptions(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_timestamp", (long argPtr) =>
+        {
+            var arg = ParseObject<string>(argPtr);
+            return SerializeObject(_imports.ImportTimestamp(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_validated_struct", (long argPtr) =>
+        {
+            var arg = ParseObject<ValidatedStruct>(argPtr);
+            return SerializeObject(_imports.ImportValidatedStruct(arg));
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_void_function", () =>
+        {
+            _imports.ImportVoidFunction();
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_void_function_empty_result", () =>
+        {
+            return SerializeObject(_imports.ImportVoidFunctionEmptyResult());
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_import_void_function_empty_return", () =>
+        {
+            _imports.ImportVoidFunctionEmptyReturn();
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_invoke_command", (long namePtr, long payloadPtr) =>
+        {
+            var name = ParseObject<string>(namePtr);
+            var payload = ParseObject<string>(payloadPtr);
+            var result = _invokeCommandHandlers.TryGetValue(name, out var handler)
+                ? handler(payload)
+                : _imports.InvokeCommand(name, payload);
+            return SerializeObject(result);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_log", (long messagePtr) =>
+        {
+            var message = ParseObject<string>(messagePtr);
+            _imports.Log(message);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_make_http_request", (long requestPtr) =>
+        {
+            CheckCapability("make_http_request", Capability.Network);
+            var request = ParseObject<Request>(requestPtr);
+            var asyncValuePtr = CreateAsyncValue();
+            var task = _imports.MakeHttpRequest(request);
+            ResolveFuture("make_http_request", asyncValuePtr, task, () => SerializeObject(task.Result));
+            return asyncValuePtr;
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_record_metric", (long namePtr, double value) =>
+        {
+            var name = ParseObject<string>(namePtr);
+            _imports.RecordMetric(name, value);
+        });
+
+        linker.DefineFunction("fp", "__fp_gen_report_progress", (long progressPtr) =>
+        {
+            var progress = ParseObject<Progress>(progressPtr);
+            ReportProgress?.Invoke(progress);
+        });
+
+        linker.DefineFunction("fp", "__fp_host_batch", (long callsPtr) =>
+        {
+            var reader = new MessagePackReader(ImportFromMemory(callsPtr));
+            var count = reader.ReadArrayHeader();
+            for (var i = 0; i < count; i++)
+            {
+                reader.ReadMapHeader();
+                var name = reader.ReadString();
+                var argCount = reader.ReadArrayHeader();
+                switch (name)
+                {
+                    case "record_metric":
+                        _imports.RecordMetric(
+                            MessagePackSerializer.Deserialize<string>(ref reader, SerializerOptions),
+                            MessagePackSerializer.Deserialize<double>(ref reader, SerializerOptions));
+                        break;
+                    default:
+                        // Calls to unknown functions are skipped.
+                        for (var j = 0; j < argCount; j++)
+                        {
+                            reader.Skip();
+                        }
+                        break;
+                }
+            }
+        });
+
+        linker.DefineFunction("fp", "__fp_error", (long reportPtr) =>
+        {
+            var report = ParseObject<GuestErrorReport>(reportPtr);
+            throw new GuestErrorException(report.Kind, report.Message);
+        });
+
+        linker.DefineFunction("fp", "__fp_host_resolve_async_value", (long asyncValuePtr, long resultPtr) =>
+        {
+            ResolvePromise(asyncValuePtr, resultPtr);
+        });
+    }
+
+    private static FpRuntimeException MissingExport(string name) =>
+        new($"Plugin did not export expected symbol: \"{name}\"");
+
+    private void CheckCapability(string functionName, Capability capability)
+    {
+        if (!_capabilities.Contains(capability))
+        {
+            throw new PermissionDeniedException(functionName, capability);
+        }
+    }
+
+    private long CreateAsyncValue()
+    {
+        const int len = 12; // std::mem::size_of::<AsyncValue>()
+        var fatPtr = Malloc(len);
+        var (ptr, _) = FromFatPtr(fatPtr);
+        _memory.GetSpan(ptr, len).Clear();
+        return fatPtr;
+    }
+
+    private long Malloc(int len)
+    {
+        var fatPtr = _malloc(len);
+        if (fatPtr == 0 && len != 0)
+        {
+            throw new AllocationFailedException(len);
+        }
+        return fatPtr;
+    }
+
+    private long ExportToMemory(byte[] bytes)
+    {
+        var fatPtr = Malloc(bytes.Length);
+        var (ptr, len) = FromFatPtr(fatPtr);
+        bytes.CopyTo(_memory.GetSpan(ptr, len));
+        return fatPtr;
+    }
+
+    private byte[] ImportFromMemory(long fatPtr)
+    {
+        var (ptr, len) = FromFatPtr(fatPtr);
+        var bytes = _memory.GetSpan(ptr, len).ToArray();
+        _free(fatPtr);
+        return bytes;
+    }
+
+    private T ParseObject<T>(long fatPtr) =>
+        MessagePackSerializer.Deserialize<T>(ImportFromMemory(fatPtr), SerializerOptions);
+
+    private long SerializeObject<T>(T value) =>
+        ExportToMemory(MessagePackSerializer.Serialize(value, SerializerOptions));
+
+    // Plugins may resolve their async values before the runtime asks for
+    // them, so whichever comes first creates the promise and the other one
+    // removes it.
+    private TaskCompletionSource<long> GetPromise(long asyncValuePtr)
+    {
+        if (_promises.Remove(asyncValuePtr, out var promise))
+        {
+            return promise;
+        }
+
+        promise = new TaskCompletionSource<long>(TaskCreationOptions.RunContinuationsAsynchronously);
+        _promises.Add(asyncValuePtr, promise);
+        return promise;
+    }
+
+    private Task<long> TaskFromPtr(long asyncValuePtr) => GetPromise(asyncValuePtr).Task;
+
+    private void ResolvePromise(long asyncValuePtr, long resultPtr) =>
+        GetPromise(asyncValuePtr).SetResult(resultPtr);
+
+    private void ResolveFuture(string functionName, long asyncValuePtr, Task task, Func<long> serializeResult)
+    {
+        task.ContinueWith(
+            completed =>
+            {
+                if (!completed.IsCompletedSuccessfully)
+                {
+                    Console.Error.WriteLine(
+                        $"Unrecoverable exception trying to call async host function \"{functionName}\": {completed.Exception}");
+                    return;
+                }
+
+                lock (_lock)
+                {
+                    _resolveFuture(asyncValuePtr, serializeResult());
+                }
+            },
+            TaskScheduler.Default);
+    }
+
+    private static void ForgetTask(string functionName, Task task)
+    {
+        task.ContinueWith(
+            completed => Console.Error.WriteLine(
+                $"Unrecoverable exception trying to call async host function \"{functionName}\": {completed.Exception}"),
+            TaskContinuationOptions.NotOnRanToCompletion);
+    }
+
+    private static (long Ptr, int Len) FromFatPtr(long fatPtr) =>
+        ((long)((ulong)fatPtr >> 32), (int)(uint)fatPtr);
+}
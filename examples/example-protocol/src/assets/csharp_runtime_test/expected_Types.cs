@@ -0,0 +1,2120 @@
+// ============================================= //
+// Types for WebAssembly runtime                 //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+using System;
+using System.Collections.Generic;
+using MessagePack;
+using MessagePack.Formatters;
+
+namespace ExampleBindings;
+
+/// <summary>
+/// Example of numbers that cannot be represented as `f64` without losing
+/// precision.
+///
+/// They are exchanged as strings, and typed as branded strings in TypeScript.
+/// Requires the `rust-decimal-compat` and `num-bigint-compat` features.
+/// </summary>
+[MessagePackObject]
+public sealed record ArbitraryPrecisionNumbers
+{
+    [Key("amount")]
+    public required string Amount { get; init; }
+
+    [Key("total_supply")]
+    public required string TotalSupply { get; init; }
+}
+
+/// <summary>
+/// The response to a batch of operations, some of which may have failed.
+/// </summary>
+[MessagePackObject]
+public sealed record BatchResponse
+{
+    [Key("results")]
+    public required List<Result<ulong, string>> Results { get; init; }
+
+    [Key("summary")]
+    public required Result<string, string> Summary { get; init; }
+
+    [Key("retryAfter")]
+    public Result<uint, string>? RetryAfter { get; init; }
+}
+
+/// <summary>
+/// Std collections that are encoded as lists.
+///
+/// Sets are generated as `Set` types in TypeScript if `generate_set_types` is
+/// enabled, while other lists are always generated as arrays.
+/// </summary>
+[MessagePackObject]
+public sealed record Collections
+{
+    [Key("tags")]
+    public required List<string> Tags { get; init; }
+
+    [Key("optionalTags")]
+    public List<string>? OptionalTags { get; init; }
+
+    [Key("queue")]
+    public required List<uint> Queue { get; init; }
+}
+
+/// <summary>
+/// Fields and variants behind a `#[cfg(...)]` attribute are only included in
+/// the bindings if the protocol is compiled with the condition enabled.
+///
+/// Using `#[fp(generators = "...")]`, fields and variants can also be limited
+/// to the bindings of specific generators.
+/// </summary>
+[MessagePackObject]
+public sealed record ConditionalFields
+{
+    [Key("name")]
+    public required string Name { get; init; }
+}
+
+[MessagePackFormatter(typeof(ConditionalVariantsFormatter))]
+public enum ConditionalVariants
+{
+    Stable,
+}
+
+public sealed class ConditionalVariantsFormatter : IMessagePackFormatter<ConditionalVariants>
+{
+    public void Serialize(ref MessagePackWriter writer, ConditionalVariants value, MessagePackSerializerOptions options)
+    {
+        writer.Write(value switch
+        {
+            ConditionalVariants.Stable => "Stable",
+            _ => throw Serialization.UnknownVariant("ConditionalVariants", value.ToString()),
+        });
+    }
+
+    public ConditionalVariants Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var tag = reader.ReadString();
+        return tag switch
+        {
+            "Stable" => ConditionalVariants.Stable,
+            _ => throw Serialization.UnknownVariant("ConditionalVariants", tag),
+        };
+    }
+}
+
+[MessagePackObject]
+public sealed record CustomSerializers
+{
+    /// <summary>
+    /// Serialized as a Unix timestamp, instead of the RFC3339 string that is
+    /// used for `OffsetDateTime` by default.
+    /// </summary>
+    [Key("timestamp")]
+    public required double Timestamp { get; init; }
+}
+
+/// <summary>
+/// # This is an enum with doc comments.
+/// </summary>
+[MessagePackFormatter(typeof(DocExampleEnumFormatter))]
+public abstract record DocExampleEnum
+{
+    private DocExampleEnum()
+    {
+    }
+
+    /// <summary>
+    /// Multi-line doc comment with complex characters
+    /// &amp; " , \ ! '
+    /// </summary>
+    public sealed record Variant1(string Value) : DocExampleEnum;
+
+    /// <summary>
+    /// Raw identifiers are supported too.
+    /// </summary>
+    public sealed record Variant2 : DocExampleEnum
+    {
+        /// <summary>
+        /// Variant property.
+        /// </summary>
+        public required sbyte Inner { get; init; }
+    }
+}
+
+public sealed class DocExampleEnumFormatter : IMessagePackFormatter<DocExampleEnum>
+{
+    public void Serialize(ref MessagePackWriter writer, DocExampleEnum value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case DocExampleEnum.Variant1 variant:
+                writer.WriteMapHeader(1);
+                writer.Write("Variant1");
+                Serialization.Write(ref writer, variant.Value, options);
+                break;
+            case DocExampleEnum.Variant2 variant:
+                writer.WriteMapHeader(1);
+                writer.Write("Variant2");
+                writer.WriteMapHeader(1);
+                writer.Write("inner");
+                Serialization.Write(ref writer, variant.Inner, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("DocExampleEnum", value.ToString());
+        }
+    }
+
+    public DocExampleEnum Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        if (reader.ReadMapHeader() != 1)
+        {
+            throw new MessagePackSerializationException("Expected a map with a single entry for DocExampleEnum");
+        }
+
+        var variant = reader.ReadString();
+        return variant switch
+        {
+            "Variant1" => new DocExampleEnum.Variant1(Serialization.Read<string>(ref reader, options)),
+            "Variant2" => ReadVariant2(ref reader, options),
+            _ => throw Serialization.UnknownVariant("DocExampleEnum", variant),
+        };
+    }
+
+    private static DocExampleEnum.Variant2 ReadVariant2(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var inner = default(sbyte)!;
+        var hasInner = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "inner":
+                    inner = Serialization.Read<sbyte>(ref reader, options);
+                    hasInner = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasInner)
+        {
+            throw Serialization.MissingField("Variant2", "inner");
+        }
+
+        return new DocExampleEnum.Variant2
+        {
+            Inner = inner,
+        };
+    }
+}
+
+/// <summary>
+/// # This is a struct with doc comments.
+/// </summary>
+[MessagePackObject]
+public sealed record DocExampleStruct
+{
+    /// <summary>
+    /// Multi-line doc comment with complex characters
+    /// &amp; " , \ ! '
+    /// </summary>
+    [Key("multi_line")]
+    public required string MultiLine { get; init; }
+
+    /// <summary>
+    /// Raw identifiers are supported too.
+    /// </summary>
+    [Key("type")]
+    public required string Type { get; init; }
+}
+
+/// <summary>
+/// Example of values at the edges of what the runtimes can represent.
+///
+/// `char` is exchanged as a single-character string, 128-bit integers are
+/// exchanged as decimal strings, and `f32` keeps NaN and infinities intact.
+/// </summary>
+[MessagePackObject]
+public sealed record EdgeValues
+{
+    [Key("character")]
+    public required string Character { get; init; }
+
+    [Key("float")]
+    public required float Float { get; init; }
+
+    [Key("signed")]
+    public required string Signed { get; init; }
+
+    [Key("unsigned")]
+    public required string Unsigned { get; init; }
+}
+
+/// <summary>
+/// A point of an arbitrary type, with explicit trait bounds.
+/// </summary>
+[MessagePackObject]
+public sealed record ExplicitBoundPoint<T>
+{
+    [Key("value")]
+    public required T Value { get; init; }
+}
+
+/// <summary>
+/// This struct is also not referenced by any function or data structure, but
+/// it will show up because there is an explicit `use` statement for it in the
+/// `fp_import!` macro.
+/// </summary>
+[MessagePackObject]
+public sealed record ExplicitedlyImportedType
+{
+    [Key("you_will_see_this")]
+    public required bool YouWillSeeThis { get; init; }
+}
+
+/// <summary>
+/// Settings for an extension whose shape is only known to the extension
+/// itself.
+///
+/// `serde_json::Value` (and `rmpv::Value`) can be used anywhere in a protocol
+/// to pass values of arbitrary shape. Both are encoded as plain MessagePack,
+/// and show up as `unknown` in the TypeScript bindings.
+/// </summary>
+[MessagePackObject]
+public sealed record ExtensionSettings
+{
+    [Key("extension")]
+    public required string Extension { get; init; }
+
+    [Key("settings")]
+    public required object Settings { get; init; }
+}
+
+[MessagePackObject]
+public sealed record FlattenedStruct
+{
+    [Key("foo")]
+    public required string Foo { get; init; }
+
+    [Key("bar")]
+    public required long Bar { get; init; }
+}
+
+[MessagePackFormatter(typeof(FpAdjacentlyTaggedFormatter))]
+public abstract record FpAdjacentlyTagged
+{
+    private FpAdjacentlyTagged()
+    {
+    }
+
+    public sealed record Foo : FpAdjacentlyTagged;
+
+    public sealed record Bar(string Value) : FpAdjacentlyTagged;
+
+    public sealed record Baz : FpAdjacentlyTagged
+    {
+        public required sbyte A { get; init; }
+
+        public required ulong B { get; init; }
+    }
+
+    public sealed record Pair((sbyte, string) Value) : FpAdjacentlyTagged;
+
+    public sealed record Unknown : FpAdjacentlyTagged;
+}
+
+public sealed class FpAdjacentlyTaggedFormatter : IMessagePackFormatter<FpAdjacentlyTagged>
+{
+    public void Serialize(ref MessagePackWriter writer, FpAdjacentlyTagged value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case FpAdjacentlyTagged.Foo:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("Foo");
+                break;
+            case FpAdjacentlyTagged.Bar variant:
+                writer.WriteMapHeader(2);
+                writer.Write("type");
+                writer.Write("Bar");
+                writer.Write("payload");
+                Serialization.Write(ref writer, variant.Value, options);
+                break;
+            case FpAdjacentlyTagged.Baz variant:
+                writer.WriteMapHeader(2);
+                writer.Write("type");
+                writer.Write("Baz");
+                writer.Write("payload");
+                writer.WriteMapHeader(2);
+                writer.Write("a");
+                Serialization.Write(ref writer, variant.A, options);
+                writer.Write("b");
+                Serialization.Write(ref writer, variant.B, options);
+                break;
+            case FpAdjacentlyTagged.Pair variant:
+                writer.WriteMapHeader(2);
+                writer.Write("type");
+                writer.Write("Pair");
+                writer.Write("payload");
+                Serialization.Write(ref writer, variant.Value, options);
+                break;
+            case FpAdjacentlyTagged.Unknown:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("Unknown");
+                break;
+            default:
+                throw Serialization.UnknownVariant("FpAdjacentlyTagged", value.ToString());
+        }
+    }
+
+    public FpAdjacentlyTagged Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        var tag = Serialization.ReadTag(reader, "type", "payload", out var content);
+        reader.Skip();
+        return tag switch
+        {
+            "Foo" => new FpAdjacentlyTagged.Foo(),
+            "Bar" or "Qux" => new FpAdjacentlyTagged.Bar(Serialization.Read<string>(ref content, options)),
+            "Baz" => ReadBaz(ref content, options),
+            "Pair" => new FpAdjacentlyTagged.Pair(Serialization.Read<(sbyte, string)>(ref content, options)),
+            _ => new FpAdjacentlyTagged.Unknown(),
+        };
+    }
+
+    private static FpAdjacentlyTagged.Baz ReadBaz(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var a = default(sbyte)!;
+        var hasA = false;
+        var b = default(ulong)!;
+        var hasB = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "a":
+                    a = Serialization.Read<sbyte>(ref reader, options);
+                    hasA = true;
+                    break;
+                case "b":
+                    b = Serialization.Read<ulong>(ref reader, options);
+                    hasB = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasA)
+        {
+            throw Serialization.MissingField("Baz", "a");
+        }
+
+        if (!hasB)
+        {
+            throw Serialization.MissingField("Baz", "b");
+        }
+
+        return new FpAdjacentlyTagged.Baz
+        {
+            A = a,
+            B = b,
+        };
+    }
+}
+
+[MessagePackObject]
+public sealed record FpFlatten
+{
+    [Key("foo")]
+    public required string Foo { get; init; }
+
+    [Key("bar")]
+    public required long Bar { get; init; }
+}
+
+[MessagePackFormatter(typeof(FpInternallyTaggedFormatter))]
+public abstract record FpInternallyTagged
+{
+    private FpInternallyTagged()
+    {
+    }
+
+    public sealed record Foo : FpInternallyTagged;
+
+    public sealed record Baz : FpInternallyTagged
+    {
+        public required sbyte A { get; init; }
+
+        public required ulong B { get; init; }
+    }
+}
+
+public sealed class FpInternallyTaggedFormatter : IMessagePackFormatter<FpInternallyTagged>
+{
+    public void Serialize(ref MessagePackWriter writer, FpInternallyTagged value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case FpInternallyTagged.Foo:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("Foo");
+                break;
+            case FpInternallyTagged.Baz variant:
+                writer.WriteMapHeader(3);
+                writer.Write("type");
+                writer.Write("Baz");
+                writer.Write("a");
+                Serialization.Write(ref writer, variant.A, options);
+                writer.Write("b");
+                Serialization.Write(ref writer, variant.B, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("FpInternallyTagged", value.ToString());
+        }
+    }
+
+    public FpInternallyTagged Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        var tag = Serialization.ReadTag(reader, "type");
+        return tag switch
+        {
+            "Foo" => Serialization.Skip(ref reader, new FpInternallyTagged.Foo()),
+            "Baz" => ReadBaz(ref reader, options),
+            _ => throw Serialization.UnknownVariant("FpInternallyTagged", tag),
+        };
+    }
+
+    private static FpInternallyTagged.Baz ReadBaz(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var a = default(sbyte)!;
+        var hasA = false;
+        var b = default(ulong)!;
+        var hasB = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "a":
+                    a = Serialization.Read<sbyte>(ref reader, options);
+                    hasA = true;
+                    break;
+                case "b":
+                    b = Serialization.Read<ulong>(ref reader, options);
+                    hasB = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasA)
+        {
+            throw Serialization.MissingField("Baz", "a");
+        }
+
+        if (!hasB)
+        {
+            throw Serialization.MissingField("Baz", "b");
+        }
+
+        return new FpInternallyTagged.Baz
+        {
+            A = a,
+            B = b,
+        };
+    }
+}
+
+[MessagePackObject]
+public sealed record FpPropertyRenaming
+{
+    [Key("fooBar")]
+    public required string FooBar { get; init; }
+
+    [Key("QUX_BAZ")]
+    public required double QuxBaz { get; init; }
+
+    [Key("rawStruct")]
+    public required int RawStruct { get; init; }
+}
+
+[MessagePackFormatter(typeof(FpUntaggedFormatter))]
+public abstract record FpUntagged
+{
+    private FpUntagged()
+    {
+    }
+
+    public sealed record Bar(string Value) : FpUntagged;
+
+    public sealed record Baz : FpUntagged
+    {
+        public required sbyte A { get; init; }
+
+        public required ulong B { get; init; }
+    }
+
+    public sealed record Pair((sbyte, string) Value) : FpUntagged;
+}
+
+public sealed class FpUntaggedFormatter : IMessagePackFormatter<FpUntagged>
+{
+    public void Serialize(ref MessagePackWriter writer, FpUntagged value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case FpUntagged.Bar variant:
+                Serialization.Write(ref writer, variant.Value, options);
+                break;
+            case FpUntagged.Baz variant:
+                writer.WriteMapHeader(2);
+                writer.Write("a");
+                Serialization.Write(ref writer, variant.A, options);
+                writer.Write("b");
+                Serialization.Write(ref writer, variant.B, options);
+                break;
+            case FpUntagged.Pair variant:
+                Serialization.Write(ref writer, variant.Value, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("FpUntagged", value.ToString());
+        }
+    }
+
+    public FpUntagged Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        if (Serialization.TryRead(ref reader, options, ReadBar, out var bar))
+        {
+            return bar;
+        }
+
+        if (Serialization.TryRead(ref reader, options, ReadBaz, out var baz))
+        {
+            return baz;
+        }
+
+        if (Serialization.TryRead(ref reader, options, ReadPair, out var pair))
+        {
+            return pair;
+        }
+
+        throw new MessagePackSerializationException("Data did not match any variant of FpUntagged");
+    }
+
+    private static FpUntagged.Bar ReadBar(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        return new FpUntagged.Bar(Serialization.Read<string>(ref reader, options));
+    }
+
+    private static FpUntagged.Baz ReadBaz(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var a = default(sbyte)!;
+        var hasA = false;
+        var b = default(ulong)!;
+        var hasB = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "a":
+                    a = Serialization.Read<sbyte>(ref reader, options);
+                    hasA = true;
+                    break;
+                case "b":
+                    b = Serialization.Read<ulong>(ref reader, options);
+                    hasB = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasA)
+        {
+            throw Serialization.MissingField("Baz", "a");
+        }
+
+        if (!hasB)
+        {
+            throw Serialization.MissingField("Baz", "b");
+        }
+
+        return new FpUntagged.Baz
+        {
+            A = a,
+            B = b,
+        };
+    }
+
+    private static FpUntagged.Pair ReadPair(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        return new FpUntagged.Pair(Serialization.Read<(sbyte, string)>(ref reader, options));
+    }
+}
+
+[MessagePackFormatter(typeof(FpVariantRenamingFormatter))]
+public abstract record FpVariantRenaming
+{
+    private FpVariantRenaming()
+    {
+    }
+
+    public sealed record FooBar : FpVariantRenaming;
+
+    public sealed record QuxBaz : FpVariantRenaming
+    {
+        /// <summary>
+        /// Will be renamed to "FOO_BAR" because of the `rename_all` on the
+        /// variant.
+        /// </summary>
+        public required string FooBarValue { get; init; }
+
+        public required double QuxBazValue { get; init; }
+    }
+}
+
+public sealed class FpVariantRenamingFormatter : IMessagePackFormatter<FpVariantRenaming>
+{
+    public void Serialize(ref MessagePackWriter writer, FpVariantRenaming value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case FpVariantRenaming.FooBar:
+                writer.Write("foo_bar");
+                break;
+            case FpVariantRenaming.QuxBaz variant:
+                writer.WriteMapHeader(1);
+                writer.Write("QUX_BAZ");
+                writer.WriteMapHeader(2);
+                writer.Write("FOO_BAR");
+                Serialization.Write(ref writer, variant.FooBarValue, options);
+                writer.Write("qux_baz");
+                Serialization.Write(ref writer, variant.QuxBazValue, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("FpVariantRenaming", value.ToString());
+        }
+    }
+
+    public FpVariantRenaming Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        if (reader.NextMessagePackType == MessagePackType.String)
+        {
+            var tag = reader.ReadString();
+            return tag switch
+            {
+                "foo_bar" => new FpVariantRenaming.FooBar(),
+                _ => throw Serialization.UnknownVariant("FpVariantRenaming", tag),
+            };
+        }
+
+        if (reader.ReadMapHeader() != 1)
+        {
+            throw new MessagePackSerializationException("Expected a map with a single entry for FpVariantRenaming");
+        }
+
+        var variant = reader.ReadString();
+        return variant switch
+        {
+            "QUX_BAZ" => ReadQuxBaz(ref reader, options),
+            _ => throw Serialization.UnknownVariant("FpVariantRenaming", variant),
+        };
+    }
+
+    private static FpVariantRenaming.QuxBaz ReadQuxBaz(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var fooBarValue = default(string)!;
+        var hasFooBarValue = false;
+        var quxBazValue = default(double)!;
+        var hasQuxBazValue = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "FOO_BAR":
+                    fooBarValue = Serialization.Read<string>(ref reader, options);
+                    hasFooBarValue = true;
+                    break;
+                case "qux_baz":
+                    quxBazValue = Serialization.Read<double>(ref reader, options);
+                    hasQuxBazValue = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasFooBarValue)
+        {
+            throw Serialization.MissingField("QuxBaz", "FOO_BAR");
+        }
+
+        if (!hasQuxBazValue)
+        {
+            throw Serialization.MissingField("QuxBaz", "qux_baz");
+        }
+
+        return new FpVariantRenaming.QuxBaz
+        {
+            FooBarValue = fooBarValue,
+            QuxBazValue = quxBazValue,
+        };
+    }
+}
+
+[MessagePackObject]
+public sealed record GroupImportedType1
+{
+    [Key("you_will_see_this")]
+    public required bool YouWillSeeThis { get; init; }
+}
+
+[MessagePackObject]
+public sealed record GroupImportedType2
+{
+    [Key("you_will_see_this")]
+    public required bool YouWillSeeThis { get; init; }
+}
+
+/// <summary>
+/// Example of integer types that are not primitives in the protocol.
+///
+/// `usize` and `isize` are exchanged as `u32` and `i32`, because that is their
+/// size on `wasm32`. `NonZero` types refuse to deserialize when they are zero.
+/// </summary>
+[MessagePackObject]
+public sealed record IntegerTypes
+{
+    [Key("count")]
+    public required uint Count { get; init; }
+
+    [Key("offset")]
+    public required int Offset { get; init; }
+
+    [Key("id")]
+    public required uint Id { get; init; }
+
+    [Key("delta")]
+    public required long Delta { get; init; }
+
+    [Key("capacity")]
+    public uint? Capacity { get; init; }
+
+    [Key("user_id")]
+    public required uint UserId { get; init; }
+}
+
+/// <summary>
+/// A measurement that is reported by the plugin.
+///
+/// Because the `name` field is annotated with `#[fp(interned)]`, the plugin
+/// registers every distinct name with the runtime once, and sends a `u32`
+/// handle in its place from then on. This keeps the payloads small when the
+/// same few names are reported over and over.
+/// </summary>
+[MessagePackObject]
+public sealed record Metric
+{
+    [Key("name")]
+    public required string Name { get; init; }
+
+    [Key("value")]
+    public required double Value { get; init; }
+}
+
+/// <summary>
+/// Config that is passed to the `init` export when the plugin is instantiated.
+///
+/// Every protocol has `init` and `shutdown` exports, but protocols can declare
+/// `init` themselves to have it take a config argument like this one.
+/// </summary>
+[MessagePackObject]
+public sealed record PluginConfig
+{
+    [Key("logLevel")]
+    public required string LogLevel { get; init; }
+}
+
+/// <summary>
+/// A point of an arbitrary type.
+/// </summary>
+[MessagePackObject]
+public sealed record Point<T>
+{
+    [Key("value")]
+    public required T Value { get; init; }
+}
+
+[MessagePackFormatter(typeof(PriorityFormatter))]
+public enum Priority
+{
+    /// <summary>
+    /// Handled after everything else.
+    /// </summary>
+    Low,
+    Normal,
+    High,
+}
+
+public sealed class PriorityFormatter : IMessagePackFormatter<Priority>
+{
+    public void Serialize(ref MessagePackWriter writer, Priority value, MessagePackSerializerOptions options)
+    {
+        writer.Write(value switch
+        {
+            Priority.Low => "low",
+            Priority.Normal => "normal",
+            Priority.High => "urgent",
+            _ => throw Serialization.UnknownVariant("Priority", value.ToString()),
+        });
+    }
+
+    public Priority Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var tag = reader.ReadString();
+        return tag switch
+        {
+            "low" => Priority.Low,
+            "normal" => Priority.Normal,
+            "urgent" => Priority.High,
+            _ => throw Serialization.UnknownVariant("Priority", tag),
+        };
+    }
+}
+
+/// <summary>
+/// Emitted by the plugin while it works on a long-running task.
+///
+/// See the `report_progress` event in `main.rs` for more info.
+/// </summary>
+[MessagePackObject]
+public sealed record Progress
+{
+    [Key("task")]
+    public required string Task { get; init; }
+
+    [Key("completedSteps")]
+    public required uint CompletedSteps { get; init; }
+
+    [Key("totalSteps")]
+    public required uint TotalSteps { get; init; }
+}
+
+/// <summary>
+/// Error that is returned to the plugin when it calls a rate-limited import
+/// more often than its limit allows.
+/// </summary>
+[MessagePackObject]
+public sealed record RateLimited
+{
+    /// <summary>
+    /// The name of the import that was called.
+    /// </summary>
+    [Key("function")]
+    public required string Function { get; init; }
+
+    /// <summary>
+    /// The number of milliseconds after which the import may be called again.
+    /// </summary>
+    [Key("retryAfterMs")]
+    public required uint RetryAfterMs { get; init; }
+}
+
+/// <summary>
+/// Example for representing Redux actions.
+/// </summary>
+[MessagePackFormatter(typeof(ReduxActionFormatter))]
+public abstract record ReduxAction
+{
+    private ReduxAction()
+    {
+    }
+
+    public sealed record ClearTitle : ReduxAction;
+
+    public sealed record UpdateTitle : ReduxAction
+    {
+        public required string Title { get; init; }
+    }
+}
+
+public sealed class ReduxActionFormatter : IMessagePackFormatter<ReduxAction>
+{
+    public void Serialize(ref MessagePackWriter writer, ReduxAction value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case ReduxAction.ClearTitle:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("clear_title");
+                break;
+            case ReduxAction.UpdateTitle variant:
+                writer.WriteMapHeader(2);
+                writer.Write("type");
+                writer.Write("update_title");
+                writer.Write("payload");
+                writer.WriteMapHeader(1);
+                writer.Write("title");
+                Serialization.Write(ref writer, variant.Title, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("ReduxAction", value.ToString());
+        }
+    }
+
+    public ReduxAction Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        var tag = Serialization.ReadTag(reader, "type", "payload", out var content);
+        reader.Skip();
+        return tag switch
+        {
+            "clear_title" => new ReduxAction.ClearTitle(),
+            "update_title" => ReadUpdateTitle(ref content, options),
+            _ => throw Serialization.UnknownVariant("ReduxAction", tag),
+        };
+    }
+
+    private static ReduxAction.UpdateTitle ReadUpdateTitle(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var title = default(string)!;
+        var hasTitle = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "title":
+                    title = Serialization.Read<string>(ref reader, options);
+                    hasTitle = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasTitle)
+        {
+            throw Serialization.MissingField("UpdateTitle", "title");
+        }
+
+        return new ReduxAction.UpdateTitle
+        {
+            Title = title,
+        };
+    }
+}
+
+/// <summary>
+/// Represents an HTTP request to be sent.
+/// </summary>
+[MessagePackObject]
+public sealed record Request
+{
+    /// <summary>
+    /// The URI to submit the request to.
+    /// </summary>
+    [Key("url")]
+    public required string Url { get; init; }
+
+    /// <summary>
+    /// HTTP method to use for the request.
+    /// </summary>
+    [Key("method")]
+    public required string Method { get; init; }
+
+    /// <summary>
+    /// HTTP headers to submit with the request.
+    /// </summary>
+    [Key("headers")]
+    public required object Headers { get; init; }
+
+    /// <summary>
+    /// The body to submit with the request.
+    /// </summary>
+    [Key("body")]
+    public byte[]? Body { get; init; }
+}
+
+/// <summary>
+/// Represents an error that occurred while attempting to submit the request.
+/// </summary>
+[MessagePackFormatter(typeof(RequestErrorFormatter))]
+public abstract record RequestError
+{
+    private RequestError()
+    {
+    }
+
+    /// <summary>
+    /// Used when we know we don't have an active network connection.
+    /// </summary>
+    public sealed record Offline : RequestError;
+
+    public sealed record NoRoute : RequestError;
+
+    public sealed record ConnectionRefused : RequestError;
+
+    public sealed record Timeout : RequestError;
+
+    public sealed record ServerError : RequestError
+    {
+        /// <summary>
+        /// HTTP status code.
+        /// </summary>
+        public required ushort StatusCode { get; init; }
+
+        /// <summary>
+        /// Response body.
+        /// </summary>
+        public required byte[] Response { get; init; }
+    }
+
+    /// <summary>
+    /// Misc.
+    /// </summary>
+    public sealed record Other : RequestError
+    {
+        public required string Reason { get; init; }
+    }
+}
+
+public sealed class RequestErrorFormatter : IMessagePackFormatter<RequestError>
+{
+    public void Serialize(ref MessagePackWriter writer, RequestError value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case RequestError.Offline:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("offline");
+                break;
+            case RequestError.NoRoute:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("no_route");
+                break;
+            case RequestError.ConnectionRefused:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("connection_refused");
+                break;
+            case RequestError.Timeout:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("timeout");
+                break;
+            case RequestError.ServerError variant:
+                writer.WriteMapHeader(3);
+                writer.Write("type");
+                writer.Write("server_error");
+                writer.Write("status_code");
+                Serialization.Write(ref writer, variant.StatusCode, options);
+                writer.Write("response");
+                Serialization.Write(ref writer, variant.Response, options);
+                break;
+            case RequestError.Other variant:
+                writer.WriteMapHeader(2);
+                writer.Write("type");
+                writer.Write("other/misc");
+                writer.Write("reason");
+                Serialization.Write(ref writer, variant.Reason, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("RequestError", value.ToString());
+        }
+    }
+
+    public RequestError Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        var tag = Serialization.ReadTag(reader, "type");
+        return tag switch
+        {
+            "offline" => Serialization.Skip(ref reader, new RequestError.Offline()),
+            "no_route" => Serialization.Skip(ref reader, new RequestError.NoRoute()),
+            "connection_refused" => Serialization.Skip(ref reader, new RequestError.ConnectionRefused()),
+            "timeout" => Serialization.Skip(ref reader, new RequestError.Timeout()),
+            "server_error" => ReadServerError(ref reader, options),
+            "other/misc" => ReadOther(ref reader, options),
+            _ => throw Serialization.UnknownVariant("RequestError", tag),
+        };
+    }
+
+    private static RequestError.ServerError ReadServerError(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var statusCode = default(ushort)!;
+        var hasStatusCode = false;
+        var response = default(byte[])!;
+        var hasResponse = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "status_code":
+                    statusCode = Serialization.Read<ushort>(ref reader, options);
+                    hasStatusCode = true;
+                    break;
+                case "response":
+                    response = Serialization.Read<byte[]>(ref reader, options);
+                    hasResponse = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasStatusCode)
+        {
+            throw Serialization.MissingField("ServerError", "status_code");
+        }
+
+        if (!hasResponse)
+        {
+            throw Serialization.MissingField("ServerError", "response");
+        }
+
+        return new RequestError.ServerError
+        {
+            StatusCode = statusCode,
+            Response = response,
+        };
+    }
+
+    private static RequestError.Other ReadOther(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var reason = default(string)!;
+        var hasReason = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "reason":
+                    reason = Serialization.Read<string>(ref reader, options);
+                    hasReason = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasReason)
+        {
+            throw Serialization.MissingField("Other", "reason");
+        }
+
+        return new RequestError.Other
+        {
+            Reason = reason,
+        };
+    }
+}
+
+/// <summary>
+/// Represents an HTTP response we received.
+///
+/// Please note we currently do not support streaming responses.
+/// </summary>
+[MessagePackObject]
+public sealed record Response
+{
+    /// <summary>
+    /// The response body. May be empty.
+    /// </summary>
+    [Key("body")]
+    public required byte[] Body { get; init; }
+
+    /// <summary>
+    /// HTTP headers that were part of the response.
+    /// </summary>
+    [Key("headers")]
+    public required object Headers { get; init; }
+
+    /// <summary>
+    /// HTTP status code.
+    /// </summary>
+    [Key("status_code")]
+    public required ushort StatusCode { get; init; }
+}
+
+/// <summary>
+/// A result that can be either successful (`Ok`) or represent an error (`Err`).
+/// </summary>
+[MessagePackFormatter(typeof(ResultFormatter<,>))]
+public abstract record Result<T, E>
+{
+    private Result()
+    {
+    }
+
+    /// <summary>
+    /// Represents a successful result.
+    /// </summary>
+    public sealed record Ok(T Value) : Result<T, E>;
+
+    /// <summary>
+    /// Represents an error.
+    /// </summary>
+    public sealed record Err(E Value) : Result<T, E>;
+}
+
+public sealed class ResultFormatter<T, E> : IMessagePackFormatter<Result<T, E>>
+{
+    public void Serialize(ref MessagePackWriter writer, Result<T, E> value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case Result<T, E>.Ok variant:
+                writer.WriteMapHeader(1);
+                writer.Write("Ok");
+                Serialization.Write(ref writer, variant.Value, options);
+                break;
+            case Result<T, E>.Err variant:
+                writer.WriteMapHeader(1);
+                writer.Write("Err");
+                Serialization.Write(ref writer, variant.Value, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("Result", value.ToString());
+        }
+    }
+
+    public Result<T, E> Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        if (reader.ReadMapHeader() != 1)
+        {
+            throw new MessagePackSerializationException("Expected a map with a single entry for Result");
+        }
+
+        var variant = reader.ReadString();
+        return variant switch
+        {
+            "Ok" => new Result<T, E>.Ok(Serialization.Read<T>(ref reader, options)),
+            "Err" => new Result<T, E>.Err(Serialization.Read<E>(ref reader, options)),
+            _ => throw Serialization.UnknownVariant("Result", variant),
+        };
+    }
+}
+
+/// <summary>
+/// A sample of a time series.
+///
+/// Because the struct is annotated with `#[fp(columnar)]`, every `Vec&lt;Sample&gt;`
+/// in the protocol is replaced with a generated `SampleColumns` struct, which
+/// holds a column with the values of every field. In TypeScript, the columns
+/// are typed arrays, so large lists of samples can be passed without encoding
+/// an object for every item.
+/// </summary>
+[MessagePackObject]
+public sealed record Sample
+{
+    [Key("timestampMs")]
+    public required ulong TimestampMs { get; init; }
+
+    [Key("value")]
+    public required double Value { get; init; }
+
+    [Key("quality")]
+    public required byte Quality { get; init; }
+}
+
+/// <summary>
+/// The columns of a list of `Sample`, with the values of every field stored contiguously.
+/// </summary>
+[MessagePackObject]
+public sealed record SampleColumns
+{
+    [Key("timestampMs")]
+    public required object TimestampMs { get; init; }
+
+    [Key("value")]
+    public required object Value { get; init; }
+
+    [Key("quality")]
+    public required byte[] Quality { get; init; }
+}
+
+[MessagePackFormatter(typeof(SerdeAdjacentlyTaggedFormatter))]
+public abstract record SerdeAdjacentlyTagged
+{
+    private SerdeAdjacentlyTagged()
+    {
+    }
+
+    public sealed record Foo : SerdeAdjacentlyTagged;
+
+    public sealed record Bar(string Value) : SerdeAdjacentlyTagged;
+
+    public sealed record Baz : SerdeAdjacentlyTagged
+    {
+        public required sbyte A { get; init; }
+
+        public required ulong B { get; init; }
+    }
+}
+
+public sealed class SerdeAdjacentlyTaggedFormatter : IMessagePackFormatter<SerdeAdjacentlyTagged>
+{
+    public void Serialize(ref MessagePackWriter writer, SerdeAdjacentlyTagged value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case SerdeAdjacentlyTagged.Foo:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("Foo");
+                break;
+            case SerdeAdjacentlyTagged.Bar variant:
+                writer.WriteMapHeader(2);
+                writer.Write("type");
+                writer.Write("Bar");
+                writer.Write("payload");
+                Serialization.Write(ref writer, variant.Value, options);
+                break;
+            case SerdeAdjacentlyTagged.Baz variant:
+                writer.WriteMapHeader(2);
+                writer.Write("type");
+                writer.Write("Baz");
+                writer.Write("payload");
+                writer.WriteMapHeader(2);
+                writer.Write("a");
+                Serialization.Write(ref writer, variant.A, options);
+                writer.Write("b");
+                Serialization.Write(ref writer, variant.B, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("SerdeAdjacentlyTagged", value.ToString());
+        }
+    }
+
+    public SerdeAdjacentlyTagged Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        var tag = Serialization.ReadTag(reader, "type", "payload", out var content);
+        reader.Skip();
+        return tag switch
+        {
+            "Foo" => new SerdeAdjacentlyTagged.Foo(),
+            "Bar" => new SerdeAdjacentlyTagged.Bar(Serialization.Read<string>(ref content, options)),
+            "Baz" => ReadBaz(ref content, options),
+            _ => throw Serialization.UnknownVariant("SerdeAdjacentlyTagged", tag),
+        };
+    }
+
+    private static SerdeAdjacentlyTagged.Baz ReadBaz(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var a = default(sbyte)!;
+        var hasA = false;
+        var b = default(ulong)!;
+        var hasB = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "a":
+                    a = Serialization.Read<sbyte>(ref reader, options);
+                    hasA = true;
+                    break;
+                case "b":
+                    b = Serialization.Read<ulong>(ref reader, options);
+                    hasB = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasA)
+        {
+            throw Serialization.MissingField("Baz", "a");
+        }
+
+        if (!hasB)
+        {
+            throw Serialization.MissingField("Baz", "b");
+        }
+
+        return new SerdeAdjacentlyTagged.Baz
+        {
+            A = a,
+            B = b,
+        };
+    }
+}
+
+[MessagePackObject]
+public sealed record SerdeFlatten
+{
+    [Key("foo")]
+    public required string Foo { get; init; }
+
+    [Key("bar")]
+    public required long Bar { get; init; }
+}
+
+[MessagePackFormatter(typeof(SerdeInternallyTaggedFormatter))]
+public abstract record SerdeInternallyTagged
+{
+    private SerdeInternallyTagged()
+    {
+    }
+
+    public sealed record Foo : SerdeInternallyTagged;
+
+    public sealed record Baz : SerdeInternallyTagged
+    {
+        public required sbyte A { get; init; }
+
+        public required ulong B { get; init; }
+    }
+}
+
+public sealed class SerdeInternallyTaggedFormatter : IMessagePackFormatter<SerdeInternallyTagged>
+{
+    public void Serialize(ref MessagePackWriter writer, SerdeInternallyTagged value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case SerdeInternallyTagged.Foo:
+                writer.WriteMapHeader(1);
+                writer.Write("type");
+                writer.Write("Foo");
+                break;
+            case SerdeInternallyTagged.Baz variant:
+                writer.WriteMapHeader(3);
+                writer.Write("type");
+                writer.Write("Baz");
+                writer.Write("a");
+                Serialization.Write(ref writer, variant.A, options);
+                writer.Write("b");
+                Serialization.Write(ref writer, variant.B, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("SerdeInternallyTagged", value.ToString());
+        }
+    }
+
+    public SerdeInternallyTagged Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        var tag = Serialization.ReadTag(reader, "type");
+        return tag switch
+        {
+            "Foo" => Serialization.Skip(ref reader, new SerdeInternallyTagged.Foo()),
+            "Baz" => ReadBaz(ref reader, options),
+            _ => throw Serialization.UnknownVariant("SerdeInternallyTagged", tag),
+        };
+    }
+
+    private static SerdeInternallyTagged.Baz ReadBaz(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var a = default(sbyte)!;
+        var hasA = false;
+        var b = default(ulong)!;
+        var hasB = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "a":
+                    a = Serialization.Read<sbyte>(ref reader, options);
+                    hasA = true;
+                    break;
+                case "b":
+                    b = Serialization.Read<ulong>(ref reader, options);
+                    hasB = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasA)
+        {
+            throw Serialization.MissingField("Baz", "a");
+        }
+
+        if (!hasB)
+        {
+            throw Serialization.MissingField("Baz", "b");
+        }
+
+        return new SerdeInternallyTagged.Baz
+        {
+            A = a,
+            B = b,
+        };
+    }
+}
+
+[MessagePackObject]
+public sealed record SerdePropertyRenaming
+{
+    [Key("fooBar")]
+    public required string FooBar { get; init; }
+
+    [Key("QUX_BAZ")]
+    public required double QuxBaz { get; init; }
+
+    [Key("rawStruct")]
+    public required int RawStruct { get; init; }
+}
+
+[MessagePackFormatter(typeof(SerdeUntaggedFormatter))]
+public abstract record SerdeUntagged
+{
+    private SerdeUntagged()
+    {
+    }
+
+    public sealed record Bar(string Value) : SerdeUntagged;
+
+    public sealed record Baz : SerdeUntagged
+    {
+        public required sbyte A { get; init; }
+
+        public required ulong B { get; init; }
+    }
+}
+
+public sealed class SerdeUntaggedFormatter : IMessagePackFormatter<SerdeUntagged>
+{
+    public void Serialize(ref MessagePackWriter writer, SerdeUntagged value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case SerdeUntagged.Bar variant:
+                Serialization.Write(ref writer, variant.Value, options);
+                break;
+            case SerdeUntagged.Baz variant:
+                writer.WriteMapHeader(2);
+                writer.Write("a");
+                Serialization.Write(ref writer, variant.A, options);
+                writer.Write("b");
+                Serialization.Write(ref writer, variant.B, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("SerdeUntagged", value.ToString());
+        }
+    }
+
+    public SerdeUntagged Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        if (Serialization.TryRead(ref reader, options, ReadBar, out var bar))
+        {
+            return bar;
+        }
+
+        if (Serialization.TryRead(ref reader, options, ReadBaz, out var baz))
+        {
+            return baz;
+        }
+
+        throw new MessagePackSerializationException("Data did not match any variant of SerdeUntagged");
+    }
+
+    private static SerdeUntagged.Bar ReadBar(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        return new SerdeUntagged.Bar(Serialization.Read<string>(ref reader, options));
+    }
+
+    private static SerdeUntagged.Baz ReadBaz(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var a = default(sbyte)!;
+        var hasA = false;
+        var b = default(ulong)!;
+        var hasB = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "a":
+                    a = Serialization.Read<sbyte>(ref reader, options);
+                    hasA = true;
+                    break;
+                case "b":
+                    b = Serialization.Read<ulong>(ref reader, options);
+                    hasB = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasA)
+        {
+            throw Serialization.MissingField("Baz", "a");
+        }
+
+        if (!hasB)
+        {
+            throw Serialization.MissingField("Baz", "b");
+        }
+
+        return new SerdeUntagged.Baz
+        {
+            A = a,
+            B = b,
+        };
+    }
+}
+
+[MessagePackFormatter(typeof(SerdeVariantRenamingFormatter))]
+public abstract record SerdeVariantRenaming
+{
+    private SerdeVariantRenaming()
+    {
+    }
+
+    public sealed record FooBar : SerdeVariantRenaming;
+
+    public sealed record QuxBaz : SerdeVariantRenaming
+    {
+        /// <summary>
+        /// Will be renamed to "FooBar" because of the `rename_all` on the
+        /// variant.
+        /// </summary>
+        public required string FooBarValue { get; init; }
+
+        public required double QuxBazValue { get; init; }
+    }
+}
+
+public sealed class SerdeVariantRenamingFormatter : IMessagePackFormatter<SerdeVariantRenaming>
+{
+    public void Serialize(ref MessagePackWriter writer, SerdeVariantRenaming value, MessagePackSerializerOptions options)
+    {
+        switch (value)
+        {
+            case null:
+                writer.WriteNil();
+                break;
+            case SerdeVariantRenaming.FooBar:
+                writer.Write("foo_bar");
+                break;
+            case SerdeVariantRenaming.QuxBaz variant:
+                writer.WriteMapHeader(1);
+                writer.Write("QUX_BAZ");
+                writer.WriteMapHeader(2);
+                writer.Write("FooBar");
+                Serialization.Write(ref writer, variant.FooBarValue, options);
+                writer.Write("qux_baz");
+                Serialization.Write(ref writer, variant.QuxBazValue, options);
+                break;
+            default:
+                throw Serialization.UnknownVariant("SerdeVariantRenaming", value.ToString());
+        }
+    }
+
+    public SerdeVariantRenaming Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+        if (reader.NextMessagePackType == MessagePackType.String)
+        {
+            var tag = reader.ReadString();
+            return tag switch
+            {
+                "foo_bar" => new SerdeVariantRenaming.FooBar(),
+                _ => throw Serialization.UnknownVariant("SerdeVariantRenaming", tag),
+            };
+        }
+
+        if (reader.ReadMapHeader() != 1)
+        {
+            throw new MessagePackSerializationException("Expected a map with a single entry for SerdeVariantRenaming");
+        }
+
+        var variant = reader.ReadString();
+        return variant switch
+        {
+            "QUX_BAZ" => ReadQuxBaz(ref reader, options),
+            _ => throw Serialization.UnknownVariant("SerdeVariantRenaming", variant),
+        };
+    }
+
+    private static SerdeVariantRenaming.QuxBaz ReadQuxBaz(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {
+        var fooBarValue = default(string)!;
+        var hasFooBarValue = false;
+        var quxBazValue = default(double)!;
+        var hasQuxBazValue = false;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            switch (reader.ReadString())
+            {
+                case "FooBar":
+                    fooBarValue = Serialization.Read<string>(ref reader, options);
+                    hasFooBarValue = true;
+                    break;
+                case "qux_baz":
+                    quxBazValue = Serialization.Read<double>(ref reader, options);
+                    hasQuxBazValue = true;
+                    break;
+                default:
+                    reader.Skip();
+                    break;
+            }
+        }
+
+        if (!hasFooBarValue)
+        {
+            throw Serialization.MissingField("QuxBaz", "FooBar");
+        }
+
+        if (!hasQuxBazValue)
+        {
+            throw Serialization.MissingField("QuxBaz", "qux_baz");
+        }
+
+        return new SerdeVariantRenaming.QuxBaz
+        {
+            FooBarValue = fooBarValue,
+            QuxBazValue = quxBazValue,
+        };
+    }
+}
+
+/// <summary>
+/// A state update to communicate to the Redux host.
+///
+/// Fields are wrapped in `Option`. If any field is `None` it means it hasn't
+/// changed.
+/// </summary>
+[MessagePackObject]
+public sealed record StateUpdate
+{
+    [Key("title")]
+    public string? Title { get; init; }
+
+    [Key("revision")]
+    public ushort? Revision { get; init; }
+}
+
+[MessagePackObject]
+public sealed record StructWithGenerics<T>
+{
+    [Key("list")]
+    public required List<T> List { get; init; }
+
+    [Key("points")]
+    public required List<Point<T>> Points { get; init; }
+
+    [Key("recursive")]
+    public required List<Point<Point<T>>> Recursive { get; init; }
+
+    [Key("complex_nested")]
+    public Dictionary<string, List<Point<double>>>? ComplexNested { get; init; }
+
+    [Key("optional_timestamp")]
+    public string? OptionalTimestamp { get; init; }
+}
+
+[MessagePackObject]
+public sealed record StructWithOptions
+{
+    [Key("filledString")]
+    public string FilledString { get; init; } = default!;
+
+    [Key("emptyString")]
+    public string EmptyString { get; init; } = default!;
+
+    [Key("filledOptionString")]
+    public string? FilledOptionString { get; init; }
+
+    [Key("emptyOptionString")]
+    public string? EmptyOptionString { get; init; }
+
+    [Key("neverSkippedFilledOptionString")]
+    public string? NeverSkippedFilledOptionString { get; init; }
+
+    [Key("neverSkippedEmptyOptionString")]
+    public string? NeverSkippedEmptyOptionString { get; init; }
+}
+
+/// <summary>
+/// Timestamps that the TypeScript runtime represents as `Date` objects.
+///
+/// Fields that are annotated with `ts_as = "Date"` are still serialized as
+/// RFC3339 strings, but the TypeScript runtime converts them into `Date`
+/// objects when they are received, and back into strings when they are sent.
+/// </summary>
+[MessagePackObject]
+public sealed record Timestamps
+{
+    [Key("createdAt")]
+    public required string CreatedAt { get; init; }
+
+    [Key("deletedAt")]
+    public string? DeletedAt { get; init; }
+}
+
+/// <summary>
+/// An identifier of a resource of type `T`.
+///
+/// `PhantomData` fields are not serialized, so they only show up in the Rust
+/// bindings, where they keep the type parameter in use. Other bindings only
+/// preserve the type parameter in the signature of the type.
+/// </summary>
+[MessagePackObject]
+public sealed record TypedId<T>
+{
+    [Key("id")]
+    public required ulong Id { get; init; }
+}
+
+/// <summary>
+/// Structs that contain validated structs get validated as a whole.
+///
+/// Fields that are not part of this struct are rejected, rather than silently
+/// ignored.
+/// </summary>
+[MessagePackObject]
+public sealed record ValidatedChild
+{
+    [Key("name")]
+    public required string Name { get; init; }
+}
+
+/// <summary>
+/// Fields with `#[fp(validate(...))]` attributes are validated when they are
+/// received from the other side, so invalid values are rejected right away.
+/// </summary>
+[MessagePackObject]
+public sealed record ValidatedStruct
+{
+    [Key("percentage")]
+    public required byte Percentage { get; init; }
+
+    [Key("slug")]
+    public required string Slug { get; init; }
+
+    [Key("ratio")]
+    public double? Ratio { get; init; }
+
+    [Key("children")]
+    public required List<ValidatedChild> Children { get; init; }
+}
+
+/// <summary>
+/// Helpers for the formatters of enums, which follow the representations that
+/// Serde uses for them.
+/// </summary>
+internal static class Serialization
+{
+    public delegate T ReadFunc<T>(ref MessagePackReader reader, MessagePackSerializerOptions options);
+
+    public static void Write<T>(ref MessagePackWriter writer, T value, MessagePackSerializerOptions options) =>
+        options.Resolver.GetFormatterWithVerify<T>().Serialize(ref writer, value, options);
+
+    public static T Read<T>(ref MessagePackReader reader, MessagePackSerializerOptions options) =>
+        options.Resolver.GetFormatterWithVerify<T>().Deserialize(ref reader, options);
+
+    /// <summary>
+    /// Returns the value of the given tag in a map, without advancing the
+    /// reader.
+    /// </summary>
+    public static string? ReadTag(MessagePackReader reader, string tag) =>
+        ReadTag(reader, tag, null, out _);
+
+    /// <summary>
+    /// Returns the value of the given tag in a map, without advancing the
+    /// reader. The content reader is positioned at the value of the given
+    /// content key, if it is present.
+    /// </summary>
+    public static string? ReadTag(
+        MessagePackReader reader,
+        string tag,
+        string? content,
+        out MessagePackReader contentReader)
+    {
+        string? value = null;
+        contentReader = reader;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            var key = reader.ReadString();
+            if (key == tag)
+            {
+                value = reader.ReadString();
+                continue;
+            }
+
+            if (key == content)
+            {
+                contentReader = reader;
+            }
+
+            reader.Skip();
+        }
+
+        return value;
+    }
+
+    public static T Skip<T>(ref MessagePackReader reader, T value)
+    {
+        reader.Skip();
+        return value;
+    }
+
+    /// <summary>
+    /// Attempts to read a value, only advancing the reader if it succeeds.
+    /// </summary>
+    public static bool TryRead<T>(
+        ref MessagePackReader reader,
+        MessagePackSerializerOptions options,
+        ReadFunc<T> read,
+        out T value)
+    {
+        var peekReader = reader.CreatePeekReader();
+        try
+        {
+            value = read(ref peekReader, options);
+        }
+        catch (MessagePackSerializationException)
+        {
+            value = default!;
+            return false;
+        }
+
+        reader = peekReader;
+        return true;
+    }
+
+    public static MessagePackSerializationException MissingField(string type, string field) =>
+        new($"Missing field \"{field}\" for {type}");
+
+    public static MessagePackSerializationException UnknownVariant(string type, string? variant) =>
+        new($"Unknown variant \"{variant}\" for {type}");
+}
@@ -0,0 +1,311 @@
+// ============================================= //
+// Fixture tests for the Rust bindings           //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+use example_bindings_fixtures::{check_fixture, types::*, FIXTURE_SIZE};
+
+#[test]
+fn fixture_batch_response() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<BatchResponse>(
+        include_bytes!("../fixtures/batch_response.msgpack"),
+        BatchResponse { results: (0..size).map(|_| Result::Ok(1)).collect(), summary: Result::Ok("x".repeat(size)), retry_after: Some(Result::Ok(1)) },
+    );
+}
+
+#[test]
+fn fixture_conditional_fields() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<ConditionalFields>(
+        include_bytes!("../fixtures/conditional_fields.msgpack"),
+        ConditionalFields { name: "x".repeat(size) },
+    );
+}
+
+#[test]
+fn fixture_conditional_variants() {
+    check_fixture::<ConditionalVariants>(
+        include_bytes!("../fixtures/conditional_variants.msgpack"),
+        ConditionalVariants::Stable,
+    );
+}
+
+#[test]
+fn fixture_doc_example_enum() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<DocExampleEnum>(
+        include_bytes!("../fixtures/doc_example_enum.msgpack"),
+        DocExampleEnum::Variant1("x".repeat(size)),
+    );
+}
+
+#[test]
+fn fixture_doc_example_struct() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<DocExampleStruct>(
+        include_bytes!("../fixtures/doc_example_struct.msgpack"),
+        DocExampleStruct { multi_line: "x".repeat(size), r#type: "x".repeat(size) },
+    );
+}
+
+#[test]
+fn fixture_explicitedly_imported_type() {
+    check_fixture::<ExplicitedlyImportedType>(
+        include_bytes!("../fixtures/explicitedly_imported_type.msgpack"),
+        ExplicitedlyImportedType { you_will_see_this: true },
+    );
+}
+
+#[test]
+fn fixture_flattened_struct() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<FlattenedStruct>(
+        include_bytes!("../fixtures/flattened_struct.msgpack"),
+        FlattenedStruct { foo: "x".repeat(size), bar: 1 },
+    );
+}
+
+#[test]
+fn fixture_floating_point() {
+    check_fixture::<FloatingPoint>(
+        include_bytes!("../fixtures/floating_point.msgpack"),
+        Point { value: 1.5 },
+    );
+}
+
+#[test]
+fn fixture_fp_adjacently_tagged() {
+    check_fixture::<FpAdjacentlyTagged>(
+        include_bytes!("../fixtures/fp_adjacently_tagged.msgpack"),
+        FpAdjacentlyTagged::Foo,
+    );
+}
+
+#[test]
+fn fixture_fp_flatten() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<FpFlatten>(
+        include_bytes!("../fixtures/fp_flatten.msgpack"),
+        FpFlatten { flattened: FlattenedStruct { foo: "x".repeat(size), bar: 1 } },
+    );
+}
+
+#[test]
+fn fixture_fp_internally_tagged() {
+    check_fixture::<FpInternallyTagged>(
+        include_bytes!("../fixtures/fp_internally_tagged.msgpack"),
+        FpInternallyTagged::Foo,
+    );
+}
+
+#[test]
+fn fixture_fp_property_renaming() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<FpPropertyRenaming>(
+        include_bytes!("../fixtures/fp_property_renaming.msgpack"),
+        FpPropertyRenaming { foo_bar: "x".repeat(size), qux_baz: 1.5, r#raw_struct: 1 },
+    );
+}
+
+#[test]
+fn fixture_fp_untagged() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<FpUntagged>(
+        include_bytes!("../fixtures/fp_untagged.msgpack"),
+        FpUntagged::Bar("x".repeat(size)),
+    );
+}
+
+#[test]
+fn fixture_fp_variant_renaming() {
+    check_fixture::<FpVariantRenaming>(
+        include_bytes!("../fixtures/fp_variant_renaming.msgpack"),
+        FpVariantRenaming::FooBar,
+    );
+}
+
+#[test]
+fn fixture_group_imported_type_1() {
+    check_fixture::<GroupImportedType1>(
+        include_bytes!("../fixtures/group_imported_type_1.msgpack"),
+        GroupImportedType1 { you_will_see_this: true },
+    );
+}
+
+#[test]
+fn fixture_group_imported_type_2() {
+    check_fixture::<GroupImportedType2>(
+        include_bytes!("../fixtures/group_imported_type_2.msgpack"),
+        GroupImportedType2 { you_will_see_this: true },
+    );
+}
+
+#[test]
+fn fixture_http_result() {
+    check_fixture::<HttpResult>(
+        include_bytes!("../fixtures/http_result.msgpack"),
+        Result::Err(RequestError::Offline),
+    );
+}
+
+#[test]
+fn fixture_int_64() {
+    check_fixture::<Int64>(
+        include_bytes!("../fixtures/int_64.msgpack"),
+        1,
+    );
+}
+
+#[test]
+fn fixture_metric() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<Metric>(
+        include_bytes!("../fixtures/metric.msgpack"),
+        Metric { name: "x".repeat(size), value: 1.5 },
+    );
+}
+
+#[test]
+fn fixture_operation_result() {
+    check_fixture::<OperationResult>(
+        include_bytes!("../fixtures/operation_result.msgpack"),
+        Result::Ok(1),
+    );
+}
+
+#[test]
+fn fixture_plugin_config() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<PluginConfig>(
+        include_bytes!("../fixtures/plugin_config.msgpack"),
+        PluginConfig { log_level: "x".repeat(size) },
+    );
+}
+
+#[test]
+fn fixture_priority() {
+    check_fixture::<Priority>(
+        include_bytes!("../fixtures/priority.msgpack"),
+        Priority::Low,
+    );
+}
+
+#[test]
+fn fixture_progress() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<Progress>(
+        include_bytes!("../fixtures/progress.msgpack"),
+        Progress { task: "x".repeat(size), completed_steps: 1, total_steps: 1 },
+    );
+}
+
+#[test]
+fn fixture_rate_limited() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<RateLimited>(
+        include_bytes!("../fixtures/rate_limited.msgpack"),
+        RateLimited { function: "x".repeat(size), retry_after_ms: 1 },
+    );
+}
+
+#[test]
+fn fixture_redux_action() {
+    check_fixture::<ReduxAction>(
+        include_bytes!("../fixtures/redux_action.msgpack"),
+        ReduxAction::ClearTitle,
+    );
+}
+
+#[test]
+fn fixture_request_error() {
+    check_fixture::<RequestError>(
+        include_bytes!("../fixtures/request_error.msgpack"),
+        RequestError::Offline,
+    );
+}
+
+#[test]
+fn fixture_sample() {
+    check_fixture::<Sample>(
+        include_bytes!("../fixtures/sample.msgpack"),
+        Sample { timestamp_ms: 1, value: 1.5, quality: 1 },
+    );
+}
+
+#[test]
+fn fixture_serde_adjacently_tagged() {
+    check_fixture::<SerdeAdjacentlyTagged>(
+        include_bytes!("../fixtures/serde_adjacently_tagged.msgpack"),
+        SerdeAdjacentlyTagged::Foo,
+    );
+}
+
+#[test]
+fn fixture_serde_flatten() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<SerdeFlatten>(
+        include_bytes!("../fixtures/serde_flatten.msgpack"),
+        SerdeFlatten { flattened: FlattenedStruct { foo: "x".repeat(size), bar: 1 } },
+    );
+}
+
+#[test]
+fn fixture_serde_internally_tagged() {
+    check_fixture::<SerdeInternallyTagged>(
+        include_bytes!("../fixtures/serde_internally_tagged.msgpack"),
+        SerdeInternallyTagged::Foo,
+    );
+}
+
+#[test]
+fn fixture_serde_property_renaming() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<SerdePropertyRenaming>(
+        include_bytes!("../fixtures/serde_property_renaming.msgpack"),
+        SerdePropertyRenaming { foo_bar: "x".repeat(size), qux_baz: 1.5, r#raw_struct: 1 },
+    );
+}
+
+#[test]
+fn fixture_serde_untagged() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<SerdeUntagged>(
+        include_bytes!("../fixtures/serde_untagged.msgpack"),
+        SerdeUntagged::Bar("x".repeat(size)),
+    );
+}
+
+#[test]
+fn fixture_serde_variant_renaming() {
+    check_fixture::<SerdeVariantRenaming>(
+        include_bytes!("../fixtures/serde_variant_renaming.msgpack"),
+        SerdeVariantRenaming::FooBar,
+    );
+}
+
+#[test]
+fn fixture_state_update() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<StateUpdate>(
+        include_bytes!("../fixtures/state_update.msgpack"),
+        StateUpdate { title: Some(std::rc::Rc::new("x".repeat(size))), revision: Some(1) },
+    );
+}
+
+#[test]
+fn fixture_struct_with_options() {
+    let size = FIXTURE_SIZE;
+    check_fixture::<StructWithOptions>(
+        include_bytes!("../fixtures/struct_with_options.msgpack"),
+        StructWithOptions { filled_string: "x".repeat(size), empty_string: "x".repeat(size), filled_option_string: Some("x".repeat(size)), empty_option_string: Some("x".repeat(size)), never_skipped_filled_option_string: Some("x".repeat(size)), never_skipped_empty_option_string: Some("x".repeat(size)) },
+    );
+}
+
+#[test]
+fn fixture_user_id() {
+    check_fixture::<UserId>(
+        include_bytes!("../fixtures/user_id.msgpack"),
+        UserId(1),
+    );
+}
@@ -0,0 +1,134 @@
+// ============================================= //
+// Fixture tests for the TypeScript runtime      //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { decode } from "https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts";
+
+/**
+ * The number of characters in strings, and the number of items in lists and
+ * maps, of the fixtures.
+ */
+export const FIXTURE_SIZE = 1;
+
+/**
+ * A fixture that could not be decoded, or that was decoded to a different
+ * value than expected.
+ */
+export type FixtureFailure = {
+    fixture: string;
+    expected: unknown;
+    actual?: unknown;
+    error?: unknown;
+};
+
+/**
+ * The values that the fixtures should decode to, by the name of their file.
+ */
+function createExpectedValues(size: number): Record<string, unknown> {
+    return {
+        "batch_response.msgpack": { results: Array.from({ length: size }, () => ({ "Ok": 1 })), summary: { "Ok": "x".repeat(size) }, retryAfter: { "Ok": 1 } },
+        "conditional_fields.msgpack": { name: "x".repeat(size) },
+        "conditional_variants.msgpack": "Stable",
+        "doc_example_enum.msgpack": { "Variant1": "x".repeat(size) },
+        "doc_example_struct.msgpack": { multi_line: "x".repeat(size), type: "x".repeat(size) },
+        "explicitedly_imported_type.msgpack": { you_will_see_this: true },
+        "flattened_struct.msgpack": { foo: "x".repeat(size), bar: 1 },
+        "floating_point.msgpack": { value: 1.5 },
+        "fp_adjacently_tagged.msgpack": { type: "Foo" },
+        "fp_flatten.msgpack": { foo: "x".repeat(size), bar: 1 },
+        "fp_internally_tagged.msgpack": { type: "Foo" },
+        "fp_property_renaming.msgpack": { fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 },
+        "fp_untagged.msgpack": "x".repeat(size),
+        "fp_variant_renaming.msgpack": "foo_bar",
+        "group_imported_type_1.msgpack": { you_will_see_this: true },
+        "group_imported_type_2.msgpack": { you_will_see_this: true },
+        "http_result.msgpack": { "Err": { type: "offline" } },
+        "int_64.msgpack": 1,
+        "metric.msgpack": { name: "x".repeat(size), value: 1.5 },
+        "operation_result.msgpack": { "Ok": 1 },
+        "plugin_config.msgpack": { logLevel: "x".repeat(size) },
+        "priority.msgpack": "low",
+        "progress.msgpack": { task: "x".repeat(size), completedSteps: 1, totalSteps: 1 },
+        "rate_limited.msgpack": { function: "x".repeat(size), retryAfterMs: 1 },
+        "redux_action.msgpack": { type: "clear_title" },
+        "request_error.msgpack": { type: "offline" },
+        "sample.msgpack": { timestampMs: 1, value: 1.5, quality: 1 },
+        "serde_adjacently_tagged.msgpack": { type: "Foo" },
+        "serde_flatten.msgpack": { foo: "x".repeat(size), bar: 1 },
+        "serde_internally_tagged.msgpack": { type: "Foo" },
+        "serde_property_renaming.msgpack": { fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 },
+        "serde_untagged.msgpack": "x".repeat(size),
+        "serde_variant_renaming.msgpack": "foo_bar",
+        "state_update.msgpack": { title: "x".repeat(size), revision: 1 },
+        "struct_with_options.msgpack": { filledString: "x".repeat(size), emptyString: "x".repeat(size), filledOptionString: "x".repeat(size), emptyOptionString: "x".repeat(size), neverSkippedFilledOptionString: "x".repeat(size), neverSkippedEmptyOptionString: "x".repeat(size) },
+        "user_id.msgpack": 1,
+    };
+}
+
+/**
+ * Decodes every fixture and compares it with the value it should decode to.
+ *
+ * @param readFixture Reads the fixture with the given file name from the
+ *                    `fixtures` directory.
+ * @returns The fixtures that could not be decoded, or that were decoded to a
+ *          different value than expected.
+ */
+export function checkFixtures(readFixture: (file: string) => Uint8Array): FixtureFailure[] {
+    const failures: FixtureFailure[] = [];
+    for (const [fixture, expected] of Object.entries(createExpectedValues(FIXTURE_SIZE))) {
+        try {
+            const actual = decode(readFixture(fixture));
+            if (!isEqual(actual, expected)) {
+                failures.push({ fixture, expected, actual });
+            }
+        } catch (error) {
+            failures.push({ fixture, expected, error });
+        }
+    }
+    return failures;
+}
+
+/**
+ * Compares two values structurally. Typed arrays are compared as regular
+ * arrays, because MessagePack arrays are always decoded as such.
+ */
+function isEqual(actual: unknown, expected: unknown): boolean {
+    if (ArrayBuffer.isView(actual) || ArrayBuffer.isView(expected)) {
+        return isEqual(fromTypedArray(actual), fromTypedArray(expected));
+    }
+
+    if (Array.isArray(actual) && Array.isArray(expected)) {
+        if (actual.length !== expected.length) {
+            return false;
+        }
+        for (let i = 0; i < expected.length; i++) {
+            if (!isEqual(actual[i], expected[i])) {
+                return false;
+            }
+        }
+        return true;
+    }
+    if (isObject(actual) && isObject(expected)) {
+        const keys = Object.keys(expected);
+        if (Object.keys(actual).length !== keys.length) {
+            return false;
+        }
+        for (const key of keys) {
+            if (!(key in actual) || !isEqual(actual[key], expected[key])) {
+                return false;
+            }
+        }
+        return true;
+    }
+    return Object.is(actual, expected);
+}
+
+function fromTypedArray(value: unknown): unknown {
+    return ArrayBuffer.isView(value) ? Array.from(value as unknown as ArrayLike<unknown>) : value;
+}
+
+function isObject(value: unknown): value is Record<string, unknown> {
+    return typeof value === "object" && value !== null && !Array.isArray(value);
+}
@@ -0,0 +1,39 @@
+// ============================================= //
+// Fixture tests for the Rust bindings           //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+pub mod types;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// The number of characters in strings, and the number of items in lists and
+/// maps, of the fixtures.
+pub const FIXTURE_SIZE: usize = 1;
+
+/// Checks that the fixture deserializes, and that serializing both the result
+/// and the expected value reproduces the fixture exactly, using the same
+/// MessagePack configuration as the Rust runtimes.
+///
+/// Values are compared by their serialization, because types from other crates
+/// do not necessarily implement `PartialEq`.
+pub fn check_fixture<T>(fixture: &[u8], expected: T)
+where
+    T: DeserializeOwned + Serialize,
+{
+    let mut deserializer = rmp_serde::Deserializer::from_read_ref(fixture).with_human_readable();
+    let value = T::deserialize(&mut deserializer).expect("Could not deserialize fixture");
+    assert_eq!(serialize(&value), fixture, "Deserialized value does not match fixture");
+    assert_eq!(serialize(&expected), fixture, "Expected value does not match fixture");
+}
+
+fn serialize<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let mut serializer = rmp_serde::Serializer::new(&mut buffer)
+        .with_struct_map()
+        .with_human_readable();
+    value
+        .serialize(&mut serializer)
+        .expect("Could not serialize value");
+    buffer
+}
@@ -0,0 +1,268 @@
+// ============================================= //
+// Fuzz script for the TypeScript runtime        //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { decode, DecodeError, encode } from "https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts";
+
+import type * as types from "./types.ts";
+import { validateValidatedStruct, ValidationError } from "./validation.ts";
+
+/**
+ * Deserializes the given bytes the same way the TypeScript runtime
+ * deserializes the values it receives from a plugin.
+ */
+export type FuzzTarget = (data: Uint8Array) => void;
+
+/**
+ * An input for which a target threw an unexpected error.
+ */
+export type FuzzFailure = { target: string; input: Uint8Array; error: unknown };
+
+/**
+ * The fuzz targets, by the name of the type they deserialize.
+ */
+export const FUZZ_TARGETS: Record<string, FuzzTarget> = {
+    string_vec_u32_bool: (data) => {
+        decode(data);
+    },
+    arbitrary_precision_numbers: (data) => {
+        decode(data);
+    },
+    batch_response: (data) => {
+        decode(data);
+    },
+    collections: (data) => {
+        decode(data);
+    },
+    edge_values: (data) => {
+        decode(data);
+    },
+    explicit_bound_point_u64: (data) => {
+        decode(data);
+    },
+    fp_adjacently_tagged: (data) => {
+        decode(data);
+    },
+    fp_flatten: (data) => {
+        decode(data);
+    },
+    fp_internally_tagged: (data) => {
+        decode(data);
+    },
+    fp_property_renaming: (data) => {
+        decode(data);
+    },
+    fp_untagged: (data) => {
+        decode(data);
+    },
+    fp_variant_renaming: (data) => {
+        decode(data);
+    },
+    integer_types: (data) => {
+        decode(data);
+    },
+    my_date_time: (data) => {
+        decode(data);
+    },
+    progress: (data) => {
+        decode(data);
+    },
+    request: (data) => {
+        decode(data);
+    },
+    result_byte_buf_string: (data) => {
+        decode(data);
+    },
+    result_bytes_string: (data) => {
+        decode(data);
+    },
+    result_string_string: (data) => {
+        decode(data);
+    },
+    sample_columns: (data) => {
+        decode(data);
+    },
+    serde_adjacently_tagged: (data) => {
+        decode(data);
+    },
+    serde_flatten: (data) => {
+        decode(data);
+    },
+    serde_internally_tagged: (data) => {
+        decode(data);
+    },
+    serde_property_renaming: (data) => {
+        decode(data);
+    },
+    serde_untagged: (data) => {
+        decode(data);
+    },
+    serde_variant_renaming: (data) => {
+        decode(data);
+    },
+    state_update: (data) => {
+        decode(data);
+    },
+    string: (data) => {
+        decode(data);
+    },
+    struct_with_generics_u64: (data) => {
+        decode(data);
+    },
+    struct_with_options: (data) => {
+        decode(data);
+    },
+    timestamps: (data) => {
+        decode(data);
+    },
+    typed_id_point_f64: (data) => {
+        decode(data);
+    },
+    validated_struct: (data) => {
+        const value = decode(data) as types.ValidatedStruct;
+        validateValidatedStruct(value);
+    },
+    value: (data) => {
+        decode(data);
+    },
+    vec_metric: (data) => {
+        decode(data);
+    },
+    vec_result_string_rate_limited: (data) => {
+        decode(data);
+    },
+    f32_3: (data) => {
+        decode(data);
+    },
+    f64_3: (data) => {
+        decode(data);
+    },
+    i16_3: (data) => {
+        decode(data);
+    },
+    i32_3: (data) => {
+        decode(data);
+    },
+    i8_3: (data) => {
+        decode(data);
+    },
+    u16_3: (data) => {
+        decode(data);
+    },
+    u32_3: (data) => {
+        decode(data);
+    },
+    u8_3: (data) => {
+        decode(data);
+    },
+};
+
+/**
+ * Encodes sample values for the targets, which are mutated to create inputs
+ * that get past the first few bytes.
+ */
+function createSeeds(size: number): Record<string, Uint8Array> {
+    return {
+        batch_response: encode({ results: Array.from({ length: size }, () => ({ "Ok": 1 })), summary: { "Ok": "x".repeat(size) }, retryAfter: { "Ok": 1 } }),
+        explicit_bound_point_u64: encode({ value: 1 }),
+        fp_adjacently_tagged: encode({ type: "Foo" }),
+        fp_flatten: encode({ foo: "x".repeat(size), bar: 1 }),
+        fp_internally_tagged: encode({ type: "Foo" }),
+        fp_property_renaming: encode({ fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 }),
+        fp_untagged: encode("x".repeat(size)),
+        fp_variant_renaming: encode("foo_bar"),
+        progress: encode({ task: "x".repeat(size), completedSteps: 1, totalSteps: 1 }),
+        result_byte_buf_string: encode({ "Err": "x".repeat(size) }),
+        result_bytes_string: encode({ "Err": "x".repeat(size) }),
+        result_string_string: encode({ "Ok": "x".repeat(size) }),
+        serde_adjacently_tagged: encode({ type: "Foo" }),
+        serde_flatten: encode({ foo: "x".repeat(size), bar: 1 }),
+        serde_internally_tagged: encode({ type: "Foo" }),
+        serde_property_renaming: encode({ fooBar: "x".repeat(size), QUX_BAZ: 1.5, rawStruct: 1 }),
+        serde_untagged: encode("x".repeat(size)),
+        serde_variant_renaming: encode("foo_bar"),
+        state_update: encode({ title: "x".repeat(size), revision: 1 }),
+        string: encode("x".repeat(size)),
+        struct_with_options: encode({ filledString: "x".repeat(size), emptyString: "x".repeat(size), filledOptionString: "x".repeat(size), emptyOptionString: "x".repeat(size), neverSkippedFilledOptionString: "x".repeat(size), neverSkippedEmptyOptionString: "x".repeat(size) }),
+        typed_id_point_f64: encode({ id: 1 }),
+        validated_struct: encode({ percentage: 1, slug: "x".repeat(size), ratio: 1.5, children: Array.from({ length: size }, () => ({ name: "x".repeat(size) })) }),
+        vec_metric: encode(Array.from({ length: size }, () => ({ name: "x".repeat(size), value: 1.5 }))),
+        vec_result_string_rate_limited: encode(Array.from({ length: size }, () => ({ "Ok": "x".repeat(size) }))),
+        f32_3: encode(new Float32Array(3).fill(1.5)),
+        f64_3: encode(new Float64Array(3).fill(1.5)),
+        i16_3: encode(new Int16Array(3).fill(1)),
+        i32_3: encode(new Int32Array(3).fill(1)),
+        i8_3: encode(new Int8Array(3).fill(1)),
+        u16_3: encode(new Uint16Array(3).fill(1)),
+        u32_3: encode(new Uint32Array(3).fill(1)),
+        u8_3: encode(new Uint8Array(3).fill(1)),
+    };
+}
+
+/**
+ * Runs every target with random mutations of encoded sample values, as well as
+ * with random bytes.
+ *
+ * Errors from decoding and validating the input are expected, but any other
+ * error is reported as a failure, together with the input that caused it.
+ *
+ * @param iterations The number of inputs to run every target with.
+ * @param random Generates random numbers in the range [0, 1).
+ * @returns The inputs that caused unexpected errors.
+ */
+export function fuzz(iterations = 10_000, random: () => number = Math.random): FuzzFailure[] {
+    const seeds = createSeeds(4);
+    const failures: FuzzFailure[] = [];
+    for (const [target, fuzzTarget] of Object.entries(FUZZ_TARGETS)) {
+        const seed = seeds[target];
+        for (let i = 0; i < iterations; i++) {
+            const input = seed && random() < 0.9
+                ? mutate(seed, random)
+                : randomBytes(Math.floor(random() * 64), random);
+            try {
+                fuzzTarget(input);
+            } catch (error) {
+                if (!isExpectedError(error)) {
+                    failures.push({ target, input, error });
+                }
+            }
+        }
+    }
+    return failures;
+}
+
+function isExpectedError(error: unknown): boolean {
+    return error instanceof DecodeError || error instanceof RangeError ||
+        error instanceof ValidationError;
+}
+
+/**
+ * Applies a few random byte-level mutations to the given input.
+ */
+function mutate(input: Uint8Array, random: () => number): Uint8Array {
+    const bytes = Array.from(input);
+    const mutations = 1 + Math.floor(random() * 4);
+    for (let i = 0; i < mutations; i++) {
+        const index = Math.floor(random() * (bytes.length + 1));
+        switch (Math.floor(random() * 4)) {
+            case 0:
+                bytes[index] = Math.floor(random() * 256);
+                break;
+            case 1:
+                bytes.splice(index, 0, Math.floor(random() * 256));
+                break;
+            case 2:
+                bytes.splice(index, 1);
+                break;
+            default:
+                bytes.length = index;
+        }
+    }
+    return Uint8Array.from(bytes);
+}
+
+function randomBytes(length: number, random: () => number): Uint8Array {
+    return Uint8Array.from({ length }, () => Math.floor(random() * 256));
+}
@@ -0,0 +1,237 @@
+// ============================================= //
+// Fuzz targets for the Rust runtimes            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+pub mod types;
+
+use serde::Deserialize;
+use types::*;
+
+/// Deserializes the given bytes the same way the Rust runtimes deserialize the
+/// values they receive from a plugin. Failing to deserialize is expected, but
+/// panicking is not.
+fn deserialize<'a, T: Deserialize<'a>>(data: &'a [u8]) {
+    let mut deserializer = rmp_serde::Deserializer::from_read_ref(data).with_human_readable();
+    let _ = T::deserialize(&mut deserializer);
+}
+
+/// Deserializes `(String, Vec<u32>, bool)`, as received through `export_multiple_values`.
+pub fn fuzz_string_vec_u32_bool(data: &[u8]) {
+    deserialize::<(String, Vec<u32>, bool)>(data);
+}
+
+/// Deserializes `ArbitraryPrecisionNumbers`, as received through `import_arbitrary_precision_numbers`.
+pub fn fuzz_arbitrary_precision_numbers(data: &[u8]) {
+    deserialize::<ArbitraryPrecisionNumbers>(data);
+}
+
+/// Deserializes `BatchResponse`, as received through `export_batch_response`.
+pub fn fuzz_batch_response(data: &[u8]) {
+    deserialize::<BatchResponse>(data);
+}
+
+/// Deserializes `Collections`, as received through `import_collections`, `export_collections`.
+pub fn fuzz_collections(data: &[u8]) {
+    deserialize::<Collections>(data);
+}
+
+/// Deserializes `EdgeValues`, as received through `export_edge_values`.
+pub fn fuzz_edge_values(data: &[u8]) {
+    deserialize::<EdgeValues>(data);
+}
+
+/// Deserializes `ExplicitBoundPoint<u64>`, as received through `import_explicit_bound_point`.
+pub fn fuzz_explicit_bound_point_u64(data: &[u8]) {
+    deserialize::<ExplicitBoundPoint<u64>>(data);
+}
+
+/// Deserializes `FpAdjacentlyTagged`, as received through `import_fp_adjacently_tagged`, `export_fp_adjacently_tagged`.
+pub fn fuzz_fp_adjacently_tagged(data: &[u8]) {
+    deserialize::<FpAdjacentlyTagged>(data);
+}
+
+/// Deserializes `FpFlatten`, as received through `import_fp_flatten`, `export_fp_flatten`.
+pub fn fuzz_fp_flatten(data: &[u8]) {
+    deserialize::<FpFlatten>(data);
+}
+
+/// Deserializes `FpInternallyTagged`, as received through `import_fp_internally_tagged`, `export_fp_internally_tagged`.
+pub fn fuzz_fp_internally_tagged(data: &[u8]) {
+    deserialize::<FpInternallyTagged>(data);
+}
+
+/// Deserializes `FpPropertyRenaming`, as received through `import_fp_struct`, `export_async_struct`, `export_fp_struct`.
+pub fn fuzz_fp_property_renaming(data: &[u8]) {
+    deserialize::<FpPropertyRenaming>(data);
+}
+
+/// Deserializes `FpUntagged`, as received through `import_fp_untagged`, `export_fp_untagged`.
+pub fn fuzz_fp_untagged(data: &[u8]) {
+    deserialize::<FpUntagged>(data);
+}
+
+/// Deserializes `FpVariantRenaming`, as received through `import_fp_enum`, `export_fp_enum`.
+pub fn fuzz_fp_variant_renaming(data: &[u8]) {
+    deserialize::<FpVariantRenaming>(data);
+}
+
+/// Deserializes `IntegerTypes`, as received through `import_integer_types`.
+pub fn fuzz_integer_types(data: &[u8]) {
+    deserialize::<IntegerTypes>(data);
+}
+
+/// Deserializes `MyDateTime`, as received through `import_timestamp`, `export_timestamp`.
+pub fn fuzz_my_date_time(data: &[u8]) {
+    deserialize::<MyDateTime>(data);
+}
+
+/// Deserializes `Progress`, as received through `report_progress`.
+pub fn fuzz_progress(data: &[u8]) {
+    deserialize::<Progress>(data);
+}
+
+/// Deserializes `Request`, as received through `make_http_request`.
+pub fn fuzz_request(data: &[u8]) {
+    deserialize::<Request>(data);
+}
+
+/// Deserializes `Result<serde_bytes::ByteBuf, String>`, as received through `export_get_serde_bytes`.
+pub fn fuzz_result_byte_buf_string(data: &[u8]) {
+    deserialize::<Result<serde_bytes::ByteBuf, String>>(data);
+}
+
+/// Deserializes `Result<bytes::Bytes, String>`, as received through `export_get_bytes`.
+pub fn fuzz_result_bytes_string(data: &[u8]) {
+    deserialize::<Result<bytes::Bytes, String>>(data);
+}
+
+/// Deserializes `Result<String, String>`, as received through `fetch_data`.
+pub fn fuzz_result_string_string(data: &[u8]) {
+    deserialize::<Result<String, String>>(data);
+}
+
+/// Deserializes `SampleColumns`, as received through `export_samples`.
+pub fn fuzz_sample_columns(data: &[u8]) {
+    deserialize::<SampleColumns>(data);
+}
+
+/// Deserializes `SerdeAdjacentlyTagged`, as received through `import_serde_adjacently_tagged`, `export_serde_adjacently_tagged`.
+pub fn fuzz_serde_adjacently_tagged(data: &[u8]) {
+    deserialize::<SerdeAdjacentlyTagged>(data);
+}
+
+/// Deserializes `SerdeFlatten`, as received through `import_serde_flatten`, `export_serde_flatten`.
+pub fn fuzz_serde_flatten(data: &[u8]) {
+    deserialize::<SerdeFlatten>(data);
+}
+
+/// Deserializes `SerdeInternallyTagged`, as received through `import_serde_internally_tagged`, `export_serde_internally_tagged`.
+pub fn fuzz_serde_internally_tagged(data: &[u8]) {
+    deserialize::<SerdeInternallyTagged>(data);
+}
+
+/// Deserializes `SerdePropertyRenaming`, as received through `import_serde_struct`, `export_serde_struct`.
+pub fn fuzz_serde_property_renaming(data: &[u8]) {
+    deserialize::<SerdePropertyRenaming>(data);
+}
+
+/// Deserializes `SerdeUntagged`, as received through `import_serde_untagged`, `export_serde_untagged`.
+pub fn fuzz_serde_untagged(data: &[u8]) {
+    deserialize::<SerdeUntagged>(data);
+}
+
+/// Deserializes `SerdeVariantRenaming`, as received through `import_serde_enum`, `export_serde_enum`.
+pub fn fuzz_serde_variant_renaming(data: &[u8]) {
+    deserialize::<SerdeVariantRenaming>(data);
+}
+
+/// Deserializes `StateUpdate`, as received through `reducer_bridge`.
+pub fn fuzz_state_update(data: &[u8]) {
+    deserialize::<StateUpdate>(data);
+}
+
+/// Deserializes `String`, as received through `import_async_void_function`, `import_experimental`, `import_get_value`, `import_multiple_primitives`, `import_rate_limited`, `import_renamed_string`, `import_set_value`, `import_string`, `invoke_command`, `log`, `record_metric`, `export_cached_call_count`, `export_experimental`, `export_lazy_string`, `export_renamed_string`, `export_string`, `export_string_after_memory_growth`.
+pub fn fuzz_string(data: &[u8]) {
+    deserialize::<String>(data);
+}
+
+/// Deserializes `StructWithGenerics<u64>`, as received through `import_generics`, `export_generics`.
+pub fn fuzz_struct_with_generics_u64(data: &[u8]) {
+    deserialize::<StructWithGenerics<u64>>(data);
+}
+
+/// Deserializes `StructWithOptions`, as received through `import_struct_with_options`, `export_struct_with_options`.
+pub fn fuzz_struct_with_options(data: &[u8]) {
+    deserialize::<StructWithOptions>(data);
+}
+
+/// Deserializes `Timestamps`, as received through `export_timestamps`.
+pub fn fuzz_timestamps(data: &[u8]) {
+    deserialize::<Timestamps>(data);
+}
+
+/// Deserializes `TypedId<Point<f64>>`, as received through `export_typed_id`.
+pub fn fuzz_typed_id_point_f64(data: &[u8]) {
+    deserialize::<TypedId<Point<f64>>>(data);
+}
+
+/// Deserializes `ValidatedStruct`, as received through `import_validated_struct`, `export_validated_struct`.
+pub fn fuzz_validated_struct(data: &[u8]) {
+    deserialize::<ValidatedStruct>(data);
+}
+
+/// Deserializes `serde_json::Value`, as received through `export_dynamic_value`.
+pub fn fuzz_value(data: &[u8]) {
+    deserialize::<serde_json::Value>(data);
+}
+
+/// Deserializes `Vec<Metric>`, as received through `export_metrics`.
+pub fn fuzz_vec_metric(data: &[u8]) {
+    deserialize::<Vec<Metric>>(data);
+}
+
+/// Deserializes `Vec<Result<String, RateLimited>>`, as received through `export_call_rate_limited_import`.
+pub fn fuzz_vec_result_string_rate_limited(data: &[u8]) {
+    deserialize::<Vec<Result<String, RateLimited>>>(data);
+}
+
+/// Deserializes `[f32; 3]`, as received through `import_array_f32`, `export_array_f32`.
+pub fn fuzz_f32_3(data: &[u8]) {
+    deserialize::<[f32; 3]>(data);
+}
+
+/// Deserializes `[f64; 3]`, as received through `import_array_f64`, `export_array_f64`.
+pub fn fuzz_f64_3(data: &[u8]) {
+    deserialize::<[f64; 3]>(data);
+}
+
+/// Deserializes `[i16; 3]`, as received through `import_array_i16`, `export_array_i16`.
+pub fn fuzz_i16_3(data: &[u8]) {
+    deserialize::<[i16; 3]>(data);
+}
+
+/// Deserializes `[i32; 3]`, as received through `import_array_i32`, `export_array_i32`.
+pub fn fuzz_i32_3(data: &[u8]) {
+    deserialize::<[i32; 3]>(data);
+}
+
+/// Deserializes `[i8; 3]`, as received through `import_array_i8`, `export_array_i8`.
+pub fn fuzz_i8_3(data: &[u8]) {
+    deserialize::<[i8; 3]>(data);
+}
+
+/// Deserializes `[u16; 3]`, as received through `import_array_u16`, `export_array_u16`.
+pub fn fuzz_u16_3(data: &[u8]) {
+    deserialize::<[u16; 3]>(data);
+}
+
+/// Deserializes `[u32; 3]`, as received through `import_array_u32`, `export_array_u32`.
+pub fn fuzz_u32_3(data: &[u8]) {
+    deserialize::<[u32; 3]>(data);
+}
+
+/// Deserializes `[u8; 3]`, as received through `import_array_u8`, `export_array_u8`.
+pub fn fuzz_u8_3(data: &[u8]) {
+    deserialize::<[u8; 3]>(data);
+}
@@ -0,0 +1,10 @@
+// ============================================= //
+// Fuzz targets for the Rust runtimes            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    example_bindings_fuzz::fuzz_validated_struct(data);
+});
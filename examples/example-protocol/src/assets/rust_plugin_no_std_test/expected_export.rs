@@ -0,0 +1,204 @@
+use alloc::{boxed::Box, collections::BTreeMap, collections::BTreeSet, collections::VecDeque, format, rc::Rc, string::String, vec::Vec};
+use crate::types::*;
+
+pub use fp_bindgen_support::guest::config::{fp_config, on_config_change};
+
+/// Called on the plugin when the host changes its config.
+///
+/// This is implemented by the bindings, which cache the config for
+/// `fp_config()` and pass it to the listeners that are registered with
+/// `on_config_change()`.
+#[doc(hidden)]
+#[no_mangle]
+pub fn __fp_gen_update_config(config: fp_bindgen_support::common::mem::FatPtr) {
+    unsafe { fp_bindgen_support::guest::config::update_config_from_host::<PluginConfig>(config) }
+}
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_array_f32(arg: [f32; 3]) -> [f32; 3];
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_array_f64(arg: [f64; 3]) -> [f64; 3];
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_array_i16(arg: [i16; 3]) -> [i16; 3];
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_array_i32(arg: [i32; 3]) -> [i32; 3];
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_array_i8(arg: [i8; 3]) -> [i8; 3];
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_array_u16(arg: [u16; 3]) -> [u16; 3];
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_array_u32(arg: [u32; 3]) -> [u32; 3];
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_array_u8(arg: [u8; 3]) -> [u8; 3];
+
+#[fp_bindgen_support::fp_export_signature]
+pub async fn export_async_struct(arg1: FpPropertyRenaming, arg2: u64) -> FpPropertyRenaming;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_batch_response(arg: BatchResponse) -> BatchResponse;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_cached_call_count(label: String) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_call_rate_limited_import(calls: u32) -> Vec<Result<String, RateLimited>>;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_collections(arg: Collections) -> Collections;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_dynamic_value(arg: ExtensionSettings) -> serde_json::Value;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_edge_values(arg: EdgeValues) -> EdgeValues;
+
+#[cfg(feature = "experimental")]
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_experimental(arg: String) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_fp_adjacently_tagged(arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_fp_enum(arg: FpVariantRenaming) -> FpVariantRenaming;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_fp_flatten(arg: FpFlatten) -> FpFlatten;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_fp_internally_tagged(arg: FpInternallyTagged) -> FpInternallyTagged;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_fp_struct(arg: FpPropertyRenaming) -> FpPropertyRenaming;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_fp_untagged(arg: FpUntagged) -> FpUntagged;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_generics(arg: StructWithGenerics<u64>) -> StructWithGenerics<u64>;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_get_bytes() -> Result<bytes::Bytes, String>;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_get_serde_bytes() -> Result<serde_bytes::ByteBuf, String>;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_lazy_string(arg: String) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_metrics(metrics: Vec<Metric>) -> Vec<Metric>;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_multiple_primitives(arg1: i8, arg2: String) -> i64;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_multiple_values(arg: u32) -> (String, Vec<u32>, bool);
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_bool(arg: bool) -> bool;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_f32(arg: f32) -> f32;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_f64(arg: f64) -> f64;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_i16(arg: i16) -> i16;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_i32(arg: i32) -> i32;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_i64(arg: i64) -> i64;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_i8(arg: i8) -> i8;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_pair(arg: u32) -> (u32, i32);
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_u16(arg: u16) -> u16;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_u32(arg: u32) -> u32;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_u64(arg: u64) -> u64;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_u8(arg: u8) -> u8;
+
+#[fp_bindgen_support::fp_export_signature(rename = "export_legacy_string")]
+pub fn export_renamed_string(arg: String) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_samples(samples: SampleColumns) -> SampleColumns;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_serde_adjacently_tagged(arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_serde_enum(arg: SerdeVariantRenaming) -> SerdeVariantRenaming;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_serde_flatten(arg: SerdeFlatten) -> SerdeFlatten;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_serde_internally_tagged(arg: SerdeInternallyTagged) -> SerdeInternallyTagged;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_serde_struct(arg: SerdePropertyRenaming) -> SerdePropertyRenaming;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_serde_untagged(arg: SerdeUntagged) -> SerdeUntagged;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_string(arg: String) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_string_after_memory_growth(arg: String) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_struct_with_options(arg: StructWithOptions) -> StructWithOptions;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_timestamp(arg: MyDateTime) -> MyDateTime;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_timestamps(arg: Timestamps) -> Timestamps;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_typed_id(id: TypedId<Point<f64>>) -> TypedId<Point<f64>>;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_validated_struct(arg: ValidatedStruct) -> ValidatedStruct;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_void_function();
+
+/// Example how plugin could expose async data-fetching capabilities.
+#[fp_bindgen_support::fp_export_signature]
+pub async fn fetch_data(r#type: String) -> Result<String, String>;
+
+/// Called on the plugin to give it a chance to initialize.
+#[fp_bindgen_support::fp_export_signature(config)]
+pub fn init(config: PluginConfig);
+
+/// Example how plugin could expose a reducer.
+#[fp_bindgen_support::fp_export_signature]
+pub fn reducer_bridge(action: ReduxAction) -> StateUpdate;
+
+/// Called on the plugin before it is discarded, to give it a chance to
+/// clean up.
+#[fp_bindgen_support::fp_export_signature]
+pub fn shutdown();
@@ -0,0 +1,32 @@
+//! Plugins that use these bindings may be compiled with the following target
+//! features, which match the WebAssembly features their runtimes are expected
+//! to enable. They can be passed through `RUSTFLAGS` or `.cargo/config.toml`:
+//!
+//! ```text
+//! -C target-feature=+simd128,+bulk-memory,+reference-types,-atomics
+//! ```
+
+#![no_std]
+#![allow(unused_imports)]
+
+extern crate alloc;
+
+#[rustfmt::skip]
+mod export;
+#[rustfmt::skip]
+mod import;
+#[rustfmt::skip]
+mod types;
+
+pub use export::*;
+pub use import::*;
+pub use types::*;
+
+pub use fp_bindgen_support::*;
+
+#[cfg(target_arch = "wasm32")]
+#[global_allocator]
+static ALLOCATOR: talc::TalckWasm = unsafe { talc::TalckWasm::new_global() };
+
+#[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+compile_error!("Plugins for this protocol must not be compiled with the `atomics` target feature");
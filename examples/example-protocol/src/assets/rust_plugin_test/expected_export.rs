@@ -1,5 +1,18 @@
 use crate::types::*;
 
+pub use fp_bindgen_support::guest::config::{fp_config, on_config_change};
+
+/// Called on the plugin when the host changes its config.
+///
+/// This is implemented by the bindings, which cache the config for
+/// `fp_config()` and pass it to the listeners that are registered with
+/// `on_config_change()`.
+#[doc(hidden)]
+#[no_mangle]
+pub fn __fp_gen_update_config(config: fp_bindgen_support::common::mem::FatPtr) {
+    unsafe { fp_bindgen_support::guest::config::update_config_from_host::<PluginConfig>(config) }
+}
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_array_f32(arg: [f32; 3]) -> [f32; 3];
 
@@ -27,6 +40,28 @@ pub fn export_array_u8(arg: [u8; 3]) -> [u8; 3];
 #[fp_bindgen_support::fp_export_signature]
 pub async fn export_async_struct(arg1: FpPropertyRenaming, arg2: u64) -> FpPropertyRenaming;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_batch_response(arg: BatchResponse) -> BatchResponse;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_cached_call_count(label: String) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_call_rate_limited_import(calls: u32) -> Vec<Result<String, RateLimited>>;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_collections(arg: Collections) -> Collections;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_dynamic_value(arg: ExtensionSettings) -> serde_json::Value;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_edge_values(arg: EdgeValues) -> EdgeValues;
+
+#[cfg(feature = "experimental")]
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_experimental(arg: String) -> String;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_fp_adjacently_tagged(arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged;
 
@@ -54,9 +89,18 @@ pub fn export_get_bytes() -> Result<bytes::Bytes, String>;
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_get_serde_bytes() -> Result<serde_bytes::ByteBuf, String>;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_lazy_string(arg: String) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_metrics(metrics: Vec<Metric>) -> Vec<Metric>;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_multiple_primitives(arg1: i8, arg2: String) -> i64;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_multiple_values(arg: u32) -> (String, Vec<u32>, bool);
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_bool(arg: bool) -> bool;
 
@@ -78,6 +122,9 @@ pub fn export_primitive_i64(arg: i64) -> i64;
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_i8(arg: i8) -> i8;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_pair(arg: u32) -> (u32, i32);
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_u16(arg: u16) -> u16;
 
@@ -90,6 +137,12 @@ pub fn export_primitive_u64(arg: u64) -> u64;
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_u8(arg: u8) -> u8;
 
+#[fp_bindgen_support::fp_export_signature(rename = "export_legacy_string")]
+pub fn export_renamed_string(arg: String) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_samples(samples: SampleColumns) -> SampleColumns;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_serde_adjacently_tagged(arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged;
 
@@ -111,12 +164,24 @@ pub fn export_serde_untagged(arg: SerdeUntagged) -> SerdeUntagged;
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_string(arg: String) -> String;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_string_after_memory_growth(arg: String) -> String;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_struct_with_options(arg: StructWithOptions) -> StructWithOptions;
 
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_timestamp(arg: MyDateTime) -> MyDateTime;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_timestamps(arg: Timestamps) -> Timestamps;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_typed_id(id: TypedId<Point<f64>>) -> TypedId<Point<f64>>;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_validated_struct(arg: ValidatedStruct) -> ValidatedStruct;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_void_function();
 
@@ -125,9 +190,14 @@ pub fn export_void_function();
 pub async fn fetch_data(r#type: String) -> Result<String, String>;
 
 /// Called on the plugin to give it a chance to initialize.
-#[fp_bindgen_support::fp_export_signature]
-pub fn init();
+#[fp_bindgen_support::fp_export_signature(config)]
+pub fn init(config: PluginConfig);
 
 /// Example how plugin could expose a reducer.
 #[fp_bindgen_support::fp_export_signature]
 pub fn reducer_bridge(action: ReduxAction) -> StateUpdate;
+
+/// Called on the plugin before it is discarded, to give it a chance to
+/// clean up.
+#[fp_bindgen_support::fp_export_signature]
+pub fn shutdown();
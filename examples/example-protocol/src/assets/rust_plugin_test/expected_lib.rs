@@ -1,3 +1,11 @@
+//! Plugins that use these bindings may be compiled with the following target
+//! features, which match the WebAssembly features their runtimes are expected
+//! to enable. They can be passed through `RUSTFLAGS` or `.cargo/config.toml`:
+//!
+//! ```text
+//! -C target-feature=+simd128,+bulk-memory,+reference-types,-atomics
+//! ```
+
 #![allow(unused_imports)]
 #[rustfmt::skip]
 mod export;
@@ -11,3 +19,6 @@ pub use import::*;
 pub use types::*;
 
 pub use fp_bindgen_support::*;
+
+#[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+compile_error!("Plugins for this protocol must not be compiled with the `atomics` target feature");
@@ -0,0 +1,5 @@
+#![allow(unused_imports)]
+#[rustfmt::skip]
+mod types;
+
+pub use types::*;
@@ -0,0 +1,899 @@
+#![allow(unused_imports)]
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, collections::BTreeSet, collections::VecDeque, rc::Rc};
+
+pub use redux_example::ReduxAction;
+pub use redux_example::StateUpdate;
+
+/// Example of numbers that cannot be represented as `f64` without losing
+/// precision.
+///
+/// They are exchanged as strings, and typed as branded strings in TypeScript.
+/// Requires the `rust-decimal-compat` and `num-bigint-compat` features.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ArbitraryPrecisionNumbers {
+    #[serde(with = "fp_bindgen_support::common::serde_str")]
+    pub amount: rust_decimal::Decimal,
+    #[serde(with = "fp_bindgen_support::common::serde_str")]
+    pub total_supply: num_bigint::BigInt,
+}
+
+/// The response to a batch of operations, some of which may have failed.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResponse {
+    pub results: Vec<OperationResult>,
+    pub summary: Result<String, String>,
+    pub retry_after: Option<Result<u32, String>>,
+}
+
+pub type Body = serde_bytes::ByteBuf;
+
+/// Std collections that are encoded as lists.
+///
+/// Sets are generated as `Set` types in TypeScript if `generate_set_types` is
+/// enabled, while other lists are always generated as arrays.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Collections {
+    pub tags: BTreeSet<String>,
+    pub optional_tags: Option<BTreeSet<String>>,
+    pub queue: VecDeque<u32>,
+}
+
+/// Fields and variants behind a `#[cfg(...)]` attribute are only included in
+/// the bindings if the protocol is compiled with the condition enabled.
+///
+/// Using `#[fp(generators = "...")]`, fields and variants can also be limited
+/// to the bindings of specific generators.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ConditionalFields {
+    pub name: String,
+
+    /// Only hosts and plugins that are written in Rust know about this field,
+    /// so it needs to be optional.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backtrace: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ConditionalVariants {
+    Stable,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CustomSerializers {
+    /// Serialized as a Unix timestamp, instead of the RFC3339 string that is
+    /// used for `OffsetDateTime` by default.
+    #[serde(with = "time::serde::timestamp")]
+    pub timestamp: time::OffsetDateTime,
+}
+
+/// # This is an enum with doc comments.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum DocExampleEnum {
+    /// Multi-line doc comment with complex characters
+    /// & " , \ ! '
+    Variant1(String),
+    /// Raw identifiers are supported too.
+    r#Variant2 {
+        /// Variant property.
+        inner: i8,
+    },
+}
+
+/// # This is a struct with doc comments.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DocExampleStruct {
+    /// Multi-line doc comment with complex characters
+    /// & " , \ ! '
+    pub multi_line: String,
+
+    /// Raw identifiers are supported too.
+    pub r#type: String,
+}
+
+/// Example of values at the edges of what the runtimes can represent.
+///
+/// `char` is exchanged as a single-character string, 128-bit integers are
+/// exchanged as decimal strings, and `f32` keeps NaN and infinities intact.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EdgeValues {
+    pub character: char,
+    pub float: f32,
+    #[serde(with = "fp_bindgen_support::common::serde_str")]
+    pub signed: i128,
+    #[serde(with = "fp_bindgen_support::common::serde_str")]
+    pub unsigned: u128,
+}
+
+/// A point of an arbitrary type, with explicit trait bounds.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ExplicitBoundPoint<T: std::fmt::Debug + std::fmt::Display> {
+    pub value: T,
+}
+
+/// This struct is also not referenced by any function or data structure, but
+/// it will show up because there is an explicit `use` statement for it in the
+/// `fp_import!` macro.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ExplicitedlyImportedType {
+    pub you_will_see_this: bool,
+}
+
+/// Settings for an extension whose shape is only known to the extension
+/// itself.
+///
+/// `serde_json::Value` (and `rmpv::Value`) can be used anywhere in a protocol
+/// to pass values of arbitrary shape. Both are encoded as plain MessagePack,
+/// and show up as `unknown` in the TypeScript bindings.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ExtensionSettings {
+    pub extension: String,
+    pub settings: serde_json::Value,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FlattenedStruct {
+    pub foo: String,
+    pub bar: i64,
+}
+
+pub type FloatingPoint = Point<f64>;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum FpAdjacentlyTagged {
+    Foo,
+    #[serde(alias = "Qux")]
+    Bar(String),
+    Baz { a: i8, b: u64 },
+    Pair(i8, String),
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FpFlatten {
+    #[serde(flatten)]
+    pub flattened: FlattenedStruct,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum FpInternallyTagged {
+    Foo,
+    Baz { a: i8, b: u64 },
+    /// A variant that was added after these bindings were generated, with its
+    /// serialized value.
+    #[serde(untagged)]
+    Unknown(fp_bindgen_support::common::non_exhaustive::Value),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FpPropertyRenaming {
+    pub foo_bar: String,
+    #[serde(rename = "QUX_BAZ")]
+    pub qux_baz: f64,
+    pub r#raw_struct: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum FpUntagged {
+    Bar(String),
+    Baz { a: i8, b: u64 },
+    Pair(i8, String),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FpVariantRenaming {
+    FooBar,
+    #[serde(rename = "QUX_BAZ", rename_all = "SCREAMING_SNAKE_CASE")]
+    QuxBaz {
+        /// Will be renamed to "FOO_BAR" because of the `rename_all` on the
+        /// variant.
+        foo_bar: String,
+        #[serde(rename = "qux_baz")]
+        qux_baz: f64,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GroupImportedType1 {
+    pub you_will_see_this: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GroupImportedType2 {
+    pub you_will_see_this: bool,
+}
+
+pub type HttpResult = Result<Response, RequestError>;
+
+pub type Int64 = u64;
+
+/// Example of integer types that are not primitives in the protocol.
+///
+/// `usize` and `isize` are exchanged as `u32` and `i32`, because that is their
+/// size on `wasm32`. `NonZero` types refuse to deserialize when they are zero.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IntegerTypes {
+    pub count: u32,
+    pub offset: i32,
+    pub id: std::num::NonZeroU32,
+    pub delta: std::num::NonZeroI64,
+    pub capacity: Option<std::num::NonZeroU32>,
+    pub user_id: UserId,
+}
+
+/// A measurement that is reported by the plugin.
+///
+/// Because the `name` field is annotated with `#[fp(interned)]`, the plugin
+/// registers every distinct name with the runtime once, and sends a `u32`
+/// handle in its place from then on. This keeps the payloads small when the
+/// same few names are reported over and over.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Metric {
+    #[serde(with = "fp_bindgen_support::common::interning")]
+    pub name: String,
+    pub value: f64,
+}
+
+/// Our struct for passing date time instances.
+///
+/// We wrap the `OffsetDateTime` type in a new struct so that the Serde
+/// attributes can be inserted. These are necessary to enable RFC3339
+/// formatting. Without a wrapper type like this, we would not be able to pass
+/// date time instances directly to function arguments and we might run into
+/// trouble embedding them into certain generic types.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MyDateTime(
+    #[serde(with = "time::serde::rfc3339")]
+    pub time::OffsetDateTime,
+);
+
+pub type OperationResult = Result<u64, String>;
+
+/// Config that is passed to the `init` export when the plugin is instantiated.
+///
+/// Every protocol has `init` and `shutdown` exports, but protocols can declare
+/// `init` themselves to have it take a config argument like this one.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginConfig {
+    pub log_level: String,
+}
+
+/// A point of an arbitrary type.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Point<T> {
+    pub value: T,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// Handled after everything else.
+    Low,
+    Normal,
+    #[serde(rename = "urgent")]
+    High,
+}
+
+/// Emitted by the plugin while it works on a long-running task.
+///
+/// See the `report_progress` event in `main.rs` for more info.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Progress {
+    pub task: String,
+    pub completed_steps: u32,
+    pub total_steps: u32,
+}
+
+/// Error that is returned to the plugin when it calls a rate-limited import
+/// more often than its limit allows.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimited {
+    /// The name of the import that was called.
+    pub function: String,
+
+    /// The number of milliseconds after which the import may be called again.
+    pub retry_after_ms: u32,
+}
+
+/// Represents an HTTP request to be sent.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Request {
+    /// The URI to submit the request to.
+    #[serde(deserialize_with = "fp_bindgen_support::http::deserialize_uri", serialize_with = "fp_bindgen_support::http::serialize_uri")]
+    pub url: http::Uri,
+
+    /// HTTP method to use for the request.
+    #[serde(deserialize_with = "fp_bindgen_support::http::deserialize_http_method", serialize_with = "fp_bindgen_support::http::serialize_http_method")]
+    pub method: http::Method,
+
+    /// HTTP headers to submit with the request.
+    #[serde(deserialize_with = "fp_bindgen_support::http::deserialize_header_map", serialize_with = "fp_bindgen_support::http::serialize_header_map")]
+    pub headers: http::HeaderMap,
+
+    /// The body to submit with the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Body>,
+}
+
+/// Represents an error that occurred while attempting to submit the request.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RequestError {
+    /// Used when we know we don't have an active network connection.
+    Offline,
+    NoRoute,
+    ConnectionRefused,
+    Timeout,
+    #[serde(rename_all = "snake_case")]
+    ServerError {
+        /// HTTP status code.
+        status_code: u16,
+
+        /// Response body.
+        response: Body,
+    },
+    /// Misc.
+    #[serde(rename = "other/misc")]
+    Other { reason: String },
+}
+
+/// Represents an HTTP response we received.
+///
+/// Please note we currently do not support streaming responses.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Response {
+    /// The response body. May be empty.
+    pub body: Body,
+
+    /// HTTP headers that were part of the response.
+    #[serde(deserialize_with = "fp_bindgen_support::http::deserialize_header_map", serialize_with = "fp_bindgen_support::http::serialize_header_map")]
+    pub headers: http::HeaderMap,
+
+    /// HTTP status code.
+    pub status_code: u16,
+}
+
+/// A sample of a time series.
+///
+/// Because the struct is annotated with `#[fp(columnar)]`, every `Vec<Sample>`
+/// in the protocol is replaced with a generated `SampleColumns` struct, which
+/// holds a column with the values of every field. In TypeScript, the columns
+/// are typed arrays, so large lists of samples can be passed without encoding
+/// an object for every item.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Sample {
+    pub timestamp_ms: u64,
+    pub value: f64,
+    pub quality: u8,
+}
+
+/// The columns of a list of `Sample`, with the values of every field stored contiguously.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SampleColumns {
+    #[serde(with = "fp_bindgen_support::common::columnar")]
+    pub timestamp_ms: Vec<u64>,
+    #[serde(with = "fp_bindgen_support::common::columnar")]
+    pub value: Vec<f64>,
+    #[serde(with = "fp_bindgen_support::common::columnar")]
+    pub quality: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum SerdeAdjacentlyTagged {
+    Foo,
+    Bar(String),
+    Baz { a: i8, b: u64 },
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SerdeFlatten {
+    #[serde(flatten)]
+    pub flattened: FlattenedStruct,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum SerdeInternallyTagged {
+    Foo,
+    Baz { a: i8, b: u64 },
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerdePropertyRenaming {
+    pub foo_bar: String,
+    #[serde(rename = "QUX_BAZ")]
+    pub qux_baz: f64,
+    pub r#raw_struct: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum SerdeUntagged {
+    Bar(String),
+    Baz { a: i8, b: u64 },
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SerdeVariantRenaming {
+    FooBar,
+    #[serde(rename = "QUX_BAZ", rename_all = "PascalCase")]
+    QuxBaz {
+        /// Will be renamed to "FooBar" because of the `rename_all` on the
+        /// variant.
+        foo_bar: String,
+        #[serde(rename = "qux_baz")]
+        qux_baz: f64,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithGenerics<T> {
+    pub list: Vec<T>,
+    pub points: Vec<Point<T>>,
+    pub recursive: Vec<Point<Point<T>>>,
+    pub complex_nested: Option<BTreeMap<String, Vec<FloatingPoint>>>,
+    pub optional_timestamp: Option<MyDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructWithOptions {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub filled_string: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub empty_string: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filled_option_string: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub empty_option_string: Option<String>,
+    #[serde(default)]
+    pub never_skipped_filled_option_string: Option<String>,
+    #[serde(default)]
+    pub never_skipped_empty_option_string: Option<String>,
+}
+
+/// Timestamps that the TypeScript runtime represents as `Date` objects.
+///
+/// Fields that are annotated with `ts_as = "Date"` are still serialized as
+/// RFC3339 strings, but the TypeScript runtime converts them into `Date`
+/// objects when they are received, and back into strings when they are sent.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timestamps {
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: time::OffsetDateTime,
+    pub deleted_at: Option<time::OffsetDateTime>,
+}
+
+/// An identifier of a resource of type `T`.
+///
+/// `PhantomData` fields are not serialized, so they only show up in the Rust
+/// bindings, where they keep the type parameter in use. Other bindings only
+/// preserve the type parameter in the signature of the type.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct TypedId<T> {
+    pub id: u64,
+    #[serde(skip)]
+    pub resource: std::marker::PhantomData<T>,
+}
+
+/// Example of a newtype that can be generated as a branded type in TypeScript.
+///
+/// With `generate_branded_types`, it is typed as
+/// `number & { readonly __brand: "UserId" }`, so that it cannot be mixed up
+/// with other numbers.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UserId(pub u32,);
+
+/// Structs that contain validated structs get validated as a whole.
+///
+/// Fields that are not part of this struct are rejected, rather than silently
+/// ignored.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatedChild {
+    #[serde(deserialize_with = "validate_validated_child_name")]
+    pub name: String,
+}
+
+/// Fields with `#[fp(validate(...))]` attributes are validated when they are
+/// received from the other side, so invalid values are rejected right away.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatedStruct {
+    #[serde(deserialize_with = "validate_validated_struct_percentage")]
+    pub percentage: u8,
+    #[serde(deserialize_with = "validate_validated_struct_slug")]
+    pub slug: String,
+    #[serde(default, deserialize_with = "validate_validated_struct_ratio")]
+    pub ratio: Option<f64>,
+    #[serde(deserialize_with = "validate_validated_struct_children")]
+    pub children: Vec<ValidatedChild>,
+}
+
+fn validate_validated_child_name<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let result: String = Deserialize::deserialize(deserializer)?;
+    let value = &result;
+    if value.is_empty() {
+        return Err(serde::de::Error::custom("`ValidatedChild.name` must not be empty"));
+    }
+    Ok(result)
+}
+
+fn validate_validated_struct_percentage<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u8, D::Error> {
+    let result: u8 = Deserialize::deserialize(deserializer)?;
+    let value = &result;
+    if !(0..=100).contains(value) {
+        return Err(serde::de::Error::custom(format!("`ValidatedStruct.percentage` must be in range 0..=100, got {}", value)));
+    }
+    Ok(result)
+}
+
+fn validate_validated_struct_slug<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let result: String = Deserialize::deserialize(deserializer)?;
+    let value = &result;
+    if value.is_empty() {
+        return Err(serde::de::Error::custom("`ValidatedStruct.slug` must not be empty"));
+    }
+    if !fp_bindgen_support::common::validation::is_match(value, "^[a-z][a-z0-9-]*$") {
+        return Err(serde::de::Error::custom("`ValidatedStruct.slug` must match the regular expression `^[a-z][a-z0-9-]*$`"));
+    }
+    Ok(result)
+}
+
+fn validate_validated_struct_ratio<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    let result: Option<f64> = Deserialize::deserialize(deserializer)?;
+    if let Some(value) = &result {
+        if !(0.0..1.0).contains(value) {
+            return Err(serde::de::Error::custom(format!("`ValidatedStruct.ratio` must be in range 0..1, got {}", value)));
+        }
+    }
+    Ok(result)
+}
+
+fn validate_validated_struct_children<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<ValidatedChild>, D::Error> {
+    let result: Vec<ValidatedChild> = Deserialize::deserialize(deserializer)?;
+    let value = &result;
+    if value.is_empty() {
+        return Err(serde::de::Error::custom("`ValidatedStruct.children` must not be empty"));
+    }
+    Ok(result)
+}
+
+impl ArbitraryPrecisionNumbers {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "db827ee0c172d54b";
+}
+
+impl BatchResponse {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "d01a07281c913349";
+}
+
+impl Collections {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "0c2e6754c3da68fb";
+}
+
+impl ConditionalFields {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "52a7d135f4e752db";
+}
+
+impl ConditionalVariants {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "ad94250852394a2c";
+}
+
+impl CustomSerializers {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "3995cbdfb49e6e86";
+}
+
+impl DocExampleEnum {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "8a247500e246a101";
+}
+
+impl DocExampleStruct {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "4d63c33e3821db51";
+}
+
+impl EdgeValues {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "3a668df6dcc030ca";
+}
+
+impl<T: std::fmt::Debug + std::fmt::Display> ExplicitBoundPoint<T> {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "01ab567ecc0d57ce";
+}
+
+impl ExplicitedlyImportedType {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "870486c71032cd06";
+}
+
+impl ExtensionSettings {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "dd7f1ec765e3177b";
+}
+
+impl FlattenedStruct {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "3309edb927ca179e";
+}
+
+impl FpAdjacentlyTagged {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "606232f618d7e394";
+}
+
+impl FpFlatten {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "46a49ef4fae36d25";
+}
+
+impl FpInternallyTagged {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "5f9268fae07343f1";
+}
+
+impl FpPropertyRenaming {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "9ceeeeb504a46f92";
+}
+
+impl FpUntagged {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "17e08f97b5746b44";
+}
+
+impl FpVariantRenaming {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "874c2a453f4dee88";
+}
+
+impl GroupImportedType1 {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "870486c71032cd06";
+}
+
+impl GroupImportedType2 {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "870486c71032cd06";
+}
+
+impl IntegerTypes {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "28d6bbb19affb584";
+}
+
+impl Metric {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "4b59598250e00632";
+}
+
+impl MyDateTime {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "909e6a1dd3c1b75c";
+}
+
+impl PluginConfig {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "058a22f99a2bf4c8";
+}
+
+impl<T> Point<T> {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "01ab567ecc0d57ce";
+}
+
+impl Priority {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "4e502f2d76067ab7";
+}
+
+impl Progress {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "78c3c560d6cc3996";
+}
+
+impl RateLimited {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "53e94d7f4ad4119a";
+}
+
+impl Request {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "f5b3e2e3135a53a4";
+}
+
+impl RequestError {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "52827bfb0a750040";
+}
+
+impl Response {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "600ce53352d19a88";
+}
+
+impl Sample {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "6abd8e557be778d8";
+}
+
+impl SerdeAdjacentlyTagged {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "d8c14e6783129ce6";
+}
+
+impl SerdeFlatten {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "46a49ef4fae36d25";
+}
+
+impl SerdeInternallyTagged {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "5f9268fae07343f1";
+}
+
+impl SerdePropertyRenaming {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "9ceeeeb504a46f92";
+}
+
+impl SerdeUntagged {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "4b76b5be929dd50f";
+}
+
+impl SerdeVariantRenaming {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "a60f3b2a9bc7e9ab";
+}
+
+impl<T> StructWithGenerics<T> {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "b93cc0c519e88f8a";
+}
+
+impl StructWithOptions {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "bd9f146b3efd1e10";
+}
+
+impl Timestamps {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "585a6e28d555d1e8";
+}
+
+impl<T> TypedId<T> {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "9ef66fb446ba7f9c";
+}
+
+impl UserId {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "b880dc810346cbbd";
+}
+
+impl ValidatedChild {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "7d2ecd620ba22cb3";
+}
+
+impl ValidatedStruct {
+    /// Hash of the wire format of this type, which changes whenever values
+    /// serialized with an earlier version of the type may no longer be
+    /// deserialized.
+    pub const SCHEMA_HASH: &'static str = "229c94022f7b70c3";
+}
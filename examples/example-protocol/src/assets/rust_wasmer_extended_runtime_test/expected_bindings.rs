@@ -0,0 +1,7560 @@
+use super::types::*;
+use fp_bindgen_support::common::signatures::SignatureVerifier;
+use fp_bindgen_support::host::deadline::{
+    deadline_remaining, enter_guest_deadline, export_deadline, WithDeadline,
+};
+use fp_bindgen_support::host::exports::resolve_export;
+use fp_bindgen_support::host::http_imports::{http_request, HttpImports};
+use fp_bindgen_support::host::overrides::{ImportOverrides, OverrideFuture};
+use fp_bindgen_support::host::payload_trace::{
+    trace_guest_payload, trace_payload, PayloadDirection, PayloadTracer,
+};
+use fp_bindgen_support::host::r#async::limit::ConcurrencyLimit;
+use fp_bindgen_support::host::routes::{check_route, RawExports, RouteError};
+use fp_bindgen_support::host::std_imports::{
+    std_monotonic_now, std_now, std_random_bytes, std_read_file, std_write_file, StdImports,
+};
+use fp_bindgen_support::host::trace_context::{
+    export_span, import_span, trace_context, Instrument,
+};
+use fp_bindgen_support::{
+    common::stdio::OutputStream,
+    host::stdio::{host_print, OutputHandler},
+};
+use fp_bindgen_support::{
+    common::{abi::WasmAbi, lazy::Lazy, mem::FatPtr, wasm_features::WasmFeatures},
+    host::{
+        errors::{guest_error, GuestError, InvocationError, PermissionDenied, RuntimeError},
+        lifecycle::ShutdownGuard,
+        mem::{
+            deserialize_from_slice, export_to_guest, export_to_guest_raw, import_from_guest,
+            import_from_guest_raw, serialize_to_vec, try_deserialize_from_slice,
+            try_export_to_guest, try_export_to_guest_raw, try_import_from_guest_raw,
+        },
+        r#async::{create_future_value, future::ModuleRawFuture, resolve_async_value},
+        runtime::RuntimeInstanceData,
+    },
+};
+use std::{cell::RefCell, sync::Arc};
+use wasmer::{
+    imports, Function, ImportObject, Instance, Module, NativeFunc, Store, WasmTypeList, WasmerEnv,
+};
+
+#[derive(Clone)]
+pub struct Runtime {
+    instance: Instance,
+    env: RuntimeInstanceData,
+    exports: Arc<ResolvedExports>,
+    async_limit: Option<ConcurrencyLimit>,
+    /// Calls the `shutdown` export of the plugin once the last clone of the
+    /// runtime is dropped.
+    _shutdown_guard: Arc<ShutdownGuard>,
+}
+
+impl Runtime {
+    /// Creates a runtime that does not grant any capabilities to the plugin.
+    pub fn new(wasm_module: impl AsRef<[u8]>) -> Result<Self, RuntimeError> {
+        Self::with_capabilities(wasm_module, &[])
+    }
+
+    /// Creates a runtime that grants the given capabilities to the plugin.
+    ///
+    /// Calls to imports that require a capability that was not granted fail
+    /// with `InvocationError::PermissionDenied`.
+    pub fn with_capabilities(
+        wasm_module: impl AsRef<[u8]>,
+        capabilities: &[Capability],
+    ) -> Result<Self, RuntimeError> {
+        let store = Self::default_store();
+        let module = Module::new(&store, wasm_module)?;
+        Self::from_module(
+            &module,
+            RuntimeInstanceData::default()
+                .with_capabilities(capabilities.iter().map(Capability::as_str)),
+        )
+    }
+
+    fn from_module(module: &Module, mut env: RuntimeInstanceData) -> Result<Self, RuntimeError> {
+        let import_object = create_import_object(module.store(), &env);
+        let instance = Instance::new(module, &import_object)?;
+        env.init_with_instance(&instance).unwrap();
+        env.negotiate_async_protocol();
+        let exports = Arc::new(ResolvedExports::resolve(&instance)?);
+        let shutdown_guard = Arc::new(ShutdownGuard::new(instance.clone()));
+        Ok(Self {
+            instance,
+            env,
+            exports,
+            _shutdown_guard: shutdown_guard,
+            async_limit: None,
+        })
+    }
+
+    /// Returns the exports of the protocol that the plugin does not export.
+    ///
+    /// All exports are resolved and checked against the protocol when the
+    /// runtime is created, so this allows hosts to log which capabilities a
+    /// plugin lacks at load time. Calling a missing export fails with
+    /// `InvocationError::FunctionNotExported`.
+    pub fn missing_exports(&self) -> &[PluginExport] {
+        &self.exports.missing
+    }
+
+    /// Creates a runtime for the given module, which is loaded from the given
+    /// cache if it was compiled before. The runtime does not grant any
+    /// capabilities to the plugin.
+    ///
+    /// Modules that are not cached yet are compiled and written to the cache,
+    /// so that subsequent runtimes for the same module can skip compilation.
+    pub fn with_module_cache(
+        wasm_module: impl AsRef<[u8]>,
+        cache: &fp_bindgen_support::host::cache::ModuleCache,
+    ) -> Result<Self, RuntimeError> {
+        let module = cache.load(&Self::default_store(), wasm_module.as_ref())?;
+        Self::from_module(&module, RuntimeInstanceData::default())
+    }
+
+    /// Creates a runtime for the given module, after verifying its signature
+    /// using the given verifier. The runtime does not grant any capabilities
+    /// to the plugin.
+    ///
+    /// Modules that are unsigned or whose signature is invalid are rejected
+    /// with `RuntimeError::InvalidSignature` before they are compiled.
+    pub fn with_signature_verifier(
+        wasm_module: impl AsRef<[u8]>,
+        verifier: &SignatureVerifier,
+    ) -> Result<Self, RuntimeError> {
+        verifier.verify(wasm_module.as_ref())?;
+        Self::new(wasm_module)
+    }
+
+    /// Limits the number of async exports that may be invoked concurrently.
+    ///
+    /// Up to `max_concurrent` invocations run at the same time, while up to
+    /// `max_queued` further invocations wait for their turn, in the order in
+    /// which they were made. Invocations beyond that fail with
+    /// `InvocationError::Overloaded`.
+    ///
+    /// The limit is shared with clones of this runtime, since they use the
+    /// same instance.
+    pub fn with_async_concurrency_limit(
+        mut self,
+        max_concurrent: usize,
+        max_queued: usize,
+    ) -> Self {
+        self.async_limit = Some(ConcurrencyLimit::new(max_concurrent, max_queued));
+        self
+    }
+
+    /// Routes the calls that the plugin makes to the given import to the
+    /// export of the same name of another plugin.
+    ///
+    /// Arguments and results are passed on in their serialized form. Only
+    /// synchronous imports that are not dynamic, batchable or generic can be
+    /// routed, and the export needs to have the same signature. Calls to the
+    /// import fail with `InvocationError::RoutedImportFailed` if the export
+    /// fails. Routing an import that was already routed replaces its route.
+    pub fn route_import(
+        &self,
+        name: &str,
+        target: impl RawExports + 'static,
+    ) -> Result<(), RouteError> {
+        check_route(name, routable_import_signature(name), &target)?;
+        self.env.set_import_route(name, std::sync::Arc::new(target));
+        Ok(())
+    }
+
+    /// Removes the route of the given import, so that its calls are handled by
+    /// the host again.
+    ///
+    /// Returns whether the import was routed.
+    pub fn unroute_import(&self, name: &str) -> bool {
+        self.env.remove_import_route(name)
+    }
+
+    fn default_store() -> wasmer::Store {
+        Self::store_with_features(WasmFeatures::default())
+    }
+
+    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+    fn store_with_features(features: WasmFeatures) -> wasmer::Store {
+        let compiler = wasmer::Cranelift::default();
+        let engine = wasmer::Universal::new(compiler)
+            .features(features.into())
+            .engine();
+        Store::new(&engine)
+    }
+
+    #[cfg(not(any(target_arch = "arm", target_arch = "aarch64")))]
+    fn store_with_features(features: WasmFeatures) -> wasmer::Store {
+        let compiler = wasmer::Singlepass::default();
+        let engine = wasmer::Universal::new(compiler)
+            .features(features.into())
+            .engine();
+        Store::new(&engine)
+    }
+
+    /// Returns statistics about the memory that is allocated by the plugin.
+    ///
+    /// This is only supported for plugins that are built with the
+    /// `memory-stats` feature of `fp-bindgen-support`. For other plugins, an
+    /// `InvocationError::FunctionNotExported` error is returned.
+    pub fn memory_stats(
+        &self,
+    ) -> Result<fp_bindgen_support::common::mem::MemoryStats, InvocationError> {
+        let function = self
+            .instance
+            .exports
+            .get_native_function::<(), FatPtr>("__fp_memory_stats")
+            .map_err(|error| InvocationError::from_export_error("__fp_memory_stats", error))?;
+        let result = function.call()?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        Ok(deserialize_from_slice(&result))
+    }
+
+    /// Returns metrics about the calls that the plugin has made to imports,
+    /// as measured by the plugin itself.
+    ///
+    /// This is only supported for plugins with bindings that are generated
+    /// with `guest_metrics` enabled. For other plugins, an
+    /// `InvocationError::FunctionNotExported` error is returned.
+    pub fn guest_metrics(
+        &self,
+    ) -> Result<fp_bindgen_support::common::mem::GuestMetrics, InvocationError> {
+        let function = self
+            .instance
+            .exports
+            .get_native_function::<(), FatPtr>("__fp_get_guest_metrics")
+            .map_err(|error| InvocationError::from_export_error("__fp_get_guest_metrics", error))?;
+        let result = function.call()?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        Ok(deserialize_from_slice(&result))
+    }
+
+    /// Returns a machine-readable description of the protocol that this
+    /// runtime was generated for, as JSON.
+    ///
+    /// The description contains the signatures of all imported and exported
+    /// functions and the types they use, in the format that is parsed by
+    /// `fp_bindgen::Protocol::from_json()`. Note that plugins may omit
+    /// exports, in which case calling them fails with
+    /// `InvocationError::FunctionNotExported`.
+    pub fn protocol() -> &'static str {
+        PROTOCOL
+    }
+
+    pub fn export_array_f32(&self, arg: [f32; 3]) -> Result<[f32; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_f32_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_f32", "[f32; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayF32, error))
+    }
+    pub fn export_array_f32_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_array_f32",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_array_f32.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_array_f32".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_array_f32");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_array_f32",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_array_f64(&self, arg: [f64; 3]) -> Result<[f64; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_f64_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_f64", "[f64; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayF64, error))
+    }
+    pub fn export_array_f64_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_array_f64",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_array_f64.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_array_f64".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_array_f64");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_array_f64",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_array_i16(&self, arg: [i16; 3]) -> Result<[i16; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_i16_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_i16", "[i16; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayI16, error))
+    }
+    pub fn export_array_i16_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_array_i16",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_array_i16.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_array_i16".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_array_i16");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_array_i16",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_array_i32(&self, arg: [i32; 3]) -> Result<[i32; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_i32_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_i32", "[i32; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayI32, error))
+    }
+    pub fn export_array_i32_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_array_i32",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_array_i32.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_array_i32".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_array_i32");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_array_i32",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_array_i8(&self, arg: [i8; 3]) -> Result<[i8; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_i8_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_i8", "[i8; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayI8, error))
+    }
+    pub fn export_array_i8_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_array_i8",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_array_i8.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_array_i8".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_array_i8");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_array_i8",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_array_u16(&self, arg: [u16; 3]) -> Result<[u16; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_u16_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_u16", "[u16; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayU16, error))
+    }
+    pub fn export_array_u16_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_array_u16",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_array_u16.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_array_u16".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_array_u16");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_array_u16",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_array_u32(&self, arg: [u32; 3]) -> Result<[u32; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_u32_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_u32", "[u32; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayU32, error))
+    }
+    pub fn export_array_u32_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_array_u32",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_array_u32.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_array_u32".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_array_u32");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_array_u32",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_array_u8(&self, arg: [u8; 3]) -> Result<[u8; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_u8_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_u8", "[u8; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayU8, error))
+    }
+    pub fn export_array_u8_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_array_u8",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_array_u8.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_array_u8".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_array_u8");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_array_u8",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub async fn export_async_struct(
+        &self,
+        arg1: FpPropertyRenaming,
+        arg2: u64,
+    ) -> Result<FpPropertyRenaming, PluginError> {
+        let arg1 = serialize_to_vec(&arg1);
+        let result = self.export_async_struct_raw(arg1, arg2);
+        let result = result.await;
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_async_struct", "FpPropertyRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportAsyncStruct, error))
+    }
+    pub async fn export_async_struct_raw(
+        &self,
+        arg1: impl AsRef<[u8]>,
+        arg2: u64,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let _permit = match &self.async_limit {
+            Some(limit) => Some(limit.acquire()?.await),
+            None => None,
+        };
+        let arg1 = try_export_to_guest_raw(&self.env, arg1)?;
+        trace_guest_payload(
+            &self.env,
+            "export_async_struct",
+            "arg1",
+            PayloadDirection::ToPlugin,
+            arg1,
+        );
+        let function = self.exports.export_async_struct.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_async_struct".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_async_struct");
+        let result = span.in_scope(|| function.call(arg1.to_abi(), arg2.to_abi()))?;
+        let result = ModuleRawFuture::new(self.env.clone(), result)
+            .instrument(span)
+            .await?;
+        trace_payload(
+            &self.env,
+            "export_async_struct",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_batch_response(&self, arg: BatchResponse) -> Result<BatchResponse, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_batch_response_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_batch_response", "BatchResponse")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportBatchResponse, error))
+    }
+    pub fn export_batch_response_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_batch_response",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_batch_response.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_batch_response".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_batch_response");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_batch_response",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_cached_call_count(&self, label: String) -> Result<String, PluginError> {
+        let cache_key = serialize_to_vec(&(&label,));
+        let label = serialize_to_vec(&label);
+        let result = match self
+            .env
+            .export_cache()
+            .get("export_cached_call_count", &cache_key)
+        {
+            Some(result) => Ok(result),
+            None => {
+                let result = self.export_cached_call_count_raw(label);
+                if let Ok(result) = &result {
+                    self.env.export_cache().insert(
+                        "export_cached_call_count",
+                        cache_key,
+                        result.clone(),
+                        std::time::Duration::from_millis(30000),
+                    );
+                }
+                result
+            }
+        };
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_cached_call_count", "String")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportCachedCallCount, error))
+    }
+    pub fn export_cached_call_count_raw(
+        &self,
+        label: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let label = try_export_to_guest_raw(&self.env, label)?;
+        trace_guest_payload(
+            &self.env,
+            "export_cached_call_count",
+            "label",
+            PayloadDirection::ToPlugin,
+            label,
+        );
+        let function = self
+            .exports
+            .export_cached_call_count
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported("__fp_gen_export_cached_call_count".to_owned())
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_cached_call_count");
+        let result = span.in_scope(|| function.call(label.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_cached_call_count",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_call_rate_limited_import(
+        &self,
+        calls: u32,
+    ) -> Result<Vec<Result<String, RateLimited>>, PluginError> {
+        let result = self.export_call_rate_limited_import_raw(calls);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(
+                data,
+                "export_call_rate_limited_import",
+                "Vec<Result<String, RateLimited>>",
+            )
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportCallRateLimitedImport, error))
+    }
+    pub fn export_call_rate_limited_import_raw(
+        &self,
+        calls: u32,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let function = self
+            .exports
+            .export_call_rate_limited_import
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported(
+                    "__fp_gen_export_call_rate_limited_import".to_owned(),
+                )
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_call_rate_limited_import");
+        let result = span.in_scope(|| function.call(calls.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_call_rate_limited_import",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_collections(&self, arg: Collections) -> Result<Collections, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_collections_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_collections", "Collections")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportCollections, error))
+    }
+    pub fn export_collections_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_collections",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_collections.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_collections".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_collections");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_collections",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_dynamic_value(
+        &self,
+        arg: ExtensionSettings,
+    ) -> Result<serde_json::Value, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_dynamic_value_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_dynamic_value", "serde_json::Value")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportDynamicValue, error))
+    }
+    pub fn export_dynamic_value_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_dynamic_value",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_dynamic_value.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_dynamic_value".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_dynamic_value");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_dynamic_value",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_edge_values(&self, arg: EdgeValues) -> Result<EdgeValues, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_edge_values_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_edge_values", "EdgeValues")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportEdgeValues, error))
+    }
+    pub fn export_edge_values_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_edge_values",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_edge_values.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_edge_values".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_edge_values");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_edge_values",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    #[cfg(feature = "experimental")]
+    pub fn export_experimental(&self, arg: String) -> Result<String, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_experimental_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_experimental", "String"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportExperimental, error))
+    }
+    #[cfg(feature = "experimental")]
+    pub fn export_experimental_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_experimental",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_experimental.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_experimental".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_experimental");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_experimental",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_fp_adjacently_tagged(
+        &self,
+        arg: FpAdjacentlyTagged,
+    ) -> Result<FpAdjacentlyTagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_adjacently_tagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_adjacently_tagged", "FpAdjacentlyTagged")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpAdjacentlyTagged, error))
+    }
+    pub fn export_fp_adjacently_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_fp_adjacently_tagged",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self
+            .exports
+            .export_fp_adjacently_tagged
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported(
+                    "__fp_gen_export_fp_adjacently_tagged".to_owned(),
+                )
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_fp_adjacently_tagged");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_fp_adjacently_tagged",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_fp_enum(&self, arg: FpVariantRenaming) -> Result<FpVariantRenaming, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_enum_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_enum", "FpVariantRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpEnum, error))
+    }
+    pub fn export_fp_enum_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_fp_enum",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_fp_enum.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_fp_enum".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_fp_enum");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_fp_enum",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_fp_flatten(&self, arg: FpFlatten) -> Result<FpFlatten, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_flatten_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_flatten", "FpFlatten")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpFlatten, error))
+    }
+    pub fn export_fp_flatten_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_fp_flatten",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_fp_flatten.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_fp_flatten".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_fp_flatten");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_fp_flatten",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_fp_internally_tagged(
+        &self,
+        arg: FpInternallyTagged,
+    ) -> Result<FpInternallyTagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_internally_tagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_internally_tagged", "FpInternallyTagged")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpInternallyTagged, error))
+    }
+    pub fn export_fp_internally_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_fp_internally_tagged",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self
+            .exports
+            .export_fp_internally_tagged
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported(
+                    "__fp_gen_export_fp_internally_tagged".to_owned(),
+                )
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_fp_internally_tagged");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_fp_internally_tagged",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_fp_struct(
+        &self,
+        arg: FpPropertyRenaming,
+    ) -> Result<FpPropertyRenaming, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_struct_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_struct", "FpPropertyRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpStruct, error))
+    }
+    pub fn export_fp_struct_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_fp_struct",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_fp_struct.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_fp_struct".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_fp_struct");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_fp_struct",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_fp_untagged(&self, arg: FpUntagged) -> Result<FpUntagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_untagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_untagged", "FpUntagged")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpUntagged, error))
+    }
+    pub fn export_fp_untagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_fp_untagged",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_fp_untagged.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_fp_untagged".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_fp_untagged");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_fp_untagged",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_generics(
+        &self,
+        arg: StructWithGenerics<u64>,
+    ) -> Result<StructWithGenerics<u64>, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_generics_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_generics", "StructWithGenerics<u64>")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportGenerics, error))
+    }
+    pub fn export_generics_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_generics",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_generics.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_generics".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_generics");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_generics",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_get_bytes(&self) -> Result<Result<bytes::Bytes, String>, PluginError> {
+        let result = self.export_get_bytes_raw();
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_get_bytes", "Result<bytes::Bytes, String>")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportGetBytes, error))
+    }
+    pub fn export_get_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        let function = self.exports.export_get_bytes.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_get_bytes".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_get_bytes");
+        let result = span.in_scope(|| function.call())?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_get_bytes",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_get_serde_bytes(
+        &self,
+    ) -> Result<Result<serde_bytes::ByteBuf, String>, PluginError> {
+        let result = self.export_get_serde_bytes_raw();
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(
+                data,
+                "export_get_serde_bytes",
+                "Result<serde_bytes::ByteBuf, String>",
+            )
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportGetSerdeBytes, error))
+    }
+    pub fn export_get_serde_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        let function = self
+            .exports
+            .export_get_serde_bytes
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported("__fp_gen_export_get_serde_bytes".to_owned())
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_get_serde_bytes");
+        let result = span.in_scope(|| function.call())?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_get_serde_bytes",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_lazy_string(&self, arg: String) -> Result<Lazy<String>, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_lazy_string_raw(arg);
+        let result = result.map(Lazy::new);
+        result.map_err(|error| PluginError::new(PluginExport::ExportLazyString, error))
+    }
+    pub fn export_lazy_string_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_lazy_string",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_lazy_string.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_lazy_string".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_lazy_string");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_lazy_string",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_metrics(&self, metrics: Vec<Metric>) -> Result<Vec<Metric>, PluginError> {
+        let metrics = serialize_to_vec(&metrics);
+        let result = self.export_metrics_raw(metrics);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_metrics", "Vec<Metric>"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportMetrics, error))
+    }
+    pub fn export_metrics_raw(
+        &self,
+        metrics: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let metrics = try_export_to_guest_raw(&self.env, metrics)?;
+        trace_guest_payload(
+            &self.env,
+            "export_metrics",
+            "metrics",
+            PayloadDirection::ToPlugin,
+            metrics,
+        );
+        let function = self.exports.export_metrics.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_metrics".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_metrics");
+        let result = span.in_scope(|| function.call(metrics.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_metrics",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_multiple_primitives(&self, arg1: i8, arg2: String) -> Result<i64, PluginError> {
+        let arg2 = serialize_to_vec(&arg2);
+        let result = self.export_multiple_primitives_raw(arg1, arg2);
+        result.map_err(|error| PluginError::new(PluginExport::ExportMultiplePrimitives, error))
+    }
+    pub fn export_multiple_primitives_raw(
+        &self,
+        arg1: i8,
+        arg2: impl AsRef<[u8]>,
+    ) -> Result<i64, InvocationError> {
+        let arg2 = try_export_to_guest_raw(&self.env, arg2)?;
+        trace_guest_payload(
+            &self.env,
+            "export_multiple_primitives",
+            "arg2",
+            PayloadDirection::ToPlugin,
+            arg2,
+        );
+        let function = self
+            .exports
+            .export_multiple_primitives
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported(
+                    "__fp_gen_export_multiple_primitives".to_owned(),
+                )
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_multiple_primitives");
+        let result = span.in_scope(|| function.call(arg1.to_abi(), arg2.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_multiple_values(
+        &self,
+        arg: u32,
+    ) -> Result<(String, Vec<u32>, bool), PluginError> {
+        let result = self.export_multiple_values_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_multiple_values", "(String, Vec<u32>, bool)")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportMultipleValues, error))
+    }
+    pub fn export_multiple_values_raw(&self, arg: u32) -> Result<Vec<u8>, InvocationError> {
+        let function = self
+            .exports
+            .export_multiple_values
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported("__fp_gen_export_multiple_values".to_owned())
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_multiple_values");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_multiple_values",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_primitive_bool(&self, arg: bool) -> Result<bool, PluginError> {
+        let result = self.export_primitive_bool_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveBool, error))
+    }
+    pub fn export_primitive_bool_raw(&self, arg: bool) -> Result<bool, InvocationError> {
+        let function = self.exports.export_primitive_bool.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_bool".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_bool");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_f32(&self, arg: f32) -> Result<f32, PluginError> {
+        let result = self.export_primitive_f32_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveF32, error))
+    }
+    pub fn export_primitive_f32_raw(&self, arg: f32) -> Result<f32, InvocationError> {
+        let function = self.exports.export_primitive_f32.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_f32".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_f32");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_f64(&self, arg: f64) -> Result<f64, PluginError> {
+        let result = self.export_primitive_f64_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveF64, error))
+    }
+    pub fn export_primitive_f64_raw(&self, arg: f64) -> Result<f64, InvocationError> {
+        let function = self.exports.export_primitive_f64.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_f64".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_f64");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_i16(&self, arg: i16) -> Result<i16, PluginError> {
+        let result = self.export_primitive_i16_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveI16, error))
+    }
+    pub fn export_primitive_i16_raw(&self, arg: i16) -> Result<i16, InvocationError> {
+        let function = self.exports.export_primitive_i16.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_i16".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_i16");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_i32(&self, arg: i32) -> Result<i32, PluginError> {
+        let result = self.export_primitive_i32_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveI32, error))
+    }
+    pub fn export_primitive_i32_raw(&self, arg: i32) -> Result<i32, InvocationError> {
+        let function = self.exports.export_primitive_i32.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_i32".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_i32");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_i64(&self, arg: i64) -> Result<i64, PluginError> {
+        let result = self.export_primitive_i64_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveI64, error))
+    }
+    pub fn export_primitive_i64_raw(&self, arg: i64) -> Result<i64, InvocationError> {
+        let function = self.exports.export_primitive_i64.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_i64".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_i64");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_i8(&self, arg: i8) -> Result<i8, PluginError> {
+        let result = self.export_primitive_i8_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveI8, error))
+    }
+    pub fn export_primitive_i8_raw(&self, arg: i8) -> Result<i8, InvocationError> {
+        let function = self.exports.export_primitive_i8.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_i8".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_i8");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_pair(&self, arg: u32) -> Result<(u32, i32), PluginError> {
+        let result = self.export_primitive_pair_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitivePair, error))
+    }
+    pub fn export_primitive_pair_raw(&self, arg: u32) -> Result<(u32, i32), InvocationError> {
+        let function = self.exports.export_primitive_pair.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_pair".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_pair");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_u16(&self, arg: u16) -> Result<u16, PluginError> {
+        let result = self.export_primitive_u16_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveU16, error))
+    }
+    pub fn export_primitive_u16_raw(&self, arg: u16) -> Result<u16, InvocationError> {
+        let function = self.exports.export_primitive_u16.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_u16".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_u16");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_u32(&self, arg: u32) -> Result<u32, PluginError> {
+        let result = self.export_primitive_u32_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveU32, error))
+    }
+    pub fn export_primitive_u32_raw(&self, arg: u32) -> Result<u32, InvocationError> {
+        let function = self.exports.export_primitive_u32.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_u32".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_u32");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_u64(&self, arg: u64) -> Result<u64, PluginError> {
+        let result = self.export_primitive_u64_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveU64, error))
+    }
+    pub fn export_primitive_u64_raw(&self, arg: u64) -> Result<u64, InvocationError> {
+        let function = self.exports.export_primitive_u64.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_u64".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_u64");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_u8(&self, arg: u8) -> Result<u8, PluginError> {
+        let result = self.export_primitive_u8_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveU8, error))
+    }
+    pub fn export_primitive_u8_raw(&self, arg: u8) -> Result<u8, InvocationError> {
+        let function = self.exports.export_primitive_u8.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_primitive_u8".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_primitive_u8");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_renamed_string(&self, arg: String) -> Result<String, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_renamed_string_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_renamed_string", "String")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportRenamedString, error))
+    }
+    pub fn export_renamed_string_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_renamed_string",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_renamed_string.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_legacy_string".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_renamed_string");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_renamed_string",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_samples(&self, samples: SampleColumns) -> Result<SampleColumns, PluginError> {
+        let samples = serialize_to_vec(&samples);
+        let result = self.export_samples_raw(samples);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_samples", "SampleColumns")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSamples, error))
+    }
+    pub fn export_samples_raw(
+        &self,
+        samples: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let samples = try_export_to_guest_raw(&self.env, samples)?;
+        trace_guest_payload(
+            &self.env,
+            "export_samples",
+            "samples",
+            PayloadDirection::ToPlugin,
+            samples,
+        );
+        let function = self.exports.export_samples.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_samples".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_samples");
+        let result = span.in_scope(|| function.call(samples.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_samples",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_serde_adjacently_tagged(
+        &self,
+        arg: SerdeAdjacentlyTagged,
+    ) -> Result<SerdeAdjacentlyTagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_adjacently_tagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(
+                data,
+                "export_serde_adjacently_tagged",
+                "SerdeAdjacentlyTagged",
+            )
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeAdjacentlyTagged, error))
+    }
+    pub fn export_serde_adjacently_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_serde_adjacently_tagged",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self
+            .exports
+            .export_serde_adjacently_tagged
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported(
+                    "__fp_gen_export_serde_adjacently_tagged".to_owned(),
+                )
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_serde_adjacently_tagged");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_serde_adjacently_tagged",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_serde_enum(
+        &self,
+        arg: SerdeVariantRenaming,
+    ) -> Result<SerdeVariantRenaming, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_enum_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_serde_enum", "SerdeVariantRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeEnum, error))
+    }
+    pub fn export_serde_enum_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_serde_enum",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_serde_enum.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_serde_enum".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_serde_enum");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_serde_enum",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_serde_flatten(&self, arg: SerdeFlatten) -> Result<SerdeFlatten, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_flatten_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_serde_flatten", "SerdeFlatten")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeFlatten, error))
+    }
+    pub fn export_serde_flatten_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_serde_flatten",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_serde_flatten.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_serde_flatten".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_serde_flatten");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_serde_flatten",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_serde_internally_tagged(
+        &self,
+        arg: SerdeInternallyTagged,
+    ) -> Result<SerdeInternallyTagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_internally_tagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(
+                data,
+                "export_serde_internally_tagged",
+                "SerdeInternallyTagged",
+            )
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeInternallyTagged, error))
+    }
+    pub fn export_serde_internally_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_serde_internally_tagged",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self
+            .exports
+            .export_serde_internally_tagged
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported(
+                    "__fp_gen_export_serde_internally_tagged".to_owned(),
+                )
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_serde_internally_tagged");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_serde_internally_tagged",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_serde_struct(
+        &self,
+        arg: SerdePropertyRenaming,
+    ) -> Result<SerdePropertyRenaming, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_struct_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_serde_struct", "SerdePropertyRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeStruct, error))
+    }
+    pub fn export_serde_struct_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_serde_struct",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_serde_struct.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_serde_struct".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_serde_struct");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_serde_struct",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_serde_untagged(&self, arg: SerdeUntagged) -> Result<SerdeUntagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_untagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_serde_untagged", "SerdeUntagged")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeUntagged, error))
+    }
+    pub fn export_serde_untagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_serde_untagged",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_serde_untagged.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_serde_untagged".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_serde_untagged");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_serde_untagged",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_string(&self, arg: String) -> Result<String, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_string_raw(arg);
+        let result =
+            result.and_then(|ref data| try_deserialize_from_slice(data, "export_string", "String"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportString, error))
+    }
+    pub fn export_string_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_string",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_string.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_string".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_string");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_string",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_string_after_memory_growth(&self, arg: String) -> Result<String, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_string_after_memory_growth_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_string_after_memory_growth", "String")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportStringAfterMemoryGrowth, error))
+    }
+    pub fn export_string_after_memory_growth_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_string_after_memory_growth",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self
+            .exports
+            .export_string_after_memory_growth
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported(
+                    "__fp_gen_export_string_after_memory_growth".to_owned(),
+                )
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_string_after_memory_growth");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_string_after_memory_growth",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_struct_with_options(
+        &self,
+        arg: StructWithOptions,
+    ) -> Result<StructWithOptions, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_struct_with_options_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_struct_with_options", "StructWithOptions")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportStructWithOptions, error))
+    }
+    pub fn export_struct_with_options_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_struct_with_options",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self
+            .exports
+            .export_struct_with_options
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported(
+                    "__fp_gen_export_struct_with_options".to_owned(),
+                )
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_struct_with_options");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_struct_with_options",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_timestamp(&self, arg: MyDateTime) -> Result<MyDateTime, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_timestamp_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_timestamp", "MyDateTime")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportTimestamp, error))
+    }
+    pub fn export_timestamp_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_timestamp",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_timestamp.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_timestamp".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_timestamp");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_timestamp",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_timestamps(&self, arg: Timestamps) -> Result<Timestamps, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_timestamps_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_timestamps", "Timestamps")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportTimestamps, error))
+    }
+    pub fn export_timestamps_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_timestamps",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self.exports.export_timestamps.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_timestamps".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_timestamps");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_timestamps",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_typed_id(
+        &self,
+        id: TypedId<Point<f64>>,
+    ) -> Result<TypedId<Point<f64>>, PluginError> {
+        let id = serialize_to_vec(&id);
+        let result = self.export_typed_id_raw(id);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_typed_id", "TypedId<Point<f64>>")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportTypedId, error))
+    }
+    pub fn export_typed_id_raw(&self, id: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let id = try_export_to_guest_raw(&self.env, id)?;
+        trace_guest_payload(
+            &self.env,
+            "export_typed_id",
+            "id",
+            PayloadDirection::ToPlugin,
+            id,
+        );
+        let function = self.exports.export_typed_id.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_typed_id".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_typed_id");
+        let result = span.in_scope(|| function.call(id.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_typed_id",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_validated_struct(
+        &self,
+        arg: ValidatedStruct,
+    ) -> Result<ValidatedStruct, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_validated_struct_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_validated_struct", "ValidatedStruct")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportValidatedStruct, error))
+    }
+    pub fn export_validated_struct_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let arg = try_export_to_guest_raw(&self.env, arg)?;
+        trace_guest_payload(
+            &self.env,
+            "export_validated_struct",
+            "arg",
+            PayloadDirection::ToPlugin,
+            arg,
+        );
+        let function = self
+            .exports
+            .export_validated_struct
+            .as_ref()
+            .ok_or_else(|| {
+                InvocationError::FunctionNotExported("__fp_gen_export_validated_struct".to_owned())
+            })?;
+        export_deadline(&self.env);
+        let span = export_span("export_validated_struct");
+        let result = span.in_scope(|| function.call(arg.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "export_validated_struct",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    pub fn export_void_function(&self) -> Result<(), PluginError> {
+        let result = self.export_void_function_raw();
+        result.map_err(|error| PluginError::new(PluginExport::ExportVoidFunction, error))
+    }
+    pub fn export_void_function_raw(&self) -> Result<(), InvocationError> {
+        let function = self.exports.export_void_function.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_export_void_function".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("export_void_function");
+        let result = span.in_scope(|| function.call())?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    /// Example how plugin could expose async data-fetching capabilities.
+    pub async fn fetch_data(&self, r#type: String) -> Result<Result<String, String>, PluginError> {
+        let r#type = serialize_to_vec(&r#type);
+        let result = self.fetch_data_raw(r#type);
+        let result = result.await;
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "fetch_data", "Result<String, String>")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::FetchData, error))
+    }
+    pub async fn fetch_data_raw(
+        &self,
+        r#type: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let _permit = match &self.async_limit {
+            Some(limit) => Some(limit.acquire()?.await),
+            None => None,
+        };
+        let r#type = try_export_to_guest_raw(&self.env, r#type)?;
+        trace_guest_payload(
+            &self.env,
+            "fetch_data",
+            "r#type",
+            PayloadDirection::ToPlugin,
+            r#type,
+        );
+        let function = self.exports.fetch_data.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_fetch_data".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("fetch_data");
+        let result = span.in_scope(|| function.call(r#type.to_abi()))?;
+        let result = ModuleRawFuture::new(self.env.clone(), result)
+            .instrument(span)
+            .await?;
+        trace_payload(
+            &self.env,
+            "fetch_data",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    /// Called on the plugin to give it a chance to initialize.
+    pub fn init(&self, config: PluginConfig) -> Result<(), PluginError> {
+        let config = serialize_to_vec(&config);
+        let result = self.init_raw(config);
+        result.map_err(|error| PluginError::new(PluginExport::Init, error))
+    }
+    pub fn init_raw(&self, config: impl AsRef<[u8]>) -> Result<(), InvocationError> {
+        let config = try_export_to_guest_raw(&self.env, config)?;
+        trace_guest_payload(
+            &self.env,
+            "init",
+            "config",
+            PayloadDirection::ToPlugin,
+            config,
+        );
+        let function = self
+            .exports
+            .init
+            .as_ref()
+            .ok_or_else(|| InvocationError::FunctionNotExported("__fp_gen_init".to_owned()))?;
+        export_deadline(&self.env);
+        let span = export_span("init");
+        let result = span.in_scope(|| function.call(config.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    /// Example how plugin could expose a reducer.
+    pub fn reducer_bridge(&self, action: ReduxAction) -> Result<StateUpdate, PluginError> {
+        let action = serialize_to_vec(&action);
+        let result = self.reducer_bridge_raw(action);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "reducer_bridge", "StateUpdate"));
+        result.map_err(|error| PluginError::new(PluginExport::ReducerBridge, error))
+    }
+    pub fn reducer_bridge_raw(&self, action: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let action = try_export_to_guest_raw(&self.env, action)?;
+        trace_guest_payload(
+            &self.env,
+            "reducer_bridge",
+            "action",
+            PayloadDirection::ToPlugin,
+            action,
+        );
+        let function = self.exports.reducer_bridge.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_reducer_bridge".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("reducer_bridge");
+        let result = span.in_scope(|| function.call(action.to_abi()))?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        trace_payload(
+            &self.env,
+            "reducer_bridge",
+            "result",
+            PayloadDirection::FromPlugin,
+            &result,
+        );
+        Ok(result)
+    }
+
+    /// Called on the plugin when the host changes its config.
+    pub fn update_config(&self, config: PluginConfig) -> Result<(), PluginError> {
+        let config = serialize_to_vec(&config);
+        let result = self.update_config_raw(config);
+        result.map_err(|error| PluginError::new(PluginExport::UpdateConfig, error))
+    }
+    pub fn update_config_raw(&self, config: impl AsRef<[u8]>) -> Result<(), InvocationError> {
+        let config = try_export_to_guest_raw(&self.env, config)?;
+        trace_guest_payload(
+            &self.env,
+            "update_config",
+            "config",
+            PayloadDirection::ToPlugin,
+            config,
+        );
+        let function = self.exports.update_config.as_ref().ok_or_else(|| {
+            InvocationError::FunctionNotExported("__fp_gen_update_config".to_owned())
+        })?;
+        export_deadline(&self.env);
+        let span = export_span("update_config");
+        let result = span.in_scope(|| function.call(config.to_abi()))?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    /// Discards the memoized results of the exports that are annotated with
+    /// `#[fp(cache)]`, for this runtime and its clones.
+    pub fn clear_export_cache(&self) {
+        self.env.export_cache().clear();
+    }
+
+    /// Registers a handler for the dynamic import `invoke_command`.
+    ///
+    /// Calls from the plugin with the given name are dispatched to this handler
+    /// instead of the static `invoke_command()` implementation. Registering a handler
+    /// with a name that is already in use replaces the existing handler.
+    pub fn register_invoke_command(
+        &self,
+        name: impl Into<String>,
+        handler: impl Fn(String) -> Result<String, String> + Send + Sync + 'static,
+    ) {
+        self.env.register_dynamic_import_handler(
+            "invoke_command",
+            name.into(),
+            std::sync::Arc::new(move |payload: &[u8]| {
+                serialize_to_vec(&handler(deserialize_from_slice(payload)))
+            }),
+        );
+    }
+
+    /// Unregisters a handler for the dynamic import `invoke_command`.
+    ///
+    /// Returns whether a handler was registered under the given name.
+    pub fn unregister_invoke_command(&self, name: &str) -> bool {
+        self.env
+            .unregister_dynamic_import_handler("invoke_command", name)
+    }
+
+    /// Subscribes to the `report_progress` event.
+    ///
+    /// Every event that the plugin emits after subscribing is sent to the
+    /// returned receiver. Dropping the receiver ends the subscription.
+    pub fn subscribe_report_progress(&self) -> std::sync::mpsc::Receiver<Progress> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.env.subscribe_to_event(
+            "report_progress",
+            Box::new(move |event: &[u8]| sender.send(deserialize_from_slice(event)).is_ok()),
+        );
+        receiver
+    }
+}
+
+fn create_import_object(store: &Store, env: &RuntimeInstanceData) -> ImportObject {
+    let mut namespace = wasmer::Exports::new();
+    namespace.insert(
+        "__fp_host_resolve_async_value",
+        Function::new_native_with_env(store, env.clone(), resolve_async_value),
+    );
+    namespace.insert(
+        "__fp_error",
+        Function::new_native_with_env(store, env.clone(), guest_error),
+    );
+    namespace.insert(
+        "__fp_gen_import_arbitrary_precision_numbers",
+        Function::new_native_with_env(store, env.clone(), _import_arbitrary_precision_numbers),
+    );
+    namespace.insert(
+        "__fp_gen_import_array_f32",
+        Function::new_native_with_env(store, env.clone(), _import_array_f32),
+    );
+    namespace.insert(
+        "__fp_gen_import_array_f64",
+        Function::new_native_with_env(store, env.clone(), _import_array_f64),
+    );
+    namespace.insert(
+        "__fp_gen_import_array_i16",
+        Function::new_native_with_env(store, env.clone(), _import_array_i16),
+    );
+    namespace.insert(
+        "__fp_gen_import_array_i32",
+        Function::new_native_with_env(store, env.clone(), _import_array_i32),
+    );
+    namespace.insert(
+        "__fp_gen_import_array_i8",
+        Function::new_native_with_env(store, env.clone(), _import_array_i8),
+    );
+    namespace.insert(
+        "__fp_gen_import_array_u16",
+        Function::new_native_with_env(store, env.clone(), _import_array_u16),
+    );
+    namespace.insert(
+        "__fp_gen_import_array_u32",
+        Function::new_native_with_env(store, env.clone(), _import_array_u32),
+    );
+    namespace.insert(
+        "__fp_gen_import_array_u8",
+        Function::new_native_with_env(store, env.clone(), _import_array_u8),
+    );
+    namespace.insert(
+        "__fp_gen_import_async_void_function",
+        Function::new_native_with_env(store, env.clone(), _import_async_void_function),
+    );
+    namespace.insert(
+        "__fp_gen_import_collections",
+        Function::new_native_with_env(store, env.clone(), _import_collections),
+    );
+    #[cfg(feature = "experimental")]
+    namespace.insert(
+        "__fp_gen_import_experimental",
+        Function::new_native_with_env(store, env.clone(), _import_experimental),
+    );
+    namespace.insert(
+        "__fp_gen_import_explicit_bound_point",
+        Function::new_native_with_env(store, env.clone(), _import_explicit_bound_point),
+    );
+    namespace.insert(
+        "__fp_gen_import_fp_adjacently_tagged",
+        Function::new_native_with_env(store, env.clone(), _import_fp_adjacently_tagged),
+    );
+    namespace.insert(
+        "__fp_gen_import_fp_enum",
+        Function::new_native_with_env(store, env.clone(), _import_fp_enum),
+    );
+    namespace.insert(
+        "__fp_gen_import_fp_flatten",
+        Function::new_native_with_env(store, env.clone(), _import_fp_flatten),
+    );
+    namespace.insert(
+        "__fp_gen_import_fp_internally_tagged",
+        Function::new_native_with_env(store, env.clone(), _import_fp_internally_tagged),
+    );
+    namespace.insert(
+        "__fp_gen_import_fp_struct",
+        Function::new_native_with_env(store, env.clone(), _import_fp_struct),
+    );
+    namespace.insert(
+        "__fp_gen_import_fp_untagged",
+        Function::new_native_with_env(store, env.clone(), _import_fp_untagged),
+    );
+    namespace.insert(
+        "__fp_gen_import_generics",
+        Function::new_native_with_env(store, env.clone(), _import_generics),
+    );
+    namespace.insert(
+        "__fp_gen_import_get_bytes",
+        Function::new_native_with_env(store, env.clone(), _import_get_bytes),
+    );
+    namespace.insert(
+        "__fp_gen_import_get_serde_bytes",
+        Function::new_native_with_env(store, env.clone(), _import_get_serde_bytes),
+    );
+    namespace.insert(
+        "__fp_gen_import_get_value",
+        Function::new_native_with_env(store, env.clone(), _import_get_value),
+    );
+    namespace.insert(
+        "__fp_gen_import_integer_types",
+        Function::new_native_with_env(store, env.clone(), _import_integer_types),
+    );
+    namespace.insert(
+        "__fp_gen_import_multiple_primitives",
+        Function::new_native_with_env(store, env.clone(), _import_multiple_primitives),
+    );
+    namespace.insert(
+        "__fp_gen_import_multiple_values",
+        Function::new_native_with_env(store, env.clone(), _import_multiple_values),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_bool",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_bool),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_f32",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_f32),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_f64",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_f64),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_i16",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i16),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_i32",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i32),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_i64",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i64),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_i8",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i8),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_pair",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_pair),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_u16",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u16),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_u32",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u32),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_u64",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u64),
+    );
+    namespace.insert(
+        "__fp_gen_import_primitive_u8",
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u8),
+    );
+    namespace.insert(
+        "__fp_gen_import_rate_limited",
+        Function::new_native_with_env(store, env.clone(), _import_rate_limited),
+    );
+    namespace.insert(
+        "__fp_gen_import_legacy_string",
+        Function::new_native_with_env(store, env.clone(), _import_renamed_string),
+    );
+    namespace.insert(
+        "__fp_gen_import_serde_adjacently_tagged",
+        Function::new_native_with_env(store, env.clone(), _import_serde_adjacently_tagged),
+    );
+    namespace.insert(
+        "__fp_gen_import_serde_enum",
+        Function::new_native_with_env(store, env.clone(), _import_serde_enum),
+    );
+    namespace.insert(
+        "__fp_gen_import_serde_flatten",
+        Function::new_native_with_env(store, env.clone(), _import_serde_flatten),
+    );
+    namespace.insert(
+        "__fp_gen_import_serde_internally_tagged",
+        Function::new_native_with_env(store, env.clone(), _import_serde_internally_tagged),
+    );
+    namespace.insert(
+        "__fp_gen_import_serde_struct",
+        Function::new_native_with_env(store, env.clone(), _import_serde_struct),
+    );
+    namespace.insert(
+        "__fp_gen_import_serde_untagged",
+        Function::new_native_with_env(store, env.clone(), _import_serde_untagged),
+    );
+    namespace.insert(
+        "__fp_gen_import_set_value",
+        Function::new_native_with_env(store, env.clone(), _import_set_value),
+    );
+    namespace.insert(
+        "__fp_gen_import_string",
+        Function::new_native_with_env(store, env.clone(), _import_string),
+    );
+    namespace.insert(
+        "__fp_gen_import_struct_with_options",
+        Function::new_native_with_env(store, env.clone(), _import_struct_with_options),
+    );
+    namespace.insert(
+        "__fp_gen_import_timestamp",
+        Function::new_native_with_env(store, env.clone(), _import_timestamp),
+    );
+    namespace.insert(
+        "__fp_gen_import_validated_struct",
+        Function::new_native_with_env(store, env.clone(), _import_validated_struct),
+    );
+    namespace.insert(
+        "__fp_gen_import_void_function",
+        Function::new_native_with_env(store, env.clone(), _import_void_function),
+    );
+    namespace.insert(
+        "__fp_gen_import_void_function_empty_result",
+        Function::new_native_with_env(store, env.clone(), _import_void_function_empty_result),
+    );
+    namespace.insert(
+        "__fp_gen_import_void_function_empty_return",
+        Function::new_native_with_env(store, env.clone(), _import_void_function_empty_return),
+    );
+    namespace.insert(
+        "__fp_gen_invoke_command",
+        Function::new_native_with_env(store, env.clone(), _invoke_command),
+    );
+    namespace.insert(
+        "__fp_gen_log",
+        Function::new_native_with_env(store, env.clone(), _log),
+    );
+    namespace.insert(
+        "__fp_gen_make_http_request",
+        Function::new_native_with_env(store, env.clone(), _make_http_request),
+    );
+    namespace.insert(
+        "__fp_gen_record_metric",
+        Function::new_native_with_env(store, env.clone(), _record_metric),
+    );
+    namespace.insert(
+        "__fp_gen_report_progress",
+        Function::new_native_with_env(store, env.clone(), _report_progress),
+    );
+    namespace.insert(
+        "__fp_host_batch",
+        Function::new_native_with_env(store, env.clone(), __fp_host_batch),
+    );
+    namespace.insert(
+        "__fp_host_trace_context",
+        Function::new_native_with_env(store, env.clone(), trace_context),
+    );
+    namespace.insert(
+        "__fp_host_deadline_remaining",
+        Function::new_native_with_env(store, env.clone(), deadline_remaining),
+    );
+    namespace.insert(
+        "__fp_host_print",
+        Function::new_native_with_env(store, env.clone(), host_print),
+    );
+    namespace.insert(
+        "__fp_std_read_file",
+        Function::new_native_with_env(store, env.clone(), std_read_file),
+    );
+    namespace.insert(
+        "__fp_std_write_file",
+        Function::new_native_with_env(store, env.clone(), std_write_file),
+    );
+    namespace.insert(
+        "__fp_std_now",
+        Function::new_native_with_env(store, env.clone(), std_now),
+    );
+    namespace.insert(
+        "__fp_std_monotonic_now",
+        Function::new_native_with_env(store, env.clone(), std_monotonic_now),
+    );
+    namespace.insert(
+        "__fp_std_random_bytes",
+        Function::new_native_with_env(store, env.clone(), std_random_bytes),
+    );
+    namespace.insert(
+        "__fp_http_request",
+        Function::new_native_with_env(store, env.clone(), http_request),
+    );
+    namespace.insert(
+        "__fp_intern_string",
+        Function::new_native_with_env(
+            store,
+            env.clone(),
+            fp_bindgen_support::host::interning::intern_string,
+        ),
+    );
+    imports! {
+        "fp" => namespace
+    }
+}
+
+/// Builds a `Runtime` with custom settings.
+///
+/// By default, modules are compiled with Singlepass (or Cranelift on ARM).
+/// Hosts may provide their own store to use another compiler, such as LLVM,
+/// or a headless engine in combination with precompiled modules.
+#[derive(Default)]
+pub struct RuntimeBuilder {
+    store: Option<Store>,
+    wasm_features: WasmFeatures,
+    capabilities: Vec<Capability>,
+    std_imports: StdImports,
+    http_imports: HttpImports,
+    import_overrides: ImportOverrides,
+    output_handler: Option<OutputHandler>,
+    payload_tracer: Option<PayloadTracer>,
+    async_limit: Option<(usize, usize)>,
+    signature_verifier: Option<SignatureVerifier>,
+    init_config: Option<PluginConfig>,
+}
+
+impl RuntimeBuilder {
+    /// Returns a new builder with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the store with which modules are compiled and deserialized.
+    pub fn with_store(mut self, store: Store) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Sets the WebAssembly features that are enabled for the plugin.
+    ///
+    /// Modules that use a feature that is disabled are rejected with
+    /// `RuntimeError::DisabledWasmFeatures` before they are compiled. The
+    /// features are also enabled on the default store, while a store that is
+    /// set using `with_store()` keeps the features of its engine.
+    pub fn with_wasm_features(mut self, features: WasmFeatures) -> Self {
+        self.wasm_features = features;
+        self
+    }
+
+    /// Grants the given capabilities to the plugin.
+    ///
+    /// Calls to imports that require a capability that was not granted fail
+    /// with `InvocationError::PermissionDenied`.
+    pub fn with_capabilities(mut self, capabilities: &[Capability]) -> Self {
+        self.capabilities = capabilities.to_vec();
+        self
+    }
+
+    /// Sets the host facilities that are exposed to the plugin through the
+    /// built-in std imports. By default, no files are exposed, while the
+    /// clock and random number generator of the operating system are used.
+    pub fn with_std_imports(mut self, std_imports: StdImports) -> Self {
+        self.std_imports = std_imports;
+        self
+    }
+
+    /// Sets the HTTP client that is exposed to the plugin through the
+    /// built-in HTTP imports. By default, requests are sent using a shared
+    /// `reqwest::Client`.
+    pub fn with_http_imports(mut self, http_imports: HttpImports) -> Self {
+        self.http_imports = http_imports;
+        self
+    }
+
+    /// Overrides the host's implementation of `import_arbitrary_precision_numbers` for this runtime.
+    pub fn override_import_arbitrary_precision_numbers(
+        mut self,
+        implementation: impl Fn(ArbitraryPrecisionNumbers) -> ArbitraryPrecisionNumbers
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        let implementation: Arc<
+            dyn Fn(ArbitraryPrecisionNumbers) -> ArbitraryPrecisionNumbers + Send + Sync,
+        > = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_arbitrary_precision_numbers", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_array_f32` for this runtime.
+    pub fn override_import_array_f32(
+        mut self,
+        implementation: impl Fn([f32; 3]) -> [f32; 3] + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn([f32; 3]) -> [f32; 3] + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_array_f32", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_array_f64` for this runtime.
+    pub fn override_import_array_f64(
+        mut self,
+        implementation: impl Fn([f64; 3]) -> [f64; 3] + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn([f64; 3]) -> [f64; 3] + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_array_f64", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_array_i16` for this runtime.
+    pub fn override_import_array_i16(
+        mut self,
+        implementation: impl Fn([i16; 3]) -> [i16; 3] + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn([i16; 3]) -> [i16; 3] + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_array_i16", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_array_i32` for this runtime.
+    pub fn override_import_array_i32(
+        mut self,
+        implementation: impl Fn([i32; 3]) -> [i32; 3] + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn([i32; 3]) -> [i32; 3] + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_array_i32", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_array_i8` for this runtime.
+    pub fn override_import_array_i8(
+        mut self,
+        implementation: impl Fn([i8; 3]) -> [i8; 3] + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn([i8; 3]) -> [i8; 3] + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_array_i8", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_array_u16` for this runtime.
+    pub fn override_import_array_u16(
+        mut self,
+        implementation: impl Fn([u16; 3]) -> [u16; 3] + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn([u16; 3]) -> [u16; 3] + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_array_u16", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_array_u32` for this runtime.
+    pub fn override_import_array_u32(
+        mut self,
+        implementation: impl Fn([u32; 3]) -> [u32; 3] + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn([u32; 3]) -> [u32; 3] + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_array_u32", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_array_u8` for this runtime.
+    pub fn override_import_array_u8(
+        mut self,
+        implementation: impl Fn([u8; 3]) -> [u8; 3] + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn([u8; 3]) -> [u8; 3] + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_array_u8", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_async_void_function` for this runtime.
+    pub fn override_import_async_void_function<F>(
+        mut self,
+        implementation: impl Fn(String) -> F + Send + Sync + 'static,
+    ) -> Self
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        let implementation: Arc<dyn Fn(String) -> OverrideFuture<()> + Send + Sync> =
+            Arc::new(move |message| Box::pin(implementation(message)));
+        self.import_overrides
+            .insert("import_async_void_function", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_collections` for this runtime.
+    pub fn override_import_collections(
+        mut self,
+        implementation: impl Fn(Collections) -> Collections + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(Collections) -> Collections + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_collections", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_experimental` for this runtime.
+    pub fn override_import_experimental(
+        mut self,
+        implementation: impl Fn(String) -> String + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(String) -> String + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_experimental", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_explicit_bound_point` for this runtime.
+    pub fn override_import_explicit_bound_point(
+        mut self,
+        implementation: impl Fn(ExplicitBoundPoint<u64>) + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(ExplicitBoundPoint<u64>) + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_explicit_bound_point", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_fp_adjacently_tagged` for this runtime.
+    pub fn override_import_fp_adjacently_tagged(
+        mut self,
+        implementation: impl Fn(FpAdjacentlyTagged) -> FpAdjacentlyTagged + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(FpAdjacentlyTagged) -> FpAdjacentlyTagged + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_fp_adjacently_tagged", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_fp_enum` for this runtime.
+    pub fn override_import_fp_enum(
+        mut self,
+        implementation: impl Fn(FpVariantRenaming) -> FpVariantRenaming + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(FpVariantRenaming) -> FpVariantRenaming + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_fp_enum", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_fp_flatten` for this runtime.
+    pub fn override_import_fp_flatten(
+        mut self,
+        implementation: impl Fn(FpFlatten) -> FpFlatten + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(FpFlatten) -> FpFlatten + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_fp_flatten", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_fp_internally_tagged` for this runtime.
+    pub fn override_import_fp_internally_tagged(
+        mut self,
+        implementation: impl Fn(FpInternallyTagged) -> FpInternallyTagged + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(FpInternallyTagged) -> FpInternallyTagged + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_fp_internally_tagged", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_fp_struct` for this runtime.
+    pub fn override_import_fp_struct(
+        mut self,
+        implementation: impl Fn(FpPropertyRenaming) -> FpPropertyRenaming + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(FpPropertyRenaming) -> FpPropertyRenaming + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_fp_struct", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_fp_untagged` for this runtime.
+    pub fn override_import_fp_untagged(
+        mut self,
+        implementation: impl Fn(FpUntagged) -> FpUntagged + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(FpUntagged) -> FpUntagged + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_fp_untagged", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_generics` for this runtime.
+    pub fn override_import_generics(
+        mut self,
+        implementation: impl Fn(StructWithGenerics<u64>) -> StructWithGenerics<u64>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        let implementation: Arc<
+            dyn Fn(StructWithGenerics<u64>) -> StructWithGenerics<u64> + Send + Sync,
+        > = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_generics", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_get_bytes` for this runtime.
+    pub fn override_import_get_bytes(
+        mut self,
+        implementation: impl Fn() -> Result<bytes::Bytes, String> + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn() -> Result<bytes::Bytes, String> + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_get_bytes", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_get_serde_bytes` for this runtime.
+    pub fn override_import_get_serde_bytes(
+        mut self,
+        implementation: impl Fn() -> Result<serde_bytes::ByteBuf, String> + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn() -> Result<serde_bytes::ByteBuf, String> + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_get_serde_bytes", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_integer_types` for this runtime.
+    pub fn override_import_integer_types(
+        mut self,
+        implementation: impl Fn(IntegerTypes) -> IntegerTypes + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(IntegerTypes) -> IntegerTypes + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_integer_types", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_multiple_primitives` for this runtime.
+    pub fn override_import_multiple_primitives(
+        mut self,
+        implementation: impl Fn(i8, String) -> i64 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(i8, String) -> i64 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_multiple_primitives", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_multiple_values` for this runtime.
+    pub fn override_import_multiple_values(
+        mut self,
+        implementation: impl Fn(u32) -> (String, Vec<u32>, bool) + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(u32) -> (String, Vec<u32>, bool) + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_multiple_values", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_bool` for this runtime.
+    pub fn override_import_primitive_bool(
+        mut self,
+        implementation: impl Fn(bool) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(bool) -> bool + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_bool", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_f32` for this runtime.
+    pub fn override_import_primitive_f32(
+        mut self,
+        implementation: impl Fn(f32) -> f32 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(f32) -> f32 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_f32", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_f64` for this runtime.
+    pub fn override_import_primitive_f64(
+        mut self,
+        implementation: impl Fn(f64) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(f64) -> f64 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_f64", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_i16` for this runtime.
+    pub fn override_import_primitive_i16(
+        mut self,
+        implementation: impl Fn(i16) -> i16 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(i16) -> i16 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_i16", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_i32` for this runtime.
+    pub fn override_import_primitive_i32(
+        mut self,
+        implementation: impl Fn(i32) -> i32 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(i32) -> i32 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_i32", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_i64` for this runtime.
+    pub fn override_import_primitive_i64(
+        mut self,
+        implementation: impl Fn(i64) -> i64 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(i64) -> i64 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_i64", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_i8` for this runtime.
+    pub fn override_import_primitive_i8(
+        mut self,
+        implementation: impl Fn(i8) -> i8 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(i8) -> i8 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_i8", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_pair` for this runtime.
+    pub fn override_import_primitive_pair(
+        mut self,
+        implementation: impl Fn(u32) -> (u32, i32) + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(u32) -> (u32, i32) + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_pair", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_u16` for this runtime.
+    pub fn override_import_primitive_u16(
+        mut self,
+        implementation: impl Fn(u16) -> u16 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(u16) -> u16 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_u16", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_u32` for this runtime.
+    pub fn override_import_primitive_u32(
+        mut self,
+        implementation: impl Fn(u32) -> u32 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(u32) -> u32 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_u32", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_u64` for this runtime.
+    pub fn override_import_primitive_u64(
+        mut self,
+        implementation: impl Fn(u64) -> u64 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(u64) -> u64 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_u64", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_primitive_u8` for this runtime.
+    pub fn override_import_primitive_u8(
+        mut self,
+        implementation: impl Fn(u8) -> u8 + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(u8) -> u8 + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_primitive_u8", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_rate_limited` for this runtime.
+    pub fn override_import_rate_limited(
+        mut self,
+        implementation: impl Fn(String) -> Result<String, RateLimited> + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(String) -> Result<String, RateLimited> + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_rate_limited", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_renamed_string` for this runtime.
+    pub fn override_import_renamed_string(
+        mut self,
+        implementation: impl Fn(String) -> String + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(String) -> String + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_renamed_string", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_serde_adjacently_tagged` for this runtime.
+    pub fn override_import_serde_adjacently_tagged(
+        mut self,
+        implementation: impl Fn(SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<
+            dyn Fn(SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged + Send + Sync,
+        > = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_serde_adjacently_tagged", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_serde_enum` for this runtime.
+    pub fn override_import_serde_enum(
+        mut self,
+        implementation: impl Fn(SerdeVariantRenaming) -> SerdeVariantRenaming + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<
+            dyn Fn(SerdeVariantRenaming) -> SerdeVariantRenaming + Send + Sync,
+        > = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_serde_enum", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_serde_flatten` for this runtime.
+    pub fn override_import_serde_flatten(
+        mut self,
+        implementation: impl Fn(SerdeFlatten) -> SerdeFlatten + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(SerdeFlatten) -> SerdeFlatten + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_serde_flatten", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_serde_internally_tagged` for this runtime.
+    pub fn override_import_serde_internally_tagged(
+        mut self,
+        implementation: impl Fn(SerdeInternallyTagged) -> SerdeInternallyTagged + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<
+            dyn Fn(SerdeInternallyTagged) -> SerdeInternallyTagged + Send + Sync,
+        > = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_serde_internally_tagged", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_serde_struct` for this runtime.
+    pub fn override_import_serde_struct(
+        mut self,
+        implementation: impl Fn(SerdePropertyRenaming) -> SerdePropertyRenaming + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<
+            dyn Fn(SerdePropertyRenaming) -> SerdePropertyRenaming + Send + Sync,
+        > = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_serde_struct", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_serde_untagged` for this runtime.
+    pub fn override_import_serde_untagged(
+        mut self,
+        implementation: impl Fn(SerdeUntagged) -> SerdeUntagged + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(SerdeUntagged) -> SerdeUntagged + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_serde_untagged", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_string` for this runtime.
+    pub fn override_import_string(
+        mut self,
+        implementation: impl Fn(String) -> String + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(String) -> String + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_string", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_struct_with_options` for this runtime.
+    pub fn override_import_struct_with_options(
+        mut self,
+        implementation: impl Fn(StructWithOptions) -> StructWithOptions + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(StructWithOptions) -> StructWithOptions + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_struct_with_options", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_timestamp` for this runtime.
+    pub fn override_import_timestamp(
+        mut self,
+        implementation: impl Fn(MyDateTime) -> MyDateTime + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(MyDateTime) -> MyDateTime + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_timestamp", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_validated_struct` for this runtime.
+    pub fn override_import_validated_struct(
+        mut self,
+        implementation: impl Fn(ValidatedStruct) -> ValidatedStruct + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(ValidatedStruct) -> ValidatedStruct + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_validated_struct", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_void_function` for this runtime.
+    pub fn override_import_void_function(
+        mut self,
+        implementation: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn() + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_void_function", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_void_function_empty_result` for this runtime.
+    pub fn override_import_void_function_empty_result(
+        mut self,
+        implementation: impl Fn() -> Result<(), u32> + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn() -> Result<(), u32> + Send + Sync> =
+            Arc::new(implementation);
+        self.import_overrides
+            .insert("import_void_function_empty_result", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `import_void_function_empty_return` for this runtime.
+    pub fn override_import_void_function_empty_return(
+        mut self,
+        implementation: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn() + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("import_void_function_empty_return", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `log` for this runtime.
+    pub fn override_log(mut self, implementation: impl Fn(String) + Send + Sync + 'static) -> Self {
+        let implementation: Arc<dyn Fn(String) + Send + Sync> = Arc::new(implementation);
+        self.import_overrides.insert("log", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `make_http_request` for this runtime.
+    pub fn override_make_http_request<F>(
+        mut self,
+        implementation: impl Fn(Request) -> F + Send + Sync + 'static,
+    ) -> Self
+    where
+        F: std::future::Future<Output = HttpResult> + Send + 'static,
+    {
+        let implementation: Arc<dyn Fn(Request) -> OverrideFuture<HttpResult> + Send + Sync> =
+            Arc::new(move |request| Box::pin(implementation(request)));
+        self.import_overrides
+            .insert("make_http_request", implementation);
+        self
+    }
+
+    /// Overrides the host's implementation of `record_metric` for this runtime.
+    pub fn override_record_metric(
+        mut self,
+        implementation: impl Fn(String, f64) + Send + Sync + 'static,
+    ) -> Self {
+        let implementation: Arc<dyn Fn(String, f64) + Send + Sync> = Arc::new(implementation);
+        self.import_overrides
+            .insert("record_metric", implementation);
+        self
+    }
+
+    /// Sets the handler for the lines that the plugin prints using the
+    /// `println!()` and `eprintln!()` macros of `fp-bindgen-support`. By
+    /// default, they are printed to the stdout and stderr of the host.
+    pub fn with_output_handler(
+        mut self,
+        handler: impl Fn(OutputStream, &str) + Send + Sync + 'static,
+    ) -> Self {
+        self.output_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Sets the tracer for the serialized payloads that are passed to and
+    /// from the plugin. By default, payloads are only traced if the
+    /// `FP_BINDGEN_TRACE_PAYLOADS` environment variable is set.
+    pub fn with_payload_tracer(mut self, tracer: PayloadTracer) -> Self {
+        self.payload_tracer = Some(tracer);
+        self
+    }
+
+    /// Limits the number of async exports that may be invoked concurrently
+    /// on the runtime. See `Runtime::with_async_concurrency_limit()`.
+    pub fn with_async_concurrency_limit(
+        mut self,
+        max_concurrent: usize,
+        max_queued: usize,
+    ) -> Self {
+        self.async_limit = Some((max_concurrent, max_queued));
+        self
+    }
+
+    /// Sets the verifier with which the signature of the plugin is checked.
+    ///
+    /// Modules that are unsigned or whose signature is invalid are rejected
+    /// with `RuntimeError::InvalidSignature` before they are compiled. Modules
+    /// that are passed to `build_with_module()` or `build_from_serialized()`
+    /// have already been compiled, so their signature is not verified.
+    pub fn with_signature_verifier(mut self, verifier: SignatureVerifier) -> Self {
+        self.signature_verifier = Some(verifier);
+        self
+    }
+
+    /// Calls the `init` export of the plugin with the given config, once the
+    /// runtime has been instantiated. Plugins that don't implement `init` are
+    /// not initialized.
+    pub fn with_init(mut self, config: PluginConfig) -> Self {
+        self.init_config = Some(config);
+        self
+    }
+
+    /// Compiles the given module and instantiates a runtime for it.
+    pub fn build(self, wasm_module: impl AsRef<[u8]>) -> Result<Runtime, RuntimeError> {
+        if let Some(verifier) = &self.signature_verifier {
+            verifier.verify(wasm_module.as_ref())?;
+        }
+        self.wasm_features.validate(wasm_module.as_ref())?;
+        let module = Module::new(&self.store(), wasm_module)?;
+        self.build_with_module(&module)
+    }
+
+    /// Instantiates a runtime for a module that has already been compiled.
+    ///
+    /// The runtime uses the store of the module, rather than the store that
+    /// was set on the builder.
+    pub fn build_with_module(self, module: &Module) -> Result<Runtime, RuntimeError> {
+        let runtime = Runtime::from_module(
+            module,
+            RuntimeInstanceData::default()
+                .with_capabilities(self.capabilities.iter().map(Capability::as_str))
+                .with_std_imports(self.std_imports)
+                .with_http_imports(self.http_imports)
+                .with_import_overrides(self.import_overrides)
+                .with_output_handler(self.output_handler)
+                .with_payload_tracer(self.payload_tracer),
+        )?;
+        let runtime = match self.async_limit {
+            Some((max_concurrent, max_queued)) => {
+                runtime.with_async_concurrency_limit(max_concurrent, max_queued)
+            }
+            None => runtime,
+        };
+        if let Some(config) = self.init_config {
+            match runtime.init(config) {
+                Ok(()) | Err(PluginError::MissingExport(_)) => {}
+                Err(error) => return Err(RuntimeError::InitFailed(error.into())),
+            }
+        }
+        Ok(runtime)
+    }
+
+    /// Deserializes a module that was precompiled using `Module::serialize()`
+    /// and instantiates a runtime for it. This avoids compiling the module
+    /// when the runtime is created.
+    ///
+    /// # Safety
+    ///
+    /// The serialized module must have been produced by `Module::serialize()`
+    /// using a compatible version of Wasmer and an engine that matches the
+    /// store of this builder. See `Module::deserialize()` for details. The
+    /// module is not checked against the WebAssembly features of the builder.
+    pub unsafe fn build_from_serialized(
+        self,
+        serialized_module: &[u8],
+    ) -> Result<Runtime, RuntimeError> {
+        let module = Module::deserialize(&self.store(), serialized_module)?;
+        self.build_with_module(&module)
+    }
+
+    /// Loads the given module from the cache, or compiles it if it was not
+    /// cached before, and instantiates a runtime for it.
+    ///
+    /// The cache is keyed by the target of the builder's store, so runtimes
+    /// with different stores may share the same cache.
+    pub fn build_with_cache(
+        self,
+        wasm_module: impl AsRef<[u8]>,
+        cache: &fp_bindgen_support::host::cache::ModuleCache,
+    ) -> Result<Runtime, RuntimeError> {
+        if let Some(verifier) = &self.signature_verifier {
+            verifier.verify(wasm_module.as_ref())?;
+        }
+        self.wasm_features.validate(wasm_module.as_ref())?;
+        let module = cache.load(&self.store(), wasm_module.as_ref())?;
+        self.build_with_module(&module)
+    }
+
+    fn store(&self) -> Store {
+        self.store
+            .clone()
+            .unwrap_or_else(|| Runtime::store_with_features(self.wasm_features))
+    }
+}
+
+/// The exports of the plugin, which are resolved and checked against the
+/// protocol once, when the runtime is created.
+struct ResolvedExports {
+    export_array_f32: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_array_f64: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_array_i16: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_array_i32: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_array_i8: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_array_u16: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_array_u32: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_array_u8: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_async_struct: Option<NativeFunc<(FatPtr, <u64 as WasmAbi>::AbiType), FatPtr>>,
+    export_batch_response: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_cached_call_count: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_call_rate_limited_import: Option<NativeFunc<<u32 as WasmAbi>::AbiType, FatPtr>>,
+    export_collections: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_dynamic_value: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_edge_values: Option<NativeFunc<FatPtr, FatPtr>>,
+    #[cfg(feature = "experimental")]
+    export_experimental: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_fp_adjacently_tagged: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_fp_enum: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_fp_flatten: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_fp_internally_tagged: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_fp_struct: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_fp_untagged: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_generics: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_get_bytes: Option<NativeFunc<(), FatPtr>>,
+    export_get_serde_bytes: Option<NativeFunc<(), FatPtr>>,
+    export_lazy_string: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_metrics: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_multiple_primitives:
+        Option<NativeFunc<(<i8 as WasmAbi>::AbiType, FatPtr), <i64 as WasmAbi>::AbiType>>,
+    export_multiple_values: Option<NativeFunc<<u32 as WasmAbi>::AbiType, FatPtr>>,
+    export_primitive_bool:
+        Option<NativeFunc<<bool as WasmAbi>::AbiType, <bool as WasmAbi>::AbiType>>,
+    export_primitive_f32: Option<NativeFunc<<f32 as WasmAbi>::AbiType, <f32 as WasmAbi>::AbiType>>,
+    export_primitive_f64: Option<NativeFunc<<f64 as WasmAbi>::AbiType, <f64 as WasmAbi>::AbiType>>,
+    export_primitive_i16: Option<NativeFunc<<i16 as WasmAbi>::AbiType, <i16 as WasmAbi>::AbiType>>,
+    export_primitive_i32: Option<NativeFunc<<i32 as WasmAbi>::AbiType, <i32 as WasmAbi>::AbiType>>,
+    export_primitive_i64: Option<NativeFunc<<i64 as WasmAbi>::AbiType, <i64 as WasmAbi>::AbiType>>,
+    export_primitive_i8: Option<NativeFunc<<i8 as WasmAbi>::AbiType, <i8 as WasmAbi>::AbiType>>,
+    export_primitive_pair:
+        Option<NativeFunc<<u32 as WasmAbi>::AbiType, <(u32, i32) as WasmAbi>::AbiType>>,
+    export_primitive_u16: Option<NativeFunc<<u16 as WasmAbi>::AbiType, <u16 as WasmAbi>::AbiType>>,
+    export_primitive_u32: Option<NativeFunc<<u32 as WasmAbi>::AbiType, <u32 as WasmAbi>::AbiType>>,
+    export_primitive_u64: Option<NativeFunc<<u64 as WasmAbi>::AbiType, <u64 as WasmAbi>::AbiType>>,
+    export_primitive_u8: Option<NativeFunc<<u8 as WasmAbi>::AbiType, <u8 as WasmAbi>::AbiType>>,
+    export_renamed_string: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_samples: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_serde_adjacently_tagged: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_serde_enum: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_serde_flatten: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_serde_internally_tagged: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_serde_struct: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_serde_untagged: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_string: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_string_after_memory_growth: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_struct_with_options: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_timestamp: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_timestamps: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_typed_id: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_validated_struct: Option<NativeFunc<FatPtr, FatPtr>>,
+    export_void_function: Option<NativeFunc<(), ()>>,
+    fetch_data: Option<NativeFunc<FatPtr, FatPtr>>,
+    init: Option<NativeFunc<FatPtr, ()>>,
+    reducer_bridge: Option<NativeFunc<FatPtr, FatPtr>>,
+    update_config: Option<NativeFunc<FatPtr, ()>>,
+    missing: Vec<PluginExport>,
+}
+
+impl ResolvedExports {
+    fn resolve(instance: &Instance) -> Result<Self, RuntimeError> {
+        let mut missing = Vec::new();
+        Ok(Self {
+            export_array_f32: Self::resolve_function(
+                instance,
+                PluginExport::ExportArrayF32,
+                &mut missing,
+            )?,
+            export_array_f64: Self::resolve_function(
+                instance,
+                PluginExport::ExportArrayF64,
+                &mut missing,
+            )?,
+            export_array_i16: Self::resolve_function(
+                instance,
+                PluginExport::ExportArrayI16,
+                &mut missing,
+            )?,
+            export_array_i32: Self::resolve_function(
+                instance,
+                PluginExport::ExportArrayI32,
+                &mut missing,
+            )?,
+            export_array_i8: Self::resolve_function(
+                instance,
+                PluginExport::ExportArrayI8,
+                &mut missing,
+            )?,
+            export_array_u16: Self::resolve_function(
+                instance,
+                PluginExport::ExportArrayU16,
+                &mut missing,
+            )?,
+            export_array_u32: Self::resolve_function(
+                instance,
+                PluginExport::ExportArrayU32,
+                &mut missing,
+            )?,
+            export_array_u8: Self::resolve_function(
+                instance,
+                PluginExport::ExportArrayU8,
+                &mut missing,
+            )?,
+            export_async_struct: Self::resolve_function(
+                instance,
+                PluginExport::ExportAsyncStruct,
+                &mut missing,
+            )?,
+            export_batch_response: Self::resolve_function(
+                instance,
+                PluginExport::ExportBatchResponse,
+                &mut missing,
+            )?,
+            export_cached_call_count: Self::resolve_function(
+                instance,
+                PluginExport::ExportCachedCallCount,
+                &mut missing,
+            )?,
+            export_call_rate_limited_import: Self::resolve_function(
+                instance,
+                PluginExport::ExportCallRateLimitedImport,
+                &mut missing,
+            )?,
+            export_collections: Self::resolve_function(
+                instance,
+                PluginExport::ExportCollections,
+                &mut missing,
+            )?,
+            export_dynamic_value: Self::resolve_function(
+                instance,
+                PluginExport::ExportDynamicValue,
+                &mut missing,
+            )?,
+            export_edge_values: Self::resolve_function(
+                instance,
+                PluginExport::ExportEdgeValues,
+                &mut missing,
+            )?,
+            #[cfg(feature = "experimental")]
+            export_experimental: Self::resolve_function(
+                instance,
+                PluginExport::ExportExperimental,
+                &mut missing,
+            )?,
+            export_fp_adjacently_tagged: Self::resolve_function(
+                instance,
+                PluginExport::ExportFpAdjacentlyTagged,
+                &mut missing,
+            )?,
+            export_fp_enum: Self::resolve_function(
+                instance,
+                PluginExport::ExportFpEnum,
+                &mut missing,
+            )?,
+            export_fp_flatten: Self::resolve_function(
+                instance,
+                PluginExport::ExportFpFlatten,
+                &mut missing,
+            )?,
+            export_fp_internally_tagged: Self::resolve_function(
+                instance,
+                PluginExport::ExportFpInternallyTagged,
+                &mut missing,
+            )?,
+            export_fp_struct: Self::resolve_function(
+                instance,
+                PluginExport::ExportFpStruct,
+                &mut missing,
+            )?,
+            export_fp_untagged: Self::resolve_function(
+                instance,
+                PluginExport::ExportFpUntagged,
+                &mut missing,
+            )?,
+            export_generics: Self::resolve_function(
+                instance,
+                PluginExport::ExportGenerics,
+                &mut missing,
+            )?,
+            export_get_bytes: Self::resolve_function(
+                instance,
+                PluginExport::ExportGetBytes,
+                &mut missing,
+            )?,
+            export_get_serde_bytes: Self::resolve_function(
+                instance,
+                PluginExport::ExportGetSerdeBytes,
+                &mut missing,
+            )?,
+            export_lazy_string: Self::resolve_function(
+                instance,
+                PluginExport::ExportLazyString,
+                &mut missing,
+            )?,
+            export_metrics: Self::resolve_function(
+                instance,
+                PluginExport::ExportMetrics,
+                &mut missing,
+            )?,
+            export_multiple_primitives: Self::resolve_function(
+                instance,
+                PluginExport::ExportMultiplePrimitives,
+                &mut missing,
+            )?,
+            export_multiple_values: Self::resolve_function(
+                instance,
+                PluginExport::ExportMultipleValues,
+                &mut missing,
+            )?,
+            export_primitive_bool: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveBool,
+                &mut missing,
+            )?,
+            export_primitive_f32: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveF32,
+                &mut missing,
+            )?,
+            export_primitive_f64: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveF64,
+                &mut missing,
+            )?,
+            export_primitive_i16: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveI16,
+                &mut missing,
+            )?,
+            export_primitive_i32: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveI32,
+                &mut missing,
+            )?,
+            export_primitive_i64: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveI64,
+                &mut missing,
+            )?,
+            export_primitive_i8: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveI8,
+                &mut missing,
+            )?,
+            export_primitive_pair: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitivePair,
+                &mut missing,
+            )?,
+            export_primitive_u16: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveU16,
+                &mut missing,
+            )?,
+            export_primitive_u32: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveU32,
+                &mut missing,
+            )?,
+            export_primitive_u64: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveU64,
+                &mut missing,
+            )?,
+            export_primitive_u8: Self::resolve_function(
+                instance,
+                PluginExport::ExportPrimitiveU8,
+                &mut missing,
+            )?,
+            export_renamed_string: Self::resolve_function(
+                instance,
+                PluginExport::ExportRenamedString,
+                &mut missing,
+            )?,
+            export_samples: Self::resolve_function(
+                instance,
+                PluginExport::ExportSamples,
+                &mut missing,
+            )?,
+            export_serde_adjacently_tagged: Self::resolve_function(
+                instance,
+                PluginExport::ExportSerdeAdjacentlyTagged,
+                &mut missing,
+            )?,
+            export_serde_enum: Self::resolve_function(
+                instance,
+                PluginExport::ExportSerdeEnum,
+                &mut missing,
+            )?,
+            export_serde_flatten: Self::resolve_function(
+                instance,
+                PluginExport::ExportSerdeFlatten,
+                &mut missing,
+            )?,
+            export_serde_internally_tagged: Self::resolve_function(
+                instance,
+                PluginExport::ExportSerdeInternallyTagged,
+                &mut missing,
+            )?,
+            export_serde_struct: Self::resolve_function(
+                instance,
+                PluginExport::ExportSerdeStruct,
+                &mut missing,
+            )?,
+            export_serde_untagged: Self::resolve_function(
+                instance,
+                PluginExport::ExportSerdeUntagged,
+                &mut missing,
+            )?,
+            export_string: Self::resolve_function(
+                instance,
+                PluginExport::ExportString,
+                &mut missing,
+            )?,
+            export_string_after_memory_growth: Self::resolve_function(
+                instance,
+                PluginExport::ExportStringAfterMemoryGrowth,
+                &mut missing,
+            )?,
+            export_struct_with_options: Self::resolve_function(
+                instance,
+                PluginExport::ExportStructWithOptions,
+                &mut missing,
+            )?,
+            export_timestamp: Self::resolve_function(
+                instance,
+                PluginExport::ExportTimestamp,
+                &mut missing,
+            )?,
+            export_timestamps: Self::resolve_function(
+                instance,
+                PluginExport::ExportTimestamps,
+                &mut missing,
+            )?,
+            export_typed_id: Self::resolve_function(
+                instance,
+                PluginExport::ExportTypedId,
+                &mut missing,
+            )?,
+            export_validated_struct: Self::resolve_function(
+                instance,
+                PluginExport::ExportValidatedStruct,
+                &mut missing,
+            )?,
+            export_void_function: Self::resolve_function(
+                instance,
+                PluginExport::ExportVoidFunction,
+                &mut missing,
+            )?,
+            fetch_data: Self::resolve_function(instance, PluginExport::FetchData, &mut missing)?,
+            init: Self::resolve_function(instance, PluginExport::Init, &mut missing)?,
+            reducer_bridge: Self::resolve_function(
+                instance,
+                PluginExport::ReducerBridge,
+                &mut missing,
+            )?,
+            update_config: Self::resolve_function(
+                instance,
+                PluginExport::UpdateConfig,
+                &mut missing,
+            )?,
+            missing,
+        })
+    }
+
+    fn resolve_function<Args: WasmTypeList, Rets: WasmTypeList>(
+        instance: &Instance,
+        export: PluginExport,
+        missing: &mut Vec<PluginExport>,
+    ) -> Result<Option<NativeFunc<Args, Rets>>, RuntimeError> {
+        let function = resolve_export(instance, export.symbol_name()).map_err(|error| *error)?;
+        if function.is_none() {
+            missing.push(export);
+        }
+        Ok(function)
+    }
+}
+
+/// A pool of pre-instantiated runtimes for the same plugin module.
+///
+/// Runtimes are checked out from the pool using `get()`, and are returned to
+/// the pool when the `PooledRuntime` is dropped. Dynamic import handlers are
+/// shared by all runtimes in the pool.
+pub struct RuntimePool {
+    module: Module,
+    env: RuntimeInstanceData,
+    capacity: usize,
+    runtimes: std::sync::Mutex<Vec<Runtime>>,
+}
+
+impl RuntimePool {
+    /// Compiles the given module and pre-instantiates `capacity` runtimes
+    /// for it, which do not grant any capabilities to the plugin.
+    pub fn with_capacity(
+        wasm_module: impl AsRef<[u8]>,
+        capacity: usize,
+    ) -> Result<Self, RuntimeError> {
+        Self::with_capacity_and_capabilities(wasm_module, capacity, &[])
+    }
+
+    /// Compiles the given module and pre-instantiates `capacity` runtimes
+    /// for it, which grant the given capabilities to the plugin.
+    pub fn with_capacity_and_capabilities(
+        wasm_module: impl AsRef<[u8]>,
+        capacity: usize,
+        capabilities: &[Capability],
+    ) -> Result<Self, RuntimeError> {
+        let store = Runtime::default_store();
+        let module = Module::new(&store, wasm_module)?;
+        let env = RuntimeInstanceData::default()
+            .with_capabilities(capabilities.iter().map(Capability::as_str));
+        let runtimes = (0..capacity)
+            .map(|_| Runtime::from_module(&module, env.with_shared_dynamic_import_handlers()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            module,
+            env,
+            capacity,
+            runtimes: std::sync::Mutex::new(runtimes),
+        })
+    }
+
+    /// Checks out a runtime from the pool.
+    ///
+    /// If all pre-instantiated runtimes are in use, a new one is instantiated
+    /// on demand. Runtimes beyond the pool's capacity are discarded when they
+    /// are dropped.
+    pub fn get(&self) -> Result<PooledRuntime<'_>, RuntimeError> {
+        let runtime = self.runtimes.lock().unwrap().pop();
+        let runtime = match runtime {
+            Some(runtime) => runtime,
+            None => {
+                Runtime::from_module(&self.module, self.env.with_shared_dynamic_import_handlers())?
+            }
+        };
+        Ok(PooledRuntime {
+            pool: self,
+            runtime: Some(runtime),
+        })
+    }
+}
+
+/// A runtime that is checked out from a `RuntimePool`.
+pub struct PooledRuntime<'a> {
+    pool: &'a RuntimePool,
+    runtime: Option<Runtime>,
+}
+
+impl std::ops::Deref for PooledRuntime<'_> {
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {
+        self.runtime.as_ref().unwrap()
+    }
+}
+
+impl Drop for PooledRuntime<'_> {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            let mut runtimes = self.pool.runtimes.lock().unwrap();
+            if runtimes.len() < self.pool.capacity {
+                runtimes.push(runtime);
+            }
+        }
+    }
+}
+
+/// A runtime that can be shared between threads.
+///
+/// A plugin instance can only run one call at a time, so the shared runtime
+/// holds a fixed number of instances of the same module. Every call is run on
+/// an instance that is not in use. If all of them are busy, calls to regular
+/// exports block the calling thread until one becomes available, while calls
+/// to async exports wait for one without blocking the thread. Instances are
+/// handed out in the order in which they were requested, and async exports
+/// keep their instance until their result has been resolved.
+///
+/// Instances don't share their memory, so state that the plugin keeps between
+/// calls is not shared between the instances either. Dynamic import handlers
+/// are shared by all instances, and clones of the shared runtime use the same
+/// instances.
+#[derive(Clone)]
+pub struct SharedRuntime {
+    inner: Arc<SharedRuntimeInner>,
+}
+
+struct SharedRuntimeInner {
+    idle: std::sync::Mutex<Vec<Runtime>>,
+    /// Grants one permit per idle instance, so that a permit holder always
+    /// finds an instance to check out.
+    permits: fp_bindgen_support::host::r#async::limit::ConcurrencyLimit,
+}
+
+impl SharedRuntime {
+    /// Compiles the given module and creates `instances` instances of it,
+    /// which do not grant any capabilities to the plugin.
+    pub fn new(wasm_module: impl AsRef<[u8]>, instances: usize) -> Result<Self, RuntimeError> {
+        Self::with_capabilities(wasm_module, instances, &[])
+    }
+
+    /// Compiles the given module and creates `instances` instances of it,
+    /// which grant the given capabilities to the plugin.
+    pub fn with_capabilities(
+        wasm_module: impl AsRef<[u8]>,
+        instances: usize,
+        capabilities: &[Capability],
+    ) -> Result<Self, RuntimeError> {
+        let store = Runtime::default_store();
+        let module = Module::new(&store, wasm_module)?;
+        let env = RuntimeInstanceData::default()
+            .with_capabilities(capabilities.iter().map(Capability::as_str));
+        assert!(
+            instances > 0,
+            "A shared runtime needs at least one instance"
+        );
+        let runtimes = (0..instances)
+            .map(|_| Runtime::from_module(&module, env.with_shared_dynamic_import_handlers()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            inner: Arc::new(SharedRuntimeInner {
+                idle: std::sync::Mutex::new(runtimes),
+                permits: fp_bindgen_support::host::r#async::limit::ConcurrencyLimit::new(
+                    instances,
+                    usize::MAX,
+                ),
+            }),
+        })
+    }
+
+    /// Checks out an instance that is not in use, which blocks the current
+    /// thread until one becomes available. Async code should use
+    /// `get_async()` instead.
+    ///
+    /// The instance is returned to the shared runtime when the
+    /// `SharedRuntimeGuard` is dropped. Calling the methods of the shared
+    /// runtime while holding on to a guard can therefore deadlock if there
+    /// are no other instances.
+    pub fn get(&self) -> SharedRuntimeGuard<'_> {
+        let permit = self.inner.permits.acquire_blocking().unwrap();
+        self.check_out(permit)
+    }
+
+    /// Checks out an instance that is not in use, once one becomes available.
+    ///
+    /// Unlike `get()`, this doesn't block the current thread while all
+    /// instances are busy.
+    pub async fn get_async(&self) -> SharedRuntimeGuard<'_> {
+        let permit = self.inner.permits.acquire().unwrap().await;
+        self.check_out(permit)
+    }
+
+    fn check_out(
+        &self,
+        permit: fp_bindgen_support::host::r#async::limit::ConcurrencyPermit,
+    ) -> SharedRuntimeGuard<'_> {
+        let runtime = self.inner.idle.lock().unwrap().pop();
+        SharedRuntimeGuard {
+            shared: &self.inner,
+            runtime: Some(runtime.expect("A permit was granted without an idle instance")),
+            _permit: permit,
+        }
+    }
+
+    pub fn export_array_f32(&self, arg: [f32; 3]) -> Result<[f32; 3], PluginError> {
+        self.get().export_array_f32(arg)
+    }
+    pub fn export_array_f32_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_array_f32_raw(arg)
+    }
+
+    pub fn export_array_f64(&self, arg: [f64; 3]) -> Result<[f64; 3], PluginError> {
+        self.get().export_array_f64(arg)
+    }
+    pub fn export_array_f64_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_array_f64_raw(arg)
+    }
+
+    pub fn export_array_i16(&self, arg: [i16; 3]) -> Result<[i16; 3], PluginError> {
+        self.get().export_array_i16(arg)
+    }
+    pub fn export_array_i16_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_array_i16_raw(arg)
+    }
+
+    pub fn export_array_i32(&self, arg: [i32; 3]) -> Result<[i32; 3], PluginError> {
+        self.get().export_array_i32(arg)
+    }
+    pub fn export_array_i32_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_array_i32_raw(arg)
+    }
+
+    pub fn export_array_i8(&self, arg: [i8; 3]) -> Result<[i8; 3], PluginError> {
+        self.get().export_array_i8(arg)
+    }
+    pub fn export_array_i8_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_array_i8_raw(arg)
+    }
+
+    pub fn export_array_u16(&self, arg: [u16; 3]) -> Result<[u16; 3], PluginError> {
+        self.get().export_array_u16(arg)
+    }
+    pub fn export_array_u16_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_array_u16_raw(arg)
+    }
+
+    pub fn export_array_u32(&self, arg: [u32; 3]) -> Result<[u32; 3], PluginError> {
+        self.get().export_array_u32(arg)
+    }
+    pub fn export_array_u32_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_array_u32_raw(arg)
+    }
+
+    pub fn export_array_u8(&self, arg: [u8; 3]) -> Result<[u8; 3], PluginError> {
+        self.get().export_array_u8(arg)
+    }
+    pub fn export_array_u8_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_array_u8_raw(arg)
+    }
+
+    pub async fn export_async_struct(
+        &self,
+        arg1: FpPropertyRenaming,
+        arg2: u64,
+    ) -> Result<FpPropertyRenaming, PluginError> {
+        self.get_async().await.export_async_struct(arg1, arg2).await
+    }
+    pub async fn export_async_struct_raw(
+        &self,
+        arg1: impl AsRef<[u8]>,
+        arg2: u64,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get_async()
+            .await
+            .export_async_struct_raw(arg1, arg2)
+            .await
+    }
+
+    pub fn export_batch_response(&self, arg: BatchResponse) -> Result<BatchResponse, PluginError> {
+        self.get().export_batch_response(arg)
+    }
+    pub fn export_batch_response_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_batch_response_raw(arg)
+    }
+
+    pub fn export_cached_call_count(&self, label: String) -> Result<String, PluginError> {
+        self.get().export_cached_call_count(label)
+    }
+    pub fn export_cached_call_count_raw(
+        &self,
+        label: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_cached_call_count_raw(label)
+    }
+
+    pub fn export_call_rate_limited_import(
+        &self,
+        calls: u32,
+    ) -> Result<Vec<Result<String, RateLimited>>, PluginError> {
+        self.get().export_call_rate_limited_import(calls)
+    }
+    pub fn export_call_rate_limited_import_raw(
+        &self,
+        calls: u32,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_call_rate_limited_import_raw(calls)
+    }
+
+    pub fn export_collections(&self, arg: Collections) -> Result<Collections, PluginError> {
+        self.get().export_collections(arg)
+    }
+    pub fn export_collections_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_collections_raw(arg)
+    }
+
+    pub fn export_dynamic_value(
+        &self,
+        arg: ExtensionSettings,
+    ) -> Result<serde_json::Value, PluginError> {
+        self.get().export_dynamic_value(arg)
+    }
+    pub fn export_dynamic_value_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_dynamic_value_raw(arg)
+    }
+
+    pub fn export_edge_values(&self, arg: EdgeValues) -> Result<EdgeValues, PluginError> {
+        self.get().export_edge_values(arg)
+    }
+    pub fn export_edge_values_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_edge_values_raw(arg)
+    }
+
+    #[cfg(feature = "experimental")]
+    pub fn export_experimental(&self, arg: String) -> Result<String, PluginError> {
+        self.get().export_experimental(arg)
+    }
+    #[cfg(feature = "experimental")]
+    pub fn export_experimental_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_experimental_raw(arg)
+    }
+
+    pub fn export_fp_adjacently_tagged(
+        &self,
+        arg: FpAdjacentlyTagged,
+    ) -> Result<FpAdjacentlyTagged, PluginError> {
+        self.get().export_fp_adjacently_tagged(arg)
+    }
+    pub fn export_fp_adjacently_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_fp_adjacently_tagged_raw(arg)
+    }
+
+    pub fn export_fp_enum(&self, arg: FpVariantRenaming) -> Result<FpVariantRenaming, PluginError> {
+        self.get().export_fp_enum(arg)
+    }
+    pub fn export_fp_enum_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_fp_enum_raw(arg)
+    }
+
+    pub fn export_fp_flatten(&self, arg: FpFlatten) -> Result<FpFlatten, PluginError> {
+        self.get().export_fp_flatten(arg)
+    }
+    pub fn export_fp_flatten_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_fp_flatten_raw(arg)
+    }
+
+    pub fn export_fp_internally_tagged(
+        &self,
+        arg: FpInternallyTagged,
+    ) -> Result<FpInternallyTagged, PluginError> {
+        self.get().export_fp_internally_tagged(arg)
+    }
+    pub fn export_fp_internally_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_fp_internally_tagged_raw(arg)
+    }
+
+    pub fn export_fp_struct(
+        &self,
+        arg: FpPropertyRenaming,
+    ) -> Result<FpPropertyRenaming, PluginError> {
+        self.get().export_fp_struct(arg)
+    }
+    pub fn export_fp_struct_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_fp_struct_raw(arg)
+    }
+
+    pub fn export_fp_untagged(&self, arg: FpUntagged) -> Result<FpUntagged, PluginError> {
+        self.get().export_fp_untagged(arg)
+    }
+    pub fn export_fp_untagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_fp_untagged_raw(arg)
+    }
+
+    pub fn export_generics(
+        &self,
+        arg: StructWithGenerics<u64>,
+    ) -> Result<StructWithGenerics<u64>, PluginError> {
+        self.get().export_generics(arg)
+    }
+    pub fn export_generics_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_generics_raw(arg)
+    }
+
+    pub fn export_get_bytes(&self) -> Result<Result<bytes::Bytes, String>, PluginError> {
+        self.get().export_get_bytes()
+    }
+    pub fn export_get_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_get_bytes_raw()
+    }
+
+    pub fn export_get_serde_bytes(
+        &self,
+    ) -> Result<Result<serde_bytes::ByteBuf, String>, PluginError> {
+        self.get().export_get_serde_bytes()
+    }
+    pub fn export_get_serde_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_get_serde_bytes_raw()
+    }
+
+    pub fn export_lazy_string(&self, arg: String) -> Result<Lazy<String>, PluginError> {
+        self.get().export_lazy_string(arg)
+    }
+    pub fn export_lazy_string_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_lazy_string_raw(arg)
+    }
+
+    pub fn export_metrics(&self, metrics: Vec<Metric>) -> Result<Vec<Metric>, PluginError> {
+        self.get().export_metrics(metrics)
+    }
+    pub fn export_metrics_raw(
+        &self,
+        metrics: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_metrics_raw(metrics)
+    }
+
+    pub fn export_multiple_primitives(&self, arg1: i8, arg2: String) -> Result<i64, PluginError> {
+        self.get().export_multiple_primitives(arg1, arg2)
+    }
+    pub fn export_multiple_primitives_raw(
+        &self,
+        arg1: i8,
+        arg2: impl AsRef<[u8]>,
+    ) -> Result<i64, InvocationError> {
+        self.get().export_multiple_primitives_raw(arg1, arg2)
+    }
+
+    pub fn export_multiple_values(
+        &self,
+        arg: u32,
+    ) -> Result<(String, Vec<u32>, bool), PluginError> {
+        self.get().export_multiple_values(arg)
+    }
+    pub fn export_multiple_values_raw(&self, arg: u32) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_multiple_values_raw(arg)
+    }
+
+    pub fn export_primitive_bool(&self, arg: bool) -> Result<bool, PluginError> {
+        self.get().export_primitive_bool(arg)
+    }
+    pub fn export_primitive_bool_raw(&self, arg: bool) -> Result<bool, InvocationError> {
+        self.get().export_primitive_bool_raw(arg)
+    }
+
+    pub fn export_primitive_f32(&self, arg: f32) -> Result<f32, PluginError> {
+        self.get().export_primitive_f32(arg)
+    }
+    pub fn export_primitive_f32_raw(&self, arg: f32) -> Result<f32, InvocationError> {
+        self.get().export_primitive_f32_raw(arg)
+    }
+
+    pub fn export_primitive_f64(&self, arg: f64) -> Result<f64, PluginError> {
+        self.get().export_primitive_f64(arg)
+    }
+    pub fn export_primitive_f64_raw(&self, arg: f64) -> Result<f64, InvocationError> {
+        self.get().export_primitive_f64_raw(arg)
+    }
+
+    pub fn export_primitive_i16(&self, arg: i16) -> Result<i16, PluginError> {
+        self.get().export_primitive_i16(arg)
+    }
+    pub fn export_primitive_i16_raw(&self, arg: i16) -> Result<i16, InvocationError> {
+        self.get().export_primitive_i16_raw(arg)
+    }
+
+    pub fn export_primitive_i32(&self, arg: i32) -> Result<i32, PluginError> {
+        self.get().export_primitive_i32(arg)
+    }
+    pub fn export_primitive_i32_raw(&self, arg: i32) -> Result<i32, InvocationError> {
+        self.get().export_primitive_i32_raw(arg)
+    }
+
+    pub fn export_primitive_i64(&self, arg: i64) -> Result<i64, PluginError> {
+        self.get().export_primitive_i64(arg)
+    }
+    pub fn export_primitive_i64_raw(&self, arg: i64) -> Result<i64, InvocationError> {
+        self.get().export_primitive_i64_raw(arg)
+    }
+
+    pub fn export_primitive_i8(&self, arg: i8) -> Result<i8, PluginError> {
+        self.get().export_primitive_i8(arg)
+    }
+    pub fn export_primitive_i8_raw(&self, arg: i8) -> Result<i8, InvocationError> {
+        self.get().export_primitive_i8_raw(arg)
+    }
+
+    pub fn export_primitive_pair(&self, arg: u32) -> Result<(u32, i32), PluginError> {
+        self.get().export_primitive_pair(arg)
+    }
+    pub fn export_primitive_pair_raw(&self, arg: u32) -> Result<(u32, i32), InvocationError> {
+        self.get().export_primitive_pair_raw(arg)
+    }
+
+    pub fn export_primitive_u16(&self, arg: u16) -> Result<u16, PluginError> {
+        self.get().export_primitive_u16(arg)
+    }
+    pub fn export_primitive_u16_raw(&self, arg: u16) -> Result<u16, InvocationError> {
+        self.get().export_primitive_u16_raw(arg)
+    }
+
+    pub fn export_primitive_u32(&self, arg: u32) -> Result<u32, PluginError> {
+        self.get().export_primitive_u32(arg)
+    }
+    pub fn export_primitive_u32_raw(&self, arg: u32) -> Result<u32, InvocationError> {
+        self.get().export_primitive_u32_raw(arg)
+    }
+
+    pub fn export_primitive_u64(&self, arg: u64) -> Result<u64, PluginError> {
+        self.get().export_primitive_u64(arg)
+    }
+    pub fn export_primitive_u64_raw(&self, arg: u64) -> Result<u64, InvocationError> {
+        self.get().export_primitive_u64_raw(arg)
+    }
+
+    pub fn export_primitive_u8(&self, arg: u8) -> Result<u8, PluginError> {
+        self.get().export_primitive_u8(arg)
+    }
+    pub fn export_primitive_u8_raw(&self, arg: u8) -> Result<u8, InvocationError> {
+        self.get().export_primitive_u8_raw(arg)
+    }
+
+    pub fn export_renamed_string(&self, arg: String) -> Result<String, PluginError> {
+        self.get().export_renamed_string(arg)
+    }
+    pub fn export_renamed_string_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_renamed_string_raw(arg)
+    }
+
+    pub fn export_samples(&self, samples: SampleColumns) -> Result<SampleColumns, PluginError> {
+        self.get().export_samples(samples)
+    }
+    pub fn export_samples_raw(
+        &self,
+        samples: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_samples_raw(samples)
+    }
+
+    pub fn export_serde_adjacently_tagged(
+        &self,
+        arg: SerdeAdjacentlyTagged,
+    ) -> Result<SerdeAdjacentlyTagged, PluginError> {
+        self.get().export_serde_adjacently_tagged(arg)
+    }
+    pub fn export_serde_adjacently_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_serde_adjacently_tagged_raw(arg)
+    }
+
+    pub fn export_serde_enum(
+        &self,
+        arg: SerdeVariantRenaming,
+    ) -> Result<SerdeVariantRenaming, PluginError> {
+        self.get().export_serde_enum(arg)
+    }
+    pub fn export_serde_enum_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_serde_enum_raw(arg)
+    }
+
+    pub fn export_serde_flatten(&self, arg: SerdeFlatten) -> Result<SerdeFlatten, PluginError> {
+        self.get().export_serde_flatten(arg)
+    }
+    pub fn export_serde_flatten_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_serde_flatten_raw(arg)
+    }
+
+    pub fn export_serde_internally_tagged(
+        &self,
+        arg: SerdeInternallyTagged,
+    ) -> Result<SerdeInternallyTagged, PluginError> {
+        self.get().export_serde_internally_tagged(arg)
+    }
+    pub fn export_serde_internally_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_serde_internally_tagged_raw(arg)
+    }
+
+    pub fn export_serde_struct(
+        &self,
+        arg: SerdePropertyRenaming,
+    ) -> Result<SerdePropertyRenaming, PluginError> {
+        self.get().export_serde_struct(arg)
+    }
+    pub fn export_serde_struct_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_serde_struct_raw(arg)
+    }
+
+    pub fn export_serde_untagged(&self, arg: SerdeUntagged) -> Result<SerdeUntagged, PluginError> {
+        self.get().export_serde_untagged(arg)
+    }
+    pub fn export_serde_untagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_serde_untagged_raw(arg)
+    }
+
+    pub fn export_string(&self, arg: String) -> Result<String, PluginError> {
+        self.get().export_string(arg)
+    }
+    pub fn export_string_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_string_raw(arg)
+    }
+
+    pub fn export_string_after_memory_growth(&self, arg: String) -> Result<String, PluginError> {
+        self.get().export_string_after_memory_growth(arg)
+    }
+    pub fn export_string_after_memory_growth_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_string_after_memory_growth_raw(arg)
+    }
+
+    pub fn export_struct_with_options(
+        &self,
+        arg: StructWithOptions,
+    ) -> Result<StructWithOptions, PluginError> {
+        self.get().export_struct_with_options(arg)
+    }
+    pub fn export_struct_with_options_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_struct_with_options_raw(arg)
+    }
+
+    pub fn export_timestamp(&self, arg: MyDateTime) -> Result<MyDateTime, PluginError> {
+        self.get().export_timestamp(arg)
+    }
+    pub fn export_timestamp_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_timestamp_raw(arg)
+    }
+
+    pub fn export_timestamps(&self, arg: Timestamps) -> Result<Timestamps, PluginError> {
+        self.get().export_timestamps(arg)
+    }
+    pub fn export_timestamps_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_timestamps_raw(arg)
+    }
+
+    pub fn export_typed_id(
+        &self,
+        id: TypedId<Point<f64>>,
+    ) -> Result<TypedId<Point<f64>>, PluginError> {
+        self.get().export_typed_id(id)
+    }
+    pub fn export_typed_id_raw(&self, id: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_typed_id_raw(id)
+    }
+
+    pub fn export_validated_struct(
+        &self,
+        arg: ValidatedStruct,
+    ) -> Result<ValidatedStruct, PluginError> {
+        self.get().export_validated_struct(arg)
+    }
+    pub fn export_validated_struct_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get().export_validated_struct_raw(arg)
+    }
+
+    pub fn export_void_function(&self) -> Result<(), PluginError> {
+        self.get().export_void_function()
+    }
+    pub fn export_void_function_raw(&self) -> Result<(), InvocationError> {
+        self.get().export_void_function_raw()
+    }
+
+    /// Example how plugin could expose async data-fetching capabilities.
+    pub async fn fetch_data(&self, r#type: String) -> Result<Result<String, String>, PluginError> {
+        self.get_async().await.fetch_data(r#type).await
+    }
+    pub async fn fetch_data_raw(
+        &self,
+        r#type: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        self.get_async().await.fetch_data_raw(r#type).await
+    }
+
+    /// Called on the plugin to give it a chance to initialize.
+    pub fn init(&self, config: PluginConfig) -> Result<(), PluginError> {
+        self.get().init(config)
+    }
+    pub fn init_raw(&self, config: impl AsRef<[u8]>) -> Result<(), InvocationError> {
+        self.get().init_raw(config)
+    }
+
+    /// Example how plugin could expose a reducer.
+    pub fn reducer_bridge(&self, action: ReduxAction) -> Result<StateUpdate, PluginError> {
+        self.get().reducer_bridge(action)
+    }
+    pub fn reducer_bridge_raw(&self, action: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        self.get().reducer_bridge_raw(action)
+    }
+
+    /// Called on the plugin when the host changes its config.
+    pub fn update_config(&self, config: PluginConfig) -> Result<(), PluginError> {
+        self.get().update_config(config)
+    }
+    pub fn update_config_raw(&self, config: impl AsRef<[u8]>) -> Result<(), InvocationError> {
+        self.get().update_config_raw(config)
+    }
+}
+
+/// An instance that is checked out from a `SharedRuntime`.
+pub struct SharedRuntimeGuard<'a> {
+    shared: &'a SharedRuntimeInner,
+    runtime: Option<Runtime>,
+    // Dropped after `drop()` has returned the instance, so that the next
+    // permit holder finds it idle:
+    _permit: fp_bindgen_support::host::r#async::limit::ConcurrencyPermit,
+}
+
+impl std::ops::Deref for SharedRuntimeGuard<'_> {
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {
+        self.runtime.as_ref().unwrap()
+    }
+}
+
+impl Drop for SharedRuntimeGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            self.shared.idle.lock().unwrap().push(runtime);
+        }
+    }
+}
+
+// Fails to compile if the shared runtime cannot be shared between threads:
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SharedRuntime>();
+};
+
+/// A capability that can be granted to the plugin. Imports that require a
+/// capability can only be called by the plugin if it was granted.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Capability {
+    Clock,
+    Fs,
+    Http,
+    Network,
+    Random,
+}
+
+impl Capability {
+    /// Returns the name of the capability, as it is declared in the protocol.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Clock => "clock",
+            Self::Fs => "fs",
+            Self::Http => "http",
+            Self::Network => "network",
+            Self::Random => "random",
+        }
+    }
+}
+
+/// An export of the protocol, which identifies the export that failed in a
+/// `PluginError`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PluginExport {
+    ExportArrayF32,
+    ExportArrayF64,
+    ExportArrayI16,
+    ExportArrayI32,
+    ExportArrayI8,
+    ExportArrayU16,
+    ExportArrayU32,
+    ExportArrayU8,
+    ExportAsyncStruct,
+    ExportBatchResponse,
+    ExportCachedCallCount,
+    ExportCallRateLimitedImport,
+    ExportCollections,
+    ExportDynamicValue,
+    ExportEdgeValues,
+    #[cfg(feature = "experimental")]
+    ExportExperimental,
+    ExportFpAdjacentlyTagged,
+    ExportFpEnum,
+    ExportFpFlatten,
+    ExportFpInternallyTagged,
+    ExportFpStruct,
+    ExportFpUntagged,
+    ExportGenerics,
+    ExportGetBytes,
+    ExportGetSerdeBytes,
+    ExportLazyString,
+    ExportMetrics,
+    ExportMultiplePrimitives,
+    ExportMultipleValues,
+    ExportPrimitiveBool,
+    ExportPrimitiveF32,
+    ExportPrimitiveF64,
+    ExportPrimitiveI16,
+    ExportPrimitiveI32,
+    ExportPrimitiveI64,
+    ExportPrimitiveI8,
+    ExportPrimitivePair,
+    ExportPrimitiveU16,
+    ExportPrimitiveU32,
+    ExportPrimitiveU64,
+    ExportPrimitiveU8,
+    ExportRenamedString,
+    ExportSamples,
+    ExportSerdeAdjacentlyTagged,
+    ExportSerdeEnum,
+    ExportSerdeFlatten,
+    ExportSerdeInternallyTagged,
+    ExportSerdeStruct,
+    ExportSerdeUntagged,
+    ExportString,
+    ExportStringAfterMemoryGrowth,
+    ExportStructWithOptions,
+    ExportTimestamp,
+    ExportTimestamps,
+    ExportTypedId,
+    ExportValidatedStruct,
+    ExportVoidFunction,
+    FetchData,
+    Init,
+    ReducerBridge,
+    UpdateConfig,
+}
+
+impl PluginExport {
+    /// Returns the name of the export, as it is declared in the protocol.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Self::ExportArrayF32 => "export_array_f32",
+            Self::ExportArrayF64 => "export_array_f64",
+            Self::ExportArrayI16 => "export_array_i16",
+            Self::ExportArrayI32 => "export_array_i32",
+            Self::ExportArrayI8 => "export_array_i8",
+            Self::ExportArrayU16 => "export_array_u16",
+            Self::ExportArrayU32 => "export_array_u32",
+            Self::ExportArrayU8 => "export_array_u8",
+            Self::ExportAsyncStruct => "export_async_struct",
+            Self::ExportBatchResponse => "export_batch_response",
+            Self::ExportCachedCallCount => "export_cached_call_count",
+            Self::ExportCallRateLimitedImport => "export_call_rate_limited_import",
+            Self::ExportCollections => "export_collections",
+            Self::ExportDynamicValue => "export_dynamic_value",
+            Self::ExportEdgeValues => "export_edge_values",
+            #[cfg(feature = "experimental")]
+            Self::ExportExperimental => "export_experimental",
+            Self::ExportFpAdjacentlyTagged => "export_fp_adjacently_tagged",
+            Self::ExportFpEnum => "export_fp_enum",
+            Self::ExportFpFlatten => "export_fp_flatten",
+            Self::ExportFpInternallyTagged => "export_fp_internally_tagged",
+            Self::ExportFpStruct => "export_fp_struct",
+            Self::ExportFpUntagged => "export_fp_untagged",
+            Self::ExportGenerics => "export_generics",
+            Self::ExportGetBytes => "export_get_bytes",
+            Self::ExportGetSerdeBytes => "export_get_serde_bytes",
+            Self::ExportLazyString => "export_lazy_string",
+            Self::ExportMetrics => "export_metrics",
+            Self::ExportMultiplePrimitives => "export_multiple_primitives",
+            Self::ExportMultipleValues => "export_multiple_values",
+            Self::ExportPrimitiveBool => "export_primitive_bool",
+            Self::ExportPrimitiveF32 => "export_primitive_f32",
+            Self::ExportPrimitiveF64 => "export_primitive_f64",
+            Self::ExportPrimitiveI16 => "export_primitive_i16",
+            Self::ExportPrimitiveI32 => "export_primitive_i32",
+            Self::ExportPrimitiveI64 => "export_primitive_i64",
+            Self::ExportPrimitiveI8 => "export_primitive_i8",
+            Self::ExportPrimitivePair => "export_primitive_pair",
+            Self::ExportPrimitiveU16 => "export_primitive_u16",
+            Self::ExportPrimitiveU32 => "export_primitive_u32",
+            Self::ExportPrimitiveU64 => "export_primitive_u64",
+            Self::ExportPrimitiveU8 => "export_primitive_u8",
+            Self::ExportRenamedString => "export_renamed_string",
+            Self::ExportSamples => "export_samples",
+            Self::ExportSerdeAdjacentlyTagged => "export_serde_adjacently_tagged",
+            Self::ExportSerdeEnum => "export_serde_enum",
+            Self::ExportSerdeFlatten => "export_serde_flatten",
+            Self::ExportSerdeInternallyTagged => "export_serde_internally_tagged",
+            Self::ExportSerdeStruct => "export_serde_struct",
+            Self::ExportSerdeUntagged => "export_serde_untagged",
+            Self::ExportString => "export_string",
+            Self::ExportStringAfterMemoryGrowth => "export_string_after_memory_growth",
+            Self::ExportStructWithOptions => "export_struct_with_options",
+            Self::ExportTimestamp => "export_timestamp",
+            Self::ExportTimestamps => "export_timestamps",
+            Self::ExportTypedId => "export_typed_id",
+            Self::ExportValidatedStruct => "export_validated_struct",
+            Self::ExportVoidFunction => "export_void_function",
+            Self::FetchData => "fetch_data",
+            Self::Init => "init",
+            Self::ReducerBridge => "reducer_bridge",
+            Self::UpdateConfig => "update_config",
+        }
+    }
+
+    /// Returns the name of the symbol through which the plugin exports it.
+    pub fn symbol_name(&self) -> &'static str {
+        match *self {
+            Self::ExportArrayF32 => "__fp_gen_export_array_f32",
+            Self::ExportArrayF64 => "__fp_gen_export_array_f64",
+            Self::ExportArrayI16 => "__fp_gen_export_array_i16",
+            Self::ExportArrayI32 => "__fp_gen_export_array_i32",
+            Self::ExportArrayI8 => "__fp_gen_export_array_i8",
+            Self::ExportArrayU16 => "__fp_gen_export_array_u16",
+            Self::ExportArrayU32 => "__fp_gen_export_array_u32",
+            Self::ExportArrayU8 => "__fp_gen_export_array_u8",
+            Self::ExportAsyncStruct => "__fp_gen_export_async_struct",
+            Self::ExportBatchResponse => "__fp_gen_export_batch_response",
+            Self::ExportCachedCallCount => "__fp_gen_export_cached_call_count",
+            Self::ExportCallRateLimitedImport => "__fp_gen_export_call_rate_limited_import",
+            Self::ExportCollections => "__fp_gen_export_collections",
+            Self::ExportDynamicValue => "__fp_gen_export_dynamic_value",
+            Self::ExportEdgeValues => "__fp_gen_export_edge_values",
+            #[cfg(feature = "experimental")]
+            Self::ExportExperimental => "__fp_gen_export_experimental",
+            Self::ExportFpAdjacentlyTagged => "__fp_gen_export_fp_adjacently_tagged",
+            Self::ExportFpEnum => "__fp_gen_export_fp_enum",
+            Self::ExportFpFlatten => "__fp_gen_export_fp_flatten",
+            Self::ExportFpInternallyTagged => "__fp_gen_export_fp_internally_tagged",
+            Self::ExportFpStruct => "__fp_gen_export_fp_struct",
+            Self::ExportFpUntagged => "__fp_gen_export_fp_untagged",
+            Self::ExportGenerics => "__fp_gen_export_generics",
+            Self::ExportGetBytes => "__fp_gen_export_get_bytes",
+            Self::ExportGetSerdeBytes => "__fp_gen_export_get_serde_bytes",
+            Self::ExportLazyString => "__fp_gen_export_lazy_string",
+            Self::ExportMetrics => "__fp_gen_export_metrics",
+            Self::ExportMultiplePrimitives => "__fp_gen_export_multiple_primitives",
+            Self::ExportMultipleValues => "__fp_gen_export_multiple_values",
+            Self::ExportPrimitiveBool => "__fp_gen_export_primitive_bool",
+            Self::ExportPrimitiveF32 => "__fp_gen_export_primitive_f32",
+            Self::ExportPrimitiveF64 => "__fp_gen_export_primitive_f64",
+            Self::ExportPrimitiveI16 => "__fp_gen_export_primitive_i16",
+            Self::ExportPrimitiveI32 => "__fp_gen_export_primitive_i32",
+            Self::ExportPrimitiveI64 => "__fp_gen_export_primitive_i64",
+            Self::ExportPrimitiveI8 => "__fp_gen_export_primitive_i8",
+            Self::ExportPrimitivePair => "__fp_gen_export_primitive_pair",
+            Self::ExportPrimitiveU16 => "__fp_gen_export_primitive_u16",
+            Self::ExportPrimitiveU32 => "__fp_gen_export_primitive_u32",
+            Self::ExportPrimitiveU64 => "__fp_gen_export_primitive_u64",
+            Self::ExportPrimitiveU8 => "__fp_gen_export_primitive_u8",
+            Self::ExportRenamedString => "__fp_gen_export_legacy_string",
+            Self::ExportSamples => "__fp_gen_export_samples",
+            Self::ExportSerdeAdjacentlyTagged => "__fp_gen_export_serde_adjacently_tagged",
+            Self::ExportSerdeEnum => "__fp_gen_export_serde_enum",
+            Self::ExportSerdeFlatten => "__fp_gen_export_serde_flatten",
+            Self::ExportSerdeInternallyTagged => "__fp_gen_export_serde_internally_tagged",
+            Self::ExportSerdeStruct => "__fp_gen_export_serde_struct",
+            Self::ExportSerdeUntagged => "__fp_gen_export_serde_untagged",
+            Self::ExportString => "__fp_gen_export_string",
+            Self::ExportStringAfterMemoryGrowth => "__fp_gen_export_string_after_memory_growth",
+            Self::ExportStructWithOptions => "__fp_gen_export_struct_with_options",
+            Self::ExportTimestamp => "__fp_gen_export_timestamp",
+            Self::ExportTimestamps => "__fp_gen_export_timestamps",
+            Self::ExportTypedId => "__fp_gen_export_typed_id",
+            Self::ExportValidatedStruct => "__fp_gen_export_validated_struct",
+            Self::ExportVoidFunction => "__fp_gen_export_void_function",
+            Self::FetchData => "__fp_gen_fetch_data",
+            Self::Init => "__fp_gen_init",
+            Self::ReducerBridge => "__fp_gen_reducer_bridge",
+            Self::UpdateConfig => "__fp_gen_update_config",
+        }
+    }
+}
+
+impl std::fmt::Display for PluginExport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The ways in which calling an export of the plugin can fail.
+#[derive(Debug)]
+pub enum PluginError {
+    /// The plugin does not export the function.
+    MissingExport(PluginExport),
+
+    /// The plugin exports the function with another signature, which usually
+    /// means that it was built for another version of the protocol.
+    SignatureMismatch(PluginExport),
+
+    /// The plugin trapped while it was running the function.
+    GuestTrap {
+        export: PluginExport,
+        error: wasmer::RuntimeError,
+    },
+
+    /// The plugin reported a failure before it aborted, such as a panic.
+    GuestPanic {
+        export: PluginExport,
+        error: GuestError,
+    },
+
+    /// The value that was returned by the plugin could not be deserialized.
+    Deserialization {
+        export: PluginExport,
+        type_name: &'static str,
+        message: String,
+    },
+
+    /// The call failed in the runtime, for instance because the plugin was
+    /// denied an import or could not allocate the arguments.
+    Transport {
+        export: PluginExport,
+        error: InvocationError,
+    },
+}
+
+impl PluginError {
+    fn new(export: PluginExport, error: InvocationError) -> Self {
+        match error {
+            InvocationError::FunctionNotExported(_) => Self::MissingExport(export),
+            InvocationError::SignatureMismatch(_) => Self::SignatureMismatch(export),
+            InvocationError::WasmerRuntimeError(error) => Self::GuestTrap { export, error },
+            InvocationError::GuestError(error) => Self::GuestPanic { export, error },
+            InvocationError::DeserializationFailed {
+                type_name, message, ..
+            } => Self::Deserialization {
+                export,
+                type_name,
+                message,
+            },
+            error => Self::Transport { export, error },
+        }
+    }
+
+    /// Returns the export that failed.
+    pub fn export(&self) -> PluginExport {
+        match self {
+            Self::MissingExport(export) | Self::SignatureMismatch(export) => *export,
+            Self::GuestTrap { export, .. }
+            | Self::GuestPanic { export, .. }
+            | Self::Deserialization { export, .. }
+            | Self::Transport { export, .. } => *export,
+        }
+    }
+}
+
+impl std::fmt::Display for PluginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingExport(export) => write!(f, "plugin does not export `{export}`"),
+            Self::SignatureMismatch(export) => {
+                write!(f, "plugin exports `{export}` with an unexpected signature")
+            }
+            Self::GuestTrap { export, error } => write!(f, "plugin trapped in `{export}`: {error}"),
+            Self::GuestPanic { export, error } => write!(f, "plugin failed in `{export}`: {error}"),
+            Self::Deserialization {
+                export,
+                type_name,
+                message,
+            } => write!(
+                f,
+                "could not deserialize the `{type_name}` returned by `{export}`: {message}"
+            ),
+            Self::Transport { export, error } => write!(f, "could not call `{export}`: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+impl From<PluginError> for InvocationError {
+    fn from(error: PluginError) -> Self {
+        match error {
+            PluginError::MissingExport(export) => {
+                Self::FunctionNotExported(export.symbol_name().to_owned())
+            }
+            PluginError::SignatureMismatch(export) => {
+                Self::SignatureMismatch(export.symbol_name().to_owned())
+            }
+            PluginError::GuestTrap { error, .. } => Self::WasmerRuntimeError(error),
+            PluginError::GuestPanic { error, .. } => Self::GuestError(error),
+            PluginError::Deserialization {
+                export,
+                type_name,
+                message,
+            } => Self::DeserializationFailed {
+                function: export.as_str().to_owned(),
+                type_name,
+                message,
+            },
+            PluginError::Transport { error, .. } => error,
+        }
+    }
+}
+
+/// Returns the signature of the given import, if it can be routed to the
+/// export of another plugin.
+fn routable_import_signature(name: &str) -> Option<&'static str> {
+    match name {
+        "import_arbitrary_precision_numbers" => {
+            Some("fn(ArbitraryPrecisionNumbers) -> ArbitraryPrecisionNumbers")
+        }
+        "import_array_f32" => Some("fn([f32; 3]) -> [f32; 3]"),
+        "import_array_f64" => Some("fn([f64; 3]) -> [f64; 3]"),
+        "import_array_i16" => Some("fn([i16; 3]) -> [i16; 3]"),
+        "import_array_i32" => Some("fn([i32; 3]) -> [i32; 3]"),
+        "import_array_i8" => Some("fn([i8; 3]) -> [i8; 3]"),
+        "import_array_u16" => Some("fn([u16; 3]) -> [u16; 3]"),
+        "import_array_u32" => Some("fn([u32; 3]) -> [u32; 3]"),
+        "import_array_u8" => Some("fn([u8; 3]) -> [u8; 3]"),
+        "import_collections" => Some("fn(Collections) -> Collections"),
+        #[cfg(feature = "experimental")]
+        "import_experimental" => Some("fn(String) -> String"),
+        "import_explicit_bound_point" => Some("fn(ExplicitBoundPoint<u64>)"),
+        "import_fp_adjacently_tagged" => Some("fn(FpAdjacentlyTagged) -> FpAdjacentlyTagged"),
+        "import_fp_enum" => Some("fn(FpVariantRenaming) -> FpVariantRenaming"),
+        "import_fp_flatten" => Some("fn(FpFlatten) -> FpFlatten"),
+        "import_fp_internally_tagged" => Some("fn(FpInternallyTagged) -> FpInternallyTagged"),
+        "import_fp_struct" => Some("fn(FpPropertyRenaming) -> FpPropertyRenaming"),
+        "import_fp_untagged" => Some("fn(FpUntagged) -> FpUntagged"),
+        "import_generics" => Some("fn(StructWithGenerics<u64>) -> StructWithGenerics<u64>"),
+        "import_get_bytes" => Some("fn() -> Result<bytes::Bytes, String>"),
+        "import_get_serde_bytes" => Some("fn() -> Result<serde_bytes::ByteBuf, String>"),
+        "import_integer_types" => Some("fn(IntegerTypes) -> IntegerTypes"),
+        "import_multiple_primitives" => Some("fn(i8, String) -> i64"),
+        "import_multiple_values" => Some("fn(u32) -> (String, Vec<u32>, bool)"),
+        "import_primitive_bool" => Some("fn(bool) -> bool"),
+        "import_primitive_f32" => Some("fn(f32) -> f32"),
+        "import_primitive_f64" => Some("fn(f64) -> f64"),
+        "import_primitive_i16" => Some("fn(i16) -> i16"),
+        "import_primitive_i32" => Some("fn(i32) -> i32"),
+        "import_primitive_i64" => Some("fn(i64) -> i64"),
+        "import_primitive_i8" => Some("fn(i8) -> i8"),
+        "import_primitive_pair" => Some("fn(u32) -> (u32, i32)"),
+        "import_primitive_u16" => Some("fn(u16) -> u16"),
+        "import_primitive_u32" => Some("fn(u32) -> u32"),
+        "import_primitive_u64" => Some("fn(u64) -> u64"),
+        "import_primitive_u8" => Some("fn(u8) -> u8"),
+        "import_rate_limited" => Some("fn(String) -> Result<String, RateLimited>"),
+        "import_renamed_string" => Some("fn(String) -> String"),
+        "import_serde_adjacently_tagged" => {
+            Some("fn(SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged")
+        }
+        "import_serde_enum" => Some("fn(SerdeVariantRenaming) -> SerdeVariantRenaming"),
+        "import_serde_flatten" => Some("fn(SerdeFlatten) -> SerdeFlatten"),
+        "import_serde_internally_tagged" => {
+            Some("fn(SerdeInternallyTagged) -> SerdeInternallyTagged")
+        }
+        "import_serde_struct" => Some("fn(SerdePropertyRenaming) -> SerdePropertyRenaming"),
+        "import_serde_untagged" => Some("fn(SerdeUntagged) -> SerdeUntagged"),
+        "import_string" => Some("fn(String) -> String"),
+        "import_struct_with_options" => Some("fn(StructWithOptions) -> StructWithOptions"),
+        "import_timestamp" => Some("fn(MyDateTime) -> MyDateTime"),
+        "import_validated_struct" => Some("fn(ValidatedStruct) -> ValidatedStruct"),
+        "import_void_function" => Some("fn()"),
+        "import_void_function_empty_result" => Some("fn() -> Result<(), u32>"),
+        "import_void_function_empty_return" => Some("fn()"),
+        "log" => Some("fn(String)"),
+        _ => None,
+    }
+}
+
+impl RawExports for Runtime {
+    fn raw_export_signature(&self, name: &str) -> Option<&'static str> {
+        match name {
+            "export_array_f32" => Some("fn([f32; 3]) -> [f32; 3]"),
+            "export_array_f64" => Some("fn([f64; 3]) -> [f64; 3]"),
+            "export_array_i16" => Some("fn([i16; 3]) -> [i16; 3]"),
+            "export_array_i32" => Some("fn([i32; 3]) -> [i32; 3]"),
+            "export_array_i8" => Some("fn([i8; 3]) -> [i8; 3]"),
+            "export_array_u16" => Some("fn([u16; 3]) -> [u16; 3]"),
+            "export_array_u32" => Some("fn([u32; 3]) -> [u32; 3]"),
+            "export_array_u8" => Some("fn([u8; 3]) -> [u8; 3]"),
+            "export_batch_response" => Some("fn(BatchResponse) -> BatchResponse"),
+            "export_cached_call_count" => Some("fn(String) -> String"),
+            "export_call_rate_limited_import" => {
+                Some("fn(u32) -> Vec<Result<String, RateLimited>>")
+            }
+            "export_collections" => Some("fn(Collections) -> Collections"),
+            "export_dynamic_value" => Some("fn(ExtensionSettings) -> serde_json::Value"),
+            "export_edge_values" => Some("fn(EdgeValues) -> EdgeValues"),
+            #[cfg(feature = "experimental")]
+            "export_experimental" => Some("fn(String) -> String"),
+            "export_fp_adjacently_tagged" => Some("fn(FpAdjacentlyTagged) -> FpAdjacentlyTagged"),
+            "export_fp_enum" => Some("fn(FpVariantRenaming) -> FpVariantRenaming"),
+            "export_fp_flatten" => Some("fn(FpFlatten) -> FpFlatten"),
+            "export_fp_internally_tagged" => Some("fn(FpInternallyTagged) -> FpInternallyTagged"),
+            "export_fp_struct" => Some("fn(FpPropertyRenaming) -> FpPropertyRenaming"),
+            "export_fp_untagged" => Some("fn(FpUntagged) -> FpUntagged"),
+            "export_generics" => Some("fn(StructWithGenerics<u64>) -> StructWithGenerics<u64>"),
+            "export_get_bytes" => Some("fn() -> Result<bytes::Bytes, String>"),
+            "export_get_serde_bytes" => Some("fn() -> Result<serde_bytes::ByteBuf, String>"),
+            "export_lazy_string" => Some("fn(String) -> String"),
+            "export_metrics" => Some("fn(Vec<Metric>) -> Vec<Metric>"),
+            "export_multiple_primitives" => Some("fn(i8, String) -> i64"),
+            "export_multiple_values" => Some("fn(u32) -> (String, Vec<u32>, bool)"),
+            "export_primitive_bool" => Some("fn(bool) -> bool"),
+            "export_primitive_f32" => Some("fn(f32) -> f32"),
+            "export_primitive_f64" => Some("fn(f64) -> f64"),
+            "export_primitive_i16" => Some("fn(i16) -> i16"),
+            "export_primitive_i32" => Some("fn(i32) -> i32"),
+            "export_primitive_i64" => Some("fn(i64) -> i64"),
+            "export_primitive_i8" => Some("fn(i8) -> i8"),
+            "export_primitive_pair" => Some("fn(u32) -> (u32, i32)"),
+            "export_primitive_u16" => Some("fn(u16) -> u16"),
+            "export_primitive_u32" => Some("fn(u32) -> u32"),
+            "export_primitive_u64" => Some("fn(u64) -> u64"),
+            "export_primitive_u8" => Some("fn(u8) -> u8"),
+            "export_renamed_string" => Some("fn(String) -> String"),
+            "export_samples" => Some("fn(SampleColumns) -> SampleColumns"),
+            "export_serde_adjacently_tagged" => {
+                Some("fn(SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged")
+            }
+            "export_serde_enum" => Some("fn(SerdeVariantRenaming) -> SerdeVariantRenaming"),
+            "export_serde_flatten" => Some("fn(SerdeFlatten) -> SerdeFlatten"),
+            "export_serde_internally_tagged" => {
+                Some("fn(SerdeInternallyTagged) -> SerdeInternallyTagged")
+            }
+            "export_serde_struct" => Some("fn(SerdePropertyRenaming) -> SerdePropertyRenaming"),
+            "export_serde_untagged" => Some("fn(SerdeUntagged) -> SerdeUntagged"),
+            "export_string" => Some("fn(String) -> String"),
+            "export_string_after_memory_growth" => Some("fn(String) -> String"),
+            "export_struct_with_options" => Some("fn(StructWithOptions) -> StructWithOptions"),
+            "export_timestamp" => Some("fn(MyDateTime) -> MyDateTime"),
+            "export_timestamps" => Some("fn(Timestamps) -> Timestamps"),
+            "export_typed_id" => Some("fn(TypedId<Point<f64>>) -> TypedId<Point<f64>>"),
+            "export_validated_struct" => Some("fn(ValidatedStruct) -> ValidatedStruct"),
+            "export_void_function" => Some("fn()"),
+            "init" => Some("fn(PluginConfig)"),
+            "reducer_bridge" => Some("fn(ReduxAction) -> StateUpdate"),
+            "update_config" => Some("fn(PluginConfig)"),
+            _ => None,
+        }
+    }
+
+    fn call_raw_export(&self, name: &str, args: Vec<Vec<u8>>) -> Result<Vec<u8>, InvocationError> {
+        match name {
+            "export_array_f32" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_array_f32` takes 1 argument(s)");
+                let result = self.export_array_f32_raw(arg)?;
+                Ok(result)
+            }
+            "export_array_f64" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_array_f64` takes 1 argument(s)");
+                let result = self.export_array_f64_raw(arg)?;
+                Ok(result)
+            }
+            "export_array_i16" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_array_i16` takes 1 argument(s)");
+                let result = self.export_array_i16_raw(arg)?;
+                Ok(result)
+            }
+            "export_array_i32" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_array_i32` takes 1 argument(s)");
+                let result = self.export_array_i32_raw(arg)?;
+                Ok(result)
+            }
+            "export_array_i8" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_array_i8` takes 1 argument(s)");
+                let result = self.export_array_i8_raw(arg)?;
+                Ok(result)
+            }
+            "export_array_u16" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_array_u16` takes 1 argument(s)");
+                let result = self.export_array_u16_raw(arg)?;
+                Ok(result)
+            }
+            "export_array_u32" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_array_u32` takes 1 argument(s)");
+                let result = self.export_array_u32_raw(arg)?;
+                Ok(result)
+            }
+            "export_array_u8" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_array_u8` takes 1 argument(s)");
+                let result = self.export_array_u8_raw(arg)?;
+                Ok(result)
+            }
+            "export_batch_response" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_batch_response` takes 1 argument(s)");
+                let result = self.export_batch_response_raw(arg)?;
+                Ok(result)
+            }
+            "export_cached_call_count" => {
+                let [label]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_cached_call_count` takes 1 argument(s)");
+                let result = self.export_cached_call_count_raw(label)?;
+                Ok(result)
+            }
+            "export_call_rate_limited_import" => {
+                let [calls]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_call_rate_limited_import` takes 1 argument(s)");
+                let result =
+                    self.export_call_rate_limited_import_raw(deserialize_from_slice(&calls))?;
+                Ok(result)
+            }
+            "export_collections" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_collections` takes 1 argument(s)");
+                let result = self.export_collections_raw(arg)?;
+                Ok(result)
+            }
+            "export_dynamic_value" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_dynamic_value` takes 1 argument(s)");
+                let result = self.export_dynamic_value_raw(arg)?;
+                Ok(result)
+            }
+            "export_edge_values" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_edge_values` takes 1 argument(s)");
+                let result = self.export_edge_values_raw(arg)?;
+                Ok(result)
+            }
+            #[cfg(feature = "experimental")]
+            "export_experimental" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_experimental` takes 1 argument(s)");
+                let result = self.export_experimental_raw(arg)?;
+                Ok(result)
+            }
+            "export_fp_adjacently_tagged" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_fp_adjacently_tagged` takes 1 argument(s)");
+                let result = self.export_fp_adjacently_tagged_raw(arg)?;
+                Ok(result)
+            }
+            "export_fp_enum" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_fp_enum` takes 1 argument(s)");
+                let result = self.export_fp_enum_raw(arg)?;
+                Ok(result)
+            }
+            "export_fp_flatten" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_fp_flatten` takes 1 argument(s)");
+                let result = self.export_fp_flatten_raw(arg)?;
+                Ok(result)
+            }
+            "export_fp_internally_tagged" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_fp_internally_tagged` takes 1 argument(s)");
+                let result = self.export_fp_internally_tagged_raw(arg)?;
+                Ok(result)
+            }
+            "export_fp_struct" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_fp_struct` takes 1 argument(s)");
+                let result = self.export_fp_struct_raw(arg)?;
+                Ok(result)
+            }
+            "export_fp_untagged" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_fp_untagged` takes 1 argument(s)");
+                let result = self.export_fp_untagged_raw(arg)?;
+                Ok(result)
+            }
+            "export_generics" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_generics` takes 1 argument(s)");
+                let result = self.export_generics_raw(arg)?;
+                Ok(result)
+            }
+            "export_get_bytes" => {
+                let []: [Vec<u8>; 0] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_get_bytes` takes 0 argument(s)");
+                let result = self.export_get_bytes_raw()?;
+                Ok(result)
+            }
+            "export_get_serde_bytes" => {
+                let []: [Vec<u8>; 0] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_get_serde_bytes` takes 0 argument(s)");
+                let result = self.export_get_serde_bytes_raw()?;
+                Ok(result)
+            }
+            "export_lazy_string" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_lazy_string` takes 1 argument(s)");
+                let result = self.export_lazy_string_raw(arg)?;
+                Ok(result)
+            }
+            "export_metrics" => {
+                let [metrics]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_metrics` takes 1 argument(s)");
+                let result = self.export_metrics_raw(metrics)?;
+                Ok(result)
+            }
+            "export_multiple_primitives" => {
+                let [arg1, arg2]: [Vec<u8>; 2] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_multiple_primitives` takes 2 argument(s)");
+                let result =
+                    self.export_multiple_primitives_raw(deserialize_from_slice(&arg1), arg2)?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_multiple_values" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_multiple_values` takes 1 argument(s)");
+                let result = self.export_multiple_values_raw(deserialize_from_slice(&arg))?;
+                Ok(result)
+            }
+            "export_primitive_bool" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_bool` takes 1 argument(s)");
+                let result = self.export_primitive_bool_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_f32" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_f32` takes 1 argument(s)");
+                let result = self.export_primitive_f32_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_f64" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_f64` takes 1 argument(s)");
+                let result = self.export_primitive_f64_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_i16" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_i16` takes 1 argument(s)");
+                let result = self.export_primitive_i16_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_i32" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_i32` takes 1 argument(s)");
+                let result = self.export_primitive_i32_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_i64" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_i64` takes 1 argument(s)");
+                let result = self.export_primitive_i64_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_i8" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_i8` takes 1 argument(s)");
+                let result = self.export_primitive_i8_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_pair" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_pair` takes 1 argument(s)");
+                let result = self.export_primitive_pair_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_u16" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_u16` takes 1 argument(s)");
+                let result = self.export_primitive_u16_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_u32" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_u32` takes 1 argument(s)");
+                let result = self.export_primitive_u32_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_u64" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_u64` takes 1 argument(s)");
+                let result = self.export_primitive_u64_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_primitive_u8" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_primitive_u8` takes 1 argument(s)");
+                let result = self.export_primitive_u8_raw(deserialize_from_slice(&arg))?;
+                Ok(serialize_to_vec(&result))
+            }
+            "export_renamed_string" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_renamed_string` takes 1 argument(s)");
+                let result = self.export_renamed_string_raw(arg)?;
+                Ok(result)
+            }
+            "export_samples" => {
+                let [samples]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_samples` takes 1 argument(s)");
+                let result = self.export_samples_raw(samples)?;
+                Ok(result)
+            }
+            "export_serde_adjacently_tagged" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_serde_adjacently_tagged` takes 1 argument(s)");
+                let result = self.export_serde_adjacently_tagged_raw(arg)?;
+                Ok(result)
+            }
+            "export_serde_enum" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_serde_enum` takes 1 argument(s)");
+                let result = self.export_serde_enum_raw(arg)?;
+                Ok(result)
+            }
+            "export_serde_flatten" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_serde_flatten` takes 1 argument(s)");
+                let result = self.export_serde_flatten_raw(arg)?;
+                Ok(result)
+            }
+            "export_serde_internally_tagged" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_serde_internally_tagged` takes 1 argument(s)");
+                let result = self.export_serde_internally_tagged_raw(arg)?;
+                Ok(result)
+            }
+            "export_serde_struct" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_serde_struct` takes 1 argument(s)");
+                let result = self.export_serde_struct_raw(arg)?;
+                Ok(result)
+            }
+            "export_serde_untagged" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_serde_untagged` takes 1 argument(s)");
+                let result = self.export_serde_untagged_raw(arg)?;
+                Ok(result)
+            }
+            "export_string" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_string` takes 1 argument(s)");
+                let result = self.export_string_raw(arg)?;
+                Ok(result)
+            }
+            "export_string_after_memory_growth" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_string_after_memory_growth` takes 1 argument(s)");
+                let result = self.export_string_after_memory_growth_raw(arg)?;
+                Ok(result)
+            }
+            "export_struct_with_options" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_struct_with_options` takes 1 argument(s)");
+                let result = self.export_struct_with_options_raw(arg)?;
+                Ok(result)
+            }
+            "export_timestamp" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_timestamp` takes 1 argument(s)");
+                let result = self.export_timestamp_raw(arg)?;
+                Ok(result)
+            }
+            "export_timestamps" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_timestamps` takes 1 argument(s)");
+                let result = self.export_timestamps_raw(arg)?;
+                Ok(result)
+            }
+            "export_typed_id" => {
+                let [id]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_typed_id` takes 1 argument(s)");
+                let result = self.export_typed_id_raw(id)?;
+                Ok(result)
+            }
+            "export_validated_struct" => {
+                let [arg]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_validated_struct` takes 1 argument(s)");
+                let result = self.export_validated_struct_raw(arg)?;
+                Ok(result)
+            }
+            "export_void_function" => {
+                let []: [Vec<u8>; 0] = std::convert::TryInto::try_into(args)
+                    .expect("Export `export_void_function` takes 0 argument(s)");
+                let result = self.export_void_function_raw()?;
+                Ok(serialize_to_vec(&result))
+            }
+            "init" => {
+                let [config]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `init` takes 1 argument(s)");
+                let result = self.init_raw(config)?;
+                Ok(serialize_to_vec(&result))
+            }
+            "reducer_bridge" => {
+                let [action]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `reducer_bridge` takes 1 argument(s)");
+                let result = self.reducer_bridge_raw(action)?;
+                Ok(result)
+            }
+            "update_config" => {
+                let [config]: [Vec<u8>; 1] = std::convert::TryInto::try_into(args)
+                    .expect("Export `update_config` takes 1 argument(s)");
+                let result = self.update_config_raw(config)?;
+                Ok(serialize_to_vec(&result))
+            }
+            _ => Err(InvocationError::FunctionNotExported(name.to_owned())),
+        }
+    }
+}
+
+pub fn _import_arbitrary_precision_numbers(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_arbitrary_precision_numbers").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_arbitrary_precision_numbers",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_arbitrary_precision_numbers") {
+        let result = route
+            .call_raw_export(
+                "import_arbitrary_precision_numbers",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_arbitrary_precision_numbers".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<ArbitraryPrecisionNumbers>(env, arg);
+    let import_override = env.import_override::<dyn Fn(ArbitraryPrecisionNumbers) -> ArbitraryPrecisionNumbers + Send + Sync>("import_arbitrary_precision_numbers");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_arbitrary_precision_numbers(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_arbitrary_precision_numbers",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_array_f32(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_array_f32").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_array_f32",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_array_f32") {
+        let result = route
+            .call_raw_export("import_array_f32", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_array_f32".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<[f32; 3]>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn([f32; 3]) -> [f32; 3] + Send + Sync>("import_array_f32");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_array_f32(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_array_f32",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_array_f64(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_array_f64").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_array_f64",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_array_f64") {
+        let result = route
+            .call_raw_export("import_array_f64", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_array_f64".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<[f64; 3]>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn([f64; 3]) -> [f64; 3] + Send + Sync>("import_array_f64");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_array_f64(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_array_f64",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_array_i16(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_array_i16").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_array_i16",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_array_i16") {
+        let result = route
+            .call_raw_export("import_array_i16", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_array_i16".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<[i16; 3]>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn([i16; 3]) -> [i16; 3] + Send + Sync>("import_array_i16");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_array_i16(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_array_i16",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_array_i32(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_array_i32").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_array_i32",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_array_i32") {
+        let result = route
+            .call_raw_export("import_array_i32", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_array_i32".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<[i32; 3]>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn([i32; 3]) -> [i32; 3] + Send + Sync>("import_array_i32");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_array_i32(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_array_i32",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_array_i8(env: &RuntimeInstanceData, arg: FatPtr) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_array_i8").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_array_i8",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_array_i8") {
+        let result = route
+            .call_raw_export("import_array_i8", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_array_i8".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<[i8; 3]>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn([i8; 3]) -> [i8; 3] + Send + Sync>("import_array_i8");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_array_i8(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_array_i8",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_array_u16(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_array_u16").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_array_u16",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_array_u16") {
+        let result = route
+            .call_raw_export("import_array_u16", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_array_u16".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<[u16; 3]>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn([u16; 3]) -> [u16; 3] + Send + Sync>("import_array_u16");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_array_u16(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_array_u16",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_array_u32(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_array_u32").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_array_u32",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_array_u32") {
+        let result = route
+            .call_raw_export("import_array_u32", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_array_u32".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<[u32; 3]>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn([u32; 3]) -> [u32; 3] + Send + Sync>("import_array_u32");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_array_u32(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_array_u32",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_array_u8(env: &RuntimeInstanceData, arg: FatPtr) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_array_u8").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_array_u8",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_array_u8") {
+        let result = route
+            .call_raw_export("import_array_u8", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_array_u8".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<[u8; 3]>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn([u8; 3]) -> [u8; 3] + Send + Sync>("import_array_u8");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_array_u8(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_array_u8",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_async_void_function(env: &RuntimeInstanceData, message: FatPtr) {
+    let _span = import_span("import_async_void_function").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_async_void_function",
+        "message",
+        PayloadDirection::FromPlugin,
+        message,
+    );
+    let message = import_from_guest::<String>(env, message);
+    let import_override = env
+        .import_override::<dyn Fn(String) -> OverrideFuture<()> + Send + Sync>(
+            "import_async_void_function",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(message),
+        None => Box::pin(super::import_async_void_function(message)),
+    };
+    let result = result.with_current_deadline();
+    let handle = tokio::runtime::Handle::current();
+    handle.spawn(result.in_current_span());
+}
+
+pub fn _import_collections(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_collections").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_collections",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_collections") {
+        let result = route
+            .call_raw_export("import_collections", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_collections".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<Collections>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(Collections) -> Collections + Send + Sync>("import_collections");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_collections(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_collections",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+#[cfg(feature = "experimental")]
+pub fn _import_experimental(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_experimental").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_experimental",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_experimental") {
+        let result = route
+            .call_raw_export("import_experimental", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_experimental".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<String>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn(String) -> String + Send + Sync>("import_experimental");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_experimental(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_experimental",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_explicit_bound_point(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<(), InvocationError> {
+    let _span = import_span("import_explicit_bound_point").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_explicit_bound_point",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_explicit_bound_point") {
+        let result = route
+            .call_raw_export(
+                "import_explicit_bound_point",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_explicit_bound_point".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(());
+    }
+    let arg = import_from_guest::<ExplicitBoundPoint<u64>>(env, arg);
+    let import_override = env.import_override::<dyn Fn(ExplicitBoundPoint<u64>) + Send + Sync>(
+        "import_explicit_bound_point",
+    );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_explicit_bound_point(arg),
+    };
+    Ok(())
+}
+
+pub fn _import_fp_adjacently_tagged(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_fp_adjacently_tagged").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_fp_adjacently_tagged",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_fp_adjacently_tagged") {
+        let result = route
+            .call_raw_export(
+                "import_fp_adjacently_tagged",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_fp_adjacently_tagged".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<FpAdjacentlyTagged>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(FpAdjacentlyTagged) -> FpAdjacentlyTagged + Send + Sync>(
+            "import_fp_adjacently_tagged",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_fp_adjacently_tagged(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_fp_adjacently_tagged",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_fp_enum(env: &RuntimeInstanceData, arg: FatPtr) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_fp_enum").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_fp_enum",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_fp_enum") {
+        let result = route
+            .call_raw_export("import_fp_enum", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_fp_enum".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<FpVariantRenaming>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(FpVariantRenaming) -> FpVariantRenaming + Send + Sync>(
+            "import_fp_enum",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_fp_enum(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_fp_enum",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_fp_flatten(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_fp_flatten").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_fp_flatten",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_fp_flatten") {
+        let result = route
+            .call_raw_export("import_fp_flatten", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_fp_flatten".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<FpFlatten>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn(FpFlatten) -> FpFlatten + Send + Sync>("import_fp_flatten");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_fp_flatten(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_fp_flatten",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_fp_internally_tagged(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_fp_internally_tagged").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_fp_internally_tagged",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_fp_internally_tagged") {
+        let result = route
+            .call_raw_export(
+                "import_fp_internally_tagged",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_fp_internally_tagged".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<FpInternallyTagged>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(FpInternallyTagged) -> FpInternallyTagged + Send + Sync>(
+            "import_fp_internally_tagged",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_fp_internally_tagged(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_fp_internally_tagged",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_fp_struct(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_fp_struct").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_fp_struct",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_fp_struct") {
+        let result = route
+            .call_raw_export("import_fp_struct", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_fp_struct".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<FpPropertyRenaming>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(FpPropertyRenaming) -> FpPropertyRenaming + Send + Sync>(
+            "import_fp_struct",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_fp_struct(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_fp_struct",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_fp_untagged(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_fp_untagged").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_fp_untagged",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_fp_untagged") {
+        let result = route
+            .call_raw_export("import_fp_untagged", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_fp_untagged".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<FpUntagged>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn(FpUntagged) -> FpUntagged + Send + Sync>("import_fp_untagged");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_fp_untagged(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_fp_untagged",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_generics(env: &RuntimeInstanceData, arg: FatPtr) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_generics").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_generics",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_generics") {
+        let result = route
+            .call_raw_export("import_generics", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_generics".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<StructWithGenerics<u64>>(env, arg);
+    let import_override = env.import_override::<dyn Fn(StructWithGenerics<u64>) -> StructWithGenerics<u64> + Send + Sync>("import_generics");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_generics(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_generics",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_get_bytes(env: &RuntimeInstanceData) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_get_bytes").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_get_bytes") {
+        let result = route
+            .call_raw_export("import_get_bytes", vec![])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_get_bytes".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+
+    let import_override = env
+        .import_override::<dyn Fn() -> Result<bytes::Bytes, String> + Send + Sync>(
+            "import_get_bytes",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(),
+        None => super::import_get_bytes(),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_get_bytes",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_get_serde_bytes(env: &RuntimeInstanceData) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_get_serde_bytes").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_get_serde_bytes") {
+        let result = route
+            .call_raw_export("import_get_serde_bytes", vec![])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_get_serde_bytes".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+
+    let import_override = env
+        .import_override::<dyn Fn() -> Result<serde_bytes::ByteBuf, String> + Send + Sync>(
+            "import_get_serde_bytes",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(),
+        None => super::import_get_serde_bytes(),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_get_serde_bytes",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_get_value(env: &RuntimeInstanceData, key: FatPtr) -> FatPtr {
+    let _span = import_span("import_get_value").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_get_value",
+        "key",
+        PayloadDirection::FromPlugin,
+        key,
+    );
+    let key = import_from_guest::<String>(env, key);
+    let result = super::import_get_value(key);
+    let result = result.with_current_deadline();
+    let env = env.clone();
+    let async_ptr = create_future_value(&env);
+    let handle = tokio::runtime::Handle::current();
+    handle.spawn(
+        async move {
+            let result = result.await;
+            let result_ptr = export_to_guest(&env, &result);
+            trace_guest_payload(
+                &env,
+                "import_get_value",
+                "result",
+                PayloadDirection::ToPlugin,
+                result_ptr,
+            );
+            env.guest_resolve_async_value(async_ptr, result_ptr);
+        }
+        .in_current_span(),
+    );
+    async_ptr
+}
+
+pub fn _import_integer_types(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_integer_types").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_integer_types",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_integer_types") {
+        let result = route
+            .call_raw_export(
+                "import_integer_types",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_integer_types".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<IntegerTypes>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(IntegerTypes) -> IntegerTypes + Send + Sync>(
+            "import_integer_types",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_integer_types(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_integer_types",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_multiple_primitives(
+    env: &RuntimeInstanceData,
+    arg1: <i8 as WasmAbi>::AbiType,
+    arg2: FatPtr,
+) -> Result<<i64 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_multiple_primitives").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_multiple_primitives",
+        "arg2",
+        PayloadDirection::FromPlugin,
+        arg2,
+    );
+    if let Some(route) = env.import_route("import_multiple_primitives") {
+        let result = route
+            .call_raw_export(
+                "import_multiple_primitives",
+                vec![
+                    serialize_to_vec(&<i8 as WasmAbi>::from_abi(arg1)),
+                    import_from_guest_raw(env, arg2),
+                ],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_multiple_primitives".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<i64>(&result).to_abi());
+    }
+    let arg1 = WasmAbi::from_abi(arg1);
+    let arg2 = import_from_guest::<String>(env, arg2);
+    let import_override = env
+        .import_override::<dyn Fn(i8, String) -> i64 + Send + Sync>("import_multiple_primitives");
+    let result = match import_override {
+        Some(implementation) => implementation(arg1, arg2),
+        None => super::import_multiple_primitives(arg1, arg2),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_multiple_values(
+    env: &RuntimeInstanceData,
+    arg: <u32 as WasmAbi>::AbiType,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_multiple_values").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_multiple_values") {
+        let result = route
+            .call_raw_export(
+                "import_multiple_values",
+                vec![serialize_to_vec(&<u32 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_multiple_values".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override = env
+        .import_override::<dyn Fn(u32) -> (String, Vec<u32>, bool) + Send + Sync>(
+            "import_multiple_values",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_multiple_values(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_multiple_values",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_primitive_bool(
+    env: &RuntimeInstanceData,
+    arg: <bool as WasmAbi>::AbiType,
+) -> Result<<bool as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_bool").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_bool") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_bool",
+                vec![serialize_to_vec(&<bool as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_bool".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<bool>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(bool) -> bool + Send + Sync>("import_primitive_bool");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_bool(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_f32(
+    env: &RuntimeInstanceData,
+    arg: <f32 as WasmAbi>::AbiType,
+) -> Result<<f32 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_f32").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_f32") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_f32",
+                vec![serialize_to_vec(&<f32 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_f32".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<f32>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(f32) -> f32 + Send + Sync>("import_primitive_f32");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_f32(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_f64(
+    env: &RuntimeInstanceData,
+    arg: <f64 as WasmAbi>::AbiType,
+) -> Result<<f64 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_f64").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_f64") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_f64",
+                vec![serialize_to_vec(&<f64 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_f64".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<f64>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(f64) -> f64 + Send + Sync>("import_primitive_f64");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_f64(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_i16(
+    env: &RuntimeInstanceData,
+    arg: <i16 as WasmAbi>::AbiType,
+) -> Result<<i16 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_i16").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_i16") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_i16",
+                vec![serialize_to_vec(&<i16 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_i16".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<i16>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(i16) -> i16 + Send + Sync>("import_primitive_i16");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_i16(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_i32(
+    env: &RuntimeInstanceData,
+    arg: <i32 as WasmAbi>::AbiType,
+) -> Result<<i32 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_i32").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_i32") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_i32",
+                vec![serialize_to_vec(&<i32 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_i32".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<i32>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(i32) -> i32 + Send + Sync>("import_primitive_i32");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_i32(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_i64(
+    env: &RuntimeInstanceData,
+    arg: <i64 as WasmAbi>::AbiType,
+) -> Result<<i64 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_i64").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_i64") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_i64",
+                vec![serialize_to_vec(&<i64 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_i64".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<i64>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(i64) -> i64 + Send + Sync>("import_primitive_i64");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_i64(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_i8(
+    env: &RuntimeInstanceData,
+    arg: <i8 as WasmAbi>::AbiType,
+) -> Result<<i8 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_i8").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_i8") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_i8",
+                vec![serialize_to_vec(&<i8 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_i8".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<i8>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(i8) -> i8 + Send + Sync>("import_primitive_i8");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_i8(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_pair(
+    env: &RuntimeInstanceData,
+    arg: <u32 as WasmAbi>::AbiType,
+) -> Result<<(u32, i32) as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_pair").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_pair") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_pair",
+                vec![serialize_to_vec(&<u32 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_pair".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<(u32, i32)>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(u32) -> (u32, i32) + Send + Sync>("import_primitive_pair");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_pair(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_u16(
+    env: &RuntimeInstanceData,
+    arg: <u16 as WasmAbi>::AbiType,
+) -> Result<<u16 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_u16").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_u16") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_u16",
+                vec![serialize_to_vec(&<u16 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_u16".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<u16>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(u16) -> u16 + Send + Sync>("import_primitive_u16");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_u16(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_u32(
+    env: &RuntimeInstanceData,
+    arg: <u32 as WasmAbi>::AbiType,
+) -> Result<<u32 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_u32").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_u32") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_u32",
+                vec![serialize_to_vec(&<u32 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_u32".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<u32>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(u32) -> u32 + Send + Sync>("import_primitive_u32");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_u32(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_u64(
+    env: &RuntimeInstanceData,
+    arg: <u64 as WasmAbi>::AbiType,
+) -> Result<<u64 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_u64").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_u64") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_u64",
+                vec![serialize_to_vec(&<u64 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_u64".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<u64>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(u64) -> u64 + Send + Sync>("import_primitive_u64");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_u64(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_primitive_u8(
+    env: &RuntimeInstanceData,
+    arg: <u8 as WasmAbi>::AbiType,
+) -> Result<<u8 as WasmAbi>::AbiType, InvocationError> {
+    let _span = import_span("import_primitive_u8").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_primitive_u8") {
+        let result = route
+            .call_raw_export(
+                "import_primitive_u8",
+                vec![serialize_to_vec(&<u8 as WasmAbi>::from_abi(arg))],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_primitive_u8".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(deserialize_from_slice::<u8>(&result).to_abi());
+    }
+    let arg = WasmAbi::from_abi(arg);
+    let import_override =
+        env.import_override::<dyn Fn(u8) -> u8 + Send + Sync>("import_primitive_u8");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_primitive_u8(arg),
+    };
+    Ok(result.to_abi())
+}
+
+pub fn _import_rate_limited(
+    env: &RuntimeInstanceData,
+    label: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_rate_limited").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_rate_limited",
+        "label",
+        PayloadDirection::FromPlugin,
+        label,
+    );
+    if let Some(route) = env.import_route("import_rate_limited") {
+        let result = route
+            .call_raw_export(
+                "import_rate_limited",
+                vec![import_from_guest_raw(env, label)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_rate_limited".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let label = import_from_guest::<String>(env, label);
+    let import_override = env
+        .import_override::<dyn Fn(String) -> Result<String, RateLimited> + Send + Sync>(
+            "import_rate_limited",
+        );
+    let result = match env.rate_limiter().try_acquire(
+        "import_rate_limited",
+        2,
+        std::time::Duration::from_millis(60000),
+    ) {
+        Ok(()) => match import_override {
+            Some(implementation) => implementation(label),
+            None => super::import_rate_limited(label),
+        },
+        Err(retry_after) => Err(RateLimited {
+            function: "import_rate_limited".to_owned(),
+            retry_after_ms: retry_after.as_millis() as u32,
+        }),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_rate_limited",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_renamed_string(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_renamed_string").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_renamed_string",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_renamed_string") {
+        let result = route
+            .call_raw_export(
+                "import_renamed_string",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_renamed_string".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<String>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn(String) -> String + Send + Sync>("import_renamed_string");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_renamed_string(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_renamed_string",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_serde_adjacently_tagged(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_serde_adjacently_tagged").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_serde_adjacently_tagged",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_serde_adjacently_tagged") {
+        let result = route
+            .call_raw_export(
+                "import_serde_adjacently_tagged",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_serde_adjacently_tagged".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<SerdeAdjacentlyTagged>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged + Send + Sync>(
+            "import_serde_adjacently_tagged",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_serde_adjacently_tagged(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_serde_adjacently_tagged",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_serde_enum(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_serde_enum").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_serde_enum",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_serde_enum") {
+        let result = route
+            .call_raw_export("import_serde_enum", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_serde_enum".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<SerdeVariantRenaming>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(SerdeVariantRenaming) -> SerdeVariantRenaming + Send + Sync>(
+            "import_serde_enum",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_serde_enum(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_serde_enum",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_serde_flatten(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_serde_flatten").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_serde_flatten",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_serde_flatten") {
+        let result = route
+            .call_raw_export(
+                "import_serde_flatten",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_serde_flatten".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<SerdeFlatten>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(SerdeFlatten) -> SerdeFlatten + Send + Sync>(
+            "import_serde_flatten",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_serde_flatten(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_serde_flatten",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_serde_internally_tagged(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_serde_internally_tagged").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_serde_internally_tagged",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_serde_internally_tagged") {
+        let result = route
+            .call_raw_export(
+                "import_serde_internally_tagged",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_serde_internally_tagged".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<SerdeInternallyTagged>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(SerdeInternallyTagged) -> SerdeInternallyTagged + Send + Sync>(
+            "import_serde_internally_tagged",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_serde_internally_tagged(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_serde_internally_tagged",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_serde_struct(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_serde_struct").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_serde_struct",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_serde_struct") {
+        let result = route
+            .call_raw_export("import_serde_struct", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_serde_struct".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<SerdePropertyRenaming>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(SerdePropertyRenaming) -> SerdePropertyRenaming + Send + Sync>(
+            "import_serde_struct",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_serde_struct(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_serde_struct",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_serde_untagged(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_serde_untagged").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_serde_untagged",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_serde_untagged") {
+        let result = route
+            .call_raw_export(
+                "import_serde_untagged",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_serde_untagged".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<SerdeUntagged>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(SerdeUntagged) -> SerdeUntagged + Send + Sync>(
+            "import_serde_untagged",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_serde_untagged(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_serde_untagged",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_set_value(env: &RuntimeInstanceData, key: FatPtr, value: FatPtr) {
+    let _span = import_span("import_set_value").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_set_value",
+        "key",
+        PayloadDirection::FromPlugin,
+        key,
+    );
+    trace_guest_payload(
+        env,
+        "import_set_value",
+        "value",
+        PayloadDirection::FromPlugin,
+        value,
+    );
+    let key = import_from_guest::<String>(env, key);
+    let value = import_from_guest::<_>(env, value);
+    let result = super::import_set_value(key, value);
+}
+
+pub fn _import_string(env: &RuntimeInstanceData, arg: FatPtr) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_string").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_string",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_string") {
+        let result = route
+            .call_raw_export("import_string", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_string".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<String>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn(String) -> String + Send + Sync>("import_string");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_string(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_string",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_struct_with_options(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_struct_with_options").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_struct_with_options",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_struct_with_options") {
+        let result = route
+            .call_raw_export(
+                "import_struct_with_options",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_struct_with_options".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<StructWithOptions>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(StructWithOptions) -> StructWithOptions + Send + Sync>(
+            "import_struct_with_options",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_struct_with_options(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_struct_with_options",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_timestamp(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_timestamp").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_timestamp",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_timestamp") {
+        let result = route
+            .call_raw_export("import_timestamp", vec![import_from_guest_raw(env, arg)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_timestamp".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<MyDateTime>(env, arg);
+    let import_override =
+        env.import_override::<dyn Fn(MyDateTime) -> MyDateTime + Send + Sync>("import_timestamp");
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_timestamp(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_timestamp",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_validated_struct(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_validated_struct").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "import_validated_struct",
+        "arg",
+        PayloadDirection::FromPlugin,
+        arg,
+    );
+    if let Some(route) = env.import_route("import_validated_struct") {
+        let result = route
+            .call_raw_export(
+                "import_validated_struct",
+                vec![import_from_guest_raw(env, arg)],
+            )
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_validated_struct".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+    let arg = import_from_guest::<ValidatedStruct>(env, arg);
+    let import_override = env
+        .import_override::<dyn Fn(ValidatedStruct) -> ValidatedStruct + Send + Sync>(
+            "import_validated_struct",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(arg),
+        None => super::import_validated_struct(arg),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_validated_struct",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_void_function(env: &RuntimeInstanceData) -> Result<(), InvocationError> {
+    let _span = import_span("import_void_function").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_void_function") {
+        let result = route
+            .call_raw_export("import_void_function", vec![])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_void_function".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(());
+    }
+
+    let import_override = env.import_override::<dyn Fn() + Send + Sync>("import_void_function");
+    let result = match import_override {
+        Some(implementation) => implementation(),
+        None => super::import_void_function(),
+    };
+    Ok(())
+}
+
+pub fn _import_void_function_empty_result(
+    env: &RuntimeInstanceData,
+) -> Result<FatPtr, InvocationError> {
+    let _span = import_span("import_void_function_empty_result").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_void_function_empty_result") {
+        let result = route
+            .call_raw_export("import_void_function_empty_result", vec![])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_void_function_empty_result".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(try_export_to_guest_raw(env, result)?);
+    }
+
+    let import_override = env.import_override::<dyn Fn() -> Result<(), u32> + Send + Sync>(
+        "import_void_function_empty_result",
+    );
+    let result = match import_override {
+        Some(implementation) => implementation(),
+        None => super::import_void_function_empty_result(),
+    };
+    let result = try_export_to_guest(env, &result)?;
+    trace_guest_payload(
+        env,
+        "import_void_function_empty_result",
+        "result",
+        PayloadDirection::ToPlugin,
+        result,
+    );
+    Ok(result)
+}
+
+pub fn _import_void_function_empty_return(
+    env: &RuntimeInstanceData,
+) -> Result<(), InvocationError> {
+    let _span = import_span("import_void_function_empty_return").entered();
+    let _deadline = enter_guest_deadline(env);
+    if let Some(route) = env.import_route("import_void_function_empty_return") {
+        let result = route
+            .call_raw_export("import_void_function_empty_return", vec![])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "import_void_function_empty_return".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(());
+    }
+
+    let import_override =
+        env.import_override::<dyn Fn() + Send + Sync>("import_void_function_empty_return");
+    let result = match import_override {
+        Some(implementation) => implementation(),
+        None => super::import_void_function_empty_return(),
+    };
+    Ok(())
+}
+
+pub fn _invoke_command(env: &RuntimeInstanceData, name: FatPtr, payload: FatPtr) -> FatPtr {
+    let _span = import_span("invoke_command").entered();
+    let _deadline = enter_guest_deadline(env);
+    let name = import_from_guest::<String>(env, name);
+    if let Some(handler) = env.dynamic_import_handler("invoke_command", &name) {
+        let payload = import_from_guest_raw(env, payload);
+        return export_to_guest_raw(env, handler(&payload));
+    }
+    let payload = import_from_guest::<String>(env, payload);
+    let result = super::invoke_command(name, payload);
+    export_to_guest(env, &result)
+}
+
+pub fn _log(env: &RuntimeInstanceData, message: FatPtr) -> Result<(), InvocationError> {
+    let _span = import_span("log").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(env, "log", "message", PayloadDirection::FromPlugin, message);
+    if let Some(route) = env.import_route("log") {
+        let result = route
+            .call_raw_export("log", vec![import_from_guest_raw(env, message)])
+            .map_err(|error| InvocationError::RoutedImportFailed {
+                function: "log".to_owned(),
+                error: Box::new(error),
+            })?;
+        return Ok(());
+    }
+    let message = import_from_guest::<String>(env, message);
+    let import_override = env.import_override::<dyn Fn(String) + Send + Sync>("log");
+    let result = match import_override {
+        Some(implementation) => implementation(message),
+        None => super::log(message),
+    };
+    Ok(())
+}
+
+pub fn _make_http_request(
+    env: &RuntimeInstanceData,
+    request: FatPtr,
+) -> Result<FatPtr, PermissionDenied> {
+    let _span = import_span("make_http_request").entered();
+    let _deadline = enter_guest_deadline(env);
+    env.check_capability("make_http_request", "network")?;
+    trace_guest_payload(
+        env,
+        "make_http_request",
+        "request",
+        PayloadDirection::FromPlugin,
+        request,
+    );
+    let request = import_from_guest::<Request>(env, request);
+    let import_override = env
+        .import_override::<dyn Fn(Request) -> OverrideFuture<HttpResult> + Send + Sync>(
+            "make_http_request",
+        );
+    let result = match import_override {
+        Some(implementation) => implementation(request),
+        None => Box::pin(super::make_http_request(request)),
+    };
+    let result = result.with_current_deadline();
+    let env = env.clone();
+    let async_ptr = create_future_value(&env);
+    let handle = tokio::runtime::Handle::current();
+    handle.spawn(
+        async move {
+            let result = result.await;
+            let result_ptr = export_to_guest(&env, &result);
+            trace_guest_payload(
+                &env,
+                "make_http_request",
+                "result",
+                PayloadDirection::ToPlugin,
+                result_ptr,
+            );
+            env.guest_resolve_async_value(async_ptr, result_ptr);
+        }
+        .in_current_span(),
+    );
+    Ok(async_ptr)
+}
+
+pub fn _record_metric(env: &RuntimeInstanceData, name: FatPtr, value: <f64 as WasmAbi>::AbiType) {
+    let _span = import_span("record_metric").entered();
+    let _deadline = enter_guest_deadline(env);
+    trace_guest_payload(
+        env,
+        "record_metric",
+        "name",
+        PayloadDirection::FromPlugin,
+        name,
+    );
+    let name = import_from_guest::<String>(env, name);
+    let value = WasmAbi::from_abi(value);
+    let import_override = env.import_override::<dyn Fn(String, f64) + Send + Sync>("record_metric");
+    let result = match import_override {
+        Some(implementation) => implementation(name, value),
+        None => super::record_metric(name, value),
+    };
+}
+
+pub fn _report_progress(env: &RuntimeInstanceData, progress: FatPtr) {
+    let _span = import_span("report_progress").entered();
+    let progress = import_from_guest_raw(env, progress);
+    env.emit_event("report_progress", &progress);
+}
+
+pub fn __fp_host_batch(env: &RuntimeInstanceData, calls: FatPtr) {
+    let _deadline = enter_guest_deadline(env);
+    for call in import_from_guest::<Vec<BatchedCall>>(env, calls) {
+        match call {
+            BatchedCall::RecordMetric((name, value)) => {
+                match env.import_override::<dyn Fn(String, f64) + Send + Sync>("record_metric") {
+                    Some(implementation) => implementation(name, value),
+                    None => super::record_metric(name, value),
+                }
+            }
+        }
+    }
+}
+
+/// A call to a batchable import, as it was queued by the plugin.
+#[derive(serde::Deserialize)]
+enum BatchedCall {
+    #[serde(rename = "record_metric")]
+    RecordMetric((String, f64)),
+}
+
+const PROTOCOL: &str = "{\"import_functions\":[{\"name\":\"import_arbitrary_precision_numbers\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ArbitraryPrecisionNumbers\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"ArbitraryPrecisionNumbers\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_f32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"f32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_f64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"f64\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_i16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i16\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i16\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_i32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_i8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i8\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_u16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u16\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_u32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_u8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u8\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_async_void_function\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"message\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_collections\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_experimental\",\"doc_lines\":[\" Example of an import behind a feature. Only plugins and runtimes that\",\" are compiled with the `experimental` feature know about it.\"],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":\"experimental\",\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_explicit_bound_point\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ExplicitBoundPoint\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_adjacently_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_enum\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_flatten\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_internally_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_untagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_generics\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}}],\"return_type\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_get_bytes\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"Bytes\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_get_serde_bytes\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_get_value\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"key\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"_\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_integer_types\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"IntegerTypes\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"IntegerTypes\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_multiple_primitives\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg1\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null}},{\"name\":\"arg2\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_multiple_values\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"(String, Vec<u32>, bool)\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_bool\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_f32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_f64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_i16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i16\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i16\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_i32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_i64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_i8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_pair\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"(u32, i32)\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_u16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_u32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_u64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_u8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_rate_limited\",\"doc_lines\":[\" Example of a rate-limited import. Calls that exceed the limit return a\",\" `RateLimited` error to the plugin, instead of reaching the runtime.\"],\"generic_params\":[],\"args\":[{\"name\":\"label\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"RateLimited\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":{\"calls\":2,\"per_ms\":60000},\"read_only\":false,\"rename\":null}},{\"name\":\"import_renamed_string\",\"doc_lines\":[\" Example of a renamed import. It is still exchanged under its old name,\",\" so plugins that were built against `import_legacy_string` keep working.\"],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":\"import_legacy_string\"}},{\"name\":\"import_serde_adjacently_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_enum\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_flatten\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_internally_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_untagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_set_value\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"key\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}},{\"name\":\"value\",\"ty\":{\"name\":\"_\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_string\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_struct_with_options\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_timestamp\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_validated_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_void_function\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_void_function_empty_result\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"()\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_void_function_empty_return\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"invoke_command\",\"doc_lines\":[\" Example of a dynamic import. The runtime can register handlers for it\",\" after instantiation, which are dispatched to by `name`. Calls for which\",\" no handler is registered fall back to the regular implementation.\"],\"generic_params\":[],\"args\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}},{\"name\":\"payload\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":true,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"log\",\"doc_lines\":[\" Logs a message to the (development) console.\"],\"generic_params\":[],\"args\":[{\"name\":\"message\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"make_http_request\",\"doc_lines\":[\" Example how a runtime could expose a `Fetch`-like function to plugins.\",\"\",\" See `types/http.rs` for more info.\",\"\",\" Plugins may only call this if the runtime granted them the `network`\",\" capability.\"],\"generic_params\":[],\"args\":[{\"name\":\"request\",\"ty\":{\"name\":\"Request\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"HttpResult\",\"generic_args\":[],\"array\":null},\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":\"network\",\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"record_metric\",\"doc_lines\":[\" Example of a batchable import. Calls that are made inside `batch()` are\",\" queued by the plugin and sent to the runtime together.\"],\"generic_params\":[],\"args\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}},{\"name\":\"value\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":true,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"report_progress\",\"doc_lines\":[\" Example of an event. The plugin can emit it at any time, after which\",\" the runtime passes it on to its subscribers.\"],\"generic_params\":[],\"args\":[{\"name\":\"progress\",\"ty\":{\"name\":\"Progress\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":true,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}}],\"export_functions\":[{\"name\":\"export_array_f32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"f32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_f64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"f64\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_i16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i16\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i16\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_i32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_i8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i8\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_u16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u16\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_u32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_u8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u8\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_async_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg1\",\"ty\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null}},{\"name\":\"arg2\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/async-struct\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_batch_response\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"BatchResponse\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"BatchResponse\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/batch\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_cached_call_count\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"label\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":{\"ttl_ms\":30000,\"key\":null},\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_call_rate_limited_import\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"calls\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"RateLimited\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_collections\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_dynamic_value\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ExtensionSettings\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Value\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_edge_values\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"EdgeValues\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"EdgeValues\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_experimental\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":\"experimental\",\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_adjacently_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_enum\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_flatten\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_internally_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_untagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_generics\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}}],\"return_type\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/generics\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_get_bytes\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"Bytes\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"GET\",\"path\":\"/bytes\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_get_serde_bytes\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_lazy_string\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":true,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_metrics\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"metrics\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Metric\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}}],\"return_type\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Metric\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_multiple_primitives\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg1\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null}},{\"name\":\"arg2\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"GET\",\"path\":\"/primitives\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_multiple_values\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"(String, Vec<u32>, bool)\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_bool\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_f32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_f64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_i16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i16\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i16\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_i32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_i64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_i8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_pair\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"(u32, i32)\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_u16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_u32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_u64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_u8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_renamed_string\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":\"export_legacy_string\"}},{\"name\":\"export_samples\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"samples\",\"ty\":{\"name\":\"SampleColumns\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SampleColumns\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_serde_adjacently_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/enums/adjacently-tagged\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_serde_enum\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/enums/renamed\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_serde_flatten\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/flatten\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_serde_internally_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/enums/internally-tagged\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_serde_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_serde_untagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/enums/untagged\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_string\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_string_after_memory_growth\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_struct_with_options\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"PUT\",\"path\":\"/options\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_timestamp\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_timestamps\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"Timestamps\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Timestamps\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_typed_id\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"id\",\"ty\":{\"name\":\"TypedId\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null}}],\"return_type\":{\"name\":\"TypedId\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_validated_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_void_function\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"fetch_data\",\"doc_lines\":[\" Example how plugin could expose async data-fetching capabilities.\"],\"generic_params\":[],\"args\":[{\"name\":\"r#type\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"GET\",\"path\":\"/data/{type}\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"init\",\"doc_lines\":[\" Called on the plugin to give it a chance to initialize.\"],\"generic_params\":[],\"args\":[{\"name\":\"config\",\"ty\":{\"name\":\"PluginConfig\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/init\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"reducer_bridge\",\"doc_lines\":[\" Example how plugin could expose a reducer.\"],\"generic_params\":[],\"args\":[{\"name\":\"action\",\"ty\":{\"name\":\"ReduxAction\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"StateUpdate\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"update_config\",\"doc_lines\":[\" Called on the plugin when the host changes its config.\"],\"generic_params\":[],\"args\":[{\"name\":\"config\",\"ty\":{\"name\":\"PluginConfig\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}}],\"types\":[[{\"name\":\"()\",\"generic_args\":[],\"array\":null},\"Unit\"],[{\"name\":\"(String, Vec<u32>, bool)\",\"generic_args\":[],\"array\":null},{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null},{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"name\":\"bool\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"(u32, i32)\",\"generic_args\":[],\"array\":null},{\"Tuple\":[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},{\"name\":\"i32\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"ArbitraryPrecisionNumbers\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ArbitraryPrecisionNumbers\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"amount\",\"ty\":{\"name\":\"Decimal\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"total_supply\",\"ty\":{\"name\":\"BigInt\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Example of numbers that cannot be represented as `f64` without losing\",\" precision.\",\"\",\" They are exchanged as strings, and typed as branded strings in TypeScript.\",\" Requires the `rust-decimal-compat` and `num-bigint-compat` features.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"BTreeMap\",\"generic_args\":[[{\"name\":\"K\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"V\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Map\":[\"BTreeMap\",{\"name\":\"K\",\"generic_args\":[],\"array\":null},{\"name\":\"V\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"BTreeSet\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"List\":[\"BTreeSet\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"BatchResponse\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"BatchResponse\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"results\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"OperationResult\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"summary\",\"ty\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"retry_after\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" The response to a batch of operations, some of which may have failed.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"BigInt\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"BigInt\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"num_bigint::BigInt\",\"serde_attrs\":[\"with = \\\"fp_bindgen_support::common::serde_str\\\"\"],\"ts_ty\":\"BigIntString\",\"ts_declaration\":\"string & { readonly __brand: \\\"BigInt\\\" }\"}}],[{\"name\":\"Body\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"Body\",{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"serde_bytes::ByteBuf\",\"serde_attrs\":[],\"ts_ty\":\"ArrayBuffer\",\"ts_declaration\":null}}],[{\"name\":\"Bytes\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Bytes\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"bytes::Bytes\",\"serde_attrs\":[],\"ts_ty\":\"Uint8Array\",\"ts_declaration\":null}}],[{\"name\":\"Collections\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"tags\",\"ty\":{\"name\":\"BTreeSet\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"optional_tags\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"BTreeSet\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"queue\",\"ty\":{\"name\":\"VecDeque\",\"generic_args\":[[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Std collections that are encoded as lists.\",\"\",\" Sets are generated as `Set` types in TypeScript if `generate_set_types` is\",\" enabled, while other lists are always generated as arrays.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ConditionalFields\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ConditionalFields\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"backtrace\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[\" Only hosts and plugins that are written in Rust know about this field,\",\" so it needs to be optional.\"],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"Option::is_none\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[\"rust-plugin\",\"rust-wasmer-runtime\"]}}],\"doc_lines\":[\" Fields and variants behind a `#[cfg(...)]` attribute are only included in\",\" the bindings if the protocol is compiled with the condition enabled.\",\"\",\" Using `#[fp(generators = \\\"...\\\")]`, fields and variants can also be limited\",\" to the bindings of specific generators.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ConditionalVariants\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"ConditionalVariants\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Stable\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"CustomSerializers\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"CustomSerializers\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"timestamp\",\"ty\":{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Serialized as a Unix timestamp, instead of the RFC3339 string that is\",\" used for `OffsetDateTime` by default.\"],\"attrs\":{\"default\":null,\"deserialize_with\":\"time::serde::timestamp\",\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":\"time::serde::timestamp\",\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":\"number\",\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Decimal\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Decimal\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"rust_decimal::Decimal\",\"serde_attrs\":[\"with = \\\"fp_bindgen_support::common::serde_str\\\"\"],\"ts_ty\":\"DecimalString\",\"ts_declaration\":\"string & { readonly __brand: \\\"Decimal\\\" }\"}}],[{\"name\":\"DocExampleEnum\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"DocExampleEnum\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Variant1\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[\" Multi-line doc comment with complex characters\",\" & \\\" , \\\\ ! '\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"r#Variant2\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"r#Variant2\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"inner\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Variant property.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[\" Raw identifiers are supported too.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[\" # This is an enum with doc comments.\"],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"DocExampleStruct\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"DocExampleStruct\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"multi_line\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Multi-line doc comment with complex characters\",\" & \\\" , \\\\ ! '\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"r#type\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Raw identifiers are supported too.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" # This is a struct with doc comments.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"EdgeValues\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"EdgeValues\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"character\",\"ty\":{\"name\":\"char\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"float\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"signed\",\"ty\":{\"name\":\"i128\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"unsigned\",\"ty\":{\"name\":\"u128\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Example of values at the edges of what the runtimes can represent.\",\"\",\" `char` is exchanged as a single-character string, 128-bit integers are\",\" exchanged as decimal strings, and `f32` keeps NaN and infinities intact.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ExplicitBoundPoint\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[\"Serializable\",\"std::fmt::Debug\",\"std::fmt::Display\"]]],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ExplicitBoundPoint\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[\"Serializable\",\"std::fmt::Debug\",\"std::fmt::Display\"]]],\"array\":null},\"fields\":[{\"name\":\"value\",\"ty\":{\"name\":\"T\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A point of an arbitrary type, with explicit trait bounds.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ExplicitedlyImportedType\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ExplicitedlyImportedType\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"you_will_see_this\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" This struct is also not referenced by any function or data structure, but\",\" it will show up because there is an explicit `use` statement for it in the\",\" `fp_import!` macro.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ExtensionSettings\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ExtensionSettings\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"extension\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"settings\",\"ty\":{\"name\":\"Value\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Settings for an extension whose shape is only known to the extension\",\" itself.\",\"\",\" `serde_json::Value` (and `rmpv::Value`) can be used anywhere in a protocol\",\" to pass values of arbitrary shape. Both are encoded as plain MessagePack,\",\" and show up as `unknown` in the TypeScript bindings.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"FlattenedStruct\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"FlattenedStruct\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"bar\",\"ty\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"FloatingPoint\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"FloatingPoint\",{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}]}],[{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Foo\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Bar\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[\"Qux\"],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Pair\",\"ty\":{\"Tuple\":[{\"name\":\"i8\",\"generic_args\":[],\"array\":null},{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Unknown\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":true,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":\"payload\",\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"flattened\",\"ty\":{\"name\":\"FlattenedStruct\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":true,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Foo\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":true}}}],[{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo_bar\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[\"foo\"],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"qux_baz\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":\"QUX_BAZ\",\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"r#raw_struct\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Bar\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Pair\",\"ty\":{\"Tuple\":[{\"name\":\"i8\",\"generic_args\":[],\"array\":null},{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":true,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"FooBar\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"QuxBaz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"QuxBaz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo_bar\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Will be renamed to \\\"FOO_BAR\\\" because of the `rename_all` on the\",\" variant.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"qux_baz\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":\"qux_baz\",\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"ScreamingSnakeCase\",\"generators\":[],\"other\":false,\"rename\":\"QUX_BAZ\",\"renamed_from\":[\"qux\"]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"GroupImportedType1\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"GroupImportedType1\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"you_will_see_this\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"GroupImportedType2\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"GroupImportedType2\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"you_will_see_this\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"HttpResult\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"HttpResult\",{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"Response\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"RequestError\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}]}],[{\"name\":\"Int64\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"Int64\",{\"name\":\"u64\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"IntegerTypes\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"IntegerTypes\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"count\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"offset\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"id\",\"ty\":{\"name\":\"NonZeroU32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"delta\",\"ty\":{\"name\":\"NonZeroI64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"capacity\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"NonZeroU32\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"user_id\",\"ty\":{\"name\":\"UserId\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Example of integer types that are not primitives in the protocol.\",\"\",\" `usize` and `isize` are exchanged as `u32` and `i32`, because that is their\",\" size on `wasm32`. `NonZero` types refuse to deserialize when they are zero.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Method\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Method\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"http::Method\",\"serde_attrs\":[\"serialize_with = \\\"fp_bindgen_support::http::serialize_http_method\\\"\",\"deserialize_with = \\\"fp_bindgen_support::http::deserialize_http_method\\\"\"],\"ts_ty\":\"Method\",\"ts_declaration\":\"\\n    | \\\"GET\\\"\\n    | \\\"POST\\\"\\n    | \\\"PUT\\\"\\n    | \\\"DELETE\\\"\\n    | \\\"HEAD\\\"\\n    | \\\"OPTIONS\\\"\\n    | \\\"CONNECT\\\"\\n    | \\\"PATCH\\\"\\n    | \\\"TRACE\\\"\"}}],[{\"name\":\"Metric\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Metric\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":true,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"value\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A measurement that is reported by the plugin.\",\"\",\" Because the `name` field is annotated with `#[fp(interned)]`, the plugin\",\" registers every distinct name with the runtime once, and sends a `u32`\",\" handle in its place from then on. This keeps the payloads small when the\",\" same few names are reported over and over.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":null,\"ty\":{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Our struct for passing date time instances.\",\"\",\" We wrap the `OffsetDateTime` type in a new struct so that the Serde\",\" attributes can be inserted. These are necessary to enable RFC3339\",\" formatting. Without a wrapper type like this, we would not be able to pass\",\" date time instances directly to function arguments and we might run into\",\" trouble embedding them into certain generic types.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"NonZeroI64\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"NonZeroI64\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"std::num::NonZeroI64\",\"serde_attrs\":[],\"ts_ty\":\"number\",\"ts_declaration\":null}}],[{\"name\":\"NonZeroU32\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"NonZeroU32\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"std::num::NonZeroU32\",\"serde_attrs\":[],\"ts_ty\":\"number\",\"ts_declaration\":null}}],[{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"time::OffsetDateTime\",\"serde_attrs\":[\"with = \\\"time::serde::rfc3339\\\"\"],\"ts_ty\":\"string\",\"ts_declaration\":null}}],[{\"name\":\"OperationResult\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"OperationResult\",{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}]}],[{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Container\":[\"Option\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"PluginConfig\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"PluginConfig\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"log_level\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Config that is passed to the `init` export when the plugin is instantiated.\",\"\",\" Every protocol has `init` and `shutdown` exports, but protocols can declare\",\" `init` themselves to have it take a config argument like this one.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"fields\":[{\"name\":\"value\",\"ty\":{\"name\":\"T\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A point of an arbitrary type.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Priority\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"Priority\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Low\",\"ty\":\"Unit\",\"doc_lines\":[\" Handled after everything else.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Normal\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"High\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":\"urgent\",\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"Progress\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Progress\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"task\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"completed_steps\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"total_steps\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Emitted by the plugin while it works on a long-running task.\",\"\",\" See the `report_progress` event in `main.rs` for more info.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"RateLimited\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"RateLimited\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"function\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" The name of the import that was called.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"retry_after_ms\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" The number of milliseconds after which the import may be called again.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Error that is returned to the plugin when it calls a rate-limited import\",\" more often than its limit allows.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Rc\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Container\":[\"Rc\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"ReduxAction\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"ReduxAction\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"ClearTitle\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"UpdateTitle\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"UpdateTitle\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"title\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[\" Example for representing Redux actions.\"],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":\"payload\",\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":\"redux_example\",\"non_exhaustive\":false}}}],[{\"name\":\"Request\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Request\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"url\",\"ty\":{\"name\":\"Uri\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" The URI to submit the request to.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"method\",\"ty\":{\"name\":\"Method\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP method to use for the request.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"headers\",\"ty\":{\"name\":\"http::HeaderMap\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP headers to submit with the request.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"body\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"Body\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[\" The body to submit with the request.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"Option::is_none\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Represents an HTTP request to be sent.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"RequestError\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"RequestError\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Offline\",\"ty\":\"Unit\",\"doc_lines\":[\" Used when we know we don't have an active network connection.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"NoRoute\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"ConnectionRefused\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Timeout\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"ServerError\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"ServerError\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"status_code\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP status code.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"response\",\"ty\":{\"name\":\"Body\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Response body.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"SnakeCase\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Other\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Other\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"reason\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[\" Misc.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":\"other/misc\",\"renamed_from\":[]}}],\"doc_lines\":[\" Represents an error that occurred while attempting to submit the request.\"],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":null,\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"Response\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Response\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"body\",\"ty\":{\"name\":\"Body\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" The response body. May be empty.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"headers\",\"ty\":{\"name\":\"http::HeaderMap\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP headers that were part of the response.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"status_code\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP status code.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Represents an HTTP response we received.\",\"\",\" Please note we currently do not support streaming responses.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"E\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"E\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"variants\":[{\"name\":\"Ok\",\"ty\":{\"Tuple\":[{\"name\":\"T\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[\" Represents a successful result.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Err\",\"ty\":{\"Tuple\":[{\"name\":\"E\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[\" Represents an error.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[\" A result that can be either successful (`Ok`) or represent an error (`Err`).\"],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"Sample\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Sample\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"timestamp_ms\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"value\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"quality\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A sample of a time series.\",\"\",\" Because the struct is annotated with `#[fp(columnar)]`, every `Vec<Sample>`\",\" in the protocol is replaced with a generated `SampleColumns` struct, which\",\" holds a column with the values of every field. In TypeScript, the columns\",\" are typed arrays, so large lists of samples can be passed without encoding\",\" an object for every item.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":true}}}],[{\"name\":\"SampleColumns\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"SampleColumns\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"timestamp_ms\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":\"fp_bindgen_support::common::columnar\",\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":\"fp_bindgen_support::common::columnar\",\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":\"BigUint64Array\",\"validate\":null,\"generators\":[]}},{\"name\":\"value\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":\"fp_bindgen_support::common::columnar\",\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":\"fp_bindgen_support::common::columnar\",\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":\"Float64Array\",\"validate\":null,\"generators\":[]}},{\"name\":\"quality\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"u8\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":\"fp_bindgen_support::common::columnar\",\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":\"fp_bindgen_support::common::columnar\",\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":\"Uint8Array\",\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" The columns of a list of `Sample`, with the values of every field stored contiguously.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Foo\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Bar\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":\"payload\",\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"flattened\",\"ty\":{\"name\":\"FlattenedStruct\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":true,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Foo\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo_bar\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"qux_baz\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":\"QUX_BAZ\",\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"r#raw_struct\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Bar\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":true,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"FooBar\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"QuxBaz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"QuxBaz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo_bar\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Will be renamed to \\\"FooBar\\\" because of the `rename_all` on the\",\" variant.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"qux_baz\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":\"qux_baz\",\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"PascalCase\",\"generators\":[],\"other\":false,\"rename\":\"QUX_BAZ\",\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"StateUpdate\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"StateUpdate\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"title\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"Rc\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"revision\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"u16\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A state update to communicate to the Redux host.\",\"\",\" Fields are wrapped in `Option`. If any field is `None` it means it hasn't\",\" changed.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":\"redux_example\",\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"String\"],[{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"fields\":[{\"name\":\"list\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"points\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"recursive\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"complex_nested\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"BTreeMap\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"FloatingPoint\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"optional_timestamp\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"filled_string\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"String::is_empty\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"empty_string\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"String::is_empty\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"filled_option_string\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"Option::is_none\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"empty_option_string\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"Option::is_none\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"never_skipped_filled_option_string\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"never_skipped_empty_option_string\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Timestamps\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Timestamps\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"created_at\",\"ty\":{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":\"Date\",\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"deleted_at\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":\"Date\",\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Timestamps that the TypeScript runtime represents as `Date` objects.\",\"\",\" Fields that are annotated with `ts_as = \\\"Date\\\"` are still serialized as\",\" RFC3339 strings, but the TypeScript runtime converts them into `Date`\",\" objects when they are received, and back into strings when they are sent.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"TypedId\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"TypedId\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"fields\":[{\"name\":\"id\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"resource\",\"ty\":{\"name\":\"std::marker::PhantomData\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" An identifier of a resource of type `T`.\",\"\",\" `PhantomData` fields are not serialized, so they only show up in the Rust\",\" bindings, where they keep the type parameter in use. Other bindings only\",\" preserve the type parameter in the signature of the type.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Uri\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Uri\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"http::Uri\",\"serde_attrs\":[\"serialize_with = \\\"fp_bindgen_support::http::serialize_uri\\\"\",\"deserialize_with = \\\"fp_bindgen_support::http::deserialize_uri\\\"\"],\"ts_ty\":\"string\",\"ts_declaration\":null}}],[{\"name\":\"UserId\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"UserId\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":null,\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Example of a newtype that can be generated as a branded type in TypeScript.\",\"\",\" With `generate_branded_types`, it is typed as\",\" `number & { readonly __brand: \\\"UserId\\\" }`, so that it cannot be mixed up\",\" with other numbers.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":true,\"columnar\":false}}}],[{\"name\":\"ValidatedChild\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ValidatedChild\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":null,\"non_empty\":true,\"regex\":null},\"generators\":[]}}],\"doc_lines\":[\" Structs that contain validated structs get validated as a whole.\",\"\",\" Fields that are not part of this struct are rejected, rather than silently\",\" ignored.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":true,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"percentage\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":{\"start\":\"0\",\"end\":\"100\",\"inclusive\":true},\"non_empty\":false,\"regex\":null},\"generators\":[]}},{\"name\":\"slug\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":null,\"non_empty\":true,\"regex\":\"^[a-z][a-z0-9-]*$\"},\"generators\":[]}},{\"name\":\"ratio\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":{\"start\":\"0\",\"end\":\"1\",\"inclusive\":false},\"non_empty\":false,\"regex\":null},\"generators\":[]}},{\"name\":\"children\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"ValidatedChild\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":null,\"non_empty\":true,\"regex\":null},\"generators\":[]}}],\"doc_lines\":[\" Fields with `#[fp(validate(...))]` attributes are validated when they are\",\" received from the other side, so invalid values are rejected right away.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Value\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Value\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"serde_json::Value\",\"serde_attrs\":[],\"ts_ty\":\"unknown\",\"ts_declaration\":null}}],[{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"List\":[\"Vec\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"VecDeque\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"List\":[\"VecDeque\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"bool\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"Bool\"}],[{\"name\":\"char\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"char\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"char\",\"serde_attrs\":[],\"ts_ty\":\"string\",\"ts_declaration\":null}}],[{\"name\":\"f32\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"F32\"}],[{\"name\":\"f32\",\"generic_args\":[],\"array\":3},{\"Array\":[\"F32\",3]}],[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"F64\"}],[{\"name\":\"f64\",\"generic_args\":[],\"array\":3},{\"Array\":[\"F64\",3]}],[{\"name\":\"http::HeaderMap\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"http::HeaderMap\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"http::HeaderMap\",\"serde_attrs\":[\"serialize_with = \\\"fp_bindgen_support::http::serialize_header_map\\\"\",\"deserialize_with = \\\"fp_bindgen_support::http::deserialize_header_map\\\"\"],\"ts_ty\":\"HeaderMap\",\"ts_declaration\":\"{ [key: string]: Uint8Array }\"}}],[{\"name\":\"i128\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"i128\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"i128\",\"serde_attrs\":[\"with = \\\"fp_bindgen_support::common::serde_str\\\"\"],\"ts_ty\":\"I128String\",\"ts_declaration\":\"string & { readonly __brand: \\\"i128\\\" }\"}}],[{\"name\":\"i16\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"I16\"}],[{\"name\":\"i16\",\"generic_args\":[],\"array\":3},{\"Array\":[\"I16\",3]}],[{\"name\":\"i32\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"I32\"}],[{\"name\":\"i32\",\"generic_args\":[],\"array\":3},{\"Array\":[\"I32\",3]}],[{\"name\":\"i64\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"I64\"}],[{\"name\":\"i8\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"I8\"}],[{\"name\":\"i8\",\"generic_args\":[],\"array\":3},{\"Array\":[\"I8\",3]}],[{\"name\":\"u128\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"u128\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"u128\",\"serde_attrs\":[\"with = \\\"fp_bindgen_support::common::serde_str\\\"\"],\"ts_ty\":\"U128String\",\"ts_declaration\":\"string & { readonly __brand: \\\"u128\\\" }\"}}],[{\"name\":\"u16\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"U16\"}],[{\"name\":\"u16\",\"generic_args\":[],\"array\":3},{\"Array\":[\"U16\",3]}],[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"U32\"}],[{\"name\":\"u32\",\"generic_args\":[],\"array\":3},{\"Array\":[\"U32\",3]}],[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"U64\"}],[{\"name\":\"u8\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"U8\"}],[{\"name\":\"u8\",\"generic_args\":[],\"array\":3},{\"Array\":[\"U8\",3]}]]}";
@@ -0,0 +1,25 @@
+#![allow(unused_imports)]
+use super::types::*;
+use fp_bindgen_support::host::migrations::*;
+
+/// Upgrades a serialized `FpPropertyRenaming` from a previous version of the protocol.
+pub fn upgrade_fp_property_renaming(value: &mut Value) {
+    rename_field(value, "foo", "fooBar");
+}
+
+/// Deserializes a `FpPropertyRenaming` from a payload that may have been serialized by a
+/// previous version of the protocol.
+pub fn migrate_fp_property_renaming(payload: &[u8]) -> Result<FpPropertyRenaming, MigrationError> {
+    migrate(payload, upgrade_fp_property_renaming)
+}
+
+/// Upgrades a serialized `FpVariantRenaming` from a previous version of the protocol.
+pub fn upgrade_fp_variant_renaming(value: &mut Value) {
+    rename_variant(value, "qux", "QUX_BAZ");
+}
+
+/// Deserializes a `FpVariantRenaming` from a payload that may have been serialized by a
+/// previous version of the protocol.
+pub fn migrate_fp_variant_renaming(payload: &[u8]) -> Result<FpVariantRenaming, MigrationError> {
+    migrate(payload, upgrade_fp_variant_renaming)
+}
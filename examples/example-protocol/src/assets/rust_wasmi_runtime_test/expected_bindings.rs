@@ -0,0 +1,2878 @@
+use super::types::*;
+use fp_bindgen_support::{
+    common::{abi::WasmAbi, lazy::Lazy, mem::FatPtr, wasm_features::WasmFeatures},
+    wasmi_host::{
+        errors::{guest_error, GuestError, InvocationError, RuntimeError},
+        mem::{
+            deserialize_from_slice, export_to_guest, export_to_guest_raw, import_from_guest,
+            import_from_guest_raw, serialize_to_vec, try_deserialize_from_slice,
+            try_export_to_guest, try_export_to_guest_raw, try_import_from_guest_raw,
+        },
+        r#async::{
+            poll_async_value, poll_pending_imports, resolve_async_value, spawn_import,
+            spawn_import_without_result,
+        },
+        runtime::RuntimeInstanceData,
+    },
+};
+use std::{cell::RefCell, future::poll_fn};
+use wasmi::{
+    core::{Trap, F32, F64},
+    Caller, Engine, Instance, Linker, Module, Store,
+};
+
+/// Runtime for plugins that uses the Wasmi interpreter.
+///
+/// Wasmi doesn't rely on a JIT compiler, threads or an async executor, so
+/// this runtime can be used on hosts where Wasmer cannot. Async imports are
+/// not spawned onto an executor, but driven while async exports are awaited,
+/// which is also why the runtime cannot be shared across threads.
+pub struct Runtime {
+    instance: Instance,
+    store: RefCell<Store<RuntimeInstanceData>>,
+}
+
+impl Runtime {
+    /// Creates a runtime that does not grant any capabilities to the plugin.
+    pub fn new(wasm_module: impl AsRef<[u8]>) -> Result<Self, RuntimeError> {
+        Self::with_capabilities(wasm_module, &[])
+    }
+
+    /// Creates a runtime that grants the given capabilities to the plugin.
+    ///
+    /// Calls to imports that require a capability that was not granted fail
+    /// with `InvocationError::PermissionDenied`.
+    pub fn with_capabilities(
+        wasm_module: impl AsRef<[u8]>,
+        capabilities: &[Capability],
+    ) -> Result<Self, RuntimeError> {
+        let engine = Engine::default();
+        let module = Module::new(&engine, wasm_module.as_ref())?;
+        Self::from_module(
+            &module,
+            RuntimeInstanceData::default()
+                .with_capabilities(capabilities.iter().map(Capability::as_str)),
+        )
+    }
+
+    fn from_module(module: &Module, env: RuntimeInstanceData) -> Result<Self, RuntimeError> {
+        let mut store = Store::new(module.engine(), env);
+        let linker = create_linker(module.engine())?;
+        let instance = linker.instantiate(&mut store, module)?.start(&mut store)?;
+        RuntimeInstanceData::initialize(&mut store, &instance)?;
+        Ok(Self {
+            instance,
+            store: RefCell::new(store),
+        })
+    }
+
+    /// Returns statistics about the memory that is allocated by the plugin.
+    ///
+    /// This is only supported for plugins that are built with the
+    /// `memory-stats` feature of `fp-bindgen-support`. For other plugins, an
+    /// `InvocationError::FunctionNotExported` error is returned.
+    pub fn memory_stats(
+        &self,
+    ) -> Result<fp_bindgen_support::common::mem::MemoryStats, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<(), FatPtr>(&*store, "__fp_memory_stats")
+            .map_err(|_| {
+                InvocationError::from_missing_export(&self.instance, &*store, "__fp_memory_stats")
+            })?;
+        let result = function.call(&mut *store, ())?;
+        Ok(import_from_guest(&mut *store, result))
+    }
+
+    /// Returns metrics about the calls that the plugin has made to imports,
+    /// as measured by the plugin itself.
+    ///
+    /// This is only supported for plugins with bindings that are generated
+    /// with `guest_metrics` enabled. For other plugins, an
+    /// `InvocationError::FunctionNotExported` error is returned.
+    pub fn guest_metrics(
+        &self,
+    ) -> Result<fp_bindgen_support::common::mem::GuestMetrics, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<(), FatPtr>(&*store, "__fp_get_guest_metrics")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_get_guest_metrics",
+                )
+            })?;
+        let result = function.call(&mut *store, ())?;
+        Ok(import_from_guest(&mut *store, result))
+    }
+
+    /// Returns a machine-readable description of the protocol that this
+    /// runtime was generated for, as JSON.
+    ///
+    /// The description contains the signatures of all imported and exported
+    /// functions and the types they use, in the format that is parsed by
+    /// `fp_bindgen::Protocol::from_json()`. Note that plugins may omit
+    /// exports, in which case calling them fails with
+    /// `InvocationError::FunctionNotExported`.
+    pub fn protocol() -> &'static str {
+        PROTOCOL
+    }
+
+    pub fn export_array_f32(&self, arg: [f32; 3]) -> Result<[f32; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_f32_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_f32", "[f32; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayF32, error))
+    }
+    pub fn export_array_f32_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_array_f32")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_array_f32",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_array_f64(&self, arg: [f64; 3]) -> Result<[f64; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_f64_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_f64", "[f64; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayF64, error))
+    }
+    pub fn export_array_f64_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_array_f64")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_array_f64",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_array_i16(&self, arg: [i16; 3]) -> Result<[i16; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_i16_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_i16", "[i16; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayI16, error))
+    }
+    pub fn export_array_i16_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_array_i16")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_array_i16",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_array_i32(&self, arg: [i32; 3]) -> Result<[i32; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_i32_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_i32", "[i32; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayI32, error))
+    }
+    pub fn export_array_i32_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_array_i32")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_array_i32",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_array_i8(&self, arg: [i8; 3]) -> Result<[i8; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_i8_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_i8", "[i8; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayI8, error))
+    }
+    pub fn export_array_i8_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_array_i8")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_array_i8",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_array_u16(&self, arg: [u16; 3]) -> Result<[u16; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_u16_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_u16", "[u16; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayU16, error))
+    }
+    pub fn export_array_u16_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_array_u16")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_array_u16",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_array_u32(&self, arg: [u32; 3]) -> Result<[u32; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_u32_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_u32", "[u32; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayU32, error))
+    }
+    pub fn export_array_u32_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_array_u32")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_array_u32",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_array_u8(&self, arg: [u8; 3]) -> Result<[u8; 3], PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_u8_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_array_u8", "[u8; 3]"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportArrayU8, error))
+    }
+    pub fn export_array_u8_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_array_u8")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_array_u8",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub async fn export_async_struct(
+        &self,
+        arg1: FpPropertyRenaming,
+        arg2: u64,
+    ) -> Result<FpPropertyRenaming, PluginError> {
+        let arg1 = serialize_to_vec(&arg1);
+        let result = self.export_async_struct_raw(arg1, arg2);
+        let result = result.await;
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_async_struct", "FpPropertyRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportAsyncStruct, error))
+    }
+    pub async fn export_async_struct_raw(
+        &self,
+        arg1: impl AsRef<[u8]>,
+        arg2: u64,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.borrow_mut();
+            let arg1 = try_export_to_guest_raw(&mut *store, arg1)?;
+            let function = self
+                .instance
+                .get_typed_func::<(FatPtr, u64), FatPtr>(&*store, "__fp_gen_export_async_struct")
+                .map_err(|_| {
+                    InvocationError::from_missing_export(
+                        &self.instance,
+                        &*store,
+                        "__fp_gen_export_async_struct",
+                    )
+                })?;
+            let result = function.call(&mut *store, (arg1, arg2))?;
+            result
+        };
+        poll_fn(|cx| poll_async_value(&mut *self.store.borrow_mut(), result, cx)).await
+    }
+
+    pub fn export_batch_response(&self, arg: BatchResponse) -> Result<BatchResponse, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_batch_response_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_batch_response", "BatchResponse")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportBatchResponse, error))
+    }
+    pub fn export_batch_response_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_batch_response")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_batch_response",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_cached_call_count(&self, label: String) -> Result<String, PluginError> {
+        let label = serialize_to_vec(&label);
+        let result = self.export_cached_call_count_raw(label);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_cached_call_count", "String")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportCachedCallCount, error))
+    }
+    pub fn export_cached_call_count_raw(
+        &self,
+        label: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let label = try_export_to_guest_raw(&mut *store, label)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_cached_call_count")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_cached_call_count",
+                )
+            })?;
+        let result = function.call(&mut *store, label)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_call_rate_limited_import(
+        &self,
+        calls: u32,
+    ) -> Result<Vec<Result<String, RateLimited>>, PluginError> {
+        let result = self.export_call_rate_limited_import_raw(calls);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(
+                data,
+                "export_call_rate_limited_import",
+                "Vec<Result<String, RateLimited>>",
+            )
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportCallRateLimitedImport, error))
+    }
+    pub fn export_call_rate_limited_import_raw(
+        &self,
+        calls: u32,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<u32, FatPtr>(&*store, "__fp_gen_export_call_rate_limited_import")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_call_rate_limited_import",
+                )
+            })?;
+        let result = function.call(&mut *store, calls)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_collections(&self, arg: Collections) -> Result<Collections, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_collections_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_collections", "Collections")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportCollections, error))
+    }
+    pub fn export_collections_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_collections")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_collections",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_dynamic_value(
+        &self,
+        arg: ExtensionSettings,
+    ) -> Result<serde_json::Value, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_dynamic_value_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_dynamic_value", "serde_json::Value")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportDynamicValue, error))
+    }
+    pub fn export_dynamic_value_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_dynamic_value")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_dynamic_value",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_edge_values(&self, arg: EdgeValues) -> Result<EdgeValues, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_edge_values_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_edge_values", "EdgeValues")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportEdgeValues, error))
+    }
+    pub fn export_edge_values_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_edge_values")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_edge_values",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    #[cfg(feature = "experimental")]
+    pub fn export_experimental(&self, arg: String) -> Result<String, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_experimental_raw(arg);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_experimental", "String"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportExperimental, error))
+    }
+    #[cfg(feature = "experimental")]
+    pub fn export_experimental_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_experimental")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_experimental",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_fp_adjacently_tagged(
+        &self,
+        arg: FpAdjacentlyTagged,
+    ) -> Result<FpAdjacentlyTagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_adjacently_tagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_adjacently_tagged", "FpAdjacentlyTagged")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpAdjacentlyTagged, error))
+    }
+    pub fn export_fp_adjacently_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_fp_adjacently_tagged")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_fp_adjacently_tagged",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_fp_enum(&self, arg: FpVariantRenaming) -> Result<FpVariantRenaming, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_enum_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_enum", "FpVariantRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpEnum, error))
+    }
+    pub fn export_fp_enum_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_fp_enum")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_fp_enum",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_fp_flatten(&self, arg: FpFlatten) -> Result<FpFlatten, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_flatten_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_flatten", "FpFlatten")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpFlatten, error))
+    }
+    pub fn export_fp_flatten_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_fp_flatten")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_fp_flatten",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_fp_internally_tagged(
+        &self,
+        arg: FpInternallyTagged,
+    ) -> Result<FpInternallyTagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_internally_tagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_internally_tagged", "FpInternallyTagged")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpInternallyTagged, error))
+    }
+    pub fn export_fp_internally_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_fp_internally_tagged")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_fp_internally_tagged",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_fp_struct(
+        &self,
+        arg: FpPropertyRenaming,
+    ) -> Result<FpPropertyRenaming, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_struct_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_struct", "FpPropertyRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpStruct, error))
+    }
+    pub fn export_fp_struct_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_fp_struct")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_fp_struct",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_fp_untagged(&self, arg: FpUntagged) -> Result<FpUntagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_untagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_fp_untagged", "FpUntagged")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportFpUntagged, error))
+    }
+    pub fn export_fp_untagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_fp_untagged")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_fp_untagged",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_generics(
+        &self,
+        arg: StructWithGenerics<u64>,
+    ) -> Result<StructWithGenerics<u64>, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_generics_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_generics", "StructWithGenerics<u64>")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportGenerics, error))
+    }
+    pub fn export_generics_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_generics")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_generics",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_get_bytes(&self) -> Result<Result<bytes::Bytes, String>, PluginError> {
+        let result = self.export_get_bytes_raw();
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_get_bytes", "Result<bytes::Bytes, String>")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportGetBytes, error))
+    }
+    pub fn export_get_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<(), FatPtr>(&*store, "__fp_gen_export_get_bytes")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_get_bytes",
+                )
+            })?;
+        let result = function.call(&mut *store, ())?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_get_serde_bytes(
+        &self,
+    ) -> Result<Result<serde_bytes::ByteBuf, String>, PluginError> {
+        let result = self.export_get_serde_bytes_raw();
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(
+                data,
+                "export_get_serde_bytes",
+                "Result<serde_bytes::ByteBuf, String>",
+            )
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportGetSerdeBytes, error))
+    }
+    pub fn export_get_serde_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<(), FatPtr>(&*store, "__fp_gen_export_get_serde_bytes")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_get_serde_bytes",
+                )
+            })?;
+        let result = function.call(&mut *store, ())?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_lazy_string(&self, arg: String) -> Result<Lazy<String>, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_lazy_string_raw(arg);
+        let result = result.map(Lazy::new);
+        result.map_err(|error| PluginError::new(PluginExport::ExportLazyString, error))
+    }
+    pub fn export_lazy_string_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_lazy_string")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_lazy_string",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_metrics(&self, metrics: Vec<Metric>) -> Result<Vec<Metric>, PluginError> {
+        let metrics = serialize_to_vec(&metrics);
+        let result = self.export_metrics_raw(metrics);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "export_metrics", "Vec<Metric>"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportMetrics, error))
+    }
+    pub fn export_metrics_raw(
+        &self,
+        metrics: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let metrics = try_export_to_guest_raw(&mut *store, metrics)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_metrics")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_metrics",
+                )
+            })?;
+        let result = function.call(&mut *store, metrics)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_multiple_primitives(&self, arg1: i8, arg2: String) -> Result<i64, PluginError> {
+        let arg2 = serialize_to_vec(&arg2);
+        let result = self.export_multiple_primitives_raw(arg1, arg2);
+        result.map_err(|error| PluginError::new(PluginExport::ExportMultiplePrimitives, error))
+    }
+    pub fn export_multiple_primitives_raw(
+        &self,
+        arg1: i8,
+        arg2: impl AsRef<[u8]>,
+    ) -> Result<i64, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg2 = try_export_to_guest_raw(&mut *store, arg2)?;
+        let function = self
+            .instance
+            .get_typed_func::<(i32, FatPtr), i64>(&*store, "__fp_gen_export_multiple_primitives")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_multiple_primitives",
+                )
+            })?;
+        let result = function.call(&mut *store, (i32::from(arg1), arg2))?;
+        Ok(result)
+    }
+
+    pub fn export_multiple_values(
+        &self,
+        arg: u32,
+    ) -> Result<(String, Vec<u32>, bool), PluginError> {
+        let result = self.export_multiple_values_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_multiple_values", "(String, Vec<u32>, bool)")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportMultipleValues, error))
+    }
+    pub fn export_multiple_values_raw(&self, arg: u32) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<u32, FatPtr>(&*store, "__fp_gen_export_multiple_values")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_multiple_values",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_bool(&self, arg: bool) -> Result<bool, PluginError> {
+        let result = self.export_primitive_bool_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveBool, error))
+    }
+    pub fn export_primitive_bool_raw(&self, arg: bool) -> Result<bool, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<u32, u32>(&*store, "__fp_gen_export_primitive_bool")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_bool",
+                )
+            })?;
+        let result = function.call(&mut *store, u32::from(arg))?;
+        let result = result != 0;
+        Ok(result)
+    }
+
+    pub fn export_primitive_f32(&self, arg: f32) -> Result<f32, PluginError> {
+        let result = self.export_primitive_f32_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveF32, error))
+    }
+    pub fn export_primitive_f32_raw(&self, arg: f32) -> Result<f32, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<F32, F32>(&*store, "__fp_gen_export_primitive_f32")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_f32",
+                )
+            })?;
+        let result = function.call(&mut *store, F32::from(arg))?;
+        let result = f32::from(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_f64(&self, arg: f64) -> Result<f64, PluginError> {
+        let result = self.export_primitive_f64_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveF64, error))
+    }
+    pub fn export_primitive_f64_raw(&self, arg: f64) -> Result<f64, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<F64, F64>(&*store, "__fp_gen_export_primitive_f64")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_f64",
+                )
+            })?;
+        let result = function.call(&mut *store, F64::from(arg))?;
+        let result = f64::from(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_i16(&self, arg: i16) -> Result<i16, PluginError> {
+        let result = self.export_primitive_i16_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveI16, error))
+    }
+    pub fn export_primitive_i16_raw(&self, arg: i16) -> Result<i16, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<i32, i32>(&*store, "__fp_gen_export_primitive_i16")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_i16",
+                )
+            })?;
+        let result = function.call(&mut *store, i32::from(arg))?;
+        let result = result as i16;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i32(&self, arg: i32) -> Result<i32, PluginError> {
+        let result = self.export_primitive_i32_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveI32, error))
+    }
+    pub fn export_primitive_i32_raw(&self, arg: i32) -> Result<i32, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<i32, i32>(&*store, "__fp_gen_export_primitive_i32")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_i32",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i64(&self, arg: i64) -> Result<i64, PluginError> {
+        let result = self.export_primitive_i64_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveI64, error))
+    }
+    pub fn export_primitive_i64_raw(&self, arg: i64) -> Result<i64, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<i64, i64>(&*store, "__fp_gen_export_primitive_i64")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_i64",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i8(&self, arg: i8) -> Result<i8, PluginError> {
+        let result = self.export_primitive_i8_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveI8, error))
+    }
+    pub fn export_primitive_i8_raw(&self, arg: i8) -> Result<i8, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<i32, i32>(&*store, "__fp_gen_export_primitive_i8")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_i8",
+                )
+            })?;
+        let result = function.call(&mut *store, i32::from(arg))?;
+        let result = result as i8;
+        Ok(result)
+    }
+
+    pub fn export_primitive_pair(&self, arg: u32) -> Result<(u32, i32), PluginError> {
+        let result = self.export_primitive_pair_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitivePair, error))
+    }
+    pub fn export_primitive_pair_raw(&self, arg: u32) -> Result<(u32, i32), InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<u32, <(u32, i32) as WasmAbi>::AbiType>(
+                &*store,
+                "__fp_gen_export_primitive_pair",
+            )
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_pair",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_u16(&self, arg: u16) -> Result<u16, PluginError> {
+        let result = self.export_primitive_u16_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveU16, error))
+    }
+    pub fn export_primitive_u16_raw(&self, arg: u16) -> Result<u16, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<u32, u32>(&*store, "__fp_gen_export_primitive_u16")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_u16",
+                )
+            })?;
+        let result = function.call(&mut *store, u32::from(arg))?;
+        let result = result as u16;
+        Ok(result)
+    }
+
+    pub fn export_primitive_u32(&self, arg: u32) -> Result<u32, PluginError> {
+        let result = self.export_primitive_u32_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveU32, error))
+    }
+    pub fn export_primitive_u32_raw(&self, arg: u32) -> Result<u32, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<u32, u32>(&*store, "__fp_gen_export_primitive_u32")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_u32",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_u64(&self, arg: u64) -> Result<u64, PluginError> {
+        let result = self.export_primitive_u64_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveU64, error))
+    }
+    pub fn export_primitive_u64_raw(&self, arg: u64) -> Result<u64, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<u64, u64>(&*store, "__fp_gen_export_primitive_u64")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_u64",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_u8(&self, arg: u8) -> Result<u8, PluginError> {
+        let result = self.export_primitive_u8_raw(arg);
+        result.map_err(|error| PluginError::new(PluginExport::ExportPrimitiveU8, error))
+    }
+    pub fn export_primitive_u8_raw(&self, arg: u8) -> Result<u8, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<u32, u32>(&*store, "__fp_gen_export_primitive_u8")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_primitive_u8",
+                )
+            })?;
+        let result = function.call(&mut *store, u32::from(arg))?;
+        let result = result as u8;
+        Ok(result)
+    }
+
+    pub fn export_renamed_string(&self, arg: String) -> Result<String, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_renamed_string_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_renamed_string", "String")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportRenamedString, error))
+    }
+    pub fn export_renamed_string_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_legacy_string")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_legacy_string",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_samples(&self, samples: SampleColumns) -> Result<SampleColumns, PluginError> {
+        let samples = serialize_to_vec(&samples);
+        let result = self.export_samples_raw(samples);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_samples", "SampleColumns")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSamples, error))
+    }
+    pub fn export_samples_raw(
+        &self,
+        samples: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let samples = try_export_to_guest_raw(&mut *store, samples)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_samples")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_samples",
+                )
+            })?;
+        let result = function.call(&mut *store, samples)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_serde_adjacently_tagged(
+        &self,
+        arg: SerdeAdjacentlyTagged,
+    ) -> Result<SerdeAdjacentlyTagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_adjacently_tagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(
+                data,
+                "export_serde_adjacently_tagged",
+                "SerdeAdjacentlyTagged",
+            )
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeAdjacentlyTagged, error))
+    }
+    pub fn export_serde_adjacently_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_serde_adjacently_tagged")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_serde_adjacently_tagged",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_serde_enum(
+        &self,
+        arg: SerdeVariantRenaming,
+    ) -> Result<SerdeVariantRenaming, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_enum_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_serde_enum", "SerdeVariantRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeEnum, error))
+    }
+    pub fn export_serde_enum_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_serde_enum")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_serde_enum",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_serde_flatten(&self, arg: SerdeFlatten) -> Result<SerdeFlatten, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_flatten_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_serde_flatten", "SerdeFlatten")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeFlatten, error))
+    }
+    pub fn export_serde_flatten_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_serde_flatten")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_serde_flatten",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_serde_internally_tagged(
+        &self,
+        arg: SerdeInternallyTagged,
+    ) -> Result<SerdeInternallyTagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_internally_tagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(
+                data,
+                "export_serde_internally_tagged",
+                "SerdeInternallyTagged",
+            )
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeInternallyTagged, error))
+    }
+    pub fn export_serde_internally_tagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_serde_internally_tagged")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_serde_internally_tagged",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_serde_struct(
+        &self,
+        arg: SerdePropertyRenaming,
+    ) -> Result<SerdePropertyRenaming, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_struct_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_serde_struct", "SerdePropertyRenaming")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeStruct, error))
+    }
+    pub fn export_serde_struct_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_serde_struct")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_serde_struct",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_serde_untagged(&self, arg: SerdeUntagged) -> Result<SerdeUntagged, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_untagged_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_serde_untagged", "SerdeUntagged")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportSerdeUntagged, error))
+    }
+    pub fn export_serde_untagged_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_serde_untagged")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_serde_untagged",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_string(&self, arg: String) -> Result<String, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_string_raw(arg);
+        let result =
+            result.and_then(|ref data| try_deserialize_from_slice(data, "export_string", "String"));
+        result.map_err(|error| PluginError::new(PluginExport::ExportString, error))
+    }
+    pub fn export_string_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_string")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_string",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_string_after_memory_growth(&self, arg: String) -> Result<String, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_string_after_memory_growth_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_string_after_memory_growth", "String")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportStringAfterMemoryGrowth, error))
+    }
+    pub fn export_string_after_memory_growth_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_string_after_memory_growth")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_string_after_memory_growth",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_struct_with_options(
+        &self,
+        arg: StructWithOptions,
+    ) -> Result<StructWithOptions, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_struct_with_options_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_struct_with_options", "StructWithOptions")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportStructWithOptions, error))
+    }
+    pub fn export_struct_with_options_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_struct_with_options")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_struct_with_options",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_timestamp(&self, arg: MyDateTime) -> Result<MyDateTime, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_timestamp_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_timestamp", "MyDateTime")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportTimestamp, error))
+    }
+    pub fn export_timestamp_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_timestamp")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_timestamp",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_timestamps(&self, arg: Timestamps) -> Result<Timestamps, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_timestamps_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_timestamps", "Timestamps")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportTimestamps, error))
+    }
+    pub fn export_timestamps_raw(&self, arg: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_timestamps")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_timestamps",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_typed_id(
+        &self,
+        id: TypedId<Point<f64>>,
+    ) -> Result<TypedId<Point<f64>>, PluginError> {
+        let id = serialize_to_vec(&id);
+        let result = self.export_typed_id_raw(id);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_typed_id", "TypedId<Point<f64>>")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportTypedId, error))
+    }
+    pub fn export_typed_id_raw(&self, id: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let id = try_export_to_guest_raw(&mut *store, id)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_typed_id")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_typed_id",
+                )
+            })?;
+        let result = function.call(&mut *store, id)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_validated_struct(
+        &self,
+        arg: ValidatedStruct,
+    ) -> Result<ValidatedStruct, PluginError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_validated_struct_raw(arg);
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "export_validated_struct", "ValidatedStruct")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::ExportValidatedStruct, error))
+    }
+    pub fn export_validated_struct_raw(
+        &self,
+        arg: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let arg = try_export_to_guest_raw(&mut *store, arg)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_export_validated_struct")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_validated_struct",
+                )
+            })?;
+        let result = function.call(&mut *store, arg)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    pub fn export_void_function(&self) -> Result<(), PluginError> {
+        let result = self.export_void_function_raw();
+        result.map_err(|error| PluginError::new(PluginExport::ExportVoidFunction, error))
+    }
+    pub fn export_void_function_raw(&self) -> Result<(), InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<(), ()>(&*store, "__fp_gen_export_void_function")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_export_void_function",
+                )
+            })?;
+        let result = function.call(&mut *store, ())?;
+        Ok(result)
+    }
+
+    /// Example how plugin could expose async data-fetching capabilities.
+    pub async fn fetch_data(&self, r#type: String) -> Result<Result<String, String>, PluginError> {
+        let r#type = serialize_to_vec(&r#type);
+        let result = self.fetch_data_raw(r#type);
+        let result = result.await;
+        let result = result.and_then(|ref data| {
+            try_deserialize_from_slice(data, "fetch_data", "Result<String, String>")
+        });
+        result.map_err(|error| PluginError::new(PluginExport::FetchData, error))
+    }
+    pub async fn fetch_data_raw(
+        &self,
+        r#type: impl AsRef<[u8]>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.borrow_mut();
+            let r#type = try_export_to_guest_raw(&mut *store, r#type)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_fetch_data")
+                .map_err(|_| {
+                    InvocationError::from_missing_export(
+                        &self.instance,
+                        &*store,
+                        "__fp_gen_fetch_data",
+                    )
+                })?;
+            let result = function.call(&mut *store, r#type)?;
+            result
+        };
+        poll_fn(|cx| poll_async_value(&mut *self.store.borrow_mut(), result, cx)).await
+    }
+
+    /// Called on the plugin to give it a chance to initialize.
+    pub fn init(&self, config: PluginConfig) -> Result<(), PluginError> {
+        let config = serialize_to_vec(&config);
+        let result = self.init_raw(config);
+        result.map_err(|error| PluginError::new(PluginExport::Init, error))
+    }
+    pub fn init_raw(&self, config: impl AsRef<[u8]>) -> Result<(), InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let config = try_export_to_guest_raw(&mut *store, config)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, ()>(&*store, "__fp_gen_init")
+            .map_err(|_| {
+                InvocationError::from_missing_export(&self.instance, &*store, "__fp_gen_init")
+            })?;
+        let result = function.call(&mut *store, config)?;
+        Ok(result)
+    }
+
+    /// Example how plugin could expose a reducer.
+    pub fn reducer_bridge(&self, action: ReduxAction) -> Result<StateUpdate, PluginError> {
+        let action = serialize_to_vec(&action);
+        let result = self.reducer_bridge_raw(action);
+        let result = result
+            .and_then(|ref data| try_deserialize_from_slice(data, "reducer_bridge", "StateUpdate"));
+        result.map_err(|error| PluginError::new(PluginExport::ReducerBridge, error))
+    }
+    pub fn reducer_bridge_raw(&self, action: impl AsRef<[u8]>) -> Result<Vec<u8>, InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let action = try_export_to_guest_raw(&mut *store, action)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, FatPtr>(&*store, "__fp_gen_reducer_bridge")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_reducer_bridge",
+                )
+            })?;
+        let result = function.call(&mut *store, action)?;
+        let result = try_import_from_guest_raw(&mut *store, result)?;
+        Ok(result)
+    }
+
+    /// Called on the plugin when the host changes its config.
+    pub fn update_config(&self, config: PluginConfig) -> Result<(), PluginError> {
+        let config = serialize_to_vec(&config);
+        let result = self.update_config_raw(config);
+        result.map_err(|error| PluginError::new(PluginExport::UpdateConfig, error))
+    }
+    pub fn update_config_raw(&self, config: impl AsRef<[u8]>) -> Result<(), InvocationError> {
+        let mut store = self.store.borrow_mut();
+        let config = try_export_to_guest_raw(&mut *store, config)?;
+        let function = self
+            .instance
+            .get_typed_func::<FatPtr, ()>(&*store, "__fp_gen_update_config")
+            .map_err(|_| {
+                InvocationError::from_missing_export(
+                    &self.instance,
+                    &*store,
+                    "__fp_gen_update_config",
+                )
+            })?;
+        let result = function.call(&mut *store, config)?;
+        Ok(result)
+    }
+
+    /// Registers a handler for the dynamic import `invoke_command`.
+    ///
+    /// Calls from the plugin with the given name are dispatched to this handler
+    /// instead of the static `invoke_command()` implementation. Registering a handler
+    /// with a name that is already in use replaces the existing handler.
+    pub fn register_invoke_command(
+        &self,
+        name: impl Into<String>,
+        handler: impl Fn(String) -> Result<String, String> + 'static,
+    ) {
+        self.store
+            .borrow_mut()
+            .data_mut()
+            .register_dynamic_import_handler(
+                "invoke_command",
+                name.into(),
+                std::rc::Rc::new(move |payload: &[u8]| {
+                    serialize_to_vec(&handler(deserialize_from_slice(payload)))
+                }),
+            );
+    }
+
+    /// Unregisters a handler for the dynamic import `invoke_command`.
+    ///
+    /// Returns whether a handler was registered under the given name.
+    pub fn unregister_invoke_command(&self, name: &str) -> bool {
+        self.store
+            .borrow_mut()
+            .data_mut()
+            .unregister_dynamic_import_handler("invoke_command", name)
+    }
+
+    /// Subscribes to the `report_progress` event.
+    ///
+    /// Every event that the plugin emits after subscribing is sent to the
+    /// returned receiver. Dropping the receiver ends the subscription.
+    pub fn subscribe_report_progress(&self) -> std::sync::mpsc::Receiver<Progress> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.store.borrow_mut().data_mut().subscribe_to_event(
+            "report_progress",
+            Box::new(move |event: &[u8]| sender.send(deserialize_from_slice(event)).is_ok()),
+        );
+        receiver
+    }
+
+    /// Drives the async imports that were called by the plugin until all of
+    /// them are done.
+    ///
+    /// Async imports are also driven while an async export is awaited, but
+    /// imports that are fire-and-forget may outlive the export that called them.
+    pub async fn run_pending_imports(&self) {
+        poll_fn(|cx| poll_pending_imports(&mut *self.store.borrow_mut(), cx)).await
+    }
+}
+
+impl Drop for Runtime {
+    /// Calls the `shutdown` export of the plugin, if it implements it.
+    ///
+    /// Failures are ignored, since there is nobody left to report them to.
+    fn drop(&mut self) {
+        let store = self.store.get_mut();
+        if let Ok(function) = self
+            .instance
+            .get_typed_func::<(), ()>(&*store, "__fp_gen_shutdown")
+        {
+            let _ = function.call(store, ());
+        }
+    }
+}
+
+fn create_linker(engine: &Engine) -> Result<Linker<RuntimeInstanceData>, RuntimeError> {
+    let mut linker = Linker::new(engine);
+    linker.func_wrap("fp", "__fp_host_resolve_async_value", resolve_async_value)?;
+    linker.func_wrap("fp", "__fp_error", guest_error)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_arbitrary_precision_numbers",
+        _import_arbitrary_precision_numbers,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_array_f32", _import_array_f32)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_f64", _import_array_f64)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_i16", _import_array_i16)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_i32", _import_array_i32)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_i8", _import_array_i8)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_u16", _import_array_u16)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_u32", _import_array_u32)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_u8", _import_array_u8)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_async_void_function",
+        _import_async_void_function,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_collections", _import_collections)?;
+    #[cfg(feature = "experimental")]
+    linker.func_wrap("fp", "__fp_gen_import_experimental", _import_experimental)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_explicit_bound_point",
+        _import_explicit_bound_point,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_fp_adjacently_tagged",
+        _import_fp_adjacently_tagged,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_fp_enum", _import_fp_enum)?;
+    linker.func_wrap("fp", "__fp_gen_import_fp_flatten", _import_fp_flatten)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_fp_internally_tagged",
+        _import_fp_internally_tagged,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_fp_struct", _import_fp_struct)?;
+    linker.func_wrap("fp", "__fp_gen_import_fp_untagged", _import_fp_untagged)?;
+    linker.func_wrap("fp", "__fp_gen_import_generics", _import_generics)?;
+    linker.func_wrap("fp", "__fp_gen_import_get_bytes", _import_get_bytes)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_get_serde_bytes",
+        _import_get_serde_bytes,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_get_value", _import_get_value)?;
+    linker.func_wrap("fp", "__fp_gen_import_integer_types", _import_integer_types)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_multiple_primitives",
+        _import_multiple_primitives,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_multiple_values",
+        _import_multiple_values,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_bool",
+        _import_primitive_bool,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_f32", _import_primitive_f32)?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_f64", _import_primitive_f64)?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_i16", _import_primitive_i16)?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_i32", _import_primitive_i32)?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_i64", _import_primitive_i64)?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_i8", _import_primitive_i8)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_pair",
+        _import_primitive_pair,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_u16", _import_primitive_u16)?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_u32", _import_primitive_u32)?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_u64", _import_primitive_u64)?;
+    linker.func_wrap("fp", "__fp_gen_import_primitive_u8", _import_primitive_u8)?;
+    linker.func_wrap("fp", "__fp_gen_import_rate_limited", _import_rate_limited)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_legacy_string",
+        _import_renamed_string,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_serde_adjacently_tagged",
+        _import_serde_adjacently_tagged,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_serde_enum", _import_serde_enum)?;
+    linker.func_wrap("fp", "__fp_gen_import_serde_flatten", _import_serde_flatten)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_serde_internally_tagged",
+        _import_serde_internally_tagged,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_serde_struct", _import_serde_struct)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_serde_untagged",
+        _import_serde_untagged,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_set_value", _import_set_value)?;
+    linker.func_wrap("fp", "__fp_gen_import_string", _import_string)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_struct_with_options",
+        _import_struct_with_options,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_timestamp", _import_timestamp)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_validated_struct",
+        _import_validated_struct,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_void_function", _import_void_function)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_void_function_empty_result",
+        _import_void_function_empty_result,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_void_function_empty_return",
+        _import_void_function_empty_return,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_invoke_command", _invoke_command)?;
+    linker.func_wrap("fp", "__fp_gen_log", _log)?;
+    linker.func_wrap("fp", "__fp_gen_make_http_request", _make_http_request)?;
+    linker.func_wrap("fp", "__fp_gen_record_metric", _record_metric)?;
+    linker.func_wrap("fp", "__fp_gen_report_progress", _report_progress)?;
+    linker.func_wrap("fp", "__fp_host_batch", __fp_host_batch)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_intern_string",
+        fp_bindgen_support::wasmi_host::interning::intern_string,
+    )?;
+    Ok(linker)
+}
+
+/// Builds a `Runtime` with custom settings.
+///
+/// By default, modules are compiled with an engine that uses the default
+/// Wasmi configuration. Hosts may provide their own engine to configure the
+/// interpreter differently.
+#[derive(Default)]
+pub struct RuntimeBuilder {
+    engine: Option<Engine>,
+    wasm_features: WasmFeatures,
+    capabilities: Vec<Capability>,
+    init_config: Option<PluginConfig>,
+}
+
+impl RuntimeBuilder {
+    /// Returns a new builder with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the engine with which modules are compiled and executed.
+    pub fn with_engine(mut self, engine: Engine) -> Self {
+        self.engine = Some(engine);
+        self
+    }
+
+    /// Sets the WebAssembly features that are enabled for the plugin.
+    ///
+    /// Modules that use a feature that is disabled are rejected with
+    /// `RuntimeError::DisabledWasmFeatures` before they are compiled. The
+    /// features are also enabled on the default engine, while an engine that
+    /// is set using `with_engine()` keeps its own configuration. Wasmi doesn't
+    /// support SIMD and threads, so plugins that use them fail to compile
+    /// regardless.
+    pub fn with_wasm_features(mut self, features: WasmFeatures) -> Self {
+        self.wasm_features = features;
+        self
+    }
+
+    /// Grants the given capabilities to the plugin.
+    ///
+    /// Calls to imports that require a capability that was not granted fail
+    /// with `InvocationError::PermissionDenied`.
+    pub fn with_capabilities(mut self, capabilities: &[Capability]) -> Self {
+        self.capabilities = capabilities.to_vec();
+        self
+    }
+
+    /// Calls the `init` export of the plugin with the given config, once the
+    /// runtime has been instantiated. Plugins that don't implement `init` are
+    /// not initialized.
+    pub fn with_init(mut self, config: PluginConfig) -> Self {
+        self.init_config = Some(config);
+        self
+    }
+
+    /// Compiles the given module and instantiates a runtime for it.
+    pub fn build(self, wasm_module: impl AsRef<[u8]>) -> Result<Runtime, RuntimeError> {
+        self.wasm_features.validate(wasm_module.as_ref())?;
+        let engine = self
+            .engine
+            .clone()
+            .unwrap_or_else(|| Engine::new(&self.wasm_features.into()));
+        let module = Module::new(&engine, wasm_module.as_ref())?;
+        self.build_with_module(&module)
+    }
+
+    /// Instantiates a runtime for a module that has already been compiled.
+    ///
+    /// The runtime uses the engine of the module, rather than the engine that
+    /// was set on the builder.
+    pub fn build_with_module(self, module: &Module) -> Result<Runtime, RuntimeError> {
+        let runtime = Runtime::from_module(
+            module,
+            RuntimeInstanceData::default()
+                .with_capabilities(self.capabilities.iter().map(Capability::as_str)),
+        )?;
+        if let Some(config) = self.init_config {
+            match runtime.init(config) {
+                Ok(()) | Err(PluginError::MissingExport(_)) => {}
+                Err(error) => return Err(RuntimeError::InitFailed(error.into())),
+            }
+        }
+        Ok(runtime)
+    }
+}
+
+/// A capability that can be granted to the plugin. Imports that require a
+/// capability can only be called by the plugin if it was granted.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Capability {
+    Network,
+}
+
+impl Capability {
+    /// Returns the name of the capability, as it is declared in the protocol.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Network => "network",
+        }
+    }
+}
+
+/// An export of the protocol, which identifies the export that failed in a
+/// `PluginError`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PluginExport {
+    ExportArrayF32,
+    ExportArrayF64,
+    ExportArrayI16,
+    ExportArrayI32,
+    ExportArrayI8,
+    ExportArrayU16,
+    ExportArrayU32,
+    ExportArrayU8,
+    ExportAsyncStruct,
+    ExportBatchResponse,
+    ExportCachedCallCount,
+    ExportCallRateLimitedImport,
+    ExportCollections,
+    ExportDynamicValue,
+    ExportEdgeValues,
+    #[cfg(feature = "experimental")]
+    ExportExperimental,
+    ExportFpAdjacentlyTagged,
+    ExportFpEnum,
+    ExportFpFlatten,
+    ExportFpInternallyTagged,
+    ExportFpStruct,
+    ExportFpUntagged,
+    ExportGenerics,
+    ExportGetBytes,
+    ExportGetSerdeBytes,
+    ExportLazyString,
+    ExportMetrics,
+    ExportMultiplePrimitives,
+    ExportMultipleValues,
+    ExportPrimitiveBool,
+    ExportPrimitiveF32,
+    ExportPrimitiveF64,
+    ExportPrimitiveI16,
+    ExportPrimitiveI32,
+    ExportPrimitiveI64,
+    ExportPrimitiveI8,
+    ExportPrimitivePair,
+    ExportPrimitiveU16,
+    ExportPrimitiveU32,
+    ExportPrimitiveU64,
+    ExportPrimitiveU8,
+    ExportRenamedString,
+    ExportSamples,
+    ExportSerdeAdjacentlyTagged,
+    ExportSerdeEnum,
+    ExportSerdeFlatten,
+    ExportSerdeInternallyTagged,
+    ExportSerdeStruct,
+    ExportSerdeUntagged,
+    ExportString,
+    ExportStringAfterMemoryGrowth,
+    ExportStructWithOptions,
+    ExportTimestamp,
+    ExportTimestamps,
+    ExportTypedId,
+    ExportValidatedStruct,
+    ExportVoidFunction,
+    FetchData,
+    Init,
+    ReducerBridge,
+    UpdateConfig,
+}
+
+impl PluginExport {
+    /// Returns the name of the export, as it is declared in the protocol.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Self::ExportArrayF32 => "export_array_f32",
+            Self::ExportArrayF64 => "export_array_f64",
+            Self::ExportArrayI16 => "export_array_i16",
+            Self::ExportArrayI32 => "export_array_i32",
+            Self::ExportArrayI8 => "export_array_i8",
+            Self::ExportArrayU16 => "export_array_u16",
+            Self::ExportArrayU32 => "export_array_u32",
+            Self::ExportArrayU8 => "export_array_u8",
+            Self::ExportAsyncStruct => "export_async_struct",
+            Self::ExportBatchResponse => "export_batch_response",
+            Self::ExportCachedCallCount => "export_cached_call_count",
+            Self::ExportCallRateLimitedImport => "export_call_rate_limited_import",
+            Self::ExportCollections => "export_collections",
+            Self::ExportDynamicValue => "export_dynamic_value",
+            Self::ExportEdgeValues => "export_edge_values",
+            #[cfg(feature = "experimental")]
+            Self::ExportExperimental => "export_experimental",
+            Self::ExportFpAdjacentlyTagged => "export_fp_adjacently_tagged",
+            Self::ExportFpEnum => "export_fp_enum",
+            Self::ExportFpFlatten => "export_fp_flatten",
+            Self::ExportFpInternallyTagged => "export_fp_internally_tagged",
+            Self::ExportFpStruct => "export_fp_struct",
+            Self::ExportFpUntagged => "export_fp_untagged",
+            Self::ExportGenerics => "export_generics",
+            Self::ExportGetBytes => "export_get_bytes",
+            Self::ExportGetSerdeBytes => "export_get_serde_bytes",
+            Self::ExportLazyString => "export_lazy_string",
+            Self::ExportMetrics => "export_metrics",
+            Self::ExportMultiplePrimitives => "export_multiple_primitives",
+            Self::ExportMultipleValues => "export_multiple_values",
+            Self::ExportPrimitiveBool => "export_primitive_bool",
+            Self::ExportPrimitiveF32 => "export_primitive_f32",
+            Self::ExportPrimitiveF64 => "export_primitive_f64",
+            Self::ExportPrimitiveI16 => "export_primitive_i16",
+            Self::ExportPrimitiveI32 => "export_primitive_i32",
+            Self::ExportPrimitiveI64 => "export_primitive_i64",
+            Self::ExportPrimitiveI8 => "export_primitive_i8",
+            Self::ExportPrimitivePair => "export_primitive_pair",
+            Self::ExportPrimitiveU16 => "export_primitive_u16",
+            Self::ExportPrimitiveU32 => "export_primitive_u32",
+            Self::ExportPrimitiveU64 => "export_primitive_u64",
+            Self::ExportPrimitiveU8 => "export_primitive_u8",
+            Self::ExportRenamedString => "export_renamed_string",
+            Self::ExportSamples => "export_samples",
+            Self::ExportSerdeAdjacentlyTagged => "export_serde_adjacently_tagged",
+            Self::ExportSerdeEnum => "export_serde_enum",
+            Self::ExportSerdeFlatten => "export_serde_flatten",
+            Self::ExportSerdeInternallyTagged => "export_serde_internally_tagged",
+            Self::ExportSerdeStruct => "export_serde_struct",
+            Self::ExportSerdeUntagged => "export_serde_untagged",
+            Self::ExportString => "export_string",
+            Self::ExportStringAfterMemoryGrowth => "export_string_after_memory_growth",
+            Self::ExportStructWithOptions => "export_struct_with_options",
+            Self::ExportTimestamp => "export_timestamp",
+            Self::ExportTimestamps => "export_timestamps",
+            Self::ExportTypedId => "export_typed_id",
+            Self::ExportValidatedStruct => "export_validated_struct",
+            Self::ExportVoidFunction => "export_void_function",
+            Self::FetchData => "fetch_data",
+            Self::Init => "init",
+            Self::ReducerBridge => "reducer_bridge",
+            Self::UpdateConfig => "update_config",
+        }
+    }
+
+    /// Returns the name of the symbol through which the plugin exports it.
+    pub fn symbol_name(&self) -> &'static str {
+        match *self {
+            Self::ExportArrayF32 => "__fp_gen_export_array_f32",
+            Self::ExportArrayF64 => "__fp_gen_export_array_f64",
+            Self::ExportArrayI16 => "__fp_gen_export_array_i16",
+            Self::ExportArrayI32 => "__fp_gen_export_array_i32",
+            Self::ExportArrayI8 => "__fp_gen_export_array_i8",
+            Self::ExportArrayU16 => "__fp_gen_export_array_u16",
+            Self::ExportArrayU32 => "__fp_gen_export_array_u32",
+            Self::ExportArrayU8 => "__fp_gen_export_array_u8",
+            Self::ExportAsyncStruct => "__fp_gen_export_async_struct",
+            Self::ExportBatchResponse => "__fp_gen_export_batch_response",
+            Self::ExportCachedCallCount => "__fp_gen_export_cached_call_count",
+            Self::ExportCallRateLimitedImport => "__fp_gen_export_call_rate_limited_import",
+            Self::ExportCollections => "__fp_gen_export_collections",
+            Self::ExportDynamicValue => "__fp_gen_export_dynamic_value",
+            Self::ExportEdgeValues => "__fp_gen_export_edge_values",
+            #[cfg(feature = "experimental")]
+            Self::ExportExperimental => "__fp_gen_export_experimental",
+            Self::ExportFpAdjacentlyTagged => "__fp_gen_export_fp_adjacently_tagged",
+            Self::ExportFpEnum => "__fp_gen_export_fp_enum",
+            Self::ExportFpFlatten => "__fp_gen_export_fp_flatten",
+            Self::ExportFpInternallyTagged => "__fp_gen_export_fp_internally_tagged",
+            Self::ExportFpStruct => "__fp_gen_export_fp_struct",
+            Self::ExportFpUntagged => "__fp_gen_export_fp_untagged",
+            Self::ExportGenerics => "__fp_gen_export_generics",
+            Self::ExportGetBytes => "__fp_gen_export_get_bytes",
+            Self::ExportGetSerdeBytes => "__fp_gen_export_get_serde_bytes",
+            Self::ExportLazyString => "__fp_gen_export_lazy_string",
+            Self::ExportMetrics => "__fp_gen_export_metrics",
+            Self::ExportMultiplePrimitives => "__fp_gen_export_multiple_primitives",
+            Self::ExportMultipleValues => "__fp_gen_export_multiple_values",
+            Self::ExportPrimitiveBool => "__fp_gen_export_primitive_bool",
+            Self::ExportPrimitiveF32 => "__fp_gen_export_primitive_f32",
+            Self::ExportPrimitiveF64 => "__fp_gen_export_primitive_f64",
+            Self::ExportPrimitiveI16 => "__fp_gen_export_primitive_i16",
+            Self::ExportPrimitiveI32 => "__fp_gen_export_primitive_i32",
+            Self::ExportPrimitiveI64 => "__fp_gen_export_primitive_i64",
+            Self::ExportPrimitiveI8 => "__fp_gen_export_primitive_i8",
+            Self::ExportPrimitivePair => "__fp_gen_export_primitive_pair",
+            Self::ExportPrimitiveU16 => "__fp_gen_export_primitive_u16",
+            Self::ExportPrimitiveU32 => "__fp_gen_export_primitive_u32",
+            Self::ExportPrimitiveU64 => "__fp_gen_export_primitive_u64",
+            Self::ExportPrimitiveU8 => "__fp_gen_export_primitive_u8",
+            Self::ExportRenamedString => "__fp_gen_export_legacy_string",
+            Self::ExportSamples => "__fp_gen_export_samples",
+            Self::ExportSerdeAdjacentlyTagged => "__fp_gen_export_serde_adjacently_tagged",
+            Self::ExportSerdeEnum => "__fp_gen_export_serde_enum",
+            Self::ExportSerdeFlatten => "__fp_gen_export_serde_flatten",
+            Self::ExportSerdeInternallyTagged => "__fp_gen_export_serde_internally_tagged",
+            Self::ExportSerdeStruct => "__fp_gen_export_serde_struct",
+            Self::ExportSerdeUntagged => "__fp_gen_export_serde_untagged",
+            Self::ExportString => "__fp_gen_export_string",
+            Self::ExportStringAfterMemoryGrowth => "__fp_gen_export_string_after_memory_growth",
+            Self::ExportStructWithOptions => "__fp_gen_export_struct_with_options",
+            Self::ExportTimestamp => "__fp_gen_export_timestamp",
+            Self::ExportTimestamps => "__fp_gen_export_timestamps",
+            Self::ExportTypedId => "__fp_gen_export_typed_id",
+            Self::ExportValidatedStruct => "__fp_gen_export_validated_struct",
+            Self::ExportVoidFunction => "__fp_gen_export_void_function",
+            Self::FetchData => "__fp_gen_fetch_data",
+            Self::Init => "__fp_gen_init",
+            Self::ReducerBridge => "__fp_gen_reducer_bridge",
+            Self::UpdateConfig => "__fp_gen_update_config",
+        }
+    }
+}
+
+impl std::fmt::Display for PluginExport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The ways in which calling an export of the plugin can fail.
+#[derive(Debug)]
+pub enum PluginError {
+    /// The plugin does not export the function.
+    MissingExport(PluginExport),
+
+    /// The plugin exports the function with another signature, which usually
+    /// means that it was built for another version of the protocol.
+    SignatureMismatch(PluginExport),
+
+    /// The plugin trapped while it was running the function.
+    GuestTrap { export: PluginExport, error: Trap },
+
+    /// The plugin reported a failure before it aborted, such as a panic.
+    GuestPanic {
+        export: PluginExport,
+        error: GuestError,
+    },
+
+    /// The value that was returned by the plugin could not be deserialized.
+    Deserialization {
+        export: PluginExport,
+        type_name: &'static str,
+        message: String,
+    },
+
+    /// The call failed in the runtime, for instance because the plugin was
+    /// denied an import or could not allocate the arguments.
+    Transport {
+        export: PluginExport,
+        error: InvocationError,
+    },
+}
+
+impl PluginError {
+    fn new(export: PluginExport, error: InvocationError) -> Self {
+        match error {
+            InvocationError::FunctionNotExported(_) => Self::MissingExport(export),
+            InvocationError::SignatureMismatch(_) => Self::SignatureMismatch(export),
+            InvocationError::WasmiTrap(error) => Self::GuestTrap { export, error },
+            InvocationError::GuestError(error) => Self::GuestPanic { export, error },
+            InvocationError::DeserializationFailed {
+                type_name, message, ..
+            } => Self::Deserialization {
+                export,
+                type_name,
+                message,
+            },
+            error => Self::Transport { export, error },
+        }
+    }
+
+    /// Returns the export that failed.
+    pub fn export(&self) -> PluginExport {
+        match self {
+            Self::MissingExport(export) | Self::SignatureMismatch(export) => *export,
+            Self::GuestTrap { export, .. }
+            | Self::GuestPanic { export, .. }
+            | Self::Deserialization { export, .. }
+            | Self::Transport { export, .. } => *export,
+        }
+    }
+}
+
+impl std::fmt::Display for PluginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingExport(export) => write!(f, "plugin does not export `{export}`"),
+            Self::SignatureMismatch(export) => {
+                write!(f, "plugin exports `{export}` with an unexpected signature")
+            }
+            Self::GuestTrap { export, error } => write!(f, "plugin trapped in `{export}`: {error}"),
+            Self::GuestPanic { export, error } => write!(f, "plugin failed in `{export}`: {error}"),
+            Self::Deserialization {
+                export,
+                type_name,
+                message,
+            } => write!(
+                f,
+                "could not deserialize the `{type_name}` returned by `{export}`: {message}"
+            ),
+            Self::Transport { export, error } => write!(f, "could not call `{export}`: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+impl From<PluginError> for InvocationError {
+    fn from(error: PluginError) -> Self {
+        match error {
+            PluginError::MissingExport(export) => {
+                Self::FunctionNotExported(export.symbol_name().to_owned())
+            }
+            PluginError::SignatureMismatch(export) => {
+                Self::SignatureMismatch(export.symbol_name().to_owned())
+            }
+            PluginError::GuestTrap { error, .. } => Self::WasmiTrap(error),
+            PluginError::GuestPanic { error, .. } => Self::GuestError(error),
+            PluginError::Deserialization {
+                export,
+                type_name,
+                message,
+            } => Self::DeserializationFailed {
+                function: export.as_str().to_owned(),
+                type_name,
+                message,
+            },
+            PluginError::Transport { error, .. } => error,
+        }
+    }
+}
+
+fn _import_arbitrary_precision_numbers(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<ArbitraryPrecisionNumbers>(&mut caller, arg);
+    let result = super::import_arbitrary_precision_numbers(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_array_f32(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<[f32; 3]>(&mut caller, arg);
+    let result = super::import_array_f32(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_array_f64(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<[f64; 3]>(&mut caller, arg);
+    let result = super::import_array_f64(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_array_i16(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<[i16; 3]>(&mut caller, arg);
+    let result = super::import_array_i16(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_array_i32(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<[i32; 3]>(&mut caller, arg);
+    let result = super::import_array_i32(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_array_i8(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<[i8; 3]>(&mut caller, arg);
+    let result = super::import_array_i8(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_array_u16(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<[u16; 3]>(&mut caller, arg);
+    let result = super::import_array_u16(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_array_u32(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<[u32; 3]>(&mut caller, arg);
+    let result = super::import_array_u32(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_array_u8(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<[u8; 3]>(&mut caller, arg);
+    let result = super::import_array_u8(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_async_void_function(mut caller: Caller<'_, RuntimeInstanceData>, message: FatPtr) {
+    let message = import_from_guest::<String>(&mut caller, message);
+    let result = super::import_async_void_function(message);
+    spawn_import_without_result(&mut caller, result);
+}
+
+fn _import_collections(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<Collections>(&mut caller, arg);
+    let result = super::import_collections(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+#[cfg(feature = "experimental")]
+fn _import_experimental(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<String>(&mut caller, arg);
+    let result = super::import_experimental(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_explicit_bound_point(mut caller: Caller<'_, RuntimeInstanceData>, arg: FatPtr) {
+    let arg = import_from_guest::<ExplicitBoundPoint<u64>>(&mut caller, arg);
+    let result = super::import_explicit_bound_point(arg);
+}
+
+fn _import_fp_adjacently_tagged(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<FpAdjacentlyTagged>(&mut caller, arg);
+    let result = super::import_fp_adjacently_tagged(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_fp_enum(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<FpVariantRenaming>(&mut caller, arg);
+    let result = super::import_fp_enum(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_fp_flatten(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<FpFlatten>(&mut caller, arg);
+    let result = super::import_fp_flatten(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_fp_internally_tagged(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<FpInternallyTagged>(&mut caller, arg);
+    let result = super::import_fp_internally_tagged(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_fp_struct(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<FpPropertyRenaming>(&mut caller, arg);
+    let result = super::import_fp_struct(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_fp_untagged(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<FpUntagged>(&mut caller, arg);
+    let result = super::import_fp_untagged(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_generics(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<StructWithGenerics<u64>>(&mut caller, arg);
+    let result = super::import_generics(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_get_bytes(mut caller: Caller<'_, RuntimeInstanceData>) -> Result<FatPtr, Trap> {
+    let result = super::import_get_bytes();
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_get_serde_bytes(mut caller: Caller<'_, RuntimeInstanceData>) -> Result<FatPtr, Trap> {
+    let result = super::import_get_serde_bytes();
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_get_value(mut caller: Caller<'_, RuntimeInstanceData>, key: FatPtr) -> FatPtr {
+    let key = import_from_guest::<String>(&mut caller, key);
+    let result = super::import_get_value(key);
+    spawn_import(&mut caller, result)
+}
+
+fn _import_integer_types(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<IntegerTypes>(&mut caller, arg);
+    let result = super::import_integer_types(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_multiple_primitives(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg1: i32,
+    arg2: FatPtr,
+) -> i64 {
+    let arg1 = arg1 as i8;
+    let arg2 = import_from_guest::<String>(&mut caller, arg2);
+    let result = super::import_multiple_primitives(arg1, arg2);
+    result
+}
+
+fn _import_multiple_values(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: u32,
+) -> Result<FatPtr, Trap> {
+    let result = super::import_multiple_values(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_primitive_bool(_caller: Caller<'_, RuntimeInstanceData>, arg: u32) -> u32 {
+    let arg = arg != 0;
+    let result = super::import_primitive_bool(arg);
+    u32::from(result)
+}
+
+fn _import_primitive_f32(_caller: Caller<'_, RuntimeInstanceData>, arg: F32) -> F32 {
+    let arg = f32::from(arg);
+    let result = super::import_primitive_f32(arg);
+    F32::from(result)
+}
+
+fn _import_primitive_f64(_caller: Caller<'_, RuntimeInstanceData>, arg: F64) -> F64 {
+    let arg = f64::from(arg);
+    let result = super::import_primitive_f64(arg);
+    F64::from(result)
+}
+
+fn _import_primitive_i16(_caller: Caller<'_, RuntimeInstanceData>, arg: i32) -> i32 {
+    let arg = arg as i16;
+    let result = super::import_primitive_i16(arg);
+    i32::from(result)
+}
+
+fn _import_primitive_i32(_caller: Caller<'_, RuntimeInstanceData>, arg: i32) -> i32 {
+    let result = super::import_primitive_i32(arg);
+    result
+}
+
+fn _import_primitive_i64(_caller: Caller<'_, RuntimeInstanceData>, arg: i64) -> i64 {
+    let result = super::import_primitive_i64(arg);
+    result
+}
+
+fn _import_primitive_i8(_caller: Caller<'_, RuntimeInstanceData>, arg: i32) -> i32 {
+    let arg = arg as i8;
+    let result = super::import_primitive_i8(arg);
+    i32::from(result)
+}
+
+fn _import_primitive_pair(
+    _caller: Caller<'_, RuntimeInstanceData>,
+    arg: u32,
+) -> <(u32, i32) as WasmAbi>::AbiType {
+    let result = super::import_primitive_pair(arg);
+    result.to_abi()
+}
+
+fn _import_primitive_u16(_caller: Caller<'_, RuntimeInstanceData>, arg: u32) -> u32 {
+    let arg = arg as u16;
+    let result = super::import_primitive_u16(arg);
+    u32::from(result)
+}
+
+fn _import_primitive_u32(_caller: Caller<'_, RuntimeInstanceData>, arg: u32) -> u32 {
+    let result = super::import_primitive_u32(arg);
+    result
+}
+
+fn _import_primitive_u64(_caller: Caller<'_, RuntimeInstanceData>, arg: u64) -> u64 {
+    let result = super::import_primitive_u64(arg);
+    result
+}
+
+fn _import_primitive_u8(_caller: Caller<'_, RuntimeInstanceData>, arg: u32) -> u32 {
+    let arg = arg as u8;
+    let result = super::import_primitive_u8(arg);
+    u32::from(result)
+}
+
+fn _import_rate_limited(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    label: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let label = import_from_guest::<String>(&mut caller, label);
+    let result = super::import_rate_limited(label);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_renamed_string(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<String>(&mut caller, arg);
+    let result = super::import_renamed_string(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_serde_adjacently_tagged(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<SerdeAdjacentlyTagged>(&mut caller, arg);
+    let result = super::import_serde_adjacently_tagged(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_serde_enum(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<SerdeVariantRenaming>(&mut caller, arg);
+    let result = super::import_serde_enum(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_serde_flatten(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<SerdeFlatten>(&mut caller, arg);
+    let result = super::import_serde_flatten(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_serde_internally_tagged(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<SerdeInternallyTagged>(&mut caller, arg);
+    let result = super::import_serde_internally_tagged(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_serde_struct(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<SerdePropertyRenaming>(&mut caller, arg);
+    let result = super::import_serde_struct(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_serde_untagged(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<SerdeUntagged>(&mut caller, arg);
+    let result = super::import_serde_untagged(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_set_value(mut caller: Caller<'_, RuntimeInstanceData>, key: FatPtr, value: FatPtr) {
+    let key = import_from_guest::<String>(&mut caller, key);
+    let value = import_from_guest::<_>(&mut caller, value);
+    let result = super::import_set_value(key, value);
+}
+
+fn _import_string(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<String>(&mut caller, arg);
+    let result = super::import_string(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_struct_with_options(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<StructWithOptions>(&mut caller, arg);
+    let result = super::import_struct_with_options(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_timestamp(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<MyDateTime>(&mut caller, arg);
+    let result = super::import_timestamp(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_validated_struct(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> Result<FatPtr, Trap> {
+    let arg = import_from_guest::<ValidatedStruct>(&mut caller, arg);
+    let result = super::import_validated_struct(arg);
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_void_function(_caller: Caller<'_, RuntimeInstanceData>) {
+    let result = super::import_void_function();
+}
+
+fn _import_void_function_empty_result(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+) -> Result<FatPtr, Trap> {
+    let result = super::import_void_function_empty_result();
+    Ok(try_export_to_guest(&mut caller, &result)?)
+}
+
+fn _import_void_function_empty_return(_caller: Caller<'_, RuntimeInstanceData>) {
+    let result = super::import_void_function_empty_return();
+}
+
+fn _invoke_command(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    name: FatPtr,
+    payload: FatPtr,
+) -> FatPtr {
+    let name = import_from_guest::<String>(&mut caller, name);
+    if let Some(handler) = caller
+        .data()
+        .dynamic_import_handler("invoke_command", &name)
+    {
+        let payload = import_from_guest_raw(&mut caller, payload);
+        return export_to_guest_raw(&mut caller, handler(&payload));
+    }
+    let payload = import_from_guest::<String>(&mut caller, payload);
+    let result = super::invoke_command(name, payload);
+    export_to_guest(&mut caller, &result)
+}
+
+fn _log(mut caller: Caller<'_, RuntimeInstanceData>, message: FatPtr) {
+    let message = import_from_guest::<String>(&mut caller, message);
+    let result = super::log(message);
+}
+
+fn _make_http_request(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    request: FatPtr,
+) -> Result<FatPtr, Trap> {
+    caller
+        .data()
+        .check_capability("make_http_request", "network")?;
+    let request = import_from_guest::<Request>(&mut caller, request);
+    let result = super::make_http_request(request);
+    Ok(spawn_import(&mut caller, result))
+}
+
+fn _record_metric(mut caller: Caller<'_, RuntimeInstanceData>, name: FatPtr, value: F64) {
+    let name = import_from_guest::<String>(&mut caller, name);
+    let value = f64::from(value);
+    let result = super::record_metric(name, value);
+}
+
+fn _report_progress(mut caller: Caller<'_, RuntimeInstanceData>, progress: FatPtr) {
+    let progress = import_from_guest_raw(&mut caller, progress);
+    caller.data_mut().emit_event("report_progress", &progress);
+}
+
+fn __fp_host_batch(mut caller: Caller<'_, RuntimeInstanceData>, calls: FatPtr) {
+    for call in import_from_guest::<Vec<BatchedCall>>(&mut caller, calls) {
+        match call {
+            BatchedCall::RecordMetric((name, value)) => super::record_metric(name, value),
+        }
+    }
+}
+
+/// A call to a batchable import, as it was queued by the plugin.
+#[derive(serde::Deserialize)]
+enum BatchedCall {
+    #[serde(rename = "record_metric")]
+    RecordMetric((String, f64)),
+}
+
+const PROTOCOL: &str = "{\"import_functions\":[{\"name\":\"import_arbitrary_precision_numbers\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ArbitraryPrecisionNumbers\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"ArbitraryPrecisionNumbers\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_f32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"f32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_f64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"f64\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_i16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i16\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i16\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_i32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_i8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i8\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_u16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u16\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_u32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_array_u8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u8\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_async_void_function\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"message\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_collections\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_experimental\",\"doc_lines\":[\" Example of an import behind a feature. Only plugins and runtimes that\",\" are compiled with the `experimental` feature know about it.\"],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":\"experimental\",\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_explicit_bound_point\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ExplicitBoundPoint\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_adjacently_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_enum\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_flatten\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_internally_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_fp_untagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_generics\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}}],\"return_type\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_get_bytes\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"Bytes\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_get_serde_bytes\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_get_value\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"key\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"_\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_integer_types\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"IntegerTypes\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"IntegerTypes\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_multiple_primitives\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg1\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null}},{\"name\":\"arg2\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_multiple_values\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"(String, Vec<u32>, bool)\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_bool\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_f32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_f64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_i16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i16\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i16\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_i32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_i64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_i8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_pair\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"(u32, i32)\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_u16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_u32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_u64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_primitive_u8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_rate_limited\",\"doc_lines\":[\" Example of a rate-limited import. Calls that exceed the limit return a\",\" `RateLimited` error to the plugin, instead of reaching the runtime.\"],\"generic_params\":[],\"args\":[{\"name\":\"label\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"RateLimited\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":{\"calls\":2,\"per_ms\":60000},\"read_only\":false,\"rename\":null}},{\"name\":\"import_renamed_string\",\"doc_lines\":[\" Example of a renamed import. It is still exchanged under its old name,\",\" so plugins that were built against `import_legacy_string` keep working.\"],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":\"import_legacy_string\"}},{\"name\":\"import_serde_adjacently_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_enum\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_flatten\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_internally_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_serde_untagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_set_value\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"key\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}},{\"name\":\"value\",\"ty\":{\"name\":\"_\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_string\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_struct_with_options\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_timestamp\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_validated_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_void_function\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_void_function_empty_result\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"()\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"import_void_function_empty_return\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"invoke_command\",\"doc_lines\":[\" Example of a dynamic import. The runtime can register handlers for it\",\" after instantiation, which are dispatched to by `name`. Calls for which\",\" no handler is registered fall back to the regular implementation.\"],\"generic_params\":[],\"args\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}},{\"name\":\"payload\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":true,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"log\",\"doc_lines\":[\" Logs a message to the (development) console.\"],\"generic_params\":[],\"args\":[{\"name\":\"message\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"make_http_request\",\"doc_lines\":[\" Example how a runtime could expose a `Fetch`-like function to plugins.\",\"\",\" See `types/http.rs` for more info.\",\"\",\" Plugins may only call this if the runtime granted them the `network`\",\" capability.\"],\"generic_params\":[],\"args\":[{\"name\":\"request\",\"ty\":{\"name\":\"Request\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"HttpResult\",\"generic_args\":[],\"array\":null},\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":\"network\",\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"record_metric\",\"doc_lines\":[\" Example of a batchable import. Calls that are made inside `batch()` are\",\" queued by the plugin and sent to the runtime together.\"],\"generic_params\":[],\"args\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}},{\"name\":\"value\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":true,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"report_progress\",\"doc_lines\":[\" Example of an event. The plugin can emit it at any time, after which\",\" the runtime passes it on to its subscribers.\"],\"generic_params\":[],\"args\":[{\"name\":\"progress\",\"ty\":{\"name\":\"Progress\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":true,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}}],\"export_functions\":[{\"name\":\"export_array_f32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"f32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_f64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"f64\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_i16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i16\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i16\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_i32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_i8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"i8\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_u16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u16\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_u32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u32\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_array_u8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":3}}],\"return_type\":{\"name\":\"u8\",\"generic_args\":[],\"array\":3},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_async_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg1\",\"ty\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null}},{\"name\":\"arg2\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/async-struct\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_batch_response\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"BatchResponse\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"BatchResponse\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/batch\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_cached_call_count\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"label\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":{\"ttl_ms\":30000,\"key\":null},\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_call_rate_limited_import\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"calls\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"RateLimited\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_collections\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_dynamic_value\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ExtensionSettings\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Value\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_edge_values\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"EdgeValues\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"EdgeValues\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_experimental\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":\"experimental\",\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_adjacently_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_enum\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_flatten\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_internally_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_fp_untagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_generics\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}}],\"return_type\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/generics\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_get_bytes\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"Bytes\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"GET\",\"path\":\"/bytes\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_get_serde_bytes\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_lazy_string\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":true,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_metrics\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"metrics\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Metric\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}}],\"return_type\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Metric\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_multiple_primitives\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg1\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null}},{\"name\":\"arg2\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"GET\",\"path\":\"/primitives\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_multiple_values\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"(String, Vec<u32>, bool)\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_bool\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_f32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_f64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_i16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i16\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i16\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_i32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_i64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_i8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_pair\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"(u32, i32)\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_u16\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_u32\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_u64\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_primitive_u8\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_renamed_string\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":\"export_legacy_string\"}},{\"name\":\"export_samples\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"samples\",\"ty\":{\"name\":\"SampleColumns\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SampleColumns\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_serde_adjacently_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/enums/adjacently-tagged\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_serde_enum\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/enums/renamed\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_serde_flatten\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/flatten\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_serde_internally_tagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/enums/internally-tagged\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_serde_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_serde_untagged\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/enums/untagged\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_string\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_string_after_memory_growth\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_struct_with_options\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"PUT\",\"path\":\"/options\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":true,\"rename\":null}},{\"name\":\"export_timestamp\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_timestamps\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"Timestamps\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Timestamps\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_typed_id\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"id\",\"ty\":{\"name\":\"TypedId\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null}}],\"return_type\":{\"name\":\"TypedId\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_validated_struct\",\"doc_lines\":[],\"generic_params\":[],\"args\":[{\"name\":\"arg\",\"ty\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"export_void_function\",\"doc_lines\":[],\"generic_params\":[],\"args\":[],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"fetch_data\",\"doc_lines\":[\" Example how plugin could expose async data-fetching capabilities.\"],\"generic_params\":[],\"args\":[{\"name\":\"r#type\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"is_async\":true,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"GET\",\"path\":\"/data/{type}\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"init\",\"doc_lines\":[\" Called on the plugin to give it a chance to initialize.\"],\"generic_params\":[],\"args\":[{\"name\":\"config\",\"ty\":{\"name\":\"PluginConfig\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":{\"method\":\"POST\",\"path\":\"/init\"},\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"reducer_bridge\",\"doc_lines\":[\" Example how plugin could expose a reducer.\"],\"generic_params\":[],\"args\":[{\"name\":\"action\",\"ty\":{\"name\":\"ReduxAction\",\"generic_args\":[],\"array\":null}}],\"return_type\":{\"name\":\"StateUpdate\",\"generic_args\":[],\"array\":null},\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}},{\"name\":\"update_config\",\"doc_lines\":[\" Called on the plugin when the host changes its config.\"],\"generic_params\":[],\"args\":[{\"name\":\"config\",\"ty\":{\"name\":\"PluginConfig\",\"generic_args\":[],\"array\":null}}],\"return_type\":null,\"is_async\":false,\"attrs\":{\"dynamic\":false,\"batchable\":false,\"capability\":null,\"http\":null,\"event\":false,\"cfg_feature\":null,\"lazy\":false,\"cache\":null,\"rate_limit\":null,\"read_only\":false,\"rename\":null}}],\"types\":[[{\"name\":\"()\",\"generic_args\":[],\"array\":null},\"Unit\"],[{\"name\":\"(String, Vec<u32>, bool)\",\"generic_args\":[],\"array\":null},{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null},{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"name\":\"bool\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"(u32, i32)\",\"generic_args\":[],\"array\":null},{\"Tuple\":[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},{\"name\":\"i32\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"ArbitraryPrecisionNumbers\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ArbitraryPrecisionNumbers\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"amount\",\"ty\":{\"name\":\"Decimal\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"total_supply\",\"ty\":{\"name\":\"BigInt\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Example of numbers that cannot be represented as `f64` without losing\",\" precision.\",\"\",\" They are exchanged as strings, and typed as branded strings in TypeScript.\",\" Requires the `rust-decimal-compat` and `num-bigint-compat` features.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"BTreeMap\",\"generic_args\":[[{\"name\":\"K\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"V\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Map\":[\"BTreeMap\",{\"name\":\"K\",\"generic_args\":[],\"array\":null},{\"name\":\"V\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"BTreeSet\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"List\":[\"BTreeSet\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"BatchResponse\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"BatchResponse\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"results\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"OperationResult\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"summary\",\"ty\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"retry_after\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" The response to a batch of operations, some of which may have failed.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"BigInt\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"BigInt\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"num_bigint::BigInt\",\"serde_attrs\":[\"with = \\\"fp_bindgen_support::common::serde_str\\\"\"],\"ts_ty\":\"BigIntString\",\"ts_declaration\":\"string & { readonly __brand: \\\"BigInt\\\" }\"}}],[{\"name\":\"Body\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"Body\",{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"ByteBuf\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"serde_bytes::ByteBuf\",\"serde_attrs\":[],\"ts_ty\":\"ArrayBuffer\",\"ts_declaration\":null}}],[{\"name\":\"Bytes\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Bytes\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"bytes::Bytes\",\"serde_attrs\":[],\"ts_ty\":\"Uint8Array\",\"ts_declaration\":null}}],[{\"name\":\"Collections\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Collections\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"tags\",\"ty\":{\"name\":\"BTreeSet\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"optional_tags\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"BTreeSet\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"queue\",\"ty\":{\"name\":\"VecDeque\",\"generic_args\":[[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Std collections that are encoded as lists.\",\"\",\" Sets are generated as `Set` types in TypeScript if `generate_set_types` is\",\" enabled, while other lists are always generated as arrays.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ConditionalFields\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ConditionalFields\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Fields and variants behind a `#[cfg(...)]` attribute are only included in\",\" the bindings if the protocol is compiled with the condition enabled.\",\"\",\" Using `#[fp(generators = \\\"...\\\")]`, fields and variants can also be limited\",\" to the bindings of specific generators.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ConditionalVariants\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"ConditionalVariants\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Stable\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"CustomSerializers\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"CustomSerializers\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"timestamp\",\"ty\":{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Serialized as a Unix timestamp, instead of the RFC3339 string that is\",\" used for `OffsetDateTime` by default.\"],\"attrs\":{\"default\":null,\"deserialize_with\":\"time::serde::timestamp\",\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":\"time::serde::timestamp\",\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":\"number\",\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Decimal\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Decimal\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"rust_decimal::Decimal\",\"serde_attrs\":[\"with = \\\"fp_bindgen_support::common::serde_str\\\"\"],\"ts_ty\":\"DecimalString\",\"ts_declaration\":\"string & { readonly __brand: \\\"Decimal\\\" }\"}}],[{\"name\":\"DocExampleEnum\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"DocExampleEnum\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Variant1\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[\" Multi-line doc comment with complex characters\",\" & \\\" , \\\\ ! '\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"r#Variant2\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"r#Variant2\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"inner\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Variant property.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[\" Raw identifiers are supported too.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[\" # This is an enum with doc comments.\"],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"DocExampleStruct\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"DocExampleStruct\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"multi_line\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Multi-line doc comment with complex characters\",\" & \\\" , \\\\ ! '\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"r#type\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Raw identifiers are supported too.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" # This is a struct with doc comments.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"EdgeValues\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"EdgeValues\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"character\",\"ty\":{\"name\":\"char\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"float\",\"ty\":{\"name\":\"f32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"signed\",\"ty\":{\"name\":\"i128\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"unsigned\",\"ty\":{\"name\":\"u128\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Example of values at the edges of what the runtimes can represent.\",\"\",\" `char` is exchanged as a single-character string, 128-bit integers are\",\" exchanged as decimal strings, and `f32` keeps NaN and infinities intact.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ExplicitBoundPoint\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[\"Serializable\",\"std::fmt::Debug\",\"std::fmt::Display\"]]],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ExplicitBoundPoint\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[\"Serializable\",\"std::fmt::Debug\",\"std::fmt::Display\"]]],\"array\":null},\"fields\":[{\"name\":\"value\",\"ty\":{\"name\":\"T\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A point of an arbitrary type, with explicit trait bounds.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ExplicitedlyImportedType\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ExplicitedlyImportedType\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"you_will_see_this\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" This struct is also not referenced by any function or data structure, but\",\" it will show up because there is an explicit `use` statement for it in the\",\" `fp_import!` macro.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ExtensionSettings\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ExtensionSettings\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"extension\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"settings\",\"ty\":{\"name\":\"Value\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Settings for an extension whose shape is only known to the extension\",\" itself.\",\"\",\" `serde_json::Value` (and `rmpv::Value`) can be used anywhere in a protocol\",\" to pass values of arbitrary shape. Both are encoded as plain MessagePack,\",\" and show up as `unknown` in the TypeScript bindings.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"FlattenedStruct\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"FlattenedStruct\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"bar\",\"ty\":{\"name\":\"i64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"FloatingPoint\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"FloatingPoint\",{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}]}],[{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"FpAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Foo\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Bar\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[\"Qux\"],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Pair\",\"ty\":{\"Tuple\":[{\"name\":\"i8\",\"generic_args\":[],\"array\":null},{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Unknown\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":true,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":\"payload\",\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"FpFlatten\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"flattened\",\"ty\":{\"name\":\"FlattenedStruct\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":true,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"FpInternallyTagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Foo\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":true}}}],[{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"FpPropertyRenaming\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo_bar\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[\"foo\"],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"qux_baz\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":\"QUX_BAZ\",\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"r#raw_struct\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"FpUntagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Bar\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Pair\",\"ty\":{\"Tuple\":[{\"name\":\"i8\",\"generic_args\":[],\"array\":null},{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":true,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"FpVariantRenaming\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"FooBar\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"QuxBaz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"QuxBaz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo_bar\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Will be renamed to \\\"FOO_BAR\\\" because of the `rename_all` on the\",\" variant.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"qux_baz\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":\"qux_baz\",\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"ScreamingSnakeCase\",\"generators\":[],\"other\":false,\"rename\":\"QUX_BAZ\",\"renamed_from\":[\"qux\"]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"GroupImportedType1\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"GroupImportedType1\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"you_will_see_this\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"GroupImportedType2\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"GroupImportedType2\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"you_will_see_this\",\"ty\":{\"name\":\"bool\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"HttpResult\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"HttpResult\",{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"Response\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"RequestError\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}]}],[{\"name\":\"Int64\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"Int64\",{\"name\":\"u64\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"IntegerTypes\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"IntegerTypes\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"count\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"offset\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"id\",\"ty\":{\"name\":\"NonZeroU32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"delta\",\"ty\":{\"name\":\"NonZeroI64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"capacity\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"NonZeroU32\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"user_id\",\"ty\":{\"name\":\"UserId\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Example of integer types that are not primitives in the protocol.\",\"\",\" `usize` and `isize` are exchanged as `u32` and `i32`, because that is their\",\" size on `wasm32`. `NonZero` types refuse to deserialize when they are zero.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Method\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Method\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"http::Method\",\"serde_attrs\":[\"serialize_with = \\\"fp_bindgen_support::http::serialize_http_method\\\"\",\"deserialize_with = \\\"fp_bindgen_support::http::deserialize_http_method\\\"\"],\"ts_ty\":\"Method\",\"ts_declaration\":\"\\n    | \\\"GET\\\"\\n    | \\\"POST\\\"\\n    | \\\"PUT\\\"\\n    | \\\"DELETE\\\"\\n    | \\\"HEAD\\\"\\n    | \\\"OPTIONS\\\"\\n    | \\\"CONNECT\\\"\\n    | \\\"PATCH\\\"\\n    | \\\"TRACE\\\"\"}}],[{\"name\":\"Metric\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Metric\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":true,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"value\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A measurement that is reported by the plugin.\",\"\",\" Because the `name` field is annotated with `#[fp(interned)]`, the plugin\",\" registers every distinct name with the runtime once, and sends a `u32`\",\" handle in its place from then on. This keeps the payloads small when the\",\" same few names are reported over and over.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":null,\"ty\":{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Our struct for passing date time instances.\",\"\",\" We wrap the `OffsetDateTime` type in a new struct so that the Serde\",\" attributes can be inserted. These are necessary to enable RFC3339\",\" formatting. Without a wrapper type like this, we would not be able to pass\",\" date time instances directly to function arguments and we might run into\",\" trouble embedding them into certain generic types.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"NonZeroI64\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"NonZeroI64\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"std::num::NonZeroI64\",\"serde_attrs\":[],\"ts_ty\":\"number\",\"ts_declaration\":null}}],[{\"name\":\"NonZeroU32\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"NonZeroU32\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"std::num::NonZeroU32\",\"serde_attrs\":[],\"ts_ty\":\"number\",\"ts_declaration\":null}}],[{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"time::OffsetDateTime\",\"serde_attrs\":[\"with = \\\"time::serde::rfc3339\\\"\"],\"ts_ty\":\"string\",\"ts_declaration\":null}}],[{\"name\":\"OperationResult\",\"generic_args\":[],\"array\":null},{\"Alias\":[\"OperationResult\",{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null}]}],[{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Container\":[\"Option\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"PluginConfig\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"PluginConfig\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"log_level\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Config that is passed to the `init` export when the plugin is instantiated.\",\"\",\" Every protocol has `init` and `shutdown` exports, but protocols can declare\",\" `init` themselves to have it take a config argument like this one.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"fields\":[{\"name\":\"value\",\"ty\":{\"name\":\"T\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A point of an arbitrary type.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Priority\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"Priority\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Low\",\"ty\":\"Unit\",\"doc_lines\":[\" Handled after everything else.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Normal\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"High\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":\"urgent\",\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"Progress\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Progress\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"task\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"completed_steps\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"total_steps\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Emitted by the plugin while it works on a long-running task.\",\"\",\" See the `report_progress` event in `main.rs` for more info.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"RateLimited\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"RateLimited\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"function\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" The name of the import that was called.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"retry_after_ms\",\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" The number of milliseconds after which the import may be called again.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Error that is returned to the plugin when it calls a rate-limited import\",\" more often than its limit allows.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Rc\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Container\":[\"Rc\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"ReduxAction\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"ReduxAction\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"ClearTitle\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"UpdateTitle\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"UpdateTitle\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"title\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[\" Example for representing Redux actions.\"],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":\"payload\",\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":\"redux_example\",\"non_exhaustive\":false}}}],[{\"name\":\"Request\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Request\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"url\",\"ty\":{\"name\":\"Uri\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" The URI to submit the request to.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"method\",\"ty\":{\"name\":\"Method\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP method to use for the request.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"headers\",\"ty\":{\"name\":\"http::HeaderMap\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP headers to submit with the request.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"body\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"Body\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[\" The body to submit with the request.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"Option::is_none\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Represents an HTTP request to be sent.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"RequestError\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"RequestError\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Offline\",\"ty\":\"Unit\",\"doc_lines\":[\" Used when we know we don't have an active network connection.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"NoRoute\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"ConnectionRefused\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Timeout\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"ServerError\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"ServerError\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"status_code\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP status code.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"response\",\"ty\":{\"name\":\"Body\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Response body.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"SnakeCase\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Other\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Other\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"reason\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[\" Misc.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":\"other/misc\",\"renamed_from\":[]}}],\"doc_lines\":[\" Represents an error that occurred while attempting to submit the request.\"],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":null,\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"Response\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Response\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"body\",\"ty\":{\"name\":\"Body\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" The response body. May be empty.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"headers\",\"ty\":{\"name\":\"http::HeaderMap\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP headers that were part of the response.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"status_code\",\"ty\":{\"name\":\"u16\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" HTTP status code.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Represents an HTTP response we received.\",\"\",\" Please note we currently do not support streaming responses.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"E\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"Result\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"E\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"variants\":[{\"name\":\"Ok\",\"ty\":{\"Tuple\":[{\"name\":\"T\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[\" Represents a successful result.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Err\",\"ty\":{\"Tuple\":[{\"name\":\"E\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[\" Represents an error.\"],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[\" A result that can be either successful (`Ok`) or represent an error (`Err`).\"],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"Sample\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Sample\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"timestamp_ms\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"value\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"quality\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A sample of a time series.\",\"\",\" Because the struct is annotated with `#[fp(columnar)]`, every `Vec<Sample>`\",\" in the protocol is replaced with a generated `SampleColumns` struct, which\",\" holds a column with the values of every field. In TypeScript, the columns\",\" are typed arrays, so large lists of samples can be passed without encoding\",\" an object for every item.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":true}}}],[{\"name\":\"SampleColumns\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"SampleColumns\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"timestamp_ms\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":\"fp_bindgen_support::common::columnar\",\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":\"fp_bindgen_support::common::columnar\",\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":\"BigUint64Array\",\"validate\":null,\"generators\":[]}},{\"name\":\"value\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":\"fp_bindgen_support::common::columnar\",\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":\"fp_bindgen_support::common::columnar\",\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":\"Float64Array\",\"validate\":null,\"generators\":[]}},{\"name\":\"quality\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"u8\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":\"fp_bindgen_support::common::columnar\",\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":\"fp_bindgen_support::common::columnar\",\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":\"Uint8Array\",\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" The columns of a list of `Sample`, with the values of every field stored contiguously.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"SerdeAdjacentlyTagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Foo\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Bar\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":\"payload\",\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"SerdeFlatten\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"flattened\",\"ty\":{\"name\":\"FlattenedStruct\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":true,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"SerdeInternallyTagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Foo\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":\"type\",\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"SerdePropertyRenaming\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo_bar\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"qux_baz\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":\"QUX_BAZ\",\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"r#raw_struct\",\"ty\":{\"name\":\"i32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"SerdeUntagged\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"Bar\",\"ty\":{\"Tuple\":[{\"name\":\"String\",\"generic_args\":[],\"array\":null}]},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"Baz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"Baz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"a\",\"ty\":{\"name\":\"i8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"b\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"Original\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":true,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null},{\"Enum\":{\"ident\":{\"name\":\"SerdeVariantRenaming\",\"generic_args\":[],\"array\":null},\"variants\":[{\"name\":\"FooBar\",\"ty\":\"Unit\",\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"Original\",\"generators\":[],\"other\":false,\"rename\":null,\"renamed_from\":[]}},{\"name\":\"QuxBaz\",\"ty\":{\"Struct\":{\"ident\":{\"name\":\"QuxBaz\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"foo_bar\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[\" Will be renamed to \\\"FooBar\\\" because of the `rename_all` on the\",\" variant.\"],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"qux_baz\",\"ty\":{\"name\":\"f64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":\"qux_baz\",\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}},\"doc_lines\":[],\"attrs\":{\"aliases\":[],\"field_casing\":\"PascalCase\",\"generators\":[],\"other\":false,\"rename\":\"QUX_BAZ\",\"renamed_from\":[]}}],\"doc_lines\":[],\"options\":{\"variant_casing\":\"SnakeCase\",\"content_prop_name\":null,\"tag_prop_name\":null,\"untagged\":false,\"rust_module\":null,\"non_exhaustive\":false}}}],[{\"name\":\"StateUpdate\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"StateUpdate\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"title\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"Rc\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"revision\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"u16\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" A state update to communicate to the Redux host.\",\"\",\" Fields are wrapped in `Option`. If any field is `None` it means it hasn't\",\" changed.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":\"redux_example\",\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"String\"],[{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"StructWithGenerics\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"fields\":[{\"name\":\"list\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"points\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"recursive\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"Point\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"complex_nested\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"BTreeMap\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]],[{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"FloatingPoint\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"optional_timestamp\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"MyDateTime\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"StructWithOptions\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"filled_string\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"String::is_empty\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"empty_string\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"String::is_empty\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"filled_option_string\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"Option::is_none\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"empty_option_string\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":\"Option::is_none\",\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"never_skipped_filled_option_string\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"never_skipped_empty_option_string\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"String\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":\"\",\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Timestamps\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"Timestamps\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"created_at\",\"ty\":{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":\"Date\",\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"deleted_at\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"OffsetDateTime\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":\"Date\",\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Timestamps that the TypeScript runtime represents as `Date` objects.\",\"\",\" Fields that are annotated with `ts_as = \\\"Date\\\"` are still serialized as\",\" RFC3339 strings, but the TypeScript runtime converts them into `Date`\",\" objects when they are received, and back into strings when they are sent.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"TypedId\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"TypedId\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"fields\":[{\"name\":\"id\",\"ty\":{\"name\":\"u64\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}},{\"name\":\"resource\",\"ty\":{\"name\":\"std::marker::PhantomData\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" An identifier of a resource of type `T`.\",\"\",\" `PhantomData` fields are not serialized, so they only show up in the Rust\",\" bindings, where they keep the type parameter in use. Other bindings only\",\" preserve the type parameter in the signature of the type.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Uri\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Uri\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"http::Uri\",\"serde_attrs\":[\"serialize_with = \\\"fp_bindgen_support::http::serialize_uri\\\"\",\"deserialize_with = \\\"fp_bindgen_support::http::deserialize_uri\\\"\"],\"ts_ty\":\"string\",\"ts_declaration\":null}}],[{\"name\":\"UserId\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"UserId\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":null,\"ty\":{\"name\":\"u32\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":null,\"generators\":[]}}],\"doc_lines\":[\" Example of a newtype that can be generated as a branded type in TypeScript.\",\"\",\" With `generate_branded_types`, it is typed as\",\" `number & { readonly __brand: \\\"UserId\\\" }`, so that it cannot be mixed up\",\" with other numbers.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":true,\"columnar\":false}}}],[{\"name\":\"ValidatedChild\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ValidatedChild\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"name\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":null,\"non_empty\":true,\"regex\":null},\"generators\":[]}}],\"doc_lines\":[\" Structs that contain validated structs get validated as a whole.\",\"\",\" Fields that are not part of this struct are rejected, rather than silently\",\" ignored.\"],\"options\":{\"field_casing\":\"Original\",\"rust_module\":null,\"deny_unknown_fields\":true,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null},{\"Struct\":{\"ident\":{\"name\":\"ValidatedStruct\",\"generic_args\":[],\"array\":null},\"fields\":[{\"name\":\"percentage\",\"ty\":{\"name\":\"u8\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":{\"start\":\"0\",\"end\":\"100\",\"inclusive\":true},\"non_empty\":false,\"regex\":null},\"generators\":[]}},{\"name\":\"slug\",\"ty\":{\"name\":\"String\",\"generic_args\":[],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":null,\"non_empty\":true,\"regex\":\"^[a-z][a-z0-9-]*$\"},\"generators\":[]}},{\"name\":\"ratio\",\"ty\":{\"name\":\"Option\",\"generic_args\":[[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":{\"start\":\"0\",\"end\":\"1\",\"inclusive\":false},\"non_empty\":false,\"regex\":null},\"generators\":[]}},{\"name\":\"children\",\"ty\":{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"ValidatedChild\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},\"doc_lines\":[],\"attrs\":{\"default\":null,\"deserialize_with\":null,\"flatten\":false,\"interned\":false,\"rename\":null,\"renamed_from\":[],\"serialize_with\":null,\"skip_serializing_if\":null,\"ts_as\":null,\"ts_type\":null,\"validate\":{\"range\":null,\"non_empty\":true,\"regex\":null},\"generators\":[]}}],\"doc_lines\":[\" Fields with `#[fp(validate(...))]` attributes are validated when they are\",\" received from the other side, so invalid values are rejected right away.\"],\"options\":{\"field_casing\":\"CamelCase\",\"rust_module\":null,\"deny_unknown_fields\":false,\"brand\":false,\"columnar\":false}}}],[{\"name\":\"Value\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"Value\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"serde_json::Value\",\"serde_attrs\":[],\"ts_ty\":\"unknown\",\"ts_declaration\":null}}],[{\"name\":\"Vec\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"List\":[\"Vec\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"VecDeque\",\"generic_args\":[[{\"name\":\"T\",\"generic_args\":[],\"array\":null},[]]],\"array\":null},{\"List\":[\"VecDeque\",{\"name\":\"T\",\"generic_args\":[],\"array\":null}]}],[{\"name\":\"bool\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"Bool\"}],[{\"name\":\"char\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"char\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"char\",\"serde_attrs\":[],\"ts_ty\":\"string\",\"ts_declaration\":null}}],[{\"name\":\"f32\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"F32\"}],[{\"name\":\"f32\",\"generic_args\":[],\"array\":3},{\"Array\":[\"F32\",3]}],[{\"name\":\"f64\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"F64\"}],[{\"name\":\"f64\",\"generic_args\":[],\"array\":3},{\"Array\":[\"F64\",3]}],[{\"name\":\"http::HeaderMap\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"http::HeaderMap\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"http::HeaderMap\",\"serde_attrs\":[\"serialize_with = \\\"fp_bindgen_support::http::serialize_header_map\\\"\",\"deserialize_with = \\\"fp_bindgen_support::http::deserialize_header_map\\\"\"],\"ts_ty\":\"HeaderMap\",\"ts_declaration\":\"{ [key: string]: Uint8Array }\"}}],[{\"name\":\"i128\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"i128\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"i128\",\"serde_attrs\":[\"with = \\\"fp_bindgen_support::common::serde_str\\\"\"],\"ts_ty\":\"I128String\",\"ts_declaration\":\"string & { readonly __brand: \\\"i128\\\" }\"}}],[{\"name\":\"i16\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"I16\"}],[{\"name\":\"i16\",\"generic_args\":[],\"array\":3},{\"Array\":[\"I16\",3]}],[{\"name\":\"i32\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"I32\"}],[{\"name\":\"i32\",\"generic_args\":[],\"array\":3},{\"Array\":[\"I32\",3]}],[{\"name\":\"i64\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"I64\"}],[{\"name\":\"i8\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"I8\"}],[{\"name\":\"i8\",\"generic_args\":[],\"array\":3},{\"Array\":[\"I8\",3]}],[{\"name\":\"u128\",\"generic_args\":[],\"array\":null},{\"Custom\":{\"ident\":{\"name\":\"u128\",\"generic_args\":[],\"array\":null},\"rs_ty\":\"u128\",\"serde_attrs\":[\"with = \\\"fp_bindgen_support::common::serde_str\\\"\"],\"ts_ty\":\"U128String\",\"ts_declaration\":\"string & { readonly __brand: \\\"u128\\\" }\"}}],[{\"name\":\"u16\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"U16\"}],[{\"name\":\"u16\",\"generic_args\":[],\"array\":3},{\"Array\":[\"U16\",3]}],[{\"name\":\"u32\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"U32\"}],[{\"name\":\"u32\",\"generic_args\":[],\"array\":3},{\"Array\":[\"U32\",3]}],[{\"name\":\"u64\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"U64\"}],[{\"name\":\"u8\",\"generic_args\":[],\"array\":null},{\"Primitive\":\"U8\"}],[{\"name\":\"u8\",\"generic_args\":[],\"array\":3},{\"Array\":[\"U8\",3]}]]}";
@@ -0,0 +1,311 @@
+// The stubs below leave their arguments unused until they are implemented:
+#![allow(unused_variables)]
+
+use example_bindings::*;
+
+#[fp_export_impl(example_bindings)]
+fn export_array_f32(arg: [f32; 3]) -> [f32; 3] {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_array_f64(arg: [f64; 3]) -> [f64; 3] {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_array_i16(arg: [i16; 3]) -> [i16; 3] {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_array_i32(arg: [i32; 3]) -> [i32; 3] {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_array_i8(arg: [i8; 3]) -> [i8; 3] {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_array_u16(arg: [u16; 3]) -> [u16; 3] {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_array_u32(arg: [u32; 3]) -> [u32; 3] {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_array_u8(arg: [u8; 3]) -> [u8; 3] {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+async fn export_async_struct(arg1: FpPropertyRenaming, arg2: u64) -> FpPropertyRenaming {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_batch_response(arg: BatchResponse) -> BatchResponse {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_cached_call_count(label: String) -> String {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_call_rate_limited_import(calls: u32) -> Vec<Result<String, RateLimited>> {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_collections(arg: Collections) -> Collections {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_dynamic_value(arg: ExtensionSettings) -> serde_json::Value {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_edge_values(arg: EdgeValues) -> EdgeValues {
+    todo!()
+}
+
+#[cfg(feature = "experimental")]
+#[fp_export_impl(example_bindings)]
+fn export_experimental(arg: String) -> String {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_fp_adjacently_tagged(arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_fp_enum(arg: FpVariantRenaming) -> FpVariantRenaming {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_fp_flatten(arg: FpFlatten) -> FpFlatten {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_fp_internally_tagged(arg: FpInternallyTagged) -> FpInternallyTagged {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_fp_struct(arg: FpPropertyRenaming) -> FpPropertyRenaming {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_fp_untagged(arg: FpUntagged) -> FpUntagged {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_generics(arg: StructWithGenerics<u64>) -> StructWithGenerics<u64> {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_get_bytes() -> Result<bytes::Bytes, String> {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_get_serde_bytes() -> Result<serde_bytes::ByteBuf, String> {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_lazy_string(arg: String) -> String {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_metrics(metrics: Vec<Metric>) -> Vec<Metric> {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_multiple_primitives(arg1: i8, arg2: String) -> i64 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_multiple_values(arg: u32) -> (String, Vec<u32>, bool) {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_bool(arg: bool) -> bool {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_f32(arg: f32) -> f32 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_f64(arg: f64) -> f64 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_i16(arg: i16) -> i16 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_i32(arg: i32) -> i32 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_i64(arg: i64) -> i64 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_i8(arg: i8) -> i8 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_pair(arg: u32) -> (u32, i32) {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_u16(arg: u16) -> u16 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_u32(arg: u32) -> u32 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_u64(arg: u64) -> u64 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_u8(arg: u8) -> u8 {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings, rename = "export_legacy_string")]
+fn export_renamed_string(arg: String) -> String {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_samples(samples: SampleColumns) -> SampleColumns {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_serde_adjacently_tagged(arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_serde_enum(arg: SerdeVariantRenaming) -> SerdeVariantRenaming {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_serde_flatten(arg: SerdeFlatten) -> SerdeFlatten {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_serde_internally_tagged(arg: SerdeInternallyTagged) -> SerdeInternallyTagged {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_serde_struct(arg: SerdePropertyRenaming) -> SerdePropertyRenaming {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_serde_untagged(arg: SerdeUntagged) -> SerdeUntagged {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_string(arg: String) -> String {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_string_after_memory_growth(arg: String) -> String {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_struct_with_options(arg: StructWithOptions) -> StructWithOptions {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_timestamp(arg: MyDateTime) -> MyDateTime {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_timestamps(arg: Timestamps) -> Timestamps {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_typed_id(id: TypedId<Point<f64>>) -> TypedId<Point<f64>> {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_validated_struct(arg: ValidatedStruct) -> ValidatedStruct {
+    todo!()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_void_function() {
+    todo!()
+}
+
+/// Example how plugin could expose async data-fetching capabilities.
+#[fp_export_impl(example_bindings)]
+async fn fetch_data(r#type: String) -> Result<String, String> {
+    todo!()
+}
+
+/// Called on the plugin to give it a chance to initialize.
+#[fp_export_impl(example_bindings)]
+fn init(config: PluginConfig) {}
+
+/// Example how plugin could expose a reducer.
+#[fp_export_impl(example_bindings)]
+fn reducer_bridge(action: ReduxAction) -> StateUpdate {
+    todo!()
+}
+
+/// Called on the plugin before it is discarded, to give it a chance to
+/// clean up.
+#[fp_export_impl(example_bindings)]
+fn shutdown() {}
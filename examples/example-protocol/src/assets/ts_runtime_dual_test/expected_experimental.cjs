@@ -0,0 +1,53 @@
+// ============================================= //
+// Feature `experimental`                        //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any no-unused-vars
+"use strict";
+
+const { createAsyncValue, exportToMemory, FPRuntimeError, getExport, hasExport, importFromMemory, interpretBigSign, interpretSign, parseObject, promiseFromPtr, resolveFuture, serializeObject, f32FromBits, f32ToBits, packPair, unpackPair, cachedExport, clearCachedExports, rateLimit } = require("./memory.cjs");
+const { validateValidatedChild, validateValidatedStruct } = require("./validation.cjs");
+const { fromInterned, internString, mapArray, mapNullable, mapRecord, toDate, toSet, convertMetric, convertTimestamps } = require("./conversions.cjs");
+
+/**
+ * Creates the functions that are imported by plugins that were compiled with
+ * the `experimental` feature, which call the given host functions.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @returns The imports to pass to `instantiatePlugin()` or `createRuntime()`.
+ */
+function createExperimentalImports(importFunctions) {
+    return (plugin) => ({
+        __fp_gen_import_experimental: (arg_ptr) => {
+            const arg = parseObject(plugin, arg_ptr);
+            return serializeObject(plugin, importFunctions.importExperimental(arg));
+        },
+    });
+}
+
+/**
+ * Returns the functions that are exported by the given plugin instance, if it
+ * was compiled with the `experimental` feature.
+ *
+ * Functions that the plugin does not implement are `undefined`.
+ */
+function createExperimentalExports(plugin) {
+    return {
+        exportExperimental: hasExport(plugin, "__fp_gen_export_experimental")
+            ? (arg) => exportExperimental(plugin, arg)
+            : undefined,
+    };
+}
+
+function exportExperimental(plugin, arg) {
+    return callExport("exportExperimental", "string", () => {
+        const export_fn = getExport(plugin, "__fp_gen_export_experimental", 1);
+        const arg_ptr = serializeObject(plugin, arg);
+        return parseObject(plugin, export_fn(arg_ptr));
+    });
+}
+
+exports.createExperimentalImports = createExperimentalImports;
+exports.createExperimentalExports = createExperimentalExports;
+exports.exportExperimental = exportExperimental;
@@ -0,0 +1,77 @@
+// ============================================= //
+// Types for WebAssembly runtime                 //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+"use strict";
+
+const ConditionalVariants = Object.freeze({
+    Stable: "Stable",
+});
+
+const Priority = Object.freeze({
+    Low: "low",
+    Normal: "normal",
+    High: "urgent",
+});
+
+const SCHEMA_HASHES = Object.freeze({
+    ArbitraryPrecisionNumbers: "db827ee0c172d54b",
+    BatchResponse: "d01a07281c913349",
+    Body: "4cca30865b1b5b29",
+    Collections: "0c2e6754c3da68fb",
+    ConditionalFields: "52a7d135f4e752db",
+    ConditionalVariants: "ad94250852394a2c",
+    CustomSerializers: "3995cbdfb49e6e86",
+    DocExampleEnum: "8a247500e246a101",
+    DocExampleStruct: "4d63c33e3821db51",
+    EdgeValues: "3a668df6dcc030ca",
+    ExplicitBoundPoint: "01ab567ecc0d57ce",
+    ExplicitedlyImportedType: "870486c71032cd06",
+    ExtensionSettings: "dd7f1ec765e3177b",
+    FlattenedStruct: "3309edb927ca179e",
+    FloatingPoint: "72901021b02749a4",
+    FpAdjacentlyTagged: "606232f618d7e394",
+    FpFlatten: "46a49ef4fae36d25",
+    FpInternallyTagged: "5f9268fae07343f1",
+    FpPropertyRenaming: "9ceeeeb504a46f92",
+    FpUntagged: "17e08f97b5746b44",
+    FpVariantRenaming: "874c2a453f4dee88",
+    GroupImportedType1: "870486c71032cd06",
+    GroupImportedType2: "870486c71032cd06",
+    HttpResult: "0392c49138515915",
+    Int64: "4d35d3193e8d66f2",
+    IntegerTypes: "28d6bbb19affb584",
+    Metric: "4b59598250e00632",
+    MyDateTime: "909e6a1dd3c1b75c",
+    OperationResult: "36591b64e87bbfd1",
+    PluginConfig: "058a22f99a2bf4c8",
+    Point: "01ab567ecc0d57ce",
+    Priority: "4e502f2d76067ab7",
+    Progress: "78c3c560d6cc3996",
+    RateLimited: "53e94d7f4ad4119a",
+    ReduxAction: "0352d4c53497dc88",
+    Request: "f5b3e2e3135a53a4",
+    RequestError: "52827bfb0a750040",
+    Response: "600ce53352d19a88",
+    Result: "0a54d7094a2e3b35",
+    Sample: "6abd8e557be778d8",
+    SerdeAdjacentlyTagged: "d8c14e6783129ce6",
+    SerdeFlatten: "46a49ef4fae36d25",
+    SerdeInternallyTagged: "5f9268fae07343f1",
+    SerdePropertyRenaming: "9ceeeeb504a46f92",
+    SerdeUntagged: "4b76b5be929dd50f",
+    SerdeVariantRenaming: "a60f3b2a9bc7e9ab",
+    StateUpdate: "3669f62b5f03b9d1",
+    StructWithGenerics: "b93cc0c519e88f8a",
+    StructWithOptions: "bd9f146b3efd1e10",
+    Timestamps: "585a6e28d555d1e8",
+    TypedId: "9ef66fb446ba7f9c",
+    UserId: "b880dc810346cbbd",
+    ValidatedChild: "7d2ecd620ba22cb3",
+    ValidatedStruct: "229c94022f7b70c3",
+});
+
+exports.ConditionalVariants = ConditionalVariants;
+exports.Priority = Priority;
+exports.SCHEMA_HASHES = SCHEMA_HASHES;
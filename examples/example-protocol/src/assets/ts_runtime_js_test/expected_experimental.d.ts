@@ -0,0 +1,38 @@
+// ============================================= //
+// Feature `experimental`                        //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import type * as types from "./types.js";
+import type { FeatureImports, PluginInstance } from "./index.js";
+
+export type ExperimentalImports = {
+    importExperimental: (arg: string) => string;
+};
+
+/**
+ * Creates the functions that are imported by plugins that were compiled with
+ * the `experimental` feature, which call the given host functions.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @returns The imports to pass to `instantiatePlugin()` or `createRuntime()`.
+ */
+export declare function createExperimentalImports(importFunctions: ExperimentalImports): FeatureImports;
+
+export type ExperimentalExports = {
+    exportExperimental?: (arg: string) => string;
+    exportExperimentalRaw?: (arg: Uint8Array) => Uint8Array;
+};
+
+/**
+ * Returns the functions that are exported by the given plugin instance, if it
+ * was compiled with the `experimental` feature.
+ *
+ * Functions that the plugin does not implement are `undefined`.
+ */
+export declare function createExperimentalExports(plugin: PluginInstance): ExperimentalExports;
+
+export declare function exportExperimental(plugin: PluginInstance, arg: string): string;
+
+export declare function exportExperimentalRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
@@ -0,0 +1,79 @@
+// ============================================= //
+// Feature `experimental`                        //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any no-unused-vars
+
+import {
+    createAsyncValue,
+    exportToMemory,
+    FPRuntimeError,
+    getExport,
+    hasExport,
+    importFromMemory,
+    interpretBigSign,
+    interpretSign,
+    parseObject,
+    promiseFromPtr,
+    resolveFuture,
+    serializeObject,
+    f32FromBits,
+    f32ToBits,
+    packPair,
+    unpackPair,
+    fromInt64String,
+    cachedExport,
+    clearCachedExports,
+    rateLimit,
+    traceGuestPayload,
+} from "./memory.js";
+import { validateValidatedChild, validateValidatedStruct } from "./validation.js";
+import { fromInterned, internString, mapArray, mapNullable, mapRecord, toDate, toSet, convertCollections, convertMetric, convertTimestamps } from "./conversions.js";
+
+/**
+ * Creates the functions that are imported by plugins that were compiled with
+ * the `experimental` feature, which call the given host functions.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @returns The imports to pass to `instantiatePlugin()` or `createRuntime()`.
+ */
+export function createExperimentalImports(importFunctions) {
+    return (plugin) => ({
+        __fp_gen_import_experimental: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_experimental", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_experimental", "result", "toPlugin", serializeObject(plugin, importFunctions.importExperimental(arg)));
+        },
+    });
+}
+
+/**
+ * Returns the functions that are exported by the given plugin instance, if it
+ * was compiled with the `experimental` feature.
+ *
+ * Functions that the plugin does not implement are `undefined`.
+ */
+export function createExperimentalExports(plugin) {
+    return {
+        exportExperimental: hasExport(plugin, "__fp_gen_export_experimental")
+            ? (arg) => exportExperimental(plugin, arg)
+            : undefined,
+        exportExperimentalRaw: hasExport(plugin, "__fp_gen_export_experimental")
+            ? (arg) => exportExperimentalRaw(plugin, arg)
+            : undefined,
+    };
+}
+
+export function exportExperimental(plugin, arg) {
+    return callExport("exportExperimental", "string", () => {
+        const export_fn = getExport(plugin, "__fp_gen_export_experimental", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_experimental", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject(plugin, traceGuestPayload(plugin, "export_experimental", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportExperimentalRaw(plugin, arg) {
+    const export_fn = getExport(plugin, "__fp_gen_export_experimental");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
@@ -0,0 +1,478 @@
+// ============================================= //
+// Imports for WebAssembly runtime               //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any no-unused-vars
+
+import {
+    createAsyncValue,
+    FPRuntimeError,
+    getDynamicImportHandlers,
+    getEventHandlers,
+    interpretBigSign,
+    interpretSign,
+    parseObject,
+    resolveFuture,
+    resolvePromise,
+    serializeObject,
+    throwGuestError,
+    f32ToBits,
+    packPair,
+    fromInt64String,
+    cachedExport,
+    clearCachedExports,
+    rateLimit,
+    traceGuestPayload,
+} from "./memory.js";
+import { validateValidatedChild, validateValidatedStruct } from "./validation.js";
+import { fromInterned, internString, mapArray, mapNullable, mapRecord, toDate, toSet, convertCollections, convertMetric, convertTimestamps } from "./conversions.js";
+
+/**
+ * A capability that can be granted to the plugin. Imports that require a
+ * capability can only be called by the plugin if it was granted.
+ */
+
+/**
+ * Thrown when the plugin calls an import that requires a capability that was
+ * not granted to it.
+ */
+export class PermissionDeniedError extends FPRuntimeError {
+
+    constructor(functionName, capability) {
+        super(`Import "${functionName}" requires the "${capability}" capability, which was not granted`);
+        this.functionName = functionName;
+        this.capability = capability;
+    }
+}
+
+/**
+ * The host facilities that are exposed to the plugin through the built-in std
+ * imports, which require the "fs", "clock" and "random" capabilities.
+ *
+ * Files are read from and written to the `files` map, keyed by their path. If
+ * no map is given, the plugin cannot access any files. By default, the clock
+ * uses `Date.now()`, the monotonic clock uses `performance.now()` and random
+ * bytes are generated using `crypto.getRandomValues()`. Both clocks return
+ * milliseconds.
+ */
+
+function checkStdPath(files, path) {
+    if (!files) {
+        return { type: "unsupported" };
+    }
+    // Only relative paths without `.` and `..` components are accepted, like
+    // in the Rust runtime:
+    if (path.split("/").some((component) => component === "" || component === "." || component === "..")) {
+        return { type: "invalid_path" };
+    }
+    return undefined;
+}
+
+function readStdFile(files, path) {
+    const error = checkStdPath(files, path);
+    if (error) {
+        return { Err: error };
+    }
+    const contents = files?.get(path);
+    return contents ? { Ok: contents } : { Err: { type: "not_found" } };
+}
+
+function writeStdFile(files, path, contents) {
+    const error = checkStdPath(files, path);
+    if (error) {
+        return { Err: error };
+    }
+    return { Ok: null };
+}
+
+function randomStdBytes(len) {
+    const bytes = new Uint8Array(len);
+    // `getRandomValues()` fills at most 65536 bytes at a time:
+    for (let offset = 0; offset < len; offset += 65536) {
+        crypto.getRandomValues(bytes.subarray(offset, offset + 65536));
+    }
+    return bytes;
+}
+
+/**
+ * A request that the plugin makes through the built-in HTTP imports, which
+ * require the "http" capability. Headers are name-value pairs.
+ */
+
+/**
+ * The response to a request that the plugin made through the built-in HTTP
+ * imports.
+ */
+
+/**
+ * The host facilities that are exposed to the plugin through the built-in
+ * HTTP imports.
+ *
+ * By default, requests are sent using the global `fetch()`. Hosts can pass
+ * their own implementation, for instance to restrict the URLs the plugin may
+ * access, or to mock requests in tests.
+ */
+
+async function sendHttpRequest(fetchImpl, request) {
+    let fetchRequest;
+    try {
+        fetchRequest = new Request(request.url, {
+            method: request.method,
+            headers: request.headers,
+            body: request.body,
+        });
+    } catch (error) {
+        return { Err: { type: "invalid_request", message: String(error) } };
+    }
+    try {
+        const response = await fetchImpl(fetchRequest);
+        const body = new Uint8Array(await response.arrayBuffer());
+        return { Ok: { status: response.status, headers: [...response.headers], body } };
+    } catch (error) {
+        if (error instanceof DOMException && error.name === "TimeoutError") {
+            return { Err: { type: "timeout" } };
+        }
+        const type = error instanceof TypeError ? "connection" : "other";
+        return { Err: { type, message: String(error) } };
+    }
+}
+
+/**
+ * Creates the functions that are imported by the plugin, which call the given
+ * host functions.
+ *
+ * @param plugin The plugin instance for which the imports are created. It only
+ *               needs to be initialized by the time the plugin calls them.
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @param capabilities The capabilities that are granted to the plugin.
+ * @returns The functions that are imported by the plugin from the `fp` module.
+ */
+export function createImports(
+    plugin,
+    importFunctions,
+    capabilities = []
+) {
+    const grantedCapabilities = new Set(capabilities);
+
+    function checkCapability(functionName, capability) {
+        if (!grantedCapabilities.has(capability)) {
+            throw new PermissionDeniedError(functionName, capability);
+        }
+    }
+
+    return {
+        __fp_gen_import_arbitrary_precision_numbers: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_arbitrary_precision_numbers", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_arbitrary_precision_numbers", "result", "toPlugin", serializeObject(plugin, importFunctions.importArbitraryPrecisionNumbers(arg)));
+        },
+        __fp_gen_import_array_f32: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_array_f32", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_f32", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayF32(arg)));
+        },
+        __fp_gen_import_array_f64: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_array_f64", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_f64", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayF64(arg)));
+        },
+        __fp_gen_import_array_i16: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_array_i16", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_i16", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayI16(arg)));
+        },
+        __fp_gen_import_array_i32: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_array_i32", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_i32", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayI32(arg)));
+        },
+        __fp_gen_import_array_i8: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_array_i8", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_i8", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayI8(arg)));
+        },
+        __fp_gen_import_array_u16: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_array_u16", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_u16", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayU16(arg)));
+        },
+        __fp_gen_import_array_u32: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_array_u32", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_u32", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayU32(arg)));
+        },
+        __fp_gen_import_array_u8: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_array_u8", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_u8", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayU8(arg)));
+        },
+        __fp_gen_import_async_void_function: (message_ptr) => {
+            const message = parseObject(plugin, traceGuestPayload(plugin, "import_async_void_function", "message", "fromPlugin", message_ptr));
+            importFunctions.importAsyncVoidFunction(message).catch((error) => {
+                console.error(
+                    'Unrecoverable exception trying to call async host function "import_async_void_function"',
+                    error
+                );
+            });
+        },
+        __fp_gen_import_collections: (arg_ptr) => {
+            const arg = convertCollections(parseObject(plugin, traceGuestPayload(plugin, "import_collections", "arg", "fromPlugin", arg_ptr)));
+            return traceGuestPayload(plugin, "import_collections", "result", "toPlugin", serializeObject(plugin, importFunctions.importCollections(arg)));
+        },
+        __fp_gen_import_explicit_bound_point: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_explicit_bound_point", "arg", "fromPlugin", arg_ptr));
+            importFunctions.importExplicitBoundPoint(arg);
+        },
+        __fp_gen_import_fp_adjacently_tagged: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_fp_adjacently_tagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_adjacently_tagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpAdjacentlyTagged(arg)));
+        },
+        __fp_gen_import_fp_enum: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_fp_enum", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_enum", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpEnum(arg)));
+        },
+        __fp_gen_import_fp_flatten: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_fp_flatten", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_flatten", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpFlatten(arg)));
+        },
+        __fp_gen_import_fp_internally_tagged: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_fp_internally_tagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_internally_tagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpInternallyTagged(arg)));
+        },
+        __fp_gen_import_fp_struct: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_fp_struct", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_struct", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpStruct(arg)));
+        },
+        __fp_gen_import_fp_untagged: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_fp_untagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_untagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpUntagged(arg)));
+        },
+        __fp_gen_import_generics: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_generics", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_generics", "result", "toPlugin", serializeObject(plugin, importFunctions.importGenerics(arg)));
+        },
+        __fp_gen_import_get_bytes: () => {
+            return traceGuestPayload(plugin, "import_get_bytes", "result", "toPlugin", serializeObject(plugin, importFunctions.importGetBytes()));
+        },
+        __fp_gen_import_get_serde_bytes: () => {
+            return traceGuestPayload(plugin, "import_get_serde_bytes", "result", "toPlugin", serializeObject(plugin, importFunctions.importGetSerdeBytes()));
+        },
+        __fp_gen_import_get_value: (key_ptr) => {
+            const key = parseObject(plugin, traceGuestPayload(plugin, "import_get_value", "key", "fromPlugin", key_ptr));
+            const _async_result_ptr = createAsyncValue(plugin);
+            importFunctions.importGetValue(key)
+                .then((result) => {
+                    resolveFuture(plugin, _async_result_ptr, traceGuestPayload(plugin, "import_get_value", "result", "toPlugin", serializeObject(plugin, result)));
+                })
+                .catch((error) => {
+                    console.error(
+                        'Unrecoverable exception trying to call async host function "import_get_value"',
+                        error
+                    );
+                });
+            return _async_result_ptr;
+        },
+        __fp_gen_import_integer_types: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_integer_types", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_integer_types", "result", "toPlugin", serializeObject(plugin, importFunctions.importIntegerTypes(arg)));
+        },
+        __fp_gen_import_multiple_primitives: (arg1, arg2_ptr) => {
+            const arg2 = parseObject(plugin, traceGuestPayload(plugin, "import_multiple_primitives", "arg2", "fromPlugin", arg2_ptr));
+            return fromInt64String(importFunctions.importMultiplePrimitives(arg1, arg2), true);
+        },
+        __fp_gen_import_multiple_values: (arg) => {
+            return traceGuestPayload(plugin, "import_multiple_values", "result", "toPlugin", serializeObject(plugin, importFunctions.importMultipleValues(arg)));
+        },
+        __fp_gen_import_primitive_bool: (arg) => {
+            return !!importFunctions.importPrimitiveBool(arg);
+        },
+        __fp_gen_import_primitive_f32: (arg) => {
+            return importFunctions.importPrimitiveF32(arg);
+        },
+        __fp_gen_import_primitive_f64: (arg) => {
+            return importFunctions.importPrimitiveF64(arg);
+        },
+        __fp_gen_import_primitive_i16: (arg) => {
+            return interpretSign(importFunctions.importPrimitiveI16(arg), 32768);
+        },
+        __fp_gen_import_primitive_i32: (arg) => {
+            return interpretSign(importFunctions.importPrimitiveI32(arg), 2147483648);
+        },
+        __fp_gen_import_primitive_i64: (arg) => {
+            return fromInt64String(importFunctions.importPrimitiveI64(arg.toString()), true);
+        },
+        __fp_gen_import_primitive_i8: (arg) => {
+            return interpretSign(importFunctions.importPrimitiveI8(arg), 128);
+        },
+        __fp_gen_import_primitive_pair: (arg) => {
+            return packPair(importFunctions.importPrimitivePair(arg), (value) => value, (value) => value);
+        },
+        __fp_gen_import_primitive_u16: (arg) => {
+            return importFunctions.importPrimitiveU16(arg);
+        },
+        __fp_gen_import_primitive_u32: (arg) => {
+            return importFunctions.importPrimitiveU32(arg);
+        },
+        __fp_gen_import_primitive_u64: (arg) => {
+            return fromInt64String(importFunctions.importPrimitiveU64(BigInt.asUintN(64, arg).toString()), false);
+        },
+        __fp_gen_import_primitive_u8: (arg) => {
+            return importFunctions.importPrimitiveU8(arg);
+        },
+        __fp_gen_import_rate_limited: (label_ptr) => {
+            const _rateLimited = rateLimit(plugin, "import_rate_limited", 2, 60000);
+            const label = parseObject(plugin, traceGuestPayload(plugin, "import_rate_limited", "label", "fromPlugin", label_ptr));
+            return traceGuestPayload(plugin, "import_rate_limited", "result", "toPlugin", serializeObject(plugin, _rateLimited ?? importFunctions.importRateLimited(label)));
+        },
+        __fp_gen_import_legacy_string: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_renamed_string", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_renamed_string", "result", "toPlugin", serializeObject(plugin, importFunctions.importRenamedString(arg)));
+        },
+        __fp_gen_import_serde_adjacently_tagged: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_serde_adjacently_tagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_adjacently_tagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeAdjacentlyTagged(arg)));
+        },
+        __fp_gen_import_serde_enum: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_serde_enum", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_enum", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeEnum(arg)));
+        },
+        __fp_gen_import_serde_flatten: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_serde_flatten", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_flatten", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeFlatten(arg)));
+        },
+        __fp_gen_import_serde_internally_tagged: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_serde_internally_tagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_internally_tagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeInternallyTagged(arg)));
+        },
+        __fp_gen_import_serde_struct: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_serde_struct", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_struct", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeStruct(arg)));
+        },
+        __fp_gen_import_serde_untagged: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_serde_untagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_untagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeUntagged(arg)));
+        },
+        __fp_gen_import_set_value: (key_ptr, value_ptr) => {
+            const key = parseObject(plugin, traceGuestPayload(plugin, "import_set_value", "key", "fromPlugin", key_ptr));
+            const value = parseObject(plugin, traceGuestPayload(plugin, "import_set_value", "value", "fromPlugin", value_ptr));
+            importFunctions.importSetValue(key, value);
+        },
+        __fp_gen_import_string: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_string", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_string", "result", "toPlugin", serializeObject(plugin, importFunctions.importString(arg)));
+        },
+        __fp_gen_import_struct_with_options: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_struct_with_options", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_struct_with_options", "result", "toPlugin", serializeObject(plugin, importFunctions.importStructWithOptions(arg)));
+        },
+        __fp_gen_import_timestamp: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_timestamp", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_timestamp", "result", "toPlugin", serializeObject(plugin, importFunctions.importTimestamp(arg)));
+        },
+        __fp_gen_import_validated_struct: (arg_ptr) => {
+            const arg = parseObject(plugin, traceGuestPayload(plugin, "import_validated_struct", "arg", "fromPlugin", arg_ptr));
+            validateValidatedStruct(arg);
+            return traceGuestPayload(plugin, "import_validated_struct", "result", "toPlugin", serializeObject(plugin, importFunctions.importValidatedStruct(arg)));
+        },
+        __fp_gen_import_void_function: () => {
+            importFunctions.importVoidFunction();
+        },
+        __fp_gen_import_void_function_empty_result: () => {
+            return traceGuestPayload(plugin, "import_void_function_empty_result", "result", "toPlugin", serializeObject(plugin, importFunctions.importVoidFunctionEmptyResult()));
+        },
+        __fp_gen_import_void_function_empty_return: () => {
+            importFunctions.importVoidFunctionEmptyReturn();
+        },
+        __fp_gen_invoke_command: (name_ptr, payload_ptr) => {
+            const name = parseObject(plugin, name_ptr);
+            const payload = parseObject(plugin, payload_ptr);
+            const handler = getDynamicImportHandlers(plugin, "invoke_command").get(name);
+            const result = handler
+                ? handler(payload)
+                : importFunctions.invokeCommand(name, payload);
+            return serializeObject(plugin, result);
+        },
+        __fp_gen_log: (message_ptr) => {
+            const message = parseObject(plugin, traceGuestPayload(plugin, "log", "message", "fromPlugin", message_ptr));
+            importFunctions.log(message);
+        },
+        __fp_gen_make_http_request: (request_ptr) => {
+            checkCapability("make_http_request", "network");
+            const request = parseObject(plugin, traceGuestPayload(plugin, "make_http_request", "request", "fromPlugin", request_ptr));
+            const _async_result_ptr = createAsyncValue(plugin);
+            importFunctions.makeHttpRequest(request)
+                .then((result) => {
+                    resolveFuture(plugin, _async_result_ptr, traceGuestPayload(plugin, "make_http_request", "result", "toPlugin", serializeObject(plugin, result)));
+                })
+                .catch((error) => {
+                    console.error(
+                        'Unrecoverable exception trying to call async host function "make_http_request"',
+                        error
+                    );
+                });
+            return _async_result_ptr;
+        },
+        __fp_gen_record_metric: (name_ptr, value) => {
+            const name = parseObject(plugin, traceGuestPayload(plugin, "record_metric", "name", "fromPlugin", name_ptr));
+            importFunctions.recordMetric(name, value);
+        },
+        __fp_gen_report_progress: (progress_ptr) => {
+            const progress = parseObject(plugin, progress_ptr);
+            for (const handler of getEventHandlers(plugin, "reportProgress")) {
+                handler(progress);
+            }
+        },
+        __fp_host_batch: (calls_ptr) => {
+            const calls = parseObject(plugin, calls_ptr);
+            for (const call of calls) {
+                const [name, args] = Object.entries(call)[0];
+                switch (name) {
+                    case "record_metric":
+                        importFunctions.recordMetric(args[0], args[1]);
+                        break;
+                }
+            }
+        },
+        __fp_std_read_file: (path_ptr) => {
+            checkCapability("read_file", "fs");
+            const path = parseObject(plugin, path_ptr);
+            return serializeObject(plugin, readStdFile(importFunctions.std?.files, path));
+        },
+        __fp_std_write_file: (path_ptr, contents_ptr) => {
+            checkCapability("write_file", "fs");
+            const path = parseObject(plugin, path_ptr);
+            const contents = parseObject(plugin, contents_ptr);
+            return serializeObject(plugin, writeStdFile(importFunctions.std?.files, path, contents));
+        },
+        __fp_std_now: () => {
+            checkCapability("now", "clock");
+            return BigInt(Math.floor(importFunctions.std?.now?.() ?? Date.now()));
+        },
+        __fp_std_monotonic_now: () => {
+            checkCapability("monotonic_now", "clock");
+            const millis = importFunctions.std?.monotonicNow?.() ?? performance.now();
+            return BigInt(Math.floor(millis * 1_000_000));
+        },
+        __fp_std_random_bytes: (len) => {
+            checkCapability("random_bytes", "random");
+            const randomBytes = importFunctions.std?.randomBytes ?? randomStdBytes;
+            return serializeObject(plugin, randomBytes(len));
+        },
+        __fp_http_request: (request_ptr) => {
+            checkCapability("http_request", "http");
+            const request = parseObject(plugin, request_ptr);
+            const _async_result_ptr = createAsyncValue(plugin);
+            sendHttpRequest(importFunctions.http?.fetch ?? fetch, request).then((result) => {
+                resolveFuture(plugin, _async_result_ptr, serializeObject(plugin, result));
+            });
+            return _async_result_ptr;
+        },
+        __fp_intern_string: (value_ptr) =>
+            internString(parseObject(plugin, value_ptr)),
+        __fp_host_print: (stream, line_ptr) => {
+            const line = parseObject(plugin, line_ptr);
+            if (importFunctions.onPrint) {
+                importFunctions.onPrint(stream === 2 ? "stderr" : "stdout", line);
+            } else if (stream === 2) {
+                console.error(line);
+            } else {
+                console.log(line);
+            }
+        },
+        __fp_error: (report_ptr) => throwGuestError(plugin, report_ptr),
+        __fp_host_resolve_async_value: (asyncValuePtr, resultPtr) =>
+    resolvePromise(plugin, asyncValuePtr, resultPtr),
+    };
+}
@@ -0,0 +1,968 @@
+// ============================================= //
+// WebAssembly runtime for TypeScript            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import type { decode, encode } from "https://esm.sh/@msgpack/msgpack@2.7.2";
+import type * as types from "./types.js";
+
+export type FatPtr = bigint;
+
+/**
+ * Options for encoding the values that are passed to the plugin and decoding
+ * the values that are received from it, such as an `extensionCodec` with the
+ * MessagePack extensions that the plugin uses, or limits like `maxStrLength`.
+ */
+export type MsgpackOptions = NonNullable<Parameters<typeof encode>[1]> &
+    NonNullable<Parameters<typeof decode>[1]>;
+
+/**
+ * Options for instantiating a plugin.
+ */
+export type RuntimeOptions = {
+    msgpack?: MsgpackOptions;
+    /**
+     * Logs the serialized payloads that are passed between the runtime and the
+     * plugin, for debugging protocol mismatches. If omitted, payloads are only
+     * logged if the `FP_BINDGEN_TRACE_PAYLOADS` environment variable is set to
+     * `hex`, `base64` or `decoded`.
+     */
+    tracePayloads?: boolean | PayloadTraceOptions;
+};
+
+/**
+ * A promise that is waiting for an async value of the plugin to be resolved.
+ */
+export type PendingPromise = {
+    resolve: (result: FatPtr) => void;
+    reject: (error: unknown) => void;
+};
+
+/**
+ * A plugin that was instantiated using `instantiatePlugin()`.
+ *
+ * The functions that call the exports of the plugin take the instance as their
+ * first argument, so that bundlers can drop the functions that are not used.
+ */
+export type PluginInstance = {
+    instance: WebAssembly.Instance;
+    memory: WebAssembly.Memory;
+    malloc: (len: number) => FatPtr;
+    free: (ptr: FatPtr) => void;
+    msgpackOptions: MsgpackOptions;
+    promises: Map<FatPtr, PendingPromise | FatPtr | GuestError>;
+    dynamicImportHandlers: Map<string, Map<string, (payload: any) => any>>;
+    eventHandlers: Map<string, Set<(event: any) => void>>;
+    disposed: boolean;
+    payloadTraceOptions?: PayloadTraceOptions;
+    missingExports?: string[];
+};
+
+/**
+ * Statistics about the memory that is allocated by a plugin.
+ */
+export type MemoryStats = {
+    allocatedBytes: number;
+    peakAllocatedBytes: number;
+    allocationCount: number;
+};
+
+/**
+ * Metrics about the calls that a plugin has made to the imports of the
+ * runtime, as measured by the plugin itself.
+ */
+export type GuestMetrics = {
+    imports: Record<string, ImportMetrics>;
+};
+
+/**
+ * Metrics about the calls that a plugin has made to a single import.
+ * Durations are in nanoseconds.
+ */
+export type ImportMetrics = {
+    callCount: number;
+    totalDurationNanos: number;
+    maxDurationNanos: number;
+};
+
+/**
+ * A machine-readable description of the protocol, as returned by `protocol()`.
+ */
+export type Protocol = {
+    import_functions: ProtocolFunction[];
+    export_functions: ProtocolFunction[];
+    /**
+     * Pairs of type identifiers and the definitions of the types they refer to.
+     */
+    types: Array<[ProtocolTypeIdent, unknown]>;
+};
+
+/**
+ * Describes the signature of an imported or exported function.
+ */
+export type ProtocolFunction = {
+    name: string;
+    doc_lines: string[];
+    args: Array<{ name: string; ty: ProtocolTypeIdent }>;
+    return_type: ProtocolTypeIdent | null;
+    is_async: boolean;
+    attrs: Record<string, unknown>;
+};
+
+/**
+ * Identifies a type by its Rust name, along with its generic arguments.
+ */
+export type ProtocolTypeIdent = {
+    name: string;
+    generic_args: Array<[ProtocolTypeIdent, string[]]>;
+    array: number | null;
+};
+
+/**
+ * Creates the imports of a feature for the given plugin instance. These are
+ * returned by the `create*Imports()` function of the module of the feature.
+ */
+export type FeatureImports = (plugin: PluginInstance) => WebAssembly.ModuleImports;
+
+export type Imports = {
+    importArbitraryPrecisionNumbers: (arg: types.ArbitraryPrecisionNumbers) => types.ArbitraryPrecisionNumbers;
+    importArrayF32: (arg: Float32Array) => Float32Array;
+    importArrayF64: (arg: Float64Array) => Float64Array;
+    importArrayI16: (arg: Int16Array) => Int16Array;
+    importArrayI32: (arg: Int32Array) => Int32Array;
+    importArrayI8: (arg: Int8Array) => Int8Array;
+    importArrayU16: (arg: Uint16Array) => Uint16Array;
+    importArrayU32: (arg: Uint32Array) => Uint32Array;
+    importArrayU8: (arg: Uint8Array) => Uint8Array;
+    importAsyncVoidFunction: (message: string) => Promise<void>;
+    importCollections: (arg: types.Collections) => types.Collections;
+    importExplicitBoundPoint: (arg: types.ExplicitBoundPoint<number>) => void;
+    importFpAdjacentlyTagged: (arg: types.FpAdjacentlyTagged) => types.FpAdjacentlyTagged;
+    importFpEnum: (arg: types.FpVariantRenaming) => types.FpVariantRenaming;
+    importFpFlatten: (arg: types.FpFlatten) => types.FpFlatten;
+    importFpInternallyTagged: (arg: types.FpInternallyTagged) => types.FpInternallyTagged;
+    importFpStruct: (arg: types.FpPropertyRenaming) => types.FpPropertyRenaming;
+    importFpUntagged: (arg: types.FpUntagged) => types.FpUntagged;
+    importGenerics: (arg: types.StructWithGenerics<number>) => types.StructWithGenerics<number>;
+    importGetBytes: () => types.Result<Uint8Array, string>;
+    importGetSerdeBytes: () => types.Result<ArrayBuffer, string>;
+    importGetValue: <T>(key: string) => Promise<T | null>;
+    importIntegerTypes: (arg: types.IntegerTypes) => types.IntegerTypes;
+    importMultiplePrimitives: (arg1: number, arg2: string) => string;
+    importMultipleValues: (arg: number) => types.ImportMultipleValuesReturn;
+    importPrimitiveBool: (arg: boolean) => boolean;
+    importPrimitiveF32: (arg: number) => number;
+    importPrimitiveF64: (arg: number) => number;
+    importPrimitiveI16: (arg: number) => number;
+    importPrimitiveI32: (arg: number) => number;
+    importPrimitiveI64: (arg: string) => string;
+    importPrimitiveI8: (arg: number) => number;
+    importPrimitivePair: (arg: number) => [number, number];
+    importPrimitiveU16: (arg: number) => number;
+    importPrimitiveU32: (arg: number) => number;
+    importPrimitiveU64: (arg: string) => string;
+    importPrimitiveU8: (arg: number) => number;
+    importRateLimited: (label: string) => types.Result<string, types.RateLimited>;
+    importRenamedString: (arg: string) => string;
+    importSerdeAdjacentlyTagged: (arg: types.SerdeAdjacentlyTagged) => types.SerdeAdjacentlyTagged;
+    importSerdeEnum: (arg: types.SerdeVariantRenaming) => types.SerdeVariantRenaming;
+    importSerdeFlatten: (arg: types.SerdeFlatten) => types.SerdeFlatten;
+    importSerdeInternallyTagged: (arg: types.SerdeInternallyTagged) => types.SerdeInternallyTagged;
+    importSerdeStruct: (arg: types.SerdePropertyRenaming) => types.SerdePropertyRenaming;
+    importSerdeUntagged: (arg: types.SerdeUntagged) => types.SerdeUntagged;
+    importSetValue: <T>(key: string, value: T) => void;
+    importString: (arg: string) => string;
+    importStructWithOptions: (arg: types.StructWithOptions) => types.StructWithOptions;
+    importTimestamp: (arg: types.MyDateTime) => types.MyDateTime;
+    importValidatedStruct: (arg: types.ValidatedStruct) => types.ValidatedStruct;
+    importVoidFunction: () => void;
+    importVoidFunctionEmptyResult: () => types.Result<void, number>;
+    importVoidFunctionEmptyReturn: () => void;
+    invokeCommand: (name: string, payload: string) => types.Result<string, string>;
+    log: (message: string) => void;
+    makeHttpRequest: (request: types.Request) => Promise<types.HttpResult>;
+    recordMetric: (name: string, value: number) => void;
+    std?: StdImports;
+    http?: HttpImports;
+    onPrint?: (stream: "stdout" | "stderr", line: string) => void;
+};
+
+/**
+ * The events that may be emitted by the plugin, keyed by name.
+ */
+export type Events = {
+    reportProgress: types.Progress;
+};
+
+export type Exports = {
+    exportArrayF32?: (arg: Float32Array) => Float32Array;
+    exportArrayF64?: (arg: Float64Array) => Float64Array;
+    exportArrayI16?: (arg: Int16Array) => Int16Array;
+    exportArrayI32?: (arg: Int32Array) => Int32Array;
+    exportArrayI8?: (arg: Int8Array) => Int8Array;
+    exportArrayU16?: (arg: Uint16Array) => Uint16Array;
+    exportArrayU32?: (arg: Uint32Array) => Uint32Array;
+    exportArrayU8?: (arg: Uint8Array) => Uint8Array;
+    exportAsyncStruct?: (arg1: types.FpPropertyRenaming, arg2: string) => Promise<types.FpPropertyRenaming>;
+    exportBatchResponse?: (arg: types.BatchResponse) => types.BatchResponse;
+    exportCachedCallCount?: (label: string) => string;
+    exportCallRateLimitedImport?: (calls: number) => ReadonlyArray<types.Result<string, types.RateLimited>>;
+    exportCollections?: (arg: types.Collections) => types.Collections;
+    exportDynamicValue?: (arg: types.ExtensionSettings) => unknown;
+    exportEdgeValues?: (arg: types.EdgeValues) => types.EdgeValues;
+    exportFpAdjacentlyTagged?: (arg: types.FpAdjacentlyTagged) => types.FpAdjacentlyTagged;
+    exportFpEnum?: (arg: types.FpVariantRenaming) => types.FpVariantRenaming;
+    exportFpFlatten?: (arg: types.FpFlatten) => types.FpFlatten;
+    exportFpInternallyTagged?: (arg: types.FpInternallyTagged) => types.FpInternallyTagged;
+    exportFpStruct?: (arg: types.FpPropertyRenaming) => types.FpPropertyRenaming;
+    exportFpUntagged?: (arg: types.FpUntagged) => types.FpUntagged;
+    exportGenerics?: (arg: types.StructWithGenerics<number>) => types.StructWithGenerics<number>;
+    exportGetBytes?: () => types.Result<Uint8Array, string>;
+    exportGetSerdeBytes?: () => types.Result<ArrayBuffer, string>;
+    exportLazyString?: (arg: string) => string;
+    exportMetrics?: (metrics: ReadonlyArray<types.Metric>) => ReadonlyArray<types.Metric>;
+    exportMultiplePrimitives?: (arg1: number, arg2: string) => string;
+    exportMultipleValues?: (arg: number) => types.ExportMultipleValuesReturn;
+    exportPrimitiveBool?: (arg: boolean) => boolean;
+    exportPrimitiveF32?: (arg: number) => number;
+    exportPrimitiveF64?: (arg: number) => number;
+    exportPrimitiveI16?: (arg: number) => number;
+    exportPrimitiveI32?: (arg: number) => number;
+    exportPrimitiveI64?: (arg: string) => string;
+    exportPrimitiveI8?: (arg: number) => number;
+    exportPrimitivePair?: (arg: number) => [number, number];
+    exportPrimitiveU16?: (arg: number) => number;
+    exportPrimitiveU32?: (arg: number) => number;
+    exportPrimitiveU64?: (arg: string) => string;
+    exportPrimitiveU8?: (arg: number) => number;
+    exportRenamedString?: (arg: string) => string;
+    exportSamples?: (samples: types.SampleColumns) => types.SampleColumns;
+    exportSerdeAdjacentlyTagged?: (arg: types.SerdeAdjacentlyTagged) => types.SerdeAdjacentlyTagged;
+    exportSerdeEnum?: (arg: types.SerdeVariantRenaming) => types.SerdeVariantRenaming;
+    exportSerdeFlatten?: (arg: types.SerdeFlatten) => types.SerdeFlatten;
+    exportSerdeInternallyTagged?: (arg: types.SerdeInternallyTagged) => types.SerdeInternallyTagged;
+    exportSerdeStruct?: (arg: types.SerdePropertyRenaming) => types.SerdePropertyRenaming;
+    exportSerdeUntagged?: (arg: types.SerdeUntagged) => types.SerdeUntagged;
+    exportString?: (arg: string) => string;
+    exportStringAfterMemoryGrowth?: (arg: string) => string;
+    exportStructWithOptions?: (arg: types.StructWithOptions) => types.StructWithOptions;
+    exportTimestamp?: (arg: types.MyDateTime) => types.MyDateTime;
+    exportTimestamps?: (arg: types.Timestamps) => types.Timestamps;
+    exportTypedId?: (id: types.TypedId<types.Point<number>>) => types.TypedId<types.Point<number>>;
+    exportValidatedStruct?: (arg: types.ValidatedStruct) => types.ValidatedStruct;
+    exportVoidFunction?: () => void;
+    fetchData?: (rType: string) => Promise<types.Result<string, string>>;
+    init?: (config: types.PluginConfig) => void;
+    reducerBridge?: (action: types.ReduxAction) => types.StateUpdate;
+    updateConfig?: (config: types.PluginConfig) => void;
+    registerInvokeCommand: (name: string, handler: (payload: string) => types.Result<string, string>) => void;
+    unregisterInvokeCommand: (name: string) => boolean;
+    on: <E extends keyof Events>(eventName: E, handler: (event: Events[E]) => void) => void;
+    off: <E extends keyof Events>(eventName: E, handler: (event: Events[E]) => void) => boolean;
+    memoryStats?: () => MemoryStats;
+    guestMetrics?: () => GuestMetrics;
+    dispose: () => void;
+    missingExports: () => string[];
+    clearExportCache: () => void;
+    exportArrayF32Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayF64Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayI16Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayI32Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayI8Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayU16Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayU32Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayU8Raw?: (arg: Uint8Array) => Uint8Array;
+    exportAsyncStructRaw?: (arg1: Uint8Array, arg2: bigint) => Promise<Uint8Array>;
+    exportBatchResponseRaw?: (arg: Uint8Array) => Uint8Array;
+    exportCachedCallCountRaw?: (label: Uint8Array) => Uint8Array;
+    exportCallRateLimitedImportRaw?: (calls: number) => Uint8Array;
+    exportCollectionsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportDynamicValueRaw?: (arg: Uint8Array) => Uint8Array;
+    exportEdgeValuesRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpAdjacentlyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpEnumRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpFlattenRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpInternallyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpStructRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpUntaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportGenericsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportGetBytesRaw?: () => Uint8Array;
+    exportGetSerdeBytesRaw?: () => Uint8Array;
+    exportLazyStringRaw?: (arg: Uint8Array) => Uint8Array;
+    exportMetricsRaw?: (metrics: Uint8Array) => Uint8Array;
+    exportMultiplePrimitivesRaw?: (arg1: number, arg2: Uint8Array) => bigint;
+    exportMultipleValuesRaw?: (arg: number) => Uint8Array;
+    exportPrimitiveBoolRaw?: (arg: boolean) => boolean;
+    exportPrimitiveI16Raw?: (arg: number) => number;
+    exportPrimitiveI32Raw?: (arg: number) => number;
+    exportPrimitiveI64Raw?: (arg: bigint) => bigint;
+    exportPrimitiveI8Raw?: (arg: number) => number;
+    exportPrimitivePairRaw?: (arg: number) => [number, number];
+    exportRenamedStringRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSamplesRaw?: (samples: Uint8Array) => Uint8Array;
+    exportSerdeAdjacentlyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeEnumRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeFlattenRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeInternallyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeStructRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeUntaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStringRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStringAfterMemoryGrowthRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStructWithOptionsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportTimestampRaw?: (arg: Uint8Array) => Uint8Array;
+    exportTimestampsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportTypedIdRaw?: (id: Uint8Array) => Uint8Array;
+    exportValidatedStructRaw?: (arg: Uint8Array) => Uint8Array;
+    fetchDataRaw?: (rType: Uint8Array) => Promise<Uint8Array>;
+    initRaw?: (config: Uint8Array) => void;
+    reducerBridgeRaw?: (action: Uint8Array) => Uint8Array;
+    updateConfigRaw?: (config: Uint8Array) => void;
+};
+
+/**
+ * A capability that can be granted to the plugin. Imports that require a
+ * capability can only be called by the plugin if it was granted.
+ */
+export type Capability = "clock" | "fs" | "http" | "network" | "random";
+
+/**
+ * The host facilities that are exposed to the plugin through the built-in std
+ * imports, which require the "fs", "clock" and "random" capabilities.
+ *
+ * Files are read from and written to the `files` map, keyed by their path. If
+ * no map is given, the plugin cannot access any files. By default, the clock
+ * uses `Date.now()`, the monotonic clock uses `performance.now()` and random
+ * bytes are generated using `crypto.getRandomValues()`. Both clocks return
+ * milliseconds.
+ */
+export type StdImports = {
+    files?: Map<string, Uint8Array>;
+    now?: () => number;
+    monotonicNow?: () => number;
+    randomBytes?: (len: number) => Uint8Array;
+};
+
+/**
+ * A request that the plugin makes through the built-in HTTP imports, which
+ * require the "http" capability. Headers are name-value pairs.
+ */
+export type HttpRequest = {
+    method: string;
+    url: string;
+    headers: Array<[string, string]>;
+    body?: Uint8Array;
+};
+
+/**
+ * The response to a request that the plugin made through the built-in HTTP
+ * imports.
+ */
+export type HttpResponse = {
+    status: number;
+    headers: Array<[string, string]>;
+    body: Uint8Array;
+};
+
+/**
+ * The host facilities that are exposed to the plugin through the built-in
+ * HTTP imports.
+ *
+ * By default, requests are sent using the global `fetch()`. Hosts can pass
+ * their own implementation, for instance to restrict the URLs the plugin may
+ * access, or to mock requests in tests.
+ */
+export type HttpImports = {
+    fetch?: typeof fetch;
+};
+
+/**
+ * Represents an unrecoverable error in the FP runtime.
+ *
+ * After this, your only recourse is to create a new runtime, probably with a different WASM plugin.
+ */
+export declare class FPRuntimeError extends Error {
+    constructor(message: string);
+}
+
+/**
+ * The kind of failure that is reported by a `GuestError`.
+ */
+export type GuestErrorKind =
+    | "deserialization"
+    | "serialization"
+    | "allocation"
+    | "invalid_pointer"
+    | "internal"
+    | "panic";
+
+/**
+ * Thrown when the plugin reports a failure before it aborts, which plugins do
+ * if they are built with the `panic-abort` feature of `fp-bindgen-support`.
+ */
+export declare class GuestError extends FPRuntimeError {
+    kind: GuestErrorKind;
+    constructor(kind: GuestErrorKind, message: string);
+}
+
+/**
+ * Thrown when the plugin fails to allocate the memory for a value that is
+ * passed to it, which it reports by returning a null pointer from its
+ * allocator.
+ */
+export declare class AllocationFailedError extends FPRuntimeError {
+    len: number;
+    constructor(len: number);
+}
+
+/**
+ * Thrown when the plugin does not export a function that is called.
+ */
+export declare class MissingExportError extends FPRuntimeError {
+    exportName: string;
+    constructor(exportName: string);
+}
+
+/**
+ * Thrown when the plugin exports a function with another signature than the
+ * one that is declared in the protocol.
+ */
+export declare class SignatureMismatchError extends FPRuntimeError {
+    exportName: string;
+    constructor(exportName: string);
+}
+
+/**
+ * Thrown when a value that is returned by the plugin cannot be decoded.
+ */
+export declare class DeserializationError extends FPRuntimeError {
+    error: unknown;
+    constructor(error: unknown);
+}
+
+/**
+ * Thrown when the plugin is called after it was disposed, and used to reject
+ * the promises that were still waiting for the plugin when it was disposed.
+ */
+export declare class PluginDisposedError extends FPRuntimeError {
+    constructor();
+}
+
+/**
+ * An import of the plugin that the runtime does not provide, or provides with
+ * another signature than the plugin expects.
+ */
+export type ImportDiagnostic = {
+    module: string;
+    name: string;
+    /**
+     * The kind of import the plugin expects, together with the signature of
+     * functions, such as `function (i64) -> i64`.
+     */
+    expected: string;
+    /**
+     * The kind and signature of the import that is provided by the runtime, or
+     * `null` if it is missing.
+     */
+    provided: string | null;
+};
+
+/**
+ * Thrown when a plugin cannot be instantiated, because it expects imports that
+ * the runtime does not provide, or provides with another signature.
+ */
+export declare class InstantiationError extends FPRuntimeError {
+    diagnostics: ImportDiagnostic[];
+    constructor(diagnostics: ImportDiagnostic[]);
+}
+
+/**
+ * The direction in which a payload is passed: to the plugin, as the argument
+ * of an export or the result of an import, or from the plugin, as the result
+ * of an export or the argument of an import.
+ */
+export type PayloadDirection = "toPlugin" | "fromPlugin";
+
+/**
+ * The format in which payloads are logged. Decoded payloads are logged as the
+ * JSON of their MessagePack value.
+ */
+export type PayloadFormat = "hex" | "base64" | "decoded";
+
+/**
+ * A serialized payload that is passed between the runtime and the plugin.
+ */
+export type PayloadTrace = {
+    function: string;
+    name: string;
+    direction: PayloadDirection;
+    payload: Uint8Array;
+};
+
+/**
+ * Options for logging the serialized payloads that are passed between the
+ * runtime and the plugin.
+ */
+export type PayloadTraceOptions = {
+    /**
+     * The format in which payloads are logged. Defaults to `"hex"`.
+     */
+    format?: PayloadFormat;
+    /**
+     * Returns the text that is logged instead of a payload that should not be
+     * logged, such as one that contains credentials, or `undefined` to log the
+     * payload itself.
+     */
+    redact?: (trace: PayloadTrace) => string | undefined;
+    /**
+     * Logs the lines that describe the payloads. Defaults to `console.debug()`.
+     */
+    log?: (line: string) => void;
+};
+
+/**
+ * Thrown when the plugin calls an import that requires a capability that was
+ * not granted to it.
+ */
+export declare class PermissionDeniedError extends FPRuntimeError {
+    functionName: string;
+    capability: Capability;
+    constructor(functionName: string, capability: Capability);
+}
+
+/**
+ * Thrown when a value that is received from the plugin contains a field that
+ * does not pass its validation.
+ */
+export declare class ValidationError extends FPRuntimeError {
+    constructor(message: string);
+}
+
+/**
+ * Creates the functions that are imported by the plugin, which call the given
+ * host functions.
+ *
+ * @param plugin The plugin instance for which the imports are created. It only
+ *               needs to be initialized by the time the plugin calls them.
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @param capabilities The capabilities that are granted to the plugin.
+ * @returns The functions that are imported by the plugin from the `fp` module.
+ */
+export declare function createImports(
+    plugin: PluginInstance,
+    importFunctions: Imports,
+    capabilities?: Capability[]
+): WebAssembly.ModuleImports;
+
+/**
+ * Returns the functions that are exported by the given plugin instance.
+ *
+ * Functions that the plugin does not implement are `undefined`. Hosts that
+ * only call some of the exports may prefer to call the functions below
+ * directly, so that the others can be dropped from their bundle.
+ */
+/**
+ * The name of a function that is exported by the plugin.
+ */
+export type ExportName = keyof Exports;
+
+/**
+ * The kind of failure that is reported by a `PluginError`.
+ *
+ * Failures that are not caused by the plugin itself, such as imports that are
+ * denied or arguments that the plugin cannot allocate, are reported as
+ * `"transport"` errors.
+ */
+export type PluginErrorKind =
+    | "missing_export"
+    | "signature_mismatch"
+    | "guest_trap"
+    | "guest_panic"
+    | "deserialization"
+    | "disposed"
+    | "transport";
+
+/**
+ * Thrown when calling an export of the plugin fails. The error that caused
+ * the failure is available as `error`.
+ */
+export declare class PluginError extends FPRuntimeError {
+    kind: PluginErrorKind;
+    exportName: ExportName;
+    typeName: string | undefined;
+    error: unknown;
+    constructor(kind: PluginErrorKind, exportName: ExportName, error: unknown, typeName: string | undefined);
+}
+
+export declare function createExports(plugin: PluginInstance): Exports;
+
+export declare function exportArrayF32(plugin: PluginInstance, arg: Float32Array): Float32Array;
+
+export declare function exportArrayF64(plugin: PluginInstance, arg: Float64Array): Float64Array;
+
+export declare function exportArrayI16(plugin: PluginInstance, arg: Int16Array): Int16Array;
+
+export declare function exportArrayI32(plugin: PluginInstance, arg: Int32Array): Int32Array;
+
+export declare function exportArrayI8(plugin: PluginInstance, arg: Int8Array): Int8Array;
+
+export declare function exportArrayU16(plugin: PluginInstance, arg: Uint16Array): Uint16Array;
+
+export declare function exportArrayU32(plugin: PluginInstance, arg: Uint32Array): Uint32Array;
+
+export declare function exportArrayU8(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportAsyncStruct(plugin: PluginInstance, arg1: types.FpPropertyRenaming, arg2: string): Promise<types.FpPropertyRenaming>;
+
+export declare function exportBatchResponse(plugin: PluginInstance, arg: types.BatchResponse): types.BatchResponse;
+
+export declare function exportCachedCallCount(plugin: PluginInstance, label: string): string;
+
+export declare function exportCallRateLimitedImport(plugin: PluginInstance, calls: number): ReadonlyArray<types.Result<string, types.RateLimited>>;
+
+export declare function exportCollections(plugin: PluginInstance, arg: types.Collections): types.Collections;
+
+export declare function exportDynamicValue(plugin: PluginInstance, arg: types.ExtensionSettings): unknown;
+
+export declare function exportEdgeValues(plugin: PluginInstance, arg: types.EdgeValues): types.EdgeValues;
+
+export declare function exportFpAdjacentlyTagged(plugin: PluginInstance, arg: types.FpAdjacentlyTagged): types.FpAdjacentlyTagged;
+
+export declare function exportFpEnum(plugin: PluginInstance, arg: types.FpVariantRenaming): types.FpVariantRenaming;
+
+export declare function exportFpFlatten(plugin: PluginInstance, arg: types.FpFlatten): types.FpFlatten;
+
+export declare function exportFpInternallyTagged(plugin: PluginInstance, arg: types.FpInternallyTagged): types.FpInternallyTagged;
+
+export declare function exportFpStruct(plugin: PluginInstance, arg: types.FpPropertyRenaming): types.FpPropertyRenaming;
+
+export declare function exportFpUntagged(plugin: PluginInstance, arg: types.FpUntagged): types.FpUntagged;
+
+export declare function exportGenerics(plugin: PluginInstance, arg: types.StructWithGenerics<number>): types.StructWithGenerics<number>;
+
+export declare function exportGetBytes(plugin: PluginInstance): types.Result<Uint8Array, string>;
+
+export declare function exportGetSerdeBytes(plugin: PluginInstance): types.Result<ArrayBuffer, string>;
+
+export declare function exportLazyString(plugin: PluginInstance, arg: string): string;
+
+export declare function exportMetrics(plugin: PluginInstance, metrics: ReadonlyArray<types.Metric>): ReadonlyArray<types.Metric>;
+
+export declare function exportMultiplePrimitives(plugin: PluginInstance, arg1: number, arg2: string): string;
+
+export declare function exportMultipleValues(plugin: PluginInstance, arg: number): types.ExportMultipleValuesReturn;
+
+export declare function exportPrimitiveBool(plugin: PluginInstance, arg: boolean): boolean;
+
+export declare function exportPrimitiveF32(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitiveF64(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitiveI16(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitiveI32(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitiveI64(plugin: PluginInstance, arg: string): string;
+
+export declare function exportPrimitiveI8(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitivePair(plugin: PluginInstance, arg: number): [number, number];
+
+export declare function exportPrimitiveU16(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitiveU32(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitiveU64(plugin: PluginInstance, arg: string): string;
+
+export declare function exportPrimitiveU8(plugin: PluginInstance, arg: number): number;
+
+export declare function exportRenamedString(plugin: PluginInstance, arg: string): string;
+
+export declare function exportSamples(plugin: PluginInstance, samples: types.SampleColumns): types.SampleColumns;
+
+export declare function exportSerdeAdjacentlyTagged(plugin: PluginInstance, arg: types.SerdeAdjacentlyTagged): types.SerdeAdjacentlyTagged;
+
+export declare function exportSerdeEnum(plugin: PluginInstance, arg: types.SerdeVariantRenaming): types.SerdeVariantRenaming;
+
+export declare function exportSerdeFlatten(plugin: PluginInstance, arg: types.SerdeFlatten): types.SerdeFlatten;
+
+export declare function exportSerdeInternallyTagged(plugin: PluginInstance, arg: types.SerdeInternallyTagged): types.SerdeInternallyTagged;
+
+export declare function exportSerdeStruct(plugin: PluginInstance, arg: types.SerdePropertyRenaming): types.SerdePropertyRenaming;
+
+export declare function exportSerdeUntagged(plugin: PluginInstance, arg: types.SerdeUntagged): types.SerdeUntagged;
+
+export declare function exportString(plugin: PluginInstance, arg: string): string;
+
+export declare function exportStringAfterMemoryGrowth(plugin: PluginInstance, arg: string): string;
+
+export declare function exportStructWithOptions(plugin: PluginInstance, arg: types.StructWithOptions): types.StructWithOptions;
+
+export declare function exportTimestamp(plugin: PluginInstance, arg: types.MyDateTime): types.MyDateTime;
+
+export declare function exportTimestamps(plugin: PluginInstance, arg: types.Timestamps): types.Timestamps;
+
+export declare function exportTypedId(plugin: PluginInstance, id: types.TypedId<types.Point<number>>): types.TypedId<types.Point<number>>;
+
+export declare function exportValidatedStruct(plugin: PluginInstance, arg: types.ValidatedStruct): types.ValidatedStruct;
+
+export declare function exportVoidFunction(plugin: PluginInstance): void;
+
+/**
+ * Example how plugin could expose async data-fetching capabilities.
+ */
+export declare function fetchData(plugin: PluginInstance, rType: string): Promise<types.Result<string, string>>;
+
+/**
+ * Called on the plugin to give it a chance to initialize.
+ */
+export declare function init(plugin: PluginInstance, config: types.PluginConfig): void;
+
+/**
+ * Example how plugin could expose a reducer.
+ */
+export declare function reducerBridge(plugin: PluginInstance, action: types.ReduxAction): types.StateUpdate;
+
+/**
+ * Called on the plugin when the host changes its config.
+ */
+export declare function updateConfig(plugin: PluginInstance, config: types.PluginConfig): void;
+
+/**
+ * Registers a handler for the dynamic import `invoke_command`.
+ */
+export declare function registerInvokeCommand(plugin: PluginInstance, name: string, handler: (payload: string) => types.Result<string, string>): void;
+
+/**
+ * Unregisters a handler for the dynamic import `invoke_command`.
+ */
+export declare function unregisterInvokeCommand(plugin: PluginInstance, name: string): boolean;
+
+/**
+ * Subscribes the given handler to the event with the given name.
+ *
+ * The handler is called with every event of that name that the plugin
+ * emits, until it is unsubscribed using `off()`.
+ */
+export declare function on<E extends keyof Events>(plugin: PluginInstance, eventName: E, handler: (event: Events[E]) => void): void;
+
+/**
+ * Unsubscribes the given handler from the event with the given name.
+ *
+ * Returns whether the handler was subscribed.
+ */
+export declare function off<E extends keyof Events>(plugin: PluginInstance, eventName: E, handler: (event: Events[E]) => void): boolean;
+
+/**
+ * Returns statistics about the memory that is allocated by the plugin.
+ *
+ * This is only supported for plugins that are built with the `memory-stats`
+ * feature of `fp-bindgen-support`.
+ */
+export declare function memoryStats(plugin: PluginInstance): MemoryStats;
+
+/**
+ * Returns metrics about the calls that the plugin has made to imports, as
+ * measured by the plugin itself.
+ *
+ * This is only supported for plugins with bindings that are generated with
+ * `guest_metrics` enabled.
+ */
+export declare function guestMetrics(plugin: PluginInstance): GuestMetrics;
+
+/**
+ * Calls the `shutdown` export of the plugin, if it implements it, and releases
+ * the resources that are held for it: pending promises are rejected with a
+ * `PluginDisposedError`, and registered handlers are dropped. Exports that
+ * exchange serialized values throw a `PluginDisposedError` afterwards.
+ */
+export declare function dispose(plugin: PluginInstance): void;
+
+/**
+ * Returns the names of the exports that the plugin omits.
+ *
+ * The exports of the plugin are checked against the protocol before it is
+ * instantiated, so this allows hosts to log which capabilities the plugin
+ * lacks at load time.
+ */
+export declare function missingExports(plugin: PluginInstance): string[];
+
+/**
+ * Discards the memoized results of the exports that are cached.
+ */
+export declare function clearExportCache(plugin: PluginInstance): void;
+
+export declare function exportArrayF32Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportArrayF64Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportArrayI16Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportArrayI32Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportArrayI8Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportArrayU16Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportArrayU32Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportArrayU8Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportAsyncStructRaw(plugin: PluginInstance, arg1: Uint8Array, arg2: bigint): Promise<Uint8Array>;
+
+export declare function exportBatchResponseRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportCachedCallCountRaw(plugin: PluginInstance, label: Uint8Array): Uint8Array;
+
+export declare function exportCallRateLimitedImportRaw(plugin: PluginInstance, calls: number): Uint8Array;
+
+export declare function exportCollectionsRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportDynamicValueRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportEdgeValuesRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportFpAdjacentlyTaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportFpEnumRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportFpFlattenRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportFpInternallyTaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportFpStructRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportFpUntaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportGenericsRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportGetBytesRaw(plugin: PluginInstance): Uint8Array;
+
+export declare function exportGetSerdeBytesRaw(plugin: PluginInstance): Uint8Array;
+
+export declare function exportLazyStringRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportMetricsRaw(plugin: PluginInstance, metrics: Uint8Array): Uint8Array;
+
+export declare function exportMultiplePrimitivesRaw(plugin: PluginInstance, arg1: number, arg2: Uint8Array): bigint;
+
+export declare function exportMultipleValuesRaw(plugin: PluginInstance, arg: number): Uint8Array;
+
+export declare function exportPrimitiveBoolRaw(plugin: PluginInstance, arg: boolean): boolean;
+
+export declare function exportPrimitiveI16Raw(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitiveI32Raw(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitiveI64Raw(plugin: PluginInstance, arg: bigint): bigint;
+
+export declare function exportPrimitiveI8Raw(plugin: PluginInstance, arg: number): number;
+
+export declare function exportPrimitivePairRaw(plugin: PluginInstance, arg: number): [number, number];
+
+export declare function exportRenamedStringRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportSamplesRaw(plugin: PluginInstance, samples: Uint8Array): Uint8Array;
+
+export declare function exportSerdeAdjacentlyTaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportSerdeEnumRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportSerdeFlattenRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportSerdeInternallyTaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportSerdeStructRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportSerdeUntaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportStringRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportStringAfterMemoryGrowthRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportStructWithOptionsRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportTimestampRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportTimestampsRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function exportTypedIdRaw(plugin: PluginInstance, id: Uint8Array): Uint8Array;
+
+export declare function exportValidatedStructRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array;
+
+export declare function fetchDataRaw(plugin: PluginInstance, rType: Uint8Array): Promise<Uint8Array>;
+
+export declare function initRaw(plugin: PluginInstance, config: Uint8Array): void;
+
+export declare function reducerBridgeRaw(plugin: PluginInstance, action: Uint8Array): Uint8Array;
+
+export declare function updateConfigRaw(plugin: PluginInstance, config: Uint8Array): void;
+
+/**
+ * Instantiates the given plugin, without wrapping its exports.
+ *
+ * The returned instance can be passed to the functions that call the exports
+ * of the plugin. Unlike `createRuntime()`, this allows bundlers to drop the
+ * functions that are not used.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @param capabilities The capabilities that are granted to the plugin.
+ * @param featureImports The imports of the features the plugin was compiled with.
+ * @param options Options for the runtime, such as the options of the MessagePack
+ *                encoder and decoder.
+ * @returns The instantiated plugin.
+ */
+export declare function instantiatePlugin(
+    plugin: ArrayBuffer,
+    importFunctions: Imports,
+    capabilities?: Capability[],
+    featureImports?: FeatureImports[],
+    options?: RuntimeOptions
+): Promise<PluginInstance>;
+
+/**
+ * Options for `createRuntime()`.
+ */
+export type CreateRuntimeOptions = RuntimeOptions & {
+    /**
+     * The config with which the `init` export of the plugin is called, once
+     * it has been instantiated. Plugins that don't implement `init` are not
+     * initialized.
+     */
+    init?: types.PluginConfig;
+};
+
+/**
+ * Creates a runtime for executing the given plugin.
+ *
+ * Call `dispose()` on the returned exports when the plugin is no longer
+ * needed, so that it can clean up through its `shutdown` export, and pending
+ * promises do not keep their callbacks alive.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @param capabilities The capabilities that are granted to the plugin.
+ * @param featureImports The imports of the features the plugin was compiled with.
+ * @param options Options for the runtime, such as the options of the MessagePack
+ *                encoder and decoder, and the config with which the plugin is
+ *                initialized.
+ * @returns The functions that may be exported by the plugin.
+ */
+export declare function createRuntime(
+    plugin: ArrayBuffer,
+    importFunctions: Imports,
+    capabilities?: Capability[],
+    featureImports?: FeatureImports[],
+    options?: CreateRuntimeOptions
+): Promise<Exports>;
+
+/**
+ * Returns a machine-readable description of the protocol that this runtime was
+ * generated for, with the signatures of all imported and exported functions and
+ * the types they use.
+ *
+ * Note that plugins may omit exports, so not every export that is described
+ * is necessarily returned by `createRuntime()`.
+ */
+export declare function protocol(): Protocol;
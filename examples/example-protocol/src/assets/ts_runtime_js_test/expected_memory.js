@@ -0,0 +1,1025 @@
+// ============================================= //
+// Memory helpers for WebAssembly runtime        //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any
+
+import { encode, decode, ExtensionCodec } from "https://esm.sh/@msgpack/msgpack@2.7.2";
+import { toEncodable } from "./conversions.js";
+
+/**
+ * Represents an unrecoverable error in the FP runtime.
+ *
+ * After this, your only recourse is to create a new runtime, probably with a different WASM plugin.
+ */
+export class FPRuntimeError extends Error {
+    constructor(message) {
+        super(message);
+    }
+}
+
+/**
+ * The kind of failure that is reported by a `GuestError`.
+ */
+
+/**
+ * Thrown when the plugin reports a failure before it aborts, which plugins do
+ * if they are built with the `panic-abort` feature of `fp-bindgen-support`.
+ */
+export class GuestError extends FPRuntimeError {
+
+    constructor(kind, message) {
+        super(`Plugin failed with ${kind} error: ${message}`);
+        this.kind = kind;
+    }
+}
+
+/**
+ * Thrown when the plugin fails to allocate the memory for a value that is
+ * passed to it, which it reports by returning a null pointer from its
+ * allocator.
+ */
+export class AllocationFailedError extends FPRuntimeError {
+
+    constructor(len) {
+        super(`Plugin could not allocate ${len} bytes`);
+        this.len = len;
+    }
+}
+
+/**
+ * Thrown when the plugin does not export a function that is called.
+ */
+export class MissingExportError extends FPRuntimeError {
+
+    constructor(exportName) {
+        super(`Plugin did not export expected symbol: "${exportName}"`);
+        this.exportName = exportName;
+    }
+}
+
+/**
+ * Thrown when the plugin exports a function with another signature than the
+ * one that is declared in the protocol.
+ */
+export class SignatureMismatchError extends FPRuntimeError {
+
+    constructor(exportName) {
+        super(`Plugin exported symbol with an unexpected signature: "${exportName}"`);
+        this.exportName = exportName;
+    }
+}
+
+/**
+ * Thrown when a value that is returned by the plugin cannot be decoded.
+ */
+export class DeserializationError extends FPRuntimeError {
+
+    constructor(error) {
+        super(`Could not decode value returned by plugin: ${error}`);
+        this.error = error;
+    }
+}
+
+/**
+ * Thrown when the plugin is called after it was disposed, and used to reject
+ * the promises that were still waiting for the plugin when it was disposed.
+ */
+export class PluginDisposedError extends FPRuntimeError {
+    constructor() {
+        super("Plugin was disposed");
+    }
+}
+
+/**
+ * An import of the plugin that the runtime does not provide, or provides with
+ * another signature than the plugin expects.
+ */
+
+/**
+ * Thrown when a plugin cannot be instantiated, because it expects imports that
+ * the runtime does not provide, or provides with another signature. This
+ * happens when the plugin was compiled against another version of the protocol,
+ * or with features whose imports were not passed to the runtime.
+ */
+export class InstantiationError extends FPRuntimeError {
+
+    constructor(diagnostics) {
+        super(
+            `Plugin could not be instantiated:\n${diagnostics.map(formatImportDiagnostic).join("\n")}`
+        );
+        this.diagnostics = diagnostics;
+    }
+}
+
+function formatImportDiagnostic({ module, name, expected, provided }) {
+    return provided === null
+        ? `- missing import "${module}"."${name}": expected ${expected}`
+        : `- mismatched import "${module}"."${name}": expected ${expected}, but got ${provided}`;
+}
+
+/**
+ * Options for encoding the values that are passed to the plugin and decoding
+ * the values that are received from it, such as an `extensionCodec` with the
+ * MessagePack extensions that the plugin uses, or limits like `maxStrLength`.
+ */
+
+/**
+ * Options for instantiating a plugin.
+ */
+
+/**
+ * A promise that is waiting for an async value of the plugin to be resolved.
+ */
+
+/**
+ * A plugin that was instantiated using `instantiatePlugin()`.
+ *
+ * The functions that call the exports of the plugin take the instance as their
+ * first argument, so that bundlers can drop the functions that are not used.
+ */
+
+export function getExport(plugin, name, arity = -1) {
+    if (plugin.disposed) {
+        throw new PluginDisposedError();
+    }
+    const exp = plugin.instance.exports[name];
+    if (!exp) {
+        throw new MissingExportError(name);
+    }
+    if (arity >= 0 && (typeof exp !== "function" || exp.length !== arity)) {
+        throw new SignatureMismatchError(name);
+    }
+    return exp;
+}
+
+export function hasExport(plugin, name) {
+    return !!plugin.instance.exports[name];
+}
+
+/**
+ * An import that is declared in the import section of a WebAssembly module.
+ */
+
+/**
+ * The limits of an imported table or memory, in elements or pages
+ * respectively. Only memories can be shared.
+ */
+
+/**
+ * Parses the imports of a WebAssembly module, including the signatures of the
+ * imported functions, which `WebAssembly.Module.imports()` does not provide.
+ */
+export function parseModuleImports(module) {
+    const bytes = new Uint8Array(module);
+    let offset = 8; // Skips the magic number and the version.
+
+    const readByte = () => bytes[offset++];
+    const readUnsigned = () => {
+        let result = 0;
+        let factor = 1;
+        let byte;
+        do {
+            byte = readByte();
+            result += (byte & 0x7f) * factor;
+            factor *= 128;
+        } while (byte & 0x80);
+        return result;
+    };
+    const readName = () => {
+        const len = readUnsigned();
+        const name = new TextDecoder().decode(bytes.subarray(offset, offset + len));
+        offset += len;
+        return name;
+    };
+    const readValueTypes = () => {
+        const types = [];
+        for (let count = readUnsigned(); count > 0; count--) {
+            types.push(formatValueType(readByte()));
+        }
+        return types;
+    };
+    const readLimits = () => {
+        const flags = readByte();
+        const initial = readUnsigned();
+        const maximum = flags & 0x01 ? readUnsigned() : null;
+        return { initial, maximum, shared: (flags & 0x02) !== 0 };
+    };
+
+    const signatures = [];
+    const imports = [];
+    while (offset < bytes.length) {
+        const sectionId = readByte();
+        const sectionEnd = readUnsigned() + offset;
+        if (sectionId === 1) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                readByte(); // The form of the type, which is always a function.
+                const params = readValueTypes();
+                const results = readValueTypes();
+                signatures.push(
+                    `(${params.join(", ")}) -> ${
+                        results.length === 1 ? results[0] : `(${results.join(", ")})`
+                    }`
+                );
+            }
+        } else if (sectionId === 2) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                const module = readName();
+                const name = readName();
+                const kind = readByte();
+                if (kind === 0x00) {
+                    const signature = signatures[readUnsigned()] ?? null;
+                    imports.push({ module, name, kind: "function", signature, limits: null });
+                } else if (kind === 0x01) {
+                    readByte();
+                    const limits = readLimits();
+                    imports.push({ module, name, kind: "table", signature: null, limits });
+                } else if (kind === 0x02) {
+                    const limits = readLimits();
+                    imports.push({ module, name, kind: "memory", signature: null, limits });
+                } else if (kind === 0x03) {
+                    readByte();
+                    readByte();
+                    imports.push({ module, name, kind: "global", signature: null, limits: null });
+                } else {
+                    readByte();
+                    readUnsigned();
+                    imports.push({ module, name, kind: "tag", signature: null, limits: null });
+                }
+            }
+
+            // The import section is followed by sections we don't need:
+            break;
+        }
+        offset = sectionEnd;
+    }
+    return imports;
+}
+
+function formatValueType(type) {
+    switch (type) {
+        case 0x7f:
+            return "i32";
+        case 0x7e:
+            return "i64";
+        case 0x7d:
+            return "f32";
+        case 0x7c:
+            return "f64";
+        case 0x7b:
+            return "v128";
+        case 0x70:
+            return "funcref";
+        case 0x6f:
+            return "externref";
+        default:
+            return `0x${type.toString(16)}`;
+    }
+}
+
+/**
+ * Checks whether the given imports provide everything the plugin imports, so
+ * that an `InstantiationError` that describes the missing and mismatched
+ * imports can be thrown instead of an opaque `LinkError`.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param imports The imports the plugin will be instantiated with.
+ * @param signatures The signatures of the functions in the `fp` module that the
+ *                   runtime provides, keyed by name.
+ */
+export function checkImports(
+    plugin,
+    imports,
+    signatures
+) {
+    const diagnostics = [];
+    for (const { module, name, kind, signature } of parseModuleImports(plugin)) {
+        const expected = signature === null ? kind : `${kind} ${signature}`;
+        const value = imports[module]?.[name];
+        if (value === undefined) {
+            diagnostics.push({ module, name, expected, provided: null });
+        } else if (kind === "function" && typeof value !== "function") {
+            diagnostics.push({ module, name, expected, provided: typeof value });
+        } else if (module === "fp" && name in signatures && signatures[name] !== signature) {
+            diagnostics.push({ module, name, expected, provided: `function ${signatures[name]}` });
+        }
+    }
+
+    if (diagnostics.length > 0) {
+        throw new InstantiationError(diagnostics);
+    }
+}
+
+/**
+ * The version of the async value protocol that is supported by this runtime.
+ */
+export const ASYNC_PROTOCOL_VERSION = 2;
+
+/**
+ * The status of an async value that failed, which holds a serialized
+ * `GuestError` instead of the result.
+ */
+const FUTURE_STATUS_ERROR = 2;
+
+export function createAsyncValue(plugin) {
+    const len = 12; // std::mem::size_of::<AsyncValue>()
+    const fatPtr = plugin.malloc(len);
+    const [ptr] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    buffer.fill(0);
+    return fatPtr;
+}
+
+export function interpretSign(num, cap) {
+    if (num < cap) {
+        return num;
+    } else {
+        return num - (cap << 1);
+    }
+}
+
+export function interpretBigSign(num, cap) {
+    if (num < cap) {
+        return num;
+    } else {
+        return num - (cap << 1n);
+    }
+}
+
+// Views on the plugin's memory must be acquired right before they are
+// used: whenever the plugin grows its memory, `memory.buffer` is replaced
+// and existing views become detached.
+function memoryView(plugin, ptr, len) {
+    return new Uint8Array(plugin.memory.buffer, ptr, len);
+}
+
+export function parseObject(plugin, fatPtr) {
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    // Without creating a copy of the memory, we risk corruption of any
+    // embedded `Uint8Array` objects returned from `decode()` after `free()`
+    // has been called :(
+    const copy = new Uint8Array(len);
+    copy.set(buffer);
+    plugin.free(fatPtr);
+    try {
+        checkSafeIntegers(copy);
+        return decode(copy, plugin.msgpackOptions);
+    } catch (error) {
+        throw new DeserializationError(error);
+    }
+}
+
+export function promiseFromPtr(plugin, ptr) {
+    const resultPtr = plugin.promises.get(ptr);
+    if (resultPtr) {
+        if (resultPtr instanceof GuestError) {
+            plugin.promises.delete(ptr);
+            return Promise.reject(resultPtr);
+        }
+        if (typeof resultPtr === "object") {
+            throw new FPRuntimeError("Already created promise for this value");
+        }
+
+        plugin.promises.delete(ptr);
+        return Promise.resolve(resultPtr);
+    } else {
+        return new Promise((resolve, reject) => {
+            plugin.promises.set(ptr, { resolve, reject });
+        });
+    }
+}
+
+/**
+ * Resolves the promise for an async value of the plugin. If the plugin marked
+ * the value as failed, the result holds a serialized error instead, with which
+ * the promise is rejected as a `GuestError`.
+ */
+export function resolvePromise(plugin, asyncValuePtr, resultPtr) {
+    const pending = plugin.promises.get(asyncValuePtr);
+    if (pending && (typeof pending !== "object" || pending instanceof GuestError)) {
+        throw new FPRuntimeError("Tried to resolve invalid promise");
+    }
+
+    const [ptr] = fromFatPtr(asyncValuePtr);
+    const status = new DataView(plugin.memory.buffer).getUint32(ptr, true);
+    if (status === FUTURE_STATUS_ERROR) {
+        const { kind, message } = parseObject(plugin, resultPtr);
+        const error = new GuestError(kind, message);
+        if (pending) {
+            plugin.promises.delete(asyncValuePtr);
+            pending.reject(error);
+        } else {
+            plugin.promises.set(asyncValuePtr, error);
+        }
+    } else if (pending) {
+        plugin.promises.delete(asyncValuePtr);
+        pending.resolve(resultPtr);
+    } else {
+        plugin.promises.set(asyncValuePtr, resultPtr);
+    }
+}
+
+export function resolveFuture(plugin, asyncValuePtr, resultPtr) {
+    // Async imports that complete after the plugin is disposed cannot be
+    // resumed anymore:
+    if (plugin.disposed) {
+        return;
+    }
+
+    const resolve = getExport(
+        plugin,
+        "__fp_guest_resolve_async_value"
+    );
+    resolve(asyncValuePtr, resultPtr);
+}
+
+/**
+ * Throws the `GuestError` that the plugin reports through the reserved
+ * `__fp_error()` import.
+ */
+export function throwGuestError(plugin, reportPtr) {
+    const { kind, message } = parseObject(plugin, reportPtr);
+    throw new GuestError(kind, message);
+}
+
+export function serializeObject(plugin, object) {
+    return exportToMemory(plugin, encode(toEncodable(object), plugin.msgpackOptions));
+}
+
+export function exportToMemory(plugin, serialized) {
+    const fatPtr = plugin.malloc(serialized.length);
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    buffer.set(serialized);
+    return fatPtr;
+}
+
+export function importFromMemory(plugin, fatPtr) {
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    const copy = new Uint8Array(len);
+    copy.set(buffer);
+    plugin.free(fatPtr);
+    return copy;
+}
+
+/**
+ * Returns the handlers that are registered for the given dynamic import.
+ */
+export function getDynamicImportHandlers(
+    plugin,
+    importName
+) {
+    let handlers = plugin.dynamicImportHandlers.get(importName);
+    if (!handlers) {
+        handlers = new Map();
+        plugin.dynamicImportHandlers.set(importName, handlers);
+    }
+    return handlers;
+}
+
+/**
+ * Rejects the promises that are still waiting for the plugin with a
+ * `PluginDisposedError`, frees the results that were never awaited and drops
+ * the registered handlers, so nothing keeps their closures alive. Afterwards,
+ * `getExport()` throws a `PluginDisposedError`.
+ */
+export function disposePlugin(plugin) {
+    const error = new PluginDisposedError();
+    for (const pending of plugin.promises.values()) {
+        if (pending instanceof GuestError) {
+            continue;
+        } else if (typeof pending === "object") {
+            pending.reject(error);
+        } else {
+            plugin.free(pending);
+        }
+    }
+    plugin.promises.clear();
+    plugin.dynamicImportHandlers.clear();
+    plugin.eventHandlers.clear();
+    plugin.disposed = true;
+}
+
+/**
+ * Returns the handlers that are subscribed to the given event.
+ */
+export function getEventHandlers(
+    plugin,
+    eventName
+) {
+    let handlers = plugin.eventHandlers.get(eventName);
+    if (!handlers) {
+        handlers = new Set();
+        plugin.eventHandlers.set(eventName, handlers);
+    }
+    return handlers;
+}
+
+function fromFatPtr(fatPtr) {
+    return [
+        Number.parseInt((fatPtr >> 32n).toString()),
+        Number.parseInt((fatPtr & 0xffff_ffffn).toString()),
+    ];
+}
+
+function toFatPtr(ptr, len) {
+    return (BigInt(ptr) << 32n) | BigInt(len);
+}
+
+const exportCaches = new WeakMap();
+
+/**
+ * Returns the result of a previous call to the given export with the same key,
+ * unless its time-to-live has passed. Otherwise, the export is called and its
+ * result is stored, unless it fails.
+ */
+export function cachedExport(
+    plugin,
+    exportName,
+    key,
+    ttlMs,
+    call
+) {
+    let cache = exportCaches.get(plugin);
+    if (!cache) {
+        cache = new Map();
+        exportCaches.set(plugin, cache);
+    }
+
+    const now = Date.now();
+    const encodedKey = encode(toEncodable(key), plugin.msgpackOptions);
+    const cacheKey = `${exportName}:${Array.from(encodedKey, (byte) => byte.toString(16).padStart(2, "0")).join("")}`;
+    const entry = cache.get(cacheKey);
+    if (entry && entry.expiresAt > now) {
+        return entry.value;
+    }
+
+    for (const [otherKey, otherEntry] of cache) {
+        if (otherEntry.expiresAt <= now) {
+            cache.delete(otherKey);
+        }
+    }
+
+    const value = call();
+    const stored = { expiresAt: now + ttlMs, value };
+    cache.set(cacheKey, stored);
+    if (value instanceof Promise) {
+        const entries = cache;
+        value.catch(() => {
+            if (entries.get(cacheKey) === stored) {
+                entries.delete(cacheKey);
+            }
+        });
+    }
+    return value;
+}
+
+/**
+ * Discards the memoized results of the cached exports of the given plugin.
+ */
+export function clearCachedExports(plugin) {
+    exportCaches.delete(plugin);
+}
+
+const rateLimitBuckets = new WeakMap();
+
+/**
+ * Takes a token from the bucket of the given import, which holds up to `calls`
+ * tokens and is refilled with `calls` tokens every `perMs` milliseconds.
+ *
+ * Returns the `RateLimited` error for the plugin if no token is available, or
+ * `undefined` if the import may be called.
+ */
+export function rateLimit(
+    plugin,
+    importName,
+    calls,
+    perMs
+) {
+    let buckets = rateLimitBuckets.get(plugin);
+    if (!buckets) {
+        buckets = new Map();
+        rateLimitBuckets.set(plugin, buckets);
+    }
+
+    const now = Date.now();
+    const tokensPerMs = calls / perMs;
+    let bucket = buckets.get(importName);
+    if (!bucket) {
+        bucket = { tokens: calls, refilledAt: now };
+        buckets.set(importName, bucket);
+    }
+    bucket.tokens = Math.min(calls, bucket.tokens + (now - bucket.refilledAt) * tokensPerMs);
+    bucket.refilledAt = now;
+
+    if (bucket.tokens >= 1) {
+        bucket.tokens -= 1;
+        return undefined;
+    }
+
+    const retryAfterMs = Math.ceil((1 - bucket.tokens) / tokensPerMs);
+    return { Err: { function: importName, retryAfterMs } };
+}
+
+/**
+ * The typed arrays in which columns are decoded, by the type of the MessagePack
+ * extension in which they are encoded. Columns of `u8` values are encoded as
+ * plain binary data, which decodes into a `Uint8Array` already.
+ */
+const COLUMN_ARRAYS = [
+    [16, Int8Array],
+    [17, Int16Array],
+    [18, Uint16Array],
+    [19, Int32Array],
+    [20, Uint32Array],
+    [21, BigInt64Array],
+    [22, BigUint64Array],
+    [23, Float32Array],
+    [24, Float64Array],
+];
+
+/**
+ * Returns the given options with an `extensionCodec` that encodes and decodes
+ * the columns of `#[fp(columnar)]` structs as typed arrays. Extensions of other
+ * types are still handled by the `extensionCodec` of the given options.
+ *
+ * Typed arrays use the byte order of the platform, which is little-endian on
+ * every platform that runs WebAssembly, just like the columns.
+ */
+export function withColumnExtensions(options) {
+    const columnCodec = new ExtensionCodec();
+    for (const [type, ColumnArray] of COLUMN_ARRAYS) {
+        columnCodec.register({
+            type,
+            encode: (value) =>
+                value instanceof ColumnArray
+                    ? new Uint8Array(value.buffer, value.byteOffset, value.byteLength)
+                    : null,
+            decode: (data) => new ColumnArray(data.slice().buffer),
+        });
+    }
+
+    const extensionCodec = options.extensionCodec;
+    if (!extensionCodec) {
+        return { ...options, extensionCodec: columnCodec };
+    }
+
+    return {
+        ...options,
+        extensionCodec: {
+            tryToEncode: (object, context) =>
+                columnCodec.tryToEncode(object, context) ?? extensionCodec.tryToEncode(object, context),
+            decode: (data, type, context) =>
+                COLUMN_ARRAYS.some(([columnType]) => columnType === type)
+                    ? columnCodec.decode(data, type, context)
+                    : extensionCodec.decode(data, type, context),
+        },
+    };
+}
+
+/**
+ * The direction in which a payload is passed: to the plugin, as the argument
+ * of an export or the result of an import, or from the plugin, as the result
+ * of an export or the argument of an import.
+ */
+
+/**
+ * The format in which payloads are logged. Decoded payloads are logged as the
+ * JSON of their MessagePack value.
+ */
+
+/**
+ * A serialized payload that is passed between the runtime and the plugin.
+ */
+
+/**
+ * Options for logging the serialized payloads that are passed between the
+ * runtime and the plugin.
+ */
+
+/**
+ * Returns the options for tracing payloads, given the `tracePayloads` option.
+ * If it is omitted, the `FP_BINDGEN_TRACE_PAYLOADS` environment variable is
+ * used instead, where available.
+ */
+export function getPayloadTraceOptions(
+    option
+) {
+    if (option === undefined) {
+        const format = (globalThis).process?.env?.FP_BINDGEN_TRACE_PAYLOADS;
+        if (format === "hex" || format === "base64" || format === "decoded") {
+            return { format };
+        }
+        return undefined;
+    }
+    return option === true ? {} : option || undefined;
+}
+
+/**
+ * Logs the serialized payload in the memory of the plugin, if payload tracing
+ * is enabled, and returns its pointer without freeing it.
+ */
+export function traceGuestPayload(
+    plugin,
+    fn,
+    name,
+    direction,
+    fatPtr
+) {
+    const options = plugin.payloadTraceOptions;
+    if (!options) {
+        return fatPtr;
+    }
+
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const payload = memoryView(plugin, ptr, len & 0xff_ffff).slice();
+    const text =
+        options.redact?.({ function: fn, name, direction, payload }) ??
+        formatPayload(plugin, payload, options.format ?? "hex");
+    const arrow = direction === "toPlugin" ? "runtime -> plugin" : "plugin -> runtime";
+    (options.log ?? console.debug)(
+        `fp-bindgen payload: ${fn}(${name}) ${arrow}, ${payload.length} bytes: ${text}`
+    );
+    return fatPtr;
+}
+
+function formatPayload(plugin, payload, format) {
+    const hex = Array.from(payload, (byte) => byte.toString(16).padStart(2, "0")).join("");
+    switch (format) {
+        case "base64":
+            return btoa(Array.from(payload, (byte) => String.fromCharCode(byte)).join(""));
+        case "decoded":
+            try {
+                return JSON.stringify(decode(payload, plugin.msgpackOptions), (_key, value) =>
+                    typeof value === "bigint" ? value.toString() : value
+                );
+            } catch {
+                return `${hex} (invalid MessagePack)`;
+            }
+        default:
+            return hex;
+    }
+}
+
+/**
+ * Checks the exports of a plugin against the signatures that are declared in
+ * the protocol, so that plugins that were built for another version of the
+ * protocol are rejected before they are instantiated, rather than once the
+ * export is called.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param signatures The symbols and signatures of the exports, keyed by the
+ *                   names under which they are returned by `createRuntime()`.
+ * @returns The names of the exports that the plugin omits.
+ */
+export function checkExports(
+    plugin,
+    signatures
+) {
+    const exports = parseModuleExports(plugin);
+    const missingExports = [];
+    for (const [name, [symbol, signature]] of Object.entries(signatures)) {
+        const exportedSignature = exports.get(symbol);
+        if (exportedSignature === undefined) {
+            missingExports.push(name);
+        } else if (exportedSignature !== signature) {
+            throw new SignatureMismatchError(symbol);
+        }
+    }
+    return missingExports;
+}
+
+/**
+ * Parses the signatures of the functions that a WebAssembly module exports,
+ * keyed by name, which `WebAssembly.Module.exports()` does not provide.
+ */
+export function parseModuleExports(module) {
+    const bytes = new Uint8Array(module);
+    let offset = 8; // Skips the magic number and the version.
+
+    const readByte = () => bytes[offset++];
+    const readUnsigned = () => {
+        let result = 0;
+        let factor = 1;
+        let byte;
+        do {
+            byte = readByte();
+            result += (byte & 0x7f) * factor;
+            factor *= 128;
+        } while (byte & 0x80);
+        return result;
+    };
+    const readValueTypes = () => {
+        const types = [];
+        for (let count = readUnsigned(); count > 0; count--) {
+            types.push(formatValueType(readByte()));
+        }
+        return types;
+    };
+
+    const signatures = [];
+    // Imported functions precede the functions that the module defines:
+    const functionSignatures = parseModuleImports(module)
+        .filter(({ kind }) => kind === "function")
+        .map(({ signature }) => signature);
+    const exports = new Map();
+    while (offset < bytes.length) {
+        const sectionId = readByte();
+        const sectionEnd = readUnsigned() + offset;
+        if (sectionId === 1) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                readByte(); // The form of the type, which is always a function.
+                const params = readValueTypes();
+                const results = readValueTypes();
+                signatures.push(
+                    `(${params.join(", ")}) -> ${
+                        results.length === 1 ? results[0] : `(${results.join(", ")})`
+                    }`
+                );
+            }
+        } else if (sectionId === 3) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                functionSignatures.push(signatures[readUnsigned()] ?? null);
+            }
+        } else if (sectionId === 7) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                const len = readUnsigned();
+                const name = new TextDecoder().decode(bytes.subarray(offset, offset + len));
+                offset += len;
+                const kind = readByte();
+                const signature = functionSignatures[readUnsigned()];
+                if (kind === 0x00 && signature) {
+                    exports.set(name, signature);
+                }
+            }
+
+            // The export section is followed by sections we don't need:
+            break;
+        }
+        offset = sectionEnd;
+    }
+    return exports;
+}
+
+export function packPair(
+    pair,
+    firstToBits,
+    secondToBits
+) {
+    return (BigInt(firstToBits(pair[0]) >>> 0) << 32n) | BigInt(secondToBits(pair[1]) >>> 0);
+}
+
+export function unpackPair(
+    packed,
+    firstFromBits,
+    secondFromBits
+) {
+    const bits = BigInt.asUintN(64, packed);
+    return [firstFromBits(Number(bits >> 32n)), secondFromBits(Number(bits & 0xffff_ffffn))];
+}
+
+const f32Bits = /* @__PURE__ */ new DataView(new ArrayBuffer(4));
+
+export function f32FromBits(bits) {
+    f32Bits.setUint32(0, bits);
+    return f32Bits.getFloat32(0);
+}
+
+export function f32ToBits(value) {
+    f32Bits.setFloat32(0, value);
+    return f32Bits.getUint32(0);
+}
+
+/**
+ * Converts a 64-bit integer that is received from the plugin into a number,
+ * throwing a `RangeError` if a number cannot represent it exactly.
+ */
+export function toSafeNumber(value) {
+    if (value > BigInt(Number.MAX_SAFE_INTEGER) || value < BigInt(Number.MIN_SAFE_INTEGER)) {
+        throw new RangeError(`64-bit integer ${value} exceeds the safe integer range`);
+    }
+    return Number(value);
+}
+
+/**
+ * Converts a number into a 64-bit integer that is sent to the plugin,
+ * throwing a `RangeError` if it is not a safe integer within the range of the
+ * type.
+ */
+export function fromSafeNumber(value, signed) {
+    if (!Number.isSafeInteger(value) || (!signed && value < 0)) {
+        throw new RangeError(`${value} is not a valid ${signed ? "i64" : "u64"}`);
+    }
+    return BigInt(value);
+}
+
+/**
+ * Parses a decimal string into a 64-bit integer that is sent to the plugin,
+ * throwing a `RangeError` if it is not an integer within the range of the
+ * type.
+ */
+export function fromInt64String(value, signed) {
+    const [min, max] = signed ? [-(2n ** 63n), 2n ** 63n - 1n] : [0n, 2n ** 64n - 1n];
+    const result = /^-?[0-9]+$/.test(value) ? BigInt(value) : undefined;
+    if (result === undefined || result < min || result > max) {
+        throw new RangeError(`"${value}" is not a valid ${signed ? "i64" : "u64"}`);
+    }
+    return result;
+}
+
+/**
+ * Checks that the 64-bit integers in the given MessagePack data are within
+ * the safe integer range, since they would silently lose precision when they
+ * are decoded into numbers. Throws a `RangeError` if they are not.
+ */
+export function checkSafeIntegers(bytes) {
+    const view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+    const max = BigInt(Number.MAX_SAFE_INTEGER);
+    let offset = 0;
+    let remaining = 1;
+    while (remaining > 0 && offset < bytes.length) {
+        remaining--;
+        const marker = bytes[offset++];
+        if (marker <= 0x7f || marker >= 0xe0 || (marker >= 0xc0 && marker <= 0xc3)) {
+            // Fixed integers, nil and booleans don't have a payload.
+        } else if (marker <= 0x8f) {
+            remaining += 2 * (marker & 0x0f);
+        } else if (marker <= 0x9f) {
+            remaining += marker & 0x0f;
+        } else if (marker <= 0xbf) {
+            offset += marker & 0x1f;
+        } else if (marker === 0xcf || marker === 0xd3) {
+            const value = marker === 0xcf ? view.getBigUint64(offset) : view.getBigInt64(offset);
+            if (value > max || value < -max) {
+                throw new RangeError(`64-bit integer ${value} exceeds the safe integer range`);
+            }
+            offset += 8;
+        } else {
+            switch (marker) {
+                case 0xc4: // bin 8
+                case 0xd9: // str 8
+                    offset += 1 + view.getUint8(offset);
+                    break;
+                case 0xc5: // bin 16
+                case 0xda: // str 16
+                    offset += 2 + view.getUint16(offset);
+                    break;
+                case 0xc6: // bin 32
+                case 0xdb: // str 32
+                    offset += 4 + view.getUint32(offset);
+                    break;
+                case 0xc7: // ext 8
+                    offset += 2 + view.getUint8(offset);
+                    break;
+                case 0xc8: // ext 16
+                    offset += 3 + view.getUint16(offset);
+                    break;
+                case 0xc9: // ext 32
+                    offset += 5 + view.getUint32(offset);
+                    break;
+                case 0xca: // float 32
+                case 0xce: // uint 32
+                case 0xd2: // int 32
+                    offset += 4;
+                    break;
+                case 0xcb: // float 64
+                    offset += 8;
+                    break;
+                case 0xcc: // uint 8
+                case 0xd0: // int 8
+                    offset += 1;
+                    break;
+                case 0xcd: // uint 16
+                case 0xd1: // int 16
+                    offset += 2;
+                    break;
+                case 0xd4: // fixext 1
+                case 0xd5: // fixext 2
+                case 0xd6: // fixext 4
+                case 0xd7: // fixext 8
+                case 0xd8: // fixext 16
+                    offset += 1 + (1 << (marker - 0xd4));
+                    break;
+                case 0xdc: // array 16
+                    remaining += view.getUint16(offset);
+                    offset += 2;
+                    break;
+                case 0xdd: // array 32
+                    remaining += view.getUint32(offset);
+                    offset += 4;
+                    break;
+                case 0xde: // map 16
+                    remaining += 2 * view.getUint16(offset);
+                    offset += 2;
+                    break;
+                case 0xdf: // map 32
+                    remaining += 2 * view.getUint32(offset);
+                    offset += 4;
+                    break;
+            }
+        }
+    }
+}
@@ -0,0 +1,623 @@
+// ============================================= //
+// Types for WebAssembly runtime                 //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+/**
+ * Example of numbers that cannot be represented as `f64` without losing
+ * precision.
+ *
+ * They are exchanged as strings, and typed as branded strings in TypeScript.
+ * Requires the `rust-decimal-compat` and `num-bigint-compat` features.
+ */
+export type ArbitraryPrecisionNumbers = {
+    readonly amount: DecimalString;
+    readonly total_supply: BigIntString;
+};
+
+/**
+ * The response to a batch of operations, some of which may have failed.
+ */
+export type BatchResponse = {
+    readonly results: ReadonlyArray<OperationResult>;
+    readonly summary: Result<string, string>;
+    readonly retryAfter: Result<number, string> | null;
+};
+
+export type BigIntString = string & { readonly __brand: "BigInt" };
+
+export type Body = ArrayBuffer;
+
+/**
+ * Std collections that are encoded as lists.
+ *
+ * Sets are generated as `Set` types in TypeScript if `generate_set_types` is
+ * enabled, while other lists are always generated as arrays.
+ */
+export type Collections = {
+    readonly tags: ReadonlySet<string>;
+    readonly optionalTags: ReadonlySet<string> | null;
+    readonly queue: ReadonlyArray<number>;
+};
+
+/**
+ * Fields and variants behind a `#[cfg(...)]` attribute are only included in
+ * the bindings if the protocol is compiled with the condition enabled.
+ *
+ * Using `#[fp(generators = "...")]`, fields and variants can also be limited
+ * to the bindings of specific generators.
+ */
+export type ConditionalFields = {
+    readonly name: string;
+};
+
+export enum ConditionalVariants {
+    Stable = "Stable",
+}
+
+export type CustomSerializers = {
+    /**
+     * Serialized as a Unix timestamp, instead of the RFC3339 string that is
+     * used for `OffsetDateTime` by default.
+     */
+    readonly timestamp: number;
+};
+
+export type DecimalString = string & { readonly __brand: "Decimal" };
+
+/**
+ * # This is an enum with doc comments.
+ */
+export type DocExampleEnum =
+    /**
+     * Multi-line doc comment with complex characters
+     * & " , \ ! '
+     */
+    | { readonly Variant1: string }
+    /**
+     * Raw identifiers are supported too.
+     */
+    | { readonly Variant2: {
+
+        /**
+         * Variant property.
+         */
+        readonly inner: number;
+    } };
+
+/**
+ * # This is a struct with doc comments.
+ */
+export type DocExampleStruct = {
+    /**
+     * Multi-line doc comment with complex characters
+     * & " , \ ! '
+     */
+    readonly multi_line: string;
+
+    /**
+     * Raw identifiers are supported too.
+     */
+    readonly type: string;
+};
+
+/**
+ * Example of values at the edges of what the runtimes can represent.
+ *
+ * `char` is exchanged as a single-character string, 128-bit integers are
+ * exchanged as decimal strings, and `f32` keeps NaN and infinities intact.
+ */
+export type EdgeValues = {
+    readonly character: string;
+    readonly float: number;
+    readonly signed: I128String;
+    readonly unsigned: U128String;
+};
+
+/**
+ * A point of an arbitrary type, with explicit trait bounds.
+ */
+export type ExplicitBoundPoint<T> = {
+    readonly value: T;
+};
+
+/**
+ * This struct is also not referenced by any function or data structure, but
+ * it will show up because there is an explicit `use` statement for it in the
+ * `fp_import!` macro.
+ */
+export type ExplicitedlyImportedType = {
+    readonly you_will_see_this: boolean;
+};
+
+/**
+ * The values that are returned by `export_multiple_values()`.
+ */
+export type ExportMultipleValuesReturn = [string, ReadonlyArray<number>, boolean];
+
+/**
+ * Settings for an extension whose shape is only known to the extension
+ * itself.
+ *
+ * `serde_json::Value` (and `rmpv::Value`) can be used anywhere in a protocol
+ * to pass values of arbitrary shape. Both are encoded as plain MessagePack,
+ * and show up as `unknown` in the TypeScript bindings.
+ */
+export type ExtensionSettings = {
+    readonly extension: string;
+    readonly settings: unknown;
+};
+
+export type FlattenedStruct = {
+    readonly foo: string;
+    readonly bar: number;
+};
+
+export type FloatingPoint = Point<number>;
+
+export type FpAdjacentlyTagged =
+    | { readonly type: "Foo" }
+    | { readonly type: "Bar"; readonly payload: string }
+    | { readonly type: "Baz"; readonly payload: { readonly a: number; readonly b: number } }
+    | { readonly type: "Pair"; readonly payload: [number, string] }
+    | { readonly type: "Unknown" };
+
+export type FpFlatten = {
+} & FlattenedStruct;
+
+export type FpInternallyTagged =
+    | { readonly type: "Foo" }
+    | { readonly type: "Baz"; readonly a: number; readonly b: number }
+    /**
+     * A variant that was added after these bindings were generated.
+     */
+    | { readonly type: string };
+
+export type FpPropertyRenaming = {
+    readonly fooBar: string;
+    readonly QUX_BAZ: number;
+    readonly rawStruct: number;
+};
+
+export type FpUntagged =
+    | string
+    | { readonly a: number; readonly b: number; }
+    | [number, string];
+
+export type FpVariantRenaming =
+    | "foo_bar"
+    | { readonly QUX_BAZ: {
+
+        /**
+         * Will be renamed to "FOO_BAR" because of the `rename_all` on the
+         * variant.
+         */
+        readonly FOO_BAR: string;
+        readonly qux_baz: number;
+    } };
+
+export type GroupImportedType1 = {
+    readonly you_will_see_this: boolean;
+};
+
+export type GroupImportedType2 = {
+    readonly you_will_see_this: boolean;
+};
+
+export type HttpResult = Result<Response, RequestError>;
+
+/**
+ * The values that are returned by `import_multiple_values()`.
+ */
+export type ImportMultipleValuesReturn = [string, ReadonlyArray<number>, boolean];
+
+export type Int64 = number | bigint;
+
+/**
+ * Example of integer types that are not primitives in the protocol.
+ *
+ * `usize` and `isize` are exchanged as `u32` and `i32`, because that is their
+ * size on `wasm32`. `NonZero` types refuse to deserialize when they are zero.
+ */
+export type IntegerTypes = {
+    readonly count: number;
+    readonly offset: number;
+    readonly id: number;
+    readonly delta: number;
+    readonly capacity: number | null;
+    readonly user_id: UserId;
+};
+
+export type Method = 
+    | "GET"
+    | "POST"
+    | "PUT"
+    | "DELETE"
+    | "HEAD"
+    | "OPTIONS"
+    | "CONNECT"
+    | "PATCH"
+    | "TRACE";
+
+/**
+ * A measurement that is reported by the plugin.
+ *
+ * Because the `name` field is annotated with `#[fp(interned)]`, the plugin
+ * registers every distinct name with the runtime once, and sends a `u32`
+ * handle in its place from then on. This keeps the payloads small when the
+ * same few names are reported over and over.
+ */
+export type Metric = {
+    readonly name: string;
+    readonly value: number;
+};
+
+/**
+ * Our struct for passing date time instances.
+ *
+ * We wrap the `OffsetDateTime` type in a new struct so that the Serde
+ * attributes can be inserted. These are necessary to enable RFC3339
+ * formatting. Without a wrapper type like this, we would not be able to pass
+ * date time instances directly to function arguments and we might run into
+ * trouble embedding them into certain generic types.
+ */
+export type MyDateTime = string;
+
+export type OperationResult = Result<number, string>;
+
+/**
+ * Config that is passed to the `init` export when the plugin is instantiated.
+ *
+ * Every protocol has `init` and `shutdown` exports, but protocols can declare
+ * `init` themselves to have it take a config argument like this one.
+ */
+export type PluginConfig = {
+    readonly logLevel: string;
+};
+
+/**
+ * A point of an arbitrary type.
+ */
+export type Point<T> = {
+    readonly value: T;
+};
+
+export enum Priority {
+    /**
+     * Handled after everything else.
+     */
+    Low = "low",
+    Normal = "normal",
+    High = "urgent",
+}
+
+/**
+ * Emitted by the plugin while it works on a long-running task.
+ *
+ * See the `report_progress` event in `main.rs` for more info.
+ */
+export type Progress = {
+    readonly task: string;
+    readonly completedSteps: number;
+    readonly totalSteps: number;
+};
+
+/**
+ * Error that is returned to the plugin when it calls a rate-limited import
+ * more often than its limit allows.
+ */
+export type RateLimited = {
+    /**
+     * The name of the import that was called.
+     */
+    readonly function: string;
+
+    /**
+     * The number of milliseconds after which the import may be called again.
+     */
+    readonly retryAfterMs: number;
+};
+
+/**
+ * Example for representing Redux actions.
+ */
+export type ReduxAction =
+    | { readonly type: "clear_title" }
+    | { readonly type: "update_title"; readonly payload: { readonly title: string } };
+
+/**
+ * Represents an HTTP request to be sent.
+ */
+export type Request = {
+    /**
+     * The URI to submit the request to.
+     */
+    readonly url: string;
+
+    /**
+     * HTTP method to use for the request.
+     */
+    readonly method: Method;
+
+    /**
+     * HTTP headers to submit with the request.
+     */
+    readonly headers: HeaderMap;
+
+    /**
+     * The body to submit with the request.
+     */
+    readonly body?: Body;
+};
+
+/**
+ * Represents an error that occurred while attempting to submit the request.
+ */
+export type RequestError =
+    /**
+     * Used when we know we don't have an active network connection.
+     */
+    | { readonly type: "offline" }
+    | { readonly type: "no_route" }
+    | { readonly type: "connection_refused" }
+    | { readonly type: "timeout" }
+    | {
+        readonly type: "server_error";
+
+        /**
+         * HTTP status code.
+         */
+        readonly status_code: number;
+
+        /**
+         * Response body.
+         */
+        readonly response: Body;
+    }
+    /**
+     * Misc.
+     */
+    | { readonly type: "other/misc"; readonly reason: string };
+
+/**
+ * Represents an HTTP response we received.
+ *
+ * Please note we currently do not support streaming responses.
+ */
+export type Response = {
+    /**
+     * The response body. May be empty.
+     */
+    readonly body: Body;
+
+    /**
+     * HTTP headers that were part of the response.
+     */
+    readonly headers: HeaderMap;
+
+    /**
+     * HTTP status code.
+     */
+    readonly status_code: number;
+};
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+export type Result<T, E> =
+    /**
+     * Represents a successful result.
+     */
+    | { readonly Ok: T }
+    /**
+     * Represents an error.
+     */
+    | { readonly Err: E };
+
+/**
+ * A sample of a time series.
+ *
+ * Because the struct is annotated with `#[fp(columnar)]`, every `Vec<Sample>`
+ * in the protocol is replaced with a generated `SampleColumns` struct, which
+ * holds a column with the values of every field. In TypeScript, the columns
+ * are typed arrays, so large lists of samples can be passed without encoding
+ * an object for every item.
+ */
+export type Sample = {
+    readonly timestampMs: number;
+    readonly value: number;
+    readonly quality: number;
+};
+
+/**
+ * The columns of a list of `Sample`, with the values of every field stored contiguously.
+ */
+export type SampleColumns = {
+    readonly timestampMs: BigUint64Array;
+    readonly value: Float64Array;
+    readonly quality: Uint8Array;
+};
+
+export type SerdeAdjacentlyTagged =
+    | { readonly type: "Foo" }
+    | { readonly type: "Bar"; readonly payload: string }
+    | { readonly type: "Baz"; readonly payload: { readonly a: number; readonly b: number } };
+
+export type SerdeFlatten = {
+} & FlattenedStruct;
+
+export type SerdeInternallyTagged =
+    | { readonly type: "Foo" }
+    | { readonly type: "Baz"; readonly a: number; readonly b: number };
+
+export type SerdePropertyRenaming = {
+    readonly fooBar: string;
+    readonly QUX_BAZ: number;
+    readonly rawStruct: number;
+};
+
+export type SerdeUntagged =
+    | string
+    | { readonly a: number; readonly b: number; };
+
+export type SerdeVariantRenaming =
+    | "foo_bar"
+    | { readonly QUX_BAZ: {
+
+        /**
+         * Will be renamed to "FooBar" because of the `rename_all` on the
+         * variant.
+         */
+        readonly FooBar: string;
+        readonly qux_baz: number;
+    } };
+
+/**
+ * A state update to communicate to the Redux host.
+ *
+ * Fields are wrapped in `Option`. If any field is `None` it means it hasn't
+ * changed.
+ */
+export type StateUpdate = {
+    readonly title: string | null;
+    readonly revision: number | null;
+};
+
+export type StructWithGenerics<T> = {
+    readonly list: ReadonlyArray<T>;
+    readonly points: ReadonlyArray<Point<T>>;
+    readonly recursive: ReadonlyArray<Point<Point<T>>>;
+    readonly complex_nested: Readonly<Record<string, ReadonlyArray<FloatingPoint>>> | null;
+    readonly optional_timestamp: MyDateTime | null;
+};
+
+export type StructWithOptions = {
+    readonly filledString?: string;
+    readonly emptyString?: string;
+    readonly filledOptionString?: string;
+    readonly emptyOptionString?: string;
+    readonly neverSkippedFilledOptionString: string | null;
+    readonly neverSkippedEmptyOptionString: string | null;
+};
+
+/**
+ * Timestamps that the TypeScript runtime represents as `Date` objects.
+ *
+ * Fields that are annotated with `ts_as = "Date"` are still serialized as
+ * RFC3339 strings, but the TypeScript runtime converts them into `Date`
+ * objects when they are received, and back into strings when they are sent.
+ */
+export type Timestamps = {
+    readonly createdAt: Date;
+    readonly deletedAt: Date | null;
+};
+
+/**
+ * An identifier of a resource of type `T`.
+ *
+ * `PhantomData` fields are not serialized, so they only show up in the Rust
+ * bindings, where they keep the type parameter in use. Other bindings only
+ * preserve the type parameter in the signature of the type.
+ */
+export type TypedId<T> = {
+    readonly id: number;
+};
+
+/**
+ * Example of a newtype that can be generated as a branded type in TypeScript.
+ *
+ * With `generate_branded_types`, it is typed as
+ * `number & { readonly __brand: "UserId" }`, so that it cannot be mixed up
+ * with other numbers.
+ */
+export type UserId = number & { readonly __brand: "UserId" };
+
+/**
+ * Structs that contain validated structs get validated as a whole.
+ *
+ * Fields that are not part of this struct are rejected, rather than silently
+ * ignored.
+ */
+export type ValidatedChild = {
+    readonly name: string;
+};
+
+/**
+ * Fields with `#[fp(validate(...))]` attributes are validated when they are
+ * received from the other side, so invalid values are rejected right away.
+ */
+export type ValidatedStruct = {
+    readonly percentage: number;
+    readonly slug: string;
+    readonly ratio: number | null;
+    readonly children: ReadonlyArray<ValidatedChild>;
+};
+
+export type HeaderMap = { [key: string]: Uint8Array };
+
+export type I128String = string & { readonly __brand: "i128" };
+
+export type U128String = string & { readonly __brand: "u128" };
+
+/**
+ * Hashes of the wire format of the protocol's types, which change whenever
+ * values serialized with an earlier version of a type may no longer be
+ * deserialized.
+ */
+export declare const SCHEMA_HASHES: {
+    readonly ArbitraryPrecisionNumbers: "db827ee0c172d54b";
+    readonly BatchResponse: "d01a07281c913349";
+    readonly Body: "4cca30865b1b5b29";
+    readonly Collections: "0c2e6754c3da68fb";
+    readonly ConditionalFields: "52a7d135f4e752db";
+    readonly ConditionalVariants: "ad94250852394a2c";
+    readonly CustomSerializers: "3995cbdfb49e6e86";
+    readonly DocExampleEnum: "8a247500e246a101";
+    readonly DocExampleStruct: "4d63c33e3821db51";
+    readonly EdgeValues: "3a668df6dcc030ca";
+    readonly ExplicitBoundPoint: "01ab567ecc0d57ce";
+    readonly ExplicitedlyImportedType: "870486c71032cd06";
+    readonly ExtensionSettings: "dd7f1ec765e3177b";
+    readonly FlattenedStruct: "3309edb927ca179e";
+    readonly FloatingPoint: "72901021b02749a4";
+    readonly FpAdjacentlyTagged: "606232f618d7e394";
+    readonly FpFlatten: "46a49ef4fae36d25";
+    readonly FpInternallyTagged: "5f9268fae07343f1";
+    readonly FpPropertyRenaming: "9ceeeeb504a46f92";
+    readonly FpUntagged: "17e08f97b5746b44";
+    readonly FpVariantRenaming: "874c2a453f4dee88";
+    readonly GroupImportedType1: "870486c71032cd06";
+    readonly GroupImportedType2: "870486c71032cd06";
+    readonly HttpResult: "0392c49138515915";
+    readonly Int64: "4d35d3193e8d66f2";
+    readonly IntegerTypes: "28d6bbb19affb584";
+    readonly Metric: "4b59598250e00632";
+    readonly MyDateTime: "909e6a1dd3c1b75c";
+    readonly OperationResult: "36591b64e87bbfd1";
+    readonly PluginConfig: "058a22f99a2bf4c8";
+    readonly Point: "01ab567ecc0d57ce";
+    readonly Priority: "4e502f2d76067ab7";
+    readonly Progress: "78c3c560d6cc3996";
+    readonly RateLimited: "53e94d7f4ad4119a";
+    readonly ReduxAction: "0352d4c53497dc88";
+    readonly Request: "f5b3e2e3135a53a4";
+    readonly RequestError: "52827bfb0a750040";
+    readonly Response: "600ce53352d19a88";
+    readonly Result: "0a54d7094a2e3b35";
+    readonly Sample: "6abd8e557be778d8";
+    readonly SerdeAdjacentlyTagged: "d8c14e6783129ce6";
+    readonly SerdeFlatten: "46a49ef4fae36d25";
+    readonly SerdeInternallyTagged: "5f9268fae07343f1";
+    readonly SerdePropertyRenaming: "9ceeeeb504a46f92";
+    readonly SerdeUntagged: "4b76b5be929dd50f";
+    readonly SerdeVariantRenaming: "a60f3b2a9bc7e9ab";
+    readonly StateUpdate: "3669f62b5f03b9d1";
+    readonly StructWithGenerics: "b93cc0c519e88f8a";
+    readonly StructWithOptions: "bd9f146b3efd1e10";
+    readonly Timestamps: "585a6e28d555d1e8";
+    readonly TypedId: "9ef66fb446ba7f9c";
+    readonly UserId: "b880dc810346cbbd";
+    readonly ValidatedChild: "7d2ecd620ba22cb3";
+    readonly ValidatedStruct: "229c94022f7b70c3";
+};
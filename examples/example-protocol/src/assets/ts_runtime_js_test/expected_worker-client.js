@@ -0,0 +1,203 @@
+// ============================================= //
+// Web Worker client for TypeScript              //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any
+
+import { encode } from "https://esm.sh/@msgpack/msgpack@2.7.2";
+import { toEncodable } from "./conversions.js";
+import { FPRuntimeError } from "./index.js";
+
+/**
+ * Asynchronous implementations of imports that the protocol declares as
+ * synchronous. They are called on the main thread, while the worker waits for
+ * them to settle.
+ */
+
+/**
+ * The names of the imports that are implemented on the main thread, and the
+ * buffer through which the worker is notified when a call has settled.
+ */
+
+/**
+ * The first message to the worker, with which the plugin is instantiated.
+ */
+
+/**
+ * The response to `WorkerInit`, with the names of the exports that the plugin
+ * implements, or the reason why it could not be instantiated.
+ */
+
+/**
+ * Sent by the worker when the plugin calls an import that is implemented on
+ * the main thread. If the result doesn't fit in the given buffer, the worker
+ * sends a larger buffer with `importResult`.
+ */
+
+/**
+ * Runs the given plugin inside a dedicated Web Worker, so that calls into the
+ * plugin don't block the main thread.
+ *
+ * The script of the worker needs to call `exposeRuntime()` from
+ * `worker-runtime.js` with the functions that may be imported by the
+ * plugin, because functions cannot be passed to the worker.
+ *
+ * The plugin is transferred to the worker, so the given buffer can no longer be
+ * used afterwards. Arguments are copied into the worker using the structured
+ * clone algorithm, while any `ArrayBuffer`s in return values are transferred
+ * back without copying them.
+ *
+ * Synchronous imports may be implemented asynchronously on the main thread,
+ * for instance to use `fetch()` or IndexedDB. While such an import is pending,
+ * the worker blocks using `Atomics.wait()`, so these require the page to be
+ * cross-origin isolated for `SharedArrayBuffer` to be available.
+ *
+ * @param worker The worker, which calls `exposeRuntime()`.
+ * @param plugin The raw WASM plugin.
+ * @param capabilities The capabilities that are granted to the plugin.
+ * @param asyncImports Asynchronous implementations of synchronous imports,
+ *                     which take precedence over the ones in the worker.
+ * @param msgpackOptions Options for encoding the results of `asyncImports`,
+ *                       which need to match the ones of the worker.
+ * @returns Asynchronous proxies for the functions exported by the plugin.
+ */
+export async function createWorkerClient(
+    worker,
+    plugin,
+    capabilities = [],
+    asyncImports = {},
+    msgpackOptions = {}
+) {
+    const asyncImportNames = Object.keys(asyncImports).filter(
+        (name) => typeof (asyncImports)[name] === "function"
+    );
+    const asyncImportsInit = asyncImportNames.length > 0
+        ? { names: asyncImportNames, signal: new SharedArrayBuffer(8) }
+        : undefined;
+
+    const availableExports = await new Promise((resolve, reject) => {
+        const onMessage = (event) => {
+            removeListeners();
+            const ready = event.data;
+            if (ready.error !== undefined) {
+                reject(new FPRuntimeError(ready.error));
+            } else {
+                resolve(ready.exports);
+            }
+        };
+        const onError = (event) => {
+            removeListeners();
+            reject(new FPRuntimeError(event.message));
+        };
+        const removeListeners = () => {
+            worker.removeEventListener("message", onMessage);
+            worker.removeEventListener("error", onError);
+        };
+        worker.addEventListener("message", onMessage);
+        worker.addEventListener("error", onError);
+
+        const init = { plugin, capabilities, asyncImports: asyncImportsInit };
+        worker.postMessage(init, [plugin]);
+    });
+
+    const pendingCalls = new Map();
+    let nextCallId = 0;
+
+    function rejectPendingCalls(error) {
+        for (const call of pendingCalls.values()) {
+            call.reject(error);
+        }
+        pendingCalls.clear();
+    }
+
+    // The result of an import call that didn't fit in the buffer of the
+    // worker, which is written once the worker sends a larger one:
+    let oversizedImportResult;
+
+    async function handleImportRequest(request, signal) {
+        if ("importResult" in request) {
+            if (oversizedImportResult) {
+                writeImportResult(signal, request.importResult.buffer, oversizedImportResult);
+                oversizedImportResult = undefined;
+            }
+            return;
+        }
+
+        const { name, args, buffer } = request.importCall;
+        let result;
+        try {
+            const implementation = (asyncImports)[name];
+            result = { status: IMPORT_READY, data: encode(toEncodable(await implementation(...args)), msgpackOptions) };
+        } catch (error) {
+            const message = error instanceof Error ? error.message : String(error);
+            result = { status: IMPORT_ERROR, data: encode(message, msgpackOptions) };
+        }
+
+        if (result.data.byteLength > buffer.byteLength) {
+            oversizedImportResult = result;
+            Atomics.store(signal, 1, result.data.byteLength);
+            Atomics.store(signal, 0, IMPORT_TOO_LARGE);
+            Atomics.notify(signal, 0);
+        } else {
+            writeImportResult(signal, buffer, result);
+        }
+    }
+
+    worker.addEventListener("message", (event) => {
+        const request = event.data;
+        if (asyncImportsInit && ("importCall" in request || "importResult" in request)) {
+            handleImportRequest(request, new Int32Array(asyncImportsInit.signal));
+            return;
+        }
+
+        const response = event.data;
+        const call = pendingCalls.get(response.id);
+        if (!call) {
+            return;
+        }
+
+        pendingCalls.delete(response.id);
+        if (response.error !== undefined) {
+            call.reject(new FPRuntimeError(response.error));
+        } else {
+            call.resolve(response.result);
+        }
+    });
+    worker.addEventListener("error", (event) => {
+        rejectPendingCalls(new FPRuntimeError(event.message));
+    });
+
+    const client = {
+        terminate: () => {
+            worker.terminate();
+            rejectPendingCalls(new FPRuntimeError("Worker was terminated"));
+        },
+    };
+    for (const name of availableExports) {
+        client[name] = (...args) => new Promise((resolve, reject) => {
+            const id = nextCallId++;
+            pendingCalls.set(id, { resolve, reject });
+            const request = { id, name, args };
+            worker.postMessage(request);
+        });
+    }
+    return client;
+}
+
+// The states of an import call that are signalled to the worker. These need to
+// match the ones in `worker-runtime`.
+const IMPORT_READY = 1;
+const IMPORT_ERROR = 2;
+const IMPORT_TOO_LARGE = 3;
+
+/**
+ * Writes the result of an import call to the buffer of the worker, and wakes
+ * up the worker.
+ */
+function writeImportResult(signal, buffer, result) {
+    new Uint8Array(buffer).set(result.data);
+    Atomics.store(signal, 1, result.data.byteLength);
+    Atomics.store(signal, 0, result.status);
+    Atomics.notify(signal, 0);
+}
@@ -0,0 +1,27 @@
+// ============================================= //
+// Web Worker runtime for TypeScript             //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import type { Imports, RuntimeOptions } from "./index.js";
+import type { AsyncImports } from "./worker-client.js";
+
+/**
+ * The host functions that are implemented inside the worker. Synchronous
+ * imports may be omitted if they are implemented on the main thread instead,
+ * by passing them to `createWorkerClient()`.
+ */
+export type WorkerImports = Omit<Imports, keyof AsyncImports> &
+    Partial<Pick<Imports, keyof AsyncImports>>;
+
+/**
+ * Instantiates the plugin that is sent by `createWorkerClient()`, and executes
+ * the calls to its exports. This needs to be called from the script of the
+ * worker.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @param options Options for the runtime, such as the options of the MessagePack
+ *                encoder and decoder.
+ */
+export declare function exposeRuntime(importFunctions: WorkerImports, options?: RuntimeOptions): void;
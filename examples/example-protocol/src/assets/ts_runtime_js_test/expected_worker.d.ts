@@ -0,0 +1,102 @@
+// ============================================= //
+// Node.js worker runtime for TypeScript         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import type { Capability, MemoryStats } from "./index.js";
+import type * as types from "./types.js";
+
+export type WorkerExports = {
+    exportArrayF32?: (arg: Float32Array) => Promise<Float32Array>;
+    exportArrayF64?: (arg: Float64Array) => Promise<Float64Array>;
+    exportArrayI16?: (arg: Int16Array) => Promise<Int16Array>;
+    exportArrayI32?: (arg: Int32Array) => Promise<Int32Array>;
+    exportArrayI8?: (arg: Int8Array) => Promise<Int8Array>;
+    exportArrayU16?: (arg: Uint16Array) => Promise<Uint16Array>;
+    exportArrayU32?: (arg: Uint32Array) => Promise<Uint32Array>;
+    exportArrayU8?: (arg: Uint8Array) => Promise<Uint8Array>;
+    exportAsyncStruct?: (arg1: types.FpPropertyRenaming, arg2: string) => Promise<types.FpPropertyRenaming>;
+    exportBatchResponse?: (arg: types.BatchResponse) => Promise<types.BatchResponse>;
+    exportCachedCallCount?: (label: string) => Promise<string>;
+    exportCallRateLimitedImport?: (calls: number) => Promise<ReadonlyArray<types.Result<string, types.RateLimited>>>;
+    exportCollections?: (arg: types.Collections) => Promise<types.Collections>;
+    exportDynamicValue?: (arg: types.ExtensionSettings) => Promise<unknown>;
+    exportEdgeValues?: (arg: types.EdgeValues) => Promise<types.EdgeValues>;
+    exportFpAdjacentlyTagged?: (arg: types.FpAdjacentlyTagged) => Promise<types.FpAdjacentlyTagged>;
+    exportFpEnum?: (arg: types.FpVariantRenaming) => Promise<types.FpVariantRenaming>;
+    exportFpFlatten?: (arg: types.FpFlatten) => Promise<types.FpFlatten>;
+    exportFpInternallyTagged?: (arg: types.FpInternallyTagged) => Promise<types.FpInternallyTagged>;
+    exportFpStruct?: (arg: types.FpPropertyRenaming) => Promise<types.FpPropertyRenaming>;
+    exportFpUntagged?: (arg: types.FpUntagged) => Promise<types.FpUntagged>;
+    exportGenerics?: (arg: types.StructWithGenerics<number>) => Promise<types.StructWithGenerics<number>>;
+    exportGetBytes?: () => Promise<types.Result<Uint8Array, string>>;
+    exportGetSerdeBytes?: () => Promise<types.Result<ArrayBuffer, string>>;
+    exportLazyString?: (arg: string) => Promise<string>;
+    exportMetrics?: (metrics: ReadonlyArray<types.Metric>) => Promise<ReadonlyArray<types.Metric>>;
+    exportMultiplePrimitives?: (arg1: number, arg2: string) => Promise<string>;
+    exportMultipleValues?: (arg: number) => Promise<types.ExportMultipleValuesReturn>;
+    exportPrimitiveBool?: (arg: boolean) => Promise<boolean>;
+    exportPrimitiveF32?: (arg: number) => Promise<number>;
+    exportPrimitiveF64?: (arg: number) => Promise<number>;
+    exportPrimitiveI16?: (arg: number) => Promise<number>;
+    exportPrimitiveI32?: (arg: number) => Promise<number>;
+    exportPrimitiveI64?: (arg: string) => Promise<string>;
+    exportPrimitiveI8?: (arg: number) => Promise<number>;
+    exportPrimitivePair?: (arg: number) => Promise<[number, number]>;
+    exportPrimitiveU16?: (arg: number) => Promise<number>;
+    exportPrimitiveU32?: (arg: number) => Promise<number>;
+    exportPrimitiveU64?: (arg: string) => Promise<string>;
+    exportPrimitiveU8?: (arg: number) => Promise<number>;
+    exportRenamedString?: (arg: string) => Promise<string>;
+    exportSamples?: (samples: types.SampleColumns) => Promise<types.SampleColumns>;
+    exportSerdeAdjacentlyTagged?: (arg: types.SerdeAdjacentlyTagged) => Promise<types.SerdeAdjacentlyTagged>;
+    exportSerdeEnum?: (arg: types.SerdeVariantRenaming) => Promise<types.SerdeVariantRenaming>;
+    exportSerdeFlatten?: (arg: types.SerdeFlatten) => Promise<types.SerdeFlatten>;
+    exportSerdeInternallyTagged?: (arg: types.SerdeInternallyTagged) => Promise<types.SerdeInternallyTagged>;
+    exportSerdeStruct?: (arg: types.SerdePropertyRenaming) => Promise<types.SerdePropertyRenaming>;
+    exportSerdeUntagged?: (arg: types.SerdeUntagged) => Promise<types.SerdeUntagged>;
+    exportString?: (arg: string) => Promise<string>;
+    exportStringAfterMemoryGrowth?: (arg: string) => Promise<string>;
+    exportStructWithOptions?: (arg: types.StructWithOptions) => Promise<types.StructWithOptions>;
+    exportTimestamp?: (arg: types.MyDateTime) => Promise<types.MyDateTime>;
+    exportTimestamps?: (arg: types.Timestamps) => Promise<types.Timestamps>;
+    exportTypedId?: (id: types.TypedId<types.Point<number>>) => Promise<types.TypedId<types.Point<number>>>;
+    exportValidatedStruct?: (arg: types.ValidatedStruct) => Promise<types.ValidatedStruct>;
+    exportVoidFunction?: () => Promise<void>;
+    fetchData?: (rType: string) => Promise<types.Result<string, string>>;
+    init?: (config: types.PluginConfig) => Promise<void>;
+    reducerBridge?: (action: types.ReduxAction) => Promise<types.StateUpdate>;
+    updateConfig?: (config: types.PluginConfig) => Promise<void>;
+    memoryStats?: () => Promise<MemoryStats>;
+    dispose: () => Promise<void>;
+};
+
+export type WorkerRuntime = WorkerExports & {
+    /**
+     * Stops the worker. Calls that are still pending are rejected.
+     */
+    terminate: () => Promise<void>;
+};
+
+/**
+ * Creates a runtime that executes the given plugin inside a `worker_threads`
+ * Worker, so that calls into the plugin don't block the event loop.
+ *
+ * The import functions are called from inside the worker as well, so they are
+ * loaded from a module that provides the `Imports` as its default export.
+ *
+ * Arguments are copied into the worker using the structured clone algorithm,
+ * while any `ArrayBuffer`s in return values are transferred back without
+ * copying them.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importsModule The URL of the module that provides the import functions.
+ * @param capabilities The capabilities that are granted to the plugin.
+ * @returns Asynchronous proxies for the functions exported by the plugin.
+ */
+export declare function createWorkerRuntime(
+    plugin: ArrayBuffer,
+    importsModule: URL | string,
+    capabilities?: Capability[]
+): Promise<WorkerRuntime>;
@@ -0,0 +1,204 @@
+// ============================================= //
+// Node.js worker runtime for TypeScript         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any
+
+import { isMainThread, parentPort, Worker, workerData } from "node:worker_threads";
+
+import { createRuntime, FPRuntimeError } from "./index.js";
+
+const EXPORT_NAMES = [
+    "exportArrayF32",
+    "exportArrayF64",
+    "exportArrayI16",
+    "exportArrayI32",
+    "exportArrayI8",
+    "exportArrayU16",
+    "exportArrayU32",
+    "exportArrayU8",
+    "exportAsyncStruct",
+    "exportBatchResponse",
+    "exportCachedCallCount",
+    "exportCallRateLimitedImport",
+    "exportCollections",
+    "exportDynamicValue",
+    "exportEdgeValues",
+    "exportFpAdjacentlyTagged",
+    "exportFpEnum",
+    "exportFpFlatten",
+    "exportFpInternallyTagged",
+    "exportFpStruct",
+    "exportFpUntagged",
+    "exportGenerics",
+    "exportGetBytes",
+    "exportGetSerdeBytes",
+    "exportLazyString",
+    "exportMetrics",
+    "exportMultiplePrimitives",
+    "exportMultipleValues",
+    "exportPrimitiveBool",
+    "exportPrimitiveF32",
+    "exportPrimitiveF64",
+    "exportPrimitiveI16",
+    "exportPrimitiveI32",
+    "exportPrimitiveI64",
+    "exportPrimitiveI8",
+    "exportPrimitivePair",
+    "exportPrimitiveU16",
+    "exportPrimitiveU32",
+    "exportPrimitiveU64",
+    "exportPrimitiveU8",
+    "exportRenamedString",
+    "exportSamples",
+    "exportSerdeAdjacentlyTagged",
+    "exportSerdeEnum",
+    "exportSerdeFlatten",
+    "exportSerdeInternallyTagged",
+    "exportSerdeStruct",
+    "exportSerdeUntagged",
+    "exportString",
+    "exportStringAfterMemoryGrowth",
+    "exportStructWithOptions",
+    "exportTimestamp",
+    "exportTimestamps",
+    "exportTypedId",
+    "exportValidatedStruct",
+    "exportVoidFunction",
+    "fetchData",
+    "init",
+    "reducerBridge",
+    "updateConfig",
+    "memoryStats",
+    "dispose",
+];
+
+/**
+ * Creates a runtime that executes the given plugin inside a `worker_threads`
+ * Worker, so that calls into the plugin don't block the event loop.
+ *
+ * The import functions are called from inside the worker as well, so they are
+ * loaded from a module that provides the `Imports` as its default export.
+ *
+ * Arguments are copied into the worker using the structured clone algorithm,
+ * while any `ArrayBuffer`s in return values are transferred back without
+ * copying them.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importsModule The URL of the module that provides the import functions.
+ * @param capabilities The capabilities that are granted to the plugin.
+ * @returns Asynchronous proxies for the functions exported by the plugin.
+ */
+export async function createWorkerRuntime(
+    plugin,
+    importsModule,
+    capabilities = []
+) {
+    const worker = new Worker(new URL(import.meta.url), {
+        workerData: { fpRuntime: { plugin, importsModule: importsModule.toString(), capabilities } },
+    });
+
+    const availableExports = await new Promise((resolve, reject) => {
+        worker.once("error", reject);
+        worker.once("message", (names) => {
+            worker.off("error", reject);
+            resolve(names);
+        });
+    });
+
+    const pendingCalls = new Map();
+    let nextCallId = 0;
+
+    function rejectPendingCalls(error) {
+        for (const call of pendingCalls.values()) {
+            call.reject(error);
+        }
+        pendingCalls.clear();
+    }
+
+    worker.on("message", (response) => {
+        const call = pendingCalls.get(response.id);
+        if (!call) {
+            return;
+        }
+
+        pendingCalls.delete(response.id);
+        if (response.error !== undefined) {
+            call.reject(new FPRuntimeError(response.error));
+        } else {
+            call.resolve(response.result);
+        }
+    });
+    worker.on("error", rejectPendingCalls);
+    worker.on("exit", (code) => {
+        rejectPendingCalls(new FPRuntimeError(`Worker exited with code ${code}`));
+    });
+
+    const runtime = {
+        terminate: async () => {
+            await worker.terminate();
+        },
+    };
+    for (const name of availableExports) {
+        runtime[name] = (...args) => new Promise((resolve, reject) => {
+            const id = nextCallId++;
+            pendingCalls.set(id, { resolve, reject });
+            const request = { id, name, args };
+            worker.postMessage(request);
+        });
+    }
+    return runtime;
+}
+
+async function runWorker(port, plugin, importsModule, capabilities) {
+    const module = await import(importsModule);
+    const runtime = await createRuntime(plugin, module.default, capabilities);
+    const exports = runtime;
+
+    port.on("message", async ({ id, name, args }) => {
+        try {
+            const result = await exports[name](...args);
+            const response = { id, result };
+            port.postMessage(response, collectTransferables(result));
+        } catch (error) {
+            const response = {
+                id,
+                error: error instanceof Error ? error.message : String(error),
+            };
+            port.postMessage(response);
+        }
+    });
+
+    port.postMessage(EXPORT_NAMES.filter((name) => typeof exports[name] === "function"));
+}
+
+/**
+ * Collects the `ArrayBuffer`s contained in a value, so they can be transferred
+ * to the main thread instead of being copied.
+ */
+function collectTransferables(value) {
+    const buffers = new Set();
+    const visit = (value) => {
+        if (value instanceof ArrayBuffer) {
+            buffers.add(value);
+        } else if (ArrayBuffer.isView(value)) {
+            if (value.buffer instanceof ArrayBuffer) {
+                buffers.add(value.buffer);
+            }
+        } else if (value instanceof Map) {
+            value.forEach((item, key) => {
+                visit(key);
+                visit(item);
+            });
+        } else if (value !== null && typeof value === "object") {
+            Object.values(value).forEach(visit);
+        }
+    };
+    visit(value);
+    return [...buffers];
+}
+
+if (!isMainThread && workerData?.fpRuntime) {
+    runWorker(parentPort, workerData.fpRuntime.plugin, workerData.fpRuntime.importsModule, workerData.fpRuntime.capabilities);
+}
@@ -0,0 +1,837 @@
+// ============================================= //
+// Memory helpers for WebAssembly runtime        //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any
+
+import { encode, decode, ExtensionCodec } from "https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts";
+import { toEncodable } from "./conversions.ts";
+
+export type FatPtr = bigint;
+
+/**
+ * Represents an unrecoverable error in the FP runtime.
+ *
+ * After this, your only recourse is to create a new runtime, probably with a different WASM plugin.
+ */
+export class FPRuntimeError extends Error {
+    constructor(message: string) {
+        super(message);
+    }
+}
+
+/**
+ * The kind of failure that is reported by a `GuestError`.
+ */
+export type GuestErrorKind =
+    | "deserialization"
+    | "serialization"
+    | "allocation"
+    | "invalid_pointer"
+    | "internal"
+    | "panic";
+
+/**
+ * Thrown when the plugin reports a failure before it aborts, which plugins do
+ * if they are built with the `panic-abort` feature of `fp-bindgen-support`.
+ */
+export class GuestError extends FPRuntimeError {
+    kind: GuestErrorKind;
+
+    constructor(kind: GuestErrorKind, message: string) {
+        super(`Plugin failed with ${kind} error: ${message}`);
+        this.kind = kind;
+    }
+}
+
+/**
+ * Thrown when the plugin fails to allocate the memory for a value that is
+ * passed to it, which it reports by returning a null pointer from its
+ * allocator.
+ */
+export class AllocationFailedError extends FPRuntimeError {
+    len: number;
+
+    constructor(len: number) {
+        super(`Plugin could not allocate ${len} bytes`);
+        this.len = len;
+    }
+}
+
+/**
+ * Thrown when the plugin does not export a function that is called.
+ */
+export class MissingExportError extends FPRuntimeError {
+    exportName: string;
+
+    constructor(exportName: string) {
+        super(`Plugin did not export expected symbol: "${exportName}"`);
+        this.exportName = exportName;
+    }
+}
+
+/**
+ * Thrown when the plugin exports a function with another signature than the
+ * one that is declared in the protocol.
+ */
+export class SignatureMismatchError extends FPRuntimeError {
+    exportName: string;
+
+    constructor(exportName: string) {
+        super(`Plugin exported symbol with an unexpected signature: "${exportName}"`);
+        this.exportName = exportName;
+    }
+}
+
+/**
+ * Thrown when a value that is returned by the plugin cannot be decoded.
+ */
+export class DeserializationError extends FPRuntimeError {
+    error: unknown;
+
+    constructor(error: unknown) {
+        super(`Could not decode value returned by plugin: ${error}`);
+        this.error = error;
+    }
+}
+
+/**
+ * Thrown when the plugin is called after it was disposed, and used to reject
+ * the promises that were still waiting for the plugin when it was disposed.
+ */
+export class PluginDisposedError extends FPRuntimeError {
+    constructor() {
+        super("Plugin was disposed");
+    }
+}
+
+/**
+ * An import of the plugin that the runtime does not provide, or provides with
+ * another signature than the plugin expects.
+ */
+export type ImportDiagnostic = {
+    module: string;
+    name: string;
+    /**
+     * The kind of import the plugin expects, together with the signature of
+     * functions, such as `function (i64) -> i64`.
+     */
+    expected: string;
+    /**
+     * The kind and signature of the import that is provided by the runtime, or
+     * `null` if it is missing.
+     */
+    provided: string | null;
+};
+
+/**
+ * Thrown when a plugin cannot be instantiated, because it expects imports that
+ * the runtime does not provide, or provides with another signature. This
+ * happens when the plugin was compiled against another version of the protocol,
+ * or with features whose imports were not passed to the runtime.
+ */
+export class InstantiationError extends FPRuntimeError {
+    diagnostics: ImportDiagnostic[];
+
+    constructor(diagnostics: ImportDiagnostic[]) {
+        super(
+            `Plugin could not be instantiated:\n${diagnostics.map(formatImportDiagnostic).join("\n")}`
+        );
+        this.diagnostics = diagnostics;
+    }
+}
+
+function formatImportDiagnostic({ module, name, expected, provided }: ImportDiagnostic): string {
+    return provided === null
+        ? `- missing import "${module}"."${name}": expected ${expected}`
+        : `- mismatched import "${module}"."${name}": expected ${expected}, but got ${provided}`;
+}
+
+/**
+ * Options for encoding the values that are passed to the plugin and decoding
+ * the values that are received from it, such as an `extensionCodec` with the
+ * MessagePack extensions that the plugin uses, or limits like `maxStrLength`.
+ */
+export type MsgpackOptions = NonNullable<Parameters<typeof encode>[1]> &
+    NonNullable<Parameters<typeof decode>[1]>;
+
+/**
+ * Options for instantiating a plugin.
+ */
+export type RuntimeOptions = {
+    msgpack?: MsgpackOptions;
+    /**
+     * The shared memory that the plugin imports. By default, a memory is
+     * created with the limits that the plugin declares for it.
+     */
+    memory?: WebAssembly.Memory;
+};
+
+/**
+ * A promise that is waiting for an async value of the plugin to be resolved.
+ */
+export type PendingPromise = {
+    resolve: (result: FatPtr) => void;
+    reject: (error: unknown) => void;
+};
+
+/**
+ * A plugin that was instantiated using `instantiatePlugin()`.
+ *
+ * The functions that call the exports of the plugin take the instance as their
+ * first argument, so that bundlers can drop the functions that are not used.
+ */
+export type PluginInstance = {
+    instance: WebAssembly.Instance;
+    memory: WebAssembly.Memory;
+    malloc: (len: number) => FatPtr;
+    free: (ptr: FatPtr) => void;
+    msgpackOptions: MsgpackOptions;
+    promises: Map<FatPtr, PendingPromise | FatPtr | GuestError>;
+    dynamicImportHandlers: Map<string, Map<string, (payload: any) => any>>;
+    eventHandlers: Map<string, Set<(event: any) => void>>;
+    disposed: boolean;
+};
+
+export function getExport<T>(plugin: PluginInstance, name: string, arity = -1): T {
+    if (plugin.disposed) {
+        throw new PluginDisposedError();
+    }
+    const exp = plugin.instance.exports[name];
+    if (!exp) {
+        throw new MissingExportError(name);
+    }
+    if (arity >= 0 && (typeof exp !== "function" || exp.length !== arity)) {
+        throw new SignatureMismatchError(name);
+    }
+    return exp as unknown as T;
+}
+
+export function hasExport(plugin: PluginInstance, name: string): boolean {
+    return !!plugin.instance.exports[name];
+}
+
+/**
+ * An import that is declared in the import section of a WebAssembly module.
+ */
+export type ModuleImport = {
+    module: string;
+    name: string;
+    kind: "function" | "table" | "memory" | "global" | "tag";
+    /**
+     * The signature of an imported function, such as `(i64, i32) -> i64`.
+     */
+    signature: string | null;
+    /**
+     * The limits of an imported table or memory.
+     */
+    limits: ImportLimits | null;
+};
+
+/**
+ * The limits of an imported table or memory, in elements or pages
+ * respectively. Only memories can be shared.
+ */
+export type ImportLimits = {
+    initial: number;
+    maximum: number | null;
+    shared: boolean;
+};
+
+/**
+ * Parses the imports of a WebAssembly module, including the signatures of the
+ * imported functions, which `WebAssembly.Module.imports()` does not provide.
+ */
+export function parseModuleImports(module: ArrayBuffer): ModuleImport[] {
+    const bytes = new Uint8Array(module);
+    let offset = 8; // Skips the magic number and the version.
+
+    const readByte = () => bytes[offset++];
+    const readUnsigned = () => {
+        let result = 0;
+        let factor = 1;
+        let byte: number;
+        do {
+            byte = readByte();
+            result += (byte & 0x7f) * factor;
+            factor *= 128;
+        } while (byte & 0x80);
+        return result;
+    };
+    const readName = () => {
+        const len = readUnsigned();
+        const name = new TextDecoder().decode(bytes.subarray(offset, offset + len));
+        offset += len;
+        return name;
+    };
+    const readValueTypes = () => {
+        const types: string[] = [];
+        for (let count = readUnsigned(); count > 0; count--) {
+            types.push(formatValueType(readByte()));
+        }
+        return types;
+    };
+    const readLimits = (): ImportLimits => {
+        const flags = readByte();
+        const initial = readUnsigned();
+        const maximum = flags & 0x01 ? readUnsigned() : null;
+        return { initial, maximum, shared: (flags & 0x02) !== 0 };
+    };
+
+    const signatures: string[] = [];
+    const imports: ModuleImport[] = [];
+    while (offset < bytes.length) {
+        const sectionId = readByte();
+        const sectionEnd = readUnsigned() + offset;
+        if (sectionId === 1) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                readByte(); // The form of the type, which is always a function.
+                const params = readValueTypes();
+                const results = readValueTypes();
+                signatures.push(
+                    `(${params.join(", ")}) -> ${
+                        results.length === 1 ? results[0] : `(${results.join(", ")})`
+                    }`
+                );
+            }
+        } else if (sectionId === 2) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                const module = readName();
+                const name = readName();
+                const kind = readByte();
+                if (kind === 0x00) {
+                    const signature = signatures[readUnsigned()] ?? null;
+                    imports.push({ module, name, kind: "function", signature, limits: null });
+                } else if (kind === 0x01) {
+                    readByte();
+                    const limits = readLimits();
+                    imports.push({ module, name, kind: "table", signature: null, limits });
+                } else if (kind === 0x02) {
+                    const limits = readLimits();
+                    imports.push({ module, name, kind: "memory", signature: null, limits });
+                } else if (kind === 0x03) {
+                    readByte();
+                    readByte();
+                    imports.push({ module, name, kind: "global", signature: null, limits: null });
+                } else {
+                    readByte();
+                    readUnsigned();
+                    imports.push({ module, name, kind: "tag", signature: null, limits: null });
+                }
+            }
+
+            // The import section is followed by sections we don't need:
+            break;
+        }
+        offset = sectionEnd;
+    }
+    return imports;
+}
+
+function formatValueType(type: number): string {
+    switch (type) {
+        case 0x7f:
+            return "i32";
+        case 0x7e:
+            return "i64";
+        case 0x7d:
+            return "f32";
+        case 0x7c:
+            return "f64";
+        case 0x7b:
+            return "v128";
+        case 0x70:
+            return "funcref";
+        case 0x6f:
+            return "externref";
+        default:
+            return `0x${type.toString(16)}`;
+    }
+}
+
+/**
+ * Checks whether the given imports provide everything the plugin imports, so
+ * that an `InstantiationError` that describes the missing and mismatched
+ * imports can be thrown instead of an opaque `LinkError`.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param imports The imports the plugin will be instantiated with.
+ * @param signatures The signatures of the functions in the `fp` module that the
+ *                   runtime provides, keyed by name.
+ */
+export function checkImports(
+    plugin: ArrayBuffer,
+    imports: WebAssembly.Imports,
+    signatures: Record<string, string>
+) {
+    const diagnostics: ImportDiagnostic[] = [];
+    for (const { module, name, kind, signature } of parseModuleImports(plugin)) {
+        const expected = signature === null ? kind : `${kind} ${signature}`;
+        const value = imports[module]?.[name];
+        if (value === undefined) {
+            diagnostics.push({ module, name, expected, provided: null });
+        } else if (kind === "function" && typeof value !== "function") {
+            diagnostics.push({ module, name, expected, provided: typeof value });
+        } else if (module === "fp" && name in signatures && signatures[name] !== signature) {
+            diagnostics.push({ module, name, expected, provided: `function ${signatures[name]}` });
+        }
+    }
+
+    if (diagnostics.length > 0) {
+        throw new InstantiationError(diagnostics);
+    }
+}
+
+/**
+ * The version of the async value protocol that is supported by this runtime.
+ */
+export const ASYNC_PROTOCOL_VERSION = 2;
+
+/**
+ * The status of an async value that failed, which holds a serialized
+ * `GuestError` instead of the result.
+ */
+const FUTURE_STATUS_ERROR = 2;
+
+export function createAsyncValue(plugin: PluginInstance): FatPtr {
+    const len = 12; // std::mem::size_of::<AsyncValue>()
+    const fatPtr = plugin.malloc(len);
+    const [ptr] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    buffer.fill(0);
+    return fatPtr;
+}
+
+export function interpretSign(num: number, cap: number) {
+    if (num < cap) {
+        return num;
+    } else {
+        return num - (cap << 1);
+    }
+}
+
+export function interpretBigSign(num: bigint, cap: bigint) {
+    if (num < cap) {
+        return num;
+    } else {
+        return num - (cap << 1n);
+    }
+}
+
+// Views on the plugin's memory must be acquired right before they are
+// used: whenever the plugin grows its memory, `memory.buffer` is replaced
+// and existing views become detached.
+function memoryView(plugin: PluginInstance, ptr: number, len: number): Uint8Array {
+    return new Uint8Array(plugin.memory.buffer, ptr, len);
+}
+
+export function parseObject<T>(plugin: PluginInstance, fatPtr: FatPtr): T {
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    // Without creating a copy of the memory, we risk corruption of any
+    // embedded `Uint8Array` objects returned from `decode()` after `free()`
+    // has been called :(
+    const copy = new Uint8Array(len);
+    copy.set(buffer);
+    plugin.free(fatPtr);
+    try {
+        return decode(copy, plugin.msgpackOptions) as unknown as T;
+    } catch (error) {
+        throw new DeserializationError(error);
+    }
+}
+
+export function promiseFromPtr(plugin: PluginInstance, ptr: FatPtr): Promise<FatPtr> {
+    const resultPtr = plugin.promises.get(ptr);
+    if (resultPtr) {
+        if (resultPtr instanceof GuestError) {
+            plugin.promises.delete(ptr);
+            return Promise.reject(resultPtr);
+        }
+        if (typeof resultPtr === "object") {
+            throw new FPRuntimeError("Already created promise for this value");
+        }
+
+        plugin.promises.delete(ptr);
+        return Promise.resolve(resultPtr);
+    } else {
+        return new Promise((resolve, reject) => {
+            plugin.promises.set(ptr, { resolve, reject });
+        });
+    }
+}
+
+/**
+ * Resolves the promise for an async value of the plugin. If the plugin marked
+ * the value as failed, the result holds a serialized error instead, with which
+ * the promise is rejected as a `GuestError`.
+ */
+export function resolvePromise(plugin: PluginInstance, asyncValuePtr: FatPtr, resultPtr: FatPtr) {
+    const pending = plugin.promises.get(asyncValuePtr);
+    if (pending && (typeof pending !== "object" || pending instanceof GuestError)) {
+        throw new FPRuntimeError("Tried to resolve invalid promise");
+    }
+
+    const [ptr] = fromFatPtr(asyncValuePtr);
+    const status = new DataView(plugin.memory.buffer).getUint32(ptr, true);
+    if (status === FUTURE_STATUS_ERROR) {
+        const { kind, message } = parseObject<{ kind: GuestErrorKind; message: string }>(plugin, resultPtr);
+        const error = new GuestError(kind, message);
+        if (pending) {
+            plugin.promises.delete(asyncValuePtr);
+            pending.reject(error);
+        } else {
+            plugin.promises.set(asyncValuePtr, error);
+        }
+    } else if (pending) {
+        plugin.promises.delete(asyncValuePtr);
+        pending.resolve(resultPtr);
+    } else {
+        plugin.promises.set(asyncValuePtr, resultPtr);
+    }
+}
+
+export function resolveFuture(plugin: PluginInstance, asyncValuePtr: FatPtr, resultPtr: FatPtr) {
+    // Async imports that complete after the plugin is disposed cannot be
+    // resumed anymore:
+    if (plugin.disposed) {
+        return;
+    }
+
+    const resolve = getExport<(asyncValuePtr: FatPtr, resultPtr: FatPtr) => void>(
+        plugin,
+        "__fp_guest_resolve_async_value"
+    );
+    resolve(asyncValuePtr, resultPtr);
+    notifyAsyncValue(plugin, asyncValuePtr);
+}
+
+/**
+ * Throws the `GuestError` that the plugin reports through the reserved
+ * `__fp_error()` import.
+ */
+export function throwGuestError(plugin: PluginInstance, reportPtr: FatPtr): never {
+    const { kind, message } = parseObject<{ kind: GuestErrorKind; message: string }>(plugin, reportPtr);
+    throw new GuestError(kind, message);
+}
+
+export function serializeObject<T>(plugin: PluginInstance, object: T): FatPtr {
+    return exportToMemory(plugin, encode(toEncodable(object), plugin.msgpackOptions));
+}
+
+export function exportToMemory(plugin: PluginInstance, serialized: Uint8Array): FatPtr {
+    const fatPtr = plugin.malloc(serialized.length);
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    buffer.set(serialized);
+    return fatPtr;
+}
+
+export function importFromMemory(plugin: PluginInstance, fatPtr: FatPtr): Uint8Array {
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    const copy = new Uint8Array(len);
+    copy.set(buffer);
+    plugin.free(fatPtr);
+    return copy;
+}
+
+/**
+ * Returns the handlers that are registered for the given dynamic import.
+ */
+export function getDynamicImportHandlers(
+    plugin: PluginInstance,
+    importName: string
+): Map<string, (payload: any) => any> {
+    let handlers = plugin.dynamicImportHandlers.get(importName);
+    if (!handlers) {
+        handlers = new Map();
+        plugin.dynamicImportHandlers.set(importName, handlers);
+    }
+    return handlers;
+}
+
+/**
+ * Rejects the promises that are still waiting for the plugin with a
+ * `PluginDisposedError`, frees the results that were never awaited and drops
+ * the registered handlers, so nothing keeps their closures alive. Afterwards,
+ * `getExport()` throws a `PluginDisposedError`.
+ */
+export function disposePlugin(plugin: PluginInstance) {
+    const error = new PluginDisposedError();
+    for (const pending of plugin.promises.values()) {
+        if (pending instanceof GuestError) {
+            continue;
+        } else if (typeof pending === "object") {
+            pending.reject(error);
+        } else {
+            plugin.free(pending);
+        }
+    }
+    plugin.promises.clear();
+    plugin.dynamicImportHandlers.clear();
+    plugin.eventHandlers.clear();
+    plugin.disposed = true;
+}
+
+/**
+ * Returns the handlers that are subscribed to the given event.
+ */
+export function getEventHandlers(
+    plugin: PluginInstance,
+    eventName: string
+): Set<(event: any) => void> {
+    let handlers = plugin.eventHandlers.get(eventName);
+    if (!handlers) {
+        handlers = new Set();
+        plugin.eventHandlers.set(eventName, handlers);
+    }
+    return handlers;
+}
+
+function fromFatPtr(fatPtr: FatPtr): [ptr: number, len: number] {
+    return [
+        Number.parseInt((fatPtr >> 32n).toString()),
+        Number.parseInt((fatPtr & 0xffff_ffffn).toString()),
+    ];
+}
+
+function toFatPtr(ptr: number, len: number): FatPtr {
+    return (BigInt(ptr) << 32n) | BigInt(len);
+}
+
+type CachedResult = {
+    expiresAt: number;
+    value: unknown;
+};
+
+const exportCaches = new WeakMap<PluginInstance, Map<string, CachedResult>>();
+
+/**
+ * Returns the result of a previous call to the given export with the same key,
+ * unless its time-to-live has passed. Otherwise, the export is called and its
+ * result is stored, unless it fails.
+ */
+export function cachedExport<T>(
+    plugin: PluginInstance,
+    exportName: string,
+    key: unknown[],
+    ttlMs: number,
+    call: () => T
+): T {
+    let cache = exportCaches.get(plugin);
+    if (!cache) {
+        cache = new Map<string, CachedResult>();
+        exportCaches.set(plugin, cache);
+    }
+
+    const now = Date.now();
+    const encodedKey = encode(toEncodable(key), plugin.msgpackOptions);
+    const cacheKey = `${exportName}:${Array.from(encodedKey, (byte) => byte.toString(16).padStart(2, "0")).join("")}`;
+    const entry = cache.get(cacheKey);
+    if (entry && entry.expiresAt > now) {
+        return entry.value as T;
+    }
+
+    for (const [otherKey, otherEntry] of cache) {
+        if (otherEntry.expiresAt <= now) {
+            cache.delete(otherKey);
+        }
+    }
+
+    const value = call();
+    const stored = { expiresAt: now + ttlMs, value };
+    cache.set(cacheKey, stored);
+    if (value instanceof Promise) {
+        const entries = cache;
+        value.catch(() => {
+            if (entries.get(cacheKey) === stored) {
+                entries.delete(cacheKey);
+            }
+        });
+    }
+    return value;
+}
+
+/**
+ * Discards the memoized results of the cached exports of the given plugin.
+ */
+export function clearCachedExports(plugin: PluginInstance) {
+    exportCaches.delete(plugin);
+}
+
+type TokenBucket = {
+    tokens: number;
+    refilledAt: number;
+};
+
+const rateLimitBuckets = new WeakMap<PluginInstance, Map<string, TokenBucket>>();
+
+/**
+ * Takes a token from the bucket of the given import, which holds up to `calls`
+ * tokens and is refilled with `calls` tokens every `perMs` milliseconds.
+ *
+ * Returns the `RateLimited` error for the plugin if no token is available, or
+ * `undefined` if the import may be called.
+ */
+export function rateLimit(
+    plugin: PluginInstance,
+    importName: string,
+    calls: number,
+    perMs: number
+): { Err: { function: string; retryAfterMs: number } } | undefined {
+    let buckets = rateLimitBuckets.get(plugin);
+    if (!buckets) {
+        buckets = new Map<string, TokenBucket>();
+        rateLimitBuckets.set(plugin, buckets);
+    }
+
+    const now = Date.now();
+    const tokensPerMs = calls / perMs;
+    let bucket = buckets.get(importName);
+    if (!bucket) {
+        bucket = { tokens: calls, refilledAt: now };
+        buckets.set(importName, bucket);
+    }
+    bucket.tokens = Math.min(calls, bucket.tokens + (now - bucket.refilledAt) * tokensPerMs);
+    bucket.refilledAt = now;
+
+    if (bucket.tokens >= 1) {
+        bucket.tokens -= 1;
+        return undefined;
+    }
+
+    const retryAfterMs = Math.ceil((1 - bucket.tokens) / tokensPerMs);
+    return { Err: { function: importName, retryAfterMs } };
+}
+
+type ColumnArray =
+    | Int8Array
+    | Int16Array
+    | Uint16Array
+    | Int32Array
+    | Uint32Array
+    | BigInt64Array
+    | BigUint64Array
+    | Float32Array
+    | Float64Array;
+
+type ColumnArrayConstructor = new (buffer: ArrayBuffer) => ColumnArray;
+
+/**
+ * The typed arrays in which columns are decoded, by the type of the MessagePack
+ * extension in which they are encoded. Columns of `u8` values are encoded as
+ * plain binary data, which decodes into a `Uint8Array` already.
+ */
+const COLUMN_ARRAYS: Array<[number, ColumnArrayConstructor]> = [
+    [16, Int8Array],
+    [17, Int16Array],
+    [18, Uint16Array],
+    [19, Int32Array],
+    [20, Uint32Array],
+    [21, BigInt64Array],
+    [22, BigUint64Array],
+    [23, Float32Array],
+    [24, Float64Array],
+];
+
+/**
+ * Returns the given options with an `extensionCodec` that encodes and decodes
+ * the columns of `#[fp(columnar)]` structs as typed arrays. Extensions of other
+ * types are still handled by the `extensionCodec` of the given options.
+ *
+ * Typed arrays use the byte order of the platform, which is little-endian on
+ * every platform that runs WebAssembly, just like the columns.
+ */
+export function withColumnExtensions(options: MsgpackOptions): MsgpackOptions {
+    const columnCodec = new ExtensionCodec();
+    for (const [type, ColumnArray] of COLUMN_ARRAYS) {
+        columnCodec.register({
+            type,
+            encode: (value: unknown) =>
+                value instanceof ColumnArray
+                    ? new Uint8Array(value.buffer, value.byteOffset, value.byteLength)
+                    : null,
+            decode: (data: Uint8Array) => new ColumnArray(data.slice().buffer),
+        });
+    }
+
+    const extensionCodec = options.extensionCodec;
+    if (!extensionCodec) {
+        return { ...options, extensionCodec: columnCodec };
+    }
+
+    return {
+        ...options,
+        extensionCodec: {
+            tryToEncode: (object: unknown, context: any) =>
+                columnCodec.tryToEncode(object, context) ?? extensionCodec.tryToEncode(object, context),
+            decode: (data: Uint8Array, type: number, context: any) =>
+                COLUMN_ARRAYS.some(([columnType]) => columnType === type)
+                    ? columnCodec.decode(data, type, context)
+                    : extensionCodec.decode(data, type, context),
+        },
+    };
+}
+
+/**
+ * Creates the shared memory that is imported by a plugin that is compiled with
+ * Wasm threads, using the limits that the plugin declares for it.
+ */
+export function createSharedMemory(plugin: ArrayBuffer): WebAssembly.Memory {
+    const memoryImport = parseModuleImports(plugin).find(
+        ({ module, name, kind }) => module === "env" && name === "memory" && kind === "memory"
+    );
+    const limits = memoryImport?.limits;
+    if (!limits?.shared) {
+        throw new FPRuntimeError(
+            "Plugin does not import a shared memory, it needs to be compiled with Wasm threads"
+        );
+    }
+
+    return new WebAssembly.Memory({
+        initial: limits.initial,
+        maximum: limits.maximum ?? undefined,
+        shared: true,
+    });
+}
+
+/**
+ * Wakes up the plugin threads that are blocked on the given async value, after
+ * it has been resolved.
+ */
+function notifyAsyncValue(plugin: PluginInstance, asyncValuePtr: FatPtr) {
+    const [ptr] = fromFatPtr(asyncValuePtr);
+    // The status of the async value is its first field:
+    Atomics.notify(new Int32Array(plugin.memory.buffer, ptr, 1), 0);
+}
+
+export function packPair<A, B>(
+    pair: [A, B],
+    firstToBits: (value: A) => number,
+    secondToBits: (value: B) => number
+): bigint {
+    return (BigInt(firstToBits(pair[0]) >>> 0) << 32n) | BigInt(secondToBits(pair[1]) >>> 0);
+}
+
+export function unpackPair<A, B>(
+    packed: bigint,
+    firstFromBits: (bits: number) => A,
+    secondFromBits: (bits: number) => B
+): [A, B] {
+    const bits = BigInt.asUintN(64, packed);
+    return [firstFromBits(Number(bits >> 32n)), secondFromBits(Number(bits & 0xffff_ffffn))];
+}
+
+const f32Bits = /* @__PURE__ */ new DataView(new ArrayBuffer(4));
+
+export function f32FromBits(bits: number): number {
+    f32Bits.setUint32(0, bits);
+    return f32Bits.getFloat32(0);
+}
+
+export function f32ToBits(value: number): number {
+    f32Bits.setFloat32(0, value);
+    return f32Bits.getUint32(0);
+}
@@ -0,0 +1,125 @@
+// ============================================= //
+// Conversions for WebAssembly runtime           //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any
+
+import type * as types from "./types.ts";
+
+type Converter = (value: any) => any;
+
+/**
+ * Converts an RFC3339 string into a date.
+ */
+export function toDate(value: string): Date {
+    return new Date(value);
+}
+
+/**
+ * Returns a converter that turns an array into a set, converting its items
+ * using the given converter.
+ */
+export function toSet(convertItem?: Converter): Converter {
+    return (items) => new Set(convertItem ? items.map(convertItem) : items);
+}
+
+/**
+ * Returns a converter that converts the items of an array.
+ */
+export function mapArray(convertItem: Converter): Converter {
+    return (items) => items.map(convertItem);
+}
+
+/**
+ * Returns a converter that converts a value unless it is `null` or
+ * `undefined`.
+ */
+export function mapNullable(convert: Converter): Converter {
+    return (value) => (value == null ? value : convert(value));
+}
+
+/**
+ * Returns a converter that converts the values of a record.
+ */
+export function mapRecord(convertValue: Converter): Converter {
+    return (record) =>
+        Object.fromEntries(Object.entries(record).map(([key, value]) => [key, convertValue(value)]));
+}
+
+/**
+ * Converts all the sets in the given value back into arrays, and all the dates
+ * back into RFC3339 strings, so they can be encoded for the plugin.
+ */
+export function toEncodable(value: any): any {
+    if (value instanceof Set) {
+        return Array.from(value, toEncodable);
+    } else if (value instanceof Date) {
+        return value.toISOString();
+    } else if (Array.isArray(value)) {
+        return value.map(toEncodable);
+    } else if (value != null && Object.getPrototypeOf(value) === Object.prototype) {
+        return Object.fromEntries(
+            Object.entries(value).map(([key, item]) => [key, toEncodable(item)])
+        );
+    } else {
+        return value;
+    }
+}
+
+const internedStrings: string[] = [];
+const internHandles = new Map<string, number>();
+
+/**
+ * Registers a string that is interned by the plugin, and returns the handle
+ * that the plugin sends in its place from now on. Handles are shared by all
+ * plugins that are loaded by this runtime.
+ */
+export function internString(value: string): number {
+    let handle = internHandles.get(value);
+    if (handle === undefined) {
+        handle = internedStrings.length;
+        internedStrings.push(value);
+        internHandles.set(value, handle);
+    }
+    return handle;
+}
+
+/**
+ * Converts the handle of an interned string back into the string. Strings
+ * that were sent as they are are returned unchanged.
+ */
+export function fromInterned(value: string | number): string {
+    if (typeof value === "string") {
+        return value;
+    }
+
+    const string = internedStrings[value];
+    if (string === undefined) {
+        throw new Error(`Unknown interned string handle: ${value}`);
+    }
+    return string;
+}
+
+export function convertCollections(value: any): types.Collections {
+    return {
+        ...value,
+        "tags": toSet()(value["tags"]),
+        "optionalTags": mapNullable(toSet())(value["optionalTags"]),
+    };
+}
+
+export function convertMetric(value: any): types.Metric {
+    return {
+        ...value,
+        "name": fromInterned(value["name"]),
+    };
+}
+
+export function convertTimestamps(value: any): types.Timestamps {
+    return {
+        ...value,
+        "createdAt": toDate(value["createdAt"]),
+        "deletedAt": mapNullable(toDate)(value["deletedAt"]),
+    };
+}
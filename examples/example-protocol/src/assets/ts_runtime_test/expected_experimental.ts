@@ -0,0 +1,90 @@
+// ============================================= //
+// Feature `experimental`                        //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any no-unused-vars
+
+import {
+    createAsyncValue,
+    exportToMemory,
+    FPRuntimeError,
+    getExport,
+    hasExport,
+    importFromMemory,
+    interpretBigSign,
+    interpretSign,
+    parseObject,
+    promiseFromPtr,
+    resolveFuture,
+    serializeObject,
+    f32FromBits,
+    f32ToBits,
+    packPair,
+    unpackPair,
+    cachedExport,
+    clearCachedExports,
+    rateLimit,
+    traceGuestPayload,
+} from "./memory.ts";
+import type { FatPtr, PluginInstance } from "./memory.ts";
+import type * as types from "./types.ts";
+import { validateValidatedChild, validateValidatedStruct } from "./validation.ts";
+import { fromInterned, internString, mapArray, mapNullable, mapRecord, toDate, toSet, convertCollections, convertMetric, convertTimestamps } from "./conversions.ts";
+import type { FeatureImports } from "./index.ts";
+
+export type ExperimentalImports = {
+    importExperimental: (arg: string) => string;
+};
+
+/**
+ * Creates the functions that are imported by plugins that were compiled with
+ * the `experimental` feature, which call the given host functions.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @returns The imports to pass to `instantiatePlugin()` or `createRuntime()`.
+ */
+export function createExperimentalImports(importFunctions: ExperimentalImports): FeatureImports {
+    return (plugin) => ({
+        __fp_gen_import_experimental: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<string>(plugin, traceGuestPayload(plugin, "import_experimental", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_experimental", "result", "toPlugin", serializeObject(plugin, importFunctions.importExperimental(arg)));
+        },
+    });
+}
+
+export type ExperimentalExports = {
+    exportExperimental?: (arg: string) => string;
+    exportExperimentalRaw?: (arg: Uint8Array) => Uint8Array;
+};
+
+/**
+ * Returns the functions that are exported by the given plugin instance, if it
+ * was compiled with the `experimental` feature.
+ *
+ * Functions that the plugin does not implement are `undefined`.
+ */
+export function createExperimentalExports(plugin: PluginInstance): ExperimentalExports {
+    return {
+        exportExperimental: hasExport(plugin, "__fp_gen_export_experimental")
+            ? (arg) => exportExperimental(plugin, arg)
+            : undefined,
+        exportExperimentalRaw: hasExport(plugin, "__fp_gen_export_experimental")
+            ? (arg) => exportExperimentalRaw(plugin, arg)
+            : undefined,
+    };
+}
+
+export function exportExperimental(plugin: PluginInstance, arg: string): string {
+    return callExport("exportExperimental", "string", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_experimental", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_experimental", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<string>(plugin, traceGuestPayload(plugin, "export_experimental", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportExperimentalRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_experimental");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
@@ -0,0 +1,1505 @@
+// ============================================= //
+// Exports for WebAssembly runtime               //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any no-unused-vars
+
+import {
+    DeserializationError,
+    FPRuntimeError,
+    GuestError,
+    MissingExportError,
+    PluginDisposedError,
+    SignatureMismatchError,
+    disposePlugin,
+    exportToMemory,
+    getDynamicImportHandlers,
+    getEventHandlers,
+    getExport,
+    hasExport,
+    importFromMemory,
+    interpretBigSign,
+    interpretSign,
+    parseObject,
+    promiseFromPtr,
+    serializeObject,
+    f32FromBits,
+    unpackPair,
+    cachedExport,
+    clearCachedExports,
+    rateLimit,
+    traceGuestPayload,
+} from "./memory.ts";
+import type { FatPtr, PluginInstance } from "./memory.ts";
+import type * as types from "./types.ts";
+import { validateValidatedChild, validateValidatedStruct } from "./validation.ts";
+import { fromInterned, internString, mapArray, mapNullable, mapRecord, toDate, toSet, convertCollections, convertMetric, convertTimestamps } from "./conversions.ts";
+
+/**
+ * Statistics about the memory that is allocated by a plugin.
+ */
+export type MemoryStats = {
+    allocatedBytes: number;
+    peakAllocatedBytes: number;
+    allocationCount: number;
+};
+
+/**
+ * Metrics about the calls that a plugin has made to the imports of the
+ * runtime, as measured by the plugin itself.
+ */
+export type GuestMetrics = {
+    imports: Record<string, ImportMetrics>;
+};
+
+/**
+ * Metrics about the calls that a plugin has made to a single import.
+ * Durations are in nanoseconds.
+ */
+export type ImportMetrics = {
+    callCount: number;
+    totalDurationNanos: number;
+    maxDurationNanos: number;
+};
+
+/**
+ * The events that may be emitted by the plugin, keyed by name.
+ */
+export type Events = {
+    reportProgress: types.Progress;
+};
+
+export type Exports = {
+    exportArrayF32?: (arg: Float32Array) => Float32Array;
+    exportArrayF64?: (arg: Float64Array) => Float64Array;
+    exportArrayI16?: (arg: Int16Array) => Int16Array;
+    exportArrayI32?: (arg: Int32Array) => Int32Array;
+    exportArrayI8?: (arg: Int8Array) => Int8Array;
+    exportArrayU16?: (arg: Uint16Array) => Uint16Array;
+    exportArrayU32?: (arg: Uint32Array) => Uint32Array;
+    exportArrayU8?: (arg: Uint8Array) => Uint8Array;
+    exportAsyncStruct?: (arg1: types.FpPropertyRenaming, arg2: bigint) => Promise<types.FpPropertyRenaming>;
+    exportBatchResponse?: (arg: types.BatchResponse) => types.BatchResponse;
+    exportCachedCallCount?: (label: string) => string;
+    exportCallRateLimitedImport?: (calls: number) => Array<types.Result<string, types.RateLimited>>;
+    exportCollections?: (arg: types.Collections) => types.Collections;
+    exportDynamicValue?: (arg: types.ExtensionSettings) => unknown;
+    exportEdgeValues?: (arg: types.EdgeValues) => types.EdgeValues;
+    exportFpAdjacentlyTagged?: (arg: types.FpAdjacentlyTagged) => types.FpAdjacentlyTagged;
+    exportFpEnum?: (arg: types.FpVariantRenaming) => types.FpVariantRenaming;
+    exportFpFlatten?: (arg: types.FpFlatten) => types.FpFlatten;
+    exportFpInternallyTagged?: (arg: types.FpInternallyTagged) => types.FpInternallyTagged;
+    exportFpStruct?: (arg: types.FpPropertyRenaming) => types.FpPropertyRenaming;
+    exportFpUntagged?: (arg: types.FpUntagged) => types.FpUntagged;
+    exportGenerics?: (arg: types.StructWithGenerics<number>) => types.StructWithGenerics<number>;
+    exportGetBytes?: () => types.Result<Uint8Array, string>;
+    exportGetSerdeBytes?: () => types.Result<ArrayBuffer, string>;
+    exportLazyString?: (arg: string) => string;
+    exportMetrics?: (metrics: Array<types.Metric>) => Array<types.Metric>;
+    exportMultiplePrimitives?: (arg1: number, arg2: string) => bigint;
+    exportMultipleValues?: (arg: number) => types.ExportMultipleValuesReturn;
+    exportPrimitiveBool?: (arg: boolean) => boolean;
+    exportPrimitiveF32?: (arg: number) => number;
+    exportPrimitiveF64?: (arg: number) => number;
+    exportPrimitiveI16?: (arg: number) => number;
+    exportPrimitiveI32?: (arg: number) => number;
+    exportPrimitiveI64?: (arg: bigint) => bigint;
+    exportPrimitiveI8?: (arg: number) => number;
+    exportPrimitivePair?: (arg: number) => [number, number];
+    exportPrimitiveU16?: (arg: number) => number;
+    exportPrimitiveU32?: (arg: number) => number;
+    exportPrimitiveU64?: (arg: bigint) => bigint;
+    exportPrimitiveU8?: (arg: number) => number;
+    exportRenamedString?: (arg: string) => string;
+    exportSamples?: (samples: types.SampleColumns) => types.SampleColumns;
+    exportSerdeAdjacentlyTagged?: (arg: types.SerdeAdjacentlyTagged) => types.SerdeAdjacentlyTagged;
+    exportSerdeEnum?: (arg: types.SerdeVariantRenaming) => types.SerdeVariantRenaming;
+    exportSerdeFlatten?: (arg: types.SerdeFlatten) => types.SerdeFlatten;
+    exportSerdeInternallyTagged?: (arg: types.SerdeInternallyTagged) => types.SerdeInternallyTagged;
+    exportSerdeStruct?: (arg: types.SerdePropertyRenaming) => types.SerdePropertyRenaming;
+    exportSerdeUntagged?: (arg: types.SerdeUntagged) => types.SerdeUntagged;
+    exportString?: (arg: string) => string;
+    exportStringAfterMemoryGrowth?: (arg: string) => string;
+    exportStructWithOptions?: (arg: types.StructWithOptions) => types.StructWithOptions;
+    exportTimestamp?: (arg: types.MyDateTime) => types.MyDateTime;
+    exportTimestamps?: (arg: types.Timestamps) => types.Timestamps;
+    exportTypedId?: (id: types.TypedId<types.Point<number>>) => types.TypedId<types.Point<number>>;
+    exportValidatedStruct?: (arg: types.ValidatedStruct) => types.ValidatedStruct;
+    exportVoidFunction?: () => void;
+    fetchData?: (rType: string) => Promise<types.Result<string, string>>;
+    init?: (config: types.PluginConfig) => void;
+    reducerBridge?: (action: types.ReduxAction) => types.StateUpdate;
+    updateConfig?: (config: types.PluginConfig) => void;
+    registerInvokeCommand: (name: string, handler: (payload: string) => types.Result<string, string>) => void;
+    unregisterInvokeCommand: (name: string) => boolean;
+    on: <E extends keyof Events>(eventName: E, handler: (event: Events[E]) => void) => void;
+    off: <E extends keyof Events>(eventName: E, handler: (event: Events[E]) => void) => boolean;
+    memoryStats?: () => MemoryStats;
+    guestMetrics?: () => GuestMetrics;
+    dispose: () => void;
+    missingExports: () => string[];
+    clearExportCache: () => void;
+    exportArrayF32Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayF64Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayI16Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayI32Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayI8Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayU16Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayU32Raw?: (arg: Uint8Array) => Uint8Array;
+    exportArrayU8Raw?: (arg: Uint8Array) => Uint8Array;
+    exportAsyncStructRaw?: (arg1: Uint8Array, arg2: bigint) => Promise<Uint8Array>;
+    exportBatchResponseRaw?: (arg: Uint8Array) => Uint8Array;
+    exportCachedCallCountRaw?: (label: Uint8Array) => Uint8Array;
+    exportCallRateLimitedImportRaw?: (calls: number) => Uint8Array;
+    exportCollectionsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportDynamicValueRaw?: (arg: Uint8Array) => Uint8Array;
+    exportEdgeValuesRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpAdjacentlyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpEnumRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpFlattenRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpInternallyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpStructRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFpUntaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportGenericsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportGetBytesRaw?: () => Uint8Array;
+    exportGetSerdeBytesRaw?: () => Uint8Array;
+    exportLazyStringRaw?: (arg: Uint8Array) => Uint8Array;
+    exportMetricsRaw?: (metrics: Uint8Array) => Uint8Array;
+    exportMultiplePrimitivesRaw?: (arg1: number, arg2: Uint8Array) => bigint;
+    exportMultipleValuesRaw?: (arg: number) => Uint8Array;
+    exportPrimitiveBoolRaw?: (arg: boolean) => boolean;
+    exportPrimitiveI16Raw?: (arg: number) => number;
+    exportPrimitiveI32Raw?: (arg: number) => number;
+    exportPrimitiveI64Raw?: (arg: bigint) => bigint;
+    exportPrimitiveI8Raw?: (arg: number) => number;
+    exportPrimitivePairRaw?: (arg: number) => [number, number];
+    exportRenamedStringRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSamplesRaw?: (samples: Uint8Array) => Uint8Array;
+    exportSerdeAdjacentlyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeEnumRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeFlattenRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeInternallyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeStructRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeUntaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStringRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStringAfterMemoryGrowthRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStructWithOptionsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportTimestampRaw?: (arg: Uint8Array) => Uint8Array;
+    exportTimestampsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportTypedIdRaw?: (id: Uint8Array) => Uint8Array;
+    exportValidatedStructRaw?: (arg: Uint8Array) => Uint8Array;
+    fetchDataRaw?: (rType: Uint8Array) => Promise<Uint8Array>;
+    initRaw?: (config: Uint8Array) => void;
+    reducerBridgeRaw?: (action: Uint8Array) => Uint8Array;
+    updateConfigRaw?: (config: Uint8Array) => void;
+};
+
+/**
+ * The name of a function that is exported by the plugin.
+ */
+export type ExportName = keyof Exports;
+
+/**
+ * The kind of failure that is reported by a `PluginError`.
+ *
+ * Failures that are not caused by the plugin itself, such as imports that are
+ * denied or arguments that the plugin cannot allocate, are reported as
+ * `"transport"` errors.
+ */
+export type PluginErrorKind =
+    | "missing_export"
+    | "signature_mismatch"
+    | "guest_trap"
+    | "guest_panic"
+    | "deserialization"
+    | "disposed"
+    | "transport";
+
+/**
+ * Thrown when calling an export of the plugin fails. The error that caused
+ * the failure is available as `error`.
+ */
+export class PluginError extends FPRuntimeError {
+    kind: PluginErrorKind;
+    exportName: ExportName;
+    typeName: string | undefined;
+    error: unknown;
+
+    constructor(kind: PluginErrorKind, exportName: ExportName, error: unknown, typeName: string | undefined) {
+        super(`Export "${exportName}" failed with ${kind} error: ${error instanceof Error ? error.message : error}`);
+        this.kind = kind;
+        this.exportName = exportName;
+        this.typeName = typeName;
+        this.error = error;
+    }
+}
+
+/**
+ * Calls an export of the plugin, and turns the errors it throws into a
+ * `PluginError`. The `typeName` is the type of the value that is returned by
+ * the export, if any.
+ */
+function callExport<T>(exportName: ExportName, typeName: string | undefined, call: () => T): T {
+    try {
+        const result = call();
+        if (result instanceof Promise) {
+            return result.catch((error) => {
+                throw toPluginError(exportName, typeName, error);
+            }) as T;
+        }
+        return result;
+    } catch (error) {
+        throw toPluginError(exportName, typeName, error);
+    }
+}
+
+function toPluginError(exportName: ExportName, typeName: string | undefined, error: unknown): PluginError {
+    if (error instanceof PluginError) {
+        return error;
+    } else if (error instanceof MissingExportError) {
+        return new PluginError("missing_export", exportName, error, undefined);
+    } else if (error instanceof SignatureMismatchError) {
+        return new PluginError("signature_mismatch", exportName, error, undefined);
+    } else if (error instanceof PluginDisposedError) {
+        return new PluginError("disposed", exportName, error, undefined);
+    } else if (error instanceof GuestError) {
+        return new PluginError("guest_panic", exportName, error, undefined);
+    } else if (error instanceof DeserializationError) {
+        return new PluginError("deserialization", exportName, error, typeName);
+    } else if (error instanceof WebAssembly.RuntimeError) {
+        return new PluginError("guest_trap", exportName, error, undefined);
+    } else {
+        return new PluginError("transport", exportName, error, undefined);
+    }
+}
+
+/**
+ * Returns the functions that are exported by the given plugin instance.
+ *
+ * Functions that the plugin does not implement are `undefined`. Hosts that
+ * only call some of the exports may prefer to call the functions below
+ * directly, so that the others can be dropped from their bundle.
+ */
+export function createExports(plugin: PluginInstance): Exports {
+    return {
+        exportArrayF32: hasExport(plugin, "__fp_gen_export_array_f32")
+            ? (arg) => exportArrayF32(plugin, arg)
+            : undefined,
+        exportArrayF64: hasExport(plugin, "__fp_gen_export_array_f64")
+            ? (arg) => exportArrayF64(plugin, arg)
+            : undefined,
+        exportArrayI16: hasExport(plugin, "__fp_gen_export_array_i16")
+            ? (arg) => exportArrayI16(plugin, arg)
+            : undefined,
+        exportArrayI32: hasExport(plugin, "__fp_gen_export_array_i32")
+            ? (arg) => exportArrayI32(plugin, arg)
+            : undefined,
+        exportArrayI8: hasExport(plugin, "__fp_gen_export_array_i8")
+            ? (arg) => exportArrayI8(plugin, arg)
+            : undefined,
+        exportArrayU16: hasExport(plugin, "__fp_gen_export_array_u16")
+            ? (arg) => exportArrayU16(plugin, arg)
+            : undefined,
+        exportArrayU32: hasExport(plugin, "__fp_gen_export_array_u32")
+            ? (arg) => exportArrayU32(plugin, arg)
+            : undefined,
+        exportArrayU8: hasExport(plugin, "__fp_gen_export_array_u8")
+            ? (arg) => exportArrayU8(plugin, arg)
+            : undefined,
+        exportAsyncStruct: hasExport(plugin, "__fp_gen_export_async_struct")
+            ? (arg1, arg2) => exportAsyncStruct(plugin, arg1, arg2)
+            : undefined,
+        exportBatchResponse: hasExport(plugin, "__fp_gen_export_batch_response")
+            ? (arg) => exportBatchResponse(plugin, arg)
+            : undefined,
+        exportCachedCallCount: hasExport(plugin, "__fp_gen_export_cached_call_count")
+            ? (label) => exportCachedCallCount(plugin, label)
+            : undefined,
+        exportCallRateLimitedImport: hasExport(plugin, "__fp_gen_export_call_rate_limited_import")
+            ? (calls) => exportCallRateLimitedImport(plugin, calls)
+            : undefined,
+        exportCollections: hasExport(plugin, "__fp_gen_export_collections")
+            ? (arg) => exportCollections(plugin, arg)
+            : undefined,
+        exportDynamicValue: hasExport(plugin, "__fp_gen_export_dynamic_value")
+            ? (arg) => exportDynamicValue(plugin, arg)
+            : undefined,
+        exportEdgeValues: hasExport(plugin, "__fp_gen_export_edge_values")
+            ? (arg) => exportEdgeValues(plugin, arg)
+            : undefined,
+        exportFpAdjacentlyTagged: hasExport(plugin, "__fp_gen_export_fp_adjacently_tagged")
+            ? (arg) => exportFpAdjacentlyTagged(plugin, arg)
+            : undefined,
+        exportFpEnum: hasExport(plugin, "__fp_gen_export_fp_enum")
+            ? (arg) => exportFpEnum(plugin, arg)
+            : undefined,
+        exportFpFlatten: hasExport(plugin, "__fp_gen_export_fp_flatten")
+            ? (arg) => exportFpFlatten(plugin, arg)
+            : undefined,
+        exportFpInternallyTagged: hasExport(plugin, "__fp_gen_export_fp_internally_tagged")
+            ? (arg) => exportFpInternallyTagged(plugin, arg)
+            : undefined,
+        exportFpStruct: hasExport(plugin, "__fp_gen_export_fp_struct")
+            ? (arg) => exportFpStruct(plugin, arg)
+            : undefined,
+        exportFpUntagged: hasExport(plugin, "__fp_gen_export_fp_untagged")
+            ? (arg) => exportFpUntagged(plugin, arg)
+            : undefined,
+        exportGenerics: hasExport(plugin, "__fp_gen_export_generics")
+            ? (arg) => exportGenerics(plugin, arg)
+            : undefined,
+        exportGetBytes: hasExport(plugin, "__fp_gen_export_get_bytes")
+            ? () => exportGetBytes(plugin)
+            : undefined,
+        exportGetSerdeBytes: hasExport(plugin, "__fp_gen_export_get_serde_bytes")
+            ? () => exportGetSerdeBytes(plugin)
+            : undefined,
+        exportLazyString: hasExport(plugin, "__fp_gen_export_lazy_string")
+            ? (arg) => exportLazyString(plugin, arg)
+            : undefined,
+        exportMetrics: hasExport(plugin, "__fp_gen_export_metrics")
+            ? (metrics) => exportMetrics(plugin, metrics)
+            : undefined,
+        exportMultiplePrimitives: hasExport(plugin, "__fp_gen_export_multiple_primitives")
+            ? (arg1, arg2) => exportMultiplePrimitives(plugin, arg1, arg2)
+            : undefined,
+        exportMultipleValues: hasExport(plugin, "__fp_gen_export_multiple_values")
+            ? (arg) => exportMultipleValues(plugin, arg)
+            : undefined,
+        exportPrimitiveBool: hasExport(plugin, "__fp_gen_export_primitive_bool")
+            ? (arg) => exportPrimitiveBool(plugin, arg)
+            : undefined,
+        exportPrimitiveF32: plugin.instance.exports.__fp_gen_export_primitive_f32 as any,
+        exportPrimitiveF64: plugin.instance.exports.__fp_gen_export_primitive_f64 as any,
+        exportPrimitiveI16: hasExport(plugin, "__fp_gen_export_primitive_i16")
+            ? (arg) => exportPrimitiveI16(plugin, arg)
+            : undefined,
+        exportPrimitiveI32: hasExport(plugin, "__fp_gen_export_primitive_i32")
+            ? (arg) => exportPrimitiveI32(plugin, arg)
+            : undefined,
+        exportPrimitiveI64: hasExport(plugin, "__fp_gen_export_primitive_i64")
+            ? (arg) => exportPrimitiveI64(plugin, arg)
+            : undefined,
+        exportPrimitiveI8: hasExport(plugin, "__fp_gen_export_primitive_i8")
+            ? (arg) => exportPrimitiveI8(plugin, arg)
+            : undefined,
+        exportPrimitivePair: hasExport(plugin, "__fp_gen_export_primitive_pair")
+            ? (arg) => exportPrimitivePair(plugin, arg)
+            : undefined,
+        exportPrimitiveU16: plugin.instance.exports.__fp_gen_export_primitive_u16 as any,
+        exportPrimitiveU32: plugin.instance.exports.__fp_gen_export_primitive_u32 as any,
+        exportPrimitiveU64: plugin.instance.exports.__fp_gen_export_primitive_u64 as any,
+        exportPrimitiveU8: plugin.instance.exports.__fp_gen_export_primitive_u8 as any,
+        exportRenamedString: hasExport(plugin, "__fp_gen_export_legacy_string")
+            ? (arg) => exportRenamedString(plugin, arg)
+            : undefined,
+        exportSamples: hasExport(plugin, "__fp_gen_export_samples")
+            ? (samples) => exportSamples(plugin, samples)
+            : undefined,
+        exportSerdeAdjacentlyTagged: hasExport(plugin, "__fp_gen_export_serde_adjacently_tagged")
+            ? (arg) => exportSerdeAdjacentlyTagged(plugin, arg)
+            : undefined,
+        exportSerdeEnum: hasExport(plugin, "__fp_gen_export_serde_enum")
+            ? (arg) => exportSerdeEnum(plugin, arg)
+            : undefined,
+        exportSerdeFlatten: hasExport(plugin, "__fp_gen_export_serde_flatten")
+            ? (arg) => exportSerdeFlatten(plugin, arg)
+            : undefined,
+        exportSerdeInternallyTagged: hasExport(plugin, "__fp_gen_export_serde_internally_tagged")
+            ? (arg) => exportSerdeInternallyTagged(plugin, arg)
+            : undefined,
+        exportSerdeStruct: hasExport(plugin, "__fp_gen_export_serde_struct")
+            ? (arg) => exportSerdeStruct(plugin, arg)
+            : undefined,
+        exportSerdeUntagged: hasExport(plugin, "__fp_gen_export_serde_untagged")
+            ? (arg) => exportSerdeUntagged(plugin, arg)
+            : undefined,
+        exportString: hasExport(plugin, "__fp_gen_export_string")
+            ? (arg) => exportString(plugin, arg)
+            : undefined,
+        exportStringAfterMemoryGrowth: hasExport(plugin, "__fp_gen_export_string_after_memory_growth")
+            ? (arg) => exportStringAfterMemoryGrowth(plugin, arg)
+            : undefined,
+        exportStructWithOptions: hasExport(plugin, "__fp_gen_export_struct_with_options")
+            ? (arg) => exportStructWithOptions(plugin, arg)
+            : undefined,
+        exportTimestamp: hasExport(plugin, "__fp_gen_export_timestamp")
+            ? (arg) => exportTimestamp(plugin, arg)
+            : undefined,
+        exportTimestamps: hasExport(plugin, "__fp_gen_export_timestamps")
+            ? (arg) => exportTimestamps(plugin, arg)
+            : undefined,
+        exportTypedId: hasExport(plugin, "__fp_gen_export_typed_id")
+            ? (id) => exportTypedId(plugin, id)
+            : undefined,
+        exportValidatedStruct: hasExport(plugin, "__fp_gen_export_validated_struct")
+            ? (arg) => exportValidatedStruct(plugin, arg)
+            : undefined,
+        exportVoidFunction: plugin.instance.exports.__fp_gen_export_void_function as any,
+        fetchData: hasExport(plugin, "__fp_gen_fetch_data")
+            ? (rType) => fetchData(plugin, rType)
+            : undefined,
+        init: hasExport(plugin, "__fp_gen_init")
+            ? (config) => init(plugin, config)
+            : undefined,
+        reducerBridge: hasExport(plugin, "__fp_gen_reducer_bridge")
+            ? (action) => reducerBridge(plugin, action)
+            : undefined,
+        updateConfig: hasExport(plugin, "__fp_gen_update_config")
+            ? (config) => updateConfig(plugin, config)
+            : undefined,
+        registerInvokeCommand: (name, handler) => registerInvokeCommand(plugin, name, handler),
+        unregisterInvokeCommand: (name) => unregisterInvokeCommand(plugin, name),
+        on: (eventName, handler) => on(plugin, eventName, handler),
+        off: (eventName, handler) => off(plugin, eventName, handler),
+        memoryStats: hasExport(plugin, "__fp_memory_stats")
+            ? () => memoryStats(plugin)
+            : undefined,
+        guestMetrics: hasExport(plugin, "__fp_get_guest_metrics")
+            ? () => guestMetrics(plugin)
+            : undefined,
+        dispose: () => dispose(plugin),
+        missingExports: () => missingExports(plugin),
+        clearExportCache: () => clearExportCache(plugin),
+        exportArrayF32Raw: hasExport(plugin, "__fp_gen_export_array_f32")
+            ? (arg) => exportArrayF32Raw(plugin, arg)
+            : undefined,
+        exportArrayF64Raw: hasExport(plugin, "__fp_gen_export_array_f64")
+            ? (arg) => exportArrayF64Raw(plugin, arg)
+            : undefined,
+        exportArrayI16Raw: hasExport(plugin, "__fp_gen_export_array_i16")
+            ? (arg) => exportArrayI16Raw(plugin, arg)
+            : undefined,
+        exportArrayI32Raw: hasExport(plugin, "__fp_gen_export_array_i32")
+            ? (arg) => exportArrayI32Raw(plugin, arg)
+            : undefined,
+        exportArrayI8Raw: hasExport(plugin, "__fp_gen_export_array_i8")
+            ? (arg) => exportArrayI8Raw(plugin, arg)
+            : undefined,
+        exportArrayU16Raw: hasExport(plugin, "__fp_gen_export_array_u16")
+            ? (arg) => exportArrayU16Raw(plugin, arg)
+            : undefined,
+        exportArrayU32Raw: hasExport(plugin, "__fp_gen_export_array_u32")
+            ? (arg) => exportArrayU32Raw(plugin, arg)
+            : undefined,
+        exportArrayU8Raw: hasExport(plugin, "__fp_gen_export_array_u8")
+            ? (arg) => exportArrayU8Raw(plugin, arg)
+            : undefined,
+        exportAsyncStructRaw: hasExport(plugin, "__fp_gen_export_async_struct")
+            ? (arg1, arg2) => exportAsyncStructRaw(plugin, arg1, arg2)
+            : undefined,
+        exportBatchResponseRaw: hasExport(plugin, "__fp_gen_export_batch_response")
+            ? (arg) => exportBatchResponseRaw(plugin, arg)
+            : undefined,
+        exportCachedCallCountRaw: hasExport(plugin, "__fp_gen_export_cached_call_count")
+            ? (label) => exportCachedCallCountRaw(plugin, label)
+            : undefined,
+        exportCallRateLimitedImportRaw: hasExport(plugin, "__fp_gen_export_call_rate_limited_import")
+            ? (calls) => exportCallRateLimitedImportRaw(plugin, calls)
+            : undefined,
+        exportCollectionsRaw: hasExport(plugin, "__fp_gen_export_collections")
+            ? (arg) => exportCollectionsRaw(plugin, arg)
+            : undefined,
+        exportDynamicValueRaw: hasExport(plugin, "__fp_gen_export_dynamic_value")
+            ? (arg) => exportDynamicValueRaw(plugin, arg)
+            : undefined,
+        exportEdgeValuesRaw: hasExport(plugin, "__fp_gen_export_edge_values")
+            ? (arg) => exportEdgeValuesRaw(plugin, arg)
+            : undefined,
+        exportFpAdjacentlyTaggedRaw: hasExport(plugin, "__fp_gen_export_fp_adjacently_tagged")
+            ? (arg) => exportFpAdjacentlyTaggedRaw(plugin, arg)
+            : undefined,
+        exportFpEnumRaw: hasExport(plugin, "__fp_gen_export_fp_enum")
+            ? (arg) => exportFpEnumRaw(plugin, arg)
+            : undefined,
+        exportFpFlattenRaw: hasExport(plugin, "__fp_gen_export_fp_flatten")
+            ? (arg) => exportFpFlattenRaw(plugin, arg)
+            : undefined,
+        exportFpInternallyTaggedRaw: hasExport(plugin, "__fp_gen_export_fp_internally_tagged")
+            ? (arg) => exportFpInternallyTaggedRaw(plugin, arg)
+            : undefined,
+        exportFpStructRaw: hasExport(plugin, "__fp_gen_export_fp_struct")
+            ? (arg) => exportFpStructRaw(plugin, arg)
+            : undefined,
+        exportFpUntaggedRaw: hasExport(plugin, "__fp_gen_export_fp_untagged")
+            ? (arg) => exportFpUntaggedRaw(plugin, arg)
+            : undefined,
+        exportGenericsRaw: hasExport(plugin, "__fp_gen_export_generics")
+            ? (arg) => exportGenericsRaw(plugin, arg)
+            : undefined,
+        exportGetBytesRaw: hasExport(plugin, "__fp_gen_export_get_bytes")
+            ? () => exportGetBytesRaw(plugin)
+            : undefined,
+        exportGetSerdeBytesRaw: hasExport(plugin, "__fp_gen_export_get_serde_bytes")
+            ? () => exportGetSerdeBytesRaw(plugin)
+            : undefined,
+        exportLazyStringRaw: hasExport(plugin, "__fp_gen_export_lazy_string")
+            ? (arg) => exportLazyStringRaw(plugin, arg)
+            : undefined,
+        exportMetricsRaw: hasExport(plugin, "__fp_gen_export_metrics")
+            ? (metrics) => exportMetricsRaw(plugin, metrics)
+            : undefined,
+        exportMultiplePrimitivesRaw: hasExport(plugin, "__fp_gen_export_multiple_primitives")
+            ? (arg1, arg2) => exportMultiplePrimitivesRaw(plugin, arg1, arg2)
+            : undefined,
+        exportMultipleValuesRaw: hasExport(plugin, "__fp_gen_export_multiple_values")
+            ? (arg) => exportMultipleValuesRaw(plugin, arg)
+            : undefined,
+        exportPrimitiveBoolRaw: hasExport(plugin, "__fp_gen_export_primitive_bool")
+            ? (arg) => exportPrimitiveBoolRaw(plugin, arg)
+            : undefined,
+        exportPrimitiveI16Raw: hasExport(plugin, "__fp_gen_export_primitive_i16")
+            ? (arg) => exportPrimitiveI16Raw(plugin, arg)
+            : undefined,
+        exportPrimitiveI32Raw: hasExport(plugin, "__fp_gen_export_primitive_i32")
+            ? (arg) => exportPrimitiveI32Raw(plugin, arg)
+            : undefined,
+        exportPrimitiveI64Raw: hasExport(plugin, "__fp_gen_export_primitive_i64")
+            ? (arg) => exportPrimitiveI64Raw(plugin, arg)
+            : undefined,
+        exportPrimitiveI8Raw: hasExport(plugin, "__fp_gen_export_primitive_i8")
+            ? (arg) => exportPrimitiveI8Raw(plugin, arg)
+            : undefined,
+        exportPrimitivePairRaw: hasExport(plugin, "__fp_gen_export_primitive_pair")
+            ? (arg) => exportPrimitivePairRaw(plugin, arg)
+            : undefined,
+        exportRenamedStringRaw: hasExport(plugin, "__fp_gen_export_legacy_string")
+            ? (arg) => exportRenamedStringRaw(plugin, arg)
+            : undefined,
+        exportSamplesRaw: hasExport(plugin, "__fp_gen_export_samples")
+            ? (samples) => exportSamplesRaw(plugin, samples)
+            : undefined,
+        exportSerdeAdjacentlyTaggedRaw: hasExport(plugin, "__fp_gen_export_serde_adjacently_tagged")
+            ? (arg) => exportSerdeAdjacentlyTaggedRaw(plugin, arg)
+            : undefined,
+        exportSerdeEnumRaw: hasExport(plugin, "__fp_gen_export_serde_enum")
+            ? (arg) => exportSerdeEnumRaw(plugin, arg)
+            : undefined,
+        exportSerdeFlattenRaw: hasExport(plugin, "__fp_gen_export_serde_flatten")
+            ? (arg) => exportSerdeFlattenRaw(plugin, arg)
+            : undefined,
+        exportSerdeInternallyTaggedRaw: hasExport(plugin, "__fp_gen_export_serde_internally_tagged")
+            ? (arg) => exportSerdeInternallyTaggedRaw(plugin, arg)
+            : undefined,
+        exportSerdeStructRaw: hasExport(plugin, "__fp_gen_export_serde_struct")
+            ? (arg) => exportSerdeStructRaw(plugin, arg)
+            : undefined,
+        exportSerdeUntaggedRaw: hasExport(plugin, "__fp_gen_export_serde_untagged")
+            ? (arg) => exportSerdeUntaggedRaw(plugin, arg)
+            : undefined,
+        exportStringRaw: hasExport(plugin, "__fp_gen_export_string")
+            ? (arg) => exportStringRaw(plugin, arg)
+            : undefined,
+        exportStringAfterMemoryGrowthRaw: hasExport(plugin, "__fp_gen_export_string_after_memory_growth")
+            ? (arg) => exportStringAfterMemoryGrowthRaw(plugin, arg)
+            : undefined,
+        exportStructWithOptionsRaw: hasExport(plugin, "__fp_gen_export_struct_with_options")
+            ? (arg) => exportStructWithOptionsRaw(plugin, arg)
+            : undefined,
+        exportTimestampRaw: hasExport(plugin, "__fp_gen_export_timestamp")
+            ? (arg) => exportTimestampRaw(plugin, arg)
+            : undefined,
+        exportTimestampsRaw: hasExport(plugin, "__fp_gen_export_timestamps")
+            ? (arg) => exportTimestampsRaw(plugin, arg)
+            : undefined,
+        exportTypedIdRaw: hasExport(plugin, "__fp_gen_export_typed_id")
+            ? (id) => exportTypedIdRaw(plugin, id)
+            : undefined,
+        exportValidatedStructRaw: hasExport(plugin, "__fp_gen_export_validated_struct")
+            ? (arg) => exportValidatedStructRaw(plugin, arg)
+            : undefined,
+        fetchDataRaw: hasExport(plugin, "__fp_gen_fetch_data")
+            ? (rType) => fetchDataRaw(plugin, rType)
+            : undefined,
+        initRaw: hasExport(plugin, "__fp_gen_init")
+            ? (config) => initRaw(plugin, config)
+            : undefined,
+        reducerBridgeRaw: hasExport(plugin, "__fp_gen_reducer_bridge")
+            ? (action) => reducerBridgeRaw(plugin, action)
+            : undefined,
+        updateConfigRaw: hasExport(plugin, "__fp_gen_update_config")
+            ? (config) => updateConfigRaw(plugin, config)
+            : undefined,
+    };
+}
+
+export function exportArrayF32(plugin: PluginInstance, arg: Float32Array): Float32Array {
+    return callExport("exportArrayF32", "Float32Array", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_array_f32", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_array_f32", "arg", "toPlugin", serializeObject(plugin, Array.from(arg)));
+        return parseObject<Float32Array>(plugin, traceGuestPayload(plugin, "export_array_f32", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportArrayF64(plugin: PluginInstance, arg: Float64Array): Float64Array {
+    return callExport("exportArrayF64", "Float64Array", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_array_f64", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_array_f64", "arg", "toPlugin", serializeObject(plugin, Array.from(arg)));
+        return parseObject<Float64Array>(plugin, traceGuestPayload(plugin, "export_array_f64", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportArrayI16(plugin: PluginInstance, arg: Int16Array): Int16Array {
+    return callExport("exportArrayI16", "Int16Array", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_array_i16", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_array_i16", "arg", "toPlugin", serializeObject(plugin, Array.from(arg)));
+        return parseObject<Int16Array>(plugin, traceGuestPayload(plugin, "export_array_i16", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportArrayI32(plugin: PluginInstance, arg: Int32Array): Int32Array {
+    return callExport("exportArrayI32", "Int32Array", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_array_i32", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_array_i32", "arg", "toPlugin", serializeObject(plugin, Array.from(arg)));
+        return parseObject<Int32Array>(plugin, traceGuestPayload(plugin, "export_array_i32", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportArrayI8(plugin: PluginInstance, arg: Int8Array): Int8Array {
+    return callExport("exportArrayI8", "Int8Array", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_array_i8", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_array_i8", "arg", "toPlugin", serializeObject(plugin, Array.from(arg)));
+        return parseObject<Int8Array>(plugin, traceGuestPayload(plugin, "export_array_i8", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportArrayU16(plugin: PluginInstance, arg: Uint16Array): Uint16Array {
+    return callExport("exportArrayU16", "Uint16Array", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_array_u16", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_array_u16", "arg", "toPlugin", serializeObject(plugin, Array.from(arg)));
+        return parseObject<Uint16Array>(plugin, traceGuestPayload(plugin, "export_array_u16", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportArrayU32(plugin: PluginInstance, arg: Uint32Array): Uint32Array {
+    return callExport("exportArrayU32", "Uint32Array", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_array_u32", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_array_u32", "arg", "toPlugin", serializeObject(plugin, Array.from(arg)));
+        return parseObject<Uint32Array>(plugin, traceGuestPayload(plugin, "export_array_u32", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportArrayU8(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    return callExport("exportArrayU8", "Uint8Array", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_array_u8", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_array_u8", "arg", "toPlugin", serializeObject(plugin, Array.from(arg)));
+        return parseObject<Uint8Array>(plugin, traceGuestPayload(plugin, "export_array_u8", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportAsyncStruct(plugin: PluginInstance, arg1: types.FpPropertyRenaming, arg2: bigint): Promise<types.FpPropertyRenaming> {
+    return callExport("exportAsyncStruct", "FpPropertyRenaming", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_async_struct", 2);
+        const arg1_ptr = traceGuestPayload(plugin, "export_async_struct", "arg1", "toPlugin", serializeObject(plugin, arg1));
+        return promiseFromPtr(plugin, export_fn(arg1_ptr, arg2)).then((ptr) => parseObject<types.FpPropertyRenaming>(plugin, traceGuestPayload(plugin, "export_async_struct", "result", "fromPlugin", ptr)));
+    });
+}
+
+export function exportBatchResponse(plugin: PluginInstance, arg: types.BatchResponse): types.BatchResponse {
+    return callExport("exportBatchResponse", "BatchResponse", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_batch_response", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_batch_response", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.BatchResponse>(plugin, traceGuestPayload(plugin, "export_batch_response", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportCachedCallCount(plugin: PluginInstance, label: string): string {
+    return callExport("exportCachedCallCount", "string", () => {
+        return cachedExport(plugin, "exportCachedCallCount", [label], 30000, () => {
+            const export_fn = getExport<any>(plugin, "__fp_gen_export_cached_call_count", 1);
+            const label_ptr = traceGuestPayload(plugin, "export_cached_call_count", "label", "toPlugin", serializeObject(plugin, label));
+            return parseObject<string>(plugin, traceGuestPayload(plugin, "export_cached_call_count", "result", "fromPlugin", export_fn(label_ptr)));
+        });
+    });
+}
+
+export function exportCallRateLimitedImport(plugin: PluginInstance, calls: number): Array<types.Result<string, types.RateLimited>> {
+    return callExport("exportCallRateLimitedImport", "Array<Result<string, RateLimited>>", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_call_rate_limited_import", 1);
+        return parseObject<Array<types.Result<string, types.RateLimited>>>(plugin, traceGuestPayload(plugin, "export_call_rate_limited_import", "result", "fromPlugin", export_fn(calls)));
+    });
+}
+
+export function exportCollections(plugin: PluginInstance, arg: types.Collections): types.Collections {
+    return callExport("exportCollections", "Collections", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_collections", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_collections", "arg", "toPlugin", serializeObject(plugin, arg));
+        return convertCollections(parseObject<types.Collections>(plugin, traceGuestPayload(plugin, "export_collections", "result", "fromPlugin", export_fn(arg_ptr))));
+    });
+}
+
+export function exportDynamicValue(plugin: PluginInstance, arg: types.ExtensionSettings): unknown {
+    return callExport("exportDynamicValue", "unknown", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_dynamic_value", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_dynamic_value", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<unknown>(plugin, traceGuestPayload(plugin, "export_dynamic_value", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportEdgeValues(plugin: PluginInstance, arg: types.EdgeValues): types.EdgeValues {
+    return callExport("exportEdgeValues", "EdgeValues", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_edge_values", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_edge_values", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.EdgeValues>(plugin, traceGuestPayload(plugin, "export_edge_values", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportFpAdjacentlyTagged(plugin: PluginInstance, arg: types.FpAdjacentlyTagged): types.FpAdjacentlyTagged {
+    return callExport("exportFpAdjacentlyTagged", "FpAdjacentlyTagged", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_adjacently_tagged", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_fp_adjacently_tagged", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.FpAdjacentlyTagged>(plugin, traceGuestPayload(plugin, "export_fp_adjacently_tagged", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportFpEnum(plugin: PluginInstance, arg: types.FpVariantRenaming): types.FpVariantRenaming {
+    return callExport("exportFpEnum", "FpVariantRenaming", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_enum", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_fp_enum", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.FpVariantRenaming>(plugin, traceGuestPayload(plugin, "export_fp_enum", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportFpFlatten(plugin: PluginInstance, arg: types.FpFlatten): types.FpFlatten {
+    return callExport("exportFpFlatten", "FpFlatten", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_flatten", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_fp_flatten", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.FpFlatten>(plugin, traceGuestPayload(plugin, "export_fp_flatten", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportFpInternallyTagged(plugin: PluginInstance, arg: types.FpInternallyTagged): types.FpInternallyTagged {
+    return callExport("exportFpInternallyTagged", "FpInternallyTagged", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_internally_tagged", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_fp_internally_tagged", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.FpInternallyTagged>(plugin, traceGuestPayload(plugin, "export_fp_internally_tagged", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportFpStruct(plugin: PluginInstance, arg: types.FpPropertyRenaming): types.FpPropertyRenaming {
+    return callExport("exportFpStruct", "FpPropertyRenaming", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_struct", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_fp_struct", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.FpPropertyRenaming>(plugin, traceGuestPayload(plugin, "export_fp_struct", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportFpUntagged(plugin: PluginInstance, arg: types.FpUntagged): types.FpUntagged {
+    return callExport("exportFpUntagged", "FpUntagged", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_untagged", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_fp_untagged", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.FpUntagged>(plugin, traceGuestPayload(plugin, "export_fp_untagged", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportGenerics(plugin: PluginInstance, arg: types.StructWithGenerics<number>): types.StructWithGenerics<number> {
+    return callExport("exportGenerics", "StructWithGenerics<number>", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_generics", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_generics", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.StructWithGenerics<number>>(plugin, traceGuestPayload(plugin, "export_generics", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportGetBytes(plugin: PluginInstance): types.Result<Uint8Array, string> {
+    return callExport("exportGetBytes", "Result<Uint8Array, string>", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_get_bytes", 0);
+        return parseObject<types.Result<Uint8Array, string>>(plugin, traceGuestPayload(plugin, "export_get_bytes", "result", "fromPlugin", export_fn()));
+    });
+}
+
+export function exportGetSerdeBytes(plugin: PluginInstance): types.Result<ArrayBuffer, string> {
+    return callExport("exportGetSerdeBytes", "Result<ArrayBuffer, string>", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_get_serde_bytes", 0);
+        return parseObject<types.Result<ArrayBuffer, string>>(plugin, traceGuestPayload(plugin, "export_get_serde_bytes", "result", "fromPlugin", export_fn()));
+    });
+}
+
+export function exportLazyString(plugin: PluginInstance, arg: string): string {
+    return callExport("exportLazyString", "string", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_lazy_string", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_lazy_string", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<string>(plugin, traceGuestPayload(plugin, "export_lazy_string", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportMetrics(plugin: PluginInstance, metrics: Array<types.Metric>): Array<types.Metric> {
+    return callExport("exportMetrics", "Array<Metric>", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_metrics", 1);
+        const metrics_ptr = traceGuestPayload(plugin, "export_metrics", "metrics", "toPlugin", serializeObject(plugin, metrics));
+        return mapArray(convertMetric)(parseObject<Array<types.Metric>>(plugin, traceGuestPayload(plugin, "export_metrics", "result", "fromPlugin", export_fn(metrics_ptr))));
+    });
+}
+
+export function exportMultiplePrimitives(plugin: PluginInstance, arg1: number, arg2: string): bigint {
+    return callExport("exportMultiplePrimitives", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_multiple_primitives", 2);
+        const arg2_ptr = traceGuestPayload(plugin, "export_multiple_primitives", "arg2", "toPlugin", serializeObject(plugin, arg2));
+        return interpretBigSign(export_fn(arg1, arg2_ptr), 9223372036854775808n);
+    });
+}
+
+export function exportMultipleValues(plugin: PluginInstance, arg: number): types.ExportMultipleValuesReturn {
+    return callExport("exportMultipleValues", "ExportMultipleValuesReturn", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_multiple_values", 1);
+        return parseObject<types.ExportMultipleValuesReturn>(plugin, traceGuestPayload(plugin, "export_multiple_values", "result", "fromPlugin", export_fn(arg)));
+    });
+}
+
+export function exportPrimitiveBool(plugin: PluginInstance, arg: boolean): boolean {
+    return callExport("exportPrimitiveBool", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_bool", 1);
+        return !!export_fn(arg);
+    });
+}
+
+export function exportPrimitiveF32(plugin: PluginInstance, arg: number): number {
+    return callExport("exportPrimitiveF32", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_f32", 1);
+        return export_fn(arg);
+    });
+}
+
+export function exportPrimitiveF64(plugin: PluginInstance, arg: number): number {
+    return callExport("exportPrimitiveF64", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_f64", 1);
+        return export_fn(arg);
+    });
+}
+
+export function exportPrimitiveI16(plugin: PluginInstance, arg: number): number {
+    return callExport("exportPrimitiveI16", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_i16", 1);
+        return interpretSign(export_fn(arg), 32768);
+    });
+}
+
+export function exportPrimitiveI32(plugin: PluginInstance, arg: number): number {
+    return callExport("exportPrimitiveI32", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_i32", 1);
+        return interpretSign(export_fn(arg), 2147483648);
+    });
+}
+
+export function exportPrimitiveI64(plugin: PluginInstance, arg: bigint): bigint {
+    return callExport("exportPrimitiveI64", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_i64", 1);
+        return interpretBigSign(export_fn(arg), 9223372036854775808n);
+    });
+}
+
+export function exportPrimitiveI8(plugin: PluginInstance, arg: number): number {
+    return callExport("exportPrimitiveI8", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_i8", 1);
+        return interpretSign(export_fn(arg), 128);
+    });
+}
+
+export function exportPrimitivePair(plugin: PluginInstance, arg: number): [number, number] {
+    return callExport("exportPrimitivePair", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_pair", 1);
+        return unpackPair(export_fn(arg), (bits) => bits, (bits) => bits | 0);
+    });
+}
+
+export function exportPrimitiveU16(plugin: PluginInstance, arg: number): number {
+    return callExport("exportPrimitiveU16", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_u16", 1);
+        return export_fn(arg);
+    });
+}
+
+export function exportPrimitiveU32(plugin: PluginInstance, arg: number): number {
+    return callExport("exportPrimitiveU32", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_u32", 1);
+        return export_fn(arg);
+    });
+}
+
+export function exportPrimitiveU64(plugin: PluginInstance, arg: bigint): bigint {
+    return callExport("exportPrimitiveU64", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_u64", 1);
+        return export_fn(arg);
+    });
+}
+
+export function exportPrimitiveU8(plugin: PluginInstance, arg: number): number {
+    return callExport("exportPrimitiveU8", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_u8", 1);
+        return export_fn(arg);
+    });
+}
+
+export function exportRenamedString(plugin: PluginInstance, arg: string): string {
+    return callExport("exportRenamedString", "string", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_legacy_string", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_renamed_string", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<string>(plugin, traceGuestPayload(plugin, "export_renamed_string", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportSamples(plugin: PluginInstance, samples: types.SampleColumns): types.SampleColumns {
+    return callExport("exportSamples", "SampleColumns", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_samples", 1);
+        const samples_ptr = traceGuestPayload(plugin, "export_samples", "samples", "toPlugin", serializeObject(plugin, samples));
+        return parseObject<types.SampleColumns>(plugin, traceGuestPayload(plugin, "export_samples", "result", "fromPlugin", export_fn(samples_ptr)));
+    });
+}
+
+export function exportSerdeAdjacentlyTagged(plugin: PluginInstance, arg: types.SerdeAdjacentlyTagged): types.SerdeAdjacentlyTagged {
+    return callExport("exportSerdeAdjacentlyTagged", "SerdeAdjacentlyTagged", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_adjacently_tagged", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_serde_adjacently_tagged", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.SerdeAdjacentlyTagged>(plugin, traceGuestPayload(plugin, "export_serde_adjacently_tagged", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportSerdeEnum(plugin: PluginInstance, arg: types.SerdeVariantRenaming): types.SerdeVariantRenaming {
+    return callExport("exportSerdeEnum", "SerdeVariantRenaming", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_enum", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_serde_enum", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.SerdeVariantRenaming>(plugin, traceGuestPayload(plugin, "export_serde_enum", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportSerdeFlatten(plugin: PluginInstance, arg: types.SerdeFlatten): types.SerdeFlatten {
+    return callExport("exportSerdeFlatten", "SerdeFlatten", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_flatten", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_serde_flatten", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.SerdeFlatten>(plugin, traceGuestPayload(plugin, "export_serde_flatten", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportSerdeInternallyTagged(plugin: PluginInstance, arg: types.SerdeInternallyTagged): types.SerdeInternallyTagged {
+    return callExport("exportSerdeInternallyTagged", "SerdeInternallyTagged", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_internally_tagged", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_serde_internally_tagged", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.SerdeInternallyTagged>(plugin, traceGuestPayload(plugin, "export_serde_internally_tagged", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportSerdeStruct(plugin: PluginInstance, arg: types.SerdePropertyRenaming): types.SerdePropertyRenaming {
+    return callExport("exportSerdeStruct", "SerdePropertyRenaming", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_struct", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_serde_struct", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.SerdePropertyRenaming>(plugin, traceGuestPayload(plugin, "export_serde_struct", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportSerdeUntagged(plugin: PluginInstance, arg: types.SerdeUntagged): types.SerdeUntagged {
+    return callExport("exportSerdeUntagged", "SerdeUntagged", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_untagged", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_serde_untagged", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.SerdeUntagged>(plugin, traceGuestPayload(plugin, "export_serde_untagged", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportString(plugin: PluginInstance, arg: string): string {
+    return callExport("exportString", "string", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_string", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_string", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<string>(plugin, traceGuestPayload(plugin, "export_string", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportStringAfterMemoryGrowth(plugin: PluginInstance, arg: string): string {
+    return callExport("exportStringAfterMemoryGrowth", "string", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_string_after_memory_growth", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_string_after_memory_growth", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<string>(plugin, traceGuestPayload(plugin, "export_string_after_memory_growth", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportStructWithOptions(plugin: PluginInstance, arg: types.StructWithOptions): types.StructWithOptions {
+    return callExport("exportStructWithOptions", "StructWithOptions", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_struct_with_options", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_struct_with_options", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.StructWithOptions>(plugin, traceGuestPayload(plugin, "export_struct_with_options", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportTimestamp(plugin: PluginInstance, arg: types.MyDateTime): types.MyDateTime {
+    return callExport("exportTimestamp", "MyDateTime", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_timestamp", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_timestamp", "arg", "toPlugin", serializeObject(plugin, arg));
+        return parseObject<types.MyDateTime>(plugin, traceGuestPayload(plugin, "export_timestamp", "result", "fromPlugin", export_fn(arg_ptr)));
+    });
+}
+
+export function exportTimestamps(plugin: PluginInstance, arg: types.Timestamps): types.Timestamps {
+    return callExport("exportTimestamps", "Timestamps", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_timestamps", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_timestamps", "arg", "toPlugin", serializeObject(plugin, arg));
+        return convertTimestamps(parseObject<types.Timestamps>(plugin, traceGuestPayload(plugin, "export_timestamps", "result", "fromPlugin", export_fn(arg_ptr))));
+    });
+}
+
+export function exportTypedId(plugin: PluginInstance, id: types.TypedId<types.Point<number>>): types.TypedId<types.Point<number>> {
+    return callExport("exportTypedId", "TypedId<Point<number>>", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_typed_id", 1);
+        const id_ptr = traceGuestPayload(plugin, "export_typed_id", "id", "toPlugin", serializeObject(plugin, id));
+        return parseObject<types.TypedId<types.Point<number>>>(plugin, traceGuestPayload(plugin, "export_typed_id", "result", "fromPlugin", export_fn(id_ptr)));
+    });
+}
+
+export function exportValidatedStruct(plugin: PluginInstance, arg: types.ValidatedStruct): types.ValidatedStruct {
+    return callExport("exportValidatedStruct", "ValidatedStruct", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_validated_struct", 1);
+        const arg_ptr = traceGuestPayload(plugin, "export_validated_struct", "arg", "toPlugin", serializeObject(plugin, arg));
+        const result = parseObject<types.ValidatedStruct>(plugin, traceGuestPayload(plugin, "export_validated_struct", "result", "fromPlugin", export_fn(arg_ptr)));
+        validateValidatedStruct(result);
+        return result;
+    });
+}
+
+export function exportVoidFunction(plugin: PluginInstance): void {
+    return callExport("exportVoidFunction", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_export_void_function", 0);
+        export_fn();
+    });
+}
+
+/**
+ * Example how plugin could expose async data-fetching capabilities.
+ */
+export function fetchData(plugin: PluginInstance, rType: string): Promise<types.Result<string, string>> {
+    return callExport("fetchData", "Result<string, string>", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_fetch_data", 1);
+        const type_ptr = traceGuestPayload(plugin, "fetch_data", "r#type", "toPlugin", serializeObject(plugin, rType));
+        return promiseFromPtr(plugin, export_fn(type_ptr)).then((ptr) => parseObject<types.Result<string, string>>(plugin, traceGuestPayload(plugin, "fetch_data", "result", "fromPlugin", ptr)));
+    });
+}
+
+/**
+ * Called on the plugin to give it a chance to initialize.
+ */
+export function init(plugin: PluginInstance, config: types.PluginConfig): void {
+    return callExport("init", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_init", 1);
+        const config_ptr = traceGuestPayload(plugin, "init", "config", "toPlugin", serializeObject(plugin, config));
+        export_fn(config_ptr);
+    });
+}
+
+/**
+ * Example how plugin could expose a reducer.
+ */
+export function reducerBridge(plugin: PluginInstance, action: types.ReduxAction): types.StateUpdate {
+    return callExport("reducerBridge", "StateUpdate", () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_reducer_bridge", 1);
+        const action_ptr = traceGuestPayload(plugin, "reducer_bridge", "action", "toPlugin", serializeObject(plugin, action));
+        return parseObject<types.StateUpdate>(plugin, traceGuestPayload(plugin, "reducer_bridge", "result", "fromPlugin", export_fn(action_ptr)));
+    });
+}
+
+/**
+ * Called on the plugin when the host changes its config.
+ */
+export function updateConfig(plugin: PluginInstance, config: types.PluginConfig): void {
+    return callExport("updateConfig", undefined, () => {
+        const export_fn = getExport<any>(plugin, "__fp_gen_update_config", 1);
+        const config_ptr = traceGuestPayload(plugin, "update_config", "config", "toPlugin", serializeObject(plugin, config));
+        export_fn(config_ptr);
+    });
+}
+
+/**
+ * Registers a handler for the dynamic import `invoke_command`.
+ */
+export function registerInvokeCommand(plugin: PluginInstance, name: string, handler: (payload: string) => types.Result<string, string>): void {
+    getDynamicImportHandlers(plugin, "invoke_command").set(name, handler);
+}
+
+/**
+ * Unregisters a handler for the dynamic import `invoke_command`.
+ */
+export function unregisterInvokeCommand(plugin: PluginInstance, name: string): boolean {
+    return getDynamicImportHandlers(plugin, "invoke_command").delete(name);
+}
+
+/**
+ * Subscribes the given handler to the event with the given name.
+ *
+ * The handler is called with every event of that name that the plugin
+ * emits, until it is unsubscribed using `off()`.
+ */
+export function on<E extends keyof Events>(plugin: PluginInstance, eventName: E, handler: (event: Events[E]) => void): void {
+    getEventHandlers(plugin, eventName).add(handler);
+}
+
+/**
+ * Unsubscribes the given handler from the event with the given name.
+ *
+ * Returns whether the handler was subscribed.
+ */
+export function off<E extends keyof Events>(plugin: PluginInstance, eventName: E, handler: (event: Events[E]) => void): boolean {
+    return getEventHandlers(plugin, eventName).delete(handler);
+}
+
+/**
+ * Returns statistics about the memory that is allocated by the plugin.
+ *
+ * This is only supported for plugins that are built with the `memory-stats`
+ * feature of `fp-bindgen-support`.
+ */
+export function memoryStats(plugin: PluginInstance): MemoryStats {
+    const export_fn = getExport<any>(plugin, "__fp_memory_stats");
+    return parseObject<MemoryStats>(plugin, export_fn());
+}
+
+/**
+ * Returns metrics about the calls that the plugin has made to imports, as
+ * measured by the plugin itself.
+ *
+ * This is only supported for plugins with bindings that are generated with
+ * `guest_metrics` enabled.
+ */
+export function guestMetrics(plugin: PluginInstance): GuestMetrics {
+    const export_fn = getExport<any>(plugin, "__fp_get_guest_metrics");
+    return parseObject<GuestMetrics>(plugin, export_fn());
+}
+
+/**
+ * Calls the `shutdown` export of the plugin, if it implements it, and releases
+ * the resources that are held for it: pending promises are rejected with a
+ * `PluginDisposedError`, and registered handlers are dropped. Exports that
+ * exchange serialized values throw a `PluginDisposedError` afterwards.
+ */
+export function dispose(plugin: PluginInstance): void {
+    if (plugin.disposed) {
+        return;
+    }
+    try {
+        if (hasExport(plugin, "__fp_gen_shutdown")) {
+            getExport<() => void>(plugin, "__fp_gen_shutdown")();
+        }
+    } finally {
+        disposePlugin(plugin);
+    }
+}
+
+/**
+ * Returns the names of the exports that the plugin omits.
+ *
+ * The exports of the plugin are checked against the protocol before it is
+ * instantiated, so this allows hosts to log which capabilities the plugin
+ * lacks at load time.
+ */
+export function missingExports(plugin: PluginInstance): string[] {
+    return plugin.missingExports ?? [];
+}
+
+/**
+ * Discards the memoized results of the exports that are cached.
+ */
+export function clearExportCache(plugin: PluginInstance): void {
+    clearCachedExports(plugin);
+}
+
+export function exportArrayF32Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_array_f32");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportArrayF64Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_array_f64");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportArrayI16Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_array_i16");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportArrayI32Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_array_i32");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportArrayI8Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_array_i8");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportArrayU16Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_array_u16");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportArrayU32Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_array_u32");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportArrayU8Raw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_array_u8");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportAsyncStructRaw(plugin: PluginInstance, arg1: Uint8Array, arg2: bigint): Promise<Uint8Array> {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_async_struct");
+    const arg1_ptr = exportToMemory(plugin, arg1);
+    return promiseFromPtr(plugin, export_fn(arg1_ptr, arg2)).then((ptr) => importFromMemory(plugin, ptr));
+}
+
+export function exportBatchResponseRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_batch_response");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportCachedCallCountRaw(plugin: PluginInstance, label: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_cached_call_count");
+    const label_ptr = exportToMemory(plugin, label);
+    return importFromMemory(plugin, export_fn(label_ptr));
+}
+
+export function exportCallRateLimitedImportRaw(plugin: PluginInstance, calls: number): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_call_rate_limited_import");
+    return importFromMemory(plugin, export_fn(calls));
+}
+
+export function exportCollectionsRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_collections");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportDynamicValueRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_dynamic_value");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportEdgeValuesRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_edge_values");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportFpAdjacentlyTaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_adjacently_tagged");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportFpEnumRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_enum");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportFpFlattenRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_flatten");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportFpInternallyTaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_internally_tagged");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportFpStructRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_struct");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportFpUntaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_fp_untagged");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportGenericsRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_generics");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportGetBytesRaw(plugin: PluginInstance): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_get_bytes");
+    return importFromMemory(plugin, export_fn());
+}
+
+export function exportGetSerdeBytesRaw(plugin: PluginInstance): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_get_serde_bytes");
+    return importFromMemory(plugin, export_fn());
+}
+
+export function exportLazyStringRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_lazy_string");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportMetricsRaw(plugin: PluginInstance, metrics: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_metrics");
+    const metrics_ptr = exportToMemory(plugin, metrics);
+    return importFromMemory(plugin, export_fn(metrics_ptr));
+}
+
+export function exportMultiplePrimitivesRaw(plugin: PluginInstance, arg1: number, arg2: Uint8Array): bigint {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_multiple_primitives");
+    const arg2_ptr = exportToMemory(plugin, arg2);
+    return interpretBigSign(export_fn(arg1, arg2_ptr), 9223372036854775808n);
+}
+
+export function exportMultipleValuesRaw(plugin: PluginInstance, arg: number): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_multiple_values");
+    return importFromMemory(plugin, export_fn(arg));
+}
+
+export function exportPrimitiveBoolRaw(plugin: PluginInstance, arg: boolean): boolean {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_bool");
+    return !!export_fn(arg);
+}
+
+export function exportPrimitiveI16Raw(plugin: PluginInstance, arg: number): number {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_i16");
+    return interpretSign(export_fn(arg), 32768);
+}
+
+export function exportPrimitiveI32Raw(plugin: PluginInstance, arg: number): number {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_i32");
+    return interpretSign(export_fn(arg), 2147483648);
+}
+
+export function exportPrimitiveI64Raw(plugin: PluginInstance, arg: bigint): bigint {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_i64");
+    return interpretBigSign(export_fn(arg), 9223372036854775808n);
+}
+
+export function exportPrimitiveI8Raw(plugin: PluginInstance, arg: number): number {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_i8");
+    return interpretSign(export_fn(arg), 128);
+}
+
+export function exportPrimitivePairRaw(plugin: PluginInstance, arg: number): [number, number] {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_primitive_pair");
+    return unpackPair(export_fn(arg), (bits) => bits, (bits) => bits | 0);
+}
+
+export function exportRenamedStringRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_legacy_string");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportSamplesRaw(plugin: PluginInstance, samples: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_samples");
+    const samples_ptr = exportToMemory(plugin, samples);
+    return importFromMemory(plugin, export_fn(samples_ptr));
+}
+
+export function exportSerdeAdjacentlyTaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_adjacently_tagged");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportSerdeEnumRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_enum");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportSerdeFlattenRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_flatten");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportSerdeInternallyTaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_internally_tagged");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportSerdeStructRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_struct");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportSerdeUntaggedRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_serde_untagged");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportStringRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_string");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportStringAfterMemoryGrowthRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_string_after_memory_growth");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportStructWithOptionsRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_struct_with_options");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportTimestampRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_timestamp");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportTimestampsRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_timestamps");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function exportTypedIdRaw(plugin: PluginInstance, id: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_typed_id");
+    const id_ptr = exportToMemory(plugin, id);
+    return importFromMemory(plugin, export_fn(id_ptr));
+}
+
+export function exportValidatedStructRaw(plugin: PluginInstance, arg: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_export_validated_struct");
+    const arg_ptr = exportToMemory(plugin, arg);
+    return importFromMemory(plugin, export_fn(arg_ptr));
+}
+
+export function fetchDataRaw(plugin: PluginInstance, rType: Uint8Array): Promise<Uint8Array> {
+    const export_fn = getExport<any>(plugin, "__fp_gen_fetch_data");
+    const type_ptr = exportToMemory(plugin, rType);
+    return promiseFromPtr(plugin, export_fn(type_ptr)).then((ptr) => importFromMemory(plugin, ptr));
+}
+
+export function initRaw(plugin: PluginInstance, config: Uint8Array): void {
+    const export_fn = getExport<any>(plugin, "__fp_gen_init");
+    const config_ptr = exportToMemory(plugin, config);
+    export_fn(config_ptr);
+}
+
+export function reducerBridgeRaw(plugin: PluginInstance, action: Uint8Array): Uint8Array {
+    const export_fn = getExport<any>(plugin, "__fp_gen_reducer_bridge");
+    const action_ptr = exportToMemory(plugin, action);
+    return importFromMemory(plugin, export_fn(action_ptr));
+}
+
+export function updateConfigRaw(plugin: PluginInstance, config: Uint8Array): void {
+    const export_fn = getExport<any>(plugin, "__fp_gen_update_config");
+    const config_ptr = exportToMemory(plugin, config);
+    export_fn(config_ptr);
+}
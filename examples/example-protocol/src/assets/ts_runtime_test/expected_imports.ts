@@ -0,0 +1,568 @@
+// ============================================= //
+// Imports for WebAssembly runtime               //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any no-unused-vars
+
+import {
+    createAsyncValue,
+    FPRuntimeError,
+    getDynamicImportHandlers,
+    getEventHandlers,
+    interpretBigSign,
+    interpretSign,
+    parseObject,
+    resolveFuture,
+    resolvePromise,
+    serializeObject,
+    throwGuestError,
+    f32ToBits,
+    packPair,
+    cachedExport,
+    clearCachedExports,
+    rateLimit,
+    traceGuestPayload,
+} from "./memory.ts";
+import type { FatPtr, PluginInstance } from "./memory.ts";
+import type * as types from "./types.ts";
+import { validateValidatedChild, validateValidatedStruct } from "./validation.ts";
+import { fromInterned, internString, mapArray, mapNullable, mapRecord, toDate, toSet, convertCollections, convertMetric, convertTimestamps } from "./conversions.ts";
+
+export type Imports = {
+    importArbitraryPrecisionNumbers: (arg: types.ArbitraryPrecisionNumbers) => types.ArbitraryPrecisionNumbers;
+    importArrayF32: (arg: Float32Array) => Float32Array;
+    importArrayF64: (arg: Float64Array) => Float64Array;
+    importArrayI16: (arg: Int16Array) => Int16Array;
+    importArrayI32: (arg: Int32Array) => Int32Array;
+    importArrayI8: (arg: Int8Array) => Int8Array;
+    importArrayU16: (arg: Uint16Array) => Uint16Array;
+    importArrayU32: (arg: Uint32Array) => Uint32Array;
+    importArrayU8: (arg: Uint8Array) => Uint8Array;
+    importAsyncVoidFunction: (message: string) => Promise<void>;
+    importCollections: (arg: types.Collections) => types.Collections;
+    importExplicitBoundPoint: (arg: types.ExplicitBoundPoint<number>) => void;
+    importFpAdjacentlyTagged: (arg: types.FpAdjacentlyTagged) => types.FpAdjacentlyTagged;
+    importFpEnum: (arg: types.FpVariantRenaming) => types.FpVariantRenaming;
+    importFpFlatten: (arg: types.FpFlatten) => types.FpFlatten;
+    importFpInternallyTagged: (arg: types.FpInternallyTagged) => types.FpInternallyTagged;
+    importFpStruct: (arg: types.FpPropertyRenaming) => types.FpPropertyRenaming;
+    importFpUntagged: (arg: types.FpUntagged) => types.FpUntagged;
+    importGenerics: (arg: types.StructWithGenerics<number>) => types.StructWithGenerics<number>;
+    importGetBytes: () => types.Result<Uint8Array, string>;
+    importGetSerdeBytes: () => types.Result<ArrayBuffer, string>;
+    importGetValue: <T>(key: string) => Promise<T | null>;
+    importIntegerTypes: (arg: types.IntegerTypes) => types.IntegerTypes;
+    importMultiplePrimitives: (arg1: number, arg2: string) => bigint;
+    importMultipleValues: (arg: number) => types.ImportMultipleValuesReturn;
+    importPrimitiveBool: (arg: boolean) => boolean;
+    importPrimitiveF32: (arg: number) => number;
+    importPrimitiveF64: (arg: number) => number;
+    importPrimitiveI16: (arg: number) => number;
+    importPrimitiveI32: (arg: number) => number;
+    importPrimitiveI64: (arg: bigint) => bigint;
+    importPrimitiveI8: (arg: number) => number;
+    importPrimitivePair: (arg: number) => [number, number];
+    importPrimitiveU16: (arg: number) => number;
+    importPrimitiveU32: (arg: number) => number;
+    importPrimitiveU64: (arg: bigint) => bigint;
+    importPrimitiveU8: (arg: number) => number;
+    importRateLimited: (label: string) => types.Result<string, types.RateLimited>;
+    importRenamedString: (arg: string) => string;
+    importSerdeAdjacentlyTagged: (arg: types.SerdeAdjacentlyTagged) => types.SerdeAdjacentlyTagged;
+    importSerdeEnum: (arg: types.SerdeVariantRenaming) => types.SerdeVariantRenaming;
+    importSerdeFlatten: (arg: types.SerdeFlatten) => types.SerdeFlatten;
+    importSerdeInternallyTagged: (arg: types.SerdeInternallyTagged) => types.SerdeInternallyTagged;
+    importSerdeStruct: (arg: types.SerdePropertyRenaming) => types.SerdePropertyRenaming;
+    importSerdeUntagged: (arg: types.SerdeUntagged) => types.SerdeUntagged;
+    importSetValue: <T>(key: string, value: T) => void;
+    importString: (arg: string) => string;
+    importStructWithOptions: (arg: types.StructWithOptions) => types.StructWithOptions;
+    importTimestamp: (arg: types.MyDateTime) => types.MyDateTime;
+    importValidatedStruct: (arg: types.ValidatedStruct) => types.ValidatedStruct;
+    importVoidFunction: () => void;
+    importVoidFunctionEmptyResult: () => types.Result<void, number>;
+    importVoidFunctionEmptyReturn: () => void;
+    invokeCommand: (name: string, payload: string) => types.Result<string, string>;
+    log: (message: string) => void;
+    makeHttpRequest: (request: types.Request) => Promise<types.HttpResult>;
+    recordMetric: (name: string, value: number) => void;
+    std?: StdImports;
+    http?: HttpImports;
+    onPrint?: (stream: "stdout" | "stderr", line: string) => void;
+};
+
+/**
+ * A capability that can be granted to the plugin. Imports that require a
+ * capability can only be called by the plugin if it was granted.
+ */
+export type Capability = "clock" | "fs" | "http" | "network" | "random";
+
+/**
+ * Thrown when the plugin calls an import that requires a capability that was
+ * not granted to it.
+ */
+export class PermissionDeniedError extends FPRuntimeError {
+    functionName: string;
+    capability: Capability;
+
+    constructor(functionName: string, capability: Capability) {
+        super(`Import "${functionName}" requires the "${capability}" capability, which was not granted`);
+        this.functionName = functionName;
+        this.capability = capability;
+    }
+}
+
+/**
+ * The host facilities that are exposed to the plugin through the built-in std
+ * imports, which require the "fs", "clock" and "random" capabilities.
+ *
+ * Files are read from and written to the `files` map, keyed by their path. If
+ * no map is given, the plugin cannot access any files. By default, the clock
+ * uses `Date.now()`, the monotonic clock uses `performance.now()` and random
+ * bytes are generated using `crypto.getRandomValues()`. Both clocks return
+ * milliseconds.
+ */
+export type StdImports = {
+    files?: Map<string, Uint8Array>;
+    now?: () => number;
+    monotonicNow?: () => number;
+    randomBytes?: (len: number) => Uint8Array;
+};
+
+type StdFileError = { type: "invalid_path" | "not_found" | "unsupported" };
+
+function checkStdPath(files: Map<string, Uint8Array> | undefined, path: string): StdFileError | undefined {
+    if (!files) {
+        return { type: "unsupported" };
+    }
+    // Only relative paths without `.` and `..` components are accepted, like
+    // in the Rust runtime:
+    if (path.split("/").some((component) => component === "" || component === "." || component === "..")) {
+        return { type: "invalid_path" };
+    }
+    return undefined;
+}
+
+function readStdFile(files: Map<string, Uint8Array> | undefined, path: string): any {
+    const error = checkStdPath(files, path);
+    if (error) {
+        return { Err: error };
+    }
+    const contents = files?.get(path);
+    return contents ? { Ok: contents } : { Err: { type: "not_found" } };
+}
+
+function writeStdFile(files: Map<string, Uint8Array> | undefined, path: string, contents: Uint8Array): any {
+    const error = checkStdPath(files, path);
+    if (error) {
+        return { Err: error };
+    }
+    files?.set(path, contents);
+    return { Ok: null };
+}
+
+function randomStdBytes(len: number): Uint8Array {
+    const bytes = new Uint8Array(len);
+    // `getRandomValues()` fills at most 65536 bytes at a time:
+    for (let offset = 0; offset < len; offset += 65536) {
+        crypto.getRandomValues(bytes.subarray(offset, offset + 65536));
+    }
+    return bytes;
+}
+
+/**
+ * A request that the plugin makes through the built-in HTTP imports, which
+ * require the "http" capability. Headers are name-value pairs.
+ */
+export type HttpRequest = {
+    method: string;
+    url: string;
+    headers: Array<[string, string]>;
+    body?: Uint8Array;
+};
+
+/**
+ * The response to a request that the plugin made through the built-in HTTP
+ * imports.
+ */
+export type HttpResponse = {
+    status: number;
+    headers: Array<[string, string]>;
+    body: Uint8Array;
+};
+
+/**
+ * The host facilities that are exposed to the plugin through the built-in
+ * HTTP imports.
+ *
+ * By default, requests are sent using the global `fetch()`. Hosts can pass
+ * their own implementation, for instance to restrict the URLs the plugin may
+ * access, or to mock requests in tests.
+ */
+export type HttpImports = {
+    fetch?: typeof fetch;
+};
+
+async function sendHttpRequest(fetchImpl: typeof fetch, request: HttpRequest): Promise<any> {
+    let fetchRequest;
+    try {
+        fetchRequest = new Request(request.url, {
+            method: request.method,
+            headers: request.headers,
+            body: request.body,
+        });
+    } catch (error) {
+        return { Err: { type: "invalid_request", message: String(error) } };
+    }
+    try {
+        const response = await fetchImpl(fetchRequest);
+        const body = new Uint8Array(await response.arrayBuffer());
+        return { Ok: { status: response.status, headers: [...response.headers], body } };
+    } catch (error) {
+        if (error instanceof DOMException && error.name === "TimeoutError") {
+            return { Err: { type: "timeout" } };
+        }
+        const type = error instanceof TypeError ? "connection" : "other";
+        return { Err: { type, message: String(error) } };
+    }
+}
+
+/**
+ * Creates the functions that are imported by the plugin, which call the given
+ * host functions.
+ *
+ * @param plugin The plugin instance for which the imports are created. It only
+ *               needs to be initialized by the time the plugin calls them.
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @param capabilities The capabilities that are granted to the plugin.
+ * @returns The functions that are imported by the plugin from the `fp` module.
+ */
+export function createImports(
+    plugin: PluginInstance,
+    importFunctions: Imports,
+    capabilities: Capability[] = []
+): WebAssembly.ModuleImports {
+    const grantedCapabilities = new Set<Capability>(capabilities);
+
+    function checkCapability(functionName: string, capability: Capability) {
+        if (!grantedCapabilities.has(capability)) {
+            throw new PermissionDeniedError(functionName, capability);
+        }
+    }
+
+    return {
+        __fp_gen_import_arbitrary_precision_numbers: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.ArbitraryPrecisionNumbers>(plugin, traceGuestPayload(plugin, "import_arbitrary_precision_numbers", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_arbitrary_precision_numbers", "result", "toPlugin", serializeObject(plugin, importFunctions.importArbitraryPrecisionNumbers(arg)));
+        },
+        __fp_gen_import_array_f32: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<Float32Array>(plugin, traceGuestPayload(plugin, "import_array_f32", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_f32", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayF32(arg)));
+        },
+        __fp_gen_import_array_f64: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<Float64Array>(plugin, traceGuestPayload(plugin, "import_array_f64", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_f64", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayF64(arg)));
+        },
+        __fp_gen_import_array_i16: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<Int16Array>(plugin, traceGuestPayload(plugin, "import_array_i16", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_i16", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayI16(arg)));
+        },
+        __fp_gen_import_array_i32: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<Int32Array>(plugin, traceGuestPayload(plugin, "import_array_i32", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_i32", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayI32(arg)));
+        },
+        __fp_gen_import_array_i8: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<Int8Array>(plugin, traceGuestPayload(plugin, "import_array_i8", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_i8", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayI8(arg)));
+        },
+        __fp_gen_import_array_u16: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<Uint16Array>(plugin, traceGuestPayload(plugin, "import_array_u16", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_u16", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayU16(arg)));
+        },
+        __fp_gen_import_array_u32: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<Uint32Array>(plugin, traceGuestPayload(plugin, "import_array_u32", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_u32", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayU32(arg)));
+        },
+        __fp_gen_import_array_u8: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<Uint8Array>(plugin, traceGuestPayload(plugin, "import_array_u8", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_array_u8", "result", "toPlugin", serializeObject(plugin, importFunctions.importArrayU8(arg)));
+        },
+        __fp_gen_import_async_void_function: (message_ptr: FatPtr) => {
+            const message = parseObject<string>(plugin, traceGuestPayload(plugin, "import_async_void_function", "message", "fromPlugin", message_ptr));
+            importFunctions.importAsyncVoidFunction(message).catch((error) => {
+                console.error(
+                    'Unrecoverable exception trying to call async host function "import_async_void_function"',
+                    error
+                );
+            });
+        },
+        __fp_gen_import_collections: (arg_ptr: FatPtr): FatPtr => {
+            const arg = convertCollections(parseObject<types.Collections>(plugin, traceGuestPayload(plugin, "import_collections", "arg", "fromPlugin", arg_ptr)));
+            return traceGuestPayload(plugin, "import_collections", "result", "toPlugin", serializeObject(plugin, importFunctions.importCollections(arg)));
+        },
+        __fp_gen_import_explicit_bound_point: (arg_ptr: FatPtr) => {
+            const arg = parseObject<types.ExplicitBoundPoint<number>>(plugin, traceGuestPayload(plugin, "import_explicit_bound_point", "arg", "fromPlugin", arg_ptr));
+            importFunctions.importExplicitBoundPoint(arg);
+        },
+        __fp_gen_import_fp_adjacently_tagged: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.FpAdjacentlyTagged>(plugin, traceGuestPayload(plugin, "import_fp_adjacently_tagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_adjacently_tagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpAdjacentlyTagged(arg)));
+        },
+        __fp_gen_import_fp_enum: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.FpVariantRenaming>(plugin, traceGuestPayload(plugin, "import_fp_enum", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_enum", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpEnum(arg)));
+        },
+        __fp_gen_import_fp_flatten: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.FpFlatten>(plugin, traceGuestPayload(plugin, "import_fp_flatten", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_flatten", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpFlatten(arg)));
+        },
+        __fp_gen_import_fp_internally_tagged: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.FpInternallyTagged>(plugin, traceGuestPayload(plugin, "import_fp_internally_tagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_internally_tagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpInternallyTagged(arg)));
+        },
+        __fp_gen_import_fp_struct: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.FpPropertyRenaming>(plugin, traceGuestPayload(plugin, "import_fp_struct", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_struct", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpStruct(arg)));
+        },
+        __fp_gen_import_fp_untagged: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.FpUntagged>(plugin, traceGuestPayload(plugin, "import_fp_untagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_fp_untagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importFpUntagged(arg)));
+        },
+        __fp_gen_import_generics: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.StructWithGenerics<number>>(plugin, traceGuestPayload(plugin, "import_generics", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_generics", "result", "toPlugin", serializeObject(plugin, importFunctions.importGenerics(arg)));
+        },
+        __fp_gen_import_get_bytes: (): FatPtr => {
+            return traceGuestPayload(plugin, "import_get_bytes", "result", "toPlugin", serializeObject(plugin, importFunctions.importGetBytes()));
+        },
+        __fp_gen_import_get_serde_bytes: (): FatPtr => {
+            return traceGuestPayload(plugin, "import_get_serde_bytes", "result", "toPlugin", serializeObject(plugin, importFunctions.importGetSerdeBytes()));
+        },
+        __fp_gen_import_get_value: (key_ptr: FatPtr): FatPtr => {
+            const key = parseObject<string>(plugin, traceGuestPayload(plugin, "import_get_value", "key", "fromPlugin", key_ptr));
+            const _async_result_ptr = createAsyncValue(plugin);
+            importFunctions.importGetValue(key)
+                .then((result) => {
+                    resolveFuture(plugin, _async_result_ptr, traceGuestPayload(plugin, "import_get_value", "result", "toPlugin", serializeObject(plugin, result)));
+                })
+                .catch((error) => {
+                    console.error(
+                        'Unrecoverable exception trying to call async host function "import_get_value"',
+                        error
+                    );
+                });
+            return _async_result_ptr;
+        },
+        __fp_gen_import_integer_types: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.IntegerTypes>(plugin, traceGuestPayload(plugin, "import_integer_types", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_integer_types", "result", "toPlugin", serializeObject(plugin, importFunctions.importIntegerTypes(arg)));
+        },
+        __fp_gen_import_multiple_primitives: (arg1: number, arg2_ptr: FatPtr): bigint => {
+            const arg2 = parseObject<string>(plugin, traceGuestPayload(plugin, "import_multiple_primitives", "arg2", "fromPlugin", arg2_ptr));
+            return interpretBigSign(importFunctions.importMultiplePrimitives(arg1, arg2), 9223372036854775808n);
+        },
+        __fp_gen_import_multiple_values: (arg: number): FatPtr => {
+            return traceGuestPayload(plugin, "import_multiple_values", "result", "toPlugin", serializeObject(plugin, importFunctions.importMultipleValues(arg)));
+        },
+        __fp_gen_import_primitive_bool: (arg: boolean): boolean => {
+            return !!importFunctions.importPrimitiveBool(arg);
+        },
+        __fp_gen_import_primitive_f32: (arg: number): number => {
+            return importFunctions.importPrimitiveF32(arg);
+        },
+        __fp_gen_import_primitive_f64: (arg: number): number => {
+            return importFunctions.importPrimitiveF64(arg);
+        },
+        __fp_gen_import_primitive_i16: (arg: number): number => {
+            return interpretSign(importFunctions.importPrimitiveI16(arg), 32768);
+        },
+        __fp_gen_import_primitive_i32: (arg: number): number => {
+            return interpretSign(importFunctions.importPrimitiveI32(arg), 2147483648);
+        },
+        __fp_gen_import_primitive_i64: (arg: bigint): bigint => {
+            return interpretBigSign(importFunctions.importPrimitiveI64(arg), 9223372036854775808n);
+        },
+        __fp_gen_import_primitive_i8: (arg: number): number => {
+            return interpretSign(importFunctions.importPrimitiveI8(arg), 128);
+        },
+        __fp_gen_import_primitive_pair: (arg: number): bigint => {
+            return packPair(importFunctions.importPrimitivePair(arg), (value) => value, (value) => value);
+        },
+        __fp_gen_import_primitive_u16: (arg: number): number => {
+            return importFunctions.importPrimitiveU16(arg);
+        },
+        __fp_gen_import_primitive_u32: (arg: number): number => {
+            return importFunctions.importPrimitiveU32(arg);
+        },
+        __fp_gen_import_primitive_u64: (arg: bigint): bigint => {
+            return importFunctions.importPrimitiveU64(arg);
+        },
+        __fp_gen_import_primitive_u8: (arg: number): number => {
+            return importFunctions.importPrimitiveU8(arg);
+        },
+        __fp_gen_import_rate_limited: (label_ptr: FatPtr): FatPtr => {
+            const _rateLimited = rateLimit(plugin, "import_rate_limited", 2, 60000);
+            const label = parseObject<string>(plugin, traceGuestPayload(plugin, "import_rate_limited", "label", "fromPlugin", label_ptr));
+            return traceGuestPayload(plugin, "import_rate_limited", "result", "toPlugin", serializeObject(plugin, _rateLimited ?? importFunctions.importRateLimited(label)));
+        },
+        __fp_gen_import_legacy_string: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<string>(plugin, traceGuestPayload(plugin, "import_renamed_string", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_renamed_string", "result", "toPlugin", serializeObject(plugin, importFunctions.importRenamedString(arg)));
+        },
+        __fp_gen_import_serde_adjacently_tagged: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.SerdeAdjacentlyTagged>(plugin, traceGuestPayload(plugin, "import_serde_adjacently_tagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_adjacently_tagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeAdjacentlyTagged(arg)));
+        },
+        __fp_gen_import_serde_enum: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.SerdeVariantRenaming>(plugin, traceGuestPayload(plugin, "import_serde_enum", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_enum", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeEnum(arg)));
+        },
+        __fp_gen_import_serde_flatten: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.SerdeFlatten>(plugin, traceGuestPayload(plugin, "import_serde_flatten", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_flatten", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeFlatten(arg)));
+        },
+        __fp_gen_import_serde_internally_tagged: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.SerdeInternallyTagged>(plugin, traceGuestPayload(plugin, "import_serde_internally_tagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_internally_tagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeInternallyTagged(arg)));
+        },
+        __fp_gen_import_serde_struct: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.SerdePropertyRenaming>(plugin, traceGuestPayload(plugin, "import_serde_struct", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_struct", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeStruct(arg)));
+        },
+        __fp_gen_import_serde_untagged: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.SerdeUntagged>(plugin, traceGuestPayload(plugin, "import_serde_untagged", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_serde_untagged", "result", "toPlugin", serializeObject(plugin, importFunctions.importSerdeUntagged(arg)));
+        },
+        __fp_gen_import_set_value: (key_ptr: FatPtr, value_ptr: FatPtr) => {
+            const key = parseObject<string>(plugin, traceGuestPayload(plugin, "import_set_value", "key", "fromPlugin", key_ptr));
+            const value = parseObject<unknown>(plugin, traceGuestPayload(plugin, "import_set_value", "value", "fromPlugin", value_ptr));
+            importFunctions.importSetValue(key, value);
+        },
+        __fp_gen_import_string: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<string>(plugin, traceGuestPayload(plugin, "import_string", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_string", "result", "toPlugin", serializeObject(plugin, importFunctions.importString(arg)));
+        },
+        __fp_gen_import_struct_with_options: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.StructWithOptions>(plugin, traceGuestPayload(plugin, "import_struct_with_options", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_struct_with_options", "result", "toPlugin", serializeObject(plugin, importFunctions.importStructWithOptions(arg)));
+        },
+        __fp_gen_import_timestamp: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.MyDateTime>(plugin, traceGuestPayload(plugin, "import_timestamp", "arg", "fromPlugin", arg_ptr));
+            return traceGuestPayload(plugin, "import_timestamp", "result", "toPlugin", serializeObject(plugin, importFunctions.importTimestamp(arg)));
+        },
+        __fp_gen_import_validated_struct: (arg_ptr: FatPtr): FatPtr => {
+            const arg = parseObject<types.ValidatedStruct>(plugin, traceGuestPayload(plugin, "import_validated_struct", "arg", "fromPlugin", arg_ptr));
+            validateValidatedStruct(arg);
+            return traceGuestPayload(plugin, "import_validated_struct", "result", "toPlugin", serializeObject(plugin, importFunctions.importValidatedStruct(arg)));
+        },
+        __fp_gen_import_void_function: () => {
+            importFunctions.importVoidFunction();
+        },
+        __fp_gen_import_void_function_empty_result: (): FatPtr => {
+            return traceGuestPayload(plugin, "import_void_function_empty_result", "result", "toPlugin", serializeObject(plugin, importFunctions.importVoidFunctionEmptyResult()));
+        },
+        __fp_gen_import_void_function_empty_return: () => {
+            importFunctions.importVoidFunctionEmptyReturn();
+        },
+        __fp_gen_invoke_command: (name_ptr: FatPtr, payload_ptr: FatPtr): FatPtr => {
+            const name = parseObject<string>(plugin, name_ptr);
+            const payload = parseObject<string>(plugin, payload_ptr);
+            const handler = getDynamicImportHandlers(plugin, "invoke_command").get(name);
+            const result: types.Result<string, string> = handler
+                ? handler(payload)
+                : importFunctions.invokeCommand(name, payload);
+            return serializeObject(plugin, result);
+        },
+        __fp_gen_log: (message_ptr: FatPtr) => {
+            const message = parseObject<string>(plugin, traceGuestPayload(plugin, "log", "message", "fromPlugin", message_ptr));
+            importFunctions.log(message);
+        },
+        __fp_gen_make_http_request: (request_ptr: FatPtr): FatPtr => {
+            checkCapability("make_http_request", "network");
+            const request = parseObject<types.Request>(plugin, traceGuestPayload(plugin, "make_http_request", "request", "fromPlugin", request_ptr));
+            const _async_result_ptr = createAsyncValue(plugin);
+            importFunctions.makeHttpRequest(request)
+                .then((result) => {
+                    resolveFuture(plugin, _async_result_ptr, traceGuestPayload(plugin, "make_http_request", "result", "toPlugin", serializeObject(plugin, result)));
+                })
+                .catch((error) => {
+                    console.error(
+                        'Unrecoverable exception trying to call async host function "make_http_request"',
+                        error
+                    );
+                });
+            return _async_result_ptr;
+        },
+        __fp_gen_record_metric: (name_ptr: FatPtr, value: number) => {
+            const name = parseObject<string>(plugin, traceGuestPayload(plugin, "record_metric", "name", "fromPlugin", name_ptr));
+            importFunctions.recordMetric(name, value);
+        },
+        __fp_gen_report_progress: (progress_ptr: FatPtr) => {
+            const progress = parseObject<types.Progress>(plugin, progress_ptr);
+            for (const handler of getEventHandlers(plugin, "reportProgress")) {
+                handler(progress);
+            }
+        },
+        __fp_host_batch: (calls_ptr: FatPtr) => {
+            const calls = parseObject<Array<Record<string, any>>>(plugin, calls_ptr);
+            for (const call of calls) {
+                const [name, args] = Object.entries(call)[0];
+                switch (name) {
+                    case "record_metric":
+                        importFunctions.recordMetric(args[0], args[1]);
+                        break;
+                }
+            }
+        },
+        __fp_std_read_file: (path_ptr: FatPtr): FatPtr => {
+            checkCapability("read_file", "fs");
+            const path = parseObject<string>(plugin, path_ptr);
+            return serializeObject(plugin, readStdFile(importFunctions.std?.files, path));
+        },
+        __fp_std_write_file: (path_ptr: FatPtr, contents_ptr: FatPtr): FatPtr => {
+            checkCapability("write_file", "fs");
+            const path = parseObject<string>(plugin, path_ptr);
+            const contents = parseObject<Uint8Array>(plugin, contents_ptr);
+            return serializeObject(plugin, writeStdFile(importFunctions.std?.files, path, contents));
+        },
+        __fp_std_now: (): bigint => {
+            checkCapability("now", "clock");
+            return BigInt(Math.floor(importFunctions.std?.now?.() ?? Date.now()));
+        },
+        __fp_std_monotonic_now: (): bigint => {
+            checkCapability("monotonic_now", "clock");
+            const millis = importFunctions.std?.monotonicNow?.() ?? performance.now();
+            return BigInt(Math.floor(millis * 1_000_000));
+        },
+        __fp_std_random_bytes: (len: number): FatPtr => {
+            checkCapability("random_bytes", "random");
+            const randomBytes = importFunctions.std?.randomBytes ?? randomStdBytes;
+            return serializeObject(plugin, randomBytes(len));
+        },
+        __fp_http_request: (request_ptr: FatPtr): FatPtr => {
+            checkCapability("http_request", "http");
+            const request = parseObject<HttpRequest>(plugin, request_ptr);
+            const _async_result_ptr = createAsyncValue(plugin);
+            sendHttpRequest(importFunctions.http?.fetch ?? fetch, request).then((result) => {
+                resolveFuture(plugin, _async_result_ptr, serializeObject(plugin, result));
+            });
+            return _async_result_ptr;
+        },
+        __fp_intern_string: (value_ptr: FatPtr): number =>
+            internString(parseObject<string>(plugin, value_ptr)),
+        __fp_host_print: (stream: number, line_ptr: FatPtr) => {
+            const line = parseObject<string>(plugin, line_ptr);
+            if (importFunctions.onPrint) {
+                importFunctions.onPrint(stream === 2 ? "stderr" : "stdout", line);
+            } else if (stream === 2) {
+                console.error(line);
+            } else {
+                console.log(line);
+            }
+        },
+        __fp_error: (report_ptr: FatPtr) => throwGuestError(plugin, report_ptr),
+        __fp_host_resolve_async_value: (asyncValuePtr: FatPtr, resultPtr: FatPtr) =>
+    resolvePromise(plugin, asyncValuePtr, resultPtr),
+    };
+}
@@ -4,8 +4,67 @@
 // This file is generated. PLEASE DO NOT MODIFY. //
 // ============================================= //
 
+/**
+ * Example of numbers that cannot be represented as `f64` without losing
+ * precision.
+ *
+ * They are exchanged as strings, and typed as branded strings in TypeScript.
+ * Requires the `rust-decimal-compat` and `num-bigint-compat` features.
+ */
+export type ArbitraryPrecisionNumbers = {
+    amount: DecimalString;
+    total_supply: BigIntString;
+};
+
+/**
+ * The response to a batch of operations, some of which may have failed.
+ */
+export type BatchResponse = {
+    results: Array<OperationResult>;
+    summary: Result<string, string>;
+    retryAfter: Result<number, string> | null;
+};
+
+export type BigIntString = string & { readonly __brand: "BigInt" };
+
 export type Body = ArrayBuffer;
 
+/**
+ * Std collections that are encoded as lists.
+ *
+ * Sets are generated as `Set` types in TypeScript if `generate_set_types` is
+ * enabled, while other lists are always generated as arrays.
+ */
+export type Collections = {
+    tags: Set<string>;
+    optionalTags: Set<string> | null;
+    queue: Array<number>;
+};
+
+/**
+ * Fields and variants behind a `#[cfg(...)]` attribute are only included in
+ * the bindings if the protocol is compiled with the condition enabled.
+ *
+ * Using `#[fp(generators = "...")]`, fields and variants can also be limited
+ * to the bindings of specific generators.
+ */
+export type ConditionalFields = {
+    name: string;
+};
+
+export type ConditionalVariants =
+    | "Stable";
+
+export type CustomSerializers = {
+    /**
+     * Serialized as a Unix timestamp, instead of the RFC3339 string that is
+     * used for `OffsetDateTime` by default.
+     */
+    timestamp: number;
+};
+
+export type DecimalString = string & { readonly __brand: "Decimal" };
+
 /**
  * # This is an enum with doc comments.
  */
@@ -42,6 +101,19 @@ export type DocExampleStruct = {
     type: string;
 };
 
+/**
+ * Example of values at the edges of what the runtimes can represent.
+ *
+ * `char` is exchanged as a single-character string, 128-bit integers are
+ * exchanged as decimal strings, and `f32` keeps NaN and infinities intact.
+ */
+export type EdgeValues = {
+    character: string;
+    float: number;
+    signed: I128String;
+    unsigned: U128String;
+};
+
 /**
  * A point of an arbitrary type, with explicit trait bounds.
  */
@@ -58,6 +130,24 @@ export type ExplicitedlyImportedType = {
     you_will_see_this: boolean;
 };
 
+/**
+ * The values that are returned by `export_multiple_values()`.
+ */
+export type ExportMultipleValuesReturn = [string, Array<number>, boolean];
+
+/**
+ * Settings for an extension whose shape is only known to the extension
+ * itself.
+ *
+ * `serde_json::Value` (and `rmpv::Value`) can be used anywhere in a protocol
+ * to pass values of arbitrary shape. Both are encoded as plain MessagePack,
+ * and show up as `unknown` in the TypeScript bindings.
+ */
+export type ExtensionSettings = {
+    extension: string;
+    settings: unknown;
+};
+
 export type FlattenedStruct = {
     foo: string;
     bar: number;
@@ -68,14 +158,20 @@ export type FloatingPoint = Point<number>;
 export type FpAdjacentlyTagged =
     | { type: "Foo" }
     | { type: "Bar"; payload: string }
-    | { type: "Baz"; payload: { a: number; b: number } };
+    | { type: "Baz"; payload: { a: number; b: number } }
+    | { type: "Pair"; payload: [number, string] }
+    | { type: "Unknown" };
 
 export type FpFlatten = {
 } & FlattenedStruct;
 
 export type FpInternallyTagged =
     | { type: "Foo" }
-    | { type: "Baz"; a: number; b: number };
+    | { type: "Baz"; a: number; b: number }
+    /**
+     * A variant that was added after these bindings were generated.
+     */
+    | { type: string };
 
 export type FpPropertyRenaming = {
     fooBar: string;
@@ -85,7 +181,8 @@ export type FpPropertyRenaming = {
 
 export type FpUntagged =
     | string
-    | { a: number; b: number; };
+    | { a: number; b: number; }
+    | [number, string];
 
 export type FpVariantRenaming =
     | "foo_bar"
@@ -109,8 +206,28 @@ export type GroupImportedType2 = {
 
 export type HttpResult = Result<Response, RequestError>;
 
+/**
+ * The values that are returned by `import_multiple_values()`.
+ */
+export type ImportMultipleValuesReturn = [string, Array<number>, boolean];
+
 export type Int64 = number | bigint;
 
+/**
+ * Example of integer types that are not primitives in the protocol.
+ *
+ * `usize` and `isize` are exchanged as `u32` and `i32`, because that is their
+ * size on `wasm32`. `NonZero` types refuse to deserialize when they are zero.
+ */
+export type IntegerTypes = {
+    count: number;
+    offset: number;
+    id: number;
+    delta: number;
+    capacity: number | null;
+    user_id: UserId;
+};
+
 export type Method = 
     | "GET"
     | "POST"
@@ -122,6 +239,19 @@ export type Method =
     | "PATCH"
     | "TRACE";
 
+/**
+ * A measurement that is reported by the plugin.
+ *
+ * Because the `name` field is annotated with `#[fp(interned)]`, the plugin
+ * registers every distinct name with the runtime once, and sends a `u32`
+ * handle in its place from then on. This keeps the payloads small when the
+ * same few names are reported over and over.
+ */
+export type Metric = {
+    name: string;
+    value: number;
+};
+
 /**
  * Our struct for passing date time instances.
  *
@@ -133,6 +263,18 @@ export type Method =
  */
 export type MyDateTime = string;
 
+export type OperationResult = Result<number, string>;
+
+/**
+ * Config that is passed to the `init` export when the plugin is instantiated.
+ *
+ * Every protocol has `init` and `shutdown` exports, but protocols can declare
+ * `init` themselves to have it take a config argument like this one.
+ */
+export type PluginConfig = {
+    logLevel: string;
+};
+
 /**
  * A point of an arbitrary type.
  */
@@ -140,6 +282,41 @@ export type Point<T> = {
     value: T;
 };
 
+export type Priority =
+    /**
+     * Handled after everything else.
+     */
+    | "low"
+    | "normal"
+    | "urgent";
+
+/**
+ * Emitted by the plugin while it works on a long-running task.
+ *
+ * See the `report_progress` event in `main.rs` for more info.
+ */
+export type Progress = {
+    task: string;
+    completedSteps: number;
+    totalSteps: number;
+};
+
+/**
+ * Error that is returned to the plugin when it calls a rate-limited import
+ * more often than its limit allows.
+ */
+export type RateLimited = {
+    /**
+     * The name of the import that was called.
+     */
+    function: string;
+
+    /**
+     * The number of milliseconds after which the import may be called again.
+     */
+    retryAfterMs: number;
+};
+
 /**
  * Example for representing Redux actions.
  */
@@ -236,6 +413,30 @@ export type Result<T, E> =
      */
     | { Err: E };
 
+/**
+ * A sample of a time series.
+ *
+ * Because the struct is annotated with `#[fp(columnar)]`, every `Vec<Sample>`
+ * in the protocol is replaced with a generated `SampleColumns` struct, which
+ * holds a column with the values of every field. In TypeScript, the columns
+ * are typed arrays, so large lists of samples can be passed without encoding
+ * an object for every item.
+ */
+export type Sample = {
+    timestampMs: number;
+    value: number;
+    quality: number;
+};
+
+/**
+ * The columns of a list of `Sample`, with the values of every field stored contiguously.
+ */
+export type SampleColumns = {
+    timestampMs: BigUint64Array;
+    value: Float64Array;
+    quality: Uint8Array;
+};
+
 export type SerdeAdjacentlyTagged =
     | { type: "Foo" }
     | { type: "Bar"; payload: string }
@@ -298,4 +499,123 @@ export type StructWithOptions = {
     neverSkippedEmptyOptionString: string | null;
 };
 
+/**
+ * Timestamps that the TypeScript runtime represents as `Date` objects.
+ *
+ * Fields that are annotated with `ts_as = "Date"` are still serialized as
+ * RFC3339 strings, but the TypeScript runtime converts them into `Date`
+ * objects when they are received, and back into strings when they are sent.
+ */
+export type Timestamps = {
+    createdAt: Date;
+    deletedAt: Date | null;
+};
+
+/**
+ * An identifier of a resource of type `T`.
+ *
+ * `PhantomData` fields are not serialized, so they only show up in the Rust
+ * bindings, where they keep the type parameter in use. Other bindings only
+ * preserve the type parameter in the signature of the type.
+ */
+export type TypedId<T> = {
+    id: number;
+};
+
+/**
+ * Example of a newtype that can be generated as a branded type in TypeScript.
+ *
+ * With `generate_branded_types`, it is typed as
+ * `number & { readonly __brand: "UserId" }`, so that it cannot be mixed up
+ * with other numbers.
+ */
+export type UserId = number;
+
+/**
+ * Structs that contain validated structs get validated as a whole.
+ *
+ * Fields that are not part of this struct are rejected, rather than silently
+ * ignored.
+ */
+export type ValidatedChild = {
+    name: string;
+};
+
+/**
+ * Fields with `#[fp(validate(...))]` attributes are validated when they are
+ * received from the other side, so invalid values are rejected right away.
+ */
+export type ValidatedStruct = {
+    percentage: number;
+    slug: string;
+    ratio: number | null;
+    children: Array<ValidatedChild>;
+};
+
 export type HeaderMap = { [key: string]: Uint8Array };
+
+export type I128String = string & { readonly __brand: "i128" };
+
+export type U128String = string & { readonly __brand: "u128" };
+
+/**
+ * Hashes of the wire format of the protocol's types, which change whenever
+ * values serialized with an earlier version of a type may no longer be
+ * deserialized.
+ */
+export const SCHEMA_HASHES = {
+    ArbitraryPrecisionNumbers: "db827ee0c172d54b",
+    BatchResponse: "d01a07281c913349",
+    Body: "4cca30865b1b5b29",
+    Collections: "0c2e6754c3da68fb",
+    ConditionalFields: "52a7d135f4e752db",
+    ConditionalVariants: "ad94250852394a2c",
+    CustomSerializers: "3995cbdfb49e6e86",
+    DocExampleEnum: "8a247500e246a101",
+    DocExampleStruct: "4d63c33e3821db51",
+    EdgeValues: "3a668df6dcc030ca",
+    ExplicitBoundPoint: "01ab567ecc0d57ce",
+    ExplicitedlyImportedType: "870486c71032cd06",
+    ExtensionSettings: "dd7f1ec765e3177b",
+    FlattenedStruct: "3309edb927ca179e",
+    FloatingPoint: "72901021b02749a4",
+    FpAdjacentlyTagged: "606232f618d7e394",
+    FpFlatten: "46a49ef4fae36d25",
+    FpInternallyTagged: "5f9268fae07343f1",
+    FpPropertyRenaming: "9ceeeeb504a46f92",
+    FpUntagged: "17e08f97b5746b44",
+    FpVariantRenaming: "874c2a453f4dee88",
+    GroupImportedType1: "870486c71032cd06",
+    GroupImportedType2: "870486c71032cd06",
+    HttpResult: "0392c49138515915",
+    Int64: "4d35d3193e8d66f2",
+    IntegerTypes: "28d6bbb19affb584",
+    Metric: "4b59598250e00632",
+    MyDateTime: "909e6a1dd3c1b75c",
+    OperationResult: "36591b64e87bbfd1",
+    PluginConfig: "058a22f99a2bf4c8",
+    Point: "01ab567ecc0d57ce",
+    Priority: "4e502f2d76067ab7",
+    Progress: "78c3c560d6cc3996",
+    RateLimited: "53e94d7f4ad4119a",
+    ReduxAction: "0352d4c53497dc88",
+    Request: "f5b3e2e3135a53a4",
+    RequestError: "52827bfb0a750040",
+    Response: "600ce53352d19a88",
+    Result: "0a54d7094a2e3b35",
+    Sample: "6abd8e557be778d8",
+    SerdeAdjacentlyTagged: "d8c14e6783129ce6",
+    SerdeFlatten: "46a49ef4fae36d25",
+    SerdeInternallyTagged: "5f9268fae07343f1",
+    SerdePropertyRenaming: "9ceeeeb504a46f92",
+    SerdeUntagged: "4b76b5be929dd50f",
+    SerdeVariantRenaming: "a60f3b2a9bc7e9ab",
+    StateUpdate: "3669f62b5f03b9d1",
+    StructWithGenerics: "b93cc0c519e88f8a",
+    StructWithOptions: "bd9f146b3efd1e10",
+    Timestamps: "585a6e28d555d1e8",
+    TypedId: "9ef66fb446ba7f9c",
+    UserId: "b880dc810346cbbd",
+    ValidatedChild: "7d2ecd620ba22cb3",
+    ValidatedStruct: "229c94022f7b70c3",
+} as const;
@@ -0,0 +1,51 @@
+// ============================================= //
+// Validation for WebAssembly runtime            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any
+
+import { FPRuntimeError } from "./memory.ts";
+import type * as types from "./types.ts";
+
+/**
+ * Thrown when a value that is received from the plugin contains a field that
+ * does not pass its validation.
+ */
+export class ValidationError extends FPRuntimeError {
+    constructor(message: string) {
+        super(message);
+    }
+}
+
+export function validateValidatedChild(value: types.ValidatedChild) {
+    for (const key of Object.keys(value)) {
+        if (!["name"].includes(key)) {
+            throw new ValidationError("`ValidatedChild` has unknown field `" + key + "`");
+        }
+    }
+    if (value["name"].length === 0) {
+        throw new ValidationError("`ValidatedChild.name` must not be empty");
+    }
+}
+
+export function validateValidatedStruct(value: types.ValidatedStruct) {
+    if (!(value["percentage"] >= 0 && value["percentage"] <= 100)) {
+        throw new ValidationError("`ValidatedStruct.percentage` must be in range 0..=100, got " + value["percentage"]);
+    }
+    if (value["slug"].length === 0) {
+        throw new ValidationError("`ValidatedStruct.slug` must not be empty");
+    }
+    if (!new RegExp("^[a-z][a-z0-9-]*$").test(value["slug"])) {
+        throw new ValidationError("`ValidatedStruct.slug` must match the regular expression `^[a-z][a-z0-9-]*$`");
+    }
+    if (value["ratio"] != null && !(value["ratio"] >= 0 && value["ratio"] < 1)) {
+        throw new ValidationError("`ValidatedStruct.ratio` must be in range 0..1, got " + value["ratio"]);
+    }
+    if (value["children"].length === 0) {
+        throw new ValidationError("`ValidatedStruct.children` must not be empty");
+    }
+    for (const item of value["children"]) {
+        validateValidatedChild(item);
+    }
+}
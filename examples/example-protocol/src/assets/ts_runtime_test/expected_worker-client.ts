@@ -0,0 +1,347 @@
+// ============================================= //
+// Web Worker client for TypeScript              //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any
+
+import { encode } from "https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts";
+import { toEncodable } from "./conversions.ts";
+import { FPRuntimeError } from "./index.ts";
+import type { Capability, MemoryStats, MsgpackOptions } from "./index.ts";
+import type * as types from "./types.ts";
+
+export type WorkerExports = {
+    exportArrayF32?: (arg: Float32Array) => Promise<Float32Array>;
+    exportArrayF64?: (arg: Float64Array) => Promise<Float64Array>;
+    exportArrayI16?: (arg: Int16Array) => Promise<Int16Array>;
+    exportArrayI32?: (arg: Int32Array) => Promise<Int32Array>;
+    exportArrayI8?: (arg: Int8Array) => Promise<Int8Array>;
+    exportArrayU16?: (arg: Uint16Array) => Promise<Uint16Array>;
+    exportArrayU32?: (arg: Uint32Array) => Promise<Uint32Array>;
+    exportArrayU8?: (arg: Uint8Array) => Promise<Uint8Array>;
+    exportAsyncStruct?: (arg1: types.FpPropertyRenaming, arg2: bigint) => Promise<types.FpPropertyRenaming>;
+    exportBatchResponse?: (arg: types.BatchResponse) => Promise<types.BatchResponse>;
+    exportCachedCallCount?: (label: string) => Promise<string>;
+    exportCallRateLimitedImport?: (calls: number) => Promise<Array<types.Result<string, types.RateLimited>>>;
+    exportCollections?: (arg: types.Collections) => Promise<types.Collections>;
+    exportDynamicValue?: (arg: types.ExtensionSettings) => Promise<unknown>;
+    exportEdgeValues?: (arg: types.EdgeValues) => Promise<types.EdgeValues>;
+    exportFpAdjacentlyTagged?: (arg: types.FpAdjacentlyTagged) => Promise<types.FpAdjacentlyTagged>;
+    exportFpEnum?: (arg: types.FpVariantRenaming) => Promise<types.FpVariantRenaming>;
+    exportFpFlatten?: (arg: types.FpFlatten) => Promise<types.FpFlatten>;
+    exportFpInternallyTagged?: (arg: types.FpInternallyTagged) => Promise<types.FpInternallyTagged>;
+    exportFpStruct?: (arg: types.FpPropertyRenaming) => Promise<types.FpPropertyRenaming>;
+    exportFpUntagged?: (arg: types.FpUntagged) => Promise<types.FpUntagged>;
+    exportGenerics?: (arg: types.StructWithGenerics<number>) => Promise<types.StructWithGenerics<number>>;
+    exportGetBytes?: () => Promise<types.Result<Uint8Array, string>>;
+    exportGetSerdeBytes?: () => Promise<types.Result<ArrayBuffer, string>>;
+    exportLazyString?: (arg: string) => Promise<string>;
+    exportMetrics?: (metrics: Array<types.Metric>) => Promise<Array<types.Metric>>;
+    exportMultiplePrimitives?: (arg1: number, arg2: string) => Promise<bigint>;
+    exportMultipleValues?: (arg: number) => Promise<types.ExportMultipleValuesReturn>;
+    exportPrimitiveBool?: (arg: boolean) => Promise<boolean>;
+    exportPrimitiveF32?: (arg: number) => Promise<number>;
+    exportPrimitiveF64?: (arg: number) => Promise<number>;
+    exportPrimitiveI16?: (arg: number) => Promise<number>;
+    exportPrimitiveI32?: (arg: number) => Promise<number>;
+    exportPrimitiveI64?: (arg: bigint) => Promise<bigint>;
+    exportPrimitiveI8?: (arg: number) => Promise<number>;
+    exportPrimitivePair?: (arg: number) => Promise<[number, number]>;
+    exportPrimitiveU16?: (arg: number) => Promise<number>;
+    exportPrimitiveU32?: (arg: number) => Promise<number>;
+    exportPrimitiveU64?: (arg: bigint) => Promise<bigint>;
+    exportPrimitiveU8?: (arg: number) => Promise<number>;
+    exportRenamedString?: (arg: string) => Promise<string>;
+    exportSamples?: (samples: types.SampleColumns) => Promise<types.SampleColumns>;
+    exportSerdeAdjacentlyTagged?: (arg: types.SerdeAdjacentlyTagged) => Promise<types.SerdeAdjacentlyTagged>;
+    exportSerdeEnum?: (arg: types.SerdeVariantRenaming) => Promise<types.SerdeVariantRenaming>;
+    exportSerdeFlatten?: (arg: types.SerdeFlatten) => Promise<types.SerdeFlatten>;
+    exportSerdeInternallyTagged?: (arg: types.SerdeInternallyTagged) => Promise<types.SerdeInternallyTagged>;
+    exportSerdeStruct?: (arg: types.SerdePropertyRenaming) => Promise<types.SerdePropertyRenaming>;
+    exportSerdeUntagged?: (arg: types.SerdeUntagged) => Promise<types.SerdeUntagged>;
+    exportString?: (arg: string) => Promise<string>;
+    exportStringAfterMemoryGrowth?: (arg: string) => Promise<string>;
+    exportStructWithOptions?: (arg: types.StructWithOptions) => Promise<types.StructWithOptions>;
+    exportTimestamp?: (arg: types.MyDateTime) => Promise<types.MyDateTime>;
+    exportTimestamps?: (arg: types.Timestamps) => Promise<types.Timestamps>;
+    exportTypedId?: (id: types.TypedId<types.Point<number>>) => Promise<types.TypedId<types.Point<number>>>;
+    exportValidatedStruct?: (arg: types.ValidatedStruct) => Promise<types.ValidatedStruct>;
+    exportVoidFunction?: () => Promise<void>;
+    fetchData?: (rType: string) => Promise<types.Result<string, string>>;
+    init?: (config: types.PluginConfig) => Promise<void>;
+    reducerBridge?: (action: types.ReduxAction) => Promise<types.StateUpdate>;
+    updateConfig?: (config: types.PluginConfig) => Promise<void>;
+    memoryStats?: () => Promise<MemoryStats>;
+    dispose: () => Promise<void>;
+};
+
+/**
+ * Asynchronous implementations of imports that the protocol declares as
+ * synchronous. They are called on the main thread, while the worker waits for
+ * them to settle.
+ */
+export type AsyncImports = {
+    importArbitraryPrecisionNumbers?: (arg: types.ArbitraryPrecisionNumbers) => Promise<types.ArbitraryPrecisionNumbers>;
+    importArrayF32?: (arg: Float32Array) => Promise<Float32Array>;
+    importArrayF64?: (arg: Float64Array) => Promise<Float64Array>;
+    importArrayI16?: (arg: Int16Array) => Promise<Int16Array>;
+    importArrayI32?: (arg: Int32Array) => Promise<Int32Array>;
+    importArrayI8?: (arg: Int8Array) => Promise<Int8Array>;
+    importArrayU16?: (arg: Uint16Array) => Promise<Uint16Array>;
+    importArrayU32?: (arg: Uint32Array) => Promise<Uint32Array>;
+    importArrayU8?: (arg: Uint8Array) => Promise<Uint8Array>;
+    importCollections?: (arg: types.Collections) => Promise<types.Collections>;
+    importExplicitBoundPoint?: (arg: types.ExplicitBoundPoint<number>) => Promise<void>;
+    importFpAdjacentlyTagged?: (arg: types.FpAdjacentlyTagged) => Promise<types.FpAdjacentlyTagged>;
+    importFpEnum?: (arg: types.FpVariantRenaming) => Promise<types.FpVariantRenaming>;
+    importFpFlatten?: (arg: types.FpFlatten) => Promise<types.FpFlatten>;
+    importFpInternallyTagged?: (arg: types.FpInternallyTagged) => Promise<types.FpInternallyTagged>;
+    importFpStruct?: (arg: types.FpPropertyRenaming) => Promise<types.FpPropertyRenaming>;
+    importFpUntagged?: (arg: types.FpUntagged) => Promise<types.FpUntagged>;
+    importGenerics?: (arg: types.StructWithGenerics<number>) => Promise<types.StructWithGenerics<number>>;
+    importGetBytes?: () => Promise<types.Result<Uint8Array, string>>;
+    importGetSerdeBytes?: () => Promise<types.Result<ArrayBuffer, string>>;
+    importIntegerTypes?: (arg: types.IntegerTypes) => Promise<types.IntegerTypes>;
+    importMultiplePrimitives?: (arg1: number, arg2: string) => Promise<bigint>;
+    importMultipleValues?: (arg: number) => Promise<types.ImportMultipleValuesReturn>;
+    importPrimitiveBool?: (arg: boolean) => Promise<boolean>;
+    importPrimitiveF32?: (arg: number) => Promise<number>;
+    importPrimitiveF64?: (arg: number) => Promise<number>;
+    importPrimitiveI16?: (arg: number) => Promise<number>;
+    importPrimitiveI32?: (arg: number) => Promise<number>;
+    importPrimitiveI64?: (arg: bigint) => Promise<bigint>;
+    importPrimitiveI8?: (arg: number) => Promise<number>;
+    importPrimitivePair?: (arg: number) => Promise<[number, number]>;
+    importPrimitiveU16?: (arg: number) => Promise<number>;
+    importPrimitiveU32?: (arg: number) => Promise<number>;
+    importPrimitiveU64?: (arg: bigint) => Promise<bigint>;
+    importPrimitiveU8?: (arg: number) => Promise<number>;
+    importRateLimited?: (label: string) => Promise<types.Result<string, types.RateLimited>>;
+    importRenamedString?: (arg: string) => Promise<string>;
+    importSerdeAdjacentlyTagged?: (arg: types.SerdeAdjacentlyTagged) => Promise<types.SerdeAdjacentlyTagged>;
+    importSerdeEnum?: (arg: types.SerdeVariantRenaming) => Promise<types.SerdeVariantRenaming>;
+    importSerdeFlatten?: (arg: types.SerdeFlatten) => Promise<types.SerdeFlatten>;
+    importSerdeInternallyTagged?: (arg: types.SerdeInternallyTagged) => Promise<types.SerdeInternallyTagged>;
+    importSerdeStruct?: (arg: types.SerdePropertyRenaming) => Promise<types.SerdePropertyRenaming>;
+    importSerdeUntagged?: (arg: types.SerdeUntagged) => Promise<types.SerdeUntagged>;
+    importSetValue?: <T>(key: string, value: T) => Promise<void>;
+    importString?: (arg: string) => Promise<string>;
+    importStructWithOptions?: (arg: types.StructWithOptions) => Promise<types.StructWithOptions>;
+    importTimestamp?: (arg: types.MyDateTime) => Promise<types.MyDateTime>;
+    importValidatedStruct?: (arg: types.ValidatedStruct) => Promise<types.ValidatedStruct>;
+    importVoidFunction?: () => Promise<void>;
+    importVoidFunctionEmptyResult?: () => Promise<types.Result<void, number>>;
+    importVoidFunctionEmptyReturn?: () => Promise<void>;
+    invokeCommand?: (name: string, payload: string) => Promise<types.Result<string, string>>;
+    log?: (message: string) => Promise<void>;
+    recordMetric?: (name: string, value: number) => Promise<void>;
+};
+
+/**
+ * The names of the imports that are implemented on the main thread, and the
+ * buffer through which the worker is notified when a call has settled.
+ */
+export type AsyncImportsInit = { names: string[]; signal: SharedArrayBuffer };
+
+export type WorkerClient = WorkerExports & {
+    /**
+     * Stops the worker. Calls that are still pending are rejected.
+     */
+    terminate: () => void;
+};
+
+/**
+ * The first message to the worker, with which the plugin is instantiated.
+ */
+export type WorkerInit = { plugin: ArrayBuffer; capabilities: Capability[]; asyncImports?: AsyncImportsInit };
+
+/**
+ * The response to `WorkerInit`, with the names of the exports that the plugin
+ * implements, or the reason why it could not be instantiated.
+ */
+export type WorkerReady = { exports?: string[]; error?: string };
+
+export type WorkerRequest = { id: number; name: string; args: unknown[] };
+
+export type WorkerResponse = { id: number; result?: unknown; error?: string };
+
+/**
+ * Sent by the worker when the plugin calls an import that is implemented on
+ * the main thread. If the result doesn't fit in the given buffer, the worker
+ * sends a larger buffer with `importResult`.
+ */
+export type WorkerImportRequest =
+    | { importCall: { name: string; args: unknown[]; buffer: SharedArrayBuffer } }
+    | { importResult: { buffer: SharedArrayBuffer } };
+
+type PendingCall = { resolve: (result: unknown) => void; reject: (error: Error) => void };
+
+/**
+ * Runs the given plugin inside a dedicated Web Worker, so that calls into the
+ * plugin don't block the main thread.
+ *
+ * The script of the worker needs to call `exposeRuntime()` from
+ * `worker-runtime.ts` with the functions that may be imported by the
+ * plugin, because functions cannot be passed to the worker.
+ *
+ * The plugin is transferred to the worker, so the given buffer can no longer be
+ * used afterwards. Arguments are copied into the worker using the structured
+ * clone algorithm, while any `ArrayBuffer`s in return values are transferred
+ * back without copying them.
+ *
+ * Synchronous imports may be implemented asynchronously on the main thread,
+ * for instance to use `fetch()` or IndexedDB. While such an import is pending,
+ * the worker blocks using `Atomics.wait()`, so these require the page to be
+ * cross-origin isolated for `SharedArrayBuffer` to be available.
+ *
+ * @param worker The worker, which calls `exposeRuntime()`.
+ * @param plugin The raw WASM plugin.
+ * @param capabilities The capabilities that are granted to the plugin.
+ * @param asyncImports Asynchronous implementations of synchronous imports,
+ *                     which take precedence over the ones in the worker.
+ * @param msgpackOptions Options for encoding the results of `asyncImports`,
+ *                       which need to match the ones of the worker.
+ * @returns Asynchronous proxies for the functions exported by the plugin.
+ */
+export async function createWorkerClient(
+    worker: Worker,
+    plugin: ArrayBuffer,
+    capabilities: Capability[] = [],
+    asyncImports: AsyncImports = {},
+    msgpackOptions: MsgpackOptions = {}
+): Promise<WorkerClient> {
+    const asyncImportNames = Object.keys(asyncImports).filter(
+        (name) => typeof (asyncImports as Record<string, unknown>)[name] === "function"
+    );
+    const asyncImportsInit: AsyncImportsInit | undefined = asyncImportNames.length > 0
+        ? { names: asyncImportNames, signal: new SharedArrayBuffer(8) }
+        : undefined;
+
+    const availableExports = await new Promise((resolve, reject) => {
+        const onMessage = (event: MessageEvent) => {
+            removeListeners();
+            const ready = event.data as WorkerReady;
+            if (ready.error !== undefined) {
+                reject(new FPRuntimeError(ready.error));
+            } else {
+                resolve(ready.exports);
+            }
+        };
+        const onError = (event: ErrorEvent) => {
+            removeListeners();
+            reject(new FPRuntimeError(event.message));
+        };
+        const removeListeners = () => {
+            worker.removeEventListener("message", onMessage);
+            worker.removeEventListener("error", onError);
+        };
+        worker.addEventListener("message", onMessage);
+        worker.addEventListener("error", onError);
+
+        const init: WorkerInit = { plugin, capabilities, asyncImports: asyncImportsInit };
+        worker.postMessage(init, [plugin]);
+    }) as string[];
+
+    const pendingCalls = new Map<number, PendingCall>();
+    let nextCallId = 0;
+
+    function rejectPendingCalls(error: Error) {
+        for (const call of pendingCalls.values()) {
+            call.reject(error);
+        }
+        pendingCalls.clear();
+    }
+
+    // The result of an import call that didn't fit in the buffer of the
+    // worker, which is written once the worker sends a larger one:
+    let oversizedImportResult: ImportResult | undefined;
+
+    async function handleImportRequest(request: WorkerImportRequest, signal: Int32Array) {
+        if ("importResult" in request) {
+            if (oversizedImportResult) {
+                writeImportResult(signal, request.importResult.buffer, oversizedImportResult);
+                oversizedImportResult = undefined;
+            }
+            return;
+        }
+
+        const { name, args, buffer } = request.importCall;
+        let result: ImportResult;
+        try {
+            const implementation = (asyncImports as Record<string, (...args: unknown[]) => Promise<unknown>>)[name];
+            result = { status: IMPORT_READY, data: encode(toEncodable(await implementation(...args)), msgpackOptions) };
+        } catch (error) {
+            const message = error instanceof Error ? error.message : String(error);
+            result = { status: IMPORT_ERROR, data: encode(message, msgpackOptions) };
+        }
+
+        if (result.data.byteLength > buffer.byteLength) {
+            oversizedImportResult = result;
+            Atomics.store(signal, 1, result.data.byteLength);
+            Atomics.store(signal, 0, IMPORT_TOO_LARGE);
+            Atomics.notify(signal, 0);
+        } else {
+            writeImportResult(signal, buffer, result);
+        }
+    }
+
+    worker.addEventListener("message", (event: MessageEvent) => {
+        const request = event.data as WorkerImportRequest;
+        if (asyncImportsInit && ("importCall" in request || "importResult" in request)) {
+            handleImportRequest(request, new Int32Array(asyncImportsInit.signal));
+            return;
+        }
+
+        const response = event.data as WorkerResponse;
+        const call = pendingCalls.get(response.id);
+        if (!call) {
+            return;
+        }
+
+        pendingCalls.delete(response.id);
+        if (response.error !== undefined) {
+            call.reject(new FPRuntimeError(response.error));
+        } else {
+            call.resolve(response.result);
+        }
+    });
+    worker.addEventListener("error", (event: ErrorEvent) => {
+        rejectPendingCalls(new FPRuntimeError(event.message));
+    });
+
+    const client: Record<string, unknown> = {
+        terminate: () => {
+            worker.terminate();
+            rejectPendingCalls(new FPRuntimeError("Worker was terminated"));
+        },
+    };
+    for (const name of availableExports) {
+        client[name] = (...args: unknown[]) => new Promise((resolve, reject) => {
+            const id = nextCallId++;
+            pendingCalls.set(id, { resolve, reject });
+            const request: WorkerRequest = { id, name, args };
+            worker.postMessage(request);
+        });
+    }
+    return client as WorkerClient;
+}
+
+// The states of an import call that are signalled to the worker. These need to
+// match the ones in `worker-runtime`.
+const IMPORT_READY = 1;
+const IMPORT_ERROR = 2;
+const IMPORT_TOO_LARGE = 3;
+
+type ImportResult = { status: number; data: Uint8Array };
+
+/**
+ * Writes the result of an import call to the buffer of the worker, and wakes
+ * up the worker.
+ */
+function writeImportResult(signal: Int32Array, buffer: SharedArrayBuffer, result: ImportResult) {
+    new Uint8Array(buffer).set(result.data);
+    Atomics.store(signal, 1, result.data.byteLength);
+    Atomics.store(signal, 0, result.status);
+    Atomics.notify(signal, 0);
+}
@@ -0,0 +1,239 @@
+// ============================================= //
+// Web Worker runtime for TypeScript             //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any
+
+import { decode } from "https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts";
+import { createRuntime, FPRuntimeError } from "./index.ts";
+import type { Exports, Imports, MsgpackOptions, RuntimeOptions } from "./index.ts";
+import type {
+    AsyncImports,
+    AsyncImportsInit,
+    WorkerImportRequest,
+    WorkerInit,
+    WorkerReady,
+    WorkerRequest,
+    WorkerResponse,
+} from "./worker-client.ts";
+
+/**
+ * The host functions that are implemented inside the worker. Synchronous
+ * imports may be omitted if they are implemented on the main thread instead,
+ * by passing them to `createWorkerClient()`.
+ */
+export type WorkerImports = Omit<Imports, keyof AsyncImports> &
+    Partial<Pick<Imports, keyof AsyncImports>>;
+
+/**
+ * The parts of the global scope of a dedicated worker that are used, so that
+ * this module doesn't require the `webworker` library of TypeScript.
+ */
+type WorkerScope = {
+    addEventListener: (type: "message", listener: (event: MessageEvent) => void) => void;
+    postMessage: (message: unknown, transfer?: Transferable[]) => void;
+};
+
+const EXPORT_NAMES = [
+    "exportArrayF32",
+    "exportArrayF64",
+    "exportArrayI16",
+    "exportArrayI32",
+    "exportArrayI8",
+    "exportArrayU16",
+    "exportArrayU32",
+    "exportArrayU8",
+    "exportAsyncStruct",
+    "exportBatchResponse",
+    "exportCachedCallCount",
+    "exportCallRateLimitedImport",
+    "exportCollections",
+    "exportDynamicValue",
+    "exportEdgeValues",
+    "exportFpAdjacentlyTagged",
+    "exportFpEnum",
+    "exportFpFlatten",
+    "exportFpInternallyTagged",
+    "exportFpStruct",
+    "exportFpUntagged",
+    "exportGenerics",
+    "exportGetBytes",
+    "exportGetSerdeBytes",
+    "exportLazyString",
+    "exportMetrics",
+    "exportMultiplePrimitives",
+    "exportMultipleValues",
+    "exportPrimitiveBool",
+    "exportPrimitiveF32",
+    "exportPrimitiveF64",
+    "exportPrimitiveI16",
+    "exportPrimitiveI32",
+    "exportPrimitiveI64",
+    "exportPrimitiveI8",
+    "exportPrimitivePair",
+    "exportPrimitiveU16",
+    "exportPrimitiveU32",
+    "exportPrimitiveU64",
+    "exportPrimitiveU8",
+    "exportRenamedString",
+    "exportSamples",
+    "exportSerdeAdjacentlyTagged",
+    "exportSerdeEnum",
+    "exportSerdeFlatten",
+    "exportSerdeInternallyTagged",
+    "exportSerdeStruct",
+    "exportSerdeUntagged",
+    "exportString",
+    "exportStringAfterMemoryGrowth",
+    "exportStructWithOptions",
+    "exportTimestamp",
+    "exportTimestamps",
+    "exportTypedId",
+    "exportValidatedStruct",
+    "exportVoidFunction",
+    "fetchData",
+    "init",
+    "reducerBridge",
+    "updateConfig",
+    "memoryStats",
+    "dispose",
+];
+
+/**
+ * Instantiates the plugin that is sent by `createWorkerClient()`, and executes
+ * the calls to its exports. This needs to be called from the script of the
+ * worker.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @param options Options for the runtime, such as the options of the MessagePack
+ *                encoder and decoder.
+ */
+export function exposeRuntime(importFunctions: WorkerImports, options: RuntimeOptions = {}): void {
+    const scope = globalThis as unknown as WorkerScope;
+    let exports: Record<string, any> | undefined;
+
+    scope.addEventListener("message", async (event: MessageEvent) => {
+        if (!exports) {
+            const init = event.data as WorkerInit;
+            let ready: WorkerReady;
+            try {
+                const imports = withAsyncImports(
+                    scope,
+                    importFunctions,
+                    init.asyncImports,
+                    options.msgpack
+                );
+                const runtime: Exports = await createRuntime(
+                    init.plugin,
+                    imports,
+                    init.capabilities,
+                    [],
+                    options
+                );
+                exports = runtime as Record<string, any>;
+                ready = { exports: EXPORT_NAMES.filter((name) => typeof exports?.[name] === "function") };
+            } catch (error) {
+                ready = { error: error instanceof Error ? error.message : String(error) };
+            }
+            scope.postMessage(ready);
+            return;
+        }
+
+        const { id, name, args } = event.data as WorkerRequest;
+        try {
+            const result = await exports[name](...args);
+            const response: WorkerResponse = { id, result };
+            scope.postMessage(response, collectTransferables(result));
+        } catch (error) {
+            const response: WorkerResponse = {
+                id,
+                error: error instanceof Error ? error.message : String(error),
+            };
+            scope.postMessage(response);
+        }
+    });
+}
+
+/**
+ * Collects the `ArrayBuffer`s contained in a value, so they can be transferred
+ * to the main thread instead of being copied.
+ */
+function collectTransferables(value: unknown): ArrayBuffer[] {
+    const buffers = new Set<ArrayBuffer>();
+    const visit = (value: unknown) => {
+        if (value instanceof ArrayBuffer) {
+            buffers.add(value);
+        } else if (ArrayBuffer.isView(value)) {
+            if (value.buffer instanceof ArrayBuffer) {
+                buffers.add(value.buffer);
+            }
+        } else if (value instanceof Map) {
+            value.forEach((item, key) => {
+                visit(key);
+                visit(item);
+            });
+        } else if (value !== null && typeof value === "object") {
+            Object.values(value).forEach(visit);
+        }
+    };
+    visit(value);
+    return [...buffers];
+}
+
+// The states of an import call that is implemented on the main thread. These
+// need to match the ones in `worker-client`.
+const IMPORT_PENDING = 0;
+const IMPORT_ERROR = 2;
+const IMPORT_TOO_LARGE = 3;
+
+/**
+ * The initial size of the buffer to which the main thread writes the results
+ * of import calls. It is replaced with a larger one if a result doesn't fit.
+ */
+const INITIAL_IMPORT_BUFFER_SIZE = 64 * 1024;
+
+/**
+ * Adds synchronous implementations for the imports that are implemented on
+ * the main thread. These block the worker until the asynchronous
+ * implementation has settled, so that the plugin can call them as usual.
+ */
+function withAsyncImports(
+    scope: WorkerScope,
+    importFunctions: WorkerImports,
+    asyncImports: AsyncImportsInit | undefined,
+    msgpackOptions: MsgpackOptions | undefined
+): Imports {
+    if (!asyncImports) {
+        return importFunctions as Imports;
+    }
+
+    const signal = new Int32Array(asyncImports.signal);
+    let buffer = new SharedArrayBuffer(INITIAL_IMPORT_BUFFER_SIZE);
+    const waitForResult = (request: WorkerImportRequest) => {
+        Atomics.store(signal, 0, IMPORT_PENDING);
+        scope.postMessage(request);
+        Atomics.wait(signal, 0, IMPORT_PENDING);
+        return Atomics.load(signal, 0);
+    };
+
+    const blockingImports: Record<string, (...args: unknown[]) => unknown> = {};
+    for (const name of asyncImports.names) {
+        blockingImports[name] = (...args: unknown[]) => {
+            let status = waitForResult({ importCall: { name, args, buffer } });
+            if (status === IMPORT_TOO_LARGE) {
+                buffer = new SharedArrayBuffer(Atomics.load(signal, 1));
+                status = waitForResult({ importResult: { buffer } });
+            }
+
+            // The result is copied, since it cannot be decoded from a shared buffer:
+            const data = new Uint8Array(buffer, 0, Atomics.load(signal, 1)).slice();
+            const result = decode(data, msgpackOptions);
+            if (status === IMPORT_ERROR) {
+                throw new FPRuntimeError(result as string);
+            }
+            return result;
+        };
+    }
+    return { ...importFunctions, ...blockingImports } as Imports;
+}
@@ -5,6 +5,7 @@ use fp_bindgen::{prelude::*, types::CargoDependency};
 use once_cell::sync::Lazy;
 use redux_example::{ReduxAction, StateUpdate};
 use serde_bytes::ByteBuf;
+use serde_json::Value;
 use std::collections::{BTreeMap, BTreeSet};
 
 // Referencing types using their full module path can be problematic in some
@@ -14,6 +15,12 @@ use std::collections::{BTreeMap, BTreeSet};
 mod types;
 use types::*;
 
+#[cfg(test)]
+mod protocol_trait;
+
+#[cfg(test)]
+mod remote_types;
+
 fp_import! {
     // Aliases need to be explicitly mentioned in either `fp_import!` or
     // `fp_export!`.
@@ -23,6 +30,7 @@ fp_import! {
     type FloatingPoint = Point<f64>;
     type HttpResult = Result<Response, RequestError>;
     type Int64 = u64;
+    type OperationResult = Result<u64, String>;
 
     // Types that are not referenced by any of the protocol functions (either
     // directly as argument or return type, or indirectly through other types)
@@ -32,7 +40,10 @@ fp_import! {
     // See `types/dead_code.rs` for more info.
     use ExplicitedlyImportedType;
     use submodule::{nested::GroupImportedType1, GroupImportedType2};
-    use types::{DocExampleEnum, DocExampleStruct};
+    use types::{
+        ConditionalFields, ConditionalVariants, CustomSerializers, DocExampleEnum,
+        DocExampleStruct, Priority,
+    };
 
     // ===============================================================
     // Imported functions that we call as part of the end-to-end tests
@@ -60,6 +71,12 @@ fp_import! {
     fn import_primitive_u32(arg: u32) -> u32;
     fn import_primitive_u64(arg: u64) -> u64;
 
+    // Returning a pair of primitives, which is packed into a single `u64`:
+    fn import_primitive_pair(arg: u32) -> (u32, i32);
+
+    // Returning multiple values as a tuple:
+    fn import_multiple_values(arg: u32) -> (String, Vec<u32>, bool);
+
     // Passing arrays:
     fn import_array_u8(arg: [u8; 3]) -> [u8; 3];
     fn import_array_u16(arg: [u16; 3]) -> [u16; 3];
@@ -94,6 +111,24 @@ fp_import! {
     // Options
     fn import_struct_with_options(arg: StructWithOptions) -> StructWithOptions;
 
+    // Validated fields.
+    //
+    // See `types/validation.rs` for more info.
+    fn import_validated_struct(arg: ValidatedStruct) -> ValidatedStruct;
+
+    // Integer types that are not primitives.
+    //
+    // See `types/numbers.rs` for more info.
+    fn import_integer_types(arg: IntegerTypes) -> IntegerTypes;
+    fn import_arbitrary_precision_numbers(
+        arg: ArbitraryPrecisionNumbers,
+    ) -> ArbitraryPrecisionNumbers;
+
+    // Std collections that are encoded as lists.
+    //
+    // See `types/collections.rs` for more info.
+    fn import_collections(arg: Collections) -> Collections;
+
     // Custom type in a generic position.
     fn import_get_bytes() -> Result<Bytes, String>;
     fn import_get_serde_bytes() -> Result<ByteBuf, String>;
@@ -119,13 +154,56 @@ fp_import! {
     // Async function:
     async fn import_fp_struct(arg1: FpPropertyRenaming, arg2: u64) -> FpPropertyRenaming;
 
+    // Async function without a return value, which is fire-and-forget:
+    async fn import_async_void_function(message: String);
+
     /// Logs a message to the (development) console.
     fn log(message: String);
 
+    /// Example of a dynamic import. The runtime can register handlers for it
+    /// after instantiation, which are dispatched to by `name`. Calls for which
+    /// no handler is registered fall back to the regular implementation.
+    #[fp(dynamic)]
+    fn invoke_command(name: String, payload: String) -> Result<String, String>;
+
+    /// Example of a batchable import. Calls that are made inside `batch()` are
+    /// queued by the plugin and sent to the runtime together.
+    #[fp(batchable)]
+    fn record_metric(name: String, value: f64);
+
+    /// Example of an import behind a feature. Only plugins and runtimes that
+    /// are compiled with the `experimental` feature know about it.
+    #[fp(cfg_feature = "experimental")]
+    fn import_experimental(arg: String) -> String;
+
+    // Generic functions, as they may be used for a key-value store in which
+    // the plugin picks the types of the values:
+    fn import_set_value<T>(key: String, value: T);
+    async fn import_get_value<T>(key: String) -> Option<T>;
+
+    /// Example of an event. The plugin can emit it at any time, after which
+    /// the runtime passes it on to its subscribers.
+    #[fp_event]
+    fn report_progress(progress: Progress);
+
     /// Example how a runtime could expose a `Fetch`-like function to plugins.
     ///
     /// See `types/http.rs` for more info.
+    ///
+    /// Plugins may only call this if the runtime granted them the `network`
+    /// capability.
+    #[fp(capability = "network")]
     async fn make_http_request(request: Request) -> HttpResult;
+
+    /// Example of a rate-limited import. Calls that exceed the limit return a
+    /// `RateLimited` error to the plugin, instead of reaching the runtime.
+    #[fp(rate_limit(calls = 2, per = "1m"))]
+    fn import_rate_limited(label: String) -> Result<String, RateLimited>;
+
+    /// Example of a renamed import. It is still exchanged under its old name,
+    /// so plugins that were built against `import_legacy_string` keep working.
+    #[fp(rename = "import_legacy_string")]
+    fn import_renamed_string(arg: String) -> String;
 }
 
 fp_export! {
@@ -149,6 +227,12 @@ fp_export! {
     fn export_primitive_u32(arg: u32) -> u32;
     fn export_primitive_u64(arg: u64) -> u64;
 
+    // Returning a pair of primitives, which is packed into a single `u64`:
+    fn export_primitive_pair(arg: u32) -> (u32, i32);
+
+    // Returning multiple values as a tuple:
+    fn export_multiple_values(arg: u32) -> (String, Vec<u32>, bool);
+
     // Passing arrays:
     fn export_array_u8(arg: [u8; 3]) -> [u8; 3];
     fn export_array_u16(arg: [u16; 3]) -> [u16; 3];
@@ -162,27 +246,86 @@ fp_export! {
     // Passing strings:
     fn export_string(arg: String) -> String;
 
+    // Passing strings while the plugin grows its memory. Runtimes must not
+    // keep views on the memory that existed before the call:
+    fn export_string_after_memory_growth(arg: String) -> String;
+
+    // Returning a value that the runtime only deserializes when it is accessed:
+    #[fp(lazy)]
+    fn export_lazy_string(arg: String) -> String;
+
+    // Memoizing results in the host, so that repeated calls with the same
+    // arguments don't reach the plugin:
+    #[fp(cache(ttl = "30s", key = "args"))]
+    fn export_cached_call_count(label: String) -> String;
+
+    // Calling a rate-limited import more often than its limit allows:
+    fn export_call_rate_limited_import(calls: u32) -> Vec<Result<String, RateLimited>>;
+
+    // Renaming an export, while runtimes still call it by its old name:
+    #[fp(rename = "export_legacy_string")]
+    fn export_renamed_string(arg: String) -> String;
+
     // Multiple arguments:
+    #[fp(http(method = "GET", path = "/primitives"))]
+    #[fp(read_only)]
     fn export_multiple_primitives(arg1: i8, arg2: String) -> i64;
 
     // Integration with the `time` crate:
     fn export_timestamp(arg: MyDateTime) -> MyDateTime;
+    fn export_timestamps(arg: Timestamps) -> Timestamps;
+
+    // Values at the edges of what the runtimes can represent.
+    //
+    // See `types/numbers.rs` for more info.
+    fn export_edge_values(arg: EdgeValues) -> EdgeValues;
 
     // Passing custom types with flattened properties.
     //
     // See `types/flattening.rs` for more info.
     fn export_fp_flatten(arg: FpFlatten) -> FpFlatten;
+    #[fp(http(method = "POST", path = "/flatten"))]
+    #[fp(read_only)]
     fn export_serde_flatten(arg: SerdeFlatten) -> SerdeFlatten;
 
     // Generics.
     //
     // See `types/generics.rs` for more info.
+    #[fp(http(method = "POST", path = "/generics"))]
+    #[fp(read_only)]
     fn export_generics(arg: StructWithGenerics<u64>) -> StructWithGenerics<u64>;
+    fn export_typed_id(id: TypedId<Point<f64>>) -> TypedId<Point<f64>>;
 
     // Options
+    #[fp(http(method = "PUT", path = "/options"))]
+    #[fp(read_only)]
     fn export_struct_with_options(arg: StructWithOptions) -> StructWithOptions;
 
+    // Validated fields.
+    //
+    // See `types/validation.rs` for more info.
+    fn export_validated_struct(arg: ValidatedStruct) -> ValidatedStruct;
+
+    // Std collections that are encoded as lists.
+    //
+    // See `types/collections.rs` for more info.
+    #[fp(read_only)]
+    fn export_collections(arg: Collections) -> Collections;
+
+    // Lists of structs that are encoded as columns.
+    //
+    // See `types/columnar.rs` for more info.
+    #[fp(read_only)]
+    fn export_samples(samples: Vec<Sample>) -> Vec<Sample>;
+
+    // Structs with interned strings.
+    //
+    // See `types/interning.rs` for more info.
+    fn export_metrics(metrics: Vec<Metric>) -> Vec<Metric>;
+
     // Custom type in a generic position.
+    #[fp(http(method = "GET", path = "/bytes"))]
+    #[fp(read_only)]
     fn export_get_bytes() -> Result<Bytes, String>;
     fn export_get_serde_bytes() -> Result<ByteBuf, String>;
 
@@ -192,6 +335,8 @@ fp_export! {
     fn export_fp_struct(arg: FpPropertyRenaming) -> FpPropertyRenaming;
     fn export_fp_enum(arg: FpVariantRenaming) -> FpVariantRenaming;
     fn export_serde_struct(arg: SerdePropertyRenaming) -> SerdePropertyRenaming;
+    #[fp(http(method = "POST", path = "/enums/renamed"))]
+    #[fp(read_only)]
     fn export_serde_enum(arg: SerdeVariantRenaming) -> SerdeVariantRenaming;
 
     // Passing custom enums with different tagging options.
@@ -200,18 +345,44 @@ fp_export! {
     fn export_fp_internally_tagged(arg: FpInternallyTagged) -> FpInternallyTagged;
     fn export_fp_adjacently_tagged(arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged;
     fn export_fp_untagged(arg: FpUntagged) -> FpUntagged;
+    #[fp(http(method = "POST", path = "/enums/internally-tagged"))]
+    #[fp(read_only)]
     fn export_serde_internally_tagged(arg: SerdeInternallyTagged) -> SerdeInternallyTagged;
+    #[fp(http(method = "POST", path = "/enums/adjacently-tagged"))]
     fn export_serde_adjacently_tagged(arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged;
+    #[fp(http(method = "POST", path = "/enums/untagged"))]
     fn export_serde_untagged(arg: SerdeUntagged) -> SerdeUntagged;
 
+    // Passing values of arbitrary shape.
+    //
+    // See `types/dynamic_values.rs` for more info.
+    fn export_dynamic_value(arg: ExtensionSettings) -> Value;
+
+    // Results inside data types.
+    //
+    // See `types/fallible.rs` for more info.
+    #[fp(http(method = "POST", path = "/batch"))]
+    fn export_batch_response(arg: BatchResponse) -> BatchResponse;
+
+    // Exports behind a feature:
+    #[fp(cfg_feature = "experimental")]
+    fn export_experimental(arg: String) -> String;
+
     // Async function:
+    #[fp(http(method = "POST", path = "/async-struct"))]
     async fn export_async_struct(arg1: FpPropertyRenaming, arg2: u64) -> FpPropertyRenaming;
 
     /// Example how plugin could expose async data-fetching capabilities.
+    #[fp(http(method = "GET", path = "/data/{type}"))]
     async fn fetch_data(r#type: String) -> Result<String, String>;
 
     /// Called on the plugin to give it a chance to initialize.
-    fn init();
+    #[fp(http(method = "POST", path = "/init"))]
+    fn init(config: PluginConfig);
+
+    /// Called on the plugin before it is discarded, to give it a chance to
+    /// clean up.
+    fn shutdown();
 
     /// Example how plugin could expose a reducer.
     fn reducer_bridge(action: ReduxAction) -> StateUpdate;
@@ -231,7 +402,7 @@ static PLUGIN_DEPENDENCIES: Lazy<BTreeMap<&str, CargoDependency>> = Lazy::new(||
             "fp-bindgen-support",
             CargoDependency::with_path_and_features(
                 "../../../../fp-bindgen-support",
-                BTreeSet::from(["async", "guest"]),
+                BTreeSet::from(["async", "guest", "memory-stats"]),
             ),
         ),
         (
@@ -241,27 +412,144 @@ static PLUGIN_DEPENDENCIES: Lazy<BTreeMap<&str, CargoDependency>> = Lazy::new(||
     ])
 });
 
+fn fuzz_config() -> FuzzConfig {
+    FuzzConfig::new()
+        .with_name("example-bindings-fuzz")
+        .with_dependency(
+            "redux-example",
+            CargoDependency::with_path("../../../redux-example"),
+        )
+        .with_dependency(
+            "fp-bindgen-support",
+            CargoDependency::with_path("../../../../fp-bindgen-support"),
+        )
+        .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
+}
+
+fn fixtures_config() -> FixturesConfig {
+    FixturesConfig::new()
+        .with_name("example-bindings-fixtures")
+        .with_dependency(
+            "redux-example",
+            CargoDependency::with_path("../../../redux-example"),
+        )
+        .with_dependency(
+            "fp-bindgen-support",
+            CargoDependency::with_path("../../../../fp-bindgen-support"),
+        )
+        .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
+}
+
+fn cli_config() -> CliConfig {
+    CliConfig::new()
+        .with_name("example-bindings-cli")
+        .with_dependency(
+            "redux-example",
+            CargoDependency::with_path("../../../redux-example"),
+        )
+        .with_dependency(
+            "fp-bindgen-support",
+            CargoDependency::with_path("../../../../fp-bindgen-support"),
+        )
+}
+
+fn rust_types_config() -> RustTypesConfig {
+    RustTypesConfig::new()
+        .with_name("example-types")
+        .with_dependency(
+            "redux-example",
+            CargoDependency::with_path("../../../redux-example"),
+        )
+        .with_dependency(
+            "fp-bindgen-support",
+            CargoDependency::with_path("../../../../fp-bindgen-support"),
+        )
+}
+
+fn scaffold_config() -> ScaffoldConfig {
+    ScaffoldConfig::new()
+        .with_name("example-plugin")
+        .with_bindings(
+            "example-bindings",
+            CargoDependency::with_path("../rust-plugin"),
+        )
+        .with_dependency(
+            "redux-example",
+            CargoDependency::with_path("../../../redux-example"),
+        )
+        .with_dependency(
+            "fp-bindgen-support",
+            CargoDependency::with_path("../../../../fp-bindgen-support"),
+        )
+}
+
 fn main() {
+    // `cargo run -- snapshot <path>` and `cargo run -- diff <path>` can be
+    // used to check the protocol for breaking changes.
+    if fp_bindgen::handle_protocol_cli(&fp_protocol!()) {
+        return;
+    }
+
     for bindings_type in [
-        BindingsType::RustPlugin(RustPluginConfig {
-            name: NAME,
-            authors: AUTHORS,
-            version: VERSION,
-            dependencies: PLUGIN_DEPENDENCIES.clone(),
-        }),
-        BindingsType::RustWasmerRuntime,
+        BindingsType::RustPlugin(
+            RustPluginConfig::new(NAME, AUTHORS, VERSION)
+                .with_dependencies(PLUGIN_DEPENDENCIES.clone()),
+        ),
+        BindingsType::RustWasmerRuntimeWithExtendedConfig(
+            RustWasmerExtendedRuntimeConfig::new()
+                .with_runtime_pool()
+                .with_shared_runtime()
+                .with_opentelemetry()
+                .with_module_cache()
+                .with_async_concurrency_limit()
+                .with_std_imports()
+                .with_http_imports()
+                .with_stdio_capture()
+                .with_import_routes()
+                .with_deadlines()
+                .with_migrations()
+                .with_import_overrides()
+                .with_signature_verification()
+                .with_payload_tracing()
+                .with_eager_export_resolution(),
+        ),
         BindingsType::RustWasmerWasiRuntime,
+        BindingsType::RustWasmiRuntime,
         BindingsType::TsRuntimeWithExtendedConfig(
             TsExtendedRuntimeConfig::new()
                 .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
-                .with_raw_export_wrappers(),
+                .with_raw_export_wrappers()
+                .with_compression()
+                .with_async_imports()
+                .with_std_imports()
+                .with_http_imports()
+                .with_stdio_capture()
+                .with_set_types()
+                .with_signature_verification()
+                .with_payload_tracing()
+                .with_eager_export_resolution(),
         ),
+        BindingsType::Benches(
+            BenchesConfig::new()
+                .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts"),
+        ),
+        BindingsType::CSharpRuntime(CSharpRuntimeConfig::new().with_namespace("ExampleBindings")),
+        BindingsType::AssemblyScriptPlugin(AssemblyScriptPluginConfig::new()),
+        BindingsType::Fuzz(fuzz_config()),
+        BindingsType::Fixtures(fixtures_config()),
+        BindingsType::Cli(cli_config()),
+        BindingsType::Scaffold(scaffold_config()),
+        BindingsType::RustTypes(rust_types_config()),
+        BindingsType::OpenApi(OpenApiConfig::new().with_title("Example API")),
+        BindingsType::GraphQl,
+        BindingsType::Docs(DocsConfig::new().with_title("Example protocol")),
     ] {
         let output_path = format!("bindings/{bindings_type}");
 
         fp_bindgen!(BindingConfig {
             bindings_type,
             path: &output_path,
+            unused_types: UnusedTypes::Keep,
         });
         println!("Generated bindings written to `{output_path}/`.");
     }
@@ -292,14 +580,86 @@ fn test_generate_rust_plugin() {
         ),
     ];
 
+    // Local variables in the field expressions must not be shadowed by the
+    // bindings that `fp_bindgen!` introduces:
+    let config = UnusedTypes::Keep;
     fp_bindgen!(BindingConfig {
-        bindings_type: BindingsType::RustPlugin(RustPluginConfig {
-            name: NAME,
-            authors: AUTHORS,
-            version: VERSION,
-            dependencies: PLUGIN_DEPENDENCIES.clone(),
-        }),
+        bindings_type: BindingsType::RustPlugin(
+            RustPluginConfig::new(NAME, AUTHORS, VERSION)
+                .with_dependencies(PLUGIN_DEPENDENCIES.clone()),
+        ),
         path: "bindings/rust-plugin",
+        unused_types: config,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_rust_plugin_no_std() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/rust-plugin-no-std/src/types.rs",
+            include_bytes!("assets/rust_plugin_no_std_test/expected_types.rs"),
+        ),
+        (
+            "bindings/rust-plugin-no-std/src/lib.rs",
+            include_bytes!("assets/rust_plugin_no_std_test/expected_lib.rs"),
+        ),
+        (
+            "bindings/rust-plugin-no-std/src/export.rs",
+            include_bytes!("assets/rust_plugin_no_std_test/expected_export.rs"),
+        ),
+        (
+            "bindings/rust-plugin-no-std/src/import.rs",
+            include_bytes!("assets/rust_plugin_no_std_test/expected_import.rs"),
+        ),
+        (
+            "bindings/rust-plugin-no-std/Cargo.toml",
+            include_bytes!("assets/rust_plugin_no_std_test/expected_Cargo.toml"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::RustPlugin(
+            RustPluginConfig::new(NAME, AUTHORS, VERSION)
+                .with_dependencies(PLUGIN_DEPENDENCIES.clone())
+                .with_no_std()
+                .with_panic_abort()
+                .with_allocator(PluginAllocator::Talc),
+        ),
+        path: "bindings/rust-plugin-no-std",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_rust_plugin_with_guest_metrics() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/rust-plugin-guest-metrics/src/import.rs",
+            include_bytes!("assets/rust_plugin_guest_metrics_test/expected_import.rs"),
+        ),
+        (
+            "bindings/rust-plugin-guest-metrics/Cargo.toml",
+            include_bytes!("assets/rust_plugin_guest_metrics_test/expected_Cargo.toml"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::RustPlugin(
+            RustPluginConfig::new(NAME, AUTHORS, VERSION)
+                .with_dependencies(PLUGIN_DEPENDENCIES.clone())
+                .with_guest_metrics(),
+        ),
+        path: "bindings/rust-plugin-guest-metrics",
+        unused_types: UnusedTypes::Keep,
     });
 
     for (path, expected) in FILES {
@@ -311,17 +671,79 @@ fn test_generate_rust_plugin() {
 fn test_generate_rust_wasmer_runtime() {
     static FILES: &[(&str, &[u8])] = &[
         (
-            "bindings/rust-wasmer-runtime/bindings.rs",
+            "bindings/rust-wasmer-runtime-plain/bindings.rs",
             include_bytes!("assets/rust_wasmer_runtime_test/expected_bindings.rs"),
         ),
         (
-            "bindings/rust-wasmer-runtime/types.rs",
+            "bindings/rust-wasmer-runtime-plain/types.rs",
             include_bytes!("assets/rust_wasmer_runtime_test/expected_types.rs"),
         ),
     ];
     fp_bindgen!(BindingConfig {
         bindings_type: BindingsType::RustWasmerRuntime,
+        path: "bindings/rust-wasmer-runtime-plain",
+        unused_types: UnusedTypes::Keep,
+    });
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_rust_wasmer_runtime_pool() {
+    static FILES: &[(&str, &[u8])] = &[(
+        "bindings/rust-wasmer-runtime-pool/bindings.rs",
+        include_bytes!("assets/rust_wasmer_runtime_pool_test/expected_bindings.rs"),
+    )];
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::RustWasmerRuntimeWithExtendedConfig(
+            RustWasmerExtendedRuntimeConfig::new().with_runtime_pool()
+        ),
+        path: "bindings/rust-wasmer-runtime-pool",
+        unused_types: UnusedTypes::Keep,
+    });
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_rust_wasmer_extended_runtime() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/rust-wasmer-runtime/bindings.rs",
+            include_bytes!("assets/rust_wasmer_extended_runtime_test/expected_bindings.rs"),
+        ),
+        (
+            "bindings/rust-wasmer-runtime/migrations.rs",
+            include_bytes!("assets/rust_wasmer_extended_runtime_test/expected_migrations.rs"),
+        ),
+        (
+            "bindings/rust-wasmer-runtime/types.rs",
+            include_bytes!("assets/rust_wasmer_extended_runtime_test/expected_types.rs"),
+        ),
+    ];
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::RustWasmerRuntimeWithExtendedConfig(
+            RustWasmerExtendedRuntimeConfig::new()
+                .with_runtime_pool()
+                .with_shared_runtime()
+                .with_opentelemetry()
+                .with_module_cache()
+                .with_async_concurrency_limit()
+                .with_std_imports()
+                .with_http_imports()
+                .with_stdio_capture()
+                .with_import_routes()
+                .with_deadlines()
+                .with_migrations()
+                .with_import_overrides()
+                .with_signature_verification()
+                .with_payload_tracing()
+                .with_eager_export_resolution(),
+        ),
         path: "bindings/rust-wasmer-runtime",
+        unused_types: UnusedTypes::Keep,
     });
     for (path, expected) in FILES {
         tests::assert_file_eq(path, expected)
@@ -343,6 +765,29 @@ fn test_generate_rust_wasmer_wasi_runtime() {
     fp_bindgen!(BindingConfig {
         bindings_type: BindingsType::RustWasmerWasiRuntime,
         path: "bindings/rust-wasmer-wasi-runtime",
+        unused_types: UnusedTypes::Keep,
+    });
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_rust_wasmi_runtime() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/rust-wasmi-runtime/bindings.rs",
+            include_bytes!("assets/rust_wasmi_runtime_test/expected_bindings.rs"),
+        ),
+        (
+            "bindings/rust-wasmi-runtime/types.rs",
+            include_bytes!("assets/rust_wasmi_runtime_test/expected_types.rs"),
+        ),
+    ];
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::RustWasmiRuntime,
+        path: "bindings/rust-wasmi-runtime",
+        unused_types: UnusedTypes::Keep,
     });
     for (path, expected) in FILES {
         tests::assert_file_eq(path, expected)
@@ -356,10 +801,42 @@ fn test_generate_ts_runtime() {
             "bindings/ts-runtime/types.ts",
             include_bytes!("assets/ts_runtime_test/expected_types.ts"),
         ),
+        (
+            "bindings/ts-runtime/memory.ts",
+            include_bytes!("assets/ts_runtime_test/expected_memory.ts"),
+        ),
+        (
+            "bindings/ts-runtime/imports.ts",
+            include_bytes!("assets/ts_runtime_test/expected_imports.ts"),
+        ),
+        (
+            "bindings/ts-runtime/exports.ts",
+            include_bytes!("assets/ts_runtime_test/expected_exports.ts"),
+        ),
+        (
+            "bindings/ts-runtime/validation.ts",
+            include_bytes!("assets/ts_runtime_test/expected_validation.ts"),
+        ),
         (
             "bindings/ts-runtime/index.ts",
             include_bytes!("assets/ts_runtime_test/expected_index.ts"),
         ),
+        (
+            "bindings/ts-runtime/experimental.ts",
+            include_bytes!("assets/ts_runtime_test/expected_experimental.ts"),
+        ),
+        (
+            "bindings/ts-runtime/worker-client.ts",
+            include_bytes!("assets/ts_runtime_test/expected_worker-client.ts"),
+        ),
+        (
+            "bindings/ts-runtime/worker-runtime.ts",
+            include_bytes!("assets/ts_runtime_test/expected_worker-runtime.ts"),
+        ),
+        (
+            "bindings/ts-runtime/conversions.ts",
+            include_bytes!("assets/ts_runtime_test/expected_conversions.ts"),
+        ),
     ];
 
     fp_bindgen!(BindingConfig {
@@ -367,8 +844,244 @@ fn test_generate_ts_runtime() {
             TsExtendedRuntimeConfig::new()
                 .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
                 .with_raw_export_wrappers()
+                .with_compression()
+                .with_async_imports()
+                .with_std_imports()
+                .with_http_imports()
+                .with_stdio_capture()
+                .with_set_types()
+                .with_signature_verification()
+                .with_payload_tracing()
+                .with_eager_export_resolution()
         ),
         path: "bindings/ts-runtime",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_ts_runtime_with_js_output() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/ts-runtime-js/types.d.ts",
+            include_bytes!("assets/ts_runtime_js_test/expected_types.d.ts"),
+        ),
+        (
+            "bindings/ts-runtime-js/index.d.ts",
+            include_bytes!("assets/ts_runtime_js_test/expected_index.d.ts"),
+        ),
+        (
+            "bindings/ts-runtime-js/types.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_types.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/memory.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_memory.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/imports.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_imports.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/exports.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_exports.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/validation.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_validation.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/index.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_index.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/experimental.d.ts",
+            include_bytes!("assets/ts_runtime_js_test/expected_experimental.d.ts"),
+        ),
+        (
+            "bindings/ts-runtime-js/experimental.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_experimental.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/worker.d.ts",
+            include_bytes!("assets/ts_runtime_js_test/expected_worker.d.ts"),
+        ),
+        (
+            "bindings/ts-runtime-js/worker.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_worker.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/worker-client.d.ts",
+            include_bytes!("assets/ts_runtime_js_test/expected_worker-client.d.ts"),
+        ),
+        (
+            "bindings/ts-runtime-js/worker-client.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_worker-client.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/worker-runtime.d.ts",
+            include_bytes!("assets/ts_runtime_js_test/expected_worker-runtime.d.ts"),
+        ),
+        (
+            "bindings/ts-runtime-js/worker-runtime.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_worker-runtime.js"),
+        ),
+        (
+            "bindings/ts-runtime-js/conversions.js",
+            include_bytes!("assets/ts_runtime_js_test/expected_conversions.js"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::TsRuntimeWithExtendedConfig(
+            TsExtendedRuntimeConfig::new()
+                .with_msgpack_module("https://esm.sh/@msgpack/msgpack@2.7.2")
+                .with_raw_export_wrappers()
+                .with_js_output()
+                .with_string_enums()
+                .with_worker_wrapper()
+                .with_payload_tracing()
+                .with_eager_export_resolution()
+                .with_async_imports()
+                .with_std_imports()
+                .with_http_imports()
+                .with_stdio_capture()
+                .with_set_types()
+                .with_readonly_types()
+                .with_branded_types()
+                .with_int64_encoding(TsInt64Encoding::String)
+        ),
+        path: "bindings/ts-runtime-js",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_ts_runtime_with_dual_modules() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/ts-runtime-dual/package.json",
+            include_bytes!("assets/ts_runtime_dual_test/expected_package.json"),
+        ),
+        (
+            "bindings/ts-runtime-dual/index.cjs",
+            include_bytes!("assets/ts_runtime_dual_test/expected_index.cjs"),
+        ),
+        (
+            "bindings/ts-runtime-dual/index.d.cts",
+            include_bytes!("assets/ts_runtime_dual_test/expected_index.d.cts"),
+        ),
+        (
+            "bindings/ts-runtime-dual/types.cjs",
+            include_bytes!("assets/ts_runtime_dual_test/expected_types.cjs"),
+        ),
+        (
+            "bindings/ts-runtime-dual/types.d.cts",
+            include_bytes!("assets/ts_runtime_dual_test/expected_types.d.cts"),
+        ),
+        (
+            "bindings/ts-runtime-dual/validation.cjs",
+            include_bytes!("assets/ts_runtime_dual_test/expected_validation.cjs"),
+        ),
+        (
+            "bindings/ts-runtime-dual/experimental.cjs",
+            include_bytes!("assets/ts_runtime_dual_test/expected_experimental.cjs"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::TsRuntimeWithExtendedConfig(
+            TsExtendedRuntimeConfig::new()
+                .with_string_enums()
+                .with_worker_wrapper()
+                .with_module_format(TsModuleFormat::Dual)
+        ),
+        path: "bindings/ts-runtime-dual",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_ts_runtime_with_pruned_types() {
+    static FILES: &[(&str, &[u8])] = &[(
+        "bindings/ts-runtime-pruned/types.ts",
+        include_bytes!("assets/ts_runtime_pruned_test/expected_types.ts"),
+    )];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::TsRuntimeWithExtendedConfig(
+            TsExtendedRuntimeConfig::new()
+                .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
+        ),
+        path: "bindings/ts-runtime-pruned",
+        unused_types: UnusedTypes::Prune,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_ts_runtime_with_shared_memory() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/ts-runtime-shared-memory/memory.ts",
+            include_bytes!("assets/ts_runtime_shared_memory_test/expected_memory.ts"),
+        ),
+        (
+            "bindings/ts-runtime-shared-memory/index.ts",
+            include_bytes!("assets/ts_runtime_shared_memory_test/expected_index.ts"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::TsRuntimeWithExtendedConfig(
+            TsExtendedRuntimeConfig::new()
+                .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
+                .with_shared_memory()
+        ),
+        path: "bindings/ts-runtime-shared-memory",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_benches() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/benches/benches.rs",
+            include_bytes!("assets/benches_test/expected_benches.rs"),
+        ),
+        (
+            "bindings/benches/benches.ts",
+            include_bytes!("assets/benches_test/expected_benches.ts"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::Benches(
+            BenchesConfig::new()
+                .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
+        ),
+        path: "bindings/benches",
+        unused_types: UnusedTypes::Keep,
     });
 
     for (path, expected) in FILES {
@@ -376,6 +1089,284 @@ fn test_generate_ts_runtime() {
     }
 }
 
+#[test]
+fn test_generate_fuzz() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/fuzz/Cargo.toml",
+            include_bytes!("assets/fuzz_test/expected_Cargo.toml"),
+        ),
+        (
+            "bindings/fuzz/src/lib.rs",
+            include_bytes!("assets/fuzz_test/expected_lib.rs"),
+        ),
+        (
+            "bindings/fuzz/fuzz_targets/validated_struct.rs",
+            include_bytes!("assets/fuzz_test/expected_validated_struct.rs"),
+        ),
+        (
+            "bindings/fuzz/fuzz.ts",
+            include_bytes!("assets/fuzz_test/expected_fuzz.ts"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::Fuzz(fuzz_config()),
+        path: "bindings/fuzz",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_fixtures() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/fixtures/Cargo.toml",
+            include_bytes!("assets/fixtures_test/expected_Cargo.toml"),
+        ),
+        (
+            "bindings/fixtures/src/lib.rs",
+            include_bytes!("assets/fixtures_test/expected_lib.rs"),
+        ),
+        (
+            "bindings/fixtures/tests/fixtures.rs",
+            include_bytes!("assets/fixtures_test/expected_fixtures.rs"),
+        ),
+        (
+            "bindings/fixtures/fixtures.ts",
+            include_bytes!("assets/fixtures_test/expected_fixtures.ts"),
+        ),
+        (
+            "bindings/fixtures/manifest.json",
+            include_bytes!("assets/fixtures_test/expected_manifest.json"),
+        ),
+    ];
+    static BINARY_FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/fixtures/fixtures/serde_adjacently_tagged.msgpack",
+            include_bytes!("assets/fixtures_test/expected_serde_adjacently_tagged.msgpack"),
+        ),
+        (
+            "bindings/fixtures/fixtures/struct_with_options.msgpack",
+            include_bytes!("assets/fixtures_test/expected_struct_with_options.msgpack"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::Fixtures(fixtures_config()),
+        path: "bindings/fixtures",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+    for (path, expected) in BINARY_FILES {
+        tests::assert_binary_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_cli() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/cli/Cargo.toml",
+            include_bytes!("assets/cli_test/expected_Cargo.toml"),
+        ),
+        (
+            "bindings/cli/src/main.rs",
+            include_bytes!("assets/cli_test/expected_main.rs"),
+        ),
+        (
+            "bindings/cli/src/spec/mod.rs",
+            include_bytes!("assets/cli_test/expected_mod.rs"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::Cli(cli_config()),
+        path: "bindings/cli",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_scaffold() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/scaffold/Cargo.toml",
+            include_bytes!("assets/scaffold_test/expected_Cargo.toml"),
+        ),
+        (
+            "bindings/scaffold/.cargo/config.toml",
+            include_bytes!("assets/scaffold_test/expected_config.toml"),
+        ),
+        (
+            "bindings/scaffold/src/lib.rs",
+            include_bytes!("assets/scaffold_test/expected_lib.rs"),
+        ),
+        (
+            "bindings/scaffold/README.md",
+            include_bytes!("assets/scaffold_test/expected_README.md"),
+        ),
+    ];
+
+    // Existing files of the scaffold are never overwritten:
+    let _ = std::fs::remove_dir_all("bindings/scaffold");
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::Scaffold(scaffold_config()),
+        path: "bindings/scaffold",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_rust_types() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/rust-types/Cargo.toml",
+            include_bytes!("assets/rust_types_test/expected_Cargo.toml"),
+        ),
+        (
+            "bindings/rust-types/src/lib.rs",
+            include_bytes!("assets/rust_types_test/expected_lib.rs"),
+        ),
+        (
+            "bindings/rust-types/src/types.rs",
+            include_bytes!("assets/rust_types_test/expected_types.rs"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::RustTypes(rust_types_config()),
+        path: "bindings/rust-types",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_csharp_runtime() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/csharp-runtime/ExampleBindings.csproj",
+            include_bytes!("assets/csharp_runtime_test/expected_ExampleBindings.csproj"),
+        ),
+        (
+            "bindings/csharp-runtime/Types.cs",
+            include_bytes!("assets/csharp_runtime_test/expected_Types.cs"),
+        ),
+        (
+            "bindings/csharp-runtime/Runtime.cs",
+            include_bytes!("assets/csharp_runtime_test/expected_Runtime.cs"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::CSharpRuntime(
+            CSharpRuntimeConfig::new().with_namespace("ExampleBindings")
+        ),
+        path: "bindings/csharp-runtime",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_assemblyscript_plugin() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/assemblyscript-plugin/support.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_support.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/types.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_types.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/imports.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_imports.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/exports.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_exports.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/index.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_index.ts"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::AssemblyScriptPlugin(AssemblyScriptPluginConfig::new()),
+        path: "bindings/assemblyscript-plugin",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_openapi() {
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::OpenApi(OpenApiConfig::new().with_title("Example API")),
+        path: "bindings/openapi",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    tests::assert_file_eq(
+        "bindings/openapi/openapi.json",
+        include_bytes!("assets/openapi_test/expected_openapi.json"),
+    );
+}
+
+#[test]
+fn test_generate_graphql() {
+    fp_bindgen!(
+        BindingConfig::new(BindingsType::GraphQl, "bindings/graphql")
+            .with_unused_types(UnusedTypes::Keep)
+    );
+
+    tests::assert_file_eq(
+        "bindings/graphql/schema.graphql",
+        include_bytes!("assets/graphql_test/expected_schema.graphql"),
+    );
+}
+
+#[test]
+fn test_generate_docs() {
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::Docs(DocsConfig::new().with_title("Example protocol")),
+        path: "bindings/docs",
+        unused_types: UnusedTypes::Keep,
+    });
+
+    tests::assert_file_eq(
+        "bindings/docs/protocol.md",
+        include_bytes!("assets/docs_test/expected_protocol.md"),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -388,4 +1379,9 @@ mod tests {
         let expected_lines = expected_code.lines().collect::<Vec<_>>();
         pretty_assertions::assert_eq!(actual_lines, expected_lines);
     }
+
+    pub fn assert_binary_file_eq(path_of_actual: impl AsRef<Path>, expected_bytes: &[u8]) {
+        let actual = std::fs::read(path_of_actual).expect("Cannot read `actual` file");
+        pretty_assertions::assert_eq!(actual, expected_bytes);
+    }
 }
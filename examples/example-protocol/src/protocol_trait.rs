@@ -0,0 +1,68 @@
+//! A protocol can be declared as a trait instead of using `fp_import!` and
+//! `fp_export!` blocks. Both of the modules below declare the same protocol.
+
+use crate::types::*;
+use fp_bindgen::prelude::*;
+
+pub type Key = String;
+
+mod with_trait {
+    use super::*;
+
+    /// Attributes on the trait apply to all methods.
+    #[fp_protocol_trait]
+    #[fp(capability = "storage")]
+    trait Store<T> {
+        type Key = String;
+
+        /// Stores a value under the given key.
+        #[import]
+        fn import_set_value(key: Key, value: T);
+
+        /// Retrieves the value stored under the given key.
+        #[import]
+        async fn import_get_value(key: Key) -> Option<T>;
+
+        /// Called when a value has changed.
+        #[export]
+        #[fp(capability = "events")]
+        fn export_value_changed(key: Key, point: Point<f64>);
+    }
+
+    pub(super) fn protocol() -> Protocol {
+        fp_protocol!()
+    }
+}
+
+mod with_blocks {
+    use super::*;
+
+    fp_import! {
+        type Key = String;
+
+        /// Stores a value under the given key.
+        #[fp(capability = "storage")]
+        fn import_set_value<T>(key: Key, value: T);
+
+        /// Retrieves the value stored under the given key.
+        #[fp(capability = "storage")]
+        async fn import_get_value<T>(key: Key) -> Option<T>;
+    }
+
+    fp_export! {
+        /// Called when a value has changed.
+        #[fp(capability = "events")]
+        fn export_value_changed(key: Key, point: Point<f64>);
+    }
+
+    pub(super) fn protocol() -> Protocol {
+        fp_protocol!()
+    }
+}
+
+#[test]
+fn test_protocol_trait() {
+    let with_trait = serde_json::to_value(with_trait::protocol()).unwrap();
+    let with_blocks = serde_json::to_value(with_blocks::protocol()).unwrap();
+    assert_eq!(with_trait, with_blocks);
+}
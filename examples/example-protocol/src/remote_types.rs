@@ -0,0 +1,105 @@
+//! Types from other crates can be used in a protocol by declaring their
+//! fields with `serializable_remote!`. Both of the modules below declare the
+//! same protocol, but only the second one can modify the types it uses.
+
+use fp_bindgen::prelude::*;
+
+/// Stands in for a crate that we cannot modify.
+pub mod third_party {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct GeoPoint {
+        pub latitude: f64,
+        pub longitude: f64,
+        pub altitude_meters: Option<f64>,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    pub enum Shape {
+        Circle { center: GeoPoint, radius: f64 },
+        Polygon { points: Vec<GeoPoint> },
+    }
+}
+
+mod with_remote {
+    use super::*;
+
+    serializable_remote! {
+        /// A point on the globe.
+        #[fp(remote = "third_party::GeoPoint")]
+        #[serde(rename_all = "camelCase")]
+        pub struct GeoPoint {
+            pub latitude: f64,
+            pub longitude: f64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub altitude_meters: Option<f64>,
+        }
+    }
+
+    serializable_remote! {
+        #[fp(remote = "third_party::Shape")]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        pub enum Shape {
+            Circle { center: GeoPoint, radius: f64 },
+            Polygon { points: Vec<GeoPoint> },
+        }
+    }
+
+    fp_import! {
+        fn import_shape_center(shape: Shape) -> GeoPoint;
+    }
+
+    fp_export! {
+        fn export_shapes_near(point: GeoPoint) -> Vec<Shape>;
+    }
+
+    pub(super) fn protocol() -> Protocol {
+        fp_protocol!()
+    }
+}
+
+mod with_rust_module {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    /// A point on the globe.
+    #[derive(Deserialize, Serialize, Serializable)]
+    #[fp(rust_module = "third_party")]
+    #[serde(rename_all = "camelCase")]
+    pub struct GeoPoint {
+        pub latitude: f64,
+        pub longitude: f64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub altitude_meters: Option<f64>,
+    }
+
+    #[derive(Deserialize, Serialize, Serializable)]
+    #[fp(rust_module = "third_party")]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    pub enum Shape {
+        Circle { center: GeoPoint, radius: f64 },
+        Polygon { points: Vec<GeoPoint> },
+    }
+
+    fp_import! {
+        fn import_shape_center(shape: Shape) -> GeoPoint;
+    }
+
+    fp_export! {
+        fn export_shapes_near(point: GeoPoint) -> Vec<Shape>;
+    }
+
+    pub(super) fn protocol() -> Protocol {
+        fp_protocol!()
+    }
+}
+
+#[test]
+fn test_serializable_remote() {
+    let with_remote = serde_json::to_value(with_remote::protocol()).unwrap();
+    let with_rust_module = serde_json::to_value(with_rust_module::protocol()).unwrap();
+    assert_eq!(with_remote, with_rust_module);
+}
@@ -0,0 +1,32 @@
+use fp_bindgen::prelude::Serializable;
+use serde::{Deserialize, Serialize};
+
+/// Fields and variants behind a `#[cfg(...)]` attribute are only included in
+/// the bindings if the protocol is compiled with the condition enabled.
+///
+/// Using `#[fp(generators = "...")]`, fields and variants can also be limited
+/// to the bindings of specific generators.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Serializable)]
+pub struct ConditionalFields {
+    pub name: String,
+    #[cfg(feature = "experimental")]
+    pub experimental: ExperimentalStruct,
+    /// Only hosts and plugins that are written in Rust know about this field,
+    /// so it needs to be optional.
+    #[fp(generators = "rust-plugin, rust-wasmer-runtime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backtrace: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Serializable)]
+pub enum ConditionalVariants {
+    Stable,
+    #[cfg(feature = "experimental")]
+    Experimental(ExperimentalStruct),
+}
+
+#[cfg(feature = "experimental")]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Serializable)]
+pub struct ExperimentalStruct {
+    pub value: u64,
+}
@@ -0,0 +1,21 @@
+use fp_bindgen::prelude::Serializable;
+use time::OffsetDateTime;
+
+// Fields can use a custom (de)serializer through the same `with`,
+// `serialize_with` and `deserialize_with` annotations as those supported by
+// Serde. The annotations are preserved on the generated Rust types.
+//
+// Because the serialized representation of such fields cannot be derived from
+// their Rust type, an explicit TypeScript type must be provided through the
+// `ts_type` annotation. Generating the TypeScript bindings fails if it is
+// missing.
+//
+// For more information, see: https://serde.rs/field-attrs.html#with
+
+#[derive(Serializable)]
+pub struct CustomSerializers {
+    /// Serialized as a Unix timestamp, instead of the RFC3339 string that is
+    /// used for `OffsetDateTime` by default.
+    #[fp(with = "time::serde::timestamp", ts_type = "number")]
+    pub timestamp: OffsetDateTime,
+}
@@ -0,0 +1,15 @@
+use fp_bindgen::prelude::Serializable;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Settings for an extension whose shape is only known to the extension
+/// itself.
+///
+/// `serde_json::Value` (and `rmpv::Value`) can be used anywhere in a protocol
+/// to pass values of arbitrary shape. Both are encoded as plain MessagePack,
+/// and show up as `unknown` in the TypeScript bindings.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Serializable)]
+pub struct ExtensionSettings {
+    pub extension: String,
+    pub settings: Value,
+}
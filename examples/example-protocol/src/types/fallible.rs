@@ -0,0 +1,18 @@
+use fp_bindgen::prelude::Serializable;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of a single operation inside a batch.
+///
+/// `Result` can be used anywhere in a protocol, not just as a return type.
+/// It is serialized the same way Serde serializes it in Rust, which shows up
+/// as `{ Ok: T } | { Err: E }` in the TypeScript bindings.
+pub type OperationResult = Result<u64, String>;
+
+/// The response to a batch of operations, some of which may have failed.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Serializable)]
+#[fp(rename_all = "camelCase")]
+pub struct BatchResponse {
+    pub results: Vec<OperationResult>,
+    pub summary: Result<String, String>,
+    pub retry_after: Option<Result<u32, String>>,
+}
@@ -1,6 +1,7 @@
 use super::MyDateTime;
 use fp_bindgen::prelude::Serializable;
 use std::collections::BTreeMap;
+use std::marker::PhantomData;
 
 // Generic arguments can be used, both on `std` types that take generic
 // arguments as well as custom types with a `Serializable` implementation.
@@ -19,6 +20,17 @@ pub struct ExplicitBoundPoint<T: Serializable + std::fmt::Debug + std::fmt::Disp
     pub value: T,
 }
 
+/// An identifier of a resource of type `T`.
+///
+/// `PhantomData` fields are not serialized, so they only show up in the Rust
+/// bindings, where they keep the type parameter in use. Other bindings only
+/// preserve the type parameter in the signature of the type.
+#[derive(Serializable)]
+pub struct TypedId<T> {
+    pub id: u64,
+    pub resource: PhantomData<T>,
+}
+
 #[derive(Serializable)]
 pub struct StructWithGenerics<T> {
     pub list: Vec<T>,
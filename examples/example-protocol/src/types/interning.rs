@@ -0,0 +1,14 @@
+use fp_bindgen::prelude::Serializable;
+
+/// A measurement that is reported by the plugin.
+///
+/// Because the `name` field is annotated with `#[fp(interned)]`, the plugin
+/// registers every distinct name with the runtime once, and sends a `u32`
+/// handle in its place from then on. This keeps the payloads small when the
+/// same few names are reported over and over.
+#[derive(Serializable)]
+pub struct Metric {
+    #[fp(interned)]
+    pub name: String,
+    pub value: f64,
+}
@@ -0,0 +1,12 @@
+use fp_bindgen::prelude::Serializable;
+use serde::{Deserialize, Serialize};
+
+/// Config that is passed to the `init` export when the plugin is instantiated.
+///
+/// Every protocol has `init` and `shutdown` exports, but protocols can declare
+/// `init` themselves to have it take a config argument like this one.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize, Serializable)]
+#[fp(rename_all = "camelCase")]
+pub struct PluginConfig {
+    pub log_level: String,
+}
@@ -1,6 +1,27 @@
 mod aliases;
 pub use aliases::*;
 
+mod collections;
+pub use collections::*;
+
+mod columnar;
+pub use columnar::*;
+
+mod conditional;
+pub use conditional::*;
+
+mod custom_serializers;
+pub use custom_serializers::*;
+
+mod dynamic_values;
+pub use dynamic_values::*;
+
+mod events;
+pub use events::*;
+
+mod fallible;
+pub use fallible::*;
+
 mod flattening;
 pub use flattening::*;
 
@@ -13,12 +34,24 @@ pub use self::http::*;
 mod inline_docs;
 pub use inline_docs::*;
 
+mod interning;
+pub use interning::*;
+
+mod lifecycle;
+pub use lifecycle::*;
+
+mod numbers;
+pub use numbers::*;
+
 mod options;
 pub use options::*;
 
 mod renaming;
 pub use renaming::*;
 
+mod string_enums;
+pub use string_enums::*;
+
 mod tagged_enums;
 pub use tagged_enums::*;
 
@@ -27,3 +60,6 @@ pub use self::time::*;
 
 mod use_statements;
 pub use use_statements::*;
+
+mod validation;
+pub use validation::*;
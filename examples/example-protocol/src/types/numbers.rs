@@ -0,0 +1,51 @@
+use fp_bindgen::prelude::Serializable;
+use num_bigint::BigInt;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::num::{NonZeroI64, NonZeroU32, NonZeroUsize};
+
+/// Example of integer types that are not primitives in the protocol.
+///
+/// `usize` and `isize` are exchanged as `u32` and `i32`, because that is their
+/// size on `wasm32`. `NonZero` types refuse to deserialize when they are zero.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, Serializable)]
+pub struct IntegerTypes {
+    pub count: usize,
+    pub offset: isize,
+    pub id: NonZeroU32,
+    pub delta: NonZeroI64,
+    pub capacity: Option<NonZeroUsize>,
+    pub user_id: UserId,
+}
+
+/// Example of a newtype that can be generated as a branded type in TypeScript.
+///
+/// With `generate_branded_types`, it is typed as
+/// `number & { readonly __brand: "UserId" }`, so that it cannot be mixed up
+/// with other numbers.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, Serializable)]
+#[fp(brand)]
+pub struct UserId(pub u32);
+
+/// Example of numbers that cannot be represented as `f64` without losing
+/// precision.
+///
+/// They are exchanged as strings, and typed as branded strings in TypeScript.
+/// Requires the `rust-decimal-compat` and `num-bigint-compat` features.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, Serializable)]
+pub struct ArbitraryPrecisionNumbers {
+    pub amount: Decimal,
+    pub total_supply: BigInt,
+}
+
+/// Example of values at the edges of what the runtimes can represent.
+///
+/// `char` is exchanged as a single-character string, 128-bit integers are
+/// exchanged as decimal strings, and `f32` keeps NaN and infinities intact.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Serializable)]
+pub struct EdgeValues {
+    pub character: char,
+    pub float: f32,
+    pub signed: i128,
+    pub unsigned: u128,
+}
@@ -16,12 +16,19 @@ use serde::{Deserialize, Serialize};
 // - https://serde.rs/field-attrs.html#rename
 // - https://serde.rs/variant-attrs.html#rename
 // - https://serde.rs/variant-attrs.html#rename_all
+//
+// Properties and variants that were renamed since a previous version of the
+// protocol can list their previous names using `#[fp(renamed_from = "...")]`.
+// Serde doesn't know this annotation, but the Rust Wasmer runtime uses it to
+// generate migrations for payloads that still use the previous names.
 
 // This struct renames its properties using the `fp` attribute namespace.
 #[derive(Serializable)]
 #[fp(rename_all = "camelCase")]
 pub struct FpPropertyRenaming {
     // Will be renamed to "fooBar" because of the `rename_all` on the struct.
+    // Previous versions of the protocol called it "foo".
+    #[fp(renamed_from = "foo")]
     pub foo_bar: String,
 
     // Custom property name.
@@ -39,7 +46,12 @@ pub enum FpVariantRenaming {
     // Will be renamed to "foo_bar" because of the `rename_all` on the enum.
     FooBar,
     // Custom variant name, while its properties will be renamed also.
-    #[fp(rename = "QUX_BAZ", rename_all = "SCREAMING_SNAKE_CASE")]
+    // Previous versions of the protocol called it "qux".
+    #[fp(
+        rename = "QUX_BAZ",
+        rename_all = "SCREAMING_SNAKE_CASE",
+        renamed_from = "qux"
+    )]
     QuxBaz {
         /// Will be renamed to "FOO_BAR" because of the `rename_all` on the
         /// variant.
@@ -0,0 +1,16 @@
+use fp_bindgen::prelude::Serializable;
+
+// Enums of which all variants are unit variants can be generated as TypeScript
+// string enums, instead of a union of string literals, by using
+// `TsExtendedRuntimeConfig::with_string_enums()`. The values of the enum
+// members are the names under which the variants are serialized.
+
+#[derive(Serializable)]
+#[fp(rename_all = "snake_case")]
+pub enum Priority {
+    /// Handled after everything else.
+    Low,
+    Normal,
+    #[fp(rename = "urgent")]
+    High,
+}
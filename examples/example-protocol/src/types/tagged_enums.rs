@@ -16,8 +16,13 @@ use serde::{Deserialize, Serialize};
 //
 // For more information, see: https://serde.rs/enum-representations.html
 
+// Enums that are marked as non-exhaustive get an `Unknown` variant in the
+// generated Rust bindings, which holds the serialized value of any variant
+// that is added later. This allows older plugins and runtimes to pass on
+// such variants, rather than failing to deserialize them.
 #[derive(Serializable)]
 #[fp(tag = "type")]
+#[non_exhaustive]
 pub enum FpInternallyTagged {
     Foo,
     // Internally tagged enums cannot have unnamed fields!
@@ -29,8 +34,22 @@ pub enum FpInternallyTagged {
 #[fp(tag = "type", content = "payload")]
 pub enum FpAdjacentlyTagged {
     Foo,
+    // Aliases allow deserializing variants that were previously known under a
+    // different name.
+    #[fp(alias = "Qux")]
     Bar(String),
-    Baz { a: i8, b: u64 },
+    Baz {
+        a: i8,
+        b: u64,
+    },
+    // Variants with multiple unnamed fields contain a tuple, which is encoded
+    // as an array.
+    Pair(i8, String),
+    // Tagged enums may have a single unit variant marked as `other`, which is
+    // used for all tags that aren't recognized. This allows older plugins and
+    // runtimes to deserialize variants that have been added later.
+    #[fp(other)]
+    Unknown,
 }
 
 #[derive(Serializable)]
@@ -40,6 +59,7 @@ pub enum FpUntagged {
     //Foo, // NOT SUPPORTED!
     Bar(String),
     Baz { a: i8, b: u64 },
+    Pair(i8, String),
 }
 
 #[derive(Serializable, Serialize, Deserialize)]
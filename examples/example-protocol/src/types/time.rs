@@ -22,3 +22,17 @@ use time::OffsetDateTime;
 /// trouble embedding them into certain generic types.
 #[derive(Serializable)]
 pub struct MyDateTime(OffsetDateTime);
+
+/// Timestamps that the TypeScript runtime represents as `Date` objects.
+///
+/// Fields that are annotated with `ts_as = "Date"` are still serialized as
+/// RFC3339 strings, but the TypeScript runtime converts them into `Date`
+/// objects when they are received, and back into strings when they are sent.
+#[derive(Serializable)]
+#[fp(rename_all = "camelCase")]
+pub struct Timestamps {
+    #[fp(ts_as = "Date")]
+    pub created_at: OffsetDateTime,
+    #[fp(ts_as = "Date")]
+    pub deleted_at: Option<OffsetDateTime>,
+}
@@ -0,0 +1,28 @@
+use fp_bindgen::prelude::Serializable;
+use serde::{Deserialize, Serialize};
+
+/// Fields with `#[fp(validate(...))]` attributes are validated when they are
+/// received from the other side, so invalid values are rejected right away.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Serializable)]
+#[fp(rename_all = "camelCase")]
+pub struct ValidatedStruct {
+    #[fp(validate(range = "0..=100"))]
+    pub percentage: u8,
+    #[fp(validate(non_empty, regex = "^[a-z][a-z0-9-]*$"))]
+    pub slug: String,
+    #[fp(validate(range = "0..1"))]
+    pub ratio: Option<f64>,
+    #[fp(validate(non_empty))]
+    pub children: Vec<ValidatedChild>,
+}
+
+/// Structs that contain validated structs get validated as a whole.
+///
+/// Fields that are not part of this struct are rejected, rather than silently
+/// ignored.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Serializable)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatedChild {
+    #[fp(validate(non_empty))]
+    pub name: String,
+}
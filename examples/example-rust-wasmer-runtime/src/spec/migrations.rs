@@ -0,0 +1 @@
+../../../example-protocol/bindings/rust-wasmer-runtime/migrations.rs
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod bindings;
+pub mod migrations;
 pub mod types;
 
 use bytes::Bytes;
@@ -48,6 +49,14 @@ fn import_primitive_u64(arg: u64) -> u64 {
     todo!()
 }
 
+fn import_primitive_pair(arg: u32) -> (u32, i32) {
+    todo!()
+}
+
+fn import_multiple_values(arg: u32) -> (String, Vec<u32>, bool) {
+    todo!()
+}
+
 fn import_array_u8(arg: [u8; 3]) -> [u8; 3] {
     todo!()
 }
@@ -77,6 +86,16 @@ fn import_string(arg: String) -> String {
     todo!()
 }
 
+#[cfg(feature = "experimental")]
+fn import_experimental(arg: String) -> String {
+    todo!()
+}
+
+#[cfg(feature = "experimental")]
+fn import_experimental(arg: String) -> String {
+    todo!()
+}
+
 fn import_multiple_primitives(arg1: i8, arg2: String) -> i64 {
     todo!()
 }
@@ -135,14 +154,54 @@ fn import_serde_untagged(arg: SerdeUntagged) -> SerdeUntagged {
     todo!()
 }
 
-fn import_struct_with_options(arg: StructWithOptions) {
+fn import_struct_with_options(arg: StructWithOptions) -> StructWithOptions {
+    todo!()
+}
+
+fn import_integer_types(arg: IntegerTypes) -> IntegerTypes {
     todo!()
 }
 
+fn import_arbitrary_precision_numbers(arg: ArbitraryPrecisionNumbers) -> ArbitraryPrecisionNumbers {
+    todo!()
+}
+
+fn import_collections(arg: Collections) -> Collections {
+    arg
+}
+
+fn import_validated_struct(arg: ValidatedStruct) -> ValidatedStruct {
+    todo!()
+}
+
+fn invoke_command(name: String, payload: String) -> Result<String, String> {
+    Err(format!("Unknown command: {}", name))
+}
+
 fn log(msg: String) {
     println!("Provider log: {}", msg);
 }
 
+fn record_metric(name: String, value: f64) {
+    println!("Provider metric: {} = {}", name, value);
+}
+
+async fn import_async_void_function(message: String) {
+    assert_eq!(message, "Hello, runtime!");
+}
+
+// The runtime picks a type that can hold whatever the plugin passes to the
+// generic imports:
+fn import_set_value(key: String, value: serde_json::Value) {
+    assert_eq!(key, "tabSize");
+    assert_eq!(value, serde_json::json!(4));
+}
+
+async fn import_get_value(key: String) -> Option<serde_json::Value> {
+    assert_eq!(key, "tabSize");
+    Some(serde_json::json!(4))
+}
+
 async fn make_http_request(opts: Request) -> Result<Response, RequestError> {
     Ok(Response {
         body: ByteBuf::from(r#"status: "confirmed"#.to_string()),
@@ -150,3 +209,11 @@ async fn make_http_request(opts: Request) -> Result<Response, RequestError> {
         status_code: 200,
     })
 }
+
+fn import_rate_limited(label: String) -> Result<String, RateLimited> {
+    Ok(label)
+}
+
+fn import_renamed_string(arg: String) -> String {
+    format!("{arg} (renamed)")
+}
@@ -3,13 +3,23 @@ use crate::spec::types::*;
 #[cfg(feature="wasi")]
 use crate::wasi_spec::types::*;
 #[cfg(not(feature="wasi"))]
-use crate::spec::bindings::Runtime;
+use crate::spec::migrations::*;
 #[cfg(feature="wasi")]
-use crate::wasi_spec::bindings::Runtime;
+use crate::wasi_spec::migrations::*;
+#[cfg(not(feature="wasi"))]
+use crate::spec::bindings::{Capability, PluginError, PluginExport, Runtime, RuntimeBuilder};
+#[cfg(feature="wasi")]
+use crate::wasi_spec::bindings::{Capability, PluginError, PluginExport, Runtime, RuntimeBuilder};
 use anyhow::Result;
 use bytes::Bytes;
+use fp_bindgen_support::{
+    common::wasm_features::{WasmFeature, WasmFeatures},
+    host::errors::{InvocationError, RuntimeError},
+};
 use serde_bytes::ByteBuf;
-use std::collections::BTreeMap;
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::marker::PhantomData;
 use time::{macros::datetime, OffsetDateTime};
 
 #[cfg(not(feature="wasi"))]
@@ -35,6 +45,13 @@ fn primitives() -> Result<()> {
     assert_eq!(rt.export_primitive_i32(-32)?, -32);
     assert_eq!(rt.export_primitive_i64(-64)?, -64);
 
+    assert_eq!(rt.export_primitive_pair(32)?, (32, -32));
+
+    let (name, values, flag) = rt.export_multiple_values(3)?;
+    assert_eq!(name, "three");
+    assert_eq!(values, vec![1, 2, 3]);
+    assert!(flag);
+
     assert_eq!(
         rt.export_multiple_primitives(-8, "Hello, 🇳🇱!".to_string())?,
         -64
@@ -71,6 +88,72 @@ fn string() -> Result<()> {
         "Hello, world!"
     );
 
+    let lazy = rt.export_lazy_string("Hello, plugin!".to_string())?;
+    assert_eq!(lazy.raw_bytes(), b"\xadHello, world!");
+    assert_eq!(lazy.get()?, "Hello, world!");
+
+    assert_eq!(
+        rt.export_renamed_string("Hello".to_string())?,
+        "Hello (renamed)"
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature="wasi"))]
+#[test]
+fn import_overrides() -> Result<()> {
+    let rt = RuntimeBuilder::new()
+        .with_init(plugin_config())
+        .override_import_renamed_string(|arg| format!("{arg} (overridden)"))
+        .build(WASM_BYTES)?;
+    assert_eq!(
+        rt.export_renamed_string("Hello".to_string())?,
+        "Hello (overridden)"
+    );
+
+    // Imports that are not overridden are handled by the host:
+    assert_eq!(
+        rt.export_call_rate_limited_import(1)?,
+        vec![Ok("call 0".to_owned())]
+    );
+
+    // Overrides only apply to the runtime they were passed to:
+    assert_eq!(
+        new_runtime()?.export_renamed_string("Hello".to_string())?,
+        "Hello (renamed)"
+    );
+    Ok(())
+}
+
+#[test]
+fn cached_export() -> Result<()> {
+    let rt = new_runtime()?;
+    assert_eq!(rt.export_cached_call_count("a".to_string())?, "a: 1");
+    assert_eq!(rt.export_cached_call_count("a".to_string())?, "a: 1");
+    assert_eq!(rt.export_cached_call_count("b".to_string())?, "b: 2");
+
+    // Clones share the cache of the runtime:
+    assert_eq!(rt.clone().export_cached_call_count("b".to_string())?, "b: 2");
+
+    rt.clear_export_cache();
+    assert_eq!(rt.export_cached_call_count("a".to_string())?, "a: 3");
+    Ok(())
+}
+
+#[test]
+fn rate_limited_import() -> Result<()> {
+    let rt = new_runtime()?;
+    let results = rt.export_call_rate_limited_import(3)?;
+    assert_eq!(results[0], Ok("call 0".to_owned()));
+    assert_eq!(results[1], Ok("call 1".to_owned()));
+    match &results[2] {
+        Err(error) => {
+            assert_eq!(error.function, "import_rate_limited");
+            assert!(error.retry_after_ms > 0);
+        }
+        Ok(_) => panic!("Expected the third call to be rate-limited"),
+    }
     Ok(())
 }
 
@@ -81,6 +164,56 @@ fn timestamp() -> Result<()> {
         rt.export_timestamp(MyDateTime(datetime!(2022-04-12 19:10 UTC)))?,
         MyDateTime(datetime!(2022-04-13 12:37 UTC))
     );
+    assert_eq!(
+        rt.export_timestamps(Timestamps {
+            created_at: datetime!(2022-04-12 19:10 UTC),
+            deleted_at: None,
+        })?,
+        Timestamps {
+            created_at: datetime!(2022-04-12 19:10 UTC),
+            deleted_at: Some(datetime!(2022-04-13 12:37 UTC)),
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn edge_values() -> Result<()> {
+    let rt = new_runtime()?;
+
+    let cases = [
+        ('\0', f32::MIN_POSITIVE, i128::MIN, 0),
+        ('\u{10FFFF}', f32::MAX, i128::MAX, u128::MAX),
+        ('🇳', f32::INFINITY, -1, 1),
+        ('é', f32::NEG_INFINITY, 0, u128::from(u64::MAX) + 1),
+    ];
+    for (character, float, signed, unsigned) in cases {
+        let value = EdgeValues {
+            character,
+            float,
+            signed,
+            unsigned,
+        };
+        assert_eq!(rt.export_edge_values(value.clone())?, value);
+    }
+
+    // NaN never equals itself, so it is compared separately:
+    let value = rt.export_edge_values(EdgeValues {
+        character: 'a',
+        float: f32::NAN,
+        signed: 0,
+        unsigned: 0,
+    })?;
+    assert!(value.float.is_nan());
+
+    let value = rt.export_edge_values(EdgeValues {
+        character: 'a',
+        float: -0.0,
+        signed: 0,
+        unsigned: 0,
+    })?;
+    assert!(value.float.is_sign_negative());
+
     Ok(())
 }
 
@@ -162,6 +295,17 @@ fn generics() -> Result<()> {
             optional_timestamp: Some(MyDateTime(OffsetDateTime::UNIX_EPOCH)),
         }
     );
+
+    assert_eq!(
+        rt.export_typed_id(TypedId {
+            id: 64,
+            resource: PhantomData,
+        })?,
+        TypedId {
+            id: 65,
+            resource: PhantomData,
+        }
+    );
     Ok(())
 }
 
@@ -202,6 +346,38 @@ fn property_renaming() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn migrations() -> Result<()> {
+    // Payloads of plugins that were built against a previous version of the
+    // protocol still use the previous names of renamed properties and variants:
+    let old_struct = rmp_serde::to_vec_named(&json!({
+        "foo": "Hello, 🇳🇱!",
+        "QUX_BAZ": -64.0,
+        "rawStruct": 32,
+    }))?;
+    assert_eq!(
+        migrate_fp_property_renaming(&old_struct)?,
+        FpPropertyRenaming {
+            foo_bar: "Hello, 🇳🇱!".to_owned(),
+            qux_baz: -64.0,
+            raw_struct: 32,
+        }
+    );
+
+    let old_enum = rmp_serde::to_vec_named(&json!({
+        "qux": { "FOO_BAR": "Hello, 🇳🇱!", "qux_baz": -64.0 },
+    }))?;
+    assert_eq!(
+        migrate_fp_variant_renaming(&old_enum)?,
+        FpVariantRenaming::QuxBaz {
+            foo_bar: "Hello, 🇳🇱!".to_owned(),
+            qux_baz: -64.0,
+        }
+    );
+
+    Ok(())
+}
+
 #[test]
 fn tagged_enums() -> Result<()> {
     let rt = new_runtime()?;
@@ -232,6 +408,96 @@ fn tagged_enums() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn dynamic_values() -> Result<()> {
+    let rt = new_runtime()?;
+
+    assert_eq!(
+        rt.export_dynamic_value(ExtensionSettings {
+            extension: "markdown".to_owned(),
+            settings: json!({ "tables": true, "tabSize": 4 }),
+        })?,
+        json!({ "enabled": true, "features": ["tables"] })
+    );
+    Ok(())
+}
+
+#[test]
+fn collections() -> Result<()> {
+    let rt = new_runtime()?;
+
+    let collections = Collections {
+        tags: BTreeSet::from(["one".to_owned(), "two".to_owned()]),
+        optional_tags: None,
+        queue: VecDeque::from([3, 1, 2]),
+    };
+    assert_eq!(rt.export_collections(collections.clone())?, collections);
+    Ok(())
+}
+
+#[test]
+fn columnar_samples() -> Result<()> {
+    let rt = new_runtime()?;
+
+    let samples = SampleColumns {
+        timestamp_ms: vec![1000, 2000],
+        value: vec![1.5, -2.0],
+        quality: vec![1, 0],
+    };
+    assert_eq!(
+        rt.export_samples(samples)?,
+        SampleColumns {
+            timestamp_ms: vec![2000, 3000],
+            value: vec![3.0, -4.0],
+            quality: vec![1, 0],
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn interned_metrics() -> Result<()> {
+    let rt = new_runtime()?;
+
+    let metric = |name: &str, value| Metric {
+        name: name.to_owned(),
+        value,
+    };
+    let metrics = vec![
+        metric("cpu.usage", 0.5),
+        metric("mem.usage", 1.5),
+        metric("cpu.usage", 0.25),
+    ];
+    let expected = vec![
+        metric("cpu.usage", 1.0),
+        metric("mem.usage", 3.0),
+        metric("cpu.usage", 0.5),
+    ];
+    assert_eq!(rt.export_metrics(metrics.clone())?, expected);
+    // The second call reuses the handles that were registered by the first:
+    assert_eq!(rt.export_metrics(metrics)?, expected);
+    Ok(())
+}
+
+#[test]
+fn results_inside_types() -> Result<()> {
+    let rt = new_runtime()?;
+
+    assert_eq!(
+        rt.export_batch_response(BatchResponse {
+            results: vec![Ok(1), Err("timeout".to_owned()), Ok(3)],
+            summary: Ok("pending".to_owned()),
+            retry_after: None,
+        })?,
+        BatchResponse {
+            results: vec![Ok(1), Err("timeout".to_owned()), Ok(3)],
+            summary: Err("1 operation(s) failed".to_owned()),
+            retry_after: Some(Ok(30)),
+        }
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn async_struct() -> Result<()> {
     let rt = new_runtime()?;
@@ -265,6 +531,56 @@ async fn fetch_async_data() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn fetch_async_data_without_network_capability() -> Result<()> {
+    let rt = RuntimeBuilder::new()
+        .with_init(plugin_config())
+        .build(WASM_BYTES)?;
+
+    let error = rt.fetch_data("sign-up".to_string()).await.unwrap_err();
+
+    assert_eq!(error.export(), PluginExport::FetchData);
+    match error {
+        PluginError::Transport {
+            error: InvocationError::PermissionDenied(error),
+            ..
+        } => {
+            assert_eq!(error.function, "make_http_request");
+            assert_eq!(error.capability, "network");
+        }
+        error => panic!("Unexpected error: {error}"),
+    }
+    Ok(())
+}
+
+#[cfg(not(feature="wasi"))]
+#[tokio::test]
+async fn async_concurrency_limit() -> Result<()> {
+    let rt = new_runtime()?.with_async_concurrency_limit(1, 1);
+
+    // The first call runs, the second one is queued and the third one is
+    // rejected, since the queue is full:
+    let (first, second, third) = tokio::join!(
+        rt.fetch_data("sign-up".to_string()),
+        rt.fetch_data("sign-up".to_string()),
+        rt.fetch_data("sign-up".to_string()),
+    );
+
+    assert_eq!(first?, Ok(r#"status: "confirmed"#.to_string()));
+    assert_eq!(second?, Ok(r#"status: "confirmed"#.to_string()));
+    match third.unwrap_err() {
+        PluginError::Transport {
+            error: InvocationError::Overloaded(error),
+            ..
+        } => {
+            assert_eq!(error.max_concurrent, 1);
+            assert_eq!(error.max_queued, 1);
+        }
+        error => panic!("Unexpected error: {error}"),
+    }
+    Ok(())
+}
+
 #[test]
 fn bytes() -> Result<()> {
     let rt = new_runtime()?;
@@ -275,8 +591,192 @@ fn bytes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn memory_stats() -> Result<()> {
+    let rt = new_runtime()?;
+
+    let before = rt.memory_stats()?;
+    rt.export_string("Hello, plugin!".to_string())?;
+    let after = rt.memory_stats()?;
+
+    assert!(after.allocation_count > before.allocation_count);
+    assert!(after.peak_allocated_bytes >= after.allocated_bytes);
+
+    Ok(())
+}
+
+#[cfg(not(feature="wasi"))]
+#[test]
+fn module_cache() -> Result<()> {
+    use fp_bindgen_support::host::cache::ModuleCache;
+
+    let dir = std::env::temp_dir().join(format!("fp-bindgen-module-cache-{}", std::process::id()));
+    let cache = ModuleCache::new(&dir)?;
+
+    // The first runtime compiles the module, the second one loads it from the cache:
+    for _ in 0..2 {
+        let rt = Runtime::with_module_cache(WASM_BYTES, &cache)?;
+        assert_eq!(rt.export_primitive_u32(32)?, 32);
+    }
+    assert_eq!(std::fs::read_dir(&dir)?.count(), 1);
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[cfg(not(feature="wasi"))]
+#[test]
+fn signature_verification() -> Result<()> {
+    use fp_bindgen_support::common::signatures::{sign_module, SignatureVerifier, SigningKey};
+
+    let signing_key = SigningKey::from_bytes(&[7; 32]);
+    let verifier = SignatureVerifier::PublicKey(signing_key.verifying_key());
+    let signed_module = sign_module(WASM_BYTES, &signing_key)?;
+
+    let rt = Runtime::with_signature_verifier(&signed_module, &verifier)?;
+    assert_eq!(rt.export_primitive_u32(32)?, 32);
+
+    let rt = RuntimeBuilder::new()
+        .with_signature_verifier(verifier.clone())
+        .build(&signed_module)?;
+    assert_eq!(rt.export_primitive_u32(32)?, 32);
+
+    assert!(matches!(
+        Runtime::with_signature_verifier(WASM_BYTES, &verifier),
+        Err(RuntimeError::InvalidSignature(_))
+    ));
+
+    let other_key = SigningKey::from_bytes(&[8; 32]);
+    assert!(matches!(
+        RuntimeBuilder::new()
+            .with_signature_verifier(verifier)
+            .build(sign_module(WASM_BYTES, &other_key)?),
+        Err(RuntimeError::InvalidSignature(_))
+    ));
+    Ok(())
+}
+
+#[cfg(not(feature="wasi"))]
+#[test]
+fn shared_runtime() -> Result<()> {
+    use crate::spec::bindings::SharedRuntime;
+
+    let rt = SharedRuntime::new(WASM_BYTES, 2)?;
+
+    // More threads than instances, so that some of them have to wait:
+    let threads: Vec<_> = (0..4u32)
+        .map(|i| {
+            let rt = rt.clone();
+            std::thread::spawn(move || -> Result<()> {
+                for j in 0..10 {
+                    assert_eq!(rt.export_primitive_u32(i * 100 + j)?, i * 100 + j);
+                }
+                Ok(())
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap()?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature="wasi"))]
+#[tokio::test]
+async fn shared_runtime_async() -> Result<()> {
+    use crate::spec::bindings::SharedRuntime;
+
+    let rt = SharedRuntime::new(WASM_BYTES, 1)?;
+    let call = || {
+        rt.export_async_struct(
+            FpPropertyRenaming {
+                foo_bar: "foo_bar".to_owned(),
+                qux_baz: 64.0,
+                raw_struct: -32,
+            },
+            64,
+        )
+    };
+
+    // More calls than instances on a single thread, so that the calls that
+    // wait for the instance must not block the one that holds it:
+    let (first, second, third) = tokio::join!(call(), call(), call());
+    for result in [first, second, third] {
+        assert_eq!(result?.foo_bar, "fooBar");
+    }
+    Ok(())
+}
+
+#[cfg(not(feature="wasi"))]
+#[test]
+fn missing_exports() -> Result<()> {
+    let rt = new_runtime()?;
+
+    // Exports are resolved when the runtime is created, so the exports that
+    // the plugin omits are known up front:
+    assert_eq!(
+        rt.missing_exports(),
+        [
+            PluginExport::ExportVoidFunction,
+            PluginExport::ReducerBridge,
+            PluginExport::UpdateConfig,
+        ]
+    );
+    assert!(matches!(
+        rt.export_void_function(),
+        Err(PluginError::MissingExport(PluginExport::ExportVoidFunction))
+    ));
+    Ok(())
+}
+
+#[test]
+fn lifecycle() -> Result<()> {
+    let rt = new_runtime()?;
+    let clone = rt.clone();
+
+    // Shutdown is only called once the last clone is dropped:
+    drop(rt);
+    assert_eq!(clone.export_primitive_u32(32)?, 32);
+    drop(clone);
+
+    // Runtimes that are not initialized may still be dropped:
+    drop(Runtime::new(WASM_BYTES)?);
+    Ok(())
+}
+
+#[test]
+fn disabled_wasm_features() -> Result<()> {
+    let features = WasmFeatures {
+        simd: false,
+        bulk_memory: false,
+        reference_types: false,
+        threads: false,
+    };
+    let result = RuntimeBuilder::new()
+        .with_wasm_features(features)
+        .build(WASM_BYTES);
+
+    // The standard library uses bulk memory instructions for copying memory:
+    match result {
+        Err(RuntimeError::DisabledWasmFeatures(error)) => {
+            assert!(error.features.contains(&WasmFeature::BulkMemory));
+        }
+        Err(error) => panic!("Unexpected error: {error}"),
+        Ok(_) => panic!("Plugin should have been rejected"),
+    }
+    Ok(())
+}
+
 fn new_runtime() -> Result<Runtime> {
-    let rt = Runtime::new(WASM_BYTES)?;
-    rt.init()?;
+    let rt = RuntimeBuilder::new()
+        .with_capabilities(&[Capability::Network])
+        .with_init(plugin_config())
+        .build(WASM_BYTES)?;
     Ok(rt)
 }
+
+fn plugin_config() -> PluginConfig {
+    PluginConfig {
+        log_level: "debug".to_owned(),
+    }
+}
@@ -47,3 +47,78 @@ macro_rules! identity_wasm_abi {
 }
 
 identity_wasm_abi!((), u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+/// Primitives that fit in 32 bits. A pair of these is returned across the Wasm
+/// bridge packed into a single `u64`, so it doesn't need to be serialized.
+pub trait PackableAbi: Sized {
+    fn to_bits(self) -> u32;
+    fn from_bits(bits: u32) -> Self;
+}
+
+impl PackableAbi for bool {
+    #[inline]
+    fn to_bits(self) -> u32 {
+        self as u32
+    }
+
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        bits != 0
+    }
+}
+
+impl PackableAbi for f32 {
+    #[inline]
+    fn to_bits(self) -> u32 {
+        f32::to_bits(self)
+    }
+
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        f32::from_bits(bits)
+    }
+}
+
+macro_rules! integer_packable_abi {
+    ($($ty:ty),*) => {
+        $(
+            impl PackableAbi for $ty {
+                // Signed integers are sign-extended, and truncated again when
+                // unpacking.
+                #[inline]
+                fn to_bits(self) -> u32 {
+                    self as u32
+                }
+
+                #[inline]
+                fn from_bits(bits: u32) -> Self {
+                    bits as $ty
+                }
+            }
+        )*
+    }
+}
+
+integer_packable_abi!(u8, u16, u32, i8, i16, i32);
+
+/// Pairs are packed with the first element in the high 32 bits.
+impl<A, B> WasmAbi for (A, B)
+where
+    A: PackableAbi,
+    B: PackableAbi,
+{
+    type AbiType = u64;
+
+    #[inline]
+    fn to_abi(self) -> Self::AbiType {
+        ((self.0.to_bits() as u64) << 32) | self.1.to_bits() as u64
+    }
+
+    #[inline]
+    fn from_abi(value: Self::AbiType) -> Self {
+        (
+            A::from_bits((value >> 32) as u32),
+            B::from_bits(value as u32),
+        )
+    }
+}
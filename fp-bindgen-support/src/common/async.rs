@@ -2,6 +2,17 @@ use super::mem::{to_fat_ptr, FatPtr};
 
 pub const FUTURE_STATUS_PENDING: u32 = 0;
 pub const FUTURE_STATUS_READY: u32 = 1;
+/// The async value failed, and points to a serialized `GuestError` instead of
+/// the result. Only used once both sides agreed on version 2 of the protocol.
+pub const FUTURE_STATUS_ERROR: u32 = 2;
+
+/// The latest version of the async value protocol.
+///
+/// Version 1 only knows pending and ready values. Version 2 adds the error
+/// status, which plugins only use after the host has announced its support
+/// through the `__fp_guest_async_protocol()` export, so that older hosts and
+/// plugins keep working with each other.
+pub const ASYNC_PROTOCOL_VERSION: u32 = 2;
 
 #[doc(hidden)]
 #[repr(C)]
@@ -0,0 +1,179 @@
+//! (De)serializes the columns of lists of structs that are annotated with
+//! `#[fp(columnar)]`, for use with `#[serde(with = "...")]`.
+//!
+//! Every column is encoded as the contiguous little-endian bytes of its
+//! values, wrapped in a MessagePack extension whose type identifies the type
+//! of the values, so the TypeScript runtime can decode it into the matching
+//! typed array. Columns of `u8` values are encoded as plain binary data
+//! instead, which decodes into a `Uint8Array` by default.
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt,
+    mem::{size_of, size_of_val},
+};
+
+/// The name by which `rmp-serde` recognizes MessagePack extensions.
+const EXT_STRUCT_NAME: &str = "_ExtStruct";
+
+/// A primitive that can be stored in a column.
+pub trait ColumnValue: Copy + Sized {
+    /// The type of the MessagePack extension in which columns of this type
+    /// are encoded, or `None` if they are encoded as plain binary data.
+    const EXT_TYPE: Option<i8>;
+
+    fn write_le_bytes(self, bytes: &mut Vec<u8>);
+
+    /// Reads a value from a slice of exactly `size_of::<Self>()` bytes.
+    fn read_le_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_column_value {
+    ($ty:ty, $ext_type:expr) => {
+        impl ColumnValue for $ty {
+            const EXT_TYPE: Option<i8> = $ext_type;
+
+            fn write_le_bytes(self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_le_bytes(bytes: &[u8]) -> Self {
+                let mut array = [0; size_of::<$ty>()];
+                array.copy_from_slice(bytes);
+                <$ty>::from_le_bytes(array)
+            }
+        }
+    };
+}
+
+impl_column_value!(u8, None);
+impl_column_value!(i8, Some(16));
+impl_column_value!(i16, Some(17));
+impl_column_value!(u16, Some(18));
+impl_column_value!(i32, Some(19));
+impl_column_value!(u32, Some(20));
+impl_column_value!(i64, Some(21));
+impl_column_value!(u64, Some(22));
+impl_column_value!(f32, Some(23));
+impl_column_value!(f64, Some(24));
+
+pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ColumnValue,
+    S: Serializer,
+{
+    let mut bytes = Vec::with_capacity(size_of_val(values));
+    for value in values {
+        value.write_le_bytes(&mut bytes);
+    }
+
+    match T::EXT_TYPE {
+        Some(ext_type) => serializer.serialize_newtype_struct(
+            EXT_STRUCT_NAME,
+            &(ext_type, serde_bytes::Bytes::new(&bytes)),
+        ),
+        None => serde_bytes::Bytes::new(&bytes).serialize(serializer),
+    }
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    T: ColumnValue,
+    D: Deserializer<'de>,
+{
+    let bytes = match T::EXT_TYPE {
+        Some(ext_type) => {
+            let (actual_type, bytes) =
+                deserializer.deserialize_newtype_struct(EXT_STRUCT_NAME, ExtVisitor)?;
+            if actual_type != ext_type {
+                return Err(de::Error::custom(format!(
+                    "expected column of extension type {ext_type}, found {actual_type}"
+                )));
+            }
+            bytes
+        }
+        None => serde_bytes::ByteBuf::deserialize(deserializer)?.into_vec(),
+    };
+
+    if bytes.len() % size_of::<T>() != 0 {
+        return Err(de::Error::invalid_length(
+            bytes.len(),
+            &"a multiple of the size of the column's values",
+        ));
+    }
+
+    Ok(bytes
+        .chunks_exact(size_of::<T>())
+        .map(T::read_le_bytes)
+        .collect())
+}
+
+/// Visits the `(type, data)` tuple of a MessagePack extension.
+struct ExtVisitor;
+
+impl<'de> de::Visitor<'de> for ExtVisitor {
+    type Value = (i8, Vec<u8>);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a MessagePack extension")
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (ext_type, bytes) = <(i8, serde_bytes::ByteBuf)>::deserialize(deserializer)?;
+        Ok((ext_type, bytes.into_vec()))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let ext_type = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let bytes: serde_bytes::ByteBuf = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok((ext_type, bytes.into_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Columns {
+        #[serde(with = "super")]
+        values: Vec<f64>,
+        #[serde(with = "super")]
+        flags: Vec<u8>,
+    }
+
+    #[test]
+    fn round_trips_columns_as_extensions() {
+        let columns = Columns {
+            values: vec![1.5, -2.0],
+            flags: vec![1, 0],
+        };
+
+        let mut bytes = Vec::new();
+        columns
+            .serialize(&mut rmp_serde::Serializer::new(&mut bytes).with_struct_map())
+            .unwrap();
+        let mut expected = vec![0x82, 0xa6];
+        expected.extend_from_slice(b"values");
+        expected.extend_from_slice(&[0xd8, 24]); // fixext 16 of type 24
+        expected.extend_from_slice(&1.5f64.to_le_bytes());
+        expected.extend_from_slice(&(-2.0f64).to_le_bytes());
+        expected.push(0xa5);
+        expected.extend_from_slice(b"flags");
+        expected.extend_from_slice(&[0xc4, 2, 1, 0]); // bin 8, 2 bytes
+        assert_eq!(bytes, expected);
+
+        let decoded: Columns = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(decoded, columns);
+    }
+}
@@ -0,0 +1,184 @@
+//! Optional compression of serialized payloads that are passed between the
+//! host and the guest.
+//!
+//! Payloads are only compressed if their size meets the configured threshold,
+//! which is disabled by default. Compressed payloads start with a marker byte
+//! that is never used by MessagePack, followed by the LZ4 block with its
+//! uncompressed size prepended. This allows either side to decompress
+//! payloads without knowing the threshold that is used by the other side.
+//!
+//! The uncompressed size is declared by the sender, so payloads that declare a
+//! size above the configured maximum are rejected before anything is
+//! allocated for them.
+
+pub use lz4_flex::block::DecompressError;
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Marker byte that precedes compressed payloads.
+///
+/// `0xc1` is reserved by the MessagePack specification and never used, so
+/// it cannot appear at the start of an uncompressed payload.
+pub const COMPRESSION_MARKER: u8 = 0xc1;
+
+/// The default maximum size (in bytes) of decompressed payloads.
+pub const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 64 * 1024 * 1024;
+
+static COMPRESSION_THRESHOLD: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+static MAX_DECOMPRESSED_SIZE: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_DECOMPRESSED_SIZE);
+
+/// Sets the size (in bytes) from which payloads will be compressed, or
+/// disables compression if `None` is given.
+///
+/// Note the other side needs to be able to decompress payloads too, so
+/// compression should only be enabled if it was built with compression
+/// support as well.
+pub fn set_compression_threshold(threshold: Option<usize>) {
+    COMPRESSION_THRESHOLD.store(threshold.unwrap_or(usize::MAX), Ordering::Relaxed);
+}
+
+/// Returns the size (in bytes) from which payloads will be compressed, if
+/// compression is enabled.
+pub fn compression_threshold() -> Option<usize> {
+    match COMPRESSION_THRESHOLD.load(Ordering::Relaxed) {
+        usize::MAX => None,
+        threshold => Some(threshold),
+    }
+}
+
+/// Sets the maximum size (in bytes) that compressed payloads may declare for
+/// their uncompressed contents. Payloads that declare a larger size fail to
+/// decompress.
+pub fn set_max_decompressed_size(max_size: usize) {
+    MAX_DECOMPRESSED_SIZE.store(max_size, Ordering::Relaxed);
+}
+
+/// Returns the maximum size (in bytes) that compressed payloads may declare
+/// for their uncompressed contents.
+pub fn max_decompressed_size() -> usize {
+    MAX_DECOMPRESSED_SIZE.load(Ordering::Relaxed)
+}
+
+/// Compresses the given payload if it meets the compression threshold and
+/// compressing actually makes it smaller.
+pub fn compress(payload: Vec<u8>) -> Vec<u8> {
+    compress_slice(&payload).unwrap_or(payload)
+}
+
+/// Returns the compressed payload if the given payload meets the compression
+/// threshold and compressing actually makes it smaller, or `None` if the
+/// payload should be passed as is.
+///
+/// Unlike `compress()`, this doesn't require ownership of the payload, so
+/// payloads below the threshold are never copied.
+pub fn compress_slice(payload: &[u8]) -> Option<Vec<u8>> {
+    if payload.len() < COMPRESSION_THRESHOLD.load(Ordering::Relaxed) {
+        return None;
+    }
+
+    let compressed = lz4_flex::compress_prepend_size(payload);
+    if compressed.len() + 1 >= payload.len() {
+        return None;
+    }
+
+    let mut buffer = Vec::with_capacity(compressed.len() + 1);
+    buffer.push(COMPRESSION_MARKER);
+    buffer.extend_from_slice(&compressed);
+    Some(buffer)
+}
+
+/// Decompresses the given payload if it is compressed, or returns it as is
+/// otherwise, or returns an error if it is not a valid compressed payload.
+pub fn try_decompress(payload: &[u8]) -> Result<Cow<'_, [u8]>, DecompressionError> {
+    match payload.split_first() {
+        Some((&COMPRESSION_MARKER, compressed)) => {
+            // Check the size that is prepended to the block before LZ4
+            // allocates a buffer for it:
+            if let [a, b, c, d, ..] = *compressed {
+                let size = u32::from_le_bytes([a, b, c, d]) as usize;
+                let max_size = max_decompressed_size();
+                if size > max_size {
+                    return Err(DecompressionError::TooLarge { size, max_size });
+                }
+            }
+
+            lz4_flex::decompress_size_prepended(compressed)
+                .map(Cow::Owned)
+                .map_err(DecompressionError::Invalid)
+        }
+        _ => Ok(Cow::Borrowed(payload)),
+    }
+}
+
+/// Returned when a compressed payload cannot be decompressed.
+#[derive(Debug)]
+pub enum DecompressionError {
+    /// The payload declares an uncompressed size that exceeds the configured
+    /// maximum.
+    TooLarge { size: usize, max_size: usize },
+
+    /// The payload is not a valid LZ4 block.
+    Invalid(DecompressError),
+}
+
+impl fmt::Display for DecompressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge { size, max_size } => write!(
+                f,
+                "compressed payload declares {} bytes, which exceeds the maximum of {} bytes",
+                size, max_size
+            ),
+            Self::Invalid(error) => write!(f, "invalid compressed payload: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for DecompressionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::TooLarge { .. } => None,
+            Self::Invalid(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compressed_payload(payload: &[u8]) -> Vec<u8> {
+        let mut buffer = vec![COMPRESSION_MARKER];
+        buffer.extend_from_slice(&lz4_flex::compress_prepend_size(payload));
+        buffer
+    }
+
+    #[test]
+    fn decompresses_payloads() {
+        let payload = vec![0x90; 1024];
+        let compressed = compressed_payload(&payload);
+        assert_eq!(try_decompress(&compressed).unwrap().as_ref(), &payload[..]);
+        assert!(matches!(try_decompress(&payload), Ok(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn rejects_oversized_payloads() {
+        let mut compressed = compressed_payload(&[0x90; 16]);
+        compressed[1..5].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            try_decompress(&compressed),
+            Err(DecompressionError::TooLarge { size, .. }) if size == u32::MAX as usize
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_payloads() {
+        let compressed = [COMPRESSION_MARKER, 16, 0, 0, 0, 0xff];
+        assert!(matches!(
+            try_decompress(&compressed),
+            Err(DecompressionError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Report of a failure inside the plugin, which plugins that are built with
+/// the `panic-abort` feature send to the host through the reserved
+/// `__fp_error()` import before they abort.
+///
+/// Without such a report, a plugin that is compiled with `panic = "abort"`
+/// can only trap without any indication of what went wrong.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GuestError {
+    pub kind: GuestErrorKind,
+    pub message: String,
+}
+
+impl fmt::Display for GuestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "plugin failed with {} error: {}",
+            self.kind, self.message
+        )
+    }
+}
+
+impl std::error::Error for GuestError {}
+
+/// The kind of failure that is reported by a `GuestError`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuestErrorKind {
+    /// A value that was passed by the host could not be deserialized.
+    Deserialization,
+
+    /// A value that is passed to the host could not be serialized.
+    Serialization,
+
+    /// Memory could not be allocated or freed.
+    Allocation,
+
+    /// The host passed an invalid pointer or async value.
+    InvalidPointer,
+
+    /// The glue code was used in a way that it doesn't support.
+    Internal,
+
+    /// The plugin panicked. This is only reported if the panic hook of
+    /// `fp-bindgen-support` is installed.
+    Panic,
+}
+
+impl fmt::Display for GuestErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Deserialization => "deserialization",
+            Self::Serialization => "serialization",
+            Self::Allocation => "allocation",
+            Self::InvalidPointer => "invalid pointer",
+            Self::Internal => "internal",
+            Self::Panic => "panic",
+        })
+    }
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use std::fmt;
+
+/// The capability that is required to make HTTP requests through the built-in
+/// HTTP imports.
+pub const HTTP_CAPABILITY: &str = "http";
+
+/// An HTTP request that is made through the built-in HTTP imports.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct HttpRequest {
+    /// The HTTP method, such as `GET` or `POST`.
+    pub method: String,
+
+    /// The absolute URL to send the request to.
+    pub url: String,
+
+    /// The headers of the request, as name-value pairs. Headers may occur more
+    /// than once.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+
+    /// The body of the request, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<ByteBuf>,
+}
+
+impl HttpRequest {
+    /// Returns a request with the given method and URL, without any headers
+    /// or body.
+    pub fn new(method: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            method: method.into(),
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Returns a `GET` request for the given URL.
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new("GET", url)
+    }
+
+    /// Returns a `POST` request for the given URL, with the given body.
+    pub fn post(url: impl Into<String>, body: impl Into<Vec<u8>>) -> Self {
+        Self::new("POST", url).with_body(body)
+    }
+
+    /// Adds the given header to the request.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the body of the request.
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(ByteBuf::from(body.into()));
+        self
+    }
+}
+
+/// The response to a request that was made through the built-in HTTP imports.
+///
+/// Responses with an error status, such as 404, are responses nonetheless, so
+/// they are not reported as an [`HttpError`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct HttpResponse {
+    /// The HTTP status code.
+    pub status: u16,
+
+    /// The headers of the response, as name-value pairs.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+
+    /// The body of the response. May be empty.
+    pub body: ByteBuf,
+}
+
+impl HttpResponse {
+    /// Returns whether the status code is in the 2xx range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Returns the value of the first header with the given name, which is
+    /// matched case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Error that is returned when a request made through the built-in HTTP
+/// imports did not result in a response.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", content = "message", rename_all = "snake_case")]
+pub enum HttpError {
+    /// The request could not be sent, for instance because the method or URL
+    /// is invalid.
+    InvalidRequest(String),
+
+    /// No connection could be made to the server.
+    Connection(String),
+
+    /// The request did not complete in time.
+    Timeout,
+
+    /// Any other error that occurred while making the request.
+    Other(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRequest(message) => write!(f, "invalid request: {message}"),
+            Self::Connection(message) => write!(f, "connection failed: {message}"),
+            Self::Timeout => f.write_str("request timed out"),
+            Self::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_headers_case_insensitively() {
+        let response = HttpResponse {
+            status: 200,
+            headers: vec![
+                ("content-type".to_owned(), "text/plain".to_owned()),
+                ("set-cookie".to_owned(), "a=1".to_owned()),
+                ("set-cookie".to_owned(), "b=2".to_owned()),
+            ],
+            body: ByteBuf::new(),
+        };
+        assert!(response.is_success());
+        assert_eq!(response.header("Content-Type"), Some("text/plain"));
+        assert_eq!(response.header("Set-Cookie"), Some("a=1"));
+        assert_eq!(response.header("Location"), None);
+    }
+}
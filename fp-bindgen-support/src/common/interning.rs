@@ -0,0 +1,151 @@
+//! (De)serializes the string fields that are annotated with `#[fp(interned)]`,
+//! for use with `#[serde(with = "...")]`.
+//!
+//! Plugins that are built with the `interning` feature register every distinct
+//! value of such fields with the runtime once, through the reserved
+//! `__fp_intern_string()` import, after which they send the `u32` handle that
+//! the runtime assigned to it instead of the string itself. Runtimes send the
+//! strings as they are, so both sides accept either a string or a handle.
+//!
+//! Handles are assigned from a table that is shared by all the runtimes in the
+//! process, so that every plugin that registers the same string receives the
+//! same handle. Strings are never removed from the table, so interning is only
+//! suitable for values that are drawn from a limited set, such as metric names
+//! or column identifiers.
+
+use serde::{de, Deserializer, Serializer};
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    fmt,
+    sync::{Arc, RwLock},
+};
+
+/// The strings that have been registered with the runtime, by handle.
+struct InternTable {
+    strings: Vec<Arc<str>>,
+    handles: BTreeMap<Arc<str>, u32>,
+}
+
+static INTERN_TABLE: RwLock<InternTable> = RwLock::new(InternTable {
+    strings: Vec::new(),
+    handles: BTreeMap::new(),
+});
+
+/// Registers the given string with the intern table of the runtime, and
+/// returns its handle. Registering the same string again returns the same
+/// handle.
+pub fn register(value: &str) -> u32 {
+    if let Some(handle) = INTERN_TABLE.read().unwrap().handles.get(value) {
+        return *handle;
+    }
+
+    let mut table = INTERN_TABLE.write().unwrap();
+    if let Some(handle) = table.handles.get(value) {
+        return *handle;
+    }
+
+    let handle = u32::try_from(table.strings.len()).expect("Too many interned strings");
+    let value: Arc<str> = Arc::from(value);
+    table.strings.push(value.clone());
+    table.handles.insert(value, handle);
+    handle
+}
+
+/// Returns the string that was registered with the given handle, if any.
+pub fn resolve(handle: u32) -> Option<Arc<str>> {
+    let table = INTERN_TABLE.read().unwrap();
+    table.strings.get(handle as usize).cloned()
+}
+
+pub fn serialize<S: Serializer>(value: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    #[cfg(feature = "interning")]
+    if let Some(handle) = crate::guest::interning::handle_for_host(value) {
+        return serializer.serialize_u32(handle);
+    }
+
+    serializer.serialize_str(value)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    deserializer.deserialize_any(InternedVisitor)
+}
+
+/// Visits either an interned string, or its handle.
+struct InternedVisitor;
+
+impl<'de> de::Visitor<'de> for InternedVisitor {
+    type Value = String;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string or the handle of an interned string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(value.to_owned())
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(value)
+    }
+
+    fn visit_u64<E: de::Error>(self, handle: u64) -> Result<Self::Value, E> {
+        u32::try_from(handle)
+            .ok()
+            .and_then(resolve)
+            .map(|value| value.as_ref().to_owned())
+            .ok_or_else(|| E::custom(format!("unknown interned string handle: {handle}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Metric {
+        #[serde(with = "super")]
+        name: String,
+        value: f64,
+    }
+
+    #[test]
+    fn registers_strings_once() {
+        let handle = register("fp_bindgen_test_registers_strings_once");
+        assert_eq!(register("fp_bindgen_test_registers_strings_once"), handle);
+        assert_eq!(
+            resolve(handle).as_deref(),
+            Some("fp_bindgen_test_registers_strings_once")
+        );
+    }
+
+    #[test]
+    fn deserializes_strings_and_handles() {
+        let metric = Metric {
+            name: "fp_bindgen_test_deserializes_strings_and_handles".to_owned(),
+            value: 1.5,
+        };
+        let bytes = rmp_serde::to_vec_named(&metric).unwrap();
+        assert_eq!(rmp_serde::from_slice::<Metric>(&bytes).unwrap(), metric);
+
+        #[derive(Serialize)]
+        struct InternedMetric {
+            name: u32,
+            value: f64,
+        }
+        let interned = InternedMetric {
+            name: register(&metric.name),
+            value: metric.value,
+        };
+        let bytes = rmp_serde::to_vec_named(&interned).unwrap();
+        assert_eq!(rmp_serde::from_slice::<Metric>(&bytes).unwrap(), metric);
+
+        let unknown = InternedMetric {
+            name: u32::MAX,
+            value: metric.value,
+        };
+        let bytes = rmp_serde::to_vec_named(&unknown).unwrap();
+        assert!(rmp_serde::from_slice::<Metric>(&bytes).is_err());
+    }
+}
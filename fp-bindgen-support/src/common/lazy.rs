@@ -0,0 +1,92 @@
+use rmp_serde::{decode, Deserializer};
+use serde::{de::DeserializeOwned, ser::Error, Serialize, Serializer};
+use std::fmt;
+use std::sync::OnceLock;
+
+/// A value that was returned by a plugin, but which is only deserialized when
+/// it is first accessed.
+///
+/// Exports that are marked with `#[fp(lazy)]` return a `Lazy<T>` in the Rust
+/// runtimes, so that hosts which forward most responses unchanged can pass on
+/// the MessagePack-encoded bytes through `raw_bytes()` without paying for a
+/// round trip through `T`.
+pub struct Lazy<T> {
+    bytes: Vec<u8>,
+    value: OnceLock<T>,
+}
+
+impl<T> Lazy<T> {
+    /// Wraps the MessagePack-encoded bytes of a value.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self {
+            bytes,
+            value: OnceLock::new(),
+        }
+    }
+
+    /// Returns the MessagePack-encoded bytes, exactly as they were returned by
+    /// the plugin.
+    pub fn raw_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the MessagePack-encoded bytes, discarding the deserialized value
+    /// if there is one.
+    pub fn into_raw_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl<T: DeserializeOwned> Lazy<T> {
+    /// Returns the value, deserializing it if this is the first access.
+    ///
+    /// If deserialization fails, the error is returned and a later call will
+    /// attempt to deserialize the value again.
+    pub fn get(&self) -> Result<&T, decode::Error> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+
+        let value = Self::deserialize_slice(&self.bytes)?;
+        Ok(self.value.get_or_init(|| value))
+    }
+
+    /// Returns the value, deserializing it if it wasn't accessed before.
+    pub fn into_inner(self) -> Result<T, decode::Error> {
+        match self.value.into_inner() {
+            Some(value) => Ok(value),
+            None => Self::deserialize_slice(&self.bytes),
+        }
+    }
+
+    fn deserialize_slice(bytes: &[u8]) -> Result<T, decode::Error> {
+        let mut deserializer = Deserializer::from_read_ref(bytes).with_human_readable();
+        T::deserialize(&mut deserializer)
+    }
+}
+
+impl<T: Clone> Clone for Lazy<T> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lazy")
+            .field("bytes", &self.bytes.len())
+            .field("value", &self.value.get())
+            .finish()
+    }
+}
+
+/// Serializes the deserialized value, so that a `Lazy<T>` can be passed on
+/// wherever a `T` would be serialized.
+impl<T: DeserializeOwned + Serialize> Serialize for Lazy<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
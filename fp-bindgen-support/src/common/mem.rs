@@ -1,3 +1,6 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
 #[doc(hidden)]
 pub type FatPtr = u64;
 
@@ -10,3 +13,50 @@ pub fn to_fat_ptr(ptr: *const u8, len: u32) -> FatPtr {
 pub fn from_fat_ptr(ptr: FatPtr) -> (*const u8, u32) {
     ((ptr >> 32) as *const u8, (ptr & 0xffffffff) as u32)
 }
+
+/// Statistics about the memory that is allocated by a plugin.
+///
+/// These are only available for plugins that are built with the
+/// `memory-stats` feature.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryStats {
+    /// The number of bytes that are currently allocated.
+    pub allocated_bytes: u64,
+
+    /// The highest number of bytes that has been allocated at any one time.
+    pub peak_allocated_bytes: u64,
+
+    /// The total number of allocations that have been made.
+    pub allocation_count: u64,
+}
+
+/// Metrics about the calls that a plugin has made to the imports of the
+/// runtime, as measured by the plugin itself.
+///
+/// These are only available for plugins that are built with the
+/// `guest-metrics` feature.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestMetrics {
+    /// The metrics of every import that has been called, by its name.
+    pub imports: BTreeMap<String, ImportMetrics>,
+}
+
+/// Metrics about the calls that a plugin has made to a single import.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportMetrics {
+    /// The number of calls that have been made.
+    pub call_count: u64,
+
+    /// The total duration of the calls, in nanoseconds.
+    ///
+    /// This includes the time that is spent (de)serializing arguments and
+    /// return values, and for async imports, the time until their result is
+    /// available.
+    pub total_duration_nanos: u64,
+
+    /// The duration of the slowest call, in nanoseconds.
+    pub max_duration_nanos: u64,
+}
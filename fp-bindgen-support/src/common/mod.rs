@@ -1,4 +1,26 @@
 pub mod abi;
 #[cfg(feature = "async")]
 pub mod r#async;
+pub mod columnar;
+#[cfg(feature = "compression")]
+pub mod compression;
+pub mod guest_error;
+#[cfg(any(feature = "http-imports", feature = "http-imports-host"))]
+pub mod http_imports;
+pub mod interning;
+pub mod lazy;
 pub mod mem;
+#[cfg(feature = "non-exhaustive")]
+pub mod non_exhaustive;
+pub mod serde_str;
+#[cfg(feature = "signatures")]
+pub mod signatures;
+#[cfg(any(feature = "std-imports", feature = "std-imports-host"))]
+pub mod std_imports;
+#[cfg(any(feature = "stdio", feature = "host"))]
+pub mod stdio;
+#[cfg(any(feature = "opentelemetry", feature = "trace-context"))]
+pub mod trace_context;
+#[cfg(feature = "validation")]
+pub mod validation;
+pub mod wasm_features;
@@ -0,0 +1,8 @@
+//! Support for enums that are marked as `#[non_exhaustive]` in the protocol.
+//!
+//! The generated bindings for such enums have an additional `Unknown`
+//! variant, which is used for all variants that were added to the protocol
+//! after the bindings were generated. It holds the serialized value of the
+//! variant, so that it can be inspected, stored or passed on without loss.
+
+pub use rmpv::Value;
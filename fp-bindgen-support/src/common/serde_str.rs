@@ -0,0 +1,26 @@
+//! (De)serializes values through their `Display` and `FromStr`
+//! implementations, for use with `#[serde(with = "...")]`.
+//!
+//! This is used for arbitrary-precision numbers, such as decimals and big
+//! integers, which are exchanged as strings so they don't lose precision.
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+use std::{fmt::Display, str::FromStr};
+
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(de::Error::custom)
+}
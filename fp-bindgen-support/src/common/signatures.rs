@@ -0,0 +1,269 @@
+//! Signing of plugin modules, and verification of their signatures before
+//! they are instantiated.
+//!
+//! A signed module ends with a custom section named `fp-signature`, which
+//! contains the Ed25519 signature of all the bytes of the module that precede
+//! the section. Because runtimes ignore custom sections, signed modules can
+//! still be loaded by runtimes that don't verify signatures.
+//!
+//! Build pipelines sign plugins using `sign_module()`, while hosts pass a
+//! `SignatureVerifier` to the runtime, which rejects plugins that are unsigned
+//! or whose signature is invalid.
+
+use ed25519_dalek::{Signature, Signer, Verifier};
+use std::{fmt, sync::Arc};
+
+pub use ed25519_dalek::{SigningKey, VerifyingKey};
+
+/// The name of the custom section that contains the signature of a module.
+pub const SIGNATURE_SECTION: &str = "fp-signature";
+
+const SECTION_ID_CUSTOM: u8 = 0;
+
+/// Size of the magic number and the version with which every module starts.
+const HEADER_SIZE: usize = 8;
+
+type VerifyFn = dyn Fn(&[u8], &[u8]) -> bool + Send + Sync;
+
+/// Verifies the signatures of plugin modules before they are instantiated.
+#[derive(Clone)]
+pub enum SignatureVerifier {
+    /// Verifies Ed25519 signatures using the given public key.
+    PublicKey(VerifyingKey),
+
+    /// Verifies signatures using a callback, which is called with the signed
+    /// bytes of the module and its signature, and returns whether the
+    /// signature is valid. This allows hosts to use other signature schemes,
+    /// or to accept the keys of multiple publishers.
+    Callback(Arc<VerifyFn>),
+}
+
+impl SignatureVerifier {
+    /// Returns a verifier for Ed25519 signatures using the given public key.
+    pub fn ed25519(public_key: &[u8; 32]) -> Result<Self, SignatureError> {
+        VerifyingKey::from_bytes(public_key)
+            .map(Self::PublicKey)
+            .map_err(|_| SignatureError::InvalidPublicKey)
+    }
+
+    /// Returns a verifier that verifies signatures using the given callback.
+    pub fn from_fn(verify: impl Fn(&[u8], &[u8]) -> bool + Send + Sync + 'static) -> Self {
+        Self::Callback(Arc::new(verify))
+    }
+
+    /// Verifies the signature of the given module.
+    pub fn verify(&self, wasm_module: &[u8]) -> Result<(), SignatureError> {
+        let (signed_bytes, signature) = split_signature(wasm_module)?;
+        let is_valid = match self {
+            Self::PublicKey(public_key) => Signature::from_slice(signature)
+                .map(|signature| public_key.verify(signed_bytes, &signature).is_ok())
+                .unwrap_or(false),
+            Self::Callback(verify) => verify(signed_bytes, signature),
+        };
+        if is_valid {
+            Ok(())
+        } else {
+            Err(SignatureError::InvalidSignature)
+        }
+    }
+}
+
+impl fmt::Debug for SignatureVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PublicKey(public_key) => f.debug_tuple("PublicKey").field(public_key).finish(),
+            Self::Callback(_) => f.write_str("Callback"),
+        }
+    }
+}
+
+/// Signs the given module using the given key, and returns the signed module.
+///
+/// If the module was signed before, its signature is replaced.
+pub fn sign_module(
+    wasm_module: &[u8],
+    signing_key: &SigningKey,
+) -> Result<Vec<u8>, SignatureError> {
+    let unsigned_module = match split_signature(wasm_module) {
+        Ok((signed_bytes, _)) => signed_bytes,
+        Err(SignatureError::Unsigned) => wasm_module,
+        Err(error) => return Err(error),
+    };
+    let signature = signing_key.sign(unsigned_module).to_bytes();
+
+    let mut payload = Vec::new();
+    write_unsigned(&mut payload, SIGNATURE_SECTION.len());
+    payload.extend_from_slice(SIGNATURE_SECTION.as_bytes());
+    payload.extend_from_slice(&signature);
+
+    let mut signed_module = unsigned_module.to_vec();
+    signed_module.push(SECTION_ID_CUSTOM);
+    write_unsigned(&mut signed_module, payload.len());
+    signed_module.extend_from_slice(&payload);
+    Ok(signed_module)
+}
+
+/// Splits a module into the bytes that are signed and the signature, which
+/// is the payload of the last section.
+fn split_signature(wasm_module: &[u8]) -> Result<(&[u8], &[u8]), SignatureError> {
+    if wasm_module.len() < HEADER_SIZE || &wasm_module[..4] != b"\0asm" {
+        return Err(SignatureError::MalformedModule);
+    }
+
+    let mut offset = HEADER_SIZE;
+    let mut last_section = None;
+    while offset < wasm_module.len() {
+        let section_start = offset;
+        let section_id = wasm_module[offset];
+        offset += 1;
+        let size = read_unsigned(wasm_module, &mut offset)?;
+        let section_end = offset
+            .checked_add(size)
+            .filter(|end| *end <= wasm_module.len())
+            .ok_or(SignatureError::MalformedModule)?;
+        last_section = Some((section_start, section_id, offset, section_end));
+        offset = section_end;
+    }
+
+    match last_section {
+        Some((section_start, SECTION_ID_CUSTOM, mut offset, section_end)) => {
+            let name_len = read_unsigned(wasm_module, &mut offset)?;
+            let name = offset
+                .checked_add(name_len)
+                .filter(|end| *end <= section_end)
+                .map(|end| &wasm_module[offset..end])
+                .ok_or(SignatureError::MalformedModule)?;
+            if name == SIGNATURE_SECTION.as_bytes() {
+                let signature = &wasm_module[offset + name_len..section_end];
+                Ok((&wasm_module[..section_start], signature))
+            } else {
+                Err(SignatureError::Unsigned)
+            }
+        }
+        _ => Err(SignatureError::Unsigned),
+    }
+}
+
+/// Reads an unsigned LEB128 integer.
+fn read_unsigned(bytes: &[u8], offset: &mut usize) -> Result<usize, SignatureError> {
+    let mut result = 0usize;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.get(*offset).ok_or(SignatureError::MalformedModule)?;
+        *offset += 1;
+        if shift >= 32 {
+            return Err(SignatureError::MalformedModule);
+        }
+        result |= ((byte & 0x7f) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+    }
+}
+
+/// Writes an unsigned LEB128 integer.
+fn write_unsigned(bytes: &mut Vec<u8>, mut value: usize) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// Error for a plugin whose signature could not be verified.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignatureError {
+    /// The module does not end with an `fp-signature` section.
+    Unsigned,
+
+    /// The signature does not match the module.
+    InvalidSignature,
+
+    /// The bytes are not a WebAssembly module.
+    MalformedModule,
+
+    /// The public key with which the verifier was created is invalid.
+    InvalidPublicKey,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Unsigned => "plugin is not signed",
+            Self::InvalidSignature => "plugin signature is invalid",
+            Self::MalformedModule => "plugin is not a valid WebAssembly module",
+            Self::InvalidPublicKey => "public key is not a valid Ed25519 key",
+        })
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // An empty module with a custom section named `name`:
+    const MODULE: &[u8] = b"\0asm\x01\0\0\0\0\x05\x04name";
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    #[test]
+    fn test_signed_modules_are_verified() {
+        let signed_module = sign_module(MODULE, &signing_key()).unwrap();
+        assert!(signed_module.starts_with(MODULE));
+
+        let verifier =
+            SignatureVerifier::ed25519(&signing_key().verifying_key().to_bytes()).unwrap();
+        assert_eq!(verifier.verify(&signed_module), Ok(()));
+        assert_eq!(verifier.verify(MODULE), Err(SignatureError::Unsigned));
+
+        let other_key = SigningKey::from_bytes(&[8; 32]);
+        let other_module = sign_module(MODULE, &other_key).unwrap();
+        assert_eq!(
+            verifier.verify(&other_module),
+            Err(SignatureError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_tampered_modules_are_rejected() {
+        let mut signed_module = sign_module(MODULE, &signing_key()).unwrap();
+        signed_module[MODULE.len() - 1] = b'x';
+
+        let verifier = SignatureVerifier::PublicKey(signing_key().verifying_key());
+        assert_eq!(
+            verifier.verify(&signed_module),
+            Err(SignatureError::InvalidSignature)
+        );
+        assert_eq!(
+            verifier.verify(b"not a module"),
+            Err(SignatureError::MalformedModule)
+        );
+    }
+
+    #[test]
+    fn test_signing_replaces_signatures() {
+        let signed_module = sign_module(MODULE, &signing_key()).unwrap();
+        assert_eq!(
+            sign_module(&signed_module, &signing_key()).unwrap(),
+            signed_module
+        );
+    }
+
+    #[test]
+    fn test_callbacks_receive_signed_bytes() {
+        let signed_module = sign_module(MODULE, &signing_key()).unwrap();
+        let verifier = SignatureVerifier::from_fn(|signed_bytes, signature| {
+            signed_bytes == MODULE && signature.len() == Signature::BYTE_SIZE
+        });
+        assert_eq!(verifier.verify(&signed_module), Ok(()));
+    }
+}
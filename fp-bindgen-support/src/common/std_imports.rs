@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The capability that is required to read and write files through the
+/// built-in std imports.
+pub const FS_CAPABILITY: &str = "fs";
+
+/// The capability that is required to read the clock of the runtime through
+/// the built-in std imports.
+pub const CLOCK_CAPABILITY: &str = "clock";
+
+/// The capability that is required to request random bytes from the runtime
+/// through the built-in std imports.
+pub const RANDOM_CAPABILITY: &str = "random";
+
+/// Error that is returned when a file cannot be read or written through the
+/// built-in std imports.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", content = "message", rename_all = "snake_case")]
+pub enum FileError {
+    /// The path is not a relative path within the virtual file system, for
+    /// instance because it is absolute or contains `..` components.
+    InvalidPath,
+
+    /// No file exists at the given path.
+    NotFound,
+
+    /// The runtime does not expose a file system to the plugin.
+    Unsupported,
+
+    /// Any other error that occurred while accessing the file.
+    Other(String),
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPath => f.write_str("invalid path"),
+            Self::NotFound => f.write_str("file not found"),
+            Self::Unsupported => f.write_str("file system is not supported by the runtime"),
+            Self::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for FileError {}
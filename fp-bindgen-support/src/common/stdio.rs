@@ -0,0 +1,26 @@
+/// The stream to which a plugin prints its output.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+impl OutputStream {
+    /// Returns the stream for the given file descriptor, which is how the
+    /// stream is passed to the `__fp_host_print()` import. Unknown descriptors
+    /// are treated as stdout.
+    pub fn from_fd(fd: u32) -> Self {
+        match fd {
+            2 => Self::Stderr,
+            _ => Self::Stdout,
+        }
+    }
+
+    /// Returns the file descriptor of the stream.
+    pub fn fd(self) -> u32 {
+        match self {
+            Self::Stdout => 1,
+            Self::Stderr => 2,
+        }
+    }
+}
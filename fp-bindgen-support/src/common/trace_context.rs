@@ -0,0 +1,8 @@
+use std::collections::HashMap;
+
+/// Trace context that is propagated across the plugin boundary.
+///
+/// It contains the headers of the configured propagator, such as the W3C
+/// `traceparent` and `tracestate` headers, so that it can be attached to
+/// outgoing requests as-is.
+pub type TraceContext = HashMap<String, String>;
@@ -0,0 +1,25 @@
+//! Helpers used by the generated bindings to validate fields that have
+//! `#[fp(validate(...))]` attributes.
+
+use regex::Regex;
+use std::{cell::RefCell, collections::HashMap};
+
+thread_local! {
+    static REGEX_CACHE: RefCell<HashMap<String, Option<Regex>>> = RefCell::new(HashMap::new());
+}
+
+/// Returns whether the value matches the given regular expression.
+///
+/// Compiled expressions are cached, so they only need to be compiled once.
+/// An invalid expression never matches.
+pub fn is_match(value: &str, pattern: &str) -> bool {
+    REGEX_CACHE.with(|cache| {
+        cache
+            .borrow_mut()
+            .entry(pattern.to_owned())
+            .or_insert_with(|| Regex::new(pattern).ok())
+            .as_ref()
+            .map(|regex| regex.is_match(value))
+            .unwrap_or_default()
+    })
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+
+/// The WebAssembly proposals that a runtime enables for the plugins it runs.
+///
+/// By default, SIMD, bulk memory and reference types are enabled, while
+/// threads are not. Plugins should be compiled with the matching target
+/// features, which are returned by `target_features()`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WasmFeatures {
+    pub simd: bool,
+    pub bulk_memory: bool,
+    pub reference_types: bool,
+    pub threads: bool,
+}
+
+impl WasmFeatures {
+    /// Returns whether the given feature is enabled.
+    pub fn is_enabled(&self, feature: WasmFeature) -> bool {
+        match feature {
+            WasmFeature::Simd => self.simd,
+            WasmFeature::BulkMemory => self.bulk_memory,
+            WasmFeature::ReferenceTypes => self.reference_types,
+            WasmFeature::Threads => self.threads,
+        }
+    }
+
+    /// Returns a copy in which the given feature is enabled or disabled.
+    pub fn with(mut self, feature: WasmFeature, enabled: bool) -> Self {
+        match feature {
+            WasmFeature::Simd => self.simd = enabled,
+            WasmFeature::BulkMemory => self.bulk_memory = enabled,
+            WasmFeature::ReferenceTypes => self.reference_types = enabled,
+            WasmFeature::Threads => self.threads = enabled,
+        }
+        self
+    }
+
+    /// Returns the target features with which plugins should be compiled, in
+    /// the format that is expected by `rustc -C target-feature`, for instance
+    /// `+simd128,+bulk-memory,+reference-types,-atomics`.
+    pub fn target_features(&self) -> String {
+        WasmFeature::ALL
+            .iter()
+            .map(|feature| {
+                let sign = if self.is_enabled(*feature) { '+' } else { '-' };
+                format!("{sign}{}", feature.target_feature())
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Checks that the given module only uses features that are enabled.
+    ///
+    /// Modules that are invalid for another reason pass this check, since the
+    /// engine reports those when the module is compiled.
+    #[cfg(any(feature = "host", feature = "wasmi-host"))]
+    pub fn validate(&self, wasm_module: &[u8]) -> Result<(), DisabledWasmFeatures> {
+        if validate_with(*self, wasm_module) {
+            return Ok(());
+        }
+
+        let all_enabled = WasmFeature::ALL
+            .iter()
+            .fold(*self, |features, feature| features.with(*feature, true));
+        if !validate_with(all_enabled, wasm_module) {
+            return Ok(());
+        }
+
+        // Every feature without which the module doesn't validate is one that
+        // the plugin uses:
+        let features = WasmFeature::ALL
+            .iter()
+            .copied()
+            .filter(|feature| !self.is_enabled(*feature))
+            .filter(|feature| !validate_with(all_enabled.with(*feature, false), wasm_module))
+            .collect();
+        Err(DisabledWasmFeatures { features })
+    }
+}
+
+impl Default for WasmFeatures {
+    fn default() -> Self {
+        Self {
+            simd: true,
+            bulk_memory: true,
+            reference_types: true,
+            threads: false,
+        }
+    }
+}
+
+#[cfg(any(feature = "host", feature = "wasmi-host"))]
+fn validate_with(features: WasmFeatures, wasm_module: &[u8]) -> bool {
+    wasmparser::Validator::new()
+        .wasm_features(wasmparser::WasmFeatures {
+            simd: features.simd,
+            bulk_memory: features.bulk_memory,
+            reference_types: features.reference_types,
+            threads: features.threads,
+            ..Default::default()
+        })
+        .validate_all(wasm_module)
+        .is_ok()
+}
+
+#[cfg(feature = "host")]
+impl From<WasmFeatures> for wasmer::Features {
+    fn from(features: WasmFeatures) -> Self {
+        let mut wasmer_features = wasmer::Features::new();
+        wasmer_features
+            .simd(features.simd)
+            .bulk_memory(features.bulk_memory)
+            .reference_types(features.reference_types)
+            .threads(features.threads);
+        wasmer_features
+    }
+}
+
+/// Wasmi supports neither SIMD nor threads, so only the other features are
+/// carried over to its configuration.
+#[cfg(feature = "wasmi-host")]
+impl From<WasmFeatures> for wasmi::Config {
+    fn from(features: WasmFeatures) -> Self {
+        let mut config = wasmi::Config::default();
+        config
+            .wasm_bulk_memory(features.bulk_memory)
+            .wasm_reference_types(features.reference_types);
+        config
+    }
+}
+
+/// A WebAssembly proposal that can be enabled or disabled for plugins.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WasmFeature {
+    Simd,
+    BulkMemory,
+    ReferenceTypes,
+    Threads,
+}
+
+impl WasmFeature {
+    pub const ALL: [Self; 4] = [
+        Self::Simd,
+        Self::BulkMemory,
+        Self::ReferenceTypes,
+        Self::Threads,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Simd => "simd",
+            Self::BulkMemory => "bulk-memory",
+            Self::ReferenceTypes => "reference-types",
+            Self::Threads => "threads",
+        }
+    }
+
+    /// Returns the name of the `rustc` target feature that makes plugins use
+    /// this proposal.
+    pub fn target_feature(&self) -> &'static str {
+        match self {
+            Self::Simd => "simd128",
+            Self::BulkMemory => "bulk-memory",
+            Self::ReferenceTypes => "reference-types",
+            Self::Threads => "atomics",
+        }
+    }
+}
+
+impl fmt::Display for WasmFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error for a plugin that uses WebAssembly features that are disabled by the
+/// runtime.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisabledWasmFeatures {
+    pub features: Vec<WasmFeature>,
+}
+
+impl fmt::Display for DisabledWasmFeatures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let features = self
+            .features
+            .iter()
+            .map(WasmFeature::as_str)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "plugin uses disabled WebAssembly features: {features}")
+    }
+}
+
+impl std::error::Error for DisabledWasmFeatures {}
@@ -1,10 +1,17 @@
 mod queue;
 pub mod task;
+use super::errors::fail;
 use crate::common::{
+    guest_error::{GuestError, GuestErrorKind},
     mem::{from_fat_ptr, FatPtr},
-    r#async::{AsyncValue, FUTURE_STATUS_PENDING, FUTURE_STATUS_READY},
+    r#async::{
+        AsyncValue, ASYNC_PROTOCOL_VERSION, FUTURE_STATUS_ERROR, FUTURE_STATUS_PENDING,
+        FUTURE_STATUS_READY,
+    },
 };
+use crate::guest::io::export_buffer_to_host;
 use once_cell::unsync::Lazy;
+use std::cell::Cell;
 use std::collections::BTreeMap;
 use std::future::Future;
 use std::ptr::{read_volatile, write_volatile};
@@ -12,6 +19,13 @@ use std::task::{Context, Poll, Waker};
 
 static mut WAKERS: Lazy<BTreeMap<FatPtr, Waker>> = Lazy::new(BTreeMap::new);
 
+thread_local! {
+    /// The version of the async value protocol that was agreed on with the
+    /// host. Hosts that never call `__fp_guest_async_protocol()` only support
+    /// version 1.
+    static ASYNC_PROTOCOL: Cell<u32> = const { Cell::new(1) };
+}
+
 /// Represents a future value that will be resolved by the host runtime.
 pub struct HostFuture {
     ptr: FatPtr,
@@ -44,7 +58,10 @@ impl Future for HostFuture {
                 Poll::Pending
             }
             FUTURE_STATUS_READY => Poll::Ready(async_value.buffer_ptr()),
-            status => panic!("Unexpected status: {}", status),
+            status => fail(
+                GuestErrorKind::InvalidPointer,
+                format!("Unexpected status: {}", status),
+            ),
         }
     }
 }
@@ -69,6 +86,52 @@ pub unsafe fn __fp_guest_resolve_async_value(async_value_fat_ptr: FatPtr, result
     }
 }
 
+/// Called by the host to announce the latest version of the async value
+/// protocol that it supports. Returns the latest version that is supported by
+/// the plugin.
+#[doc(hidden)]
+#[no_mangle]
+pub fn __fp_guest_async_protocol(host_version: u32) -> u32 {
+    ASYNC_PROTOCOL.with(|version| version.set(host_version.min(ASYNC_PROTOCOL_VERSION)));
+    ASYNC_PROTOCOL_VERSION
+}
+
+/// Marks the async value of the task that is currently running as failed with
+/// the given error, and passes it to the host, so that the host doesn't wait
+/// for a result that never comes.
+///
+/// This does nothing if the host doesn't support version 2 of the protocol,
+/// or if no task that returns an async value is running.
+pub(crate) fn reject_current_async_value(error: &GuestError) {
+    if ASYNC_PROTOCOL.with(Cell::get) < 2 {
+        return;
+    }
+    let async_value_fat_ptr = match task::current_async_value() {
+        Some(async_value_fat_ptr) => async_value_fat_ptr,
+        None => return,
+    };
+
+    // The error is serialized by hand, since the regular glue code could fail
+    // again:
+    let error_ptr = match rmp_serde::to_vec_named(error) {
+        Ok(buffer) => export_buffer_to_host(buffer),
+        Err(_) => return,
+    };
+    let (ptr, len) = from_fat_ptr(error_ptr);
+    let (async_value_ptr, _) = from_fat_ptr(async_value_fat_ptr);
+    unsafe {
+        write_volatile(
+            async_value_ptr as *mut AsyncValue,
+            AsyncValue {
+                status: FUTURE_STATUS_ERROR,
+                ptr: ptr as u32,
+                len,
+            },
+        );
+    }
+    host_resolve_async_value(async_value_fat_ptr, error_ptr);
+}
+
 #[link(wasm_import_module = "fp")]
 extern "C" {
     fn __fp_host_resolve_async_value(async_value_ptr: FatPtr, result_ptr: FatPtr);
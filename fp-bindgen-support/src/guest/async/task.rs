@@ -30,13 +30,43 @@ pub struct Task {
 
     // This is used to ensure that the Task will only be queued once
     is_queued: Cell<bool>,
+
+    // The async value through which the result of the Task is passed to the
+    // host, if any, so that failures can be reported through it as well.
+    async_value: Option<FatPtr>,
+
+    // The deadline of the call that spawned the Task, which is the current
+    // deadline while the Task is running.
+    #[cfg(feature = "deadline")]
+    deadline: u64,
+}
+
+thread_local! {
+    // The async value of the Task that is currently running, if any.
+    static CURRENT_ASYNC_VALUE: Cell<Option<FatPtr>> = const { Cell::new(None) };
+}
+
+/// Returns the async value of the Task that is currently running, if it
+/// passes its result to the host.
+pub(crate) fn current_async_value() -> Option<FatPtr> {
+    CURRENT_ASYNC_VALUE.with(Cell::get)
 }
 
 impl Task {
     pub fn spawn(future: Pin<Box<dyn Future<Output = ()> + 'static>>) {
+        Task::spawn_with_async_value(future, None)
+    }
+
+    fn spawn_with_async_value(
+        future: Pin<Box<dyn Future<Output = ()> + 'static>>,
+        async_value: Option<FatPtr>,
+    ) {
         let this = Rc::new(Self {
             inner: RefCell::new(None),
             is_queued: Cell::new(false),
+            async_value,
+            #[cfg(feature = "deadline")]
+            deadline: crate::guest::deadline::current(),
         });
 
         let waker = unsafe { Waker::from_raw(Task::into_raw_waker(Rc::clone(&this))) };
@@ -56,11 +86,14 @@ impl Task {
         let ptr = unsafe { std::alloc::alloc_zeroed(layout) };
         let fat_ptr = to_fat_ptr(ptr, len);
 
-        Task::spawn(Box::pin(async move {
-            let ret = future.await;
-            let result_ptr = export_value_to_host(&ret);
-            host_resolve_async_value(fat_ptr, result_ptr);
-        }));
+        Task::spawn_with_async_value(
+            Box::pin(async move {
+                let ret = future.await;
+                let result_ptr = export_value_to_host(&ret);
+                host_resolve_async_value(fat_ptr, result_ptr);
+            }),
+            Some(fat_ptr),
+        );
 
         fat_ptr
     }
@@ -123,11 +156,22 @@ impl Task {
         // the run queue.
         self.is_queued.set(false);
 
+        #[cfg(feature = "deadline")]
+        let previous_deadline = crate::guest::deadline::replace(self.deadline);
+
+        let previous_async_value =
+            CURRENT_ASYNC_VALUE.with(|current| current.replace(self.async_value));
+
         let poll = {
             let mut cx = Context::from_waker(&inner.waker);
             inner.future.as_mut().poll(&mut cx)
         };
 
+        CURRENT_ASYNC_VALUE.with(|current| current.set(previous_async_value));
+
+        #[cfg(feature = "deadline")]
+        crate::guest::deadline::replace(previous_deadline);
+
         // If a future has finished (`Ready`) then clean up resources associated
         // with the future ASAP. This ensures that we don't keep anything extra
         // alive in-memory by accident. Our own struct, `Rc<Task>` won't
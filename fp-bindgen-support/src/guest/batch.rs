@@ -0,0 +1,129 @@
+use super::{errors::fail, io::export_buffer_to_host};
+use crate::common::{guest_error::GuestErrorKind, mem::FatPtr};
+use rmp_serde::Serializer;
+use serde::{ser::SerializeMap, Serialize};
+use std::cell::RefCell;
+
+#[link(wasm_import_module = "fp")]
+extern "C" {
+    fn __fp_host_batch(calls: FatPtr);
+}
+
+thread_local! {
+    static BATCH: RefCell<Option<Batch>> = const { RefCell::new(None) };
+}
+
+/// The calls that are queued while a batch is active.
+///
+/// Calls are serialized as soon as they are queued, so that the whole batch
+/// can be passed to the host as a single MessagePack array of
+/// `{ function: args }` maps without serializing them again.
+#[derive(Default)]
+struct Batch {
+    len: u32,
+    buffer: Vec<u8>,
+}
+
+struct BatchedCall<'a, T> {
+    function: &'a str,
+    args: &'a T,
+}
+
+impl<T: Serialize> Serialize for BatchedCall<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(self.function, self.args)?;
+        map.end()
+    }
+}
+
+/// Runs the given function, while queueing the calls it makes to batchable
+/// imports. Once the function returns, the queued calls are sent to the host
+/// in a single call.
+///
+/// Imports that are not marked with `#[fp(batchable)]` are still called
+/// immediately, so the host may observe them before calls that were queued
+/// earlier. Nested batches are merged into the outermost batch.
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    let is_outermost = BATCH.with(|batch| {
+        let mut batch = batch.borrow_mut();
+        if batch.is_some() {
+            false
+        } else {
+            *batch = Some(Batch::default());
+            true
+        }
+    });
+
+    let result = f();
+
+    if is_outermost {
+        if let Some(batch) = BATCH.with(|batch| batch.borrow_mut().take()) {
+            flush(batch);
+        }
+    }
+
+    result
+}
+
+#[doc(hidden)]
+pub fn is_batching() -> bool {
+    BATCH.with(|batch| batch.borrow().is_some())
+}
+
+#[doc(hidden)]
+pub fn queue_call<T: Serialize>(function: &str, args: &T) {
+    BATCH.with(|batch| {
+        let mut batch = batch.borrow_mut();
+        let batch = batch
+            .as_mut()
+            .unwrap_or_else(|| fail(GuestErrorKind::Internal, "No batch is active"));
+        let serialize = |buffer: &mut Vec<u8>| {
+            BatchedCall { function, args }.serialize(
+                &mut Serializer::new(buffer)
+                    .with_struct_map()
+                    .with_human_readable(),
+            )
+        };
+
+        #[cfg(feature = "interning")]
+        let result = super::interning::serialize_for_host(|| serialize(&mut batch.buffer));
+        #[cfg(not(feature = "interning"))]
+        let result = serialize(&mut batch.buffer);
+
+        result.unwrap_or_else(|error| {
+            fail(
+                GuestErrorKind::Serialization,
+                format!("Serialization error: {}", error),
+            )
+        });
+        batch.len += 1;
+    });
+}
+
+fn flush(batch: Batch) {
+    if batch.len == 0 {
+        return;
+    }
+
+    let mut buffer = Vec::with_capacity(batch.buffer.len() + 5);
+    write_array_len(&mut buffer, batch.len);
+    buffer.extend_from_slice(&batch.buffer);
+
+    unsafe { __fp_host_batch(export_buffer_to_host(buffer)) };
+}
+
+/// Writes the header of a MessagePack array with the given length.
+fn write_array_len(buffer: &mut Vec<u8>, len: u32) {
+    match len {
+        0..=15 => buffer.push(0x90 | len as u8),
+        16..=0xffff => {
+            buffer.push(0xdc);
+            buffer.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        _ => {
+            buffer.push(0xdd);
+            buffer.extend_from_slice(&len.to_be_bytes());
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Caching of the config that the host passes to the plugin.
+//!
+//! Plugin bindings for protocols whose `init` export takes a config argument
+//! store that config before the plugin's own `init` is called, and replace it
+//! whenever the host calls the `update_config` export. This saves protocols
+//! from declaring imports through which plugins fetch their config.
+//!
+//! The config is stored per thread, so plugins that spawn threads should pass
+//! it to them explicitly.
+
+use super::io::import_value_from_host;
+use crate::common::mem::FatPtr;
+use serde::de::DeserializeOwned;
+use std::{
+    any::{type_name, Any},
+    cell::RefCell,
+    mem,
+    rc::Rc,
+};
+
+type Listener = Box<dyn Fn(&dyn Any)>;
+
+thread_local! {
+    static CONFIG: RefCell<Option<Rc<dyn Any>>> = RefCell::new(None);
+    static LISTENERS: RefCell<Vec<Listener>> = RefCell::new(Vec::new());
+}
+
+/// Returns the config that the host passed to the plugin, or `None` if the
+/// plugin has not been initialized yet.
+///
+/// Panics if `T` is not the type of the config argument of the protocol's
+/// `init` export.
+pub fn fp_config<T: 'static>() -> Option<Rc<T>> {
+    let config = CONFIG.with(|config| config.borrow().clone())?;
+    Some(config.downcast::<T>().unwrap_or_else(|_| {
+        panic!(
+            "The config was requested as `{}`, which is not the config type of the protocol",
+            type_name::<T>()
+        )
+    }))
+}
+
+/// Registers a listener that is called with the new config whenever the host
+/// updates it. Listeners are not called for the config that is passed to
+/// `init`.
+///
+/// Listeners for another type than the config type of the protocol are never
+/// called.
+pub fn on_config_change<T: 'static>(listener: impl Fn(&T) + 'static) {
+    let listener: Listener = Box::new(move |config| {
+        if let Some(config) = config.downcast_ref::<T>() {
+            listener(config)
+        }
+    });
+    LISTENERS.with(|listeners| listeners.borrow_mut().push(listener));
+}
+
+/// Stores the config that is passed to `init`.
+#[doc(hidden)]
+pub fn set_config<T: 'static>(config: T) {
+    CONFIG.with(|cached| *cached.borrow_mut() = Some(Rc::new(config)));
+}
+
+/// Stores the config that is passed to `update_config`, and notifies the
+/// listeners of the change.
+///
+/// # Safety
+///
+/// The pointer must point to a serialized config that was allocated by
+/// `__fp_malloc()`, and is not used afterwards.
+#[doc(hidden)]
+pub unsafe fn update_config_from_host<T: DeserializeOwned + 'static>(config_ptr: FatPtr) {
+    let config: Rc<T> = Rc::new(import_value_from_host(config_ptr));
+    CONFIG.with(|cached| *cached.borrow_mut() = Some(config.clone()));
+
+    // The listeners are taken out while they are called, so that they may
+    // register further listeners:
+    let listeners = LISTENERS.with(|listeners| mem::take(&mut *listeners.borrow_mut()));
+    for listener in &listeners {
+        listener(&*config);
+    }
+    LISTENERS.with(|registered| {
+        let mut registered = registered.borrow_mut();
+        let added = mem::replace(&mut *registered, listeners);
+        registered.extend(added);
+    });
+}
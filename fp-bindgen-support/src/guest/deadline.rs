@@ -0,0 +1,78 @@
+use std::cell::Cell;
+use std::time::Duration;
+
+#[link(wasm_import_module = "fp")]
+extern "C" {
+    fn __fp_host_deadline_remaining(deadline: u64) -> u64;
+}
+
+thread_local! {
+    /// The deadline of the call or task that is currently running, in
+    /// milliseconds since the Unix epoch, or 0 if there is none.
+    static CURRENT_DEADLINE: Cell<u64> = const { Cell::new(0) };
+}
+
+/// The deadline that the host has set for a call to the plugin.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Deadline {
+    since_unix_epoch: Duration,
+}
+
+impl Deadline {
+    /// Returns the deadline as the duration since the Unix epoch.
+    pub fn since_unix_epoch(self) -> Duration {
+        self.since_unix_epoch
+    }
+
+    /// Returns the time that remains until the deadline, according to the
+    /// clock of the runtime. Returns zero once the deadline has passed.
+    ///
+    /// This requires runtime bindings that are generated with deadline
+    /// support, or the plugin fails to instantiate.
+    pub fn remaining(self) -> Duration {
+        let deadline = self.since_unix_epoch.as_millis() as u64;
+        Duration::from_millis(unsafe { __fp_host_deadline_remaining(deadline) })
+    }
+
+    /// Returns whether the deadline has passed, in which case the host is no
+    /// longer waiting for the result of the call.
+    pub fn is_exceeded(self) -> bool {
+        self.remaining().is_zero()
+    }
+}
+
+/// Returns the deadline of the call that is currently being handled by the
+/// plugin, if the host has set one.
+///
+/// Async exports keep the deadline of the call that started them, and calls
+/// to imports pass it back to the host, so that the host's implementations of
+/// the imports can respect it as well.
+pub fn fp_deadline() -> Option<Deadline> {
+    match current() {
+        0 => None,
+        deadline => Some(Deadline {
+            since_unix_epoch: Duration::from_millis(deadline),
+        }),
+    }
+}
+
+pub(crate) fn current() -> u64 {
+    CURRENT_DEADLINE.with(Cell::get)
+}
+
+/// Sets the current deadline, returning the previous one.
+pub(crate) fn replace(deadline: u64) -> u64 {
+    CURRENT_DEADLINE.with(|current| current.replace(deadline))
+}
+
+#[doc(hidden)]
+#[no_mangle]
+pub fn __fp_guest_set_deadline(deadline: u64) {
+    replace(deadline);
+}
+
+#[doc(hidden)]
+#[no_mangle]
+pub fn __fp_guest_deadline() -> u64 {
+    current()
+}
@@ -0,0 +1,76 @@
+//! Handling of failures in the glue code of plugins.
+//!
+//! By default, the glue code panics when something goes wrong, for instance
+//! when a value from the host cannot be deserialized. With the `panic-abort`
+//! feature, it reports a structured `GuestError` to the host instead, and
+//! aborts without unwinding. This way, plugins that are compiled with
+//! `panic = "abort"` fail with a diagnosable error, rather than an opaque
+//! trap.
+
+use crate::common::guest_error::{GuestError, GuestErrorKind};
+#[cfg(feature = "panic-abort")]
+use crate::common::mem::FatPtr;
+
+#[cfg(feature = "panic-abort")]
+#[link(wasm_import_module = "fp")]
+extern "C" {
+    fn __fp_error(report: FatPtr);
+}
+
+/// Fails with an error of the given kind.
+///
+/// With the `panic-abort` feature, the error is reported to the host before
+/// the plugin aborts. Otherwise, this panics with the message of the error.
+///
+/// If this happens inside an async export, and the host supports version 2 of
+/// the async value protocol, the error is passed to the host through the
+/// export's async value as well.
+pub(crate) fn fail(kind: GuestErrorKind, message: impl Into<String>) -> ! {
+    let error = GuestError {
+        kind,
+        message: message.into(),
+    };
+
+    #[cfg(feature = "async")]
+    super::r#async::reject_current_async_value(&error);
+
+    #[cfg(feature = "panic-abort")]
+    report_and_abort(&error);
+
+    #[cfg(not(feature = "panic-abort"))]
+    panic!("{}", error.message);
+}
+
+/// Installs a panic hook that reports panics in the plugin to the host, as a
+/// `GuestError` of the `Panic` kind.
+///
+/// Plugins that are compiled with `panic = "abort"` should call this early,
+/// for instance from their `init()` export, so that their own panics are
+/// diagnosable as well.
+#[cfg(feature = "panic-abort")]
+pub fn set_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        let error = GuestError {
+            kind: GuestErrorKind::Panic,
+            message: info.to_string(),
+        };
+
+        #[cfg(feature = "async")]
+        super::r#async::reject_current_async_value(&error);
+
+        report_and_abort(&error)
+    }));
+}
+
+#[cfg(feature = "panic-abort")]
+fn report_and_abort(error: &GuestError) -> ! {
+    // The report is serialized by hand, since the regular glue code could fail
+    // again:
+    if let Ok(buffer) = rmp_serde::to_vec_named(error) {
+        unsafe { __fp_error(super::io::export_buffer_to_host(buffer)) };
+    }
+
+    // Hosts trap the plugin from `__fp_error()`, so this is only reached if
+    // the report could not be sent:
+    std::process::abort()
+}
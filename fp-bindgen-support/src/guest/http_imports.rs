@@ -0,0 +1,23 @@
+use super::io::{export_value_to_host, import_value_from_host};
+use super::r#async::HostFuture;
+use crate::common::mem::FatPtr;
+
+pub use crate::common::http_imports::{HttpError, HttpRequest, HttpResponse};
+
+#[link(wasm_import_module = "fp")]
+extern "C" {
+    fn __fp_http_request(request: FatPtr) -> FatPtr;
+}
+
+/// Sends the given request using the HTTP client of the runtime, and returns
+/// its response.
+///
+/// Responses with an error status are returned as an `HttpResponse`, while an
+/// `HttpError` is returned if no response was received at all. This requires
+/// the `http` capability.
+pub async fn http_request(request: &HttpRequest) -> Result<HttpResponse, HttpError> {
+    unsafe {
+        let async_value_ptr = __fp_http_request(export_value_to_host(request));
+        import_value_from_host(HostFuture::new(async_value_ptr).await)
+    }
+}
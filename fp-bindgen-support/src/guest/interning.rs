@@ -0,0 +1,50 @@
+use super::io::export_value_to_host;
+use crate::common::mem::FatPtr;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
+
+#[link(wasm_import_module = "fp")]
+extern "C" {
+    fn __fp_intern_string(value: FatPtr) -> u32;
+}
+
+thread_local! {
+    /// The handles that the host assigned to the strings that were registered
+    /// with it.
+    static HANDLES: RefCell<HashMap<String, u32>> = RefCell::new(HashMap::new());
+
+    /// Whether a value is being serialized for the host, in which case the
+    /// strings of interned fields are replaced with their handles.
+    static SERIALIZING_FOR_HOST: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs the given function, which serializes a value for the host, so that
+/// interned strings are replaced with their handles.
+///
+/// Values that are serialized outside of this function, for instance to store
+/// them as JSON, keep their strings.
+pub(crate) fn serialize_for_host<T>(serialize: impl FnOnce() -> T) -> T {
+    let was_serializing = SERIALIZING_FOR_HOST.with(|flag| flag.replace(true));
+    let result = serialize();
+    SERIALIZING_FOR_HOST.with(|flag| flag.set(was_serializing));
+    result
+}
+
+/// Returns the handle of the given string, registering it with the host if
+/// this is the first time it is sent, or `None` if the value is not being
+/// serialized for the host.
+pub(crate) fn handle_for_host(value: &str) -> Option<u32> {
+    if !SERIALIZING_FOR_HOST.with(Cell::get) {
+        return None;
+    }
+
+    if let Some(handle) = HANDLES.with(|handles| handles.borrow().get(value).copied()) {
+        return Some(handle);
+    }
+
+    let handle = unsafe { __fp_intern_string(export_value_to_host(&value)) };
+    HANDLES.with(|handles| handles.borrow_mut().insert(value.to_owned(), handle));
+    Some(handle)
+}
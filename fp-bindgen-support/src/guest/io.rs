@@ -1,4 +1,5 @@
-use crate::common::mem::*;
+use super::errors::fail;
+use crate::common::{guest_error::GuestErrorKind, mem::*};
 use rmp_serde::{Deserializer, Serializer};
 use serde::{Deserialize, Serialize};
 use std::alloc::Layout;
@@ -6,13 +7,35 @@ use std::alloc::Layout;
 #[doc(hidden)]
 pub fn export_value_to_host<T: Serialize>(value: &T) -> FatPtr {
     let mut buffer = Vec::new();
-    value
-        .serialize(
-            &mut Serializer::new(&mut buffer)
+    let serialize = |buffer: &mut Vec<u8>| {
+        value.serialize(
+            &mut Serializer::new(buffer)
                 .with_struct_map()
                 .with_human_readable(),
         )
-        .expect("Serialization error");
+    };
+
+    #[cfg(feature = "interning")]
+    let result = super::interning::serialize_for_host(|| serialize(&mut buffer));
+    #[cfg(not(feature = "interning"))]
+    let result = serialize(&mut buffer);
+
+    result.unwrap_or_else(|error| {
+        fail(
+            GuestErrorKind::Serialization,
+            format!("Serialization error: {}", error),
+        )
+    });
+
+    export_buffer_to_host(buffer)
+}
+
+/// Passes ownership of an already serialized buffer to the host.
+pub(crate) fn export_buffer_to_host(buffer: Vec<u8>) -> FatPtr {
+    #[cfg(feature = "compression")]
+    let mut buffer = crate::common::compression::compress(buffer);
+    #[cfg(not(feature = "compression"))]
+    let mut buffer = buffer;
 
     let len = buffer.len();
 
@@ -33,7 +56,10 @@ pub fn export_value_to_host<T: Serialize>(value: &T) -> FatPtr {
 
     // Make sure the length marker does not run into our extension bits:
     if len & 0xff000000 != 0 {
-        panic!("Buffer too large ({} bytes)", len);
+        fail(
+            GuestErrorKind::Allocation,
+            format!("Buffer too large ({} bytes)", len),
+        );
     }
 
     let ptr = buffer.as_ptr();
@@ -49,12 +75,28 @@ pub fn export_value_to_host<T: Serialize>(value: &T) -> FatPtr {
 pub unsafe fn import_value_from_host<'de, T: Deserialize<'de>>(fat_ptr: FatPtr) -> T {
     let (ptr, len) = from_fat_ptr(fat_ptr);
     if len & 0xff000000 != 0 {
-        panic!("Unknown extension bits");
+        fail(GuestErrorKind::InvalidPointer, "Unknown extension bits");
     }
 
     let slice = std::slice::from_raw_parts(ptr, len as usize);
+
+    #[cfg(feature = "compression")]
+    let slice = crate::common::compression::try_decompress(slice).unwrap_or_else(|error| {
+        fail(
+            GuestErrorKind::Deserialization,
+            format!("Decompression error: {}", error),
+        )
+    });
+    #[cfg(feature = "compression")]
+    let slice = slice.as_ref();
+
     let mut deserializer = Deserializer::new(slice).with_human_readable();
-    let value = T::deserialize(&mut deserializer).unwrap();
+    let value = T::deserialize(&mut deserializer).unwrap_or_else(|error| {
+        fail(
+            GuestErrorKind::Deserialization,
+            format!("Deserialization error: {}", error),
+        )
+    });
 
     __fp_free(fat_ptr);
 
@@ -63,16 +105,24 @@ pub unsafe fn import_value_from_host<'de, T: Deserialize<'de>>(fat_ptr: FatPtr)
 
 const MALLOC_ALIGNMENT: usize = 16;
 
+/// Allocates memory on behalf of the host.
+///
+/// Returns a null pointer if the memory could not be allocated, so that the
+/// runtime can fail the invocation with an `AllocationFailed` error, instead
+/// of the plugin trapping.
 #[doc(hidden)]
 #[no_mangle]
 pub fn __fp_malloc(len: u32) -> FatPtr {
-    let ptr = unsafe {
-        std::alloc::alloc(
-            Layout::from_size_align(len as usize, MALLOC_ALIGNMENT)
-                .expect("Allocation failed unexpectedly, check requested allocation size"),
-        )
+    let layout = match Layout::from_size_align(len as usize, MALLOC_ALIGNMENT) {
+        Ok(layout) => layout,
+        Err(_) => return 0,
     };
-    to_fat_ptr(ptr, len)
+    let ptr = unsafe { std::alloc::alloc(layout) };
+    if ptr.is_null() {
+        0
+    } else {
+        to_fat_ptr(ptr, len)
+    }
 }
 
 /// # Safety
@@ -89,15 +139,18 @@ pub fn __fp_malloc(len: u32) -> FatPtr {
 pub unsafe fn __fp_free(ptr: FatPtr) {
     let (ptr, len) = from_fat_ptr(ptr);
 
-    assert_eq!(
-        len & 0xff000000,
-        0,
-        "__fp_free() failed: unknown extension bits"
-    );
-
-    std::alloc::dealloc(
-        ptr as *mut u8,
-        Layout::from_size_align(len as usize, MALLOC_ALIGNMENT)
-            .expect("Deallocation failed unexpectedly, check the pointer is valid"),
-    );
+    if len & 0xff000000 != 0 {
+        fail(
+            GuestErrorKind::InvalidPointer,
+            "__fp_free() failed: unknown extension bits",
+        );
+    }
+
+    let layout = Layout::from_size_align(len as usize, MALLOC_ALIGNMENT).unwrap_or_else(|_| {
+        fail(
+            GuestErrorKind::Allocation,
+            "Deallocation failed unexpectedly, check the pointer is valid",
+        )
+    });
+    std::alloc::dealloc(ptr as *mut u8, layout);
 }
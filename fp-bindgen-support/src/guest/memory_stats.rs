@@ -0,0 +1,79 @@
+use super::io::export_value_to_host;
+use crate::common::mem::{FatPtr, MemoryStats};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator that keeps track of the plugin's memory usage, so it can
+/// be reported to the host through `__fp_memory_stats()`.
+struct StatsAllocator;
+
+impl StatsAllocator {
+    fn record_allocation(&self, size: usize) {
+        self.record_growth(size);
+        ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_growth(&self, size: usize) {
+        let allocated = ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_ALLOCATED_BYTES.fetch_max(allocated, Ordering::Relaxed);
+    }
+
+    fn record_deallocation(&self, size: usize) {
+        ALLOCATED_BYTES.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for StatsAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // Reallocations are not counted as new allocations:
+            self.record_deallocation(layout.size());
+            self.record_growth(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: StatsAllocator = StatsAllocator;
+
+/// Returns statistics about the memory that is allocated by the plugin.
+pub fn memory_stats() -> MemoryStats {
+    MemoryStats {
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) as u64,
+        peak_allocated_bytes: PEAK_ALLOCATED_BYTES.load(Ordering::Relaxed) as u64,
+        allocation_count: ALLOCATION_COUNT.load(Ordering::Relaxed) as u64,
+    }
+}
+
+#[doc(hidden)]
+#[no_mangle]
+pub fn __fp_memory_stats() -> FatPtr {
+    export_value_to_host(&memory_stats())
+}
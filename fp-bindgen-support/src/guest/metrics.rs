@@ -0,0 +1,64 @@
+use super::{io::export_value_to_host, std_imports::Instant};
+use crate::common::mem::{FatPtr, GuestMetrics, ImportMetrics};
+use std::{cell::RefCell, collections::BTreeMap};
+
+thread_local! {
+    static IMPORT_METRICS: RefCell<BTreeMap<&'static str, ImportMetrics>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Measures a single call to an import, which is recorded once the guard is
+/// dropped.
+///
+/// Guards are created by the import functions of plugin bindings that are
+/// generated with guest metrics enabled.
+#[doc(hidden)]
+pub struct ImportCallGuard {
+    name: &'static str,
+    start: Instant,
+}
+
+impl ImportCallGuard {
+    pub fn start(name: &'static str) -> Self {
+        Self {
+            name,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for ImportCallGuard {
+    fn drop(&mut self) {
+        let nanos = Instant::now().duration_since(self.start).as_nanos() as u64;
+        IMPORT_METRICS.with(|metrics| {
+            let mut metrics = metrics.borrow_mut();
+            let import = metrics.entry(self.name).or_default();
+            import.call_count += 1;
+            import.total_duration_nanos += nanos;
+            import.max_duration_nanos = import.max_duration_nanos.max(nanos);
+        });
+    }
+}
+
+/// Returns the metrics of the calls that the plugin has made to imports so
+/// far.
+pub fn guest_metrics() -> GuestMetrics {
+    IMPORT_METRICS.with(|metrics| GuestMetrics {
+        imports: metrics
+            .borrow()
+            .iter()
+            .map(|(name, import)| ((*name).to_owned(), *import))
+            .collect(),
+    })
+}
+
+/// Clears the metrics that have been recorded so far.
+pub fn reset_guest_metrics() {
+    IMPORT_METRICS.with(|metrics| metrics.borrow_mut().clear());
+}
+
+#[doc(hidden)]
+#[no_mangle]
+pub fn __fp_get_guest_metrics() -> FatPtr {
+    export_value_to_host(&guest_metrics())
+}
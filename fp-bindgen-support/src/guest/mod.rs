@@ -1,3 +1,25 @@
 #[cfg(feature = "async")]
 pub mod r#async;
+pub mod batch;
+#[cfg(feature = "config")]
+pub mod config;
+#[cfg(feature = "deadline")]
+pub mod deadline;
+pub mod errors;
+#[cfg(feature = "http-imports")]
+pub mod http_imports;
+#[cfg(feature = "interning")]
+pub mod interning;
 pub mod io;
+#[cfg(feature = "memory-stats")]
+pub mod memory_stats;
+#[cfg(feature = "guest-metrics")]
+pub mod metrics;
+#[cfg(feature = "std-imports")]
+pub mod std_imports;
+#[cfg(feature = "stdio")]
+pub mod stdio;
+#[cfg(feature = "threads")]
+pub mod threads;
+#[cfg(feature = "trace-context")]
+pub mod trace_context;
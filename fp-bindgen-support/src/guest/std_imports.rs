@@ -0,0 +1,140 @@
+use super::io::{export_value_to_host, import_value_from_host};
+use crate::common::{mem::FatPtr, std_imports::FileError};
+use serde_bytes::{ByteBuf, Bytes};
+use std::ops::{Add, Sub};
+use std::time::Duration;
+
+#[link(wasm_import_module = "fp")]
+extern "C" {
+    fn __fp_std_read_file(path: FatPtr) -> FatPtr;
+
+    fn __fp_std_write_file(path: FatPtr, contents: FatPtr) -> FatPtr;
+
+    fn __fp_std_now() -> u64;
+
+    fn __fp_std_monotonic_now() -> u64;
+
+    fn __fp_std_random_bytes(len: u32) -> FatPtr;
+}
+
+/// Reads the file at the given path from the virtual file system of the
+/// runtime.
+///
+/// Paths are relative to the root of the virtual file system, and may not
+/// contain `..` components. This requires the `fs` capability.
+pub fn read_file(path: &str) -> Result<Vec<u8>, FileError> {
+    let result: Result<ByteBuf, FileError> =
+        unsafe { import_value_from_host(__fp_std_read_file(export_value_to_host(&path))) };
+    result.map(ByteBuf::into_vec)
+}
+
+/// Writes the given contents to the file at the given path in the virtual
+/// file system of the runtime, replacing the file if it already exists.
+///
+/// Paths are relative to the root of the virtual file system, and may not
+/// contain `..` components. This requires the `fs` capability.
+pub fn write_file(path: &str, contents: &[u8]) -> Result<(), FileError> {
+    unsafe {
+        import_value_from_host(__fp_std_write_file(
+            export_value_to_host(&path),
+            export_value_to_host(&Bytes::new(contents)),
+        ))
+    }
+}
+
+/// Returns the time of the runtime's clock, as the duration since the Unix
+/// epoch.
+///
+/// This requires the `clock` capability.
+pub fn now() -> Duration {
+    Duration::from_millis(unsafe { __fp_std_now() })
+}
+
+/// Returns the time of the runtime's monotonic clock, which can be used to
+/// measure elapsed time.
+///
+/// Unlike the clock of [`now()`], the monotonic clock never goes backwards.
+/// This requires the `clock` capability.
+pub fn fp_now() -> Instant {
+    Instant(Duration::from_nanos(unsafe { __fp_std_monotonic_now() }))
+}
+
+/// A measurement of the runtime's monotonic clock, which takes the place of
+/// `std::time::Instant`, since that is not available on
+/// `wasm32-unknown-unknown`.
+///
+/// Instants are only comparable with other instants of the same runtime.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Instant(Duration);
+
+impl Instant {
+    /// Returns the current time of the runtime's monotonic clock. See
+    /// [`fp_now()`].
+    pub fn now() -> Self {
+        fp_now()
+    }
+
+    /// Returns the time that has passed since the given instant, or zero if
+    /// the given instant is later than this one.
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Returns the time that has passed since the given instant, or `None` if
+    /// the given instant is later than this one.
+    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        self.0.checked_sub(earlier.0)
+    }
+
+    /// Returns the time that has passed since this instant.
+    pub fn elapsed(&self) -> Duration {
+        fp_now().duration_since(*self)
+    }
+
+    /// Returns the instant that is the given duration later, unless that
+    /// cannot be represented.
+    pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+        self.0.checked_add(duration).map(Instant)
+    }
+
+    /// Returns the instant that is the given duration earlier, unless that
+    /// lies before the origin of the clock.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+        self.0.checked_sub(duration).map(Instant)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, duration: Duration) -> Instant {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, duration: Duration) -> Instant {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    fn sub(self, earlier: Instant) -> Duration {
+        self.duration_since(earlier)
+    }
+}
+
+/// Returns the given number of random bytes, as they are generated by the
+/// runtime.
+///
+/// This requires the `random` capability.
+pub fn random_bytes(len: u32) -> Vec<u8> {
+    let bytes: ByteBuf = unsafe { import_value_from_host(__fp_std_random_bytes(len)) };
+    bytes.into_vec()
+}
@@ -0,0 +1,54 @@
+use super::io::export_value_to_host;
+use crate::common::{mem::FatPtr, stdio::OutputStream};
+
+#[link(wasm_import_module = "fp")]
+extern "C" {
+    fn __fp_host_print(stream: u32, line: FatPtr);
+}
+
+/// Passes a line of output to the runtime, which hands it to the output
+/// handler of the host.
+///
+/// This requires runtime bindings that are generated with stdio capture, or
+/// the plugin fails to instantiate. Plugins usually call this through the
+/// `println!()` and `eprintln!()` macros of this crate.
+pub fn print_to_host(stream: OutputStream, line: &str) {
+    unsafe { __fp_host_print(stream.fd(), export_value_to_host(&line)) }
+}
+
+/// Prints a line to the stdout of the host, like `std::println!()`.
+///
+/// Plugins that are compiled for `wasm32-unknown-unknown` have no stdout, so
+/// this macro can be imported to shadow the one from `std`:
+///
+/// ```ignore
+/// use fp_bindgen_support::{eprintln, println};
+/// ```
+#[macro_export]
+macro_rules! println {
+    () => {
+        $crate::guest::stdio::print_to_host($crate::common::stdio::OutputStream::Stdout, "")
+    };
+    ($($arg:tt)*) => {
+        $crate::guest::stdio::print_to_host(
+            $crate::common::stdio::OutputStream::Stdout,
+            &::std::format!($($arg)*),
+        )
+    };
+}
+
+/// Prints a line to the stderr of the host, like `std::eprintln!()`.
+///
+/// See [`println!`] for details.
+#[macro_export]
+macro_rules! eprintln {
+    () => {
+        $crate::guest::stdio::print_to_host($crate::common::stdio::OutputStream::Stderr, "")
+    };
+    ($($arg:tt)*) => {
+        $crate::guest::stdio::print_to_host(
+            $crate::common::stdio::OutputStream::Stderr,
+            &::std::format!($($arg)*),
+        )
+    };
+}
@@ -0,0 +1,50 @@
+use crate::common::{
+    mem::{from_fat_ptr, FatPtr},
+    r#async::{AsyncValue, FUTURE_STATUS_PENDING},
+};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Blocks the current thread until the host has resolved the given async
+/// value, and returns the pointer to its result.
+///
+/// Unlike awaiting a `HostFuture`, this doesn't require an executor, so it can
+/// be used by threads that a plugin spawns to process data in parallel. The
+/// runtime needs to share its memory with the plugin, such as the TypeScript
+/// runtime that is generated with `with_shared_memory()`, which wakes up the
+/// blocked threads when it resolves an async value. Note that browsers don't
+/// allow their main thread to block.
+///
+/// # Safety
+///
+/// This function is only safe if passed a valid pointer to an `AsyncValue`
+/// created by the host, of which the result is not claimed by anyone else.
+pub unsafe fn wait_for_async_value(async_value_ptr: FatPtr) -> FatPtr {
+    let (ptr, _) = from_fat_ptr(async_value_ptr);
+    // The status is the first field of the async value:
+    let status = &*(ptr as *const AtomicU32);
+    while status.load(Ordering::Acquire) == FUTURE_STATUS_PENDING {
+        wait(status, FUTURE_STATUS_PENDING);
+    }
+
+    (*(ptr as *const AsyncValue)).buffer_ptr()
+}
+
+/// Waits until the host notifies that the status has changed, unless it
+/// already differs from the expected one.
+#[cfg(all(target_arch = "wasm32", target_feature = "atomics"))]
+fn wait(status: &AtomicU32, expected: u32) {
+    unsafe {
+        core::arch::wasm32::memory_atomic_wait32(
+            status as *const AtomicU32 as *mut i32,
+            expected as i32,
+            -1,
+        );
+    }
+}
+
+/// Without Wasm threads, other threads cannot be blocked on, so the status is
+/// polled instead.
+#[cfg(not(all(target_arch = "wasm32", target_feature = "atomics")))]
+fn wait(_status: &AtomicU32, _expected: u32) {
+    std::hint::spin_loop();
+}
@@ -0,0 +1,20 @@
+use super::io::import_value_from_host;
+use crate::common::{mem::FatPtr, trace_context::TraceContext};
+
+#[link(wasm_import_module = "fp")]
+extern "C" {
+    fn __fp_host_trace_context() -> FatPtr;
+}
+
+/// Returns the trace context of the call that is currently being handled by
+/// the plugin.
+///
+/// Calls from the plugin to imports already continue the trace of the runtime
+/// automatically. The returned context can be used to continue the trace
+/// across other boundaries, such as requests that are made by the plugin.
+///
+/// This requires runtime bindings that are generated with OpenTelemetry
+/// support, or the plugin fails to instantiate.
+pub fn current_trace_context() -> TraceContext {
+    unsafe { import_value_from_host(__fp_host_trace_context()) }
+}
@@ -1,18 +1,20 @@
 use crate::{
     common::{
         mem::FatPtr,
-        r#async::{FUTURE_STATUS_PENDING, FUTURE_STATUS_READY},
+        r#async::{FUTURE_STATUS_ERROR, FUTURE_STATUS_PENDING, FUTURE_STATUS_READY},
     },
     host::{
+        errors::InvocationError,
         io::{to_fat_ptr, to_wasm_ptr},
-        mem::import_from_guest_raw,
+        mem::{deserialize_from_slice, try_import_from_guest_raw},
         runtime::RuntimeInstanceData,
     },
 };
 use std::{future::Future, task::Poll};
 
 // The ModuleRawFuture implements the Future Trait to handle async Futures as
-// returned from the module.
+// returned from the module. Plugins that support version 2 of the async value
+// protocol may reject them with a `GuestError`.
 pub struct ModuleRawFuture {
     ptr: FatPtr,
     env: RuntimeInstanceData,
@@ -25,7 +27,7 @@ impl ModuleRawFuture {
 }
 
 impl Future for ModuleRawFuture {
-    type Output = Vec<u8>;
+    type Output = Result<Vec<u8>, InvocationError>;
 
     fn poll(
         self: std::pin::Pin<&mut Self>,
@@ -47,12 +49,20 @@ impl Future for ModuleRawFuture {
             FUTURE_STATUS_READY => {
                 let result_ptr = values[1].get();
                 let result_len = values[2].get();
-                let result = import_from_guest_raw(&self.env, to_fat_ptr(result_ptr, result_len));
+                let result =
+                    try_import_from_guest_raw(&self.env, to_fat_ptr(result_ptr, result_len));
                 Poll::Ready(result)
             }
+            FUTURE_STATUS_ERROR => {
+                let error_ptr = values[1].get();
+                let error_len = values[2].get();
+                let error = try_import_from_guest_raw(&self.env, to_fat_ptr(error_ptr, error_len))
+                    .map(|error| InvocationError::GuestError(deserialize_from_slice(&error)));
+                Poll::Ready(Err(error.unwrap_or_else(|error| error)))
+            }
             value => panic!(
-                "expected async value FUTURE_STATUS_PENDING ({}) or FUTURE_STATUS_READY ({}) but got: {}",
-                FUTURE_STATUS_PENDING, FUTURE_STATUS_READY, value
+                "expected async value FUTURE_STATUS_PENDING ({}), FUTURE_STATUS_READY ({}) or FUTURE_STATUS_ERROR ({}) but got: {}",
+                FUTURE_STATUS_PENDING, FUTURE_STATUS_READY, FUTURE_STATUS_ERROR, value
             ),
         }
     }
@@ -0,0 +1,354 @@
+use crate::host::errors::Overloaded;
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+/// Limits the number of async plugin invocations that may run concurrently.
+///
+/// Invocations that exceed the limit are queued and are granted a permit in
+/// the order in which they arrived. Once the queue is full as well, further
+/// invocations are rejected with an `Overloaded` error, rather than piling
+/// up more work on the plugin's single-threaded executor.
+///
+/// Clones of a limit share the same permits.
+#[derive(Clone, Debug)]
+pub struct ConcurrencyLimit {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    available: usize,
+    max_concurrent: usize,
+    max_queued: usize,
+    next_id: u64,
+    queue: VecDeque<Waiter>,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    id: u64,
+    granted: bool,
+    waker: Option<Waker>,
+}
+
+impl State {
+    /// Hands out available permits to the waiters at the front of the queue.
+    fn grant(&mut self) {
+        for waiter in self.queue.iter_mut() {
+            if self.available == 0 {
+                break;
+            }
+            if !waiter.granted {
+                waiter.granted = true;
+                self.available -= 1;
+                if let Some(waker) = waiter.waker.take() {
+                    waker.wake();
+                }
+            }
+        }
+    }
+}
+
+impl ConcurrencyLimit {
+    /// Returns a limit that allows up to `max_concurrent` invocations to run
+    /// at the same time, while up to `max_queued` invocations wait for a
+    /// permit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrent` is zero, since no invocation could ever
+    /// run.
+    pub fn new(max_concurrent: usize, max_queued: usize) -> Self {
+        assert!(max_concurrent > 0, "max_concurrent must be at least 1");
+        Self {
+            state: Arc::new(Mutex::new(State {
+                available: max_concurrent,
+                max_concurrent,
+                max_queued,
+                next_id: 0,
+                queue: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Requests a permit to invoke the plugin.
+    ///
+    /// Returns a future that resolves once a permit is available, or an
+    /// `Overloaded` error if the queue of waiting invocations is full.
+    pub fn acquire(&self) -> Result<Acquire, Overloaded> {
+        let mut state = self.state.lock().unwrap();
+        if state.queue.is_empty() && state.available > 0 {
+            state.available -= 1;
+            return Ok(Acquire {
+                limit: self.clone(),
+                id: None,
+                permit: Some(ConcurrencyPermit {
+                    limit: self.clone(),
+                }),
+            });
+        }
+
+        if state.queue.len() >= state.max_queued {
+            return Err(Overloaded {
+                max_concurrent: state.max_concurrent,
+                max_queued: state.max_queued,
+            });
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+        state.queue.push_back(Waiter {
+            id,
+            granted: false,
+            waker: None,
+        });
+        Ok(Acquire {
+            limit: self.clone(),
+            id: Some(id),
+            permit: None,
+        })
+    }
+
+    /// Requests a permit to invoke the plugin, and blocks the current thread
+    /// until it is granted.
+    ///
+    /// This must not be called from async code, which should await the future
+    /// that is returned by `acquire()` instead.
+    pub fn acquire_blocking(&self) -> Result<ConcurrencyPermit, Overloaded> {
+        let mut acquire = self.acquire()?;
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(permit) = Pin::new(&mut acquire).poll(&mut cx) {
+                return Ok(permit);
+            }
+            thread::park();
+        }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.available += 1;
+        state.grant();
+    }
+}
+
+/// Wakes a thread that is blocked in `ConcurrencyLimit::acquire_blocking()`.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Future that resolves to a `ConcurrencyPermit` once it is this invocation's
+/// turn.
+///
+/// Dropping the future before it resolves gives up its place in the queue.
+#[derive(Debug)]
+pub struct Acquire {
+    limit: ConcurrencyLimit,
+    /// The ID of the queued waiter, or `None` if the permit was granted
+    /// immediately (or has been handed out already).
+    id: Option<u64>,
+    /// The permit, if it was granted immediately.
+    permit: Option<ConcurrencyPermit>,
+}
+
+impl Future for Acquire {
+    type Output = ConcurrencyPermit;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(permit) = self.permit.take() {
+            return Poll::Ready(permit);
+        }
+
+        let id = self.id.expect("Acquire polled after completion");
+
+        let mut state = self.limit.state.lock().unwrap();
+        let index = state
+            .queue
+            .iter()
+            .position(|waiter| waiter.id == id)
+            .expect("queued invocation is missing from the queue");
+        if state.queue[index].granted {
+            state.queue.remove(index);
+            drop(state);
+            self.id = None;
+            Poll::Ready(ConcurrencyPermit {
+                limit: self.limit.clone(),
+            })
+        } else {
+            state.queue[index].waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Acquire {
+    fn drop(&mut self) {
+        let id = match self.id {
+            Some(id) => id,
+            None => return,
+        };
+
+        let mut state = self.limit.state.lock().unwrap();
+        if let Some(index) = state.queue.iter().position(|waiter| waiter.id == id) {
+            if let Some(waiter) = state.queue.remove(index) {
+                // A permit that was granted, but never picked up, is passed
+                // on to the next waiter:
+                if waiter.granted {
+                    state.available += 1;
+                    state.grant();
+                }
+            }
+        }
+    }
+}
+
+/// Permit to invoke the plugin, which is released when it is dropped.
+#[derive(Debug)]
+pub struct ConcurrencyPermit {
+    limit: ConcurrencyLimit,
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        self.limit.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Records whether the task that polled an `Acquire` was woken.
+    #[derive(Default)]
+    struct WakeFlag(AtomicBool);
+
+    impl WakeFlag {
+        fn take(&self) -> bool {
+            self.0.swap(false, Ordering::SeqCst)
+        }
+    }
+
+    impl Wake for WakeFlag {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn poll(acquire: &mut Acquire, flag: &Arc<WakeFlag>) -> Poll<ConcurrencyPermit> {
+        let waker = Waker::from(flag.clone());
+        Pin::new(acquire).poll(&mut Context::from_waker(&waker))
+    }
+
+    #[test]
+    fn test_rejects_invocations_once_the_queue_is_full() {
+        let limit = ConcurrencyLimit::new(1, 1);
+        let _permit = limit.acquire().unwrap();
+        let _queued = limit.acquire().unwrap();
+
+        let error = limit.acquire().unwrap_err();
+        assert_eq!(error.max_concurrent, 1);
+        assert_eq!(error.max_queued, 1);
+    }
+
+    #[test]
+    fn test_waiters_are_granted_permits_in_order() {
+        let limit = ConcurrencyLimit::new(1, 2);
+        let (first_flag, second_flag) = (Arc::default(), Arc::default());
+        let permit = limit.acquire_blocking().unwrap();
+        let mut first = limit.acquire().unwrap();
+        let mut second = limit.acquire().unwrap();
+        assert!(poll(&mut first, &first_flag).is_pending());
+        assert!(poll(&mut second, &second_flag).is_pending());
+
+        drop(permit);
+        assert!(first_flag.take());
+        assert!(!second_flag.take());
+        let permit = match poll(&mut first, &first_flag) {
+            Poll::Ready(permit) => permit,
+            Poll::Pending => panic!("the first waiter was not granted a permit"),
+        };
+        assert!(poll(&mut second, &second_flag).is_pending());
+
+        drop(permit);
+        assert!(second_flag.take());
+        assert!(poll(&mut second, &second_flag).is_ready());
+    }
+
+    #[test]
+    fn test_permits_are_released_on_drop() {
+        let limit = ConcurrencyLimit::new(2, 0);
+        let first = limit.acquire().unwrap();
+        let second = limit.acquire().unwrap();
+        assert!(limit.acquire().is_err());
+
+        drop(first);
+        let third = limit.acquire().unwrap();
+        drop(second);
+        drop(third);
+        assert_eq!(limit.state.lock().unwrap().available, 2);
+    }
+
+    #[test]
+    fn test_dropping_a_queued_acquire_gives_up_its_place() {
+        let limit = ConcurrencyLimit::new(1, 2);
+        let flag = Arc::default();
+        let permit = limit.acquire_blocking().unwrap();
+        let first = limit.acquire().unwrap();
+        let mut second = limit.acquire().unwrap();
+
+        drop(first);
+        drop(permit);
+        assert!(poll(&mut second, &flag).is_ready());
+        assert!(limit.state.lock().unwrap().queue.is_empty());
+    }
+
+    #[test]
+    fn test_dropping_a_granted_acquire_passes_the_permit_on() {
+        let limit = ConcurrencyLimit::new(1, 2);
+        let (first_flag, second_flag) = (Arc::default(), Arc::default());
+        let permit = limit.acquire_blocking().unwrap();
+        let mut first = limit.acquire().unwrap();
+        let mut second = limit.acquire().unwrap();
+        assert!(poll(&mut first, &first_flag).is_pending());
+        assert!(poll(&mut second, &second_flag).is_pending());
+
+        // The first waiter is granted the permit, but never picks it up:
+        drop(permit);
+        drop(first);
+        assert!(second_flag.take());
+        let permit = poll(&mut second, &second_flag);
+        assert!(permit.is_ready());
+
+        drop(permit);
+        drop(second);
+        assert_eq!(limit.state.lock().unwrap().available, 1);
+    }
+
+    #[test]
+    fn test_blocking_acquire_waits_for_release() {
+        let limit = ConcurrencyLimit::new(1, 1);
+        let permit = limit.acquire_blocking().unwrap();
+
+        let waiting_limit = limit.clone();
+        let waiter = thread::spawn(move || waiting_limit.acquire_blocking().map(drop));
+        while limit.state.lock().unwrap().queue.is_empty() {
+            thread::yield_now();
+        }
+
+        drop(permit);
+        waiter.join().unwrap().unwrap();
+        assert_eq!(limit.state.lock().unwrap().available, 1);
+    }
+}
@@ -4,11 +4,12 @@ use super::{
 };
 use crate::common::{
     mem::FatPtr,
-    r#async::{AsyncValue, FUTURE_STATUS_PENDING, FUTURE_STATUS_READY},
+    r#async::{AsyncValue, FUTURE_STATUS_ERROR, FUTURE_STATUS_PENDING, FUTURE_STATUS_READY},
 };
 use std::{mem::size_of, task::Waker};
 
 pub mod future;
+pub mod limit;
 
 /// Create an empty FutureValue in the linear memory and return a FatPtr to it.
 pub fn create_future_value(env: &RuntimeInstanceData) -> FatPtr {
@@ -31,6 +32,9 @@ pub fn create_future_value(env: &RuntimeInstanceData) -> FatPtr {
 /// want to deserialize it (which would actually free it as well).
 /// This function also doesn't call another function since everything is
 /// contained in the env object.
+///
+/// Plugins that reject the async value with an error mark it as failed
+/// themselves, in which case the status is left as is.
 pub fn resolve_async_value(env: &RuntimeInstanceData, async_value_ptr: FatPtr, result_ptr: FatPtr) {
     // First assign the result ptr and mark the async value as ready:
     let memory = unsafe { env.memory.get_unchecked() };
@@ -38,7 +42,9 @@ pub fn resolve_async_value(env: &RuntimeInstanceData, async_value_ptr: FatPtr, r
     let (result_ptr, result_len) = from_fat_ptr(result_ptr);
     let values = async_ptr.deref(memory, 0, async_len).unwrap();
 
-    values[0].set(FUTURE_STATUS_READY);
+    if values[0].get() != FUTURE_STATUS_ERROR {
+        values[0].set(FUTURE_STATUS_READY);
+    }
     values[1].set(result_ptr);
     values[2].set(result_len);
 
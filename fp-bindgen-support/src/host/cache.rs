@@ -0,0 +1,77 @@
+use super::errors::RuntimeError;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+use wasmer::{Module, Store};
+
+/// Caches compiled modules on disk, so that a plugin only needs to be
+/// compiled the first time it is loaded.
+///
+/// Compiled artifacts are keyed by a hash of the module, the version of
+/// Wasmer and the target they were compiled for. Upgrading Wasmer or moving
+/// the cache to another platform therefore causes the module to be compiled
+/// again, rather than loading an incompatible artifact.
+#[derive(Clone, Debug)]
+pub struct ModuleCache {
+    dir: PathBuf,
+}
+
+impl ModuleCache {
+    /// Returns a cache that stores its artifacts in the given directory,
+    /// which is created if it does not exist yet.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Returns the directory in which the artifacts are stored.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the given module, compiled for the given store.
+    ///
+    /// If the cache contains an artifact for the module, it is deserialized
+    /// instead of compiling the module. Otherwise, the module is compiled and
+    /// the artifact is written to the cache for subsequent calls. Failing to
+    /// read or write the cache is not an error, since the module can always
+    /// be compiled instead.
+    #[allow(clippy::result_large_err)]
+    pub fn load(&self, store: &Store, wasm_module: &[u8]) -> Result<Module, RuntimeError> {
+        let path = self.artifact_path(store, wasm_module);
+        if let Ok(artifact) = fs::read(&path) {
+            // SAFETY: Artifacts are only written by `write_artifact()`, and
+            // their path includes the Wasmer version and target they were
+            // compiled with.
+            if let Ok(module) = unsafe { Module::deserialize(store, &artifact) } {
+                return Ok(module);
+            }
+        }
+
+        let module = Module::new(store, wasm_module)?;
+        let _ = write_artifact(&path, &module);
+        Ok(module)
+    }
+
+    fn artifact_path(&self, store: &Store, wasm_module: &[u8]) -> PathBuf {
+        self.dir.join(format!(
+            "{}-{}-{:016x}.bin",
+            wasmer::VERSION,
+            store.engine().target().triple(),
+            seahash::hash(wasm_module)
+        ))
+    }
+}
+
+/// Writes the artifact to a temporary file first, so that other processes
+/// that share the cache never read a partially written artifact.
+fn write_artifact(path: &Path, module: &Module) -> io::Result<()> {
+    let artifact = module.serialize().map_err(io::Error::other)?;
+    let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+    fs::write(&temp_path, artifact)?;
+    fs::rename(&temp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp_path);
+    })
+}
@@ -0,0 +1,118 @@
+use super::runtime::RuntimeInstanceData;
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+thread_local! {
+    static CURRENT_DEADLINE: Cell<Option<SystemTime>> = const { Cell::new(None) };
+}
+
+/// Returns the deadline of the current call, if any.
+///
+/// This is the deadline that was attached to the future that is currently
+/// being polled using `WithDeadline`. Inside the host's implementations of
+/// imports, it is the deadline of the call to the plugin that led to the
+/// import being called.
+pub fn current_deadline() -> Option<SystemTime> {
+    CURRENT_DEADLINE.with(Cell::get)
+}
+
+/// Restores the previous deadline of the current thread when it is dropped.
+pub struct DeadlineGuard {
+    previous: Option<SystemTime>,
+}
+
+impl Drop for DeadlineGuard {
+    fn drop(&mut self) {
+        CURRENT_DEADLINE.with(|current| current.set(self.previous));
+    }
+}
+
+fn enter(deadline: Option<SystemTime>) -> DeadlineGuard {
+    DeadlineGuard {
+        previous: CURRENT_DEADLINE.with(|current| current.replace(deadline)),
+    }
+}
+
+/// Attaches deadlines to futures, similar to how `tracing::Instrument`
+/// attaches spans.
+///
+/// Calls to the plugin that are made while the future is polled pass the
+/// deadline on to the plugin, which can retrieve it using `fp_deadline()`.
+pub trait WithDeadline: Future + Sized {
+    /// Attaches the given deadline to the future.
+    fn with_deadline(self, deadline: SystemTime) -> DeadlineFuture<Self> {
+        DeadlineFuture {
+            future: Box::pin(self),
+            deadline: Some(deadline),
+        }
+    }
+
+    /// Attaches the deadline of the current call to the future, so that it
+    /// is kept when the future is spawned.
+    fn with_current_deadline(self) -> DeadlineFuture<Self> {
+        DeadlineFuture {
+            future: Box::pin(self),
+            deadline: current_deadline(),
+        }
+    }
+}
+
+impl<F: Future> WithDeadline for F {}
+
+/// A future with an attached deadline. See `WithDeadline`.
+pub struct DeadlineFuture<F> {
+    future: Pin<Box<F>>,
+    deadline: Option<SystemTime>,
+}
+
+impl<F: Future> Future for DeadlineFuture<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _guard = enter(self.deadline);
+        self.future.as_mut().poll(cx)
+    }
+}
+
+/// Passes the deadline of the current call to the plugin, before one of its
+/// exports is called.
+pub fn export_deadline(env: &RuntimeInstanceData) {
+    env.set_guest_deadline(to_millis(current_deadline()));
+}
+
+/// Makes the deadline of the plugin's current call the deadline of the
+/// current thread, while the host handles a call to one of its imports.
+///
+/// The deadline of the thread is left as it is for plugins that don't
+/// support deadlines.
+pub fn enter_guest_deadline(env: &RuntimeInstanceData) -> DeadlineGuard {
+    match env.guest_deadline() {
+        Some(deadline) => enter(from_millis(deadline)),
+        None => enter(current_deadline()),
+    }
+}
+
+/// Implementation of the reserved `__fp_host_deadline_remaining()` import,
+/// which returns the number of milliseconds until the given deadline.
+pub fn deadline_remaining(_env: &RuntimeInstanceData, deadline: u64) -> u64 {
+    from_millis(deadline)
+        .and_then(|deadline| deadline.duration_since(SystemTime::now()).ok())
+        .map(|remaining| remaining.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Deadlines are passed to the plugin as milliseconds since the Unix epoch,
+/// where 0 means there is no deadline.
+fn to_millis(deadline: Option<SystemTime>) -> u64 {
+    deadline
+        .and_then(|deadline| deadline.duration_since(UNIX_EPOCH).ok())
+        .map(|since_epoch| (since_epoch.as_millis() as u64).max(1))
+        .unwrap_or_default()
+}
+
+fn from_millis(deadline: u64) -> Option<SystemTime> {
+    (deadline != 0).then(|| UNIX_EPOCH + Duration::from_millis(deadline))
+}
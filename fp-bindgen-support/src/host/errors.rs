@@ -1,9 +1,32 @@
+use super::{mem::import_from_guest, runtime::RuntimeInstanceData};
+pub use crate::common::guest_error::{GuestError, GuestErrorKind};
+use crate::common::mem::FatPtr;
+pub use crate::common::wasm_features::DisabledWasmFeatures;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum RuntimeError {
     #[error(transparent)]
     CompileError(#[from] wasmer::CompileError),
+
+    #[error(transparent)]
+    DeserializeError(#[from] wasmer::DeserializeError),
+
+    #[error(transparent)]
+    InstantiationError(#[from] wasmer::InstantiationError),
+
+    #[error("plugin failed to initialize: {0}")]
+    InitFailed(InvocationError),
+
+    #[error(transparent)]
+    DisabledWasmFeatures(#[from] DisabledWasmFeatures),
+
+    #[error("plugin exported symbol with an unexpected signature: {0}")]
+    ExportSignatureMismatch(String),
+
+    #[cfg(feature = "signatures")]
+    #[error(transparent)]
+    InvalidSignature(#[from] crate::common::signatures::SignatureError),
 }
 
 #[derive(Debug, Error)]
@@ -11,9 +34,127 @@ pub enum InvocationError {
     #[error("expected function was not exported: {0}")]
     FunctionNotExported(String),
 
-    #[error("returned data did not match expected type")]
-    UnexpectedReturnType,
+    #[error("function was exported with an unexpected signature: {0}")]
+    SignatureMismatch(String),
+
+    #[error("could not deserialize the `{type_name}` returned by `{function}`: {message}")]
+    DeserializationFailed {
+        function: String,
+        type_name: &'static str,
+        message: String,
+    },
+
+    #[error(transparent)]
+    PermissionDenied(PermissionDenied),
+
+    #[error(transparent)]
+    Overloaded(Overloaded),
+
+    #[error(transparent)]
+    AllocationFailed(AllocationFailed),
+
+    #[error(transparent)]
+    GuestError(GuestError),
+
+    #[cfg(feature = "compression")]
+    #[error("could not decompress the payload sent by the plugin: {0}")]
+    DecompressionFailed(crate::common::compression::DecompressionError),
+
+    #[error("import `{function}` was routed to another plugin, which failed: {error}")]
+    RoutedImportFailed {
+        function: String,
+        error: Box<InvocationError>,
+    },
 
     #[error(transparent)]
-    WasmerRuntimeError(#[from] wasmer::RuntimeError),
+    WasmerRuntimeError(wasmer::RuntimeError),
+}
+
+impl InvocationError {
+    /// Returns the error for an export that could not be retrieved from the
+    /// plugin, which depends on whether the plugin exports it at all.
+    pub fn from_export_error(name: &str, error: wasmer::ExportError) -> Self {
+        match error {
+            wasmer::ExportError::IncompatibleType => Self::SignatureMismatch(name.to_owned()),
+            wasmer::ExportError::Missing(_) => Self::FunctionNotExported(name.to_owned()),
+        }
+    }
+}
+
+impl From<wasmer::RuntimeError> for InvocationError {
+    fn from(error: wasmer::RuntimeError) -> Self {
+        // Imports that are denied trap the plugin with a `PermissionDenied`
+        // error, failures reported by the plugin trap it with a `GuestError`,
+        // and imports that are routed to other plugins trap it with the error
+        // of the routed call, which we surface as is.
+        match error.downcast::<PermissionDenied>() {
+            Ok(error) => Self::PermissionDenied(error),
+            Err(error) => match error.downcast::<GuestError>() {
+                Ok(error) => Self::GuestError(error),
+                Err(error) => match error.downcast::<InvocationError>() {
+                    Ok(error) => error,
+                    Err(error) => match error.downcast::<AllocationFailed>() {
+                        Ok(error) => Self::AllocationFailed(error),
+                        Err(error) => Self::WasmerRuntimeError(error),
+                    },
+                },
+            },
+        }
+    }
+}
+
+impl From<PermissionDenied> for InvocationError {
+    fn from(error: PermissionDenied) -> Self {
+        Self::PermissionDenied(error)
+    }
+}
+
+impl From<Overloaded> for InvocationError {
+    fn from(error: Overloaded) -> Self {
+        Self::Overloaded(error)
+    }
+}
+
+impl From<AllocationFailed> for InvocationError {
+    fn from(error: AllocationFailed) -> Self {
+        Self::AllocationFailed(error)
+    }
+}
+
+/// Returned when a plugin calls an import that requires a capability that was
+/// not granted to it.
+#[derive(Debug, Error)]
+#[error("import `{function}` requires the `{capability}` capability, which was not granted")]
+pub struct PermissionDenied {
+    pub function: String,
+    pub capability: String,
+}
+
+/// Returned when an async export is invoked while the runtime is already
+/// running the maximum number of concurrent invocations, and the queue of
+/// invocations waiting for their turn is full as well.
+#[derive(Debug, Error)]
+#[error(
+    "plugin is overloaded: {max_concurrent} invocation(s) are running and {max_queued} are queued"
+)]
+pub struct Overloaded {
+    pub max_concurrent: usize,
+    pub max_queued: usize,
+}
+
+/// Returned when the plugin could not allocate the memory for a value that is
+/// passed to it, for instance because it ran out of memory.
+#[derive(Debug, Error)]
+#[error("plugin could not allocate {len} bytes")]
+pub struct AllocationFailed {
+    pub len: u32,
+}
+
+/// Implementation of the reserved `__fp_error()` import, through which plugins
+/// that are built with the `panic-abort` feature report their failures.
+///
+/// The report is returned as an error, so that the plugin is trapped and the
+/// invocation fails with `InvocationError::GuestError`.
+pub fn guest_error(env: &RuntimeInstanceData, report: FatPtr) -> Result<(), GuestError> {
+    Err(import_from_guest(env, report))
 }
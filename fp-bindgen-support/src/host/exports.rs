@@ -0,0 +1,35 @@
+//! Eager resolution of the exports of a plugin.
+//!
+//! Runtimes that are generated with eager export resolution look up every
+//! export of the protocol when they are created, rather than on every call, so
+//! that plugins that export a function with another signature are rejected at
+//! load time, and hosts can tell which of the optional exports are missing.
+
+use super::errors::RuntimeError;
+use wasmer::{ExportError, Instance, NativeFunc, WasmTypeList};
+
+/// An export that was looked up in a plugin, or `None` if the plugin does not
+/// export it.
+pub type ResolvedExport<Args, Rets> = Option<NativeFunc<Args, Rets>>;
+
+/// Resolves the export with the given symbol name, and checks whether it has
+/// the expected signature.
+///
+/// Returns `None` if the plugin does not export the symbol, since plugins may
+/// omit exports. The error is boxed, since `RuntimeError` is large.
+pub fn resolve_export<Args, Rets>(
+    instance: &Instance,
+    symbol: &str,
+) -> Result<ResolvedExport<Args, Rets>, Box<RuntimeError>>
+where
+    Args: WasmTypeList,
+    Rets: WasmTypeList,
+{
+    match instance.exports.get_native_function(symbol) {
+        Ok(function) => Ok(Some(function)),
+        Err(ExportError::Missing(_)) => Ok(None),
+        Err(ExportError::IncompatibleType) => Err(Box::new(RuntimeError::ExportSignatureMismatch(
+            symbol.to_owned(),
+        ))),
+    }
+}
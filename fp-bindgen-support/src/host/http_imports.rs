@@ -0,0 +1,151 @@
+use super::{
+    errors::PermissionDenied,
+    mem::{export_to_guest, import_from_guest},
+    r#async::create_future_value,
+    runtime::RuntimeInstanceData,
+};
+use crate::common::{
+    http_imports::{HttpError, HttpRequest, HttpResponse, HTTP_CAPABILITY},
+    mem::FatPtr,
+};
+use once_cell::sync::Lazy;
+use serde_bytes::ByteBuf;
+use std::{fmt, future::Future, pin::Pin, sync::Arc};
+
+/// Future that resolves to the response to a request made through the
+/// built-in HTTP imports.
+pub type HttpFuture = Pin<Box<dyn Future<Output = Result<HttpResponse, HttpError>> + Send>>;
+
+/// Handler that is used to answer calls to the `http_request()` import.
+pub type HttpHandler = Arc<dyn Fn(HttpRequest) -> HttpFuture + Send + Sync>;
+
+/// The client that is used by all runtimes that don't configure their own, so
+/// that they share a connection pool.
+static DEFAULT_CLIENT: Lazy<reqwest::Client> = Lazy::new(reqwest::Client::new);
+
+/// The HTTP client that is exposed to the plugin through the built-in HTTP
+/// imports.
+///
+/// By default, requests are sent using a shared `reqwest::Client`. Hosts can
+/// pass their own client, for instance to configure timeouts or proxies, or
+/// replace it with a handler that virtualizes the requests altogether.
+///
+/// Regardless of these settings, the plugin can only make requests if it was
+/// granted the `http` capability.
+#[derive(Clone, Default)]
+pub struct HttpImports {
+    client: Option<reqwest::Client>,
+    handler: Option<HttpHandler>,
+}
+
+impl fmt::Debug for HttpImports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpImports")
+            .field("client", &self.client)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpImports {
+    /// Returns the default HTTP imports. See [`HttpImports`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends requests using the given client, rather than the shared default
+    /// one.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Answers requests using the given handler, rather than sending them
+    /// using a client.
+    pub fn with_handler<F>(
+        mut self,
+        handler: impl Fn(HttpRequest) -> F + Send + Sync + 'static,
+    ) -> Self
+    where
+        F: Future<Output = Result<HttpResponse, HttpError>> + Send + 'static,
+    {
+        self.handler = Some(Arc::new(move |request| Box::pin(handler(request))));
+        self
+    }
+
+    /// Sends the given request, and returns a future that resolves to its
+    /// response.
+    pub fn request(&self, request: HttpRequest) -> HttpFuture {
+        match &self.handler {
+            Some(handler) => handler(request),
+            None => {
+                let client = self.client.as_ref().unwrap_or(&DEFAULT_CLIENT).clone();
+                Box::pin(send_request(client, request))
+            }
+        }
+    }
+}
+
+async fn send_request(
+    client: reqwest::Client,
+    request: HttpRequest,
+) -> Result<HttpResponse, HttpError> {
+    let method = reqwest::Method::from_bytes(request.method.as_bytes())
+        .map_err(|_| HttpError::InvalidRequest(format!("invalid method: {}", request.method)))?;
+    let mut builder = client.request(method, &request.url);
+    for (name, value) in request.headers {
+        builder = builder.header(name, value);
+    }
+    if let Some(body) = request.body {
+        builder = builder.body(body.into_vec());
+    }
+
+    let response = builder.send().await.map_err(to_http_error)?;
+    let status = response.status().as_u16();
+    let headers = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+            (name.as_str().to_owned(), value)
+        })
+        .collect();
+    let body = response.bytes().await.map_err(to_http_error)?;
+    Ok(HttpResponse {
+        status,
+        headers,
+        body: ByteBuf::from(body.to_vec()),
+    })
+}
+
+fn to_http_error(error: reqwest::Error) -> HttpError {
+    if error.is_timeout() {
+        HttpError::Timeout
+    } else if error.is_builder() {
+        HttpError::InvalidRequest(error.to_string())
+    } else if error.is_connect() {
+        HttpError::Connection(error.to_string())
+    } else {
+        HttpError::Other(error.to_string())
+    }
+}
+
+/// Implementation of the reserved `__fp_http_request()` import.
+///
+/// The request is sent on the current Tokio runtime, and the plugin's future
+/// is resolved once the response has been received.
+pub fn http_request(
+    env: &RuntimeInstanceData,
+    request: FatPtr,
+) -> Result<FatPtr, PermissionDenied> {
+    env.check_capability("http_request", HTTP_CAPABILITY)?;
+    let request: HttpRequest = import_from_guest(env, request);
+    let response = env.http_imports().request(request);
+    let env = env.clone();
+    let async_ptr = create_future_value(&env);
+    tokio::runtime::Handle::current().spawn(async move {
+        let result = response.await;
+        let result_ptr = export_to_guest(&env, &result);
+        env.guest_resolve_async_value(async_ptr, result_ptr);
+    });
+    Ok(async_ptr)
+}
@@ -0,0 +1,29 @@
+use wasmer::Instance;
+
+/// Calls the `shutdown` export of a plugin when it is dropped.
+///
+/// Runtimes share the guard between their clones, so that the plugin is only
+/// shut down once the last clone is dropped. Plugins that don't implement
+/// `shutdown` are simply discarded, and failures are ignored, since there is
+/// nobody left to report them to.
+pub struct ShutdownGuard {
+    instance: Instance,
+}
+
+impl ShutdownGuard {
+    pub fn new(instance: Instance) -> Self {
+        Self { instance }
+    }
+}
+
+impl Drop for ShutdownGuard {
+    fn drop(&mut self) {
+        if let Ok(function) = self
+            .instance
+            .exports
+            .get_native_function::<(), ()>("__fp_gen_shutdown")
+        {
+            let _ = function.call();
+        }
+    }
+}
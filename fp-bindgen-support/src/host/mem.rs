@@ -1,8 +1,11 @@
-use super::{io::to_wasm_ptr, runtime::RuntimeInstanceData};
+use super::{
+    errors::{AllocationFailed, InvocationError},
+    io::from_fat_ptr,
+    runtime::RuntimeInstanceData,
+};
 use crate::common::mem::FatPtr;
 use rmp_serde::{decode::ReadReader, Deserializer, Serializer};
 use serde::{Deserialize, Serialize};
-use wasmer::WasmCell;
 
 /// Serialize the given value to MessagePack
 pub fn serialize_to_vec<T: Serialize>(value: &T) -> Vec<u8> {
@@ -15,11 +18,32 @@ pub fn serialize_to_vec<T: Serialize>(value: &T) -> Vec<u8> {
 }
 
 /// Deserialize the given MessagePack-encoded slice
+///
+/// Strings and byte arrays may borrow from the slice, so types such as `&[u8]`
+/// can be deserialized without copying.
 pub fn deserialize_from_slice<'a, T: Deserialize<'a>>(slice: &'a [u8]) -> T {
-    let mut deserializer = rmp_serde::Deserializer::new(slice).with_human_readable();
+    let mut deserializer = Deserializer::from_read_ref(slice).with_human_readable();
     T::deserialize(&mut deserializer).unwrap()
 }
 
+/// Deserialize the given MessagePack-encoded slice, which was returned by the
+/// given function.
+///
+/// Unlike `deserialize_from_slice()`, this returns an error if the slice does
+/// not contain a valid `type_name`.
+pub fn try_deserialize_from_slice<'a, T: Deserialize<'a>>(
+    slice: &'a [u8],
+    function: &str,
+    type_name: &'static str,
+) -> Result<T, InvocationError> {
+    let mut deserializer = Deserializer::from_read_ref(slice).with_human_readable();
+    T::deserialize(&mut deserializer).map_err(|error| InvocationError::DeserializationFailed {
+        function: function.to_owned(),
+        type_name,
+        message: error.to_string(),
+    })
+}
+
 /// Serialize an object from the linear memory and after that free up the memory
 pub fn import_from_guest<'de, T: Deserialize<'de>>(
     env: &RuntimeInstanceData,
@@ -37,36 +61,90 @@ pub fn import_from_guest<'de, T: Deserialize<'de>>(
 ///
 /// Useful when the consumer wants to pass the result, without having the
 /// deserialize and serialize it.
+///
+/// This is meant to be called by imports. If the payload cannot be
+/// decompressed, the plugin is trapped, so the export that called the import
+/// fails with the error. Use `try_import_from_guest_raw()` outside of imports.
 pub fn import_from_guest_raw(env: &RuntimeInstanceData, fat_ptr: FatPtr) -> Vec<u8> {
+    // Most imports don't return a `Result` through which the error could be
+    // passed to Wasmer, so the trap is raised directly:
+    #[allow(deprecated)]
+    try_import_from_guest_raw(env, fat_ptr)
+        .unwrap_or_else(|error| wasmer::RuntimeError::raise(Box::new(error)))
+}
+
+/// Retrieve a serialized object from the linear memory as a Vec<u8> and free up
+/// the memory it was using, or return an error if it cannot be decompressed.
+pub fn try_import_from_guest_raw(
+    env: &RuntimeInstanceData,
+    fat_ptr: FatPtr,
+) -> Result<Vec<u8>, InvocationError> {
     if fat_ptr == 0 {
         // This may happen with async calls that don't return a result:
-        return Vec::new();
+        return Ok(Vec::new());
     }
 
     let memory = unsafe { env.memory.get_unchecked() };
 
-    let (ptr, len) = to_wasm_ptr::<u8>(fat_ptr);
+    let (ptr, len) = from_fat_ptr(fat_ptr);
     if len & 0xff000000 != 0 {
         panic!("Unknown extension bits");
     }
 
-    let value: Vec<u8> = {
-        let view = ptr.deref(memory, 0, len).unwrap();
-        view.iter().map(WasmCell::get).collect()
-    };
+    // Copy the bytes in one go, rather than cell by cell:
+    let (start, end) = (ptr as usize, ptr as usize + len as usize);
+    let data = unsafe { memory.data_unchecked() };
+    let value = data[start..end].to_vec();
 
     env.free(fat_ptr);
 
-    value
+    #[cfg(feature = "compression")]
+    let value = crate::common::compression::try_decompress(&value)
+        .map_err(InvocationError::DecompressionFailed)?
+        .into_owned();
+
+    Ok(value)
 }
 
 /// Serialize a value and put it in linear memory.
+///
+/// Panics if the plugin could not allocate the memory for it.
 pub fn export_to_guest<T: Serialize>(env: &RuntimeInstanceData, value: &T) -> FatPtr {
     export_to_guest_raw(env, rmp_serde::to_vec(value).unwrap())
 }
 
+/// Serialize a value and put it in linear memory, or return an error if the
+/// plugin could not allocate the memory for it.
+pub fn try_export_to_guest<T: Serialize>(
+    env: &RuntimeInstanceData,
+    value: &T,
+) -> Result<FatPtr, AllocationFailed> {
+    try_export_to_guest_raw(env, rmp_serde::to_vec(value).unwrap())
+}
+
 /// Copy the buffer into linear memory.
-pub fn export_to_guest_raw(env: &RuntimeInstanceData, buffer: Vec<u8>) -> FatPtr {
+///
+/// Any byte container can be given, so buffers such as `bytes::Bytes` can be
+/// passed along without copying them into a `Vec<u8>` first.
+///
+/// Panics if the plugin could not allocate the memory for it.
+pub fn export_to_guest_raw(env: &RuntimeInstanceData, buffer: impl AsRef<[u8]>) -> FatPtr {
+    try_export_to_guest_raw(env, buffer).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Copy the buffer into linear memory, or return an error if the plugin could
+/// not allocate the memory for it.
+pub fn try_export_to_guest_raw(
+    env: &RuntimeInstanceData,
+    buffer: impl AsRef<[u8]>,
+) -> Result<FatPtr, AllocationFailed> {
+    #[cfg(feature = "compression")]
+    let compressed = crate::common::compression::compress_slice(buffer.as_ref());
+    #[cfg(feature = "compression")]
+    let buffer: &[u8] = compressed.as_deref().unwrap_or_else(|| buffer.as_ref());
+    #[cfg(not(feature = "compression"))]
+    let buffer: &[u8] = buffer.as_ref();
+
     let memory = unsafe { env.memory.get_unchecked() };
 
     let len = buffer.len() as u32;
@@ -76,14 +154,13 @@ pub fn export_to_guest_raw(env: &RuntimeInstanceData, buffer: Vec<u8>) -> FatPtr
         panic!("Buffer too large ({} bytes)", len);
     }
 
-    let fat_ptr = env.malloc(len);
+    let fat_ptr = env.try_malloc(len)?;
 
-    let (ptr, len) = to_wasm_ptr(fat_ptr);
+    let (ptr, len) = from_fat_ptr(fat_ptr);
 
-    let values = ptr.deref(memory, 0, len).unwrap();
-    for (i, val) in buffer.iter().enumerate() {
-        values[i].set(*val);
-    }
+    let (start, end) = (ptr as usize, ptr as usize + len as usize);
+    let data = unsafe { memory.data_unchecked_mut() };
+    data[start..end].copy_from_slice(buffer);
 
-    fat_ptr
+    Ok(fat_ptr)
 }
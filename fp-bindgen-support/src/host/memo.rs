@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Memoizes the results of exports that are annotated with `#[fp(cache)]`.
+///
+/// Results are stored in their MessagePack encoding, keyed by the name of the
+/// export and the MessagePack encoding of its arguments, and are evicted once
+/// their time-to-live has passed. Only successful results are stored.
+///
+/// Clones share the same entries, so that clones of a runtime benefit from
+/// each other's results.
+#[derive(Clone, Debug, Default)]
+pub struct ExportCache {
+    entries: Arc<Mutex<CacheEntries>>,
+}
+
+/// Cached results, keyed by export name and encoded arguments.
+type CacheEntries = HashMap<(&'static str, Vec<u8>), CacheEntry>;
+
+#[derive(Debug)]
+struct CacheEntry {
+    expires_at: Instant,
+    value: Vec<u8>,
+}
+
+impl ExportCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the result that is stored for the given export and arguments,
+    /// unless it has expired.
+    pub fn get(&self, export: &'static str, key: &[u8]) -> Option<Vec<u8>> {
+        let mut entries = self.entries.lock().unwrap();
+        let cache_key = (export, key.to_vec());
+        match entries.get(&cache_key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.value.clone()),
+            Some(_) => {
+                entries.remove(&cache_key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Stores the result for the given export and arguments, which is kept for
+    /// the given time-to-live. Expired entries of other calls are evicted
+    /// along the way.
+    pub fn insert(&self, export: &'static str, key: Vec<u8>, value: Vec<u8>, ttl: Duration) {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, entry| entry.expires_at > now);
+        entries.insert(
+            (export, key),
+            CacheEntry {
+                expires_at: now + ttl,
+                value,
+            },
+        );
+    }
+
+    /// Removes all stored results of the given export.
+    pub fn invalidate(&self, export: &str) {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|(name, _), _| *name != export);
+    }
+
+    /// Removes all stored results.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Returns the number of stored results, including ones that have expired
+    /// but were not evicted yet.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
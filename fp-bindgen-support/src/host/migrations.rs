@@ -0,0 +1,241 @@
+//! Helpers for the migrations that are generated for renamed fields and
+//! variants.
+//!
+//! Migrations operate on the MessagePack encoding of a payload, decoded into
+//! a dynamic `Value`. The generated `upgrade_*()` functions rename the members
+//! that were serialized under an old name by previous versions of the
+//! protocol, after which the generated `migrate_*()` functions deserialize
+//! the upgraded value into the current type.
+
+use rmp_serde::Deserializer;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+pub use rmpv::Value;
+
+/// Returned when a payload cannot be migrated to the current type.
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    #[error("payload is not valid MessagePack: {0}")]
+    InvalidPayload(String),
+
+    #[error("upgraded payload could not be deserialized as `{type_name}`: {message}")]
+    DeserializationFailed {
+        type_name: &'static str,
+        message: String,
+    },
+}
+
+/// Decodes the given payload, upgrades it using the given function and
+/// deserializes the result.
+pub fn migrate<T: DeserializeOwned>(
+    payload: &[u8],
+    upgrade: impl FnOnce(&mut Value),
+) -> Result<T, MigrationError> {
+    let mut value = rmpv::decode::read_value(&mut &payload[..])
+        .map_err(|error| MigrationError::InvalidPayload(error.to_string()))?;
+    upgrade(&mut value);
+
+    let mut upgraded = Vec::new();
+    rmpv::encode::write_value(&mut upgraded, &value).expect("Writing to a Vec should not fail");
+
+    let mut deserializer = Deserializer::from_read_ref(&upgraded).with_human_readable();
+    T::deserialize(&mut deserializer).map_err(|error| MigrationError::DeserializationFailed {
+        type_name: std::any::type_name::<T>(),
+        message: error.to_string(),
+    })
+}
+
+/// Returns the value of the field with the given name, if the value is a map
+/// that contains it.
+pub fn field_mut<'a>(value: &'a mut Value, name: &str) -> Option<&'a mut Value> {
+    match value {
+        Value::Map(entries) => entries
+            .iter_mut()
+            .find(|(key, _)| key.as_str() == Some(name))
+            .map(|(_, value)| value),
+        _ => None,
+    }
+}
+
+/// Returns the item at the given index, if the value is an array that is
+/// long enough.
+pub fn item_mut(value: &mut Value, index: usize) -> Option<&mut Value> {
+    match value {
+        Value::Array(items) => items.get_mut(index),
+        _ => None,
+    }
+}
+
+/// Renames the field `old` of a map to `new`, unless the map already
+/// contains a field named `new`.
+pub fn rename_field(value: &mut Value, old: &str, new: &str) {
+    if let Value::Map(entries) = value {
+        if entries.iter().any(|(key, _)| key.as_str() == Some(new)) {
+            return;
+        }
+        if let Some((key, _)) = entries
+            .iter_mut()
+            .find(|(key, _)| key.as_str() == Some(old))
+        {
+            *key = Value::from(new);
+        }
+    }
+}
+
+/// Renames the variant `old` of an externally tagged enum to `new`.
+///
+/// Unit variants are serialized as their name, while other variants are
+/// serialized as a map from their name to their payload.
+pub fn rename_variant(value: &mut Value, old: &str, new: &str) {
+    match value {
+        Value::String(name) if name.as_str() == Some(old) => *value = Value::from(new),
+        Value::Map(entries) if entries.len() == 1 => {
+            let (key, _) = &mut entries[0];
+            if key.as_str() == Some(old) {
+                *key = Value::from(new);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the payload of an externally tagged enum, if it is the variant with
+/// the given name.
+pub fn variant_payload_mut<'a>(value: &'a mut Value, name: &str) -> Option<&'a mut Value> {
+    match value {
+        Value::Map(entries) if entries.len() == 1 => {
+            let (key, payload) = &mut entries[0];
+            (key.as_str() == Some(name)).then_some(payload)
+        }
+        _ => None,
+    }
+}
+
+/// Renames the variant `old` of an internally or adjacently tagged enum to
+/// `new`.
+pub fn rename_tag(value: &mut Value, tag: &str, old: &str, new: &str) {
+    if let Some(name) = field_mut(value, tag) {
+        if name.as_str() == Some(old) {
+            *name = Value::from(new);
+        }
+    }
+}
+
+/// Returns whether an internally or adjacently tagged enum is the variant with
+/// the given name.
+pub fn has_tag(value: &Value, tag: &str, name: &str) -> bool {
+    match value {
+        Value::Map(entries) => entries
+            .iter()
+            .any(|(key, value)| key.as_str() == Some(tag) && value.as_str() == Some(name)),
+        _ => false,
+    }
+}
+
+/// Upgrades every item of an array using the given function.
+pub fn for_each_item(value: &mut Value, upgrade: impl Fn(&mut Value)) {
+    if let Value::Array(items) = value {
+        items.iter_mut().for_each(upgrade);
+    }
+}
+
+/// Upgrades every value of a map using the given function.
+pub fn for_each_map_value(value: &mut Value, upgrade: impl Fn(&mut Value)) {
+    if let Value::Map(entries) = value {
+        for (_, value) in entries {
+            upgrade(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct User {
+        display_name: String,
+        tags: Vec<Tag>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum Tag {
+        Admin,
+        Guest { since: u32 },
+    }
+
+    fn encode(value: Value) -> Vec<u8> {
+        let mut payload = Vec::new();
+        rmpv::encode::write_value(&mut payload, &value).unwrap();
+        payload
+    }
+
+    fn upgrade_tag(value: &mut Value) {
+        rename_variant(value, "Root", "Admin");
+        rename_variant(value, "Visitor", "Guest");
+        if let Some(payload) = variant_payload_mut(value, "Guest") {
+            rename_field(payload, "from", "since");
+        }
+    }
+
+    fn upgrade_user(value: &mut Value) {
+        rename_field(value, "userName", "displayName");
+        if let Some(value) = field_mut(value, "tags") {
+            for_each_item(value, upgrade_tag);
+        }
+    }
+
+    #[test]
+    fn test_migrate_renamed_members() {
+        let payload = encode(Value::Map(vec![
+            (Value::from("userName"), Value::from("Alice")),
+            (
+                Value::from("tags"),
+                Value::Array(vec![
+                    Value::from("Root"),
+                    Value::Map(vec![(
+                        Value::from("Visitor"),
+                        Value::Map(vec![(Value::from("from"), Value::from(2020))]),
+                    )]),
+                ]),
+            ),
+        ]));
+
+        let user: User = migrate(&payload, upgrade_user).unwrap();
+        assert_eq!(
+            user,
+            User {
+                display_name: "Alice".to_owned(),
+                tags: vec![Tag::Admin, Tag::Guest { since: 2020 }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_current_names_take_precedence() {
+        let mut value = Value::Map(vec![
+            (Value::from("userName"), Value::from("old")),
+            (Value::from("displayName"), Value::from("new")),
+        ]);
+        rename_field(&mut value, "userName", "displayName");
+        assert_eq!(
+            field_mut(&mut value, "displayName"),
+            Some(&mut Value::from("new"))
+        );
+        assert!(field_mut(&mut value, "userName").is_some());
+    }
+
+    #[test]
+    fn test_tagged_variants() {
+        let mut value = Value::Map(vec![
+            (Value::from("type"), Value::from("Old")),
+            (Value::from("content"), Value::Nil),
+        ]);
+        rename_tag(&mut value, "type", "Old", "New");
+        assert!(has_tag(&value, "type", "New"));
+        assert!(!has_tag(&value, "type", "Old"));
+    }
+}
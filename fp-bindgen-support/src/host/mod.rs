@@ -1,7 +1,28 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+#[cfg(feature = "module-cache")]
+pub mod cache;
+pub mod deadline;
 pub mod errors;
+pub mod exports;
+#[cfg(feature = "http-imports-host")]
+pub mod http_imports;
+pub mod interning;
 pub mod io;
+pub mod lifecycle;
 pub mod mem;
+pub mod memo;
+#[cfg(feature = "migrations")]
+pub mod migrations;
+pub mod overrides;
+#[cfg(feature = "payload-tracing")]
+pub mod payload_trace;
+pub mod rate_limit;
+pub mod routes;
 pub mod runtime;
+#[cfg(feature = "std-imports-host")]
+pub mod std_imports;
+pub mod stdio;
+#[cfg(feature = "opentelemetry")]
+pub mod trace_context;
@@ -0,0 +1,38 @@
+//! Overrides of imports for a single runtime.
+//!
+//! Runtimes that are generated with import overrides let the host replace
+//! some of its implementations of the imports with closures when a runtime is
+//! built, such as to bake the credentials of a tenant into them. Imports that
+//! are not overridden are handled by the host's own implementations.
+
+use std::{any::Any, collections::HashMap, future::Future, pin::Pin, sync::Arc};
+
+/// Future that is returned by the override of an async import.
+pub type OverrideFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// Overrides of imports, keyed by import name.
+///
+/// Every override is stored as an `Arc` of the trait object with the
+/// signature of the import, such as `dyn Fn(String) -> String + Send + Sync`.
+/// The generated `RuntimeBuilder` has a typed method for every import that
+/// can be overridden, so hosts don't need to use this directly.
+#[derive(Clone, Default)]
+pub struct ImportOverrides(HashMap<String, Arc<dyn Any + Send + Sync>>);
+
+impl ImportOverrides {
+    /// Overrides the import with the given name, replacing any override that
+    /// was set for it before.
+    pub fn insert<F: ?Sized + Send + Sync + 'static>(
+        &mut self,
+        name: &str,
+        implementation: Arc<F>,
+    ) {
+        self.0.insert(name.to_owned(), Arc::new(implementation));
+    }
+
+    /// Returns the override of the import with the given name, unless it is
+    /// not overridden or the override has another signature than `F`.
+    pub fn get<F: ?Sized + Send + Sync + 'static>(&self, name: &str) -> Option<Arc<F>> {
+        self.0.get(name)?.downcast_ref::<Arc<F>>().cloned()
+    }
+}
@@ -0,0 +1,341 @@
+//! Tracing of the serialized payloads that are passed between the runtime and
+//! the plugin, for debugging protocol mismatches.
+//!
+//! Runtimes that are generated with payload tracing pass every serialized
+//! argument and result to the `PayloadTracer` of the runtime, which logs the
+//! function, the direction and the payload itself. Runtimes without a tracer
+//! of their own use the one that is configured through the
+//! `FP_BINDGEN_TRACE_PAYLOADS` environment variable, if any.
+
+use super::{io::from_fat_ptr, runtime::RuntimeInstanceData};
+use crate::common::mem::FatPtr;
+use std::{borrow::Cow, fmt, sync::Arc, sync::OnceLock};
+
+/// The environment variable that enables payload tracing for runtimes that
+/// have no tracer of their own. Its value is the format in which payloads are
+/// logged: `hex`, `base64` or `decoded`.
+pub const PAYLOAD_TRACE_ENV_VAR: &str = "FP_BINDGEN_TRACE_PAYLOADS";
+
+/// Redacts payloads that should not be logged, such as those that contain
+/// credentials. It returns the text that is logged instead of the payload, or
+/// `None` to log the payload itself.
+pub type PayloadRedactor = Arc<dyn Fn(&PayloadTrace) -> Option<String> + Send + Sync>;
+
+/// Logs the lines that describe the traced payloads.
+pub type PayloadLogger = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// The direction in which a payload is passed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PayloadDirection {
+    /// The payload is passed from the runtime to the plugin, as the argument
+    /// of an export or the result of an import.
+    ToPlugin,
+
+    /// The payload is passed from the plugin to the runtime, as the result of
+    /// an export or the argument of an import.
+    FromPlugin,
+}
+
+impl fmt::Display for PayloadDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ToPlugin => "runtime -> plugin",
+            Self::FromPlugin => "plugin -> runtime",
+        })
+    }
+}
+
+/// The format in which payloads are logged.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PayloadFormat {
+    /// The bytes of the payload, in hexadecimal.
+    Hex,
+
+    /// The bytes of the payload, in standard Base64 with padding.
+    Base64,
+
+    /// The MessagePack value of the payload, in a JSON-like notation. Payloads
+    /// that are not valid MessagePack are logged in hexadecimal.
+    Decoded,
+}
+
+impl PayloadFormat {
+    /// Parses the format from the value of the `FP_BINDGEN_TRACE_PAYLOADS`
+    /// environment variable.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "hex" => Some(Self::Hex),
+            "base64" => Some(Self::Base64),
+            "decoded" | "msgpack" => Some(Self::Decoded),
+            _ => None,
+        }
+    }
+}
+
+/// A payload that is passed between the runtime and the plugin.
+#[derive(Clone, Copy, Debug)]
+pub struct PayloadTrace<'a> {
+    /// The name of the function that is called.
+    pub function: &'a str,
+
+    /// The name of the argument, or `result` for the result.
+    pub name: &'a str,
+
+    pub direction: PayloadDirection,
+
+    /// The serialized payload.
+    pub payload: &'a [u8],
+}
+
+/// Logs the payloads that are passed between the runtime and the plugin.
+///
+/// By default, payloads are logged to the stderr of the host.
+#[derive(Clone)]
+pub struct PayloadTracer {
+    format: PayloadFormat,
+    redactors: Vec<PayloadRedactor>,
+    logger: Option<PayloadLogger>,
+}
+
+impl PayloadTracer {
+    /// Returns a tracer that logs payloads in the given format.
+    pub fn new(format: PayloadFormat) -> Self {
+        Self {
+            format,
+            redactors: Vec::new(),
+            logger: None,
+        }
+    }
+
+    /// Returns the tracer that is configured through the
+    /// `FP_BINDGEN_TRACE_PAYLOADS` environment variable, if it is set to a
+    /// known format.
+    pub fn from_env() -> Option<Self> {
+        let format = std::env::var(PAYLOAD_TRACE_ENV_VAR).ok()?;
+        PayloadFormat::from_name(&format).map(Self::new)
+    }
+
+    /// Adds a redactor, which may replace the payloads that should not be
+    /// logged. Redactors are asked in the order in which they were added,
+    /// until one of them returns a replacement.
+    pub fn with_redactor(
+        mut self,
+        redactor: impl Fn(&PayloadTrace) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.redactors.push(Arc::new(redactor));
+        self
+    }
+
+    /// Redacts all payloads of the given function.
+    pub fn with_redacted_function(self, function: &str) -> Self {
+        let function = function.to_owned();
+        self.with_redactor(move |trace| {
+            (trace.function == function).then(|| "<redacted>".to_owned())
+        })
+    }
+
+    /// Sets the logger to which the lines that describe the payloads are
+    /// passed, instead of printing them to stderr.
+    pub fn with_logger(mut self, logger: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.logger = Some(Arc::new(logger));
+        self
+    }
+
+    /// Logs the given payload.
+    pub fn trace(&self, trace: &PayloadTrace) {
+        let payload = self
+            .redactors
+            .iter()
+            .find_map(|redactor| redactor(trace))
+            .unwrap_or_else(|| self.format_payload(trace.payload));
+        let line = format!(
+            "fp-bindgen payload: {}({}) {}, {} bytes: {payload}",
+            trace.function,
+            trace.name,
+            trace.direction,
+            trace.payload.len()
+        );
+        match &self.logger {
+            Some(logger) => logger(&line),
+            None => eprintln!("{line}"),
+        }
+    }
+
+    /// Formats the given payload in the format of the tracer.
+    pub fn format_payload(&self, payload: &[u8]) -> String {
+        match self.format {
+            PayloadFormat::Hex => to_hex(payload),
+            PayloadFormat::Base64 => to_base64(payload),
+            PayloadFormat::Decoded => {
+                let mut reader = payload;
+                match rmpv::decode::read_value(&mut reader) {
+                    Ok(value) if reader.is_empty() => value.to_string(),
+                    _ => format!("{} (invalid MessagePack)", to_hex(payload)),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for PayloadTracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PayloadTracer")
+            .field("format", &self.format)
+            .field("redactors", &self.redactors.len())
+            .finish()
+    }
+}
+
+/// Passes a serialized payload to the payload tracer of the runtime.
+pub fn trace_payload(
+    env: &RuntimeInstanceData,
+    function: &str,
+    name: &str,
+    direction: PayloadDirection,
+    payload: &[u8],
+) {
+    if let Some(tracer) = tracer(env) {
+        tracer.trace(&PayloadTrace {
+            function,
+            name,
+            direction,
+            payload,
+        });
+    }
+}
+
+/// Passes a serialized payload in the memory of the plugin to the payload
+/// tracer of the runtime, without freeing it.
+pub fn trace_guest_payload(
+    env: &RuntimeInstanceData,
+    function: &str,
+    name: &str,
+    direction: PayloadDirection,
+    fat_ptr: FatPtr,
+) {
+    if tracer(env).is_none() {
+        return;
+    }
+
+    let memory = unsafe { env.memory.get_unchecked() };
+    let (ptr, len) = from_fat_ptr(fat_ptr);
+    let (start, end) = (ptr as usize, ptr as usize + (len & 0x00ffffff) as usize);
+    let data = unsafe { memory.data_unchecked() };
+    if let Some(payload) = data.get(start..end) {
+        trace_payload(env, function, name, direction, &guest_payload(payload));
+    }
+}
+
+/// Returns the serialized payload that is contained in the given bytes from
+/// the memory of the plugin, by decompressing them the same way that
+/// `import_from_guest_raw()` does. Bytes that fail to decompress are returned
+/// as they are, since the call itself reports the failure.
+fn guest_payload(bytes: &[u8]) -> Cow<'_, [u8]> {
+    #[cfg(feature = "compression")]
+    let payload = crate::common::compression::try_decompress(bytes).unwrap_or(Cow::Borrowed(bytes));
+    #[cfg(not(feature = "compression"))]
+    let payload = Cow::Borrowed(bytes);
+    payload
+}
+
+fn tracer(env: &RuntimeInstanceData) -> Option<&PayloadTracer> {
+    static ENV_TRACER: OnceLock<Option<PayloadTracer>> = OnceLock::new();
+
+    env.payload_tracer()
+        .or_else(|| ENV_TRACER.get_or_init(PayloadTracer::from_env).as_ref())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn to_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = chunk.iter().enumerate().fold(0u32, |triple, (i, byte)| {
+            triple | (*byte as u32) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(triple >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_payload_formats() {
+        // `{"id": 1}` in MessagePack:
+        let payload = [0x81, 0xa2, b'i', b'd', 0x01];
+        assert_eq!(
+            PayloadTracer::new(PayloadFormat::Hex).format_payload(&payload),
+            "81a2696401"
+        );
+        assert_eq!(
+            PayloadTracer::new(PayloadFormat::Base64).format_payload(&payload),
+            "gaJpZAE="
+        );
+        assert_eq!(
+            PayloadTracer::new(PayloadFormat::Decoded).format_payload(&payload),
+            "{\"id\": 1}"
+        );
+        assert_eq!(
+            PayloadTracer::new(PayloadFormat::Decoded).format_payload(&payload[..3]),
+            "81a269 (invalid MessagePack)"
+        );
+        assert_eq!(to_base64(b"fo"), "Zm8=");
+        assert_eq!(to_base64(b"foo"), "Zm9v");
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_compressed_payloads_are_decompressed() {
+        use crate::common::compression::COMPRESSION_MARKER;
+
+        let payload = [0x90; 64];
+        let mut compressed = vec![COMPRESSION_MARKER];
+        compressed.extend_from_slice(&lz4_flex::compress_prepend_size(&payload));
+        assert_eq!(guest_payload(&compressed).as_ref(), &payload[..]);
+
+        // Payloads that are not compressed, or fail to decompress, are traced
+        // as they are:
+        assert_eq!(guest_payload(&payload).as_ref(), &payload[..]);
+        let invalid = [COMPRESSION_MARKER, 16, 0, 0, 0, 0xff];
+        assert_eq!(guest_payload(&invalid).as_ref(), &invalid[..]);
+    }
+
+    #[test]
+    fn test_payloads_can_be_redacted() {
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let logged_lines = lines.clone();
+        let tracer = PayloadTracer::new(PayloadFormat::Hex)
+            .with_redacted_function("login")
+            .with_logger(move |line| logged_lines.lock().unwrap().push(line.to_owned()));
+
+        for function in ["login", "logout"] {
+            tracer.trace(&PayloadTrace {
+                function,
+                name: "user",
+                direction: PayloadDirection::ToPlugin,
+                payload: &[0xc0],
+            });
+        }
+        assert_eq!(
+            *lines.lock().unwrap(),
+            vec![
+                "fp-bindgen payload: login(user) runtime -> plugin, 1 bytes: <redacted>",
+                "fp-bindgen payload: logout(user) runtime -> plugin, 1 bytes: c0",
+            ]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Limits how often the plugin may call the imports that are annotated with
+/// `#[fp(rate_limit(...))]`.
+///
+/// Every import has a token bucket of its own, which holds up to `calls`
+/// tokens and is refilled at a rate of `calls` tokens every `per`. Each call
+/// takes a token, and calls for which no token is available are rejected.
+///
+/// Clones share the same buckets, so that the limits apply to all clones of a
+/// runtime together.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<&'static str, TokenBucket>>>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes a token from the bucket of the given import, which is created
+    /// full on its first call.
+    ///
+    /// Returns the time after which a token becomes available if the bucket
+    /// is empty, rounded up to whole milliseconds.
+    pub fn try_acquire(
+        &self,
+        function: &'static str,
+        calls: u32,
+        per: Duration,
+    ) -> Result<(), Duration> {
+        let now = Instant::now();
+        let capacity = f64::from(calls);
+        let tokens_per_second = capacity / per.as_secs_f64();
+
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(function).or_insert(TokenBucket {
+            tokens: capacity,
+            refilled_at: now,
+        });
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * tokens_per_second).min(capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let retry_after_ms = (1000.0 * (1.0 - bucket.tokens) / tokens_per_second).ceil();
+            Err(Duration::from_millis(retry_after_ms as u64))
+        }
+    }
+
+    /// Refills the buckets of all imports.
+    pub fn reset(&self) {
+        self.buckets.lock().unwrap().clear();
+    }
+}
@@ -0,0 +1,61 @@
+use super::errors::InvocationError;
+use thiserror::Error;
+
+/// Runtime whose exports can be called with serialized arguments, so that the
+/// imports of other plugins can be routed to them.
+///
+/// This is implemented by runtimes that are generated with import routes.
+/// Arguments and results are passed on in their MessagePack encoding, so a
+/// routed call doesn't deserialize and re-serialize them in host code.
+pub trait RawExports: Send + Sync {
+    /// Returns the signature of the export with the given name, such as
+    /// `fn(String) -> String`, or `None` if the protocol has no such export
+    /// that can be called with serialized arguments.
+    fn raw_export_signature(&self, name: &str) -> Option<&'static str>;
+
+    /// Calls the export with the given name, passing the MessagePack-encoded
+    /// arguments and returning its MessagePack-encoded result.
+    fn call_raw_export(&self, name: &str, args: Vec<Vec<u8>>) -> Result<Vec<u8>, InvocationError>;
+}
+
+/// Returned when an import cannot be routed to the export of another plugin.
+#[derive(Debug, Error)]
+pub enum RouteError {
+    #[error("`{0}` is not an import that can be routed")]
+    UnknownImport(String),
+
+    #[error("the target has no export named `{0}`")]
+    MissingExport(String),
+
+    #[error("import `{name}` has signature `{import}`, but the export has `{export}`")]
+    SignatureMismatch {
+        name: String,
+        import: &'static str,
+        export: &'static str,
+    },
+}
+
+/// Checks that the given import can be routed to the export of the same name
+/// of the target.
+///
+/// `import_signature` is the signature of the import, or `None` if it
+/// cannot be routed. Signatures are compared by the names of their types.
+pub fn check_route(
+    name: &str,
+    import_signature: Option<&'static str>,
+    target: &dyn RawExports,
+) -> Result<(), RouteError> {
+    let import = import_signature.ok_or_else(|| RouteError::UnknownImport(name.to_owned()))?;
+    let export = target
+        .raw_export_signature(name)
+        .ok_or_else(|| RouteError::MissingExport(name.to_owned()))?;
+    if import == export {
+        Ok(())
+    } else {
+        Err(RouteError::SignatureMismatch {
+            name: name.to_owned(),
+            import,
+            export,
+        })
+    }
+}
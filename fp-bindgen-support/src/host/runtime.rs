@@ -1,9 +1,35 @@
+use super::errors::{AllocationFailed, PermissionDenied};
+#[cfg(feature = "http-imports-host")]
+use super::http_imports::HttpImports;
+use super::memo::ExportCache;
+use super::overrides::ImportOverrides;
+#[cfg(feature = "payload-tracing")]
+use super::payload_trace::PayloadTracer;
+use super::rate_limit::RateLimiter;
+use super::routes::RawExports;
+#[cfg(feature = "std-imports-host")]
+use super::std_imports::StdImports;
+use super::stdio::OutputHandler;
+use crate::common::guest_error::GuestError;
 use crate::common::mem::FatPtr;
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+#[cfg(feature = "async")]
+use crate::common::r#async::ASYNC_PROTOCOL_VERSION;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 use std::task::Waker;
 use wasmer::{LazyInit, Memory, NativeFunc, WasmerEnv};
 
+/// Handler for a dynamic import. It receives the MessagePack-encoded payload
+/// and returns the MessagePack-encoded result.
+pub type DynamicImportHandler = Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>;
+
+/// Subscriber to an event that is emitted by the plugin. It receives the
+/// MessagePack-encoded event and returns whether it wants to receive further
+/// events.
+pub type EventSubscriber = Box<dyn Fn(&[u8]) -> bool + Send + Sync>;
+
 #[derive(Clone, Default, WasmerEnv)]
 pub struct RuntimeInstanceData {
     #[wasmer(export)]
@@ -11,32 +37,389 @@ pub struct RuntimeInstanceData {
 
     pub(crate) wakers: Arc<Mutex<HashMap<FatPtr, Waker>>>,
 
+    /// The version of the async value protocol that was agreed on with the
+    /// plugin, or 0 if it wasn't negotiated.
+    async_protocol: Arc<AtomicU32>,
+
+    /// Handlers for dynamic imports, keyed by import name and handler name.
+    dynamic_import_handlers: Arc<RwLock<HashMap<(String, String), DynamicImportHandler>>>,
+
+    /// Subscribers to the events emitted by the plugin, keyed by event name.
+    event_subscribers: Arc<RwLock<HashMap<String, Vec<EventSubscriber>>>>,
+
+    /// Runtimes of other plugins to which imports are routed, keyed by
+    /// import name.
+    import_routes: Arc<RwLock<HashMap<String, Arc<dyn RawExports>>>>,
+
+    /// The capabilities that are granted to the plugin.
+    capabilities: Arc<HashSet<String>>,
+
+    /// The host facilities that are exposed through the built-in std imports.
+    #[cfg(feature = "std-imports-host")]
+    std_imports: Arc<StdImports>,
+
+    /// The HTTP client that is exposed through the built-in HTTP imports.
+    #[cfg(feature = "http-imports-host")]
+    http_imports: Arc<HttpImports>,
+
+    /// The context that the host attached, which is passed to its
+    /// implementations of the imports.
+    context: Option<Arc<dyn Any + Send + Sync>>,
+
+    /// The closures that replace the host's implementations of some of the
+    /// imports for this runtime.
+    import_overrides: Arc<ImportOverrides>,
+
+    /// The handler for the lines that are printed by the plugin.
+    output_handler: Option<OutputHandler>,
+
+    /// The tracer for the payloads that are passed to and from the plugin.
+    #[cfg(feature = "payload-tracing")]
+    payload_tracer: Option<PayloadTracer>,
+
+    /// The memoized results of the exports that are annotated with
+    /// `#[fp(cache)]`.
+    export_cache: ExportCache,
+
+    /// The token buckets of the imports that are annotated with
+    /// `#[fp(rate_limit)]`.
+    rate_limiter: RateLimiter,
+
     #[wasmer(export)]
     __fp_free: LazyInit<NativeFunc<FatPtr>>,
 
+    #[wasmer(export(optional = true))]
+    __fp_guest_async_protocol: LazyInit<NativeFunc<u32, u32>>,
+
+    #[wasmer(export(optional = true))]
+    __fp_guest_deadline: LazyInit<NativeFunc<(), u64>>,
+
     #[wasmer(export)]
     __fp_guest_resolve_async_value: LazyInit<NativeFunc<(FatPtr, FatPtr)>>,
 
+    #[wasmer(export(optional = true))]
+    __fp_guest_set_deadline: LazyInit<NativeFunc<u64>>,
+
     #[wasmer(export)]
     __fp_malloc: LazyInit<NativeFunc<u32, FatPtr>>,
 }
 
 impl RuntimeInstanceData {
     pub fn guest_resolve_async_value(&self, async_ptr: FatPtr, result_ptr: FatPtr) {
-        unsafe {
+        let result = unsafe {
             self.__fp_guest_resolve_async_value
                 .get_unchecked()
                 .call(async_ptr, result_ptr)
-                .expect("Runtime error: Cannot resolve async value");
+        };
+        if let Err(error) = result {
+            // Plugins that fail while resuming a task report the `GuestError`
+            // through the task's async value as well, once version 2 of the
+            // async value protocol was agreed on, so the trap has been handled:
+            let rejected = self.async_protocol.load(Ordering::Relaxed) >= 2;
+            if !(rejected && error.is::<GuestError>()) {
+                panic!("Runtime error: Cannot resolve async value: {}", error);
+            }
         }
     }
 
+    /// Announces the latest version of the async value protocol that the
+    /// runtime supports to the plugin, so that plugins that support version 2
+    /// can reject async values with a `GuestError`.
+    ///
+    /// Does nothing if the plugin only supports version 1.
+    #[cfg(feature = "async")]
+    pub fn negotiate_async_protocol(&self) {
+        if let Some(function) = self.__fp_guest_async_protocol.get_ref() {
+            let version = function
+                .call(ASYNC_PROTOCOL_VERSION)
+                .expect("Runtime error: Cannot negotiate async protocol");
+            self.async_protocol
+                .store(version.min(ASYNC_PROTOCOL_VERSION), Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the deadline of the plugin's current call, in milliseconds
+    /// since the Unix epoch, or 0 if it has none.
+    ///
+    /// Returns `None` if the plugin doesn't support deadlines.
+    pub fn guest_deadline(&self) -> Option<u64> {
+        let function = self.__fp_guest_deadline.get_ref()?;
+        Some(
+            function
+                .call()
+                .expect("Runtime error: Cannot retrieve deadline"),
+        )
+    }
+
+    /// Sets the deadline of the call that is about to be made to the plugin,
+    /// in milliseconds since the Unix epoch, or 0 if it has none.
+    ///
+    /// Does nothing if the plugin doesn't support deadlines.
+    pub fn set_guest_deadline(&self, deadline: u64) {
+        if let Some(function) = self.__fp_guest_set_deadline.get_ref() {
+            function
+                .call(deadline)
+                .expect("Runtime error: Cannot set deadline");
+        }
+    }
+
+    /// Returns a new, uninitialized instance that shares its dynamic import
+    /// handlers, event subscribers, import routes, granted capabilities, std
+    /// and HTTP imports, host context, import overrides, output handler,
+    /// export cache and rate limits with this one.
+    ///
+    /// This is used for pooled runtimes, so that handlers registered through
+    /// any of the instances in the pool apply to all of them.
+    pub fn with_shared_dynamic_import_handlers(&self) -> Self {
+        Self {
+            dynamic_import_handlers: self.dynamic_import_handlers.clone(),
+            event_subscribers: self.event_subscribers.clone(),
+            import_routes: self.import_routes.clone(),
+            capabilities: self.capabilities.clone(),
+            #[cfg(feature = "std-imports-host")]
+            std_imports: self.std_imports.clone(),
+            #[cfg(feature = "http-imports-host")]
+            http_imports: self.http_imports.clone(),
+            context: self.context.clone(),
+            import_overrides: self.import_overrides.clone(),
+            output_handler: self.output_handler.clone(),
+            #[cfg(feature = "payload-tracing")]
+            payload_tracer: self.payload_tracer.clone(),
+            export_cache: self.export_cache.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Grants the given capabilities to the plugin, in addition to the ones
+    /// that were already granted.
+    pub fn with_capabilities<'a>(
+        mut self,
+        capabilities: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let mut granted = (*self.capabilities).clone();
+        granted.extend(capabilities.into_iter().map(str::to_owned));
+        self.capabilities = Arc::new(granted);
+        self
+    }
+
+    /// Sets the host facilities that are exposed through the built-in std
+    /// imports.
+    #[cfg(feature = "std-imports-host")]
+    pub fn with_std_imports(mut self, std_imports: StdImports) -> Self {
+        self.std_imports = Arc::new(std_imports);
+        self
+    }
+
+    /// Returns the host facilities that are exposed through the built-in std
+    /// imports.
+    #[cfg(feature = "std-imports-host")]
+    pub fn std_imports(&self) -> &StdImports {
+        &self.std_imports
+    }
+
+    /// Sets the HTTP client that is exposed through the built-in HTTP
+    /// imports.
+    #[cfg(feature = "http-imports-host")]
+    pub fn with_http_imports(mut self, http_imports: HttpImports) -> Self {
+        self.http_imports = Arc::new(http_imports);
+        self
+    }
+
+    /// Returns the HTTP client that is exposed through the built-in HTTP
+    /// imports.
+    #[cfg(feature = "http-imports-host")]
+    pub fn http_imports(&self) -> &HttpImports {
+        &self.http_imports
+    }
+
+    /// Attaches the context that is passed to the host's implementations of
+    /// the imports, replacing any context that was attached before.
+    pub fn with_context(mut self, context: Option<Arc<dyn Any + Send + Sync>>) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Returns the context that the host attached, unless none was attached
+    /// or it is not of type `T`.
+    pub fn context<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.context.clone()?.downcast().ok()
+    }
+
+    /// Sets the closures that replace the host's implementations of some of
+    /// the imports, replacing any overrides that were set before.
+    pub fn with_import_overrides(mut self, import_overrides: ImportOverrides) -> Self {
+        self.import_overrides = Arc::new(import_overrides);
+        self
+    }
+
+    /// Returns the override of the import with the given name, if it is
+    /// overridden with a closure of type `F`.
+    pub fn import_override<F: ?Sized + Send + Sync + 'static>(&self, name: &str) -> Option<Arc<F>> {
+        self.import_overrides.get(name)
+    }
+
+    /// Sets the handler for the lines that are printed by the plugin.
+    pub fn with_output_handler(mut self, output_handler: Option<OutputHandler>) -> Self {
+        self.output_handler = output_handler;
+        self
+    }
+
+    /// Returns the handler for the lines that are printed by the plugin, if
+    /// any.
+    pub fn output_handler(&self) -> Option<&OutputHandler> {
+        self.output_handler.as_ref()
+    }
+
+    /// Sets the tracer for the payloads that are passed to and from the
+    /// plugin. Without one, the tracer that is configured through the
+    /// `FP_BINDGEN_TRACE_PAYLOADS` environment variable is used, if any.
+    #[cfg(feature = "payload-tracing")]
+    pub fn with_payload_tracer(mut self, payload_tracer: Option<PayloadTracer>) -> Self {
+        self.payload_tracer = payload_tracer;
+        self
+    }
+
+    /// Returns the tracer for the payloads that are passed to and from the
+    /// plugin, if the runtime has one of its own.
+    #[cfg(feature = "payload-tracing")]
+    pub fn payload_tracer(&self) -> Option<&PayloadTracer> {
+        self.payload_tracer.as_ref()
+    }
+
+    /// Returns the memoized results of the exports that are annotated with
+    /// `#[fp(cache)]`.
+    pub fn export_cache(&self) -> &ExportCache {
+        &self.export_cache
+    }
+
+    /// Returns the token buckets of the imports that are annotated with
+    /// `#[fp(rate_limit)]`.
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Checks whether the given capability, which is required by the import
+    /// `function`, was granted to the plugin.
+    pub fn check_capability(
+        &self,
+        function: &str,
+        capability: &str,
+    ) -> Result<(), PermissionDenied> {
+        if self.capabilities.contains(capability) {
+            Ok(())
+        } else {
+            Err(PermissionDenied {
+                function: function.to_owned(),
+                capability: capability.to_owned(),
+            })
+        }
+    }
+
+    /// Returns the handler registered under `handler_name` for the given
+    /// dynamic import, if any.
+    pub fn dynamic_import_handler(
+        &self,
+        import_name: &str,
+        handler_name: &str,
+    ) -> Option<DynamicImportHandler> {
+        self.dynamic_import_handlers
+            .read()
+            .unwrap()
+            .get(&(import_name.to_owned(), handler_name.to_owned()))
+            .cloned()
+    }
+
+    /// Registers a handler for a dynamic import, replacing any handler that
+    /// was previously registered under the same name.
+    pub fn register_dynamic_import_handler(
+        &self,
+        import_name: &str,
+        handler_name: String,
+        handler: DynamicImportHandler,
+    ) {
+        self.dynamic_import_handlers
+            .write()
+            .unwrap()
+            .insert((import_name.to_owned(), handler_name), handler);
+    }
+
+    /// Unregisters a handler for a dynamic import. Returns whether a handler
+    /// was registered under the given name.
+    pub fn unregister_dynamic_import_handler(&self, import_name: &str, handler_name: &str) -> bool {
+        self.dynamic_import_handlers
+            .write()
+            .unwrap()
+            .remove(&(import_name.to_owned(), handler_name.to_owned()))
+            .is_some()
+    }
+
+    /// Returns the runtime to which the given import is routed, if any.
+    pub fn import_route(&self, import_name: &str) -> Option<Arc<dyn RawExports>> {
+        self.import_routes.read().unwrap().get(import_name).cloned()
+    }
+
+    /// Routes the given import to the export of the same name of the given
+    /// runtime, replacing any route that was previously set for it.
+    pub fn set_import_route(&self, import_name: &str, target: Arc<dyn RawExports>) {
+        self.import_routes
+            .write()
+            .unwrap()
+            .insert(import_name.to_owned(), target);
+    }
+
+    /// Removes the route of the given import. Returns whether the import was
+    /// routed.
+    pub fn remove_import_route(&self, import_name: &str) -> bool {
+        self.import_routes
+            .write()
+            .unwrap()
+            .remove(import_name)
+            .is_some()
+    }
+
+    /// Subscribes to the event with the given name.
+    pub fn subscribe_to_event(&self, event_name: &str, subscriber: EventSubscriber) {
+        self.event_subscribers
+            .write()
+            .unwrap()
+            .entry(event_name.to_owned())
+            .or_default()
+            .push(subscriber);
+    }
+
+    /// Passes the MessagePack-encoded event to all subscribers of the event
+    /// with the given name. Subscribers that no longer want to receive events
+    /// are removed.
+    pub fn emit_event(&self, event_name: &str, event: &[u8]) {
+        if let Some(subscribers) = self.event_subscribers.write().unwrap().get_mut(event_name) {
+            subscribers.retain(|subscriber| subscriber(event));
+        }
+    }
+
+    /// Allocates `len` bytes in the linear memory of the plugin.
+    ///
+    /// Panics if the plugin could not allocate the memory. Use `try_malloc()`
+    /// to handle that case instead.
     pub fn malloc(&self, len: u32) -> FatPtr {
-        unsafe {
+        self.try_malloc(len)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Allocates `len` bytes in the linear memory of the plugin, or returns
+    /// an error if the plugin could not allocate them.
+    pub fn try_malloc(&self, len: u32) -> Result<FatPtr, AllocationFailed> {
+        let fat_ptr = unsafe {
             self.__fp_malloc
                 .get_unchecked()
                 .call(len)
                 .expect("unable to call malloc")
+        };
+        // Plugins return a null pointer if the allocation failed:
+        if fat_ptr == 0 && len != 0 {
+            Err(AllocationFailed { len })
+        } else {
+            Ok(fat_ptr)
         }
     }
 
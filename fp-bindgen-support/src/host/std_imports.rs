@@ -0,0 +1,212 @@
+use super::{
+    errors::PermissionDenied,
+    mem::{export_to_guest, import_from_guest},
+    runtime::RuntimeInstanceData,
+};
+use crate::common::{
+    mem::FatPtr,
+    std_imports::{FileError, CLOCK_CAPABILITY, FS_CAPABILITY, RANDOM_CAPABILITY},
+};
+use serde_bytes::ByteBuf;
+use std::{
+    fmt, fs, io,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Clock that is used to answer calls to the `now()` std import.
+pub type Clock = Arc<dyn Fn() -> SystemTime + Send + Sync>;
+
+/// Monotonic clock that is used to answer calls to the `fp_now()` std import.
+/// It returns the time that has passed since an arbitrary, fixed origin.
+pub type MonotonicClock = Arc<dyn Fn() -> Duration + Send + Sync>;
+
+/// Random number generator that is used to answer calls to the
+/// `random_bytes()` std import. It fills the given buffer with random bytes.
+pub type RandomSource = Arc<dyn Fn(&mut [u8]) + Send + Sync>;
+
+/// The host facilities that are exposed to the plugin through the built-in std
+/// imports.
+///
+/// By default, the plugin doesn't have access to any files, while the clocks
+/// and random number generator of the operating system are used. Any of them
+/// can be virtualized, for instance to run plugins deterministically.
+///
+/// Regardless of these settings, the plugin can only use the std imports if
+/// it was granted the `fs`, `clock` and `random` capabilities.
+#[derive(Clone)]
+pub struct StdImports {
+    root: Option<PathBuf>,
+    clock: Clock,
+    monotonic_clock: MonotonicClock,
+    random: RandomSource,
+}
+
+impl Default for StdImports {
+    fn default() -> Self {
+        Self {
+            root: None,
+            clock: Arc::new(SystemTime::now),
+            monotonic_clock: {
+                let origin = Instant::now();
+                Arc::new(move || origin.elapsed())
+            },
+            random: Arc::new(|buffer| {
+                getrandom::getrandom(buffer).expect("Could not generate random bytes")
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for StdImports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StdImports")
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StdImports {
+    /// Returns the default std imports. See [`StdImports`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exposes the given directory to the plugin as the root of its virtual
+    /// file system.
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    /// Uses the given clock, rather than the clock of the operating system.
+    pub fn with_clock(mut self, clock: impl Fn() -> SystemTime + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Uses the given monotonic clock, rather than the one of the operating
+    /// system. The clock returns the time since an arbitrary origin, and
+    /// should never go backwards.
+    pub fn with_monotonic_clock(
+        mut self,
+        monotonic_clock: impl Fn() -> Duration + Send + Sync + 'static,
+    ) -> Self {
+        self.monotonic_clock = Arc::new(monotonic_clock);
+        self
+    }
+
+    /// Uses the given random number generator, rather than the one of the
+    /// operating system.
+    pub fn with_random(mut self, random: impl Fn(&mut [u8]) + Send + Sync + 'static) -> Self {
+        self.random = Arc::new(random);
+        self
+    }
+
+    /// Reads the file at the given virtual path.
+    pub fn read_file(&self, path: &str) -> Result<Vec<u8>, FileError> {
+        fs::read(self.resolve_path(path)?).map_err(to_file_error)
+    }
+
+    /// Writes the file at the given virtual path, creating any parent
+    /// directories that don't exist yet.
+    pub fn write_file(&self, path: &str, contents: &[u8]) -> Result<(), FileError> {
+        let path = self.resolve_path(path)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(to_file_error)?;
+        }
+        fs::write(path, contents).map_err(to_file_error)
+    }
+
+    /// Returns the current time of the clock.
+    pub fn now(&self) -> SystemTime {
+        (self.clock)()
+    }
+
+    /// Returns the current time of the monotonic clock, as the time since its
+    /// origin.
+    pub fn monotonic_now(&self) -> Duration {
+        (self.monotonic_clock)()
+    }
+
+    /// Returns the given number of random bytes.
+    pub fn random_bytes(&self, len: usize) -> Vec<u8> {
+        let mut buffer = vec![0; len];
+        (self.random)(&mut buffer);
+        buffer
+    }
+
+    /// Resolves a virtual path to a path within the root directory.
+    ///
+    /// Only relative paths that consist of normal components are accepted, so
+    /// that the plugin cannot escape the root directory.
+    fn resolve_path(&self, path: &str) -> Result<PathBuf, FileError> {
+        let root = self.root.as_ref().ok_or(FileError::Unsupported)?;
+        let path = Path::new(path);
+        let is_valid = path.components().next().is_some()
+            && path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if is_valid {
+            Ok(root.join(path))
+        } else {
+            Err(FileError::InvalidPath)
+        }
+    }
+}
+
+fn to_file_error(error: io::Error) -> FileError {
+    match error.kind() {
+        io::ErrorKind::NotFound => FileError::NotFound,
+        _ => FileError::Other(error.to_string()),
+    }
+}
+
+/// Implementation of the reserved `__fp_std_read_file()` import.
+pub fn std_read_file(env: &RuntimeInstanceData, path: FatPtr) -> Result<FatPtr, PermissionDenied> {
+    env.check_capability("read_file", FS_CAPABILITY)?;
+    let path: String = import_from_guest(env, path);
+    let result = env.std_imports().read_file(&path).map(ByteBuf::from);
+    Ok(export_to_guest(env, &result))
+}
+
+/// Implementation of the reserved `__fp_std_write_file()` import.
+pub fn std_write_file(
+    env: &RuntimeInstanceData,
+    path: FatPtr,
+    contents: FatPtr,
+) -> Result<FatPtr, PermissionDenied> {
+    env.check_capability("write_file", FS_CAPABILITY)?;
+    let path: String = import_from_guest(env, path);
+    let contents: ByteBuf = import_from_guest(env, contents);
+    let result = env.std_imports().write_file(&path, &contents);
+    Ok(export_to_guest(env, &result))
+}
+
+/// Implementation of the reserved `__fp_std_now()` import, which returns the
+/// number of milliseconds since the Unix epoch.
+pub fn std_now(env: &RuntimeInstanceData) -> Result<u64, PermissionDenied> {
+    env.check_capability("now", CLOCK_CAPABILITY)?;
+    let millis = env
+        .std_imports()
+        .now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default();
+    Ok(millis)
+}
+
+/// Implementation of the reserved `__fp_std_monotonic_now()` import, which
+/// returns the number of nanoseconds since the origin of the monotonic clock.
+pub fn std_monotonic_now(env: &RuntimeInstanceData) -> Result<u64, PermissionDenied> {
+    env.check_capability("monotonic_now", CLOCK_CAPABILITY)?;
+    Ok(env.std_imports().monotonic_now().as_nanos() as u64)
+}
+
+/// Implementation of the reserved `__fp_std_random_bytes()` import.
+pub fn std_random_bytes(env: &RuntimeInstanceData, len: u32) -> Result<FatPtr, PermissionDenied> {
+    env.check_capability("random_bytes", RANDOM_CAPABILITY)?;
+    let bytes = ByteBuf::from(env.std_imports().random_bytes(len as usize));
+    Ok(export_to_guest(env, &bytes))
+}
@@ -0,0 +1,22 @@
+use super::{mem::import_from_guest, runtime::RuntimeInstanceData};
+use crate::common::{mem::FatPtr, stdio::OutputStream};
+use std::sync::Arc;
+
+/// Handler for the lines that are printed by the plugin.
+pub type OutputHandler = Arc<dyn Fn(OutputStream, &str) + Send + Sync>;
+
+/// Implementation of the reserved `__fp_host_print()` import.
+///
+/// Lines are passed to the output handler of the runtime, or printed to the
+/// stdout or stderr of the host if it has none.
+pub fn host_print(env: &RuntimeInstanceData, stream: u32, line: FatPtr) {
+    let line: String = import_from_guest(env, line);
+    let stream = OutputStream::from_fd(stream);
+    match env.output_handler() {
+        Some(handler) => handler(stream, &line),
+        None => match stream {
+            OutputStream::Stdout => println!("{}", line),
+            OutputStream::Stderr => eprintln!("{}", line),
+        },
+    }
+}
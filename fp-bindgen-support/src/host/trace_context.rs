@@ -0,0 +1,39 @@
+use super::{mem::export_to_guest, runtime::RuntimeInstanceData};
+use crate::common::{mem::FatPtr, trace_context::TraceContext};
+use opentelemetry::global;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+pub use tracing::Instrument;
+
+/// Creates the span for a call from the runtime to a function that is
+/// exported by the plugin.
+pub fn export_span(function: &'static str) -> Span {
+    tracing::info_span!("fp_bindgen.export", function)
+}
+
+/// Creates the span for a call from the plugin to a function that is
+/// imported from the runtime.
+pub fn import_span(function: &'static str) -> Span {
+    tracing::info_span!("fp_bindgen.import", function)
+}
+
+/// Returns the trace context of the current span, as it is injected by the
+/// globally configured text map propagator.
+///
+/// The context is empty if no propagator is configured, or if the current
+/// span is not recorded by a `tracing-opentelemetry` layer.
+pub fn current_trace_context() -> TraceContext {
+    let context = Span::current().context();
+    let mut trace_context = TraceContext::new();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut trace_context)
+    });
+    trace_context
+}
+
+/// Implementation of the reserved `__fp_host_trace_context()` import, which
+/// passes the trace context of the current call to the plugin.
+pub fn trace_context(env: &RuntimeInstanceData) -> FatPtr {
+    export_to_guest(env, &current_trace_context())
+}
@@ -5,5 +5,7 @@ pub mod guest;
 pub mod host;
 #[cfg(feature = "http")]
 pub mod http;
+#[cfg(feature = "wasmi-host")]
+pub mod wasmi_host;
 
 pub use fp_bindgen_macros::{fp_export_impl, fp_export_signature, fp_import_signature};
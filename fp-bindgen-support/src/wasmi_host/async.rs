@@ -0,0 +1,201 @@
+use super::{
+    errors::InvocationError,
+    mem::{export_to_guest_raw, from_fat_ptr, malloc, to_fat_ptr, try_import_from_guest_raw},
+    runtime::RuntimeInstanceData,
+};
+use crate::common::{
+    mem::FatPtr,
+    r#async::{AsyncValue, FUTURE_STATUS_PENDING, FUTURE_STATUS_READY},
+};
+use serde::Serialize;
+use std::{
+    convert::TryInto,
+    future::Future,
+    mem::{size_of, take},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use wasmi::{AsContextMut, Caller};
+
+/// An async import that was called by the plugin, but has not resolved yet.
+pub struct PendingImport {
+    /// Pointer to the `AsyncValue` the plugin awaits, or `None` if the plugin
+    /// is not interested in the result.
+    async_ptr: Option<FatPtr>,
+    future: Pin<Box<dyn Future<Output = Vec<u8>>>>,
+}
+
+/// Create an empty AsyncValue in the linear memory and return a FatPtr to it.
+pub fn create_future_value(mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>) -> FatPtr {
+    let size = size_of::<AsyncValue>();
+    let ptr = malloc(&mut ctx, size as u32);
+
+    write_async_value(&mut ctx, ptr, AsyncValue::new());
+
+    ptr
+}
+
+/// Registers the future returned by an async import, so it can be driven
+/// while the host awaits an async export, and returns a pointer to the
+/// AsyncValue the plugin can await.
+pub fn spawn_import<T: Serialize>(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    future: impl Future<Output = T> + 'static,
+) -> FatPtr {
+    let async_ptr = create_future_value(&mut ctx);
+    ctx.as_context_mut()
+        .data_mut()
+        .pending_imports
+        .push(PendingImport {
+            async_ptr: Some(async_ptr),
+            future: Box::pin(async move { rmp_serde::to_vec(&future.await).unwrap() }),
+        });
+    async_ptr
+}
+
+/// Registers the future returned by an async import that doesn't return a
+/// value. The plugin doesn't await these, so they are fire-and-forget.
+pub fn spawn_import_without_result(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    future: impl Future<Output = ()> + 'static,
+) {
+    ctx.as_context_mut()
+        .data_mut()
+        .pending_imports
+        .push(PendingImport {
+            async_ptr: None,
+            future: Box::pin(async move {
+                future.await;
+                Vec::new()
+            }),
+        });
+}
+
+/// Note: In this case we are only interested in the pointer itself, we do not
+/// want to deserialize it (which would actually free it as well).
+pub fn resolve_async_value(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    async_value_ptr: FatPtr,
+    result_ptr: FatPtr,
+) {
+    let (ptr, len) = from_fat_ptr(result_ptr);
+    write_async_value(
+        &mut caller,
+        async_value_ptr,
+        AsyncValue {
+            status: FUTURE_STATUS_READY,
+            ptr,
+            len,
+        },
+    );
+}
+
+/// Polls the AsyncValue returned by an async export.
+///
+/// There is no executor that drives the futures of async imports in the
+/// background, so while the value is pending, this polls the pending imports
+/// instead and resolves the ones that are ready in the plugin, which may in
+/// turn resolve the value.
+pub fn poll_async_value(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    async_ptr: FatPtr,
+    cx: &mut Context<'_>,
+) -> Poll<Result<Vec<u8>, InvocationError>> {
+    loop {
+        let value = read_async_value(&mut ctx, async_ptr);
+        match value.status {
+            FUTURE_STATUS_PENDING => {
+                if !drive_pending_imports(&mut ctx, cx) {
+                    return Poll::Pending;
+                }
+            }
+            FUTURE_STATUS_READY => {
+                let result =
+                    try_import_from_guest_raw(&mut ctx, to_fat_ptr(value.ptr, value.len));
+                return Poll::Ready(result);
+            }
+            status => panic!(
+                "expected async value FUTURE_STATUS_PENDING ({}) or FUTURE_STATUS_READY ({}) but got: {}",
+                FUTURE_STATUS_PENDING, FUTURE_STATUS_READY, status
+            ),
+        }
+    }
+}
+
+/// Polls the pending async imports until all of them are done, including the
+/// fire-and-forget ones that aren't awaited by any async export.
+pub fn poll_pending_imports(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    cx: &mut Context<'_>,
+) -> Poll<()> {
+    loop {
+        if ctx.as_context().data().pending_imports.is_empty() {
+            return Poll::Ready(());
+        }
+        if !drive_pending_imports(&mut ctx, cx) {
+            return Poll::Pending;
+        }
+    }
+}
+
+/// Polls every pending import once. Returns whether any of them completed.
+fn drive_pending_imports(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    cx: &mut Context<'_>,
+) -> bool {
+    let pending_imports = take(&mut ctx.as_context_mut().data_mut().pending_imports);
+
+    let mut completed_any = false;
+    let mut still_pending = Vec::with_capacity(pending_imports.len());
+    for mut import in pending_imports {
+        match import.future.as_mut().poll(cx) {
+            Poll::Ready(result) => {
+                completed_any = true;
+                if let Some(async_ptr) = import.async_ptr {
+                    let result_ptr = export_to_guest_raw(&mut ctx, result);
+                    let resolve = ctx.as_context().data().guest_resolve_async_value_func();
+                    resolve
+                        .call(&mut ctx, (async_ptr, result_ptr))
+                        .expect("Runtime error: Cannot resolve async value");
+                }
+            }
+            Poll::Pending => still_pending.push(import),
+        }
+    }
+
+    // Resolving values in the plugin may have called new async imports:
+    let mut ctx = ctx.as_context_mut();
+    let data = ctx.data_mut();
+    still_pending.append(&mut data.pending_imports);
+    data.pending_imports = still_pending;
+
+    completed_any
+}
+
+fn read_async_value(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    async_ptr: FatPtr,
+) -> AsyncValue {
+    let (ptr, _) = from_fat_ptr(async_ptr);
+    let memory = ctx.as_context().data().memory();
+    let data = &memory.data(&mut ctx)[ptr as usize..ptr as usize + size_of::<AsyncValue>()];
+    let read_u32 = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+    AsyncValue {
+        status: read_u32(0),
+        ptr: read_u32(4),
+        len: read_u32(8),
+    }
+}
+
+fn write_async_value(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    async_ptr: FatPtr,
+    value: AsyncValue,
+) {
+    let (ptr, _) = from_fat_ptr(async_ptr);
+    let memory = ctx.as_context().data().memory();
+    let data = &mut memory.data_mut(&mut ctx)[ptr as usize..ptr as usize + size_of::<AsyncValue>()];
+    data[0..4].copy_from_slice(&value.status.to_le_bytes());
+    data[4..8].copy_from_slice(&value.ptr.to_le_bytes());
+    data[8..12].copy_from_slice(&value.len.to_le_bytes());
+}
@@ -0,0 +1,204 @@
+use super::{mem::import_from_guest, runtime::RuntimeInstanceData};
+pub use crate::common::guest_error::{GuestError, GuestErrorKind};
+use crate::common::mem::FatPtr;
+pub use crate::common::wasm_features::DisabledWasmFeatures;
+use std::fmt::{self, Display, Formatter};
+use wasmi::{
+    core::{HostError, Trap},
+    AsContext, Caller, Instance,
+};
+
+#[derive(Debug)]
+pub enum RuntimeError {
+    WasmiError(wasmi::Error),
+
+    /// The plugin does not export a function or memory that is required by
+    /// the runtime, such as `__fp_malloc`.
+    MissingExport(String),
+
+    /// The `init` export of the plugin failed, when it was called while the
+    /// runtime was built.
+    InitFailed(InvocationError),
+
+    /// The plugin uses WebAssembly features that were disabled on the
+    /// `RuntimeBuilder`.
+    DisabledWasmFeatures(DisabledWasmFeatures),
+}
+
+impl Display for RuntimeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WasmiError(error) => error.fmt(f),
+            Self::MissingExport(name) => write!(f, "required export is missing: {}", name),
+            Self::InitFailed(error) => write!(f, "plugin failed to initialize: {}", error),
+            Self::DisabledWasmFeatures(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for RuntimeError {}
+
+impl From<wasmi::Error> for RuntimeError {
+    fn from(error: wasmi::Error) -> Self {
+        Self::WasmiError(error)
+    }
+}
+
+impl From<DisabledWasmFeatures> for RuntimeError {
+    fn from(error: DisabledWasmFeatures) -> Self {
+        Self::DisabledWasmFeatures(error)
+    }
+}
+
+impl From<wasmi::errors::LinkerError> for RuntimeError {
+    fn from(error: wasmi::errors::LinkerError) -> Self {
+        Self::WasmiError(error.into())
+    }
+}
+
+#[derive(Debug)]
+pub enum InvocationError {
+    FunctionNotExported(String),
+
+    SignatureMismatch(String),
+
+    DeserializationFailed {
+        function: String,
+        type_name: &'static str,
+        message: String,
+    },
+
+    PermissionDenied(PermissionDenied),
+
+    AllocationFailed(AllocationFailed),
+
+    GuestError(GuestError),
+
+    #[cfg(feature = "compression")]
+    DecompressionFailed(crate::common::compression::DecompressionError),
+
+    WasmiTrap(Trap),
+}
+
+impl Display for InvocationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FunctionNotExported(name) => {
+                write!(f, "expected function was not exported: {}", name)
+            }
+            Self::SignatureMismatch(name) => {
+                write!(
+                    f,
+                    "function was exported with an unexpected signature: {}",
+                    name
+                )
+            }
+            Self::DeserializationFailed {
+                function,
+                type_name,
+                message,
+            } => write!(
+                f,
+                "could not deserialize the `{}` returned by `{}`: {}",
+                type_name, function, message
+            ),
+            Self::PermissionDenied(error) => error.fmt(f),
+            Self::AllocationFailed(error) => error.fmt(f),
+            Self::GuestError(error) => error.fmt(f),
+            #[cfg(feature = "compression")]
+            Self::DecompressionFailed(error) => write!(
+                f,
+                "could not decompress the payload sent by the plugin: {}",
+                error
+            ),
+            Self::WasmiTrap(trap) => trap.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for InvocationError {}
+
+impl InvocationError {
+    /// Returns the error for an export that could not be retrieved from the
+    /// plugin, which depends on whether the plugin exports it at all.
+    pub fn from_missing_export(instance: &Instance, store: impl AsContext, name: &str) -> Self {
+        if instance.get_func(store, name).is_some() {
+            Self::SignatureMismatch(name.to_owned())
+        } else {
+            Self::FunctionNotExported(name.to_owned())
+        }
+    }
+}
+
+impl From<Trap> for InvocationError {
+    fn from(trap: Trap) -> Self {
+        // Imports that are denied trap the plugin with a `PermissionDenied`
+        // error, and failures reported by the plugin trap it with a
+        // `GuestError`, which we surface as is.
+        if let Some(error) = trap.downcast_ref::<PermissionDenied>() {
+            Self::PermissionDenied(error.clone())
+        } else if let Some(error) = trap.downcast_ref::<AllocationFailed>() {
+            Self::AllocationFailed(error.clone())
+        } else if let Some(error) = trap.downcast_ref::<GuestError>() {
+            Self::GuestError(error.clone())
+        } else {
+            Self::WasmiTrap(trap)
+        }
+    }
+}
+
+/// Returned when a plugin calls an import that requires a capability that was
+/// not granted to it.
+#[derive(Clone, Debug)]
+pub struct PermissionDenied {
+    pub function: String,
+    pub capability: String,
+}
+
+impl Display for PermissionDenied {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "import `{}` requires the `{}` capability, which was not granted",
+            self.function, self.capability
+        )
+    }
+}
+
+impl HostError for PermissionDenied {}
+
+impl From<AllocationFailed> for InvocationError {
+    fn from(error: AllocationFailed) -> Self {
+        Self::AllocationFailed(error)
+    }
+}
+
+/// Returned when the plugin could not allocate the memory for a value that is
+/// passed to it, for instance because it ran out of memory.
+#[derive(Clone, Debug)]
+pub struct AllocationFailed {
+    pub len: u32,
+}
+
+impl Display for AllocationFailed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "plugin could not allocate {} bytes", self.len)
+    }
+}
+
+impl HostError for AllocationFailed {}
+
+impl HostError for GuestError {}
+
+/// Implementation of the reserved `__fp_error()` import, through which plugins
+/// that are built with the `panic-abort` feature report their failures.
+///
+/// The report is returned as a trap, so that the invocation fails with
+/// `InvocationError::GuestError`.
+pub fn guest_error(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    report: FatPtr,
+) -> Result<(), Trap> {
+    let error: GuestError = import_from_guest(&mut caller, report);
+    Err(error.into())
+}
@@ -0,0 +1,12 @@
+use super::{mem::import_from_guest, runtime::RuntimeInstanceData};
+use crate::common::{interning::register, mem::FatPtr};
+use wasmi::Caller;
+
+/// Implementation of the reserved `__fp_intern_string()` import.
+///
+/// Registers the string with the intern table of the runtime, and returns the
+/// handle that the plugin sends in its place from now on.
+pub fn intern_string(mut caller: Caller<'_, RuntimeInstanceData>, value: FatPtr) -> u32 {
+    let value: String = import_from_guest(&mut caller, value);
+    register(&value)
+}
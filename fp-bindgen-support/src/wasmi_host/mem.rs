@@ -0,0 +1,207 @@
+use super::{
+    errors::{AllocationFailed, InvocationError},
+    runtime::RuntimeInstanceData,
+};
+use crate::common::mem::FatPtr;
+use rmp_serde::{decode::ReadReader, Deserializer, Serializer};
+use serde::{Deserialize, Serialize};
+use wasmi::AsContextMut;
+
+/// Serialize the given value to MessagePack
+pub fn serialize_to_vec<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let mut serializer = Serializer::new(&mut buffer)
+        .with_struct_map()
+        .with_human_readable();
+    value.serialize(&mut serializer).unwrap();
+    buffer
+}
+
+/// Deserialize the given MessagePack-encoded slice
+///
+/// Strings and byte arrays may borrow from the slice, so types such as `&[u8]`
+/// can be deserialized without copying.
+pub fn deserialize_from_slice<'a, T: Deserialize<'a>>(slice: &'a [u8]) -> T {
+    let mut deserializer = Deserializer::from_read_ref(slice).with_human_readable();
+    T::deserialize(&mut deserializer).unwrap()
+}
+
+/// Deserialize the given MessagePack-encoded slice, which was returned by the
+/// given function.
+///
+/// Unlike `deserialize_from_slice()`, this returns an error if the slice does
+/// not contain a valid `type_name`.
+pub fn try_deserialize_from_slice<'a, T: Deserialize<'a>>(
+    slice: &'a [u8],
+    function: &str,
+    type_name: &'static str,
+) -> Result<T, InvocationError> {
+    let mut deserializer = Deserializer::from_read_ref(slice).with_human_readable();
+    T::deserialize(&mut deserializer).map_err(|error| InvocationError::DeserializationFailed {
+        function: function.to_owned(),
+        type_name,
+        message: error.to_string(),
+    })
+}
+
+/// Get a regular pointer and the length from a fat pointer
+pub(crate) fn from_fat_ptr(ptr: FatPtr) -> (u32, u32) {
+    ((ptr >> 32) as u32, (ptr & 0xffffffff) as u32)
+}
+
+/// Create a fat pointer from a ptr and length
+#[cfg(feature = "async")]
+pub(crate) fn to_fat_ptr(ptr: u32, len: u32) -> FatPtr {
+    (ptr as FatPtr) << 32 | (len as FatPtr)
+}
+
+/// Allocate `len` bytes in the linear memory of the plugin.
+///
+/// Panics if the plugin could not allocate the memory. Use `try_malloc()` to
+/// handle that case instead.
+pub fn malloc(ctx: impl AsContextMut<UserState = RuntimeInstanceData>, len: u32) -> FatPtr {
+    try_malloc(ctx, len).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Allocate `len` bytes in the linear memory of the plugin, or return an error
+/// if the plugin could not allocate them.
+pub fn try_malloc(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    len: u32,
+) -> Result<FatPtr, AllocationFailed> {
+    let malloc = ctx.as_context().data().malloc_func();
+    let fat_ptr = malloc.call(&mut ctx, len).expect("unable to call malloc");
+    // Plugins return a null pointer if the allocation failed:
+    if fat_ptr == 0 && len != 0 {
+        Err(AllocationFailed { len })
+    } else {
+        Ok(fat_ptr)
+    }
+}
+
+/// Free memory that was allocated in the linear memory of the plugin.
+pub fn free(mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>, ptr: FatPtr) {
+    let free = ctx.as_context().data().free_func();
+    free.call(&mut ctx, ptr).expect("unable to call free")
+}
+
+/// Serialize an object from the linear memory and after that free up the memory
+pub fn import_from_guest<'de, T: Deserialize<'de>>(
+    ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    fat_ptr: FatPtr,
+) -> T {
+    let value = import_from_guest_raw(ctx, fat_ptr);
+
+    let mut deserializer =
+        Deserializer::<ReadReader<&[u8]>>::new(value.as_ref()).with_human_readable();
+    T::deserialize(&mut deserializer).unwrap()
+}
+
+/// Retrieve a serialized object from the linear memory as a Vec<u8> and free up
+/// the memory it was using.
+///
+/// Useful when the consumer wants to pass the result, without having the
+/// deserialize and serialize it.
+///
+/// Panics if the payload cannot be decompressed. Use
+/// `try_import_from_guest_raw()` to handle this case.
+pub fn import_from_guest_raw(
+    ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    fat_ptr: FatPtr,
+) -> Vec<u8> {
+    try_import_from_guest_raw(ctx, fat_ptr).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Retrieve a serialized object from the linear memory as a Vec<u8> and free up
+/// the memory it was using, or return an error if it cannot be decompressed.
+pub fn try_import_from_guest_raw(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    fat_ptr: FatPtr,
+) -> Result<Vec<u8>, InvocationError> {
+    if fat_ptr == 0 {
+        // This may happen with async calls that don't return a result:
+        return Ok(Vec::new());
+    }
+
+    let (ptr, len) = from_fat_ptr(fat_ptr);
+    if len & 0xff000000 != 0 {
+        panic!("Unknown extension bits");
+    }
+
+    // Copy the bytes in one go, rather than cell by cell:
+    let (start, end) = (ptr as usize, ptr as usize + len as usize);
+    let memory = ctx.as_context().data().memory();
+    let value = memory.data(&ctx)[start..end].to_vec();
+
+    free(&mut ctx, fat_ptr);
+
+    #[cfg(feature = "compression")]
+    let value = crate::common::compression::try_decompress(&value)
+        .map_err(InvocationError::DecompressionFailed)?
+        .into_owned();
+
+    Ok(value)
+}
+
+/// Serialize a value and put it in linear memory.
+///
+/// Panics if the plugin could not allocate the memory for it.
+pub fn export_to_guest<T: Serialize>(
+    ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    value: &T,
+) -> FatPtr {
+    export_to_guest_raw(ctx, rmp_serde::to_vec(value).unwrap())
+}
+
+/// Serialize a value and put it in linear memory, or return an error if the
+/// plugin could not allocate the memory for it.
+pub fn try_export_to_guest<T: Serialize>(
+    ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    value: &T,
+) -> Result<FatPtr, AllocationFailed> {
+    try_export_to_guest_raw(ctx, rmp_serde::to_vec(value).unwrap())
+}
+
+/// Copy the buffer into linear memory.
+///
+/// Any byte container can be given, so buffers such as `bytes::Bytes` can be
+/// passed along without copying them into a `Vec<u8>` first.
+///
+/// Panics if the plugin could not allocate the memory for it.
+pub fn export_to_guest_raw(
+    ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    buffer: impl AsRef<[u8]>,
+) -> FatPtr {
+    try_export_to_guest_raw(ctx, buffer).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Copy the buffer into linear memory, or return an error if the plugin could
+/// not allocate the memory for it.
+pub fn try_export_to_guest_raw(
+    mut ctx: impl AsContextMut<UserState = RuntimeInstanceData>,
+    buffer: impl AsRef<[u8]>,
+) -> Result<FatPtr, AllocationFailed> {
+    #[cfg(feature = "compression")]
+    let compressed = crate::common::compression::compress_slice(buffer.as_ref());
+    #[cfg(feature = "compression")]
+    let buffer: &[u8] = compressed.as_deref().unwrap_or_else(|| buffer.as_ref());
+    #[cfg(not(feature = "compression"))]
+    let buffer: &[u8] = buffer.as_ref();
+
+    let len = buffer.len() as u32;
+
+    // Make sure the length marker does not run into our extension bits:
+    if len & 0xff000000 != 0 {
+        panic!("Buffer too large ({} bytes)", len);
+    }
+
+    let fat_ptr = try_malloc(&mut ctx, len)?;
+
+    let (ptr, len) = from_fat_ptr(fat_ptr);
+
+    let (start, end) = (ptr as usize, ptr as usize + len as usize);
+    let memory = ctx.as_context().data().memory();
+    memory.data_mut(&mut ctx)[start..end].copy_from_slice(buffer);
+
+    Ok(fat_ptr)
+}
@@ -0,0 +1,7 @@
+#[cfg(feature = "async")]
+pub mod r#async;
+
+pub mod errors;
+pub mod interning;
+pub mod mem;
+pub mod runtime;
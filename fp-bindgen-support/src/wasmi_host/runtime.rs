@@ -0,0 +1,180 @@
+use super::errors::{PermissionDenied, RuntimeError};
+use crate::common::mem::FatPtr;
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+use wasmi::{AsContextMut, Instance, Memory, TypedFunc};
+
+#[cfg(feature = "async")]
+use super::r#async::PendingImport;
+
+/// Handler for a dynamic import. It receives the MessagePack-encoded payload
+/// and returns the MessagePack-encoded result.
+pub type DynamicImportHandler = Rc<dyn Fn(&[u8]) -> Vec<u8>>;
+
+/// Subscriber to an event that is emitted by the plugin. It receives the
+/// MessagePack-encoded event and returns whether it wants to receive further
+/// events.
+pub type EventSubscriber = Box<dyn Fn(&[u8]) -> bool>;
+
+/// The data that is kept in the `wasmi` store of a runtime.
+///
+/// Unlike its `wasmer` counterpart, this data is not shared between threads,
+/// since `wasmi` stores are used from a single thread.
+#[derive(Default)]
+pub struct RuntimeInstanceData {
+    memory: Option<Memory>,
+
+    /// Handlers for dynamic imports, keyed by import name and handler name.
+    dynamic_import_handlers: BTreeMap<(String, String), DynamicImportHandler>,
+
+    /// Subscribers to the events emitted by the plugin, keyed by event name.
+    event_subscribers: BTreeMap<String, Vec<EventSubscriber>>,
+
+    /// The capabilities that are granted to the plugin.
+    capabilities: BTreeSet<String>,
+
+    /// Async imports that were called by the plugin, but have not resolved
+    /// yet. These are driven while the host awaits an async export.
+    #[cfg(feature = "async")]
+    pub(crate) pending_imports: Vec<PendingImport>,
+
+    __fp_free: Option<TypedFunc<FatPtr, ()>>,
+
+    __fp_guest_resolve_async_value: Option<TypedFunc<(FatPtr, FatPtr), ()>>,
+
+    __fp_malloc: Option<TypedFunc<u32, FatPtr>>,
+}
+
+impl RuntimeInstanceData {
+    /// Looks up the exports of the plugin that are used by the runtime.
+    ///
+    /// This should be called once the plugin is instantiated, before calling
+    /// any of its functions.
+    pub fn initialize(
+        mut ctx: impl AsContextMut<UserState = Self>,
+        instance: &Instance,
+    ) -> Result<(), RuntimeError> {
+        let missing = |name: &str| RuntimeError::MissingExport(name.to_owned());
+
+        let memory = instance
+            .get_memory(&ctx, "memory")
+            .ok_or_else(|| missing("memory"))?;
+        let free = instance
+            .get_typed_func(&ctx, "__fp_free")
+            .map_err(|_| missing("__fp_free"))?;
+        let malloc = instance
+            .get_typed_func(&ctx, "__fp_malloc")
+            .map_err(|_| missing("__fp_malloc"))?;
+        // Plugins without async functions don't export this one:
+        let guest_resolve_async_value = instance
+            .get_typed_func(&ctx, "__fp_guest_resolve_async_value")
+            .ok();
+
+        let mut ctx = ctx.as_context_mut();
+        let data = ctx.data_mut();
+        data.memory = Some(memory);
+        data.__fp_free = Some(free);
+        data.__fp_malloc = Some(malloc);
+        data.__fp_guest_resolve_async_value = guest_resolve_async_value;
+        Ok(())
+    }
+
+    pub(crate) fn memory(&self) -> Memory {
+        self.memory.expect("Runtime was not initialized")
+    }
+
+    pub(crate) fn free_func(&self) -> TypedFunc<FatPtr, ()> {
+        self.__fp_free.expect("Runtime was not initialized")
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn guest_resolve_async_value_func(&self) -> TypedFunc<(FatPtr, FatPtr), ()> {
+        self.__fp_guest_resolve_async_value
+            .expect("Runtime error: Cannot resolve async value")
+    }
+
+    pub(crate) fn malloc_func(&self) -> TypedFunc<u32, FatPtr> {
+        self.__fp_malloc.expect("Runtime was not initialized")
+    }
+
+    /// Grants the given capabilities to the plugin, in addition to the ones
+    /// that were already granted.
+    pub fn with_capabilities<'a>(
+        mut self,
+        capabilities: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        self.capabilities
+            .extend(capabilities.into_iter().map(str::to_owned));
+        self
+    }
+
+    /// Checks whether the given capability, which is required by the import
+    /// `function`, was granted to the plugin.
+    pub fn check_capability(
+        &self,
+        function: &str,
+        capability: &str,
+    ) -> Result<(), PermissionDenied> {
+        if self.capabilities.contains(capability) {
+            Ok(())
+        } else {
+            Err(PermissionDenied {
+                function: function.to_owned(),
+                capability: capability.to_owned(),
+            })
+        }
+    }
+
+    /// Returns the handler registered under `handler_name` for the given
+    /// dynamic import, if any.
+    pub fn dynamic_import_handler(
+        &self,
+        import_name: &str,
+        handler_name: &str,
+    ) -> Option<DynamicImportHandler> {
+        self.dynamic_import_handlers
+            .get(&(import_name.to_owned(), handler_name.to_owned()))
+            .cloned()
+    }
+
+    /// Registers a handler for a dynamic import, replacing any handler that
+    /// was previously registered under the same name.
+    pub fn register_dynamic_import_handler(
+        &mut self,
+        import_name: &str,
+        handler_name: String,
+        handler: DynamicImportHandler,
+    ) {
+        self.dynamic_import_handlers
+            .insert((import_name.to_owned(), handler_name), handler);
+    }
+
+    /// Unregisters a handler for a dynamic import. Returns whether a handler
+    /// was registered under the given name.
+    pub fn unregister_dynamic_import_handler(
+        &mut self,
+        import_name: &str,
+        handler_name: &str,
+    ) -> bool {
+        self.dynamic_import_handlers
+            .remove(&(import_name.to_owned(), handler_name.to_owned()))
+            .is_some()
+    }
+
+    /// Subscribes to the event with the given name.
+    pub fn subscribe_to_event(&mut self, event_name: &str, subscriber: EventSubscriber) {
+        self.event_subscribers
+            .entry(event_name.to_owned())
+            .or_default()
+            .push(subscriber);
+    }
+
+    /// Passes the MessagePack-encoded event to all subscribers of the event
+    /// with the given name. Subscribers that no longer want to receive events
+    /// are removed.
+    pub fn emit_event(&mut self, event_name: &str, event: &[u8]) {
+        if let Some(subscribers) = self.event_subscribers.get_mut(event_name) {
+            subscribers.retain(|subscriber| subscriber(event));
+        }
+    }
+}
@@ -1,8 +1,10 @@
 use inflector::Inflector;
+use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Casing {
+    #[default]
     Original,
     CamelCase,
     PascalCase,
@@ -32,12 +34,6 @@ impl Casing {
     }
 }
 
-impl Default for Casing {
-    fn default() -> Self {
-        Self::Original
-    }
-}
-
 impl TryFrom<&str> for Casing {
     type Error = String;
 
@@ -1,11 +1,18 @@
 use crate::utils::normalize_return_type;
-use crate::{docs::get_doc_lines, types::TypeIdent};
+use crate::{
+    docs::get_doc_lines,
+    types::{Type, TypeIdent, TypeMap},
+};
 use quote::ToTokens;
-use std::{collections::BTreeSet, convert::TryFrom};
-use syn::{FnArg, ForeignItemFn};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeSet, convert::TryFrom, iter::FromIterator};
+use syn::{
+    ext::IdentExt, parenthesized, parse::Parse, parse::ParseStream, Attribute, Error, FnArg,
+    ForeignItemFn, GenericParam, Ident, LitInt, LitStr, Result, Token,
+};
 
 /// Maps from function name to the stringified function declaration.
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct FunctionList(BTreeSet<Function>);
 
 impl FunctionList {
@@ -20,6 +27,57 @@ impl FunctionList {
     pub fn new() -> Self {
         Self(BTreeSet::new())
     }
+
+    /// Adds the functions of another list, skipping the ones that are already
+    /// in this list with the same signature.
+    ///
+    /// Panics if the other list contains a function that has the same name as
+    /// one in this list, but a different signature.
+    pub fn merge(&mut self, other: FunctionList) {
+        for function in other {
+            match self.iter().find(|existing| existing.name == function.name) {
+                Some(existing) if !existing.has_same_signature(&function) => panic!(
+                    "Function `{}` is declared with different signatures",
+                    function.name
+                ),
+                Some(_) => {}
+                None => {
+                    self.0.insert(function);
+                }
+            }
+        }
+    }
+
+    /// Returns the capabilities that are required by any of the functions.
+    #[cfg(feature = "generators")]
+    pub(crate) fn capabilities(&self) -> BTreeSet<&str> {
+        self.iter()
+            .filter_map(|function| function.attrs.capability.as_deref())
+            .collect()
+    }
+
+    /// Returns the features behind which any of the functions are generated.
+    #[cfg(feature = "generators")]
+    pub(crate) fn cfg_features(&self) -> BTreeSet<&str> {
+        self.iter()
+            .filter_map(|function| function.attrs.cfg_feature.as_deref())
+            .collect()
+    }
+
+    /// Returns the list with the type parameters of generic functions replaced
+    /// by the given type. See [`Function::with_generic_params_replaced()`].
+    #[cfg(feature = "generators")]
+    pub(crate) fn with_generic_params_replaced(self, replacement: &str) -> Self {
+        self.into_iter()
+            .map(|function| function.with_generic_params_replaced(replacement))
+            .collect()
+    }
+}
+
+impl FromIterator<Function> for FunctionList {
+    fn from_iter<I: IntoIterator<Item = Function>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
 }
 
 impl IntoIterator for FunctionList {
@@ -40,13 +98,18 @@ impl<'a> IntoIterator for &'a FunctionList {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Function {
     pub name: String,
     pub doc_lines: Vec<String>,
+    /// The names of the type parameters of a generic function, which may be
+    /// used in its argument and return types.
+    #[serde(default)]
+    pub generic_params: Vec<String>,
     pub args: Vec<FunctionArg>,
     pub return_type: Option<TypeIdent>,
     pub is_async: bool,
+    pub attrs: FunctionAttrs,
 }
 
 impl Function {
@@ -56,6 +119,20 @@ impl Function {
 
         let name = item.sig.ident.to_string();
         let doc_lines = get_doc_lines(&item.attrs);
+        let generic_params = item
+            .sig
+            .generics
+            .params
+            .iter()
+            .map(|param| match param {
+                GenericParam::Type(param) => param.ident.to_string(),
+                _ => panic!(
+                    "Only type parameters are supported. Found `{}` in declaration of function {}",
+                    param.to_token_stream(),
+                    name
+                ),
+            })
+            .collect::<Vec<_>>();
         let args = item
             .sig
             .inputs
@@ -72,19 +149,276 @@ impl Function {
                     }),
                 },
             })
-            .collect();
+            .collect::<Vec<_>>();
         let return_type = normalize_return_type(&item.sig.output).map(|return_type| {
-            TypeIdent::try_from(return_type)
+            parse_return_type(return_type)
                 .unwrap_or_else(|_| panic!("Invalid return type for function {}", name))
         });
         let is_async = item.sig.asyncness.is_some();
+        let attrs = FunctionAttrs::from_attrs(&item.attrs);
+
+        if !generic_params.is_empty() {
+            validate_generic_function(&name, &attrs);
+        }
+        if attrs.dynamic {
+            validate_dynamic_import(&name, &args, return_type.as_ref(), is_async);
+        }
+        if attrs.batchable {
+            validate_batchable_import(&name, return_type.as_ref(), is_async);
+        }
+        if let Some(http) = &attrs.http {
+            validate_http_endpoint(&name, &args, http);
+        }
+        if attrs.event {
+            validate_event(&name, &args, return_type.as_ref(), is_async, &attrs);
+        }
+        if let Some(feature) = &attrs.cfg_feature {
+            validate_cfg_feature(&name, feature, &attrs);
+        }
+        if attrs.lazy {
+            validate_lazy_export(&name, return_type.as_ref());
+        }
+        if attrs.read_only && return_type.is_none() {
+            panic!("Read-only function `{}` must return a value", name);
+        }
 
         Self {
             name,
             doc_lines,
+            generic_params,
             args,
             return_type,
             is_async,
+            attrs,
+        }
+    }
+
+    /// Returns whether both functions have the same signature and attributes,
+    /// regardless of their documentation.
+    pub fn has_same_signature(&self, other: &Function) -> bool {
+        self.name == other.name
+            && self.generic_params == other.generic_params
+            && self.args == other.args
+            && self.return_type == other.return_type
+            && self.is_async == other.is_async
+            && self.attrs == other.attrs
+    }
+
+    /// Returns the name under which the function is exchanged between runtime
+    /// and plugin, which is its own name unless it was renamed.
+    pub fn wire_name(&self) -> &str {
+        self.attrs.rename.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Returns the name of the Wasm symbol through which the function is
+    /// imported or exported.
+    pub fn symbol_name(&self) -> String {
+        format!("__fp_gen_{}", self.wire_name())
+    }
+
+    /// Returns whether this is an async function without a return value.
+    ///
+    /// Imports of this kind are fire-and-forget: the runtime starts the call
+    /// and returns right away, without an async value for the plugin to await.
+    pub fn is_fire_and_forget(&self) -> bool {
+        self.is_async && self.return_type.is_none()
+    }
+
+    /// Returns whether the given type refers to any of the type parameters of
+    /// the function.
+    pub fn uses_generic_params(&self, ty: &TypeIdent) -> bool {
+        ty.contains_any_of(&self.generic_params)
+    }
+
+    /// Returns the function with every use of its type parameters replaced by
+    /// the given type, for runtimes that don't have a static type for them.
+    pub fn with_generic_params_replaced(mut self, replacement: &str) -> Self {
+        if self.generic_params.is_empty() {
+            return self;
+        }
+
+        let replacement = TypeIdent::from(replacement);
+        let params = std::mem::take(&mut self.generic_params);
+        for arg in self.args.iter_mut() {
+            arg.ty = arg.ty.replace_any_of(&params, &replacement);
+        }
+        if let Some(ty) = self.return_type.as_mut() {
+            *ty = ty.replace_any_of(&params, &replacement);
+        }
+        self
+    }
+}
+
+/// Registers the tuples that functions return, with the concrete types of
+/// their items. `Serializable` only knows the generic identifiers of the items,
+/// such as `Vec<T>`, so it cannot register these tuples itself.
+pub(crate) fn register_tuple_return_types(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &mut TypeMap,
+) {
+    for ty in import_functions
+        .iter()
+        .chain(export_functions.iter())
+        .filter_map(|function| function.return_type.as_ref())
+    {
+        if let Some(items) = ty.tuple_items() {
+            types
+                .entry(ty.clone())
+                .or_insert_with(|| Type::Tuple(items));
+        }
+    }
+}
+
+/// Parses the return type of a function. Unlike arguments, functions may return
+/// tuples of any types, which are exchanged as an implicit tuple struct.
+fn parse_return_type(ty: &syn::Type) -> std::result::Result<TypeIdent, String> {
+    match ty {
+        syn::Type::Tuple(tuple) if tuple.elems.len() > 1 => {
+            let items = tuple
+                .elems
+                .iter()
+                .map(TypeIdent::try_from)
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            Ok(TypeIdent::from(format!(
+                "({})",
+                items
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )))
+        }
+        ty => TypeIdent::try_from(ty),
+    }
+}
+
+/// The plugin decides which types to pass for the type parameters of a generic
+/// function, so the runtime only sees the serialized values. Attributes that
+/// require the runtime to know the types up front are not supported.
+fn validate_generic_function(name: &str, attrs: &FunctionAttrs) {
+    if attrs.dynamic || attrs.batchable || attrs.event || attrs.http.is_some() || attrs.read_only {
+        panic!(
+            "Generic function `{}` cannot be combined with the `dynamic`, `batchable`, \
+                `http`, `read_only` or `fp_event` attributes",
+            name
+        );
+    }
+}
+
+/// Dynamic imports are dispatched by name at runtime, so their signature is
+/// restricted to a name and a single serialized payload.
+fn validate_dynamic_import(
+    name: &str,
+    args: &[FunctionArg],
+    return_type: Option<&TypeIdent>,
+    is_async: bool,
+) {
+    if is_async {
+        panic!("Dynamic import `{}` cannot be async", name);
+    }
+
+    match args {
+        [handler_name, payload]
+            if handler_name.ty.name == "String" && !payload.ty.is_primitive() => {}
+        _ => panic!(
+            "Dynamic import `{}` must take exactly two arguments: a `String` with the \
+                handler name and a non-primitive payload",
+            name
+        ),
+    }
+
+    if return_type
+        .map(|ty| ty.is_primitive() || ty.is_packed_pair())
+        .unwrap_or(true)
+    {
+        panic!(
+            "Dynamic import `{}` must return a non-primitive type that is not a pair of primitives",
+            name
+        );
+    }
+}
+
+/// Calls to batchable imports may be queued by the plugin, so they cannot
+/// return anything to it.
+fn validate_batchable_import(name: &str, return_type: Option<&TypeIdent>, is_async: bool) {
+    if is_async {
+        panic!("Batchable import `{}` cannot be async", name);
+    }
+
+    if return_type.is_some() {
+        panic!("Batchable import `{}` cannot have a return type", name);
+    }
+}
+
+/// Events are pushed to the subscribers of the runtime, so they carry a single
+/// serialized payload and cannot return anything to the plugin.
+fn validate_event(
+    name: &str,
+    args: &[FunctionArg],
+    return_type: Option<&TypeIdent>,
+    is_async: bool,
+    attrs: &FunctionAttrs,
+) {
+    if is_async {
+        panic!("Event `{}` cannot be async", name);
+    }
+
+    match args {
+        [payload] if !payload.ty.is_primitive() => {}
+        _ => panic!(
+            "Event `{}` must take exactly one argument with a non-primitive payload",
+            name
+        ),
+    }
+
+    if return_type.is_some() {
+        panic!("Event `{}` cannot have a return type", name);
+    }
+
+    if attrs.dynamic || attrs.batchable || attrs.capability.is_some() {
+        panic!(
+            "Event `{}` cannot be combined with the `dynamic`, `batchable` or `capability` attributes",
+            name
+        );
+    }
+}
+
+/// Feature-gated functions are left out of the shared dispatching code, so they
+/// cannot use any of the attributes that rely on it.
+fn validate_cfg_feature(name: &str, feature: &str, attrs: &FunctionAttrs) {
+    if attrs.dynamic || attrs.batchable || attrs.capability.is_some() || attrs.event {
+        panic!(
+            "Function `{}` behind the `{}` feature cannot be combined with the `dynamic`, \
+                `batchable`, `capability` or `fp_event` attributes",
+            name, feature
+        );
+    }
+}
+
+/// Lazy exports hand out the serialized return value, so there must be one.
+fn validate_lazy_export(name: &str, return_type: Option<&TypeIdent>) {
+    if return_type
+        .map(|ty| ty.is_primitive() || ty.is_packed_pair())
+        .unwrap_or(true)
+    {
+        panic!(
+            "Lazy export `{}` must return a non-primitive type that is not a pair of primitives",
+            name
+        );
+    }
+}
+
+/// Every parameter in the path of an HTTP endpoint needs to be bound to an
+/// argument of the function.
+fn validate_http_endpoint(name: &str, args: &[FunctionArg], http: &HttpEndpoint) {
+    for param in http.path_params() {
+        if !args.iter().any(|arg| arg.name_without_prefix() == param) {
+            panic!(
+                "HTTP path `{}` of function `{}` contains parameter `{}`, but the function has \
+                    no argument with that name",
+                http.path, name, param
+            );
         }
     }
 }
@@ -97,12 +431,568 @@ impl Ord for Function {
 
 impl PartialOrd for Function {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.name.partial_cmp(&other.name)
+        Some(self.cmp(other))
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct FunctionArg {
     pub name: String,
     pub ty: TypeIdent,
 }
+
+impl FunctionArg {
+    /// Returns the name of the argument, without the `r#` prefix of raw
+    /// identifiers.
+    pub fn name_without_prefix(&self) -> &str {
+        self.name.strip_prefix("r#").unwrap_or(&self.name)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct FunctionAttrs {
+    /// Marks an import as dynamic: besides the regular implementation that
+    /// the runtime provides, handlers may be registered and unregistered for
+    /// it at runtime, keyed by the name passed as the first argument.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(dynamic)]
+    /// fn call_command(name: String, payload: CommandPayload) -> CommandResult;
+    /// ```
+    pub dynamic: bool,
+
+    /// Marks an import as batchable: calls to it that are made inside a
+    /// `batch()` block in the plugin are queued, and are sent to the runtime
+    /// together in a single call once the block ends.
+    ///
+    /// Batchable imports must be synchronous and cannot return anything.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(batchable)]
+    /// fn emit_metric(name: String, value: f64);
+    /// ```
+    #[serde(default)]
+    pub batchable: bool,
+
+    /// The capability that a runtime needs to grant to a plugin before the
+    /// plugin is allowed to call this import. Calls to imports for which the
+    /// capability was not granted fail with a permission error.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(capability = "network")]
+    /// async fn make_http_request(request: Request) -> HttpResult;
+    /// ```
+    #[serde(default)]
+    pub capability: Option<String>,
+
+    /// The HTTP endpoint through which this function is exposed in the
+    /// generated OpenAPI document.
+    ///
+    /// Parameters in the path, such as `{id}`, are bound to the function
+    /// argument with the same name.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(http(method = "GET", path = "/series/{id}"))]
+    /// fn get_series(id: String) -> Series;
+    /// ```
+    #[serde(default)]
+    pub http: Option<HttpEndpoint>,
+
+    /// Marks an import as an event, which is declared with the `#[fp_event]`
+    /// attribute instead of `#[fp(...)]`. The plugin may emit events at any
+    /// time, and the runtime passes them on to its subscribers instead of to
+    /// an implementation of the import.
+    ///
+    /// Events must be synchronous, take a single non-primitive payload and
+    /// cannot return anything.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp_event]
+    /// fn progress(event: Progress);
+    /// ```
+    #[serde(default)]
+    pub event: bool,
+
+    /// The Cargo feature behind which the function is generated. The Rust
+    /// bindings wrap the function in `#[cfg(feature = "...")]`, while the
+    /// TypeScript bindings move it to a separate module named after the
+    /// feature.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(cfg_feature = "experimental")]
+    /// fn preview_chart(query: ChartQuery) -> Chart;
+    /// ```
+    #[serde(default)]
+    pub cfg_feature: Option<String>,
+
+    /// Marks an export as lazy: the Rust runtimes return a `Lazy<T>` that
+    /// holds the serialized value, and only deserialize it when it is first
+    /// accessed. This avoids the cost of deserialization for hosts that pass
+    /// on most responses unchanged.
+    ///
+    /// Lazy exports must return a non-primitive type.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(lazy)]
+    /// fn render_report(query: ReportQuery) -> Report;
+    /// ```
+    #[serde(default)]
+    pub lazy: bool,
+
+    /// Memoizes the results of an export in the Rust Wasmer and TypeScript
+    /// runtimes: calls with the same (serialized) arguments return the result
+    /// of the first call, until its time-to-live has passed. Only successful
+    /// results are memoized.
+    ///
+    /// Cached exports must return a non-primitive type.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(cache(ttl = "30s", key = "args"))]
+    /// fn search_series(query: SeriesQuery) -> Vec<Series>;
+    /// ```
+    #[serde(default)]
+    pub cache: Option<CacheOptions>,
+
+    /// Limits how often a plugin may call an import in the Rust Wasmer and
+    /// TypeScript runtimes, using a token bucket that holds up to `calls`
+    /// tokens and is refilled with `calls` tokens every `per`. Calls that
+    /// exceed the limit are not passed on to the host, but return a
+    /// `RateLimited` error to the plugin instead.
+    ///
+    /// Rate-limited imports must return `Result<T, RateLimited>`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(rate_limit(calls = 10, per = "1s"))]
+    /// async fn make_http_request(request: Request) -> Result<HttpResult, RateLimited>;
+    /// ```
+    #[serde(default)]
+    pub rate_limit: Option<RateLimitOptions>,
+
+    /// Marks an export as read-only, which exposes it as a query in the
+    /// generated GraphQL schema.
+    ///
+    /// Read-only exports must return a value.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(read_only)]
+    /// fn search_series(query: SeriesQuery) -> Vec<Series>;
+    /// ```
+    #[serde(default)]
+    pub read_only: bool,
+
+    /// The name under which the function is exchanged between runtime and
+    /// plugin, when it differs from the name of the function itself. This
+    /// allows a function to be renamed in the protocol without breaking
+    /// plugins that were built against the old name.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(rename = "fetch_data")]
+    /// async fn fetch_series(query: SeriesQuery) -> Vec<Series>;
+    /// ```
+    #[serde(default)]
+    pub rename: Option<String>,
+}
+
+/// Determines how long the results of an export are memoized, and by which
+/// arguments they are keyed.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct CacheOptions {
+    /// The time-to-live of memoized results, in milliseconds.
+    pub ttl_ms: u64,
+
+    /// The names of the arguments by which results are keyed, or `None` to key
+    /// them by all arguments, which is what `key = "args"` does.
+    pub key: Option<Vec<String>>,
+}
+
+impl CacheOptions {
+    /// Returns the names of the given function's arguments by which results
+    /// are keyed.
+    pub fn key_args<'a>(&'a self, function: &'a Function) -> Vec<&'a str> {
+        match &self.key {
+            Some(key) => key.iter().map(String::as_str).collect(),
+            None => function.args.iter().map(|arg| arg.name.as_str()).collect(),
+        }
+    }
+}
+
+/// Determines how often an import may be called.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RateLimitOptions {
+    /// The number of calls that may be made in a burst, which is also the
+    /// number of calls that become available again every `per_ms`.
+    pub calls: u32,
+
+    /// The interval in which the bucket is refilled, in milliseconds.
+    pub per_ms: u32,
+}
+
+impl Parse for RateLimitOptions {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+
+        let mut calls = None;
+        let mut per_ms = None;
+        loop {
+            let name: Ident = content.call(IdentExt::parse_any)?;
+            content.parse::<Token![=]>()?;
+            match name.to_string().as_ref() {
+                "calls" => {
+                    let value = content.parse::<LitInt>()?;
+                    match value.base10_parse::<u32>() {
+                        Ok(value) if value > 0 => calls = Some(value),
+                        _ => {
+                            return Err(Error::new(
+                                value.span(),
+                                "Rate limit `calls` must be a positive number",
+                            ))
+                        }
+                    }
+                }
+                "per" => {
+                    let value = content.parse::<LitStr>()?;
+                    match parse_duration_ms(&value.value()).map(u32::try_from) {
+                        Some(Ok(per)) if per > 0 => per_ms = Some(per),
+                        _ => {
+                            return Err(Error::new(
+                                value.span(),
+                                format!(
+                                    "Invalid rate limit interval: {:?}, expected a duration such as \"1s\"",
+                                    value.value()
+                                ),
+                            ))
+                        }
+                    }
+                }
+                other => {
+                    return Err(Error::new(
+                        name.span(),
+                        format!("Unexpected rate limit attribute: {other}"),
+                    ))
+                }
+            }
+
+            if content.is_empty() {
+                break;
+            }
+
+            content.parse::<Token![,]>()?;
+        }
+
+        match (calls, per_ms) {
+            (Some(calls), Some(per_ms)) => Ok(Self { calls, per_ms }),
+            _ => Err(Error::new(
+                input.span(),
+                "Rate limit attribute requires both `calls` and `per`",
+            )),
+        }
+    }
+}
+
+/// Parses a duration such as `500ms`, `30s`, `5m` or `1h` into milliseconds.
+fn parse_duration_ms(duration: &str) -> Option<u64> {
+    let unit_start = duration.find(|c: char| !c.is_ascii_digit())?;
+    let (value, unit) = duration.split_at(unit_start);
+    let multiplier = match unit {
+        "ms" => 1,
+        "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        _ => return None,
+    };
+    value.parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+impl Parse for CacheOptions {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+
+        let mut ttl_ms = None;
+        let mut key = None;
+        loop {
+            let name: Ident = content.call(IdentExt::parse_any)?;
+            content.parse::<Token![=]>()?;
+            let value = content.parse::<LitStr>()?;
+            match name.to_string().as_ref() {
+                "ttl" => match parse_duration_ms(&value.value()) {
+                    Some(ttl) if ttl > 0 => ttl_ms = Some(ttl),
+                    _ => {
+                        return Err(Error::new(
+                            value.span(),
+                            format!(
+                                "Invalid cache TTL: {:?}, expected a duration such as \"30s\"",
+                                value.value()
+                            ),
+                        ))
+                    }
+                },
+                "key" => {
+                    let args = value
+                        .value()
+                        .split(',')
+                        .map(|arg| arg.trim().to_owned())
+                        .collect::<Vec<_>>();
+                    if args.iter().any(String::is_empty) {
+                        return Err(Error::new(value.span(), "Invalid cache key"));
+                    }
+                    key = if args == ["args"] { None } else { Some(args) };
+                }
+                other => {
+                    return Err(Error::new(
+                        name.span(),
+                        format!("Unexpected cache attribute: {other}"),
+                    ))
+                }
+            }
+
+            if content.is_empty() {
+                break;
+            }
+
+            content.parse::<Token![,]>()?;
+        }
+
+        match ttl_ms {
+            Some(ttl_ms) => Ok(Self { ttl_ms, key }),
+            None => Err(Error::new(input.span(), "Cache attribute requires a `ttl`")),
+        }
+    }
+}
+
+/// An HTTP method and path through which a function is exposed.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct HttpEndpoint {
+    /// The HTTP method, in uppercase.
+    pub method: String,
+
+    /// The path, which may contain parameters such as `{id}`.
+    pub path: String,
+}
+
+impl HttpEndpoint {
+    const METHODS: &'static [&'static str] =
+        &["DELETE", "GET", "HEAD", "OPTIONS", "PATCH", "POST", "PUT"];
+
+    /// Returns the names of the parameters in the path.
+    pub fn path_params(&self) -> impl Iterator<Item = &str> {
+        self.path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+    }
+}
+
+impl std::fmt::Display for HttpEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.method, self.path)
+    }
+}
+
+impl Parse for HttpEndpoint {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+
+        let mut method = None;
+        let mut path = None;
+        loop {
+            let key: Ident = content.call(IdentExt::parse_any)?;
+            content.parse::<Token![=]>()?;
+            let value = content.parse::<LitStr>()?;
+            match key.to_string().as_ref() {
+                "method" => {
+                    let uppercase = value.value().to_uppercase();
+                    if !Self::METHODS.contains(&uppercase.as_str()) {
+                        return Err(Error::new(
+                            value.span(),
+                            format!("Unsupported HTTP method: {}", value.value()),
+                        ));
+                    }
+                    method = Some(uppercase);
+                }
+                "path" => {
+                    if !value.value().starts_with('/') {
+                        return Err(Error::new(value.span(), "HTTP path must start with `/`"));
+                    }
+                    path = Some(value.value());
+                }
+                other => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!("Unexpected HTTP attribute: {other}"),
+                    ))
+                }
+            }
+
+            if content.is_empty() {
+                break;
+            }
+
+            content.parse::<Token![,]>()?;
+        }
+
+        match (method, path) {
+            (Some(method), Some(path)) => Ok(Self { method, path }),
+            _ => Err(Error::new(
+                input.span(),
+                "HTTP attribute requires both a `method` and a `path`",
+            )),
+        }
+    }
+}
+
+impl FunctionAttrs {
+    pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut opts = Self::default();
+        for attr in attrs {
+            if attr.path.is_ident("fp") {
+                opts.merge_with(
+                    &syn::parse2::<Self>(attr.tokens.clone())
+                        .expect("Could not parse function attributes"),
+                );
+            } else if attr.path.is_ident("fp_event") {
+                opts.event = true;
+            }
+        }
+        opts
+    }
+
+    fn merge_with(&mut self, other: &Self) {
+        if other.dynamic {
+            self.dynamic = true;
+        }
+        if other.batchable {
+            self.batchable = true;
+        }
+        if let Some(capability) = &other.capability {
+            self.capability = Some(capability.clone());
+        }
+        if let Some(http) = &other.http {
+            self.http = Some(http.clone());
+        }
+        if other.event {
+            self.event = true;
+        }
+        if let Some(feature) = &other.cfg_feature {
+            self.cfg_feature = Some(feature.clone());
+        }
+        if other.lazy {
+            self.lazy = true;
+        }
+        if let Some(cache) = &other.cache {
+            self.cache = Some(cache.clone());
+        }
+        if let Some(rate_limit) = &other.rate_limit {
+            self.rate_limit = Some(rate_limit.clone());
+        }
+        if other.read_only {
+            self.read_only = true;
+        }
+        if let Some(rename) = &other.rename {
+            self.rename = Some(rename.clone());
+        }
+    }
+}
+
+impl Parse for FunctionAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+
+        let mut result = Self::default();
+        loop {
+            let key: Ident = content.call(IdentExt::parse_any)?;
+            match key.to_string().as_ref() {
+                "batchable" => result.batchable = true,
+                "cache" => result.cache = Some(content.parse()?),
+                "capability" => {
+                    content.parse::<Token![=]>()?;
+                    let capability = content.parse::<LitStr>()?;
+                    if capability.value().is_empty() {
+                        return Err(Error::new(capability.span(), "Capability cannot be empty"));
+                    }
+                    result.capability = Some(capability.value());
+                }
+                "cfg_feature" => {
+                    content.parse::<Token![=]>()?;
+                    let feature = content.parse::<LitStr>()?;
+                    let is_valid = !feature.value().is_empty()
+                        && feature
+                            .value()
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+                    if !is_valid {
+                        return Err(Error::new(
+                            feature.span(),
+                            format!("Invalid feature name: {:?}", feature.value()),
+                        ));
+                    }
+                    result.cfg_feature = Some(feature.value());
+                }
+                "dynamic" => result.dynamic = true,
+                "http" => result.http = Some(content.parse()?),
+                "lazy" => result.lazy = true,
+                "rate_limit" => result.rate_limit = Some(content.parse()?),
+                "read_only" => result.read_only = true,
+                "rename" => {
+                    content.parse::<Token![=]>()?;
+                    let rename = content.parse::<LitStr>()?;
+                    let is_valid = rename
+                        .value()
+                        .chars()
+                        .next()
+                        .map(|c| c.is_ascii_alphabetic() || c == '_')
+                        .unwrap_or(false)
+                        && rename
+                            .value()
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+                    if !is_valid {
+                        return Err(Error::new(
+                            rename.span(),
+                            format!("Invalid function name: {:?}", rename.value()),
+                        ));
+                    }
+                    result.rename = Some(rename.value());
+                }
+                other => {
+                    return Err(Error::new(
+                        content.span(),
+                        format!("Unexpected function attribute: {other}"),
+                    ))
+                }
+            }
+
+            if content.is_empty() {
+                break;
+            }
+
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(result)
+    }
+}
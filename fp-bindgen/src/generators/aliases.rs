@@ -0,0 +1,250 @@
+//! Handling of the aliases that are declared in the `fp_import!` and
+//! `fp_export!` sections.
+//!
+//! Aliases cannot have a derive macro, so they are only known to the
+//! generators if they are registered explicitly, by repeating them as
+//! `type Name = Target;` in either section. Depending on the `AliasMode`,
+//! registered aliases are either preserved as named types, or expanded into
+//! their targets before any bindings are generated.
+
+use crate::{
+    functions::{Function, FunctionList},
+    types::{Type, TypeIdent, TypeMap},
+};
+use std::collections::BTreeMap;
+
+/// Checks that every type that is used by the protocol is known.
+///
+/// Types that are not part of the type map would otherwise be mistaken for
+/// type parameters, which is what happens to aliases that are used without
+/// being registered.
+pub(super) fn check_registered_types(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) {
+    for function in import_functions.iter().chain(export_functions.iter()) {
+        let context = format!("function `{}`", function.name);
+        for arg in &function.args {
+            check_registered_type(&arg.ty, &function.generic_params, &context, types);
+        }
+        if let Some(ty) = &function.return_type {
+            check_registered_type(ty, &function.generic_params, &context, types);
+        }
+    }
+
+    for (ident, ty) in types {
+        let context = format!("type `{ident}`");
+        let params = ident
+            .generic_args
+            .iter()
+            .map(|(arg, _)| arg.name.clone())
+            .collect::<Vec<_>>();
+        for used_ident in used_idents(ty) {
+            check_registered_type(used_ident, &params, &context, types);
+        }
+    }
+}
+
+fn check_registered_type(ident: &TypeIdent, params: &[String], context: &str, types: &TypeMap) {
+    let is_param = ident.generic_args.is_empty() && params.contains(&ident.name);
+    if !is_param && !ident.is_phantom_data() && !types.contains_key(ident) {
+        panic!(
+            "Type `{}` is used by {}, but is not part of the protocol. If it is an alias, please \
+            register it by repeating it as `type {} = ...;` in either the `fp_import!` or \
+            `fp_export!` section",
+            ident, context, ident.name
+        );
+    }
+
+    for (arg, _) in &ident.generic_args {
+        check_registered_type(arg, params, context, types);
+    }
+}
+
+/// Replaces every use of an alias with the type it refers to, and removes the
+/// aliases from the type map.
+pub(super) fn expand_aliases(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+) -> (FunctionList, FunctionList, TypeMap) {
+    let aliases = types
+        .values()
+        .filter_map(|ty| match ty {
+            Type::Alias(name, target) => Some((name.clone(), target.clone())),
+            _ => None,
+        })
+        .collect::<BTreeMap<_, _>>();
+    if aliases.is_empty() {
+        return (import_functions, export_functions, types);
+    }
+
+    let expand = |ident: &mut TypeIdent| *ident = expand_ident(ident, &aliases, &mut Vec::new());
+    let expand_function = |mut function: Function| {
+        for arg in function.args.iter_mut() {
+            expand(&mut arg.ty);
+        }
+        if let Some(ty) = function.return_type.as_mut() {
+            expand(ty);
+        }
+        function
+    };
+
+    let types = types
+        .into_iter()
+        .filter(|(_, ty)| !matches!(ty, Type::Alias(_, _)))
+        .map(|(ident, mut ty)| {
+            for used_ident in used_idents_mut(&mut ty) {
+                expand(used_ident);
+            }
+            (ident, ty)
+        })
+        .collect();
+
+    (
+        import_functions.into_iter().map(expand_function).collect(),
+        export_functions.into_iter().map(expand_function).collect(),
+        types,
+    )
+}
+
+fn expand_ident(
+    ident: &TypeIdent,
+    aliases: &BTreeMap<String, TypeIdent>,
+    stack: &mut Vec<String>,
+) -> TypeIdent {
+    if ident.generic_args.is_empty() && !ident.is_array() {
+        if let Some(target) = aliases.get(&ident.name) {
+            if stack.contains(&ident.name) {
+                panic!("Alias `{}` refers to itself", ident.name);
+            }
+
+            stack.push(ident.name.clone());
+            let expanded = expand_ident(target, aliases, stack);
+            stack.pop();
+            return expanded;
+        }
+    }
+
+    let mut ident = ident.clone();
+    for (arg, _) in ident.generic_args.iter_mut() {
+        *arg = expand_ident(arg, aliases, stack);
+    }
+    ident
+}
+
+/// Returns the identifiers of the types that are used by the given type,
+/// such as the types of its fields.
+fn used_idents(ty: &Type) -> Vec<&TypeIdent> {
+    match ty {
+        Type::Alias(_, target) => vec![target],
+        Type::Enum(ty) => ty
+            .variants
+            .iter()
+            .flat_map(|variant| match &variant.ty {
+                Type::Struct(ty) => ty.fields.iter().map(|field| &field.ty).collect(),
+                Type::Tuple(items) => items.iter().collect(),
+                _ => Vec::new(),
+            })
+            .collect(),
+        Type::Struct(ty) => ty.fields.iter().map(|field| &field.ty).collect(),
+        Type::Tuple(items) => items.iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub(super) fn used_idents_mut(ty: &mut Type) -> Vec<&mut TypeIdent> {
+    match ty {
+        Type::Alias(_, target) => vec![target],
+        Type::Enum(ty) => ty
+            .variants
+            .iter_mut()
+            .flat_map(|variant| match &mut variant.ty {
+                Type::Struct(ty) => ty.fields.iter_mut().map(|field| &mut field.ty).collect(),
+                Type::Tuple(items) => items.iter_mut().collect(),
+                _ => Vec::new(),
+            })
+            .collect(),
+        Type::Struct(ty) => ty.fields.iter_mut().map(|field| &mut field.ty).collect(),
+        Type::Tuple(items) => items.iter_mut().collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn types_with_aliases() -> TypeMap {
+        let response = Type::from_item(
+            "struct Response {
+                pub body: Body,
+                pub headers: Option<Headers>,
+            }",
+        );
+        TypeMap::from([
+            (TypeIdent::from("Response"), response),
+            (
+                TypeIdent::from("Body"),
+                Type::Alias("Body".to_owned(), TypeIdent::from("ByteBuf")),
+            ),
+            (
+                TypeIdent::from("Headers"),
+                Type::Alias("Headers".to_owned(), TypeIdent::from("Vec<Header>")),
+            ),
+            (
+                TypeIdent::from("Header"),
+                Type::Alias("Header".to_owned(), TypeIdent::from("String")),
+            ),
+            (
+                TypeIdent::from("ByteBuf"),
+                Type::List("ByteBuf".to_owned(), TypeIdent::from("u8")),
+            ),
+            (
+                TypeIdent::from("Option<T>"),
+                Type::Container("Option".to_owned(), TypeIdent::from("T")),
+            ),
+            (TypeIdent::from("String"), Type::String),
+            (
+                TypeIdent::from("Vec<T>"),
+                Type::List("Vec".to_owned(), TypeIdent::from("T")),
+            ),
+            (
+                TypeIdent::from("u8"),
+                Type::Primitive(crate::primitives::Primitive::U8),
+            ),
+        ])
+    }
+
+    #[test]
+    fn expand_nested_aliases() {
+        let mut functions = FunctionList::new();
+        functions.add_function("fn fetch(body: Body) -> Response;");
+
+        let (functions, _, types) =
+            expand_aliases(functions, FunctionList::new(), types_with_aliases());
+
+        assert!(!types.values().any(|ty| matches!(ty, Type::Alias(_, _))));
+        let field_types = match types.get(&TypeIdent::from("Response")) {
+            Some(Type::Struct(ty)) => ty
+                .fields
+                .iter()
+                .map(|field| field.ty.to_string())
+                .collect::<Vec<_>>(),
+            _ => unreachable!(),
+        };
+        assert_eq!(field_types, vec!["ByteBuf", "Option<Vec<String>>"]);
+
+        let function = functions.iter().next().unwrap();
+        assert_eq!(function.args[0].ty, TypeIdent::from("ByteBuf"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Type `Headers` is used by type `Response`")]
+    fn check_unregistered_alias() {
+        let mut types = types_with_aliases();
+        types.remove(&TypeIdent::from("Headers"));
+        check_registered_types(&FunctionList::new(), &FunctionList::new(), &types);
+    }
+}
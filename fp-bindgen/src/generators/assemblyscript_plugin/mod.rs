@@ -0,0 +1,819 @@
+mod types;
+
+use crate::{
+    functions::{Function, FunctionList},
+    generators::{
+        lifecycle::{INIT, SHUTDOWN, UPDATE_CONFIG},
+        write_bindings_file,
+    },
+    types::{Type, TypeIdent, TypeMap},
+    AssemblyScriptPluginConfig, FileHeaders,
+};
+use inflector::Inflector;
+use std::collections::BTreeSet;
+use types::{
+    format_decode_callback, format_encode_callback, format_ident, generate_type_bindings, Support,
+    RESERVED_WORDS,
+};
+
+const HEADER_DIVIDER: &str = "// ============================================= //";
+
+/// Helpers that are shared by the generated files. Besides the conversions
+/// between AssemblyScript values and the MessagePack data that is exchanged
+/// with the runtime, this includes the memory management functions that the
+/// runtimes expect plugins to export.
+const SUPPORT: &str = r#"// @ts-ignore: decorator
+@external("fp", "__fp_error")
+declare function __fp_error(report: u64): void;
+
+// @ts-ignore: decorator
+@external("fp", "__fp_host_resolve_async_value")
+declare function __fp_host_resolve_async_value(asyncValuePtr: u64, resultPtr: u64): void;
+
+/**
+ * Holds a value of a primitive type, so that it can be optional.
+ */
+export class Some<T> {
+    constructor(public value: T) {}
+}
+
+/**
+ * An error that is reported to the runtime.
+ */
+class GuestError {
+    constructor(public kind: string, public message: string) {}
+
+    encode(writer: Writer): void {
+        writer.writeMapSize(2);
+        writer.writeString("kind");
+        writer.writeString(this.kind);
+        writer.writeString("message");
+        writer.writeString(this.message);
+    }
+}
+
+/**
+ * Reports an error of the given kind to the runtime, after which the plugin
+ * traps.
+ */
+export function fail(kind: string, message: string): void {
+    const error = new GuestError(kind, message);
+    __fp_error(
+        serialize<GuestError>(error, (writer: Writer, error: GuestError): void => {
+            error.encode(writer);
+        })
+    );
+    unreachable();
+}
+
+/**
+ * Reports aborts, such as failed assertions and thrown errors, to the runtime
+ * as panics.
+ *
+ * Plugins are compiled with `--use abort=<path to the bindings>/support/abort`,
+ * so that AssemblyScript doesn't import its own `abort()` from the runtime.
+ */
+export function abort(
+    message: string | null,
+    fileName: string | null,
+    line: u32,
+    column: u32
+): void {
+    fail(
+        "panic",
+        (message !== null ? message! : "abort") +
+            " at " +
+            (fileName !== null ? fileName! : "unknown") +
+            ":" +
+            line.toString() +
+            ":" +
+            column.toString()
+    );
+}
+
+export function __fp_malloc(len: u32): u64 {
+    return toFatPtr(heap.alloc(<usize>len), len);
+}
+
+export function __fp_free(fatPtr: u64): void {
+    heap.free(<usize>(fatPtr >> 32));
+}
+
+/**
+ * Resolves an async value that was returned to the runtime by an async import.
+ *
+ * The bindings don't support async imports with return values, but the
+ * runtimes expect plugins to export this nonetheless.
+ */
+export function __fp_guest_resolve_async_value(asyncValuePtr: u64, resultPtr: u64): void {
+    const ptr = <usize>(asyncValuePtr >> 32);
+    store<u32>(ptr, <u32>(resultPtr >> 32), 4);
+    store<u32>(ptr, <u32>(resultPtr & 0xffffffff), 8);
+    store<u32>(ptr, 1); // Ready.
+}
+
+/**
+ * Returns an async value that is already resolved with the given result, for
+ * async exports.
+ */
+export function resolveAsyncValue(resultPtr: u64): u64 {
+    const len: u32 = 12; // The size of an `AsyncValue`.
+    const ptr = heap.alloc(len);
+    memory.fill(ptr, 0, len);
+    const asyncValuePtr = toFatPtr(ptr, len);
+    __fp_host_resolve_async_value(asyncValuePtr, resultPtr);
+    return asyncValuePtr;
+}
+
+function toFatPtr(ptr: usize, len: u32): u64 {
+    return (<u64>ptr << 32) | <u64>len;
+}
+
+/**
+ * Serializes a value into memory that is handed over to the runtime, which
+ * frees it once it's done with it.
+ */
+export function serialize<T>(value: T, encode: (writer: Writer, value: T) => void): u64 {
+    const sizer = new Sizer();
+    encode(sizer, value);
+    const buffer = new ArrayBuffer(<i32>sizer.length);
+    encode(new Encoder(buffer), value);
+
+    const len = <u32>buffer.byteLength;
+    const ptr = heap.alloc(len);
+    memory.copy(ptr, changetype<usize>(buffer), len);
+    return toFatPtr(ptr, len);
+}
+
+/**
+ * Deserializes a value from memory that was handed over by the runtime, which
+ * is freed afterwards.
+ */
+export function deserialize<T>(fatPtr: u64, decode: (decoder: Decoder) => T): T {
+    const ptr = <usize>(fatPtr >> 32);
+    const len = <u32>(fatPtr & 0xffffffff);
+    if ((len & 0xff000000) != 0) {
+        fail("invalid_pointer", "Unknown extension bits in pointer");
+    }
+
+    const buffer = new ArrayBuffer(<i32>len);
+    memory.copy(changetype<usize>(buffer), ptr, len);
+    heap.free(ptr);
+    return decode(new Decoder(buffer));
+}
+
+/**
+ * Skips the given number of entries of a map.
+ */
+export function skipMapEntries(decoder: Decoder, count: u32): void {
+    for (let i: u32 = 0; i < count; i++) {
+        decoder.skip();
+        decoder.skip();
+    }
+}
+
+/**
+ * Encodes a value of a type parameter, which may be a primitive, a string or
+ * any of the generated types.
+ */
+export function encodeValue<T>(writer: Writer, value: T): void {
+    if (isBoolean<T>()) {
+        writer.writeBool(<bool>value);
+    } else if (isInteger<T>()) {
+        if (isSigned<T>()) {
+            writer.writeInt64(<i64>value);
+        } else {
+            writer.writeUInt64(<u64>value);
+        }
+    } else if (isFloat<T>()) {
+        if (sizeof<T>() == 4) {
+            writer.writeFloat32(<f32>value);
+        } else {
+            writer.writeFloat64(<f64>value);
+        }
+    } else if (isString<T>()) {
+        writer.writeString(changetype<string>(value));
+    } else if (changetype<usize>(value) == 0) {
+        writer.writeNil();
+    } else {
+        // @ts-ignore: generated types implement `encode()`
+        changetype<nonnull<T>>(value).encode(writer);
+    }
+}
+
+/**
+ * Decodes a value of a type parameter, which may be a primitive, a string or
+ * any of the generated types.
+ */
+export function decodeValue<T>(decoder: Decoder): T {
+    if (isBoolean<T>()) {
+        return <T>decoder.readBool();
+    } else if (isInteger<T>()) {
+        if (isSigned<T>()) {
+            return <T>decoder.readInt64();
+        } else {
+            return <T>decoder.readUInt64();
+        }
+    } else if (isFloat<T>()) {
+        if (sizeof<T>() == 4) {
+            return <T>decoder.readFloat32();
+        } else {
+            return <T>decoder.readFloat64();
+        }
+    } else if (isString<T>()) {
+        return changetype<T>(decoder.readString());
+    } else if (isNullable<T>() && decoder.isNextNil()) {
+        return changetype<T>(0);
+    } else {
+        // @ts-ignore: generated types implement `decode()`
+        return changetype<T>(instantiate<nonnull<T>>().decode(decoder));
+    }
+}
+"#;
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: AssemblyScriptPluginConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let support = Support::new(&types);
+    let (import_functions, unsupported_imports) =
+        partition_functions(import_functions, |function| {
+            // Async imports would need to be awaited, which AssemblyScript cannot
+            // do, unless they're fire-and-forget:
+            is_function_supported(function, &support)
+                && (!function.is_async || function.is_fire_and_forget())
+        });
+    let (export_functions, unsupported_exports) =
+        partition_functions(export_functions, |function| {
+            is_function_supported(function, &support)
+        });
+
+    generate_support_file(&config, headers, path);
+    generate_type_bindings(&types, &support, &config, headers, path);
+    generate_imports_file(
+        &import_functions,
+        &unsupported_imports,
+        &types,
+        &config,
+        headers,
+        path,
+    );
+    generate_exports_file(
+        &export_functions,
+        &unsupported_exports,
+        &types,
+        headers,
+        path,
+    );
+    generate_index_file(&export_functions, &types, &config, headers, path);
+}
+
+/// Splits the functions into those that are supported by the bindings and
+/// the names of those that are not.
+fn partition_functions<F>(functions: FunctionList, is_supported: F) -> (Vec<Function>, Vec<String>)
+where
+    F: Fn(&Function) -> bool,
+{
+    let (supported, unsupported): (Vec<_>, Vec<_>) = functions.into_iter().partition(is_supported);
+    (
+        supported,
+        unsupported
+            .into_iter()
+            .map(|function| function.name)
+            .collect(),
+    )
+}
+
+/// Returns whether all the types of the function can be represented in
+/// AssemblyScript. Generic functions are not supported, since their type
+/// parameters cannot be resolved at the boundary with the runtime.
+fn is_function_supported(function: &Function, support: &Support) -> bool {
+    function.generic_params.is_empty()
+        && function
+            .args
+            .iter()
+            .map(|arg| &arg.ty)
+            .chain(function.return_type.iter())
+            .all(|ty| support.is_supported(ty, &[]))
+}
+
+fn format_header(description: &str) -> String {
+    format!(
+        "{HEADER_DIVIDER}
+// {description:<46}//
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+{HEADER_DIVIDER}"
+    )
+}
+
+/// Formats a comment that lists the functions that are left out of the
+/// bindings.
+fn format_unsupported_comment(reason: &str, names: &[String]) -> String {
+    if names.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        "// {reason}
+{}
+",
+        names
+            .iter()
+            .map(|name| format!("// - {name}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
+fn generate_support_file(config: &AssemblyScriptPluginConfig, headers: &FileHeaders, path: &str) {
+    write_bindings_file(
+        format!("{path}/support.ts"),
+        format!(
+            "{}
+
+import {{ Decoder, Encoder, Sizer, Writer }} from \"{}\";
+
+{SUPPORT}",
+            format_header("Support for AssemblyScript plugin"),
+            config.msgpack_module
+        ),
+        headers,
+    );
+}
+
+fn generate_imports_file(
+    import_functions: &[Function],
+    unsupported_imports: &[String],
+    types: &TypeMap,
+    config: &AssemblyScriptPluginConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let imports = import_functions
+        .iter()
+        .map(|function| format_import_wrapper(function, types))
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{path}/imports.ts"),
+        format!(
+            "{}
+
+import {{ Decoder, Writer }} from \"{}\";
+import {{ deserialize, serialize, Some }} from \"./support\";
+{}
+{}
+{}
+",
+            format_header("Imports for AssemblyScript plugin"),
+            config.msgpack_module,
+            format_types_import(import_functions, types),
+            format_unsupported_comment(
+                "The following imports are not included, because they use types that cannot be
+// represented in AssemblyScript or return their result asynchronously:",
+                unsupported_imports
+            ),
+            imports.join("\n\n")
+        ),
+        headers,
+    );
+}
+
+fn format_import_wrapper(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let abi_params = function
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                format_abi_param_name(&arg.name, &arg.ty),
+                format_abi_type(&arg.ty)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let abi_return_type = function
+        .return_type
+        .as_ref()
+        .filter(|_| !function.is_async)
+        .map(format_abi_type)
+        .unwrap_or_else(|| "void".to_owned());
+
+    let mut body = format_serialize_args(function, types);
+    let call = format!(
+        "__fp_gen_{name}({})",
+        function
+            .args
+            .iter()
+            .map(|arg| format_abi_param_name(&arg.name, &arg.ty))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    match &function.return_type {
+        Some(ty) if !function.is_async && !ty.is_primitive() => {
+            body.extend(format_deserialize("return", &call, ty, types))
+        }
+        Some(_) if !function.is_async => body.push(format!("return {call};")),
+        _ => body.push(format!("{call};")),
+    }
+
+    format!(
+        "// @ts-ignore: decorator
+@external(\"fp\", \"{}\")
+declare function __fp_gen_{name}({abi_params}): {abi_return_type};
+
+{}export function {}({}): {} {{
+{}}}",
+        function.symbol_name(),
+        join_lines(&format_docs(&function.doc_lines)),
+        function.name.to_camel_case(),
+        format_params(function, types),
+        format_return_type(function, types),
+        body.iter()
+            .map(|line| format!("{}\n", indent(line, 1)))
+            .collect::<String>()
+    )
+}
+
+/// Formats the statements that serialize the arguments that are not passed as
+/// primitives.
+fn format_serialize_args(function: &Function, types: &TypeMap) -> Vec<String> {
+    function
+        .args
+        .iter()
+        .filter(|arg| !arg.ty.is_primitive())
+        .flat_map(|arg| {
+            let callback = format_encode_callback(&arg.ty, "value", types);
+            let mut lines = vec![format!(
+                "const {} = serialize<{}>({}, {}",
+                format_abi_param_name(&arg.name, &arg.ty),
+                format_ident(&arg.ty, types),
+                format_variable_name(&arg.name),
+                callback[0]
+            )];
+            lines.extend(callback[1..callback.len() - 1].iter().cloned());
+            lines.push("});".to_owned());
+            lines
+        })
+        .collect()
+}
+
+/// Formats a statement that deserializes a value of the given type from the
+/// given pointer, and which starts with the given prefix.
+fn format_deserialize(prefix: &str, ptr: &str, ty: &TypeIdent, types: &TypeMap) -> Vec<String> {
+    vec![
+        format!("{prefix} deserialize<{}>(", format_ident(ty, types)),
+        format!("    {ptr},"),
+        format!("    {}", format_decode_callback(ty, types)),
+        ");".to_owned(),
+    ]
+}
+
+fn generate_exports_file(
+    export_functions: &[Function],
+    unsupported_exports: &[String],
+    types: &TypeMap,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let methods = export_functions
+        .iter()
+        .map(|function| {
+            let body = match function.name.as_str() {
+                INIT | SHUTDOWN | UPDATE_CONFIG => "{}".to_owned(),
+                name => format!(
+                    "{{\n        fail(\"internal\", \"Export `{name}` is not implemented by the plugin\");\n{}    }}",
+                    if function.return_type.is_some() {
+                        "        return unreachable();\n"
+                    } else {
+                        ""
+                    }
+                ),
+            };
+            format!(
+                "{}    {}({}): {} {body}",
+                join_lines(
+                    &format_docs(&function.doc_lines)
+                        .iter()
+                        .map(|line| indent(line, 1))
+                        .collect::<Vec<_>>()
+                ),
+                function.name.to_camel_case(),
+                format_params(function, types),
+                format_return_type(function, types)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{path}/exports.ts"),
+        format!(
+            "{}
+
+import {{ fail, Some }} from \"./support\";
+{}
+{}
+/**
+ * The exports of the plugin.
+ *
+ * Plugins extend this class to implement the exports, and pass an instance
+ * of their subclass to `registerExports()`. Exports that are not implemented
+ * report an error when they are called, except for the lifecycle exports,
+ * which do nothing by default.
+ */
+export class Exports {{
+{}
+}}
+
+let current = new Exports();
+
+/**
+ * Registers the implementation of the exports.
+ */
+export function registerExports(implementation: Exports): void {{
+    current = implementation;
+}}
+
+/**
+ * Returns the implementation of the exports.
+ */
+export function getExports(): Exports {{
+    return current;
+}}
+",
+            format_header("Exports for AssemblyScript plugin"),
+            format_types_import(export_functions, types),
+            format_unsupported_comment(
+                "The following exports are not included, because they use types that cannot be
+// represented in AssemblyScript:",
+                unsupported_exports
+            ),
+            methods.join("\n\n")
+        ),
+        headers,
+    );
+}
+
+/// Generates the file that exports the functions the runtime calls, which
+/// forward to the implementation of the exports.
+///
+/// The entry file of the plugin re-exports everything from this file.
+fn generate_index_file(
+    export_functions: &[Function],
+    types: &TypeMap,
+    config: &AssemblyScriptPluginConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let exports = export_functions
+        .iter()
+        .map(|function| format_export_wrapper(function, types))
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{path}/index.ts"),
+        format!(
+            "{}
+
+import {{ Decoder, Writer }} from \"{}\";
+import {{ getExports }} from \"./exports\";
+import {{ deserialize, resolveAsyncValue, serialize, Some }} from \"./support\";
+{}
+export {{
+    __fp_free,
+    __fp_guest_resolve_async_value,
+    __fp_malloc,
+}} from \"./support\";
+
+{}
+",
+            format_header("Entry point for AssemblyScript plugin"),
+            config.msgpack_module,
+            format_types_import(export_functions, types),
+            exports.join("\n\n")
+        ),
+        headers,
+    );
+}
+
+fn format_export_wrapper(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let abi_params = function
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                format_abi_param_name(&arg.name, &arg.ty),
+                format_abi_type(&arg.ty)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let abi_return_type = if function.is_async {
+        "u64".to_owned()
+    } else {
+        function
+            .return_type
+            .as_ref()
+            .map(format_abi_type)
+            .unwrap_or_else(|| "void".to_owned())
+    };
+
+    let mut body = function
+        .args
+        .iter()
+        .filter(|arg| !arg.ty.is_primitive())
+        .flat_map(|arg| {
+            format_deserialize(
+                &format!("const {} =", format_variable_name(&arg.name)),
+                &format_abi_param_name(&arg.name, &arg.ty),
+                &arg.ty,
+                types,
+            )
+        })
+        .collect::<Vec<_>>();
+    let call = format!(
+        "getExports().{}({})",
+        name.to_camel_case(),
+        function
+            .args
+            .iter()
+            .map(|arg| format_variable_name(&arg.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    match &function.return_type {
+        Some(ty) if function.is_async || !ty.is_primitive() => {
+            let callback = format_encode_callback(ty, "value", types);
+            body.push(format!("const result = {call};"));
+            body.push(format!(
+                "const resultPtr = serialize<{}>(result, {}",
+                format_ident(ty, types),
+                callback[0]
+            ));
+            body.extend(callback[1..callback.len() - 1].iter().cloned());
+            body.push("});".to_owned());
+            body.push(if function.is_async {
+                "return resolveAsyncValue(resultPtr);".to_owned()
+            } else {
+                "return resultPtr;".to_owned()
+            });
+        }
+        Some(_) => body.push(format!("return {call};")),
+        None if function.is_async => {
+            // The result of async exports without a return type is `()`,
+            // which is serialized as `nil`:
+            body.push(format!("{call};"));
+            body.push(
+                "return resolveAsyncValue(serialize<bool>(false, (writer: Writer, _: bool): void => {"
+                    .to_owned(),
+            );
+            body.push("    writer.writeNil();".to_owned());
+            body.push("}));".to_owned());
+        }
+        None => body.push(format!("{call};")),
+    }
+
+    format!(
+        "export function {}({abi_params}): {abi_return_type} {{
+{}}}",
+        function.symbol_name(),
+        body.iter()
+            .map(|line| format!("{}\n", indent(line, 1)))
+            .collect::<String>()
+    )
+}
+
+/// Formats the import of the generated types that are used by the given
+/// functions.
+fn format_types_import(functions: &[Function], types: &TypeMap) -> String {
+    let mut names = BTreeSet::new();
+    for function in functions {
+        for ty in function
+            .args
+            .iter()
+            .map(|arg| &arg.ty)
+            .chain(function.return_type.iter())
+        {
+            collect_type_names(ty, types, &mut names);
+        }
+    }
+
+    if names.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "import {{\n{}}} from \"./types\";\n",
+            names
+                .iter()
+                .map(|name| format!("    {name},\n"))
+                .collect::<String>()
+        )
+    }
+}
+
+/// Collects the names of the generated classes that are referenced by the
+/// given type.
+fn collect_type_names(ident: &TypeIdent, types: &TypeMap, names: &mut BTreeSet<String>) {
+    match types.get(ident) {
+        Some(Type::Alias(_, aliased)) => collect_type_names(aliased, types, names),
+        Some(Type::Struct(ty)) if ty.fields.iter().all(|field| field.name.is_none()) => {
+            if let Some(field) = ty.fields.first() {
+                collect_type_names(&field.ty, types, names);
+            }
+        }
+        Some(Type::Enum(_) | Type::Struct(_)) => {
+            names.insert(ident.name.clone());
+        }
+        _ => {}
+    }
+    for (arg, _) in ident.generic_args.iter() {
+        collect_type_names(arg, types, names);
+    }
+}
+
+fn format_params(function: &Function, types: &TypeMap) -> String {
+    function
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                format_variable_name(&arg.name),
+                format_ident(&arg.ty, types)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_return_type(function: &Function, types: &TypeMap) -> String {
+    match &function.return_type {
+        Some(ty) => format_ident(ty, types),
+        None => "void".to_owned(),
+    }
+}
+
+/// Formats the type of a value as it is passed across the boundary with the
+/// runtime. Anything but primitives is passed as a fat pointer.
+fn format_abi_type(ty: &TypeIdent) -> String {
+    match ty.as_primitive() {
+        Some(primitive) => primitive.name(),
+        None => "u64".to_owned(),
+    }
+}
+
+/// Formats the name of the parameter through which the given argument is
+/// passed to or from the runtime.
+fn format_abi_param_name(name: &str, ty: &TypeIdent) -> String {
+    if ty.is_primitive() {
+        format_variable_name(name)
+    } else {
+        format!("{}Ptr", get_variable_name(name).to_camel_case())
+    }
+}
+
+fn format_docs(doc_lines: &[String]) -> Vec<String> {
+    if doc_lines.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec!["/**".to_owned()];
+    lines.extend(doc_lines.iter().map(|line| format!(" *{line}")));
+    lines.push(" */".to_owned());
+    lines
+}
+
+fn get_variable_name(name: &str) -> &str {
+    name.strip_prefix("r#").unwrap_or(name)
+}
+
+/// Formats the name of a parameter or local variable, escaping it if it is a
+/// reserved word.
+fn format_variable_name(name: &str) -> String {
+    let name = get_variable_name(name).to_camel_case();
+    if RESERVED_WORDS.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+fn indent(line: &str, level: usize) -> String {
+    if line.is_empty() {
+        String::new()
+    } else {
+        format!("{}{line}", "    ".repeat(level))
+    }
+}
+
+fn join_lines(lines: &[String]) -> String {
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
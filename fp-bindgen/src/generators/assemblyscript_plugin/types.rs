@@ -0,0 +1,1066 @@
+use super::{format_docs, get_variable_name, indent, write_bindings_file, HEADER_DIVIDER};
+use crate::{
+    casing::Casing,
+    primitives::Primitive,
+    types::{Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+    AssemblyScriptPluginConfig, FileHeaders,
+};
+use inflector::Inflector;
+use std::{cell::RefCell, collections::BTreeMap};
+
+pub(super) fn generate_type_bindings(
+    types: &TypeMap,
+    support: &Support,
+    config: &AssemblyScriptPluginConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let type_defs = types
+        .values()
+        .filter(|ty| support.is_declaration_supported(ty))
+        .filter_map(|ty| match ty {
+            Type::Enum(ty) => Some(create_enum_definition(ty, types)),
+            Type::Struct(ty) if !is_transparent_struct(ty) => {
+                Some(create_struct_definition(ty, types))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{path}/types.ts"),
+        format!(
+            "{HEADER_DIVIDER}
+// Types for AssemblyScript plugin               //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+{HEADER_DIVIDER}
+
+import {{ Decoder, Writer }} from \"{}\";
+import {{ decodeValue, encodeValue, fail, skipMapEntries, Some }} from \"./support\";
+
+{}
+",
+            config.msgpack_module,
+            type_defs.join("\n\n")
+        ),
+        headers,
+    );
+}
+
+/// Keeps track of which types can be represented in AssemblyScript.
+///
+/// AssemblyScript has no tuples, no union types other than nullable
+/// references, and no way to represent the values of custom types, so types
+/// that (transitively) use any of these are left out of the bindings, together
+/// with the functions that use them.
+pub(super) struct Support<'a> {
+    types: &'a TypeMap,
+    declarations: RefCell<BTreeMap<TypeIdent, bool>>,
+}
+
+impl<'a> Support<'a> {
+    pub fn new(types: &'a TypeMap) -> Self {
+        Self {
+            types,
+            declarations: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns whether the given identifier can be used in the bindings. The
+    /// given generic params are the type parameters that are in scope.
+    pub fn is_supported(&self, ident: &TypeIdent, generic_params: &[String]) -> bool {
+        let ty = match self.types.get(ident) {
+            Some(ty) => ty,
+            None => return ident.generic_args.is_empty() && generic_params.contains(&ident.name),
+        };
+
+        let args_supported = || {
+            ident
+                .generic_args
+                .iter()
+                .all(|(arg, _)| self.is_supported(arg, generic_params))
+        };
+        match ty {
+            Type::Alias(_, aliased) => self.is_supported(aliased, generic_params),
+            Type::Array(_, _) | Type::Primitive(_) | Type::String => true,
+            // Optional values need to be nullable, which values of a type
+            // parameter might not be:
+            Type::Container(name, _) if name == "Option" => {
+                args_supported()
+                    && !generic_params.contains(&first_generic_arg(ident).name)
+                    && !is_optional(first_generic_arg(ident), self.types)
+            }
+            Type::Container(_, _) | Type::List(_, _) | Type::Map(_, _, _) => args_supported(),
+            Type::Custom(_) | Type::Tuple(_) | Type::Unit => false,
+            Type::Enum(_) | Type::Struct(_) => {
+                args_supported() && self.is_declaration_supported(ty)
+            }
+        }
+    }
+
+    /// Returns whether the given struct or enum can be declared in the
+    /// bindings.
+    pub fn is_declaration_supported(&self, ty: &Type) -> bool {
+        let (ident, generic_params) = match ty {
+            Type::Enum(ty) => (&ty.ident, get_generic_params(&ty.ident)),
+            Type::Struct(ty) => (&ty.ident, get_generic_params(&ty.ident)),
+            _ => return false,
+        };
+
+        if let Some(supported) = self.declarations.borrow().get(ident) {
+            return *supported;
+        }
+
+        // Recursive types are assumed to be supported while they are being
+        // checked:
+        self.declarations.borrow_mut().insert(ident.clone(), true);
+        let supported = match ty {
+            Type::Enum(ty) => {
+                let tagging = Tagging::from_options(&ty.options);
+                !matches!(tagging, Tagging::Untagged)
+                    && ty.variants.iter().all(|variant| {
+                        match Payload::from_variant(variant) {
+                            Some(Payload::Unit) => true,
+                            Some(Payload::Struct(fields)) => {
+                                self.are_fields_supported(fields, &generic_params)
+                            }
+                            // Internally tagged variants can only hold the
+                            // fields of a struct, which would need to be merged
+                            // with the tag:
+                            Some(Payload::Newtype(item)) => {
+                                !matches!(tagging, Tagging::Internal(_))
+                                    && self.is_supported(item, &generic_params)
+                            }
+                            None => false,
+                        }
+                    })
+            }
+            Type::Struct(ty) if is_transparent_struct(ty) => {
+                ty.fields.len() == 1 && self.is_supported(&ty.fields[0].ty, &generic_params)
+            }
+            Type::Struct(ty) => self.are_fields_supported(&ty.fields, &generic_params),
+            _ => false,
+        };
+        self.declarations
+            .borrow_mut()
+            .insert(ident.clone(), supported);
+        supported
+    }
+
+    fn are_fields_supported(&self, fields: &[Field], generic_params: &[String]) -> bool {
+        fields.iter().all(|field| {
+            if field.attrs.has_custom_serializer() {
+                false
+            } else if field.attrs.flatten {
+                match self.types.get(&field.ty) {
+                    Some(Type::Struct(ty)) => {
+                        !is_transparent_struct(ty)
+                            && self.are_fields_supported(&ty.fields, generic_params)
+                    }
+                    _ => false,
+                }
+            } else {
+                self.is_supported(&field.ty, generic_params)
+            }
+        })
+    }
+}
+
+/// A property of a generated class, together with the key under which it is
+/// serialized.
+struct Property {
+    key: String,
+    name: String,
+    ty: TypeIdent,
+    doc_lines: Vec<String>,
+}
+
+/// Collects the properties for the given fields, with the fields of flattened
+/// structs inlined.
+fn collect_properties(fields: &[Field], casing: Casing, types: &TypeMap) -> Vec<Property> {
+    fields
+        .iter()
+        .flat_map(|field| {
+            if field.attrs.flatten {
+                return match types.get(&field.ty) {
+                    Some(Type::Struct(ty)) => {
+                        collect_properties(&ty.fields, ty.options.field_casing, types)
+                    }
+                    _ => Vec::new(),
+                };
+            }
+
+            let name = get_variable_name(field.name.as_deref().unwrap_or_default());
+            vec![Property {
+                key: field
+                    .attrs
+                    .rename
+                    .clone()
+                    .unwrap_or_else(|| casing.format_string(name)),
+                name: format_property_name(name),
+                ty: field.ty.clone(),
+                doc_lines: field.doc_lines.clone(),
+            }]
+        })
+        .collect()
+}
+
+fn format_property_declarations(properties: &[Property], types: &TypeMap) -> Vec<String> {
+    properties
+        .iter()
+        .flat_map(|property| {
+            let ty = format_ident(&property.ty, types);
+            let mut lines = format_docs(&property.doc_lines);
+            lines.push(if is_optional(&property.ty, types) {
+                format!("{}: {ty} = null;", property.name)
+            } else if is_value_type(&property.ty, types) {
+                format!("{}: {ty};", property.name)
+            } else {
+                // References are assigned once the value is decoded, or by the
+                // plugin before the value is encoded:
+                format!("{}!: {ty};", property.name)
+            });
+            lines
+        })
+        .collect()
+}
+
+/// Formats the statements that write the properties as map entries.
+fn format_property_writes(properties: &[Property], types: &TypeMap) -> Vec<String> {
+    properties
+        .iter()
+        .flat_map(|property| {
+            let mut lines = vec![format!("writer.writeString(\"{}\");", property.key)];
+            lines.extend(format_encode(
+                &property.ty,
+                &format!("this.{}", property.name),
+                "writer",
+                types,
+            ));
+            lines
+        })
+        .collect()
+}
+
+/// Formats a loop that reads the given number of map entries into the
+/// properties.
+fn format_property_reads(
+    properties: &[Property],
+    size: &str,
+    owner: &str,
+    deny_unknown_fields: bool,
+    types: &TypeMap,
+) -> Vec<String> {
+    let mut lines = vec![
+        format!("for (let i: u32 = 0; i < {size}; i++) {{"),
+        "    const key = decoder.readString();".to_owned(),
+    ];
+    for (index, property) in properties.iter().enumerate() {
+        lines.push(format!(
+            "    {}if (key == \"{}\") {{",
+            if index == 0 { "" } else { "} else " },
+            property.key
+        ));
+        lines.push(format!(
+            "        this.{} = {};",
+            property.name,
+            format_decode(&property.ty, types)
+        ));
+    }
+    // The key has been read already, so only the value needs to be skipped:
+    let unknown_field = if deny_unknown_fields {
+        format!("fail(\"deserialization\", \"Unknown field `\" + key + \"` in `{owner}`\");")
+    } else {
+        "decoder.skip();".to_owned()
+    };
+    if properties.is_empty() {
+        lines.push(format!("    {unknown_field}"));
+    } else {
+        lines.push("    } else {".to_owned());
+        lines.push(format!("        {unknown_field}"));
+        lines.push("    }".to_owned());
+    }
+    lines.push("}".to_owned());
+    lines
+}
+
+fn create_struct_definition(ty: &Struct, types: &TypeMap) -> String {
+    let decl = format_declaration(&ty.ident);
+    let properties = collect_properties(&ty.fields, ty.options.field_casing, types);
+
+    let mut encode = vec![format!("writer.writeMapSize({});", properties.len())];
+    encode.extend(format_property_writes(&properties, types));
+
+    let mut decode = vec!["const size = decoder.readMapSize();".to_owned()];
+    decode.extend(format_property_reads(
+        &properties,
+        "size",
+        &ty.ident.name,
+        ty.options.deny_unknown_fields,
+        types,
+    ));
+    decode.push("return this;".to_owned());
+
+    let mut members = format_property_declarations(&properties, types);
+    if !members.is_empty() {
+        members.push(String::new());
+    }
+    members.extend(format_method("encode(writer: Writer): void", &encode));
+    members.push(String::new());
+    members.extend(format_method(
+        &format!("decode(decoder: Decoder): {decl}"),
+        &decode,
+    ));
+
+    format!(
+        "{}export class {decl} {{\n{}}}",
+        join_lines(&format_docs(&ty.doc_lines), 0),
+        join_lines(&members, 1)
+    )
+}
+
+/// Returns whether the given struct is serialized as the value(s) it wraps,
+/// in which case it is not generated, but replaced with the type it wraps.
+fn is_transparent_struct(ty: &Struct) -> bool {
+    !ty.fields.is_empty() && ty.fields.iter().all(|field| field.name.is_none())
+}
+
+/// The payload of an enum variant.
+enum Payload<'a> {
+    Unit,
+    Struct(&'a [Field]),
+    Newtype(&'a TypeIdent),
+}
+
+impl<'a> Payload<'a> {
+    /// Returns the payload of the given variant, or `None` if it holds
+    /// multiple unnamed fields, since those are serialized as tuples.
+    fn from_variant(variant: &'a Variant) -> Option<Self> {
+        match &variant.ty {
+            Type::Unit => Some(Self::Unit),
+            Type::Struct(ty) => Some(Self::Struct(&ty.fields)),
+            Type::Tuple(items) if items.len() == 1 => Some(Self::Newtype(&items[0])),
+            _ => None,
+        }
+    }
+}
+
+/// The representation of an enum, as determined by its Serde attributes.
+enum Tagging<'a> {
+    External,
+    Internal(&'a str),
+    Adjacent(&'a str, &'a str),
+    Untagged,
+}
+
+impl<'a> Tagging<'a> {
+    fn from_options(options: &'a EnumOptions) -> Self {
+        match (
+            options.untagged,
+            options.tag_prop_name.as_deref(),
+            options.content_prop_name.as_deref(),
+        ) {
+            (true, _, _) => Self::Untagged,
+            (false, Some(tag), Some(content)) => Self::Adjacent(tag, content),
+            (false, Some(tag), None) => Self::Internal(tag),
+            (false, None, _) => Self::External,
+        }
+    }
+}
+
+/// Creates a class for the enum, which decodes its variants, together with a
+/// subclass for every variant, which encodes it the way Serde would.
+fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
+    let decl = format_declaration(&ty.ident);
+    let tagging = Tagging::from_options(&ty.options);
+
+    let base = format!(
+        "{}export class {decl} {{
+    encode(writer: Writer): void {{
+        // Only the variants can be encoded.
+        unreachable();
+    }}
+
+{}}}",
+        join_lines(&format_docs(&ty.doc_lines), 0),
+        join_lines(
+            &format_method(
+                &format!("decode(decoder: Decoder): {decl}"),
+                &format_enum_decode_body(ty, &tagging, types)
+            ),
+            1
+        )
+    );
+
+    let variants = ty
+        .variants
+        .iter()
+        .map(|variant| create_variant_definition(ty, variant, &tagging, types));
+
+    std::iter::once(base)
+        .chain(variants)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn create_variant_definition(
+    ty: &Enum,
+    variant: &Variant,
+    tagging: &Tagging,
+    types: &TypeMap,
+) -> String {
+    let name = get_variant_class_name(&ty.ident, variant);
+    let decl = format_variant_declaration(&ty.ident, variant);
+    let base = format_declaration(&ty.ident);
+    let variant_name = get_variant_name(variant, &ty.options);
+    let payload = Payload::from_variant(variant).expect("Unsupported variant");
+
+    let mut members = Vec::new();
+    let mut encode = Vec::new();
+    match payload {
+        Payload::Unit => {
+            encode.extend(match tagging {
+                Tagging::External => vec![],
+                Tagging::Internal(tag) | Tagging::Adjacent(tag, _) => vec![
+                    "writer.writeMapSize(1);".to_owned(),
+                    format!("writer.writeString(\"{tag}\");"),
+                ],
+                Tagging::Untagged => unreachable!(),
+            });
+            encode.push(format!("writer.writeString(\"{variant_name}\");"));
+        }
+        Payload::Newtype(item) => {
+            members.push(format!(
+                "constructor(public value: {}) {{",
+                format_ident(item, types)
+            ));
+            members.push("    super();".to_owned());
+            members.push("}".to_owned());
+            members.push(String::new());
+
+            encode.extend(match tagging {
+                Tagging::External => vec![
+                    "writer.writeMapSize(1);".to_owned(),
+                    format!("writer.writeString(\"{variant_name}\");"),
+                ],
+                Tagging::Adjacent(tag, content) => vec![
+                    "writer.writeMapSize(2);".to_owned(),
+                    format!("writer.writeString(\"{tag}\");"),
+                    format!("writer.writeString(\"{variant_name}\");"),
+                    format!("writer.writeString(\"{content}\");"),
+                ],
+                Tagging::Internal(_) | Tagging::Untagged => unreachable!(),
+            });
+            encode.extend(format_encode(item, "this.value", "writer", types));
+        }
+        Payload::Struct(fields) => {
+            let properties = collect_properties(fields, variant.attrs.field_casing, types);
+            members.extend(format_property_declarations(&properties, types));
+            if !members.is_empty() {
+                members.push(String::new());
+            }
+
+            encode.extend(match tagging {
+                Tagging::External => vec![
+                    "writer.writeMapSize(1);".to_owned(),
+                    format!("writer.writeString(\"{variant_name}\");"),
+                    format!("writer.writeMapSize({});", properties.len()),
+                ],
+                Tagging::Internal(tag) => vec![
+                    format!("writer.writeMapSize({});", properties.len() + 1),
+                    format!("writer.writeString(\"{tag}\");"),
+                    format!("writer.writeString(\"{variant_name}\");"),
+                ],
+                Tagging::Adjacent(tag, content) => vec![
+                    "writer.writeMapSize(2);".to_owned(),
+                    format!("writer.writeString(\"{tag}\");"),
+                    format!("writer.writeString(\"{variant_name}\");"),
+                    format!("writer.writeString(\"{content}\");"),
+                    format!("writer.writeMapSize({});", properties.len()),
+                ],
+                Tagging::Untagged => unreachable!(),
+            });
+            encode.extend(format_property_writes(&properties, types));
+
+            let mut decode = format_property_reads(&properties, "size", &name, false, types);
+            decode.push("return this;".to_owned());
+            members.extend(format_method(
+                &format!("decodeFields(decoder: Decoder, size: u32): {decl}"),
+                &decode,
+            ));
+            members.push(String::new());
+        }
+    }
+    members.extend(format_method("encode(writer: Writer): void", &encode));
+
+    format!(
+        "{}export class {decl} extends {base} {{\n{}}}",
+        join_lines(&format_docs(&variant.doc_lines), 0),
+        join_lines(&members, 1)
+    )
+}
+
+/// Formats the body of the `decode()` method of an enum, which returns an
+/// instance of the variant that is decoded.
+///
+/// Internally and adjacently tagged enums expect the tag to come first, as it
+/// does when they are serialized by Serde.
+fn format_enum_decode_body(ty: &Enum, tagging: &Tagging, types: &TypeMap) -> Vec<String> {
+    let name = &ty.ident.name;
+    let unknown_variant =
+        format!("fail(\"deserialization\", \"Unknown variant `\" + tag + \"` for `{name}`\");");
+    let variants = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            (
+                variant,
+                Payload::from_variant(variant).expect("Unsupported variant"),
+                format_variant_condition(variant, &ty.options),
+                format!("new {}", format_variant_declaration(&ty.ident, variant)),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    match tagging {
+        Tagging::External => {
+            lines.push("if (decoder.isNextString()) {".to_owned());
+            lines.push("    const tag = decoder.readString();".to_owned());
+            for (_, payload, condition, constructor) in variants.iter() {
+                if matches!(payload, Payload::Unit) {
+                    lines.push(format!("    if ({condition}) return {constructor}();"));
+                }
+            }
+            lines.push(format!("    {unknown_variant}"));
+            lines.push("}".to_owned());
+            lines.push("if (decoder.readMapSize() != 1) {".to_owned());
+            lines.push(format!(
+                "    fail(\"deserialization\", \"Expected a single variant of `{name}`\");"
+            ));
+            lines.push("}".to_owned());
+            lines.push("const tag = decoder.readString();".to_owned());
+            for (_, payload, condition, constructor) in variants.iter() {
+                match payload {
+                    Payload::Unit => {}
+                    Payload::Newtype(item) => lines.push(format!(
+                        "if ({condition}) return {constructor}({});",
+                        format_decode(item, types)
+                    )),
+                    Payload::Struct(_) => lines.push(format!(
+                        "if ({condition}) return {constructor}().decodeFields(decoder, decoder.readMapSize());"
+                    )),
+                }
+            }
+        }
+        Tagging::Internal(tag_name) | Tagging::Adjacent(tag_name, _) => {
+            lines.push("const size = decoder.readMapSize();".to_owned());
+            lines.push(format!(
+                "if (size == 0 || decoder.readString() != \"{tag_name}\") {{"
+            ));
+            lines.push(format!(
+                "    fail(\"deserialization\", \"Expected the tag of `{name}` to come first\");"
+            ));
+            lines.push("}".to_owned());
+            lines.push("const tag = decoder.readString();".to_owned());
+
+            // Unit variants don't have any content, so anything that follows
+            // their tag is skipped:
+            let (content, skip_rest) = match tagging {
+                Tagging::Adjacent(_, content) => {
+                    lines.push(format!(
+                        "const hasContent = size > 1 && decoder.readString() == \"{content}\";"
+                    ));
+                    (Some(content), "if (hasContent) decoder.skip();")
+                }
+                _ => (None, "skipMapEntries(decoder, size - 1);"),
+            };
+            for (variant, payload, condition, constructor) in variants.iter() {
+                if variant.attrs.other {
+                    continue;
+                }
+                match (payload, content) {
+                    (Payload::Unit, _) => {
+                        lines.push(format!("if ({condition}) {{"));
+                        lines.push(format!("    {skip_rest}"));
+                        lines.push(format!("    return {constructor}();"));
+                        lines.push("}".to_owned());
+                    }
+                    (Payload::Newtype(item), Some(_)) => lines.push(format!(
+                        "if (hasContent && {condition}) return {constructor}({});",
+                        format_decode(item, types)
+                    )),
+                    (Payload::Struct(_), Some(_)) => lines.push(format!(
+                        "if (hasContent && {condition}) return {constructor}().decodeFields(decoder, decoder.readMapSize());"
+                    )),
+                    (Payload::Struct(_), None) => lines.push(format!(
+                        "if ({condition}) return {constructor}().decodeFields(decoder, size - 1);"
+                    )),
+                    (Payload::Newtype(_), None) => unreachable!(),
+                }
+            }
+            if let Some((_, _, _, constructor)) = variants
+                .iter()
+                .find(|(variant, _, _, _)| variant.attrs.other)
+            {
+                lines.push(skip_rest.to_owned());
+                lines.push(format!("return {constructor}();"));
+                return lines;
+            }
+        }
+        Tagging::Untagged => unreachable!(),
+    }
+    lines.push(unknown_variant);
+    lines.push("return unreachable();".to_owned());
+    lines
+}
+
+/// Formats the condition under which the decoded `tag` matches the variant.
+fn format_variant_condition(variant: &Variant, options: &EnumOptions) -> String {
+    std::iter::once(get_variant_name(variant, options))
+        .chain(variant.attrs.aliases.iter().cloned())
+        .map(|name| format!("tag == \"{name}\""))
+        .collect::<Vec<_>>()
+        .join(" || ")
+}
+
+fn get_variant_name(variant: &Variant, options: &EnumOptions) -> String {
+    match variant.attrs.rename.as_ref() {
+        Some(rename) => rename.to_owned(),
+        None => options
+            .variant_casing
+            .format_string(get_variable_name(&variant.name)),
+    }
+}
+
+/// Returns the name of the subclass for the given variant.
+fn get_variant_class_name(enum_ident: &TypeIdent, variant: &Variant) -> String {
+    format!("{}{}", enum_ident.name, get_variable_name(&variant.name))
+}
+
+/// Formats the declaration of the subclass for the given variant, which takes
+/// the same type parameters as the enum.
+fn format_variant_declaration(enum_ident: &TypeIdent, variant: &Variant) -> String {
+    format_declaration(&TypeIdent::new(
+        get_variant_class_name(enum_ident, variant),
+        enum_ident.generic_args.clone(),
+    ))
+}
+
+fn format_declaration(ident: &TypeIdent) -> String {
+    if ident.generic_args.is_empty() {
+        ident.name.clone()
+    } else {
+        format!(
+            "{}<{}>",
+            ident.name,
+            ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| arg.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+fn get_generic_params(ident: &TypeIdent) -> Vec<String> {
+    ident
+        .generic_args
+        .iter()
+        .map(|(arg, _)| arg.name.clone())
+        .collect()
+}
+
+fn format_method(signature: &str, body: &[String]) -> Vec<String> {
+    let mut lines = vec![format!("{signature} {{")];
+    lines.extend(body.iter().map(|line| indent(line, 1)));
+    lines.push("}".to_owned());
+    lines
+}
+
+fn join_lines(lines: &[String], level: usize) -> String {
+    lines
+        .iter()
+        .map(|line| format!("{}\n", indent(line, level)))
+        .collect()
+}
+
+/// Formats a type so it's valid AssemblyScript.
+pub(super) fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
+    let ty = match types.get(ident) {
+        Some(ty) => ty,
+        None => return ident.name.clone(), // Must be a generic.
+    };
+
+    let format_arg = |index: usize| {
+        let (arg, _) = ident
+            .generic_args
+            .get(index)
+            .expect("Identifier was expected to contain a generic argument");
+        format_ident(arg, types)
+    };
+    match ty {
+        Type::Alias(_, aliased) => format_ident(aliased, types),
+        Type::Array(primitive, _) => format!("Array<{}>", primitive.name()),
+        Type::Container(name, _) if name == "Option" => {
+            if is_value_type(first_generic_arg(ident), types) {
+                format!("Some<{}> | null", format_arg(0))
+            } else {
+                format!("{} | null", format_arg(0))
+            }
+        }
+        Type::Container(_, _) => format_arg(0),
+        Type::List(_, _) => format!("Array<{}>", format_arg(0)),
+        Type::Map(_, _, _) => format!("Map<{}, {}>", format_arg(0), format_arg(1)),
+        Type::Primitive(primitive) => primitive.name(),
+        Type::String => "string".to_owned(),
+        Type::Struct(ty) if is_transparent_struct(ty) => {
+            format_ident(&get_transparent_item(ty, ident), types)
+        }
+        Type::Enum(_) | Type::Struct(_) => {
+            if ident.generic_args.is_empty() {
+                ident.name.clone()
+            } else {
+                format!(
+                    "{}<{}>",
+                    ident.name,
+                    (0..ident.generic_args.len())
+                        .map(format_arg)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+        }
+        Type::Custom(_) | Type::Tuple(_) | Type::Unit => {
+            panic!(
+                "Type `{}` is not supported by the AssemblyScript bindings",
+                ident
+            )
+        }
+    }
+}
+
+/// Formats the statements that encode the given value using the given writer.
+pub(super) fn format_encode(
+    ident: &TypeIdent,
+    value: &str,
+    writer: &str,
+    types: &TypeMap,
+) -> Vec<String> {
+    let ty = match types.get(ident) {
+        Some(ty) => ty,
+        None => return vec![format!("encodeValue<{}>({writer}, {value});", ident.name)],
+    };
+
+    match ty {
+        Type::Alias(_, aliased) => format_encode(aliased, value, writer, types),
+        Type::Array(primitive, _) => format_encode_array(
+            &TypeIdent::from(primitive.name().as_str()),
+            value,
+            writer,
+            types,
+        ),
+        Type::Container(name, _) if name == "Option" => {
+            let item = first_generic_arg(ident);
+            let inner = if is_value_type(item, types) {
+                format!("{value}!.value")
+            } else {
+                format!("{value}!")
+            };
+            let mut lines = vec![
+                format!("if ({value} === null) {{"),
+                format!("    {writer}.writeNil();"),
+                "} else {".to_owned(),
+            ];
+            lines.extend(
+                format_encode(item, &inner, writer, types)
+                    .iter()
+                    .map(|line| indent(line, 1)),
+            );
+            lines.push("}".to_owned());
+            lines
+        }
+        Type::Container(_, _) => format_encode(first_generic_arg(ident), value, writer, types),
+        Type::List(_, _) => format_encode_array(first_generic_arg(ident), value, writer, types),
+        Type::Map(_, _, _) => {
+            let (key, _) = &ident.generic_args[0];
+            let (item, _) = &ident.generic_args[1];
+            let key_callback = format_encode_callback(key, "key", types);
+            let item_callback = format_encode_callback(item, "value", types);
+            let mut lines = vec![format!(
+                "{writer}.writeMap<{}, {}>({value}, {}",
+                format_ident(key, types),
+                format_ident(item, types),
+                key_callback[0]
+            )];
+            lines.extend(key_callback[1..key_callback.len() - 1].iter().cloned());
+            lines.push(format!("}}, {}", item_callback[0]));
+            lines.extend(item_callback[1..item_callback.len() - 1].iter().cloned());
+            lines.push("});".to_owned());
+            lines
+        }
+        Type::Primitive(primitive) => vec![format!(
+            "{writer}.write{}({value});",
+            format_msgpack_name(*primitive)
+        )],
+        Type::String => vec![format!("{writer}.writeString({value});")],
+        Type::Struct(ty) if is_transparent_struct(ty) => {
+            format_encode(&get_transparent_item(ty, ident), value, writer, types)
+        }
+        Type::Enum(_) | Type::Struct(_) => vec![format!("{value}.encode({writer});")],
+        Type::Custom(_) | Type::Tuple(_) | Type::Unit => {
+            panic!(
+                "Type `{}` is not supported by the AssemblyScript bindings",
+                ident
+            )
+        }
+    }
+}
+
+fn format_encode_array(
+    item: &TypeIdent,
+    value: &str,
+    writer: &str,
+    types: &TypeMap,
+) -> Vec<String> {
+    let callback = format_encode_callback(item, "item", types);
+    let mut lines = vec![format!(
+        "{writer}.writeArray<{}>({value}, {}",
+        format_ident(item, types),
+        callback[0]
+    )];
+    lines.extend(callback[1..callback.len() - 1].iter().cloned());
+    lines.push("});".to_owned());
+    lines
+}
+
+/// Formats an arrow function that encodes its argument. The function doesn't
+/// capture anything, since AssemblyScript doesn't support closures.
+pub(super) fn format_encode_callback(
+    ident: &TypeIdent,
+    name: &str,
+    types: &TypeMap,
+) -> Vec<String> {
+    let mut lines = vec![format!(
+        "(writer: Writer, {name}: {}): void => {{",
+        format_ident(ident, types)
+    )];
+    lines.extend(
+        format_encode(ident, name, "writer", types)
+            .iter()
+            .map(|line| indent(line, 1)),
+    );
+    lines.push("}".to_owned());
+    lines
+}
+
+/// Formats an expression that decodes a value of the given type from the
+/// `decoder`.
+pub(super) fn format_decode(ident: &TypeIdent, types: &TypeMap) -> String {
+    let ty = match types.get(ident) {
+        Some(ty) => ty,
+        None => return format!("decodeValue<{}>(decoder)", ident.name),
+    };
+
+    match ty {
+        Type::Alias(_, aliased) => format_decode(aliased, types),
+        Type::Array(primitive, _) => {
+            format_decode_array(&TypeIdent::from(primitive.name().as_str()), types)
+        }
+        Type::Container(name, _) if name == "Option" => {
+            let item = first_generic_arg(ident);
+            if is_value_type(item, types) {
+                format!(
+                    "decoder.isNextNil() ? null : new Some<{}>({})",
+                    format_ident(item, types),
+                    format_decode(item, types)
+                )
+            } else {
+                format!(
+                    "decoder.isNextNil() ? null : {}",
+                    format_decode(item, types)
+                )
+            }
+        }
+        Type::Container(_, _) => format_decode(first_generic_arg(ident), types),
+        Type::List(_, _) => format_decode_array(first_generic_arg(ident), types),
+        Type::Map(_, _, _) => {
+            let (key, _) = &ident.generic_args[0];
+            let (item, _) = &ident.generic_args[1];
+            format!(
+                "decoder.readMap<{}, {}>({}, {})",
+                format_ident(key, types),
+                format_ident(item, types),
+                format_decode_callback(key, types),
+                format_decode_callback(item, types)
+            )
+        }
+        Type::Primitive(primitive) => format!("decoder.read{}()", format_msgpack_name(*primitive)),
+        Type::String => "decoder.readString()".to_owned(),
+        Type::Struct(ty) if is_transparent_struct(ty) => {
+            format_decode(&get_transparent_item(ty, ident), types)
+        }
+        Type::Enum(_) | Type::Struct(_) => {
+            format!("new {}().decode(decoder)", format_ident(ident, types))
+        }
+        Type::Custom(_) | Type::Tuple(_) | Type::Unit => {
+            panic!(
+                "Type `{}` is not supported by the AssemblyScript bindings",
+                ident
+            )
+        }
+    }
+}
+
+fn format_decode_array(item: &TypeIdent, types: &TypeMap) -> String {
+    format!(
+        "decoder.readArray<{}>({})",
+        format_ident(item, types),
+        format_decode_callback(item, types)
+    )
+}
+
+/// Formats an arrow function that decodes a value from its argument.
+pub(super) fn format_decode_callback(ident: &TypeIdent, types: &TypeMap) -> String {
+    format!(
+        "(decoder: Decoder): {} => {}",
+        format_ident(ident, types),
+        format_decode(ident, types)
+    )
+}
+
+/// Returns the name of the primitive, as it is used in the methods of the
+/// MessagePack encoder and decoder.
+fn format_msgpack_name(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "Bool",
+        Primitive::F32 => "Float32",
+        Primitive::F64 => "Float64",
+        Primitive::I8 => "Int8",
+        Primitive::I16 => "Int16",
+        Primitive::I32 => "Int32",
+        Primitive::I64 => "Int64",
+        Primitive::U8 => "UInt8",
+        Primitive::U16 => "UInt16",
+        Primitive::U32 => "UInt32",
+        Primitive::U64 => "UInt64",
+    }
+}
+
+/// Returns whether the given type is represented by an AssemblyScript value
+/// type, which cannot be `null`.
+pub(super) fn is_value_type(ident: &TypeIdent, types: &TypeMap) -> bool {
+    match types.get(ident) {
+        Some(Type::Alias(_, aliased)) => is_value_type(aliased, types),
+        Some(Type::Container(name, _)) if name != "Option" => {
+            is_value_type(first_generic_arg(ident), types)
+        }
+        Some(Type::Primitive(_)) => true,
+        Some(Type::Struct(ty)) if is_transparent_struct(ty) => {
+            is_value_type(&get_transparent_item(ty, ident), types)
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the given type is an `Option`, and therefore nullable.
+fn is_optional(ident: &TypeIdent, types: &TypeMap) -> bool {
+    match types.get(ident) {
+        Some(Type::Alias(_, aliased)) => is_optional(aliased, types),
+        Some(Type::Container(name, _)) if name == "Option" => true,
+        Some(Type::Container(_, _)) => is_optional(first_generic_arg(ident), types),
+        Some(Type::Struct(ty)) if is_transparent_struct(ty) => {
+            is_optional(&get_transparent_item(ty, ident), types)
+        }
+        _ => false,
+    }
+}
+
+fn first_generic_arg(ident: &TypeIdent) -> &TypeIdent {
+    let (arg, _) = ident
+        .generic_args
+        .first()
+        .expect("Identifier was expected to contain a generic argument");
+    arg
+}
+
+/// Returns the type wrapped by a transparent struct, with the generic
+/// parameters of its declaration replaced by the arguments from the
+/// identifier by which it is used.
+fn get_transparent_item(ty: &Struct, usage: &TypeIdent) -> TypeIdent {
+    substitute_generic_args(&ty.fields[0].ty, &ty.ident, usage)
+}
+
+fn substitute_generic_args(ty: &TypeIdent, decl: &TypeIdent, usage: &TypeIdent) -> TypeIdent {
+    if ty.generic_args.is_empty() {
+        if let Some(index) = decl
+            .generic_args
+            .iter()
+            .position(|(param, _)| param.name == ty.name)
+        {
+            if let Some((arg, _)) = usage.generic_args.get(index) {
+                return arg.clone();
+            }
+        }
+    }
+
+    let mut ty = ty.clone();
+    for (arg, _) in ty.generic_args.iter_mut() {
+        *arg = substitute_generic_args(arg, decl, usage);
+    }
+    ty
+}
+
+/// Formats the name of a property, escaping it if it is a reserved word.
+fn format_property_name(name: &str) -> String {
+    let name = name.to_camel_case();
+    if RESERVED_WORDS.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+pub(super) const RESERVED_WORDS: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "let",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
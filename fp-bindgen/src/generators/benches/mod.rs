@@ -0,0 +1,440 @@
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    primitives::Primitive,
+    types::{Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+    BenchesConfig, FileHeaders,
+};
+use std::{collections::BTreeMap, fs};
+
+pub(crate) mod rust;
+pub(crate) mod typescript;
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: BenchesConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    fs::create_dir_all(path).expect("Could not create output directory");
+
+    let import_benches = collect_benches(&import_functions, &types);
+    let export_benches = collect_benches(&export_functions, &types);
+
+    rust::generate_benches(
+        &import_benches,
+        &export_benches,
+        &types,
+        &config,
+        headers,
+        path,
+    );
+    typescript::generate_benches(
+        &import_benches,
+        &export_benches,
+        &types,
+        &config,
+        headers,
+        path,
+    );
+}
+
+/// The sample payloads for benchmarking a single function, or the reason why
+/// no payloads could be generated for it.
+pub(crate) struct FunctionBench<'a> {
+    pub function: &'a Function,
+    pub samples: Result<FunctionSamples, String>,
+}
+
+pub(crate) struct FunctionSamples {
+    pub args: Vec<Sample>,
+    pub return_value: Option<Sample>,
+}
+
+impl FunctionSamples {
+    /// Whether any of the payloads grows with the payload size. If not, the
+    /// function is only benchmarked once.
+    pub fn depends_on_size(&self) -> bool {
+        self.args
+            .iter()
+            .chain(self.return_value.iter())
+            .any(Sample::depends_on_size)
+    }
+}
+
+fn collect_benches<'a>(functions: &'a FunctionList, types: &TypeMap) -> Vec<FunctionBench<'a>> {
+    functions
+        .iter()
+        .map(|function| FunctionBench {
+            function,
+            samples: collect_samples(function, types),
+        })
+        .collect()
+}
+
+fn collect_samples(function: &Function, types: &TypeMap) -> Result<FunctionSamples, String> {
+    if let Some(feature) = &function.attrs.cfg_feature {
+        return Err(format!("it is behind the `{feature}` feature"));
+    }
+    if !function.generic_params.is_empty() {
+        return Err("it is generic".to_owned());
+    }
+
+    let args = function
+        .args
+        .iter()
+        .map(|arg| create_sample(&arg.ty, types, &BTreeMap::new(), &mut Vec::new()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let return_value = function
+        .return_type
+        .as_ref()
+        .map(|ty| create_sample(ty, types, &BTreeMap::new(), &mut Vec::new()))
+        .transpose()?;
+    Ok(FunctionSamples { args, return_value })
+}
+
+/// A representative value of a protocol type, from which the generators
+/// render the payloads for the benchmarks.
+///
+/// Strings, lists and maps contain as many characters or items as the payload
+/// size, while all other values are fixed.
+#[derive(Clone, Debug)]
+pub(crate) enum Sample {
+    Array(Primitive, usize),
+    Container(String, Box<Sample>),
+    List(Box<Sample>),
+    Map(MapKey, Box<Sample>),
+    Option(Box<Sample>),
+    Pair(Primitive, Primitive),
+    Primitive(Primitive),
+    String,
+    Struct(StructSample),
+    Unit,
+    Variant(VariantSample),
+}
+
+/// Map keys are derived from the index of the entry, so they are unique.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum MapKey {
+    Integer(Primitive),
+    String,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct StructSample {
+    /// The name of the Rust type, or the path of the variant.
+    pub rust_path: String,
+    pub fields: Vec<FieldSample>,
+    pub is_tuple: bool,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct FieldSample {
+    pub rust_name: String,
+    pub serialized_name: String,
+    pub flatten: bool,
+    pub value: Sample,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct VariantSample {
+    pub options: EnumOptions,
+    pub serialized_name: String,
+    pub value: VariantValue,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum VariantValue {
+    /// A unit variant, with the path of the Rust variant.
+    Unit(String),
+    Struct(StructSample),
+    /// A variant with unnamed fields, with the path of the Rust variant.
+    Tuple(String, Vec<Sample>),
+}
+
+impl Sample {
+    /// Whether the Rust type of the sample is guaranteed to implement `Copy`.
+    pub fn is_copy(&self) -> bool {
+        matches!(
+            self,
+            Self::Array(_, _) | Self::Pair(_, _) | Self::Primitive(_) | Self::Unit
+        )
+    }
+
+    pub fn depends_on_size(&self) -> bool {
+        match self {
+            Self::Container(_, item) | Self::Option(item) => item.depends_on_size(),
+            Self::List(_) | Self::Map(_, _) | Self::String => true,
+            Self::Struct(sample) => sample.depends_on_size(),
+            Self::Variant(sample) => match &sample.value {
+                VariantValue::Unit(_) => false,
+                VariantValue::Struct(sample) => sample.depends_on_size(),
+                VariantValue::Tuple(_, items) => items.iter().any(Sample::depends_on_size),
+            },
+            Self::Array(_, _) | Self::Pair(_, _) | Self::Primitive(_) | Self::Unit => false,
+        }
+    }
+}
+
+impl StructSample {
+    fn depends_on_size(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|field| field.value.depends_on_size())
+    }
+}
+
+/// Creates a sample value for the given type.
+///
+/// `generic_args` maps the generic parameters of the surrounding type to their
+/// concrete arguments, while `stack` contains the (fully specified) types that
+/// are currently being sampled, so that recursive types can be rejected.
+pub(crate) fn create_sample(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    generic_args: &BTreeMap<String, TypeIdent>,
+    stack: &mut Vec<String>,
+) -> Result<Sample, String> {
+    if let Some(arg) = generic_args.get(&ident.name) {
+        return create_sample(arg, types, &BTreeMap::new(), stack);
+    }
+
+    let ident = substitute_generic_args(ident, generic_args);
+    if let Some(primitive) = ident.as_primitive() {
+        return Ok(Sample::Primitive(primitive));
+    }
+    if let Some((first, second)) = ident.as_packed_pair() {
+        return Ok(Sample::Pair(first, second));
+    }
+
+    let ty = types
+        .get(&ident)
+        .ok_or_else(|| format!("type `{ident}` is unknown"))?;
+    let first_generic_arg = || {
+        ident
+            .generic_args
+            .first()
+            .map(|(arg, _)| arg)
+            .ok_or_else(|| format!("type `{ident}` is missing a generic argument"))
+    };
+
+    match ty {
+        Type::Alias(_, aliased) => create_sample(aliased, types, &BTreeMap::new(), stack),
+        Type::Array(primitive, len) => Ok(Sample::Array(*primitive, *len)),
+        Type::Container(name, _) => {
+            let item = create_sample(first_generic_arg()?, types, &BTreeMap::new(), stack)?;
+            match name.as_str() {
+                "Option" => Ok(Sample::Option(Box::new(item))),
+                "Box" | "Rc" | "Arc" => Ok(Sample::Container(name.clone(), Box::new(item))),
+                _ => Err(format!("container `{name}` is not supported")),
+            }
+        }
+        Type::Custom(_) => Err(format!("custom type `{ident}` is not supported")),
+        Type::Enum(ty) => with_stack(stack, &ident, |stack| {
+            create_variant_sample(ty, &ident, types, stack)
+        }),
+        Type::List(name, _) if name == "Vec" => {
+            let item = create_sample(first_generic_arg()?, types, &BTreeMap::new(), stack)?;
+            Ok(Sample::List(Box::new(item)))
+        }
+        Type::List(name, _) => Err(format!("list type `{name}` is not supported")),
+        Type::Map(name, _, _) => {
+            let key = first_generic_arg()?;
+            let value = ident
+                .generic_args
+                .get(1)
+                .map(|(arg, _)| arg)
+                .ok_or_else(|| format!("type `{ident}` is missing a generic argument"))?;
+            let key = match key.as_primitive() {
+                Some(Primitive::Bool | Primitive::F32 | Primitive::F64) => None,
+                Some(primitive) => Some(MapKey::Integer(primitive)),
+                None if key.name == "String" => Some(MapKey::String),
+                None => None,
+            }
+            .ok_or_else(|| format!("key type `{key}` of map `{name}` is not supported"))?;
+            let value = create_sample(value, types, &BTreeMap::new(), stack)?;
+            Ok(Sample::Map(key, Box::new(value)))
+        }
+        Type::Primitive(primitive) => Ok(Sample::Primitive(*primitive)),
+        Type::String => Ok(Sample::String),
+        Type::Struct(ty) => with_stack(stack, &ident, |stack| {
+            let generic_args = resolve_generic_args(&ty.ident, &ident);
+            create_struct_sample(ty, &ty.ident.name, types, &generic_args, stack)
+                .map(Sample::Struct)
+        }),
+        Type::Tuple(_) => Err(format!("tuple `{ident}` is not supported")),
+        Type::Unit => Ok(Sample::Unit),
+    }
+}
+
+fn with_stack<F>(stack: &mut Vec<String>, ident: &TypeIdent, f: F) -> Result<Sample, String>
+where
+    F: FnOnce(&mut Vec<String>) -> Result<Sample, String>,
+{
+    let name = ident.to_string();
+    if stack.contains(&name) {
+        return Err(format!("recursive type `{}` is not supported", ident.name));
+    }
+
+    stack.push(name);
+    let result = f(stack);
+    stack.pop();
+    result
+}
+
+fn create_struct_sample(
+    ty: &Struct,
+    rust_path: &str,
+    types: &TypeMap,
+    generic_args: &BTreeMap<String, TypeIdent>,
+    stack: &mut Vec<String>,
+) -> Result<StructSample, String> {
+    create_fields_sample(
+        &ty.fields,
+        rust_path,
+        ty.options.field_casing,
+        types,
+        generic_args,
+        stack,
+    )
+}
+
+fn create_fields_sample(
+    fields: &[Field],
+    rust_path: &str,
+    casing: Casing,
+    types: &TypeMap,
+    generic_args: &BTreeMap<String, TypeIdent>,
+    stack: &mut Vec<String>,
+) -> Result<StructSample, String> {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            if field.attrs.has_custom_serializer() {
+                return Err(format!(
+                    "field `{}` of `{rust_path}` has a custom (de)serializer",
+                    field.name.as_deref().unwrap_or("0")
+                ));
+            }
+            if field.ty.is_phantom_data() {
+                return Err(format!(
+                    "field `{}` of `{rust_path}` is a `PhantomData` marker",
+                    field.name.as_deref().unwrap_or("0")
+                ));
+            }
+
+            let rust_name = field.name.clone().unwrap_or_default();
+            let serialized_name = match field.attrs.rename.as_ref() {
+                Some(rename) => rename.clone(),
+                None => casing.format_string(rust_name.trim_start_matches("r#")),
+            };
+            Ok(FieldSample {
+                rust_name,
+                serialized_name,
+                flatten: field.attrs.flatten,
+                value: create_sample(&field.ty, types, generic_args, stack)?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(StructSample {
+        rust_path: rust_path.to_owned(),
+        is_tuple: fields.first().map(|field| field.rust_name.is_empty()) == Some(true),
+        fields,
+    })
+}
+
+/// Samples an enum using its first variant that can be sampled, preferring
+/// unit variants because they are the most common payload.
+fn create_variant_sample(
+    ty: &Enum,
+    ident: &TypeIdent,
+    types: &TypeMap,
+    stack: &mut Vec<String>,
+) -> Result<Sample, String> {
+    let generic_args = resolve_generic_args(&ty.ident, ident);
+    let mut variants = ty.variants.iter().collect::<Vec<_>>();
+    variants.sort_by_key(|variant| !matches!(variant.ty, Type::Unit));
+
+    let mut error = format!("enum `{}` has no variants", ty.ident.name);
+    for variant in variants {
+        match create_variant_value(ty, variant, types, &generic_args, stack) {
+            Ok(value) => {
+                return Ok(Sample::Variant(VariantSample {
+                    options: ty.options.clone(),
+                    serialized_name: match variant.attrs.rename.as_ref() {
+                        Some(rename) => rename.clone(),
+                        None => ty
+                            .options
+                            .variant_casing
+                            .format_string(variant.name.trim_start_matches("r#")),
+                    },
+                    value,
+                }))
+            }
+            Err(err) => error = err,
+        }
+    }
+    Err(error)
+}
+
+fn create_variant_value(
+    ty: &Enum,
+    variant: &Variant,
+    types: &TypeMap,
+    generic_args: &BTreeMap<String, TypeIdent>,
+    stack: &mut Vec<String>,
+) -> Result<VariantValue, String> {
+    let rust_path = format!("{}::{}", ty.ident.name, variant.name);
+    match &variant.ty {
+        Type::Unit => Ok(VariantValue::Unit(rust_path)),
+        Type::Struct(variant_struct) => create_fields_sample(
+            &variant_struct.fields,
+            &rust_path,
+            variant.attrs.field_casing,
+            types,
+            generic_args,
+            stack,
+        )
+        .map(VariantValue::Struct),
+        Type::Tuple(items) => {
+            let items = items
+                .iter()
+                .map(|item| create_sample(item, types, generic_args, stack))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(VariantValue::Tuple(rust_path, items))
+        }
+        other => Err(format!(
+            "variant `{rust_path}` of type `{}` is not supported",
+            other.name()
+        )),
+    }
+}
+
+/// Maps the generic parameters of a type definition to the arguments used by
+/// the given identifier.
+fn resolve_generic_args(definition: &TypeIdent, ident: &TypeIdent) -> BTreeMap<String, TypeIdent> {
+    definition
+        .generic_args
+        .iter()
+        .zip(ident.generic_args.iter())
+        .map(|((param, _), (arg, _))| (param.name.clone(), arg.clone()))
+        .collect()
+}
+
+fn substitute_generic_args(
+    ident: &TypeIdent,
+    generic_args: &BTreeMap<String, TypeIdent>,
+) -> TypeIdent {
+    let mut ident = ident.clone();
+    for (arg, _) in ident.generic_args.iter_mut() {
+        if let Some(substitute) = generic_args.get(&arg.name) {
+            *arg = substitute.clone();
+        } else {
+            *arg = substitute_generic_args(arg, generic_args);
+        }
+    }
+    ident
+}
@@ -0,0 +1,341 @@
+use super::{FunctionBench, FunctionSamples, MapKey, Sample, StructSample, VariantValue};
+use crate::{
+    functions::Function,
+    generators::{formatting::rustfmt, rust_plugin::format_ident, write_bindings_file},
+    primitives::Primitive,
+    types::TypeMap,
+    BenchesConfig, FileHeaders,
+};
+
+/// Generates a module with criterion benchmarks for the Wasmer runtime. It is
+/// meant to be placed next to the `bindings.rs` and `types.rs` of the runtime.
+pub(super) fn generate_benches(
+    import_benches: &[FunctionBench],
+    export_benches: &[FunctionBench],
+    types: &TypeMap,
+    config: &BenchesConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let needs_async_runtime = export_benches
+        .iter()
+        .any(|bench| bench.function.is_async && bench.samples.is_ok());
+
+    let mut calls = Vec::new();
+    let mut bench_functions = Vec::new();
+    for (bench, is_export) in export_benches
+        .iter()
+        .map(|bench| (bench, true))
+        .chain(import_benches.iter().map(|bench| (bench, false)))
+    {
+        let name = &bench.function.name;
+        let samples = match &bench.samples {
+            Ok(samples) => samples,
+            Err(reason) => {
+                calls.push(format!("// Skipped `{name}`: {reason}."));
+                continue;
+            }
+        };
+
+        let stages = format_stages(bench.function, samples, is_export);
+        if stages.is_empty() {
+            continue;
+        }
+
+        let (params, args) = if is_export && bench.function.is_async {
+            (
+                ", rt: &Runtime, async_runtime: &tokio::runtime::Runtime",
+                ", &rt, &async_runtime",
+            )
+        } else if is_export {
+            (", rt: &Runtime", ", &rt")
+        } else {
+            ("", "")
+        };
+        calls.push(format!("bench_{name}(c{args});"));
+        bench_functions.push(format_bench_function(
+            bench.function,
+            samples,
+            &stages,
+            params,
+            types,
+        ));
+    }
+
+    let async_runtime = if needs_async_runtime {
+        "let async_runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect(\"Could not create async runtime\");\n"
+    } else {
+        ""
+    };
+
+    let contents = rustfmt(format!(
+        "use super::bindings::Runtime;
+use super::types::*;
+use criterion::{{BatchSize, BenchmarkId, Criterion}};
+use fp_bindgen_support::host::mem::{{deserialize_from_slice, serialize_to_vec}};
+
+/// The payload sizes to benchmark with. Strings, lists and maps in the payloads
+/// contain this many characters or items.
+pub const PAYLOAD_SIZES: [usize; {}] = [{}];
+
+/// Benchmarks the costs of serializing arguments, calling the plugin and
+/// deserializing return values for every protocol function.
+///
+/// Calls are only benchmarked for exported functions, because imported
+/// functions can only be called by the plugin.
+pub fn bench_functions(c: &mut Criterion, wasm_module: &[u8]) {{
+    let rt = Runtime::new(wasm_module).expect(\"Could not create runtime\");
+    {async_runtime}
+    {}
+}}
+
+{}
+",
+        config.payload_sizes.len(),
+        config
+            .payload_sizes
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+        calls.join("\n"),
+        bench_functions.join("\n\n")
+    ));
+
+    write_bindings_file(
+        format!("{path}/benches.rs"),
+        format!(
+            "// ============================================= //
+// Benchmarks for the Wasmer runtime             //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+{contents}"
+        ),
+        headers,
+    );
+}
+
+enum Stage {
+    Serialize(Vec<String>),
+    Call(String),
+    Deserialize,
+}
+
+fn format_stages(function: &Function, samples: &FunctionSamples, is_export: bool) -> Vec<Stage> {
+    let mut stages = Vec::new();
+
+    let serialized_args = function
+        .args
+        .iter()
+        .filter(|arg| !arg.ty.is_primitive())
+        .map(|arg| arg.name.clone())
+        .collect::<Vec<_>>();
+    if !serialized_args.is_empty() {
+        stages.push(Stage::Serialize(serialized_args));
+    }
+
+    if is_export {
+        let args = function
+            .args
+            .iter()
+            .map(|arg| arg.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let call = format!("rt.{}({args})", function.name);
+        stages.push(Stage::Call(if function.is_async {
+            format!("async_runtime.block_on({call})")
+        } else {
+            call
+        }));
+    }
+
+    if let (Some(ty), Some(_)) = (&function.return_type, &samples.return_value) {
+        if function.is_async || !(ty.is_primitive() || ty.is_packed_pair()) {
+            stages.push(Stage::Deserialize);
+        }
+    }
+
+    stages
+}
+
+fn format_bench_function(
+    function: &Function,
+    samples: &FunctionSamples,
+    stages: &[Stage],
+    params: &str,
+    types: &TypeMap,
+) -> String {
+    let name = &function.name;
+    let depends_on_size = samples.depends_on_size();
+    let has_call = stages.iter().any(|stage| matches!(stage, Stage::Call(_)));
+
+    // Primitive arguments are only needed when the function gets called.
+    let mut lines = function
+        .args
+        .iter()
+        .zip(samples.args.iter())
+        .filter(|(arg, _)| has_call || !arg.ty.is_primitive())
+        .map(|(arg, sample)| {
+            format!(
+                "let {}: {} = {};",
+                arg.name,
+                format_ident(&arg.ty, types),
+                format_sample(sample)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    for stage in stages {
+        let (id, body) = match stage {
+            Stage::Serialize(args) => (
+                "serialize",
+                format!(
+                    "b.iter(|| {})",
+                    format_tuple(args.iter().map(|arg| format!("serialize_to_vec(&{arg})")))
+                ),
+            ),
+            Stage::Call(call) => {
+                // Arguments are passed by value, so every iteration gets its
+                // own copy of them.
+                let setup = format_tuple(function.args.iter().zip(samples.args.iter()).map(
+                    |(arg, sample)| {
+                        if sample.is_copy() {
+                            arg.name.clone()
+                        } else {
+                            format!("{}.clone()", arg.name)
+                        }
+                    },
+                ));
+                let names = format_tuple(function.args.iter().map(|arg| arg.name.clone()));
+                (
+                    "call",
+                    format!("b.iter_batched(|| {setup}, |{names}| {call}, BatchSize::SmallInput)"),
+                )
+            }
+            Stage::Deserialize => {
+                let return_type = format_ident(function.return_type.as_ref().unwrap(), types);
+                lines.push(format!(
+                    "let result: {return_type} = {};\nlet result = serialize_to_vec(&result);",
+                    format_sample(samples.return_value.as_ref().unwrap())
+                ));
+                (
+                    "deserialize",
+                    format!("b.iter(|| deserialize_from_slice::<{return_type}>(&result))"),
+                )
+            }
+        };
+
+        lines.push(if depends_on_size {
+            format!(
+                "group.bench_with_input(BenchmarkId::new(\"{id}\", size), &size, |b, _| {body});"
+            )
+        } else {
+            format!("group.bench_function(\"{id}\", |b| {body});")
+        });
+    }
+
+    let body = lines.join("\n");
+    let body = if depends_on_size {
+        format!("for size in PAYLOAD_SIZES {{\n{body}\n}}")
+    } else {
+        body
+    };
+
+    format!(
+        "fn bench_{name}(c: &mut Criterion{params}) {{
+    let mut group = c.benchmark_group(\"{name}\");
+    {body}
+    group.finish();
+}}"
+    )
+}
+
+/// Formats the given items as a tuple, unless there is only a single item.
+fn format_tuple(items: impl Iterator<Item = String>) -> String {
+    let items = items.collect::<Vec<_>>();
+    match items.as_slice() {
+        [item] => item.clone(),
+        items => format!("({})", items.join(", ")),
+    }
+}
+
+/// Formats a sample as a Rust expression, in which `size` determines the
+/// length of strings, lists and maps.
+pub(crate) fn format_sample(sample: &Sample) -> String {
+    match sample {
+        Sample::Array(primitive, len) => format!("[{}; {len}]", format_primitive(*primitive)),
+        Sample::Container(name, item) => match name.as_str() {
+            "Rc" => format!("std::rc::Rc::new({})", format_sample(item)),
+            "Arc" => format!("std::sync::Arc::new({})", format_sample(item)),
+            _ => format!("{name}::new({})", format_sample(item)),
+        },
+        Sample::List(item) => format!("(0..size).map(|_| {}).collect()", format_sample(item)),
+        Sample::Map(key, value) => format!(
+            "(0..size).map(|i| ({}, {})).collect()",
+            match key {
+                MapKey::Integer(primitive) => format!("i as {}", primitive.name()),
+                MapKey::String => "i.to_string()".to_owned(),
+            },
+            format_sample(value)
+        ),
+        Sample::Option(item) => format!("Some({})", format_sample(item)),
+        Sample::Pair(first, second) => format!(
+            "({}, {})",
+            format_primitive(*first),
+            format_primitive(*second)
+        ),
+        Sample::Primitive(primitive) => format_primitive(*primitive).to_owned(),
+        Sample::String => "\"x\".repeat(size)".to_owned(),
+        Sample::Struct(sample) => format_struct_sample(sample),
+        Sample::Unit => "()".to_owned(),
+        Sample::Variant(sample) => match &sample.value {
+            VariantValue::Unit(path) => path.clone(),
+            VariantValue::Struct(sample) => format_struct_sample(sample),
+            VariantValue::Tuple(path, items) => format!(
+                "{path}({})",
+                items
+                    .iter()
+                    .map(format_sample)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        },
+    }
+}
+
+fn format_struct_sample(sample: &StructSample) -> String {
+    let path = &sample.rust_path;
+    if sample.is_tuple {
+        format!(
+            "{path}({})",
+            sample
+                .fields
+                .iter()
+                .map(|field| format_sample(&field.value))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    } else {
+        format!(
+            "{path} {{ {} }}",
+            sample
+                .fields
+                .iter()
+                .map(|field| format!("{}: {}", field.rust_name, format_sample(&field.value)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+fn format_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "true",
+        Primitive::F32 | Primitive::F64 => "1.5",
+        _ => "1",
+    }
+}
@@ -0,0 +1,393 @@
+use super::{
+    FunctionBench, FunctionSamples, MapKey, Sample, StructSample, VariantSample, VariantValue,
+};
+use crate::{
+    functions::Function,
+    generators::{ts_runtime::format_plain_primitive_or_ident, write_bindings_file},
+    primitives::Primitive,
+    types::TypeMap,
+    BenchesConfig, FileHeaders, TsInt64Encoding,
+};
+
+/// Generates a script with tinybench benchmarks for the TypeScript runtime. It
+/// is meant to be placed next to the `index.ts` and `types.ts` of the runtime.
+pub(super) fn generate_benches(
+    import_benches: &[FunctionBench],
+    export_benches: &[FunctionBench],
+    types: &TypeMap,
+    config: &BenchesConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let mut benches = Vec::new();
+    for (bench, is_export) in export_benches
+        .iter()
+        .map(|bench| (bench, true))
+        .chain(import_benches.iter().map(|bench| (bench, false)))
+    {
+        let samples = match &bench.samples {
+            Ok(samples) => samples,
+            Err(reason) => {
+                benches.push(format!(
+                    "    // Skipped `{}`: {reason}.",
+                    bench.function.name
+                ));
+                continue;
+            }
+        };
+
+        if let Some(lines) =
+            format_function_benches(bench.function, samples, is_export, types, config)
+        {
+            benches.push(lines);
+        }
+    }
+
+    let extension = if config.msgpack_module.ends_with(".ts") {
+        ".ts"
+    } else {
+        ""
+    };
+
+    let contents = format!(
+        "// ============================================= //
+// Benchmarks for the TypeScript runtime         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {{ Bench }} from \"tinybench\";
+import {{ decode, encode }} from \"{}\";
+
+import {{ createRuntime }} from \"./index{extension}\";
+import type {{ Imports }} from \"./index{extension}\";
+import type * as types from \"./types{extension}\";
+
+/**
+ * The payload sizes to benchmark with. Strings, lists and maps in the payloads
+ * contain this many characters or items.
+ */
+export const PAYLOAD_SIZES = [{}];
+
+/**
+ * Creates benchmarks for the costs of serializing arguments, calling the
+ * plugin and deserializing return values for every protocol function.
+ *
+ * Calls are only benchmarked for exported functions, because imported
+ * functions can only be called by the plugin.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @returns A `Bench` instance on which the benchmarks can be run.
+ */
+export async function createBenches(
+    plugin: ArrayBuffer,
+    importFunctions: Imports
+): Promise<Bench> {{
+    const runtime = await createRuntime(plugin, importFunctions);
+    const bench = new Bench();
+
+{}
+    return bench;
+}}
+",
+        config.msgpack_module,
+        config
+            .payload_sizes
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+        benches
+            .iter()
+            .map(|lines| format!("{lines}\n"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+
+    write_bindings_file(format!("{path}/benches.ts"), contents, headers);
+}
+
+fn format_function_benches(
+    function: &Function,
+    samples: &FunctionSamples,
+    is_export: bool,
+    types: &TypeMap,
+    config: &BenchesConfig,
+) -> Option<String> {
+    let name = &function.name;
+    let casing = &config.ts_identifier_casing;
+    let depends_on_size = samples.depends_on_size();
+    let label = |stage: &str| {
+        if depends_on_size {
+            format!("`{name}/{stage}/${{size}}`")
+        } else {
+            format!("\"{name}/{stage}\"")
+        }
+    };
+
+    let mut lines = Vec::new();
+    let mut benches = Vec::new();
+
+    let serialized_args = function
+        .args
+        .iter()
+        .filter(|arg| !arg.ty.is_primitive())
+        .map(|arg| format!("encode({});", casing.format_name(&arg.name)))
+        .collect::<Vec<_>>();
+    if !serialized_args.is_empty() {
+        benches.push(format!(
+            "bench.add({}, () => {{\n    {}\n}});",
+            label("serialize"),
+            serialized_args.join("\n    ")
+        ));
+    }
+
+    if is_export {
+        let call = format!(
+            "runtime.{}?.({})",
+            casing.format_name(name),
+            function
+                .args
+                .iter()
+                .map(|arg| casing.format_name(&arg.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        benches.push(if function.is_async {
+            format!(
+                "bench.add({}, async () => {{\n    await {call};\n}});",
+                label("call")
+            )
+        } else {
+            format!("bench.add({}, () => {{\n    {call};\n}});", label("call"))
+        });
+    }
+
+    // Primitive arguments are only needed when the function gets called.
+    for (arg, sample) in function.args.iter().zip(samples.args.iter()) {
+        if is_export || !arg.ty.is_primitive() {
+            let value = match arg.ty.as_primitive() {
+                Some(Primitive::I64 | Primitive::U64) => {
+                    format_int64_sample(config.ts_int64_encoding).to_owned()
+                }
+                _ => format_sample(sample, !arg.ty.is_primitive()),
+            };
+            lines.push(format!(
+                "const {}: {} = {value};",
+                casing.format_name(&arg.name),
+                format_plain_primitive_or_ident(&arg.ty, types, config.ts_int64_encoding),
+            ));
+        }
+    }
+
+    if let (Some(ty), Some(sample)) = (&function.return_type, &samples.return_value) {
+        if function.is_async || !(ty.is_primitive() || ty.is_packed_pair()) {
+            lines.push(format!(
+                "const result = encode({});",
+                format_sample(sample, true)
+            ));
+            benches.push(format!(
+                "bench.add({}, () => {{\n    decode(result);\n}});",
+                label("deserialize")
+            ));
+        }
+    }
+
+    if benches.is_empty() {
+        return None;
+    }
+
+    lines.extend(benches);
+    let body = lines
+        .iter()
+        .flat_map(|line| line.split('\n'))
+        .map(|line| format!("        {line}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Some(if depends_on_size {
+        format!("    for (const size of PAYLOAD_SIZES) {{\n{body}\n    }}")
+    } else {
+        format!("    {{\n{body}\n    }}")
+    })
+}
+
+/// Formats a sample as a JavaScript expression.
+///
+/// 64-bit integers are passed as `bigint` when they are plain arguments, but
+/// as regular numbers when they are encoded as part of a MessagePack payload.
+pub(crate) fn format_sample(sample: &Sample, is_encoded: bool) -> String {
+    match sample {
+        Sample::Array(primitive, len) => format!(
+            "new {}({len}).fill({})",
+            primitive
+                .js_array_name()
+                .unwrap_or_else(|| "Array".to_owned()),
+            format_primitive(*primitive, is_encoded)
+        ),
+        Sample::Container(_, item) | Sample::Option(item) => format_sample(item, true),
+        Sample::List(item) => {
+            // Object literals need to be parenthesized, or they would be
+            // parsed as the body of the arrow function:
+            let item = format_sample(item, true);
+            if item.starts_with('{') {
+                format!("Array.from({{ length: size }}, () => ({item}))")
+            } else {
+                format!("Array.from({{ length: size }}, () => {item})")
+            }
+        }
+        Sample::Map(key, value) => format!(
+            "Object.fromEntries(Array.from({{ length: size }}, (_, i) => [{}, {}]))",
+            match key {
+                MapKey::Integer(_) => "i",
+                MapKey::String => "String(i)",
+            },
+            format_sample(value, true)
+        ),
+        Sample::Pair(first, second) => format!(
+            "[{}, {}]",
+            format_primitive(*first, is_encoded),
+            format_primitive(*second, is_encoded)
+        ),
+        Sample::Primitive(primitive) => format_primitive(*primitive, is_encoded).to_owned(),
+        Sample::String => "\"x\".repeat(size)".to_owned(),
+        Sample::Struct(sample) => format_struct_sample(sample),
+        Sample::Unit => "null".to_owned(),
+        Sample::Variant(sample) => format_variant_sample(sample),
+    }
+}
+
+fn format_struct_sample(sample: &StructSample) -> String {
+    match sample.fields.as_slice() {
+        [field] if sample.is_tuple => format_sample(&field.value, true),
+        fields if sample.is_tuple => format!(
+            "[{}]",
+            fields
+                .iter()
+                .map(|field| format_sample(&field.value, true))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        _ => format_object(format_struct_properties(sample)),
+    }
+}
+
+/// Formats the properties of a struct, with the properties of flattened
+/// structs inlined.
+fn format_struct_properties(sample: &StructSample) -> Vec<String> {
+    let mut properties = Vec::new();
+    for field in &sample.fields {
+        if field.flatten {
+            properties.extend(format_flattened_properties(&field.value));
+        } else {
+            properties.push(format!(
+                "{}: {}",
+                format_property_name(&field.serialized_name),
+                format_sample(&field.value, true)
+            ));
+        }
+    }
+    properties
+}
+
+fn format_flattened_properties(sample: &Sample) -> Vec<String> {
+    match sample {
+        Sample::Struct(sample) if !sample.is_tuple => format_struct_properties(sample),
+        sample => vec![format!("...{}", format_sample(sample, true))],
+    }
+}
+
+fn format_object(properties: Vec<String>) -> String {
+    if properties.is_empty() {
+        "{}".to_owned()
+    } else {
+        format!("{{ {} }}", properties.join(", "))
+    }
+}
+
+fn format_variant_sample(sample: &VariantSample) -> String {
+    let options = &sample.options;
+    let name = format!("\"{}\"", sample.serialized_name);
+    let tag = options.tag_prop_name.as_deref().map(format_property_name);
+    let (value, properties) = match &sample.value {
+        VariantValue::Unit(_) => {
+            return match (tag, options.untagged) {
+                (_, true) => "null".to_owned(),
+                (Some(tag), false) => format!("{{ {tag}: {name} }}"),
+                (None, false) => name,
+            };
+        }
+        VariantValue::Struct(sample) => (
+            format_struct_sample(sample),
+            format_struct_properties(sample),
+        ),
+        VariantValue::Tuple(_, items) => match items.as_slice() {
+            [item] => (format_sample(item, true), format_flattened_properties(item)),
+            // Variants with multiple unnamed fields cannot be inlined into a
+            // tag, so they have no properties:
+            items => (
+                format!(
+                    "[{}]",
+                    items
+                        .iter()
+                        .map(|item| format_sample(item, true))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                Vec::new(),
+            ),
+        },
+    };
+
+    if options.untagged {
+        return value;
+    }
+
+    match (tag, &options.content_prop_name) {
+        (Some(tag), Some(content)) => format!(
+            "{{ {tag}: {name}, {}: {value} }}",
+            format_property_name(content)
+        ),
+        (Some(tag), None) => {
+            let mut entries = vec![format!("{tag}: {name}")];
+            entries.extend(properties);
+            format_object(entries)
+        }
+        (None, _) => format!("{{ {name}: {value} }}"),
+    }
+}
+
+fn format_property_name(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .map(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        == Some(true)
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_owned()
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
+/// Formats the sample of a 64-bit integer that is passed as a plain argument.
+fn format_int64_sample(int64_encoding: TsInt64Encoding) -> &'static str {
+    match int64_encoding {
+        TsInt64Encoding::BigInt => "1n",
+        TsInt64Encoding::Number => "1",
+        TsInt64Encoding::String => "\"1\"",
+    }
+}
+
+fn format_primitive(primitive: Primitive, is_encoded: bool) -> &'static str {
+    match primitive {
+        Primitive::Bool => "true",
+        Primitive::F32 | Primitive::F64 => "1.5",
+        Primitive::I64 | Primitive::U64 if !is_encoded => "1n",
+        _ => "1",
+    }
+}
@@ -0,0 +1,360 @@
+use crate::{
+    functions::{Function, FunctionList},
+    generators::{
+        formatting::rustfmt,
+        rust_plugin::{format_cfg_attr, format_ident, uses_unknown_variants},
+        rust_wasmer_runtime,
+        schema_hash::SchemaHashes,
+        validation::uses_regex_validation,
+        write_bindings_file,
+    },
+    types::{CargoDependency, Type, TypeIdent, TypeMap},
+    CliConfig, FileHeaders, RustWasmerExtendedRuntimeConfig,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+
+const CLI_HEADER: &str = "// ============================================= //
+// Command-line harness for plugins              //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //";
+
+/// Generates a crate with a binary that loads a plugin using the Rust Wasmer
+/// runtime, and that exposes every export as a subcommand which takes its
+/// arguments as JSON.
+///
+/// The crate contains its own copy of the runtime, with stubs for all the
+/// imports, so plugin authors can smoke-test their exports without a host.
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    schema_hashes: &SchemaHashes,
+    config: CliConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let src_path = format!("{path}/src");
+    let spec_path = format!("{src_path}/spec");
+    fs::create_dir_all(&spec_path).expect("Could not create output directory");
+
+    generate_cargo_file(
+        &import_functions,
+        &export_functions,
+        &types,
+        &config,
+        headers,
+        path,
+    );
+    generate_main_file(&export_functions, &types, &config, headers, &src_path);
+    generate_spec_file(&import_functions, &types, headers, &spec_path);
+
+    rust_wasmer_runtime::generate_bindings(
+        import_functions,
+        export_functions,
+        types,
+        schema_hashes,
+        RustWasmerExtendedRuntimeConfig::default(),
+        headers,
+        &spec_path,
+    );
+}
+
+fn generate_cargo_file(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &CliConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let mut support_features = BTreeSet::from(["async", "host"]);
+    if uses_regex_validation(types) {
+        support_features.insert("validation");
+    }
+    if uses_unknown_variants(types) {
+        support_features.insert("non-exhaustive");
+    }
+
+    let mut dependencies = BTreeMap::from([
+        (
+            "fp-bindgen-support",
+            CargoDependency::with_version_and_features(env!("CARGO_PKG_VERSION"), support_features),
+        ),
+        (
+            "serde",
+            CargoDependency::with_version_and_features("1.0", BTreeSet::from(["derive"])),
+        ),
+        ("serde_json", CargoDependency::with_version("1.0")),
+        (
+            "tokio",
+            CargoDependency::with_version_and_features("1", BTreeSet::from(["macros", "rt"])),
+        ),
+        (
+            "wasmer",
+            CargoDependency::with_version_and_features(
+                "2.3",
+                BTreeSet::from(["cranelift", "singlepass"]),
+            ),
+        ),
+    ]);
+
+    // Inject dependencies from custom types, followed by those passed through
+    // the config:
+    let custom_dependencies = types.values().flat_map(|ty| match ty {
+        Type::Custom(custom_type) => custom_type.rs_dependencies.iter().collect(),
+        _ => Vec::new(),
+    });
+    for (name, dependency) in custom_dependencies.chain(config.dependencies.iter()) {
+        let dependency = match dependencies.remove(name) {
+            Some(existing_dependency) => existing_dependency.merge_or_replace_with(dependency),
+            None => dependency.clone(),
+        };
+        dependencies.insert(name, dependency);
+    }
+
+    // Declare the features behind which functions are generated:
+    let features = import_functions
+        .cfg_features()
+        .union(&export_functions.cfg_features())
+        .map(|feature| format!("{feature} = []\n"))
+        .collect::<Vec<_>>();
+    let features = if features.is_empty() {
+        String::new()
+    } else {
+        format!("\n[features]\n{}", features.join(""))
+    };
+
+    write_bindings_file(
+        format!("{path}/Cargo.toml"),
+        format!(
+            "[package]
+name = \"{}\"
+version = \"0.0.0\"
+publish = false
+edition = \"2021\"
+
+[dependencies]
+{}
+{features}",
+            config.name,
+            dependencies
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        headers,
+    );
+}
+
+fn generate_main_file(
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &CliConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let usage_lines = export_functions
+        .iter()
+        .map(|function| {
+            let args = function
+                .args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        " <{}: {}>",
+                        format_arg_name(&arg.name),
+                        format_ident(&arg.ty, types)
+                    )
+                })
+                .collect::<String>();
+            format!("    {}{args}", function.name)
+        })
+        .collect::<Vec<_>>();
+
+    let commands = export_functions
+        .iter()
+        .map(format_export_command)
+        .collect::<Vec<_>>();
+
+    let contents = rustfmt(format!(
+            "{CLI_HEADER}
+mod spec;
+
+use serde::{{de::DeserializeOwned, Serialize}};
+use spec::bindings::{{PluginError, Runtime}};
+
+const USAGE: &str = r\"Usage: {name} <PLUGIN> <EXPORT> [ARGS]...
+
+Calls an export of the given plugin with the given arguments, which are passed
+as JSON, and prints its result as JSON. Imports are not implemented, so exports
+that call them fail.
+
+Exports:
+{usage}\";
+
+#[tokio::main(flavor = \"current_thread\")]
+async fn main() {{
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match run(&args).await {{
+        Ok(result) => println!(\"{{result}}\"),
+        Err(error) => {{
+            eprintln!(\"{{error}}\");
+            std::process::exit(1);
+        }}
+    }}
+}}
+
+async fn run(args: &[String]) -> Result<String, String> {{
+    let (plugin_path, export, args) = match args {{
+        [plugin_path, export, args @ ..] => (plugin_path, export.as_str(), args),
+        _ => return Err(USAGE.to_owned()),
+    }};
+
+    let wasm_module = std::fs::read(plugin_path)
+        .map_err(|error| format!(\"Could not read plugin `{{plugin_path}}`: {{error}}\"))?;
+    let runtime = Runtime::new(wasm_module)
+        .map_err(|error| format!(\"Could not instantiate plugin: {{error}}\"))?;
+
+    match export {{
+{commands}
+        _ => Err(format!(\"Unknown export `{{export}}`\\n\\n{{USAGE}}\")),
+    }}
+}}
+
+/// Checks that the given number of arguments was passed to an export.
+fn check_arg_count(export: &str, args: &[String], count: usize) -> Result<(), String> {{
+    if args.len() == count {{
+        Ok(())
+    }} else {{
+        Err(format!(
+            \"Export `{{export}}` takes {{count}} argument(s), but {{}} were given\",
+            args.len()
+        ))
+    }}
+}}
+
+/// Parses the JSON value of an argument.
+fn parse_arg<T: DeserializeOwned>(name: &str, value: &str) -> Result<T, String> {{
+    serde_json::from_str(value).map_err(|error| format!(\"Invalid value for `{{name}}`: {{error}}\"))
+}}
+
+/// Formats the result of an export as JSON.
+fn format_result<T: Serialize>(result: Result<T, PluginError>) -> Result<String, String> {{
+    let value = result.map_err(|error| format!(\"Invocation failed: {{error}}\"))?;
+    serde_json::to_string_pretty(&value)
+        .map_err(|error| format!(\"Could not format result: {{error}}\"))
+}}
+",
+            name = config.name,
+            usage = usage_lines.join("\n"),
+        commands = commands.join("\n"),
+    ));
+    write_bindings_file(format!("{path}/main.rs"), contents, headers);
+}
+
+/// Formats the match arm that calls the given export.
+fn format_export_command(function: &Function) -> String {
+    let name = &function.name;
+    let args = function
+        .args
+        .iter()
+        .enumerate()
+        .map(|(index, arg)| {
+            format!(
+                "parse_arg(\"{}\", &args[{index}])?",
+                format_arg_name(&arg.name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let modifiers = if function.is_async { ".await" } else { "" };
+    format!(
+        "        {cfg}\"{name}\" => {{
+            check_arg_count(export, args, {count})?;
+            format_result(runtime.{name}({args}){modifiers})
+        }}",
+        cfg = format_cfg_attr(function, "        "),
+        count = function.args.len(),
+    )
+}
+
+/// Formats the name of an argument as it is shown to the user, without the
+/// prefix of raw identifiers.
+fn format_arg_name(name: &str) -> &str {
+    name.strip_prefix("r#").unwrap_or(name)
+}
+
+/// Generates the module that contains the runtime, together with stubs for
+/// the imports it calls.
+fn generate_spec_file(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let stubs = import_functions
+        .iter()
+        .filter(|function| !function.attrs.event)
+        .map(|function| format_import_stub(function, types))
+        .collect::<Vec<_>>();
+
+    let contents = rustfmt(format!(
+        "{CLI_HEADER}
+pub mod bindings;
+pub mod types;
+
+#[allow(unused_imports)]
+use types::*;
+
+{}
+
+fn unimplemented_import(name: &str) -> ! {{
+    panic!(\"Import `{{name}}` is not implemented by the CLI\")
+}}
+",
+        stubs.join("\n\n")
+    ));
+    write_bindings_file(format!("{path}/mod.rs"), contents, headers);
+}
+
+/// Formats a stub for an import, which fails as soon as it is called.
+///
+/// Async imports return a ready future, so that they fail while the plugin
+/// calls them, rather than in a task that the plugin would wait on forever.
+fn format_import_stub(function: &Function, types: &TypeMap) -> String {
+    // The runtime infers the type parameters of generic imports from the
+    // stubs, so any value is accepted:
+    let value = TypeIdent::from("serde_json::Value");
+    let format_ident =
+        |ty: &TypeIdent| format_ident(&ty.replace_any_of(&function.generic_params, &value), types);
+
+    let name = &function.name;
+    let args = function
+        .args
+        .iter()
+        .map(|arg| format!("_: {}", format_ident(&arg.ty)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = match &function.return_type {
+        Some(ty) => format_ident(ty),
+        None => "()".to_owned(),
+    };
+    let return_type = if function.is_async {
+        format!(" -> std::future::Ready<{return_type}>")
+    } else if function.return_type.is_some() {
+        format!(" -> {return_type}")
+    } else {
+        String::new()
+    };
+    format!(
+        "{cfg}fn {name}({args}){return_type} {{
+    unimplemented_import(\"{name}\")
+}}",
+        cfg = format_cfg_attr(function, ""),
+    )
+}
@@ -0,0 +1,277 @@
+//! Handling of the structs that are annotated with `#[fp(columnar)]`.
+//!
+//! Lists of such structs are encoded as columns: a struct with a contiguous
+//! array for every field, rather than a list with a separate map for every
+//! item. Before any bindings are generated, every use of `Vec<T>` is replaced
+//! with a generated `TColumns` struct, whose fields are (de)serialized by
+//! `fp_bindgen_support::common::columnar`. This way, the generators only need
+//! to know about the TypeScript type of the columns, which is a typed array.
+
+use crate::{
+    functions::{Function, FunctionList},
+    primitives::Primitive,
+    types::{Field, FieldAttrs, Struct, StructOptions, Type, TypeIdent, TypeMap},
+};
+
+/// The module with which the columns are (de)serialized.
+pub(crate) const COLUMNAR_SERIALIZER: &str = "fp_bindgen_support::common::columnar";
+
+/// Checks that columnar structs only have named fields of numeric types.
+pub(super) fn check_columnar_structs(types: &TypeMap) {
+    for ty in types.values() {
+        let ty = match ty {
+            Type::Struct(ty) if ty.options.columnar => ty,
+            _ => continue,
+        };
+
+        if !ty.ident.generic_args.is_empty() {
+            panic!("Columnar struct `{}` cannot be generic", ty.ident);
+        }
+        if ty.fields.is_empty() || ty.fields.iter().any(|field| field.name.is_none()) {
+            panic!("Columnar struct `{}` must have named fields", ty.ident);
+        }
+        for field in &ty.fields {
+            let name = field.name.as_deref().unwrap_or_default();
+            if field.attrs.flatten || field.attrs.has_custom_serializer() {
+                panic!(
+                    "Field `{}` of columnar struct `{}` cannot be flattened or use a custom \
+                    (de)serializer",
+                    name, ty.ident
+                );
+            }
+            if get_column_primitive(&field.ty, types).is_none() {
+                panic!(
+                    "Field `{}` of columnar struct `{}` has type `{}`, but columns can only \
+                    contain numbers",
+                    name, ty.ident, field.ty
+                );
+            }
+        }
+    }
+}
+
+/// Returns whether any of the given types has columns, in which case the
+/// TypeScript runtime needs to register the MessagePack extensions for them.
+pub(crate) fn has_columns(types: &TypeMap) -> bool {
+    types.values().any(|ty| match ty {
+        Type::Struct(ty) => ty
+            .fields
+            .iter()
+            .any(|field| field.attrs.serialize_with.as_deref() == Some(COLUMNAR_SERIALIZER)),
+        _ => false,
+    })
+}
+
+/// Replaces every use of `Vec<T>` for a columnar struct `T` with a generated
+/// `TColumns` struct.
+pub(super) fn with_columns(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    mut types: TypeMap,
+) -> (FunctionList, FunctionList, TypeMap) {
+    // Type identifiers are ordered by name only, so the replacements are kept
+    // in a list rather than a map:
+    let replacements = types
+        .values()
+        .filter_map(|ty| match ty {
+            Type::Struct(ty) if ty.options.columnar => Some(ty),
+            _ => None,
+        })
+        .map(|ty| {
+            let list_ident = TypeIdent {
+                name: "Vec".to_owned(),
+                generic_args: vec![(ty.ident.clone(), Vec::new())],
+                array: None,
+            };
+            (list_ident, format_columns_struct(ty, &types))
+        })
+        .collect::<Vec<_>>();
+    if replacements.is_empty() {
+        return (import_functions, export_functions, types);
+    }
+
+    let replace = |ident: &mut TypeIdent| *ident = replace_ident(ident, &replacements);
+    let replace_function = |mut function: Function| {
+        for arg in function.args.iter_mut() {
+            replace(&mut arg.ty);
+        }
+        if let Some(ty) = function.return_type.as_mut() {
+            replace(ty);
+        }
+        function
+    };
+
+    for ty in types.values_mut() {
+        for used_ident in super::aliases::used_idents_mut(ty) {
+            replace(used_ident);
+        }
+    }
+    let import_functions = import_functions.into_iter().map(replace_function).collect();
+    let export_functions = export_functions.into_iter().map(replace_function).collect();
+
+    for (list_ident, columns) in replacements {
+        if types.contains_key(&columns.ident) {
+            panic!(
+                "Type `{}` conflicts with the columns that are generated for `{}`",
+                columns.ident, list_ident
+            );
+        }
+        types.insert(columns.ident.clone(), Type::Struct(columns));
+    }
+
+    (import_functions, export_functions, types)
+}
+
+/// Formats the `TColumns` struct for the given columnar struct, with a `Vec`
+/// of the values of every field.
+fn format_columns_struct(ty: &Struct, types: &TypeMap) -> Struct {
+    let fields = ty
+        .fields
+        .iter()
+        .map(|field| {
+            let primitive = get_column_primitive(&field.ty, types)
+                .expect("Columns were expected to contain numbers");
+            Field {
+                name: field.name.clone(),
+                ty: TypeIdent {
+                    name: "Vec".to_owned(),
+                    generic_args: vec![(TypeIdent::from(primitive.name().as_str()), Vec::new())],
+                    array: None,
+                },
+                doc_lines: field.doc_lines.clone(),
+                attrs: FieldAttrs {
+                    rename: field.attrs.rename.clone(),
+                    deserialize_with: Some(COLUMNAR_SERIALIZER.to_owned()),
+                    serialize_with: Some(COLUMNAR_SERIALIZER.to_owned()),
+                    ts_type: Some(format_typed_array(primitive).to_owned()),
+                    ..Default::default()
+                },
+            }
+        })
+        .collect();
+
+    Struct {
+        ident: TypeIdent::from(format!("{}Columns", ty.ident.name).as_str()),
+        fields,
+        doc_lines: vec![format!(
+            " The columns of a list of `{}`, with the values of every field stored contiguously.",
+            ty.ident.name
+        )],
+        options: StructOptions {
+            field_casing: ty.options.field_casing,
+            deny_unknown_fields: ty.options.deny_unknown_fields,
+            ..Default::default()
+        },
+        location: ty.location.clone(),
+    }
+}
+
+/// Returns the numeric primitive of a column, if the given type is one.
+fn get_column_primitive(ident: &TypeIdent, types: &TypeMap) -> Option<Primitive> {
+    match types.get(ident) {
+        Some(Type::Primitive(Primitive::Bool)) => None,
+        Some(Type::Primitive(primitive)) => Some(*primitive),
+        _ => None,
+    }
+}
+
+/// Formats the TypeScript typed array in which a column is decoded. Columns
+/// of 64-bit integers are decoded as `BigInt64Array` and `BigUint64Array`.
+fn format_typed_array(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => unreachable!("Columns cannot contain booleans"),
+        Primitive::F32 => "Float32Array",
+        Primitive::F64 => "Float64Array",
+        Primitive::I8 => "Int8Array",
+        Primitive::I16 => "Int16Array",
+        Primitive::I32 => "Int32Array",
+        Primitive::I64 => "BigInt64Array",
+        Primitive::U8 => "Uint8Array",
+        Primitive::U16 => "Uint16Array",
+        Primitive::U32 => "Uint32Array",
+        Primitive::U64 => "BigUint64Array",
+    }
+}
+
+fn replace_ident(ident: &TypeIdent, replacements: &[(TypeIdent, Struct)]) -> TypeIdent {
+    if let Some((_, columns)) = replacements
+        .iter()
+        .find(|(list_ident, _)| list_ident == ident)
+    {
+        return columns.ident.clone();
+    }
+
+    let mut ident = ident.clone();
+    for (arg, _) in ident.generic_args.iter_mut() {
+        *arg = replace_ident(arg, replacements);
+    }
+    ident
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::casing::Casing;
+
+    #[test]
+    fn replaces_lists_of_columnar_structs() {
+        let sample = Type::from_item(
+            "#[fp(columnar, rename_all = \"camelCase\")]
+            struct Sample {
+                pub timestamp_ms: u64,
+                pub value: f64,
+            }",
+        );
+        let types = TypeMap::from([
+            (TypeIdent::from("Sample"), sample),
+            (TypeIdent::from("f64"), Type::Primitive(Primitive::F64)),
+            (TypeIdent::from("u64"), Type::Primitive(Primitive::U64)),
+            (
+                TypeIdent::from("Vec<T>"),
+                Type::List("Vec".to_owned(), TypeIdent::from("T")),
+            ),
+            (
+                TypeIdent::from("Option<T>"),
+                Type::Container("Option".to_owned(), TypeIdent::from("T")),
+            ),
+        ]);
+        check_columnar_structs(&types);
+
+        let mut export_functions = FunctionList::new();
+        export_functions
+            .add_function("fn export_samples(since: Option<Vec<Sample>>) -> Vec<Sample>;");
+        let (_, export_functions, types) =
+            with_columns(FunctionList::new(), export_functions, types);
+
+        let function = export_functions.iter().next().unwrap();
+        assert_eq!(
+            function.args[0].ty,
+            TypeIdent::from("Option<SampleColumns>")
+        );
+        assert_eq!(function.return_type, Some(TypeIdent::from("SampleColumns")));
+
+        let columns = match types.get(&TypeIdent::from("SampleColumns")) {
+            Some(Type::Struct(columns)) => columns,
+            other => panic!("Unexpected columns: {:?}", other),
+        };
+        assert_eq!(columns.options.field_casing, Casing::CamelCase);
+        let fields = columns
+            .fields
+            .iter()
+            .map(|field| {
+                (
+                    field.name.as_deref().unwrap(),
+                    field.ty.to_string(),
+                    field.attrs.ts_type.as_deref().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                ("timestamp_ms", "Vec<u64>".to_owned(), "BigUint64Array"),
+                ("value", "Vec<f64>".to_owned(), "Float64Array"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1185 @@
+mod types;
+
+use crate::{
+    functions::{Function, FunctionList},
+    generators::{interning::has_interned_fields, write_bindings_file},
+    primitives::Primitive,
+    types::{TypeIdent, TypeMap},
+    CSharpRuntimeConfig, FileHeaders,
+};
+use inflector::Inflector;
+use std::collections::BTreeSet;
+use types::{format_ident, format_primitive, generate_type_bindings};
+
+const HEADER_DIVIDER: &str = "// ============================================= //";
+
+/// The version of the MessagePack-CSharp package that the generated project
+/// depends on.
+const MESSAGE_PACK_VERSION: &str = "2.5.140";
+
+/// The version of the Wasmtime package that the generated project depends on.
+const WASMTIME_VERSION: &str = "22.0.0";
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: CSharpRuntimeConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    if has_interned_fields(&types) {
+        println!(
+            "WARNING: The C# runtime does not provide the `__fp_intern_string()` import, so \
+            plugins that send interned strings cannot be loaded by it."
+        );
+    }
+
+    generate_project_file(&config, headers, path);
+    generate_type_bindings(&types, &config, headers, path);
+    // MessagePack values of arbitrary shape are represented as `object`:
+    let import_functions = import_functions.with_generic_params_replaced("object");
+    generate_runtime_bindings(
+        &import_functions,
+        &export_functions,
+        &types,
+        &config,
+        headers,
+        path,
+    );
+}
+
+fn generate_project_file(config: &CSharpRuntimeConfig, headers: &FileHeaders, path: &str) {
+    write_bindings_file(
+        format!("{path}/{}.csproj", config.namespace),
+        format!(
+            "<Project Sdk=\"Microsoft.NET.Sdk\">
+
+  <PropertyGroup>
+    <TargetFramework>{}</TargetFramework>
+    <RootNamespace>{}</RootNamespace>
+    <ImplicitUsings>disable</ImplicitUsings>
+    <Nullable>enable</Nullable>
+  </PropertyGroup>
+
+  <ItemGroup>
+    <PackageReference Include=\"MessagePack\" Version=\"{MESSAGE_PACK_VERSION}\" />
+    <PackageReference Include=\"Wasmtime\" Version=\"{WASMTIME_VERSION}\" />
+  </ItemGroup>
+
+</Project>
+",
+            config.target_framework, config.namespace
+        ),
+        headers,
+    );
+}
+
+fn generate_runtime_bindings(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &CSharpRuntimeConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    // Fire-and-forget imports don't resolve an async value:
+    let has_async_import_functions = import_functions
+        .iter()
+        .any(|function| function.is_async && !function.is_fire_and_forget());
+    let has_fire_and_forget_import_functions = import_functions
+        .iter()
+        .any(|function| function.is_fire_and_forget());
+    let has_async_export_functions = export_functions.iter().any(|function| function.is_async);
+    let capabilities = import_functions.capabilities();
+
+    let import_decls = import_functions
+        .iter()
+        // Events are raised on the runtime instead of being implemented:
+        .filter(|function| !function.attrs.event)
+        .map(|function| format_import_declaration(function, types))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut fields = vec![
+        "private readonly object _lock = new();".to_owned(),
+        "private readonly IImports _imports;".to_owned(),
+    ];
+    if !capabilities.is_empty() {
+        fields.push("private readonly HashSet<Capability> _capabilities;".to_owned());
+    }
+    fields.extend([
+        "private readonly Engine _engine;".to_owned(),
+        "private readonly Store _store;".to_owned(),
+        "private readonly Instance _instance;".to_owned(),
+        "private readonly Memory _memory;".to_owned(),
+        "private readonly Func<int, long> _malloc;".to_owned(),
+        "private readonly Action<long> _free;".to_owned(),
+    ]);
+    if has_async_import_functions {
+        fields.push("private readonly Action<long, long> _resolveFuture;".to_owned());
+    }
+    if has_async_export_functions {
+        fields.push(
+            "private readonly Dictionary<long, TaskCompletionSource<long>> _promises = new();"
+                .to_owned(),
+        );
+    }
+    for function in import_functions
+        .iter()
+        .filter(|function| function.attrs.dynamic)
+    {
+        fields.push(format!(
+            "private readonly Dictionary<string, {}> {} = new();",
+            format_dynamic_handler_type(function, types),
+            get_dynamic_handlers_name(function)
+        ));
+    }
+
+    let (capability_decls, capability_param, capability_param_doc, capability_init) =
+        if capabilities.is_empty() {
+            (String::new(), "", "", "")
+        } else {
+            (
+                format_capability_declarations(&capabilities),
+                ",\n        IEnumerable<Capability>? capabilities = null",
+                "\n    /// <param name=\"capabilities\">The capabilities that are granted to the plugin.</param>",
+                "        _capabilities = new HashSet<Capability>(capabilities ?? Array.Empty<Capability>());\n",
+            )
+        };
+
+    let export_wrappers = export_functions
+        .iter()
+        .map(|function| format_export_wrapper(function, types))
+        .chain(
+            import_functions
+                .iter()
+                .filter(|function| function.attrs.dynamic)
+                .map(|function| format_dynamic_import_registration(function, types)),
+        )
+        .chain(
+            import_functions
+                .iter()
+                .filter(|function| function.attrs.event)
+                .map(|function| format_event_declaration(function, types)),
+        )
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut import_wrappers = import_functions
+        .iter()
+        .map(|function| format_import_wrapper(function, types))
+        .collect::<Vec<_>>();
+    if let Some(dispatcher) = format_batch_dispatcher(import_functions, types) {
+        import_wrappers.push(dispatcher);
+    }
+    import_wrappers.push(
+        "linker.DefineFunction(\"fp\", \"__fp_error\", (long reportPtr) =>
+{
+    var report = ParseObject<GuestErrorReport>(reportPtr);
+    throw new GuestErrorException(report.Kind, report.Message);
+});"
+        .to_owned(),
+    );
+    if has_async_export_functions {
+        import_wrappers.push(
+            "linker.DefineFunction(\"fp\", \"__fp_host_resolve_async_value\", (long asyncValuePtr, long resultPtr) =>
+{
+    ResolvePromise(asyncValuePtr, resultPtr);
+});"
+            .to_owned(),
+        );
+    }
+
+    let mut helpers = vec![
+        "private static FpRuntimeException MissingExport(string name) =>
+    new($\"Plugin did not export expected symbol: \\\"{name}\\\"\");"
+            .to_owned(),
+    ];
+    if !capabilities.is_empty() {
+        helpers.push(
+            "private void CheckCapability(string functionName, Capability capability)
+{
+    if (!_capabilities.Contains(capability))
+    {
+        throw new PermissionDeniedException(functionName, capability);
+    }
+}"
+            .to_owned(),
+        );
+    }
+    if has_async_import_functions {
+        helpers.push(
+            "private long CreateAsyncValue()
+{
+    const int len = 12; // std::mem::size_of::<AsyncValue>()
+    var fatPtr = Malloc(len);
+    var (ptr, _) = FromFatPtr(fatPtr);
+    _memory.GetSpan(ptr, len).Clear();
+    return fatPtr;
+}"
+            .to_owned(),
+        );
+    }
+    helpers.push(
+        "private long Malloc(int len)
+{
+    var fatPtr = _malloc(len);
+    if (fatPtr == 0 && len != 0)
+    {
+        throw new AllocationFailedException(len);
+    }
+    return fatPtr;
+}
+
+private long ExportToMemory(byte[] bytes)
+{
+    var fatPtr = Malloc(bytes.Length);
+    var (ptr, len) = FromFatPtr(fatPtr);
+    bytes.CopyTo(_memory.GetSpan(ptr, len));
+    return fatPtr;
+}
+
+private byte[] ImportFromMemory(long fatPtr)
+{
+    var (ptr, len) = FromFatPtr(fatPtr);
+    var bytes = _memory.GetSpan(ptr, len).ToArray();
+    _free(fatPtr);
+    return bytes;
+}
+
+private T ParseObject<T>(long fatPtr) =>
+    MessagePackSerializer.Deserialize<T>(ImportFromMemory(fatPtr), SerializerOptions);
+
+private long SerializeObject<T>(T value) =>
+    ExportToMemory(MessagePackSerializer.Serialize(value, SerializerOptions));"
+            .to_owned(),
+    );
+    if has_async_export_functions {
+        helpers.push(
+            "// Plugins may resolve their async values before the runtime asks for
+// them, so whichever comes first creates the promise and the other one
+// removes it.
+private TaskCompletionSource<long> GetPromise(long asyncValuePtr)
+{
+    if (_promises.Remove(asyncValuePtr, out var promise))
+    {
+        return promise;
+    }
+
+    promise = new TaskCompletionSource<long>(TaskCreationOptions.RunContinuationsAsynchronously);
+    _promises.Add(asyncValuePtr, promise);
+    return promise;
+}
+
+private Task<long> TaskFromPtr(long asyncValuePtr) => GetPromise(asyncValuePtr).Task;
+
+private void ResolvePromise(long asyncValuePtr, long resultPtr) =>
+    GetPromise(asyncValuePtr).SetResult(resultPtr);"
+                .to_owned(),
+        );
+    }
+    if has_async_import_functions {
+        helpers.push(
+            "private void ResolveFuture(string functionName, long asyncValuePtr, Task task, Func<long> serializeResult)
+{
+    task.ContinueWith(
+        completed =>
+        {
+            if (!completed.IsCompletedSuccessfully)
+            {
+                Console.Error.WriteLine(
+                    $\"Unrecoverable exception trying to call async host function \\\"{functionName}\\\": {completed.Exception}\");
+                return;
+            }
+
+            lock (_lock)
+            {
+                _resolveFuture(asyncValuePtr, serializeResult());
+            }
+        },
+        TaskScheduler.Default);
+}"
+            .to_owned(),
+        );
+    }
+    if has_fire_and_forget_import_functions {
+        helpers.push(
+            "private static void ForgetTask(string functionName, Task task)
+{
+    task.ContinueWith(
+        completed => Console.Error.WriteLine(
+            $\"Unrecoverable exception trying to call async host function \\\"{functionName}\\\": {completed.Exception}\"),
+        TaskContinuationOptions.NotOnRanToCompletion);
+}"
+            .to_owned(),
+        );
+    }
+    helpers.push(
+        "private static (long Ptr, int Len) FromFatPtr(long fatPtr) =>
+    ((long)((ulong)fatPtr >> 32), (int)(uint)fatPtr);"
+            .to_owned(),
+    );
+
+    write_bindings_file(
+        format!("{path}/Runtime.cs"),
+        format!(
+            "{HEADER_DIVIDER}
+// WebAssembly runtime for C#                    //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+{HEADER_DIVIDER}
+
+using System;
+using System.Collections.Generic;
+using System.Threading.Tasks;
+using MessagePack;
+using Wasmtime;
+
+namespace {namespace};
+
+/// <summary>
+/// Statistics about the memory that is allocated by a plugin.
+/// </summary>
+[MessagePackObject]
+public sealed record MemoryStats
+{{
+    [Key(\"allocatedBytes\")]
+    public required ulong AllocatedBytes {{ get; init; }}
+
+    [Key(\"peakAllocatedBytes\")]
+    public required ulong PeakAllocatedBytes {{ get; init; }}
+
+    [Key(\"allocationCount\")]
+    public required ulong AllocationCount {{ get; init; }}
+}}
+
+/// <summary>
+/// The host functions that may be imported by the plugin.
+/// </summary>
+public interface IImports
+{{
+{import_decls}}}
+
+/// <summary>
+/// Represents an unrecoverable error in the FP runtime.
+///
+/// After this, your only recourse is to create a new runtime, probably with a
+/// different WASM plugin.
+/// </summary>
+public class FpRuntimeException : Exception
+{{
+    public FpRuntimeException(string message) : base(message)
+    {{
+    }}
+}}
+
+/// <summary>
+/// Thrown when the plugin reports a failure before it aborts, which plugins do
+/// if they are built with the `panic-abort` feature of `fp-bindgen-support`.
+/// </summary>
+public sealed class GuestErrorException : FpRuntimeException
+{{
+    public GuestErrorException(string kind, string message)
+        : base($\"Plugin failed with {{kind}} error: {{message}}\")
+    {{
+        Kind = kind;
+        GuestMessage = message;
+    }}
+
+    /// <summary>
+    /// The kind of failure, such as <c>\"deserialization\"</c> or <c>\"panic\"</c>.
+    /// </summary>
+    public string Kind {{ get; }}
+
+    /// <summary>
+    /// The message that was reported by the plugin.
+    /// </summary>
+    public string GuestMessage {{ get; }}
+}}
+
+/// <summary>
+/// Thrown when the plugin fails to allocate the memory for a value that is
+/// passed to it, which it reports by returning a null pointer from its
+/// allocator.
+/// </summary>
+public sealed class AllocationFailedException : FpRuntimeException
+{{
+    public AllocationFailedException(int len)
+        : base($\"Plugin could not allocate {{len}} bytes\")
+    {{
+        Len = len;
+    }}
+
+    public int Len {{ get; }}
+}}
+
+[MessagePackObject]
+internal sealed record GuestErrorReport
+{{
+    [Key(\"kind\")]
+    public required string Kind {{ get; init; }}
+
+    [Key(\"message\")]
+    public required string Message {{ get; init; }}
+}}
+{capability_decls}
+/// <summary>
+/// Runtime for executing a plugin.
+///
+/// Calls into the plugin are serialized, so a runtime may be shared between
+/// threads. Imports are invoked on the thread that calls into the plugin.
+/// </summary>
+public sealed class Runtime : IDisposable
+{{
+    private static readonly MessagePackSerializerOptions SerializerOptions =
+        MessagePackSerializerOptions.Standard;
+
+{fields}
+    /// <summary>
+    /// Creates a runtime for executing the given plugin.
+    /// </summary>
+    /// <param name=\"plugin\">The raw WASM plugin.</param>
+    /// <param name=\"imports\">The host functions that may be imported by the plugin.</param>{capability_param_doc}
+    public Runtime(
+        byte[] plugin,
+        IImports imports{capability_param})
+    {{
+        _imports = imports;
+{capability_init}        _engine = new Engine();
+        _store = new Store(_engine);
+
+        using var module = Module.FromBytes(_engine, \"plugin\", plugin);
+        using var linker = new Linker(_engine);
+        DefineImports(linker);
+
+        _instance = linker.Instantiate(_store, module);
+        _memory = _instance.GetMemory(\"memory\") ?? throw MissingExport(\"memory\");
+        _malloc = _instance.GetFunction<int, long>(\"__fp_malloc\") ?? throw MissingExport(\"__fp_malloc\");
+        _free = _instance.GetAction<long>(\"__fp_free\") ?? throw MissingExport(\"__fp_free\");
+{resolve_future_init}    }}
+
+{export_wrappers}
+    /// <summary>
+    /// Returns statistics about the memory that is allocated by the plugin.
+    /// </summary>
+    public MemoryStats GetMemoryStats()
+    {{
+        lock (_lock)
+        {{
+            var exportFn = _instance.GetFunction<long>(\"__fp_memory_stats\") ??
+                throw MissingExport(\"__fp_memory_stats\");
+            return ParseObject<MemoryStats>(exportFn());
+        }}
+    }}
+
+    /// <summary>
+    /// Calls the <c>shutdown</c> export of the plugin, if it has one, and
+    /// releases the resources of the runtime.
+    /// </summary>
+    public void Dispose()
+    {{
+        lock (_lock)
+        {{
+            try
+            {{
+                _instance.GetAction(\"__fp_gen_shutdown\")?.Invoke();
+            }}
+            finally
+            {{
+                _store.Dispose();
+                _engine.Dispose();
+            }}
+        }}
+    }}
+
+    private void DefineImports(Linker linker)
+    {{
+{import_wrappers}    }}
+
+{helpers}
+}}
+",
+            namespace = config.namespace,
+            fields = join_lines(&fields, |line| format!("    {line}")),
+            resolve_future_init = if has_async_import_functions {
+                "        _resolveFuture = _instance.GetAction<long, long>(\"__fp_guest_resolve_async_value\") ??
+            throw MissingExport(\"__fp_guest_resolve_async_value\");\n"
+            } else {
+                ""
+            },
+            import_wrappers = import_wrappers
+                .iter()
+                .map(|wrapper| join_lines(&split_lines(wrapper), |line| format!("        {line}")))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            helpers = helpers
+                .iter()
+                .map(|helper| join_lines(&split_lines(helper), |line| format!("    {line}")))
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim_end(),
+        ), headers,
+    );
+}
+
+/// Formats the `Capability` enum and the exception that is thrown when the
+/// plugin calls an import that requires a capability it wasn't granted.
+fn format_capability_declarations(capabilities: &BTreeSet<&str>) -> String {
+    format!(
+        "
+/// <summary>
+/// A capability that can be granted to the plugin. Imports that require a
+/// capability can only be called by the plugin if it was granted.
+/// </summary>
+public enum Capability
+{{
+{}}}
+
+/// <summary>
+/// Thrown when the plugin calls an import that requires a capability that was
+/// not granted to it. The plugin call that triggered the import fails as a
+/// result.
+/// </summary>
+public sealed class PermissionDeniedException : FpRuntimeException
+{{
+    public PermissionDeniedException(string functionName, Capability capability)
+        : base($\"Import \\\"{{functionName}}\\\" requires the \\\"{{capability}}\\\" capability, which was not granted\")
+    {{
+        FunctionName = functionName;
+        Capability = capability;
+    }}
+
+    public string FunctionName {{ get; }}
+
+    public Capability Capability {{ get; }}
+}}
+",
+        capabilities
+            .iter()
+            .map(|capability| format!("    {},\n", capability.to_pascal_case()))
+            .collect::<Vec<_>>()
+            .join("")
+    )
+}
+
+fn format_import_declaration(function: &Function, types: &TypeMap) -> String {
+    let mut lines = format_docs(&function.doc_lines);
+    lines.push(format!(
+        "{} {}({});",
+        format_return_type(function, types),
+        function.name.to_pascal_case(),
+        format_params(function, types)
+    ));
+    join_lines(&lines, |line| format!("    {line}"))
+}
+
+fn format_export_wrapper(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let export_name = function.symbol_name();
+
+    let mut abi_types = function
+        .args
+        .iter()
+        .map(|arg| format_abi_type(&arg.ty))
+        .collect::<Vec<_>>();
+    let abi_args = function
+        .args
+        .iter()
+        .map(|arg| match arg.ty.as_primitive() {
+            Some(primitive) => to_abi(primitive, &format_variable_name(&arg.name)),
+            None => get_pointer_name(&arg.name),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let has_return_value = function.is_async || function.return_type.is_some();
+    if has_return_value {
+        abi_types.push(match (&function.return_type, function.is_async) {
+            (Some(ty), false) => format_abi_type(ty),
+            _ => "long",
+        });
+    }
+    let getter = match (has_return_value, abi_types.is_empty()) {
+        (true, _) => format!("GetFunction<{}>", abi_types.join(", ")),
+        (false, true) => "GetAction".to_owned(),
+        (false, false) => format!("GetAction<{}>", abi_types.join(", ")),
+    };
+
+    let mut lines = vec![
+        format!("var exportFn = _instance.{getter}(\"{export_name}\") ??"),
+        format!("    throw MissingExport(\"{export_name}\");"),
+    ];
+    lines.extend(function.args.iter().filter_map(|arg| {
+        if arg.ty.is_primitive() {
+            None
+        } else {
+            Some(format!(
+                "var {} = SerializeObject({});",
+                get_pointer_name(&arg.name),
+                format_variable_name(&arg.name)
+            ))
+        }
+    }));
+    let call = format!("exportFn({abi_args})");
+
+    let body = if function.is_async {
+        lines.push(format!("task = TaskFromPtr({call});"));
+        let result = match &function.return_type {
+            Some(ty) => format!(
+                "
+
+        var resultPtr = await task;
+        lock (_lock)
+        {{
+            return ParseObject<{}>(resultPtr);
+        }}",
+                format_ident(ty, types)
+            ),
+            None => "
+
+        await task;"
+                .to_owned(),
+        };
+        format!(
+            "        Task<long> task;
+        lock (_lock)
+        {{
+{}        }}{result}",
+            join_lines(&lines, |line| format!("            {line}"))
+        )
+    } else {
+        match &function.return_type {
+            Some(ty) => {
+                if let Some((first, second)) = ty.as_packed_pair() {
+                    lines.push(format!("var packed = (ulong){call};"));
+                    lines.push(format!(
+                        "return ({}, {});",
+                        from_bits(first, "(uint)(packed >> 32)"),
+                        from_bits(second, "(uint)packed")
+                    ));
+                } else if let Some(primitive) = ty.as_primitive() {
+                    lines.push(format!("return {};", from_abi(primitive, &call)));
+                } else {
+                    lines.push(format!(
+                        "return ParseObject<{}>({call});",
+                        format_ident(ty, types)
+                    ));
+                }
+            }
+            None => lines.push(format!("{call};")),
+        }
+        format!(
+            "        lock (_lock)
+        {{
+{}        }}",
+            join_lines(&lines, |line| format!("            {line}"))
+        )
+    };
+
+    let mut decl = format_docs(&function.doc_lines);
+    decl.push(format!(
+        "public {}{} {}({})",
+        if function.is_async { "async " } else { "" },
+        format_return_type(function, types),
+        name.to_pascal_case(),
+        format_params(function, types)
+    ));
+    format!(
+        "{}    {{\n{body}\n    }}\n",
+        join_lines(&decl, |line| format!("    {line}"))
+    )
+}
+
+/// Formats the methods for registering and unregistering handlers for a
+/// dynamic import.
+fn format_dynamic_import_registration(function: &Function, types: &TypeMap) -> String {
+    let name = function.name.to_pascal_case();
+    let handlers = get_dynamic_handlers_name(function);
+    format!(
+        "    /// <summary>
+    /// Registers a handler for <see cref=\"IImports.{name}\"/>, which is
+    /// called instead of the import when the plugin passes the given name.
+    /// </summary>
+    public void Register{name}(string name, {} handler)
+    {{
+        lock (_lock)
+        {{
+            {handlers}[name] = handler;
+        }}
+    }}
+
+    /// <summary>
+    /// Unregisters the handler for <see cref=\"IImports.{name}\"/> with the
+    /// given name. Returns whether a handler was registered.
+    /// </summary>
+    public bool Unregister{name}(string name)
+    {{
+        lock (_lock)
+        {{
+            return {handlers}.Remove(name);
+        }}
+    }}
+",
+        format_dynamic_handler_type(function, types)
+    )
+}
+
+/// Formats the C# event that is raised when the plugin emits an event.
+fn format_event_declaration(function: &Function, types: &TypeMap) -> String {
+    let payload = function
+        .args
+        .first()
+        .expect("Events are validated to take one argument");
+    let mut docs = format_docs(&function.doc_lines);
+    if docs.is_empty() {
+        docs = vec![
+            "/// <summary>".to_owned(),
+            format!(
+                "/// Raised when the plugin emits the `{}` event.",
+                function.name
+            ),
+            "/// </summary>".to_owned(),
+        ];
+    }
+    format!(
+        "{}    public event Action<{}>? {};\n",
+        join_lines(&docs, |line| format!("    {line}")),
+        format_ident(&payload.ty, types),
+        function.name.to_pascal_case()
+    )
+}
+
+fn format_dynamic_handler_type(function: &Function, types: &TypeMap) -> String {
+    let payload = function
+        .args
+        .get(1)
+        .expect("Dynamic imports are validated to take two arguments");
+    let return_type = function
+        .return_type
+        .as_ref()
+        .expect("Dynamic imports are validated to have a return type");
+    format!(
+        "Func<{}, {}>",
+        format_ident(&payload.ty, types),
+        format_ident(return_type, types)
+    )
+}
+
+fn get_dynamic_handlers_name(function: &Function) -> String {
+    format!("_{}Handlers", function.name.to_camel_case())
+}
+
+fn format_import_wrapper(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let params = function
+        .args
+        .iter()
+        .map(|arg| match arg.ty.as_primitive() {
+            Some(primitive) => format!(
+                "{} {}",
+                format_abi_primitive(primitive),
+                format_variable_name(&arg.name)
+            ),
+            None => format!("long {}", get_pointer_name(&arg.name)),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut lines = Vec::new();
+    if let Some(capability) = &function.attrs.capability {
+        lines.push(format!(
+            "CheckCapability(\"{name}\", Capability.{});",
+            capability.to_pascal_case()
+        ));
+    }
+    lines.extend(function.args.iter().filter_map(|arg| {
+        if arg.ty.is_primitive() {
+            None
+        } else {
+            Some(format!(
+                "var {} = ParseObject<{}>({});",
+                format_variable_name(&arg.name),
+                format_ident(&arg.ty, types),
+                get_pointer_name(&arg.name)
+            ))
+        }
+    }));
+
+    let args = function
+        .args
+        .iter()
+        .map(|arg| match arg.ty.as_primitive() {
+            Some(primitive) => from_abi(primitive, &format_variable_name(&arg.name)),
+            None => format_variable_name(&arg.name),
+        })
+        .collect::<Vec<_>>();
+    let call = format!("_imports.{}({})", name.to_pascal_case(), args.join(", "));
+
+    if function.attrs.event {
+        lines.push(format!(
+            "{}?.Invoke({});",
+            name.to_pascal_case(),
+            args.join(", ")
+        ));
+    } else if function.attrs.dynamic {
+        let (handler_name, payload) = match args.as_slice() {
+            [handler_name, payload] => (handler_name, payload),
+            _ => unreachable!("Dynamic imports are validated to take two arguments"),
+        };
+        lines.push(format!(
+            "var result = {}.TryGetValue({handler_name}, out var handler)",
+            get_dynamic_handlers_name(function)
+        ));
+        lines.push(format!("    ? handler({payload})"));
+        lines.push(format!("    : {call};"));
+        lines.push("return SerializeObject(result);".to_owned());
+    } else if function.is_fire_and_forget() {
+        lines.push(format!("ForgetTask(\"{name}\", {call});"));
+    } else if function.is_async {
+        lines.push("var asyncValuePtr = CreateAsyncValue();".to_owned());
+        lines.push(format!("var task = {call};"));
+        lines.push(format!(
+            "ResolveFuture(\"{name}\", asyncValuePtr, task, () => SerializeObject(task.Result));"
+        ));
+        lines.push("return asyncValuePtr;".to_owned());
+    } else {
+        match &function.return_type {
+            Some(ty) => {
+                if let Some((first, second)) = ty.as_packed_pair() {
+                    lines.push(format!("var result = {call};"));
+                    lines.push(format!(
+                        "return (long)(((ulong){} << 32) | {});",
+                        to_bits(first, "result.Item1"),
+                        to_bits(second, "result.Item2")
+                    ));
+                } else if let Some(primitive) = ty.as_primitive() {
+                    lines.push(format!("return {};", to_abi(primitive, &call)));
+                } else {
+                    lines.push(format!("return SerializeObject({call});"));
+                }
+            }
+            None => lines.push(format!("{call};")),
+        }
+    }
+
+    format!(
+        "linker.DefineFunction(\"fp\", \"{}\", ({params}) =>\n{{\n{}}});",
+        function.symbol_name(),
+        join_lines(&lines, |line| format!("    {line}"))
+    )
+}
+
+/// Formats the `__fp_host_batch()` import, which dispatches the calls that the
+/// plugin queued during a batch to the batchable import functions.
+fn format_batch_dispatcher(import_functions: &FunctionList, types: &TypeMap) -> Option<String> {
+    let cases = import_functions
+        .iter()
+        .filter(|function| function.attrs.batchable)
+        .map(|function| {
+            let mut lines = vec![format!("case \"{}\":", function.wire_name())];
+            if let Some(capability) = &function.attrs.capability {
+                lines.push(format!(
+                    "    CheckCapability(\"{}\", Capability.{});",
+                    function.name,
+                    capability.to_pascal_case()
+                ));
+            }
+            // Arguments are read in order, so each goes on a line of its own:
+            let args = function
+                .args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        "\n        MessagePackSerializer.Deserialize<{}>(ref reader, SerializerOptions)",
+                        format_ident(&arg.ty, types)
+                    )
+                })
+                .collect::<Vec<_>>();
+            lines.push(format!(
+                "    _imports.{}({});",
+                function.name.to_pascal_case(),
+                args.join(",")
+            ));
+            lines.push("    break;".to_owned());
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>();
+    if cases.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "linker.DefineFunction(\"fp\", \"__fp_host_batch\", (long callsPtr) =>
+{{
+    var reader = new MessagePackReader(ImportFromMemory(callsPtr));
+    var count = reader.ReadArrayHeader();
+    for (var i = 0; i < count; i++)
+    {{
+        reader.ReadMapHeader();
+        var name = reader.ReadString();
+        var argCount = reader.ReadArrayHeader();
+        switch (name)
+        {{
+{}            default:
+                // Calls to unknown functions are skipped.
+                for (var j = 0; j < argCount; j++)
+                {{
+                    reader.Skip();
+                }}
+                break;
+        }}
+    }}
+}});",
+        join_lines(
+            &cases
+                .iter()
+                .flat_map(|case| split_lines(case))
+                .collect::<Vec<_>>(),
+            |line| format!("            {line}")
+        )
+    ))
+}
+
+fn format_params(function: &Function, types: &TypeMap) -> String {
+    function
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{} {}",
+                format_ident(&arg.ty, types),
+                format_variable_name(&arg.name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_return_type(function: &Function, types: &TypeMap) -> String {
+    match (&function.return_type, function.is_async) {
+        (Some(ty), true) => format!("Task<{}>", format_ident(ty, types)),
+        (None, true) => "Task".to_owned(),
+        (Some(ty), false) => format_ident(ty, types),
+        (None, false) => "void".to_owned(),
+    }
+}
+
+/// Returns the type by which a function argument or return value is passed
+/// across the WASM boundary.
+fn format_abi_type(ty: &TypeIdent) -> &'static str {
+    match ty.as_primitive() {
+        Some(primitive) => format_abi_primitive(primitive),
+        None => "long", // Fat pointers and packed pairs.
+    }
+}
+
+fn format_abi_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::F32 => "float",
+        Primitive::F64 => "double",
+        Primitive::I64 | Primitive::U64 => "long",
+        _ => "int",
+    }
+}
+
+/// Converts a primitive value to the type by which it is passed to the plugin.
+fn to_abi(primitive: Primitive, value: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("{value} ? 1 : 0"),
+        Primitive::U32 => format!("(int){value}"),
+        Primitive::U64 => format!("(long){value}"),
+        _ => value.to_owned(),
+    }
+}
+
+/// Converts a primitive value that was passed by the plugin to its C# type.
+fn from_abi(primitive: Primitive, value: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("{value} != 0"),
+        Primitive::F32 | Primitive::F64 | Primitive::I32 | Primitive::I64 => value.to_owned(),
+        _ => format!("({}){value}", format_primitive(primitive)),
+    }
+}
+
+fn to_bits(primitive: Primitive, value: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("({value} ? 1u : 0u)"),
+        Primitive::F32 => format!("BitConverter.SingleToUInt32Bits({value})"),
+        Primitive::U32 => value.to_owned(),
+        _ => format!("(uint){value}"),
+    }
+}
+
+fn from_bits(primitive: Primitive, bits: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("{bits} != 0"),
+        Primitive::F32 => format!("BitConverter.UInt32BitsToSingle({bits})"),
+        Primitive::U32 => bits.to_owned(),
+        _ => format!("({}){bits}", format_primitive(primitive)),
+    }
+}
+
+fn format_docs(doc_lines: &[String]) -> Vec<String> {
+    if doc_lines.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec!["/// <summary>".to_owned()];
+    lines.extend(doc_lines.iter().map(|line| {
+        format!(
+            "///{}",
+            line.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+        )
+    }));
+    lines.push("/// </summary>".to_owned());
+    lines
+}
+
+fn get_pointer_name(name: &str) -> String {
+    format!("{}Ptr", get_variable_name(name).to_camel_case())
+}
+
+fn get_variable_name(name: &str) -> &str {
+    name.strip_prefix("r#").unwrap_or(name)
+}
+
+/// Formats the name of a parameter or local variable, escaping it if it is a
+/// C# keyword.
+fn format_variable_name(name: &str) -> String {
+    let name = get_variable_name(name).to_camel_case();
+    if CSHARP_KEYWORDS.contains(&name.as_str()) {
+        format!("@{name}")
+    } else {
+        name
+    }
+}
+
+/// Formats the name of a local variable in the generated formatters, which
+/// may not clash with the parameters and locals that those use themselves.
+fn format_local_name(name: &str) -> String {
+    let name = format_variable_name(name);
+    if matches!(name.as_str(), "count" | "i" | "options" | "reader") {
+        format!("{name}Value")
+    } else {
+        name
+    }
+}
+
+/// Formats the name of a property, which may not be the same as the name of
+/// the type that contains it, nor as any of the reserved names.
+fn format_property_name(name: &str, owner: &str, reserved_names: &[&str]) -> String {
+    let name = get_variable_name(name).to_pascal_case();
+    if name == owner || reserved_names.contains(&name.as_str()) {
+        format!("{name}Value")
+    } else {
+        name
+    }
+}
+
+fn split_lines(string: &str) -> Vec<String> {
+    string.split('\n').map(str::to_owned).collect()
+}
+
+fn join_lines<F>(lines: &[String], formatter: F) -> String
+where
+    F: Fn(&String) -> String,
+{
+    let lines = lines
+        .iter()
+        .map(|line| {
+            if line.is_empty() {
+                line.clone()
+            } else {
+                formatter(line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    if lines.is_empty() {
+        lines
+    } else {
+        format!("{lines}\n")
+    }
+}
+
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
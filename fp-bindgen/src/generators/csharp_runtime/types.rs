@@ -0,0 +1,1176 @@
+use super::{
+    format_docs, format_local_name, format_property_name, get_variable_name, join_lines,
+    write_bindings_file, HEADER_DIVIDER,
+};
+use crate::{
+    casing::Casing,
+    primitives::Primitive,
+    types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+    CSharpRuntimeConfig, FileHeaders,
+};
+
+pub(super) fn generate_type_bindings(
+    types: &TypeMap,
+    config: &CSharpRuntimeConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let type_defs = types
+        .values()
+        .filter_map(|ty| match ty {
+            Type::Enum(ty) if is_string_enum(ty) => Some(create_string_enum_definition(ty)),
+            Type::Enum(ty) => Some(create_enum_definition(ty, types)),
+            Type::Struct(ty) if !is_transparent_struct(ty) => {
+                Some(create_struct_definition(ty, types))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{path}/Types.cs"),
+        format!(
+            "{HEADER_DIVIDER}
+// Types for WebAssembly runtime                 //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+{HEADER_DIVIDER}
+
+using System;
+using System.Collections.Generic;
+using MessagePack;
+using MessagePack.Formatters;
+
+namespace {};
+
+{}
+
+{SERIALIZATION_HELPERS}",
+            config.namespace,
+            type_defs.join("\n\n")
+        ),
+        headers,
+    );
+}
+
+/// Helpers for the generated enum formatters, which follow the representations
+/// that Serde uses for enums.
+const SERIALIZATION_HELPERS: &str = r#"/// <summary>
+/// Helpers for the formatters of enums, which follow the representations that
+/// Serde uses for them.
+/// </summary>
+internal static class Serialization
+{
+    public delegate T ReadFunc<T>(ref MessagePackReader reader, MessagePackSerializerOptions options);
+
+    public static void Write<T>(ref MessagePackWriter writer, T value, MessagePackSerializerOptions options) =>
+        options.Resolver.GetFormatterWithVerify<T>().Serialize(ref writer, value, options);
+
+    public static T Read<T>(ref MessagePackReader reader, MessagePackSerializerOptions options) =>
+        options.Resolver.GetFormatterWithVerify<T>().Deserialize(ref reader, options);
+
+    /// <summary>
+    /// Returns the value of the given tag in a map, without advancing the
+    /// reader.
+    /// </summary>
+    public static string? ReadTag(MessagePackReader reader, string tag) =>
+        ReadTag(reader, tag, null, out _);
+
+    /// <summary>
+    /// Returns the value of the given tag in a map, without advancing the
+    /// reader. The content reader is positioned at the value of the given
+    /// content key, if it is present.
+    /// </summary>
+    public static string? ReadTag(
+        MessagePackReader reader,
+        string tag,
+        string? content,
+        out MessagePackReader contentReader)
+    {
+        string? value = null;
+        contentReader = reader;
+
+        var count = reader.ReadMapHeader();
+        for (var i = 0; i < count; i++)
+        {
+            var key = reader.ReadString();
+            if (key == tag)
+            {
+                value = reader.ReadString();
+                continue;
+            }
+
+            if (key == content)
+            {
+                contentReader = reader;
+            }
+
+            reader.Skip();
+        }
+
+        return value;
+    }
+
+    public static T Skip<T>(ref MessagePackReader reader, T value)
+    {
+        reader.Skip();
+        return value;
+    }
+
+    /// <summary>
+    /// Attempts to read a value, only advancing the reader if it succeeds.
+    /// </summary>
+    public static bool TryRead<T>(
+        ref MessagePackReader reader,
+        MessagePackSerializerOptions options,
+        ReadFunc<T> read,
+        out T value)
+    {
+        var peekReader = reader.CreatePeekReader();
+        try
+        {
+            value = read(ref peekReader, options);
+        }
+        catch (MessagePackSerializationException)
+        {
+            value = default!;
+            return false;
+        }
+
+        reader = peekReader;
+        return true;
+    }
+
+    public static MessagePackSerializationException MissingField(string type, string field) =>
+        new($"Missing field \"{field}\" for {type}");
+
+    public static MessagePackSerializationException UnknownVariant(string type, string? variant) =>
+        new($"Unknown variant \"{variant}\" for {type}");
+}
+"#;
+
+/// A property of a generated record, together with the key under which it is
+/// serialized.
+struct Property {
+    key: String,
+    name: String,
+    ty: String,
+    is_required: bool,
+    doc_lines: Vec<String>,
+}
+
+/// Collects the properties for the given fields, with the fields of flattened
+/// structs inlined.
+///
+/// Properties may not have the same name as their owner, nor as any of the
+/// given reserved names, which are used for the sibling records of enum
+/// variants.
+fn collect_properties(
+    owner: &str,
+    reserved_names: &[&str],
+    fields: &[Field],
+    casing: Casing,
+    types: &TypeMap,
+) -> Vec<Property> {
+    fields
+        .iter()
+        .flat_map(|field| {
+            if field.attrs.flatten {
+                return match types.get(&field.ty) {
+                    Some(Type::Struct(ty)) => collect_properties(
+                        owner,
+                        reserved_names,
+                        &ty.fields,
+                        ty.options.field_casing,
+                        types,
+                    ),
+                    _ => panic!(
+                        "Field `{}` of `{}` flattens type `{}`, but only structs can be \
+                            flattened in the C# runtime",
+                        field.name.as_deref().unwrap_or("0"),
+                        owner,
+                        field.ty
+                    ),
+                };
+            }
+
+            let name = get_variable_name(field.name.as_deref().unwrap_or_default());
+            let is_optional = matches!(
+                types.get(&field.ty),
+                Some(Type::Container(name, _)) if name == "Option"
+            );
+            let ty = if field.attrs.has_custom_serializer() || field.attrs.ts_type.is_some() {
+                // The wire shape of fields with custom (de)serializers can
+                // only be derived from their TypeScript type:
+                field
+                    .attrs
+                    .ts_type
+                    .as_deref()
+                    .map(format_ts_type)
+                    .unwrap_or_else(|| "object".to_owned())
+            } else {
+                format_ident(&field.ty, types)
+            };
+
+            vec![Property {
+                key: field
+                    .attrs
+                    .rename
+                    .clone()
+                    .unwrap_or_else(|| casing.format_string(name)),
+                name: format_property_name(name, owner, reserved_names),
+                ty,
+                is_required: !is_optional && field.attrs.default.is_none(),
+                doc_lines: field.doc_lines.clone(),
+            }]
+        })
+        .collect()
+}
+
+fn format_properties(properties: &[Property], with_keys: bool) -> String {
+    properties
+        .iter()
+        .map(|property| {
+            let mut lines = format_docs(&property.doc_lines);
+            if with_keys {
+                lines.push(format!("[Key(\"{}\")]", property.key));
+            }
+            lines.push(if property.is_required {
+                format!(
+                    "public required {} {} {{ get; init; }}",
+                    property.ty, property.name
+                )
+            } else if property.ty.ends_with('?') {
+                format!("public {} {} {{ get; init; }}", property.ty, property.name)
+            } else {
+                // Fields with a default value may be omitted, so they are not
+                // required, even though they are not nullable.
+                format!(
+                    "public {} {} {{ get; init; }} = default!;",
+                    property.ty, property.name
+                )
+            });
+            join_lines(&lines, |line| format!("    {line}"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn create_struct_definition(ty: &Struct, types: &TypeMap) -> String {
+    let properties = collect_properties(
+        &ty.ident.name,
+        &[],
+        &ty.fields,
+        ty.options.field_casing,
+        types,
+    );
+
+    format!(
+        "{}[MessagePackObject]\npublic sealed record {}{}",
+        join_lines(&format_docs(&ty.doc_lines), String::to_owned),
+        ty.ident.format(false),
+        if properties.is_empty() {
+            ";".to_owned()
+        } else {
+            format!("\n{{\n{}}}", format_properties(&properties, true))
+        }
+    )
+}
+
+/// Returns whether the given struct is serialized as the value(s) it wraps,
+/// in which case it is not generated, but replaced with the type it wraps.
+fn is_transparent_struct(ty: &Struct) -> bool {
+    !ty.fields.is_empty() && ty.fields.iter().all(|field| field.name.is_none())
+}
+
+/// Returns whether the given enum can be represented as a C# enum. This is the
+/// case if all its variants are unit variants that are serialized as plain
+/// strings.
+fn is_string_enum(ty: &Enum) -> bool {
+    ty.ident.generic_args.is_empty()
+        && ty.options.tag_prop_name.is_none()
+        && !ty.options.untagged
+        && !ty.variants.is_empty()
+        && ty
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.ty, Type::Unit))
+}
+
+fn create_string_enum_definition(ty: &Enum) -> String {
+    let name = &ty.ident.name;
+    let members = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            let mut lines = format_docs(&variant.doc_lines);
+            lines.push(format!("{},", get_variable_name(&variant.name)));
+            join_lines(&lines, |line| format!("    {line}"))
+        })
+        .collect::<Vec<_>>()
+        .join("");
+    let serialize_arms = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            format!(
+                "            {name}.{} => \"{}\",\n",
+                get_variable_name(&variant.name),
+                get_variant_name(variant, &ty.options)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+    let deserialize_arms = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            format!(
+                "            {} => {name}.{},\n",
+                format_variant_patterns(variant, &ty.options),
+                get_variable_name(&variant.name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "{}[MessagePackFormatter(typeof({name}Formatter))]
+public enum {name}
+{{
+{members}}}
+
+public sealed class {name}Formatter : IMessagePackFormatter<{name}>
+{{
+    public void Serialize(ref MessagePackWriter writer, {name} value, MessagePackSerializerOptions options)
+    {{
+        writer.Write(value switch
+        {{
+{serialize_arms}            _ => throw Serialization.UnknownVariant(\"{name}\", value.ToString()),
+        }});
+    }}
+
+    public {name} Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {{
+        var tag = reader.ReadString();
+        return tag switch
+        {{
+{deserialize_arms}            _ => throw Serialization.UnknownVariant(\"{name}\", tag),
+        }};
+    }}
+}}",
+        join_lines(&format_docs(&ty.doc_lines), String::to_owned),
+    )
+}
+
+/// The payload of an enum variant.
+enum Payload<'a> {
+    Unit,
+    Struct(&'a Struct),
+    /// A variant with unnamed fields. Variants with multiple fields hold a
+    /// value tuple, which is serialized as an array, just like Serde does.
+    Newtype(&'a [TypeIdent]),
+}
+
+impl<'a> Payload<'a> {
+    fn from_variant(variant: &'a Variant) -> Self {
+        match &variant.ty {
+            Type::Unit => Self::Unit,
+            Type::Struct(ty) => Self::Struct(ty),
+            Type::Tuple(items) if !items.is_empty() => Self::Newtype(items),
+            other => panic!("Unsupported type for enum variant: {:?}", other),
+        }
+    }
+}
+
+/// The representation of an enum, as determined by its Serde attributes.
+enum Tagging<'a> {
+    External,
+    Internal(&'a str),
+    Adjacent(&'a str, &'a str),
+    Untagged,
+}
+
+impl<'a> Tagging<'a> {
+    fn from_options(options: &'a EnumOptions) -> Self {
+        match (
+            options.untagged,
+            options.tag_prop_name.as_deref(),
+            options.content_prop_name.as_deref(),
+        ) {
+            (true, _, _) => Self::Untagged,
+            (false, Some(tag), Some(content)) => Self::Adjacent(tag, content),
+            (false, Some(tag), None) => Self::Internal(tag),
+            (false, None, _) => Self::External,
+        }
+    }
+}
+
+/// Creates an abstract record with a nested record for every variant, together
+/// with a formatter that (de)serializes it the way Serde would.
+fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
+    let name = &ty.ident.name;
+    let decl = ty.ident.format(false);
+    let tagging = Tagging::from_options(&ty.options);
+    let variant_names = ty
+        .variants
+        .iter()
+        .map(|variant| get_variable_name(&variant.name))
+        .collect::<Vec<_>>();
+
+    let variants = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            let variant_name = get_variable_name(&variant.name);
+            let mut lines = format_docs(&variant.doc_lines);
+            match Payload::from_variant(variant) {
+                Payload::Unit => {
+                    lines.push(format!("public sealed record {variant_name} : {decl};"));
+                }
+                Payload::Newtype(items) => lines.push(format!(
+                    "public sealed record {variant_name}({} Value) : {decl};",
+                    format_payload_ident(items, types)
+                )),
+                Payload::Struct(struct_variant) => {
+                    let properties = collect_properties(
+                        variant_name,
+                        &variant_names,
+                        &struct_variant.fields,
+                        variant.attrs.field_casing,
+                        types,
+                    );
+                    lines.push(format!("public sealed record {variant_name} : {decl}"));
+                    lines.push("{".to_owned());
+                    lines.extend(
+                        format_properties(&properties, false)
+                            .trim_end()
+                            .split('\n')
+                            .map(str::to_owned),
+                    );
+                    lines.push("}".to_owned());
+                }
+            }
+            join_lines(&lines, |line| format!("    {line}"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let generic_params = ty
+        .ident
+        .generic_args
+        .iter()
+        .map(|_| "")
+        .collect::<Vec<_>>()
+        .join(",");
+    let formatter_type = if ty.ident.generic_args.is_empty() {
+        format!("{name}Formatter")
+    } else {
+        format!("{name}Formatter<{generic_params}>")
+    };
+    let formatter_decl = match decl.strip_prefix(name.as_str()) {
+        Some(args) => format!("{name}Formatter{args}"),
+        None => format!("{name}Formatter"),
+    };
+
+    // Untagged enums serialize unit variants as `nil`, so only other enums
+    // can use `nil` to represent `null`:
+    let has_nil_variant = matches!(tagging, Tagging::Untagged)
+        && ty
+            .variants
+            .iter()
+            .any(|variant| matches!(variant.ty, Type::Unit));
+    let nil_check = if has_nil_variant {
+        ""
+    } else {
+        "        if (reader.TryReadNil())
+        {
+            return null!;
+        }
+
+"
+    };
+
+    let serialize_cases = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            format_serialize_case(&decl, variant, &variant_names, &ty.options, &tagging, types)
+        })
+        .collect::<Vec<_>>()
+        .join("");
+    let read_methods = ty
+        .variants
+        .iter()
+        .filter_map(|variant| format_read_method(&decl, variant, &variant_names, &tagging, types))
+        .map(|method| format!("\n{method}"))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "{}[MessagePackFormatter(typeof({formatter_type}))]
+public abstract record {decl}
+{{
+    private {name}()
+    {{
+    }}
+
+{variants}}}
+
+public sealed class {formatter_decl} : IMessagePackFormatter<{decl}>
+{{
+    public void Serialize(ref MessagePackWriter writer, {decl} value, MessagePackSerializerOptions options)
+    {{
+        switch (value)
+        {{
+            case null:
+                writer.WriteNil();
+                break;
+{serialize_cases}            default:
+                throw Serialization.UnknownVariant(\"{name}\", value.ToString());
+        }}
+    }}
+
+    public {decl} Deserialize(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {{
+{nil_check}{}
+    }}
+{read_methods}}}",
+        join_lines(&format_docs(&ty.doc_lines), String::to_owned),
+        format_deserialize_body(ty, &decl, &tagging, types),
+    )
+}
+
+fn format_serialize_case(
+    decl: &str,
+    variant: &Variant,
+    variant_names: &[&str],
+    options: &EnumOptions,
+    tagging: &Tagging,
+    types: &TypeMap,
+) -> String {
+    let variant_name = get_variable_name(&variant.name);
+    let serialized_name = get_variant_name(variant, options);
+    let payload = Payload::from_variant(variant);
+
+    let write_map = |len: usize| format!("writer.WriteMapHeader({len});");
+    let write_string = |string: &str| format!("writer.Write(\"{string}\");");
+    let write_value = |expr: &str| format!("Serialization.Write(ref writer, {expr}, options);");
+    let write_properties = |properties: &[Property], prefix: &str| {
+        properties
+            .iter()
+            .flat_map(|property| {
+                vec![
+                    write_string(&property.key),
+                    write_value(&format!("{prefix}.{}", property.name)),
+                ]
+            })
+            .collect::<Vec<_>>()
+    };
+    let struct_properties = |ty: &Struct| {
+        collect_properties(
+            variant_name,
+            variant_names,
+            &ty.fields,
+            variant.attrs.field_casing,
+            types,
+        )
+    };
+
+    let mut statements = Vec::new();
+    match (tagging, &payload) {
+        (Tagging::External, Payload::Unit) => statements.push(write_string(&serialized_name)),
+        (Tagging::External, Payload::Newtype(_)) => {
+            statements.push(write_map(1));
+            statements.push(write_string(&serialized_name));
+            statements.push(write_value("variant.Value"));
+        }
+        (Tagging::External, Payload::Struct(ty)) => {
+            let properties = struct_properties(ty);
+            statements.push(write_map(1));
+            statements.push(write_string(&serialized_name));
+            statements.push(write_map(properties.len()));
+            statements.extend(write_properties(&properties, "variant"));
+        }
+        (Tagging::Internal(tag) | Tagging::Adjacent(tag, _), Payload::Unit) => {
+            statements.push(write_map(1));
+            statements.push(write_string(tag));
+            statements.push(write_string(&serialized_name));
+        }
+        (Tagging::Internal(tag), Payload::Newtype(items)) => {
+            // Internally tagged variants are validated to have a single field:
+            let item = &items[0];
+            let properties = match types.get(item) {
+                Some(Type::Struct(ty)) => collect_properties(
+                    &ty.ident.name,
+                    &[],
+                    &ty.fields,
+                    ty.options.field_casing,
+                    types,
+                ),
+                _ => panic!(
+                    "Variant `{}` of internally tagged enum `{}` wraps type `{}`, but only \
+                        structs can be wrapped in the C# runtime",
+                    variant_name, decl, item
+                ),
+            };
+            statements.push(write_map(properties.len() + 1));
+            statements.push(write_string(tag));
+            statements.push(write_string(&serialized_name));
+            statements.extend(write_properties(&properties, "variant.Value"));
+        }
+        (Tagging::Internal(tag), Payload::Struct(ty)) => {
+            let properties = struct_properties(ty);
+            statements.push(write_map(properties.len() + 1));
+            statements.push(write_string(tag));
+            statements.push(write_string(&serialized_name));
+            statements.extend(write_properties(&properties, "variant"));
+        }
+        (Tagging::Adjacent(tag, content), Payload::Newtype(_)) => {
+            statements.push(write_map(2));
+            statements.push(write_string(tag));
+            statements.push(write_string(&serialized_name));
+            statements.push(write_string(content));
+            statements.push(write_value("variant.Value"));
+        }
+        (Tagging::Adjacent(tag, content), Payload::Struct(ty)) => {
+            let properties = struct_properties(ty);
+            statements.push(write_map(2));
+            statements.push(write_string(tag));
+            statements.push(write_string(&serialized_name));
+            statements.push(write_string(content));
+            statements.push(write_map(properties.len()));
+            statements.extend(write_properties(&properties, "variant"));
+        }
+        (Tagging::Untagged, Payload::Unit) => statements.push("writer.WriteNil();".to_owned()),
+        (Tagging::Untagged, Payload::Newtype(_)) => statements.push(write_value("variant.Value")),
+        (Tagging::Untagged, Payload::Struct(ty)) => {
+            let properties = struct_properties(ty);
+            statements.push(write_map(properties.len()));
+            statements.extend(write_properties(&properties, "variant"));
+        }
+    }
+    statements.push("break;".to_owned());
+
+    format!(
+        "            case {decl}.{variant_name}{}:\n{}",
+        match payload {
+            Payload::Unit => "",
+            _ => " variant",
+        },
+        join_lines(&statements, |line| format!("                {line}"))
+    )
+}
+
+fn format_deserialize_body(ty: &Enum, decl: &str, tagging: &Tagging, types: &TypeMap) -> String {
+    let name = &ty.ident.name;
+    let options = &ty.options;
+    let other_variant = ty.variants.iter().find(|variant| variant.attrs.other);
+    let fallback_arm = |tag: &str, read_unit: &dyn Fn(&str) -> String| match other_variant {
+        Some(variant) => format!(
+            "_ => {},",
+            read_unit(&format!(
+                "new {decl}.{}()",
+                get_variable_name(&variant.name)
+            ))
+        ),
+        None => format!("_ => throw Serialization.UnknownVariant(\"{name}\", {tag}),"),
+    };
+    let format_switch = |tag: &str, arms: Vec<String>| {
+        format!(
+            "return {tag} switch
+        {{
+{}        }};",
+            join_lines(&arms, |line| format!("            {line}"))
+        )
+    };
+
+    match tagging {
+        Tagging::External => {
+            let (unit_variants, data_variants): (Vec<_>, Vec<_>) = ty
+                .variants
+                .iter()
+                .partition(|variant| matches!(variant.ty, Type::Unit));
+            let mut lines = Vec::new();
+            if !unit_variants.is_empty() {
+                let mut arms = unit_variants
+                    .iter()
+                    .map(|variant| {
+                        format!(
+                            "{} => new {decl}.{}(),",
+                            format_variant_patterns(variant, options),
+                            get_variable_name(&variant.name)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                arms.push(format!(
+                    "_ => throw Serialization.UnknownVariant(\"{name}\", tag),"
+                ));
+                let switch = format!(
+                    "var tag = reader.ReadString();\n        {}",
+                    format_switch("tag", arms)
+                );
+                if data_variants.is_empty() {
+                    return format!("        {switch}");
+                }
+
+                lines.push(format!(
+                    "        if (reader.NextMessagePackType == MessagePackType.String)
+        {{
+            {}
+        }}
+",
+                    switch.replace("\n        ", "\n            ")
+                ));
+            }
+
+            let mut arms = data_variants
+                .iter()
+                .map(|variant| {
+                    format!(
+                        "{} => {},",
+                        format_variant_patterns(variant, options),
+                        format_read_expression(decl, variant, "reader", types)
+                    )
+                })
+                .collect::<Vec<_>>();
+            arms.push(format!(
+                "_ => throw Serialization.UnknownVariant(\"{name}\", variant),"
+            ));
+            lines.push(format!(
+                "        if (reader.ReadMapHeader() != 1)
+        {{
+            throw new MessagePackSerializationException(\"Expected a map with a single entry for {name}\");
+        }}
+
+        var variant = reader.ReadString();
+        {}",
+                format_switch("variant", arms)
+            ));
+            lines.join("\n")
+        }
+        Tagging::Internal(tag) => {
+            let mut arms = ty
+                .variants
+                .iter()
+                .filter(|variant| !variant.attrs.other)
+                .map(|variant| {
+                    let expression = match Payload::from_variant(variant) {
+                        Payload::Unit => format!(
+                            "Serialization.Skip(ref reader, new {decl}.{}())",
+                            get_variable_name(&variant.name)
+                        ),
+                        _ => format_read_expression(decl, variant, "reader", types),
+                    };
+                    format!(
+                        "{} => {expression},",
+                        format_variant_patterns(variant, options)
+                    )
+                })
+                .collect::<Vec<_>>();
+            arms.push(fallback_arm("tag", &|value| {
+                format!("Serialization.Skip(ref reader, {value})")
+            }));
+            format!(
+                "        var tag = Serialization.ReadTag(reader, \"{tag}\");
+        {}",
+                format_switch("tag", arms)
+            )
+        }
+        Tagging::Adjacent(tag, content) => {
+            let mut arms = ty
+                .variants
+                .iter()
+                .filter(|variant| !variant.attrs.other)
+                .map(|variant| {
+                    let expression = match Payload::from_variant(variant) {
+                        Payload::Unit => {
+                            format!("new {decl}.{}()", get_variable_name(&variant.name))
+                        }
+                        _ => format_read_expression(decl, variant, "content", types),
+                    };
+                    format!(
+                        "{} => {expression},",
+                        format_variant_patterns(variant, options)
+                    )
+                })
+                .collect::<Vec<_>>();
+            arms.push(fallback_arm("tag", &|value| value.to_owned()));
+            let has_content = ty
+                .variants
+                .iter()
+                .any(|variant| !matches!(variant.ty, Type::Unit));
+            format!(
+                "        var tag = Serialization.ReadTag(reader, \"{tag}\", \"{content}\", out {});
+        reader.Skip();
+        {}",
+                if has_content { "var content" } else { "_" },
+                format_switch("tag", arms)
+            )
+        }
+        Tagging::Untagged => {
+            let attempts = ty
+                .variants
+                .iter()
+                .map(|variant| {
+                    let variant_name = get_variable_name(&variant.name);
+                    let local_name = format_local_name(variant_name);
+                    format!(
+                        "        if (Serialization.TryRead(ref reader, options, Read{variant_name}, out var {local_name}))
+        {{
+            return {local_name};
+        }}
+"
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!(
+                "{attempts}
+        throw new MessagePackSerializationException(\"Data did not match any variant of {name}\");"
+            )
+        }
+    }
+}
+
+/// Formats the expression that reads the payload of a non-unit variant from
+/// the given reader.
+fn format_read_expression(decl: &str, variant: &Variant, reader: &str, types: &TypeMap) -> String {
+    let variant_name = get_variable_name(&variant.name);
+    match Payload::from_variant(variant) {
+        Payload::Newtype(items) => format!(
+            "new {decl}.{variant_name}(Serialization.Read<{}>(ref {reader}, options))",
+            format_payload_ident(items, types)
+        ),
+        _ => format!("Read{variant_name}(ref {reader}, options)"),
+    }
+}
+
+/// Formats the method that reads the payload of a variant, if the variant
+/// needs one.
+///
+/// Struct variants always need one, while unit and newtype variants only need
+/// one for untagged enums, so that deserialization can be attempted.
+fn format_read_method(
+    decl: &str,
+    variant: &Variant,
+    variant_names: &[&str],
+    tagging: &Tagging,
+    types: &TypeMap,
+) -> Option<String> {
+    let variant_name = get_variable_name(&variant.name);
+    let is_untagged = matches!(tagging, Tagging::Untagged);
+    let body = match Payload::from_variant(variant) {
+        Payload::Unit if is_untagged => format!(
+            "        reader.ReadNil();
+        return new {decl}.{variant_name}();"
+        ),
+        Payload::Newtype(items) if is_untagged => format!(
+            "        return new {decl}.{variant_name}(Serialization.Read<{}>(ref reader, options));",
+            format_payload_ident(items, types)
+        ),
+        Payload::Struct(ty) => {
+            let properties = collect_properties(
+                variant_name,
+                variant_names,
+                &ty.fields,
+                variant.attrs.field_casing,
+                types,
+            );
+            format_struct_reader(decl, variant_name, &properties)
+        }
+        _ => return None,
+    };
+
+    Some(format!(
+        "    private static {decl}.{variant_name} Read{variant_name}(ref MessagePackReader reader, MessagePackSerializerOptions options)
+    {{
+{body}
+    }}
+"
+    ))
+}
+
+fn format_struct_reader(decl: &str, variant_name: &str, properties: &[Property]) -> String {
+    let locals = properties
+        .iter()
+        .map(|property| format_local_name(&property.name))
+        .collect::<Vec<_>>();
+    let flags = properties
+        .iter()
+        .map(|property| format!("has{}", property.name))
+        .collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    for ((property, local), flag) in properties.iter().zip(&locals).zip(&flags) {
+        lines.push(format!("var {local} = default({})!;", property.ty));
+        if property.is_required {
+            lines.push(format!("var {flag} = false;"));
+        }
+    }
+    if !lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines.push("var count = reader.ReadMapHeader();".to_owned());
+    lines.push("for (var i = 0; i < count; i++)".to_owned());
+    lines.push("{".to_owned());
+    lines.push("    switch (reader.ReadString())".to_owned());
+    lines.push("    {".to_owned());
+    for ((property, local), flag) in properties.iter().zip(&locals).zip(&flags) {
+        lines.push(format!("        case \"{}\":", property.key));
+        lines.push(format!(
+            "            {local} = Serialization.Read<{}>(ref reader, options);",
+            property.ty
+        ));
+        if property.is_required {
+            lines.push(format!("            {flag} = true;"));
+        }
+        lines.push("            break;".to_owned());
+    }
+    lines.push("        default:".to_owned());
+    lines.push("            reader.Skip();".to_owned());
+    lines.push("            break;".to_owned());
+    lines.push("    }".to_owned());
+    lines.push("}".to_owned());
+
+    let required_checks = properties
+        .iter()
+        .zip(&flags)
+        .filter(|(property, _)| property.is_required)
+        .map(|(property, flag)| {
+            format!(
+                "if (!{flag})
+{{
+    throw Serialization.MissingField(\"{variant_name}\", \"{}\");
+}}",
+                property.key
+            )
+        })
+        .collect::<Vec<_>>();
+    for check in required_checks {
+        lines.push(String::new());
+        lines.extend(check.split('\n').map(str::to_owned));
+    }
+
+    lines.push(String::new());
+    if properties.is_empty() {
+        lines.push(format!("return new {decl}.{variant_name}();"));
+    } else {
+        lines.push(format!("return new {decl}.{variant_name}"));
+        lines.push("{".to_owned());
+        for (property, local) in properties.iter().zip(&locals) {
+            lines.push(format!("    {} = {local},", property.name));
+        }
+        lines.push("};".to_owned());
+    }
+
+    join_lines(&lines, |line| format!("        {line}"))
+        .trim_end()
+        .to_owned()
+}
+
+/// Formats the pattern that matches the serialized name of a variant, or any
+/// of its aliases.
+fn format_variant_patterns(variant: &Variant, options: &EnumOptions) -> String {
+    std::iter::once(get_variant_name(variant, options))
+        .chain(variant.attrs.aliases.iter().cloned())
+        .map(|name| format!("\"{name}\""))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+fn get_variant_name(variant: &Variant, options: &EnumOptions) -> String {
+    match variant.attrs.rename.as_ref() {
+        Some(rename) => rename.to_owned(),
+        None => options
+            .variant_casing
+            .format_string(get_variable_name(&variant.name)),
+    }
+}
+
+/// Formats a type so it's valid C#.
+pub(super) fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
+    match types.get(ident) {
+        Some(ty) => format_type_with_ident(ty, ident, types),
+        None => ident.name.clone(), // Must be a generic.
+    }
+}
+
+fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap) -> String {
+    match ty {
+        Type::Alias(_, ident) => format_ident(ident, types),
+        // `byte[]` is serialized as binary, while Serde expects arrays to be
+        // serialized as MessagePack arrays:
+        Type::Array(Primitive::U8, _) => "List<byte>".to_owned(),
+        Type::Array(primitive, _) => format!("{}[]", format_primitive(*primitive)),
+        Type::Container(name, _) => {
+            let item = format_ident(first_generic_arg(ident), types);
+            if name == "Option" && !item.ends_with('?') {
+                format!("{item}?")
+            } else {
+                item
+            }
+        }
+        Type::Custom(custom) => format_custom_type(custom),
+        Type::Enum(_) => format_generic_type(ident, types),
+        Type::List(_, _) => format!("List<{}>", format_ident(first_generic_arg(ident), types)),
+        Type::Map(_, _, _) => {
+            let (value, _) = ident
+                .generic_args
+                .get(1)
+                .expect("Identifier was expected to contain two arguments");
+            format!(
+                "Dictionary<{}, {}>",
+                format_ident(first_generic_arg(ident), types),
+                format_ident(value, types)
+            )
+        }
+        Type::Primitive(primitive) => format_primitive(*primitive).to_owned(),
+        Type::String => "string".to_owned(),
+        Type::Struct(ty) if is_transparent_struct(ty) => {
+            let items = ty
+                .fields
+                .iter()
+                .map(|field| {
+                    format_ident(&substitute_generic_args(&field.ty, &ty.ident, ident), types)
+                })
+                .collect::<Vec<_>>();
+            format_tuple(&items)
+        }
+        Type::Struct(_) => format_generic_type(ident, types),
+        Type::Tuple(items) => format_tuple(
+            &items
+                .iter()
+                .map(|item| format_ident(item, types))
+                .collect::<Vec<_>>(),
+        ),
+        Type::Unit => "Nil".to_owned(),
+    }
+}
+
+fn first_generic_arg(ident: &TypeIdent) -> &TypeIdent {
+    let (arg, _) = ident
+        .generic_args
+        .first()
+        .expect("Identifier was expected to contain a generic argument");
+    arg
+}
+
+fn format_generic_type(ident: &TypeIdent, types: &TypeMap) -> String {
+    if ident.generic_args.is_empty() {
+        ident.name.clone()
+    } else {
+        format!(
+            "{}<{}>",
+            ident.name,
+            ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| format_ident(arg, types))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Formats the type of the value held by a variant with unnamed fields.
+fn format_payload_ident(items: &[TypeIdent], types: &TypeMap) -> String {
+    format_tuple(
+        &items
+            .iter()
+            .map(|item| format_ident(item, types))
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn format_tuple(items: &[String]) -> String {
+    match items {
+        [item] => item.clone(),
+        items => format!("({})", items.join(", ")),
+    }
+}
+
+/// Replaces the generic parameters of a declaration in the given type with
+/// the arguments from the identifier by which the declaration is used.
+fn substitute_generic_args(ty: &TypeIdent, decl: &TypeIdent, usage: &TypeIdent) -> TypeIdent {
+    if ty.generic_args.is_empty() {
+        if let Some(index) = decl
+            .generic_args
+            .iter()
+            .position(|(param, _)| param.name == ty.name)
+        {
+            if let Some((arg, _)) = usage.generic_args.get(index) {
+                return arg.clone();
+            }
+        }
+    }
+
+    let mut ty = ty.clone();
+    for (arg, _) in ty.generic_args.iter_mut() {
+        *arg = substitute_generic_args(arg, decl, usage);
+    }
+    ty
+}
+
+/// Formats a custom type, based on the TypeScript type that describes its
+/// serialized representation.
+fn format_custom_type(custom: &CustomType) -> String {
+    if let Some(primitive) = custom
+        .rs_ty
+        .strip_prefix("std::num::NonZero")
+        .and_then(|name| name.to_lowercase().parse::<Primitive>().ok())
+    {
+        return format_primitive(primitive).to_owned();
+    }
+
+    match &custom.ts_declaration {
+        Some(declaration) if is_string_literal_union(declaration) => "string".to_owned(),
+        Some(declaration) if is_branded_string(declaration) => "string".to_owned(),
+        Some(_) => "object".to_owned(),
+        None => format_ts_type(&custom.ts_ty),
+    }
+}
+
+fn format_ts_type(ts_ty: &str) -> String {
+    match ts_ty {
+        "ArrayBuffer" | "Uint8Array" => "byte[]",
+        "bigint" => "long",
+        "boolean" => "bool",
+        "number" => "double",
+        "string" => "string",
+        _ if is_string_literal_union(ts_ty) => "string",
+        _ => "object",
+    }
+    .to_owned()
+}
+
+/// Returns whether the given declaration is a branded string, such as
+/// `string & { readonly __brand: "Decimal" }`.
+fn is_branded_string(declaration: &str) -> bool {
+    declaration.starts_with("string & ")
+}
+
+fn is_string_literal_union(ts_ty: &str) -> bool {
+    ts_ty
+        .split('|')
+        .map(str::trim)
+        .filter(|literal| !literal.is_empty())
+        .all(|literal| literal.len() >= 2 && literal.starts_with('"') && literal.ends_with('"'))
+}
+
+pub(super) fn format_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "bool",
+        Primitive::F32 => "float",
+        Primitive::F64 => "double",
+        Primitive::I8 => "sbyte",
+        Primitive::I16 => "short",
+        Primitive::I32 => "int",
+        Primitive::I64 => "long",
+        Primitive::U8 => "byte",
+        Primitive::U16 => "ushort",
+        Primitive::U32 => "uint",
+        Primitive::U64 => "ulong",
+    }
+}
@@ -0,0 +1,398 @@
+use crate::{
+    functions::{Function, FunctionList},
+    generators::{fixtures::collect_fixtures, write_bindings_file},
+    types::{Enum, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+    DocsConfig, FileHeaders,
+};
+use std::collections::BTreeMap;
+
+const DOCS_HEADER: &str = "<!-- This file is generated. PLEASE DO NOT MODIFY. -->";
+
+/// Generates a markdown document that describes the whole protocol: the
+/// imports and exports with their signatures, followed by the structs, enums
+/// and aliases they use, together with an example payload for every type
+/// that has a wire-format fixture.
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: DocsConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let (fixtures, _) = collect_fixtures(&types);
+    let examples = fixtures
+        .into_iter()
+        .map(|fixture| {
+            let value = rmpv::decode::read_value(&mut fixture.bytes.as_slice())
+                .expect("Could not decode fixture");
+            let json = serde_json::to_string_pretty(&value).expect("Could not format example");
+            (fixture.ty, json)
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let mut sections = vec![format!("# {}", config.title)];
+    sections.push(format_functions_section(
+        "Imports",
+        "Functions that the runtime provides to plugins.",
+        &import_functions,
+        &types,
+    ));
+    sections.push(format_functions_section(
+        "Exports",
+        "Functions that plugins provide to the runtime.",
+        &export_functions,
+        &types,
+    ));
+    sections.push(format_types_section(&types, &examples));
+
+    write_bindings_file(
+        format!("{path}/protocol.md"),
+        format!("{DOCS_HEADER}\n\n{}\n", sections.join("\n\n")),
+        headers,
+    );
+}
+
+fn format_functions_section(
+    title: &str,
+    description: &str,
+    functions: &FunctionList,
+    types: &TypeMap,
+) -> String {
+    let mut section = format!("## {title}\n\n{description}");
+    if functions.iter().next().is_none() {
+        section.push_str("\n\nThe protocol has none.");
+    }
+    for function in functions {
+        section.push_str("\n\n");
+        section.push_str(&format_function(function, types));
+    }
+    section
+}
+
+fn format_function(function: &Function, types: &TypeMap) -> String {
+    let mut parts = vec![format!("### `{}`", function.name)];
+    if let Some(doc) = format_doc_lines(&function.doc_lines) {
+        parts.push(doc);
+    }
+    parts.push(format!("```rust\n{}\n```", format_signature(function)));
+
+    if !function.args.is_empty() {
+        let rows = function
+            .args
+            .iter()
+            .map(|arg| {
+                format!(
+                    "| `{}` | {} |",
+                    arg.name_without_prefix(),
+                    format_type_ref(&arg.ty, types)
+                )
+            })
+            .collect::<Vec<_>>();
+        parts.push(format!(
+            "| Argument | Type |\n| --- | --- |\n{}",
+            rows.join("\n")
+        ));
+    }
+    if let Some(return_type) = &function.return_type {
+        parts.push(format!(
+            "**Returns:** {}",
+            format_type_ref(return_type, types)
+        ));
+    }
+
+    let mut notes = Vec::new();
+    if function.wire_name() != function.name {
+        notes.push(format!(
+            "Exchanged between runtime and plugin as `{}`.",
+            function.wire_name()
+        ));
+    }
+    if let Some(capability) = &function.attrs.capability {
+        notes.push(format!("Requires the `{capability}` capability."));
+    }
+    if let Some(feature) = &function.attrs.cfg_feature {
+        notes.push(format!("Only available with the `{feature}` feature."));
+    }
+    if let Some(http) = &function.attrs.http {
+        notes.push(format!(
+            "Available over HTTP as `{} {}`.",
+            http.method, http.path
+        ));
+    }
+    if !notes.is_empty() {
+        parts.push(notes.join(" "));
+    }
+
+    parts.join("\n\n")
+}
+
+fn format_signature(function: &Function) -> String {
+    let generic_params = if function.generic_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", function.generic_params.join(", "))
+    };
+    let args = function
+        .args
+        .iter()
+        .map(|arg| format!("{}: {}", arg.name, arg.ty))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = match &function.return_type {
+        Some(return_type) => format!(" -> {return_type}"),
+        None => String::new(),
+    };
+    format!(
+        "{}fn {}{generic_params}({args}){return_type};",
+        if function.is_async { "async " } else { "" },
+        function.name
+    )
+}
+
+/// Formats the section with all the structs, enums and aliases of the
+/// protocol. Primitives, standard types and custom types are not described,
+/// since their representation is defined by Serde.
+fn format_types_section(types: &TypeMap, examples: &BTreeMap<TypeIdent, String>) -> String {
+    let mut section = "## Types".to_owned();
+    for (ident, ty) in types {
+        let definition = match ty {
+            Type::Alias(_, aliased) => format!(
+                "An alias of {}, which it is serialized as.",
+                format_type_ref(aliased, types)
+            ),
+            Type::Enum(ty) => format_enum(ty, types),
+            Type::Struct(ty) => format_struct(ty, types),
+            _ => continue,
+        };
+
+        section.push_str(&format!("\n\n### {}", ident.name));
+        let doc_lines = match ty {
+            Type::Enum(ty) => ty.doc_lines.as_slice(),
+            Type::Struct(ty) => ty.doc_lines.as_slice(),
+            _ => &[],
+        };
+        if let Some(doc) = format_doc_lines(doc_lines) {
+            section.push_str(&format!("\n\n{doc}"));
+        }
+        if !ident.generic_args.is_empty() {
+            let params = ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| format!("`{arg}`"))
+                .collect::<Vec<_>>();
+            section.push_str(&format!("\n\nGeneric over {}.", params.join(", ")));
+        }
+        section.push_str(&format!("\n\n{definition}"));
+        if let Some(example) = examples.get(ident) {
+            section.push_str(&format!(
+                "\n\n**Example payload** (MessagePack, shown as JSON):\n\n```json\n{example}\n```"
+            ));
+        }
+    }
+    section
+}
+
+fn format_struct(ty: &Struct, types: &TypeMap) -> String {
+    match ty.fields.as_slice() {
+        [] => "A struct without fields, which is serialized as an empty map.".to_owned(),
+        [field] if field.name.is_none() => format!(
+            "A newtype, which is serialized as its field of type {}.",
+            format_type_ref(&field.ty, types)
+        ),
+        fields if fields.iter().all(|field| field.name.is_none()) => format!(
+            "A tuple struct, which is serialized as an array of its fields.\n\n\
+            | Index | Type | Description |\n| --- | --- | --- |\n{}",
+            fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| format_field_row(&index.to_string(), field, types))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        fields => format!(
+            "A struct, which is serialized as a map of its fields.\n\n\
+            | Field | Type | Description |\n| --- | --- | --- |\n{}",
+            format_field_rows(fields, ty, types)
+        ),
+    }
+}
+
+fn format_field_rows(fields: &[Field], ty: &Struct, types: &TypeMap) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            let name = field.name.as_deref().unwrap_or_default();
+            let name = field.attrs.rename.clone().unwrap_or_else(|| {
+                ty.options
+                    .field_casing
+                    .format_string(name.strip_prefix("r#").unwrap_or(name))
+            });
+            format_field_row(&name, field, types)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_field_row(name: &str, field: &Field, types: &TypeMap) -> String {
+    let mut description = format_doc_lines(&field.doc_lines)
+        .map(|doc| doc.replace("\n\n", "<br><br>").replace('\n', " "))
+        .into_iter()
+        .collect::<Vec<_>>();
+    if field.attrs.flatten {
+        description.push("Its fields are inlined into the surrounding map.".to_owned());
+    }
+    if field.attrs.default.is_some() {
+        description.push("May be omitted, in which case a default is used.".to_owned());
+    }
+    if field.attrs.skip_serializing_if.is_some() {
+        description.push("May be omitted when serialized.".to_owned());
+    }
+    if let Some(validation) = &field.attrs.validate {
+        description.push(format!("Must satisfy `{validation}`."));
+    }
+
+    let name = if field.attrs.flatten {
+        "(flattened)".to_owned()
+    } else {
+        format!("`{name}`")
+    };
+    format!(
+        "| {name} | {} | {} |",
+        format_type_ref(&field.ty, types),
+        description.join(" ").replace('|', "\\|")
+    )
+}
+
+fn format_enum(ty: &Enum, types: &TypeMap) -> String {
+    let options = &ty.options;
+    let representation = match (
+        options.untagged,
+        &options.tag_prop_name,
+        &options.content_prop_name,
+    ) {
+        (true, _, _) => {
+            "An untagged enum, which is serialized as the payload of its variant.".to_owned()
+        }
+        (false, Some(tag), Some(content)) => format!(
+            "An adjacently tagged enum, which is serialized as a map with the name of its \
+            variant in `{tag}` and its payload in `{content}`."
+        ),
+        (false, Some(tag), None) => format!(
+            "An internally tagged enum, which is serialized as a map with the name of its \
+            variant in `{tag}`, alongside the fields of its payload."
+        ),
+        (false, None, _) => "An externally tagged enum, which is serialized as the name of its \
+            variant, or as a map from the name of its variant to its payload."
+            .to_owned(),
+    };
+
+    let rows = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            let name = variant.attrs.rename.clone().unwrap_or_else(|| {
+                options
+                    .variant_casing
+                    .format_string(variant.name.strip_prefix("r#").unwrap_or(&variant.name))
+            });
+            let description = format_doc_lines(&variant.doc_lines)
+                .map(|doc| doc.replace("\n\n", "<br><br>").replace('\n', " "))
+                .unwrap_or_default()
+                .replace('|', "\\|");
+            format!(
+                "| `{name}` | {} | {description} |",
+                format_variant_payload(variant, types)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "{representation}\n\n| Variant | Payload | Description |\n| --- | --- | --- |\n{}",
+        rows.join("\n")
+    )
+}
+
+fn format_variant_payload(variant: &Variant, types: &TypeMap) -> String {
+    match &variant.ty {
+        Type::Struct(ty) => {
+            let casing = variant.attrs.field_casing;
+            ty.fields
+                .iter()
+                .map(|field| {
+                    let name = field.name.as_deref().unwrap_or_default();
+                    let name = field.attrs.rename.clone().unwrap_or_else(|| {
+                        casing.format_string(name.strip_prefix("r#").unwrap_or(name))
+                    });
+                    format!("`{name}`: {}", format_type_ref(&field.ty, types))
+                })
+                .collect::<Vec<_>>()
+                .join("<br>")
+        }
+        Type::Tuple(items) => items
+            .iter()
+            .map(|item| format_type_ref(item, types))
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => "None".to_owned(),
+    }
+}
+
+/// Formats a reference to a type as inline code, which links to the
+/// description of the type if the protocol declares it.
+fn format_type_ref(ident: &TypeIdent, types: &TypeMap) -> String {
+    let code = format!("`{ident}`");
+    let is_described = types.iter().any(|(declared, ty)| {
+        declared.name == ident.name
+            && matches!(ty, Type::Alias(_, _) | Type::Enum(_) | Type::Struct(_))
+    });
+    if is_described {
+        format!("[{code}](#{})", ident.name.to_lowercase())
+    } else {
+        code
+    }
+}
+
+/// Formats doc lines as markdown paragraphs, or returns `None` if there are
+/// no (non-empty) doc lines.
+fn format_doc_lines(doc_lines: &[String]) -> Option<String> {
+    let doc = doc_lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let doc = doc.trim_matches('\n');
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_signature() {
+        let function = Function::new("async fn fetch<T>(id: u64, filter: Option<T>) -> Vec<T>;");
+        assert_eq!(
+            format_signature(&function),
+            "async fn fetch<T>(id: u64, filter: Option<T>) -> Vec<T>;"
+        );
+    }
+
+    #[test]
+    fn test_format_doc_lines() {
+        let doc_lines = vec![
+            " First line.".to_owned(),
+            "".to_owned(),
+            " Second paragraph.".to_owned(),
+        ];
+        assert_eq!(
+            format_doc_lines(&doc_lines).as_deref(),
+            Some("First line.\n\nSecond paragraph.")
+        );
+        assert_eq!(format_doc_lines(&[" ".to_owned()]), None);
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    generators::{
+        benches::{create_sample, MapKey, Sample, StructSample, VariantSample, VariantValue},
+        schema_hash::SchemaHashes,
+        validation::{collect_structs_containing, contains_validated_struct},
+        write_bindings_file,
+    },
+    primitives::Primitive,
+    types::{Type, TypeIdent, TypeMap},
+    FileHeaders, FixturesConfig,
+};
+use inflector::Inflector;
+use rmpv::Value;
+use serde_json::json;
+use std::{collections::BTreeMap, fs};
+
+mod rust;
+mod typescript;
+
+/// The number of characters in strings, and the number of items in lists and
+/// maps, of the fixtures. Maps contain a single entry, so that their encoding
+/// does not depend on the iteration order of the map type.
+const FIXTURE_SIZE: usize = 1;
+
+pub(crate) fn generate_bindings(
+    types: TypeMap,
+    schema_hashes: &SchemaHashes,
+    config: FixturesConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let fixtures_path = format!("{path}/fixtures");
+    fs::create_dir_all(&fixtures_path).expect("Could not create fixtures directory");
+
+    let (fixtures, skipped) = collect_fixtures(&types);
+    for fixture in &fixtures {
+        fs::write(format!("{fixtures_path}/{}", fixture.file), &fixture.bytes)
+            .expect("Could not write fixture");
+    }
+
+    generate_manifest(&fixtures, &skipped, headers, path);
+    rust::generate_fixtures_crate(&fixtures, &types, schema_hashes, &config, headers, path);
+    typescript::generate_fixtures_script(&fixtures, &config, headers, path);
+}
+
+/// The canonical MessagePack encoding of a representative value of a type.
+pub(crate) struct Fixture {
+    pub ty: TypeIdent,
+    /// The name of the fixture file, relative to the `fixtures` directory.
+    pub file: String,
+    pub sample: Sample,
+    pub bytes: Vec<u8>,
+}
+
+/// A type for which no fixture could be created, with the reason why.
+pub(crate) struct SkippedType {
+    pub ty: TypeIdent,
+    pub reason: String,
+}
+
+/// Creates a fixture for every struct, enum and alias that has no generic
+/// parameters. Generic types are covered by the fixtures of the types that
+/// use them.
+///
+/// Types with validated fields are skipped, because their representative
+/// values do not necessarily pass validation.
+pub(crate) fn collect_fixtures(types: &TypeMap) -> (Vec<Fixture>, Vec<SkippedType>) {
+    let validated_structs = collect_structs_containing(types, |ty| {
+        ty.fields.iter().any(|field| field.attrs.validate.is_some())
+    });
+
+    let mut fixtures = Vec::new();
+    let mut skipped = Vec::new();
+    for (ident, ty) in types {
+        if !matches!(ty, Type::Alias(_, _) | Type::Enum(_) | Type::Struct(_))
+            || !ident.generic_args.is_empty()
+        {
+            continue;
+        }
+        if contains_validated_struct(ident, types, &validated_structs) {
+            skipped.push(SkippedType {
+                ty: ident.clone(),
+                reason: "it has validated fields".to_owned(),
+            });
+            continue;
+        }
+
+        let result = create_sample(ident, types, &BTreeMap::new(), &mut Vec::new())
+            .and_then(|sample| Ok((encode_sample(&sample)?, sample)));
+        match result {
+            Ok((value, sample)) => {
+                let mut bytes = Vec::new();
+                rmpv::encode::write_value(&mut bytes, &value).expect("Could not encode fixture");
+                fixtures.push(Fixture {
+                    ty: ident.clone(),
+                    file: format!("{}.msgpack", ident.name.to_snake_case()),
+                    sample,
+                    bytes,
+                });
+            }
+            Err(reason) => skipped.push(SkippedType {
+                ty: ident.clone(),
+                reason,
+            }),
+        }
+    }
+    (fixtures, skipped)
+}
+
+/// Writes a manifest that lists the fixture of every type, as well as the
+/// types for which no fixture could be created.
+fn generate_manifest(
+    fixtures: &[Fixture],
+    skipped: &[SkippedType],
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let manifest = json!({
+        "size": FIXTURE_SIZE,
+        "fixtures": fixtures
+            .iter()
+            .map(|fixture| json!({
+                "type": fixture.ty.to_string(),
+                "file": format!("fixtures/{}", fixture.file),
+            }))
+            .collect::<Vec<_>>(),
+        "skipped": skipped
+            .iter()
+            .map(|skipped| json!({
+                "type": skipped.ty.to_string(),
+                "reason": skipped.reason,
+            }))
+            .collect::<Vec<_>>(),
+    });
+
+    write_bindings_file(
+        format!("{path}/manifest.json"),
+        format!(
+            "{}\n",
+            serde_json::to_string_pretty(&manifest).expect("Could not serialize manifest")
+        ),
+        headers,
+    );
+}
+
+/// Encodes a sample the same way `rmp-serde` serializes it in the Rust
+/// runtimes and plugins: structs are encoded as maps, and enums use the
+/// representation given by their Serde attributes.
+fn encode_sample(sample: &Sample) -> Result<Value, String> {
+    let value = match sample {
+        Sample::Array(primitive, len) => Value::Array(vec![encode_primitive(*primitive); *len]),
+        Sample::Container(_, item) | Sample::Option(item) => encode_sample(item)?,
+        Sample::List(item) => Value::Array(vec![encode_sample(item)?; FIXTURE_SIZE]),
+        Sample::Map(key, value) => {
+            let value = encode_sample(value)?;
+            Value::Map(
+                (0..FIXTURE_SIZE)
+                    .map(|i| {
+                        let key = match key {
+                            MapKey::Integer(_) => Value::from(i as u64),
+                            MapKey::String => Value::from(i.to_string()),
+                        };
+                        (key, value.clone())
+                    })
+                    .collect(),
+            )
+        }
+        Sample::Pair(first, second) => {
+            Value::Array(vec![encode_primitive(*first), encode_primitive(*second)])
+        }
+        Sample::Primitive(primitive) => encode_primitive(*primitive),
+        Sample::String => Value::from("x".repeat(FIXTURE_SIZE)),
+        Sample::Struct(sample) => encode_struct_sample(sample)?,
+        Sample::Unit => Value::Nil,
+        Sample::Variant(sample) => encode_variant_sample(sample)?,
+    };
+    Ok(value)
+}
+
+fn encode_primitive(primitive: Primitive) -> Value {
+    match primitive {
+        Primitive::Bool => Value::Boolean(true),
+        Primitive::F32 => Value::F32(1.5),
+        Primitive::F64 => Value::F64(1.5),
+        _ => Value::from(1),
+    }
+}
+
+fn encode_struct_sample(sample: &StructSample) -> Result<Value, String> {
+    match sample.fields.as_slice() {
+        [field] if sample.is_tuple => encode_sample(&field.value),
+        fields if sample.is_tuple => Ok(Value::Array(
+            fields
+                .iter()
+                .map(|field| encode_sample(&field.value))
+                .collect::<Result<_, _>>()?,
+        )),
+        _ => Ok(Value::Map(encode_struct_entries(sample)?)),
+    }
+}
+
+/// Encodes the entries of a struct map, with the entries of flattened fields
+/// inlined.
+fn encode_struct_entries(sample: &StructSample) -> Result<Vec<(Value, Value)>, String> {
+    let mut entries = Vec::new();
+    for field in &sample.fields {
+        if field.flatten {
+            match encode_sample(&field.value)? {
+                Value::Map(flattened) => entries.extend(flattened),
+                _ => {
+                    return Err(format!(
+                        "flattened field `{}` of `{}` is not a map",
+                        field.rust_name, sample.rust_path
+                    ))
+                }
+            }
+        } else {
+            entries.push((
+                Value::from(field.serialized_name.as_str()),
+                encode_sample(&field.value)?,
+            ));
+        }
+    }
+    Ok(entries)
+}
+
+fn encode_variant_sample(sample: &VariantSample) -> Result<Value, String> {
+    let options = &sample.options;
+    let name = Value::from(sample.serialized_name.as_str());
+    let value = match &sample.value {
+        VariantValue::Unit(_) => {
+            return Ok(match (&options.tag_prop_name, options.untagged) {
+                (_, true) => Value::Nil,
+                (Some(tag), false) => Value::Map(vec![(Value::from(tag.as_str()), name)]),
+                (None, false) => name,
+            });
+        }
+        VariantValue::Struct(sample) => Value::Map(encode_struct_entries(sample)?),
+        VariantValue::Tuple(_, items) => match items.as_slice() {
+            [item] => encode_sample(item)?,
+            items => Value::Array(items.iter().map(encode_sample).collect::<Result<_, _>>()?),
+        },
+    };
+
+    if options.untagged {
+        return Ok(value);
+    }
+
+    match (&options.tag_prop_name, &options.content_prop_name) {
+        (Some(tag), Some(content)) => Ok(Value::Map(vec![
+            (Value::from(tag.as_str()), name),
+            (Value::from(content.as_str()), value),
+        ])),
+        (Some(tag), None) => match value {
+            Value::Map(entries) => {
+                let mut tagged_entries = vec![(Value::from(tag.as_str()), name)];
+                tagged_entries.extend(entries);
+                Ok(Value::Map(tagged_entries))
+            }
+            _ => Err(format!(
+                "variant `{}` of an internally tagged enum is not a map",
+                sample.serialized_name
+            )),
+        },
+        (None, _) => Ok(Value::Map(vec![(name, value)])),
+    }
+}
@@ -0,0 +1,215 @@
+use super::{Fixture, FIXTURE_SIZE};
+use crate::{
+    generators::{
+        benches::rust::format_sample,
+        rust_plugin::{format_ident, generate_type_bindings, uses_unknown_variants},
+        schema_hash::SchemaHashes,
+        validation::uses_regex_validation,
+        write_bindings_file,
+    },
+    types::{CargoDependency, Type, TypeMap},
+    FileHeaders, FixturesConfig,
+};
+use inflector::Inflector;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+
+/// Generates a crate with a test for every fixture, which checks that the
+/// fixture deserializes, and that serializing both the result and the expected
+/// value results in the exact same bytes.
+///
+/// The crate contains its own copy of the types of the runtimes, so it can be
+/// tested without depending on the crate in which the runtime is embedded.
+pub(super) fn generate_fixtures_crate(
+    fixtures: &[Fixture],
+    types: &TypeMap,
+    schema_hashes: &SchemaHashes,
+    config: &FixturesConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let src_path = format!("{path}/src");
+    let tests_path = format!("{path}/tests");
+    fs::create_dir_all(&src_path).expect("Could not create source directory");
+    fs::create_dir_all(&tests_path).expect("Could not create tests directory");
+
+    generate_type_bindings(types, schema_hashes, headers, &src_path);
+    generate_cargo_file(types, config, headers, path);
+    generate_lib_file(headers, &src_path);
+    generate_tests_file(fixtures, types, config, headers, &tests_path);
+}
+
+const FIXTURES_HEADER: &str = "// ============================================= //
+// Fixture tests for the Rust bindings           //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //";
+
+fn generate_cargo_file(
+    types: &TypeMap,
+    config: &FixturesConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let mut support_features = BTreeSet::new();
+    if uses_regex_validation(types) {
+        support_features.insert("validation");
+    }
+    if uses_unknown_variants(types) {
+        support_features.insert("non-exhaustive");
+    }
+
+    let mut dependencies = BTreeMap::from([
+        (
+            "fp-bindgen-support",
+            CargoDependency::with_version_and_features(env!("CARGO_PKG_VERSION"), support_features),
+        ),
+        ("rmp-serde", CargoDependency::with_version("1.0")),
+        (
+            "serde",
+            CargoDependency::with_version_and_features("1.0", BTreeSet::from(["derive"])),
+        ),
+    ]);
+
+    // Inject dependencies from custom types, followed by those passed through
+    // the config:
+    let custom_dependencies = types.values().flat_map(|ty| match ty {
+        Type::Custom(custom_type) => custom_type.rs_dependencies.iter().collect(),
+        _ => Vec::new(),
+    });
+    for (name, dependency) in custom_dependencies.chain(config.dependencies.iter()) {
+        let dependency = match dependencies.remove(name) {
+            Some(existing_dependency) => existing_dependency.merge_or_replace_with(dependency),
+            None => dependency.clone(),
+        };
+        dependencies.insert(name, dependency);
+    }
+
+    write_bindings_file(
+        format!("{path}/Cargo.toml"),
+        format!(
+            "[package]
+name = \"{}\"
+version = \"0.0.0\"
+publish = false
+edition = \"2021\"
+
+[dependencies]
+{}
+
+# The crate is only used for running its tests, so it doesn't need to be part
+# of the workspace of the crate in which it is generated.
+[workspace]
+",
+            config.name,
+            dependencies
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        headers,
+    );
+}
+
+fn generate_lib_file(headers: &FileHeaders, path: &str) {
+    write_bindings_file(
+        format!("{path}/lib.rs"),
+        format!(
+            "{FIXTURES_HEADER}
+pub mod types;
+
+use serde::{{de::DeserializeOwned, Serialize}};
+
+/// The number of characters in strings, and the number of items in lists and
+/// maps, of the fixtures.
+pub const FIXTURE_SIZE: usize = {FIXTURE_SIZE};
+
+/// Checks that the fixture deserializes, and that serializing both the result
+/// and the expected value reproduces the fixture exactly, using the same
+/// MessagePack configuration as the Rust runtimes.
+///
+/// Values are compared by their serialization, because types from other crates
+/// do not necessarily implement `PartialEq`.
+pub fn check_fixture<T>(fixture: &[u8], expected: T)
+where
+    T: DeserializeOwned + Serialize,
+{{
+    let mut deserializer = rmp_serde::Deserializer::from_read_ref(fixture).with_human_readable();
+    let value = T::deserialize(&mut deserializer).expect(\"Could not deserialize fixture\");
+    assert_eq!(serialize(&value), fixture, \"Deserialized value does not match fixture\");
+    assert_eq!(serialize(&expected), fixture, \"Expected value does not match fixture\");
+}}
+
+fn serialize<T: Serialize>(value: &T) -> Vec<u8> {{
+    let mut buffer = Vec::new();
+    let mut serializer = rmp_serde::Serializer::new(&mut buffer)
+        .with_struct_map()
+        .with_human_readable();
+    value
+        .serialize(&mut serializer)
+        .expect(\"Could not serialize value\");
+    buffer
+}}
+"
+        ),
+        headers,
+    );
+}
+
+fn generate_tests_file(
+    fixtures: &[Fixture],
+    types: &TypeMap,
+    config: &FixturesConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let tests = fixtures
+        .iter()
+        .map(|fixture| {
+            let size = if fixture.sample.depends_on_size() {
+                "    let size = FIXTURE_SIZE;\n"
+            } else {
+                ""
+            };
+            format!(
+                "#[test]
+fn fixture_{name}() {{
+{size}    check_fixture::<{ty}>(
+        include_bytes!(\"../fixtures/{file}\"),
+        {value},
+    );
+}}",
+                name = fixture.ty.name.to_snake_case(),
+                ty = format_ident(&fixture.ty, types),
+                file = fixture.file,
+                value = format_sample(&fixture.sample)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut imports = vec!["check_fixture", "types::*"];
+    if fixtures
+        .iter()
+        .any(|fixture| fixture.sample.depends_on_size())
+    {
+        imports.push("FIXTURE_SIZE");
+    }
+
+    write_bindings_file(
+        format!("{path}/fixtures.rs"),
+        format!(
+            "{FIXTURES_HEADER}
+use {crate_name}::{{{imports}}};
+
+{tests}
+",
+            crate_name = config.name.replace('-', "_"),
+            imports = imports.join(", "),
+            tests = tests.join("\n\n")
+        ),
+        headers,
+    );
+}
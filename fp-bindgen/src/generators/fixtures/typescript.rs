@@ -0,0 +1,136 @@
+use super::{Fixture, FIXTURE_SIZE};
+use crate::{
+    generators::{benches::typescript::format_sample, write_bindings_file},
+    FileHeaders, FixturesConfig,
+};
+
+/// Generates a script that decodes the fixtures the same way the TypeScript
+/// runtime decodes the values it receives from a plugin, and compares them
+/// with the values that the TypeScript types describe.
+///
+/// The script does not read the fixtures itself, so it can be used from both
+/// Node.js and Deno.
+pub(super) fn generate_fixtures_script(
+    fixtures: &[Fixture],
+    config: &FixturesConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let expected_values = fixtures
+        .iter()
+        .map(|fixture| {
+            format!(
+                "        \"{}\": {},",
+                fixture.file,
+                format_sample(&fixture.sample, true)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let contents = format!(
+        "// ============================================= //
+// Fixture tests for the TypeScript runtime      //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {{ decode }} from \"{msgpack_module}\";
+
+/**
+ * The number of characters in strings, and the number of items in lists and
+ * maps, of the fixtures.
+ */
+export const FIXTURE_SIZE = {FIXTURE_SIZE};
+
+/**
+ * A fixture that could not be decoded, or that was decoded to a different
+ * value than expected.
+ */
+export type FixtureFailure = {{
+    fixture: string;
+    expected: unknown;
+    actual?: unknown;
+    error?: unknown;
+}};
+
+/**
+ * The values that the fixtures should decode to, by the name of their file.
+ */
+function createExpectedValues(size: number): Record<string, unknown> {{
+    return {{
+{expected_values}
+    }};
+}}
+
+/**
+ * Decodes every fixture and compares it with the value it should decode to.
+ *
+ * @param readFixture Reads the fixture with the given file name from the
+ *                    `fixtures` directory.
+ * @returns The fixtures that could not be decoded, or that were decoded to a
+ *          different value than expected.
+ */
+export function checkFixtures(readFixture: (file: string) => Uint8Array): FixtureFailure[] {{
+    const failures: FixtureFailure[] = [];
+    for (const [fixture, expected] of Object.entries(createExpectedValues(FIXTURE_SIZE))) {{
+        try {{
+            const actual = decode(readFixture(fixture));
+            if (!isEqual(actual, expected)) {{
+                failures.push({{ fixture, expected, actual }});
+            }}
+        }} catch (error) {{
+            failures.push({{ fixture, expected, error }});
+        }}
+    }}
+    return failures;
+}}
+
+/**
+ * Compares two values structurally. Typed arrays are compared as regular
+ * arrays, because MessagePack arrays are always decoded as such.
+ */
+function isEqual(actual: unknown, expected: unknown): boolean {{
+    if (ArrayBuffer.isView(actual) || ArrayBuffer.isView(expected)) {{
+        return isEqual(fromTypedArray(actual), fromTypedArray(expected));
+    }}
+
+    if (Array.isArray(actual) && Array.isArray(expected)) {{
+        if (actual.length !== expected.length) {{
+            return false;
+        }}
+        for (let i = 0; i < expected.length; i++) {{
+            if (!isEqual(actual[i], expected[i])) {{
+                return false;
+            }}
+        }}
+        return true;
+    }}
+    if (isObject(actual) && isObject(expected)) {{
+        const keys = Object.keys(expected);
+        if (Object.keys(actual).length !== keys.length) {{
+            return false;
+        }}
+        for (const key of keys) {{
+            if (!(key in actual) || !isEqual(actual[key], expected[key])) {{
+                return false;
+            }}
+        }}
+        return true;
+    }}
+    return Object.is(actual, expected);
+}}
+
+function fromTypedArray(value: unknown): unknown {{
+    return ArrayBuffer.isView(value) ? Array.from(value as unknown as ArrayLike<unknown>) : value;
+}}
+
+function isObject(value: unknown): value is Record<string, unknown> {{
+    return typeof value === \"object\" && value !== null && !Array.isArray(value);
+}}
+",
+        msgpack_module = config.msgpack_module,
+        expected_values = expected_values.join("\n"),
+    );
+
+    write_bindings_file(format!("{path}/fixtures.ts"), contents, headers);
+}
@@ -0,0 +1,293 @@
+use std::{
+    cell::RefCell,
+    env,
+    ffi::OsString,
+    fs,
+    io::{self, Write},
+    path::Path,
+    process::{Command, Output, Stdio},
+    thread,
+};
+
+/// Determines how the generated files are formatted.
+///
+/// Rust files are formatted using `rustfmt`, which is looked up through the
+/// `RUSTFMT` environment variable, or on the `PATH` otherwise. Setting
+/// `RUSTFMT` to an empty string disables it. TypeScript and JavaScript files
+/// are written as they are generated, unless an external formatter, such as
+/// Prettier, is configured for them.
+///
+/// ```ignore
+/// let formatting = Formatting::new()
+///     .with_rustfmt_edition("2021")
+///     .with_ts_formatter(&["npx", "prettier", "--write"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Formatting {
+    rustfmt_edition: String,
+    missing_formatter: MissingFormatter,
+    ts_formatter: Option<Vec<String>>,
+}
+
+impl Default for Formatting {
+    fn default() -> Self {
+        Self {
+            rustfmt_edition: "2018".to_owned(),
+            missing_formatter: MissingFormatter::default(),
+            ts_formatter: None,
+        }
+    }
+}
+
+impl Formatting {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the edition of Rust for which `rustfmt` formats the generated
+    /// Rust files. Defaults to `"2018"`.
+    pub fn with_rustfmt_edition(mut self, edition: &str) -> Self {
+        self.rustfmt_edition = edition.to_owned();
+        self
+    }
+
+    /// Sets what happens if a formatter cannot be found. See
+    /// [`MissingFormatter`].
+    pub fn with_missing_formatter(mut self, missing_formatter: MissingFormatter) -> Self {
+        self.missing_formatter = missing_formatter;
+        self
+    }
+
+    /// Sets the command that formats the generated TypeScript and JavaScript
+    /// files, such as `["npx", "prettier", "--write"]`.
+    ///
+    /// The command is run once all files of the bindings have been written,
+    /// with the paths of the files appended to its arguments, and is expected
+    /// to format them in place.
+    pub fn with_ts_formatter(mut self, command: &[&str]) -> Self {
+        self.ts_formatter = Some(command.iter().map(|arg| arg.to_string()).collect());
+        self
+    }
+}
+
+/// Determines what happens if a formatter cannot be found, for instance
+/// because `rustfmt` is not installed in the build environment.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MissingFormatter {
+    /// The files are written without being formatted, and a warning is
+    /// printed.
+    #[default]
+    Skip,
+
+    /// Generating the bindings fails.
+    Fail,
+}
+
+#[derive(Default)]
+struct FormattingState {
+    formatting: Formatting,
+
+    /// The command with which `rustfmt` is invoked, once it has been looked
+    /// up, or `None` if it could not be found.
+    rustfmt: Option<Option<OsString>>,
+
+    /// The TypeScript and JavaScript files that have been written, which are
+    /// passed to the TypeScript formatter.
+    ts_files: Vec<String>,
+}
+
+thread_local! {
+    /// The formatting of the bindings that are being generated on this thread,
+    /// which is set for the duration of `generate_bindings()`.
+    static FORMATTING: RefCell<FormattingState> = RefCell::new(FormattingState::default());
+}
+
+/// Sets the formatting of the bindings that are about to be generated.
+pub(crate) fn start_formatting(formatting: Formatting) {
+    FORMATTING.with(|state| {
+        state.replace(FormattingState {
+            formatting,
+            ..FormattingState::default()
+        })
+    });
+}
+
+/// Runs the TypeScript formatter on the files that have been written, if any,
+/// and resets the formatting.
+pub(crate) fn finish_formatting() {
+    let state = FORMATTING.with(|state| state.take());
+    let command = match state.formatting.ts_formatter {
+        Some(command) if !state.ts_files.is_empty() => command,
+        _ => return,
+    };
+
+    let (program, args) = command
+        .split_first()
+        .expect("The TypeScript formatter command is empty");
+    match Command::new(program)
+        .args(args)
+        .args(&state.ts_files)
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        Ok(status) => panic!(
+            "TypeScript formatter `{}` failed with {status}",
+            command.join(" ")
+        ),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            report_missing_formatter(program, state.formatting.missing_formatter)
+        }
+        Err(error) => panic!(
+            "Could not run TypeScript formatter `{}`: {}",
+            program, error
+        ),
+    }
+}
+
+/// Keeps track of a file that has been written, so that it is formatted by
+/// the TypeScript formatter if it is a TypeScript or JavaScript file.
+pub(crate) fn track_written_file(file_path: &str) {
+    let is_ts_file = matches!(
+        Path::new(file_path)
+            .extension()
+            .and_then(|ext| ext.to_str()),
+        Some("ts" | "js")
+    );
+    if is_ts_file {
+        FORMATTING.with(|state| state.borrow_mut().ts_files.push(file_path.to_owned()));
+    }
+}
+
+/// Formats the given Rust source using `rustfmt`.
+///
+/// If `rustfmt` cannot be found, the source is returned as is, unless the
+/// formatting is configured to fail in that case. If `rustfmt` fails, the
+/// generated source is invalid, so that always panics.
+pub(crate) fn rustfmt(source: String) -> String {
+    let (rustfmt, edition) = FORMATTING.with(|state| {
+        let mut state = state.borrow_mut();
+        if state.rustfmt.is_none() {
+            let rustfmt = find_rustfmt();
+            if rustfmt.is_none() {
+                report_missing_formatter("rustfmt", state.formatting.missing_formatter);
+            }
+            state.rustfmt = Some(rustfmt);
+        }
+        (
+            state.rustfmt.clone().flatten(),
+            state.formatting.rustfmt_edition.clone(),
+        )
+    });
+    let rustfmt = match rustfmt {
+        Some(rustfmt) => rustfmt,
+        None => return source,
+    };
+
+    let output = run_rustfmt(&rustfmt, &edition, source)
+        .unwrap_or_else(|error| panic!("Could not run rustfmt: {}", error));
+    if !output.status.success() {
+        panic!(
+            "Could not format generated Rust code: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    String::from_utf8(output.stdout).expect("rustfmt returned invalid UTF-8")
+}
+
+/// Returns the command with which `rustfmt` can be invoked, if it is
+/// installed.
+fn find_rustfmt() -> Option<OsString> {
+    let rustfmt = env::var_os("RUSTFMT").unwrap_or_else(|| OsString::from("rustfmt"));
+    if rustfmt.is_empty() {
+        return None;
+    }
+
+    // The `rustfmt` proxy of rustup exists even if the component is not
+    // installed for the toolchain, in which case it exits with an error:
+    let is_installed = Command::new(&rustfmt)
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+    is_installed.then_some(rustfmt)
+}
+
+fn run_rustfmt(rustfmt: &OsString, edition: &str, source: String) -> io::Result<Output> {
+    // An empty config keeps the output independent of any `rustfmt.toml` in
+    // the directory from which the bindings are generated:
+    let config_dir = tempfile::tempdir()?;
+    fs::write(config_dir.path().join("rustfmt.toml"), "")?;
+
+    let mut child = Command::new(rustfmt)
+        .arg(format!("--edition={edition}"))
+        .arg("--config-path")
+        .arg(config_dir.path())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Writing from another thread avoids a deadlock when the output fills the
+    // pipe before all input has been written. If rustfmt exits before reading
+    // all input, its exit status reports why:
+    let mut stdin = child.stdin.take().expect("stdin of rustfmt is piped");
+    let writer = thread::spawn(move || {
+        let _ = stdin.write_all(source.as_bytes());
+    });
+    let output = child.wait_with_output()?;
+    let _ = writer.join();
+    Ok(output)
+}
+
+fn report_missing_formatter(formatter: &str, missing_formatter: MissingFormatter) {
+    match missing_formatter {
+        MissingFormatter::Skip => println!(
+            "WARNING: `{formatter}` could not be found, so the generated files are not \
+            formatted with it."
+        ),
+        MissingFormatter::Fail => panic!("`{}` could not be found", formatter),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MISSING_COMMAND: &str = "fp-bindgen-missing-formatter";
+
+    #[test]
+    fn tracks_ts_files_only() {
+        start_formatting(Formatting::new());
+        for file_path in [
+            "bindings/index.ts",
+            "bindings/index.d.ts",
+            "bindings/lib.rs",
+        ] {
+            track_written_file(file_path);
+        }
+        let ts_files = FORMATTING.with(|state| state.take().ts_files);
+        assert_eq!(ts_files, ["bindings/index.ts", "bindings/index.d.ts"]);
+    }
+
+    #[test]
+    fn skips_missing_ts_formatter() {
+        start_formatting(Formatting::new().with_ts_formatter(&[MISSING_COMMAND, "--write"]));
+        track_written_file("bindings/index.ts");
+        finish_formatting();
+    }
+
+    #[test]
+    #[should_panic(expected = "could not be found")]
+    fn fails_on_missing_ts_formatter() {
+        start_formatting(
+            Formatting::new()
+                .with_ts_formatter(&[MISSING_COMMAND, "--write"])
+                .with_missing_formatter(MissingFormatter::Fail),
+        );
+        track_written_file("bindings/index.ts");
+        finish_formatting();
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    functions::FunctionList,
+    generators::schema_hash::SchemaHashes,
+    types::{Type, TypeIdent, TypeMap},
+    FileHeaders, FuzzConfig,
+};
+use inflector::Inflector;
+use std::{collections::BTreeMap, fs};
+
+mod rust;
+mod typescript;
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    schema_hashes: &SchemaHashes,
+    config: FuzzConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    fs::create_dir_all(path).expect("Could not create output directory");
+
+    let targets = collect_targets(&import_functions, &export_functions, &types);
+
+    rust::generate_fuzz_crate(&targets, &types, schema_hashes, &config, headers, path);
+    typescript::generate_fuzz_script(&targets, &types, &config, headers, path);
+}
+
+/// A type that the runtimes deserialize from the data they receive from a
+/// plugin, which makes it a target for fuzzing.
+pub(crate) struct FuzzTarget {
+    /// The name of the target, derived from the type.
+    pub name: String,
+    pub ty: TypeIdent,
+    /// The functions through which the type is received from the plugin.
+    pub functions: Vec<String>,
+}
+
+/// Collects the types of the arguments of imported functions and of the return
+/// values of exported functions, which are the values that are deserialized by
+/// the runtimes. Primitives are only deserialized when they are the result of
+/// an async export.
+fn collect_targets(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) -> Vec<FuzzTarget> {
+    // Targets are keyed by their full type, because identifiers only compare
+    // by name:
+    let mut targets = BTreeMap::<String, FuzzTarget>::new();
+    let mut add_target = |ty: &TypeIdent, function: &str| {
+        if matches!(types.get(ty), Some(Type::Unit)) {
+            return;
+        }
+
+        let target = targets.entry(ty.to_string()).or_insert_with(|| FuzzTarget {
+            name: format_target_name(ty),
+            ty: ty.clone(),
+            functions: Vec::new(),
+        });
+        if !target.functions.iter().any(|name| name == function) {
+            target.functions.push(function.to_owned());
+        }
+    };
+
+    for function in import_functions.iter() {
+        // Values of generic types are decoded by the implementation:
+        for arg in function
+            .args
+            .iter()
+            .filter(|arg| !arg.ty.is_primitive() && !function.uses_generic_params(&arg.ty))
+        {
+            add_target(&arg.ty, &function.name);
+        }
+    }
+    for function in export_functions.iter() {
+        if let Some(ty) = &function.return_type {
+            if function.is_async || !(ty.is_primitive() || ty.is_packed_pair()) {
+                add_target(ty, &function.name);
+            }
+        }
+    }
+
+    targets.into_values().collect()
+}
+
+/// Formats the name of the target for a type, such as `result_bytes_string`
+/// for `Result<Bytes, String>`.
+fn format_target_name(ty: &TypeIdent) -> String {
+    ty.to_string()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            if part.chars().any(|c| c.is_ascii_uppercase()) {
+                part.to_snake_case()
+            } else {
+                part.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("_")
+}
@@ -0,0 +1,195 @@
+use super::FuzzTarget;
+use crate::{
+    generators::{
+        rust_plugin::{format_ident, generate_type_bindings, uses_unknown_variants},
+        schema_hash::SchemaHashes,
+        validation::uses_regex_validation,
+        write_bindings_file,
+    },
+    types::{CargoDependency, Type, TypeMap},
+    FileHeaders, FuzzConfig,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+
+/// Generates a crate with `cargo-fuzz` targets, which deserialize arbitrary
+/// bytes into the types that the Rust runtimes receive from a plugin.
+///
+/// The crate contains its own copy of the types of the runtimes, so it can be
+/// fuzzed without depending on the crate in which the runtime is embedded.
+pub(super) fn generate_fuzz_crate(
+    targets: &[FuzzTarget],
+    types: &TypeMap,
+    schema_hashes: &SchemaHashes,
+    config: &FuzzConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let src_path = format!("{path}/src");
+    let targets_path = format!("{path}/fuzz_targets");
+    fs::create_dir_all(&src_path).expect("Could not create source directory");
+    fs::create_dir_all(&targets_path).expect("Could not create targets directory");
+
+    generate_type_bindings(types, schema_hashes, headers, &src_path);
+    generate_cargo_file(targets, types, config, headers, path);
+    generate_lib_file(targets, types, headers, &src_path);
+
+    let crate_name = config.name.replace('-', "_");
+    for target in targets {
+        write_bindings_file(
+            format!("{targets_path}/{}.rs", target.name),
+            format!(
+                "{header}
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {{
+    {crate_name}::fuzz_{name}(data);
+}});
+",
+                header = FUZZ_HEADER,
+                name = target.name
+            ),
+            headers,
+        );
+    }
+}
+
+const FUZZ_HEADER: &str = "// ============================================= //
+// Fuzz targets for the Rust runtimes            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //";
+
+fn generate_cargo_file(
+    targets: &[FuzzTarget],
+    types: &TypeMap,
+    config: &FuzzConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let mut support_features = BTreeSet::new();
+    if uses_regex_validation(types) {
+        support_features.insert("validation");
+    }
+    if uses_unknown_variants(types) {
+        support_features.insert("non-exhaustive");
+    }
+
+    let mut dependencies = BTreeMap::from([
+        (
+            "fp-bindgen-support",
+            CargoDependency::with_version_and_features(env!("CARGO_PKG_VERSION"), support_features),
+        ),
+        ("libfuzzer-sys", CargoDependency::with_version("0.4")),
+        ("rmp-serde", CargoDependency::with_version("1.0")),
+        (
+            "serde",
+            CargoDependency::with_version_and_features("1.0", BTreeSet::from(["derive"])),
+        ),
+    ]);
+
+    // Inject dependencies from custom types, followed by those passed through
+    // the config:
+    let custom_dependencies = types.values().flat_map(|ty| match ty {
+        Type::Custom(custom_type) => custom_type.rs_dependencies.iter().collect(),
+        _ => Vec::new(),
+    });
+    for (name, dependency) in custom_dependencies.chain(config.dependencies.iter()) {
+        let dependency = match dependencies.remove(name) {
+            Some(existing_dependency) => existing_dependency.merge_or_replace_with(dependency),
+            None => dependency.clone(),
+        };
+        dependencies.insert(name, dependency);
+    }
+
+    let bins = targets
+        .iter()
+        .map(|target| {
+            format!(
+                "[[bin]]
+name = \"{name}\"
+path = \"fuzz_targets/{name}.rs\"
+test = false
+doc = false
+bench = false
+",
+                name = target.name
+            )
+        })
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{path}/Cargo.toml"),
+        format!(
+            "[package]
+name = \"{}\"
+version = \"0.0.0\"
+publish = false
+edition = \"2021\"
+
+[package.metadata]
+cargo-fuzz = true
+
+[dependencies]
+{}
+
+{}",
+            config.name,
+            dependencies
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            bins.join("\n")
+        ),
+        headers,
+    );
+}
+
+fn generate_lib_file(targets: &[FuzzTarget], types: &TypeMap, headers: &FileHeaders, path: &str) {
+    let fuzz_functions = targets
+        .iter()
+        .map(|target| {
+            let functions = target
+                .functions
+                .iter()
+                .map(|function| format!("`{function}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "/// Deserializes `{ty}`, as received through {functions}.
+pub fn fuzz_{name}(data: &[u8]) {{
+    deserialize::<{ty}>(data);
+}}",
+                name = target.name,
+                ty = format_ident(&target.ty, types)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{path}/lib.rs"),
+        format!(
+            "{FUZZ_HEADER}
+pub mod types;
+
+use serde::Deserialize;
+use types::*;
+
+/// Deserializes the given bytes the same way the Rust runtimes deserialize the
+/// values they receive from a plugin. Failing to deserialize is expected, but
+/// panicking is not.
+fn deserialize<'a, T: Deserialize<'a>>(data: &'a [u8]) {{
+    let mut deserializer = rmp_serde::Deserializer::from_read_ref(data).with_human_readable();
+    let _ = T::deserialize(&mut deserializer);
+}}
+
+{}
+",
+            fuzz_functions.join("\n\n")
+        ),
+        headers,
+    );
+}
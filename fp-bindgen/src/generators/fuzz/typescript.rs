@@ -0,0 +1,196 @@
+use super::FuzzTarget;
+use crate::{
+    generators::{
+        benches::{create_sample, typescript::format_sample},
+        ts_runtime::{format_plain_primitive_or_ident, format_validation},
+        validation::collect_validated_structs,
+        write_bindings_file,
+    },
+    types::TypeMap,
+    FileHeaders, FuzzConfig, TsInt64Encoding,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Generates a script that fuzzes the deserialization of the values that the
+/// TypeScript runtime receives from a plugin. It is meant to be placed next to
+/// the `index.ts` and `types.ts` of the runtime.
+pub(super) fn generate_fuzz_script(
+    targets: &[FuzzTarget],
+    types: &TypeMap,
+    config: &FuzzConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let validated_structs = collect_validated_structs(types);
+
+    let mut validators = BTreeSet::new();
+    let mut fuzz_targets = Vec::new();
+    let mut seeds = Vec::new();
+    for target in targets {
+        let validation = format_validation(&target.ty, "value", types, &validated_structs, 0);
+        let body = if validation.is_empty() {
+            "decode(data);".to_owned()
+        } else {
+            validators.extend(
+                validated_structs
+                    .iter()
+                    .map(|ident| format!("validate{}", ident.name))
+                    .filter(|name| validation.iter().any(|line| line.contains(name.as_str()))),
+            );
+            format!(
+                "const value = decode(data) as {};\n{}",
+                format_plain_primitive_or_ident(&target.ty, types, TsInt64Encoding::BigInt),
+                validation.join("\n")
+            )
+        };
+        fuzz_targets.push(format!(
+            "    {}: (data) => {{\n{}\n    }},",
+            target.name,
+            body.split('\n')
+                .map(|line| format!("        {line}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ));
+
+        // Targets for which no sample can be created are only fuzzed with
+        // random bytes:
+        if let Ok(sample) = create_sample(&target.ty, types, &BTreeMap::new(), &mut Vec::new()) {
+            seeds.push(format!(
+                "        {}: encode({}),",
+                target.name,
+                format_sample(&sample, true)
+            ));
+        }
+    }
+
+    let extension = if config.msgpack_module.ends_with(".ts") {
+        ".ts"
+    } else {
+        ""
+    };
+    let uses_validation = !validators.is_empty();
+    let validation_import = if uses_validation {
+        let mut names = validators.into_iter().collect::<Vec<_>>();
+        names.push("ValidationError".to_owned());
+        format!(
+            "\nimport type * as types from \"./types{extension}\";\nimport {{ {} }} from \"./validation{extension}\";\n",
+            names.join(", ")
+        )
+    } else {
+        String::new()
+    };
+    let expected_errors = if uses_validation {
+        "error instanceof DecodeError || error instanceof RangeError ||\n        error instanceof ValidationError"
+    } else {
+        "error instanceof DecodeError || error instanceof RangeError"
+    };
+
+    let contents = format!(
+        "// ============================================= //
+// Fuzz script for the TypeScript runtime        //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {{ decode, DecodeError, encode }} from \"{msgpack_module}\";
+{validation_import}
+/**
+ * Deserializes the given bytes the same way the TypeScript runtime
+ * deserializes the values it receives from a plugin.
+ */
+export type FuzzTarget = (data: Uint8Array) => void;
+
+/**
+ * An input for which a target threw an unexpected error.
+ */
+export type FuzzFailure = {{ target: string; input: Uint8Array; error: unknown }};
+
+/**
+ * The fuzz targets, by the name of the type they deserialize.
+ */
+export const FUZZ_TARGETS: Record<string, FuzzTarget> = {{
+{fuzz_targets}
+}};
+
+/**
+ * Encodes sample values for the targets, which are mutated to create inputs
+ * that get past the first few bytes.
+ */
+function createSeeds(size: number): Record<string, Uint8Array> {{
+    return {{
+{seeds}
+    }};
+}}
+
+/**
+ * Runs every target with random mutations of encoded sample values, as well as
+ * with random bytes.
+ *
+ * Errors from decoding and validating the input are expected, but any other
+ * error is reported as a failure, together with the input that caused it.
+ *
+ * @param iterations The number of inputs to run every target with.
+ * @param random Generates random numbers in the range [0, 1).
+ * @returns The inputs that caused unexpected errors.
+ */
+export function fuzz(iterations = 10_000, random: () => number = Math.random): FuzzFailure[] {{
+    const seeds = createSeeds(4);
+    const failures: FuzzFailure[] = [];
+    for (const [target, fuzzTarget] of Object.entries(FUZZ_TARGETS)) {{
+        const seed = seeds[target];
+        for (let i = 0; i < iterations; i++) {{
+            const input = seed && random() < 0.9
+                ? mutate(seed, random)
+                : randomBytes(Math.floor(random() * 64), random);
+            try {{
+                fuzzTarget(input);
+            }} catch (error) {{
+                if (!isExpectedError(error)) {{
+                    failures.push({{ target, input, error }});
+                }}
+            }}
+        }}
+    }}
+    return failures;
+}}
+
+function isExpectedError(error: unknown): boolean {{
+    return {expected_errors};
+}}
+
+/**
+ * Applies a few random byte-level mutations to the given input.
+ */
+function mutate(input: Uint8Array, random: () => number): Uint8Array {{
+    const bytes = Array.from(input);
+    const mutations = 1 + Math.floor(random() * 4);
+    for (let i = 0; i < mutations; i++) {{
+        const index = Math.floor(random() * (bytes.length + 1));
+        switch (Math.floor(random() * 4)) {{
+            case 0:
+                bytes[index] = Math.floor(random() * 256);
+                break;
+            case 1:
+                bytes.splice(index, 0, Math.floor(random() * 256));
+                break;
+            case 2:
+                bytes.splice(index, 1);
+                break;
+            default:
+                bytes.length = index;
+        }}
+    }}
+    return Uint8Array.from(bytes);
+}}
+
+function randomBytes(length: number, random: () => number): Uint8Array {{
+    return Uint8Array.from({{ length }}, () => Math.floor(random() * 256));
+}}
+",
+        msgpack_module = config.msgpack_module,
+        fuzz_targets = fuzz_targets.join("\n"),
+        seeds = seeds.join("\n"),
+    );
+
+    write_bindings_file(format!("{path}/fuzz.ts"), contents, headers);
+}
@@ -0,0 +1,95 @@
+mod types;
+
+use crate::{
+    functions::{Function, FunctionList},
+    generators::write_bindings_file,
+    types::{TypeIdent, TypeMap},
+    FileHeaders,
+};
+use inflector::Inflector;
+use types::{format_description, Direction, TypeCollector};
+
+const SCHEMA_HEADER: &str = "# ============================================= #
+# GraphQL schema for the plugin exports         #
+#                                               #
+# This file is generated. PLEASE DO NOT MODIFY. #
+# ============================================= #";
+
+/// Generates a GraphQL schema with a query for every export that is marked as
+/// `#[fp(read_only)]`, along with the types those queries use.
+pub(crate) fn generate_bindings(
+    export_functions: FunctionList,
+    types: TypeMap,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let mut collector = TypeCollector::new(&types);
+    let queries = export_functions
+        .iter()
+        .filter(|function| function.attrs.read_only)
+        .map(|function| format_query(function, &mut collector))
+        .collect::<Vec<_>>();
+    if queries.is_empty() {
+        panic!(
+            "A GraphQL schema requires at least one query, but none of the exports are marked \
+            as `#[fp(read_only)]`"
+        );
+    }
+
+    let definitions = std::iter::once(format!("type Query {{\n{}\n}}", queries.join("\n")))
+        .chain(collector.into_definitions())
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{path}/schema.graphql"),
+        format!("{SCHEMA_HEADER}\n\n{}\n", definitions.join("\n\n")),
+        headers,
+    );
+}
+
+/// Formats the query field for a read-only export.
+///
+/// Exports that return a `Result` resolve to the `Ok` value, since errors are
+/// reported through the `errors` of the GraphQL response instead.
+fn format_query(function: &Function, collector: &mut TypeCollector) -> String {
+    let args = function
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                format_name(arg.name_without_prefix()),
+                collector.type_ref(&arg.ty, Direction::Input)
+            )
+        })
+        .collect::<Vec<_>>();
+    let args = if args.is_empty() {
+        String::new()
+    } else {
+        format!("({})", args.join(", "))
+    };
+
+    let return_type = function
+        .return_type
+        .as_ref()
+        .expect("Read-only functions should have a return type");
+    let return_type = match return_type {
+        TypeIdent {
+            name, generic_args, ..
+        } if name == "Result" && generic_args.len() == 2 => &generic_args[0].0,
+        other => other,
+    };
+
+    format!(
+        "{}  {}{args}: {}",
+        format_description(&function.doc_lines, "  "),
+        format_name(&function.name),
+        collector.type_ref(return_type, Direction::Output)
+    )
+}
+
+/// Formats the name of a query or argument, which are camel-cased following
+/// GraphQL conventions.
+fn format_name(name: &str) -> String {
+    name.to_camel_case()
+}
@@ -0,0 +1,461 @@
+use crate::{
+    casing::Casing,
+    primitives::Primitive,
+    types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Whether a type is used as an argument or as a result, which determines
+/// whether structs are defined as input types or as object types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Direction {
+    Input,
+    Output,
+}
+
+/// Custom scalars for values that GraphQL's built-in scalars cannot
+/// represent.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Scalar {
+    BigInt,
+    Json,
+}
+
+impl Scalar {
+    fn name(self) -> &'static str {
+        match self {
+            Scalar::BigInt => "BigInt",
+            Scalar::Json => "JSON",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Scalar::BigInt => "An integer that doesn't fit in the 32 bits of `Int`.",
+            Scalar::Json => "A value that is passed as-is, in its JSON representation.",
+        }
+    }
+}
+
+/// A reference to a GraphQL type, such as `[String!]!`.
+pub(super) struct TypeRef {
+    name: String,
+    nullable: bool,
+}
+
+impl TypeRef {
+    fn non_null(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            nullable: false,
+        }
+    }
+
+    pub fn nullable(mut self) -> Self {
+        self.nullable = true;
+        self
+    }
+}
+
+impl std::fmt::Display for TypeRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.nullable {
+            f.write_str(&self.name)
+        } else {
+            write!(f, "{}!", self.name)
+        }
+    }
+}
+
+/// Converts protocol types to GraphQL types, while collecting the definitions
+/// of the object types, input types, enums and scalars they refer to.
+pub(super) struct TypeCollector<'a> {
+    types: &'a TypeMap,
+
+    /// Definitions by name. Definitions are inserted as `None` while they are
+    /// being formatted, so recursive types don't cause infinite recursion.
+    definitions: BTreeMap<String, Option<String>>,
+
+    scalars: BTreeSet<Scalar>,
+}
+
+impl<'a> TypeCollector<'a> {
+    pub fn new(types: &'a TypeMap) -> Self {
+        Self {
+            types,
+            definitions: BTreeMap::new(),
+            scalars: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the collected definitions, followed by the custom scalars.
+    pub fn into_definitions(self) -> Vec<String> {
+        let scalars = self.scalars.into_iter().map(|scalar| {
+            format!(
+                "{}scalar {}",
+                format_description(&[scalar.description().to_owned()], ""),
+                scalar.name()
+            )
+        });
+        self.definitions
+            .into_values()
+            .flatten()
+            .chain(scalars)
+            .collect()
+    }
+
+    /// Returns the GraphQL type for the given type.
+    ///
+    /// Structs with named fields are defined as object types when they are
+    /// used as a result, and as input types when they are used as an argument.
+    /// Types that GraphQL cannot describe, such as maps, tuples and enums with
+    /// data, use the `JSON` scalar.
+    pub fn type_ref(&mut self, ident: &TypeIdent, direction: Direction) -> TypeRef {
+        let ty = match self.types.get(ident) {
+            Some(ty) => ty,
+            None => return self.scalar(Scalar::Json), // Must be a generic.
+        };
+
+        match ty {
+            Type::Alias(_, target) => self.type_ref(target, direction),
+            Type::Array(primitive, _) => {
+                TypeRef::non_null(format!("[{}]", self.primitive(*primitive)))
+            }
+            Type::Container(name, _) => {
+                let item = self.type_ref(first_generic_arg(ident), direction);
+                if name == "Option" {
+                    item.nullable()
+                } else {
+                    item
+                }
+            }
+            Type::Custom(custom) => self.custom_type(custom),
+            Type::Enum(ty) if is_string_enum(ty) => self.enum_type(ty, ident),
+            Type::Enum(_) | Type::Map(_, _, _) | Type::Tuple(_) | Type::Unit => {
+                self.scalar(Scalar::Json)
+            }
+            Type::List(_, _) => {
+                let item = self.type_ref(first_generic_arg(ident), direction);
+                TypeRef::non_null(format!("[{item}]"))
+            }
+            Type::Primitive(primitive) => self.primitive(*primitive),
+            Type::String => TypeRef::non_null("String"),
+            Type::Struct(ty) if is_transparent_struct(ty) => match ty.fields.as_slice() {
+                [field] => {
+                    let item = substitute_generic_args(&field.ty, &ty.ident, ident);
+                    self.type_ref(&item, direction)
+                }
+                _ => self.scalar(Scalar::Json),
+            },
+            Type::Struct(ty) if ty.fields.is_empty() => self.scalar(Scalar::Json),
+            Type::Struct(ty) => self.struct_type(ty, ident, direction),
+        }
+    }
+
+    fn scalar(&mut self, scalar: Scalar) -> TypeRef {
+        self.scalars.insert(scalar);
+        TypeRef::non_null(scalar.name())
+    }
+
+    fn primitive(&mut self, primitive: Primitive) -> TypeRef {
+        match primitive {
+            Primitive::Bool => TypeRef::non_null("Boolean"),
+            Primitive::F32 | Primitive::F64 => TypeRef::non_null("Float"),
+            Primitive::I8 | Primitive::I16 | Primitive::I32 | Primitive::U8 | Primitive::U16 => {
+                TypeRef::non_null("Int")
+            }
+            Primitive::I64 | Primitive::U32 | Primitive::U64 => self.scalar(Scalar::BigInt),
+        }
+    }
+
+    /// Formats a custom type, based on the TypeScript type that describes its
+    /// serialized representation.
+    fn custom_type(&mut self, custom: &CustomType) -> TypeRef {
+        if let Some(primitive) = custom
+            .rs_ty
+            .strip_prefix("std::num::NonZero")
+            .and_then(|name| name.to_lowercase().parse::<Primitive>().ok())
+        {
+            return self.primitive(primitive);
+        }
+
+        match &custom.ts_declaration {
+            Some(declaration) if declaration.starts_with("string & ") => {
+                TypeRef::non_null("String")
+            }
+            Some(_) => self.scalar(Scalar::Json),
+            None => self.ts_type(&custom.ts_ty),
+        }
+    }
+
+    fn ts_type(&mut self, ts_ty: &str) -> TypeRef {
+        match ts_ty {
+            "bigint" => self.scalar(Scalar::BigInt),
+            "boolean" => TypeRef::non_null("Boolean"),
+            "number" => TypeRef::non_null("Float"),
+            "string" => TypeRef::non_null("String"),
+            _ => self.scalar(Scalar::Json),
+        }
+    }
+
+    /// Defines an enum whose variants are serialized as plain strings. If any
+    /// of the strings is not a valid GraphQL name, it is typed as a `String`
+    /// instead.
+    fn enum_type(&mut self, ty: &Enum, ident: &TypeIdent) -> TypeRef {
+        let values = ty
+            .variants
+            .iter()
+            .map(|variant| (get_variant_name(variant, &ty.options), variant))
+            .collect::<Vec<_>>();
+        if !values.iter().all(|(value, _)| is_enum_value(value)) {
+            return TypeRef::non_null("String");
+        }
+
+        let name = format_type_name(ident);
+        if !self.definitions.contains_key(&name) {
+            let values = values
+                .iter()
+                .map(|(value, variant)| {
+                    format!("{}  {value}", format_description(&variant.doc_lines, "  "))
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let definition = format!(
+                "{}enum {name} {{\n{values}\n}}",
+                format_description(&ty.doc_lines, "")
+            );
+            self.definitions.insert(name.clone(), Some(definition));
+        }
+        TypeRef::non_null(name)
+    }
+
+    fn struct_type(&mut self, ty: &Struct, ident: &TypeIdent, direction: Direction) -> TypeRef {
+        let (name, keyword) = match direction {
+            Direction::Input => (format!("{}Input", format_type_name(ident)), "input"),
+            Direction::Output => (format_type_name(ident), "type"),
+        };
+
+        if !self.definitions.contains_key(&name) {
+            self.definitions.insert(name.clone(), None);
+
+            let mut fields = Vec::new();
+            self.collect_fields(
+                &mut fields,
+                &ty.fields,
+                ty.options.field_casing,
+                &ty.ident,
+                ident,
+                direction,
+            );
+            let definition = format!(
+                "{}{keyword} {name} {{\n{}\n}}",
+                format_description(&ty.doc_lines, ""),
+                fields.join("\n")
+            );
+            self.definitions.insert(name.clone(), Some(definition));
+        }
+        TypeRef::non_null(name)
+    }
+
+    /// Collects the formatted fields, with the fields of flattened structs
+    /// inlined. Other flattened fields, such as maps, cannot be described in
+    /// GraphQL and are left out.
+    ///
+    /// Generic arguments in the field types are substituted using the
+    /// declaration and the identifier by which it is used.
+    fn collect_fields(
+        &mut self,
+        fields: &mut Vec<String>,
+        struct_fields: &[Field],
+        casing: Casing,
+        decl: &TypeIdent,
+        usage: &TypeIdent,
+        direction: Direction,
+    ) {
+        for field in struct_fields {
+            let ty = substitute_generic_args(&field.ty, decl, usage);
+            if field.attrs.flatten {
+                if let Some(Type::Struct(flattened)) = self.types.get(&ty) {
+                    if !is_transparent_struct(flattened) {
+                        self.collect_fields(
+                            fields,
+                            &flattened.fields,
+                            flattened.options.field_casing,
+                            &flattened.ident,
+                            &ty,
+                            direction,
+                        );
+                    }
+                }
+                continue;
+            }
+
+            let name = field.name.as_deref().unwrap_or_default();
+            let key = field
+                .attrs
+                .rename
+                .clone()
+                .unwrap_or_else(|| casing.format_string(get_variable_name(name)));
+            if !is_name(&key) {
+                panic!(
+                    "Field `{}` of type `{}` is serialized as `{}`, which is not a valid GraphQL \
+                    name",
+                    name, decl, key
+                );
+            }
+
+            let field_type = if field.attrs.has_custom_serializer() || field.attrs.ts_type.is_some()
+            {
+                // The wire shape of fields with custom (de)serializers can
+                // only be derived from their TypeScript type:
+                match field.attrs.ts_type.as_deref() {
+                    Some(ts_type) => self.ts_type(ts_type),
+                    None => self.scalar(Scalar::Json),
+                }
+            } else {
+                self.type_ref(&ty, direction)
+            };
+
+            // Fields may be omitted from results if they are skipped when
+            // serializing, and from arguments if they have a default:
+            let may_be_omitted = match direction {
+                Direction::Input => field.attrs.default.is_some(),
+                Direction::Output => field.attrs.skip_serializing_if.is_some(),
+            };
+            let field_type = if may_be_omitted {
+                field_type.nullable()
+            } else {
+                field_type
+            };
+
+            fields.push(format!(
+                "{}  {key}: {field_type}",
+                format_description(&field.doc_lines, "  ")
+            ));
+        }
+    }
+}
+
+/// Formats a description from the given doc lines, as a string that precedes
+/// the definition it describes. Returns an empty string if there is no
+/// description.
+pub(super) fn format_description(doc_lines: &[String], indent: &str) -> String {
+    let lines = doc_lines
+        .iter()
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(line)
+                .replace("\"\"\"", "\\\"\"\"")
+        })
+        .collect::<Vec<_>>();
+    let description = lines.join("\n");
+    match description.trim() {
+        "" => String::new(),
+        line if !line.contains('\n') => format!("{indent}\"\"\"{line}\"\"\"\n"),
+        _ => format!(
+            "{indent}\"\"\"\n{}\n{indent}\"\"\"\n",
+            lines
+                .iter()
+                .map(|line| if line.is_empty() {
+                    String::new()
+                } else {
+                    format!("{indent}{line}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim_matches('\n')
+        ),
+    }
+}
+
+/// Formats the name under which a type is defined.
+///
+/// GraphQL has no notion of generics, so every instantiation of a generic
+/// type is defined separately, such as `Point_f64` for `Point<f64>`.
+fn format_type_name(ident: &TypeIdent) -> String {
+    let name = match ident.array {
+        Some(size) => format!("{}_{}", ident.name, size),
+        None => ident.name.clone(),
+    };
+    std::iter::once(name)
+        .chain(
+            ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| format_type_name(arg)),
+        )
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Returns whether the given string is a valid GraphQL name.
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns whether the given string can be used as an enum value, which are
+/// names other than `true`, `false` and `null`.
+fn is_enum_value(value: &str) -> bool {
+    is_name(value) && !matches!(value, "true" | "false" | "null")
+}
+
+fn is_transparent_struct(ty: &Struct) -> bool {
+    !ty.fields.is_empty() && ty.fields.iter().all(|field| field.name.is_none())
+}
+
+/// Returns whether the given enum is serialized as a plain string. This is the
+/// case if all its variants are unit variants and it is not tagged.
+fn is_string_enum(ty: &Enum) -> bool {
+    ty.options.tag_prop_name.is_none()
+        && !ty.options.untagged
+        && !ty.variants.is_empty()
+        && ty
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.ty, Type::Unit))
+}
+
+fn get_variant_name(variant: &Variant, options: &EnumOptions) -> String {
+    match variant.attrs.rename.as_ref() {
+        Some(rename) => rename.to_owned(),
+        None => options
+            .variant_casing
+            .format_string(get_variable_name(&variant.name)),
+    }
+}
+
+fn get_variable_name(name: &str) -> &str {
+    name.strip_prefix("r#").unwrap_or(name)
+}
+
+fn first_generic_arg(ident: &TypeIdent) -> &TypeIdent {
+    let (arg, _) = ident
+        .generic_args
+        .first()
+        .expect("Identifier was expected to contain a generic argument");
+    arg
+}
+
+/// Replaces the generic parameters of a declaration in the given type with
+/// the arguments from the identifier by which the declaration is used.
+fn substitute_generic_args(ty: &TypeIdent, decl: &TypeIdent, usage: &TypeIdent) -> TypeIdent {
+    if ty.generic_args.is_empty() {
+        if let Some(index) = decl
+            .generic_args
+            .iter()
+            .position(|(param, _)| param.name == ty.name)
+        {
+            if let Some((arg, _)) = usage.generic_args.get(index) {
+                return arg.clone();
+            }
+        }
+    }
+
+    let mut ty = ty.clone();
+    for (arg, _) in ty.generic_args.iter_mut() {
+        *arg = substitute_generic_args(arg, decl, usage);
+    }
+    ty
+}
@@ -0,0 +1,191 @@
+//! Handling of the string fields that are annotated with `#[fp(interned)]`.
+//!
+//! Plugins register every distinct value of such a field with the runtime
+//! once, through the reserved `__fp_intern_string()` import, and then send the
+//! `u32` handle that the runtime assigned to it instead of the string. The
+//! Rust bindings (de)serialize the fields using
+//! `fp_bindgen_support::common::interning`, while the TypeScript runtime keeps
+//! an intern table of its own in `conversions.ts`.
+
+use crate::types::{Field, Type, TypeIdent, TypeMap};
+use std::collections::BTreeSet;
+
+/// The reserved import through which plugins register interned strings.
+pub(crate) const INTERN_STRING_IMPORT: &str = "__fp_intern_string";
+
+/// Checks that only the named string fields of structs without type
+/// parameters are interned.
+///
+/// Interned fields are resolved by the TypeScript runtime using the same
+/// converters as dates, which cannot reach into enum variants or generic
+/// arguments.
+pub(super) fn check_interned_fields(types: &TypeMap) {
+    for ty in types.values() {
+        match ty {
+            Type::Struct(ty) => {
+                for field in ty.fields.iter().filter(|field| field.attrs.interned) {
+                    let name = field.name.as_deref().unwrap_or("0");
+                    if !ty.ident.generic_args.is_empty() || field.name.is_none() {
+                        panic!(
+                            "Field `{}` of `{}` cannot be interned, because only the named \
+                            fields of structs without type parameters can be interned",
+                            name, ty.ident
+                        );
+                    }
+                    check_interned_field(field, &ty.ident, types);
+                }
+            }
+            Type::Enum(ty) => {
+                for variant in &ty.variants {
+                    if let Type::Struct(variant_ty) = &variant.ty {
+                        if let Some(field) =
+                            variant_ty.fields.iter().find(|field| field.attrs.interned)
+                        {
+                            panic!(
+                                "Field `{}` of variant `{}::{}` cannot be interned, because \
+                                only the fields of structs can be interned",
+                                field.name.as_deref().unwrap_or("0"),
+                                ty.ident,
+                                variant.name
+                            );
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_interned_field(field: &Field, owner: &TypeIdent, types: &TypeMap) {
+    let name = field.name.as_deref().unwrap_or("0");
+    if field.attrs.flatten
+        || field.attrs.has_custom_serializer()
+        || field.attrs.ts_as.is_some()
+        || field.attrs.ts_type.is_some()
+        || field.attrs.validate.is_some()
+    {
+        panic!(
+            "Field `{}` of `{}` cannot be interned, because it is flattened, uses a custom \
+            (de)serializer or is annotated with `ts_as`, `ts_type` or `validate`",
+            name, owner
+        );
+    }
+    if !is_string(&field.ty, types) {
+        panic!(
+            "Field `{}` of `{}` has type `{}`, but only strings can be interned",
+            name, owner, field.ty
+        );
+    }
+}
+
+fn is_string(ident: &TypeIdent, types: &TypeMap) -> bool {
+    match types.get(ident) {
+        Some(Type::Alias(_, ident)) => is_string(ident, types),
+        Some(Type::String) => true,
+        _ => false,
+    }
+}
+
+/// Returns whether any struct has interned fields, in which case the runtimes
+/// provide the import through which plugins register their strings.
+pub(crate) fn has_interned_fields(types: &TypeMap) -> bool {
+    types.values().any(|ty| match ty {
+        Type::Struct(ty) => ty.fields.iter().any(|field| field.attrs.interned),
+        _ => false,
+    })
+}
+
+/// Returns whether the given type contains an interned field anywhere, in
+/// which case its serialized values may contain handles that are only known
+/// to the runtime of the plugin that sent them.
+pub(crate) fn contains_interned_fields(ident: &TypeIdent, types: &TypeMap) -> bool {
+    contains_interned_fields_inner(ident, types, &mut BTreeSet::new())
+}
+
+fn contains_interned_fields_inner(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    visited: &mut BTreeSet<TypeIdent>,
+) -> bool {
+    if !visited.insert(ident.clone()) {
+        return false;
+    }
+
+    let mut idents: Vec<&TypeIdent> = ident.generic_args.iter().map(|(arg, _)| arg).collect();
+    match types.get(ident) {
+        Some(Type::Alias(_, ident)) => idents.push(ident),
+        Some(Type::Enum(ty)) => {
+            for variant in &ty.variants {
+                match &variant.ty {
+                    Type::Struct(ty) => idents.extend(ty.fields.iter().map(|field| &field.ty)),
+                    Type::Tuple(items) => idents.extend(items),
+                    _ => {}
+                }
+            }
+        }
+        Some(Type::Struct(ty)) if ty.fields.iter().any(|field| field.attrs.interned) => {
+            return true
+        }
+        Some(Type::Struct(ty)) => idents.extend(ty.fields.iter().map(|field| &field.ty)),
+        Some(Type::Tuple(items)) => idents.extend(items),
+        _ => {}
+    }
+    idents
+        .into_iter()
+        .any(|ident| contains_interned_fields_inner(ident, types, visited))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        primitives::Primitive,
+        types::{FieldAttrs, Struct, StructOptions},
+    };
+
+    fn metric_types(ty: &str) -> TypeMap {
+        let metric = Struct {
+            ident: TypeIdent::from("Metric"),
+            fields: vec![Field {
+                name: Some("name".to_owned()),
+                ty: TypeIdent::from(ty),
+                doc_lines: Vec::new(),
+                attrs: FieldAttrs {
+                    interned: true,
+                    ..Default::default()
+                },
+            }],
+            doc_lines: Vec::new(),
+            options: StructOptions::default(),
+            location: None,
+        };
+        TypeMap::from([
+            (TypeIdent::from("Metric"), Type::Struct(metric)),
+            (TypeIdent::from("String"), Type::String),
+            (TypeIdent::from("u32"), Type::Primitive(Primitive::U32)),
+            (
+                TypeIdent::from("Vec<Metric>"),
+                Type::List("Vec".to_owned(), TypeIdent::from("Metric")),
+            ),
+        ])
+    }
+
+    #[test]
+    fn finds_interned_fields() {
+        let types = metric_types("String");
+        check_interned_fields(&types);
+        assert!(has_interned_fields(&types));
+        assert!(contains_interned_fields(
+            &TypeIdent::from("Vec<Metric>"),
+            &types
+        ));
+        assert!(!contains_interned_fields(&TypeIdent::from("u32"), &types));
+    }
+
+    #[test]
+    #[should_panic(expected = "only strings can be interned")]
+    fn rejects_interned_numbers() {
+        check_interned_fields(&metric_types("u32"));
+    }
+}
@@ -0,0 +1,185 @@
+//! Handling of the lifecycle exports, `init`, `update_config` and `shutdown`.
+//!
+//! Every protocol has `init` and `shutdown`, so that runtimes can call them in
+//! a standardized way: `init` once the plugin has been instantiated, and
+//! `shutdown` when the runtime is dropped or disposed. Protocols may declare
+//! them explicitly, for instance to pass a typed config to `init`, but if they
+//! don't, the generators add them. Plugins may always omit them.
+//!
+//! Protocols whose `init` takes a config also have `update_config`, through
+//! which the host replaces the config later on. Rust plugin bindings implement
+//! it themselves, by caching the config for `fp_config()`.
+
+use crate::functions::{Function, FunctionList};
+
+/// Name of the export that is called after the plugin has been instantiated.
+pub(crate) const INIT: &str = "init";
+
+/// Name of the export that is called before the plugin is discarded.
+pub(crate) const SHUTDOWN: &str = "shutdown";
+
+/// Name of the export that is called when the host changes the config of the
+/// plugin.
+pub(crate) const UPDATE_CONFIG: &str = "update_config";
+
+/// Adds the lifecycle exports that are not declared by the protocol itself.
+///
+/// Panics if the protocol declares a lifecycle export with a signature that
+/// runtimes cannot call: `init` may only take a single config argument,
+/// `update_config` only the same config and `shutdown` nothing, none of them
+/// may return a value, and none of them may be async, be generic or be behind
+/// a feature.
+pub(super) fn add_lifecycle_exports(export_functions: &mut FunctionList) {
+    let mut has_init = false;
+    let mut has_shutdown = false;
+    let mut config_type = None;
+    let mut update_config = None;
+    for function in export_functions.iter() {
+        match function.name.as_str() {
+            INIT => {
+                check_lifecycle_export(function, 1);
+                has_init = true;
+                config_type = function.args.first().map(|arg| arg.ty.clone());
+            }
+            SHUTDOWN => {
+                check_lifecycle_export(function, 0);
+                has_shutdown = true;
+            }
+            UPDATE_CONFIG => {
+                check_lifecycle_export(function, 1);
+                update_config = Some(function.args.first().map(|arg| arg.ty.clone()));
+            }
+            _ => {}
+        }
+    }
+
+    match (config_type, update_config) {
+        (Some(config_type), None) => export_functions.add_function(&format!(
+            "/// Called on the plugin when the host changes its config.
+            fn {UPDATE_CONFIG}(config: {config_type});"
+        )),
+        (config_type, Some(update_type)) if config_type != update_type || update_type.is_none() => {
+            panic!(
+                "Lifecycle export `{}` must take the same config argument as `{}`",
+                UPDATE_CONFIG, INIT
+            )
+        }
+        _ => {}
+    }
+
+    if !has_init {
+        export_functions.add_function(
+            "/// Called on the plugin after it has been instantiated, to give it a chance to initialize.
+            fn init();",
+        );
+    }
+    if !has_shutdown {
+        export_functions.add_function(
+            "/// Called on the plugin before it is discarded, to give it a chance to clean up.
+            fn shutdown();",
+        );
+    }
+}
+
+/// Returns the `init` export, which is declared by every protocol after
+/// [add_lifecycle_exports()] was called.
+pub(crate) fn get_init_export(export_functions: &FunctionList) -> &Function {
+    export_functions
+        .iter()
+        .find(|function| function.name == INIT)
+        .expect("Protocol should declare an `init` export")
+}
+
+/// Removes the `shutdown` export, which runtimes only call when they are
+/// dropped or disposed, rather than exposing it to the host.
+pub(super) fn without_shutdown_export(export_functions: FunctionList) -> FunctionList {
+    export_functions
+        .into_iter()
+        .filter(|function| function.name != SHUTDOWN)
+        .collect()
+}
+
+fn check_lifecycle_export(function: &Function, max_args: usize) {
+    if function.args.len() > max_args
+        || function.return_type.is_some()
+        || function.is_async
+        || !function.generic_params.is_empty()
+        || function.attrs.cfg_feature.is_some()
+    {
+        panic!(
+            "Lifecycle export `{}` must be a synchronous function that returns nothing and takes {}",
+            function.name,
+            if max_args == 0 {
+                "no arguments"
+            } else {
+                "at most one config argument"
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TypeIdent;
+
+    #[test]
+    fn test_add_lifecycle_exports() {
+        let mut export_functions = FunctionList::new();
+        export_functions.add_function("fn init(config: Config);");
+        add_lifecycle_exports(&mut export_functions);
+
+        let init = get_init_export(&export_functions);
+        assert_eq!(init.args.len(), 1);
+        assert!(export_functions
+            .iter()
+            .any(|function| function.name == SHUTDOWN));
+
+        let export_functions = without_shutdown_export(export_functions);
+        assert_eq!(export_functions.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_add_update_config_export() {
+        let mut export_functions = FunctionList::new();
+        export_functions.add_function("fn init(config: Config);");
+        add_lifecycle_exports(&mut export_functions);
+
+        let update_config = export_functions
+            .iter()
+            .find(|function| function.name == UPDATE_CONFIG)
+            .expect("Missing `update_config` export");
+        assert_eq!(update_config.args[0].ty, TypeIdent::from("Config"));
+
+        let mut export_functions = FunctionList::new();
+        add_lifecycle_exports(&mut export_functions);
+        assert!(!export_functions
+            .iter()
+            .any(|function| function.name == UPDATE_CONFIG));
+    }
+
+    #[test]
+    #[should_panic(expected = "Lifecycle export `update_config` must take the same config")]
+    fn test_update_config_with_other_type() {
+        let mut export_functions = FunctionList::new();
+        export_functions.add_function("fn init(config: Config);");
+        export_functions.add_function("fn update_config(config: OtherConfig);");
+        add_lifecycle_exports(&mut export_functions);
+    }
+
+    #[test]
+    #[should_panic(expected = "Lifecycle export `shutdown` must be a synchronous function")]
+    fn test_shutdown_with_arguments() {
+        let mut export_functions = FunctionList::new();
+        export_functions.add_function("fn shutdown(reason: String);");
+        add_lifecycle_exports(&mut export_functions);
+    }
+
+    #[test]
+    #[should_panic(expected = "Lifecycle export `init` must be a synchronous function")]
+    fn test_init_with_return_value() {
+        let mut export_functions = FunctionList::new();
+        export_functions.add_function("fn init() -> bool;");
+        add_lifecycle_exports(&mut export_functions);
+    }
+}
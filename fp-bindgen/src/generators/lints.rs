@@ -0,0 +1,629 @@
+//! Lints that check the protocol for problems before any bindings are
+//! generated.
+//!
+//! Unlike the checks that panic, lints report declarations that are valid
+//! Rust, but that some of the generators or the MessagePack codec cannot
+//! express faithfully. They are printed as warnings, and point at the
+//! declaration of the type involved if the `Serializable` derive macro
+//! recorded its location.
+
+use super::pruning::reachable_types;
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    types::{Enum, EnumOptions, Field, SourceLocation, Struct, Type, TypeIdent, TypeMap, Variant},
+};
+use inflector::Inflector;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display},
+};
+
+/// Words that cannot be used as identifiers in the generated TypeScript code.
+const TS_RESERVED_WORDS: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Built-in TypeScript types that the generated code relies on, and which
+/// would be shadowed by protocol types of the same name.
+const TS_BUILTIN_TYPES: &[&str] = &[
+    "Array",
+    "ArrayBuffer",
+    "DataView",
+    "Error",
+    "Map",
+    "Object",
+    "Promise",
+    "Readonly",
+    "ReadonlyArray",
+    "ReadonlySet",
+    "Record",
+    "Set",
+    "Uint8Array",
+    "WebAssembly",
+];
+
+/// A problem that was found in the protocol.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct Lint {
+    pub location: Option<SourceLocation>,
+    pub message: String,
+}
+
+impl Lint {
+    fn new(location: Option<&SourceLocation>, message: String) -> Self {
+        Self {
+            location: location.cloned(),
+            message,
+        }
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{location}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Checks the protocol for problems, and returns the lints for the problems
+/// that were found.
+pub(super) fn lint_protocol(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) -> Vec<Lint> {
+    let mut lints = Vec::new();
+
+    for (functions, kind) in [(import_functions, "Imports"), (export_functions, "Exports")] {
+        lint_name_collisions(
+            &mut lints,
+            None,
+            functions
+                .iter()
+                .map(|function| (function.name.as_str(), format_ts_identifier(&function.name))),
+            kind,
+        );
+        for function in functions.iter() {
+            lint_function(&mut lints, function);
+        }
+    }
+
+    for (ident, ty) in types {
+        match ty {
+            Type::Enum(ty) => lint_enum(&mut lints, ty, types),
+            Type::Struct(ty) => lint_struct_fields(
+                &mut lints,
+                ty.location.as_ref(),
+                &format!("struct `{ident}`"),
+                &ty.fields,
+                ty.options.field_casing,
+            ),
+            _ => {}
+        }
+        if matches!(ty, Type::Alias(..) | Type::Enum(_) | Type::Struct(_))
+            && TS_BUILTIN_TYPES.contains(&ident.name.as_str())
+        {
+            lints.push(Lint::new(
+                ty.location(),
+                format!(
+                    "Type `{ident}` shadows the built-in TypeScript type of the same name, \
+                    which the generated TypeScript bindings rely on"
+                ),
+            ));
+        }
+    }
+
+    lint_unsupported_usages(&mut lints, import_functions, export_functions, types);
+    lint_unused_types(&mut lints, import_functions, export_functions, types);
+
+    lints
+}
+
+fn lint_function(lints: &mut Vec<Lint>, function: &Function) {
+    let context = format!("function `{}`", function.name);
+    lint_name_collisions(
+        lints,
+        None,
+        function
+            .args
+            .iter()
+            .map(|arg| (arg.name.as_str(), format_ts_identifier(&arg.name))),
+        &format!("Arguments of {context}"),
+    );
+
+    for name in std::iter::once(&function.name).chain(function.args.iter().map(|arg| &arg.name)) {
+        let identifier = format_ts_identifier(name);
+        if TS_RESERVED_WORDS.contains(&identifier.as_str()) {
+            lints.push(Lint::new(
+                None,
+                format!(
+                    "`{name}` in {context} becomes `{identifier}` in TypeScript, which is a \
+                    reserved word"
+                ),
+            ));
+        }
+    }
+}
+
+fn lint_struct_fields(
+    lints: &mut Vec<Lint>,
+    location: Option<&SourceLocation>,
+    context: &str,
+    fields: &[Field],
+    casing: Casing,
+) {
+    let names = fields
+        .iter()
+        .filter(|field| !field.attrs.flatten)
+        .filter_map(|field| {
+            let name = field.name.as_deref()?;
+            let serialized_name = match &field.attrs.rename {
+                Some(rename) => rename.clone(),
+                None => casing.format_string(strip_raw_prefix(name)),
+            };
+            Some((name, serialized_name))
+        });
+    lint_name_collisions(lints, location, names, &format!("Fields of {context}"));
+}
+
+fn lint_enum(lints: &mut Vec<Lint>, ty: &Enum, types: &TypeMap) {
+    let location = ty.location.as_ref();
+    let context = format!("enum `{}`", ty.ident);
+
+    for variant in &ty.variants {
+        if let Type::Struct(variant_struct) = &variant.ty {
+            lint_struct_fields(
+                lints,
+                location,
+                &format!("variant `{}` of {context}", variant.name),
+                &variant_struct.fields,
+                variant.attrs.field_casing,
+            );
+        }
+    }
+
+    if ty.options.untagged {
+        let unit_variants: Vec<_> = ty
+            .variants
+            .iter()
+            .filter(|variant| variant.ty == Type::Unit)
+            .map(|variant| format!("`{}`", variant.name))
+            .collect();
+        if unit_variants.len() > 1 {
+            lints.push(Lint::new(
+                location,
+                format!(
+                    "Variants {} of untagged {context} are all serialized as `null`, so only \
+                    the first of them can be deserialized",
+                    unit_variants.join(", ")
+                ),
+            ));
+        }
+        return;
+    }
+
+    let variant_names = ty.variants.iter().map(|variant| {
+        (
+            variant.name.as_str(),
+            get_variant_name(variant, &ty.options),
+        )
+    });
+    lint_name_collisions(
+        lints,
+        location,
+        variant_names,
+        &format!("Variants of {context}"),
+    );
+
+    // Internally tagged variants merge the tag into their content, which
+    // therefore needs to be serialized as a map:
+    if ty.options.tag_prop_name.is_some() && ty.options.content_prop_name.is_none() {
+        for variant in &ty.variants {
+            if let Type::Tuple(items) = &variant.ty {
+                if let [item] = items.as_slice() {
+                    if !is_map_like(item, types) {
+                        lints.push(Lint::new(
+                            location,
+                            format!(
+                                "Variant `{}` of internally tagged {context} contains a `{item}`, \
+                                which cannot be combined with the tag, because it is not \
+                                serialized as a map. Add a `content` attribute to the enum, or \
+                                wrap the value in a struct",
+                                variant.name
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Lints the usages of types that cannot be represented in every target
+/// language, in both function signatures and type declarations.
+fn lint_unsupported_usages(
+    lints: &mut Vec<Lint>,
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) {
+    let mut usages: Vec<(&TypeIdent, String, Option<&SourceLocation>)> = Vec::new();
+    for function in import_functions.iter().chain(export_functions.iter()) {
+        let context = format!("function `{}`", function.name);
+        for ident in function
+            .args
+            .iter()
+            .map(|arg| &arg.ty)
+            .chain(function.return_type.iter())
+        {
+            usages.push((ident, context.clone(), None));
+        }
+    }
+    for (ident, ty) in types {
+        let location = ty.location();
+        match ty {
+            Type::Struct(ty) => {
+                let context = format!("struct `{ident}`");
+                for field in &ty.fields {
+                    usages.push((&field.ty, context.clone(), location));
+                }
+            }
+            Type::Enum(ty) => {
+                let context = format!("enum `{ident}`");
+                for variant in &ty.variants {
+                    match &variant.ty {
+                        Type::Struct(variant_struct) => {
+                            for field in &variant_struct.fields {
+                                usages.push((&field.ty, context.clone(), location));
+                            }
+                        }
+                        Type::Tuple(items) => {
+                            for item in items {
+                                usages.push((item, context.clone(), location));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut reported = BTreeSet::new();
+    while let Some((ident, context, location)) = usages.pop() {
+        usages.extend(
+            ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| (arg, context.clone(), location)),
+        );
+
+        let message = match types.get(ident) {
+            Some(Type::Container(name, _)) if name == "Option" => {
+                match ident.generic_args.first() {
+                    Some((arg, _)) if is_option(arg, types) => format!(
+                        "`{ident}` in {context} cannot be represented in TypeScript and JSON, \
+                    where both `None` and `Some(None)` become `null`"
+                    ),
+                    _ => continue,
+                }
+            }
+            Some(Type::Map(..)) => match ident.generic_args.first() {
+                Some((key, _)) if !is_valid_map_key(key, types) => format!(
+                    "`{ident}` in {context} has keys of type `{key}`, which cannot be \
+                    represented as object keys in TypeScript"
+                ),
+                _ => continue,
+            },
+            _ => continue,
+        };
+        if reported.insert(message.clone()) {
+            lints.push(Lint::new(location, message));
+        }
+    }
+}
+
+/// Lints the structs, enums and aliases that are not used by any function.
+fn lint_unused_types(
+    lints: &mut Vec<Lint>,
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) {
+    let used = reachable_types(import_functions, export_functions, types);
+    for (ident, ty) in types {
+        if matches!(ty, Type::Alias(..) | Type::Enum(_) | Type::Struct(_)) && !used.contains(ident)
+        {
+            lints.push(Lint::new(
+                ty.location(),
+                format!(
+                    "Type `{ident}` is registered, but not used by any function, so it is only \
+                    generated if `unused_types` is set to `UnusedTypes::Keep`"
+                ),
+            ));
+        }
+    }
+}
+
+/// Lints the names that collide once they are converted to the identifiers
+/// or serialized names that are used in the generated bindings.
+fn lint_name_collisions<'a>(
+    lints: &mut Vec<Lint>,
+    location: Option<&SourceLocation>,
+    names: impl Iterator<Item = (&'a str, String)>,
+    subject: &str,
+) {
+    let mut names_by_identifier: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for (name, identifier) in names {
+        names_by_identifier
+            .entry(identifier)
+            .or_default()
+            .push(name);
+    }
+
+    for (identifier, names) in names_by_identifier {
+        if names.len() > 1 {
+            lints.push(Lint::new(
+                location,
+                format!(
+                    "{subject} {} collide, because they all become `{identifier}`",
+                    names
+                        .iter()
+                        .map(|name| format!("`{name}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ));
+        }
+    }
+}
+
+fn get_variant_name(variant: &Variant, options: &EnumOptions) -> String {
+    match &variant.attrs.rename {
+        Some(rename) => rename.clone(),
+        None => options
+            .variant_casing
+            .format_string(strip_raw_prefix(&variant.name)),
+    }
+}
+
+fn format_ts_identifier(name: &str) -> String {
+    strip_raw_prefix(name).to_camel_case()
+}
+
+fn strip_raw_prefix(name: &str) -> &str {
+    name.strip_prefix("r#").unwrap_or(name)
+}
+
+fn resolve<'a>(ident: &TypeIdent, types: &'a TypeMap) -> Option<&'a Type> {
+    match types.get(ident) {
+        Some(Type::Alias(_, target)) => resolve(target, types),
+        ty => ty,
+    }
+}
+
+fn is_option(ident: &TypeIdent, types: &TypeMap) -> bool {
+    matches!(resolve(ident, types), Some(Type::Container(name, _)) if name == "Option")
+}
+
+/// Returns whether values of the given type are serialized as a map, or
+/// whether that is unknown because it is a type parameter.
+fn is_map_like(ident: &TypeIdent, types: &TypeMap) -> bool {
+    match resolve(ident, types) {
+        Some(Type::Struct(Struct { fields, .. })) => {
+            fields.iter().all(|field| field.name.is_some())
+        }
+        Some(Type::Map(..) | Type::Custom(_) | Type::Unit) | None => true,
+        Some(Type::Container(name, _)) if name != "Option" => match ident.generic_args.first() {
+            Some((arg, _)) => is_map_like(arg, types),
+            None => true,
+        },
+        Some(_) => false,
+    }
+}
+
+/// Returns whether values of the given type can be used as keys of the
+/// objects that represent maps in TypeScript.
+fn is_valid_map_key(ident: &TypeIdent, types: &TypeMap) -> bool {
+    match resolve(ident, types) {
+        Some(Type::Enum(ty)) => ty
+            .variants
+            .iter()
+            .all(|variant| variant.ty == Type::Unit && !ty.options.untagged),
+        Some(Type::Primitive(_) | Type::String | Type::Custom(_)) | None => true,
+        Some(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lint(functions: &[&str], items: &[&str]) -> Vec<String> {
+        let mut export_functions = FunctionList::new();
+        for decl in functions {
+            export_functions.add_function(decl);
+        }
+
+        let mut types = TypeMap::new();
+        for ident in ["u32", "String"] {
+            types.insert(
+                TypeIdent::from(ident),
+                if ident == "String" {
+                    Type::String
+                } else {
+                    Type::Primitive(crate::primitives::Primitive::U32)
+                },
+            );
+        }
+        types.insert(
+            TypeIdent::from("Option<T>"),
+            Type::Container("Option".to_owned(), TypeIdent::from("T")),
+        );
+        types.insert(
+            TypeIdent::from("Vec<T>"),
+            Type::List("Vec".to_owned(), TypeIdent::from("T")),
+        );
+        types.insert(
+            TypeIdent::from("BTreeMap<K, V>"),
+            Type::Map(
+                "BTreeMap".to_owned(),
+                TypeIdent::from("K"),
+                TypeIdent::from("V"),
+            ),
+        );
+        for item in items {
+            let ty = Type::from_item(item).with_location("src/types.rs", 1);
+            let ident = match &ty {
+                Type::Enum(ty) => ty.ident.clone(),
+                Type::Struct(ty) => ty.ident.clone(),
+                _ => unreachable!(),
+            };
+            types.insert(ident, ty);
+        }
+
+        lint_protocol(&FunctionList::new(), &export_functions, &types)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_name_collisions() {
+        assert_eq!(
+            lint(
+                &["fn my_value() -> Foo;", "fn myValue() -> Foo;"],
+                &["#[serde(rename_all = \"camelCase\")] struct Foo { my_value: u32, myValue: u32 }"]
+            ),
+            vec![
+                "Exports `myValue`, `my_value` collide, because they all become `myValue`",
+                "src/types.rs:1: Fields of struct `Foo` `my_value`, `myValue` collide, because \
+                    they all become `myValue`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reserved_words() {
+        assert_eq!(
+            lint(&["fn delete(r#default: u32);"], &[]),
+            vec![
+                "`delete` in function `delete` becomes `delete` in TypeScript, which is a \
+                    reserved word",
+                "`r#default` in function `delete` becomes `default` in TypeScript, which is a \
+                    reserved word",
+            ]
+        );
+        assert_eq!(
+            lint(&["fn f() -> Record;"], &["struct Record { id: u32 }"]),
+            vec![
+                "src/types.rs:1: Type `Record` shadows the built-in TypeScript type of the same \
+                    name, which the generated TypeScript bindings rely on"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_usages() {
+        assert_eq!(
+            lint(
+                &["fn f(a: Option<Option<u32>>) -> Foo;"],
+                &[
+                    "struct Foo { map: BTreeMap<Bar, u32> }",
+                    "struct Bar { id: u32 }"
+                ]
+            ),
+            vec![
+                "src/types.rs:1: `BTreeMap<Bar, u32>` in struct `Foo` has keys of type `Bar`, \
+                    which cannot be represented as object keys in TypeScript",
+                "`Option<Option<u32>>` in function `f` cannot be represented in TypeScript and \
+                    JSON, where both `None` and `Some(None)` become `null`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unused_types() {
+        assert_eq!(
+            lint(
+                &["fn f() -> Foo;"],
+                &["struct Foo { bar: Bar }", "struct Bar {}", "struct Baz {}"]
+            ),
+            vec![
+                "src/types.rs:1: Type `Baz` is registered, but not used by any function, so it is \
+                    only generated if `unused_types` is set to `UnusedTypes::Keep`"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_representations() {
+        assert_eq!(
+            lint(
+                &["fn f(a: Untagged) -> Tagged;"],
+                &[
+                    "#[serde(untagged)] enum Untagged { A, B, C(u32) }",
+                    "#[serde(tag = \"type\")] enum Tagged { A(Vec<u32>), B(Foo) }",
+                    "struct Foo { id: u32 }",
+                ]
+            ),
+            vec![
+                "src/types.rs:1: Variant `A` of internally tagged enum `Tagged` contains a \
+                    `Vec<u32>`, which cannot be combined with the tag, because it is not \
+                    serialized as a map. Add a `content` attribute to the enum, or wrap the \
+                    value in a struct",
+                "src/types.rs:1: Variants `A`, `B` of untagged enum `Untagged` are all \
+                    serialized as `null`, so only the first of them can be deserialized",
+            ]
+        );
+    }
+}
@@ -1,44 +1,333 @@
 use crate::{
-    functions::FunctionList,
-    types::{CargoDependency, Type, TypeIdent, TypeMap},
+    functions::{register_tuple_return_types, CacheOptions, Function, FunctionList},
+    types::{CargoDependency, Struct, Type, TypeIdent, TypeMap},
 };
+use inflector::Inflector;
 use std::{
     collections::{BTreeMap, BTreeSet},
     fmt::Display,
     fs,
+    path::Path,
 };
 
+mod aliases;
+pub mod assemblyscript_plugin;
+pub mod benches;
+pub mod cli;
+mod columnar;
+pub mod csharp_runtime;
+pub mod docs;
+pub mod fixtures;
+mod formatting;
+pub mod fuzz;
+pub mod graphql;
+mod interning;
+mod lifecycle;
+mod lints;
+pub mod openapi;
+mod pruning;
 pub mod rust_plugin;
+pub mod rust_types;
 pub mod rust_wasmer_runtime;
 pub mod rust_wasmer_wasi_runtime;
+pub mod rust_wasmi_runtime;
+pub mod scaffold;
+mod schema_hash;
 pub mod ts_runtime;
+mod tuple_returns;
+mod validation;
+
+pub use formatting::{Formatting, MissingFormatter};
 
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 pub enum BindingsType<'a> {
+    AssemblyScriptPlugin(AssemblyScriptPluginConfig),
+    Benches(BenchesConfig),
+    Cli(CliConfig),
+    CSharpRuntime(CSharpRuntimeConfig),
+    Docs(DocsConfig),
+    Fixtures(FixturesConfig),
+    Fuzz(FuzzConfig),
+    GraphQl,
+    OpenApi(OpenApiConfig),
     RustPlugin(RustPluginConfig<'a>),
+    RustTypes(RustTypesConfig),
     RustWasmerRuntime,
+    RustWasmerRuntimeWithExtendedConfig(RustWasmerExtendedRuntimeConfig),
     RustWasmerWasiRuntime,
+    RustWasmiRuntime,
+    Scaffold(ScaffoldConfig),
     TsRuntimeWithExtendedConfig(TsExtendedRuntimeConfig),
 }
 
 impl<'a> Display for BindingsType<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
+            BindingsType::AssemblyScriptPlugin { .. } => "assemblyscript-plugin",
+            BindingsType::Benches { .. } => "benches",
+            BindingsType::Cli { .. } => "cli",
+            BindingsType::CSharpRuntime { .. } => "csharp-runtime",
+            BindingsType::Docs { .. } => "docs",
+            BindingsType::Fixtures { .. } => "fixtures",
+            BindingsType::Fuzz { .. } => "fuzz",
+            BindingsType::GraphQl { .. } => "graphql",
+            BindingsType::OpenApi { .. } => "openapi",
             BindingsType::RustPlugin { .. } => "rust-plugin",
+            BindingsType::RustTypes { .. } => "rust-types",
             BindingsType::RustWasmerRuntime { .. } => "rust-wasmer-runtime",
+            BindingsType::RustWasmerRuntimeWithExtendedConfig { .. } => "rust-wasmer-runtime",
             BindingsType::RustWasmerWasiRuntime { .. } => "rust-wasmer-wasi-runtime",
+            BindingsType::RustWasmiRuntime { .. } => "rust-wasmi-runtime",
+            BindingsType::Scaffold { .. } => "scaffold",
             BindingsType::TsRuntimeWithExtendedConfig { .. } => "ts-runtime",
         })
     }
 }
 
+/// The configuration with which bindings are generated.
+///
+/// Besides the type of the bindings and the path to which they are written,
+/// all settings have defaults. Within `fp_bindgen!()`, the config can also be
+/// written as a struct expression that only lists the settings that differ
+/// from their defaults.
+#[non_exhaustive]
 #[derive(Debug)]
 pub struct BindingConfig<'a> {
     pub bindings_type: BindingsType<'a>,
     pub path: &'a str,
+
+    /// Whether aliases are emitted as named types, or expanded into the types
+    /// they refer to.
+    ///
+    /// By default, aliases are preserved.
+    pub aliases: AliasMode,
+
+    /// Headers that are prepended to the generated files, such as license
+    /// notices or pragmas that exclude the files from linting.
+    ///
+    /// By default, no headers are prepended.
+    pub headers: FileHeaders,
+
+    /// Whether types that are not used by any function are generated.
+    ///
+    /// By default, unused types are pruned.
+    pub unused_types: UnusedTypes,
+
+    /// How the generated files are formatted.
+    ///
+    /// By default, Rust files are formatted using `rustfmt` for the 2018
+    /// edition, and TypeScript files are left as they are generated.
+    pub formatting: Formatting,
+}
+
+impl<'a> BindingConfig<'a> {
+    /// Returns a new config instance for the given bindings type and output
+    /// path, with default settings for everything else.
+    pub fn new(bindings_type: BindingsType<'a>, path: &'a str) -> Self {
+        Self {
+            bindings_type,
+            path,
+            aliases: AliasMode::default(),
+            headers: FileHeaders::default(),
+            unused_types: UnusedTypes::default(),
+            formatting: Formatting::default(),
+        }
+    }
+
+    /// Sets the `aliases` setting.
+    pub fn with_aliases(mut self, aliases: AliasMode) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Sets the `headers` setting.
+    pub fn with_headers(mut self, headers: FileHeaders) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Sets the `unused_types` setting.
+    pub fn with_unused_types(mut self, unused_types: UnusedTypes) -> Self {
+        self.unused_types = unused_types;
+        self
+    }
+
+    /// Sets the `formatting` setting.
+    pub fn with_formatting(mut self, formatting: Formatting) -> Self {
+        self.formatting = formatting;
+        self
+    }
+}
+
+/// Headers that are prepended to the generated files, by file extension.
+///
+/// Every file that is written with an extension for which a header is
+/// configured starts with that header, followed by its regular contents.
+/// Files for which no header is configured are left untouched, which is also
+/// why JSON files, which cannot contain comments, should not be given one.
+///
+/// ```ignore
+/// let headers = FileHeaders::new()
+///     .with_header("rs", "// @generated\n#![allow(clippy::all)]")
+///     .with_header("ts", "// @generated\n/* eslint-disable */");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FileHeaders {
+    headers: BTreeMap<String, String>,
+}
+
+impl FileHeaders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the header for files with the given extension, such as `"rs"` or
+    /// `"ts"`. TypeScript declaration files (`.d.ts`) use the header of `"ts"`.
+    pub fn with_header(mut self, extension: &str, header: &str) -> Self {
+        self.headers.insert(extension.to_owned(), header.to_owned());
+        self
+    }
+
+    /// Returns the header for the file at the given path, if any.
+    fn header_for(&self, file_path: &str) -> Option<&str> {
+        let extension = Path::new(file_path).extension()?.to_str()?;
+        self.headers.get(extension).map(String::as_str)
+    }
+}
+
+/// Writes a generated file, prepending the header that is configured for its
+/// extension, if any.
+pub(crate) fn write_bindings_file<C>(file_path: String, contents: C, headers: &FileHeaders)
+where
+    C: AsRef<[u8]>,
+{
+    let header = headers
+        .header_for(&file_path)
+        .map(|header| format!("{}\n", header.trim_end_matches('\n')));
+    let contents = match header {
+        Some(header) => [header.as_bytes(), contents.as_ref()].concat(),
+        None => contents.as_ref().to_vec(),
+    };
+    fs::write(&file_path, contents).expect("Could not write bindings file");
+    formatting::track_written_file(&file_path);
+}
+
+/// Determines how the aliases that are declared in the `fp_import!` and
+/// `fp_export!` sections are represented in the generated bindings.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AliasMode {
+    /// Aliases are declared as named types, such as `pub type Body = ByteBuf;`
+    /// in Rust and `export type Body = ArrayBuffer;` in TypeScript, and every
+    /// use of them refers to them by name. The OpenAPI specification lists
+    /// them as schemas of their own.
+    ///
+    /// C# has no aliases that can be shared between files, so the C# runtime
+    /// always expands them.
+    #[default]
+    Preserve,
+
+    /// Every use of an alias is replaced by the type it refers to, and no
+    /// declarations are generated for them.
+    Expand,
+}
+
+/// Determines what happens to the types that are registered using `use`
+/// statements or aliases in the `fp_import!` and `fp_export!` sections, but
+/// that are not used by any function, either directly or through other types.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum UnusedTypes {
+    /// Unused types are left out of the generated bindings, which keeps the
+    /// generated type declarations small for protocols that share a large
+    /// registry of types.
+    #[default]
+    Prune,
+
+    /// Unused types are generated as type declarations, so they can be used
+    /// by the host or plugin, even though they are not exchanged through any
+    /// function.
+    Keep,
+}
+
+/// Determines which global allocator the generated plugin crate installs.
+///
+/// Whichever allocator is used, `__fp_malloc` returns a null pointer if an
+/// allocation fails, which the runtimes report as an `AllocationFailed` error
+/// instead of trapping.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PluginAllocator {
+    /// No allocator is installed, so the plugin uses the default allocator of
+    /// the standard library, or the one that the plugin installs itself.
+    #[default]
+    Default,
+
+    /// Installs the allocator from the `dlmalloc` crate, which is what the
+    /// standard library uses on `wasm32-unknown-unknown` as well.
+    Dlmalloc,
+
+    /// Installs the allocator from the `wee_alloc` crate, which is small but
+    /// slow and unmaintained.
+    WeeAlloc,
+
+    /// Installs the allocator from the `talc` crate, which is both small and
+    /// fast.
+    Talc,
+}
+
+/// The WebAssembly features that plugins may use.
+///
+/// These should match the features that are enabled on the runtimes that run
+/// the plugins. The generated plugin crate fails to compile when the plugin is
+/// built with the target feature of a WebAssembly feature that is disabled.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WasmFeatures {
+    /// Whether plugins may use SIMD instructions, through the `simd128`
+    /// target feature.
+    pub simd: bool,
+
+    /// Whether plugins may use bulk memory instructions, through the
+    /// `bulk-memory` target feature.
+    pub bulk_memory: bool,
+
+    /// Whether plugins may use reference types, through the `reference-types`
+    /// target feature.
+    pub reference_types: bool,
+
+    /// Whether plugins may use shared memory and atomics, through the
+    /// `atomics` target feature.
+    pub threads: bool,
 }
 
+impl WasmFeatures {
+    /// Returns the target features that correspond to the WebAssembly
+    /// features, together with whether they are enabled.
+    pub(crate) fn target_features(&self) -> [(&'static str, bool); 4] {
+        [
+            ("simd128", self.simd),
+            ("bulk-memory", self.bulk_memory),
+            ("reference-types", self.reference_types),
+            ("atomics", self.threads),
+        ]
+    }
+}
+
+/// The defaults match those of the runtimes: SIMD, bulk memory and reference
+/// types are enabled, while threads are not.
+impl Default for WasmFeatures {
+    fn default() -> Self {
+        Self {
+            simd: true,
+            bulk_memory: true,
+            reference_types: true,
+            threads: false,
+        }
+    }
+}
+
+#[non_exhaustive]
 #[derive(Debug, Clone)]
 pub struct RustPluginConfig<'a> {
     /// Name of the plugin crate that will be generated.
@@ -50,36 +339,1172 @@ pub struct RustPluginConfig<'a> {
     /// Version of the plugin crate that will be generated.
     pub version: &'a str,
 
-    /// *Additional* dependencies to be listed in the plugin crate that will be
-    /// generated.
+    /// *Additional* dependencies to be listed in the plugin crate that will be
+    /// generated.
+    ///
+    /// These are merged with a small set of dependencies that are necessary
+    /// for the plugin to work and which will always be included. Specifying
+    /// these dependencies yourself can be useful if you want to explicitly bump
+    /// a dependency version or you want to enable a Cargo feature in them.
+    pub dependencies: BTreeMap<&'a str, CargoDependency>,
+
+    /// Whether the generated crate should avoid the standard library.
+    ///
+    /// If `true`, the generated crate is marked `#![no_std]`, only uses `core`
+    /// and `alloc`, and only depends on `fp-bindgen-support` and `serde`
+    /// (without its `std` feature), besides the dependencies of custom types.
+    /// `HashMap` and `HashSet` cannot be used in this mode.
+    pub no_std: bool,
+
+    /// Whether the generated import functions should take owned arguments.
+    ///
+    /// By default, `String` and `Vec<T>` arguments of imports are taken as
+    /// `&str` and `&[T]`, since they only need to be borrowed in order to be
+    /// serialized. This avoids clones in plugins that want to keep using the
+    /// values they pass. Set this to `true` to take owned arguments instead,
+    /// as earlier versions did.
+    pub owned_import_args: bool,
+
+    /// Whether the generated crate is meant to be compiled with
+    /// `panic = "abort"`.
+    ///
+    /// If `true`, the `panic-abort` feature of `fp-bindgen-support` is
+    /// enabled, so that failures in the glue code are reported to the host as
+    /// a `GuestError` before the plugin aborts, instead of unwinding. Plugins
+    /// can call `fp_bindgen_support::guest::errors::set_panic_hook()` to report
+    /// their own panics the same way.
+    pub panic_abort: bool,
+
+    /// Whether the generated import functions should record metrics about
+    /// their calls.
+    ///
+    /// If `true`, the `guest-metrics` feature of `fp-bindgen-support` is
+    /// enabled, and every call to an import is counted and timed inside the
+    /// plugin. Runtimes can retrieve the metrics through the
+    /// `__fp_get_guest_metrics()` export. Calls are timed using the monotonic
+    /// clock of the standard imports, so this requires a runtime that provides
+    /// these imports and grants the `clock` capability. It cannot be combined
+    /// with `no_std`.
+    pub guest_metrics: bool,
+
+    /// The global allocator that is installed by the generated crate when it
+    /// is compiled for `wasm32`.
+    ///
+    /// Picking a smaller allocator can be useful for `no_std` plugins, which
+    /// have to provide one themselves otherwise.
+    pub allocator: PluginAllocator,
+
+    /// The WebAssembly features that the plugin may use.
+    ///
+    /// The generated crate documents the matching target features, and
+    /// refuses to compile for plugins that are built with target features of
+    /// disabled WebAssembly features.
+    pub wasm_features: WasmFeatures,
+}
+
+impl<'a> RustPluginConfig<'a> {
+    /// Returns a new config instance for the crate with the given name, authors
+    /// and version. All other settings use their defaults, which generate a
+    /// crate for a regular `std` plugin.
+    pub fn new(name: &'a str, authors: &'a str, version: &'a str) -> Self {
+        Self {
+            name,
+            authors,
+            version,
+            dependencies: BTreeMap::new(),
+            no_std: false,
+            owned_import_args: false,
+            panic_abort: false,
+            guest_metrics: false,
+            allocator: PluginAllocator::Default,
+            wasm_features: WasmFeatures::default(),
+        }
+    }
+
+    /// Sets the `dependencies` setting.
+    pub fn with_dependencies(mut self, dependencies: BTreeMap<&'a str, CargoDependency>) -> Self {
+        self.dependencies = dependencies;
+        self
+    }
+
+    /// Adds a dependency to the `dependencies` setting.
+    pub fn with_dependency(mut self, name: &'a str, dependency: CargoDependency) -> Self {
+        self.dependencies.insert(name, dependency);
+        self
+    }
+
+    /// Sets the `no_std` setting.
+    pub fn with_no_std(mut self) -> Self {
+        self.no_std = true;
+        self
+    }
+
+    /// Sets the `owned_import_args` setting.
+    pub fn with_owned_import_args(mut self) -> Self {
+        self.owned_import_args = true;
+        self
+    }
+
+    /// Sets the `panic_abort` setting.
+    pub fn with_panic_abort(mut self) -> Self {
+        self.panic_abort = true;
+        self
+    }
+
+    /// Sets the `guest_metrics` setting.
+    pub fn with_guest_metrics(mut self) -> Self {
+        self.guest_metrics = true;
+        self
+    }
+
+    /// Sets the `allocator` setting.
+    pub fn with_allocator(mut self, allocator: PluginAllocator) -> Self {
+        self.allocator = allocator;
+        self
+    }
+
+    /// Sets the `wasm_features` setting.
+    pub fn with_wasm_features(mut self, wasm_features: WasmFeatures) -> Self {
+        self.wasm_features = wasm_features;
+        self
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct BenchesConfig {
+    /// The module from which the TypeScript benchmarks import the MessagePack
+    /// dependency.
+    ///
+    /// By default, "@msgpack/msgpack" is used. This should match the module
+    /// used by the TypeScript runtime, so that the benchmarks measure the same
+    /// (de)serialization code.
+    pub msgpack_module: String,
+
+    /// The payload sizes to benchmark with.
+    ///
+    /// Strings, lists and maps in the sample payloads contain this many
+    /// characters or items, while all other values are fixed. Functions
+    /// without any such payloads are only benchmarked once. By default, the
+    /// sizes 1, 16 and 256 are used.
+    pub payload_sizes: Vec<usize>,
+
+    /// How the TypeScript benchmarks refer to functions and their arguments.
+    ///
+    /// This should match the `identifier_casing` of the TypeScript runtime.
+    pub ts_identifier_casing: TsIdentifierCasing,
+
+    /// How the TypeScript benchmarks pass 64-bit integers as arguments.
+    ///
+    /// This should match the `int64_encoding` of the TypeScript runtime.
+    pub ts_int64_encoding: TsInt64Encoding,
+}
+
+impl BenchesConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `msgpack_module` setting.
+    pub fn with_msgpack_module(mut self, msgpack_module: &str) -> Self {
+        self.msgpack_module = msgpack_module.to_owned();
+        self
+    }
+
+    /// Sets the `payload_sizes` setting.
+    pub fn with_payload_sizes(mut self, payload_sizes: &[usize]) -> Self {
+        self.payload_sizes = payload_sizes.to_vec();
+        self
+    }
+
+    /// Sets the `ts_identifier_casing` setting.
+    pub fn with_ts_identifier_casing(mut self, ts_identifier_casing: TsIdentifierCasing) -> Self {
+        self.ts_identifier_casing = ts_identifier_casing;
+        self
+    }
+
+    /// Sets the `ts_int64_encoding` setting.
+    pub fn with_ts_int64_encoding(mut self, ts_int64_encoding: TsInt64Encoding) -> Self {
+        self.ts_int64_encoding = ts_int64_encoding;
+        self
+    }
+}
+
+impl Default for BenchesConfig {
+    fn default() -> Self {
+        Self {
+            msgpack_module: "@msgpack/msgpack".to_owned(),
+            payload_sizes: vec![1, 16, 256],
+            ts_identifier_casing: TsIdentifierCasing::default(),
+            ts_int64_encoding: TsInt64Encoding::default(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct CliConfig {
+    /// Name of the CLI crate that will be generated, which is also the name of
+    /// its binary.
+    ///
+    /// By default, "plugin-cli" is used.
+    pub name: String,
+
+    /// *Additional* dependencies to be listed in the CLI crate that will be
+    /// generated.
+    ///
+    /// The CLI crate contains its own copy of the Rust Wasmer runtime, so
+    /// these should provide the dependencies of custom types that are not
+    /// declared by the types themselves, just like for the Rust plugin.
+    pub dependencies: BTreeMap<&'static str, CargoDependency>,
+}
+
+impl CliConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `name` setting.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_owned();
+        self
+    }
+
+    /// Adds a dependency to the `dependencies` setting.
+    pub fn with_dependency(mut self, name: &'static str, dependency: CargoDependency) -> Self {
+        self.dependencies.insert(name, dependency);
+        self
+    }
+}
+
+impl Default for CliConfig {
+    fn default() -> Self {
+        Self {
+            name: "plugin-cli".to_owned(),
+            dependencies: BTreeMap::new(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct RustTypesConfig {
+    /// Name of the types crate that will be generated.
+    ///
+    /// By default, "types" is used.
+    pub name: String,
+
+    /// Version of the types crate that will be generated.
+    ///
+    /// By default, "0.1.0" is used.
+    pub version: String,
+
+    /// *Additional* dependencies to be listed in the types crate that will be
+    /// generated.
+    ///
+    /// These are merged with `serde` and the dependencies of custom types,
+    /// so they can be used to bump their versions or to override their paths.
+    pub dependencies: BTreeMap<&'static str, CargoDependency>,
+}
+
+impl RustTypesConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `name` setting.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_owned();
+        self
+    }
+
+    /// Sets the `version` setting.
+    pub fn with_version(mut self, version: &str) -> Self {
+        self.version = version.to_owned();
+        self
+    }
+
+    /// Adds a dependency to the `dependencies` setting.
+    pub fn with_dependency(mut self, name: &'static str, dependency: CargoDependency) -> Self {
+        self.dependencies.insert(name, dependency);
+        self
+    }
+}
+
+impl Default for RustTypesConfig {
+    fn default() -> Self {
+        Self {
+            name: "types".to_owned(),
+            version: "0.1.0".to_owned(),
+            dependencies: BTreeMap::new(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct ScaffoldConfig {
+    /// Name of the plugin crate that will be generated.
+    ///
+    /// By default, "plugin" is used.
+    pub name: String,
+
+    /// Name of the crate with the Rust plugin bindings, which the plugin
+    /// depends on. This should match the `name` of the `RustPluginConfig`.
+    ///
+    /// By default, "bindings" is used.
+    pub bindings_name: &'static str,
+
+    /// The dependency on the crate with the Rust plugin bindings.
+    ///
+    /// By default, the crate is expected at `../bindings/rust-plugin`,
+    /// relative to the plugin crate.
+    pub bindings_dependency: CargoDependency,
+
+    /// *Additional* dependencies to be listed in the plugin crate that will be
+    /// generated.
+    ///
+    /// The dependencies of custom types are listed as well, since the stubs
+    /// may refer to their types by path, so these can be used to override
+    /// their paths, just like for the CLI.
+    pub dependencies: BTreeMap<&'static str, CargoDependency>,
+
+    /// The WebAssembly features with which the plugin is compiled. This should
+    /// match the `wasm_features` of the `RustPluginConfig`.
+    pub wasm_features: WasmFeatures,
+}
+
+impl ScaffoldConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `name` setting.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_owned();
+        self
+    }
+
+    /// Sets the `bindings_name` and `bindings_dependency` settings.
+    pub fn with_bindings(mut self, name: &'static str, dependency: CargoDependency) -> Self {
+        self.bindings_name = name;
+        self.bindings_dependency = dependency;
+        self
+    }
+
+    /// Adds a dependency to the `dependencies` setting.
+    pub fn with_dependency(mut self, name: &'static str, dependency: CargoDependency) -> Self {
+        self.dependencies.insert(name, dependency);
+        self
+    }
+
+    /// Sets the `wasm_features` setting.
+    pub fn with_wasm_features(mut self, wasm_features: WasmFeatures) -> Self {
+        self.wasm_features = wasm_features;
+        self
+    }
+}
+
+impl Default for ScaffoldConfig {
+    fn default() -> Self {
+        Self {
+            name: "plugin".to_owned(),
+            bindings_name: "bindings",
+            bindings_dependency: CargoDependency::with_path("../bindings/rust-plugin"),
+            dependencies: BTreeMap::new(),
+            wasm_features: WasmFeatures::default(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct FixturesConfig {
+    /// Name of the Rust crate with the fixture tests that will be generated.
+    ///
+    /// By default, "fixtures" is used.
+    pub name: String,
+
+    /// *Additional* dependencies to be listed in the crate that will be
+    /// generated.
+    ///
+    /// The crate contains its own copy of the types of the Rust runtime, so
+    /// these should provide the dependencies of custom types that are not
+    /// declared by the types themselves, just like for the Rust plugin.
+    pub dependencies: BTreeMap<&'static str, CargoDependency>,
+
+    /// The module from which the TypeScript fixture script imports the
+    /// MessagePack dependency.
+    ///
+    /// By default, "@msgpack/msgpack" is used. This should match the module
+    /// used by the TypeScript runtime, so that the fixtures are decoded by the
+    /// same code.
+    pub msgpack_module: String,
+}
+
+impl FixturesConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `name` setting.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_owned();
+        self
+    }
+
+    /// Adds a dependency to the `dependencies` setting.
+    pub fn with_dependency(mut self, name: &'static str, dependency: CargoDependency) -> Self {
+        self.dependencies.insert(name, dependency);
+        self
+    }
+
+    /// Sets the `msgpack_module` setting.
+    pub fn with_msgpack_module(mut self, msgpack_module: &str) -> Self {
+        self.msgpack_module = msgpack_module.to_owned();
+        self
+    }
+}
+
+impl Default for FixturesConfig {
+    fn default() -> Self {
+        Self {
+            name: "fixtures".to_owned(),
+            dependencies: BTreeMap::new(),
+            msgpack_module: "@msgpack/msgpack".to_owned(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct FuzzConfig {
+    /// Name of the fuzz crate that will be generated.
+    ///
+    /// By default, "fuzz" is used.
+    pub name: String,
+
+    /// *Additional* dependencies to be listed in the fuzz crate that will be
+    /// generated.
+    ///
+    /// The fuzz crate contains its own copy of the types of the Rust runtime,
+    /// so these should provide the dependencies of custom types that are not
+    /// declared by the types themselves, just like for the Rust plugin.
+    pub dependencies: BTreeMap<&'static str, CargoDependency>,
+
+    /// The module from which the TypeScript fuzz script imports the MessagePack
+    /// dependency.
+    ///
+    /// By default, "@msgpack/msgpack" is used. This should match the module
+    /// used by the TypeScript runtime, so that the script fuzzes the same
+    /// deserialization code.
+    pub msgpack_module: String,
+}
+
+impl FuzzConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `name` setting.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_owned();
+        self
+    }
+
+    /// Adds a dependency to the `dependencies` setting.
+    pub fn with_dependency(mut self, name: &'static str, dependency: CargoDependency) -> Self {
+        self.dependencies.insert(name, dependency);
+        self
+    }
+
+    /// Sets the `msgpack_module` setting.
+    pub fn with_msgpack_module(mut self, msgpack_module: &str) -> Self {
+        self.msgpack_module = msgpack_module.to_owned();
+        self
+    }
+}
+
+impl Default for FuzzConfig {
+    fn default() -> Self {
+        Self {
+            name: "fuzz".to_owned(),
+            dependencies: BTreeMap::new(),
+            msgpack_module: "@msgpack/msgpack".to_owned(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct CSharpRuntimeConfig {
+    /// The namespace of the generated C# code.
+    ///
+    /// This is also used as the name of the generated project file. By
+    /// default, "FpRuntime" is used.
+    pub namespace: String,
+
+    /// The target framework of the generated project, such as "net8.0".
+    ///
+    /// The generated code uses `required` members, so .NET 7 or later is
+    /// needed. By default, "net8.0" is used.
+    pub target_framework: String,
+}
+
+impl CSharpRuntimeConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `namespace` setting.
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.namespace = namespace.to_owned();
+        self
+    }
+
+    /// Sets the `target_framework` setting.
+    pub fn with_target_framework(mut self, target_framework: &str) -> Self {
+        self.target_framework = target_framework.to_owned();
+        self
+    }
+}
+
+impl Default for CSharpRuntimeConfig {
+    fn default() -> Self {
+        Self {
+            namespace: "FpRuntime".to_owned(),
+            target_framework: "net8.0".to_owned(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct AssemblyScriptPluginConfig {
+    /// The module from which the generated files import the MessagePack
+    /// implementation.
+    ///
+    /// The bindings use the `Writer` and `Decoder` API of `@wapc/as-msgpack`,
+    /// which is used by default, so any module that provides the same API may
+    /// be used instead.
+    pub msgpack_module: String,
+}
+
+impl AssemblyScriptPluginConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `msgpack_module` setting.
+    pub fn with_msgpack_module(mut self, msgpack_module: &str) -> Self {
+        self.msgpack_module = msgpack_module.to_owned();
+        self
+    }
+}
+
+impl Default for AssemblyScriptPluginConfig {
+    fn default() -> Self {
+        Self {
+            msgpack_module: "@wapc/as-msgpack".to_owned(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct OpenApiConfig {
+    /// The title of the API, as listed in the `info` section of the generated
+    /// document. By default, "Plugin API" is used.
+    pub title: String,
+
+    /// The version of the API, as listed in the `info` section of the
+    /// generated document. By default, "1.0.0" is used.
+    pub version: String,
+}
+
+impl OpenApiConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `title` setting.
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_owned();
+        self
+    }
+
+    /// Sets the `version` setting.
+    pub fn with_version(mut self, version: &str) -> Self {
+        self.version = version.to_owned();
+        self
+    }
+}
+
+impl Default for OpenApiConfig {
+    fn default() -> Self {
+        Self {
+            title: "Plugin API".to_owned(),
+            version: "1.0.0".to_owned(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct DocsConfig {
+    /// The title of the generated document. By default, "Plugin protocol" is
+    /// used.
+    pub title: String,
+}
+
+impl DocsConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `title` setting.
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_owned();
+        self
+    }
+}
+
+impl Default for DocsConfig {
+    fn default() -> Self {
+        Self {
+            title: "Plugin protocol".to_owned(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Default)]
+pub struct RustWasmerExtendedRuntimeConfig {
+    /// Whether or not to generate a `RuntimePool`.
+    ///
+    /// A runtime pool pre-instantiates a number of runtimes for the same
+    /// module using `RuntimePool::with_capacity()`. Runtimes are checked out
+    /// from the pool using `RuntimePool::get()` and are returned to it when
+    /// they are dropped, so that calls on the hot path don't pay the cost of
+    /// instantiation. The pool holds the runtimes themselves, and does not
+    /// change how Wasmer allocates instances.
+    pub generate_runtime_pool: bool,
+
+    /// Whether or not to generate a `SharedRuntime`.
+    ///
+    /// A `Runtime` wraps a single plugin instance, which can only run one call
+    /// at a time, so hosts that call a plugin from multiple threads would
+    /// otherwise have to put it behind a lock of their own. A shared runtime
+    /// is `Send + Sync` and holds a fixed number of instances of the same
+    /// module, which are created using `SharedRuntime::new()`. It has the
+    /// same export methods as the `Runtime`, which run every call on an
+    /// instance that is not in use, and block until one becomes available if
+    /// all of them are busy.
+    pub generate_shared_runtime: bool,
+
+    /// Whether or not to generate OpenTelemetry integration.
+    ///
+    /// If enabled, every call to an export is wrapped in a span, and calls
+    /// from the plugin to imports are handled in a child span. The trace
+    /// context of the current call is passed to plugins that ask for it
+    /// through the `__fp_host_trace_context()` import, so that the trace can
+    /// be continued across other boundaries as well.
+    ///
+    /// The generated bindings require the `opentelemetry` feature of
+    /// `fp-bindgen-support`.
+    pub generate_opentelemetry: bool,
+
+    /// Whether or not to generate constructors that use a `ModuleCache`.
+    ///
+    /// A module cache stores compiled modules on disk, so that a plugin only
+    /// needs to be compiled the first time it is loaded. Runtimes are created
+    /// from the cache using `Runtime::with_module_cache()` or
+    /// `RuntimeBuilder::build_with_cache()`.
+    ///
+    /// The generated bindings require the `module-cache` feature of
+    /// `fp-bindgen-support`.
+    pub generate_module_cache: bool,
+
+    /// Whether or not to generate a limit on concurrent async invocations.
+    ///
+    /// Plugins run their async exports on a single-threaded executor, which
+    /// degrades badly when the host fires hundreds of concurrent calls. If
+    /// enabled, hosts can call `Runtime::with_async_concurrency_limit()` (or
+    /// the `RuntimeBuilder` method of the same name) to limit the number of
+    /// async invocations that run at the same time. Invocations that exceed
+    /// the limit wait for their turn in order of arrival, and once the queue
+    /// is full, they fail with `InvocationError::Overloaded`.
+    pub generate_async_concurrency_limit: bool,
+
+    /// Whether or not to generate the built-in std imports.
+    ///
+    /// If enabled, the runtime provides the imports behind the `read_file()`,
+    /// `write_file()`, `now()` and `random_bytes()` functions of the
+    /// `std-imports` feature of `fp-bindgen-support`, so that plugins don't
+    /// need every protocol to define such facilities. They require the `fs`,
+    /// `clock` and `random` capabilities respectively, and hosts can
+    /// virtualize them by passing a `StdImports` to
+    /// `RuntimeBuilder::with_std_imports()`.
+    ///
+    /// The generated bindings require the `std-imports-host` feature of
+    /// `fp-bindgen-support`.
+    pub generate_std_imports: bool,
+
+    /// Whether or not to generate the built-in HTTP imports.
+    ///
+    /// If enabled, the runtime provides the import behind the async
+    /// `http_request()` function of the `http-imports` feature of
+    /// `fp-bindgen-support`, so that protocols don't need to define their own
+    /// request and response types. It requires the `http` capability, and
+    /// requests are sent using `reqwest`, unless hosts pass an `HttpImports`
+    /// with another client or handler to `RuntimeBuilder::with_http_imports()`.
+    ///
+    /// The generated bindings require the `http-imports-host` feature of
+    /// `fp-bindgen-support`.
+    pub generate_http_imports: bool,
+
+    /// Whether or not to generate routes from imports to the exports of other
+    /// plugins.
+    ///
+    /// If enabled, hosts can call `Runtime::route_import()` to route the calls
+    /// that the plugin makes to an import to the export of the same name of
+    /// another plugin, whose runtime implements `RawExports`. The runtime
+    /// implements `RawExports` itself as well. Arguments and results are
+    /// passed on in their serialized form, without deserializing them in host
+    /// code. Only synchronous imports that are not dynamic, batchable or
+    /// generic can be routed, to exports with the same signature.
+    pub generate_import_routes: bool,
+
+    /// Path of the type of the context that is passed to the host's
+    /// implementations of the imports, if any.
+    ///
+    /// If set, hosts attach a context of this type using
+    /// `RuntimeBuilder::with_context()`, and every import that is implemented
+    /// by the host receives a reference to it as its first argument, so that
+    /// it can access things such as database pools without resorting to
+    /// global state. Async imports receive an `Arc` instead, since their
+    /// futures may outlive the call. The type needs to be `Send + Sync`, and
+    /// its path is resolved from the module of the generated bindings, such
+    /// as `crate::HostContext`.
+    ///
+    /// Calls to imports panic if the runtime was created without a context,
+    /// so runtimes need to be created using the `RuntimeBuilder`.
+    pub host_context: Option<String>,
+
+    /// Whether or not to generate the capture of the output of the plugin.
+    ///
+    /// Plugins that are compiled for `wasm32-unknown-unknown` have no stdout
+    /// or stderr, so anything they print disappears. If enabled, the runtime
+    /// provides the import behind the `println!()` and `eprintln!()` macros of
+    /// the `stdio` feature of `fp-bindgen-support`, and hosts can route the
+    /// printed lines to their logs by passing a handler to
+    /// `RuntimeBuilder::with_output_handler()`. Without a handler, the lines
+    /// are printed to the stdout and stderr of the host.
+    pub generate_stdio_capture: bool,
+
+    /// Whether or not to generate the propagation of deadlines.
+    ///
+    /// If enabled, hosts can attach a deadline to the future of a call to an
+    /// export using `WithDeadline::with_deadline()`, which is passed on to
+    /// plugins that are built with the `deadline` feature of
+    /// `fp-bindgen-support`. Plugins retrieve it using `fp_deadline()`, so
+    /// they can stop early once the host has stopped waiting for the result.
+    /// Calls from the plugin to imports inherit the deadline, so the host's
+    /// implementations can retrieve it using `current_deadline()`.
+    pub generate_deadlines: bool,
+
+    /// Whether or not to generate migrations for renamed fields and variants.
+    ///
+    /// If enabled, a `migrations.rs` file is generated next to the bindings,
+    /// with functions that upgrade payloads which were serialized by plugins
+    /// that were built against a previous version of the protocol. Fields
+    /// and variants declare their previous names using
+    /// `#[fp(renamed_from = "...")]`, and for every type that contains such
+    /// members, a `migrate_*()` function deserializes a payload in which the
+    /// members may still have their previous names. This lets hosts keep
+    /// loading old plugins by calling the raw versions of the exports and
+    /// migrating their results.
+    ///
+    /// The generated migrations require the `migrations` feature of
+    /// `fp-bindgen-support`.
+    pub generate_migrations: bool,
+
+    /// Whether or not to generate overrides of imports per runtime.
+    ///
+    /// If enabled, the `RuntimeBuilder` gets an `override_*()` method for
+    /// every import, which replaces the host's implementation of the import
+    /// with a closure for the runtime that is built. This allows hosts that
+    /// run plugins of different tenants to bake the credentials or behavior
+    /// of each tenant into the imports, while the imports that are not
+    /// overridden keep using the host's implementations. Overrides take the
+    /// same arguments as the host's implementations, and those of async
+    /// imports return a future. Dynamic, event and generic imports cannot be
+    /// overridden.
+    pub generate_import_overrides: bool,
+
+    /// Whether or not to generate verification of plugin signatures.
+    ///
+    /// If enabled, runtimes can be created using
+    /// `Runtime::with_signature_verifier()` or by passing a verifier to
+    /// `RuntimeBuilder::with_signature_verifier()`, which reject plugins that
+    /// are unsigned or whose signature is invalid before they are compiled.
+    /// Plugins are signed using `fp_bindgen_support::common::signatures::sign_module()`.
+    ///
+    /// The generated bindings require the `signatures` feature of
+    /// `fp-bindgen-support`.
+    pub generate_signature_verification: bool,
+
+    /// Whether or not to generate tracing of serialized payloads.
+    ///
+    /// If enabled, every serialized argument and result that is passed to or
+    /// from the plugin is passed to a `PayloadTracer`, which logs the name of
+    /// the function, the direction and the payload in hexadecimal, Base64 or
+    /// as a decoded MessagePack value, so that protocol mismatches can be
+    /// debugged in the field. Hosts set a tracer using
+    /// `RuntimeBuilder::with_payload_tracer()`, which may redact sensitive
+    /// payloads. Runtimes without one log payloads if the
+    /// `FP_BINDGEN_TRACE_PAYLOADS` environment variable is set to `hex`,
+    /// `base64` or `decoded`. Payloads of dynamic, event and batched imports
+    /// are not traced.
+    ///
+    /// The generated bindings require the `payload-tracing` feature of
+    /// `fp-bindgen-support`.
+    pub generate_payload_tracing: bool,
+
+    /// Whether or not to generate eager resolution of exports.
+    ///
+    /// By default, exports are looked up every time they are called, so a
+    /// plugin that exports a function with an unexpected signature is only
+    /// detected once the function is called. If enabled, all exports are
+    /// resolved and checked against the protocol when the runtime is created,
+    /// which fails with `RuntimeError::ExportSignatureMismatch` for exports
+    /// with another signature. Calls use the resolved exports instead of
+    /// looking them up again, and hosts can log which exports the plugin
+    /// omits using `Runtime::missing_exports()`.
+    pub generate_eager_export_resolution: bool,
+}
+
+impl RustWasmerExtendedRuntimeConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables the `generate_runtime_pool` setting.
+    pub fn with_runtime_pool(mut self) -> Self {
+        self.generate_runtime_pool = true;
+        self
+    }
+
+    /// Enables the `generate_shared_runtime` setting.
+    pub fn with_shared_runtime(mut self) -> Self {
+        self.generate_shared_runtime = true;
+        self
+    }
+
+    /// Enables the `generate_opentelemetry` setting.
+    pub fn with_opentelemetry(mut self) -> Self {
+        self.generate_opentelemetry = true;
+        self
+    }
+
+    /// Enables the `generate_module_cache` setting.
+    pub fn with_module_cache(mut self) -> Self {
+        self.generate_module_cache = true;
+        self
+    }
+
+    /// Enables the `generate_async_concurrency_limit` setting.
+    pub fn with_async_concurrency_limit(mut self) -> Self {
+        self.generate_async_concurrency_limit = true;
+        self
+    }
+
+    /// Enables the `generate_std_imports` setting.
+    pub fn with_std_imports(mut self) -> Self {
+        self.generate_std_imports = true;
+        self
+    }
+
+    /// Enables the `generate_http_imports` setting.
+    pub fn with_http_imports(mut self) -> Self {
+        self.generate_http_imports = true;
+        self
+    }
+
+    /// Enables the `generate_import_routes` setting.
+    pub fn with_import_routes(mut self) -> Self {
+        self.generate_import_routes = true;
+        self
+    }
+
+    /// Sets the `host_context` setting to the given type path.
+    pub fn with_host_context(mut self, type_path: &str) -> Self {
+        self.host_context = Some(type_path.to_owned());
+        self
+    }
+
+    /// Enables the `generate_stdio_capture` setting.
+    pub fn with_stdio_capture(mut self) -> Self {
+        self.generate_stdio_capture = true;
+        self
+    }
+
+    /// Enables the `generate_deadlines` setting.
+    pub fn with_deadlines(mut self) -> Self {
+        self.generate_deadlines = true;
+        self
+    }
+
+    /// Enables the `generate_migrations` setting.
+    pub fn with_migrations(mut self) -> Self {
+        self.generate_migrations = true;
+        self
+    }
+
+    /// Enables the `generate_import_overrides` setting.
+    pub fn with_import_overrides(mut self) -> Self {
+        self.generate_import_overrides = true;
+        self
+    }
+
+    /// Enables the `generate_signature_verification` setting.
+    pub fn with_signature_verification(mut self) -> Self {
+        self.generate_signature_verification = true;
+        self
+    }
+
+    /// Enables the `generate_payload_tracing` setting.
+    pub fn with_payload_tracing(mut self) -> Self {
+        self.generate_payload_tracing = true;
+        self
+    }
+
+    /// Enables the `generate_eager_export_resolution` setting.
+    pub fn with_eager_export_resolution(mut self) -> Self {
+        self.generate_eager_export_resolution = true;
+        self
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct TsExtendedRuntimeConfig {
+    /// The module from which to import the MessagePack dependency.
+    ///
+    /// By default, "@msgpack/msgpack" is used, which should work with Node.js
+    /// and most NPM-based bundlers. If you use Deno, you may wish to specify
+    /// "https://unpkg.com/@msgpack/msgpack/mod.ts".
+    pub msgpack_module: String,
+
+    /// Whether or not to generate raw export wrappers.
+    ///
+    /// Raw export wrappers allow you to call `fp_export!` functions from the
+    /// runtime while passing raw MessagePack data, which you can use in some
+    /// situations to avoid (de)serialization overhead. If you don't need these
+    /// wrappers, you can omit them to optimize your bundle size.
+    ///
+    /// Raw export wrappers are named similarly to the regular wrappers (which
+    /// are generated in any case), but with a `Raw` suffix.
+    pub generate_raw_export_wrappers: bool,
+
+    /// Whether or not to generate JavaScript output.
+    ///
+    /// If enabled, an `index.js` file is generated together with `index.d.ts`
+    /// and `types.d.ts` declaration files, instead of the `index.ts` and
+    /// `types.ts` files. This allows the runtime to be used by hosts without a
+    /// TypeScript build step, such as plain Node.js scripts or browsers.
+    ///
+    /// Note that you'll probably want to point `msgpack_module` to a
+    /// JavaScript module as well, such as "https://esm.sh/@msgpack/msgpack".
+    pub generate_js_output: bool,
+
+    /// Whether or not to support compressed payloads.
+    ///
+    /// Plugins that are built with the `compression` feature of
+    /// `fp-bindgen-support` can compress payloads that exceed a configured
+    /// threshold. If enabled, the runtime includes a small decompressor for
+    /// such payloads. Payloads sent to the plugin are never compressed.
+    pub support_compression: bool,
+
+    /// Whether or not to generate string enums.
+    ///
+    /// By default, enums are generated as a union of string literals. If
+    /// enabled, enums of which all variants are unit variants are generated
+    /// as TypeScript string enums instead, which allows iterating over their
+    /// values at runtime. Tagged and untagged enums are not affected.
+    ///
+    /// When combined with `generate_js_output`, a `types.js` file is generated
+    /// for the enum objects.
+    pub generate_string_enums: bool,
+
+    /// Whether or not to generate a Node.js worker wrapper.
+    ///
+    /// If enabled, a `worker.ts` file is generated (or `worker.js` with
+    /// `worker.d.ts` when combined with `generate_js_output`), which runs the
+    /// plugin inside a `worker_threads` Worker so that CPU-heavy plugin calls
+    /// don't block the event loop. Its `createWorkerRuntime()` function
+    /// returns asynchronous proxies for all the exported functions.
+    ///
+    /// Because import functions are called from inside the worker, they are
+    /// loaded from a module that is passed by URL. The worker wrapper needs
+    /// to be loaded as an ES module.
+    pub generate_worker_wrapper: bool,
+
+    /// Whether or not to generate a Web Worker wrapper for browsers.
+    ///
+    /// If enabled, `worker-client.ts` and `worker-runtime.ts` files are
+    /// generated (or `.js` files with `.d.ts` declarations when combined with
+    /// `generate_js_output`). The script of a dedicated Worker calls
+    /// `exposeRuntime()` with the import functions, after which
+    /// `createWorkerClient()` on the main thread instantiates the plugin
+    /// inside the worker and returns asynchronous proxies for all the exported
+    /// functions, which keeps heavy plugin calls off the UI thread.
+    pub generate_browser_worker_wrapper: bool,
+
+    /// Whether or not synchronous imports may be implemented asynchronously.
+    ///
+    /// Many browser APIs, such as `fetch()` and IndexedDB, are inherently
+    /// asynchronous, while the protocol may declare the imports that use them
+    /// as synchronous. If enabled, such imports can be passed to
+    /// `createWorkerClient()` as async functions, which run on the main
+    /// thread while the worker blocks on `Atomics.wait()` until they settle.
+    /// This implies `generate_browser_worker_wrapper`, and requires the page
+    /// to be cross-origin isolated so that `SharedArrayBuffer` is available.
+    pub generate_async_imports: bool,
+
+    /// Whether or not to generate the built-in std imports.
+    ///
+    /// If enabled, the runtime provides the imports behind the `read_file()`,
+    /// `write_file()`, `now()` and `random_bytes()` functions of the
+    /// `std-imports` feature of `fp-bindgen-support`. They require the `fs`,
+    /// `clock` and `random` capabilities respectively, and hosts can
+    /// virtualize them through the optional `std` member of the imports.
+    pub generate_std_imports: bool,
+
+    /// Whether or not to generate the built-in HTTP imports.
+    ///
+    /// If enabled, the runtime provides the import behind the async
+    /// `http_request()` function of the `http-imports` feature of
+    /// `fp-bindgen-support`. It requires the `http` capability, and requests
+    /// are sent using `fetch()`, unless hosts pass another implementation
+    /// through the optional `http` member of the imports.
+    pub generate_http_imports: bool,
+
+    /// Whether or not to generate the capture of the output of the plugin.
+    ///
+    /// If enabled, the runtime provides the import behind the `println!()`
+    /// and `eprintln!()` macros of the `stdio` feature of
+    /// `fp-bindgen-support`. Printed lines are passed to the optional
+    /// `onPrint` member of the imports, or are logged to the console if there
+    /// is none.
+    pub generate_stdio_capture: bool,
+
+    /// Whether or not to generate `Set` types for `BTreeSet` and `HashSet`.
+    ///
+    /// By default, sets are represented as arrays, just like `Vec` and
+    /// `VecDeque`. If enabled, they are typed as `Set<T>` instead, and the
+    /// arrays that the plugin encodes them as are converted when they are
+    /// received. Sets that are sent to the plugin are encoded as arrays again,
+    /// and since the plugin deserializes these into a Rust set, duplicate items
+    /// are dropped either way. Note that JavaScript sets compare objects by
+    /// reference, so sets of structs are best avoided.
+    pub generate_set_types: bool,
+
+    /// Whether or not to generate read-only types.
+    ///
+    /// If enabled, the fields of the generated types are marked `readonly`,
+    /// and lists, sets and maps are typed as `ReadonlyArray<T>`,
+    /// `ReadonlySet<T>` and `Readonly<Record<K, V>>`, also in the signatures
+    /// of functions. This prevents hosts from accidentally mutating values
+    /// that they receive from the plugin.
+    pub generate_readonly_types: bool,
+
+    /// Whether or not to generate branded types.
+    ///
+    /// If enabled, newtype structs that are annotated with `#[fp(brand)]` are
+    /// generated as branded types, such as
+    /// `number & { readonly __brand: "UserId" }`, instead of plain aliases of
+    /// their inner type. Values of a branded type can only be created using
+    /// an explicit cast, which prevents them from being mixed up with other
+    /// values of the same inner type.
+    pub generate_branded_types: bool,
+
+    /// Whether or not to instantiate plugins with a shared memory.
+    ///
+    /// Plugins that are compiled with Wasm threads, such as those that process
+    /// data in parallel, import their memory instead of exporting it. If
+    /// enabled, the runtime creates a `WebAssembly.Memory` with `shared: true`
+    /// and the limits that the plugin declares, and passes it to the plugin as
+    /// `env.memory`. Async values are resolved through `Atomics.notify()`, so
+    /// plugin threads can block on them using the `threads` feature of
+    /// `fp-bindgen-support`. This requires `SharedArrayBuffer` to be
+    /// available, which browsers only allow on cross-origin isolated pages.
+    pub generate_shared_memory: bool,
+
+    /// Whether or not to generate verification of plugin signatures.
+    ///
+    /// If enabled, `RuntimeOptions` accept a `signatureVerifier`, which is
+    /// either the raw Ed25519 public key with which plugins are signed, or a
+    /// callback that verifies their signature. Plugins that are unsigned or
+    /// whose signature is invalid are rejected with an `InvalidSignatureError`
+    /// before they are instantiated. Ed25519 keys are verified using the Web
+    /// Crypto API, which needs to support the `Ed25519` algorithm. Plugins are
+    /// signed using `fp_bindgen_support::common::signatures::sign_module()`.
+    pub generate_signature_verification: bool,
+
+    /// Whether or not to generate tracing of serialized payloads.
     ///
-    /// These are merged with a small set of dependencies that are necessary
-    /// for the plugin to work and which will always be included. Specifying
-    /// these dependencies yourself can be useful if you want to explicitly bump
-    /// a dependency version or you want to enable a Cargo feature in them.
-    pub dependencies: BTreeMap<&'a str, CargoDependency>,
-}
+    /// If enabled, `RuntimeOptions` accept a `tracePayloads` option, which
+    /// logs every serialized argument and result that is passed to or from the
+    /// plugin, in hexadecimal, Base64 or as a decoded MessagePack value. A
+    /// `redact` callback may replace sensitive payloads. If the option is
+    /// omitted, payloads are logged if the `FP_BINDGEN_TRACE_PAYLOADS`
+    /// environment variable is set to `hex`, `base64` or `decoded` in Node.js.
+    pub generate_payload_tracing: bool,
 
-#[non_exhaustive]
-#[derive(Debug, Clone)]
-pub struct TsExtendedRuntimeConfig {
-    /// The module from which to import the MessagePack dependency.
+    /// Whether or not to generate eager resolution of exports.
     ///
-    /// By default, "@msgpack/msgpack" is used, which should work with Node.js
-    /// and most NPM-based bundlers. If you use Deno, you may wish to specify
-    /// "https://unpkg.com/@msgpack/msgpack/mod.ts".
-    pub msgpack_module: String,
+    /// If enabled, the exports of plugins are checked against the signatures
+    /// in the protocol before the plugin is instantiated, and plugins that
+    /// export a function with another signature are rejected with a
+    /// `SignatureMismatchError`, rather than failing once the function is
+    /// called. The exports that the plugin omits are returned by the
+    /// `missingExports()` function of the runtime, so that hosts can log them
+    /// at load time.
+    pub generate_eager_export_resolution: bool,
 
-    /// Whether or not to generate raw export wrappers.
+    /// How the names of functions and their arguments are converted to
+    /// TypeScript identifiers.
     ///
-    /// Raw export wrappers allow you to call `fp_export!` functions from the
-    /// runtime while passing raw MessagePack data, which you can use in some
-    /// situations to avoid (de)serialization overhead. If you don't need these
-    /// wrappers, you can omit them to optimize your bundle size.
+    /// By default, names are converted to camelCase. Note that this doesn't
+    /// affect the names of fields and variants in the generated types, which
+    /// follow their serialized names.
+    pub identifier_casing: TsIdentifierCasing,
+
+    /// How 64-bit integers are represented in TypeScript.
     ///
-    /// Raw export wrappers are named similarly to the regular wrappers (which
-    /// are generated in any case), but with a `Raw` suffix.
-    pub generate_raw_export_wrappers: bool,
+    /// By default, 64-bit integers that are passed as arguments or return
+    /// values are `bigint`s, which `JSON.stringify()` cannot serialize, while
+    /// those inside data types are numbers that silently lose precision beyond
+    /// `Number.MAX_SAFE_INTEGER`. See [TsInt64Encoding] for the alternatives.
+    pub int64_encoding: TsInt64Encoding,
+
+    /// The module syntax and file extensions of the generated modules.
+    ///
+    /// By default, the modules are ES modules that are meant to be processed
+    /// by a bundler. See [TsModuleFormat] for the alternatives.
+    pub module_format: TsModuleFormat,
 }
 
 impl TsExtendedRuntimeConfig {
@@ -99,57 +1524,712 @@ impl TsExtendedRuntimeConfig {
         self.generate_raw_export_wrappers = true;
         self
     }
+
+    /// Enables the `generate_js_output` setting.
+    pub fn with_js_output(mut self) -> Self {
+        self.generate_js_output = true;
+        self
+    }
+
+    /// Enables the `support_compression` setting.
+    pub fn with_compression(mut self) -> Self {
+        self.support_compression = true;
+        self
+    }
+
+    /// Enables the `generate_string_enums` setting.
+    pub fn with_string_enums(mut self) -> Self {
+        self.generate_string_enums = true;
+        self
+    }
+
+    /// Enables the `generate_worker_wrapper` setting.
+    pub fn with_worker_wrapper(mut self) -> Self {
+        self.generate_worker_wrapper = true;
+        self
+    }
+
+    /// Enables the `generate_browser_worker_wrapper` setting.
+    pub fn with_browser_worker_wrapper(mut self) -> Self {
+        self.generate_browser_worker_wrapper = true;
+        self
+    }
+
+    /// Enables the `generate_async_imports` setting, as well as the
+    /// `generate_browser_worker_wrapper` setting it depends on.
+    pub fn with_async_imports(mut self) -> Self {
+        self.generate_browser_worker_wrapper = true;
+        self.generate_async_imports = true;
+        self
+    }
+
+    /// Enables the `generate_std_imports` setting.
+    pub fn with_std_imports(mut self) -> Self {
+        self.generate_std_imports = true;
+        self
+    }
+
+    /// Enables the `generate_http_imports` setting.
+    pub fn with_http_imports(mut self) -> Self {
+        self.generate_http_imports = true;
+        self
+    }
+
+    /// Enables the `generate_stdio_capture` setting.
+    pub fn with_stdio_capture(mut self) -> Self {
+        self.generate_stdio_capture = true;
+        self
+    }
+
+    /// Enables the `generate_set_types` setting.
+    pub fn with_set_types(mut self) -> Self {
+        self.generate_set_types = true;
+        self
+    }
+
+    /// Enables the `generate_readonly_types` setting.
+    pub fn with_readonly_types(mut self) -> Self {
+        self.generate_readonly_types = true;
+        self
+    }
+
+    /// Enables the `generate_branded_types` setting.
+    pub fn with_branded_types(mut self) -> Self {
+        self.generate_branded_types = true;
+        self
+    }
+
+    /// Enables the `generate_shared_memory` setting.
+    pub fn with_shared_memory(mut self) -> Self {
+        self.generate_shared_memory = true;
+        self
+    }
+
+    /// Enables the `generate_signature_verification` setting.
+    pub fn with_signature_verification(mut self) -> Self {
+        self.generate_signature_verification = true;
+        self
+    }
+
+    /// Enables the `generate_payload_tracing` setting.
+    pub fn with_payload_tracing(mut self) -> Self {
+        self.generate_payload_tracing = true;
+        self
+    }
+
+    /// Enables the `generate_eager_export_resolution` setting.
+    pub fn with_eager_export_resolution(mut self) -> Self {
+        self.generate_eager_export_resolution = true;
+        self
+    }
+
+    /// Sets the `identifier_casing` setting.
+    pub fn with_identifier_casing(mut self, identifier_casing: TsIdentifierCasing) -> Self {
+        self.identifier_casing = identifier_casing;
+        self
+    }
+
+    /// Sets the `int64_encoding` setting.
+    pub fn with_int64_encoding(mut self, int64_encoding: TsInt64Encoding) -> Self {
+        self.int64_encoding = int64_encoding;
+        self
+    }
+
+    /// Sets the `module_format` setting. Formats that include CommonJS
+    /// modules also enable the `generate_js_output` setting they depend on.
+    pub fn with_module_format(mut self, module_format: TsModuleFormat) -> Self {
+        if module_format.requires_js_output() {
+            self.generate_js_output = true;
+        }
+        self.module_format = module_format;
+        self
+    }
 }
 
 impl Default for TsExtendedRuntimeConfig {
     fn default() -> Self {
         Self {
+            generate_js_output: false,
+            generate_string_enums: false,
+            generate_worker_wrapper: false,
+            generate_browser_worker_wrapper: false,
+            generate_async_imports: false,
+            generate_std_imports: false,
+            generate_http_imports: false,
+            generate_stdio_capture: false,
+            generate_set_types: false,
+            generate_readonly_types: false,
+            generate_branded_types: false,
+            generate_shared_memory: false,
+            generate_signature_verification: false,
+            generate_payload_tracing: false,
+            generate_eager_export_resolution: false,
             generate_raw_export_wrappers: false,
+            identifier_casing: TsIdentifierCasing::default(),
+            int64_encoding: TsInt64Encoding::default(),
+            module_format: TsModuleFormat::default(),
             msgpack_module: "@msgpack/msgpack".to_owned(),
+            support_compression: false,
         }
     }
 }
 
 impl TsExtendedRuntimeConfig {}
 
+/// Determines how the names of functions and their arguments are converted to
+/// identifiers in the generated TypeScript code.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default)]
+pub enum TsIdentifierCasing {
+    /// Converts names to camelCase, so that `get_user_name` becomes
+    /// `getUserName`.
+    #[default]
+    CamelCase,
+
+    /// Uses names exactly as they are declared in the protocol.
+    Preserve,
+
+    /// Looks up names in the given map, which maps names as they are declared
+    /// in the protocol to their TypeScript identifiers. Names that are not
+    /// contained in the map are converted to camelCase.
+    ///
+    /// This is useful for names that camelCase conversion mangles, such as
+    /// `get_ipv4_addr`, which would otherwise become `getIpv4Addr`.
+    Custom(BTreeMap<String, String>),
+}
+
+impl TsIdentifierCasing {
+    /// Returns the TypeScript identifier for the given function or argument
+    /// name.
+    pub fn format_name(&self, name: &str) -> String {
+        let unprefixed_name = name.strip_prefix("r#").unwrap_or(name);
+        match self {
+            Self::CamelCase => name.to_camel_case(),
+            Self::Preserve => unprefixed_name.to_owned(),
+            Self::Custom(names) => names
+                .get(unprefixed_name)
+                .cloned()
+                .unwrap_or_else(|| name.to_camel_case()),
+        }
+    }
+
+    /// Returns the identifier for the given name with its first letter in
+    /// uppercase, for use as a suffix of other identifiers.
+    pub fn format_pascal_name(&self, name: &str) -> String {
+        let name = self.format_name(name);
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => name,
+        }
+    }
+}
+
+/// Determines how 64-bit integers are represented in the generated TypeScript
+/// code.
+///
+/// This applies to `i64` and `u64` values that are passed as arguments or
+/// return values. Inside data types, they are always decoded as numbers, but
+/// with the `Number` and `String` encodings the runtime throws a `RangeError`
+/// for values that a number cannot represent exactly, rather than silently
+/// losing precision.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TsInt64Encoding {
+    /// Represents 64-bit integers as `bigint`s.
+    #[default]
+    BigInt,
+
+    /// Represents 64-bit integers as numbers, which are checked to be safe
+    /// integers when they are passed to or received from the plugin.
+    Number,
+
+    /// Represents 64-bit integers as decimal strings, which can hold any
+    /// value. Strings that are passed to the plugin are checked to contain an
+    /// integer within the range of the type.
+    String,
+}
+
+/// Determines the module syntax and file extensions of the generated
+/// TypeScript runtime.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TsModuleFormat {
+    /// Generates ES modules that import each other without extensions, which
+    /// works with bundlers. If `msgpack_module` ends with `.ts`, `.ts`
+    /// extensions are used instead, so the modules can be used from Deno.
+    /// JavaScript output always uses `.js` extensions.
+    #[default]
+    Bundler,
+
+    /// Generates ES modules that import each other with `.js` extensions, as
+    /// Node.js requires, which TypeScript resolves to the `.ts` files with the
+    /// `node16` or `nodenext` module resolution.
+    Esm,
+
+    /// Generates CommonJS modules, which import each other using `require()`,
+    /// as `.cjs` files with `.d.cts` declarations. This implies JavaScript
+    /// output. The worker wrappers need ES modules, so they cannot be
+    /// generated in this format.
+    CommonJs,
+
+    /// Generates both ES modules as `.js` files and CommonJS modules as
+    /// `.cjs` files, together with a `package.json` that maps both to the
+    /// same entry points, so the runtime can be published as a package that
+    /// works with both `import` and `require()`. This implies JavaScript
+    /// output. The worker wrappers are only generated as ES modules.
+    Dual,
+}
+
+impl TsModuleFormat {
+    /// Returns whether the format can only be generated as JavaScript.
+    pub(crate) fn requires_js_output(self) -> bool {
+        matches!(self, Self::CommonJs | Self::Dual)
+    }
+}
+
 pub fn generate_bindings(
     import_functions: FunctionList,
-    export_functions: FunctionList,
-    types: TypeMap,
+    mut export_functions: FunctionList,
+    mut types: TypeMap,
     config: BindingConfig,
 ) {
     fs::create_dir_all(config.path).expect("Could not create output directory");
 
+    lifecycle::add_lifecycle_exports(&mut export_functions);
+    register_tuple_return_types(&import_functions, &export_functions, &mut types);
+
+    if let Some(function) = export_functions
+        .iter()
+        .find(|function| function.attrs.dynamic)
+    {
+        panic!(
+            "Export `{}` cannot be dynamic, only imports support `#[fp(dynamic)]`",
+            function.name
+        );
+    }
+    if let Some(function) = export_functions
+        .iter()
+        .find(|function| function.attrs.batchable)
+    {
+        panic!(
+            "Export `{}` cannot be batchable, only imports support `#[fp(batchable)]`",
+            function.name
+        );
+    }
+    if let Some(function) = export_functions
+        .iter()
+        .find(|function| function.attrs.capability.is_some())
+    {
+        panic!(
+            "Export `{}` cannot require a capability, only imports support `#[fp(capability)]`",
+            function.name
+        );
+    }
+    if let Some(function) = export_functions
+        .iter()
+        .find(|function| function.attrs.event)
+    {
+        panic!(
+            "Export `{}` cannot be an event, only imports support `#[fp_event]`",
+            function.name
+        );
+    }
+    if let Some(function) = import_functions
+        .iter()
+        .find(|function| function.attrs.read_only)
+    {
+        panic!(
+            "Import `{}` cannot be read-only, only exports support `#[fp(read_only)]`",
+            function.name
+        );
+    }
+    if let Some(function) = import_functions.iter().find(|function| function.attrs.lazy) {
+        panic!(
+            "Import `{}` cannot be lazy, only exports support `#[fp(lazy)]`",
+            function.name
+        );
+    }
+    if let Some(function) = import_functions
+        .iter()
+        .find(|function| function.attrs.cache.is_some())
+    {
+        panic!(
+            "Import `{}` cannot be cached, only exports support `#[fp(cache)]`",
+            function.name
+        );
+    }
+    for function in export_functions.iter() {
+        if let Some(cache) = &function.attrs.cache {
+            check_cache_options(function, cache);
+        }
+    }
+    if let Some(function) = export_functions
+        .iter()
+        .find(|function| function.attrs.rate_limit.is_some())
+    {
+        panic!(
+            "Export `{}` cannot be rate-limited, only imports support `#[fp(rate_limit)]`",
+            function.name
+        );
+    }
+    for function in import_functions.iter() {
+        if function.attrs.rate_limit.is_some() {
+            check_rate_limited_import(function);
+        }
+    }
+    if let Some(function) = export_functions
+        .iter()
+        .find(|function| !function.generic_params.is_empty())
+    {
+        panic!(
+            "Export `{}` cannot be generic, only imports support type parameters",
+            function.name
+        );
+    }
+    check_wire_names(&import_functions, "Import");
+    check_wire_names(&export_functions, "Export");
+
+    validation::check_validations(&types);
+    columnar::check_columnar_structs(&types);
+    interning::check_interned_fields(&types);
+    aliases::check_registered_types(&import_functions, &export_functions, &types);
+
     display_warnings(&import_functions, &export_functions, &types);
 
+    // Hashes are calculated before any members are dropped, so that all
+    // generators emit the same hashes:
+    let schema_hashes = schema_hash::calculate_schema_hashes(&types);
+
+    // The CLI embeds the Rust Wasmer runtime, so it needs the same members,
+    // while the scaffold implements the exports of the Rust plugin bindings
+    // and the types crate shares their type definitions:
+    let generator = match &config.bindings_type {
+        BindingsType::Cli(_) => "rust-wasmer-runtime".to_owned(),
+        BindingsType::RustTypes(_) | BindingsType::Scaffold(_) => "rust-plugin".to_owned(),
+        bindings_type => bindings_type.to_string(),
+    };
+    let types = retain_generator_members(types, &generator);
+    let types = match config.unused_types {
+        UnusedTypes::Prune => {
+            pruning::prune_unused_types(&import_functions, &export_functions, types)
+        }
+        UnusedTypes::Keep => types,
+    };
+    let (import_functions, export_functions, types) = match config.aliases {
+        AliasMode::Preserve => (import_functions, export_functions, types),
+        AliasMode::Expand => aliases::expand_aliases(import_functions, export_functions, types),
+    };
+    let (import_functions, export_functions, types) =
+        columnar::with_columns(import_functions, export_functions, types);
+
+    // Rust has tuples of its own, while other languages receive the tuples
+    // that functions return as a tuple struct:
+    let (import_functions, export_functions, types) = match &config.bindings_type {
+        BindingsType::AssemblyScriptPlugin(_)
+        | BindingsType::CSharpRuntime(_)
+        | BindingsType::GraphQl
+        | BindingsType::OpenApi(_)
+        | BindingsType::TsRuntimeWithExtendedConfig(_) => {
+            tuple_returns::with_tuple_return_structs(import_functions, export_functions, types)
+        }
+        _ => (import_functions, export_functions, types),
+    };
+
+    // Only plugins implement `shutdown`, while runtimes call it when they are
+    // dropped or disposed:
+    let export_functions = match &config.bindings_type {
+        BindingsType::AssemblyScriptPlugin(_)
+        | BindingsType::RustPlugin(_)
+        | BindingsType::Scaffold(_) => export_functions,
+        _ => lifecycle::without_shutdown_export(export_functions),
+    };
+
+    formatting::start_formatting(config.formatting);
+
     match config.bindings_type {
+        BindingsType::AssemblyScriptPlugin(plugin_config) => {
+            assemblyscript_plugin::generate_bindings(
+                import_functions,
+                export_functions,
+                types,
+                plugin_config,
+                &config.headers,
+                config.path,
+            )
+        }
+        BindingsType::Benches(benches_config) => benches::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            benches_config,
+            &config.headers,
+            config.path,
+        ),
+        BindingsType::Cli(cli_config) => cli::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            &schema_hashes,
+            cli_config,
+            &config.headers,
+            config.path,
+        ),
+        BindingsType::CSharpRuntime(csharp_config) => csharp_runtime::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            csharp_config,
+            &config.headers,
+            config.path,
+        ),
+        BindingsType::Docs(docs_config) => docs::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            docs_config,
+            &config.headers,
+            config.path,
+        ),
+        BindingsType::Fixtures(fixtures_config) => fixtures::generate_bindings(
+            types,
+            &schema_hashes,
+            fixtures_config,
+            &config.headers,
+            config.path,
+        ),
+        BindingsType::Fuzz(fuzz_config) => fuzz::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            &schema_hashes,
+            fuzz_config,
+            &config.headers,
+            config.path,
+        ),
+        BindingsType::GraphQl => {
+            graphql::generate_bindings(export_functions, types, &config.headers, config.path)
+        }
+        BindingsType::OpenApi(openapi_config) => openapi::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            openapi_config,
+            &config.headers,
+            config.path,
+        ),
         BindingsType::RustPlugin(plugin_config) => rust_plugin::generate_bindings(
             import_functions,
             export_functions,
             types,
+            &schema_hashes,
             plugin_config,
+            &config.headers,
+            config.path,
+        ),
+        BindingsType::RustTypes(types_config) => rust_types::generate_bindings(
+            types,
+            &schema_hashes,
+            types_config,
+            &config.headers,
             config.path,
         ),
         BindingsType::RustWasmerRuntime => rust_wasmer_runtime::generate_bindings(
             import_functions,
             export_functions,
             types,
+            &schema_hashes,
+            RustWasmerExtendedRuntimeConfig::default(),
+            &config.headers,
             config.path,
         ),
+        BindingsType::RustWasmerRuntimeWithExtendedConfig(runtime_config) => {
+            rust_wasmer_runtime::generate_bindings(
+                import_functions,
+                export_functions,
+                types,
+                &schema_hashes,
+                runtime_config,
+                &config.headers,
+                config.path,
+            )
+        }
         BindingsType::RustWasmerWasiRuntime => rust_wasmer_wasi_runtime::generate_bindings(
             import_functions,
             export_functions,
             types,
+            &schema_hashes,
+            &config.headers,
+            config.path,
+        ),
+        BindingsType::RustWasmiRuntime => rust_wasmi_runtime::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            &schema_hashes,
+            &config.headers,
+            config.path,
+        ),
+        BindingsType::Scaffold(scaffold_config) => scaffold::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            scaffold_config,
+            &config.headers,
             config.path,
         ),
         BindingsType::TsRuntimeWithExtendedConfig(runtime_config) => ts_runtime::generate_bindings(
             import_functions,
             export_functions,
             types,
+            &schema_hashes,
             runtime_config,
+            &config.headers,
             config.path,
         ),
     };
+
+    formatting::finish_formatting();
+}
+
+/// The capabilities that are required by the built-in std imports, which
+/// are granted to plugins like those of the protocol.
+pub(crate) const STD_IMPORT_CAPABILITIES: [&str; 3] = ["clock", "fs", "random"];
+
+/// The capability that is required by the built-in HTTP imports.
+pub(crate) const HTTP_IMPORT_CAPABILITY: &str = "http";
+
+/// Names of the generators, as they may be used in `#[fp(generators = "...")]`
+/// attributes.
+const GENERATOR_NAMES: &[&str] = &[
+    "assemblyscript-plugin",
+    "benches",
+    "csharp-runtime",
+    "fuzz",
+    "graphql",
+    "openapi",
+    "rust-plugin",
+    "rust-wasmer-runtime",
+    "rust-wasmer-wasi-runtime",
+    "rust-wasmi-runtime",
+    "ts-runtime",
+];
+
+/// Generators that include `PhantomData` fields in their Rust types, either
+/// because they generate those types or because they construct values of them.
+const PHANTOM_DATA_GENERATORS: &[&str] = &[
+    "benches",
+    "fixtures",
+    "rust-plugin",
+    "rust-wasmer-runtime",
+    "rust-wasmer-wasi-runtime",
+    "rust-wasmi-runtime",
+];
+
+/// Removes the fields and variants that are restricted to other generators
+/// through `#[fp(generators = "...")]` attributes, as well as `PhantomData`
+/// fields for generators that don't include them.
+fn retain_generator_members(mut types: TypeMap, generator: &str) -> TypeMap {
+    let keeps_phantom_data = PHANTOM_DATA_GENERATORS.contains(&generator);
+    let check_names = |names: &[String], member: &str, ident: &TypeIdent| {
+        if let Some(name) = names
+            .iter()
+            .find(|name| !GENERATOR_NAMES.contains(&name.as_str()))
+        {
+            panic!(
+                "Unknown generator `{name}` in `#[fp(generators)]` attribute of {member} in type \
+                `{ident}`. Expected one of: {}",
+                GENERATOR_NAMES.join(", ")
+            );
+        }
+    };
+    let retain_fields = |ty: &mut Struct, ident: &TypeIdent| {
+        ty.fields.retain(|field| {
+            check_names(&field.attrs.generators, "a field", ident);
+            field.attrs.is_included_in(generator)
+                && (keeps_phantom_data || !field.ty.is_phantom_data())
+        });
+    };
+
+    for (ident, ty) in types.iter_mut() {
+        match ty {
+            Type::Struct(ty) => retain_fields(ty, ident),
+            Type::Enum(ty) => {
+                ty.variants.retain(|variant| {
+                    check_names(&variant.attrs.generators, "a variant", ident);
+                    variant.attrs.is_included_in(generator)
+                });
+                for variant in ty.variants.iter_mut() {
+                    if let Type::Struct(ty) = &mut variant.ty {
+                        retain_fields(ty, ident);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    types
+}
+
+/// Checks that no two functions are exchanged under the same name, which
+/// may happen when a function is renamed to the name of another.
+fn check_wire_names(functions: &FunctionList, kind: &str) {
+    let mut names = BTreeMap::new();
+    for function in functions.iter() {
+        if let Some(other) = names.insert(function.wire_name(), &function.name) {
+            panic!(
+                "{} `{}` has the same name on the wire as `{}`: `{}`",
+                kind,
+                function.name,
+                other,
+                function.wire_name()
+            );
+        }
+    }
+}
+
+/// Checks that the results of the given export can be cached.
+fn check_cache_options(function: &Function, cache: &CacheOptions) {
+    match &function.return_type {
+        Some(ty) if !ty.is_primitive() && !ty.is_packed_pair() => {}
+        _ => panic!(
+            "Export `{}` cannot be cached, because it does not return a non-primitive type",
+            function.name
+        ),
+    }
+    if let Some(arg) = cache
+        .key_args(function)
+        .into_iter()
+        .find(|name| !function.args.iter().any(|arg| arg.name == *name))
+    {
+        panic!(
+            "Cache key of export `{}` refers to unknown argument `{}`",
+            function.name, arg
+        );
+    }
+}
+
+/// Checks that the given import can be rate-limited, which requires it to
+/// return the `RateLimited` error to the plugin.
+fn check_rate_limited_import(function: &Function) {
+    if function.attrs.dynamic || function.attrs.batchable {
+        panic!(
+            "Import `{}` cannot be rate-limited, because it is dynamic or batchable",
+            function.name
+        );
+    }
+    let returns_rate_limited = matches!(
+        &function.return_type,
+        Some(ty) if ty.name == "Result"
+            && matches!(ty.generic_args.get(1), Some((error, _)) if error.name == "RateLimited")
+    );
+    if !returns_rate_limited {
+        panic!(
+            "Import `{}` cannot be rate-limited, because it does not return `Result<T, RateLimited>`",
+            function.name
+        );
+    }
 }
 
 fn display_warnings(
@@ -201,6 +2281,10 @@ fn display_warnings(
         "generic argument",
         types,
     );
+
+    for lint in lints::lint_protocol(import_functions, export_functions, types) {
+        println!("WARNING: {lint}");
+    }
 }
 
 fn warn_about_custom_serializer_usage<'a, T>(idents: T, context: &str, types: &TypeMap)
@@ -233,3 +2317,116 @@ where
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ts_identifier_casing() {
+        let casing = TsIdentifierCasing::CamelCase;
+        assert_eq!(casing.format_name("get_ipv4_addr"), "getIpv4Addr");
+        assert_eq!(casing.format_pascal_name("my_import"), "MyImport");
+
+        let casing = TsIdentifierCasing::Preserve;
+        assert_eq!(casing.format_name("get_ipv4_addr"), "get_ipv4_addr");
+        assert_eq!(casing.format_name("r#type"), "type");
+        assert_eq!(casing.format_pascal_name("my_import"), "My_import");
+
+        let casing = TsIdentifierCasing::Custom(BTreeMap::from([(
+            "get_ipv4_addr".to_owned(),
+            "getIPv4Addr".to_owned(),
+        )]));
+        assert_eq!(casing.format_name("get_ipv4_addr"), "getIPv4Addr");
+        assert_eq!(casing.format_name("get_user_name"), "getUserName");
+    }
+
+    #[test]
+    fn file_headers_by_extension() {
+        let headers = FileHeaders::new()
+            .with_header("rs", "#![allow(clippy::all)]")
+            .with_header("ts", "/* eslint-disable */");
+        assert_eq!(
+            headers.header_for("bindings/rust-plugin/src/lib.rs"),
+            Some("#![allow(clippy::all)]")
+        );
+        assert_eq!(
+            headers.header_for("bindings/ts-runtime/index.d.ts"),
+            Some("/* eslint-disable */")
+        );
+        assert_eq!(headers.header_for("bindings/openapi/openapi.json"), None);
+        assert_eq!(headers.header_for("bindings/rust-plugin/Cargo.toml"), None);
+    }
+
+    #[test]
+    fn retain_generator_members_per_generator() {
+        let types = [
+            "struct Diagnostics {
+                pub message: String,
+                #[fp(generators = \"rust-plugin, rust-wasmer-runtime\")]
+                pub backtrace: Option<String>,
+            }",
+            "enum Event {
+                Started,
+                #[fp(generators = \"ts-runtime\")]
+                Rendered { #[fp(generators = \"rust-plugin\")] frames: u32 },
+            }",
+        ]
+        .iter()
+        .map(|item| {
+            let ty = Type::from_item(item);
+            (TypeIdent::from(ty.name().as_str()), ty)
+        })
+        .collect::<TypeMap>();
+
+        let field_names = |types: &TypeMap| match types.get(&TypeIdent::from("Diagnostics")) {
+            Some(Type::Struct(ty)) => ty
+                .fields
+                .iter()
+                .map(|field| field.name.clone().unwrap())
+                .collect::<Vec<_>>(),
+            _ => unreachable!(),
+        };
+        let variants = |types: &TypeMap| match types.get(&TypeIdent::from("Event")) {
+            Some(Type::Enum(ty)) => ty.variants.clone(),
+            _ => unreachable!(),
+        };
+
+        let rust_types = retain_generator_members(types.clone(), "rust-plugin");
+        assert_eq!(field_names(&rust_types), vec!["message", "backtrace"]);
+        assert_eq!(variants(&rust_types).len(), 1);
+
+        let ts_types = retain_generator_members(types, "ts-runtime");
+        assert_eq!(field_names(&ts_types), vec!["message"]);
+        let variants = variants(&ts_types);
+        assert_eq!(variants.len(), 2);
+        assert!(matches!(&variants[1].ty, Type::Struct(ty) if ty.fields.is_empty()));
+    }
+
+    #[test]
+    fn retain_generator_members_phantom_data() {
+        let ty = Type::from_item("struct TypedId<T> { pub id: u64, pub marker: PhantomData<T> }");
+        let types = TypeMap::from([(TypeIdent::from("TypedId<T>"), ty)]);
+        let field_count = |types: &TypeMap| match types.get(&TypeIdent::from("TypedId<T>")) {
+            Some(Type::Struct(ty)) => ty.fields.len(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(
+            field_count(&retain_generator_members(types.clone(), "rust-plugin")),
+            2
+        );
+        assert_eq!(
+            field_count(&retain_generator_members(types, "ts-runtime")),
+            1
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown generator `ts`")]
+    fn retain_generator_members_unknown_generator() {
+        let ty = Type::from_item("struct Foo { #[fp(generators = \"ts\")] pub bar: u32 }");
+        let types = TypeMap::from([(TypeIdent::from("Foo"), ty)]);
+        retain_generator_members(types, "ts-runtime");
+    }
+}
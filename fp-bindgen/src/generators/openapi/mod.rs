@@ -0,0 +1,225 @@
+mod schemas;
+
+use crate::{
+    functions::{Function, FunctionList, HttpEndpoint},
+    generators::write_bindings_file,
+    types::{Type, TypeIdent, TypeMap},
+    FileHeaders, OpenApiConfig,
+};
+use schemas::{format_description, SchemaCollector};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// The version of the OpenAPI specification that generated documents adhere
+/// to.
+const OPENAPI_VERSION: &str = "3.1.0";
+
+/// The top-level OpenAPI document.
+///
+/// This is a struct rather than a JSON value, so that the sections are
+/// written in their conventional order.
+#[derive(Serialize)]
+struct Document {
+    openapi: &'static str,
+    info: Info,
+    paths: BTreeMap<String, BTreeMap<String, Value>>,
+    components: Components,
+}
+
+#[derive(Serialize)]
+struct Info {
+    title: String,
+    version: String,
+}
+
+#[derive(Serialize)]
+struct Components {
+    schemas: BTreeMap<String, Value>,
+}
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: OpenApiConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let mut collector = SchemaCollector::new(&types);
+    let mut paths = BTreeMap::<String, BTreeMap<String, Value>>::new();
+    for function in import_functions.iter().chain(export_functions.iter()) {
+        let http = match &function.attrs.http {
+            Some(http) => http,
+            None => continue,
+        };
+
+        let operation = format_operation(function, http, &types, &mut collector);
+        let operations = paths.entry(http.path.clone()).or_default();
+        if operations
+            .insert(http.method.to_lowercase(), operation)
+            .is_some()
+        {
+            panic!(
+                "Function `{}` uses HTTP endpoint `{}`, which is already used by another function",
+                function.name, http
+            );
+        }
+    }
+
+    let document = Document {
+        openapi: OPENAPI_VERSION,
+        info: Info {
+            title: config.title,
+            version: config.version,
+        },
+        paths,
+        components: Components {
+            schemas: collector.into_components(),
+        },
+    };
+
+    let contents =
+        serde_json::to_string_pretty(&document).expect("Could not serialize OpenAPI document");
+    write_bindings_file(
+        format!("{path}/openapi.json"),
+        format!("{contents}\n"),
+        headers,
+    );
+}
+
+/// Formats the operation for a function.
+///
+/// Arguments that are bound to path parameters are passed in the path. The
+/// remaining arguments are passed as query parameters for methods that don't
+/// take a request body, or as a JSON request body otherwise.
+fn format_operation(
+    function: &Function,
+    http: &HttpEndpoint,
+    types: &TypeMap,
+    collector: &mut SchemaCollector,
+) -> Value {
+    let path_params = http.path_params().collect::<Vec<_>>();
+    let takes_body = !matches!(http.method.as_str(), "DELETE" | "GET" | "HEAD" | "OPTIONS");
+
+    let mut parameters = Vec::new();
+    let mut body_args = Vec::new();
+    for arg in &function.args {
+        if path_params.contains(&arg.name_without_prefix()) {
+            parameters.push(json!({
+                "name": arg.name_without_prefix(),
+                "in": "path",
+                "required": true,
+                "schema": collector.schema(&arg.ty),
+            }));
+        } else if takes_body {
+            body_args.push(arg);
+        } else {
+            parameters.push(json!({
+                "name": arg.name_without_prefix(),
+                "in": "query",
+                "required": !is_option(&arg.ty, types),
+                "schema": collector.schema(&arg.ty),
+            }));
+        }
+    }
+
+    let mut operation = Map::new();
+    operation.insert("operationId".to_owned(), json!(function.name));
+    if let Some(description) = format_description(&function.doc_lines) {
+        operation.insert("description".to_owned(), json!(description));
+    }
+    if !parameters.is_empty() {
+        operation.insert("parameters".to_owned(), json!(parameters));
+    }
+
+    let body_schema = match body_args.as_slice() {
+        [] => None,
+        [arg] => Some(collector.schema(&arg.ty)),
+        args => {
+            // Multiple arguments are passed as an object keyed by their names:
+            let properties = args
+                .iter()
+                .map(|arg| {
+                    (
+                        arg.name_without_prefix().to_owned(),
+                        collector.schema(&arg.ty),
+                    )
+                })
+                .collect::<Map<_, _>>();
+            let required = args
+                .iter()
+                .filter(|arg| !is_option(&arg.ty, types))
+                .map(|arg| arg.name_without_prefix().to_owned())
+                .collect::<Vec<_>>();
+            Some(json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+            }))
+        }
+    };
+    if let Some(schema) = body_schema {
+        operation.insert(
+            "requestBody".to_owned(),
+            json!({
+                "required": true,
+                "content": { "application/json": { "schema": schema } },
+            }),
+        );
+    }
+
+    operation.insert(
+        "responses".to_owned(),
+        format_responses(function.return_type.as_ref(), types, collector),
+    );
+
+    Value::Object(operation)
+}
+
+/// Formats the responses for a function with the given return type.
+///
+/// Functions that return a `Result` respond with the `Ok` value on success,
+/// and with the `Err` value otherwise.
+fn format_responses(
+    return_type: Option<&TypeIdent>,
+    types: &TypeMap,
+    collector: &mut SchemaCollector,
+) -> Value {
+    let mut responses = Map::new();
+    let success_type = match return_type {
+        Some(ty) if ty.name == "Result" && ty.generic_args.len() == 2 => {
+            let (error_type, _) = &ty.generic_args[1];
+            responses.insert(
+                "default".to_owned(),
+                format_response("Error", Some(collector.schema(error_type))),
+            );
+            Some(&ty.generic_args[0].0)
+        }
+        other => other,
+    };
+
+    match success_type {
+        Some(ty) if !matches!(types.get(ty), Some(Type::Unit)) => responses.insert(
+            "200".to_owned(),
+            format_response("Success", Some(collector.schema(ty))),
+        ),
+        _ => responses.insert("204".to_owned(), format_response("No content", None)),
+    };
+
+    Value::Object(responses)
+}
+
+fn format_response(description: &str, schema: Option<Value>) -> Value {
+    match schema {
+        Some(schema) => json!({
+            "description": description,
+            "content": { "application/json": { "schema": schema } },
+        }),
+        None => json!({ "description": description }),
+    }
+}
+
+fn is_option(ty: &TypeIdent, types: &TypeMap) -> bool {
+    matches!(types.get(ty), Some(Type::Container(name, _)) if name == "Option")
+}
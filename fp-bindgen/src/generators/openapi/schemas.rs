@@ -0,0 +1,569 @@
+use crate::{
+    casing::Casing,
+    primitives::Primitive,
+    types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// Converts protocol types to JSON schemas, while collecting the schemas of
+/// structs and enums so they can be listed as components and referenced by
+/// name.
+pub(super) struct SchemaCollector<'a> {
+    types: &'a TypeMap,
+    components: BTreeMap<String, Value>,
+}
+
+/// The properties of an object schema.
+#[derive(Default)]
+struct ObjectSchema {
+    properties: Map<String, Value>,
+    required: Vec<String>,
+    additional_properties: Option<Value>,
+}
+
+impl ObjectSchema {
+    fn into_schema(self) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".to_owned(), json!("object"));
+        if !self.properties.is_empty() {
+            schema.insert("properties".to_owned(), Value::Object(self.properties));
+        }
+        if !self.required.is_empty() {
+            schema.insert("required".to_owned(), json!(self.required));
+        }
+        if let Some(additional_properties) = self.additional_properties {
+            schema.insert("additionalProperties".to_owned(), additional_properties);
+        }
+        Value::Object(schema)
+    }
+}
+
+impl<'a> SchemaCollector<'a> {
+    pub fn new(types: &'a TypeMap) -> Self {
+        Self {
+            types,
+            components: BTreeMap::new(),
+        }
+    }
+
+    pub fn into_components(self) -> BTreeMap<String, Value> {
+        self.components
+    }
+
+    /// Returns the schema for the given type.
+    ///
+    /// Structs, enums and aliases are returned as a reference to a component.
+    pub fn schema(&mut self, ident: &TypeIdent) -> Value {
+        let ty = match self.types.get(ident) {
+            Some(ty) => ty,
+            None => return json!({}), // Must be a generic.
+        };
+
+        match ty {
+            Type::Alias(name, target) => {
+                if !self.components.contains_key(name) {
+                    self.components.insert(name.clone(), Value::Null);
+                    let schema = self.schema(target);
+                    self.components.insert(name.clone(), schema);
+                }
+                json!({ "$ref": format!("#/components/schemas/{name}") })
+            }
+            Type::Array(primitive, size) => json!({
+                "type": "array",
+                "items": format_primitive(*primitive),
+                "minItems": size,
+                "maxItems": size,
+            }),
+            Type::Container(name, _) => {
+                let item = self.schema(first_generic_arg(ident));
+                if name == "Option" {
+                    json!({ "anyOf": [item, { "type": "null" }] })
+                } else {
+                    item
+                }
+            }
+            Type::Custom(custom) => format_custom_type(custom),
+            Type::Enum(_) | Type::Struct(_) if !is_transparent(ty) => {
+                let name = format_component_name(ident);
+                if !self.components.contains_key(&name) {
+                    // Insert a placeholder first, so recursive types don't
+                    // cause infinite recursion:
+                    self.components.insert(name.clone(), Value::Null);
+                    let schema = match ty {
+                        Type::Enum(ty) => self.enum_schema(ty, ident),
+                        Type::Struct(ty) => self.struct_schema(ty, ident),
+                        _ => unreachable!(),
+                    };
+                    self.components.insert(name.clone(), schema);
+                }
+                json!({ "$ref": format!("#/components/schemas/{name}") })
+            }
+            Type::Enum(_) => unreachable!("Enums are never transparent"),
+            Type::List(name, _) => {
+                let mut schema = json!({
+                    "type": "array",
+                    "items": self.schema(first_generic_arg(ident)),
+                });
+                if name.ends_with("Set") {
+                    schema["uniqueItems"] = json!(true);
+                }
+                schema
+            }
+            Type::Map(_, _, _) => {
+                let (value, _) = ident
+                    .generic_args
+                    .get(1)
+                    .expect("Identifier was expected to contain two arguments");
+                json!({
+                    "type": "object",
+                    "additionalProperties": self.schema(value),
+                })
+            }
+            Type::Primitive(primitive) => format_primitive(*primitive),
+            Type::String => json!({ "type": "string" }),
+            Type::Struct(ty) => {
+                let items = ty
+                    .fields
+                    .iter()
+                    .map(|field| substitute_generic_args(&field.ty, &ty.ident, ident))
+                    .collect::<Vec<_>>();
+                match items.as_slice() {
+                    [item] => self.schema(item),
+                    items => self.tuple_schema(items),
+                }
+            }
+            Type::Tuple(items) => self.tuple_schema(items),
+            Type::Unit => json!({ "type": "null" }),
+        }
+    }
+
+    fn tuple_schema(&mut self, items: &[TypeIdent]) -> Value {
+        json!({
+            "type": "array",
+            "prefixItems": items.iter().map(|item| self.schema(item)).collect::<Vec<_>>(),
+            "minItems": items.len(),
+            "maxItems": items.len(),
+        })
+    }
+
+    fn struct_schema(&mut self, ty: &Struct, ident: &TypeIdent) -> Value {
+        let mut object = ObjectSchema::default();
+        self.collect_properties(
+            &mut object,
+            &ty.fields,
+            ty.options.field_casing,
+            &ty.ident,
+            ident,
+        );
+        if ty.options.deny_unknown_fields {
+            object.additional_properties = Some(json!(false));
+        }
+        with_description(object.into_schema(), &ty.doc_lines)
+    }
+
+    /// Collects the properties for the given fields into the object schema,
+    /// with the fields of flattened structs inlined.
+    ///
+    /// Generic arguments in the field types are substituted using the
+    /// declaration and the identifier by which it is used.
+    fn collect_properties(
+        &mut self,
+        object: &mut ObjectSchema,
+        fields: &[Field],
+        casing: Casing,
+        decl: &TypeIdent,
+        usage: &TypeIdent,
+    ) {
+        for field in fields {
+            let ty = substitute_generic_args(&field.ty, decl, usage);
+            if field.attrs.flatten {
+                match self.types.get(&ty) {
+                    Some(Type::Struct(flattened)) if !is_transparent_struct(flattened) => self
+                        .collect_properties(
+                            object,
+                            &flattened.fields,
+                            flattened.options.field_casing,
+                            &flattened.ident,
+                            &ty,
+                        ),
+                    _ => {
+                        object.additional_properties = Some(match self.schema(&ty) {
+                            Value::Object(mut schema) => schema
+                                .remove("additionalProperties")
+                                .unwrap_or_else(|| json!({})),
+                            _ => json!({}),
+                        })
+                    }
+                }
+                continue;
+            }
+
+            let name = field.name.as_deref().unwrap_or_default();
+            let key = field
+                .attrs
+                .rename
+                .clone()
+                .unwrap_or_else(|| casing.format_string(get_variable_name(name)));
+            let schema = if field.attrs.has_custom_serializer() || field.attrs.ts_type.is_some() {
+                // The wire shape of fields with custom (de)serializers can
+                // only be derived from their TypeScript type:
+                field
+                    .attrs
+                    .ts_type
+                    .as_deref()
+                    .map(format_ts_type)
+                    .unwrap_or_else(|| json!({}))
+            } else {
+                self.schema(&ty)
+            };
+
+            let is_optional = matches!(
+                self.types.get(&ty),
+                Some(Type::Container(name, _)) if name == "Option"
+            );
+            if !is_optional
+                && field.attrs.default.is_none()
+                && field.attrs.skip_serializing_if.is_none()
+            {
+                object.required.push(key.clone());
+            }
+
+            object
+                .properties
+                .insert(key, with_description(schema, &field.doc_lines));
+        }
+    }
+
+    fn enum_schema(&mut self, ty: &Enum, ident: &TypeIdent) -> Value {
+        let options = &ty.options;
+        let schema = if is_string_enum(ty) {
+            json!({
+                "type": "string",
+                "enum": ty
+                    .variants
+                    .iter()
+                    .map(|variant| get_variant_name(variant, options))
+                    .collect::<Vec<_>>(),
+            })
+        } else {
+            let variants = ty
+                .variants
+                .iter()
+                .map(|variant| {
+                    let schema = self.variant_schema(variant, options, &ty.ident, ident);
+                    with_description(schema, &variant.doc_lines)
+                })
+                .collect::<Vec<_>>();
+            if options.untagged {
+                json!({ "anyOf": variants })
+            } else {
+                json!({ "oneOf": variants })
+            }
+        };
+
+        with_description(schema, &ty.doc_lines)
+    }
+
+    fn variant_schema(
+        &mut self,
+        variant: &Variant,
+        options: &EnumOptions,
+        decl: &TypeIdent,
+        usage: &TypeIdent,
+    ) -> Value {
+        let name = get_variant_name(variant, options);
+
+        let mut object = ObjectSchema::default();
+        let content = match &variant.ty {
+            Type::Struct(variant_struct) => {
+                if options.tag_prop_name.is_some() && options.content_prop_name.is_none() {
+                    // Internally tagged variants are inlined next to the tag:
+                    self.collect_properties(
+                        &mut object,
+                        &variant_struct.fields,
+                        variant.attrs.field_casing,
+                        decl,
+                        usage,
+                    );
+                    None
+                } else {
+                    let mut content = ObjectSchema::default();
+                    self.collect_properties(
+                        &mut content,
+                        &variant_struct.fields,
+                        variant.attrs.field_casing,
+                        decl,
+                        usage,
+                    );
+                    Some(content.into_schema())
+                }
+            }
+            Type::Tuple(items) => {
+                let items = items
+                    .iter()
+                    .map(|item| substitute_generic_args(item, decl, usage))
+                    .collect::<Vec<_>>();
+                Some(match items.as_slice() {
+                    [item] => self.schema(item),
+                    items => self.tuple_schema(items),
+                })
+            }
+            _ => None,
+        };
+
+        match (&options.tag_prop_name, &options.content_prop_name) {
+            (Some(tag), Some(content_prop)) => {
+                object
+                    .properties
+                    .insert(tag.clone(), json!({ "const": name }));
+                object.required.push(tag.clone());
+                if let Some(content) = content {
+                    object.properties.insert(content_prop.clone(), content);
+                    object.required.push(content_prop.clone());
+                }
+                object.into_schema()
+            }
+            (Some(tag), None) => {
+                object
+                    .properties
+                    .insert(tag.clone(), json!({ "const": name }));
+                object.required.insert(0, tag.clone());
+                match content {
+                    Some(content) => json!({ "allOf": [object.into_schema(), content] }),
+                    None => object.into_schema(),
+                }
+            }
+            (None, _) if options.untagged => content.unwrap_or_else(|| json!({ "type": "null" })),
+            (None, _) => match content {
+                Some(content) => {
+                    object.properties.insert(name.clone(), content);
+                    object.required.push(name);
+                    object.additional_properties = Some(json!(false));
+                    object.into_schema()
+                }
+                None => json!({ "const": name }),
+            },
+        }
+    }
+}
+
+/// Returns whether the given type is serialized as the value(s) it wraps, in
+/// which case it is inlined rather than listed as a component.
+fn is_transparent(ty: &Type) -> bool {
+    matches!(ty, Type::Struct(ty) if is_transparent_struct(ty))
+}
+
+fn is_transparent_struct(ty: &Struct) -> bool {
+    !ty.fields.is_empty() && ty.fields.iter().all(|field| field.name.is_none())
+}
+
+/// Returns whether the given enum is serialized as a plain string. This is the
+/// case if all its variants are unit variants and it is not tagged.
+fn is_string_enum(ty: &Enum) -> bool {
+    ty.options.tag_prop_name.is_none()
+        && !ty.options.untagged
+        && !ty.variants.is_empty()
+        && ty
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.ty, Type::Unit))
+}
+
+fn get_variant_name(variant: &Variant, options: &EnumOptions) -> String {
+    match variant.attrs.rename.as_ref() {
+        Some(rename) => rename.to_owned(),
+        None => options
+            .variant_casing
+            .format_string(get_variable_name(&variant.name)),
+    }
+}
+
+fn get_variable_name(name: &str) -> &str {
+    name.strip_prefix("r#").unwrap_or(name)
+}
+
+/// Formats the name under which a type is listed as a component.
+///
+/// OpenAPI has no notion of generics, so every instantiation of a generic
+/// type is listed separately, such as `Point_f64` for `Point<f64>`.
+fn format_component_name(ident: &TypeIdent) -> String {
+    let name = match ident.array {
+        Some(size) => format!("{}_{}", ident.name, size),
+        None => ident.name.clone(),
+    };
+    std::iter::once(name)
+        .chain(
+            ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| format_component_name(arg)),
+        )
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Adds a description to the schema, based on the given doc lines.
+///
+/// References may have sibling keywords since OpenAPI 3.1, so this works for
+/// references too.
+fn with_description(schema: Value, doc_lines: &[String]) -> Value {
+    match (schema, format_description(doc_lines)) {
+        (Value::Object(mut schema), Some(description)) => {
+            schema.insert("description".to_owned(), json!(description));
+            Value::Object(schema)
+        }
+        (schema, _) => schema,
+    }
+}
+
+pub(super) fn format_description(doc_lines: &[String]) -> Option<String> {
+    let description = doc_lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let description = description.trim();
+    if description.is_empty() {
+        None
+    } else {
+        Some(description.to_owned())
+    }
+}
+
+fn first_generic_arg(ident: &TypeIdent) -> &TypeIdent {
+    let (arg, _) = ident
+        .generic_args
+        .first()
+        .expect("Identifier was expected to contain a generic argument");
+    arg
+}
+
+/// Replaces the generic parameters of a declaration in the given type with
+/// the arguments from the identifier by which the declaration is used.
+fn substitute_generic_args(ty: &TypeIdent, decl: &TypeIdent, usage: &TypeIdent) -> TypeIdent {
+    if ty.generic_args.is_empty() {
+        if let Some(index) = decl
+            .generic_args
+            .iter()
+            .position(|(param, _)| param.name == ty.name)
+        {
+            if let Some((arg, _)) = usage.generic_args.get(index) {
+                return arg.clone();
+            }
+        }
+    }
+
+    let mut ty = ty.clone();
+    for (arg, _) in ty.generic_args.iter_mut() {
+        *arg = substitute_generic_args(arg, decl, usage);
+    }
+    ty
+}
+
+/// Formats a custom type, based on the TypeScript type that describes its
+/// serialized representation.
+fn format_custom_type(custom: &CustomType) -> Value {
+    if let Some(primitive) = custom
+        .rs_ty
+        .strip_prefix("std::num::NonZero")
+        .and_then(|name| name.to_lowercase().parse::<Primitive>().ok())
+    {
+        let mut schema = format_primitive(primitive);
+        if is_unsigned(primitive) {
+            schema["minimum"] = json!(1);
+        } else {
+            schema["not"] = json!({ "const": 0 });
+        }
+        return schema;
+    }
+
+    match &custom.ts_declaration {
+        Some(declaration) if is_string_literal_union(declaration) => {
+            format_string_literal_union(declaration)
+        }
+        Some(declaration) if is_branded_string(declaration) => format_ts_type("string"),
+        Some(_) => json!({}),
+        None => format_ts_type(&custom.ts_ty),
+    }
+}
+
+fn format_ts_type(ts_ty: &str) -> Value {
+    match ts_ty {
+        // Byte buffers are serialized to JSON as arrays of numbers:
+        "ArrayBuffer" | "Uint8Array" => json!({
+            "type": "array",
+            "items": format_primitive(Primitive::U8),
+        }),
+        "bigint" => json!({ "type": "integer", "format": "int64" }),
+        "boolean" => json!({ "type": "boolean" }),
+        "number" => json!({ "type": "number" }),
+        "string" => json!({ "type": "string" }),
+        _ if is_string_literal_union(ts_ty) => format_string_literal_union(ts_ty),
+        _ => json!({}),
+    }
+}
+
+/// Returns whether the given declaration is a branded string, such as
+/// `string & { readonly __brand: "Decimal" }`.
+fn is_branded_string(declaration: &str) -> bool {
+    declaration.starts_with("string & ")
+}
+
+fn is_string_literal_union(ts_ty: &str) -> bool {
+    ts_ty
+        .split('|')
+        .map(str::trim)
+        .filter(|literal| !literal.is_empty())
+        .all(|literal| literal.len() >= 2 && literal.starts_with('"') && literal.ends_with('"'))
+}
+
+fn format_string_literal_union(ts_ty: &str) -> Value {
+    json!({
+        "type": "string",
+        "enum": ts_ty
+            .split('|')
+            .map(str::trim)
+            .filter(|literal| !literal.is_empty())
+            .map(|literal| &literal[1..literal.len() - 1])
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn is_unsigned(primitive: Primitive) -> bool {
+    matches!(
+        primitive,
+        Primitive::U8 | Primitive::U16 | Primitive::U32 | Primitive::U64
+    )
+}
+
+pub(super) fn format_primitive(primitive: Primitive) -> Value {
+    let mut schema = match primitive {
+        Primitive::Bool => return json!({ "type": "boolean" }),
+        Primitive::F32 => return json!({ "type": "number", "format": "float" }),
+        Primitive::F64 => return json!({ "type": "number", "format": "double" }),
+        Primitive::I8 | Primitive::I16 | Primitive::I32 | Primitive::U8 | Primitive::U16 => {
+            json!({ "type": "integer", "format": "int32" })
+        }
+        Primitive::I64 | Primitive::U32 | Primitive::U64 => {
+            json!({ "type": "integer", "format": "int64" })
+        }
+    };
+
+    // The maximum of `u64` is left out, since many JSON parsers cannot
+    // represent it accurately:
+    let (minimum, maximum): (i64, Option<i64>) = match primitive {
+        Primitive::I8 => (i8::MIN.into(), Some(i8::MAX.into())),
+        Primitive::I16 => (i16::MIN.into(), Some(i16::MAX.into())),
+        Primitive::U8 => (0, Some(u8::MAX.into())),
+        Primitive::U16 => (0, Some(u16::MAX.into())),
+        Primitive::U32 => (0, Some(u32::MAX.into())),
+        Primitive::U64 => (0, None),
+        _ => return schema,
+    };
+    schema["minimum"] = json!(minimum);
+    if let Some(maximum) = maximum {
+        schema["maximum"] = json!(maximum);
+    }
+    schema
+}
@@ -0,0 +1,145 @@
+//! Pruning of the types that are not used by any function.
+//!
+//! Besides the types that are used by the functions in the `fp_import!` and
+//! `fp_export!` sections, the type map contains the types that are registered
+//! explicitly, using `use` statements or aliases. Depending on `UnusedTypes`,
+//! the types that cannot be reached from any function signature are dropped
+//! before any bindings are generated, so they don't end up in the generated
+//! type declarations.
+
+use crate::{
+    functions::FunctionList,
+    types::{Type, TypeIdent, TypeMap},
+};
+use std::collections::BTreeSet;
+
+/// Returns the identifiers of the types that are used by any of the
+/// functions, either directly or through other types.
+pub(super) fn reachable_types<'a>(
+    import_functions: &'a FunctionList,
+    export_functions: &'a FunctionList,
+    types: &'a TypeMap,
+) -> BTreeSet<&'a TypeIdent> {
+    let mut reachable = BTreeSet::new();
+    let mut pending: Vec<&TypeIdent> = import_functions
+        .iter()
+        .chain(export_functions.iter())
+        .flat_map(|function| {
+            function
+                .args
+                .iter()
+                .map(|arg| &arg.ty)
+                .chain(function.return_type.iter())
+        })
+        .collect();
+    while let Some(ident) = pending.pop() {
+        pending.extend(ident.generic_args.iter().map(|(arg, _)| arg));
+        let (key, ty) = match types.get_key_value(ident) {
+            Some(entry) => entry,
+            None => continue,
+        };
+        if !reachable.insert(key) {
+            continue;
+        }
+        match ty {
+            Type::Alias(_, target) | Type::Container(_, target) | Type::List(_, target) => {
+                pending.push(target)
+            }
+            Type::Map(_, key, value) => pending.extend([key, value]),
+            Type::Tuple(items) => pending.extend(items),
+            Type::Enum(ty) => {
+                for variant in &ty.variants {
+                    match &variant.ty {
+                        Type::Struct(variant_struct) => {
+                            pending.extend(variant_struct.fields.iter().map(|field| &field.ty))
+                        }
+                        Type::Tuple(items) => pending.extend(items),
+                        _ => {}
+                    }
+                }
+            }
+            Type::Struct(ty) => pending.extend(ty.fields.iter().map(|field| &field.ty)),
+            Type::Array(..) | Type::Custom(_) | Type::Primitive(_) | Type::String | Type::Unit => {}
+        }
+    }
+    reachable
+}
+
+/// Removes the types that cannot be reached from any of the functions.
+///
+/// Primitives, strings and the unit type are always kept, since generators
+/// may look them up for the functions they add themselves.
+pub(super) fn prune_unused_types(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: TypeMap,
+) -> TypeMap {
+    let reachable: BTreeSet<TypeIdent> =
+        reachable_types(import_functions, export_functions, &types)
+            .into_iter()
+            .cloned()
+            .collect();
+    types
+        .into_iter()
+        .filter(|(ident, ty)| {
+            matches!(ty, Type::Primitive(_) | Type::String | Type::Unit)
+                || reachable.contains(ident)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_unused_types() {
+        let mut import_functions = FunctionList::new();
+        import_functions.add_function("fn f(values: Vec<Foo>) -> Alias;");
+        let export_functions = FunctionList::new();
+
+        let mut types = TypeMap::new();
+        for item in [
+            "struct Foo { bar: Option<Bar> }",
+            "enum Bar { A { baz: Baz }, B(Qux) }",
+            "struct Baz {}",
+            "struct Qux {}",
+            "struct Unused { foo: Foo }",
+        ] {
+            let ty = Type::from_item(item);
+            types.insert(TypeIdent::from(ty.name().as_str()), ty);
+        }
+        types.insert(
+            TypeIdent::from("Vec<T>"),
+            Type::List("Vec".to_owned(), TypeIdent::from("T")),
+        );
+        types.insert(
+            TypeIdent::from("Option<T>"),
+            Type::Container("Option".to_owned(), TypeIdent::from("T")),
+        );
+        types.insert(
+            TypeIdent::from("Alias"),
+            Type::Alias("Alias".to_owned(), TypeIdent::from("u32")),
+        );
+        types.insert(
+            TypeIdent::from("UnusedAlias"),
+            Type::Alias("UnusedAlias".to_owned(), TypeIdent::from("Unused")),
+        );
+        types.insert(TypeIdent::from("String"), Type::String);
+
+        let types = prune_unused_types(&import_functions, &export_functions, types);
+        assert_eq!(
+            types.keys().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "Alias",
+                "Bar",
+                "Baz",
+                "Foo",
+                "Option<T>",
+                "Qux",
+                "String",
+                "Vec<T>"
+            ]
+        );
+    }
+}
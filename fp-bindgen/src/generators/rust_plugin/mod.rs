@@ -1,10 +1,20 @@
+use super::schema_hash::SchemaHashes;
+use super::validation::{
+    format_bound, get_validated_field, uses_regex_validation, ValidatedField, ValidatedKind,
+};
 use crate::functions::Function;
 use crate::types::is_runtime_bound;
 use crate::{
     functions::FunctionList,
+    generators::{
+        interning::has_interned_fields,
+        lifecycle::{INIT, UPDATE_CONFIG},
+        write_bindings_file,
+    },
     types::{CargoDependency, Enum, Field, Struct, Type, TypeIdent, TypeMap},
-    RustPluginConfig,
+    FileHeaders, PluginAllocator, RustPluginConfig, WasmFeatures,
 };
+use inflector::Inflector;
 use std::{
     collections::{BTreeMap, BTreeSet},
     fs,
@@ -14,22 +24,75 @@ pub(crate) fn generate_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: TypeMap,
+    schema_hashes: &SchemaHashes,
     config: RustPluginConfig,
+    headers: &FileHeaders,
     path: &str,
 ) {
     let src_path = format!("{path}/src");
     fs::create_dir_all(&src_path).expect("Could not create output directory");
 
-    generate_cargo_file(config, &import_functions, &types, path);
+    if config.no_std && config.guest_metrics {
+        panic!("Guest metrics cannot be recorded by `no_std` plugins");
+    }
+
+    let no_std = config.no_std;
+    let guest_metrics = config.guest_metrics;
+    let borrow_import_args = !config.owned_import_args;
+    let allocator = config.allocator;
+    let wasm_features = config.wasm_features;
+    generate_cargo_file(
+        config,
+        &import_functions,
+        &export_functions,
+        &types,
+        headers,
+        path,
+    );
 
-    generate_type_bindings(&types, &src_path);
-    generate_imported_function_bindings(import_functions, &types, &src_path);
-    generate_exported_function_bindings(export_functions, &types, &src_path);
+    write_type_bindings(&types, schema_hashes, &src_path, no_std, headers);
+    generate_imported_function_bindings(
+        import_functions,
+        &types,
+        no_std,
+        borrow_import_args,
+        guest_metrics,
+        headers,
+        &src_path,
+    );
+    generate_exported_function_bindings(export_functions, &types, no_std, headers, &src_path);
 
+    let crate_attrs = if no_std {
+        "#![no_std]\n#![allow(unused_imports)]\n\nextern crate alloc;\n\n"
+    } else {
+        "#![allow(unused_imports)]\n"
+    };
+    let global_allocator = match allocator {
+        PluginAllocator::Default => "",
+        PluginAllocator::Dlmalloc => {
+            "\n#[cfg(target_arch = \"wasm32\")]
+#[global_allocator]
+static ALLOCATOR: dlmalloc::GlobalDlmalloc = dlmalloc::GlobalDlmalloc;
+"
+        }
+        PluginAllocator::WeeAlloc => {
+            "\n#[cfg(target_arch = \"wasm32\")]
+#[global_allocator]
+static ALLOCATOR: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
+"
+        }
+        PluginAllocator::Talc => {
+            "\n#[cfg(target_arch = \"wasm32\")]
+#[global_allocator]
+static ALLOCATOR: talc::TalckWasm = unsafe { talc::TalckWasm::new_global() };
+"
+        }
+    };
+    let (target_features_doc, target_feature_checks) = format_target_features(&wasm_features);
     write_bindings_file(
         format!("{src_path}/lib.rs"),
-        "#![allow(unused_imports)]
-#[rustfmt::skip]
+        format!(
+            "{target_features_doc}{crate_attrs}#[rustfmt::skip]
 mod export;
 #[rustfmt::skip]
 mod import;
@@ -41,14 +104,55 @@ pub use import::*;
 pub use types::*;
 
 pub use fp_bindgen_support::*;
-",
+{global_allocator}{target_feature_checks}"
+        ),
+        headers,
+    );
+}
+
+/// Formats the crate documentation that lists the target features with which
+/// plugins may be compiled, along with the checks that refuse to compile
+/// plugins that are built with the target features of disabled WebAssembly
+/// features.
+fn format_target_features(wasm_features: &WasmFeatures) -> (String, String) {
+    let target_features = wasm_features.target_features();
+    let flags = target_features
+        .iter()
+        .map(|(name, enabled)| format!("{}{name}", if *enabled { '+' } else { '-' }))
+        .collect::<Vec<_>>()
+        .join(",");
+    let doc = format!(
+        "//! Plugins that use these bindings may be compiled with the following target
+//! features, which match the WebAssembly features their runtimes are expected
+//! to enable. They can be passed through `RUSTFLAGS` or `.cargo/config.toml`:
+//!
+//! ```text
+//! -C target-feature={flags}
+//! ```
+
+"
     );
+    let checks = target_features
+        .iter()
+        .filter(|(_, enabled)| !enabled)
+        .map(|(name, _)| {
+            format!(
+                "
+#[cfg(all(target_arch = \"wasm32\", target_feature = \"{name}\"))]
+compile_error!(\"Plugins for this protocol must not be compiled with the `{name}` target feature\");
+"
+            )
+        })
+        .collect();
+    (doc, checks)
 }
 
 fn generate_cargo_file(
     config: RustPluginConfig,
     import_functions: &FunctionList,
+    export_functions: &FunctionList,
     types: &TypeMap,
+    headers: &FileHeaders,
     path: &str,
 ) {
     let requires_async = import_functions.iter().any(|function| function.is_async);
@@ -57,19 +161,67 @@ fn generate_cargo_file(
     if requires_async {
         support_features.insert("async");
     }
+    if uses_regex_validation(types) {
+        support_features.insert("validation");
+    }
+    if uses_unknown_variants(types) {
+        support_features.insert("non-exhaustive");
+    }
+    if has_interned_fields(types) {
+        support_features.insert("interning");
+    }
+    if config.panic_abort {
+        support_features.insert("panic-abort");
+    }
+    if config.guest_metrics {
+        support_features.insert("guest-metrics");
+    }
+    if export_functions
+        .iter()
+        .any(|function| function.name == UPDATE_CONFIG)
+    {
+        support_features.insert("config");
+    }
 
-    let mut dependencies = BTreeMap::from([
-        (
-            "fp-bindgen-support",
-            CargoDependency::with_version_and_features(env!("CARGO_PKG_VERSION"), support_features),
-        ),
-        ("once_cell", CargoDependency::with_version("1")),
-        ("rmp-serde", CargoDependency::with_version("1.0")),
-        (
+    let mut dependencies = BTreeMap::from([(
+        "fp-bindgen-support",
+        CargoDependency::with_version_and_features(env!("CARGO_PKG_VERSION"), support_features),
+    )]);
+    if config.no_std {
+        dependencies.insert(
+            "serde",
+            CargoDependency {
+                default_features: Some(false),
+                ..CargoDependency::with_version_and_features(
+                    "1.0",
+                    BTreeSet::from(["alloc", "derive"]),
+                )
+            },
+        );
+    } else {
+        dependencies.insert("once_cell", CargoDependency::with_version("1"));
+        dependencies.insert("rmp-serde", CargoDependency::with_version("1.0"));
+        dependencies.insert(
             "serde",
             CargoDependency::with_version_and_features("1.0", BTreeSet::from(["derive"])),
-        ),
-    ]);
+        );
+    }
+
+    match config.allocator {
+        PluginAllocator::Default => {}
+        PluginAllocator::Dlmalloc => {
+            dependencies.insert(
+                "dlmalloc",
+                CargoDependency::with_version_and_features("0.2", BTreeSet::from(["global"])),
+            );
+        }
+        PluginAllocator::WeeAlloc => {
+            dependencies.insert("wee_alloc", CargoDependency::with_version("0.4"));
+        }
+        PluginAllocator::Talc => {
+            dependencies.insert("talc", CargoDependency::with_version("4"));
+        }
+    }
 
     // Inject dependencies from custom types:
     for ty in types.values() {
@@ -95,6 +247,18 @@ fn generate_cargo_file(
         dependencies.insert(name, dependency);
     }
 
+    // Declare the features behind which functions are generated:
+    let features = import_functions
+        .cfg_features()
+        .union(&export_functions.cfg_features())
+        .map(|feature| format!("{feature} = []\n"))
+        .collect::<Vec<_>>();
+    let features = if features.is_empty() {
+        String::new()
+    } else {
+        format!("\n[features]\n{}", features.join(""))
+    };
+
     write_bindings_file(
         format!("{path}/Cargo.toml"),
         format!(
@@ -106,7 +270,7 @@ edition = \"2018\"
 
 [dependencies]
 {}
-",
+{features}",
             config.name,
             config.version,
             config.authors,
@@ -116,20 +280,54 @@ edition = \"2018\"
                 .collect::<Vec<_>>()
                 .join("\n")
         ),
+        headers,
     );
 }
 
-pub fn generate_type_bindings(types: &TypeMap, path: &str) {
-    let std_types: BTreeSet<_> = types.values().filter_map(collect_std_types).collect();
-    let std_imports = if std_types.is_empty() {
-        "".to_owned()
-    } else if std_types.len() == 1 {
-        format!("use std::{};\n", std_types.iter().next().unwrap())
+pub fn generate_type_bindings(
+    types: &TypeMap,
+    schema_hashes: &SchemaHashes,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    write_type_bindings(types, schema_hashes, path, false, headers)
+}
+
+fn write_type_bindings(
+    types: &TypeMap,
+    schema_hashes: &SchemaHashes,
+    path: &str,
+    no_std: bool,
+    headers: &FileHeaders,
+) {
+    write_bindings_file(
+        format!("{path}/types.rs"),
+        format_type_bindings(types, schema_hashes, no_std),
+        headers,
+    );
+}
+
+/// Formats the contents of `types.rs`, which declares the protocol's types
+/// together with their Serde attributes.
+pub(crate) fn format_type_bindings(
+    types: &TypeMap,
+    schema_hashes: &SchemaHashes,
+    no_std: bool,
+) -> String {
+    let std_imports = if no_std {
+        format_alloc_imports(types)
     } else {
-        format!(
-            "use std::{{{}}};\n",
-            std_types.into_iter().collect::<Vec<_>>().join(", ")
-        )
+        let std_types: BTreeSet<_> = types.values().filter_map(collect_std_types).collect();
+        if std_types.is_empty() {
+            "".to_owned()
+        } else if std_types.len() == 1 {
+            format!("use std::{};\n", std_types.iter().next().unwrap())
+        } else {
+            format!(
+                "use std::{{{}}};\n",
+                std_types.into_iter().collect::<Vec<_>>().join(", ")
+            )
+        }
     };
 
     let type_imports = types
@@ -174,18 +372,22 @@ pub fn generate_type_bindings(types: &TypeMap, path: &str) {
             }
             _ => None,
         })
+        .chain(create_validators(types))
+        .chain(create_schema_hash_impls(types, schema_hashes))
         .collect::<Vec<_>>();
 
-    write_bindings_file(
-        format!("{path}/types.rs"),
-        format!(
-            "#![allow(unused_imports)]\n\
-            use serde::{{Deserialize, Serialize}};\n{}\n{}{}\n",
-            std_imports,
-            type_imports,
-            type_defs.join("\n\n")
-        ),
+    let contents = format!(
+        "#![allow(unused_imports)]\n\
+        use serde::{{Deserialize, Serialize}};\n{}\n{}{}\n",
+        std_imports,
+        type_imports,
+        type_defs.join("\n\n")
     );
+    if no_std {
+        replace_std_paths(&contents)
+    } else {
+        contents
+    }
 }
 
 pub fn format_doc_lines(doc_lines: &[String]) -> String {
@@ -200,7 +402,14 @@ pub fn format_modifiers(function: &Function) -> String {
     if function.is_async { "async " } else { "" }.to_owned()
 }
 
-fn format_functions(functions: FunctionList, types: &TypeMap, macro_path: &str) -> String {
+fn format_functions(
+    functions: FunctionList,
+    types: &TypeMap,
+    macro_path: &str,
+    borrow_args: bool,
+    record_metrics: bool,
+    stores_config: bool,
+) -> String {
     functions
         .iter()
         .map(|func| {
@@ -210,21 +419,86 @@ fn format_functions(functions: FunctionList, types: &TypeMap, macro_path: &str)
             let args_with_types = func
                 .args
                 .iter()
-                .map(|arg| format!("{}: {}", arg.name, format_ident(&arg.ty, types)))
+                .map(|arg| {
+                    let ty = if borrow_args {
+                        format_borrowed_ident(&arg.ty, types)
+                    } else {
+                        format_ident(&arg.ty, types)
+                    };
+                    format!("{}: {ty}", arg.name)
+                })
                 .collect::<Vec<_>>()
                 .join(", ");
             let return_type = match &func.return_type {
                 Some(ty) => format!(" -> {}", format_ident(ty, types)),
                 None => "".to_owned(),
             };
+            let macro_args = func
+                .attrs
+                .batchable
+                .then(|| "batchable".to_owned())
+                .into_iter()
+                .chain(record_metrics.then(|| "metrics".to_owned()))
+                .chain((stores_config && func.name == INIT).then(|| "config".to_owned()))
+                .chain(
+                    func.attrs
+                        .rename
+                        .as_ref()
+                        .map(|rename| format!("rename = \"{rename}\"")),
+                )
+                .collect::<Vec<_>>();
+            let macro_args = if macro_args.is_empty() {
+                String::new()
+            } else {
+                format!("({})", macro_args.join(", "))
+            };
+            // The plugin picks the types of generic functions, so they only
+            // need to be (de)serializable:
+            let generics = if func.generic_params.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "<{}>",
+                    func.generic_params
+                        .iter()
+                        .map(|param| format!(
+                            "{param}: serde::Serialize + serde::de::DeserializeOwned"
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            };
+            let cfg = format_cfg_attr(func, "");
             format!(
-                "{doc}#[{macro_path}]\npub {modifiers}fn {name}({args_with_types}){return_type};",
+                "{doc}{cfg}#[{macro_path}{macro_args}]\npub {modifiers}fn {name}{generics}({args_with_types}){return_type};",
             )
         })
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
+/// Formats the `#[cfg(...)]` attribute for functions that are generated behind
+/// a feature, followed by a newline and the given indentation.
+pub(crate) fn format_cfg_attr(function: &Function, indent: &str) -> String {
+    match &function.attrs.cfg_feature {
+        Some(feature) => format!("#[cfg(feature = \"{feature}\")]\n{indent}"),
+        None => String::new(),
+    }
+}
+
+/// Formats the type of an argument that only needs to be borrowed in order to
+/// be serialized. Strings and vectors are borrowed as `&str` and `&[T]`, which
+/// have the same serialized representation. Other types are passed as is.
+fn format_borrowed_ident(ident: &TypeIdent, types: &TypeMap) -> String {
+    match types.get(ident) {
+        Some(Type::String) => "&str".to_owned(),
+        Some(Type::List(name, item)) if name == "Vec" => {
+            format!("&[{}]", format_ident(item, types))
+        }
+        _ => format_ident(ident, types),
+    }
+}
+
 pub fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
     match types.get(ident) {
         Some(ty) => format_type_with_ident(ty, ident, types),
@@ -264,7 +538,7 @@ fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap) -> Stri
         Type::Map(name, _, _) => format_name_with_args(name, Some(2)),
         Type::Struct(Struct { ident, .. }) => format_name_with_args(&ident.name, None),
         Type::Tuple(items) => format!(
-            "[{}]",
+            "({})",
             items
                 .iter()
                 .map(|item| format_ident(item, types))
@@ -288,43 +562,184 @@ fn format_bounds(bounds: &[String]) -> String {
 fn generate_imported_function_bindings(
     import_functions: FunctionList,
     types: &TypeMap,
+    no_std: bool,
+    borrow_args: bool,
+    record_metrics: bool,
+    headers: &FileHeaders,
     path: &str,
 ) {
+    let batch_import = if import_functions
+        .iter()
+        .any(|function| function.attrs.batchable)
+    {
+        "pub use fp_bindgen_support::guest::batch::batch;\n"
+    } else {
+        ""
+    };
+
+    let contents = format!(
+        "use crate::types::*;\n{batch_import}\n{}\n",
+        format_functions(
+            import_functions,
+            types,
+            "fp_bindgen_support::fp_import_signature",
+            borrow_args,
+            record_metrics,
+            false,
+        )
+    );
     write_bindings_file(
         format!("{path}/import.rs"),
-        format!(
-            "use crate::types::*;\n\n{}\n",
-            format_functions(
-                import_functions,
-                types,
-                "fp_bindgen_support::fp_import_signature"
-            )
-        ),
+        format_module_contents(contents, types, no_std),
+        headers,
     );
 }
 
 fn generate_exported_function_bindings(
     export_functions: FunctionList,
     types: &TypeMap,
+    no_std: bool,
+    headers: &FileHeaders,
     path: &str,
 ) {
+    // The bindings implement `update_config` themselves, so that plugins only
+    // need to read the config through `fp_config()`:
+    let (update_config, export_functions): (Vec<_>, Vec<_>) = export_functions
+        .into_iter()
+        .partition(|function| function.name == UPDATE_CONFIG);
+    let config_support = match update_config
+        .first()
+        .and_then(|function| function.args.first())
+    {
+        Some(arg) => format!(
+            "pub use fp_bindgen_support::guest::config::{{fp_config, on_config_change}};
+
+/// Called on the plugin when the host changes its config.
+///
+/// This is implemented by the bindings, which cache the config for
+/// `fp_config()` and pass it to the listeners that are registered with
+/// `on_config_change()`.
+#[doc(hidden)]
+#[no_mangle]
+pub fn __fp_gen_{UPDATE_CONFIG}(config: fp_bindgen_support::common::mem::FatPtr) {{
+    unsafe {{ fp_bindgen_support::guest::config::update_config_from_host::<{}>(config) }}
+}}
+
+",
+            format_ident(&arg.ty, types)
+        ),
+        None => String::new(),
+    };
+
+    let contents = format!(
+        "use crate::types::*;\n\n{config_support}{}\n",
+        format_functions(
+            export_functions.into_iter().collect(),
+            types,
+            "fp_bindgen_support::fp_export_signature",
+            false,
+            false,
+            !config_support.is_empty(),
+        )
+    );
     write_bindings_file(
         format!("{path}/export.rs"),
-        format!(
-            "use crate::types::*;\n\n{}\n",
-            format_functions(
-                export_functions,
-                types,
-                "fp_bindgen_support::fp_export_signature"
-            )
-        ),
+        format_module_contents(contents, types, no_std),
+        headers,
     );
 }
 
+/// Makes the contents of a function bindings module suitable for a
+/// `#![no_std]` crate, if necessary.
+fn format_module_contents(contents: String, types: &TypeMap, no_std: bool) -> String {
+    if no_std {
+        format!(
+            "{}{}",
+            format_alloc_imports(types),
+            replace_std_paths(&contents)
+        )
+    } else {
+        contents
+    }
+}
+
+/// Formats the `use` statement for the items that are part of the prelude of
+/// `std`, but need to be imported from `alloc` in `#![no_std]` crates.
+fn format_alloc_imports(types: &TypeMap) -> String {
+    let mut alloc_types = BTreeSet::from([
+        "boxed::Box".to_owned(),
+        "format".to_owned(),
+        "string::String".to_owned(),
+        "vec::Vec".to_owned(),
+    ]);
+    for ty in types.values() {
+        match ty {
+            Type::List(name, _) | Type::Map(name, _, _) if name.starts_with("Hash") => panic!(
+                "`{name}` is not available in `#![no_std]` plugins, use `BTree{}` instead",
+                name.trim_start_matches("Hash")
+            ),
+            ty => alloc_types.extend(collect_std_types(ty)),
+        }
+    }
+
+    format!(
+        "use alloc::{{{}}};\n",
+        alloc_types.into_iter().collect::<Vec<_>>().join(", ")
+    )
+}
+
+/// Modules of `std` that are re-exported by `alloc`. Paths into other modules
+/// are replaced with paths into `core`.
+const ALLOC_MODULES: &[&str] = &[
+    "borrow",
+    "boxed",
+    "collections",
+    "rc",
+    "slice",
+    "str",
+    "string",
+    "sync",
+    "vec",
+];
+
+/// Replaces paths into `std`, such as those used in trait bounds or by custom
+/// types, with paths into `alloc` or `core`.
+fn replace_std_paths(code: &str) -> String {
+    let mut result = String::with_capacity(code.len());
+    let mut rest = code;
+    while let Some(index) = rest.find("std::") {
+        let (before, after) = rest.split_at(index);
+        result.push_str(before);
+
+        let is_path_start = !result
+            .chars()
+            .last()
+            .map(|c| c.is_alphanumeric() || c == '_' || c == ':')
+            .unwrap_or(false);
+        let after = &after["std::".len()..];
+        if is_path_start {
+            let module = after
+                .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .next()
+                .unwrap_or_default();
+            result.push_str(if ALLOC_MODULES.contains(&module) {
+                "alloc::"
+            } else {
+                "core::"
+            });
+        } else {
+            result.push_str("std::");
+        }
+        rest = after;
+    }
+    result.push_str(rest);
+    result
+}
+
 fn collect_std_types(ty: &Type) -> Option<String> {
     match ty {
         Type::Container(name, _) if name == "Rc" => Some("rc::Rc".to_owned()),
-        Type::List(name, _) if (name == "BTreeSet" || name == "HashSet") => {
+        Type::List(name, _) if (name == "BTreeSet" || name == "HashSet" || name == "VecDeque") => {
             Some(format!("collections::{name}"))
         }
         Type::Map(name, _, _) if (name == "BTreeMap" || name == "HashMap") => {
@@ -334,6 +749,23 @@ fn collect_std_types(ty: &Type) -> Option<String> {
     }
 }
 
+/// The variant that non-exhaustive enums get for the variants that are
+/// unknown to the bindings. Untagged variants are only tried after all tagged
+/// variants failed to match.
+const UNKNOWN_VARIANT: &str =
+    "    /// A variant that was added after these bindings were generated, with its
+    /// serialized value.
+    #[serde(untagged)]
+    Unknown(fp_bindgen_support::common::non_exhaustive::Value),";
+
+/// Returns whether any of the generated enums is non-exhaustive, which
+/// requires the `non-exhaustive` feature of `fp-bindgen-support`.
+pub(crate) fn uses_unknown_variants(types: &TypeMap) -> bool {
+    types.values().any(|ty| {
+        matches!(ty, Type::Enum(ty) if ty.options.non_exhaustive && ty.options.rust_module.is_none())
+    })
+}
+
 fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
     let variants = ty
         .variants
@@ -343,7 +775,7 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
             let mut variant_decl = match &variant.ty {
                 Type::Unit => format!("{},", variant.name),
                 Type::Struct(variant) => {
-                    let fields = format_struct_fields(&variant.fields, types);
+                    let fields = format_struct_fields(&variant.ident, &variant.fields, types);
                     let has_multiple_lines = fields.iter().any(|field| field.contains('\n'));
                     let fields = if has_multiple_lines {
                         format!(
@@ -413,6 +845,11 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
                 })
                 .collect::<Vec<_>>()
         })
+        .chain(
+            ty.options
+                .non_exhaustive
+                .then(|| UNKNOWN_VARIANT.to_owned()),
+        )
         .collect::<Vec<_>>()
         .join("\n");
 
@@ -444,7 +881,7 @@ fn create_struct_definition(ty: &Struct, types: &TypeMap) -> String {
         .map(|field| field.name.is_none())
         .unwrap_or_default();
 
-    let fields = format_struct_fields(&ty.fields, types)
+    let fields = format_struct_fields(&ty.ident, &ty.fields, types)
         .iter()
         .flat_map(|field| field.split('\n'))
         .map(|line| {
@@ -524,15 +961,35 @@ fn format_docs(doc_lines: &[String]) -> String {
         .join("")
 }
 
-fn format_struct_fields(fields: &[Field], types: &TypeMap) -> Vec<String> {
+fn format_struct_fields(owner: &TypeIdent, fields: &[Field], types: &TypeMap) -> Vec<String> {
     fields
         .iter()
         .map(|field| {
             let mut serde_attrs = field.attrs.to_serde_attrs();
+            if field.ty.is_phantom_data() {
+                serde_attrs.push("skip".to_owned());
+            }
+
+            if let Some(validated) = get_validated_field(owner, field, types) {
+                serde_attrs.push(format!(
+                    "deserialize_with = \"{}\"",
+                    format_validator_name(owner, field)
+                ));
+                // Serde only treats `Option` fields as optional if it
+                // deserializes them itself:
+                if validated.is_optional && field.attrs.default.is_none() {
+                    serde_attrs.push("default".to_owned());
+                }
+            }
 
             if let Some(Type::Custom(custom_type)) = types.get(&field.ty) {
+                // A custom (de)serializer on the field itself takes precedence
+                // over the one implied by the custom type:
+                let has_custom_serializer = field.attrs.has_custom_serializer();
                 for attr in custom_type.serde_attrs.iter() {
-                    serde_attrs.push(attr.clone());
+                    if !(has_custom_serializer && is_serializer_attr(attr)) {
+                        serde_attrs.push(attr.clone());
+                    }
                 }
             }
 
@@ -572,9 +1029,142 @@ fn format_struct_fields(fields: &[Field], types: &TypeMap) -> Vec<String> {
         .collect()
 }
 
-fn write_bindings_file<C>(file_path: String, contents: C)
-where
-    C: AsRef<[u8]>,
-{
-    fs::write(file_path, &contents).expect("Could not write bindings file");
+fn format_validator_name(owner: &TypeIdent, field: &Field) -> String {
+    format!(
+        "validate_{}_{}",
+        owner.name.to_snake_case(),
+        field
+            .name
+            .as_deref()
+            .unwrap_or_default()
+            .trim_start_matches("r#")
+    )
+}
+
+/// Creates the `SCHEMA_HASH` constants, with which serialized values can be
+/// tagged to detect whether they were written by an incompatible version of
+/// their type.
+fn create_schema_hash_impls(types: &TypeMap, schema_hashes: &SchemaHashes) -> Vec<String> {
+    types
+        .values()
+        .filter_map(|ty| match ty {
+            Type::Enum(ty) if ty.options.rust_module.is_none() && ty.ident.name != "Result" => {
+                Some(&ty.ident)
+            }
+            Type::Struct(ty) if ty.options.rust_module.is_none() => Some(&ty.ident),
+            _ => None,
+        })
+        .filter_map(|ident| {
+            let hash = schema_hashes.get(&ident.name)?;
+            let ident_with_bounds = ident.format(true);
+            let generics = ident_with_bounds
+                .strip_prefix(&ident.name)
+                .unwrap_or_default();
+            Some(format!(
+                "impl{generics} {} {{\n    \
+                    /// Hash of the wire format of this type, which changes whenever values\n    \
+                    /// serialized with an earlier version of the type may no longer be\n    \
+                    /// deserialized.\n    \
+                    pub const SCHEMA_HASH: &'static str = \"{hash}\";\n\
+                }}",
+                ident.format(false)
+            ))
+        })
+        .collect()
+}
+
+/// Creates the functions that deserialize and validate the fields that have
+/// `#[fp(validate(...))]` attributes.
+fn create_validators(types: &TypeMap) -> Vec<String> {
+    types
+        .values()
+        .filter_map(|ty| match ty {
+            Type::Struct(ty) if ty.options.rust_module.is_none() => Some(ty),
+            _ => None,
+        })
+        .flat_map(|ty| {
+            ty.fields.iter().filter_map(move |field| {
+                get_validated_field(&ty.ident, field, types).map(|validated| {
+                    create_validator(
+                        &format_validator_name(&ty.ident, field),
+                        &format_ident(&field.ty, types),
+                        &validated,
+                    )
+                })
+            })
+        })
+        .collect()
+}
+
+fn create_validator(name: &str, ty: &str, validated: &ValidatedField) -> String {
+    let field_name = &validated.name;
+    let validation = validated.validation;
+
+    let mut checks = Vec::new();
+    if let (Some(range), ValidatedKind::Number(primitive)) = (&validation.range, validated.kind) {
+        let start = range
+            .start
+            .as_deref()
+            .map(|bound| format_bound(bound, primitive))
+            .unwrap_or_default();
+        let end = range
+            .end
+            .as_deref()
+            .map(|bound| format_bound(bound, primitive))
+            .unwrap_or_default();
+        let operator = if range.inclusive { "..=" } else { ".." };
+        checks.push((
+            format!("!({start}{operator}{end}).contains(value)"),
+            format!("format!(\"`{field_name}` must be in range {range}, got {{}}\", value)"),
+        ));
+    }
+    if validation.non_empty {
+        checks.push((
+            "value.is_empty()".to_owned(),
+            format!("\"`{field_name}` must not be empty\""),
+        ));
+    }
+    if let Some(regex) = &validation.regex {
+        let message = format!("`{field_name}` must match the regular expression `{regex}`");
+        checks.push((
+            format!("!fp_bindgen_support::common::validation::is_match(value, {regex:?})"),
+            format!("{message:?}"),
+        ));
+    }
+
+    let indent = if validated.is_optional {
+        "        "
+    } else {
+        "    "
+    };
+    let checks = checks
+        .into_iter()
+        .map(|(condition, message)| {
+            format!(
+                "{indent}if {condition} {{\n\
+                 {indent}    return Err(serde::de::Error::custom({message}));\n\
+                 {indent}}}\n"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+    let body = if validated.is_optional {
+        format!("    if let Some(value) = &result {{\n{checks}    }}\n")
+    } else {
+        format!("    let value = &result;\n{checks}")
+    };
+
+    format!(
+        "fn {name}<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<{ty}, D::Error> {{\n\
+         \x20   let result: {ty} = Deserialize::deserialize(deserializer)?;\n\
+         {body}\
+         \x20   Ok(result)\n\
+         }}"
+    )
+}
+
+fn is_serializer_attr(attr: &str) -> bool {
+    attr.starts_with("with = ")
+        || attr.starts_with("serialize_with = ")
+        || attr.starts_with("deserialize_with = ")
 }
@@ -0,0 +1,118 @@
+use crate::{
+    generators::{
+        rust_plugin::{format_type_bindings, uses_unknown_variants},
+        schema_hash::SchemaHashes,
+        validation::uses_regex_validation,
+        write_bindings_file,
+    },
+    types::{CargoDependency, Type, TypeMap},
+    FileHeaders, RustTypesConfig,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+
+/// Generates a crate that only contains the types of the protocol, with the
+/// same definitions and Serde attributes as the Rust plugin bindings.
+///
+/// The crate has no WebAssembly glue, so services that exchange the protocol's
+/// types without loading plugins can depend on it instead of on the plugin or
+/// runtime bindings.
+pub(crate) fn generate_bindings(
+    types: TypeMap,
+    schema_hashes: &SchemaHashes,
+    config: RustTypesConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let src_path = format!("{path}/src");
+    fs::create_dir_all(&src_path).expect("Could not create output directory");
+
+    let type_bindings = format_type_bindings(&types, schema_hashes, false);
+    generate_cargo_file(
+        &types,
+        &config,
+        type_bindings.contains("fp_bindgen_support::"),
+        headers,
+        path,
+    );
+
+    write_bindings_file(format!("{src_path}/types.rs"), type_bindings, headers);
+    write_bindings_file(
+        format!("{src_path}/lib.rs"),
+        "#![allow(unused_imports)]
+#[rustfmt::skip]
+mod types;
+
+pub use types::*;
+",
+        headers,
+    );
+}
+
+fn generate_cargo_file(
+    types: &TypeMap,
+    config: &RustTypesConfig,
+    uses_support_crate: bool,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let mut dependencies = BTreeMap::from([(
+        "serde",
+        CargoDependency::with_version_and_features("1.0", BTreeSet::from(["derive"])),
+    )]);
+
+    // The support crate is only needed for the helpers that are referenced
+    // by Serde attributes and validators, none of which need its features
+    // for plugins or runtimes:
+    if uses_support_crate {
+        let mut support_features = BTreeSet::new();
+        if uses_regex_validation(types) {
+            support_features.insert("validation");
+        }
+        if uses_unknown_variants(types) {
+            support_features.insert("non-exhaustive");
+        }
+        dependencies.insert(
+            "fp-bindgen-support",
+            CargoDependency::with_version_and_features(env!("CARGO_PKG_VERSION"), support_features),
+        );
+    }
+
+    // Inject dependencies from custom types, followed by those passed through
+    // the config:
+    let custom_dependencies = types.values().flat_map(|ty| match ty {
+        Type::Custom(custom_type) => custom_type.rs_dependencies.iter().collect(),
+        _ => Vec::new(),
+    });
+    for (name, dependency) in custom_dependencies.chain(config.dependencies.iter()) {
+        let dependency = match dependencies.remove(name) {
+            Some(existing_dependency) => existing_dependency.merge_or_replace_with(dependency),
+            None => dependency.clone(),
+        };
+        dependencies.insert(name, dependency);
+    }
+
+    write_bindings_file(
+        format!("{path}/Cargo.toml"),
+        format!(
+            "[package]
+name = \"{}\"
+version = \"{}\"
+edition = \"2018\"
+
+[dependencies]
+{}
+",
+            config.name,
+            config.version,
+            dependencies
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        headers,
+    );
+}
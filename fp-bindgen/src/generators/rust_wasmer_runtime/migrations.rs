@@ -0,0 +1,368 @@
+use crate::{
+    casing::Casing,
+    generators::{formatting::rustfmt, write_bindings_file},
+    types::{Enum, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+    FileHeaders,
+};
+use inflector::Inflector;
+use std::collections::BTreeSet;
+
+const GENERATOR_NAME: &str = "rust-wasmer-runtime";
+
+/// Generates `migrations.rs`, with functions that upgrade the payloads of
+/// plugins that were built against a previous version of the protocol.
+///
+/// An `upgrade_*()` function is generated for every type that declares
+/// `renamed_from` names for its fields or variants, or that contains such a
+/// type. Non-generic types also get a `migrate_*()` function, which upgrades
+/// a serialized payload and deserializes it.
+pub(super) fn generate_migrations(types: &TypeMap, headers: &FileHeaders, path: &str) {
+    let upgradable = collect_upgradable_types(types);
+
+    let functions: Vec<_> = types
+        .values()
+        .filter(|ty| upgradable.contains(type_name(ty)))
+        .map(|ty| format_migration_functions(ty, types, &upgradable))
+        .collect();
+
+    let contents = rustfmt(format!(
+        "#![allow(unused_imports)]
+use super::types::*;
+use fp_bindgen_support::host::migrations::*;
+
+{}",
+        functions.join("\n\n")
+    ));
+    write_bindings_file(format!("{path}/migrations.rs"), contents, headers);
+}
+
+/// Returns the names of the types that need to be upgraded, because they
+/// have renamed members themselves or contain types that do.
+fn collect_upgradable_types(types: &TypeMap) -> BTreeSet<String> {
+    let mut upgradable: BTreeSet<String> = types
+        .values()
+        .filter(|ty| has_renamed_members(ty))
+        .map(|ty| type_name(ty).to_owned())
+        .collect();
+
+    // Containing types are added until a fixpoint is reached, which also
+    // takes care of recursive types:
+    loop {
+        let added: Vec<_> = types
+            .values()
+            .filter(|ty| !upgradable.contains(type_name(ty)))
+            .filter(|ty| {
+                member_types(ty)
+                    .iter()
+                    .any(|ident| format_upgrade(ident, types, &upgradable).is_some())
+            })
+            .map(|ty| type_name(ty).to_owned())
+            .collect();
+        if added.is_empty() {
+            return upgradable;
+        }
+        upgradable.extend(added);
+    }
+}
+
+fn has_renamed_members(ty: &Type) -> bool {
+    match ty {
+        Type::Struct(ty) => {
+            included_fields(&ty.fields).any(|field| !field.attrs.renamed_from.is_empty())
+        }
+        Type::Enum(ty) => included_variants(ty).any(|variant| {
+            if !variant.attrs.renamed_from.is_empty() {
+                if ty.options.untagged {
+                    panic!(
+                        "Variant `{}` of untagged enum `{}` cannot be renamed from another name, \
+                        because untagged enums don't serialize the names of their variants",
+                        variant.name, ty.ident
+                    );
+                }
+                return true;
+            }
+            matches!(&variant.ty, Type::Struct(variant_ty) if included_fields(&variant_ty.fields)
+                .any(|field| !field.attrs.renamed_from.is_empty()))
+        }),
+        _ => false,
+    }
+}
+
+fn member_types(ty: &Type) -> Vec<&TypeIdent> {
+    match ty {
+        Type::Struct(ty) => included_fields(&ty.fields).map(|field| &field.ty).collect(),
+        Type::Enum(ty) => included_variants(ty)
+            .flat_map(|variant| match &variant.ty {
+                Type::Struct(variant_ty) => included_fields(&variant_ty.fields)
+                    .map(|field| &field.ty)
+                    .collect(),
+                Type::Tuple(items) => items.iter().collect(),
+                _ => vec![],
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn included_fields(fields: &[Field]) -> impl Iterator<Item = &Field> {
+    fields
+        .iter()
+        .filter(|field| field.attrs.is_included_in(GENERATOR_NAME))
+}
+
+fn included_variants(ty: &Enum) -> impl Iterator<Item = &Variant> {
+    ty.variants
+        .iter()
+        .filter(|variant| variant.attrs.is_included_in(GENERATOR_NAME))
+}
+
+fn type_name(ty: &Type) -> &str {
+    match ty {
+        Type::Struct(ty) => &ty.ident.name,
+        Type::Enum(ty) => &ty.ident.name,
+        _ => "",
+    }
+}
+
+fn upgrade_function_name(name: &str) -> String {
+    format!("upgrade_{}", name.to_snake_case())
+}
+
+/// Returns an expression for a function that upgrades a value of the given
+/// type, or `None` if values of the type never need to be upgraded.
+///
+/// Options and boxes are serialized as their content, so their content can be
+/// upgraded in place. Type parameters and custom types are never upgraded.
+fn format_upgrade(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    upgradable: &BTreeSet<String>,
+) -> Option<String> {
+    let arg = |index: usize| {
+        ident
+            .generic_args
+            .get(index)
+            .and_then(|(arg, _)| format_upgrade(arg, types, upgradable))
+    };
+
+    match types.get(ident)? {
+        Type::Alias(_, target) => format_upgrade(target, types, upgradable),
+        Type::Container(_, _) => arg(0),
+        Type::List(_, _) => {
+            arg(0).map(|upgrade| format!("|value: &mut Value| for_each_item(value, {upgrade})"))
+        }
+        Type::Map(_, _, _) => arg(1)
+            .map(|upgrade| format!("|value: &mut Value| for_each_map_value(value, {upgrade})")),
+        Type::Struct(Struct { ident, .. }) | Type::Enum(Enum { ident, .. })
+            if upgradable.contains(&ident.name) =>
+        {
+            Some(upgrade_function_name(&ident.name))
+        }
+        _ => None,
+    }
+}
+
+/// Formats a call of the given upgrade expression with the given argument.
+fn format_upgrade_call(upgrade: &str, arg: &str) -> String {
+    if upgrade.starts_with('|') {
+        format!("({upgrade})({arg});")
+    } else {
+        format!("{upgrade}({arg});")
+    }
+}
+
+fn format_migration_functions(ty: &Type, types: &TypeMap, upgradable: &BTreeSet<String>) -> String {
+    let (ident, statements) = match ty {
+        Type::Struct(ty) => (
+            &ty.ident,
+            format_fields_upgrade(
+                &ty.fields,
+                ty.options.field_casing,
+                "value",
+                types,
+                upgradable,
+            ),
+        ),
+        Type::Enum(ty) => (&ty.ident, format_enum_upgrade(ty, types, upgradable)),
+        _ => unreachable!("Only structs and enums can be upgraded"),
+    };
+
+    let name = &ident.name;
+    let upgrade_name = upgrade_function_name(name);
+    let upgrade = format!(
+        "/// Upgrades a serialized `{name}` from a previous version of the protocol.
+pub fn {upgrade_name}(value: &mut Value) {{
+    {}
+}}",
+        statements.join("\n    ")
+    );
+
+    if ident.generic_args.is_empty() {
+        format!(
+            "{upgrade}
+
+/// Deserializes a `{name}` from a payload that may have been serialized by a
+/// previous version of the protocol.
+pub fn migrate_{}(payload: &[u8]) -> Result<{name}, MigrationError> {{
+    migrate(payload, {upgrade_name})
+}}",
+            name.to_snake_case()
+        )
+    } else {
+        upgrade
+    }
+}
+
+/// Returns the statements that upgrade the fields of a struct, or of a struct
+/// variant, which are stored in the variable with the given name.
+fn format_fields_upgrade(
+    fields: &[Field],
+    casing: Casing,
+    var: &str,
+    types: &TypeMap,
+    upgradable: &BTreeSet<String>,
+) -> Vec<String> {
+    let fields: Vec<_> = included_fields(fields).collect();
+
+    // Tuple structs are serialized as an array, unless they are newtypes,
+    // which are serialized as their content:
+    if fields.iter().all(|field| field.name.is_none()) {
+        return match fields.as_slice() {
+            [field] => format_upgrade(&field.ty, types, upgradable)
+                .map(|upgrade| vec![format_upgrade_call(&upgrade, var)])
+                .unwrap_or_default(),
+            fields => fields
+                .iter()
+                .enumerate()
+                .filter_map(|(index, field)| {
+                    format_upgrade(&field.ty, types, upgradable).map(|upgrade| {
+                        format!(
+                            "if let Some(item) = item_mut({var}, {index}) {{ {} }}",
+                            format_upgrade_call(&upgrade, "item")
+                        )
+                    })
+                })
+                .collect(),
+        };
+    }
+
+    let mut statements = Vec::new();
+    for field in &fields {
+        let name = field.name.as_deref().unwrap_or_default();
+        let serialized_name = field
+            .attrs
+            .rename
+            .clone()
+            .unwrap_or_else(|| casing.format_string(name.strip_prefix("r#").unwrap_or(name)));
+        for old_name in &field.attrs.renamed_from {
+            statements.push(format!(
+                "rename_field({var}, {old_name:?}, {serialized_name:?});"
+            ));
+        }
+    }
+    for field in &fields {
+        let upgrade = match format_upgrade(&field.ty, types, upgradable) {
+            Some(upgrade) => upgrade,
+            None => continue,
+        };
+        if field.attrs.flatten {
+            // The fields of flattened types are inlined into the map itself:
+            statements.push(format_upgrade_call(&upgrade, var));
+        } else {
+            let name = field.name.as_deref().unwrap_or_default();
+            let serialized_name =
+                field.attrs.rename.clone().unwrap_or_else(|| {
+                    casing.format_string(name.strip_prefix("r#").unwrap_or(name))
+                });
+            statements.push(format!(
+                "if let Some(field) = field_mut({var}, {serialized_name:?}) {{ {} }}",
+                format_upgrade_call(&upgrade, "field")
+            ));
+        }
+    }
+    statements
+}
+
+fn format_enum_upgrade(ty: &Enum, types: &TypeMap, upgradable: &BTreeSet<String>) -> Vec<String> {
+    let options = &ty.options;
+    let mut statements = Vec::new();
+    for variant in included_variants(ty) {
+        let name = serialized_variant_name(ty, variant);
+        for old_name in &variant.attrs.renamed_from {
+            statements.push(match &options.tag_prop_name {
+                Some(tag) => format!("rename_tag(value, {tag:?}, {old_name:?}, {name:?});"),
+                None => format!("rename_variant(value, {old_name:?}, {name:?});"),
+            });
+        }
+    }
+
+    for variant in included_variants(ty) {
+        // The payload of internally tagged variants is inlined into the map
+        // that contains the tag:
+        let var = match (&options.tag_prop_name, &options.content_prop_name) {
+            (Some(_), None) => "value",
+            _ => "payload",
+        };
+        let payload_statements = match &variant.ty {
+            Type::Struct(variant_ty) => format_fields_upgrade(
+                &variant_ty.fields,
+                variant.attrs.field_casing,
+                var,
+                types,
+                upgradable,
+            ),
+            Type::Tuple(items) => match items.as_slice() {
+                [item] => format_upgrade(item, types, upgradable)
+                    .map(|upgrade| vec![format_upgrade_call(&upgrade, var)])
+                    .unwrap_or_default(),
+                items => items
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, item)| {
+                        format_upgrade(item, types, upgradable).map(|upgrade| {
+                            format!(
+                                "if let Some(item) = item_mut({var}, {index}) {{ {} }}",
+                                format_upgrade_call(&upgrade, "item")
+                            )
+                        })
+                    })
+                    .collect(),
+            },
+            _ => vec![],
+        };
+        if payload_statements.is_empty() || options.untagged {
+            continue;
+        }
+
+        let name = serialized_variant_name(ty, variant);
+        let payload_statements = payload_statements.join("\n");
+        statements.push(match (&options.tag_prop_name, &options.content_prop_name) {
+            (Some(tag), Some(content)) => format!(
+                "if has_tag(value, {tag:?}, {name:?}) {{
+                    if let Some(payload) = field_mut(value, {content:?}) {{
+                        {payload_statements}
+                    }}
+                }}"
+            ),
+            (Some(tag), None) => format!(
+                "if has_tag(value, {tag:?}, {name:?}) {{
+                    {payload_statements}
+                }}"
+            ),
+            (None, _) => format!(
+                "if let Some(payload) = variant_payload_mut(value, {name:?}) {{
+                    {payload_statements}
+                }}"
+            ),
+        });
+    }
+    statements
+}
+
+fn serialized_variant_name(ty: &Enum, variant: &Variant) -> String {
+    variant.attrs.rename.clone().unwrap_or_else(|| {
+        ty.options
+            .variant_casing
+            .format_string(variant.name.strip_prefix("r#").unwrap_or(&variant.name))
+    })
+}
@@ -1,51 +1,493 @@
 use crate::{
-    functions::{Function, FunctionArg, FunctionList},
-    generators::rust_plugin::{
-        format_doc_lines, format_ident, format_modifiers, generate_type_bindings,
+    functions::{CacheOptions, Function, FunctionArg, FunctionList},
+    generators::{
+        formatting::rustfmt,
+        interning::{contains_interned_fields, has_interned_fields, INTERN_STRING_IMPORT},
+        lifecycle::get_init_export,
+        rust_plugin::{
+            format_cfg_attr, format_doc_lines, format_ident, format_modifiers,
+            generate_type_bindings,
+        },
+        schema_hash::SchemaHashes,
+        write_bindings_file, HTTP_IMPORT_CAPABILITY, STD_IMPORT_CAPABILITIES,
     },
+    protocol::protocol_to_json,
     types::{TypeIdent, TypeMap},
+    FileHeaders, RustWasmerExtendedRuntimeConfig,
 };
-use std::fs;
+use inflector::Inflector;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+
+mod migrations;
 
 pub(crate) fn generate_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: TypeMap,
+    schema_hashes: &SchemaHashes,
+    config: RustWasmerExtendedRuntimeConfig,
+    headers: &FileHeaders,
     path: &str,
 ) {
     fs::create_dir_all(path).expect("Could not create output directory");
 
-    // We use the same type generation as for the Rust plugin, only with the
-    // serializable and deserializable types inverted:
-    generate_type_bindings(&types, path);
+    // We use the same type generation as for the Rust plugin, so that all the
+    // serde attributes that determine the wire format (such as the enum
+    // representation) are identical on both sides:
+    generate_type_bindings(&types, schema_hashes, headers, path);
+
+    if config.generate_migrations {
+        migrations::generate_migrations(&types, headers, path);
+    }
+
+    // Routes are determined before the type parameters are replaced, since
+    // generic imports cannot be routed:
+    let import_routes = if config.generate_import_routes {
+        collect_import_routes(&import_functions, &types)
+    } else {
+        BTreeMap::new()
+    };
+
+    // Generic imports cannot be overridden either, because the types of their
+    // arguments are only known to the plugin:
+    let import_overrides = if config.generate_import_overrides {
+        import_functions
+            .iter()
+            .filter(|function| is_overridable(function))
+            .map(|function| function.name.clone())
+            .collect()
+    } else {
+        BTreeSet::new()
+    };
+
+    // The host implements generic imports for the concrete types it expects,
+    // which are inferred when the arguments are deserialized:
+    let import_functions = import_functions.with_generic_params_replaced("_");
+    generate_function_bindings(
+        import_functions,
+        export_functions,
+        &types,
+        &import_routes,
+        &import_overrides,
+        config,
+        headers,
+        path,
+    );
+}
+
+/// Returns whether the host's implementation of the given import can be
+/// overridden per runtime.
+///
+/// Dynamic imports and events are already handled by closures that are
+/// registered on the runtime.
+fn is_overridable(function: &Function) -> bool {
+    !function.attrs.dynamic && !function.attrs.event && function.generic_params.is_empty()
+}
+
+/// Formats the argument types and the return type of the host's
+/// implementation of the given import.
+fn format_host_signature(
+    function: &Function,
+    types: &TypeMap,
+    host_context: Option<&str>,
+) -> (String, Option<String>) {
+    let host_context = host_context.map(|host_context| {
+        if function.is_async {
+            format!("Arc<{host_context}>")
+        } else {
+            format!("&{host_context}")
+        }
+    });
+    let args = host_context
+        .into_iter()
+        .chain(function.args.iter().map(|arg| format_ident(&arg.ty, types)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = function
+        .return_type
+        .as_ref()
+        .map(|ty| format_ident(ty, types));
+    (args, return_type)
+}
+
+/// Formats the type of the closure with which the given import can be
+/// overridden, which takes the same arguments as the host's implementation.
+/// Async imports return an `OverrideFuture`.
+fn format_override_type(
+    function: &Function,
+    types: &TypeMap,
+    host_context: Option<&str>,
+) -> String {
+    let (args, return_type) = format_host_signature(function, types, host_context);
+    if function.is_async {
+        let return_type = return_type.as_deref().unwrap_or("()");
+        format!("dyn Fn({args}) -> OverrideFuture<{return_type}> + Send + Sync")
+    } else {
+        let return_type = format_return_type(return_type);
+        format!("dyn Fn({args}){return_type} + Send + Sync")
+    }
+}
+
+fn format_return_type(return_type: Option<String>) -> String {
+    return_type
+        .map(|return_type| format!(" -> {return_type}"))
+        .unwrap_or_default()
+}
+
+/// Formats the `RuntimeBuilder` method that overrides the given import.
+fn format_override_setter(
+    function: &Function,
+    types: &TypeMap,
+    host_context: Option<&str>,
+) -> String {
+    let name = &function.name;
+    let (args, return_type) = format_host_signature(function, types, host_context);
+    let override_type = format_override_type(function, types, host_context);
+    if function.is_async {
+        let return_type = return_type.as_deref().unwrap_or("()");
+        let arg_names = format_host_call_args(function, host_context.is_some());
+        format!(
+            r#"
+
+    /// Overrides the host's implementation of `{name}` for this runtime.
+    pub fn override_{name}<F>(
+        mut self,
+        implementation: impl Fn({args}) -> F + Send + Sync + 'static,
+    ) -> Self
+    where
+        F: std::future::Future<Output = {return_type}> + Send + 'static,
+    {{
+        let implementation: Arc<{override_type}> =
+            Arc::new(move |{arg_names}| Box::pin(implementation({arg_names})));
+        self.import_overrides.insert("{name}", implementation);
+        self
+    }}"#
+        )
+    } else {
+        let return_type = format_return_type(return_type);
+        format!(
+            r#"
+
+    /// Overrides the host's implementation of `{name}` for this runtime.
+    pub fn override_{name}(
+        mut self,
+        implementation: impl Fn({args}){return_type} + Send + Sync + 'static,
+    ) -> Self {{
+        let implementation: Arc<{override_type}> = Arc::new(implementation);
+        self.import_overrides.insert("{name}", implementation);
+        self
+    }}"#
+        )
+    }
+}
+
+/// Returns the signatures of the imports that can be routed to the exports of
+/// other plugins, keyed by import name.
+///
+/// Async imports cannot be routed, because the failure of a routed call could
+/// not be reported to the plugin. Neither can imports with interned strings,
+/// because their handles are only known to the runtime of the plugin that
+/// sent them.
+fn collect_import_routes(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+) -> BTreeMap<String, String> {
+    import_functions
+        .iter()
+        .filter(|function| {
+            !function.is_async
+                && !function.attrs.dynamic
+                && !function.attrs.batchable
+                && !function.attrs.event
+                && function.generic_params.is_empty()
+                && !function
+                    .args
+                    .iter()
+                    .map(|arg| &arg.ty)
+                    .chain(&function.return_type)
+                    .any(|ty| contains_interned_fields(ty, types))
+        })
+        .map(|function| {
+            (
+                function.name.clone(),
+                format_route_signature(function, types),
+            )
+        })
+        .collect()
+}
+
+/// Formats the signature by which routed imports are matched with exports,
+/// such as `fn(String) -> String`.
+fn format_route_signature(function: &Function, types: &TypeMap) -> String {
+    let args = function
+        .args
+        .iter()
+        .map(|arg| format_ident(&arg.ty, types))
+        .collect::<Vec<_>>()
+        .join(", ");
+    match &function.return_type {
+        Some(ty) => format!("fn({args}) -> {}", format_ident(ty, types)),
+        None => format!("fn({args})"),
+    }
+}
+
+/// Formats the statements that pass a call to an import on to the runtime it
+/// is routed to, if any.
+fn format_import_route(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let args = function
+        .args
+        .iter()
+        .map(|arg| {
+            if arg.ty.is_primitive() {
+                format!(
+                    "serialize_to_vec(&<{} as WasmAbi>::from_abi({}))",
+                    format_ident(&arg.ty, types),
+                    arg.name
+                )
+            } else {
+                format!("import_from_guest_raw(env, {})", arg.name)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_value = match &function.return_type {
+        None => "()".to_owned(),
+        Some(ty) if ty.is_primitive() || ty.is_packed_pair() => format!(
+            "deserialize_from_slice::<{}>(&result).to_abi()",
+            format_ident(ty, types)
+        ),
+        Some(_) => "try_export_to_guest_raw(env, result)?".to_owned(),
+    };
+
+    format!(
+        r#"if let Some(route) = env.import_route("{name}") {{
+        let result = route
+            .call_raw_export("{name}", vec![{args}])
+            .map_err(|error| InvocationError::RoutedImportFailed {{
+                function: "{name}".to_owned(),
+                error: Box::new(error),
+            }})?;
+        return Ok({return_value});
+    }}
+    "#
+    )
+}
+
+/// The `Runtime` methods for routing imports to the exports of other plugins.
+const IMPORT_ROUTE_METHODS: &str = r#"
+
+    /// Routes the calls that the plugin makes to the given import to the
+    /// export of the same name of another plugin.
+    ///
+    /// Arguments and results are passed on in their serialized form. Only
+    /// synchronous imports that are not dynamic, batchable or generic can be
+    /// routed, and the export needs to have the same signature. Calls to the
+    /// import fail with `InvocationError::RoutedImportFailed` if the export
+    /// fails. Routing an import that was already routed replaces its route.
+    pub fn route_import(&self, name: &str, target: impl RawExports + 'static) -> Result<(), RouteError> {
+        check_route(name, routable_import_signature(name), &target)?;
+        self.env.set_import_route(name, std::sync::Arc::new(target));
+        Ok(())
+    }
+
+    /// Removes the route of the given import, so that its calls are handled by
+    /// the host again.
+    ///
+    /// Returns whether the import was routed.
+    pub fn unroute_import(&self, name: &str) -> bool {
+        self.env.remove_import_route(name)
+    }"#;
+
+/// Formats the lookup of the signatures of routable imports, and the
+/// implementation of `RawExports` for the `Runtime`.
+fn format_import_routes(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    import_routes: &BTreeMap<String, String>,
+    types: &TypeMap,
+) -> String {
+    let import_signatures = import_functions
+        .iter()
+        .filter_map(|function| {
+            import_routes.get(&function.name).map(|signature| {
+                format!(
+                    "{}\"{}\" => Some(\"{signature}\"),",
+                    format_cfg_attr(function, ""),
+                    function.name
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let raw_exports = export_functions
+        .iter()
+        .filter(|function| !function.is_async)
+        .collect::<Vec<_>>();
+    let export_signatures = raw_exports
+        .iter()
+        .map(|function| {
+            format!(
+                "{}\"{}\" => Some(\"{}\"),",
+                format_cfg_attr(function, ""),
+                function.name,
+                format_route_signature(function, types)
+            )
+        })
+        .collect::<Vec<_>>();
+    let export_calls = raw_exports
+        .iter()
+        .map(|function| {
+            let name = &function.name;
+            let arg_names = function
+                .args
+                .iter()
+                .map(|arg| arg.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let args = function
+                .args
+                .iter()
+                .map(|arg| {
+                    if arg.ty.is_primitive() {
+                        format!("deserialize_from_slice(&{})", arg.name)
+                    } else {
+                        arg.name.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let result = match &function.return_type {
+                Some(ty) if !ty.is_primitive() && !ty.is_packed_pair() => "result",
+                _ => "serialize_to_vec(&result)",
+            };
+            format!(
+                r#"{cfg}"{name}" => {{
+                let [{arg_names}]: [Vec<u8>; {count}] = std::convert::TryInto::try_into(args)
+                    .expect("Export `{name}` takes {count} argument(s)");
+                let result = self.{name}_raw({args})?;
+                Ok({result})
+            }}"#,
+                cfg = format_cfg_attr(function, ""),
+                count = function.args.len(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        r#"
+
+/// Returns the signature of the given import, if it can be routed to the
+/// export of another plugin.
+fn routable_import_signature(name: &str) -> Option<&'static str> {{
+    match name {{
+        {}
+        _ => None,
+    }}
+}}
+
+impl RawExports for Runtime {{
+    fn raw_export_signature(&self, name: &str) -> Option<&'static str> {{
+        match name {{
+            {}
+            _ => None,
+        }}
+    }}
 
-    generate_function_bindings(import_functions, export_functions, &types, path);
+    fn call_raw_export(&self, name: &str, args: Vec<Vec<u8>>) -> Result<Vec<u8>, InvocationError> {{
+        match name {{
+            {}
+            _ => Err(InvocationError::FunctionNotExported(name.to_owned())),
+        }}
+    }}
+}}"#,
+        import_signatures.join("\n"),
+        export_signatures.join("\n"),
+        export_calls.join("\n"),
+    )
 }
 
-fn generate_create_import_object_func(import_functions: &FunctionList) -> String {
+fn generate_create_import_object_func(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+    config: &RustWasmerExtendedRuntimeConfig,
+) -> String {
+    // Imports are inserted one by one, so that those behind a feature can be
+    // left out:
     let imports = import_functions
         .iter()
         .map(|function| {
             let name = &function.name;
+            let symbol = function.symbol_name();
+            let cfg = format_cfg_attr(function, "    ");
             format!(
-                "\"__fp_gen_{name}\" => Function::new_native_with_env(store, env.clone(), _{name}),"
+                "{cfg}namespace.insert(\"{symbol}\", Function::new_native_with_env(store, env.clone(), _{name}));"
             )
         })
+        .chain(
+            has_batchable_imports(import_functions).then(|| {
+                "namespace.insert(\"__fp_host_batch\", Function::new_native_with_env(store, env.clone(), __fp_host_batch));"
+                    .to_owned()
+            }),
+        )
+        .chain(config.generate_opentelemetry.then(|| {
+            "namespace.insert(\"__fp_host_trace_context\", Function::new_native_with_env(store, env.clone(), trace_context));"
+                .to_owned()
+        }))
+        .chain(config.generate_deadlines.then(|| {
+            "namespace.insert(\"__fp_host_deadline_remaining\", Function::new_native_with_env(store, env.clone(), deadline_remaining));"
+                .to_owned()
+        }))
+        .chain(config.generate_stdio_capture.then(|| {
+            "namespace.insert(\"__fp_host_print\", Function::new_native_with_env(store, env.clone(), host_print));"
+                .to_owned()
+        }))
+        .chain(
+            config
+                .generate_std_imports
+                .then_some(STD_IMPORTS)
+                .into_iter()
+                .flatten()
+                .map(|name| {
+                    format!("namespace.insert(\"__fp_std_{name}\", Function::new_native_with_env(store, env.clone(), std_{name}));")
+                }),
+        )
+        .chain(config.generate_http_imports.then(|| {
+            "namespace.insert(\"__fp_http_request\", Function::new_native_with_env(store, env.clone(), http_request));"
+                .to_owned()
+        }))
+        .chain(has_interned_fields(types).then(|| {
+            format!("namespace.insert(\"{INTERN_STRING_IMPORT}\", Function::new_native_with_env(store, env.clone(), fp_bindgen_support::host::interning::intern_string));")
+        }))
         .collect::<Vec<_>>()
-        .join("\n            ");
+        .join("\n    ");
 
     format!(
         r#"fn create_import_object(store: &Store, env: &RuntimeInstanceData) -> ImportObject {{
+    let mut namespace = wasmer::Exports::new();
+    namespace.insert("__fp_host_resolve_async_value", Function::new_native_with_env(store, env.clone(), resolve_async_value));
+    namespace.insert("__fp_error", Function::new_native_with_env(store, env.clone(), guest_error));
+    {imports}
     imports! {{
-        "fp" => {{
-            "__fp_host_resolve_async_value" => Function::new_native_with_env(store, env.clone(), resolve_async_value),
-            {imports}
-        }}
+        "fp" => namespace
     }}
 }}"#
     )
 }
 
+/// The names of the built-in std imports, which are imported by the plugin
+/// with an `__fp_std_` prefix.
+const STD_IMPORTS: [&str; 5] = [
+    "read_file",
+    "write_file",
+    "now",
+    "monotonic_now",
+    "random_bytes",
+];
+
 pub(crate) fn format_raw_ident(ty: &TypeIdent, types: &TypeMap) -> String {
     if ty.is_primitive() {
         format_ident(ty, types)
@@ -54,6 +496,16 @@ pub(crate) fn format_raw_ident(ty: &TypeIdent, types: &TypeMap) -> String {
     }
 }
 
+/// Raw arguments accept any byte container, so buffers such as `bytes::Bytes`
+/// can be passed along without copying them into a `Vec<u8>` first.
+fn format_raw_arg_ident(ty: &TypeIdent, types: &TypeMap) -> String {
+    if ty.is_primitive() {
+        format_ident(ty, types)
+    } else {
+        "impl AsRef<[u8]>".to_owned()
+    }
+}
+
 pub(crate) fn format_wasm_ident(ty: &TypeIdent) -> String {
     if ty.is_primitive() {
         format!("<{} as WasmAbi>::AbiType", ty.name)
@@ -62,6 +514,24 @@ pub(crate) fn format_wasm_ident(ty: &TypeIdent) -> String {
     }
 }
 
+/// Pairs of primitives are only packed when they are returned, so return types
+/// are formatted separately from arguments.
+fn format_raw_return_ident(ty: &TypeIdent, types: &TypeMap) -> String {
+    if ty.is_packed_pair() {
+        format_ident(ty, types)
+    } else {
+        format_raw_ident(ty, types)
+    }
+}
+
+fn format_wasm_return_ident(ty: &TypeIdent) -> String {
+    if ty.is_packed_pair() {
+        format!("<{} as WasmAbi>::AbiType", ty.name)
+    } else {
+        format_wasm_ident(ty)
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub(crate) fn generate_import_function_variables<'a>(
     function: &'a Function,
@@ -97,7 +567,7 @@ pub(crate) fn generate_import_function_variables<'a>(
     let raw_args = function
         .args
         .iter()
-        .map(|FunctionArg { name, ty }| format!(", {name}: {}", format_raw_ident(ty, types)))
+        .map(|FunctionArg { name, ty }| format!(", {name}: {}", format_raw_arg_ident(ty, types)))
         .collect::<Vec<_>>()
         .join("");
     let wasm_args = function
@@ -113,15 +583,22 @@ pub(crate) fn generate_import_function_variables<'a>(
     };
 
     let return_type = match &function.return_type {
+        Some(ty) if function.attrs.lazy => format!("Lazy<{}>", format_ident(ty, types)),
         Some(ty) => format_ident(ty, types),
         None => "()".to_owned(),
     };
+    // Lazy exports hold on to the serialized value instead of deserializing it:
+    let deserialize_result = if function.attrs.lazy {
+        "let result = result.map(Lazy::new);".to_owned()
+    } else {
+        format!("let result = result.and_then(|ref data| try_deserialize_from_slice(data, \"{name}\", {return_type:?}));")
+    };
     let raw_return_type = match &function.return_type {
-        Some(ty) => format_raw_ident(ty, types),
+        Some(ty) => format_raw_return_ident(ty, types),
         None => "()".to_owned(),
     };
     let wasm_return_type = match &function.return_type {
-        Some(ty) => format_wasm_ident(ty),
+        Some(ty) => format_wasm_return_ident(ty),
         None => "()".to_owned(),
     };
 
@@ -137,7 +614,7 @@ pub(crate) fn generate_import_function_variables<'a>(
         .iter()
         .filter(|arg| !arg.ty.is_primitive())
         .map(|FunctionArg { name, .. }| {
-            format!("let {name} = export_to_guest_raw(&self.env, {name});")
+            format!("let {name} = try_export_to_guest_raw(&self.env, {name})?;")
         })
         .collect::<Vec<_>>()
         .join("\n");
@@ -157,18 +634,18 @@ pub(crate) fn generate_import_function_variables<'a>(
 
     let (raw_return_wrapper, return_wrapper) = if function.is_async {
         (
-            "let result = ModuleRawFuture::new(self.env.clone(), result).await;".to_string(),
-            "let result = result.await;\nlet result = result.map(|ref data| deserialize_from_slice(data));".to_string(),
+            "let result = ModuleRawFuture::new(self.env.clone(), result).await?;".to_string(),
+            format!("let result = result.await;\n{deserialize_result}"),
         )
     } else if !function
         .return_type
         .as_ref()
-        .map(TypeIdent::is_primitive)
+        .map(|ty| ty.is_primitive() || ty.is_packed_pair())
         .unwrap_or(true)
     {
         (
-            "let result = import_from_guest_raw(&self.env, result);".to_string(),
-            "let result = result.map(|ref data| deserialize_from_slice(data));".to_string(),
+            "let result = try_import_from_guest_raw(&self.env, result)?;".to_string(),
+            deserialize_result,
         )
     } else {
         (
@@ -196,7 +673,15 @@ pub(crate) fn generate_import_function_variables<'a>(
     )
 }
 
-fn format_import_function(function: &Function, types: &TypeMap) -> String {
+fn format_import_function(
+    function: &Function,
+    types: &TypeMap,
+    tracing: bool,
+    concurrency_limit: bool,
+    deadlines: bool,
+    payload_tracing: bool,
+    eager_resolution: bool,
+) -> String {
     let (
         doc,
         modifiers,
@@ -215,23 +700,174 @@ fn format_import_function(function: &Function, types: &TypeMap) -> String {
         return_wrapper,
     ) = generate_import_function_variables(function, types);
 
+    // The span is only entered while the plugin is called, so that it isn't
+    // held across the `.await` of async exports:
+    let (call, raw_return_wrapper) = if tracing {
+        (
+            format!(
+                "let span = export_span(\"{name}\");\n    \
+                let result = span.in_scope(|| function.call({wasm_arg_names}))?;"
+            ),
+            if function.is_async {
+                "let result = ModuleRawFuture::new(self.env.clone(), result)\n        \
+                .instrument(span)\n        \
+                .await?;"
+                    .to_owned()
+            } else {
+                raw_return_wrapper
+            },
+        )
+    } else {
+        (
+            format!("let result = function.call({wasm_arg_names})?;"),
+            raw_return_wrapper,
+        )
+    };
+
+    // The permit is acquired before any arguments are passed to the plugin,
+    // and is held until the plugin has resolved the result:
+    let acquire_permit = if concurrency_limit && function.is_async {
+        "let _permit = match &self.async_limit {\n        \
+            Some(limit) => Some(limit.acquire()?.await),\n        \
+            None => None,\n    \
+        };\n    "
+    } else {
+        ""
+    };
+
+    // The deadline is passed to the plugin right before it is called, since
+    // the call may be made from another thread than the one that created the
+    // future of async exports:
+    let call = if deadlines {
+        format!("export_deadline(&self.env);\n    {call}")
+    } else {
+        call
+    };
+
+    let (serialize_raw_args, raw_return_wrapper) = if payload_tracing {
+        let trace_args = function
+            .args
+            .iter()
+            .filter(|arg| !arg.ty.is_primitive())
+            .map(|FunctionArg { name: arg_name, .. }| {
+                format!(
+                    "\n    trace_guest_payload(&self.env, \"{name}\", \"{arg_name}\", PayloadDirection::ToPlugin, {arg_name});"
+                )
+            })
+            .collect::<String>();
+        let returns_payload = if function.is_async {
+            function.return_type.is_some()
+        } else {
+            returns_serialized_value(function)
+        };
+        let trace_result = if returns_payload {
+            format!(
+                "\n    trace_payload(&self.env, \"{name}\", \"result\", PayloadDirection::FromPlugin, &result);"
+            )
+        } else {
+            String::new()
+        };
+        (
+            format!("{serialize_raw_args}{trace_args}"),
+            format!("{raw_return_wrapper}{trace_result}"),
+        )
+    } else {
+        (serialize_raw_args, raw_return_wrapper)
+    };
+
+    let (cache_key, call_raw) = match &function.attrs.cache {
+        Some(cache) => format_cached_raw_call(function, cache, &arg_names, &return_wrapper),
+        None => (
+            String::new(),
+            format!("let result = self.{name}_raw({arg_names});\n    {return_wrapper}"),
+        ),
+    };
+
+    let cfg = format_cfg_attr(function, "");
+    let variant = name.to_pascal_case();
+    let symbol = function.symbol_name();
+    let resolve_function = if eager_resolution {
+        format!(
+            "let function = self.exports.{name}.as_ref().ok_or_else(|| \
+                InvocationError::FunctionNotExported(\"{symbol}\".to_owned()))?;"
+        )
+    } else {
+        format!(
+            "let function = self.instance
+        .exports
+        .get_native_function::<{wasm_args}, {wasm_return_type}>(\"{symbol}\")
+        .map_err(|error| InvocationError::from_export_error(\"{symbol}\", error))?;"
+        )
+    };
     format!(
-        r#"{doc}pub {modifiers}fn {name}(&self{args}) -> Result<{return_type}, InvocationError> {{
-    {serialize_args}
-    let result = self.{name}_raw({arg_names});
-    {return_wrapper}result
+        r#"{doc}{cfg}pub {modifiers}fn {name}(&self{args}) -> Result<{return_type}, PluginError> {{
+    {cache_key}{serialize_args}
+    {call_raw}result.map_err(|error| PluginError::new(PluginExport::{variant}, error))
 }}
-pub {modifiers}fn {name}_raw(&self{raw_args}) -> Result<{raw_return_type}, InvocationError> {{
-    {serialize_raw_args}let function = self.instance
-        .exports
-        .get_native_function::<{wasm_args}, {wasm_return_type}>("__fp_gen_{name}")
-        .map_err(|_| InvocationError::FunctionNotExported("__fp_gen_{name}".to_owned()))?;
-    let result = function.call({wasm_arg_names})?;
+{cfg}pub {modifiers}fn {name}_raw(&self{raw_args}) -> Result<{raw_return_type}, InvocationError> {{
+    {acquire_permit}{serialize_raw_args}{resolve_function}
+    {call}
     {raw_return_wrapper}Ok(result)
 }}"#
     )
 }
 
+/// Formats the statement that serializes the arguments by which the results
+/// of a cached export are keyed, together with the call to the raw export
+/// that is skipped if a result for the same key is still cached.
+///
+/// Results are cached in their serialized form, so every call deserializes
+/// its own copy.
+fn format_cached_raw_call(
+    function: &Function,
+    cache: &CacheOptions,
+    arg_names: &str,
+    return_wrapper: &str,
+) -> (String, String) {
+    let name = &function.name;
+    let key_args = cache
+        .key_args(function)
+        .into_iter()
+        .map(|arg| format!("&{arg}"))
+        .collect::<Vec<_>>();
+    let (await_result, deserialize_result) =
+        match return_wrapper.strip_prefix("let result = result.await;\n") {
+            Some(deserialize_result) => (".await", deserialize_result),
+            None => ("", return_wrapper),
+        };
+    let ttl_ms = cache.ttl_ms;
+    (
+        format!(
+            "let cache_key = serialize_to_vec(&{});\n    ",
+            format_args_tuple(&key_args)
+        ),
+        format!(
+            r#"let result = match self.env.export_cache().get("{name}", &cache_key) {{
+        Some(result) => Ok(result),
+        None => {{
+            let result = self.{name}_raw({arg_names}){await_result};
+            if let Ok(result) = &result {{
+                self.env.export_cache().insert("{name}", cache_key, result.clone(), std::time::Duration::from_millis({ttl_ms}));
+            }}
+            result
+        }}
+    }};
+    {deserialize_result}"#
+        ),
+    )
+}
+
+/// Formats the `Runtime` method that discards the memoized results of cached
+/// exports.
+fn format_clear_export_cache() -> String {
+    r#"/// Discards the memoized results of the exports that are annotated with
+/// `#[fp(cache)]`, for this runtime and its clones.
+pub fn clear_export_cache(&self) {
+    self.env.export_cache().clear();
+}"#
+    .to_owned()
+}
+
 pub(crate) fn format_import_arg(name: &str, ty: &TypeIdent, types: &TypeMap) -> String {
     if ty.is_primitive() {
         format!("let {name} = WasmAbi::from_abi({name});")
@@ -241,7 +877,30 @@ pub(crate) fn format_import_arg(name: &str, ty: &TypeIdent, types: &TypeMap) ->
     }
 }
 
-pub(crate) fn format_export_function(function: &Function, types: &TypeMap) -> String {
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn format_export_function(
+    function: &Function,
+    types: &TypeMap,
+    tracing: bool,
+    routable: bool,
+    has_host_context: bool,
+    deadlines: bool,
+    payload_tracing: bool,
+    import_override: Option<&str>,
+) -> String {
+    if function.attrs.dynamic {
+        return format_dynamic_export_function(
+            function,
+            types,
+            tracing,
+            has_host_context,
+            deadlines,
+        );
+    }
+    if function.attrs.event {
+        return format_event_export_function(function, tracing);
+    }
+
     let name = &function.name;
     let wasm_args = function
         .args
@@ -250,11 +909,13 @@ pub(crate) fn format_export_function(function: &Function, types: &TypeMap) -> St
         .collect::<Vec<_>>()
         .join("");
 
-    let wrapper_return_type = if function.is_async {
+    let wrapper_return_type = if function.is_fire_and_forget() {
+        "".to_owned()
+    } else if function.is_async {
         " -> FatPtr".to_owned()
     } else {
         match &function.return_type {
-            Some(ty) => format!(" -> {}", format_wasm_ident(ty)),
+            Some(ty) => format!(" -> {}", format_wasm_return_ident(ty)),
             None => "".to_owned(),
         }
     };
@@ -266,127 +927,2005 @@ pub(crate) fn format_export_function(function: &Function, types: &TypeMap) -> St
         .collect::<Vec<_>>()
         .join("\n");
 
-    let arg_names = function
-        .args
-        .iter()
-        .map(|arg| arg.name.as_ref())
-        .collect::<Vec<_>>()
-        .join(", ");
+    let host_context = format_host_context_statement(has_host_context);
+    let arg_names = format_host_call_args(function, has_host_context);
+    let host_call = format_host_call(function, &arg_names, import_override);
 
-    let return_wrapper = if function.is_async {
-        r#"let env = env.clone();
+    let trace_args = if payload_tracing {
+        function
+            .args
+            .iter()
+            .filter(|arg| !arg.ty.is_primitive())
+            .map(|FunctionArg { name: arg_name, .. }| {
+                format!(
+                    "trace_guest_payload(env, \"{name}\", \"{arg_name}\", PayloadDirection::FromPlugin, {arg_name});\n    "
+                )
+            })
+            .collect::<String>()
+    } else {
+        String::new()
+    };
+
+    // Spawned tasks continue the trace of the import that spawned them:
+    let in_current_span = if tracing { ".in_current_span()" } else { "" };
+    let (return_statements, return_value) = if function.is_fire_and_forget() {
+        // There is no result to pass back, so the plugin doesn't wait for one:
+        (
+            format!(
+                r#"let handle = tokio::runtime::Handle::current();
+    handle.spawn(result{in_current_span});"#
+            ),
+            "",
+        )
+    } else if function.is_async {
+        let trace_result = if payload_tracing {
+            format!(
+                "\n        trace_guest_payload(&env, \"{name}\", \"result\", PayloadDirection::ToPlugin, result_ptr);"
+            )
+        } else {
+            String::new()
+        };
+        (
+            format!(
+                r#"let env = env.clone();
     let async_ptr = create_future_value(&env);
     let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
+    handle.spawn(async move {{
         let result = result.await;
-        let result_ptr = export_to_guest(&env, &result);
+        let result_ptr = export_to_guest(&env, &result);{trace_result}
         env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr"#
+    }}{in_current_span});
+    "#
+            ),
+            "async_ptr",
+        )
     } else {
         match &function.return_type {
-            None => "",
-            Some(ty) if ty.is_primitive() => "result.to_abi()",
-            _ => "export_to_guest(env, &result)",
+            None => (String::new(), ""),
+            Some(ty) if ty.is_primitive() || ty.is_packed_pair() => {
+                (String::new(), "result.to_abi()")
+            }
+            _ if payload_tracing => (
+                format!(
+                    "let result = try_export_to_guest(env, &result)?;\n    \
+                    trace_guest_payload(env, \"{name}\", \"result\", PayloadDirection::ToPlugin, result);\n    "
+                ),
+                "result",
+            ),
+            _ => (String::new(), "try_export_to_guest(env, &result)?"),
         }
     };
+    // Async imports keep the deadline of the call after they are spawned:
+    let return_statements = if deadlines && function.is_async {
+        format!("let result = result.with_current_deadline();\n    {return_statements}")
+    } else {
+        return_statements
+    };
+
+    let capability_check = format_capability_check(function);
+    let span = format_import_span(function, tracing);
+    let deadline = format_deadline_guard(deadlines);
+    let route = if routable {
+        format_import_route(function, types)
+    } else {
+        String::new()
+    };
+    // Routed imports can fail with the error of the export they are routed to,
+    // and values that are passed back to the plugin fail the call if the
+    // plugin cannot allocate them:
+    let allocates_result = !function.is_async && returns_serialized_value(function);
+    let error_type = if routable || allocates_result {
+        Some("InvocationError")
+    } else if function.attrs.capability.is_some() {
+        Some("PermissionDenied")
+    } else {
+        None
+    };
+    let (wrapper_return_type, return_value) = if let Some(error_type) = error_type {
+        (
+            format!(
+                " -> Result<{}, {error_type}>",
+                wrapper_return_type.strip_prefix(" -> ").unwrap_or("()")
+            ),
+            format!(
+                "Ok({})",
+                if return_value.is_empty() {
+                    "()"
+                } else {
+                    return_value
+                }
+            ),
+        )
+    } else {
+        (wrapper_return_type, return_value.to_owned())
+    };
 
     format!(
         r#"pub fn _{name}(env: &RuntimeInstanceData{wasm_args}){wrapper_return_type} {{
-    {import_args}
-    let result = super::{name}({arg_names});
-    {return_wrapper}
+    {span}{deadline}{capability_check}{trace_args}{route}{import_args}
+    {host_context}{host_call}
+    {return_statements}{return_value}
 }}"#
     )
 }
 
-fn generate_function_bindings(
-    import_functions: FunctionList,
-    export_functions: FunctionList,
-    types: &TypeMap,
-    path: &str,
-) {
-    let imports = import_functions
-        .iter()
-        .map(|function| format_export_function(function, types))
-        .collect::<Vec<_>>()
-        .join("\n\n");
-    let exports = export_functions
-        .iter()
-        .map(|function| format_import_function(function, types))
-        .collect::<Vec<_>>()
-        .join("\n\n");
-    let new_func = r#"pub fn new(wasm_module: impl AsRef<[u8]>) -> Result<Self, RuntimeError> {
-        let store = Self::default_store();
-        let module = Module::new(&store, wasm_module)?;
-        let mut env = RuntimeInstanceData::default();
-        let import_object = create_import_object(module.store(), &env);
-        let instance = Instance::new(&module, &import_object).unwrap();
-        env.init_with_instance(&instance).unwrap();
-        Ok(Self { instance, env })
-    }"#
-    .to_string();
-    let create_import_object_func = generate_create_import_object_func(&import_functions);
-    format_function_bindings(imports, exports, new_func, create_import_object_func, path);
-}
+/// Formats the statement that calls the host's implementation of an import.
+///
+/// For rate-limited imports, the call is only made if a token is available,
+/// and a `RateLimited` error is returned to the plugin otherwise. For imports
+/// that can be overridden, the override of the runtime is called instead of
+/// the host's implementation, if there is one.
+fn format_host_call(function: &Function, arg_names: &str, import_override: Option<&str>) -> String {
+    let name = &function.name;
+    let (lookup, callee) = match import_override {
+        Some(override_type) => (
+            format!("let import_override = env.import_override::<{override_type}>(\"{name}\");\n    "),
+            format!(
+                "match import_override {{ Some(implementation) => implementation({arg_names}), None => {} }}",
+                if function.is_async {
+                    format!("Box::pin(super::{name}({arg_names}))")
+                } else {
+                    format!("super::{name}({arg_names})")
+                }
+            ),
+        ),
+        None => (String::new(), format!("super::{name}({arg_names})")),
+    };
+    let rate_limit = match &function.attrs.rate_limit {
+        Some(rate_limit) => rate_limit,
+        None => return format!("{lookup}let result = {callee};"),
+    };
 
-pub(crate) fn format_function_bindings(
-    imports: String,
-    exports: String,
-    new_func: String,
-    create_import_object_func: String,
-    path: &str,
-) {
-    let full = rustfmt_wrapper::rustfmt(format!(r#"use super::types::*;
-use fp_bindgen_support::{{
-    common::{{mem::FatPtr, abi::WasmAbi}},
-    host::{{
-        errors::{{InvocationError, RuntimeError}},
-        mem::{{export_to_guest, export_to_guest_raw, import_from_guest, import_from_guest_raw, deserialize_from_slice, serialize_to_vec}},
-        r#async::{{create_future_value, future::ModuleRawFuture, resolve_async_value}},
-        runtime::RuntimeInstanceData,
-    }},
-}};
-use std::cell::RefCell;
-use wasmer::{{imports, Function, ImportObject, Instance, Module, Store, WasmerEnv}};
+    let acquire = format!(
+        "env.rate_limiter().try_acquire(\"{name}\", {}, std::time::Duration::from_millis({}))",
+        rate_limit.calls, rate_limit.per_ms
+    );
+    let rate_limited = format!(
+        "Err(RateLimited {{ function: \"{name}\".to_owned(), retry_after_ms: retry_after.as_millis() as u32 }})"
+    );
+    if function.is_async {
+        let callee = if import_override.is_some() {
+            format!("({callee})")
+        } else {
+            callee
+        };
+        format!(
+            r#"{lookup}let rate_limit = {acquire};
+    let result = async move {{
+        match rate_limit {{
+            Ok(()) => {callee}.await,
+            Err(retry_after) => {rate_limited},
+        }}
+    }};"#
+        )
+    } else {
+        format!(
+            r#"{lookup}let result = match {acquire} {{
+        Ok(()) => {callee},
+        Err(retry_after) => {rate_limited},
+    }};"#
+        )
+    }
+}
 
-#[derive(Clone)]
-pub struct Runtime {{
-    instance: Instance,
-    env: RuntimeInstanceData,
-}}
+/// Returns whether the function returns a value that is serialized, rather
+/// than passed as a primitive.
+pub(crate) fn returns_serialized_value(function: &Function) -> bool {
+    function
+        .return_type
+        .as_ref()
+        .map(|ty| !ty.is_primitive() && !ty.is_packed_pair())
+        .unwrap_or_default()
+}
 
-impl Runtime {{
-    {new_func}
+/// Formats the statement that enters the span for a call to an import, if
+/// OpenTelemetry integration is enabled.
+fn format_import_span(function: &Function, tracing: bool) -> String {
+    if tracing {
+        format!(
+            "let _span = import_span(\"{}\").entered();\n    ",
+            function.name
+        )
+    } else {
+        String::new()
+    }
+}
+
+/// Formats the statement that makes the deadline of the plugin's current call
+/// the current deadline of the host, while it handles a call to an import.
+fn format_deadline_guard(deadlines: bool) -> &'static str {
+    if deadlines {
+        "let _deadline = enter_guest_deadline(env);\n    "
+    } else {
+        ""
+    }
+}
+
+/// Formats the statement that checks whether the capability that is required
+/// by an import was granted to the plugin, if it requires one.
+fn format_capability_check(function: &Function) -> String {
+    match &function.attrs.capability {
+        Some(capability) => format!(
+            "env.check_capability(\"{}\", \"{capability}\")?;\n    ",
+            function.name
+        ),
+        None => String::new(),
+    }
+}
+
+/// Formats the statement that retrieves the host context, if the runtime has
+/// one.
+fn format_host_context_statement(has_host_context: bool) -> &'static str {
+    if has_host_context {
+        "let host_context = get_host_context(env);\n    "
+    } else {
+        ""
+    }
+}
+
+/// Formats the arguments with which the host's implementation of an import is
+/// called. These start with the host context if the runtime has one, which is
+/// passed by reference, except to async imports.
+fn format_host_call_args(function: &Function, has_host_context: bool) -> String {
+    let host_context = match (has_host_context, function.is_async) {
+        (false, _) => None,
+        (true, false) => Some("&host_context"),
+        (true, true) => Some("host_context"),
+    };
+    host_context
+        .into_iter()
+        .chain(function.args.iter().map(|arg| arg.name.as_str()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Formats the function that retrieves the host context of the given type
+/// from the runtime.
+fn format_host_context_getter(host_context: &str) -> String {
+    format!(
+        r#"/// Returns the context that was attached using `RuntimeBuilder::with_context()`.
+fn get_host_context(env: &RuntimeInstanceData) -> Arc<{host_context}> {{
+    env.context().expect("Imports require a context, which needs to be attached using `RuntimeBuilder::with_context()`")
+}}"#
+    )
+}
+
+fn format_dynamic_export_function(
+    function: &Function,
+    types: &TypeMap,
+    tracing: bool,
+    has_host_context: bool,
+    deadlines: bool,
+) -> String {
+    let name = &function.name;
+    let (handler_name, payload) = match function.args.as_slice() {
+        [handler_name, payload] => (&handler_name.name, payload),
+        _ => unreachable!("Dynamic imports are validated to take two arguments"),
+    };
+    let payload_name = &payload.name;
+    let payload_ty = format_ident(&payload.ty, types);
+
+    let capability_check = format_capability_check(function);
+    let span = format_import_span(function, tracing);
+    let deadline = format_deadline_guard(deadlines);
+    let (return_type, wrap_ok): (_, fn(String) -> String) = if function.attrs.capability.is_some() {
+        ("Result<FatPtr, PermissionDenied>", |value| {
+            format!("Ok({value})")
+        })
+    } else {
+        ("FatPtr", |value| value)
+    };
+    let handler_result = wrap_ok(format!(
+        "export_to_guest_raw(env, handler(&{payload_name}))"
+    ));
+    let result = wrap_ok("export_to_guest(env, &result)".to_owned());
+    let host_context = format_host_context_statement(has_host_context);
+    let arg_names = format_host_call_args(function, has_host_context);
+
+    format!(
+        r#"pub fn _{name}(env: &RuntimeInstanceData, {handler_name}: FatPtr, {payload_name}: FatPtr) -> {return_type} {{
+    {span}{deadline}{capability_check}let {handler_name} = import_from_guest::<String>(env, {handler_name});
+    if let Some(handler) = env.dynamic_import_handler("{name}", &{handler_name}) {{
+        let {payload_name} = import_from_guest_raw(env, {payload_name});
+        return {handler_result};
+    }}
+    let {payload_name} = import_from_guest::<{payload_ty}>(env, {payload_name});
+    {host_context}let result = super::{name}({arg_names});
+    {result}
+}}"#
+    )
+}
+
+/// Formats the import through which the plugin emits an event. The event is
+/// passed on to the subscribers as-is, without deserializing it first.
+fn format_event_export_function(function: &Function, tracing: bool) -> String {
+    let name = &function.name;
+    let payload_name = match function.args.as_slice() {
+        [payload] => &payload.name,
+        _ => unreachable!("Events are validated to take one argument"),
+    };
+
+    let span = format_import_span(function, tracing);
+    format!(
+        r#"pub fn _{name}(env: &RuntimeInstanceData, {payload_name}: FatPtr) {{
+    {span}let {payload_name} = import_from_guest_raw(env, {payload_name});
+    env.emit_event("{name}", &{payload_name});
+}}"#
+    )
+}
+
+pub(crate) fn has_batchable_imports(import_functions: &FunctionList) -> bool {
+    import_functions
+        .iter()
+        .any(|function| function.attrs.batchable)
+}
+
+/// The runtime for which the `__fp_host_batch()` import is formatted. The
+/// calls are dispatched the same way, only the access to the plugin differs.
+#[derive(Clone, Copy)]
+pub(crate) enum BatchDispatcherRuntime {
+    Wasmer,
+    Wasmi,
+}
+
+/// Formats the `__fp_host_batch()` import, which dispatches the calls that the
+/// plugin queued during a batch to the implementations of the batchable
+/// imports, in the order in which they were queued.
+pub(crate) fn format_batch_dispatcher(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+    runtime: BatchDispatcherRuntime,
+    has_host_context: bool,
+    deadlines: bool,
+    override_types: &BTreeMap<String, String>,
+) -> Option<String> {
+    let functions = import_functions
+        .iter()
+        .filter(|function| function.attrs.batchable)
+        .collect::<Vec<_>>();
+    if functions.is_empty() {
+        return None;
+    }
+
+    let (signature, plugin, data, error_type) = match runtime {
+        BatchDispatcherRuntime::Wasmer => (
+            "pub fn __fp_host_batch(env: &RuntimeInstanceData, calls: FatPtr)",
+            "env",
+            "env",
+            "PermissionDenied",
+        ),
+        BatchDispatcherRuntime::Wasmi => (
+            "fn __fp_host_batch(mut caller: Caller<'_, RuntimeInstanceData>, calls: FatPtr)",
+            "&mut caller",
+            "caller.data()",
+            "Trap",
+        ),
+    };
+    let arms = functions
+        .iter()
+        .map(|function| {
+            let variant = function.name.to_pascal_case();
+            let arg_names = function
+                .args
+                .iter()
+                .map(|arg| arg.name.as_str())
+                .collect::<Vec<_>>();
+            let name = &function.name;
+            let args = format_host_call_args(function, has_host_context);
+            let call = match override_types.get(name) {
+                Some(override_type) => format!(
+                    "match env.import_override::<{override_type}>(\"{name}\") {{ \
+                        Some(implementation) => implementation({args}), \
+                        None => super::{name}({args}), \
+                    }}"
+                ),
+                None => format!("super::{name}({args})"),
+            };
+            format!(
+                "BatchedCall::{variant}({}) => {},",
+                format_args_tuple(&arg_names),
+                match &function.attrs.capability {
+                    Some(capability) => format!(
+                        "{{ {data}.check_capability(\"{}\", \"{capability}\")?; {call} }}",
+                        function.name
+                    ),
+                    None => call,
+                }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n            ");
+    let variants = functions
+        .iter()
+        .map(|function| {
+            let arg_types = function
+                .args
+                .iter()
+                .map(|arg| format_ident(&arg.ty, types))
+                .collect::<Vec<_>>();
+            format!(
+                "#[serde(rename = \"{}\")]\n    {}({}),",
+                function.wire_name(),
+                function.name.to_pascal_case(),
+                format_args_tuple(&arg_types)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ");
+
+    // Calls that are queued before a denied call have already been executed
+    // by the time the plugin traps.
+    let (return_type, return_value) = if functions
+        .iter()
+        .any(|function| function.attrs.capability.is_some())
+    {
+        (format!(" -> Result<(), {error_type}>"), "\n    Ok(())")
+    } else {
+        (String::new(), "")
+    };
+
+    let deadline = format_deadline_guard(deadlines);
+    let host_context = format_host_context_statement(has_host_context);
+    Some(format!(
+        r#"{signature}{return_type} {{
+    {deadline}{host_context}for call in import_from_guest::<Vec<BatchedCall>>({plugin}, calls) {{
+        match call {{
+            {arms}
+        }}
+    }}{return_value}
+}}
+
+/// A call to a batchable import, as it was queued by the plugin.
+#[derive(serde::Deserialize)]
+enum BatchedCall {{
+    {variants}
+}}"#
+    ))
+}
+
+/// Formats the arguments of a batched call as a tuple, which is how the plugin
+/// serializes them.
+fn format_args_tuple<T: AsRef<str>>(items: &[T]) -> String {
+    match items {
+        [] => "()".to_owned(),
+        items => format!(
+            "({},)",
+            items
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Formats the `Runtime` methods for registering and unregistering handlers
+/// for a dynamic import.
+pub(crate) fn format_dynamic_import_registration(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let payload_ty = match function.args.as_slice() {
+        [_, payload] => format_ident(&payload.ty, types),
+        _ => unreachable!("Dynamic imports are validated to take two arguments"),
+    };
+    let return_type = function
+        .return_type
+        .as_ref()
+        .map(|ty| format_ident(ty, types))
+        .expect("Dynamic imports are validated to have a return type");
+
+    format!(
+        r#"/// Registers a handler for the dynamic import `{name}`.
+///
+/// Calls from the plugin with the given name are dispatched to this handler
+/// instead of the static `{name}()` implementation. Registering a handler
+/// with a name that is already in use replaces the existing handler.
+pub fn register_{name}(&self, name: impl Into<String>, handler: impl Fn({payload_ty}) -> {return_type} + Send + Sync + 'static) {{
+    self.env.register_dynamic_import_handler(
+        "{name}",
+        name.into(),
+        std::sync::Arc::new(move |payload: &[u8]| serialize_to_vec(&handler(deserialize_from_slice(payload)))),
+    );
+}}
+
+/// Unregisters a handler for the dynamic import `{name}`.
+///
+/// Returns whether a handler was registered under the given name.
+pub fn unregister_{name}(&self, name: &str) -> bool {{
+    self.env.unregister_dynamic_import_handler("{name}", name)
+}}"#
+    )
+}
+
+/// Formats the `Runtime` method for subscribing to an event.
+pub(crate) fn format_event_subscription(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let payload_ty = match function.args.as_slice() {
+        [payload] => format_ident(&payload.ty, types),
+        _ => unreachable!("Events are validated to take one argument"),
+    };
+
+    format!(
+        r#"/// Subscribes to the `{name}` event.
+///
+/// Every event that the plugin emits after subscribing is sent to the
+/// returned receiver. Dropping the receiver ends the subscription.
+pub fn subscribe_{name}(&self) -> std::sync::mpsc::Receiver<{payload_ty}> {{
+    let (sender, receiver) = std::sync::mpsc::channel();
+    self.env.subscribe_to_event(
+        "{name}",
+        Box::new(move |event: &[u8]| sender.send(deserialize_from_slice(event)).is_ok()),
+    );
+    receiver
+}}"#
+    )
+}
+
+/// Formats the `Capability` enum with the capabilities that can be granted to
+/// the plugin.
+pub(crate) fn format_capability_enum(capabilities: &BTreeSet<&str>) -> String {
+    let variants = capabilities
+        .iter()
+        .map(|capability| format!("{},", capability.to_pascal_case()))
+        .collect::<Vec<_>>()
+        .join("\n    ");
+    let names = capabilities
+        .iter()
+        .map(|capability| format!("Self::{} => \"{capability}\",", capability.to_pascal_case()))
+        .collect::<Vec<_>>()
+        .join("\n            ");
+
+    format!(
+        r#"
+
+/// A capability that can be granted to the plugin. Imports that require a
+/// capability can only be called by the plugin if it was granted.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Capability {{
+    {variants}
+}}
+
+impl Capability {{
+    /// Returns the name of the capability, as it is declared in the protocol.
+    pub fn as_str(&self) -> &'static str {{
+        match self {{
+            {names}
+        }}
+    }}
+}}"#
+    )
+}
+
+/// Formats the `PluginExport` and `PluginError` types, through which the
+/// exports of the protocol report their failures.
+///
+/// The runtime is identified by the variant of `InvocationError` that holds its
+/// traps, and the type of those traps.
+pub(crate) fn format_plugin_error(
+    export_functions: &FunctionList,
+    trap_variant: &str,
+    trap_type: &str,
+) -> String {
+    let variants = export_functions
+        .iter()
+        .map(|function| {
+            format!(
+                "{}{},",
+                format_cfg_attr(function, "    "),
+                function.name.to_pascal_case()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ");
+    let names = export_functions
+        .iter()
+        .map(|function| {
+            format!(
+                "{}Self::{} => \"{}\",",
+                format_cfg_attr(function, "            "),
+                function.name.to_pascal_case(),
+                function.name
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n            ");
+    let symbols = export_functions
+        .iter()
+        .map(|function| {
+            format!(
+                "{}Self::{} => \"{}\",",
+                format_cfg_attr(function, "            "),
+                function.name.to_pascal_case(),
+                function.symbol_name()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n            ");
+
+    format!(
+        r#"
+
+/// An export of the protocol, which identifies the export that failed in a
+/// `PluginError`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PluginExport {{
+    {variants}
+}}
+
+impl PluginExport {{
+    /// Returns the name of the export, as it is declared in the protocol.
+    pub fn as_str(&self) -> &'static str {{
+        match *self {{
+            {names}
+        }}
+    }}
+
+    /// Returns the name of the symbol through which the plugin exports it.
+    pub fn symbol_name(&self) -> &'static str {{
+        match *self {{
+            {symbols}
+        }}
+    }}
+}}
+
+impl std::fmt::Display for PluginExport {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.write_str(self.as_str())
+    }}
+}}
+
+/// The ways in which calling an export of the plugin can fail.
+#[derive(Debug)]
+pub enum PluginError {{
+    /// The plugin does not export the function.
+    MissingExport(PluginExport),
+
+    /// The plugin exports the function with another signature, which usually
+    /// means that it was built for another version of the protocol.
+    SignatureMismatch(PluginExport),
+
+    /// The plugin trapped while it was running the function.
+    GuestTrap {{
+        export: PluginExport,
+        error: {trap_type},
+    }},
+
+    /// The plugin reported a failure before it aborted, such as a panic.
+    GuestPanic {{
+        export: PluginExport,
+        error: GuestError,
+    }},
+
+    /// The value that was returned by the plugin could not be deserialized.
+    Deserialization {{
+        export: PluginExport,
+        type_name: &'static str,
+        message: String,
+    }},
+
+    /// The call failed in the runtime, for instance because the plugin was
+    /// denied an import or could not allocate the arguments.
+    Transport {{
+        export: PluginExport,
+        error: InvocationError,
+    }},
+}}
+
+impl PluginError {{
+    fn new(export: PluginExport, error: InvocationError) -> Self {{
+        match error {{
+            InvocationError::FunctionNotExported(_) => Self::MissingExport(export),
+            InvocationError::SignatureMismatch(_) => Self::SignatureMismatch(export),
+            InvocationError::{trap_variant}(error) => Self::GuestTrap {{ export, error }},
+            InvocationError::GuestError(error) => Self::GuestPanic {{ export, error }},
+            InvocationError::DeserializationFailed {{
+                type_name, message, ..
+            }} => Self::Deserialization {{
+                export,
+                type_name,
+                message,
+            }},
+            error => Self::Transport {{ export, error }},
+        }}
+    }}
+
+    /// Returns the export that failed.
+    pub fn export(&self) -> PluginExport {{
+        match self {{
+            Self::MissingExport(export) | Self::SignatureMismatch(export) => *export,
+            Self::GuestTrap {{ export, .. }}
+            | Self::GuestPanic {{ export, .. }}
+            | Self::Deserialization {{ export, .. }}
+            | Self::Transport {{ export, .. }} => *export,
+        }}
+    }}
+}}
+
+impl std::fmt::Display for PluginError {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        match self {{
+            Self::MissingExport(export) => write!(f, "plugin does not export `{{export}}`"),
+            Self::SignatureMismatch(export) => {{
+                write!(f, "plugin exports `{{export}}` with an unexpected signature")
+            }}
+            Self::GuestTrap {{ export, error }} => write!(f, "plugin trapped in `{{export}}`: {{error}}"),
+            Self::GuestPanic {{ export, error }} => write!(f, "plugin failed in `{{export}}`: {{error}}"),
+            Self::Deserialization {{
+                export,
+                type_name,
+                message,
+            }} => write!(
+                f,
+                "could not deserialize the `{{type_name}}` returned by `{{export}}`: {{message}}"
+            ),
+            Self::Transport {{ export, error }} => write!(f, "could not call `{{export}}`: {{error}}"),
+        }}
+    }}
+}}
+
+impl std::error::Error for PluginError {{}}
+
+impl From<PluginError> for InvocationError {{
+    fn from(error: PluginError) -> Self {{
+        match error {{
+            PluginError::MissingExport(export) => {{
+                Self::FunctionNotExported(export.symbol_name().to_owned())
+            }}
+            PluginError::SignatureMismatch(export) => {{
+                Self::SignatureMismatch(export.symbol_name().to_owned())
+            }}
+            PluginError::GuestTrap {{ error, .. }} => Self::{trap_variant}(error),
+            PluginError::GuestPanic {{ error, .. }} => Self::GuestError(error),
+            PluginError::Deserialization {{
+                export,
+                type_name,
+                message,
+            }} => Self::DeserializationFailed {{
+                function: export.as_str().to_owned(),
+                type_name,
+                message,
+            }},
+            PluginError::Transport {{ error, .. }} => error,
+        }}
+    }}
+}}"#
+    )
+}
+
+/// Formats the public constructor of the runtime, which creates the module
+/// using the given statements and passes it to `from_module()`.
+///
+/// If the protocol declares capabilities, this constructor is called
+/// `with_capabilities()` and grants the given capabilities, while `new()`
+/// delegates to it without granting any.
+pub(crate) fn format_new_function(create_module: &str, has_capabilities: bool) -> String {
+    if !has_capabilities {
+        return format!(
+            r#"pub fn new(wasm_module: impl AsRef<[u8]>) -> Result<Self, RuntimeError> {{
+        {create_module}
+        Self::from_module(&module, RuntimeInstanceData::default())
+    }}"#
+        );
+    }
+
+    format!(
+        r#"/// Creates a runtime that does not grant any capabilities to the plugin.
+    pub fn new(wasm_module: impl AsRef<[u8]>) -> Result<Self, RuntimeError> {{
+        Self::with_capabilities(wasm_module, &[])
+    }}
+
+    /// Creates a runtime that grants the given capabilities to the plugin.
+    ///
+    /// Calls to imports that require a capability that was not granted fail
+    /// with `InvocationError::PermissionDenied`.
+    pub fn with_capabilities(wasm_module: impl AsRef<[u8]>, capabilities: &[Capability]) -> Result<Self, RuntimeError> {{
+        {create_module}
+        Self::from_module(&module, RuntimeInstanceData::default().with_capabilities(capabilities.iter().map(Capability::as_str)))
+    }}"#
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn generate_function_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: &TypeMap,
+    import_routes: &BTreeMap<String, String>,
+    import_overrides: &BTreeSet<String>,
+    config: RustWasmerExtendedRuntimeConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let overridable_imports = import_functions
+        .iter()
+        .filter(|function| import_overrides.contains(&function.name))
+        .collect::<Vec<_>>();
+    let override_types = overridable_imports
+        .iter()
+        .map(|function| {
+            (
+                function.name.clone(),
+                format_override_type(function, types, config.host_context.as_deref()),
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+    let imports = import_functions
+        .iter()
+        .map(|function| {
+            format_cfg_attr(function, "")
+                + &format_export_function(
+                    function,
+                    types,
+                    config.generate_opentelemetry,
+                    import_routes.contains_key(&function.name),
+                    config.host_context.is_some(),
+                    config.generate_deadlines,
+                    config.generate_payload_tracing,
+                    override_types.get(&function.name).map(String::as_str),
+                )
+        })
+        .chain(format_batch_dispatcher(
+            &import_functions,
+            types,
+            BatchDispatcherRuntime::Wasmer,
+            config.host_context.is_some(),
+            config.generate_deadlines,
+            &override_types,
+        ))
+        .chain(
+            config
+                .host_context
+                .as_deref()
+                .map(format_host_context_getter),
+        )
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let exports = export_functions
+        .iter()
+        .map(|function| {
+            format_import_function(
+                function,
+                types,
+                config.generate_opentelemetry,
+                config.generate_async_concurrency_limit,
+                config.generate_deadlines,
+                config.generate_payload_tracing,
+                config.generate_eager_export_resolution,
+            )
+        })
+        .chain(
+            export_functions
+                .iter()
+                .any(|function| function.attrs.cache.is_some())
+                .then(format_clear_export_cache),
+        )
+        .chain(
+            import_functions
+                .iter()
+                .filter(|function| function.attrs.dynamic)
+                .map(|function| format_dynamic_import_registration(function, types)),
+        )
+        .chain(
+            import_functions
+                .iter()
+                .filter(|function| function.attrs.event)
+                .map(|function| format_event_subscription(function, types)),
+        )
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let mut capabilities = import_functions.capabilities();
+    if config.generate_std_imports {
+        capabilities.extend(STD_IMPORT_CAPABILITIES);
+    }
+    if config.generate_http_imports {
+        capabilities.insert(HTTP_IMPORT_CAPABILITY);
+    }
+    let has_capabilities = !capabilities.is_empty();
+    // Exports are resolved before the shutdown guard is created, so that
+    // plugins that are rejected are not shut down:
+    let (resolve_exports, exports_field) = if config.generate_eager_export_resolution {
+        (
+            "let exports = Arc::new(ResolvedExports::resolve(&instance)?);\n        ",
+            "exports, ",
+        )
+    } else {
+        ("", "")
+    };
+    let async_limit_field = if config.generate_async_concurrency_limit {
+        ", async_limit: None"
+    } else {
+        ""
+    };
+    let new_func = format_new_function(
+        "let store = Self::default_store();\n        let module = Module::new(&store, wasm_module)?;",
+        has_capabilities,
+    );
+    let new_func = format!(
+        r#"{new_func}
+
+    fn from_module(module: &Module, mut env: RuntimeInstanceData) -> Result<Self, RuntimeError> {{
+        let import_object = create_import_object(module.store(), &env);
+        let instance = Instance::new(module, &import_object)?;
+        env.init_with_instance(&instance).unwrap();
+        env.negotiate_async_protocol();
+        {resolve_exports}let shutdown_guard = Arc::new(ShutdownGuard::new(instance.clone()));
+        Ok(Self {{ instance, env, {exports_field}_shutdown_guard: shutdown_guard{async_limit_field} }})
+    }}"#
+    );
+    let new_func = if config.generate_eager_export_resolution {
+        new_func + MISSING_EXPORTS_GETTER
+    } else {
+        new_func
+    };
+    let resolved_exports = if config.generate_eager_export_resolution {
+        format_resolved_exports(&export_functions, types)
+    } else {
+        String::new()
+    };
+    let runtime_pool = if config.generate_runtime_pool {
+        format_runtime_pool(has_capabilities)
+    } else {
+        String::new()
+    };
+    let shared_runtime = if config.generate_shared_runtime {
+        format_shared_runtime(&export_functions, types, has_capabilities)
+    } else {
+        String::new()
+    };
+    let capability_enum = if has_capabilities {
+        format_capability_enum(&capabilities)
+    } else {
+        String::new()
+    };
+    let new_func = if config.generate_module_cache {
+        new_func + MODULE_CACHE_CONSTRUCTOR
+    } else {
+        new_func
+    };
+    let new_func = if config.generate_signature_verification {
+        new_func + SIGNATURE_VERIFIER_CONSTRUCTOR
+    } else {
+        new_func
+    };
+    let new_func = if config.generate_async_concurrency_limit {
+        new_func + ASYNC_CONCURRENCY_LIMIT_SETTER
+    } else {
+        new_func
+    };
+    let (new_func, import_routes) = if config.generate_import_routes {
+        (
+            new_func + IMPORT_ROUTE_METHODS,
+            format_import_routes(&import_functions, &export_functions, import_routes, types),
+        )
+    } else {
+        (new_func, String::new())
+    };
+    let create_import_object_func =
+        generate_create_import_object_func(&import_functions, types, &config);
+    let runtime_builder = format_runtime_builder(
+        has_capabilities,
+        &config,
+        get_init_export(&export_functions),
+        &overridable_imports,
+        types,
+    );
+    format_function_bindings(
+        imports,
+        exports,
+        new_func,
+        create_import_object_func
+            + &runtime_builder
+            + &resolved_exports
+            + &runtime_pool
+            + &shared_runtime
+            + &capability_enum
+            + &format_plugin_error(
+                &export_functions,
+                "WasmerRuntimeError",
+                "wasmer::RuntimeError",
+            )
+            + &import_routes,
+        protocol_to_json(&import_functions, &export_functions, types),
+        &config,
+        headers,
+        path,
+    );
+}
+
+/// Returns the exports that were found to be missing when the runtime was
+/// created.
+const MISSING_EXPORTS_GETTER: &str = r#"
+
+    /// Returns the exports of the protocol that the plugin does not export.
+    ///
+    /// All exports are resolved and checked against the protocol when the
+    /// runtime is created, so this allows hosts to log which capabilities a
+    /// plugin lacks at load time. Calling a missing export fails with
+    /// `InvocationError::FunctionNotExported`.
+    pub fn missing_exports(&self) -> &[PluginExport] {
+        &self.exports.missing
+    }"#;
+
+/// Formats the `ResolvedExports` of a runtime, which holds every export of the
+/// protocol that the plugin exports, once it has been checked to have the
+/// expected signature.
+fn format_resolved_exports(export_functions: &FunctionList, types: &TypeMap) -> String {
+    let (fields, resolutions): (Vec<_>, Vec<_>) = export_functions
+        .iter()
+        .map(|function| {
+            let (_, _, name, _, _, wasm_args, _, _, wasm_return_type, ..) =
+                generate_import_function_variables(function, types);
+            let field = format!(
+                "{}{name}: Option<NativeFunc<{wasm_args}, {wasm_return_type}>>,",
+                format_cfg_attr(function, "    ")
+            );
+            let resolution = format!(
+                "{}{name}: Self::resolve_function(instance, PluginExport::{}, &mut missing)?,",
+                format_cfg_attr(function, "            "),
+                name.to_pascal_case()
+            );
+            (field, resolution)
+        })
+        .unzip();
+    let fields = fields.join("\n    ");
+    let resolutions = resolutions.join("\n            ");
+
+    format!(
+        r#"
+
+/// The exports of the plugin, which are resolved and checked against the
+/// protocol once, when the runtime is created.
+struct ResolvedExports {{
+    {fields}
+    missing: Vec<PluginExport>,
+}}
+
+impl ResolvedExports {{
+    fn resolve(instance: &Instance) -> Result<Self, RuntimeError> {{
+        let mut missing = Vec::new();
+        Ok(Self {{
+            {resolutions}
+            missing,
+        }})
+    }}
+
+    fn resolve_function<Args: WasmTypeList, Rets: WasmTypeList>(
+        instance: &Instance,
+        export: PluginExport,
+        missing: &mut Vec<PluginExport>,
+    ) -> Result<Option<NativeFunc<Args, Rets>>, RuntimeError> {{
+        let function = resolve_export(instance, export.symbol_name()).map_err(|error| *error)?;
+        if function.is_none() {{
+            missing.push(export);
+        }}
+        Ok(function)
+    }}
+}}"#
+    )
+}
+
+/// Formats the `RuntimeBuilder`, which allows the host to choose the store
+/// (and thereby the engine and compiler) that is used, or to instantiate a
+/// runtime from a module that was compiled beforehand.
+/// Creates a runtime from a `ModuleCache`, so that a plugin is only compiled
+/// the first time it is loaded.
+const MODULE_CACHE_CONSTRUCTOR: &str = r#"
+
+    /// Creates a runtime for the given module, which is loaded from the given
+    /// cache if it was compiled before. The runtime does not grant any
+    /// capabilities to the plugin.
+    ///
+    /// Modules that are not cached yet are compiled and written to the cache,
+    /// so that subsequent runtimes for the same module can skip compilation.
+    pub fn with_module_cache(
+        wasm_module: impl AsRef<[u8]>,
+        cache: &fp_bindgen_support::host::cache::ModuleCache,
+    ) -> Result<Self, RuntimeError> {
+        let module = cache.load(&Self::default_store(), wasm_module.as_ref())?;
+        Self::from_module(&module, RuntimeInstanceData::default())
+    }"#;
+
+/// Creates a runtime for a plugin, once its signature has been verified.
+const SIGNATURE_VERIFIER_CONSTRUCTOR: &str = r#"
+
+    /// Creates a runtime for the given module, after verifying its signature
+    /// using the given verifier. The runtime does not grant any capabilities
+    /// to the plugin.
+    ///
+    /// Modules that are unsigned or whose signature is invalid are rejected
+    /// with `RuntimeError::InvalidSignature` before they are compiled.
+    pub fn with_signature_verifier(
+        wasm_module: impl AsRef<[u8]>,
+        verifier: &SignatureVerifier,
+    ) -> Result<Self, RuntimeError> {
+        verifier.verify(wasm_module.as_ref())?;
+        Self::new(wasm_module)
+    }"#;
+
+/// Limits the number of concurrent invocations of async exports.
+const ASYNC_CONCURRENCY_LIMIT_SETTER: &str = r#"
+
+    /// Limits the number of async exports that may be invoked concurrently.
+    ///
+    /// Up to `max_concurrent` invocations run at the same time, while up to
+    /// `max_queued` further invocations wait for their turn, in the order in
+    /// which they were made. Invocations beyond that fail with
+    /// `InvocationError::Overloaded`.
+    ///
+    /// The limit is shared with clones of this runtime, since they use the
+    /// same instance.
+    pub fn with_async_concurrency_limit(mut self, max_concurrent: usize, max_queued: usize) -> Self {
+        self.async_limit = Some(ConcurrencyLimit::new(max_concurrent, max_queued));
+        self
+    }"#;
+
+/// The `RuntimeBuilder` method for loading a module from a `ModuleCache`.
+/// Formats the `RuntimeBuilder` method that builds a runtime for a module that
+/// is loaded from a `ModuleCache`, which verifies the module's signature in
+/// the same way as `build()`.
+fn format_module_cache_builder_method(verify: &str) -> String {
+    format!(
+        r#"
+
+    /// Loads the given module from the cache, or compiles it if it was not
+    /// cached before, and instantiates a runtime for it.
+    ///
+    /// The cache is keyed by the target of the builder's store, so runtimes
+    /// with different stores may share the same cache.
+    pub fn build_with_cache(
+        self,
+        wasm_module: impl AsRef<[u8]>,
+        cache: &fp_bindgen_support::host::cache::ModuleCache,
+    ) -> Result<Runtime, RuntimeError> {{{verify}
+        self.wasm_features.validate(wasm_module.as_ref())?;
+        let module = cache.load(&self.store(), wasm_module.as_ref())?;
+        self.build_with_module(&module)
+    }}"#
+    )
+}
+
+/// Formats the field and setter with which a `RuntimeBuilder` is told to call
+/// the `init` export, along with the statement that calls it once the runtime
+/// has been built.
+pub(crate) fn format_init_builder_parts(
+    init: &Function,
+    types: &TypeMap,
+) -> (String, String, String) {
+    let init_call = |condition: &str, args: &str| {
+        format!(
+            r#"
+        {condition} {{
+            match runtime.init({args}) {{
+                Ok(()) | Err(PluginError::MissingExport(_)) => {{}}
+                Err(error) => return Err(RuntimeError::InitFailed(error.into())),
+            }}
+        }}"#
+        )
+    };
+
+    match init.args.first() {
+        Some(arg) => {
+            let ty = format_ident(&arg.ty, types);
+            (
+                format!("\n    init_config: Option<{ty}>,"),
+                format!(
+                    r#"
+
+    /// Calls the `init` export of the plugin with the given config, once the
+    /// runtime has been instantiated. Plugins that don't implement `init` are
+    /// not initialized.
+    pub fn with_init(mut self, config: {ty}) -> Self {{
+        self.init_config = Some(config);
+        self
+    }}"#
+                ),
+                init_call("if let Some(config) = self.init_config", "config"),
+            )
+        }
+        None => (
+            "\n    init: bool,".to_owned(),
+            r#"
+
+    /// Calls the `init` export of the plugin, once the runtime has been
+    /// instantiated. Plugins that don't implement `init` are not initialized.
+    pub fn with_init(mut self) -> Self {
+        self.init = true;
+        self
+    }"#
+            .to_owned(),
+            init_call("if self.init", ""),
+        ),
+    }
+}
+
+pub(crate) fn format_runtime_builder(
+    has_capabilities: bool,
+    config: &RustWasmerExtendedRuntimeConfig,
+    init: &Function,
+    overridable_imports: &[&Function],
+    types: &TypeMap,
+) -> String {
+    let (capabilities_field, capabilities_setter, env) = if has_capabilities {
+        (
+            "\n    capabilities: Vec<Capability>,",
+            r#"
+
+    /// Grants the given capabilities to the plugin.
+    ///
+    /// Calls to imports that require a capability that was not granted fail
+    /// with `InvocationError::PermissionDenied`.
+    pub fn with_capabilities(mut self, capabilities: &[Capability]) -> Self {
+        self.capabilities = capabilities.to_vec();
+        self
+    }"#,
+            "RuntimeInstanceData::default()
+            .with_capabilities(self.capabilities.iter().map(Capability::as_str))",
+        )
+    } else {
+        ("", "", "RuntimeInstanceData::default()")
+    };
+    let (std_imports_field, std_imports_setter, env) = if config.generate_std_imports {
+        (
+            "\n    std_imports: StdImports,",
+            r#"
+
+    /// Sets the host facilities that are exposed to the plugin through the
+    /// built-in std imports. By default, no files are exposed, while the
+    /// clock and random number generator of the operating system are used.
+    pub fn with_std_imports(mut self, std_imports: StdImports) -> Self {
+        self.std_imports = std_imports;
+        self
+    }"#,
+            format!("{env}\n            .with_std_imports(self.std_imports)"),
+        )
+    } else {
+        ("", "", env.to_owned())
+    };
+    let (http_imports_field, http_imports_setter, env) = if config.generate_http_imports {
+        (
+            "\n    http_imports: HttpImports,",
+            r#"
+
+    /// Sets the HTTP client that is exposed to the plugin through the
+    /// built-in HTTP imports. By default, requests are sent using a shared
+    /// `reqwest::Client`.
+    pub fn with_http_imports(mut self, http_imports: HttpImports) -> Self {
+        self.http_imports = http_imports;
+        self
+    }"#,
+            format!("{env}\n            .with_http_imports(self.http_imports)"),
+        )
+    } else {
+        ("", "", env)
+    };
+    let (context_field, context_setter, env) = match config.host_context.as_deref() {
+        Some(host_context) => (
+            "\n    context: Option<Arc<dyn std::any::Any + Send + Sync>>,",
+            format!(
+                r#"
+
+    /// Attaches the context that is passed to the host's implementations of
+    /// the imports. The context may be shared with other runtimes by passing
+    /// an `Arc`.
+    pub fn with_context(mut self, context: impl Into<Arc<{host_context}>>) -> Self {{
+        let context: Arc<{host_context}> = context.into();
+        self.context = Some(context);
+        self
+    }}"#
+            ),
+            format!("{env}\n            .with_context(self.context)"),
+        ),
+        None => ("", String::new(), env),
+    };
+    let (overrides_field, overrides_setters, env) = if config.generate_import_overrides {
+        (
+            "\n    import_overrides: ImportOverrides,",
+            overridable_imports
+                .iter()
+                .map(|function| {
+                    format_override_setter(function, types, config.host_context.as_deref())
+                })
+                .collect::<String>(),
+            format!("{env}\n            .with_import_overrides(self.import_overrides)"),
+        )
+    } else {
+        ("", String::new(), env)
+    };
+    let (output_handler_field, output_handler_setter, env) = if config.generate_stdio_capture {
+        (
+            "\n    output_handler: Option<OutputHandler>,",
+            r#"
+
+    /// Sets the handler for the lines that the plugin prints using the
+    /// `println!()` and `eprintln!()` macros of `fp-bindgen-support`. By
+    /// default, they are printed to the stdout and stderr of the host.
+    pub fn with_output_handler(
+        mut self,
+        handler: impl Fn(OutputStream, &str) + Send + Sync + 'static,
+    ) -> Self {
+        self.output_handler = Some(Arc::new(handler));
+        self
+    }"#,
+            format!("{env}\n            .with_output_handler(self.output_handler)"),
+        )
+    } else {
+        ("", "", env)
+    };
+    let (payload_tracer_field, payload_tracer_setter, env) = if config.generate_payload_tracing {
+        (
+            "\n    payload_tracer: Option<PayloadTracer>,",
+            r#"
+
+    /// Sets the tracer for the serialized payloads that are passed to and
+    /// from the plugin. By default, payloads are only traced if the
+    /// `FP_BINDGEN_TRACE_PAYLOADS` environment variable is set.
+    pub fn with_payload_tracer(mut self, tracer: PayloadTracer) -> Self {
+        self.payload_tracer = Some(tracer);
+        self
+    }"#,
+            format!("{env}\n            .with_payload_tracer(self.payload_tracer)"),
+        )
+    } else {
+        ("", "", env)
+    };
+    let (limit_field, limit_setter, limit) = if config.generate_async_concurrency_limit {
+        (
+            "\n    async_limit: Option<(usize, usize)>,",
+            r#"
+
+    /// Limits the number of async exports that may be invoked concurrently
+    /// on the runtime. See `Runtime::with_async_concurrency_limit()`.
+    pub fn with_async_concurrency_limit(mut self, max_concurrent: usize, max_queued: usize) -> Self {
+        self.async_limit = Some((max_concurrent, max_queued));
+        self
+    }"#,
+            r#"
+        let runtime = match self.async_limit {
+            Some((max_concurrent, max_queued)) => {
+                runtime.with_async_concurrency_limit(max_concurrent, max_queued)
+            }
+            None => runtime,
+        };"#,
+        )
+    } else {
+        ("", "", "")
+    };
+    let (verifier_field, verifier_setter, verify) = if config.generate_signature_verification {
+        (
+            "\n    signature_verifier: Option<SignatureVerifier>,",
+            r#"
+
+    /// Sets the verifier with which the signature of the plugin is checked.
+    ///
+    /// Modules that are unsigned or whose signature is invalid are rejected
+    /// with `RuntimeError::InvalidSignature` before they are compiled. Modules
+    /// that are passed to `build_with_module()` or `build_from_serialized()`
+    /// have already been compiled, so their signature is not verified.
+    pub fn with_signature_verifier(mut self, verifier: SignatureVerifier) -> Self {
+        self.signature_verifier = Some(verifier);
+        self
+    }"#,
+            r#"
+        if let Some(verifier) = &self.signature_verifier {
+            verifier.verify(wasm_module.as_ref())?;
+        }"#,
+        )
+    } else {
+        ("", "", "")
+    };
+    let (init_field, init_setter, init_call) = format_init_builder_parts(init, types);
+
+    let module_cache_method = if config.generate_module_cache {
+        format_module_cache_builder_method(verify)
+    } else {
+        String::new()
+    };
+    format!(
+        r#"
+
+/// Builds a `Runtime` with custom settings.
+///
+/// By default, modules are compiled with Singlepass (or Cranelift on ARM).
+/// Hosts may provide their own store to use another compiler, such as LLVM,
+/// or a headless engine in combination with precompiled modules.
+#[derive(Default)]
+pub struct RuntimeBuilder {{
+    store: Option<Store>,
+    wasm_features: WasmFeatures,{capabilities_field}{std_imports_field}{http_imports_field}{context_field}{overrides_field}{output_handler_field}{payload_tracer_field}{limit_field}{verifier_field}{init_field}
+}}
+
+impl RuntimeBuilder {{
+    /// Returns a new builder with default settings.
+    pub fn new() -> Self {{
+        Self::default()
+    }}
+
+    /// Sets the store with which modules are compiled and deserialized.
+    pub fn with_store(mut self, store: Store) -> Self {{
+        self.store = Some(store);
+        self
+    }}
+
+    /// Sets the WebAssembly features that are enabled for the plugin.
+    ///
+    /// Modules that use a feature that is disabled are rejected with
+    /// `RuntimeError::DisabledWasmFeatures` before they are compiled. The
+    /// features are also enabled on the default store, while a store that is
+    /// set using `with_store()` keeps the features of its engine.
+    pub fn with_wasm_features(mut self, features: WasmFeatures) -> Self {{
+        self.wasm_features = features;
+        self
+    }}{capabilities_setter}{std_imports_setter}{http_imports_setter}{context_setter}{overrides_setters}{output_handler_setter}{payload_tracer_setter}{limit_setter}{verifier_setter}{init_setter}
+
+    /// Compiles the given module and instantiates a runtime for it.
+    pub fn build(self, wasm_module: impl AsRef<[u8]>) -> Result<Runtime, RuntimeError> {{{verify}
+        self.wasm_features.validate(wasm_module.as_ref())?;
+        let module = Module::new(&self.store(), wasm_module)?;
+        self.build_with_module(&module)
+    }}
+
+    /// Instantiates a runtime for a module that has already been compiled.
+    ///
+    /// The runtime uses the store of the module, rather than the store that
+    /// was set on the builder.
+    pub fn build_with_module(self, module: &Module) -> Result<Runtime, RuntimeError> {{
+        let runtime = Runtime::from_module(module, {env})?;{limit}{init_call}
+        Ok(runtime)
+    }}
+
+    /// Deserializes a module that was precompiled using `Module::serialize()`
+    /// and instantiates a runtime for it. This avoids compiling the module
+    /// when the runtime is created.
+    ///
+    /// # Safety
+    ///
+    /// The serialized module must have been produced by `Module::serialize()`
+    /// using a compatible version of Wasmer and an engine that matches the
+    /// store of this builder. See `Module::deserialize()` for details. The
+    /// module is not checked against the WebAssembly features of the builder.
+    pub unsafe fn build_from_serialized(
+        self,
+        serialized_module: &[u8],
+    ) -> Result<Runtime, RuntimeError> {{
+        let module = Module::deserialize(&self.store(), serialized_module)?;
+        self.build_with_module(&module)
+    }}{module_cache_method}
+
+    fn store(&self) -> Store {{
+        self.store
+            .clone()
+            .unwrap_or_else(|| Runtime::store_with_features(self.wasm_features))
+    }}
+}}"#
+    )
+}
+
+/// Formats the `RuntimePool` that keeps pre-instantiated runtimes around, so
+/// they can be checked out per call.
+fn format_runtime_pool(has_capabilities: bool) -> String {
+    let constructors = if has_capabilities {
+        r#"/// Compiles the given module and pre-instantiates `capacity` runtimes
+    /// for it, which do not grant any capabilities to the plugin.
+    pub fn with_capacity(wasm_module: impl AsRef<[u8]>, capacity: usize) -> Result<Self, RuntimeError> {
+        Self::with_capacity_and_capabilities(wasm_module, capacity, &[])
+    }
+
+    /// Compiles the given module and pre-instantiates `capacity` runtimes
+    /// for it, which grant the given capabilities to the plugin.
+    pub fn with_capacity_and_capabilities(
+        wasm_module: impl AsRef<[u8]>,
+        capacity: usize,
+        capabilities: &[Capability],
+    ) -> Result<Self, RuntimeError> {
+        let store = Runtime::default_store();
+        let module = Module::new(&store, wasm_module)?;
+        let env = RuntimeInstanceData::default()
+            .with_capabilities(capabilities.iter().map(Capability::as_str));"#
+    } else {
+        r#"/// Compiles the given module and pre-instantiates `capacity` runtimes
+    /// for it.
+    pub fn with_capacity(wasm_module: impl AsRef<[u8]>, capacity: usize) -> Result<Self, RuntimeError> {
+        let store = Runtime::default_store();
+        let module = Module::new(&store, wasm_module)?;
+        let env = RuntimeInstanceData::default();"#
+    };
+
+    format!(
+        r#"
+
+/// A pool of pre-instantiated runtimes for the same plugin module.
+///
+/// Runtimes are checked out from the pool using `get()`, and are returned to
+/// the pool when the `PooledRuntime` is dropped. Dynamic import handlers are
+/// shared by all runtimes in the pool.
+pub struct RuntimePool {{
+    module: Module,
+    env: RuntimeInstanceData,
+    capacity: usize,
+    runtimes: std::sync::Mutex<Vec<Runtime>>,
+}}
+
+impl RuntimePool {{
+    {constructors}
+        let runtimes = (0..capacity)
+            .map(|_| Runtime::from_module(&module, env.with_shared_dynamic_import_handlers()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {{
+            module,
+            env,
+            capacity,
+            runtimes: std::sync::Mutex::new(runtimes),
+        }})
+    }}
+
+    /// Checks out a runtime from the pool.
+    ///
+    /// If all pre-instantiated runtimes are in use, a new one is instantiated
+    /// on demand. Runtimes beyond the pool's capacity are discarded when they
+    /// are dropped.
+    pub fn get(&self) -> Result<PooledRuntime<'_>, RuntimeError> {{
+        let runtime = self.runtimes.lock().unwrap().pop();
+        let runtime = match runtime {{
+            Some(runtime) => runtime,
+            None => Runtime::from_module(&self.module, self.env.with_shared_dynamic_import_handlers())?,
+        }};
+        Ok(PooledRuntime {{
+            pool: self,
+            runtime: Some(runtime),
+        }})
+    }}
+}}
+
+/// A runtime that is checked out from a `RuntimePool`.
+pub struct PooledRuntime<'a> {{
+    pool: &'a RuntimePool,
+    runtime: Option<Runtime>,
+}}
+
+impl std::ops::Deref for PooledRuntime<'_> {{
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {{
+        self.runtime.as_ref().unwrap()
+    }}
+}}
+
+impl Drop for PooledRuntime<'_> {{
+    fn drop(&mut self) {{
+        if let Some(runtime) = self.runtime.take() {{
+            let mut runtimes = self.pool.runtimes.lock().unwrap();
+            if runtimes.len() < self.pool.capacity {{
+                runtimes.push(runtime);
+            }}
+        }}
+    }}
+}}"#
+    )
+}
+
+/// Formats the `SharedRuntime`, which holds a fixed number of instances so
+/// that it can be called from multiple threads at the same time.
+fn format_shared_runtime(
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    has_capabilities: bool,
+) -> String {
+    let constructors = if has_capabilities {
+        r#"/// Compiles the given module and creates `instances` instances of it,
+    /// which do not grant any capabilities to the plugin.
+    pub fn new(wasm_module: impl AsRef<[u8]>, instances: usize) -> Result<Self, RuntimeError> {
+        Self::with_capabilities(wasm_module, instances, &[])
+    }
+
+    /// Compiles the given module and creates `instances` instances of it,
+    /// which grant the given capabilities to the plugin.
+    pub fn with_capabilities(
+        wasm_module: impl AsRef<[u8]>,
+        instances: usize,
+        capabilities: &[Capability],
+    ) -> Result<Self, RuntimeError> {
+        let store = Runtime::default_store();
+        let module = Module::new(&store, wasm_module)?;
+        let env = RuntimeInstanceData::default()
+            .with_capabilities(capabilities.iter().map(Capability::as_str));"#
+    } else {
+        r#"/// Compiles the given module and creates `instances` instances of it.
+    pub fn new(wasm_module: impl AsRef<[u8]>, instances: usize) -> Result<Self, RuntimeError> {
+        let store = Runtime::default_store();
+        let module = Module::new(&store, wasm_module)?;
+        let env = RuntimeInstanceData::default();"#
+    };
+
+    let exports = export_functions
+        .iter()
+        .map(|function| {
+            let (doc, modifiers, name, args, raw_args, _, return_type, raw_return_type, ..) =
+                generate_import_function_variables(function, types);
+            let arg_names = function
+                .args
+                .iter()
+                .map(|arg| arg.name.as_ref())
+                .collect::<Vec<_>>()
+                .join(", ");
+            // Async exports wait for an instance without blocking the thread:
+            let (get, call) = if function.is_async {
+                ("get_async().await", ".await")
+            } else {
+                ("get()", "")
+            };
+            let cfg = format_cfg_attr(function, "");
+            format!(
+                r#"{doc}{cfg}pub {modifiers}fn {name}(&self{args}) -> Result<{return_type}, PluginError> {{
+    self.{get}.{name}({arg_names}){call}
+}}
+{cfg}pub {modifiers}fn {name}_raw(&self{raw_args}) -> Result<{raw_return_type}, InvocationError> {{
+    self.{get}.{name}_raw({arg_names}){call}
+}}"#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    format!(
+        r#"
+
+/// A runtime that can be shared between threads.
+///
+/// A plugin instance can only run one call at a time, so the shared runtime
+/// holds a fixed number of instances of the same module. Every call is run on
+/// an instance that is not in use. If all of them are busy, calls to regular
+/// exports block the calling thread until one becomes available, while calls
+/// to async exports wait for one without blocking the thread. Instances are
+/// handed out in the order in which they were requested, and async exports
+/// keep their instance until their result has been resolved.
+///
+/// Instances don't share their memory, so state that the plugin keeps between
+/// calls is not shared between the instances either. Dynamic import handlers
+/// are shared by all instances, and clones of the shared runtime use the same
+/// instances.
+#[derive(Clone)]
+pub struct SharedRuntime {{
+    inner: Arc<SharedRuntimeInner>,
+}}
+
+struct SharedRuntimeInner {{
+    idle: std::sync::Mutex<Vec<Runtime>>,
+    /// Grants one permit per idle instance, so that a permit holder always
+    /// finds an instance to check out.
+    permits: fp_bindgen_support::host::r#async::limit::ConcurrencyLimit,
+}}
+
+impl SharedRuntime {{
+    {constructors}
+        assert!(instances > 0, "A shared runtime needs at least one instance");
+        let runtimes = (0..instances)
+            .map(|_| Runtime::from_module(&module, env.with_shared_dynamic_import_handlers()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {{
+            inner: Arc::new(SharedRuntimeInner {{
+                idle: std::sync::Mutex::new(runtimes),
+                permits: fp_bindgen_support::host::r#async::limit::ConcurrencyLimit::new(instances, usize::MAX),
+            }}),
+        }})
+    }}
+
+    /// Checks out an instance that is not in use, which blocks the current
+    /// thread until one becomes available. Async code should use
+    /// `get_async()` instead.
+    ///
+    /// The instance is returned to the shared runtime when the
+    /// `SharedRuntimeGuard` is dropped. Calling the methods of the shared
+    /// runtime while holding on to a guard can therefore deadlock if there
+    /// are no other instances.
+    pub fn get(&self) -> SharedRuntimeGuard<'_> {{
+        let permit = self.inner.permits.acquire_blocking().unwrap();
+        self.check_out(permit)
+    }}
+
+    /// Checks out an instance that is not in use, once one becomes available.
+    ///
+    /// Unlike `get()`, this doesn't block the current thread while all
+    /// instances are busy.
+    pub async fn get_async(&self) -> SharedRuntimeGuard<'_> {{
+        let permit = self.inner.permits.acquire().unwrap().await;
+        self.check_out(permit)
+    }}
+
+    fn check_out(&self, permit: fp_bindgen_support::host::r#async::limit::ConcurrencyPermit) -> SharedRuntimeGuard<'_> {{
+        let runtime = self.inner.idle.lock().unwrap().pop();
+        SharedRuntimeGuard {{
+            shared: &self.inner,
+            runtime: Some(runtime.expect("A permit was granted without an idle instance")),
+            _permit: permit,
+        }}
+    }}
+
+    {exports}
+}}
+
+/// An instance that is checked out from a `SharedRuntime`.
+pub struct SharedRuntimeGuard<'a> {{
+    shared: &'a SharedRuntimeInner,
+    runtime: Option<Runtime>,
+    // Dropped after `drop()` has returned the instance, so that the next
+    // permit holder finds it idle:
+    _permit: fp_bindgen_support::host::r#async::limit::ConcurrencyPermit,
+}}
+
+impl std::ops::Deref for SharedRuntimeGuard<'_> {{
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {{
+        self.runtime.as_ref().unwrap()
+    }}
+}}
+
+impl Drop for SharedRuntimeGuard<'_> {{
+    fn drop(&mut self) {{
+        if let Some(runtime) = self.runtime.take() {{
+            self.shared.idle.lock().unwrap().push(runtime);
+        }}
+    }}
+}}
+
+// Fails to compile if the shared runtime cannot be shared between threads:
+const _: fn() = || {{
+    fn assert_send_sync<T: Send + Sync>() {{}}
+    assert_send_sync::<SharedRuntime>();
+}};"#
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn format_function_bindings(
+    imports: String,
+    exports: String,
+    new_func: String,
+    create_import_object_func: String,
+    protocol: String,
+    config: &RustWasmerExtendedRuntimeConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let trace_context_import = if config.generate_opentelemetry {
+        "\nuse fp_bindgen_support::host::trace_context::{export_span, import_span, trace_context, Instrument};"
+    } else {
+        ""
+    };
+    let std_imports_import = if config.generate_std_imports {
+        "\nuse fp_bindgen_support::host::std_imports::{std_monotonic_now, std_now, std_random_bytes, std_read_file, std_write_file, StdImports};"
+    } else {
+        ""
+    };
+    let http_imports_import = if config.generate_http_imports {
+        "\nuse fp_bindgen_support::host::http_imports::{http_request, HttpImports};"
+    } else {
+        ""
+    };
+    let deadline_import = if config.generate_deadlines {
+        "\nuse fp_bindgen_support::host::deadline::{deadline_remaining, enter_guest_deadline, export_deadline, WithDeadline};"
+    } else {
+        ""
+    };
+    let stdio_import = if config.generate_stdio_capture {
+        "\nuse fp_bindgen_support::{common::stdio::OutputStream, host::stdio::{host_print, OutputHandler}};"
+    } else {
+        ""
+    };
+    let routes_import = if config.generate_import_routes {
+        "\nuse fp_bindgen_support::host::routes::{check_route, RawExports, RouteError};"
+    } else {
+        ""
+    };
+    let overrides_import = if config.generate_import_overrides {
+        "\nuse fp_bindgen_support::host::overrides::{ImportOverrides, OverrideFuture};"
+    } else {
+        ""
+    };
+    let signatures_import = if config.generate_signature_verification {
+        "\nuse fp_bindgen_support::common::signatures::SignatureVerifier;"
+    } else {
+        ""
+    };
+    let payload_trace_import = if config.generate_payload_tracing {
+        "\nuse fp_bindgen_support::host::payload_trace::{trace_guest_payload, trace_payload, PayloadDirection, PayloadTracer};"
+    } else {
+        ""
+    };
+    let (resolved_exports_import, native_func_import, resolved_exports_field) =
+        if config.generate_eager_export_resolution {
+            (
+                "\nuse fp_bindgen_support::host::exports::resolve_export;",
+                " NativeFunc, WasmTypeList,",
+                "\n    exports: Arc<ResolvedExports>,",
+            )
+        } else {
+            ("", "", "")
+        };
+    let (limit_import, limit_field) = if config.generate_async_concurrency_limit {
+        (
+            "\nuse fp_bindgen_support::host::r#async::limit::ConcurrencyLimit;",
+            "\n    async_limit: Option<ConcurrencyLimit>,",
+        )
+    } else {
+        ("", "")
+    };
+    let full = rustfmt(format!(
+        r#"use super::types::*;
+use fp_bindgen_support::{{
+    common::{{mem::FatPtr, abi::WasmAbi, lazy::Lazy, wasm_features::WasmFeatures}},
+    host::{{
+        errors::{{guest_error, GuestError, InvocationError, PermissionDenied, RuntimeError}},
+        mem::{{export_to_guest, export_to_guest_raw, import_from_guest, import_from_guest_raw, deserialize_from_slice, serialize_to_vec, try_deserialize_from_slice, try_export_to_guest, try_export_to_guest_raw, try_import_from_guest_raw}},
+        r#async::{{create_future_value, future::ModuleRawFuture, resolve_async_value}},
+        lifecycle::ShutdownGuard,
+        runtime::RuntimeInstanceData,
+    }},
+}};{trace_context_import}{deadline_import}{std_imports_import}{http_imports_import}{stdio_import}{routes_import}{overrides_import}{signatures_import}{payload_trace_import}{resolved_exports_import}{limit_import}
+use std::{{cell::RefCell, sync::Arc}};
+use wasmer::{{imports, Function, ImportObject, Instance, Module,{native_func_import} Store, WasmerEnv}};
+
+#[derive(Clone)]
+pub struct Runtime {{
+    instance: Instance,
+    env: RuntimeInstanceData,{resolved_exports_field}{limit_field}
+    /// Calls the `shutdown` export of the plugin once the last clone of the
+    /// runtime is dropped.
+    _shutdown_guard: Arc<ShutdownGuard>,
+}}
+
+impl Runtime {{
+    {new_func}
 
-    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
     fn default_store() -> wasmer::Store {{
+        Self::store_with_features(WasmFeatures::default())
+    }}
+
+    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+    fn store_with_features(features: WasmFeatures) -> wasmer::Store {{
         let compiler = wasmer::Cranelift::default();
-        let engine = wasmer::Universal::new(compiler).engine();
+        let engine = wasmer::Universal::new(compiler).features(features.into()).engine();
         Store::new(&engine)
     }}
 
     #[cfg(not(any(target_arch = "arm", target_arch = "aarch64")))]
-    fn default_store() -> wasmer::Store {{
+    fn store_with_features(features: WasmFeatures) -> wasmer::Store {{
         let compiler = wasmer::Singlepass::default();
-        let engine = wasmer::Universal::new(compiler).engine();
+        let engine = wasmer::Universal::new(compiler).features(features.into()).engine();
         Store::new(&engine)
     }}
 
+    /// Returns statistics about the memory that is allocated by the plugin.
+    ///
+    /// This is only supported for plugins that are built with the
+    /// `memory-stats` feature of `fp-bindgen-support`. For other plugins, an
+    /// `InvocationError::FunctionNotExported` error is returned.
+    pub fn memory_stats(&self) -> Result<fp_bindgen_support::common::mem::MemoryStats, InvocationError> {{
+        let function = self
+            .instance
+            .exports
+            .get_native_function::<(), FatPtr>("__fp_memory_stats")
+            .map_err(|error| InvocationError::from_export_error("__fp_memory_stats", error))?;
+        let result = function.call()?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        Ok(deserialize_from_slice(&result))
+    }}
+
+    /// Returns metrics about the calls that the plugin has made to imports,
+    /// as measured by the plugin itself.
+    ///
+    /// This is only supported for plugins with bindings that are generated
+    /// with `guest_metrics` enabled. For other plugins, an
+    /// `InvocationError::FunctionNotExported` error is returned.
+    pub fn guest_metrics(&self) -> Result<fp_bindgen_support::common::mem::GuestMetrics, InvocationError> {{
+        let function = self
+            .instance
+            .exports
+            .get_native_function::<(), FatPtr>("__fp_get_guest_metrics")
+            .map_err(|error| InvocationError::from_export_error("__fp_get_guest_metrics", error))?;
+        let result = function.call()?;
+        let result = try_import_from_guest_raw(&self.env, result)?;
+        Ok(deserialize_from_slice(&result))
+    }}
+
+    /// Returns a machine-readable description of the protocol that this
+    /// runtime was generated for, as JSON.
+    ///
+    /// The description contains the signatures of all imported and exported
+    /// functions and the types they use, in the format that is parsed by
+    /// `fp_bindgen::Protocol::from_json()`. Note that plugins may omit
+    /// exports, in which case calling them fails with
+    /// `InvocationError::FunctionNotExported`.
+    pub fn protocol() -> &'static str {{
+        PROTOCOL
+    }}
+
     {exports}
 }}
 
 {create_import_object_func}
 
 {imports}
-"#))
-    .unwrap();
-    write_bindings_file(format!("{path}/bindings.rs"), full);
+
+const PROTOCOL: &str = {protocol:?};
+"#
+    ));
+    write_bindings_file(format!("{path}/bindings.rs"), full, headers);
 }
 
-pub(crate) fn write_bindings_file<C>(file_path: String, contents: C)
-where
-    C: AsRef<[u8]>,
-{
-    fs::write(file_path, &contents).expect("Could not write bindings file");
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_context_is_passed_to_imports() {
+        let mut import_functions = FunctionList::new();
+        import_functions.add_function("fn count_users(active: bool) -> u32;");
+        import_functions.add_function("async fn fetch_user(id: u32) -> u32;");
+        let types = TypeMap::new();
+
+        let wrappers = import_functions
+            .iter()
+            .map(|function| {
+                format_export_function(function, &types, false, false, true, false, false, None)
+            })
+            .collect::<Vec<_>>();
+        assert!(wrappers[0].contains("let host_context = get_host_context(env);"));
+        assert!(wrappers[0].contains("super::count_users(&host_context, active)"));
+        assert!(wrappers[1].contains("super::fetch_user(host_context, id)"));
+
+        let wrapper = format_export_function(
+            import_functions.iter().next().unwrap(),
+            &types,
+            false,
+            false,
+            false,
+            false,
+            false,
+            None,
+        );
+        assert!(!wrapper.contains("host_context"));
+        assert!(wrapper.contains("super::count_users(active)"));
+    }
+
+    #[test]
+    fn test_imports_can_be_overridden() {
+        let mut import_functions = FunctionList::new();
+        import_functions.add_function("fn count_users(active: bool) -> u32;");
+        import_functions.add_function("async fn fetch_user(id: u32) -> u32;");
+        let types = TypeMap::new();
+        let host_context = Some("crate::HostContext");
+
+        let override_types = import_functions
+            .iter()
+            .map(|function| format_override_type(function, &types, host_context))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            override_types[0],
+            "dyn Fn(&crate::HostContext, bool) -> u32 + Send + Sync"
+        );
+        assert_eq!(
+            override_types[1],
+            "dyn Fn(Arc<crate::HostContext>, u32) -> OverrideFuture<u32> + Send + Sync"
+        );
+
+        let wrappers = import_functions
+            .iter()
+            .zip(&override_types)
+            .map(|(function, override_type)| {
+                format_export_function(
+                    function,
+                    &types,
+                    false,
+                    false,
+                    true,
+                    false,
+                    false,
+                    Some(override_type),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert!(wrappers[0].contains("env.import_override::<dyn Fn(&crate::HostContext, bool) -> u32 + Send + Sync>(\"count_users\")"));
+        assert!(wrappers[0].contains("None => super::count_users(&host_context, active)"));
+        assert!(wrappers[1].contains("None => Box::pin(super::fetch_user(host_context, id))"));
+    }
 }
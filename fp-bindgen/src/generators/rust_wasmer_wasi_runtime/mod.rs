@@ -1,42 +1,74 @@
 use crate::{
     functions::{Function, FunctionList},
     generators::{
-        rust_plugin::generate_type_bindings,
+        interning::{has_interned_fields, INTERN_STRING_IMPORT},
+        lifecycle::get_init_export,
+        rust_plugin::{format_cfg_attr, generate_type_bindings},
         rust_wasmer_runtime::{
-            format_export_function, format_function_bindings, generate_import_function_variables,
+            format_batch_dispatcher, format_capability_enum, format_dynamic_import_registration,
+            format_event_subscription, format_export_function, format_function_bindings,
+            format_new_function, format_plugin_error, format_runtime_builder,
+            generate_import_function_variables, has_batchable_imports, BatchDispatcherRuntime,
         },
+        schema_hash::SchemaHashes,
     },
+    protocol::protocol_to_json,
     types::TypeMap,
+    FileHeaders, RustWasmerExtendedRuntimeConfig,
 };
-use std::fs;
+use inflector::Inflector;
+use std::{collections::BTreeMap, fs};
 
 pub(crate) fn generate_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: TypeMap,
+    schema_hashes: &SchemaHashes,
+    headers: &FileHeaders,
     path: &str,
 ) {
     fs::create_dir_all(path).expect("Could not create output directory");
 
-    // We use the same type generation as for the Rust plugin, only with the
-    // serializable and deserializable types inverted:
-    generate_type_bindings(&types, path);
+    // We use the same type generation as for the Rust plugin, so that all the
+    // serde attributes that determine the wire format (such as the enum
+    // representation) are identical on both sides:
+    generate_type_bindings(&types, schema_hashes, headers, path);
 
-    generate_function_bindings(import_functions, export_functions, &types, path);
+    // The host implements generic imports for the concrete types it expects,
+    // which are inferred when the arguments are deserialized:
+    let import_functions = import_functions.with_generic_params_replaced("_");
+    generate_function_bindings(import_functions, export_functions, &types, headers, path);
 }
 
-fn generate_create_import_object_func(import_functions: &FunctionList) -> String {
+fn generate_create_import_object_func(import_functions: &FunctionList, types: &TypeMap) -> String {
     let imports = import_functions
         .iter()
         .map(|function| {
             let name = &function.name;
+            let symbol = function.symbol_name();
+            let cfg = format_cfg_attr(function, "    ");
             format!(
-                r#"namespace.insert(
-            "__fp_gen_{name}",
+                r#"{cfg}namespace.insert(
+            "{symbol}",
             Function::new_native_with_env(store, env.clone(), _{name})
     );"#
             )
         })
+        .chain(has_batchable_imports(import_functions).then(|| {
+            r#"namespace.insert(
+            "__fp_host_batch",
+            Function::new_native_with_env(store, env.clone(), __fp_host_batch)
+    );"#
+            .to_owned()
+        }))
+        .chain(has_interned_fields(types).then(|| {
+            format!(
+                r#"namespace.insert(
+            "{INTERN_STRING_IMPORT}",
+            Function::new_native_with_env(store, env.clone(), fp_bindgen_support::host::interning::intern_string)
+    );"#
+            )
+        }))
         .collect::<Vec<_>>()
         .join("\n    ");
 
@@ -47,6 +79,10 @@ fn generate_create_import_object_func(import_functions: &FunctionList) -> String
             "__fp_host_resolve_async_value",
             Function::new_native_with_env(store, env.clone(), resolve_async_value)
     );
+    namespace.insert(
+            "__fp_error",
+            Function::new_native_with_env(store, env.clone(), guest_error)
+    );
     {imports}
     namespace
 }}"#
@@ -72,17 +108,20 @@ fn format_import_function(function: &Function, types: &TypeMap) -> String {
         return_wrapper,
     ) = generate_import_function_variables(function, types);
 
+    let cfg = format_cfg_attr(function, "");
+    let variant = name.to_pascal_case();
+    let symbol = function.symbol_name();
     format!(
-        r#"{doc}pub {modifiers}fn {name}(&self{args}) -> Result<{return_type}, InvocationError> {{
+        r#"{doc}{cfg}pub {modifiers}fn {name}(&self{args}) -> Result<{return_type}, PluginError> {{
     {serialize_args}
     let result = self.{name}_raw({arg_names});
-    {return_wrapper}result
+    {return_wrapper}result.map_err(|error| PluginError::new(PluginExport::{variant}, error))
 }}
-pub {modifiers}fn {name}_raw(&self{raw_args}) -> Result<{raw_return_type}, InvocationError> {{
+{cfg}pub {modifiers}fn {name}_raw(&self{raw_args}) -> Result<{raw_return_type}, InvocationError> {{
     {serialize_raw_args}let function = self.instance
         .exports
-        .get_native_function::<{wasm_args}, {wasm_return_type}>("__fp_gen_{name}")
-        .map_err(|_| InvocationError::FunctionNotExported("__fp_gen_{name}".to_owned()))?;
+        .get_native_function::<{wasm_args}, {wasm_return_type}>("{symbol}")
+        .map_err(|error| InvocationError::from_export_error("{symbol}", error))?;
     let result = function.call({wasm_arg_names})?;
     {raw_return_wrapper}Ok(result)
 }}"#
@@ -93,31 +132,90 @@ fn generate_function_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: &TypeMap,
+    headers: &FileHeaders,
     path: &str,
 ) {
     let imports = import_functions
         .iter()
-        .map(|function| format_export_function(function, types))
+        .map(|function| {
+            format_cfg_attr(function, "")
+                + &format_export_function(function, types, false, false, false, false, false, None)
+        })
+        .chain(format_batch_dispatcher(
+            &import_functions,
+            types,
+            BatchDispatcherRuntime::Wasmer,
+            false,
+            false,
+            &BTreeMap::new(),
+        ))
         .collect::<Vec<_>>()
         .join("\n\n");
     let exports = export_functions
         .iter()
         .map(|function| format_import_function(function, types))
+        .chain(
+            import_functions
+                .iter()
+                .filter(|function| function.attrs.dynamic)
+                .map(|function| format_dynamic_import_registration(function, types)),
+        )
+        .chain(
+            import_functions
+                .iter()
+                .filter(|function| function.attrs.event)
+                .map(|function| format_event_subscription(function, types)),
+        )
         .collect::<Vec<_>>()
         .join("\n\n");
-    let new_func = r#"pub fn new(wasm_module: impl AsRef<[u8]>) -> Result<Self, RuntimeError> {
-        let store = Self::default_store();
-        let module = Module::new(&store, wasm_module)?;
-        let mut env = RuntimeInstanceData::default();
+    let capabilities = import_functions.capabilities();
+    let new_func = format_new_function(
+        "let store = Self::default_store();\n        let module = Module::new(&store, wasm_module)?;",
+        !capabilities.is_empty(),
+    );
+    let new_func = format!(
+        r#"{new_func}
+
+    fn from_module(module: &Module, mut env: RuntimeInstanceData) -> Result<Self, RuntimeError> {{
         let mut wasi_env = wasmer_wasi::WasiState::new("fp").finalize().unwrap();
-        let mut import_object = wasi_env.import_object(&module).unwrap();
+        let mut import_object = wasi_env.import_object(module).unwrap();
         let namespace = create_import_object(module.store(), &env);
         import_object.register("fp", namespace);
-        let instance = Instance::new(&module, &import_object).unwrap();
+        let instance = Instance::new(module, &import_object)?;
         env.init_with_instance(&instance).unwrap();
-        Ok(Self { instance, env })
-    }"#
-    .to_string();
-    let create_import_object_func = generate_create_import_object_func(&import_functions);
-    format_function_bindings(imports, exports, new_func, create_import_object_func, path);
+        env.negotiate_async_protocol();
+        let shutdown_guard = Arc::new(ShutdownGuard::new(instance.clone()));
+        Ok(Self {{ instance, env, _shutdown_guard: shutdown_guard }})
+    }}"#
+    );
+    let capability_enum = if capabilities.is_empty() {
+        String::new()
+    } else {
+        format_capability_enum(&capabilities)
+    };
+    let create_import_object_func = generate_create_import_object_func(&import_functions, types);
+    let runtime_builder = format_runtime_builder(
+        !capabilities.is_empty(),
+        &RustWasmerExtendedRuntimeConfig::default(),
+        get_init_export(&export_functions),
+        &[],
+        types,
+    );
+    format_function_bindings(
+        imports,
+        exports,
+        new_func,
+        create_import_object_func
+            + &runtime_builder
+            + &capability_enum
+            + &format_plugin_error(
+                &export_functions,
+                "WasmerRuntimeError",
+                "wasmer::RuntimeError",
+            ),
+        protocol_to_json(&import_functions, &export_functions, types),
+        &RustWasmerExtendedRuntimeConfig::default(),
+        headers,
+        path,
+    );
 }
@@ -0,0 +1,788 @@
+use crate::{
+    functions::{Function, FunctionArg, FunctionList},
+    generators::{
+        formatting::rustfmt,
+        interning::{has_interned_fields, INTERN_STRING_IMPORT},
+        lifecycle::get_init_export,
+        rust_plugin::{format_cfg_attr, format_ident, generate_type_bindings},
+        rust_wasmer_runtime::{
+            format_batch_dispatcher, format_capability_enum, format_init_builder_parts,
+            format_new_function, format_plugin_error, format_raw_ident,
+            generate_import_function_variables, has_batchable_imports, returns_serialized_value,
+            BatchDispatcherRuntime,
+        },
+        schema_hash::SchemaHashes,
+        write_bindings_file,
+    },
+    primitives::Primitive,
+    protocol::protocol_to_json,
+    types::{TypeIdent, TypeMap},
+    FileHeaders,
+};
+use inflector::Inflector;
+use std::{collections::BTreeMap, fs};
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    schema_hashes: &SchemaHashes,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    fs::create_dir_all(path).expect("Could not create output directory");
+
+    // We use the same type generation as for the Rust plugin, so that all the
+    // serde attributes that determine the wire format (such as the enum
+    // representation) are identical on both sides:
+    generate_type_bindings(&types, schema_hashes, headers, path);
+
+    // The host implements generic imports for the concrete types it expects,
+    // which are inferred when the arguments are deserialized:
+    let import_functions = import_functions.with_generic_params_replaced("_");
+    generate_function_bindings(import_functions, export_functions, &types, headers, path);
+}
+
+fn generate_create_linker_func(import_functions: &FunctionList, types: &TypeMap) -> String {
+    let imports =
+        import_functions
+            .iter()
+            .map(|function| {
+                let name = &function.name;
+                let symbol = function.symbol_name();
+                let cfg = format_cfg_attr(function, "    ");
+                format!("{cfg}linker.func_wrap(\"fp\", \"{symbol}\", _{name})?;")
+            })
+            .chain(has_batchable_imports(import_functions).then(|| {
+                "linker.func_wrap(\"fp\", \"__fp_host_batch\", __fp_host_batch)?;".to_owned()
+            }))
+            .chain(has_interned_fields(types).then(|| {
+                format!("linker.func_wrap(\"fp\", \"{INTERN_STRING_IMPORT}\", fp_bindgen_support::wasmi_host::interning::intern_string)?;")
+            }))
+            .collect::<Vec<_>>()
+            .join("\n    ");
+
+    format!(
+        r#"fn create_linker(engine: &Engine) -> Result<Linker<RuntimeInstanceData>, RuntimeError> {{
+    let mut linker = Linker::new(engine);
+    linker.func_wrap("fp", "__fp_host_resolve_async_value", resolve_async_value)?;
+    linker.func_wrap("fp", "__fp_error", guest_error)?;
+    {imports}
+    Ok(linker)
+}}"#
+    )
+}
+
+/// Wasmi only supports the value types of WebAssembly itself, so smaller
+/// integers are passed as 32-bit integers and floats use Wasmi's own types.
+fn format_wasmi_ident(ty: &TypeIdent) -> String {
+    match ty.as_primitive() {
+        Some(Primitive::Bool | Primitive::U8 | Primitive::U16 | Primitive::U32) => "u32",
+        Some(Primitive::I8 | Primitive::I16 | Primitive::I32) => "i32",
+        Some(Primitive::U64) => "u64",
+        Some(Primitive::I64) => "i64",
+        Some(Primitive::F32) => "F32",
+        Some(Primitive::F64) => "F64",
+        None => "FatPtr",
+    }
+    .to_owned()
+}
+
+fn format_wasmi_return_ident(ty: &TypeIdent) -> String {
+    if ty.is_packed_pair() {
+        format!("<{} as WasmAbi>::AbiType", ty.name)
+    } else {
+        format_wasmi_ident(ty)
+    }
+}
+
+/// Formats the conversion of a primitive value to its Wasmi representation.
+fn format_to_wasmi(value: &str, ty: &TypeIdent) -> String {
+    match ty.as_primitive() {
+        Some(Primitive::Bool | Primitive::U8 | Primitive::U16) => format!("u32::from({value})"),
+        Some(Primitive::I8 | Primitive::I16) => format!("i32::from({value})"),
+        Some(Primitive::F32) => format!("F32::from({value})"),
+        Some(Primitive::F64) => format!("F64::from({value})"),
+        _ => value.to_owned(),
+    }
+}
+
+/// Formats the conversion of a primitive value from its Wasmi representation,
+/// or returns `None` if no conversion is necessary.
+fn format_from_wasmi(value: &str, ty: &TypeIdent) -> Option<String> {
+    match ty.as_primitive() {
+        Some(Primitive::Bool) => Some(format!("{value} != 0")),
+        Some(primitive @ (Primitive::U8 | Primitive::U16 | Primitive::I8 | Primitive::I16)) => {
+            Some(format!("{value} as {}", primitive.name()))
+        }
+        Some(primitive @ (Primitive::F32 | Primitive::F64)) => {
+            Some(format!("{}::from({value})", primitive.name()))
+        }
+        _ => None,
+    }
+}
+
+/// Formats a list of Wasmi params or values, which are passed as a tuple if
+/// there is more than one.
+fn format_wasmi_list(items: Vec<String>) -> String {
+    if items.len() == 1 {
+        let mut items = items;
+        items.remove(0)
+    } else {
+        format!("({})", items.join(", "))
+    }
+}
+
+fn format_import_function(function: &Function, types: &TypeMap) -> String {
+    let (
+        doc,
+        modifiers,
+        name,
+        args,
+        raw_args,
+        _,
+        return_type,
+        _,
+        _,
+        serialize_args,
+        _,
+        arg_names,
+        _,
+        _,
+        return_wrapper,
+    ) = generate_import_function_variables(function, types);
+
+    let serialize_raw_args = function
+        .args
+        .iter()
+        .filter(|arg| !arg.ty.is_primitive())
+        .map(|FunctionArg { name, .. }| {
+            format!("let {name} = try_export_to_guest_raw(&mut *store, {name})?;\n")
+        })
+        .collect::<Vec<_>>()
+        .join("");
+    let wasmi_args = format_wasmi_list(
+        function
+            .args
+            .iter()
+            .map(|arg| format_wasmi_ident(&arg.ty))
+            .collect(),
+    );
+    let wasmi_arg_values = format_wasmi_list(
+        function
+            .args
+            .iter()
+            .map(|arg| format_to_wasmi(&arg.name, &arg.ty))
+            .collect(),
+    );
+    let symbol = function.symbol_name();
+    let call = format!(
+        r#"let function = self
+        .instance
+        .get_typed_func::<{wasmi_args}, {}>(&*store, "{symbol}")
+        .map_err(|_| InvocationError::from_missing_export(&self.instance, &*store, "{symbol}"))?;
+    let result = function.call(&mut *store, {wasmi_arg_values})?;"#,
+        match &function.return_type {
+            _ if function.is_async => "FatPtr".to_owned(),
+            Some(ty) => format_wasmi_return_ident(ty),
+            None => "()".to_owned(),
+        }
+    );
+
+    let raw = if function.is_async {
+        // The store is only borrowed while the plugin is called, because the
+        // async value is resolved while other calls may be made:
+        format!(
+            r#"pub async fn {name}_raw(&self{raw_args}) -> Result<Vec<u8>, InvocationError> {{
+    let result = {{
+        let mut store = self.store.borrow_mut();
+        {serialize_raw_args}{call}
+        result
+    }};
+    poll_fn(|cx| poll_async_value(&mut *self.store.borrow_mut(), result, cx)).await
+}}"#
+        )
+    } else {
+        let (raw_return_type, raw_return_wrapper) = match &function.return_type {
+            None => ("()".to_owned(), String::new()),
+            Some(ty) if ty.is_packed_pair() => (
+                format_ident(ty, types),
+                "let result = WasmAbi::from_abi(result);\n    ".to_owned(),
+            ),
+            Some(ty) if ty.is_primitive() => (
+                format_raw_ident(ty, types),
+                format_from_wasmi("result", ty)
+                    .map(|result| format!("let result = {result};\n    "))
+                    .unwrap_or_default(),
+            ),
+            Some(ty) => (
+                format_raw_ident(ty, types),
+                "let result = try_import_from_guest_raw(&mut *store, result)?;\n    ".to_owned(),
+            ),
+        };
+        format!(
+            r#"pub fn {name}_raw(&self{raw_args}) -> Result<{raw_return_type}, InvocationError> {{
+    let mut store = self.store.borrow_mut();
+    {serialize_raw_args}{call}
+    {raw_return_wrapper}Ok(result)
+}}"#
+        )
+    };
+
+    let cfg = format_cfg_attr(function, "");
+    let variant = name.to_pascal_case();
+    format!(
+        r#"{doc}{cfg}pub {modifiers}fn {name}(&self{args}) -> Result<{return_type}, PluginError> {{
+    {serialize_args}
+    let result = self.{name}_raw({arg_names});
+    {return_wrapper}result.map_err(|error| PluginError::new(PluginExport::{variant}, error))
+}}
+{cfg}{raw}"#
+    )
+}
+
+fn format_import_arg(name: &str, ty: &TypeIdent, types: &TypeMap) -> Option<String> {
+    if ty.is_primitive() {
+        format_from_wasmi(name, ty).map(|value| format!("let {name} = {value};"))
+    } else {
+        let ty = format_ident(ty, types);
+        Some(format!(
+            "let {name} = import_from_guest::<{ty}>(&mut caller, {name});"
+        ))
+    }
+}
+
+fn format_export_function(function: &Function, types: &TypeMap) -> String {
+    if function.attrs.dynamic {
+        return format_dynamic_export_function(function, types);
+    }
+    if function.attrs.event {
+        return format_event_export_function(function);
+    }
+
+    let name = &function.name;
+    let wasmi_args = function
+        .args
+        .iter()
+        .map(|FunctionArg { name, ty }| format!(", {name}: {}", format_wasmi_ident(ty)))
+        .collect::<Vec<_>>()
+        .join("");
+
+    let wrapper_return_type = if function.is_fire_and_forget() {
+        "".to_owned()
+    } else if function.is_async {
+        " -> FatPtr".to_owned()
+    } else {
+        match &function.return_type {
+            Some(ty) => format!(" -> {}", format_wasmi_return_ident(ty)),
+            None => "".to_owned(),
+        }
+    };
+
+    let import_args = function
+        .args
+        .iter()
+        .filter_map(|arg| format_import_arg(&arg.name, &arg.ty, types))
+        .map(|arg| format!("{arg}\n    "))
+        .collect::<Vec<_>>()
+        .join("");
+
+    let arg_names = function
+        .args
+        .iter()
+        .map(|arg| arg.name.as_ref())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let (return_statements, return_value) = if function.is_fire_and_forget() {
+        // There is no result to pass back, so the plugin doesn't wait for one:
+        (
+            "spawn_import_without_result(&mut caller, result);".to_owned(),
+            "".to_owned(),
+        )
+    } else if function.is_async {
+        (
+            "".to_owned(),
+            "spawn_import(&mut caller, result)".to_owned(),
+        )
+    } else {
+        match &function.return_type {
+            None => ("".to_owned(), "".to_owned()),
+            Some(ty) if ty.is_packed_pair() => ("".to_owned(), "result.to_abi()".to_owned()),
+            Some(ty) if ty.is_primitive() => ("".to_owned(), format_to_wasmi("result", ty)),
+            _ => (
+                "".to_owned(),
+                "try_export_to_guest(&mut caller, &result)?".to_owned(),
+            ),
+        }
+    };
+    // Values that are passed back to the plugin fail the call if the plugin
+    // cannot allocate them:
+    let allocates_result = !function.is_async && returns_serialized_value(function);
+
+    let uses_memory = function.is_async
+        || function.args.iter().any(|arg| !arg.ty.is_primitive())
+        || function
+            .return_type
+            .as_ref()
+            .map(|ty| !ty.is_primitive() && !ty.is_packed_pair())
+            .unwrap_or_default();
+    let caller = if uses_memory {
+        "mut caller"
+    } else if function.attrs.capability.is_some() {
+        "caller"
+    } else {
+        "_caller"
+    };
+
+    let capability_check = format_capability_check(function);
+    let (wrapper_return_type, return_value) =
+        if function.attrs.capability.is_some() || allocates_result {
+            (
+                format!(
+                    " -> Result<{}, Trap>",
+                    wrapper_return_type.strip_prefix(" -> ").unwrap_or("()")
+                ),
+                format!(
+                    "Ok({})",
+                    if return_value.is_empty() {
+                        "()"
+                    } else {
+                        &return_value
+                    }
+                ),
+            )
+        } else {
+            (wrapper_return_type, return_value)
+        };
+
+    format!(
+        r#"fn _{name}({caller}: Caller<'_, RuntimeInstanceData>{wasmi_args}){wrapper_return_type} {{
+    {capability_check}{import_args}let result = super::{name}({arg_names});
+    {return_statements}{return_value}
+}}"#
+    )
+}
+
+/// Formats the statement that checks whether the capability that is required
+/// by an import was granted to the plugin, if it requires one.
+fn format_capability_check(function: &Function) -> String {
+    match &function.attrs.capability {
+        Some(capability) => format!(
+            "caller.data().check_capability(\"{}\", \"{capability}\")?;\n    ",
+            function.name
+        ),
+        None => String::new(),
+    }
+}
+
+fn format_dynamic_export_function(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let (handler_name, payload) = match function.args.as_slice() {
+        [handler_name, payload] => (&handler_name.name, payload),
+        _ => unreachable!("Dynamic imports are validated to take two arguments"),
+    };
+    let payload_name = &payload.name;
+    let payload_ty = format_ident(&payload.ty, types);
+
+    let capability_check = format_capability_check(function);
+    let (return_type, wrap_ok): (_, fn(String) -> String) = if function.attrs.capability.is_some() {
+        ("Result<FatPtr, Trap>", |value| format!("Ok({value})"))
+    } else {
+        ("FatPtr", |value| value)
+    };
+    let handler_result = wrap_ok(format!(
+        "export_to_guest_raw(&mut caller, handler(&{payload_name}))"
+    ));
+    let result = wrap_ok("export_to_guest(&mut caller, &result)".to_owned());
+
+    format!(
+        r#"fn _{name}(mut caller: Caller<'_, RuntimeInstanceData>, {handler_name}: FatPtr, {payload_name}: FatPtr) -> {return_type} {{
+    {capability_check}let {handler_name} = import_from_guest::<String>(&mut caller, {handler_name});
+    if let Some(handler) = caller.data().dynamic_import_handler("{name}", &{handler_name}) {{
+        let {payload_name} = import_from_guest_raw(&mut caller, {payload_name});
+        return {handler_result};
+    }}
+    let {payload_name} = import_from_guest::<{payload_ty}>(&mut caller, {payload_name});
+    let result = super::{name}({handler_name}, {payload_name});
+    {result}
+}}"#
+    )
+}
+
+/// Formats the import through which the plugin emits an event. The event is
+/// passed on to the subscribers as-is, without deserializing it first.
+fn format_event_export_function(function: &Function) -> String {
+    let name = &function.name;
+    let payload_name = match function.args.as_slice() {
+        [payload] => &payload.name,
+        _ => unreachable!("Events are validated to take one argument"),
+    };
+
+    format!(
+        r#"fn _{name}(mut caller: Caller<'_, RuntimeInstanceData>, {payload_name}: FatPtr) {{
+    let {payload_name} = import_from_guest_raw(&mut caller, {payload_name});
+    caller.data_mut().emit_event("{name}", &{payload_name});
+}}"#
+    )
+}
+
+/// Formats the `Runtime` methods for registering and unregistering handlers
+/// for a dynamic import.
+fn format_dynamic_import_registration(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let payload_ty = match function.args.as_slice() {
+        [_, payload] => format_ident(&payload.ty, types),
+        _ => unreachable!("Dynamic imports are validated to take two arguments"),
+    };
+    let return_type = function
+        .return_type
+        .as_ref()
+        .map(|ty| format_ident(ty, types))
+        .expect("Dynamic imports are validated to have a return type");
+
+    format!(
+        r#"/// Registers a handler for the dynamic import `{name}`.
+///
+/// Calls from the plugin with the given name are dispatched to this handler
+/// instead of the static `{name}()` implementation. Registering a handler
+/// with a name that is already in use replaces the existing handler.
+pub fn register_{name}(&self, name: impl Into<String>, handler: impl Fn({payload_ty}) -> {return_type} + 'static) {{
+    self.store.borrow_mut().data_mut().register_dynamic_import_handler(
+        "{name}",
+        name.into(),
+        std::rc::Rc::new(move |payload: &[u8]| serialize_to_vec(&handler(deserialize_from_slice(payload)))),
+    );
+}}
+
+/// Unregisters a handler for the dynamic import `{name}`.
+///
+/// Returns whether a handler was registered under the given name.
+pub fn unregister_{name}(&self, name: &str) -> bool {{
+    self.store
+        .borrow_mut()
+        .data_mut()
+        .unregister_dynamic_import_handler("{name}", name)
+}}"#
+    )
+}
+
+/// Formats the `Runtime` method for subscribing to an event.
+fn format_event_subscription(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let payload_ty = match function.args.as_slice() {
+        [payload] => format_ident(&payload.ty, types),
+        _ => unreachable!("Events are validated to take one argument"),
+    };
+
+    format!(
+        r#"/// Subscribes to the `{name}` event.
+///
+/// Every event that the plugin emits after subscribing is sent to the
+/// returned receiver. Dropping the receiver ends the subscription.
+pub fn subscribe_{name}(&self) -> std::sync::mpsc::Receiver<{payload_ty}> {{
+    let (sender, receiver) = std::sync::mpsc::channel();
+    self.store.borrow_mut().data_mut().subscribe_to_event(
+        "{name}",
+        Box::new(move |event: &[u8]| sender.send(deserialize_from_slice(event)).is_ok()),
+    );
+    receiver
+}}"#
+    )
+}
+
+/// Formats the `Runtime` method that drives the async imports which were
+/// called by the plugin, for protocols that have any.
+fn format_run_pending_imports(import_functions: &FunctionList) -> Option<String> {
+    import_functions
+        .iter()
+        .any(|function| function.is_async)
+        .then(|| {
+            r#"/// Drives the async imports that were called by the plugin until all of
+/// them are done.
+///
+/// Async imports are also driven while an async export is awaited, but
+/// imports that are fire-and-forget may outlive the export that called them.
+pub async fn run_pending_imports(&self) {
+    poll_fn(|cx| poll_pending_imports(&mut *self.store.borrow_mut(), cx)).await
+}"#
+            .to_owned()
+        })
+}
+
+fn generate_function_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: &TypeMap,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let imports = import_functions
+        .iter()
+        .map(|function| format_cfg_attr(function, "") + &format_export_function(function, types))
+        .chain(format_batch_dispatcher(
+            &import_functions,
+            types,
+            BatchDispatcherRuntime::Wasmi,
+            false,
+            false,
+            &BTreeMap::new(),
+        ))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let exports = export_functions
+        .iter()
+        .map(|function| format_import_function(function, types))
+        .chain(
+            import_functions
+                .iter()
+                .filter(|function| function.attrs.dynamic)
+                .map(|function| format_dynamic_import_registration(function, types)),
+        )
+        .chain(
+            import_functions
+                .iter()
+                .filter(|function| function.attrs.event)
+                .map(|function| format_event_subscription(function, types)),
+        )
+        .chain(format_run_pending_imports(&import_functions))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let capabilities = import_functions.capabilities();
+    let new_func = format_new_function(
+        "let engine = Engine::default();\n        let module = Module::new(&engine, wasm_module.as_ref())?;",
+        !capabilities.is_empty(),
+    );
+    let new_func = format!(
+        r#"{new_func}
+
+    fn from_module(module: &Module, env: RuntimeInstanceData) -> Result<Self, RuntimeError> {{
+        let mut store = Store::new(module.engine(), env);
+        let linker = create_linker(module.engine())?;
+        let instance = linker.instantiate(&mut store, module)?.start(&mut store)?;
+        RuntimeInstanceData::initialize(&mut store, &instance)?;
+        Ok(Self {{
+            instance,
+            store: RefCell::new(store),
+        }})
+    }}"#
+    );
+    let capability_enum = if capabilities.is_empty() {
+        String::new()
+    } else {
+        format_capability_enum(&capabilities)
+    };
+    let create_linker_func = generate_create_linker_func(&import_functions, types);
+    let runtime_builder = format_runtime_builder(
+        !capabilities.is_empty(),
+        get_init_export(&export_functions),
+        types,
+    );
+    format_function_bindings(
+        imports,
+        exports,
+        new_func,
+        create_linker_func
+            + &runtime_builder
+            + &capability_enum
+            + &format_plugin_error(&export_functions, "WasmiTrap", "Trap"),
+        protocol_to_json(&import_functions, &export_functions, types),
+        headers,
+        path,
+    );
+}
+
+/// Formats the `RuntimeBuilder`, which allows the host to choose the engine
+/// that is used, or to instantiate a runtime from a module that was compiled
+/// beforehand.
+fn format_runtime_builder(has_capabilities: bool, init: &Function, types: &TypeMap) -> String {
+    let (capabilities_field, capabilities_setter, env) = if has_capabilities {
+        (
+            "\n    capabilities: Vec<Capability>,",
+            r#"
+
+    /// Grants the given capabilities to the plugin.
+    ///
+    /// Calls to imports that require a capability that was not granted fail
+    /// with `InvocationError::PermissionDenied`.
+    pub fn with_capabilities(mut self, capabilities: &[Capability]) -> Self {
+        self.capabilities = capabilities.to_vec();
+        self
+    }"#,
+            "RuntimeInstanceData::default()
+            .with_capabilities(self.capabilities.iter().map(Capability::as_str))",
+        )
+    } else {
+        ("", "", "RuntimeInstanceData::default()")
+    };
+    let (init_field, init_setter, init_call) = format_init_builder_parts(init, types);
+
+    format!(
+        r#"
+
+/// Builds a `Runtime` with custom settings.
+///
+/// By default, modules are compiled with an engine that uses the default
+/// Wasmi configuration. Hosts may provide their own engine to configure the
+/// interpreter differently.
+#[derive(Default)]
+pub struct RuntimeBuilder {{
+    engine: Option<Engine>,
+    wasm_features: WasmFeatures,{capabilities_field}{init_field}
+}}
+
+impl RuntimeBuilder {{
+    /// Returns a new builder with default settings.
+    pub fn new() -> Self {{
+        Self::default()
+    }}
+
+    /// Sets the engine with which modules are compiled and executed.
+    pub fn with_engine(mut self, engine: Engine) -> Self {{
+        self.engine = Some(engine);
+        self
+    }}
+
+    /// Sets the WebAssembly features that are enabled for the plugin.
+    ///
+    /// Modules that use a feature that is disabled are rejected with
+    /// `RuntimeError::DisabledWasmFeatures` before they are compiled. The
+    /// features are also enabled on the default engine, while an engine that
+    /// is set using `with_engine()` keeps its own configuration. Wasmi doesn't
+    /// support SIMD and threads, so plugins that use them fail to compile
+    /// regardless.
+    pub fn with_wasm_features(mut self, features: WasmFeatures) -> Self {{
+        self.wasm_features = features;
+        self
+    }}{capabilities_setter}{init_setter}
+
+    /// Compiles the given module and instantiates a runtime for it.
+    pub fn build(self, wasm_module: impl AsRef<[u8]>) -> Result<Runtime, RuntimeError> {{
+        self.wasm_features.validate(wasm_module.as_ref())?;
+        let engine = self
+            .engine
+            .clone()
+            .unwrap_or_else(|| Engine::new(&self.wasm_features.into()));
+        let module = Module::new(&engine, wasm_module.as_ref())?;
+        self.build_with_module(&module)
+    }}
+
+    /// Instantiates a runtime for a module that has already been compiled.
+    ///
+    /// The runtime uses the engine of the module, rather than the engine that
+    /// was set on the builder.
+    pub fn build_with_module(self, module: &Module) -> Result<Runtime, RuntimeError> {{
+        let runtime = Runtime::from_module(module, {env})?;{init_call}
+        Ok(runtime)
+    }}
+}}"#
+    )
+}
+
+fn format_function_bindings(
+    imports: String,
+    exports: String,
+    new_func: String,
+    create_linker_func: String,
+    protocol: String,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let full = rustfmt(format!(
+        r#"use super::types::*;
+use fp_bindgen_support::{{
+    common::{{mem::FatPtr, abi::WasmAbi, lazy::Lazy, wasm_features::WasmFeatures}},
+    wasmi_host::{{
+        errors::{{guest_error, GuestError, InvocationError, RuntimeError}},
+        mem::{{export_to_guest, export_to_guest_raw, import_from_guest, import_from_guest_raw, deserialize_from_slice, serialize_to_vec, try_deserialize_from_slice, try_export_to_guest, try_export_to_guest_raw, try_import_from_guest_raw}},
+        r#async::{{poll_async_value, poll_pending_imports, resolve_async_value, spawn_import, spawn_import_without_result}},
+        runtime::RuntimeInstanceData,
+    }},
+}};
+use std::{{cell::RefCell, future::poll_fn}};
+use wasmi::{{
+    core::{{Trap, F32, F64}},
+    Caller, Engine, Instance, Linker, Module, Store,
+}};
+
+/// Runtime for plugins that uses the Wasmi interpreter.
+///
+/// Wasmi doesn't rely on a JIT compiler, threads or an async executor, so
+/// this runtime can be used on hosts where Wasmer cannot. Async imports are
+/// not spawned onto an executor, but driven while async exports are awaited,
+/// which is also why the runtime cannot be shared across threads.
+pub struct Runtime {{
+    instance: Instance,
+    store: RefCell<Store<RuntimeInstanceData>>,
+}}
+
+impl Runtime {{
+    {new_func}
+
+    /// Returns statistics about the memory that is allocated by the plugin.
+    ///
+    /// This is only supported for plugins that are built with the
+    /// `memory-stats` feature of `fp-bindgen-support`. For other plugins, an
+    /// `InvocationError::FunctionNotExported` error is returned.
+    pub fn memory_stats(&self) -> Result<fp_bindgen_support::common::mem::MemoryStats, InvocationError> {{
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<(), FatPtr>(&*store, "__fp_memory_stats")
+            .map_err(|_| InvocationError::from_missing_export(&self.instance, &*store, "__fp_memory_stats"))?;
+        let result = function.call(&mut *store, ())?;
+        Ok(import_from_guest(&mut *store, result))
+    }}
+
+    /// Returns metrics about the calls that the plugin has made to imports,
+    /// as measured by the plugin itself.
+    ///
+    /// This is only supported for plugins with bindings that are generated
+    /// with `guest_metrics` enabled. For other plugins, an
+    /// `InvocationError::FunctionNotExported` error is returned.
+    pub fn guest_metrics(&self) -> Result<fp_bindgen_support::common::mem::GuestMetrics, InvocationError> {{
+        let mut store = self.store.borrow_mut();
+        let function = self
+            .instance
+            .get_typed_func::<(), FatPtr>(&*store, "__fp_get_guest_metrics")
+            .map_err(|_| InvocationError::from_missing_export(&self.instance, &*store, "__fp_get_guest_metrics"))?;
+        let result = function.call(&mut *store, ())?;
+        Ok(import_from_guest(&mut *store, result))
+    }}
+
+    /// Returns a machine-readable description of the protocol that this
+    /// runtime was generated for, as JSON.
+    ///
+    /// The description contains the signatures of all imported and exported
+    /// functions and the types they use, in the format that is parsed by
+    /// `fp_bindgen::Protocol::from_json()`. Note that plugins may omit
+    /// exports, in which case calling them fails with
+    /// `InvocationError::FunctionNotExported`.
+    pub fn protocol() -> &'static str {{
+        PROTOCOL
+    }}
+
+    {exports}
+}}
+
+impl Drop for Runtime {{
+    /// Calls the `shutdown` export of the plugin, if it implements it.
+    ///
+    /// Failures are ignored, since there is nobody left to report them to.
+    fn drop(&mut self) {{
+        let store = self.store.get_mut();
+        if let Ok(function) = self.instance.get_typed_func::<(), ()>(&*store, "__fp_gen_shutdown") {{
+            let _ = function.call(store, ());
+        }}
+    }}
+}}
+
+{create_linker_func}
+
+{imports}
+
+const PROTOCOL: &str = {protocol:?};
+"#
+    ));
+    write_bindings_file(format!("{path}/bindings.rs"), full, headers);
+}
@@ -0,0 +1,243 @@
+use crate::{
+    functions::{Function, FunctionList},
+    generators::{
+        formatting::rustfmt,
+        lifecycle::{INIT, SHUTDOWN, UPDATE_CONFIG},
+        rust_plugin::{format_cfg_attr, format_doc_lines, format_ident, format_modifiers},
+        write_bindings_file,
+    },
+    types::{CargoDependency, Type, TypeMap},
+    FileHeaders, ScaffoldConfig,
+};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// Generates the skeleton of a plugin crate, with a stub for every export,
+/// which plugin authors can use as a starting point.
+///
+/// Unlike the other generators, this one generates files that are meant to be
+/// edited, so files that already exist are left untouched.
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: ScaffoldConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let src_path = format!("{path}/src");
+    let cargo_path = format!("{path}/.cargo");
+    fs::create_dir_all(&src_path).expect("Could not create output directory");
+    fs::create_dir_all(&cargo_path).expect("Could not create output directory");
+
+    generate_cargo_file(
+        &import_functions,
+        &export_functions,
+        &types,
+        &config,
+        headers,
+        path,
+    );
+    generate_cargo_config_file(&config, headers, &cargo_path);
+    generate_lib_file(&export_functions, &types, &config, headers, &src_path);
+    generate_readme_file(&config, headers, path);
+}
+
+/// Writes a file of the scaffold, unless it already exists.
+fn write_scaffold_file(file_path: String, contents: String, headers: &FileHeaders) {
+    if !Path::new(&file_path).exists() {
+        write_bindings_file(file_path, contents, headers);
+    }
+}
+
+fn generate_cargo_file(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &ScaffoldConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let mut dependencies =
+        BTreeMap::from([(config.bindings_name, config.bindings_dependency.clone())]);
+
+    // Inject dependencies from custom types, followed by those passed through
+    // the config:
+    let custom_dependencies = types.values().flat_map(|ty| match ty {
+        Type::Custom(custom_type) => custom_type.rs_dependencies.iter().collect(),
+        _ => Vec::new(),
+    });
+    for (name, dependency) in custom_dependencies.chain(config.dependencies.iter()) {
+        let dependency = match dependencies.remove(name) {
+            Some(existing_dependency) => existing_dependency.merge_or_replace_with(dependency),
+            None => dependency.clone(),
+        };
+        dependencies.insert(name, dependency);
+    }
+
+    // Features of the bindings are forwarded, so the plugin can enable them:
+    let features = import_functions
+        .cfg_features()
+        .union(&export_functions.cfg_features())
+        .map(|feature| format!("{feature} = [\"{}/{feature}\"]\n", config.bindings_name))
+        .collect::<Vec<_>>();
+    let features = if features.is_empty() {
+        String::new()
+    } else {
+        format!("\n[features]\n{}", features.join(""))
+    };
+
+    write_scaffold_file(
+        format!("{path}/Cargo.toml"),
+        format!(
+            "[package]
+name = \"{}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[lib]
+crate-type = [\"cdylib\"]
+
+[dependencies]
+{}
+{features}
+[profile.release]
+codegen-units = 1
+lto = true
+opt-level = \"s\"
+",
+            config.name,
+            format_dependencies(&dependencies),
+        ),
+        headers,
+    );
+}
+
+fn format_dependencies(dependencies: &BTreeMap<&str, CargoDependency>) -> String {
+    dependencies
+        .iter()
+        .map(|(name, value)| format!("{name} = {value}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Generates the Cargo config, which makes `cargo build` target WebAssembly
+/// with the target features that match those of the runtimes.
+fn generate_cargo_config_file(config: &ScaffoldConfig, headers: &FileHeaders, path: &str) {
+    let target_features = config
+        .wasm_features
+        .target_features()
+        .iter()
+        .map(|(name, enabled)| format!("{}{name}", if *enabled { '+' } else { '-' }))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    write_scaffold_file(
+        format!("{path}/config.toml"),
+        format!(
+            "[build]
+target = \"wasm32-unknown-unknown\"
+
+[target.wasm32-unknown-unknown]
+rustflags = [\"-C\", \"target-feature={target_features}\"]
+"
+        ),
+        headers,
+    );
+}
+
+fn generate_lib_file(
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &ScaffoldConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let bindings = config.bindings_name.replace('-', "_");
+    // The Rust plugin bindings implement `update_config` themselves:
+    let stubs = export_functions
+        .iter()
+        .filter(|function| function.name != UPDATE_CONFIG)
+        .map(|function| format_export_stub(function, types, &bindings))
+        .collect::<Vec<_>>();
+
+    let contents = rustfmt(format!(
+        "// The stubs below leave their arguments unused until they are implemented:
+#![allow(unused_variables)]
+
+use {bindings}::*;
+
+{}",
+        stubs.join("\n\n")
+    ));
+    write_scaffold_file(format!("{path}/lib.rs"), contents, headers);
+}
+
+/// Formats the stub that implements the given export.
+///
+/// The lifecycle exports are called by the runtimes on every plugin, so their
+/// stubs do nothing, while all the others still need to be implemented.
+fn format_export_stub(function: &Function, types: &TypeMap, bindings: &str) -> String {
+    let args = function
+        .args
+        .iter()
+        .map(|arg| format!("{}: {}", arg.name, format_ident(&arg.ty, types)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = match &function.return_type {
+        Some(ty) => format!(" -> {}", format_ident(ty, types)),
+        None => String::new(),
+    };
+    let body = match function.name.as_str() {
+        INIT | SHUTDOWN => "",
+        _ => "todo!()",
+    };
+    // Renamed exports need to be exported under their name on the wire:
+    let rename = match &function.attrs.rename {
+        Some(rename) => format!(", rename = \"{rename}\""),
+        None => String::new(),
+    };
+    format!(
+        "{doc}{cfg}#[fp_export_impl({bindings}{rename})]\n{modifiers}fn {name}({args}){return_type} {{\n    {body}\n}}",
+        doc = format_doc_lines(&function.doc_lines),
+        cfg = format_cfg_attr(function, ""),
+        modifiers = format_modifiers(function),
+        name = function.name,
+    )
+}
+
+fn generate_readme_file(config: &ScaffoldConfig, headers: &FileHeaders, path: &str) {
+    let name = &config.name;
+    let artifact = name.replace('-', "_");
+    write_scaffold_file(
+        format!("{path}/README.md"),
+        format!(
+            "# {name}
+
+This plugin implements the exports of its protocol through the `{bindings}`
+crate. Every export starts out as a stub in `src/lib.rs`, which panics until it
+is implemented. Exports that you don't need can be removed, since plugins may
+omit any of them.
+
+## Building
+
+Plugins are compiled to WebAssembly, so you need to install the target first:
+
+```sh
+rustup target add wasm32-unknown-unknown
+```
+
+After that, `.cargo/config.toml` makes Cargo build the plugin for the target,
+with the target features that match the runtimes:
+
+```sh
+cargo build --release
+```
+
+The plugin can then be found at
+`target/wasm32-unknown-unknown/release/{artifact}.wasm`.
+",
+            bindings = config.bindings_name,
+        ),
+        headers,
+    );
+}
@@ -0,0 +1,327 @@
+//! Hashes of the wire format of the protocol's types.
+//!
+//! Every struct, enum and alias gets a hash of the way it is serialized: the
+//! serialized names of its fields and variants, whether they are optional or
+//! flattened, the representation of enums, and the wire format of the types
+//! it contains, recursively. Names of types, docs and other attributes that
+//! don't affect serialization are left out, so the hash only changes when
+//! values that were serialized before may no longer be deserialized.
+//!
+//! The hashes are calculated once for the whole protocol, before the members
+//! that are excluded from specific generators are removed, so that every
+//! generator emits the same hash for a type.
+
+use crate::{
+    casing::Casing,
+    types::{EnumOptions, Field, Type, TypeIdent, TypeMap, Variant},
+};
+use std::collections::BTreeMap;
+
+/// The hashes of the types of a protocol, by type name, as 16 hexadecimal
+/// digits.
+pub(crate) type SchemaHashes = BTreeMap<String, String>;
+
+/// Calculates the hashes of all structs, enums and aliases in the given type
+/// map, which are passed on to the generators.
+pub(super) fn calculate_schema_hashes(types: &TypeMap) -> SchemaHashes {
+    types
+        .iter()
+        .filter(|(_, ty)| matches!(ty, Type::Alias(..) | Type::Enum(_) | Type::Struct(_)))
+        .map(|(ident, _)| (ident.name.clone(), calculate_schema_hash(ident, types)))
+        .collect()
+}
+
+/// Calculates the hash of the type with the given identifier, using the
+/// 64-bit FNV-1a hash of its schema, which is stable across platforms and
+/// Rust versions.
+pub(crate) fn calculate_schema_hash(ident: &TypeIdent, types: &TypeMap) -> String {
+    let schema = describe_declaration(ident, types, &mut Vec::new());
+    let hash = schema
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    format!("{hash:016x}")
+}
+
+/// Describes the wire format of a declared type, with its generic parameters
+/// described by their position.
+///
+/// The stack contains the declarations that are being described, so that
+/// recursive types refer to themselves by their depth, rather than by name.
+fn describe_declaration<'a>(
+    ident: &TypeIdent,
+    types: &'a TypeMap,
+    stack: &mut Vec<&'a TypeIdent>,
+) -> String {
+    let (decl, ty) = match types.get_key_value(ident) {
+        Some(entry) => entry,
+        None => return format!("unknown({})", ident.name),
+    };
+    if let Some(depth) = stack.iter().position(|parent| *parent == decl) {
+        return format!("rec({depth})");
+    }
+
+    stack.push(decl);
+    let description = match ty {
+        Type::Alias(_, target) => describe_usage(target, decl, types, stack),
+        Type::Enum(ty) => {
+            let options = &ty.options;
+            let variants = ty
+                .variants
+                .iter()
+                .map(|variant| describe_variant(variant, options, decl, types, stack))
+                .collect::<Vec<_>>();
+            format!(
+                "enum(tag={},content={},untagged={}){{{}}}",
+                options.tag_prop_name.as_deref().unwrap_or_default(),
+                options.content_prop_name.as_deref().unwrap_or_default(),
+                options.untagged,
+                variants.join(",")
+            )
+        }
+        Type::Struct(ty) => {
+            let fields = describe_fields(&ty.fields, ty.options.field_casing, decl, types, stack);
+            if ty.options.columnar {
+                format!("columnar{fields}")
+            } else {
+                fields
+            }
+        }
+        _ => describe_usage(ident, decl, types, stack),
+    };
+    stack.pop();
+    description
+}
+
+/// Describes the wire format of a type that is used by a declaration, in
+/// which the declaration's generic parameters are described by position.
+fn describe_usage<'a>(
+    ident: &TypeIdent,
+    decl: &TypeIdent,
+    types: &'a TypeMap,
+    stack: &mut Vec<&'a TypeIdent>,
+) -> String {
+    if ident.generic_args.is_empty() {
+        if let Some(index) = decl
+            .generic_args
+            .iter()
+            .position(|(param, _)| param.name == ident.name)
+        {
+            return format!("${index}");
+        }
+    }
+
+    let args = ident
+        .generic_args
+        .iter()
+        .map(|(arg, _)| describe_usage(arg, decl, types, stack))
+        .collect::<Vec<_>>();
+    let description = match types.get(ident) {
+        Some(Type::Array(primitive, len)) => format!("[{}; {len}]", primitive.name()),
+        Some(Type::Container(name, _)) if name == "Option" => {
+            return format!("option({})", args.join(","))
+        }
+        // Other containers, such as `Box`, are serialized as their contents:
+        Some(Type::Container(..)) => return args.join(","),
+        Some(Type::Custom(custom)) => format!("custom({})", custom.rs_ty),
+        Some(Type::List(..)) => return format!("list({})", args.join(",")),
+        Some(Type::Map(..)) => return format!("map({})", args.join(",")),
+        Some(Type::Primitive(primitive)) => primitive.name(),
+        Some(Type::String) => "string".to_owned(),
+        Some(Type::Tuple(items)) => {
+            let items = items
+                .iter()
+                .map(|item| describe_usage(item, decl, types, stack))
+                .collect::<Vec<_>>();
+            format!("tuple({})", items.join(","))
+        }
+        Some(Type::Unit) => "unit".to_owned(),
+        Some(Type::Alias(..) | Type::Enum(_) | Type::Struct(_)) => {
+            describe_declaration(ident, types, stack)
+        }
+        None => format!("unknown({})", ident.name),
+    };
+    if args.is_empty() {
+        description
+    } else {
+        format!("{description}<{}>", args.join(","))
+    }
+}
+
+fn describe_fields<'a>(
+    fields: &[Field],
+    casing: Casing,
+    decl: &TypeIdent,
+    types: &'a TypeMap,
+    stack: &mut Vec<&'a TypeIdent>,
+) -> String {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let name = match (&field.attrs.rename, &field.name) {
+                (Some(rename), _) => rename.clone(),
+                (None, Some(name)) => casing.format_string(name.strip_prefix("r#").unwrap_or(name)),
+                (None, None) => String::new(),
+            };
+            // The wire format of fields with custom (de)serializers can only
+            // be derived from their TypeScript type:
+            let ty = if field.attrs.has_custom_serializer() {
+                format!(
+                    "custom({})",
+                    field.attrs.ts_type.as_deref().unwrap_or_default()
+                )
+            } else if field.attrs.interned {
+                // Interned fields may be sent as handles instead of strings:
+                format!(
+                    "interned({})",
+                    describe_usage(&field.ty, decl, types, stack)
+                )
+            } else {
+                describe_usage(&field.ty, decl, types, stack)
+            };
+            let optional =
+                field.attrs.default.is_some() || field.attrs.skip_serializing_if.is_some();
+            format!(
+                "{name}{}{}:{ty}",
+                if optional { "?" } else { "" },
+                if field.attrs.flatten { "..." } else { "" }
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", fields.join(","))
+}
+
+fn describe_variant<'a>(
+    variant: &Variant,
+    options: &EnumOptions,
+    decl: &TypeIdent,
+    types: &'a TypeMap,
+    stack: &mut Vec<&'a TypeIdent>,
+) -> String {
+    let name = match &variant.attrs.rename {
+        Some(rename) => rename.clone(),
+        None => options
+            .variant_casing
+            .format_string(variant.name.strip_prefix("r#").unwrap_or(&variant.name)),
+    };
+    let content = match &variant.ty {
+        Type::Struct(variant_struct) => describe_fields(
+            &variant_struct.fields,
+            variant.attrs.field_casing,
+            decl,
+            types,
+            stack,
+        ),
+        Type::Tuple(items) => {
+            let items = items
+                .iter()
+                .map(|item| describe_usage(item, decl, types, stack))
+                .collect::<Vec<_>>();
+            format!("({})", items.join(","))
+        }
+        _ => String::new(),
+    };
+    let other = if variant.attrs.other { "(other)" } else { "" };
+    format!("{name}{other}{content}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::Primitive;
+
+    fn hash(items: &[&str], name: &str) -> String {
+        let mut types = TypeMap::new();
+        types.insert(TypeIdent::from("u32"), Type::Primitive(Primitive::U32));
+        types.insert(TypeIdent::from("String"), Type::String);
+        types.insert(
+            TypeIdent::from("Option<T>"),
+            Type::Container("Option".to_owned(), TypeIdent::from("T")),
+        );
+        types.insert(
+            TypeIdent::from("Vec<T>"),
+            Type::List("Vec".to_owned(), TypeIdent::from("T")),
+        );
+        for item in items {
+            let ty = Type::from_item(item);
+            let ident = match &ty {
+                Type::Enum(ty) => ty.ident.clone(),
+                Type::Struct(ty) => ty.ident.clone(),
+                _ => unreachable!(),
+            };
+            types.insert(ident, ty);
+        }
+        calculate_schema_hash(&TypeIdent::from(name), &types)
+    }
+
+    #[test]
+    fn test_hashes_ignore_names_and_docs() {
+        let original = hash(
+            &["struct Foo { id: u32, bar: Bar }", "struct Bar {}"],
+            "Foo",
+        );
+        assert_eq!(original.len(), 16);
+        assert_eq!(
+            hash(
+                &[
+                    "/// Docs.\nstruct Renamed { id: u32, bar: Baz }",
+                    "struct Baz {}"
+                ],
+                "Renamed"
+            ),
+            original
+        );
+    }
+
+    #[test]
+    fn test_hashes_follow_wire_format() {
+        let original = hash(
+            &["struct Foo { id: u32, bar: Bar }", "struct Bar {}"],
+            "Foo",
+        );
+        for (items, name) in [
+            (
+                &["struct Foo { id: String, bar: Bar }", "struct Bar {}"][..],
+                "Foo",
+            ),
+            (
+                &["struct Foo { bar: Bar, id: u32 }", "struct Bar {}"],
+                "Foo",
+            ),
+            (
+                &[
+                    "struct Foo { #[serde(rename = \"ID\")] id: u32, bar: Bar }",
+                    "struct Bar {}",
+                ],
+                "Foo",
+            ),
+            (
+                &[
+                    "struct Foo { id: u32, bar: Bar }",
+                    "struct Bar { baz: u32 }",
+                ],
+                "Foo",
+            ),
+            (
+                &["struct Foo { id: u32, bar: Option<Bar> }", "struct Bar {}"],
+                "Foo",
+            ),
+        ] {
+            assert_ne!(hash(items, name), original, "{items:?}");
+        }
+    }
+
+    #[test]
+    fn test_hashes_of_enums_and_recursive_types() {
+        let tagged = hash(
+            &["#[serde(tag = \"type\")] enum Foo { A { id: u32 }, B }"],
+            "Foo",
+        );
+        let untagged = hash(&["#[serde(untagged)] enum Foo { A { id: u32 }, B }"], "Foo");
+        assert_ne!(tagged, untagged);
+
+        let tree = hash(&["struct Tree { children: Vec<Tree> }"], "Tree");
+        assert_eq!(hash(&["struct Node { children: Vec<Node> }"], "Node"), tree);
+    }
+}
@@ -0,0 +1,204 @@
+//! Minimal ES-module-to-CommonJS conversion for the generated runtime.
+//!
+//! Like `strip_types`, this only understands the constructs that are emitted
+//! by the TypeScript runtime generator. Import and export statements always
+//! start at the beginning of a line:
+//!
+//! - `import { a, b as c } from "module";` becomes
+//!   `const { a, b: c } = require("module");`.
+//! - `import * as a from "module";` becomes `const a = require("module");`.
+//! - `export * from "module";` copies all exports of the module.
+//! - `export { a, b } from "module";` copies the given exports of the module.
+//! - `export` is removed from the declarations of functions, classes and
+//!   constants, which are assigned to `exports` at the end of the module.
+//!
+//! Relative paths of modules are given the `.cjs` extension. The generated
+//! modules do not import each other in cycles, so exports don't need to be
+//! assigned before the module is evaluated.
+
+/// Converts the given JavaScript ES module to a CommonJS module.
+pub(super) fn to_commonjs(source: &str) -> String {
+    let mut lines = source.lines();
+    let mut output = Vec::new();
+    let mut exported_names = Vec::new();
+
+    // The directive needs to come before any statement, but may follow the
+    // header:
+    let mut has_directive = false;
+    while let Some(line) = lines.next() {
+        if !has_directive && !line.starts_with("//") {
+            output.push("\"use strict\";".to_owned());
+            has_directive = true;
+        }
+
+        if line.starts_with("import {") || line.starts_with("export {") {
+            let mut statement = line.to_owned();
+            while !statement.ends_with(';') {
+                match lines.next() {
+                    Some(line) => statement.push_str(line),
+                    None => panic!("Unterminated statement: {}", statement),
+                }
+            }
+            output.push(format_named_import_or_export(&statement));
+        } else if let Some(statement) = line.strip_prefix("import * as ") {
+            let (name, module) = split_module(statement);
+            output.push(format!("const {name} = require(\"{module}\");"));
+        } else if line.starts_with("export * from ") {
+            let (_, module) = split_module(line);
+            output.push(format!("Object.assign(exports, require(\"{module}\"));"));
+        } else if let Some(declaration) = line.strip_prefix("export ") {
+            exported_names.push(get_declared_name(declaration));
+            output.push(declaration.to_owned());
+        } else {
+            output.push(line.to_owned());
+        }
+    }
+
+    if !exported_names.is_empty() {
+        output.push(String::new());
+        output.extend(
+            exported_names
+                .iter()
+                .map(|name| format!("exports.{name} = {name};")),
+        );
+    }
+
+    let mut output = output.join("\n");
+    output.push('\n');
+    output
+}
+
+/// Formats an `import { ... } from "..."` or `export { ... } from "..."`
+/// statement, which may span multiple lines, as its CommonJS equivalent.
+fn format_named_import_or_export(statement: &str) -> String {
+    let (names, module) = split_module(statement);
+    let names = names
+        .trim_start_matches("import")
+        .trim_start_matches("export")
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| match name.split_once(" as ") {
+            Some((name, alias)) => (name.trim(), alias.trim()),
+            None => (name, name),
+        })
+        .collect::<Vec<_>>();
+
+    if statement.starts_with("import") {
+        let bindings = names
+            .iter()
+            .map(|&(name, alias)| {
+                if name == alias {
+                    name.to_owned()
+                } else {
+                    format!("{name}: {alias}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("const {{ {bindings} }} = require(\"{module}\");")
+    } else {
+        names
+            .iter()
+            .map(|(name, alias)| format!("exports.{alias} = require(\"{module}\").{name};"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Splits a statement of the form `<names> from "<module>";` into the names
+/// and the path of the module, with relative paths pointing to `.cjs` files.
+fn split_module(statement: &str) -> (&str, String) {
+    let (names, module) = statement
+        .rsplit_once(" from ")
+        .unwrap_or_else(|| panic!("Statement without module: {}", statement));
+    let module = module.trim().trim_end_matches(';').trim_matches('"');
+    let module = match module.strip_suffix(".js") {
+        Some(path) if module.starts_with("./") => format!("{path}.cjs"),
+        _ => module.to_owned(),
+    };
+    (names.trim(), module)
+}
+
+/// Returns the name that is declared by a function, class or constant
+/// declaration.
+fn get_declared_name(declaration: &str) -> &str {
+    let declaration = declaration
+        .trim_start_matches("async ")
+        .trim_start_matches("function ")
+        .trim_start_matches("class ")
+        .trim_start_matches("const ")
+        .trim_start_matches("let ");
+    let end = declaration
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(declaration.len());
+    &declaration[..end]
+}
+
+/// Points the relative module paths in the given declarations to the
+/// declarations of the CommonJS modules.
+pub(super) fn to_commonjs_declarations(declarations: &str) -> String {
+    declarations
+        .lines()
+        .map(|line| match line.split_once(" from \"./") {
+            Some((statement, path)) if path.ends_with(".js\";") => format!(
+                "{statement} from \"./{}.cjs\";",
+                path.trim_end_matches(".js\";")
+            ),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_imports_and_exports() {
+        let source = r#"// Header
+import { encode, decode as decodeValue } from "@msgpack/msgpack";
+import {
+    FPRuntimeError,
+    getExport,
+} from "./memory.js";
+
+export * from "./exports.js";
+export { ValidationError } from "./validation.js";
+
+export class PluginError extends FPRuntimeError {
+}
+
+export async function createRuntime(plugin) {
+    return getExport(plugin, "init");
+}
+"#;
+
+        assert_eq!(
+            to_commonjs(source),
+            r#"// Header
+"use strict";
+const { encode, decode: decodeValue } = require("@msgpack/msgpack");
+const { FPRuntimeError, getExport } = require("./memory.cjs");
+
+Object.assign(exports, require("./exports.cjs"));
+exports.ValidationError = require("./validation.cjs").ValidationError;
+
+class PluginError extends FPRuntimeError {
+}
+
+async function createRuntime(plugin) {
+    return getExport(plugin, "init");
+}
+
+exports.PluginError = PluginError;
+exports.createRuntime = createRuntime;
+"#
+        );
+    }
+}
@@ -0,0 +1,534 @@
+//! Conversion of decoded values into the JavaScript objects that represent
+//! them, where MessagePack cannot decode those by itself:
+//!
+//! - The arrays in which sets are encoded are converted into `Set` objects,
+//!   for runtimes that are generated with `generate_set_types`.
+//! - The strings in which timestamps are encoded are converted into `Date`
+//!   objects, for fields that are annotated with `#[fp(ts_as = "Date")]`.
+//! - The handles of interned strings are converted back into the strings,
+//!   for fields that are annotated with `#[fp(interned)]`. The plugin
+//!   registers the strings through the `__fp_intern_string()` import, which
+//!   adds them to the intern table in `conversions.ts`.
+//!
+//! Values that are received from the plugin are converted using converter
+//! functions, which are composed from the helpers in `conversions.ts`
+//! according to their type. Values that are sent to the plugin don't need to
+//! be typed, so `serializeObject()` simply turns all sets back into arrays and
+//! all dates back into strings using `toEncodable()`. Interned strings are
+//! always sent as strings, which the plugin accepts as well.
+
+use super::{format_header, get_field_name, join_lines, readonly::READONLY_SET, ModuleContext};
+use crate::{
+    generators::interning::INTERN_STRING_IMPORT,
+    types::{Field, Type, TypeIdent, TypeMap},
+};
+use std::collections::BTreeSet;
+
+/// Helpers for composing converters, which are functions that convert the
+/// values in a decoded value into the objects that represent them.
+const CONVERSION_HELPERS: &str = r#"
+type Converter = (value: any) => any;
+
+/**
+ * Converts an RFC3339 string into a date.
+ */
+export function toDate(value: string): Date {
+    return new Date(value);
+}
+
+/**
+ * Returns a converter that turns an array into a set, converting its items
+ * using the given converter.
+ */
+export function toSet(convertItem?: Converter): Converter {
+    return (items) => new Set(convertItem ? items.map(convertItem) : items);
+}
+
+/**
+ * Returns a converter that converts the items of an array.
+ */
+export function mapArray(convertItem: Converter): Converter {
+    return (items) => items.map(convertItem);
+}
+
+/**
+ * Returns a converter that converts a value unless it is `null` or
+ * `undefined`.
+ */
+export function mapNullable(convert: Converter): Converter {
+    return (value) => (value == null ? value : convert(value));
+}
+
+/**
+ * Returns a converter that converts the values of a record.
+ */
+export function mapRecord(convertValue: Converter): Converter {
+    return (record) =>
+        Object.fromEntries(Object.entries(record).map(([key, value]) => [key, convertValue(value)]));
+}
+
+/**
+ * Converts all the sets in the given value back into arrays, and all the dates
+ * back into RFC3339 strings, so they can be encoded for the plugin.
+ */
+export function toEncodable(value: any): any {
+    if (value instanceof Set) {
+        return Array.from(value, toEncodable);
+    } else if (value instanceof Date) {
+        return value.toISOString();
+    } else if (Array.isArray(value)) {
+        return value.map(toEncodable);
+    } else if (value != null && Object.getPrototypeOf(value) === Object.prototype) {
+        return Object.fromEntries(
+            Object.entries(value).map(([key, item]) => [key, toEncodable(item)])
+        );
+    } else {
+        return value;
+    }
+}
+"#;
+
+/// Helpers for the intern table, which holds the strings that plugins have
+/// registered through the `__fp_intern_string()` import.
+const INTERNING_HELPERS: &str = r#"
+const internedStrings: string[] = [];
+const internHandles = new Map<string, number>();
+
+/**
+ * Registers a string that is interned by the plugin, and returns the handle
+ * that the plugin sends in its place from now on. Handles are shared by all
+ * plugins that are loaded by this runtime.
+ */
+export function internString(value: string): number {
+    let handle = internHandles.get(value);
+    if (handle === undefined) {
+        handle = internedStrings.length;
+        internedStrings.push(value);
+        internHandles.set(value, handle);
+    }
+    return handle;
+}
+
+/**
+ * Converts the handle of an interned string back into the string. Strings
+ * that were sent as they are are returned unchanged.
+ */
+export function fromInterned(value: string | number): string {
+    if (typeof value === "string") {
+        return value;
+    }
+
+    const string = internedStrings[value];
+    if (string === undefined) {
+        throw new Error(`Unknown interned string handle: ${value}`);
+    }
+    return string;
+}
+"#;
+
+/// Returns the wrapper of the import through which plugins register interned
+/// strings.
+pub(super) fn format_intern_string_import_wrapper() -> String {
+    format!(
+        "{INTERN_STRING_IMPORT}: (value_ptr: FatPtr): number =>\n    \
+        internString(parseObject<string>(plugin, value_ptr)),"
+    )
+}
+
+/// Returns whether the list type with the given name is a set, after it has
+/// been renamed by `with_set_types()`, and possibly `with_readonly_types()`.
+fn is_set(name: &str) -> bool {
+    name == "Set" || name == READONLY_SET
+}
+
+/// Renames the set types in the given map to `Set`, so they are formatted as
+/// TypeScript sets instead of arrays.
+pub(super) fn with_set_types(types: TypeMap) -> TypeMap {
+    types
+        .into_iter()
+        .map(|(ident, ty)| match ty {
+            Type::List(name, item) if name == "BTreeSet" || name == "HashSet" => {
+                (ident, Type::List("Set".to_owned(), item))
+            }
+            ty => (ident, ty),
+        })
+        .collect()
+}
+
+/// Returns whether the given field is annotated with `#[fp(ts_as = "Date")]`.
+pub(super) fn is_date_field(field: &Field) -> bool {
+    field.attrs.ts_as.as_deref() == Some("Date")
+}
+
+/// Returns whether any struct has fields that are annotated with
+/// `#[fp(ts_as = "Date")]`.
+pub(super) fn has_date_fields(types: &TypeMap) -> bool {
+    types.values().any(|ty| match ty {
+        Type::Struct(ty) => ty.fields.iter().any(is_date_field),
+        _ => false,
+    })
+}
+
+/// Checks that the fields that are annotated with `#[fp(ts_as = "...")]` can
+/// be converted.
+///
+/// # Panics
+///
+/// Panics if any annotation requests another class than `Date`, or is used on
+/// a field that is not a named field of a non-generic struct, or whose type
+/// is not an (optional) timestamp that is serialized as a string.
+pub(super) fn check_date_fields(types: &TypeMap) {
+    for ty in types.values() {
+        let (ty_ident, fields, convertible) = match ty {
+            Type::Struct(ty) => (&ty.ident, &ty.fields, ty.ident.generic_args.is_empty()),
+            Type::Enum(ty) => {
+                for variant in &ty.variants {
+                    if let Type::Struct(variant_ty) = &variant.ty {
+                        if let Some(field) = variant_ty
+                            .fields
+                            .iter()
+                            .find(|field| field.attrs.ts_as.is_some())
+                        {
+                            panic!(
+                                "Field `{}` of variant `{}::{}` cannot be annotated with \
+                                `ts_as`, because only the fields of structs can be converted",
+                                field.name.as_deref().unwrap_or("0"),
+                                ty.ident,
+                                variant.name
+                            );
+                        }
+                    }
+                }
+                continue;
+            }
+            _ => continue,
+        };
+
+        for field in fields {
+            let class = match field.attrs.ts_as.as_deref() {
+                Some(class) => class,
+                None => continue,
+            };
+            let name = field.name.as_deref().unwrap_or("0");
+            if class != "Date" {
+                panic!(
+                    "Field `{}` of `{}` has `ts_as = \"{}\"`, but only `Date` is supported",
+                    name, ty_ident, class
+                );
+            }
+            if !convertible || field.name.is_none() || field.attrs.flatten {
+                panic!(
+                    "Field `{}` of `{}` cannot be annotated with `ts_as`, because only the \
+                    named fields of structs without type parameters can be converted",
+                    name, ty_ident
+                );
+            }
+            if field.attrs.has_custom_serializer() || field.attrs.ts_type.is_some() {
+                panic!(
+                    "Field `{}` of `{}` cannot be annotated with both `ts_as` and a custom \
+                    (de)serializer or `ts_type`",
+                    name, ty_ident
+                );
+            }
+            if !is_timestamp(&field.ty, types) {
+                panic!(
+                    "Field `{}` of `{}` has type `{}`, but only timestamps that are \
+                    serialized as RFC3339 strings can be converted to a `Date`",
+                    name, ty_ident, field.ty
+                );
+            }
+        }
+    }
+}
+
+/// Returns whether the given type is a custom type that is serialized as a
+/// string, or an `Option` of one.
+fn is_timestamp(ident: &TypeIdent, types: &TypeMap) -> bool {
+    let is_string = |ident: &TypeIdent| matches!(types.get(ident), Some(Type::Custom(custom)) if custom.ts_ty == "string");
+    match types.get(ident) {
+        Some(Type::Container(name, _)) if name == "Option" => ident
+            .generic_args
+            .first()
+            .map(|(arg, _)| is_string(arg))
+            .unwrap_or(false),
+        _ => is_string(ident),
+    }
+}
+
+/// The types that need to be converted when they are received from the
+/// plugin, because they contain sets, dates or interned strings.
+pub(super) struct Conversions {
+    enabled: bool,
+    /// Whether any fields are interned, in which case `conversions.ts` holds
+    /// the intern table.
+    interning: bool,
+    /// The structs with fields that need to be converted, for which a
+    /// converter function is generated in `conversions.ts`.
+    structs: BTreeSet<TypeIdent>,
+}
+
+impl Conversions {
+    /// Collects the structs that need to be converted. If sets are not
+    /// generated as `Set` types and there are no date or interned fields,
+    /// nothing is converted.
+    pub(super) fn new(
+        types: &TypeMap,
+        has_set_types: bool,
+        has_date_fields: bool,
+        has_interned_fields: bool,
+    ) -> Self {
+        let mut conversions = Self {
+            enabled: has_set_types || has_date_fields || has_interned_fields,
+            interning: has_interned_fields,
+            structs: BTreeSet::new(),
+        };
+        if !conversions.enabled {
+            return conversions;
+        }
+
+        loop {
+            let mut changed = false;
+            for ty in types.values() {
+                if let Type::Struct(ty) = ty {
+                    if !conversions.structs.contains(&ty.ident)
+                        && ty.ident.generic_args.is_empty()
+                        && ty
+                            .fields
+                            .iter()
+                            .any(|field| conversions.format_field_converter(field, types).is_some())
+                    {
+                        conversions.structs.insert(ty.ident.clone());
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                return conversions;
+            }
+        }
+    }
+
+    /// Returns whether any values need to be converted, in which case
+    /// `conversions.ts` is generated.
+    pub(super) fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the names of the functions that are exported by
+    /// `conversions.ts`.
+    pub(super) fn function_names(&self) -> Vec<String> {
+        if !self.enabled {
+            return Vec::new();
+        }
+
+        let interning_helpers: &[&str] = if self.interning {
+            &["fromInterned", "internString"]
+        } else {
+            &[]
+        };
+        interning_helpers
+            .iter()
+            .chain(&["mapArray", "mapNullable", "mapRecord", "toDate", "toSet"])
+            .map(|name| (*name).to_owned())
+            .chain(self.structs.iter().map(format_struct_converter_name))
+            .collect()
+    }
+
+    /// Returns the expression that converts the value of the given
+    /// expression, as it is decoded from the plugin, into the given type.
+    pub(super) fn format_conversion(
+        &self,
+        ident: &TypeIdent,
+        value: &str,
+        types: &TypeMap,
+    ) -> String {
+        match self.format_converter(ident, types) {
+            Some(converter) => format!("{converter}({value})"),
+            None => value.to_owned(),
+        }
+    }
+
+    /// Returns the converter for the value of the given field, or `None` if
+    /// it doesn't need to be converted.
+    fn format_field_converter(&self, field: &Field, types: &TypeMap) -> Option<String> {
+        if field.attrs.interned {
+            return Some("fromInterned".to_owned());
+        }
+        if !is_date_field(field) {
+            return self.format_converter(&field.ty, types);
+        }
+
+        match types.get(&field.ty) {
+            Some(Type::Container(_, _)) => Some("mapNullable(toDate)".to_owned()),
+            _ => Some("toDate".to_owned()),
+        }
+    }
+
+    /// Returns the converter for values of the given type, or `None` if they
+    /// don't contain anything that needs to be converted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type contains sets, dates or interned strings in
+    /// positions that cannot be converted, such as enum variants and tuples.
+    fn format_converter(&self, ident: &TypeIdent, types: &TypeMap) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+
+        let first_arg = || {
+            &ident
+                .generic_args
+                .first()
+                .expect("Identifier was expected to contain a generic argument")
+                .0
+        };
+        match types.get(ident) {
+            Some(Type::Alias(_, ident)) => self.format_converter(ident, types),
+            Some(Type::Container(name, _)) if name == "Option" => self
+                .format_converter(first_arg(), types)
+                .map(|converter| format!("mapNullable({converter})")),
+            Some(Type::Container(_, _)) => self.format_converter(first_arg(), types),
+            Some(Type::List(name, _)) if is_set(name) => Some(format!(
+                "toSet({})",
+                self.format_converter(first_arg(), types)
+                    .unwrap_or_default()
+            )),
+            Some(Type::List(_, _)) => self
+                .format_converter(first_arg(), types)
+                .map(|converter| format!("mapArray({converter})")),
+            Some(Type::Map(_, _, _)) => {
+                let (value, _) = ident
+                    .generic_args
+                    .get(1)
+                    .expect("Identifier was expected to contain two arguments");
+                self.format_converter(value, types)
+                    .map(|converter| format!("mapRecord({converter})"))
+            }
+            // Structs without type parameters are collected up front, so that
+            // those that need to be converted get a converter of their own:
+            Some(Type::Struct(ty)) if ty.ident.generic_args.is_empty() => self
+                .structs
+                .contains(ident)
+                .then(|| format_struct_converter_name(ident)),
+            _ => {
+                if contains_conversion(ident, types, &mut BTreeSet::new()) {
+                    panic!(
+                        "Type `{}` contains a set, a `Date` or an interned string in a \
+                        position that cannot be converted, such as an enum variant, a tuple or \
+                        a generic argument. Either move it into a struct field, or disable \
+                        `generate_set_types` or remove the `ts_as` or `interned` annotation",
+                        ident
+                    );
+                }
+                None
+            }
+        }
+    }
+
+    /// Formats `conversions.ts`, which contains the converters for the
+    /// structs that need to be converted, and the intern table if any fields
+    /// are interned.
+    pub(super) fn format_module(&self, modules: &ModuleContext, types: &TypeMap) -> String {
+        let extension = modules.extension;
+        let interning_helpers = if self.interning {
+            INTERNING_HELPERS
+        } else {
+            ""
+        };
+        let converters = self
+            .structs
+            .iter()
+            .filter_map(|ident| match types.get(ident) {
+                Some(Type::Struct(ty)) => Some(ty),
+                _ => None,
+            })
+            .map(|ty| {
+                let fields = ty
+                    .fields
+                    .iter()
+                    .filter_map(|field| {
+                        if field.attrs.flatten {
+                            // Flattened fields are part of the same object:
+                            return self.structs.contains(&field.ty).then(|| {
+                                format!("...{}(value),", format_struct_converter_name(&field.ty))
+                            });
+                        }
+
+                        let name = serde_json::to_string(&get_field_name(
+                            field,
+                            ty.options.field_casing,
+                        ))
+                        .expect("Could not serialize field name");
+                        self.format_field_converter(field, types)
+                            .map(|converter| format!("{name}: {converter}(value[{name}]),"))
+                    })
+                    .collect::<Vec<_>>();
+                format!(
+                    "\nexport function {}(value: any): types.{} {{\n    return {{\n        ...value,\n{}    }};\n}}\n",
+                    format_struct_converter_name(&ty.ident),
+                    ty.ident.name,
+                    join_lines(&fields, |line| format!("        {line}"))
+                )
+            })
+            .collect::<String>();
+
+        format!(
+            "{header}
+// deno-lint-ignore-file no-explicit-any
+
+import type * as types from \"./types{extension}\";
+{CONVERSION_HELPERS}{interning_helpers}{converters}",
+            header = format_header("Conversions for WebAssembly runtime"),
+        )
+    }
+}
+
+fn format_struct_converter_name(ident: &TypeIdent) -> String {
+    format!("convert{}", ident.name)
+}
+
+/// Returns whether the given type contains a set, a date or an interned string
+/// anywhere.
+fn contains_conversion(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    visited: &mut BTreeSet<TypeIdent>,
+) -> bool {
+    if !visited.insert(ident.clone()) {
+        return false;
+    }
+
+    let ty = match types.get(ident) {
+        Some(ty) => ty,
+        None => return false,
+    };
+    let mut idents: Vec<&TypeIdent> = ident.generic_args.iter().map(|(arg, _)| arg).collect();
+    match ty {
+        Type::Alias(_, ident) => idents.push(ident),
+        Type::List(name, _) if is_set(name) => return true,
+        Type::Enum(ty) => {
+            for variant in &ty.variants {
+                match &variant.ty {
+                    Type::Struct(ty) => idents.extend(ty.fields.iter().map(|field| &field.ty)),
+                    Type::Tuple(items) => idents.extend(items),
+                    _ => {}
+                }
+            }
+        }
+        Type::Struct(ty)
+            if ty
+                .fields
+                .iter()
+                .any(|field| is_date_field(field) || field.attrs.interned) =>
+        {
+            return true
+        }
+        Type::Struct(ty) => idents.extend(ty.fields.iter().map(|field| &field.ty)),
+        Type::Tuple(items) => idents.extend(items),
+        _ => {}
+    }
+    idents
+        .into_iter()
+        .any(|ident| contains_conversion(ident, types, visited))
+}
@@ -0,0 +1,214 @@
+//! Conversion of 64-bit integers for runtimes that are generated with an
+//! `int64_encoding` other than `BigInt`.
+//!
+//! WebAssembly passes `i64` and `u64` arguments and return values as `bigint`,
+//! so these are converted from and to the representation of the encoding,
+//! checking that no value is silently truncated. Inside data types, 64-bit
+//! integers are decoded into numbers by MessagePack, so `parseObject()` checks
+//! that the encoded values are within the safe integer range before decoding.
+
+use crate::{prelude::Primitive, types::TypeIdent, TsInt64Encoding};
+
+/// Helpers for converting and checking 64-bit integers, which are included in
+/// `memory.ts`.
+pub(super) const INT64_FUNCTIONS: &str = r#"
+/**
+ * Converts a 64-bit integer that is received from the plugin into a number,
+ * throwing a `RangeError` if a number cannot represent it exactly.
+ */
+export function toSafeNumber(value: bigint): number {
+    if (value > BigInt(Number.MAX_SAFE_INTEGER) || value < BigInt(Number.MIN_SAFE_INTEGER)) {
+        throw new RangeError(`64-bit integer ${value} exceeds the safe integer range`);
+    }
+    return Number(value);
+}
+
+/**
+ * Converts a number into a 64-bit integer that is sent to the plugin,
+ * throwing a `RangeError` if it is not a safe integer within the range of the
+ * type.
+ */
+export function fromSafeNumber(value: number, signed: boolean): bigint {
+    if (!Number.isSafeInteger(value) || (!signed && value < 0)) {
+        throw new RangeError(`${value} is not a valid ${signed ? "i64" : "u64"}`);
+    }
+    return BigInt(value);
+}
+
+/**
+ * Parses a decimal string into a 64-bit integer that is sent to the plugin,
+ * throwing a `RangeError` if it is not an integer within the range of the
+ * type.
+ */
+export function fromInt64String(value: string, signed: boolean): bigint {
+    const [min, max] = signed ? [-(2n ** 63n), 2n ** 63n - 1n] : [0n, 2n ** 64n - 1n];
+    const result = /^-?[0-9]+$/.test(value) ? BigInt(value) : undefined;
+    if (result === undefined || result < min || result > max) {
+        throw new RangeError(`"${value}" is not a valid ${signed ? "i64" : "u64"}`);
+    }
+    return result;
+}
+
+/**
+ * Checks that the 64-bit integers in the given MessagePack data are within
+ * the safe integer range, since they would silently lose precision when they
+ * are decoded into numbers. Throws a `RangeError` if they are not.
+ */
+export function checkSafeIntegers(bytes: Uint8Array) {
+    const view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+    const max = BigInt(Number.MAX_SAFE_INTEGER);
+    let offset = 0;
+    let remaining = 1;
+    while (remaining > 0 && offset < bytes.length) {
+        remaining--;
+        const marker = bytes[offset++];
+        if (marker <= 0x7f || marker >= 0xe0 || (marker >= 0xc0 && marker <= 0xc3)) {
+            // Fixed integers, nil and booleans don't have a payload.
+        } else if (marker <= 0x8f) {
+            remaining += 2 * (marker & 0x0f);
+        } else if (marker <= 0x9f) {
+            remaining += marker & 0x0f;
+        } else if (marker <= 0xbf) {
+            offset += marker & 0x1f;
+        } else if (marker === 0xcf || marker === 0xd3) {
+            const value = marker === 0xcf ? view.getBigUint64(offset) : view.getBigInt64(offset);
+            if (value > max || value < -max) {
+                throw new RangeError(`64-bit integer ${value} exceeds the safe integer range`);
+            }
+            offset += 8;
+        } else {
+            switch (marker) {
+                case 0xc4: // bin 8
+                case 0xd9: // str 8
+                    offset += 1 + view.getUint8(offset);
+                    break;
+                case 0xc5: // bin 16
+                case 0xda: // str 16
+                    offset += 2 + view.getUint16(offset);
+                    break;
+                case 0xc6: // bin 32
+                case 0xdb: // str 32
+                    offset += 4 + view.getUint32(offset);
+                    break;
+                case 0xc7: // ext 8
+                    offset += 2 + view.getUint8(offset);
+                    break;
+                case 0xc8: // ext 16
+                    offset += 3 + view.getUint16(offset);
+                    break;
+                case 0xc9: // ext 32
+                    offset += 5 + view.getUint32(offset);
+                    break;
+                case 0xca: // float 32
+                case 0xce: // uint 32
+                case 0xd2: // int 32
+                    offset += 4;
+                    break;
+                case 0xcb: // float 64
+                    offset += 8;
+                    break;
+                case 0xcc: // uint 8
+                case 0xd0: // int 8
+                    offset += 1;
+                    break;
+                case 0xcd: // uint 16
+                case 0xd1: // int 16
+                    offset += 2;
+                    break;
+                case 0xd4: // fixext 1
+                case 0xd5: // fixext 2
+                case 0xd6: // fixext 4
+                case 0xd7: // fixext 8
+                case 0xd8: // fixext 16
+                    offset += 1 + (1 << (marker - 0xd4));
+                    break;
+                case 0xdc: // array 16
+                    remaining += view.getUint16(offset);
+                    offset += 2;
+                    break;
+                case 0xdd: // array 32
+                    remaining += view.getUint32(offset);
+                    offset += 4;
+                    break;
+                case 0xde: // map 16
+                    remaining += 2 * view.getUint16(offset);
+                    offset += 2;
+                    break;
+                case 0xdf: // map 32
+                    remaining += 2 * view.getUint32(offset);
+                    offset += 4;
+                    break;
+            }
+        }
+    }
+}
+"#;
+
+impl TsInt64Encoding {
+    /// Returns the names of the helpers in `memory.ts` that the conversions
+    /// use.
+    pub(super) fn helper_names(self) -> &'static [&'static str] {
+        match self {
+            Self::BigInt => &[],
+            Self::Number => &["fromSafeNumber", "toSafeNumber"],
+            Self::String => &["fromInt64String"],
+        }
+    }
+
+    /// Returns whether the helpers and the check in `parseObject()` are
+    /// included in `memory.ts`.
+    pub(super) fn checks_safe_integers(self) -> bool {
+        self != Self::BigInt
+    }
+
+    /// Returns the TypeScript type of 64-bit integers that are passed as
+    /// arguments or return values.
+    pub(super) fn format_type(self) -> &'static str {
+        match self {
+            Self::BigInt => "bigint",
+            Self::Number => "number",
+            Self::String => "string",
+        }
+    }
+
+    /// Returns whether values of the given type need to be converted between
+    /// the `bigint` that WebAssembly uses and the representation of the
+    /// encoding.
+    pub(super) fn converts(self, ty: &TypeIdent) -> bool {
+        self != Self::BigInt && matches!(ty.as_primitive(), Some(Primitive::I64 | Primitive::U64))
+    }
+
+    /// Converts a `bigint` value received from the plugin.
+    pub(super) fn format_from_plugin(self, ty: &TypeIdent, value: &str) -> String {
+        let value = if ty.as_primitive() == Some(Primitive::U64) {
+            format!("BigInt.asUintN(64, {value})")
+        } else {
+            value.to_owned()
+        };
+        match self {
+            Self::BigInt => value,
+            Self::Number => format!("toSafeNumber({value})"),
+            Self::String => format!("{value}.toString()"),
+        }
+    }
+
+    /// Converts a value into the `bigint` that is sent to the plugin.
+    pub(super) fn format_to_plugin(self, ty: &TypeIdent, value: &str) -> String {
+        let signed = ty.as_primitive() == Some(Primitive::I64);
+        match self {
+            Self::BigInt => value.to_owned(),
+            Self::Number => format!("fromSafeNumber({value}, {signed})"),
+            Self::String => format!("fromInt64String({value}, {signed})"),
+        }
+    }
+
+    /// Converts a 64-bit integer that was decoded from MessagePack, and which
+    /// is therefore a number.
+    pub(super) fn format_from_decoded(self, value: &str) -> String {
+        match self {
+            Self::BigInt => format!("BigInt({value})"),
+            Self::Number => value.to_owned(),
+            Self::String => format!("String({value})"),
+        }
+    }
+}
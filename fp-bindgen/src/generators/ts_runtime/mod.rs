@@ -1,255 +1,3719 @@
+use super::schema_hash::SchemaHashes;
+use super::validation::{
+    collect_validated_structs, contains_validated_struct, get_validated_field, ValidatedKind,
+};
 use crate::{
     casing::Casing,
     functions::{Function, FunctionList},
+    generators::{
+        interning::has_interned_fields, lifecycle::get_init_export, write_bindings_file,
+        HTTP_IMPORT_CAPABILITY, STD_IMPORT_CAPABILITIES,
+    },
     prelude::Primitive,
+    protocol::protocol_to_json,
     types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
-    TsExtendedRuntimeConfig,
+    FileHeaders, TsExtendedRuntimeConfig, TsIdentifierCasing, TsInt64Encoding, TsModuleFormat,
 };
+use commonjs::{to_commonjs, to_commonjs_declarations};
+use conversions::Conversions;
 use inflector::Inflector;
-use std::fs;
+use int64::INT64_FUNCTIONS;
+use readonly::{READONLY_ARRAY, READONLY_RECORD, READONLY_SET};
+use std::collections::{BTreeMap, BTreeSet};
+use strip_types::strip_types;
+
+mod commonjs;
+mod conversions;
+mod int64;
+mod readonly;
+mod strip_types;
+mod worker;
 
 pub(crate) fn generate_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: TypeMap,
-    config: TsExtendedRuntimeConfig,
+    schema_hashes: &SchemaHashes,
+    mut config: TsExtendedRuntimeConfig,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    if config.module_format.requires_js_output() {
+        config.generate_js_output = true;
+    }
+    if config.module_format == TsModuleFormat::CommonJs
+        && (config.generate_worker_wrapper
+            || config.generate_browser_worker_wrapper
+            || config.generate_async_imports)
+    {
+        panic!(
+            "The worker wrappers of the TypeScript runtime need ES modules, so they cannot be \
+                generated with `TsModuleFormat::CommonJs`"
+        );
+    }
+
+    let casing = &config.identifier_casing;
+    let int64 = config.int64_encoding;
+
+    // A JSON string is also a valid string literal in TypeScript:
+    let protocol = serde_json::to_string(&protocol_to_json(
+        &import_functions,
+        &export_functions,
+        &types,
+    ))
+    .expect("Could not serialize protocol");
+
+    let types = if config.generate_set_types {
+        conversions::with_set_types(types)
+    } else {
+        types
+    };
+    let types = if config.generate_readonly_types {
+        readonly::with_readonly_types(types)
+    } else {
+        types
+    };
+    conversions::check_date_fields(&types);
+    generate_type_bindings(&types, schema_hashes, path, &config, headers);
+
+    let has_async_export_functions = export_functions.iter().any(|function| function.is_async);
+    let has_cached_exports = export_functions
+        .iter()
+        .any(|function| function.attrs.cache.is_some());
+    let has_rate_limited_imports = import_functions
+        .iter()
+        .any(|function| function.attrs.rate_limit.is_some());
+    let has_packed_pair_functions =
+        import_functions
+            .iter()
+            .chain(export_functions.iter())
+            .any(|function| {
+                !function.is_async
+                    && function
+                        .return_type
+                        .as_ref()
+                        .map(TypeIdent::is_packed_pair)
+                        .unwrap_or(false)
+            });
+
+    // Imports behind a feature are included, so that they are checked too if
+    // the plugin was compiled with the feature:
+    let import_signatures = format_import_signatures(&import_functions);
+    let export_signatures = format_export_signatures(&export_functions, casing);
+
+    // Functions behind a feature are moved to a module of their own, so that
+    // they only end up in the bundles of hosts that import it:
+    let (import_functions, mut feature_imports) = split_features(import_functions);
+    let (export_functions, mut feature_exports) = split_features(export_functions);
+    let features = feature_imports
+        .keys()
+        .chain(feature_exports.keys())
+        .cloned()
+        .collect::<BTreeSet<_>>();
+
+    let mut import_decls = format_function_declarations(
+        &import_functions,
+        &types,
+        FunctionType::Import,
+        casing,
+        int64,
+    );
+    if config.generate_std_imports {
+        import_decls.push("std?: StdImports".to_owned());
+    }
+    if config.generate_http_imports {
+        import_decls.push("http?: HttpImports".to_owned());
+    }
+    if config.generate_stdio_capture {
+        import_decls
+            .push("onPrint?: (stream: \"stdout\" | \"stderr\", line: string) => void".to_owned());
+    }
+    let mut export_decls = format_function_declarations(
+        &export_functions,
+        &types,
+        FunctionType::Export,
+        casing,
+        int64,
+    );
+    export_decls.append(&mut format_dynamic_import_declarations(
+        &import_functions,
+        &types,
+        casing,
+    ));
+    export_decls.append(&mut format_event_subscription_declarations(
+        &import_functions,
+    ));
+    export_decls.push("memoryStats?: () => MemoryStats".to_owned());
+    export_decls.push("guestMetrics?: () => GuestMetrics".to_owned());
+    export_decls.push("dispose: () => void".to_owned());
+    if config.generate_eager_export_resolution {
+        export_decls.push("missingExports: () => string[]".to_owned());
+    }
+    if has_cached_exports {
+        export_decls.push("clearExportCache: () => void".to_owned());
+    }
+    let raw_export_decls = if config.generate_raw_export_wrappers {
+        format_raw_function_declarations(&export_functions, FunctionType::Export, casing)
+    } else {
+        Vec::new()
+    };
+
+    let mut capabilities = import_functions.capabilities();
+    if config.generate_std_imports {
+        capabilities.extend(STD_IMPORT_CAPABILITIES);
+    }
+    if config.generate_http_imports {
+        capabilities.insert(HTTP_IMPORT_CAPABILITY);
+    }
+    let validated_structs = collect_validated_structs(&types);
+    let validators = format_validators(&types, &validated_structs);
+    let validator_names = validated_structs
+        .iter()
+        .filter(|ident| matches!(types.get(ident), Some(Type::Struct(_))))
+        .map(|ident| format!("validate{}", ident.name))
+        .collect::<Vec<_>>();
+    let conversions = Conversions::new(
+        &types,
+        config.generate_set_types,
+        conversions::has_date_fields(&types),
+        has_interned_fields(&types),
+    );
+    let conversion_function_names = conversions.function_names();
+
+    let mut import_wrappers = format_import_wrappers(
+        &import_functions,
+        &types,
+        &validated_structs,
+        &conversions,
+        casing,
+        int64,
+        config.generate_payload_tracing,
+    );
+    import_wrappers.append(&mut format_batch_dispatcher(
+        &import_functions,
+        &types,
+        &conversions,
+        casing,
+        int64,
+    ));
+    if config.generate_std_imports {
+        import_wrappers.extend(STD_IMPORT_WRAPPERS.lines().map(str::to_owned));
+    }
+    if config.generate_http_imports {
+        import_wrappers.extend(HTTP_IMPORT_WRAPPER.lines().map(str::to_owned));
+    }
+    if has_interned_fields(&types) {
+        import_wrappers.extend(
+            conversions::format_intern_string_import_wrapper()
+                .lines()
+                .map(str::to_owned),
+        );
+    }
+    if config.generate_stdio_capture {
+        import_wrappers.extend(STDIO_IMPORT_WRAPPER.lines().map(str::to_owned));
+    }
+    import_wrappers.push(
+        "__fp_error: (report_ptr: FatPtr) => throwGuestError(plugin, report_ptr),".to_owned(),
+    );
+    if has_async_export_functions {
+        import_wrappers.push(
+            "__fp_host_resolve_async_value: (asyncValuePtr: FatPtr, resultPtr: FatPtr) =>\n    \
+            resolvePromise(plugin, asyncValuePtr, resultPtr),"
+                .to_owned(),
+        );
+    }
+
+    let mut export_wrappers = format_export_functions(
+        &export_functions,
+        &types,
+        &validated_structs,
+        &conversions,
+        casing,
+        int64,
+        config.generate_payload_tracing,
+    );
+    export_wrappers.append(&mut format_dynamic_import_registrations(
+        &import_functions,
+        &types,
+        casing,
+    ));
+    export_wrappers.append(&mut format_event_subscriptions(&import_functions));
+    export_wrappers.push(format_memory_stats_function());
+    export_wrappers.push(format_guest_metrics_function());
+    export_wrappers.push(format_dispose_function());
+    if config.generate_eager_export_resolution {
+        export_wrappers.push(format_missing_exports_function());
+    }
+    if has_cached_exports {
+        export_wrappers.push(format_clear_export_cache_function());
+    }
+    if config.generate_raw_export_wrappers {
+        export_wrappers.append(&mut format_raw_export_functions(&export_functions, casing));
+    }
+
+    let extension = get_import_extension(&config);
+    let events_type = format_events_type(&import_functions, &types, casing);
+    let init_option = InitOption::new(get_init_export(&export_functions), &types, casing, int64);
+    let modules = ModuleContext {
+        extension,
+        msgpack_module: &config.msgpack_module,
+        has_capabilities: !capabilities.is_empty(),
+        has_feature_imports: !feature_imports.is_empty(),
+        has_packed_pair_functions,
+        has_std_imports: config.generate_std_imports,
+        has_http_imports: config.generate_http_imports,
+        validator_names: &validator_names,
+        conversion_function_names: &conversion_function_names,
+        init: &init_option,
+        int64_encoding: int64,
+        has_cached_exports,
+        has_rate_limited_imports,
+        has_columns: super::columnar::has_columns(&types),
+        has_shared_memory: config.generate_shared_memory,
+        has_signature_verification: config.generate_signature_verification,
+        has_payload_tracing: config.generate_payload_tracing,
+        has_eager_export_resolution: config.generate_eager_export_resolution,
+    };
+
+    let mut files = vec![
+        ("memory", format_memory_module(&modules, &config)),
+        (
+            "imports",
+            format_imports_module(&modules, &import_decls, &capabilities, &import_wrappers),
+        ),
+        (
+            "exports",
+            format_exports_module(
+                &modules,
+                &events_type,
+                &export_decls,
+                &raw_export_decls,
+                &export_wrappers,
+            ),
+        ),
+        (
+            "index",
+            format_index_module(&modules, &protocol, &import_signatures, &export_signatures),
+        ),
+    ];
+    if !validators.is_empty() {
+        files.push((
+            "validation",
+            format_validation_module(&modules, &validators),
+        ));
+    }
+    if conversions.is_enabled() {
+        files.push(("conversions", conversions.format_module(&modules, &types)));
+    }
+
+    let mut feature_declarations = Vec::new();
+    for feature in &features {
+        if matches!(
+            feature.as_str(),
+            "conversions"
+                | "exports"
+                | "imports"
+                | "index"
+                | "memory"
+                | "types"
+                | "validation"
+                | "worker"
+                | "worker-client"
+                | "worker-runtime"
+        ) {
+            panic!(
+                "Feature `{}` cannot be used, because it conflicts with the name of a \
+                    generated TypeScript module",
+                feature
+            );
+        }
+
+        let import_functions = feature_imports.remove(feature).unwrap_or_default();
+        let export_functions = feature_exports.remove(feature).unwrap_or_default();
+        let feature_module = FeatureModule {
+            feature,
+            import_decls: format_function_declarations(
+                &import_functions,
+                &types,
+                FunctionType::Import,
+                casing,
+                int64,
+            ),
+            import_wrappers: format_import_wrappers(
+                &import_functions,
+                &types,
+                &validated_structs,
+                &conversions,
+                casing,
+                int64,
+                config.generate_payload_tracing,
+            ),
+            export_decls: format_function_declarations(
+                &export_functions,
+                &types,
+                FunctionType::Export,
+                casing,
+                int64,
+            ),
+            raw_export_decls: if config.generate_raw_export_wrappers {
+                format_raw_function_declarations(&export_functions, FunctionType::Export, casing)
+            } else {
+                Vec::new()
+            },
+            export_functions: {
+                let mut export_wrappers = format_export_functions(
+                    &export_functions,
+                    &types,
+                    &validated_structs,
+                    &conversions,
+                    casing,
+                    int64,
+                    config.generate_payload_tracing,
+                );
+                if config.generate_raw_export_wrappers {
+                    export_wrappers
+                        .append(&mut format_raw_export_functions(&export_functions, casing));
+                }
+                export_wrappers
+            },
+        };
+        files.push((feature.as_str(), feature_module.format_module(&modules)));
+        feature_declarations.push((feature.as_str(), feature_module.format_declarations()));
+    }
+
+    let format = config.module_format;
+    for (name, contents) in files {
+        if config.generate_js_output {
+            write_js_module(path, name, &strip_types(&contents), format, headers);
+        } else {
+            write_bindings_file(format!("{path}/{name}.ts"), contents, headers);
+        }
+    }
+    if config.generate_js_output {
+        write_js_declarations(
+            path,
+            "index",
+            format_index_declarations(
+                &import_decls,
+                &events_type,
+                &export_decls,
+                &raw_export_decls,
+                &export_wrappers,
+                &capabilities,
+                &modules,
+            ),
+            format,
+            headers,
+        );
+        for (feature, declarations) in &feature_declarations {
+            write_js_declarations(path, feature, declarations.clone(), format, headers);
+        }
+    }
+    if format == TsModuleFormat::Dual {
+        generate_package_file(
+            &types,
+            schema_hashes,
+            &feature_declarations,
+            &config,
+            headers,
+            path,
+        );
+    }
+
+    if config.generate_worker_wrapper {
+        worker::generate_worker_wrapper(
+            &export_functions,
+            &types,
+            &config,
+            !capabilities.is_empty(),
+            headers,
+            path,
+        );
+    }
+
+    if config.generate_browser_worker_wrapper || config.generate_async_imports {
+        worker::generate_browser_worker_wrapper(
+            &import_functions,
+            &export_functions,
+            &types,
+            &config,
+            !capabilities.is_empty(),
+            modules.has_feature_imports,
+            headers,
+            path,
+        );
+    }
+}
+
+/// Returns the extension of the paths from which the generated modules import
+/// each other.
+pub(super) fn get_import_extension(config: &TsExtendedRuntimeConfig) -> &'static str {
+    // HACK: Import paths in TypeScript are a bit of a mess. Usually, you
+    // shouldn't need an extension with a bundler, but with some configurations
+    // you do. For now, we just try to detect Deno users by looking at the
+    // `msgpack_module` and accomodate them here. JavaScript modules and
+    // Node.js always need one:
+    if config.generate_js_output || config.module_format == TsModuleFormat::Esm {
+        ".js"
+    } else if config.msgpack_module.ends_with(".ts") {
+        ".ts"
+    } else {
+        ""
+    }
+}
+
+/// Writes a JavaScript module as an ES module, a CommonJS module or both,
+/// depending on the module format.
+fn write_js_module(
+    path: &str,
+    name: &str,
+    contents: &str,
+    format: TsModuleFormat,
+    headers: &FileHeaders,
+) {
+    if format != TsModuleFormat::CommonJs {
+        write_bindings_file(format!("{path}/{name}.js"), contents, headers);
+    }
+    if format.requires_js_output() {
+        write_bindings_file(format!("{path}/{name}.cjs"), to_commonjs(contents), headers);
+    }
+}
+
+/// Writes the declarations of a JavaScript module, for every format in which
+/// the module is written.
+fn write_js_declarations(
+    path: &str,
+    name: &str,
+    declarations: String,
+    format: TsModuleFormat,
+    headers: &FileHeaders,
+) {
+    if format.requires_js_output() {
+        write_bindings_file(
+            format!("{path}/{name}.d.cts"),
+            to_commonjs_declarations(&declarations),
+            headers,
+        );
+    }
+    if format != TsModuleFormat::CommonJs {
+        write_bindings_file(format!("{path}/{name}.d.ts"), declarations, headers);
+    }
+}
+
+/// Generates a `package.json` for the `Dual` module format, of which the
+/// `exports` map points `import` and `require()` to the ES and CommonJS
+/// modules respectively. The worker wrappers are only available as ES modules.
+fn generate_package_file(
+    types: &TypeMap,
+    schema_hashes: &SchemaHashes,
+    feature_declarations: &[(&str, String)],
+    config: &TsExtendedRuntimeConfig,
+    headers: &FileHeaders,
     path: &str,
 ) {
-    generate_type_bindings(&types, path);
+    let has_runtime_types = !collect_schema_hashes(types, schema_hashes).is_empty()
+        || config.generate_string_enums
+            && types
+                .values()
+                .any(|ty| matches!(ty, Type::Enum(ty) if is_string_enum(ty)));
+
+    let mut entries = vec![format_package_entry(".", "index", true, true)];
+    entries.extend(
+        feature_declarations
+            .iter()
+            .map(|(feature, _)| format_package_entry(&format!("./{feature}"), feature, true, true)),
+    );
+    entries.push(format_package_entry(
+        "./types",
+        "types",
+        true,
+        has_runtime_types,
+    ));
+    if config.generate_worker_wrapper {
+        entries.push(format_package_entry("./worker", "worker", false, true));
+    }
+    if config.generate_browser_worker_wrapper || config.generate_async_imports {
+        entries.push(format_package_entry(
+            "./worker-client",
+            "worker-client",
+            false,
+            true,
+        ));
+        entries.push(format_package_entry(
+            "./worker-runtime",
+            "worker-runtime",
+            false,
+            true,
+        ));
+    }
+
+    write_bindings_file(
+        format!("{path}/package.json"),
+        format!(
+            "{{
+  \"type\": \"module\",
+  \"main\": \"./index.cjs\",
+  \"types\": \"./index.d.ts\",
+  \"exports\": {{
+{}
+  }},
+  \"sideEffects\": false
+}}
+",
+            entries.join(",\n")
+        ),
+        headers,
+    );
+}
+
+/// Formats an entry of the `exports` map of the `package.json`, with the
+/// conditions for `import` and, optionally, `require()`. Modules that only
+/// contain types have no `default` condition.
+fn format_package_entry(
+    subpath: &str,
+    module: &str,
+    has_commonjs: bool,
+    has_javascript: bool,
+) -> String {
+    let format_conditions = |declarations: &str, javascript: &str| {
+        if has_javascript {
+            format!(
+                "{{ \"types\": \"./{module}.{declarations}\", \"default\": \"./{module}.{javascript}\" }}"
+            )
+        } else {
+            format!("{{ \"types\": \"./{module}.{declarations}\" }}")
+        }
+    };
+
+    let mut conditions = vec![format!(
+        "      \"import\": {}",
+        format_conditions("d.ts", "js")
+    )];
+    if has_commonjs {
+        conditions.push(format!(
+            "      \"require\": {}",
+            format_conditions("d.cts", "cjs")
+        ));
+    }
+    format!("    \"{subpath}\": {{\n{}\n    }}", conditions.join(",\n"))
+}
+
+/// Splits the functions that are behind a feature from the others, grouped by
+/// feature.
+fn split_features(functions: FunctionList) -> (FunctionList, BTreeMap<String, FunctionList>) {
+    let mut features = BTreeMap::<String, Vec<Function>>::new();
+    let mut other_functions = Vec::new();
+    for function in functions {
+        match function.attrs.cfg_feature.clone() {
+            Some(feature) => features.entry(feature).or_default().push(function),
+            None => other_functions.push(function),
+        }
+    }
+
+    let features = features
+        .into_iter()
+        .map(|(feature, functions)| (feature, functions.into_iter().collect()))
+        .collect();
+    (other_functions.into_iter().collect(), features)
+}
+
+/// Settings that determine how the generated modules import each other.
+struct ModuleContext<'a> {
+    /// The extension of the paths from which modules are imported.
+    extension: &'a str,
+    /// The module from which the MessagePack encoder and decoder are imported.
+    msgpack_module: &'a str,
+    has_capabilities: bool,
+    /// Whether any imports are behind a feature, in which case the plugin can
+    /// be instantiated with the imports of the feature modules.
+    has_feature_imports: bool,
+    has_packed_pair_functions: bool,
+    /// Whether the built-in std imports are generated.
+    has_std_imports: bool,
+    /// Whether the built-in HTTP imports are generated.
+    has_http_imports: bool,
+    /// The names of the functions that are exported by `validation.ts`.
+    validator_names: &'a [String],
+    /// The names of the functions that are exported by `conversions.ts`.
+    conversion_function_names: &'a [String],
+    /// The `init` option of `createRuntime()`.
+    init: &'a InitOption,
+    /// Determines which of the 64-bit integer helpers are imported.
+    int64_encoding: TsInt64Encoding,
+    /// Whether any exports are annotated with `#[fp(cache)]`, in which case
+    /// the helpers for memoizing their results are generated.
+    has_cached_exports: bool,
+    /// Whether any imports are annotated with `#[fp(rate_limit)]`, in which
+    /// case the helper that keeps track of their token buckets is generated.
+    has_rate_limited_imports: bool,
+    /// Whether any lists of `#[fp(columnar)]` structs are encoded as columns,
+    /// in which case the MessagePack extensions for them are registered.
+    has_columns: bool,
+    /// Whether plugins are instantiated with a shared memory that they import.
+    has_shared_memory: bool,
+    /// Whether the signature of plugins may be verified before they are
+    /// instantiated.
+    has_signature_verification: bool,
+    /// Whether the serialized payloads that are passed between the runtime
+    /// and the plugin may be traced.
+    has_payload_tracing: bool,
+    /// Whether the exports of plugins are checked against the protocol before
+    /// they are instantiated.
+    has_eager_export_resolution: bool,
+}
+
+impl ModuleContext<'_> {
+    /// Formats the statement that imports the given helpers from `memory.ts`,
+    /// together with the types that every module uses.
+    fn format_memory_imports(&self, helpers: &[&str], packed_pair_helpers: &[&str]) -> String {
+        let helpers = helpers
+            .iter()
+            .chain(
+                packed_pair_helpers
+                    .iter()
+                    .filter(|_| self.has_packed_pair_functions),
+            )
+            .chain(self.int64_encoding.helper_names())
+            .chain(
+                ["cachedExport", "clearCachedExports"]
+                    .iter()
+                    .filter(|_| self.has_cached_exports),
+            )
+            .chain(
+                ["rateLimit"]
+                    .iter()
+                    .filter(|_| self.has_rate_limited_imports),
+            )
+            .chain(
+                ["traceGuestPayload"]
+                    .iter()
+                    .filter(|_| self.has_payload_tracing),
+            )
+            .map(|helper| format!("    {helper},\n"))
+            .collect::<Vec<_>>()
+            .join("");
+        let extension = self.extension;
+        let validators = if self.validator_names.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "import {{ {} }} from \"./validation{extension}\";\n",
+                self.validator_names.join(", ")
+            )
+        };
+        let conversions = if self.conversion_function_names.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "import {{ {} }} from \"./conversions{extension}\";\n",
+                self.conversion_function_names.join(", ")
+            )
+        };
+
+        format!(
+            "import {{\n{helpers}}} from \"./memory{extension}\";
+import type {{ FatPtr, PluginInstance }} from \"./memory{extension}\";
+import type * as types from \"./types{extension}\";
+{validators}{conversions}"
+        )
+    }
+}
+
+/// Formats the banner at the top of a generated module.
+fn format_header(title: &str) -> String {
+    format!(
+        "// ============================================= //
+// {title:<46}//
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //"
+    )
+}
+
+/// Formats `memory.ts`, which contains the helpers for exchanging data with
+/// the plugin. Its contents only depend on the configuration.
+fn format_memory_module(modules: &ModuleContext, config: &TsExtendedRuntimeConfig) -> String {
+    let copy = if config.support_compression {
+        "decompress(copy)"
+    } else {
+        "copy"
+    };
+    let has_conversions = !modules.conversion_function_names.is_empty();
+
+    format!(
+        "{header}
+// deno-lint-ignore-file no-explicit-any
+
+import {{ encode, decode{extension_codec_import} }} from \"{msgpack_module}\";{conversions_import}
+
+export type FatPtr = bigint;
+
+/**
+ * Represents an unrecoverable error in the FP runtime.
+ *
+ * After this, your only recourse is to create a new runtime, probably with a different WASM plugin.
+ */
+export class FPRuntimeError extends Error {{
+    constructor(message: string) {{
+        super(message);
+    }}
+}}
+
+/**
+ * The kind of failure that is reported by a `GuestError`.
+ */
+export type GuestErrorKind =
+    | \"deserialization\"
+    | \"serialization\"
+    | \"allocation\"
+    | \"invalid_pointer\"
+    | \"internal\"
+    | \"panic\";
+
+/**
+ * Thrown when the plugin reports a failure before it aborts, which plugins do
+ * if they are built with the `panic-abort` feature of `fp-bindgen-support`.
+ */
+export class GuestError extends FPRuntimeError {{
+    kind: GuestErrorKind;
+
+    constructor(kind: GuestErrorKind, message: string) {{
+        super(`Plugin failed with ${{kind}} error: ${{message}}`);
+        this.kind = kind;
+    }}
+}}
+
+/**
+ * Thrown when the plugin fails to allocate the memory for a value that is
+ * passed to it, which it reports by returning a null pointer from its
+ * allocator.
+ */
+export class AllocationFailedError extends FPRuntimeError {{
+    len: number;
+
+    constructor(len: number) {{
+        super(`Plugin could not allocate ${{len}} bytes`);
+        this.len = len;
+    }}
+}}
+
+/**
+ * Thrown when the plugin does not export a function that is called.
+ */
+export class MissingExportError extends FPRuntimeError {{
+    exportName: string;
+
+    constructor(exportName: string) {{
+        super(`Plugin did not export expected symbol: \"${{exportName}}\"`);
+        this.exportName = exportName;
+    }}
+}}
+
+/**
+ * Thrown when the plugin exports a function with another signature than the
+ * one that is declared in the protocol.
+ */
+export class SignatureMismatchError extends FPRuntimeError {{
+    exportName: string;
+
+    constructor(exportName: string) {{
+        super(`Plugin exported symbol with an unexpected signature: \"${{exportName}}\"`);
+        this.exportName = exportName;
+    }}
+}}
+
+/**
+ * Thrown when a value that is returned by the plugin cannot be decoded.
+ */
+export class DeserializationError extends FPRuntimeError {{
+    error: unknown;
+
+    constructor(error: unknown) {{
+        super(`Could not decode value returned by plugin: ${{error}}`);
+        this.error = error;
+    }}
+}}
+
+/**
+ * Thrown when the plugin is called after it was disposed, and used to reject
+ * the promises that were still waiting for the plugin when it was disposed.
+ */
+export class PluginDisposedError extends FPRuntimeError {{
+    constructor() {{
+        super(\"Plugin was disposed\");
+    }}
+}}
+
+/**
+ * An import of the plugin that the runtime does not provide, or provides with
+ * another signature than the plugin expects.
+ */
+export type ImportDiagnostic = {{
+    module: string;
+    name: string;
+    /**
+     * The kind of import the plugin expects, together with the signature of
+     * functions, such as `function (i64) -> i64`.
+     */
+    expected: string;
+    /**
+     * The kind and signature of the import that is provided by the runtime, or
+     * `null` if it is missing.
+     */
+    provided: string | null;
+}};
+
+/**
+ * Thrown when a plugin cannot be instantiated, because it expects imports that
+ * the runtime does not provide, or provides with another signature. This
+ * happens when the plugin was compiled against another version of the protocol,
+ * or with features whose imports were not passed to the runtime.
+ */
+export class InstantiationError extends FPRuntimeError {{
+    diagnostics: ImportDiagnostic[];
+
+    constructor(diagnostics: ImportDiagnostic[]) {{
+        super(
+            `Plugin could not be instantiated:\\n${{diagnostics.map(formatImportDiagnostic).join(\"\\n\")}}`
+        );
+        this.diagnostics = diagnostics;
+    }}
+}}
+
+function formatImportDiagnostic({{ module, name, expected, provided }}: ImportDiagnostic): string {{
+    return provided === null
+        ? `- missing import \"${{module}}\".\"${{name}}\": expected ${{expected}}`
+        : `- mismatched import \"${{module}}\".\"${{name}}\": expected ${{expected}}, but got ${{provided}}`;
+}}
+
+/**
+ * Options for encoding the values that are passed to the plugin and decoding
+ * the values that are received from it, such as an `extensionCodec` with the
+ * MessagePack extensions that the plugin uses, or limits like `maxStrLength`.
+ */
+export type MsgpackOptions = NonNullable<Parameters<typeof encode>[1]> &
+    NonNullable<Parameters<typeof decode>[1]>;
+
+/**
+ * Options for instantiating a plugin.
+ */
+export type RuntimeOptions = {{
+    msgpack?: MsgpackOptions;{runtime_options_memory}{runtime_options_signature}{runtime_options_payload_tracing}
+}};
+
+/**
+ * A promise that is waiting for an async value of the plugin to be resolved.
+ */
+export type PendingPromise = {{
+    resolve: (result: FatPtr) => void;
+    reject: (error: unknown) => void;
+}};
+
+/**
+ * A plugin that was instantiated using `instantiatePlugin()`.
+ *
+ * The functions that call the exports of the plugin take the instance as their
+ * first argument, so that bundlers can drop the functions that are not used.
+ */
+export type PluginInstance = {{
+    instance: WebAssembly.Instance;
+    memory: WebAssembly.Memory;
+    malloc: (len: number) => FatPtr;
+    free: (ptr: FatPtr) => void;
+    msgpackOptions: MsgpackOptions;
+    promises: Map<FatPtr, PendingPromise | FatPtr | GuestError>;
+    dynamicImportHandlers: Map<string, Map<string, (payload: any) => any>>;
+    eventHandlers: Map<string, Set<(event: any) => void>>;
+    disposed: boolean;{plugin_instance_payload_tracing}{plugin_instance_missing_exports}
+}};
+
+export function getExport<T>(plugin: PluginInstance, name: string, arity = -1): T {{
+    if (plugin.disposed) {{
+        throw new PluginDisposedError();
+    }}
+    const exp = plugin.instance.exports[name];
+    if (!exp) {{
+        throw new MissingExportError(name);
+    }}
+    if (arity >= 0 && (typeof exp !== \"function\" || exp.length !== arity)) {{
+        throw new SignatureMismatchError(name);
+    }}
+    return exp as unknown as T;
+}}
+
+export function hasExport(plugin: PluginInstance, name: string): boolean {{
+    return !!plugin.instance.exports[name];
+}}
+
+/**
+ * An import that is declared in the import section of a WebAssembly module.
+ */
+export type ModuleImport = {{
+    module: string;
+    name: string;
+    kind: \"function\" | \"table\" | \"memory\" | \"global\" | \"tag\";
+    /**
+     * The signature of an imported function, such as `(i64, i32) -> i64`.
+     */
+    signature: string | null;
+    /**
+     * The limits of an imported table or memory.
+     */
+    limits: ImportLimits | null;
+}};
+
+/**
+ * The limits of an imported table or memory, in elements or pages
+ * respectively. Only memories can be shared.
+ */
+export type ImportLimits = {{
+    initial: number;
+    maximum: number | null;
+    shared: boolean;
+}};
+
+/**
+ * Parses the imports of a WebAssembly module, including the signatures of the
+ * imported functions, which `WebAssembly.Module.imports()` does not provide.
+ */
+export function parseModuleImports(module: ArrayBuffer): ModuleImport[] {{
+    const bytes = new Uint8Array(module);
+    let offset = 8; // Skips the magic number and the version.
+
+    const readByte = () => bytes[offset++];
+    const readUnsigned = () => {{
+        let result = 0;
+        let factor = 1;
+        let byte: number;
+        do {{
+            byte = readByte();
+            result += (byte & 0x7f) * factor;
+            factor *= 128;
+        }} while (byte & 0x80);
+        return result;
+    }};
+    const readName = () => {{
+        const len = readUnsigned();
+        const name = new TextDecoder().decode(bytes.subarray(offset, offset + len));
+        offset += len;
+        return name;
+    }};
+    const readValueTypes = () => {{
+        const types: string[] = [];
+        for (let count = readUnsigned(); count > 0; count--) {{
+            types.push(formatValueType(readByte()));
+        }}
+        return types;
+    }};
+    const readLimits = (): ImportLimits => {{
+        const flags = readByte();
+        const initial = readUnsigned();
+        const maximum = flags & 0x01 ? readUnsigned() : null;
+        return {{ initial, maximum, shared: (flags & 0x02) !== 0 }};
+    }};
+
+    const signatures: string[] = [];
+    const imports: ModuleImport[] = [];
+    while (offset < bytes.length) {{
+        const sectionId = readByte();
+        const sectionEnd = readUnsigned() + offset;
+        if (sectionId === 1) {{
+            for (let count = readUnsigned(); count > 0; count--) {{
+                readByte(); // The form of the type, which is always a function.
+                const params = readValueTypes();
+                const results = readValueTypes();
+                signatures.push(
+                    `(${{params.join(\", \")}}) -> ${{
+                        results.length === 1 ? results[0] : `(${{results.join(\", \")}})`
+                    }}`
+                );
+            }}
+        }} else if (sectionId === 2) {{
+            for (let count = readUnsigned(); count > 0; count--) {{
+                const module = readName();
+                const name = readName();
+                const kind = readByte();
+                if (kind === 0x00) {{
+                    const signature = signatures[readUnsigned()] ?? null;
+                    imports.push({{ module, name, kind: \"function\", signature, limits: null }});
+                }} else if (kind === 0x01) {{
+                    readByte();
+                    const limits = readLimits();
+                    imports.push({{ module, name, kind: \"table\", signature: null, limits }});
+                }} else if (kind === 0x02) {{
+                    const limits = readLimits();
+                    imports.push({{ module, name, kind: \"memory\", signature: null, limits }});
+                }} else if (kind === 0x03) {{
+                    readByte();
+                    readByte();
+                    imports.push({{ module, name, kind: \"global\", signature: null, limits: null }});
+                }} else {{
+                    readByte();
+                    readUnsigned();
+                    imports.push({{ module, name, kind: \"tag\", signature: null, limits: null }});
+                }}
+            }}
+
+            // The import section is followed by sections we don't need:
+            break;
+        }}
+        offset = sectionEnd;
+    }}
+    return imports;
+}}
+
+function formatValueType(type: number): string {{
+    switch (type) {{
+        case 0x7f:
+            return \"i32\";
+        case 0x7e:
+            return \"i64\";
+        case 0x7d:
+            return \"f32\";
+        case 0x7c:
+            return \"f64\";
+        case 0x7b:
+            return \"v128\";
+        case 0x70:
+            return \"funcref\";
+        case 0x6f:
+            return \"externref\";
+        default:
+            return `0x${{type.toString(16)}}`;
+    }}
+}}
+
+/**
+ * Checks whether the given imports provide everything the plugin imports, so
+ * that an `InstantiationError` that describes the missing and mismatched
+ * imports can be thrown instead of an opaque `LinkError`.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param imports The imports the plugin will be instantiated with.
+ * @param signatures The signatures of the functions in the `fp` module that the
+ *                   runtime provides, keyed by name.
+ */
+export function checkImports(
+    plugin: ArrayBuffer,
+    imports: WebAssembly.Imports,
+    signatures: Record<string, string>
+) {{
+    const diagnostics: ImportDiagnostic[] = [];
+    for (const {{ module, name, kind, signature }} of parseModuleImports(plugin)) {{
+        const expected = signature === null ? kind : `${{kind}} ${{signature}}`;
+        const value = imports[module]?.[name];
+        if (value === undefined) {{
+            diagnostics.push({{ module, name, expected, provided: null }});
+        }} else if (kind === \"function\" && typeof value !== \"function\") {{
+            diagnostics.push({{ module, name, expected, provided: typeof value }});
+        }} else if (module === \"fp\" && name in signatures && signatures[name] !== signature) {{
+            diagnostics.push({{ module, name, expected, provided: `function ${{signatures[name]}}` }});
+        }}
+    }}
+
+    if (diagnostics.length > 0) {{
+        throw new InstantiationError(diagnostics);
+    }}
+}}
+
+/**
+ * The version of the async value protocol that is supported by this runtime.
+ */
+export const ASYNC_PROTOCOL_VERSION = 2;
+
+/**
+ * The status of an async value that failed, which holds a serialized
+ * `GuestError` instead of the result.
+ */
+const FUTURE_STATUS_ERROR = 2;
+
+export function createAsyncValue(plugin: PluginInstance): FatPtr {{
+    const len = 12; // std::mem::size_of::<AsyncValue>()
+    const fatPtr = plugin.malloc(len);
+    const [ptr] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    buffer.fill(0);
+    return fatPtr;
+}}
+
+export function interpretSign(num: number, cap: number) {{
+    if (num < cap) {{
+        return num;
+    }} else {{
+        return num - (cap << 1);
+    }}
+}}
+
+export function interpretBigSign(num: bigint, cap: bigint) {{
+    if (num < cap) {{
+        return num;
+    }} else {{
+        return num - (cap << 1n);
+    }}
+}}
+
+// Views on the plugin's memory must be acquired right before they are
+// used: whenever the plugin grows its memory, `memory.buffer` is replaced
+// and existing views become detached.
+function memoryView(plugin: PluginInstance, ptr: number, len: number): Uint8Array {{
+    return new Uint8Array(plugin.memory.buffer, ptr, len);
+}}
+
+export function parseObject<T>(plugin: PluginInstance, fatPtr: FatPtr): T {{
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    // Without creating a copy of the memory, we risk corruption of any
+    // embedded `Uint8Array` objects returned from `decode()` after `free()`
+    // has been called :(
+    const copy = new Uint8Array(len);
+    copy.set(buffer);
+    plugin.free(fatPtr);
+    try {{
+        {decode}
+    }} catch (error) {{
+        throw new DeserializationError(error);
+    }}
+}}
+
+export function promiseFromPtr(plugin: PluginInstance, ptr: FatPtr): Promise<FatPtr> {{
+    const resultPtr = plugin.promises.get(ptr);
+    if (resultPtr) {{
+        if (resultPtr instanceof GuestError) {{
+            plugin.promises.delete(ptr);
+            return Promise.reject(resultPtr);
+        }}
+        if (typeof resultPtr === \"object\") {{
+            throw new FPRuntimeError(\"Already created promise for this value\");
+        }}
+
+        plugin.promises.delete(ptr);
+        return Promise.resolve(resultPtr);
+    }} else {{
+        return new Promise((resolve, reject) => {{
+            plugin.promises.set(ptr, {{ resolve, reject }});
+        }});
+    }}
+}}
+
+/**
+ * Resolves the promise for an async value of the plugin. If the plugin marked
+ * the value as failed, the result holds a serialized error instead, with which
+ * the promise is rejected as a `GuestError`.
+ */
+export function resolvePromise(plugin: PluginInstance, asyncValuePtr: FatPtr, resultPtr: FatPtr) {{
+    const pending = plugin.promises.get(asyncValuePtr);
+    if (pending && (typeof pending !== \"object\" || pending instanceof GuestError)) {{
+        throw new FPRuntimeError(\"Tried to resolve invalid promise\");
+    }}
+
+    const [ptr] = fromFatPtr(asyncValuePtr);
+    const status = new DataView(plugin.memory.buffer).getUint32(ptr, true);
+    if (status === FUTURE_STATUS_ERROR) {{
+        const {{ kind, message }} = parseObject<{{ kind: GuestErrorKind; message: string }}>(plugin, resultPtr);
+        const error = new GuestError(kind, message);
+        if (pending) {{
+            plugin.promises.delete(asyncValuePtr);
+            pending.reject(error);
+        }} else {{
+            plugin.promises.set(asyncValuePtr, error);
+        }}
+    }} else if (pending) {{
+        plugin.promises.delete(asyncValuePtr);
+        pending.resolve(resultPtr);
+    }} else {{
+        plugin.promises.set(asyncValuePtr, resultPtr);
+    }}
+}}
+
+export function resolveFuture(plugin: PluginInstance, asyncValuePtr: FatPtr, resultPtr: FatPtr) {{
+    // Async imports that complete after the plugin is disposed cannot be
+    // resumed anymore:
+    if (plugin.disposed) {{
+        return;
+    }}
+
+    const resolve = getExport<(asyncValuePtr: FatPtr, resultPtr: FatPtr) => void>(
+        plugin,
+        \"__fp_guest_resolve_async_value\"
+    );
+    resolve(asyncValuePtr, resultPtr);{notify_async_value}
+}}
+
+/**
+ * Throws the `GuestError` that the plugin reports through the reserved
+ * `__fp_error()` import.
+ */
+export function throwGuestError(plugin: PluginInstance, reportPtr: FatPtr): never {{
+    const {{ kind, message }} = parseObject<{{ kind: GuestErrorKind; message: string }}>(plugin, reportPtr);
+    throw new GuestError(kind, message);
+}}
+
+export function serializeObject<T>(plugin: PluginInstance, object: T): FatPtr {{
+    return exportToMemory(plugin, encode({serialized_object}, plugin.msgpackOptions));
+}}
+
+export function exportToMemory(plugin: PluginInstance, serialized: Uint8Array): FatPtr {{
+    const fatPtr = plugin.malloc(serialized.length);
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    buffer.set(serialized);
+    return fatPtr;
+}}
+
+export function importFromMemory(plugin: PluginInstance, fatPtr: FatPtr): Uint8Array {{
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const buffer = memoryView(plugin, ptr, len);
+    const copy = new Uint8Array(len);
+    copy.set(buffer);
+    plugin.free(fatPtr);
+    return {copy};
+}}
+
+/**
+ * Returns the handlers that are registered for the given dynamic import.
+ */
+export function getDynamicImportHandlers(
+    plugin: PluginInstance,
+    importName: string
+): Map<string, (payload: any) => any> {{
+    let handlers = plugin.dynamicImportHandlers.get(importName);
+    if (!handlers) {{
+        handlers = new Map();
+        plugin.dynamicImportHandlers.set(importName, handlers);
+    }}
+    return handlers;
+}}
+
+/**
+ * Rejects the promises that are still waiting for the plugin with a
+ * `PluginDisposedError`, frees the results that were never awaited and drops
+ * the registered handlers, so nothing keeps their closures alive. Afterwards,
+ * `getExport()` throws a `PluginDisposedError`.
+ */
+export function disposePlugin(plugin: PluginInstance) {{
+    const error = new PluginDisposedError();
+    for (const pending of plugin.promises.values()) {{
+        if (pending instanceof GuestError) {{
+            continue;
+        }} else if (typeof pending === \"object\") {{
+            pending.reject(error);
+        }} else {{
+            plugin.free(pending);
+        }}
+    }}
+    plugin.promises.clear();
+    plugin.dynamicImportHandlers.clear();
+    plugin.eventHandlers.clear();
+    plugin.disposed = true;
+}}
+
+/**
+ * Returns the handlers that are subscribed to the given event.
+ */
+export function getEventHandlers(
+    plugin: PluginInstance,
+    eventName: string
+): Set<(event: any) => void> {{
+    let handlers = plugin.eventHandlers.get(eventName);
+    if (!handlers) {{
+        handlers = new Set();
+        plugin.eventHandlers.set(eventName, handlers);
+    }}
+    return handlers;
+}}
+
+function fromFatPtr(fatPtr: FatPtr): [ptr: number, len: number] {{
+    return [
+        Number.parseInt((fatPtr >> 32n).toString()),
+        Number.parseInt((fatPtr & 0xffff_ffffn).toString()),
+    ];
+}}
+
+function toFatPtr(ptr: number, len: number): FatPtr {{
+    return (BigInt(ptr) << 32n) | BigInt(len);
+}}
+{decompress}{cached_export_functions}{rate_limit_functions}{column_extension_functions}{shared_memory_functions}{signature_functions}{payload_trace_functions}{export_resolution_functions}{packed_pair_functions}{int64_functions}",
+        header = format_header("Memory helpers for WebAssembly runtime"),
+        runtime_options_memory = if modules.has_shared_memory {
+            RUNTIME_OPTIONS_MEMORY
+        } else {
+            ""
+        },
+        runtime_options_signature = if modules.has_signature_verification {
+            RUNTIME_OPTIONS_SIGNATURE
+        } else {
+            ""
+        },
+        runtime_options_payload_tracing = if modules.has_payload_tracing {
+            RUNTIME_OPTIONS_PAYLOAD_TRACING
+        } else {
+            ""
+        },
+        plugin_instance_payload_tracing = if modules.has_payload_tracing {
+            "\n    payloadTraceOptions?: PayloadTraceOptions;"
+        } else {
+            ""
+        },
+        plugin_instance_missing_exports = if modules.has_eager_export_resolution {
+            "\n    missingExports?: string[];"
+        } else {
+            ""
+        },
+        notify_async_value = if modules.has_shared_memory {
+            "\n    notifyAsyncValue(plugin, asyncValuePtr);"
+        } else {
+            ""
+        },
+        shared_memory_functions = if modules.has_shared_memory {
+            SHARED_MEMORY_FUNCTIONS
+        } else {
+            ""
+        },
+        signature_functions = if modules.has_signature_verification {
+            SIGNATURE_FUNCTIONS
+        } else {
+            ""
+        },
+        payload_trace_functions = if modules.has_payload_tracing {
+            format!("{PAYLOAD_TRACE_TYPES}{PAYLOAD_TRACE_FUNCTIONS}")
+        } else {
+            String::new()
+        },
+        export_resolution_functions = if modules.has_eager_export_resolution {
+            EXPORT_RESOLUTION_FUNCTIONS
+        } else {
+            ""
+        },
+        decode = if config.int64_encoding.checks_safe_integers() {
+            let (decompress, bytes) = if config.support_compression {
+                ("const bytes = decompress(copy);\n        ", "bytes")
+            } else {
+                ("", "copy")
+            };
+            format!(
+                "{decompress}checkSafeIntegers({bytes});
+        return decode({bytes}, plugin.msgpackOptions) as unknown as T;"
+            )
+        } else {
+            format!("return decode({copy}, plugin.msgpackOptions) as unknown as T;")
+        },
+        msgpack_module = config.msgpack_module,
+        conversions_import = if has_conversions {
+            format!(
+                "\nimport {{ toEncodable }} from \"./conversions{}\";",
+                modules.extension
+            )
+        } else {
+            String::new()
+        },
+        serialized_object = if has_conversions {
+            "toEncodable(object)"
+        } else {
+            "object"
+        },
+        decompress = if config.support_compression {
+            DECOMPRESS_FUNCTION
+        } else {
+            ""
+        },
+        cached_export_functions = if modules.has_cached_exports {
+            CACHED_EXPORT_FUNCTIONS.replace(
+                "KEY",
+                if has_conversions {
+                    "toEncodable(key)"
+                } else {
+                    "key"
+                },
+            )
+        } else {
+            String::new()
+        },
+        rate_limit_functions = if modules.has_rate_limited_imports {
+            RATE_LIMIT_FUNCTIONS
+        } else {
+            ""
+        },
+        extension_codec_import = if modules.has_columns {
+            ", ExtensionCodec"
+        } else {
+            ""
+        },
+        column_extension_functions = if modules.has_columns {
+            COLUMN_EXTENSION_FUNCTIONS
+        } else {
+            ""
+        },
+        packed_pair_functions = if modules.has_packed_pair_functions {
+            PACKED_PAIR_FUNCTIONS
+        } else {
+            ""
+        },
+        int64_functions = if config.int64_encoding.checks_safe_integers() {
+            INT64_FUNCTIONS
+        } else {
+            ""
+        },
+    )
+}
+
+/// Formats `imports.ts`, which creates the functions that are imported by the
+/// plugin.
+fn format_imports_module(
+    modules: &ModuleContext,
+    import_decls: &[String],
+    capabilities: &BTreeSet<&str>,
+    import_wrappers: &[String],
+) -> String {
+    let (permission_denied_error, capability_param, capability_param_doc, capability_check) =
+        if capabilities.is_empty() {
+            ("", "", "", "")
+        } else {
+            (
+                PERMISSION_DENIED_ERROR,
+                ",\n    capabilities: Capability[] = []",
+                "\n * @param capabilities The capabilities that are granted to the plugin.",
+                CAPABILITY_CHECK_FUNCTION,
+            )
+        };
+
+    format!(
+        "{header}
+// deno-lint-ignore-file no-explicit-any no-unused-vars
+
+{memory_imports}
+export type Imports = {{
+{import_decls}}};
+{capability_type}{permission_denied_error}{std_imports}{http_imports}
+/**
+ * Creates the functions that are imported by the plugin, which call the given
+ * host functions.
+ *
+ * @param plugin The plugin instance for which the imports are created. It only
+ *               needs to be initialized by the time the plugin calls them.
+ * @param importFunctions The host functions that may be imported by the plugin.{capability_param_doc}
+ * @returns The functions that are imported by the plugin from the `fp` module.
+ */
+export function createImports(
+    plugin: PluginInstance,
+    importFunctions: Imports{capability_param}
+): WebAssembly.ModuleImports {{
+{capability_check}    return {{
+{import_wrappers}    }};
+}}
+",
+        header = format_header("Imports for WebAssembly runtime"),
+        memory_imports = modules.format_memory_imports(
+            &[
+                "createAsyncValue",
+                "FPRuntimeError",
+                "getDynamicImportHandlers",
+                "getEventHandlers",
+                "interpretBigSign",
+                "interpretSign",
+                "parseObject",
+                "resolveFuture",
+                "resolvePromise",
+                "serializeObject",
+                "throwGuestError",
+            ],
+            &["f32ToBits", "packPair"],
+        ),
+        import_decls = join_lines(import_decls, |line| format!("    {line};")),
+        capability_type = format_capability_type(capabilities),
+        std_imports = if modules.has_std_imports {
+            format!("{STD_IMPORTS_TYPE}{STD_IMPORTS_FUNCTIONS}")
+        } else {
+            String::new()
+        },
+        http_imports = if modules.has_http_imports {
+            format!("{HTTP_IMPORTS_TYPE}{HTTP_IMPORTS_FUNCTIONS}")
+        } else {
+            String::new()
+        },
+        capability_check = if capability_check.is_empty() {
+            String::new()
+        } else {
+            format!("{capability_check}\n")
+        },
+        import_wrappers = join_lines(import_wrappers, |line| format!("        {line}")),
+    )
+}
+
+/// Formats `exports.ts`, which contains a function for every export of the
+/// plugin, as well as `createExports()` to wrap them all at once.
+fn format_exports_module(
+    modules: &ModuleContext,
+    events_type: &str,
+    export_decls: &[String],
+    raw_export_decls: &[String],
+    export_functions: &[ExportFunction],
+) -> String {
+    let entries = format_export_entries(export_functions);
+    let functions = format_export_function_definitions(export_functions);
+
+    format!(
+        "{header}
+// deno-lint-ignore-file no-explicit-any no-unused-vars
+
+{memory_imports}
+/**
+ * Statistics about the memory that is allocated by a plugin.
+ */
+export type MemoryStats = {{
+    allocatedBytes: number;
+    peakAllocatedBytes: number;
+    allocationCount: number;
+}};
+
+/**
+ * Metrics about the calls that a plugin has made to the imports of the
+ * runtime, as measured by the plugin itself.
+ */
+export type GuestMetrics = {{
+    imports: Record<string, ImportMetrics>;
+}};
+
+/**
+ * Metrics about the calls that a plugin has made to a single import.
+ * Durations are in nanoseconds.
+ */
+export type ImportMetrics = {{
+    callCount: number;
+    totalDurationNanos: number;
+    maxDurationNanos: number;
+}};
+{events_type}
+export type Exports = {{
+{export_decls}{raw_export_decls}}};
+
+/**
+ * The name of a function that is exported by the plugin.
+ */
+export type ExportName = keyof Exports;
+{PLUGIN_ERROR_KIND}
+/**
+ * Thrown when calling an export of the plugin fails. The error that caused
+ * the failure is available as `error`.
+ */
+export class PluginError extends FPRuntimeError {{
+    kind: PluginErrorKind;
+    exportName: ExportName;
+    typeName: string | undefined;
+    error: unknown;
+
+    constructor(kind: PluginErrorKind, exportName: ExportName, error: unknown, typeName: string | undefined) {{
+        super(`Export \"${{exportName}}\" failed with ${{kind}} error: ${{error instanceof Error ? error.message : error}}`);
+        this.kind = kind;
+        this.exportName = exportName;
+        this.typeName = typeName;
+        this.error = error;
+    }}
+}}
+
+/**
+ * Calls an export of the plugin, and turns the errors it throws into a
+ * `PluginError`. The `typeName` is the type of the value that is returned by
+ * the export, if any.
+ */
+function callExport<T>(exportName: ExportName, typeName: string | undefined, call: () => T): T {{
+    try {{
+        const result = call();
+        if (result instanceof Promise) {{
+            return result.catch((error) => {{
+                throw toPluginError(exportName, typeName, error);
+            }}) as T;
+        }}
+        return result;
+    }} catch (error) {{
+        throw toPluginError(exportName, typeName, error);
+    }}
+}}
+
+function toPluginError(exportName: ExportName, typeName: string | undefined, error: unknown): PluginError {{
+    if (error instanceof PluginError) {{
+        return error;
+    }} else if (error instanceof MissingExportError) {{
+        return new PluginError(\"missing_export\", exportName, error, undefined);
+    }} else if (error instanceof SignatureMismatchError) {{
+        return new PluginError(\"signature_mismatch\", exportName, error, undefined);
+    }} else if (error instanceof PluginDisposedError) {{
+        return new PluginError(\"disposed\", exportName, error, undefined);
+    }} else if (error instanceof GuestError) {{
+        return new PluginError(\"guest_panic\", exportName, error, undefined);
+    }} else if (error instanceof DeserializationError) {{
+        return new PluginError(\"deserialization\", exportName, error, typeName);
+    }} else if (error instanceof WebAssembly.RuntimeError) {{
+        return new PluginError(\"guest_trap\", exportName, error, undefined);
+    }} else {{
+        return new PluginError(\"transport\", exportName, error, undefined);
+    }}
+}}
+
+/**
+ * Returns the functions that are exported by the given plugin instance.
+ *
+ * Functions that the plugin does not implement are `undefined`. Hosts that
+ * only call some of the exports may prefer to call the functions below
+ * directly, so that the others can be dropped from their bundle.
+ */
+export function createExports(plugin: PluginInstance): Exports {{
+    return {{
+{entries}    }};
+}}
+{functions}",
+        header = format_header("Exports for WebAssembly runtime"),
+        memory_imports = modules.format_memory_imports(
+            &[
+                "DeserializationError",
+                "FPRuntimeError",
+                "GuestError",
+                "MissingExportError",
+                "PluginDisposedError",
+                "SignatureMismatchError",
+                "disposePlugin",
+                "exportToMemory",
+                "getDynamicImportHandlers",
+                "getEventHandlers",
+                "getExport",
+                "hasExport",
+                "importFromMemory",
+                "interpretBigSign",
+                "interpretSign",
+                "parseObject",
+                "promiseFromPtr",
+                "serializeObject",
+            ],
+            &["f32FromBits", "unpackPair"],
+        ),
+        export_decls = join_lines(export_decls, |line| format!("    {line};")),
+        raw_export_decls = join_lines(raw_export_decls, |line| format!("    {line};")),
+        entries = join_lines(&entries, |line| format!("        {line}")),
+    )
+}
+
+/// Formats the lines of the object that binds the given functions to a plugin
+/// instance.
+fn format_export_entries(export_functions: &[ExportFunction]) -> Vec<String> {
+    export_functions
+        .iter()
+        .flat_map(|function| {
+            function
+                .format_entry()
+                .split('\n')
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn format_export_function_definitions(export_functions: &[ExportFunction]) -> String {
+    export_functions
+        .iter()
+        .map(|function| {
+            format!(
+                "\n{}export function {} {{\n{}}}\n",
+                join_lines(&function.docs, String::to_owned),
+                function.format_signature(),
+                join_lines(&function.body, |line| format!("    {line}"))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// The functions that are behind a single feature, which are generated in a
+/// module of their own.
+struct FeatureModule<'a> {
+    feature: &'a str,
+    import_decls: Vec<String>,
+    import_wrappers: Vec<String>,
+    export_decls: Vec<String>,
+    raw_export_decls: Vec<String>,
+    export_functions: Vec<ExportFunction>,
+}
+
+impl FeatureModule<'_> {
+    /// Formats the module, which contains the imports and exports of the
+    /// feature. Imports are passed to `instantiatePlugin()`, while exports
+    /// are called on the instance like the exports in `exports.ts`.
+    fn format_module(&self, modules: &ModuleContext) -> String {
+        let feature = self.feature;
+        let prefix = feature.to_pascal_case();
+        let extension = modules.extension;
+
+        let (feature_imports_type, imports) = if self.import_decls.is_empty() {
+            (String::new(), String::new())
+        } else {
+            (
+                format!("import type {{ FeatureImports }} from \"./index{extension}\";\n"),
+                format!(
+                    "
+export type {prefix}Imports = {{
+{import_decls}}};
+
+/**
+ * Creates the functions that are imported by plugins that were compiled with
+ * the `{feature}` feature, which call the given host functions.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @returns The imports to pass to `instantiatePlugin()` or `createRuntime()`.
+ */
+export function create{prefix}Imports(importFunctions: {prefix}Imports): FeatureImports {{
+    return (plugin) => ({{
+{import_wrappers}    }});
+}}
+",
+                    import_decls = join_lines(&self.import_decls, |line| format!("    {line};")),
+                    import_wrappers =
+                        join_lines(&self.import_wrappers, |line| format!("        {line}")),
+                ),
+            )
+        };
+        let exports = if self.export_functions.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "
+export type {prefix}Exports = {{
+{export_decls}{raw_export_decls}}};
+
+/**
+ * Returns the functions that are exported by the given plugin instance, if it
+ * was compiled with the `{feature}` feature.
+ *
+ * Functions that the plugin does not implement are `undefined`.
+ */
+export function create{prefix}Exports(plugin: PluginInstance): {prefix}Exports {{
+    return {{
+{entries}    }};
+}}
+{functions}",
+                export_decls = join_lines(&self.export_decls, |line| format!("    {line};")),
+                raw_export_decls =
+                    join_lines(&self.raw_export_decls, |line| format!("    {line};")),
+                entries = join_lines(&format_export_entries(&self.export_functions), |line| {
+                    format!("        {line}")
+                }),
+                functions = format_export_function_definitions(&self.export_functions),
+            )
+        };
+
+        format!(
+            "{header}
+// deno-lint-ignore-file no-explicit-any no-unused-vars
+
+{memory_imports}{feature_imports_type}{imports}{exports}",
+            header = format_header(&format!("Feature `{feature}`")),
+            memory_imports = modules.format_memory_imports(
+                &[
+                    "createAsyncValue",
+                    "exportToMemory",
+                    "FPRuntimeError",
+                    "getExport",
+                    "hasExport",
+                    "importFromMemory",
+                    "interpretBigSign",
+                    "interpretSign",
+                    "parseObject",
+                    "promiseFromPtr",
+                    "resolveFuture",
+                    "serializeObject",
+                ],
+                &["f32FromBits", "f32ToBits", "packPair", "unpackPair"],
+            ),
+        )
+    }
+
+    /// Formats the declarations that accompany the module if the runtime is
+    /// generated as JavaScript.
+    fn format_declarations(&self) -> String {
+        let feature = self.feature;
+        let prefix = feature.to_pascal_case();
+
+        let imports = if self.import_decls.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "
+export type {prefix}Imports = {{
+{}}};
+
+/**
+ * Creates the functions that are imported by plugins that were compiled with
+ * the `{feature}` feature, which call the given host functions.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @returns The imports to pass to `instantiatePlugin()` or `createRuntime()`.
+ */
+export declare function create{prefix}Imports(importFunctions: {prefix}Imports): FeatureImports;
+",
+                join_lines(&self.import_decls, |line| format!("    {line};")),
+            )
+        };
+        let exports = if self.export_functions.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "
+export type {prefix}Exports = {{
+{}{}}};
+
+/**
+ * Returns the functions that are exported by the given plugin instance, if it
+ * was compiled with the `{feature}` feature.
+ *
+ * Functions that the plugin does not implement are `undefined`.
+ */
+export declare function create{prefix}Exports(plugin: PluginInstance): {prefix}Exports;
+{}",
+                join_lines(&self.export_decls, |line| format!("    {line};")),
+                join_lines(&self.raw_export_decls, |line| format!("    {line};")),
+                self.export_functions
+                    .iter()
+                    .map(|function| {
+                        format!(
+                            "\n{}export declare function {};\n",
+                            join_lines(&function.docs, String::to_owned),
+                            function.format_signature()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(""),
+            )
+        };
+
+        let mut index_types = Vec::new();
+        if !self.import_decls.is_empty() {
+            index_types.push("FeatureImports");
+        }
+        if !self.export_functions.is_empty() {
+            index_types.push("PluginInstance");
+        }
+        let index_types = index_types.join(", ");
+
+        format!(
+            "{header}
+
+import type * as types from \"./types.js\";
+import type {{ {index_types} }} from \"./index.js\";
+{imports}{exports}",
+            header = format_header(&format!("Feature `{feature}`")),
+        )
+    }
+}
+
+/// Formats `validation.ts`, which validates the values that are received from
+/// the plugin.
+fn format_validation_module(modules: &ModuleContext, validators: &str) -> String {
+    let extension = modules.extension;
+    format!(
+        "{header}
+// deno-lint-ignore-file no-explicit-any
+
+import {{ FPRuntimeError }} from \"./memory{extension}\";
+import type * as types from \"./types{extension}\";
+{VALIDATION_ERROR}{validators}",
+        header = format_header("Validation for WebAssembly runtime"),
+    )
+}
+
+/// The `init` option of `createRuntime()`, with which the `init` export of the
+/// plugin is called once it has been instantiated.
+struct InitOption {
+    /// The type of the option, which is the type of the config that is passed
+    /// to `init`, or a flag if `init` doesn't take a config.
+    ty: String,
+    docs: &'static str,
+    call: String,
+}
+
+impl InitOption {
+    fn new(
+        init: &Function,
+        types: &TypeMap,
+        casing: &TsIdentifierCasing,
+        int64: TsInt64Encoding,
+    ) -> Self {
+        let name = casing.format_name(&init.name);
+        match init.args.first() {
+            Some(arg) => Self {
+                ty: format_plain_primitive_or_ident(&arg.ty, types, int64),
+                docs: "The config with which the `init` export of the plugin is called, once
+     * it has been instantiated. Plugins that don't implement `init` are not
+     * initialized.",
+                call: format!(
+                    "if (options.init !== undefined) {{\n        exports.{name}?.(options.init);\n    }}"
+                ),
+            },
+            None => Self {
+                ty: "boolean".to_owned(),
+                docs: "Whether the `init` export of the plugin is called, once it has been
+     * instantiated. Plugins that don't implement `init` are not initialized.",
+                call: format!("if (options.init) {{\n        exports.{name}?.();\n    }}"),
+            },
+        }
+    }
+
+    /// Formats the `CreateRuntimeOptions` type, which adds the `init` option
+    /// to the `RuntimeOptions`.
+    fn format_options_type(&self) -> String {
+        format!(
+            "/**
+ * Options for `createRuntime()`.
+ */
+export type CreateRuntimeOptions = RuntimeOptions & {{
+    /**
+     * {}
+     */
+    init?: {};
+}};
+",
+            self.docs, self.ty
+        )
+    }
+}
+
+const CREATE_RUNTIME_DOC: &str = "
+ * Call `dispose()` on the returned exports when the plugin is no longer
+ * needed, so that it can clean up through its `shutdown` export, and pending
+ * promises do not keep their callbacks alive.";
+
+const CREATE_RUNTIME_OPTIONS_PARAM_DOC: &str = "
+ * @param options Options for the runtime, such as the options of the MessagePack
+ *                encoder and decoder, and the config with which the plugin is
+ *                initialized.";
+
+/// Formats `index.ts`, which re-exports the other modules and provides the
+/// functions for instantiating the plugin.
+fn format_index_module(
+    modules: &ModuleContext,
+    protocol: &str,
+    import_signatures: &str,
+    export_signatures: &str,
+) -> String {
+    let extension = modules.extension;
+    let init = modules.init;
+    let types_import = if init.ty.starts_with("types.") {
+        format!("import type * as types from \"./types{extension}\";\n")
+    } else {
+        String::new()
+    };
+    let create_runtime_options_type = init.format_options_type();
+    let init_call = &init.call;
+    let (capability_import, capability_param, capability_param_doc, capability_arg) =
+        if modules.has_capabilities {
+            (
+                "Capability, ",
+                ",\n    capabilities: Capability[] = []",
+                "\n * @param capabilities The capabilities that are granted to the plugin.",
+                ", capabilities",
+            )
+        } else {
+            ("", "", "", "")
+        };
+    let (column_extensions_import, msgpack_options) = if modules.has_columns {
+        (
+            ", withColumnExtensions",
+            "withColumnExtensions(options.msgpack ?? {})",
+        )
+    } else {
+        ("", "options.msgpack ?? {}")
+    };
+    // Plugins that are compiled with Wasm threads import their memory, which
+    // the runtime then shares with them instead:
+    let (shared_memory_import, shared_memory, env_import, memory) = if modules.has_shared_memory {
+        (
+            ", createSharedMemory",
+            "\n    const memory = options.memory ?? createSharedMemory(plugin);",
+            "\n        env: { memory },",
+            "memory",
+        )
+    } else {
+        (
+            "",
+            "",
+            "",
+            "getExport<WebAssembly.Memory>(pluginInstance, \"memory\")",
+        )
+    };
+    let validation_export = if modules.validator_names.is_empty() {
+        String::new()
+    } else {
+        format!("export {{ ValidationError }} from \"./validation{extension}\";\n")
+    };
+    let (signature_import, signature_export, verify_signature) =
+        if modules.has_signature_verification {
+            (
+                ", verifySignature",
+                format!(
+                    "export {{ InvalidSignatureError }} from \"./memory{extension}\";
+export type {{ SignatureVerifier }} from \"./memory{extension}\";\n"
+                ),
+                "
+    if (options.signatureVerifier !== undefined) {
+        await verifySignature(plugin, options.signatureVerifier);
+    }",
+            )
+        } else {
+            ("", String::new(), "")
+        };
+    let (payload_trace_import, payload_trace_export, payload_trace_options) =
+        if modules.has_payload_tracing {
+            (
+                ", getPayloadTraceOptions",
+                format!(
+                    "export type {{
+    PayloadDirection,
+    PayloadFormat,
+    PayloadTrace,
+    PayloadTraceOptions,
+}} from \"./memory{extension}\";\n"
+                ),
+                "\n        payloadTraceOptions: getPayloadTraceOptions(options.tracePayloads),",
+            )
+        } else {
+            ("", String::new(), "")
+        };
+    let (check_exports_import, check_exports, export_signatures) =
+        if modules.has_eager_export_resolution {
+            (
+                ", checkExports",
+                "\n    pluginInstance.missingExports = checkExports(plugin, EXPORT_SIGNATURES);",
+                format!(
+                    "
+/**
+ * The symbols and signatures of the functions that the plugin may export, which
+ * are checked against the exports of the plugin before it is instantiated.
+ */
+const EXPORT_SIGNATURES: Record<string, [string, string]> = {{
+{export_signatures}
+}};
+"
+                ),
+            )
+        } else {
+            ("", "", String::new())
+        };
+    let (feature_imports_type, feature_param, feature_param_doc, feature_arg, import_object) =
+        if modules.has_feature_imports {
+            (
+                FEATURE_IMPORTS_TYPE,
+                ",\n    featureImports: FeatureImports[] = []",
+                FEATURE_IMPORTS_PARAM_DOC,
+                ", featureImports",
+                format!(
+                    "Object.assign(
+            createImports(pluginInstance, importFunctions{capability_arg}),
+            ...featureImports.map((createFeatureImports) => createFeatureImports(pluginInstance)),
+        )"
+                ),
+            )
+        } else {
+            (
+                "",
+                "",
+                "",
+                "",
+                format!("createImports(pluginInstance, importFunctions{capability_arg})"),
+            )
+        };
+
+    format!(
+        "{header}
+// deno-lint-ignore-file no-explicit-any
+
+import {{ createExports }} from \"./exports{extension}\";
+import type {{ Exports }} from \"./exports{extension}\";
+import {{ createImports }} from \"./imports{extension}\";
+import type {{ {capability_import}Imports }} from \"./imports{extension}\";
+import {{ ASYNC_PROTOCOL_VERSION, AllocationFailedError, checkImports{check_exports_import}{shared_memory_import}, getExport{column_extensions_import}{signature_import}{payload_trace_import} }} from \"./memory{extension}\";
+import type {{ FatPtr, PluginInstance, RuntimeOptions }} from \"./memory{extension}\";
+{types_import}
+export * from \"./exports{extension}\";
+export * from \"./imports{extension}\";
+export {{
+    AllocationFailedError,
+    DeserializationError,
+    FPRuntimeError,
+    GuestError,
+    InstantiationError,
+    MissingExportError,
+    PluginDisposedError,
+    SignatureMismatchError,
+}} from \"./memory{extension}\";
+export type {{
+    FatPtr,
+    GuestErrorKind,
+    ImportDiagnostic,
+    MsgpackOptions,
+    PendingPromise,
+    PluginInstance,
+    RuntimeOptions,
+}} from \"./memory{extension}\";
+{validation_export}{signature_export}{payload_trace_export}{PROTOCOL_TYPES}{feature_imports_type}
+/**
+ * Instantiates the given plugin, without wrapping its exports.
+ *
+ * The returned instance can be passed to the functions that call the exports
+ * of the plugin. Unlike `createRuntime()`, this allows bundlers to drop the
+ * functions that are not used.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importFunctions The host functions that may be imported by the plugin.{capability_param_doc}{feature_param_doc}{OPTIONS_PARAM_DOC}
+ * @returns The instantiated plugin.
+ */
+export async function instantiatePlugin(
+    plugin: ArrayBuffer,
+    importFunctions: Imports{capability_param}{feature_param},
+    options: RuntimeOptions = {{}}
+): Promise<PluginInstance> {{
+    const pluginInstance = {{
+        msgpackOptions: {msgpack_options},
+        promises: new Map(),
+        dynamicImportHandlers: new Map(),
+        eventHandlers: new Map(),
+        disposed: false,{payload_trace_options}
+    }} as PluginInstance;{verify_signature}{shared_memory}
+    const imports = {{{env_import}
+        fp: {import_object},
+    }};
+    checkImports(plugin, imports, IMPORT_SIGNATURES);{check_exports}
+    const {{ instance }} = await WebAssembly.instantiate(plugin, imports);
+
+    pluginInstance.instance = instance;
+    pluginInstance.memory = {memory};
+    const malloc = getExport<(len: number) => FatPtr>(pluginInstance, \"__fp_malloc\");
+    pluginInstance.malloc = (len: number) => {{
+        const fatPtr = malloc(len);
+        if (fatPtr === 0n && len !== 0) {{
+            throw new AllocationFailedError(len);
+        }}
+        return fatPtr;
+    }};
+    pluginInstance.free = getExport<(ptr: FatPtr) => void>(pluginInstance, \"__fp_free\");
+
+    // Plugins that support version 2 of the async value protocol can reject
+    // async values with an error, once the host has told them it supports it:
+    const negotiateAsyncProtocol = instance.exports.__fp_guest_async_protocol as
+        | ((hostVersion: number) => number)
+        | undefined;
+    if (negotiateAsyncProtocol) {{
+        negotiateAsyncProtocol(ASYNC_PROTOCOL_VERSION);
+    }}
+    return pluginInstance;
+}}
+
+{create_runtime_options_type}
+/**
+ * Creates a runtime for executing the given plugin.
+ *{CREATE_RUNTIME_DOC}
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importFunctions The host functions that may be imported by the plugin.{capability_param_doc}{feature_param_doc}{CREATE_RUNTIME_OPTIONS_PARAM_DOC}
+ * @returns The functions that may be exported by the plugin.
+ */
+export async function createRuntime(
+    plugin: ArrayBuffer,
+    importFunctions: Imports{capability_param}{feature_param},
+    options: CreateRuntimeOptions = {{}}
+): Promise<Exports> {{
+    const exports = createExports(
+        await instantiatePlugin(plugin, importFunctions{capability_arg}{feature_arg}, options)
+    );
+    {init_call}
+    return exports;
+}}
+{PROTOCOL_DOC}
+export function protocol(): Protocol {{
+    return JSON.parse(PROTOCOL);
+}}
+
+const PROTOCOL = {protocol};
+
+/**
+ * The signatures of the functions that the runtime provides to the plugin,
+ * which are checked against the imports of the plugin before it is
+ * instantiated.
+ */
+const IMPORT_SIGNATURES: Record<string, string> = {{
+{import_signatures}
+}};
+{export_signatures}",
+        header = format_header("WebAssembly runtime for TypeScript"),
+    )
+}
+
+const FEATURE_IMPORTS_TYPE: &str = r#"
+/**
+ * Creates the imports of a feature for the given plugin instance. These are
+ * returned by the `create*Imports()` function of the module of the feature.
+ */
+export type FeatureImports = (plugin: PluginInstance) => WebAssembly.ModuleImports;
+"#;
+
+const FEATURE_IMPORTS_PARAM_DOC: &str = "
+ * @param featureImports The imports of the features the plugin was compiled with.";
+
+const OPTIONS_PARAM_DOC: &str = "
+ * @param options Options for the runtime, such as the options of the MessagePack
+ *                encoder and decoder.";
+
+/// Types that describe the JSON that is returned by `protocol()`, which uses
+/// the same format as `fp_bindgen::Protocol`. Type definitions are left
+/// untyped, since their format mirrors the generator's internal representation.
+const PROTOCOL_TYPES: &str = r#"
+/**
+ * A machine-readable description of the protocol, as returned by `protocol()`.
+ */
+export type Protocol = {
+    import_functions: ProtocolFunction[];
+    export_functions: ProtocolFunction[];
+    /**
+     * Pairs of type identifiers and the definitions of the types they refer to.
+     */
+    types: Array<[ProtocolTypeIdent, unknown]>;
+};
+
+/**
+ * Describes the signature of an imported or exported function.
+ */
+export type ProtocolFunction = {
+    name: string;
+    doc_lines: string[];
+    args: Array<{ name: string; ty: ProtocolTypeIdent }>;
+    return_type: ProtocolTypeIdent | null;
+    is_async: boolean;
+    attrs: Record<string, unknown>;
+};
+
+/**
+ * Identifies a type by its Rust name, along with its generic arguments.
+ */
+export type ProtocolTypeIdent = {
+    name: string;
+    generic_args: Array<[ProtocolTypeIdent, string[]]>;
+    array: number | null;
+};
+"#;
+
+const PROTOCOL_DOC: &str = r#"
+/**
+ * Returns a machine-readable description of the protocol that this runtime was
+ * generated for, with the signatures of all imported and exported functions and
+ * the types they use.
+ *
+ * Note that plugins may omit exports, so not every export that is described
+ * is necessarily returned by `createRuntime()`.
+ */"#;
+
+/// Formats the `Capability` type with the capabilities that can be granted to
+/// the plugin, if the protocol declares any.
+/// Formats the entries of the object that maps the functions that the runtime
+/// provides to the plugin to their signatures in WebAssembly.
+fn format_import_signatures(import_functions: &FunctionList) -> String {
+    import_functions
+        .iter()
+        .map(|function| {
+            format!(
+                "    {}: \"{}\",",
+                function.symbol_name(),
+                format_wasm_signature(function, FunctionType::Import)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats the entries of the object that maps the functions that the plugin
+/// may export to their symbols and signatures in WebAssembly.
+fn format_export_signatures(
+    export_functions: &FunctionList,
+    casing: &TsIdentifierCasing,
+) -> String {
+    export_functions
+        .iter()
+        .map(|function| {
+            format!(
+                "    {}: [\"{}\", \"{}\"],",
+                casing.format_name(&function.name),
+                function.symbol_name(),
+                format_wasm_signature(function, FunctionType::Export)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats the signature of a function the way it is declared in the import
+/// or export section of the plugin, such as `(i64, i32) -> i64`.
+fn format_wasm_signature(function: &Function, function_type: FunctionType) -> String {
+    let args = function
+        .args
+        .iter()
+        .map(|arg| format_wasm_type(&arg.ty))
+        .collect::<Vec<_>>();
+    // Async exports always return an async value, while async imports without
+    // a return value are fire-and-forget:
+    let result = if matches!(function_type, FunctionType::Import) && function.is_fire_and_forget() {
+        "()"
+    } else if function.is_async {
+        "i64"
+    } else {
+        match &function.return_type {
+            Some(ty) if ty.is_packed_pair() => "i64",
+            Some(ty) => format_wasm_type(ty),
+            None => "()",
+        }
+    };
+    format!("({}) -> {result}", args.join(", "))
+}
+
+/// Primitives are passed as the WebAssembly type of their ABI type, while all
+/// other values are passed as fat pointers.
+fn format_wasm_type(ty: &TypeIdent) -> &'static str {
+    match ty.as_primitive() {
+        Some(Primitive::F32) => "f32",
+        Some(Primitive::F64) => "f64",
+        Some(Primitive::I64 | Primitive::U64) => "i64",
+        Some(_) => "i32",
+        None => "i64",
+    }
+}
+
+fn format_capability_type(capabilities: &BTreeSet<&str>) -> String {
+    if capabilities.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        "
+/**
+ * A capability that can be granted to the plugin. Imports that require a
+ * capability can only be called by the plugin if it was granted.
+ */
+export type Capability = {};
+",
+        capabilities
+            .iter()
+            .map(|capability| format!("\"{capability}\""))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    )
+}
+
+const PLUGIN_ERROR_KIND: &str = r#"
+/**
+ * The kind of failure that is reported by a `PluginError`.
+ *
+ * Failures that are not caused by the plugin itself, such as imports that are
+ * denied or arguments that the plugin cannot allocate, are reported as
+ * `"transport"` errors.
+ */
+export type PluginErrorKind =
+    | "missing_export"
+    | "signature_mismatch"
+    | "guest_trap"
+    | "guest_panic"
+    | "deserialization"
+    | "disposed"
+    | "transport";
+"#;
+
+const PERMISSION_DENIED_ERROR: &str = r#"
+/**
+ * Thrown when the plugin calls an import that requires a capability that was
+ * not granted to it.
+ */
+export class PermissionDeniedError extends FPRuntimeError {
+    functionName: string;
+    capability: Capability;
+
+    constructor(functionName: string, capability: Capability) {
+        super(`Import "${functionName}" requires the "${capability}" capability, which was not granted`);
+        this.functionName = functionName;
+        this.capability = capability;
+    }
+}
+"#;
+
+const STD_IMPORTS_TYPE: &str = r#"
+/**
+ * The host facilities that are exposed to the plugin through the built-in std
+ * imports, which require the "fs", "clock" and "random" capabilities.
+ *
+ * Files are read from and written to the `files` map, keyed by their path. If
+ * no map is given, the plugin cannot access any files. By default, the clock
+ * uses `Date.now()`, the monotonic clock uses `performance.now()` and random
+ * bytes are generated using `crypto.getRandomValues()`. Both clocks return
+ * milliseconds.
+ */
+export type StdImports = {
+    files?: Map<string, Uint8Array>;
+    now?: () => number;
+    monotonicNow?: () => number;
+    randomBytes?: (len: number) => Uint8Array;
+};
+"#;
+
+/// Helpers for the built-in std imports. Results are encoded the way Serde
+/// encodes a `Result<T, FileError>`.
+const STD_IMPORTS_FUNCTIONS: &str = r#"
+type StdFileError = { type: "invalid_path" | "not_found" | "unsupported" };
+
+function checkStdPath(files: Map<string, Uint8Array> | undefined, path: string): StdFileError | undefined {
+    if (!files) {
+        return { type: "unsupported" };
+    }
+    // Only relative paths without `.` and `..` components are accepted, like
+    // in the Rust runtime:
+    if (path.split("/").some((component) => component === "" || component === "." || component === "..")) {
+        return { type: "invalid_path" };
+    }
+    return undefined;
+}
+
+function readStdFile(files: Map<string, Uint8Array> | undefined, path: string): any {
+    const error = checkStdPath(files, path);
+    if (error) {
+        return { Err: error };
+    }
+    const contents = files?.get(path);
+    return contents ? { Ok: contents } : { Err: { type: "not_found" } };
+}
+
+function writeStdFile(files: Map<string, Uint8Array> | undefined, path: string, contents: Uint8Array): any {
+    const error = checkStdPath(files, path);
+    if (error) {
+        return { Err: error };
+    }
+    files?.set(path, contents);
+    return { Ok: null };
+}
+
+function randomStdBytes(len: number): Uint8Array {
+    const bytes = new Uint8Array(len);
+    // `getRandomValues()` fills at most 65536 bytes at a time:
+    for (let offset = 0; offset < len; offset += 65536) {
+        crypto.getRandomValues(bytes.subarray(offset, offset + 65536));
+    }
+    return bytes;
+}
+"#;
+
+/// The wrapper of the import behind the `println!()` and `eprintln!()` macros
+/// of `fp-bindgen-support`, which passes the printed lines to `onPrint`.
+const STDIO_IMPORT_WRAPPER: &str = r#"__fp_host_print: (stream: number, line_ptr: FatPtr) => {
+    const line = parseObject<string>(plugin, line_ptr);
+    if (importFunctions.onPrint) {
+        importFunctions.onPrint(stream === 2 ? "stderr" : "stdout", line);
+    } else if (stream === 2) {
+        console.error(line);
+    } else {
+        console.log(line);
+    }
+},"#;
+
+/// The wrappers of the built-in std imports, which are imported by the plugin
+/// with an `__fp_std_` prefix.
+const STD_IMPORT_WRAPPERS: &str = r#"__fp_std_read_file: (path_ptr: FatPtr): FatPtr => {
+    checkCapability("read_file", "fs");
+    const path = parseObject<string>(plugin, path_ptr);
+    return serializeObject(plugin, readStdFile(importFunctions.std?.files, path));
+},
+__fp_std_write_file: (path_ptr: FatPtr, contents_ptr: FatPtr): FatPtr => {
+    checkCapability("write_file", "fs");
+    const path = parseObject<string>(plugin, path_ptr);
+    const contents = parseObject<Uint8Array>(plugin, contents_ptr);
+    return serializeObject(plugin, writeStdFile(importFunctions.std?.files, path, contents));
+},
+__fp_std_now: (): bigint => {
+    checkCapability("now", "clock");
+    return BigInt(Math.floor(importFunctions.std?.now?.() ?? Date.now()));
+},
+__fp_std_monotonic_now: (): bigint => {
+    checkCapability("monotonic_now", "clock");
+    const millis = importFunctions.std?.monotonicNow?.() ?? performance.now();
+    return BigInt(Math.floor(millis * 1_000_000));
+},
+__fp_std_random_bytes: (len: number): FatPtr => {
+    checkCapability("random_bytes", "random");
+    const randomBytes = importFunctions.std?.randomBytes ?? randomStdBytes;
+    return serializeObject(plugin, randomBytes(len));
+},"#;
+
+const HTTP_IMPORTS_TYPE: &str = r#"
+/**
+ * A request that the plugin makes through the built-in HTTP imports, which
+ * require the "http" capability. Headers are name-value pairs.
+ */
+export type HttpRequest = {
+    method: string;
+    url: string;
+    headers: Array<[string, string]>;
+    body?: Uint8Array;
+};
+
+/**
+ * The response to a request that the plugin made through the built-in HTTP
+ * imports.
+ */
+export type HttpResponse = {
+    status: number;
+    headers: Array<[string, string]>;
+    body: Uint8Array;
+};
+
+/**
+ * The host facilities that are exposed to the plugin through the built-in
+ * HTTP imports.
+ *
+ * By default, requests are sent using the global `fetch()`. Hosts can pass
+ * their own implementation, for instance to restrict the URLs the plugin may
+ * access, or to mock requests in tests.
+ */
+export type HttpImports = {
+    fetch?: typeof fetch;
+};
+"#;
+
+/// Helper for the built-in HTTP imports. The result is encoded the way Serde
+/// encodes a `Result<HttpResponse, HttpError>`, and the returned promise never
+/// rejects.
+const HTTP_IMPORTS_FUNCTIONS: &str = r#"
+async function sendHttpRequest(fetchImpl: typeof fetch, request: HttpRequest): Promise<any> {
+    let fetchRequest;
+    try {
+        fetchRequest = new Request(request.url, {
+            method: request.method,
+            headers: request.headers,
+            body: request.body,
+        });
+    } catch (error) {
+        return { Err: { type: "invalid_request", message: String(error) } };
+    }
+    try {
+        const response = await fetchImpl(fetchRequest);
+        const body = new Uint8Array(await response.arrayBuffer());
+        return { Ok: { status: response.status, headers: [...response.headers], body } };
+    } catch (error) {
+        if (error instanceof DOMException && error.name === "TimeoutError") {
+            return { Err: { type: "timeout" } };
+        }
+        const type = error instanceof TypeError ? "connection" : "other";
+        return { Err: { type, message: String(error) } };
+    }
+}
+"#;
+
+/// The wrapper of the built-in HTTP import, which is imported by the plugin as
+/// `__fp_http_request`.
+const HTTP_IMPORT_WRAPPER: &str = r#"__fp_http_request: (request_ptr: FatPtr): FatPtr => {
+    checkCapability("http_request", "http");
+    const request = parseObject<HttpRequest>(plugin, request_ptr);
+    const _async_result_ptr = createAsyncValue(plugin);
+    sendHttpRequest(importFunctions.http?.fetch ?? fetch, request).then((result) => {
+        resolveFuture(plugin, _async_result_ptr, serializeObject(plugin, result));
+    });
+    return _async_result_ptr;
+},"#;
+
+const VALIDATION_ERROR: &str = r#"
+/**
+ * Thrown when a value that is received from the plugin contains a field that
+ * does not pass its validation.
+ */
+export class ValidationError extends FPRuntimeError {
+    constructor(message: string) {
+        super(message);
+    }
+}
+"#;
+
+const CAPABILITY_CHECK_FUNCTION: &str = r#"    const grantedCapabilities = new Set<Capability>(capabilities);
+
+    function checkCapability(functionName: string, capability: Capability) {
+        if (!grantedCapabilities.has(capability)) {
+            throw new PermissionDeniedError(functionName, capability);
+        }
+    }
+"#;
+
+/// Converts pairs of primitives that are returned packed into a `u64`, with the
+/// first element in the high 32 bits.
+const PACKED_PAIR_FUNCTIONS: &str = r#"
+export function packPair<A, B>(
+    pair: [A, B],
+    firstToBits: (value: A) => number,
+    secondToBits: (value: B) => number
+): bigint {
+    return (BigInt(firstToBits(pair[0]) >>> 0) << 32n) | BigInt(secondToBits(pair[1]) >>> 0);
+}
+
+export function unpackPair<A, B>(
+    packed: bigint,
+    firstFromBits: (bits: number) => A,
+    secondFromBits: (bits: number) => B
+): [A, B] {
+    const bits = BigInt.asUintN(64, packed);
+    return [firstFromBits(Number(bits >> 32n)), secondFromBits(Number(bits & 0xffff_ffffn))];
+}
+
+const f32Bits = /* @__PURE__ */ new DataView(new ArrayBuffer(4));
+
+export function f32FromBits(bits: number): number {
+    f32Bits.setUint32(0, bits);
+    return f32Bits.getFloat32(0);
+}
+
+export function f32ToBits(value: number): number {
+    f32Bits.setFloat32(0, value);
+    return f32Bits.getUint32(0);
+}
+"#;
+
+/// Memoizes the results of exports that are annotated with `#[fp(cache)]`.
+///
+/// Results are keyed by the MessagePack encoding of the arguments, and are
+/// kept per plugin instance, so they are discarded together with it. Async
+/// exports store their promise, so that concurrent calls with the same
+/// arguments share a single call to the plugin.
+const CACHED_EXPORT_FUNCTIONS: &str = r#"
+type CachedResult = {
+    expiresAt: number;
+    value: unknown;
+};
+
+const exportCaches = new WeakMap<PluginInstance, Map<string, CachedResult>>();
+
+/**
+ * Returns the result of a previous call to the given export with the same key,
+ * unless its time-to-live has passed. Otherwise, the export is called and its
+ * result is stored, unless it fails.
+ */
+export function cachedExport<T>(
+    plugin: PluginInstance,
+    exportName: string,
+    key: unknown[],
+    ttlMs: number,
+    call: () => T
+): T {
+    let cache = exportCaches.get(plugin);
+    if (!cache) {
+        cache = new Map<string, CachedResult>();
+        exportCaches.set(plugin, cache);
+    }
+
+    const now = Date.now();
+    const encodedKey = encode(KEY, plugin.msgpackOptions);
+    const cacheKey = `${exportName}:${Array.from(encodedKey, (byte) => byte.toString(16).padStart(2, "0")).join("")}`;
+    const entry = cache.get(cacheKey);
+    if (entry && entry.expiresAt > now) {
+        return entry.value as T;
+    }
+
+    for (const [otherKey, otherEntry] of cache) {
+        if (otherEntry.expiresAt <= now) {
+            cache.delete(otherKey);
+        }
+    }
+
+    const value = call();
+    const stored = { expiresAt: now + ttlMs, value };
+    cache.set(cacheKey, stored);
+    if (value instanceof Promise) {
+        const entries = cache;
+        value.catch(() => {
+            if (entries.get(cacheKey) === stored) {
+                entries.delete(cacheKey);
+            }
+        });
+    }
+    return value;
+}
+
+/**
+ * Discards the memoized results of the cached exports of the given plugin.
+ */
+export function clearCachedExports(plugin: PluginInstance) {
+    exportCaches.delete(plugin);
+}
+"#;
+
+/// Limits how often the plugin may call imports that are annotated with
+/// `#[fp(rate_limit)]`, using a token bucket per import.
+///
+/// Buckets are kept per plugin instance, so they are discarded together with
+/// it.
+const RUNTIME_OPTIONS_MEMORY: &str = "
+    /**
+     * The shared memory that the plugin imports. By default, a memory is
+     * created with the limits that the plugin declares for it.
+     */
+    memory?: WebAssembly.Memory;";
+
+const RUNTIME_OPTIONS_SIGNATURE: &str = "
+    /**
+     * Verifies the signature of the plugin before it is instantiated, using
+     * either the raw Ed25519 public key with which plugins are signed, or a
+     * callback that is called with the signed bytes of the plugin and its
+     * signature. Plugins that are unsigned or whose signature is invalid are
+     * rejected with an `InvalidSignatureError`.
+     */
+    signatureVerifier?: SignatureVerifier;";
+
+/// Helpers for verifying the signature in the `fp-signature` section with
+/// which signed plugins end.
+const SIGNATURE_FUNCTIONS: &str = r#"
+/**
+ * Verifies the signature of a plugin, given the bytes of the plugin that are
+ * signed and the signature.
+ */
+export type SignatureVerifier =
+    | Uint8Array
+    | ((signedBytes: Uint8Array, signature: Uint8Array) => boolean | Promise<boolean>);
+
+/**
+ * Thrown when a plugin is rejected, because it is unsigned or its signature
+ * is invalid.
+ */
+export class InvalidSignatureError extends FPRuntimeError {
+    constructor(message: string) {
+        super(message);
+    }
+}
+
+/**
+ * Verifies the signature of the given plugin, which is stored in the custom
+ * `fp-signature` section with which signed plugins end.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param verifier The Ed25519 public key or callback to verify it with.
+ */
+export async function verifySignature(plugin: ArrayBuffer, verifier: SignatureVerifier) {
+    const bytes = new Uint8Array(plugin);
+    let offset = 8; // Skips the magic number and the version.
+
+    const readUnsigned = () => {
+        let result = 0;
+        let factor = 1;
+        let byte: number;
+        do {
+            byte = bytes[offset++];
+            result += (byte & 0x7f) * factor;
+            factor *= 128;
+        } while (byte & 0x80);
+        return result;
+    };
+
+    let signatureSection: { start: number; payload: Uint8Array } | null = null;
+    while (offset < bytes.length) {
+        const start = offset;
+        const sectionId = bytes[offset++];
+        const sectionEnd = readUnsigned() + offset;
+        if (sectionEnd > bytes.length) {
+            throw new InvalidSignatureError("Plugin is not a valid WebAssembly module");
+        }
+
+        signatureSection = null;
+        if (sectionId === 0) {
+            const nameLen = readUnsigned();
+            const name = new TextDecoder().decode(bytes.subarray(offset, offset + nameLen));
+            if (name === "fp-signature") {
+                signatureSection = { start, payload: bytes.subarray(offset + nameLen, sectionEnd) };
+            }
+        }
+        offset = sectionEnd;
+    }
+    if (!signatureSection) {
+        throw new InvalidSignatureError("Plugin is not signed");
+    }
+
+    const signedBytes = bytes.subarray(0, signatureSection.start);
+    const signature = signatureSection.payload;
+    const isValid =
+        typeof verifier === "function"
+            ? await verifier(signedBytes, signature)
+            : await crypto.subtle.verify(
+                  "Ed25519",
+                  await crypto.subtle.importKey("raw", verifier, "Ed25519", false, ["verify"]),
+                  signature,
+                  signedBytes
+              );
+    if (!isValid) {
+        throw new InvalidSignatureError("Plugin signature is invalid");
+    }
+}
+"#;
+
+const RUNTIME_OPTIONS_PAYLOAD_TRACING: &str = "
+    /**
+     * Logs the serialized payloads that are passed between the runtime and the
+     * plugin, for debugging protocol mismatches. If omitted, payloads are only
+     * logged if the `FP_BINDGEN_TRACE_PAYLOADS` environment variable is set to
+     * `hex`, `base64` or `decoded`.
+     */
+    tracePayloads?: boolean | PayloadTraceOptions;";
+
+/// The types of the options for tracing payloads, which are shared by the
+/// memory module and the declarations.
+const PAYLOAD_TRACE_TYPES: &str = r#"
+/**
+ * The direction in which a payload is passed: to the plugin, as the argument
+ * of an export or the result of an import, or from the plugin, as the result
+ * of an export or the argument of an import.
+ */
+export type PayloadDirection = "toPlugin" | "fromPlugin";
+
+/**
+ * The format in which payloads are logged. Decoded payloads are logged as the
+ * JSON of their MessagePack value.
+ */
+export type PayloadFormat = "hex" | "base64" | "decoded";
+
+/**
+ * A serialized payload that is passed between the runtime and the plugin.
+ */
+export type PayloadTrace = {
+    function: string;
+    name: string;
+    direction: PayloadDirection;
+    payload: Uint8Array;
+};
+
+/**
+ * Options for logging the serialized payloads that are passed between the
+ * runtime and the plugin.
+ */
+export type PayloadTraceOptions = {
+    /**
+     * The format in which payloads are logged. Defaults to `"hex"`.
+     */
+    format?: PayloadFormat;
+    /**
+     * Returns the text that is logged instead of a payload that should not be
+     * logged, such as one that contains credentials, or `undefined` to log the
+     * payload itself.
+     */
+    redact?: (trace: PayloadTrace) => string | undefined;
+    /**
+     * Logs the lines that describe the payloads. Defaults to `console.debug()`.
+     */
+    log?: (line: string) => void;
+};
+"#;
+
+/// Helpers for tracing the serialized payloads that are passed between the
+/// runtime and the plugin.
+const PAYLOAD_TRACE_FUNCTIONS: &str = r#"
+/**
+ * Returns the options for tracing payloads, given the `tracePayloads` option.
+ * If it is omitted, the `FP_BINDGEN_TRACE_PAYLOADS` environment variable is
+ * used instead, where available.
+ */
+export function getPayloadTraceOptions(
+    option: boolean | PayloadTraceOptions | undefined
+): PayloadTraceOptions | undefined {
+    if (option === undefined) {
+        const format = (globalThis as any).process?.env?.FP_BINDGEN_TRACE_PAYLOADS;
+        if (format === "hex" || format === "base64" || format === "decoded") {
+            return { format };
+        }
+        return undefined;
+    }
+    return option === true ? {} : option || undefined;
+}
+
+/**
+ * Logs the serialized payload in the memory of the plugin, if payload tracing
+ * is enabled, and returns its pointer without freeing it.
+ */
+export function traceGuestPayload(
+    plugin: PluginInstance,
+    fn: string,
+    name: string,
+    direction: PayloadDirection,
+    fatPtr: FatPtr
+): FatPtr {
+    const options = plugin.payloadTraceOptions;
+    if (!options) {
+        return fatPtr;
+    }
+
+    const [ptr, len] = fromFatPtr(fatPtr);
+    const payload = memoryView(plugin, ptr, len & 0xff_ffff).slice();
+    const text =
+        options.redact?.({ function: fn, name, direction, payload }) ??
+        formatPayload(plugin, payload, options.format ?? "hex");
+    const arrow = direction === "toPlugin" ? "runtime -> plugin" : "plugin -> runtime";
+    (options.log ?? console.debug)(
+        `fp-bindgen payload: ${fn}(${name}) ${arrow}, ${payload.length} bytes: ${text}`
+    );
+    return fatPtr;
+}
+
+function formatPayload(plugin: PluginInstance, payload: Uint8Array, format: PayloadFormat) {
+    const hex = Array.from(payload, (byte) => byte.toString(16).padStart(2, "0")).join("");
+    switch (format) {
+        case "base64":
+            return btoa(Array.from(payload, (byte) => String.fromCharCode(byte)).join(""));
+        case "decoded":
+            try {
+                return JSON.stringify(decode(payload, plugin.msgpackOptions), (_key, value) =>
+                    typeof value === "bigint" ? value.toString() : value
+                );
+            } catch {
+                return `${hex} (invalid MessagePack)`;
+            }
+        default:
+            return hex;
+    }
+}
+"#;
+
+/// Helpers for checking the exports of a plugin against the protocol before it
+/// is instantiated.
+const EXPORT_RESOLUTION_FUNCTIONS: &str = r#"
+/**
+ * Checks the exports of a plugin against the signatures that are declared in
+ * the protocol, so that plugins that were built for another version of the
+ * protocol are rejected before they are instantiated, rather than once the
+ * export is called.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param signatures The symbols and signatures of the exports, keyed by the
+ *                   names under which they are returned by `createRuntime()`.
+ * @returns The names of the exports that the plugin omits.
+ */
+export function checkExports(
+    plugin: ArrayBuffer,
+    signatures: Record<string, [string, string]>
+): string[] {
+    const exports = parseModuleExports(plugin);
+    const missingExports: string[] = [];
+    for (const [name, [symbol, signature]] of Object.entries(signatures)) {
+        const exportedSignature = exports.get(symbol);
+        if (exportedSignature === undefined) {
+            missingExports.push(name);
+        } else if (exportedSignature !== signature) {
+            throw new SignatureMismatchError(symbol);
+        }
+    }
+    return missingExports;
+}
+
+/**
+ * Parses the signatures of the functions that a WebAssembly module exports,
+ * keyed by name, which `WebAssembly.Module.exports()` does not provide.
+ */
+export function parseModuleExports(module: ArrayBuffer): Map<string, string> {
+    const bytes = new Uint8Array(module);
+    let offset = 8; // Skips the magic number and the version.
+
+    const readByte = () => bytes[offset++];
+    const readUnsigned = () => {
+        let result = 0;
+        let factor = 1;
+        let byte: number;
+        do {
+            byte = readByte();
+            result += (byte & 0x7f) * factor;
+            factor *= 128;
+        } while (byte & 0x80);
+        return result;
+    };
+    const readValueTypes = () => {
+        const types: string[] = [];
+        for (let count = readUnsigned(); count > 0; count--) {
+            types.push(formatValueType(readByte()));
+        }
+        return types;
+    };
+
+    const signatures: string[] = [];
+    // Imported functions precede the functions that the module defines:
+    const functionSignatures = parseModuleImports(module)
+        .filter(({ kind }) => kind === "function")
+        .map(({ signature }) => signature);
+    const exports = new Map<string, string>();
+    while (offset < bytes.length) {
+        const sectionId = readByte();
+        const sectionEnd = readUnsigned() + offset;
+        if (sectionId === 1) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                readByte(); // The form of the type, which is always a function.
+                const params = readValueTypes();
+                const results = readValueTypes();
+                signatures.push(
+                    `(${params.join(", ")}) -> ${
+                        results.length === 1 ? results[0] : `(${results.join(", ")})`
+                    }`
+                );
+            }
+        } else if (sectionId === 3) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                functionSignatures.push(signatures[readUnsigned()] ?? null);
+            }
+        } else if (sectionId === 7) {
+            for (let count = readUnsigned(); count > 0; count--) {
+                const len = readUnsigned();
+                const name = new TextDecoder().decode(bytes.subarray(offset, offset + len));
+                offset += len;
+                const kind = readByte();
+                const signature = functionSignatures[readUnsigned()];
+                if (kind === 0x00 && signature) {
+                    exports.set(name, signature);
+                }
+            }
+
+            // The export section is followed by sections we don't need:
+            break;
+        }
+        offset = sectionEnd;
+    }
+    return exports;
+}
+"#;
+
+/// Helpers for plugins that are compiled with Wasm threads, which import a
+/// shared memory that plugin threads may block on.
+const SHARED_MEMORY_FUNCTIONS: &str = r#"
+/**
+ * Creates the shared memory that is imported by a plugin that is compiled with
+ * Wasm threads, using the limits that the plugin declares for it.
+ */
+export function createSharedMemory(plugin: ArrayBuffer): WebAssembly.Memory {
+    const memoryImport = parseModuleImports(plugin).find(
+        ({ module, name, kind }) => module === "env" && name === "memory" && kind === "memory"
+    );
+    const limits = memoryImport?.limits;
+    if (!limits?.shared) {
+        throw new FPRuntimeError(
+            "Plugin does not import a shared memory, it needs to be compiled with Wasm threads"
+        );
+    }
+
+    return new WebAssembly.Memory({
+        initial: limits.initial,
+        maximum: limits.maximum ?? undefined,
+        shared: true,
+    });
+}
+
+/**
+ * Wakes up the plugin threads that are blocked on the given async value, after
+ * it has been resolved.
+ */
+function notifyAsyncValue(plugin: PluginInstance, asyncValuePtr: FatPtr) {
+    const [ptr] = fromFatPtr(asyncValuePtr);
+    // The status of the async value is its first field:
+    Atomics.notify(new Int32Array(plugin.memory.buffer, ptr, 1), 0);
+}
+"#;
+
+const COLUMN_EXTENSION_FUNCTIONS: &str = r#"
+type ColumnArray =
+    | Int8Array
+    | Int16Array
+    | Uint16Array
+    | Int32Array
+    | Uint32Array
+    | BigInt64Array
+    | BigUint64Array
+    | Float32Array
+    | Float64Array;
+
+type ColumnArrayConstructor = new (buffer: ArrayBuffer) => ColumnArray;
+
+/**
+ * The typed arrays in which columns are decoded, by the type of the MessagePack
+ * extension in which they are encoded. Columns of `u8` values are encoded as
+ * plain binary data, which decodes into a `Uint8Array` already.
+ */
+const COLUMN_ARRAYS: Array<[number, ColumnArrayConstructor]> = [
+    [16, Int8Array],
+    [17, Int16Array],
+    [18, Uint16Array],
+    [19, Int32Array],
+    [20, Uint32Array],
+    [21, BigInt64Array],
+    [22, BigUint64Array],
+    [23, Float32Array],
+    [24, Float64Array],
+];
+
+/**
+ * Returns the given options with an `extensionCodec` that encodes and decodes
+ * the columns of `#[fp(columnar)]` structs as typed arrays. Extensions of other
+ * types are still handled by the `extensionCodec` of the given options.
+ *
+ * Typed arrays use the byte order of the platform, which is little-endian on
+ * every platform that runs WebAssembly, just like the columns.
+ */
+export function withColumnExtensions(options: MsgpackOptions): MsgpackOptions {
+    const columnCodec = new ExtensionCodec();
+    for (const [type, ColumnArray] of COLUMN_ARRAYS) {
+        columnCodec.register({
+            type,
+            encode: (value: unknown) =>
+                value instanceof ColumnArray
+                    ? new Uint8Array(value.buffer, value.byteOffset, value.byteLength)
+                    : null,
+            decode: (data: Uint8Array) => new ColumnArray(data.slice().buffer),
+        });
+    }
+
+    const extensionCodec = options.extensionCodec;
+    if (!extensionCodec) {
+        return { ...options, extensionCodec: columnCodec };
+    }
+
+    return {
+        ...options,
+        extensionCodec: {
+            tryToEncode: (object: unknown, context: any) =>
+                columnCodec.tryToEncode(object, context) ?? extensionCodec.tryToEncode(object, context),
+            decode: (data: Uint8Array, type: number, context: any) =>
+                COLUMN_ARRAYS.some(([columnType]) => columnType === type)
+                    ? columnCodec.decode(data, type, context)
+                    : extensionCodec.decode(data, type, context),
+        },
+    };
+}
+"#;
+
+const RATE_LIMIT_FUNCTIONS: &str = r#"
+type TokenBucket = {
+    tokens: number;
+    refilledAt: number;
+};
+
+const rateLimitBuckets = new WeakMap<PluginInstance, Map<string, TokenBucket>>();
+
+/**
+ * Takes a token from the bucket of the given import, which holds up to `calls`
+ * tokens and is refilled with `calls` tokens every `perMs` milliseconds.
+ *
+ * Returns the `RateLimited` error for the plugin if no token is available, or
+ * `undefined` if the import may be called.
+ */
+export function rateLimit(
+    plugin: PluginInstance,
+    importName: string,
+    calls: number,
+    perMs: number
+): { Err: { function: string; retryAfterMs: number } } | undefined {
+    let buckets = rateLimitBuckets.get(plugin);
+    if (!buckets) {
+        buckets = new Map<string, TokenBucket>();
+        rateLimitBuckets.set(plugin, buckets);
+    }
+
+    const now = Date.now();
+    const tokensPerMs = calls / perMs;
+    let bucket = buckets.get(importName);
+    if (!bucket) {
+        bucket = { tokens: calls, refilledAt: now };
+        buckets.set(importName, bucket);
+    }
+    bucket.tokens = Math.min(calls, bucket.tokens + (now - bucket.refilledAt) * tokensPerMs);
+    bucket.refilledAt = now;
+
+    if (bucket.tokens >= 1) {
+        bucket.tokens -= 1;
+        return undefined;
+    }
+
+    const retryAfterMs = Math.ceil((1 - bucket.tokens) / tokensPerMs);
+    return { Err: { function: importName, retryAfterMs } };
+}
+"#;
+
+/// Decompresses payloads that were compressed by `fp-bindgen-support`.
+///
+/// Compressed payloads start with the `0xc1` marker byte (which is never used
+/// by MessagePack), followed by an LZ4 block with its uncompressed size
+/// prepended as a little-endian `u32`. Payloads that declare a size above the
+/// default maximum of `fp-bindgen-support` are rejected.
+const DECOMPRESS_FUNCTION: &str = r#"
+const MAX_DECOMPRESSED_SIZE = 64 * 1024 * 1024;
+
+/**
+ * Decompresses the given payload if it was compressed by the plugin.
+ */
+function decompress(payload: Uint8Array): Uint8Array {
+    if (payload[0] !== 0xc1) {
+        return payload;
+    }
 
-    let import_decls =
-        format_function_declarations(&import_functions, &types, FunctionType::Import);
-    let export_decls =
-        format_function_declarations(&export_functions, &types, FunctionType::Export);
-    let raw_export_decls = if config.generate_raw_export_wrappers {
-        format_raw_function_declarations(&export_functions, FunctionType::Export)
-    } else {
-        Vec::new()
-    };
+    const view = new DataView(payload.buffer, payload.byteOffset, payload.byteLength);
+    const size = view.getUint32(1, true);
+    if (size > MAX_DECOMPRESSED_SIZE) {
+        throw new Error(
+            `Compressed payload declares ${size} bytes, which exceeds the maximum of ${MAX_DECOMPRESSED_SIZE} bytes`,
+        );
+    }
+    const output = new Uint8Array(size);
+    let i = 5;
+    let o = 0;
+    while (i < payload.length) {
+        const token = payload[i++];
 
-    let has_async_import_functions = import_functions.iter().any(|function| function.is_async);
-    let has_async_export_functions = export_functions.iter().any(|function| function.is_async);
+        let literalLength = token >> 4;
+        if (literalLength === 15) {
+            let byte: number;
+            do {
+                byte = payload[i++];
+                literalLength += byte;
+            } while (byte === 255);
+        }
+        output.set(payload.subarray(i, i + literalLength), o);
+        i += literalLength;
+        o += literalLength;
+        if (i >= payload.length) {
+            break;
+        }
 
-    let mut import_wrappers = format_import_wrappers(&import_functions, &types);
-    if has_async_export_functions {
-        import_wrappers.push("__fp_host_resolve_async_value: resolvePromise,".to_owned());
+        const offset = payload[i++] | (payload[i++] << 8);
+        let matchLength = (token & 15) + 4;
+        if ((token & 15) === 15) {
+            let byte: number;
+            do {
+                byte = payload[i++];
+                matchLength += byte;
+            } while (byte === 255);
+        }
+        for (let m = o - offset; matchLength > 0; matchLength--) {
+            output[o++] = output[m++];
+        }
     }
+    return output;
+}
+"#;
 
-    let export_wrappers = format_export_wrappers(&export_functions, &types);
-    let raw_export_wrappers = if config.generate_raw_export_wrappers {
-        format_raw_export_wrappers(&export_functions)
+/// Formats the contents of the `index.d.ts` file that accompanies the
+/// JavaScript modules when generating JavaScript output.
+fn format_index_declarations(
+    import_decls: &[String],
+    events_type: &str,
+    export_decls: &[String],
+    raw_export_decls: &[String],
+    export_functions: &[ExportFunction],
+    capabilities: &BTreeSet<&str>,
+    modules: &ModuleContext,
+) -> String {
+    let capability_type = format_capability_type(capabilities);
+    let create_runtime_options_type = modules.init.format_options_type();
+    let std_imports_type = if modules.has_std_imports {
+        STD_IMPORTS_TYPE
     } else {
-        Vec::new()
+        ""
     };
+    let http_imports_type = if modules.has_http_imports {
+        HTTP_IMPORTS_TYPE
+    } else {
+        ""
+    };
+    let (feature_imports_type, feature_param, feature_param_doc) = if modules.has_feature_imports {
+        (
+            FEATURE_IMPORTS_TYPE,
+            ",\n    featureImports?: FeatureImports[]",
+            FEATURE_IMPORTS_PARAM_DOC,
+        )
+    } else {
+        ("", "", "")
+    };
+    let signature_declarations = if modules.has_signature_verification {
+        "
+/**
+ * Verifies the signature of a plugin, given the bytes of the plugin that are
+ * signed and the signature.
+ */
+export type SignatureVerifier =
+    | Uint8Array
+    | ((signedBytes: Uint8Array, signature: Uint8Array) => boolean | Promise<boolean>);
 
-    let contents = format!(
-        "// ============================================= //
-// WebAssembly runtime for TypeScript            //
-//                                               //
-// This file is generated. PLEASE DO NOT MODIFY. //
-// ============================================= //
-// deno-lint-ignore-file no-explicit-any no-unused-vars
+/**
+ * Thrown when a plugin is rejected, because it is unsigned or its signature
+ * is invalid.
+ */
+export declare class InvalidSignatureError extends FPRuntimeError {
+    constructor(message: string);
+}
+"
+    } else {
+        ""
+    };
+    let payload_trace_declarations = if modules.has_payload_tracing {
+        PAYLOAD_TRACE_TYPES
+    } else {
+        ""
+    };
+    let validation_error = if !modules.validator_names.is_empty() {
+        "
+/**
+ * Thrown when a value that is received from the plugin contains a field that
+ * does not pass its validation.
+ */
+export declare class ValidationError extends FPRuntimeError {
+    constructor(message: string);
+}
+"
+    } else {
+        ""
+    };
+    let (permission_denied_error, capability_param, capability_param_doc) =
+        if capabilities.is_empty() {
+            ("", "", "")
+        } else {
+            (
+                "
+/**
+ * Thrown when the plugin calls an import that requires a capability that was
+ * not granted to it.
+ */
+export declare class PermissionDeniedError extends FPRuntimeError {
+    functionName: string;
+    capability: Capability;
+    constructor(functionName: string, capability: Capability);
+}
+",
+                ",\n    capabilities?: Capability[]",
+                "\n * @param capabilities The capabilities that are granted to the plugin.",
+            )
+        };
+    let export_functions = export_functions
+        .iter()
+        .map(|function| {
+            format!(
+                "\n{}export declare function {};\n",
+                join_lines(&function.docs, String::to_owned),
+                function.format_signature()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "{header}
+
+import type {{ decode, encode }} from \"{msgpack_module}\";
+import type * as types from \"./types.js\";
+
+export type FatPtr = bigint;
+
+/**
+ * Options for encoding the values that are passed to the plugin and decoding
+ * the values that are received from it, such as an `extensionCodec` with the
+ * MessagePack extensions that the plugin uses, or limits like `maxStrLength`.
+ */
+export type MsgpackOptions = NonNullable<Parameters<typeof encode>[1]> &
+    NonNullable<Parameters<typeof decode>[1]>;
+
+/**
+ * Options for instantiating a plugin.
+ */
+export type RuntimeOptions = {{
+    msgpack?: MsgpackOptions;{runtime_options_memory}{runtime_options_signature}{runtime_options_payload_tracing}
+}};
+
+/**
+ * A promise that is waiting for an async value of the plugin to be resolved.
+ */
+export type PendingPromise = {{
+    resolve: (result: FatPtr) => void;
+    reject: (error: unknown) => void;
+}};
 
-import {{ encode, decode }} from \"{}\";
+/**
+ * A plugin that was instantiated using `instantiatePlugin()`.
+ *
+ * The functions that call the exports of the plugin take the instance as their
+ * first argument, so that bundlers can drop the functions that are not used.
+ */
+export type PluginInstance = {{
+    instance: WebAssembly.Instance;
+    memory: WebAssembly.Memory;
+    malloc: (len: number) => FatPtr;
+    free: (ptr: FatPtr) => void;
+    msgpackOptions: MsgpackOptions;
+    promises: Map<FatPtr, PendingPromise | FatPtr | GuestError>;
+    dynamicImportHandlers: Map<string, Map<string, (payload: any) => any>>;
+    eventHandlers: Map<string, Set<(event: any) => void>>;
+    disposed: boolean;{plugin_instance_payload_tracing}{plugin_instance_missing_exports}
+}};
 
-import type * as types from \"./types{}\";
+/**
+ * Statistics about the memory that is allocated by a plugin.
+ */
+export type MemoryStats = {{
+    allocatedBytes: number;
+    peakAllocatedBytes: number;
+    allocationCount: number;
+}};
 
-type FatPtr = bigint;
+/**
+ * Metrics about the calls that a plugin has made to the imports of the
+ * runtime, as measured by the plugin itself.
+ */
+export type GuestMetrics = {{
+    imports: Record<string, ImportMetrics>;
+}};
 
+/**
+ * Metrics about the calls that a plugin has made to a single import.
+ * Durations are in nanoseconds.
+ */
+export type ImportMetrics = {{
+    callCount: number;
+    totalDurationNanos: number;
+    maxDurationNanos: number;
+}};
+{PROTOCOL_TYPES}{feature_imports_type}
 export type Imports = {{
 {}}};
-
+{events_type}
 export type Exports = {{
 {}{}}};
-
+{capability_type}{std_imports_type}{http_imports_type}
 /**
  * Represents an unrecoverable error in the FP runtime.
  *
  * After this, your only recourse is to create a new runtime, probably with a different WASM plugin.
  */
-export class FPRuntimeError extends Error {{
-    constructor(message: string) {{
-        super(message);
-    }}
+export declare class FPRuntimeError extends Error {{
+    constructor(message: string);
 }}
 
 /**
- * Creates a runtime for executing the given plugin.
- *
- * @param plugin The raw WASM plugin.
- * @param importFunctions The host functions that may be imported by the plugin.
- * @returns The functions that may be exported by the plugin.
+ * The kind of failure that is reported by a `GuestError`.
  */
-export async function createRuntime(
-    plugin: ArrayBuffer,
-    importFunctions: Imports
-): Promise<Exports> {{
-    const promises = new Map<FatPtr, ((result: FatPtr) => void) | FatPtr>();
-
-    function createAsyncValue(): FatPtr {{
-        const len = 12; // std::mem::size_of::<AsyncValue>()
-        const fatPtr = malloc(len);
-        const [ptr] = fromFatPtr(fatPtr);
-        const buffer = new Uint8Array(memory.buffer, ptr, len);
-        buffer.fill(0);
-        return fatPtr;
-    }}
-
-    function interpretSign(num: number, cap: number) {{
-        if (num < cap) {{
-            return num;
-        }} else {{
-            return num - (cap << 1);
-        }}
-    }}
+export type GuestErrorKind =
+    | \"deserialization\"
+    | \"serialization\"
+    | \"allocation\"
+    | \"invalid_pointer\"
+    | \"internal\"
+    | \"panic\";
 
-    function interpretBigSign(num: bigint, cap: bigint) {{
-        if (num < cap) {{
-            return num;
-        }} else {{
-            return num - (cap << 1n);
-        }}
-    }}
-
-    function parseObject<T>(fatPtr: FatPtr): T {{
-        const [ptr, len] = fromFatPtr(fatPtr);
-        const buffer = new Uint8Array(memory.buffer, ptr, len);
-        // Without creating a copy of the memory, we risk corruption of any
-        // embedded `Uint8Array` objects returned from `decode()` after `free()`
-        // has been called :(
-        const copy = new Uint8Array(len);
-        copy.set(buffer);
-        free(fatPtr);
-        const object = decode(copy) as unknown as T;
-        return object;
-    }}
-
-    function promiseFromPtr(ptr: FatPtr): Promise<FatPtr> {{
-        const resultPtr = promises.get(ptr);
-        if (resultPtr) {{
-            if (typeof resultPtr === \"function\") {{
-                throw new FPRuntimeError(\"Already created promise for this value\");
-            }}
-
-            promises.delete(ptr);
-            return Promise.resolve(resultPtr);
-        }} else {{
-            return new Promise((resolve) => {{
-                promises.set(ptr, resolve as (result: FatPtr) => void);
-            }});
-        }}
-    }}
-
-    function resolvePromise(asyncValuePtr: FatPtr, resultPtr: FatPtr) {{
-        const resolve = promises.get(asyncValuePtr);
-        if (resolve) {{
-            if (typeof resolve !== \"function\") {{
-                throw new FPRuntimeError(\"Tried to resolve invalid promise\");
-            }}
+/**
+ * Thrown when the plugin reports a failure before it aborts, which plugins do
+ * if they are built with the `panic-abort` feature of `fp-bindgen-support`.
+ */
+export declare class GuestError extends FPRuntimeError {{
+    kind: GuestErrorKind;
+    constructor(kind: GuestErrorKind, message: string);
+}}
 
-            promises.delete(asyncValuePtr);
-            resolve(resultPtr);
-        }} else {{
-            promises.set(asyncValuePtr, resultPtr);
-        }}
-    }}
+/**
+ * Thrown when the plugin fails to allocate the memory for a value that is
+ * passed to it, which it reports by returning a null pointer from its
+ * allocator.
+ */
+export declare class AllocationFailedError extends FPRuntimeError {{
+    len: number;
+    constructor(len: number);
+}}
 
-    function serializeObject<T>(object: T): FatPtr {{
-        return exportToMemory(encode(object));
-    }}
+/**
+ * Thrown when the plugin does not export a function that is called.
+ */
+export declare class MissingExportError extends FPRuntimeError {{
+    exportName: string;
+    constructor(exportName: string);
+}}
 
-    function exportToMemory(serialized: Uint8Array): FatPtr {{
-        const fatPtr = malloc(serialized.length);
-        const [ptr, len] = fromFatPtr(fatPtr);
-        const buffer = new Uint8Array(memory.buffer, ptr, len);
-        buffer.set(serialized);
-        return fatPtr;
-    }}
+/**
+ * Thrown when the plugin exports a function with another signature than the
+ * one that is declared in the protocol.
+ */
+export declare class SignatureMismatchError extends FPRuntimeError {{
+    exportName: string;
+    constructor(exportName: string);
+}}
 
-    function importFromMemory(fatPtr: FatPtr): Uint8Array {{
-        const [ptr, len] = fromFatPtr(fatPtr);
-        const buffer = new Uint8Array(memory.buffer, ptr, len);
-        const copy = new Uint8Array(len);
-        copy.set(buffer);
-        free(fatPtr);
-        return copy;
-    }}
+/**
+ * Thrown when a value that is returned by the plugin cannot be decoded.
+ */
+export declare class DeserializationError extends FPRuntimeError {{
+    error: unknown;
+    constructor(error: unknown);
+}}
 
-    const {{ instance }} = await WebAssembly.instantiate(plugin, {{
-        fp: {{
-{}        }},
-    }});
+/**
+ * Thrown when the plugin is called after it was disposed, and used to reject
+ * the promises that were still waiting for the plugin when it was disposed.
+ */
+export declare class PluginDisposedError extends FPRuntimeError {{
+    constructor();
+}}
 
-    const getExport = <T>(name: string): T => {{
-        const exp = instance.exports[name];
-        if (!exp) {{
-            throw new FPRuntimeError(`Plugin did not export expected symbol: \"${{name}}\"`);
-        }}
-        return exp as unknown as T;
-    }};
+/**
+ * An import of the plugin that the runtime does not provide, or provides with
+ * another signature than the plugin expects.
+ */
+export type ImportDiagnostic = {{
+    module: string;
+    name: string;
+    /**
+     * The kind of import the plugin expects, together with the signature of
+     * functions, such as `function (i64) -> i64`.
+     */
+    expected: string;
+    /**
+     * The kind and signature of the import that is provided by the runtime, or
+     * `null` if it is missing.
+     */
+    provided: string | null;
+}};
 
-    const memory = getExport<WebAssembly.Memory>(\"memory\");
-    const malloc = getExport<(len: number) => FatPtr>(\"__fp_malloc\");
-    const free = getExport<(ptr: FatPtr) => void>(\"__fp_free\");
-{}
-    return {{
-{}{}    }};
+/**
+ * Thrown when a plugin cannot be instantiated, because it expects imports that
+ * the runtime does not provide, or provides with another signature.
+ */
+export declare class InstantiationError extends FPRuntimeError {{
+    diagnostics: ImportDiagnostic[];
+    constructor(diagnostics: ImportDiagnostic[]);
 }}
+{signature_declarations}{payload_trace_declarations}{permission_denied_error}{validation_error}
+/**
+ * Creates the functions that are imported by the plugin, which call the given
+ * host functions.
+ *
+ * @param plugin The plugin instance for which the imports are created. It only
+ *               needs to be initialized by the time the plugin calls them.
+ * @param importFunctions The host functions that may be imported by the plugin.{capability_param_doc}
+ * @returns The functions that are imported by the plugin from the `fp` module.
+ */
+export declare function createImports(
+    plugin: PluginInstance,
+    importFunctions: Imports{capability_param}
+): WebAssembly.ModuleImports;
 
-function fromFatPtr(fatPtr: FatPtr): [ptr: number, len: number] {{
-    return [
-        Number.parseInt((fatPtr >> 32n).toString()),
-        Number.parseInt((fatPtr & 0xffff_ffffn).toString()),
-    ];
+/**
+ * Returns the functions that are exported by the given plugin instance.
+ *
+ * Functions that the plugin does not implement are `undefined`. Hosts that
+ * only call some of the exports may prefer to call the functions below
+ * directly, so that the others can be dropped from their bundle.
+ */
+/**
+ * The name of a function that is exported by the plugin.
+ */
+export type ExportName = keyof Exports;
+{PLUGIN_ERROR_KIND}
+/**
+ * Thrown when calling an export of the plugin fails. The error that caused
+ * the failure is available as `error`.
+ */
+export declare class PluginError extends FPRuntimeError {{
+    kind: PluginErrorKind;
+    exportName: ExportName;
+    typeName: string | undefined;
+    error: unknown;
+    constructor(kind: PluginErrorKind, exportName: ExportName, error: unknown, typeName: string | undefined);
 }}
 
-function toFatPtr(ptr: number, len: number): FatPtr {{
-    return (BigInt(ptr) << 32n) | BigInt(len);
-}}
+export declare function createExports(plugin: PluginInstance): Exports;
+{export_functions}
+/**
+ * Instantiates the given plugin, without wrapping its exports.
+ *
+ * The returned instance can be passed to the functions that call the exports
+ * of the plugin. Unlike `createRuntime()`, this allows bundlers to drop the
+ * functions that are not used.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importFunctions The host functions that may be imported by the plugin.{capability_param_doc}{feature_param_doc}{OPTIONS_PARAM_DOC}
+ * @returns The instantiated plugin.
+ */
+export declare function instantiatePlugin(
+    plugin: ArrayBuffer,
+    importFunctions: Imports{capability_param}{feature_param},
+    options?: RuntimeOptions
+): Promise<PluginInstance>;
+
+{create_runtime_options_type}
+/**
+ * Creates a runtime for executing the given plugin.
+ *{CREATE_RUNTIME_DOC}
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importFunctions The host functions that may be imported by the plugin.{capability_param_doc}{feature_param_doc}{CREATE_RUNTIME_OPTIONS_PARAM_DOC}
+ * @returns The functions that may be exported by the plugin.
+ */
+export declare function createRuntime(
+    plugin: ArrayBuffer,
+    importFunctions: Imports{capability_param}{feature_param},
+    options?: CreateRuntimeOptions
+): Promise<Exports>;
+{PROTOCOL_DOC}
+export declare function protocol(): Protocol;
 ",
-        config.msgpack_module,
-        // HACK: Import paths in TypeScript are a bit of a mess. Usually, you
-        // shouldn't need an extension, but with some configurations you do.
-        // For now, we just try to detect Deno users by looking at the
-        // `msgpack_module` and accomodate them here:
-        if config.msgpack_module.ends_with(".ts") {
-            ".ts"
+        join_lines(import_decls, |line| format!("    {line};")),
+        join_lines(export_decls, |line| format!("    {line};")),
+        join_lines(raw_export_decls, |line| format!("    {line};")),
+        header = format_header("WebAssembly runtime for TypeScript"),
+        runtime_options_memory = if modules.has_shared_memory {
+            RUNTIME_OPTIONS_MEMORY
         } else {
             ""
         },
-        join_lines(&import_decls, |line| format!("    {line};")),
-        join_lines(&export_decls, |line| format!("    {line};")),
-        join_lines(&raw_export_decls, |line| format!("    {line};")),
-        join_lines(&import_wrappers, |line| format!("            {line}")),
-        if has_async_import_functions {
-            "    const resolveFuture = getExport<(asyncValuePtr: FatPtr, resultPtr: FatPtr) => void>(\"__fp_guest_resolve_async_value\");\n"
+        runtime_options_signature = if modules.has_signature_verification {
+            RUNTIME_OPTIONS_SIGNATURE
         } else {
             ""
         },
-        join_lines(&export_wrappers, |line| format!("        {line}")),
-        join_lines(&raw_export_wrappers, |line| format!("        {line}")),
-    );
-    write_bindings_file(format!("{path}/index.ts"), contents);
+        runtime_options_payload_tracing = if modules.has_payload_tracing {
+            RUNTIME_OPTIONS_PAYLOAD_TRACING
+        } else {
+            ""
+        },
+        plugin_instance_payload_tracing = if modules.has_payload_tracing {
+            "\n    payloadTraceOptions?: PayloadTraceOptions;"
+        } else {
+            ""
+        },
+        plugin_instance_missing_exports = if modules.has_eager_export_resolution {
+            "\n    missingExports?: string[];"
+        } else {
+            ""
+        },
+        msgpack_module = modules.msgpack_module,
+    )
 }
 
 enum FunctionType {
     Import,
     Export,
+    /// Exports as they are proxied by the worker wrapper, which makes every
+    /// function asynchronous.
+    WorkerExport,
+    /// Synchronous imports as they may be implemented on the main thread by
+    /// the browser worker wrapper, which makes them asynchronous as well.
+    AsyncImport,
 }
 
 fn format_function_declarations(
     functions: &FunctionList,
     types: &TypeMap,
     function_type: FunctionType,
+    casing: &TsIdentifierCasing,
+    int64: TsInt64Encoding,
 ) -> Vec<String> {
     // Plugins can always omit exports, while runtimes are always expected to provide all imports:
     let optional_marker = match function_type {
         FunctionType::Import => "",
-        FunctionType::Export => "?",
+        FunctionType::Export | FunctionType::WorkerExport | FunctionType::AsyncImport => "?",
     };
 
     functions
         .iter()
+        // Events are passed to the subscribers instead of being implemented:
+        .filter(|function| !function.attrs.event)
+        // Async imports can already be implemented asynchronously:
+        .filter(|function| {
+            !(matches!(function_type, FunctionType::AsyncImport) && function.is_async)
+        })
         .map(|function| {
             let args = function
                 .args
@@ -257,33 +3721,27 @@ fn format_function_declarations(
                 .map(|arg| {
                     format!(
                         "{}: {}",
-                        arg.name.to_camel_case(),
-                        format_plain_primitive_or_ident(&arg.ty, types)
+                        casing.format_name(&arg.name),
+                        format_plain_primitive_or_ident(&arg.ty, types, int64)
                     )
                 })
                 .collect::<Vec<_>>()
                 .join(", ");
-            let return_type = if function.is_async {
-                format!(
-                    " => Promise<{}>",
-                    match &function.return_type {
-                        Some(ty) => format_ident(ty, types, "types."),
-                        None => "void".to_owned(),
-                    }
-                )
+            let return_type = format_return_type(function, types, int64);
+            let return_type = if matches!(
+                function_type,
+                FunctionType::WorkerExport | FunctionType::AsyncImport
+            ) && !function.is_async
+            {
+                format!(" => Promise<{return_type}>")
             } else {
-                format!(
-                    " => {}",
-                    match &function.return_type {
-                        Some(ty) => format_plain_primitive_or_ident(ty, types),
-                        None => "void".to_owned(),
-                    }
-                )
+                format!(" => {return_type}")
             };
             format!(
-                "{}{}: ({}){}",
-                function.name.to_camel_case(),
+                "{}{}: {}({}){}",
+                casing.format_name(&function.name),
                 optional_marker,
+                format_generic_params(function),
                 args,
                 return_type
             )
@@ -291,61 +3749,393 @@ fn format_function_declarations(
         .collect()
 }
 
-fn format_raw_function_declarations(
-    functions: &FunctionList,
-    function_type: FunctionType,
-) -> Vec<String> {
-    // Plugins can always omit exports, while runtimes are always expected to provide all imports:
-    let optional_marker = match function_type {
-        FunctionType::Import => "",
-        FunctionType::Export => "?",
-    };
-
-    functions
+/// Formats the type parameters of a generic function, such as `<T>`, which
+/// the caller picks for every call.
+fn format_generic_params(function: &Function) -> String {
+    if function.generic_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", function.generic_params.join(", "))
+    }
+}
+
+fn format_raw_function_declarations(
+    functions: &FunctionList,
+    function_type: FunctionType,
+    casing: &TsIdentifierCasing,
+) -> Vec<String> {
+    // Plugins can always omit exports, while runtimes are always expected to provide all imports:
+    let optional_marker = match function_type {
+        FunctionType::Import => "",
+        FunctionType::Export | FunctionType::WorkerExport | FunctionType::AsyncImport => "?",
+    };
+
+    functions
+        .iter()
+        .filter(|function| !is_primitive_function(function))
+        .map(|function| {
+            let args = function
+                .args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        "{}: {}",
+                        casing.format_name(&arg.name),
+                        format_raw_type(&arg.ty)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "{}Raw{}: ({}) => {}",
+                casing.format_name(&function.name),
+                optional_marker,
+                args,
+                format_raw_return_type(function)
+            )
+        })
+        .collect()
+}
+
+/// Formats the type that is returned by the wrapper of a function.
+fn format_return_type(function: &Function, types: &TypeMap, int64: TsInt64Encoding) -> String {
+    match &function.return_type {
+        Some(ty) if function.is_async => format!("Promise<{}>", format_ident(ty, types, "types.")),
+        Some(ty) => format_plain_primitive_or_ident(ty, types, int64),
+        None if function.is_async => "Promise<void>".to_owned(),
+        None => "void".to_owned(),
+    }
+}
+
+/// Formats the type that is returned by the raw wrapper of a function.
+fn format_raw_return_type(function: &Function) -> String {
+    let return_type = function
+        .return_type
+        .as_ref()
+        .map(|ty| match ty.as_packed_pair() {
+            Some(pair) if !function.is_async => format_packed_pair_type(pair),
+            _ => format_raw_type(ty).to_owned(),
+        })
+        .unwrap_or_else(|| "void".to_owned());
+    if function.is_async {
+        format!("Promise<{return_type}>")
+    } else {
+        return_type
+    }
+}
+
+/// Formats the declarations for registering and unregistering handlers for
+/// dynamic imports, which are part of the `Exports` type.
+fn format_dynamic_import_declarations(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+    casing: &TsIdentifierCasing,
+) -> Vec<String> {
+    import_functions
+        .iter()
+        .filter(|function| function.attrs.dynamic)
+        .flat_map(|function| {
+            let name = casing.format_pascal_name(&function.name);
+            vec![
+                format!(
+                    "register{name}: (name: string, handler: {}) => void",
+                    format_dynamic_import_handler_type(function, types, casing)
+                ),
+                format!("unregister{name}: (name: string) => boolean"),
+            ]
+        })
+        .collect()
+}
+
+fn format_dynamic_import_handler_type(
+    function: &Function,
+    types: &TypeMap,
+    casing: &TsIdentifierCasing,
+) -> String {
+    let payload = function
+        .args
+        .get(1)
+        .expect("Dynamic imports are validated to take two arguments");
+    let return_type = function
+        .return_type
+        .as_ref()
+        .expect("Dynamic imports are validated to have a return type");
+    format!(
+        "({}: {}) => {}",
+        casing.format_name(&payload.name),
+        format_ident(&payload.ty, types, "types."),
+        format_ident(return_type, types, "types.")
+    )
+}
+
+fn format_dynamic_import_registrations(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+    casing: &TsIdentifierCasing,
+) -> Vec<ExportFunction> {
+    import_functions
+        .iter()
+        .filter(|function| function.attrs.dynamic)
+        .flat_map(|function| {
+            let name = &function.name;
+            let pascal_name = casing.format_pascal_name(name);
+            vec![
+                ExportFunction {
+                    docs: format_docs(&[format!(
+                        " Registers a handler for the dynamic import `{name}`."
+                    )]),
+                    name: format!("register{pascal_name}"),
+                    type_params: String::new(),
+                    args: vec![
+                        ("name".to_owned(), "string".to_owned()),
+                        (
+                            "handler".to_owned(),
+                            format_dynamic_import_handler_type(function, types, casing),
+                        ),
+                    ],
+                    return_type: "void".to_owned(),
+                    body: vec![format!(
+                        "getDynamicImportHandlers(plugin, \"{name}\").set(name, handler);"
+                    )],
+                    entry: ExportEntry::Always,
+                },
+                ExportFunction {
+                    docs: format_docs(&[format!(
+                        " Unregisters a handler for the dynamic import `{name}`."
+                    )]),
+                    name: format!("unregister{pascal_name}"),
+                    type_params: String::new(),
+                    args: vec![("name".to_owned(), "string".to_owned())],
+                    return_type: "boolean".to_owned(),
+                    body: vec![format!(
+                        "return getDynamicImportHandlers(plugin, \"{name}\").delete(name);"
+                    )],
+                    entry: ExportEntry::Always,
+                },
+            ]
+        })
+        .collect()
+}
+
+/// Formats the `Events` type, which maps the name of every event that may be
+/// emitted by the plugin to the type of the event.
+fn format_events_type(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+    casing: &TsIdentifierCasing,
+) -> String {
+    let events = import_functions
         .iter()
-        .filter(|function| !is_primitive_function(function))
+        .filter(|function| function.attrs.event)
         .map(|function| {
-            let args = function
+            let payload = function
                 .args
-                .iter()
-                .map(|arg| format!("{}: {}", arg.name.to_camel_case(), format_raw_type(&arg.ty)))
-                .collect::<Vec<_>>()
-                .join(", ");
-            let return_type = if function.is_async {
-                format!(
-                    " => Promise<{}>",
-                    function
-                        .return_type
-                        .as_ref()
-                        .map(format_raw_type)
-                        .unwrap_or("void")
-                )
-            } else {
-                format!(
-                    " => {}",
-                    function
-                        .return_type
-                        .as_ref()
-                        .map(format_raw_type)
-                        .unwrap_or("void")
-                )
-            };
+                .first()
+                .expect("Events are validated to take one argument");
             format!(
-                "{}Raw{}: ({}){}",
-                function.name.to_camel_case(),
-                optional_marker,
-                args,
-                return_type
+                "{}: {}",
+                casing.format_name(&function.name),
+                format_ident(&payload.ty, types, "types.")
             )
         })
-        .collect()
+        .collect::<Vec<_>>();
+    if events.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        "
+/**
+ * The events that may be emitted by the plugin, keyed by name.
+ */
+export type Events = {{
+{}}};
+",
+        join_lines(&events, |line| format!("    {line};"))
+    )
+}
+
+const EVENT_TYPE_PARAMS: &str = "<E extends keyof Events>";
+
+const EVENT_HANDLER_TYPE: &str = "(event: Events[E]) => void";
+
+/// Formats the declarations for subscribing to events, which are part of the
+/// `Exports` type.
+fn format_event_subscription_declarations(import_functions: &FunctionList) -> Vec<String> {
+    if !import_functions.iter().any(|function| function.attrs.event) {
+        return Vec::new();
+    }
+
+    vec![
+        format!("on: {EVENT_TYPE_PARAMS}(eventName: E, handler: {EVENT_HANDLER_TYPE}) => void"),
+        format!("off: {EVENT_TYPE_PARAMS}(eventName: E, handler: {EVENT_HANDLER_TYPE}) => boolean"),
+    ]
+}
+
+fn format_event_subscriptions(import_functions: &FunctionList) -> Vec<ExportFunction> {
+    if !import_functions.iter().any(|function| function.attrs.event) {
+        return Vec::new();
+    }
+
+    let args = vec![
+        ("eventName".to_owned(), "E".to_owned()),
+        ("handler".to_owned(), EVENT_HANDLER_TYPE.to_owned()),
+    ];
+    vec![
+        ExportFunction {
+            docs: format_docs(&[
+                " Subscribes the given handler to the event with the given name.".to_owned(),
+                "".to_owned(),
+                " The handler is called with every event of that name that the plugin".to_owned(),
+                " emits, until it is unsubscribed using `off()`.".to_owned(),
+            ]),
+            name: "on".to_owned(),
+            type_params: EVENT_TYPE_PARAMS.to_owned(),
+            args: args.clone(),
+            return_type: "void".to_owned(),
+            body: vec!["getEventHandlers(plugin, eventName).add(handler);".to_owned()],
+            entry: ExportEntry::Always,
+        },
+        ExportFunction {
+            docs: format_docs(&[
+                " Unsubscribes the given handler from the event with the given name.".to_owned(),
+                "".to_owned(),
+                " Returns whether the handler was subscribed.".to_owned(),
+            ]),
+            name: "off".to_owned(),
+            type_params: EVENT_TYPE_PARAMS.to_owned(),
+            args,
+            return_type: "boolean".to_owned(),
+            body: vec!["return getEventHandlers(plugin, eventName).delete(handler);".to_owned()],
+            entry: ExportEntry::Always,
+        },
+    ]
+}
+
+fn format_event_wrapper(
+    function: &Function,
+    types: &TypeMap,
+    validated_structs: &BTreeSet<TypeIdent>,
+    conversions: &Conversions,
+    casing: &TsIdentifierCasing,
+) -> Vec<String> {
+    let name = &function.name;
+    let payload = function
+        .args
+        .first()
+        .expect("Events are validated to take one argument");
+    let payload_name = casing.format_name(&payload.name);
+    let validation = format_validation(&payload.ty, &payload_name, types, validated_structs, 0)
+        .into_iter()
+        .map(|line| format!("    {line}\n"))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "{symbol}: ({payload_ptr}: FatPtr) => {{
+    const {payload_name} = {payload};
+{validation}    for (const handler of getEventHandlers(plugin, \"{event_name}\")) {{
+        handler({payload_name});
+    }}
+}},",
+        payload = conversions.format_conversion(
+            &payload.ty,
+            &format!(
+                "parseObject<{}>(plugin, {})",
+                format_ident(&payload.ty, types, "types."),
+                get_pointer_name(&payload.name)
+            ),
+            types
+        ),
+        event_name = casing.format_name(name),
+        payload_ptr = get_pointer_name(&payload.name),
+        symbol = function.symbol_name(),
+    )
+    .split('\n')
+    .map(str::to_owned)
+    .collect()
+}
+
+fn format_dynamic_import_wrapper(
+    function: &Function,
+    types: &TypeMap,
+    conversions: &Conversions,
+    casing: &TsIdentifierCasing,
+) -> Vec<String> {
+    let name = &function.name;
+    let (handler_name, payload) = match function.args.as_slice() {
+        [handler_name, payload] => (handler_name, payload),
+        _ => unreachable!("Dynamic imports are validated to take two arguments"),
+    };
+    let return_type = function
+        .return_type
+        .as_ref()
+        .expect("Dynamic imports are validated to have a return type");
+
+    format!(
+        "{symbol}: ({handler_ptr}: FatPtr, {payload_ptr}: FatPtr): FatPtr => {{
+{capability_check}    const {handler_name} = parseObject<string>(plugin, {handler_ptr});
+    const {payload_name} = {payload};
+    const handler = getDynamicImportHandlers(plugin, \"{name}\").get({handler_name});
+    const result: {return_ty} = handler
+        ? handler({payload_name})
+        : importFunctions.{fn_name}({handler_name}, {payload_name});
+    return serializeObject(plugin, result);
+}},",
+        handler_ptr = get_pointer_name(&handler_name.name),
+        payload_ptr = get_pointer_name(&payload.name),
+        symbol = function.symbol_name(),
+        handler_name = casing.format_name(&handler_name.name),
+        payload_name = casing.format_name(&payload.name),
+        payload = conversions.format_conversion(
+            &payload.ty,
+            &format!(
+                "parseObject<{}>(plugin, {})",
+                format_ident(&payload.ty, types, "types."),
+                get_pointer_name(&payload.name)
+            ),
+            types
+        ),
+        return_ty = format_ident(return_type, types, "types."),
+        fn_name = casing.format_name(name),
+        capability_check = format_capability_check(function)
+            .map(|line| format!("    {line}\n"))
+            .unwrap_or_default(),
+    )
+    .split('\n')
+    .map(str::to_owned)
+    .collect()
 }
 
-fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> Vec<String> {
+fn format_import_wrappers(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+    validated_structs: &BTreeSet<TypeIdent>,
+    conversions: &Conversions,
+    casing: &TsIdentifierCasing,
+    int64: TsInt64Encoding,
+    payload_tracing: bool,
+) -> Vec<String> {
     import_functions
         .into_iter()
         .flat_map(|function| {
+            if function.attrs.dynamic {
+                return format_dynamic_import_wrapper(function, types, conversions, casing);
+            }
+            if function.attrs.event {
+                return format_event_wrapper(
+                    function,
+                    types,
+                    validated_structs,
+                    conversions,
+                    casing,
+                );
+            }
+
             let name = &function.name;
+            let trace = |arg_name: &str, direction: &str, ptr: String| {
+                format_payload_trace(payload_tracing, name, arg_name, direction, ptr)
+            };
             let args_with_ptr_types = function
                 .args
                 .iter()
@@ -353,7 +4143,7 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
                     if let Some(primitive) = arg.ty.as_primitive() {
                         format!(
                             "{}: {}",
-                            arg.name.to_camel_case(),
+                            casing.format_name(&arg.name),
                             format_plain_primitive(primitive)
                         )
                     } else {
@@ -362,45 +4152,99 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
                 })
                 .collect::<Vec<_>>()
                 .join(", ");
-            let return_type = match &function.return_type.as_ref().map(|ty| ty.as_primitive()) {
+            let return_type = match &function.return_type {
                 None => "".to_owned(),
-                Some(Some(primitive)) => format!(": {}", format_plain_primitive(*primitive)),
-                Some(_) => ": FatPtr".to_owned(),
+                Some(ty) => match ty.as_primitive() {
+                    Some(primitive) => format!(": {}", format_plain_primitive(primitive)),
+                    None if ty.is_packed_pair() && !function.is_async => ": bigint".to_owned(),
+                    None => ": FatPtr".to_owned(),
+                },
             };
-            let import_args = function
-                .args
-                .iter()
-                .filter_map(|arg| {
+            let import_args = format_capability_check(function)
+                .into_iter()
+                .chain(format_rate_limit_check(function))
+                .chain(function.args.iter().flat_map(|arg| {
                     if arg.ty.is_primitive() {
-                        None
+                        Vec::new()
                     } else {
-                        Some(format!(
-                            "const {} = parseObject<{}>({});",
-                            arg.name.to_camel_case(),
-                            format_ident(&arg.ty, types, "types."),
-                            get_pointer_name(&arg.name)
-                        ))
+                        let name = casing.format_name(&arg.name);
+                        // Values of generic types are passed on as they are
+                        // decoded, for the implementation to interpret:
+                        let ty = arg
+                            .ty
+                            .replace_any_of(&function.generic_params, &TypeIdent::from("unknown"));
+                        let value = format!(
+                            "parseObject<{}>(plugin, {})",
+                            format_ident(&ty, types, "types."),
+                            trace(&arg.name, "fromPlugin", get_pointer_name(&arg.name))
+                        );
+                        let mut lines = vec![format!(
+                            "const {} = {};",
+                            name,
+                            conversions.format_conversion(&arg.ty, &value, types)
+                        )];
+                        lines.append(&mut format_validation(
+                            &arg.ty,
+                            &name,
+                            types,
+                            validated_structs,
+                            0,
+                        ));
+                        lines
                     }
-                })
+                }))
                 .collect::<Vec<_>>();
             let args = function
                 .args
                 .iter()
-                .map(|arg| arg.name.to_camel_case())
+                .map(|arg| {
+                    let name = casing.format_name(&arg.name);
+                    if int64.converts(&arg.ty) {
+                        int64.format_from_plugin(&arg.ty, &name)
+                    } else {
+                        name
+                    }
+                })
                 .collect::<Vec<_>>()
                 .join(", ");
-            if function.is_async {
-                let async_result = match &function.return_type {
-                    Some(_) => "serializeObject(result)",
-                    None => "0",
+            if function.is_fire_and_forget() {
+                format!(
+                    "{}: ({}) => {{
+{}    importFunctions.{}({}).catch((error) => {{
+        console.error(
+            'Unrecoverable exception trying to call async host function \"{}\"',
+            error
+        );
+    }});
+}},",
+                    function.symbol_name(),
+                    args_with_ptr_types,
+                    import_args
+                        .iter()
+                        .map(|line| format!("    {line}\n"))
+                        .collect::<Vec<_>>()
+                        .join(""),
+                    casing.format_name(name),
+                    args,
+                    name
+                )
+                .split('\n')
+                .map(|line| line.to_owned())
+                .collect::<Vec<_>>()
+            } else if function.is_async {
+                let call = format!("importFunctions.{}({})", casing.format_name(name), args);
+                // Calls that exceed the rate limit resolve with the error:
+                let call = if function.attrs.rate_limit.is_some() {
+                    format!("Promise.resolve(_rateLimited ?? {call})")
+                } else {
+                    call
                 };
-
                 format!(
-                    "__fp_gen_{}: ({}){} => {{
-{}    const _async_result_ptr = createAsyncValue();
-    importFunctions.{}({})
+                    "{}: ({}){} => {{
+{}    const _async_result_ptr = createAsyncValue(plugin);
+    {}
         .then((result) => {{
-            resolveFuture(_async_result_ptr, {});
+            resolveFuture(plugin, _async_result_ptr, {});
         }})
         .catch((error) => {{
             console.error(
@@ -410,7 +4254,7 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
         }});
     return _async_result_ptr;
 }},",
-                    name,
+                    function.symbol_name(),
                     args_with_ptr_types,
                     return_type,
                     import_args
@@ -418,9 +4262,12 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
                         .map(|line| format!("    {line}\n"))
                         .collect::<Vec<_>>()
                         .join(""),
-                    name.to_camel_case(),
-                    args,
-                    async_result,
+                    call,
+                    trace(
+                        "result",
+                        "toPlugin",
+                        "serializeObject(plugin, result)".to_owned()
+                    ),
                     name
                 )
                 .split('\n')
@@ -428,26 +4275,59 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
                 .collect::<Vec<_>>()
             } else {
                 let fn_call = match &function.return_type {
-                    None => format!("importFunctions.{}({});", name.to_camel_case(), args),
+                    None => format!("importFunctions.{}({});", casing.format_name(name), args),
+                    Some(ty) if int64.converts(ty) => format!(
+                        "return {};",
+                        int64.format_to_plugin(
+                            ty,
+                            &format!("importFunctions.{}({})", casing.format_name(name), args)
+                        )
+                    ),
                     Some(ty) if ty.is_primitive() => {
                         format!(
                             "return {};",
                             import_primitive(
                                 ty,
-                                &format!("importFunctions.{}({})", name.to_camel_case(), args)
+                                &format!("importFunctions.{}({})", casing.format_name(name), args)
                             )
                         )
                     }
+                    Some(ty) if ty.is_packed_pair() => format!(
+                        "return {};",
+                        export_packed_pair(
+                            ty,
+                            &format!("importFunctions.{}({})", casing.format_name(name), args)
+                        )
+                    ),
+                    _ if function.attrs.rate_limit.is_some() => format!(
+                        "return {};",
+                        trace(
+                            "result",
+                            "toPlugin",
+                            format!(
+                                "serializeObject(plugin, _rateLimited ?? importFunctions.{}({}))",
+                                casing.format_name(name),
+                                args
+                            )
+                        )
+                    ),
                     _ => format!(
-                        "return serializeObject(importFunctions.{}({}));",
-                        name.to_camel_case(),
-                        args
+                        "return {};",
+                        trace(
+                            "result",
+                            "toPlugin",
+                            format!(
+                                "serializeObject(plugin, importFunctions.{}({}))",
+                                casing.format_name(name),
+                                args
+                            )
+                        )
                     ),
                 };
 
                 format!(
-                    "__fp_gen_{}: ({}){} => {{\n{}    {}\n}},",
-                    name,
+                    "{}: ({}){} => {{\n{}    {}\n}},",
+                    function.symbol_name(),
                     args_with_ptr_types,
                     return_type,
                     import_args
@@ -465,160 +4345,733 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
         .collect()
 }
 
-fn format_export_wrappers(export_functions: &FunctionList, types: &TypeMap) -> Vec<String> {
-    export_functions
+/// Formats the functions that validate the structs with validated fields,
+/// including structs that only contain other structs that need validation.
+fn format_validators(types: &TypeMap, validated_structs: &BTreeSet<TypeIdent>) -> String {
+    validated_structs
+        .iter()
+        .filter_map(|ident| match types.get(ident) {
+            Some(Type::Struct(ty)) => Some(ty),
+            _ => None,
+        })
+        .map(|ty| {
+            let checks = format_unknown_field_check(ty)
+                .into_iter()
+                .chain(ty.fields.iter().flat_map(|field| {
+                    if field.attrs.flatten {
+                        // Flattened fields are part of the same object:
+                        return if validated_structs.contains(&field.ty) {
+                            vec![format!("validate{}(value as any);", field.ty.name)]
+                        } else {
+                            Vec::new()
+                        };
+                    }
+
+                    let value = format!(
+                        "value[{}]",
+                        serde_json::to_string(&get_field_name(field, ty.options.field_casing))
+                            .expect("Could not serialize field name")
+                    );
+                    let mut lines = format_field_checks(&ty.ident, field, &value, types);
+                    lines.append(&mut format_validation(
+                        &field.ty,
+                        &value,
+                        types,
+                        validated_structs,
+                        0,
+                    ));
+                    lines
+                }))
+                .collect::<Vec<_>>();
+            format!(
+                "\nexport function validate{}(value: types.{}) {{\n{}}}\n",
+                ty.ident.name,
+                ty.ident.name,
+                join_lines(&checks, |line| format!("    {line}"))
+            )
+        })
+        .collect()
+}
+
+/// Formats the check that rejects the fields that are not part of a struct,
+/// if it denies unknown fields. Tuple structs are serialized as arrays, so
+/// they have no fields to check.
+fn format_unknown_field_check(ty: &Struct) -> Vec<String> {
+    if !ty.options.deny_unknown_fields || ty.fields.iter().any(|field| field.name.is_none()) {
+        return Vec::new();
+    }
+
+    let field_names = ty
+        .fields
+        .iter()
+        .map(|field| get_field_name(field, ty.options.field_casing))
+        .collect::<Vec<_>>();
+    let field_names = serde_json::to_string(&field_names).expect("Could not serialize field names");
+    let message = serde_json::to_string(&format!("`{}` has unknown field `", ty.ident.name))
+        .expect("Could not serialize string");
+    vec![
+        "for (const key of Object.keys(value)) {".to_owned(),
+        format!("    if (!{field_names}.includes(key)) {{"),
+        format!("        throw new ValidationError({message} + key + \"`\");"),
+        "    }".to_owned(),
+        "}".to_owned(),
+    ]
+}
+
+/// Formats the checks for the `#[fp(validate(...))]` attribute of a field.
+fn format_field_checks(
+    owner: &TypeIdent,
+    field: &Field,
+    value: &str,
+    types: &TypeMap,
+) -> Vec<String> {
+    let validated = match get_validated_field(owner, field, types) {
+        Some(validated) => validated,
+        None => return Vec::new(),
+    };
+    let validation = validated.validation;
+    let to_js_string =
+        |value: &str| serde_json::to_string(value).expect("Could not serialize string");
+
+    let mut checks = Vec::new();
+    if let Some(range) = &validation.range {
+        let bounds =
+            range
+                .start
+                .iter()
+                .map(|start| format!("{value} >= {start}"))
+                .chain(range.end.iter().map(|end| {
+                    format!("{value} {} {end}", if range.inclusive { "<=" } else { "<" })
+                }))
+                .collect::<Vec<_>>()
+                .join(" && ");
+        let message = format!("`{}` must be in range {range}, got ", validated.name);
+        checks.push((
+            format!("!({bounds})"),
+            format!("{} + {value}", to_js_string(&message)),
+        ));
+    }
+    if validation.non_empty {
+        let condition = if validated.kind == ValidatedKind::Map {
+            format!("Object.keys({value}).length === 0")
+        } else {
+            format!("{value}.length === 0")
+        };
+        let message = format!("`{}` must not be empty", validated.name);
+        checks.push((condition, to_js_string(&message)));
+    }
+    if let Some(regex) = &validation.regex {
+        let message = format!(
+            "`{}` must match the regular expression `{regex}`",
+            validated.name
+        );
+        checks.push((
+            format!("!new RegExp({}).test({value})", to_js_string(regex)),
+            to_js_string(&message),
+        ));
+    }
+
+    checks
         .into_iter()
-        .flat_map(|function| {
-            let name = &function.name;
+        .flat_map(|(condition, message)| {
+            let condition = if validated.is_optional {
+                format!("{value} != null && {condition}")
+            } else {
+                condition
+            };
+            vec![
+                format!("if ({condition}) {{"),
+                format!("    throw new ValidationError({message});"),
+                "}".to_owned(),
+            ]
+        })
+        .collect()
+}
 
-            // Trivial functions can simply be returned as is:
-            if is_primitive_function(function) {
-                return vec![format!(
-                    "{}: instance.exports.__fp_gen_{} as any,",
-                    name.to_camel_case(),
-                    name
-                )];
-            }
+/// Formats the statements that validate the structs that are contained in the
+/// given value, if any.
+pub(crate) fn format_validation(
+    ident: &TypeIdent,
+    value: &str,
+    types: &TypeMap,
+    validated_structs: &BTreeSet<TypeIdent>,
+    depth: usize,
+) -> Vec<String> {
+    if !contains_validated_struct(ident, types, validated_structs) {
+        return Vec::new();
+    }
+
+    let first_arg = || {
+        &ident
+            .generic_args
+            .first()
+            .expect("Identifier was expected to contain a generic argument")
+            .0
+    };
+    let item = if depth == 0 {
+        "item".to_owned()
+    } else {
+        format!("item{depth}")
+    };
+    let (header, lines) = match types.get(ident) {
+        Some(Type::Alias(_, ident)) => {
+            return format_validation(ident, value, types, validated_structs, depth)
+        }
+        Some(Type::Container(_, _)) => (
+            format!("if ({value} != null) {{"),
+            format_validation(first_arg(), value, types, validated_structs, depth),
+        ),
+        Some(Type::List(_, _)) => (
+            format!("for (const {item} of {value}) {{"),
+            format_validation(first_arg(), &item, types, validated_structs, depth + 1),
+        ),
+        Some(Type::Map(_, _, _)) => {
+            let (arg, _) = ident
+                .generic_args
+                .get(1)
+                .expect("Identifier was expected to contain two arguments");
+            (
+                format!("for (const {item} of Object.values({value})) {{"),
+                format_validation(arg, &item, types, validated_structs, depth + 1),
+            )
+        }
+        _ => return vec![format!("validate{}({value});", ident.name)],
+    };
+
+    let mut result = vec![header];
+    result.extend(lines.into_iter().map(|line| format!("    {line}")));
+    result.push("}".to_owned());
+    result
+}
+
+/// Formats the statement that guards an import with the capability it
+/// requires, if any.
+fn format_capability_check(function: &Function) -> Option<String> {
+    function
+        .attrs
+        .capability
+        .as_ref()
+        .map(|capability| format!("checkCapability(\"{}\", \"{capability}\");", function.name))
+}
+
+/// Formats the statement that takes a token from the bucket of a rate-limited
+/// import, which yields the error that is returned instead of calling the
+/// import if none is available.
+fn format_rate_limit_check(function: &Function) -> Option<String> {
+    function.attrs.rate_limit.as_ref().map(|rate_limit| {
+        format!(
+            "const _rateLimited = rateLimit(plugin, \"{}\", {}, {});",
+            function.name, rate_limit.calls, rate_limit.per_ms
+        )
+    })
+}
 
+/// Formats the `__fp_host_batch()` import, which dispatches the calls that the
+/// plugin queued during a batch to the batchable import functions.
+fn format_batch_dispatcher(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+    conversions: &Conversions,
+    casing: &TsIdentifierCasing,
+    int64: TsInt64Encoding,
+) -> Vec<String> {
+    let cases = import_functions
+        .iter()
+        .filter(|function| function.attrs.batchable)
+        .map(|function| {
             let args = function
                 .args
                 .iter()
-                .map(|arg| {
-                    format!(
-                        "{}: {}",
-                        arg.name.to_camel_case(),
-                        format_plain_primitive_or_ident(&arg.ty, types)
-                    )
+                .enumerate()
+                .map(|(index, arg)| match arg.ty.as_primitive() {
+                    Some(Primitive::I64 | Primitive::U64) => {
+                        int64.format_from_decoded(&format!("args[{index}]"))
+                    }
+                    _ => conversions.format_conversion(&arg.ty, &format!("args[{index}]"), types),
                 })
                 .collect::<Vec<_>>()
                 .join(", ");
-            let export_args = function
+            let capability_check = format_capability_check(function)
+                .map(|line| format!("\n                {line}"))
+                .unwrap_or_default();
+            format!(
+                "            case \"{}\":{capability_check}
+                importFunctions.{}({args});
+                break;",
+                function.wire_name(),
+                casing.format_name(&function.name)
+            )
+        })
+        .collect::<Vec<_>>();
+    if cases.is_empty() {
+        return Vec::new();
+    }
+
+    format!(
+        "__fp_host_batch: (calls_ptr: FatPtr) => {{
+    const calls = parseObject<Array<Record<string, any>>>(plugin, calls_ptr);
+    for (const call of calls) {{
+        const [name, args] = Object.entries(call)[0];
+        switch (name) {{
+{}
+        }}
+    }}
+}},",
+        cases.join("\n")
+    )
+    .split('\n')
+    .map(str::to_owned)
+    .collect()
+}
+
+/// A function that calls an export of the plugin, which takes the plugin
+/// instance as its first argument.
+pub(super) struct ExportFunction {
+    docs: Vec<String>,
+    name: String,
+    /// The type parameter list of the function, if it is generic.
+    type_params: String,
+    /// The names and types of the arguments, excluding the plugin instance.
+    args: Vec<(String, String)>,
+    return_type: String,
+    body: Vec<String>,
+    entry: ExportEntry,
+}
+
+/// Determines how a function is exposed by `createExports()`.
+enum ExportEntry {
+    /// The function is always available.
+    Always,
+    /// The function is only available if the plugin implements the export
+    /// with the given name.
+    IfExported(String),
+    /// The export with the given name is exposed as is, because its arguments
+    /// and return value don't need any conversion.
+    Direct(String),
+}
+
+impl ExportFunction {
+    fn format_signature(&self) -> String {
+        format!(
+            "{}{}({}): {}",
+            self.name,
+            self.type_params,
+            std::iter::once("plugin: PluginInstance".to_owned())
+                .chain(self.args.iter().map(|(name, ty)| format!("{name}: {ty}")))
+                .collect::<Vec<_>>()
+                .join(", "),
+            self.return_type
+        )
+    }
+
+    /// Formats the property in the object that is returned by
+    /// `createExports()`, which binds the function to the plugin instance.
+    fn format_entry(&self) -> String {
+        let name = &self.name;
+        let args = self
+            .args
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        let wrapper = format!(
+            "({}) => {name}({})",
+            args.join(", "),
+            std::iter::once("plugin")
+                .chain(args.iter().copied())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        match &self.entry {
+            ExportEntry::Always => format!("{name}: {wrapper},"),
+            ExportEntry::IfExported(export) => format!(
+                "{name}: hasExport(plugin, \"{export}\")\n    ? {wrapper}\n    : undefined,"
+            ),
+            ExportEntry::Direct(export) => {
+                format!("{name}: plugin.instance.exports.{export} as any,")
+            }
+        }
+    }
+}
+
+fn format_export_functions(
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    validated_structs: &BTreeSet<TypeIdent>,
+    conversions: &Conversions,
+    casing: &TsIdentifierCasing,
+    int64: TsInt64Encoding,
+    payload_tracing: bool,
+) -> Vec<ExportFunction> {
+    export_functions
+        .into_iter()
+        .map(|function| {
+            let name = &function.name;
+            let trace = |arg_name: &str, direction: &str, ptr: String| {
+                format_payload_trace(payload_tracing, name, arg_name, direction, ptr)
+            };
+            let args = function
                 .args
                 .iter()
-                .filter(|arg| !arg.ty.is_primitive())
                 .map(|arg| {
-                    let wrapped_arg = if arg.ty.is_array() {
-                        // Arrays need to be converted from a typed array to a regular array,
-                        // otherwise msgpack decoding on the Rust side will fail (byte arrays
-                        // cannot be deserialized to Rust arrays by rmp-serde, currently).
-                        // Importing from Rust --> TS works fine though, so we don't need the
-                        // conversion there.
-                        format!("Array.from({})", arg.name.to_camel_case())
-                    } else {
-                        arg.name.to_camel_case()
-                    };
-
-                    format!(
-                        "const {} = serializeObject({});",
-                        get_pointer_name(&arg.name),
-                        wrapped_arg
+                    (
+                        casing.format_name(&arg.name),
+                        format_plain_primitive_or_ident(&arg.ty, types, int64),
                     )
                 })
-                .collect::<Vec<_>>();
+                .collect();
+            let mut body = vec![format!(
+                "const export_fn = getExport<any>(plugin, \"{}\", {});",
+                function.symbol_name(),
+                function.args.len()
+            )];
+            body.extend(
+                function
+                    .args
+                    .iter()
+                    .filter(|arg| !arg.ty.is_primitive())
+                    .map(|arg| {
+                        let wrapped_arg = if arg.ty.is_array() {
+                            // Arrays need to be converted from a typed array to a regular array,
+                            // otherwise msgpack decoding on the Rust side will fail (byte arrays
+                            // cannot be deserialized to Rust arrays by rmp-serde, currently).
+                            // Importing from Rust --> TS works fine though, so we don't need the
+                            // conversion there.
+                            format!("Array.from({})", casing.format_name(&arg.name))
+                        } else {
+                            casing.format_name(&arg.name)
+                        };
+
+                        format!(
+                            "const {} = {};",
+                            get_pointer_name(&arg.name),
+                            trace(
+                                &arg.name,
+                                "toPlugin",
+                                format!("serializeObject(plugin, {wrapped_arg})")
+                            )
+                        )
+                    }),
+            );
 
             let call_args = function
                 .args
                 .iter()
                 .map(|arg| {
-                    if arg.ty.is_primitive() {
-                        arg.name.to_camel_case()
+                    if int64.converts(&arg.ty) {
+                        int64.format_to_plugin(&arg.ty, &casing.format_name(&arg.name))
+                    } else if arg.ty.is_primitive() {
+                        casing.format_name(&arg.name)
                     } else {
                         get_pointer_name(&arg.name)
                     }
                 })
                 .collect::<Vec<_>>()
                 .join(", ");
-            let fn_call = if function.is_async {
-                format!(
-                    "return promiseFromPtr(export_fn({})).then((ptr) => parseObject<{}>(ptr));",
-                    call_args,
+            let validation = function
+                .return_type
+                .as_ref()
+                .map(|ty| format_validation(ty, "result", types, validated_structs, 0))
+                .unwrap_or_default();
+            let parse_result = |ptr: &str| {
+                let value = format!(
+                    "parseObject<{}>(plugin, {})",
                     function
                         .return_type
                         .as_ref()
                         .map(|ty| format_ident(ty, types, "types."))
                         .unwrap_or_else(|| "void".to_owned()),
+                    trace("result", "fromPlugin", ptr.to_owned())
+                );
+                match &function.return_type {
+                    Some(ty) => conversions.format_conversion(ty, &value, types),
+                    None => value,
+                }
+            };
+            let fn_call = if function.is_async && !validation.is_empty() {
+                format!(
+                    "return promiseFromPtr(plugin, export_fn({})).then((ptr) => {{\n    \
+                        const result = {};\n{}    return result;\n}});",
+                    call_args,
+                    parse_result("ptr"),
+                    join_lines(&validation, |line| format!("    {line}")),
+                )
+            } else if function.is_async {
+                format!(
+                    "return promiseFromPtr(plugin, export_fn({})).then((ptr) => {});",
+                    call_args,
+                    parse_result("ptr"),
                 )
             } else {
                 match &function.return_type {
                     None => format!("export_fn({call_args});"),
+                    Some(ty) if int64.converts(ty) => format!(
+                        "return {};",
+                        int64.format_from_plugin(ty, &format!("export_fn({call_args})"))
+                    ),
                     Some(ty) if ty.is_primitive() => format!(
                         "return {};",
                         import_primitive(ty, &format!("export_fn({call_args})"))
                     ),
-                    Some(ty) => format!(
-                        "return parseObject<{}>(export_fn({}));",
-                        format_ident(ty, types, "types."),
-                        call_args
+                    Some(ty) if ty.is_packed_pair() => format!(
+                        "return {};",
+                        import_packed_pair(ty, &format!("export_fn({call_args})"))
+                    ),
+                    Some(_) if !validation.is_empty() => format!(
+                        "const result = {};\n{}return result;",
+                        parse_result(&format!("export_fn({call_args})")),
+                        join_lines(&validation, String::clone),
+                    ),
+                    Some(_) => format!(
+                        "return {};",
+                        parse_result(&format!("export_fn({call_args})"))
                     ),
                 }
             };
-            let return_fn = if export_args.is_empty() {
-                format!("return ({}) => {}", args, fn_call.replace("return ", ""))
-            } else {
-                format!(
-                    "return ({}) => {{\n{}        {}\n    }};",
-                    args,
-                    join_lines(&export_args, |line| format!("        {line}")),
-                    fn_call
-                )
+            body.extend(fn_call.split('\n').map(str::to_owned));
+
+            // Cached exports only call the plugin if there is no result for
+            // the same key yet:
+            let body = match &function.attrs.cache {
+                Some(cache) => std::iter::once(format!(
+                    "return cachedExport(plugin, \"{}\", [{}], {}, () => {{",
+                    casing.format_name(name),
+                    cache
+                        .key_args(function)
+                        .into_iter()
+                        .map(|arg| casing.format_name(arg))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    cache.ttl_ms
+                ))
+                .chain(body.iter().map(|line| format!("    {line}")))
+                .chain(std::iter::once("});".to_owned()))
+                .collect(),
+                None => body,
             };
-            format!(
-                "{}: (() => {{
-    const export_fn = instance.exports.__fp_gen_{} as any;
-    if (!export_fn) return;
 
-    {}
-}})(),",
-                name.to_camel_case(),
-                name,
-                return_fn
-            )
-            .split('\n')
-            .map(str::to_owned)
-            .collect::<Vec<_>>()
+            // Errors are reported as a `PluginError` for the export:
+            let type_name = match &function.return_type {
+                Some(ty) if function.is_async || !(ty.is_primitive() || ty.is_packed_pair()) => {
+                    format!("{:?}", format_ident(ty, types, ""))
+                }
+                _ => "undefined".to_owned(),
+            };
+            let body = std::iter::once(format!(
+                "return callExport(\"{}\", {type_name}, () => {{",
+                casing.format_name(name)
+            ))
+            .chain(body.iter().map(|line| format!("    {line}")))
+            .chain(std::iter::once("});".to_owned()))
+            .collect();
+
+            ExportFunction {
+                docs: format_docs(&function.doc_lines),
+                name: casing.format_name(name),
+                type_params: String::new(),
+                args,
+                return_type: format_return_type(function, types, int64),
+                body,
+                entry: if is_primitive_function(function)
+                    && !function
+                        .args
+                        .iter()
+                        .map(|arg| &arg.ty)
+                        .chain(function.return_type.as_ref())
+                        .any(|ty| int64.converts(ty))
+                {
+                    // Trivial functions can simply be returned as is:
+                    ExportEntry::Direct(function.symbol_name())
+                } else {
+                    ExportEntry::IfExported(function.symbol_name())
+                },
+            }
         })
         .collect()
 }
 
-fn format_raw_export_wrappers(export_functions: &FunctionList) -> Vec<String> {
+/// Wraps the expression for the pointer to a serialized payload in a call to
+/// `traceGuestPayload()`, if payload tracing is enabled.
+fn format_payload_trace(
+    payload_tracing: bool,
+    function_name: &str,
+    name: &str,
+    direction: &str,
+    ptr: String,
+) -> String {
+    if payload_tracing {
+        format!(
+            "traceGuestPayload(plugin, \"{function_name}\", \"{name}\", \"{direction}\", {ptr})"
+        )
+    } else {
+        ptr
+    }
+}
+
+/// Formats the function that calls the `shutdown` export, which plugins may
+/// implement to clean up before they are discarded.
+fn format_dispose_function() -> ExportFunction {
+    ExportFunction {
+        docs: format_docs(&[
+            " Calls the `shutdown` export of the plugin, if it implements it, and releases"
+                .to_owned(),
+            " the resources that are held for it: pending promises are rejected with a".to_owned(),
+            " `PluginDisposedError`, and registered handlers are dropped. Exports that".to_owned(),
+            " exchange serialized values throw a `PluginDisposedError` afterwards.".to_owned(),
+        ]),
+        name: "dispose".to_owned(),
+        type_params: String::new(),
+        args: Vec::new(),
+        return_type: "void".to_owned(),
+        body: vec![
+            "if (plugin.disposed) {".to_owned(),
+            "    return;".to_owned(),
+            "}".to_owned(),
+            "try {".to_owned(),
+            "    if (hasExport(plugin, \"__fp_gen_shutdown\")) {".to_owned(),
+            "        getExport<() => void>(plugin, \"__fp_gen_shutdown\")();".to_owned(),
+            "    }".to_owned(),
+            "} finally {".to_owned(),
+            "    disposePlugin(plugin);".to_owned(),
+            "}".to_owned(),
+        ],
+        entry: ExportEntry::Always,
+    }
+}
+
+/// Formats the function that returns the exports that the plugin omits, as
+/// they were found when it was instantiated.
+fn format_missing_exports_function() -> ExportFunction {
+    ExportFunction {
+        docs: format_docs(&[
+            " Returns the names of the exports that the plugin omits.".to_owned(),
+            "".to_owned(),
+            " The exports of the plugin are checked against the protocol before it is".to_owned(),
+            " instantiated, so this allows hosts to log which capabilities the plugin".to_owned(),
+            " lacks at load time.".to_owned(),
+        ]),
+        name: "missingExports".to_owned(),
+        type_params: String::new(),
+        args: Vec::new(),
+        return_type: "string[]".to_owned(),
+        body: vec!["return plugin.missingExports ?? [];".to_owned()],
+        entry: ExportEntry::Always,
+    }
+}
+
+/// Formats the function that discards the memoized results of the exports
+/// that are annotated with `#[fp(cache)]`.
+fn format_clear_export_cache_function() -> ExportFunction {
+    ExportFunction {
+        docs: format_docs(&[
+            " Discards the memoized results of the exports that are cached.".to_owned(),
+        ]),
+        name: "clearExportCache".to_owned(),
+        type_params: String::new(),
+        args: Vec::new(),
+        return_type: "void".to_owned(),
+        body: vec!["clearCachedExports(plugin);".to_owned()],
+        entry: ExportEntry::Always,
+    }
+}
+
+/// Formats the function for retrieving memory statistics, which are only
+/// exported by plugins that are built with the `memory-stats` feature.
+fn format_memory_stats_function() -> ExportFunction {
+    ExportFunction {
+        docs: format_docs(&[
+            " Returns statistics about the memory that is allocated by the plugin.".to_owned(),
+            "".to_owned(),
+            " This is only supported for plugins that are built with the `memory-stats`".to_owned(),
+            " feature of `fp-bindgen-support`.".to_owned(),
+        ]),
+        name: "memoryStats".to_owned(),
+        type_params: String::new(),
+        args: Vec::new(),
+        return_type: "MemoryStats".to_owned(),
+        body: vec![
+            "const export_fn = getExport<any>(plugin, \"__fp_memory_stats\");".to_owned(),
+            "return parseObject<MemoryStats>(plugin, export_fn());".to_owned(),
+        ],
+        entry: ExportEntry::IfExported("__fp_memory_stats".to_owned()),
+    }
+}
+
+/// Formats the function for retrieving guest metrics, which are only exported
+/// by plugins with bindings that are generated with `guest_metrics` enabled.
+fn format_guest_metrics_function() -> ExportFunction {
+    ExportFunction {
+        docs: format_docs(&[
+            " Returns metrics about the calls that the plugin has made to imports, as".to_owned(),
+            " measured by the plugin itself.".to_owned(),
+            "".to_owned(),
+            " This is only supported for plugins with bindings that are generated with".to_owned(),
+            " `guest_metrics` enabled.".to_owned(),
+        ]),
+        name: "guestMetrics".to_owned(),
+        type_params: String::new(),
+        args: Vec::new(),
+        return_type: "GuestMetrics".to_owned(),
+        body: vec![
+            "const export_fn = getExport<any>(plugin, \"__fp_get_guest_metrics\");".to_owned(),
+            "return parseObject<GuestMetrics>(plugin, export_fn());".to_owned(),
+        ],
+        entry: ExportEntry::IfExported("__fp_get_guest_metrics".to_owned()),
+    }
+}
+
+fn format_raw_export_functions(
+    export_functions: &FunctionList,
+    casing: &TsIdentifierCasing,
+) -> Vec<ExportFunction> {
     export_functions
         .into_iter()
         .filter(|function| !is_primitive_function(function))
-        .flat_map(|function| {
+        .map(|function| {
             let name = &function.name;
             let args = function
                 .args
                 .iter()
-                .map(|arg| format!("{}: {}", arg.name.to_camel_case(), format_raw_type(&arg.ty)))
-                .collect::<Vec<_>>()
-                .join(", ");
-            let export_args = function
-                .args
-                .iter()
-                .filter(|arg| !arg.ty.is_primitive())
                 .map(|arg| {
-                    format!(
-                        "const {} = exportToMemory({});",
-                        get_pointer_name(&arg.name),
-                        arg.name.to_camel_case()
+                    (
+                        casing.format_name(&arg.name),
+                        format_raw_type(&arg.ty).to_owned(),
                     )
                 })
-                .collect::<Vec<_>>();
+                .collect();
+            let mut body = vec![format!(
+                "const export_fn = getExport<any>(plugin, \"{}\");",
+                function.symbol_name()
+            )];
+            body.extend(
+                function
+                    .args
+                    .iter()
+                    .filter(|arg| !arg.ty.is_primitive())
+                    .map(|arg| {
+                        format!(
+                            "const {} = exportToMemory(plugin, {});",
+                            get_pointer_name(&arg.name),
+                            casing.format_name(&arg.name)
+                        )
+                    }),
+            );
 
             let call_args = function
                 .args
                 .iter()
                 .map(|arg| {
                     if arg.ty.is_primitive() {
-                        arg.name.to_camel_case()
+                        casing.format_name(&arg.name)
                     } else {
                         get_pointer_name(&arg.name)
                     }
                 })
                 .collect::<Vec<_>>()
                 .join(", ");
-            let fn_call = if function.is_async {
-                format!("return promiseFromPtr(export_fn({call_args})).then(importFromMemory);")
+            body.push(if function.is_async {
+                format!(
+                    "return promiseFromPtr(plugin, export_fn({call_args})).then((ptr) => importFromMemory(plugin, ptr));"
+                )
             } else {
                 match &function.return_type {
                     None => format!("export_fn({call_args});"),
@@ -626,41 +5079,35 @@ fn format_raw_export_wrappers(export_functions: &FunctionList) -> Vec<String> {
                         "return {};",
                         if ty.is_primitive() {
                             import_primitive(ty, &format!("export_fn({call_args})"))
+                        } else if ty.is_packed_pair() {
+                            import_packed_pair(ty, &format!("export_fn({call_args})"))
                         } else {
-                            format!("importFromMemory(export_fn({call_args}))")
+                            format!("importFromMemory(plugin, export_fn({call_args}))")
                         }
                     ),
                 }
-            };
-            let return_fn = if export_args.is_empty() {
-                format!("return ({}) => {}", args, fn_call.replace("return ", ""))
-            } else {
-                format!(
-                    "return ({}) => {{\n{}        {}\n    }};",
-                    args,
-                    join_lines(&export_args, |line| format!("        {line}")),
-                    fn_call
-                )
-            };
-            format!(
-                "{}Raw: (() => {{
-    const export_fn = instance.exports.__fp_gen_{} as any;
-    if (!export_fn) return;
+            });
 
-    {}
-}})(),",
-                name.to_camel_case(),
-                name,
-                return_fn
-            )
-            .split('\n')
-            .map(str::to_owned)
-            .collect::<Vec<_>>()
+            ExportFunction {
+                docs: Vec::new(),
+                name: format!("{}Raw", casing.format_name(name)),
+                type_params: String::new(),
+                args,
+                return_type: format_raw_return_type(function),
+                body,
+                entry: ExportEntry::IfExported(function.symbol_name()),
+            }
         })
         .collect()
 }
 
-fn generate_type_bindings(types: &TypeMap, path: &str) {
+fn generate_type_bindings(
+    types: &TypeMap,
+    schema_hashes: &SchemaHashes,
+    path: &str,
+    config: &TsExtendedRuntimeConfig,
+    headers: &FileHeaders,
+) {
     let type_defs = types
         .values()
         .filter_map(|ty| match ty {
@@ -681,25 +5128,137 @@ fn generate_type_bindings(types: &TypeMap, path: &str) {
                 ts_declaration: Some(ts_declaration),
                 ..
             }) => Some(format!("export type {ts_ty} = {ts_declaration};")),
-            Type::Enum(ty) => Some(create_enum_definition(ty, types)),
-            Type::Struct(ty) => Some(create_struct_definition(ty, types)),
+            Type::Enum(ty) if config.generate_string_enums && is_string_enum(ty) => {
+                Some(create_string_enum_definition(ty))
+            }
+            Type::Enum(ty) => Some(create_enum_definition(ty, types, config)),
+            Type::Struct(ty) => Some(create_struct_definition(ty, types, config)),
             _ => None,
         })
         .collect::<Vec<_>>();
+    let schema_hashes = collect_schema_hashes(types, schema_hashes);
 
-    write_bindings_file(
-        format!("{path}/types.ts"),
-        format!(
-            "// ============================================= //
+    let header = "// ============================================= //
 // Types for WebAssembly runtime                 //
 //                                               //
 // This file is generated. PLEASE DO NOT MODIFY. //
-// ============================================= //
+// ============================================= //";
 
-{}\n",
-            type_defs.join("\n\n")
-        ),
-    )
+    if !config.generate_js_output {
+        let type_defs = type_defs
+            .into_iter()
+            .chain(create_schema_hashes_definition(&schema_hashes))
+            .collect::<Vec<_>>();
+        write_bindings_file(
+            format!("{path}/types.ts"),
+            format!("{header}\n\n{}\n", type_defs.join("\n\n")),
+            headers,
+        );
+        return;
+    }
+
+    let declarations = type_defs
+        .into_iter()
+        .chain(create_schema_hashes_declaration(&schema_hashes))
+        .collect::<Vec<_>>();
+    write_js_declarations(
+        path,
+        "types",
+        format!("{header}\n\n{}\n", declarations.join("\n\n")),
+        config.module_format,
+        headers,
+    );
+
+    // String enums and the schema hashes are the only types that exist at
+    // runtime, so they also need to be emitted as JavaScript:
+    let enum_objects = types
+        .values()
+        .filter_map(|ty| match ty {
+            Type::Enum(ty) if config.generate_string_enums && is_string_enum(ty) => {
+                Some(create_string_enum_object(ty))
+            }
+            _ => None,
+        })
+        .chain(create_schema_hashes_object(&schema_hashes))
+        .collect::<Vec<_>>();
+    if !enum_objects.is_empty() {
+        write_js_module(
+            path,
+            "types",
+            &format!("{header}\n\n{}\n", enum_objects.join("\n\n")),
+            config.module_format,
+            headers,
+        );
+    }
+}
+
+/// Returns the schema hashes of the structs, enums and aliases for which
+/// types are declared, by type name.
+fn collect_schema_hashes<'a>(
+    types: &'a TypeMap,
+    schema_hashes: &SchemaHashes,
+) -> Vec<(&'a str, String)> {
+    types
+        .values()
+        .filter_map(|ty| match ty {
+            Type::Alias(name, _) => Some(name.as_str()),
+            Type::Enum(ty) => Some(ty.ident.name.as_str()),
+            Type::Struct(ty) => Some(ty.ident.name.as_str()),
+            _ => None,
+        })
+        .filter_map(|name| schema_hashes.get(name).map(|hash| (name, hash.clone())))
+        .collect()
+}
+
+const SCHEMA_HASHES_DOC: &str = "/**
+ * Hashes of the wire format of the protocol's types, which change whenever
+ * values serialized with an earlier version of a type may no longer be
+ * deserialized.
+ */";
+
+fn create_schema_hashes_definition(schema_hashes: &[(&str, String)]) -> Option<String> {
+    if schema_hashes.is_empty() {
+        return None;
+    }
+
+    let entries = schema_hashes
+        .iter()
+        .map(|(name, hash)| format!("    {name}: \"{hash}\",\n"))
+        .collect::<Vec<_>>()
+        .join("");
+    Some(format!(
+        "{SCHEMA_HASHES_DOC}\nexport const SCHEMA_HASHES = {{\n{entries}}} as const;"
+    ))
+}
+
+fn create_schema_hashes_declaration(schema_hashes: &[(&str, String)]) -> Option<String> {
+    if schema_hashes.is_empty() {
+        return None;
+    }
+
+    let entries = schema_hashes
+        .iter()
+        .map(|(name, hash)| format!("    readonly {name}: \"{hash}\";\n"))
+        .collect::<Vec<_>>()
+        .join("");
+    Some(format!(
+        "{SCHEMA_HASHES_DOC}\nexport declare const SCHEMA_HASHES: {{\n{entries}}};"
+    ))
+}
+
+fn create_schema_hashes_object(schema_hashes: &[(&str, String)]) -> Option<String> {
+    if schema_hashes.is_empty() {
+        return None;
+    }
+
+    let entries = schema_hashes
+        .iter()
+        .map(|(name, hash)| format!("    {name}: \"{hash}\",\n"))
+        .collect::<Vec<_>>()
+        .join("");
+    Some(format!(
+        "export const SCHEMA_HASHES = Object.freeze({{\n{entries}}});"
+    ))
 }
 
 fn is_primitive_function(function: &Function) -> bool {
@@ -715,7 +5274,73 @@ fn is_primitive_function(function: &Function) -> bool {
             .unwrap_or(true)
 }
 
-fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
+/// Returns whether the given enum can be represented as a TypeScript string
+/// enum. This is the case if all its variants are unit variants that are
+/// serialized as plain strings, and no other variants may be added.
+fn is_string_enum(ty: &Enum) -> bool {
+    ty.ident.generic_args.is_empty()
+        && !ty.options.non_exhaustive
+        && ty.options.tag_prop_name.is_none()
+        && !ty.options.untagged
+        && !ty.variants.is_empty()
+        && ty
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.ty, Type::Unit))
+}
+
+fn create_string_enum_definition(ty: &Enum) -> String {
+    let members = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            let mut lines = format_docs(&variant.doc_lines);
+            lines.push(format!(
+                "{} = \"{}\",",
+                get_variable_name(&variant.name),
+                get_variant_name(variant, &ty.options)
+            ));
+            join_lines(&lines, |line| format!("    {line}"))
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "{}export enum {} {{\n{}}}",
+        join_lines(&format_docs(&ty.doc_lines), String::to_owned),
+        ty.ident.format(false),
+        members
+    )
+}
+
+/// Creates the JavaScript object that a string enum compiles to.
+fn create_string_enum_object(ty: &Enum) -> String {
+    let members = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            format!(
+                "    {}: \"{}\",\n",
+                get_variable_name(&variant.name),
+                get_variant_name(variant, &ty.options)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "export const {} = Object.freeze({{\n{}}});",
+        ty.ident.format(false),
+        members
+    )
+}
+
+fn create_enum_definition(ty: &Enum, types: &TypeMap, config: &TsExtendedRuntimeConfig) -> String {
+    let modifier = if config.generate_readonly_types {
+        "readonly "
+    } else {
+        ""
+    };
     let variants = ty
         .variants
         .iter()
@@ -724,7 +5349,7 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
             let variant_decl = match &variant.ty {
                 Type::Unit => {
                     if let Some(tag) = &ty.options.tag_prop_name {
-                        format!("| {{ {tag}: \"{variant_name}\" }}")
+                        format!("| {{ {modifier}{tag}: \"{variant_name}\" }}")
                     } else {
                         format!("| \"{variant_name}\"")
                     }
@@ -736,7 +5361,8 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
                             format_struct_fields(
                                 &struct_variant.fields,
                                 types,
-                                variant.attrs.field_casing
+                                variant.attrs.field_casing,
+                                config.generate_readonly_types
                             )
                             .join(" ")
                         )
@@ -745,6 +5371,7 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
                             &struct_variant.fields,
                             types,
                             variant.attrs.field_casing,
+                            config.generate_readonly_types,
                         );
                         let formatted_fields = if field_lines.len() > struct_variant.fields.len() {
                             format!(
@@ -758,7 +5385,7 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
                         match (&ty.options.tag_prop_name, &ty.options.content_prop_name) {
                             (Some(tag), Some(content)) => {
                                 format!(
-                                    "| {{ {tag}: \"{variant_name}\"; {content}: {{{formatted_fields}}} }}"
+                                    "| {{ {modifier}{tag}: \"{variant_name}\"; {modifier}{content}: {{{formatted_fields}}} }}"
                                 )
                             }
                             (Some(tag), None) => {
@@ -768,44 +5395,42 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
                                     " "
                                 };
                                 format!(
-                                    "| {{{space}{tag}: \"{variant_name}\";{formatted_fields}}}"
+                                    "| {{{space}{modifier}{tag}: \"{variant_name}\";{formatted_fields}}}"
                                 )
                             }
                             (None, _) => {
-                                format!("| {{ {variant_name}: {{{formatted_fields}}} }}")
+                                format!("| {{ {modifier}{variant_name}: {{{formatted_fields}}} }}")
                             }
                         }
                     }
                 }
-                Type::Tuple(items) if items.len() == 1 => {
-                    let item = items.first().unwrap();
+                Type::Tuple(items) => {
+                    // Variants with multiple unnamed fields contain a tuple:
+                    let content = match items.as_slice() {
+                        [item] => format_ident(item, types, ""),
+                        items => format!(
+                            "[{}]",
+                            items
+                                .iter()
+                                .map(|item| format_ident(item, types, ""))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    };
                     if ty.options.untagged {
-                        format!("| {}", format_ident(item, types, ""))
+                        format!("| {content}")
                     } else {
                         match (&ty.options.tag_prop_name, &ty.options.content_prop_name) {
-                            (Some(tag), Some(content)) => {
+                            (Some(tag), Some(content_prop_name)) => {
                                 format!(
-                                    "| {{ {}: \"{}\"; {}: {} }}",
-                                    tag,
-                                    variant_name,
-                                    content,
-                                    format_ident(item, types, "")
+                                    "| {{ {modifier}{tag}: \"{variant_name}\"; {modifier}{content_prop_name}: {content} }}"
                                 )
                             }
                             (Some(tag), None) => {
-                                format!(
-                                    "| {{ {}: \"{}\" }} & {}",
-                                    tag,
-                                    variant_name,
-                                    format_ident(item, types, "")
-                                )
+                                format!("| {{ {modifier}{tag}: \"{variant_name}\" }} & {content}")
                             }
                             (None, _) => {
-                                format!(
-                                    "| {{ {}: {} }}",
-                                    variant_name,
-                                    format_ident(item, types, "")
-                                )
+                                format!("| {{ {modifier}{variant_name}: {content} }}")
                             }
                         }
                     }
@@ -831,6 +5456,11 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
 
             join_lines(&lines, |line| format!("    {line}"))
         })
+        .chain(
+            ty.options
+                .non_exhaustive
+                .then(|| create_unknown_variant(&ty.options, modifier)),
+        )
         .collect::<Vec<_>>()
         .join("");
 
@@ -842,17 +5472,69 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
     )
 }
 
-fn create_struct_definition(ty: &Struct, types: &TypeMap) -> String {
+/// Creates the catch-all for the variants of a non-exhaustive enum that were
+/// added after the bindings were generated.
+fn create_unknown_variant(options: &EnumOptions, modifier: &str) -> String {
+    let variant_decl = match (&options.tag_prop_name, &options.content_prop_name) {
+        (Some(tag), Some(content)) => {
+            format!("| {{ {modifier}{tag}: string; {modifier}{content}?: unknown }}")
+        }
+        (Some(tag), None) => format!("| {{ {modifier}{tag}: string }}"),
+        (None, _) => format!("| string\n| {{ {modifier}[variant: string]: unknown }}"),
+    };
+    let mut lines =
+        format_docs(&[" A variant that was added after these bindings were generated.".to_owned()]);
+    lines.extend(variant_decl.split('\n').map(str::to_owned));
+    join_lines(&lines, |line| format!("    {line}"))
+}
+
+fn create_struct_definition(
+    ty: &Struct,
+    types: &TypeMap,
+    config: &TsExtendedRuntimeConfig,
+) -> String {
     let is_newtype = ty.fields.len() == 1 && ty.fields.iter().any(|field| field.name.is_none());
+    if ty.options.brand && config.generate_branded_types && !is_newtype {
+        panic!(
+            "Struct `{}` is annotated with `#[fp(brand)]`, but only newtype structs can be \
+                branded.",
+            ty.ident
+        );
+    }
+
     if is_newtype {
+        let inner_type = ty
+            .fields
+            .first()
+            .map(|field| {
+                if field.attrs.has_custom_serializer() || field.attrs.ts_type.is_some() {
+                    get_custom_field_type(field)
+                } else {
+                    format_ident(&field.ty, types, "")
+                }
+            })
+            .unwrap();
         format!(
             "{}export type {} = {};",
             join_lines(&format_docs(&ty.doc_lines), String::to_owned),
             ty.ident,
+            if ty.options.brand && config.generate_branded_types {
+                format_branded_type(&inner_type, &ty.ident.name)
+            } else {
+                inner_type
+            }
+        )
+    } else if !ty.fields.is_empty() && ty.fields.iter().all(|field| field.name.is_none()) {
+        // Tuple structs are serialized as arrays, just like tuples:
+        format!(
+            "{}export type {} = [{}];",
+            join_lines(&format_docs(&ty.doc_lines), String::to_owned),
+            ty.ident.format(false),
             ty.fields
-                .first()
+                .iter()
                 .map(|field| format_ident(&field.ty, types, ""))
-                .unwrap()
+                .collect::<Vec<_>>()
+                .join(", ")
         )
     } else {
         let (flattened_fields, fields): (Vec<_>, Vec<_>) =
@@ -866,7 +5548,8 @@ fn create_struct_definition(ty: &Struct, types: &TypeMap) -> String {
                 &format_struct_fields(
                     &fields.into_iter().cloned().collect::<Vec<_>>(),
                     types,
-                    ty.options.field_casing
+                    ty.options.field_casing,
+                    config.generate_readonly_types
                 ),
                 |line| format!("    {line}")
             )
@@ -880,6 +5563,17 @@ fn create_struct_definition(ty: &Struct, types: &TypeMap) -> String {
     }
 }
 
+/// Formats the given type as a branded type, which can only be assigned
+/// values that are explicitly cast to it, such as `value as UserId`.
+fn format_branded_type(inner_type: &str, brand: &str) -> String {
+    let inner_type = if inner_type.contains(" | ") {
+        format!("({inner_type})")
+    } else {
+        inner_type.to_owned()
+    };
+    format!("{inner_type} & {{ readonly __brand: \"{brand}\" }}")
+}
+
 fn format_docs(doc_lines: &[String]) -> Vec<String> {
     if doc_lines.is_empty() {
         Vec::new()
@@ -896,12 +5590,30 @@ fn format_docs(doc_lines: &[String]) -> Vec<String> {
     }
 }
 
-fn format_struct_fields(fields: &[Field], types: &TypeMap, casing: Casing) -> Vec<String> {
+fn format_struct_fields(
+    fields: &[Field],
+    types: &TypeMap,
+    casing: Casing,
+    readonly: bool,
+) -> Vec<String> {
+    let modifier = if readonly { "readonly " } else { "" };
     fields
         .iter()
         .flat_map(|field| {
             let has_skip_serializing_attribute = field.attrs.skip_serializing_if.is_some();
             let field_decl = match types.get(&field.ty) {
+                _ if field.attrs.has_custom_serializer() || field.attrs.ts_type.is_some() => {
+                    format!(
+                        "{modifier}{}{}: {};",
+                        get_field_name(field, casing),
+                        if has_skip_serializing_attribute {
+                            "?"
+                        } else {
+                            ""
+                        },
+                        get_custom_field_type(field)
+                    )
+                }
                 Some(Type::Container(name, _)) => {
                     let is_option_type = name == "Option";
                     let (arg, _) = field
@@ -910,14 +5622,14 @@ fn format_struct_fields(fields: &[Field], types: &TypeMap, casing: Casing) -> Ve
                         .first()
                         .expect("Identifier was expected to contain a generic argument");
                     format!(
-                        "{}{}: {}{};",
+                        "{modifier}{}{}: {}{};",
                         get_field_name(field, casing),
                         if is_option_type && has_skip_serializing_attribute {
                             "?"
                         } else {
                             ""
                         },
-                        format_ident(arg, types, ""),
+                        format_field_ident(field, arg, types),
                         if is_option_type && !has_skip_serializing_attribute {
                             " | null"
                         } else {
@@ -926,14 +5638,14 @@ fn format_struct_fields(fields: &[Field], types: &TypeMap, casing: Casing) -> Ve
                     )
                 }
                 _ => format!(
-                    "{}{}: {};",
+                    "{modifier}{}{}: {};",
                     get_field_name(field, casing),
                     if has_skip_serializing_attribute {
                         "?"
                     } else {
                         ""
                     },
-                    format_ident(&field.ty, types, ""),
+                    format_field_ident(field, &field.ty, types),
                 ),
             };
             if field.doc_lines.is_empty() {
@@ -948,6 +5660,31 @@ fn format_struct_fields(fields: &[Field], types: &TypeMap, casing: Casing) -> Ve
         .collect()
 }
 
+/// Formats the type of a field, or of the content of an optional field, which
+/// is `Date` for fields that are annotated with `#[fp(ts_as = "Date")]`.
+fn format_field_ident(field: &Field, ident: &TypeIdent, types: &TypeMap) -> String {
+    if conversions::is_date_field(field) {
+        "Date".to_owned()
+    } else {
+        format_ident(ident, types, "")
+    }
+}
+
+/// Returns the TypeScript type for a field that has an explicit `ts_type`
+/// annotation. Fields with a custom (de)serializer are required to have one,
+/// because their wire shape cannot be derived from the Rust type.
+fn get_custom_field_type(field: &Field) -> String {
+    field.attrs.ts_type.clone().unwrap_or_else(|| {
+        panic!(
+            "Field `{}` of type `{}` uses a custom (de)serializer, but has no explicit \
+                TypeScript type. Please add a `#[fp(ts_type = \"...\")]` annotation that \
+                describes its serialized representation.",
+            field.name.as_deref().unwrap_or("0"),
+            field.ty
+        )
+    })
+}
+
 fn format_raw_type(ty: &TypeIdent) -> &str {
     if let Some(primitive) = ty.as_primitive() {
         format_plain_primitive(primitive)
@@ -999,14 +5736,20 @@ fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap, scope:
                 format!("{}{}<{}>", scope, ident.name, args.join(", "))
             }
         }
-        Type::List(_, _) => {
+        Type::List(name, _) => {
             let (arg, _) = ident
                 .generic_args
                 .first()
                 .expect("Identifier was expected to contain a generic argument");
-            format!("Array<{}>", format_ident(arg, types, scope))
+            // Sets are only renamed to `Set` if `generate_set_types` is enabled,
+            // and read-only types are only used if `generate_readonly_types` is:
+            let container = match name.as_str() {
+                "Set" | READONLY_ARRAY | READONLY_SET => name.as_str(),
+                _ => "Array",
+            };
+            format!("{container}<{}>", format_ident(arg, types, scope))
         }
-        Type::Map(_, _, _) => {
+        Type::Map(name, _, _) => {
             let (arg1, _) = ident
                 .generic_args
                 .first()
@@ -1015,11 +5758,16 @@ fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap, scope:
                 .generic_args
                 .get(1)
                 .expect("Identifier was expected to contain two arguments");
-            format!(
+            let record = format!(
                 "Record<{}, {}>",
                 format_ident(arg1, types, scope),
                 format_ident(arg2, types, scope)
-            )
+            );
+            if name == READONLY_RECORD {
+                format!("Readonly<{record}>")
+            } else {
+                record
+            }
         }
         Type::Primitive(primitive) => format_encoded_primitive(*primitive).to_owned(),
         Type::String => "string".to_owned(),
@@ -1051,8 +5799,16 @@ fn format_plain_primitive(primitive: Primitive) -> &'static str {
     }
 }
 
-fn format_plain_primitive_or_ident(ident: &TypeIdent, types: &TypeMap) -> String {
-    if let Some(primitive) = ident.as_primitive() {
+/// Formats the type of an argument or return value, as it is exposed to the
+/// host.
+pub(crate) fn format_plain_primitive_or_ident(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    int64: TsInt64Encoding,
+) -> String {
+    if let Some(Primitive::I64 | Primitive::U64) = ident.as_primitive() {
+        int64.format_type().to_owned()
+    } else if let Some(primitive) = ident.as_primitive() {
         format_plain_primitive(primitive).to_owned()
     } else {
         format_ident(ident, types, "types.")
@@ -1111,6 +5867,41 @@ fn import_primitive(ty: &TypeIdent, value: &str) -> String {
     }
 }
 
+/// Unpacks a pair of primitives that was returned packed into a `u64`.
+fn import_packed_pair(ty: &TypeIdent, value: &str) -> String {
+    let (first, second) = ty.as_packed_pair().expect("Expected a packed pair");
+    let from_bits = |primitive| match primitive {
+        Primitive::Bool => "(bits) => bits !== 0",
+        Primitive::F32 => "f32FromBits",
+        Primitive::I8 | Primitive::I16 | Primitive::I32 => "(bits) => bits | 0",
+        _ => "(bits) => bits",
+    };
+    format!(
+        "unpackPair({value}, {}, {})",
+        from_bits(first),
+        from_bits(second)
+    )
+}
+
+/// Packs a pair of primitives into a `u64`, so it can be returned to the plugin.
+fn export_packed_pair(ty: &TypeIdent, value: &str) -> String {
+    let (first, second) = ty.as_packed_pair().expect("Expected a packed pair");
+    let to_bits = |primitive| match primitive {
+        Primitive::Bool => "(value) => (value ? 1 : 0)",
+        Primitive::F32 => "f32ToBits",
+        _ => "(value) => value",
+    };
+    format!("packPair({value}, {}, {})", to_bits(first), to_bits(second))
+}
+
+fn format_packed_pair_type((first, second): (Primitive, Primitive)) -> String {
+    format!(
+        "[{}, {}]",
+        format_plain_primitive(first),
+        format_plain_primitive(second)
+    )
+}
+
 fn needs_primitive_cast(ty: &TypeIdent) -> bool {
     matches!(ty.name.as_str(), "bool" | "i8" | "i16" | "i32" | "i64")
 }
@@ -1137,9 +5928,41 @@ where
     }
 }
 
-fn write_bindings_file<C>(file_path: String, contents: C)
-where
-    C: AsRef<[u8]>,
-{
-    fs::write(file_path, &contents).expect("Could not write bindings file");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::FieldAttrs;
+
+    fn custom_serializer_field(ts_type: Option<&str>) -> Field {
+        Field {
+            name: Some("amount".to_owned()),
+            ty: TypeIdent::from("Decimal"),
+            doc_lines: Vec::new(),
+            attrs: FieldAttrs {
+                deserialize_with: Some("decimal::deserialize".to_owned()),
+                serialize_with: Some("decimal::serialize".to_owned()),
+                ts_type: ts_type.map(str::to_owned),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_custom_field_type() {
+        let fields = [custom_serializer_field(Some("string"))];
+        assert_eq!(
+            format_struct_fields(&fields, &TypeMap::new(), Casing::Original, false),
+            vec!["amount: string;".to_owned()]
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Field `amount` of type `Decimal` uses a custom (de)serializer, \
+        but has no explicit TypeScript type"
+    )]
+    fn test_custom_field_without_ts_type() {
+        let fields = [custom_serializer_field(None)];
+        format_struct_fields(&fields, &TypeMap::new(), Casing::Original, false);
+    }
 }
@@ -0,0 +1,42 @@
+//! Renaming of the collection types into their read-only counterparts, for
+//! runtimes that are generated with `generate_readonly_types`.
+//!
+//! Lists are typed as `ReadonlyArray<T>`, sets as `ReadonlySet<T>` and maps
+//! as `Readonly<Record<K, V>>`. This applies to function signatures as well
+//! as to the generated types, so that values taken from a read-only field can
+//! be passed back to the plugin as they are.
+
+use crate::types::{Type, TypeMap};
+
+/// The name of read-only lists, after they have been renamed by
+/// `with_readonly_types()`.
+pub(super) const READONLY_ARRAY: &str = "ReadonlyArray";
+
+/// The name of read-only sets, after they have been renamed by
+/// `with_readonly_types()`.
+pub(super) const READONLY_SET: &str = "ReadonlySet";
+
+/// The name of read-only maps, after they have been renamed by
+/// `with_readonly_types()`.
+pub(super) const READONLY_RECORD: &str = "ReadonlyRecord";
+
+/// Renames the list and map types in the given map, so they are formatted as
+/// read-only TypeScript types. Sets must already have been renamed by
+/// `with_set_types()`, if enabled.
+pub(super) fn with_readonly_types(types: TypeMap) -> TypeMap {
+    types
+        .into_iter()
+        .map(|(ident, ty)| match ty {
+            Type::List(name, item) => {
+                let name = if name == "Set" {
+                    READONLY_SET
+                } else {
+                    READONLY_ARRAY
+                };
+                (ident, Type::List(name.to_owned(), item))
+            }
+            Type::Map(_, key, value) => (ident, Type::Map(READONLY_RECORD.to_owned(), key, value)),
+            ty => (ident, ty),
+        })
+        .collect()
+}
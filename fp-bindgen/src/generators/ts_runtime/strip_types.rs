@@ -0,0 +1,601 @@
+//! Minimal TypeScript-to-JavaScript conversion for the generated runtime.
+//!
+//! This is not a general-purpose TypeScript compiler. It only understands the
+//! constructs that are emitted by the TypeScript runtime generator, which
+//! allows us to emit plain JavaScript without requiring a build step:
+//!
+//! - `import type` statements and `type` declarations are removed.
+//! - Type annotations on function parameters (including rest parameters and
+//!   destructuring patterns), return types and variable declarations are
+//!   removed.
+//! - Type arguments of calls (`parseObject<T>(...)`) and type parameters of
+//!   functions (`function parseObject<T>(...)`) are removed.
+//! - `as` casts, non-null assertions (`value!`) and definite assignment
+//!   assertions (`let value!: Type`) are removed.
+//! - Class field declarations (`name: Type;` on a line of their own) are
+//!   removed, since their values are assigned by the constructor.
+//!
+//! Declarations that would need to be compiled to JavaScript, rather than
+//! removed, such as `enum` and `namespace`, are rejected with a panic, so that
+//! the generator cannot silently emit invalid JavaScript.
+
+/// Strips all type information from the given TypeScript source.
+pub(super) fn strip_types(source: &str) -> String {
+    let chars = source.chars().collect::<Vec<_>>();
+    let mut stripper = Stripper {
+        chars: &chars,
+        pos: 0,
+        output: String::with_capacity(source.len()),
+    };
+    stripper.strip_until(&[]);
+
+    // Removed declarations may leave excess blank lines behind:
+    let mut output = stripper.output;
+    while output.contains("\n\n\n") {
+        output = output.replace("\n\n\n", "\n\n");
+    }
+    output
+}
+
+struct Stripper<'a> {
+    chars: &'a [char],
+    pos: usize,
+    output: String,
+}
+
+impl<'a> Stripper<'a> {
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn starts_with(&self, pos: usize, pattern: &str) -> bool {
+        pattern
+            .chars()
+            .enumerate()
+            .all(|(i, c)| self.chars.get(pos + i) == Some(&c))
+    }
+
+    fn is_at_line_start(&self) -> bool {
+        self.pos == 0 || self.chars[self.pos - 1] == '\n'
+    }
+
+    fn copy_char(&mut self) {
+        self.output.push(self.chars[self.pos]);
+        self.pos += 1;
+    }
+
+    fn copy_whitespace(&mut self) {
+        while matches!(self.peek(0), Some(c) if c.is_whitespace()) {
+            self.copy_char();
+        }
+    }
+
+    fn skip_whitespace_from(&self, mut pos: usize) -> usize {
+        while matches!(self.chars.get(pos), Some(c) if c.is_whitespace()) {
+            pos += 1;
+        }
+        pos
+    }
+
+    /// Returns the position right after the string literal or comment that
+    /// starts at `pos`, if any.
+    fn skip_literal_or_comment(&self, pos: usize) -> Option<usize> {
+        match self.chars.get(pos) {
+            Some(&quote) if quote == '"' || quote == '\'' || quote == '`' => {
+                let mut pos = pos + 1;
+                while pos < self.chars.len() && self.chars[pos] != quote {
+                    if self.chars[pos] == '\\' {
+                        pos += 1;
+                    }
+                    pos += 1;
+                }
+                Some(pos + 1)
+            }
+            Some('/') if self.starts_with(pos, "//") => Some(
+                (pos..self.chars.len())
+                    .find(|&pos| self.chars[pos] == '\n')
+                    .unwrap_or(self.chars.len()),
+            ),
+            Some('/') if self.starts_with(pos, "/*") => Some(
+                (pos + 2..self.chars.len())
+                    .find(|&pos| self.starts_with(pos, "*/"))
+                    .map(|pos| pos + 2)
+                    .unwrap_or(self.chars.len()),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Returns the position of the bracket that closes the one at `pos`.
+    ///
+    /// Angle brackets are only taken into account if the bracket at `pos` is
+    /// one, because they are used as comparison operators outside of types.
+    fn find_closing_bracket(&self, mut pos: usize) -> Option<usize> {
+        let count_angle_brackets = self.chars.get(pos) == Some(&'<');
+        let mut depth = 0;
+        while pos < self.chars.len() {
+            if let Some(end) = self.skip_literal_or_comment(pos) {
+                pos = end;
+                continue;
+            }
+            match self.chars[pos] {
+                '(' | '[' | '{' => depth += 1,
+                '<' if count_angle_brackets => depth += 1,
+                '>' if count_angle_brackets && pos > 0 && self.chars[pos - 1] == '=' => {}
+                ')' | ']' | '}' => depth -= 1,
+                '>' if count_angle_brackets => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                return Some(pos);
+            }
+            pos += 1;
+        }
+        None
+    }
+
+    /// Returns the position right after the type that starts at `pos`.
+    ///
+    /// Return types of arrow functions are terminated by `=>`, while function
+    /// types inside parameter annotations continue past it.
+    fn find_type_end(&self, pos: usize, is_return_type: bool) -> usize {
+        let mut pos = self.skip_whitespace_from(pos);
+        let start = pos;
+        while pos < self.chars.len() {
+            match self.chars[pos] {
+                '(' | '[' | '<' => match self.find_closing_bracket(pos) {
+                    Some(end) => pos = end + 1,
+                    None => return self.chars.len(),
+                },
+                '{' if pos == start => match self.find_closing_bracket(pos) {
+                    Some(end) => pos = end + 1,
+                    None => return self.chars.len(),
+                },
+                '=' if self.chars.get(pos + 1) == Some(&'>') => {
+                    if is_return_type {
+                        break;
+                    }
+                    pos += 2;
+                }
+                ',' | ')' | ']' | '}' | ';' | '=' | '{' => break,
+                _ => pos += 1,
+            }
+        }
+
+        // Leave trailing whitespace in place:
+        while pos > start && self.chars[pos - 1].is_whitespace() {
+            pos -= 1;
+        }
+        pos
+    }
+
+    fn read_identifier(&mut self) -> String {
+        let start = self.pos;
+        while matches!(self.peek(0), Some(c) if c.is_alphanumeric() || c == '_' || c == '$') {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Copies and strips the source until one of the given terminators is
+    /// encountered outside of any brackets, or until the end of the input.
+    fn strip_until(&mut self, terminators: &[char]) {
+        let mut depth = 0;
+        while self.pos < self.chars.len() {
+            if let Some(end) = self.skip_literal_or_comment(self.pos) {
+                self.output.extend(&self.chars[self.pos..end]);
+                self.pos = end;
+                continue;
+            }
+
+            let c = self.chars[self.pos];
+            if depth == 0 && terminators.contains(&c) {
+                return;
+            }
+
+            if self.is_at_line_start() && self.starts_with(self.pos, "import type ") {
+                self.skip_declaration();
+            } else if self.is_type_declaration() {
+                // Remove the declaration, including its indentation:
+                self.output
+                    .truncate(self.output.trim_end_matches([' ', '\t']).len());
+                self.skip_declaration();
+            } else if c == '!' && self.is_non_null_assertion() {
+                self.pos += 1;
+            } else if c.is_alphabetic() || c == '_' || c == '$' {
+                self.strip_identifier();
+            } else if c == '(' {
+                if !self.strip_arrow_function_params() {
+                    depth += 1;
+                    self.copy_char();
+                }
+            } else if c == '<' && self.is_type_parameter_list() {
+                self.pos = self.find_closing_bracket(self.pos).unwrap_or(self.pos) + 1;
+            } else {
+                match c {
+                    '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => depth -= 1,
+                    _ => {}
+                }
+                self.copy_char();
+            }
+        }
+    }
+
+    /// Returns whether the output is at the start of a statement, ignoring
+    /// indentation and a preceding `export` keyword.
+    fn is_at_statement_start(&self) -> bool {
+        let before = self.output.trim_end_matches([' ', '\t']);
+        let before = before
+            .strip_suffix("export")
+            .map(|before| before.trim_end_matches([' ', '\t']))
+            .unwrap_or(before);
+        before.is_empty() || before.ends_with(['\n', ';', '{', '}'])
+    }
+
+    /// Returns whether a type declaration, such as `type Name<T> = ...;` or
+    /// `export type Name = ...;`, or a type-only export starts at the current
+    /// position.
+    ///
+    /// Other statements that start with `type`, such as an assignment to a
+    /// variable of that name, are not declarations.
+    fn is_type_declaration(&self) -> bool {
+        if !self.is_at_statement_start() {
+            return false;
+        }
+
+        let mut pos = self.pos;
+        let is_export = self.starts_with(pos, "export ");
+        if is_export {
+            pos = self.skip_whitespace_from(pos + "export".len());
+        }
+        if !self.starts_with(pos, "type") {
+            return false;
+        }
+        pos += "type".len();
+        let name_start = self.skip_whitespace_from(pos);
+        if name_start == pos {
+            return false;
+        }
+        if is_export && matches!(self.chars.get(name_start), Some('{' | '*')) {
+            // Type-only exports, such as `export type { Name } from "...";`:
+            return true;
+        }
+
+        pos = name_start;
+        while matches!(self.chars.get(pos), Some(c) if c.is_alphanumeric() || *c == '_' || *c == '$')
+        {
+            pos += 1;
+        }
+        if pos == name_start {
+            return false;
+        }
+        pos = self.skip_whitespace_from(pos);
+        if self.chars.get(pos) == Some(&'<') {
+            pos = match self.find_closing_bracket(pos) {
+                Some(end) => self.skip_whitespace_from(end + 1),
+                None => return false,
+            };
+        }
+        self.chars.get(pos) == Some(&'=') && self.chars.get(pos + 1) != Some(&'=')
+    }
+
+    /// Returns whether the `!` at the current position is a non-null
+    /// assertion, which directly follows an expression, rather than a
+    /// negation or part of an inequality operator.
+    fn is_non_null_assertion(&self) -> bool {
+        matches!(
+            self.output.chars().last(),
+            Some(c) if c.is_alphanumeric() || matches!(c, '_' | '$' | ')' | ']')
+        ) && self.peek(1) != Some('=')
+    }
+
+    /// Skips a type declaration, including its trailing newline.
+    fn skip_declaration(&mut self) {
+        while self.pos < self.chars.len() && self.chars[self.pos] != ';' {
+            if let Some(end) = self.skip_literal_or_comment(self.pos) {
+                self.pos = end;
+            } else if matches!(self.chars[self.pos], '(' | '[' | '{' | '<') {
+                self.pos = self.find_closing_bracket(self.pos).unwrap_or(self.pos) + 1;
+            } else {
+                self.pos += 1;
+            }
+        }
+        self.pos += 1;
+        if self.peek(0) == Some('\n') {
+            self.pos += 1;
+        }
+    }
+
+    fn strip_identifier(&mut self) {
+        let is_at_statement_start = self.is_at_statement_start();
+        let identifier = self.read_identifier();
+        match identifier.as_str() {
+            "declare" | "enum" | "interface" | "namespace"
+                if is_at_statement_start
+                    && matches!(self.peek(0), Some(c) if c.is_whitespace()) =>
+            {
+                panic!(
+                    "Cannot strip the types of a TypeScript `{}` declaration, which has no \
+                    JavaScript equivalent",
+                    identifier
+                )
+            }
+            "as" if self.output.ends_with(char::is_whitespace) => {
+                // Remove the cast, including the whitespace before it:
+                self.output.truncate(self.output.trim_end().len());
+                self.pos = self.find_type_end(self.pos, false);
+            }
+            "function" | "constructor" => {
+                self.output.push_str(&identifier);
+                self.copy_whitespace();
+                self.read_identifier_into_output();
+                if self.peek(0) == Some('<') {
+                    self.pos = self.find_closing_bracket(self.pos).unwrap_or(self.pos) + 1;
+                }
+                if self.peek(0) == Some('(') {
+                    self.strip_params();
+                    self.strip_annotation(true);
+                }
+            }
+            "const" | "let" | "var" => {
+                self.output.push_str(&identifier);
+                self.copy_whitespace();
+                self.read_identifier_into_output();
+                self.strip_annotation(false);
+            }
+            _ if self.is_field_declaration() => {
+                // Remove the declaration, including its indentation and
+                // trailing newline:
+                self.output
+                    .truncate(self.output.trim_end_matches([' ', '\t']).len());
+                self.pos = self.find_type_end(self.skip_annotation_colon(), false) + 1;
+                if self.peek(0) == Some('\n') {
+                    self.pos += 1;
+                }
+            }
+            _ => {
+                self.output.push_str(&identifier);
+                if self.peek(0) == Some('<') && self.peek(1) != Some('<') {
+                    // Strip type arguments from calls, such as `parseObject<T>()`:
+                    if let Some(end) = self.find_closing_bracket(self.pos) {
+                        if self.chars.get(end + 1) == Some(&'(') {
+                            self.pos = end + 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether the identifier that was just read is the name of a
+    /// class field declaration, which spans the rest of its line.
+    fn is_field_declaration(&self) -> bool {
+        let at_line_start = self.output.trim_end_matches([' ', '\t']).ends_with('\n');
+        if !at_line_start || !matches!(self.peek(0), Some('?' | '!' | ':')) {
+            return false;
+        }
+
+        let end = self.find_type_end(self.skip_annotation_colon(), false);
+        self.chars.get(end) == Some(&';')
+            && matches!(self.chars.get(end + 1), Some('\n') | None)
+            && !self.chars[self.pos..end].contains(&'\n')
+    }
+
+    /// Returns the position after the colon of the annotation at the current
+    /// position, which may be preceded by a `?` or `!`.
+    fn skip_annotation_colon(&self) -> usize {
+        if matches!(self.peek(0), Some('?' | '!')) {
+            self.pos + 2
+        } else {
+            self.pos + 1
+        }
+    }
+
+    fn read_identifier_into_output(&mut self) {
+        let identifier = self.read_identifier();
+        self.output.push_str(&identifier);
+    }
+
+    /// Strips a type annotation at the current position, if there is one.
+    fn strip_annotation(&mut self, is_return_type: bool) {
+        let pos = if matches!(self.peek(0), Some('?' | '!')) && self.peek(1) == Some(':') {
+            self.pos + 1
+        } else {
+            self.pos
+        };
+        if self.chars.get(pos) == Some(&':') {
+            self.pos = self.find_type_end(pos + 1, is_return_type);
+        }
+    }
+
+    /// Returns whether the `<` at the current position starts the type
+    /// parameters of an arrow function, such as `<T>(name: string): T => {}`.
+    fn is_type_parameter_list(&self) -> bool {
+        let previous = self.output.trim_end().chars().last();
+        matches!(previous, Some('=' | '(' | ',' | ':'))
+            && self
+                .find_closing_bracket(self.pos)
+                .map(|end| self.chars.get(end + 1) == Some(&'('))
+                .unwrap_or(false)
+    }
+
+    /// Strips the parameters and return type of an arrow function, if the
+    /// parenthesis at the current position starts one. Returns `false` if it
+    /// does not.
+    fn strip_arrow_function_params(&mut self) -> bool {
+        let end = match self.find_closing_bracket(self.pos) {
+            Some(end) => end,
+            None => return false,
+        };
+        let mut pos = self.skip_whitespace_from(end + 1);
+        if self.chars.get(pos) == Some(&':') {
+            pos = self.skip_whitespace_from(self.find_type_end(pos + 1, true));
+        }
+        if !self.starts_with(pos, "=>") {
+            return false;
+        }
+
+        self.strip_params();
+        self.strip_annotation(true);
+        true
+    }
+
+    /// Strips the type annotations from the parameter list at the current
+    /// position.
+    fn strip_params(&mut self) {
+        self.copy_char(); // (
+        loop {
+            self.copy_whitespace();
+            if self.starts_with(self.pos, "...") {
+                self.output.push_str("...");
+                self.pos += 3;
+            }
+            match self.peek(0) {
+                Some('{' | '[') => {
+                    // Destructuring patterns don't contain types, so they
+                    // are copied as-is:
+                    let end = self.find_closing_bracket(self.pos).unwrap_or(self.pos);
+                    while self.pos <= end {
+                        self.copy_char();
+                    }
+                }
+                _ => self.read_identifier_into_output(),
+            }
+            self.strip_annotation(false);
+            self.strip_until(&[',', ')']);
+            match self.peek(0) {
+                Some(',') => self.copy_char(),
+                Some(')') => {
+                    self.copy_char();
+                    break;
+                }
+                _ => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_types;
+
+    #[test]
+    fn test_strip_declarations() {
+        assert_eq!(
+            strip_types(
+                "import { encode } from \"msgpack\";\n\nimport type * as types from \"./types\";\n\n\
+                type FatPtr = bigint;\n\nexport type Imports = {\n    log: (message: string) => void;\n};\n\n\
+                export type { Options } from \"./options\";\nexport const a = 1;\n"
+            ),
+            "import { encode } from \"msgpack\";\n\nexport const a = 1;\n"
+        );
+    }
+
+    #[test]
+    fn test_strip_nested_type_declarations() {
+        assert_eq!(
+            strip_types(
+                "function f() {\n    type Pair<T> = [T, T];\n    const pair: Pair<number> = [1, 2];\n}"
+            ),
+            "function f() {\n    const pair = [1, 2];\n}"
+        );
+    }
+
+    #[test]
+    fn test_preserve_statements_starting_with_type() {
+        assert_eq!(
+            strip_types("let type = \"a\";\ntype = \"b\";\ntype == \"b\";\ntypeof type;\n"),
+            "let type = \"a\";\ntype = \"b\";\ntype == \"b\";\ntypeof type;\n"
+        );
+    }
+
+    #[test]
+    fn test_strip_non_null_assertions() {
+        assert_eq!(
+            strip_types(
+                "const a = map.get(key)!.value!;\nconst b = items[0]!;\nlet c!: number;\n\
+                if (!a && b !== c && b!= c) {}"
+            ),
+            "const a = map.get(key).value;\nconst b = items[0];\nlet c;\nif (!a && b !== c && b!= c) {}"
+        );
+        assert_eq!(
+            strip_types("class A {\n    port!: MessagePort;\n}"),
+            "class A {\n}"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot strip the types of a TypeScript `enum` declaration")]
+    fn test_reject_enums() {
+        strip_types("export enum Color {\n    Red = \"red\",\n}");
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot strip the types of a TypeScript `namespace` declaration")]
+    fn test_reject_namespaces() {
+        strip_types("namespace Colors {\n    export const red = \"red\";\n}");
+    }
+
+    #[test]
+    fn test_strip_functions() {
+        assert_eq!(
+            strip_types(
+                "function parseObject<T>(fatPtr: FatPtr, len?: number): [ptr: number, len: number] {\n    \
+                return decode(copy) as unknown as T;\n}"
+            ),
+            "function parseObject(fatPtr, len) {\n    return decode(copy);\n}"
+        );
+        assert_eq!(
+            strip_types("const getExport = <T>(name: string): T => instance.exports[name] as T;"),
+            "const getExport = (name) => instance.exports[name];"
+        );
+        assert_eq!(
+            strip_types(
+                "const f = (handler: (payload: string) => void): FatPtr => {\n    \
+                const result: types.Result<string, string> = handler ? (a) : b;\n}"
+            ),
+            "const f = (handler) => {\n    const result = handler ? (a) : b;\n}"
+        );
+        assert_eq!(
+            strip_types("port.on(\"message\", ({ id, args }: Request, ...rest: unknown[]) => id);"),
+            "port.on(\"message\", ({ id, args }, ...rest) => id);"
+        );
+    }
+
+    #[test]
+    fn test_strip_type_arguments() {
+        assert_eq!(
+            strip_types(
+                "const promises = new Map<FatPtr, ((result: FatPtr) => void) | FatPtr>();\n\
+                const value = parseObject<types.Foo<number>>(ptr) << 2;\n\
+                const ok = a < b && c > d;"
+            ),
+            "const promises = new Map();\nconst value = parseObject(ptr) << 2;\nconst ok = a < b && c > d;"
+        );
+    }
+
+    #[test]
+    fn test_strip_class_fields() {
+        assert_eq!(
+            strip_types(
+                "class E extends Error {\n    kind: string;\n    cause?: unknown;\n\n    \
+                constructor(kind: string) {\n        super(kind);\n        this.kind = kind;\n    }\n}"
+            ),
+            "class E extends Error {\n\n    constructor(kind) {\n        super(kind);\n        \
+            this.kind = kind;\n    }\n}"
+        );
+        assert_eq!(
+            strip_types("const value = {\n    a: b ? c : d,\n};\nswitch (a) {\n    default:\n        break;\n}"),
+            "const value = {\n    a: b ? c : d,\n};\nswitch (a) {\n    default:\n        break;\n}"
+        );
+    }
+
+    #[test]
+    fn test_preserve_literals_and_comments() {
+        assert_eq!(
+            strip_types("const s = \"a: string\"; // b: number\nconst t = `${name}: T`;"),
+            "const s = \"a: string\"; // b: number\nconst t = `${name}: T`;"
+        );
+    }
+}
@@ -0,0 +1,1086 @@
+use super::{
+    format_function_declarations, format_header, get_import_extension, join_lines, strip_types,
+    write_bindings_file, FunctionType,
+};
+use crate::{functions::FunctionList, types::TypeMap, FileHeaders, TsExtendedRuntimeConfig};
+
+/// Collects the `ArrayBuffer`s in the return values of the plugin, so that
+/// they can be transferred from the worker instead of being copied.
+const COLLECT_TRANSFERABLES_FUNCTION: &str = r#"/**
+ * Collects the `ArrayBuffer`s contained in a value, so they can be transferred
+ * to the main thread instead of being copied.
+ */
+function collectTransferables(value: unknown): ArrayBuffer[] {
+    const buffers = new Set<ArrayBuffer>();
+    const visit = (value: unknown) => {
+        if (value instanceof ArrayBuffer) {
+            buffers.add(value);
+        } else if (ArrayBuffer.isView(value)) {
+            if (value.buffer instanceof ArrayBuffer) {
+                buffers.add(value.buffer);
+            }
+        } else if (value instanceof Map) {
+            value.forEach((item, key) => {
+                visit(key);
+                visit(item);
+            });
+        } else if (value !== null && typeof value === "object") {
+            Object.values(value).forEach(visit);
+        }
+    };
+    visit(value);
+    return [...buffers];
+}
+"#;
+
+/// The type of the messages with which the worker asks the main thread to
+/// call an import that is implemented there.
+const IMPORT_REQUEST_TYPE: &str = r#"
+/**
+ * Sent by the worker when the plugin calls an import that is implemented on
+ * the main thread. If the result doesn't fit in the given buffer, the worker
+ * sends a larger buffer with `importResult`.
+ */
+export type WorkerImportRequest =
+    | { importCall: { name: string; args: unknown[]; buffer: SharedArrayBuffer } }
+    | { importResult: { buffer: SharedArrayBuffer } };
+"#;
+
+const ASYNC_IMPORTS_DOC: &str = r#"
+ *
+ * Synchronous imports may be implemented asynchronously on the main thread,
+ * for instance to use `fetch()` or IndexedDB. While such an import is pending,
+ * the worker blocks using `Atomics.wait()`, so these require the page to be
+ * cross-origin isolated for `SharedArrayBuffer` to be available."#;
+
+const ASYNC_IMPORTS_PARAM_DOC: &str = r#"
+ * @param asyncImports Asynchronous implementations of synchronous imports,
+ *                     which take precedence over the ones in the worker.
+ * @param msgpackOptions Options for encoding the results of `asyncImports`,
+ *                       which need to match the ones of the worker."#;
+
+const ASYNC_IMPORTS_CLIENT_SETUP: &str = r#"
+    const asyncImportNames = Object.keys(asyncImports).filter(
+        (name) => typeof (asyncImports as Record<string, unknown>)[name] === "function"
+    );
+    const asyncImportsInit: AsyncImportsInit | undefined = asyncImportNames.length > 0
+        ? { names: asyncImportNames, signal: new SharedArrayBuffer(8) }
+        : undefined;
+"#;
+
+const ASYNC_IMPORTS_CLIENT_DISPATCH: &str = r#"
+        const request = event.data as WorkerImportRequest;
+        if (asyncImportsInit && ("importCall" in request || "importResult" in request)) {
+            handleImportRequest(request, new Int32Array(asyncImportsInit.signal));
+            return;
+        }
+"#;
+
+const ASYNC_IMPORTS_CLIENT_WRITER: &str = r#"
+// The states of an import call that are signalled to the worker. These need to
+// match the ones in `worker-runtime`.
+const IMPORT_READY = 1;
+const IMPORT_ERROR = 2;
+const IMPORT_TOO_LARGE = 3;
+
+type ImportResult = { status: number; data: Uint8Array };
+
+/**
+ * Writes the result of an import call to the buffer of the worker, and wakes
+ * up the worker.
+ */
+function writeImportResult(signal: Int32Array, buffer: SharedArrayBuffer, result: ImportResult) {
+    new Uint8Array(buffer).set(result.data);
+    Atomics.store(signal, 1, result.data.byteLength);
+    Atomics.store(signal, 0, result.status);
+    Atomics.notify(signal, 0);
+}
+"#;
+
+const WORKER_IMPORTS_TYPE: &str = r#"/**
+ * The host functions that are implemented inside the worker. Synchronous
+ * imports may be omitted if they are implemented on the main thread instead,
+ * by passing them to `createWorkerClient()`.
+ */
+export type WorkerImports = Omit<Imports, keyof AsyncImports> &
+    Partial<Pick<Imports, keyof AsyncImports>>;
+"#;
+
+const ASYNC_IMPORTS_RUNTIME: &str = r#"
+// The states of an import call that is implemented on the main thread. These
+// need to match the ones in `worker-client`.
+const IMPORT_PENDING = 0;
+const IMPORT_ERROR = 2;
+const IMPORT_TOO_LARGE = 3;
+
+/**
+ * The initial size of the buffer to which the main thread writes the results
+ * of import calls. It is replaced with a larger one if a result doesn't fit.
+ */
+const INITIAL_IMPORT_BUFFER_SIZE = 64 * 1024;
+
+/**
+ * Adds synchronous implementations for the imports that are implemented on
+ * the main thread. These block the worker until the asynchronous
+ * implementation has settled, so that the plugin can call them as usual.
+ */
+function withAsyncImports(
+    scope: WorkerScope,
+    importFunctions: WorkerImports,
+    asyncImports: AsyncImportsInit | undefined,
+    msgpackOptions: MsgpackOptions | undefined
+): Imports {
+    if (!asyncImports) {
+        return importFunctions as Imports;
+    }
+
+    const signal = new Int32Array(asyncImports.signal);
+    let buffer = new SharedArrayBuffer(INITIAL_IMPORT_BUFFER_SIZE);
+    const waitForResult = (request: WorkerImportRequest) => {
+        Atomics.store(signal, 0, IMPORT_PENDING);
+        scope.postMessage(request);
+        Atomics.wait(signal, 0, IMPORT_PENDING);
+        return Atomics.load(signal, 0);
+    };
+
+    const blockingImports: Record<string, (...args: unknown[]) => unknown> = {};
+    for (const name of asyncImports.names) {
+        blockingImports[name] = (...args: unknown[]) => {
+            let status = waitForResult({ importCall: { name, args, buffer } });
+            if (status === IMPORT_TOO_LARGE) {
+                buffer = new SharedArrayBuffer(Atomics.load(signal, 1));
+                status = waitForResult({ importResult: { buffer } });
+            }
+
+            // The result is copied, since it cannot be decoded from a shared buffer:
+            const data = new Uint8Array(buffer, 0, Atomics.load(signal, 1)).slice();
+            const result = decode(data, msgpackOptions);
+            if (status === IMPORT_ERROR) {
+                throw new FPRuntimeError(result as string);
+            }
+            return result;
+        };
+    }
+    return { ...importFunctions, ...blockingImports } as Imports;
+}
+"#;
+
+/// Generates a wrapper that runs the plugin inside a Node.js `worker_threads`
+/// Worker, with a proxy for calling its exports from the main thread.
+pub(super) fn generate_worker_wrapper(
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &TsExtendedRuntimeConfig,
+    has_capabilities: bool,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let export_decls = format_worker_export_decls(export_functions, types, config);
+    let export_names = format_worker_export_names(export_functions, config);
+    let extension = get_import_extension(config);
+
+    let (capability_import, capability_param, capability_param_doc, capability_arg) =
+        if has_capabilities {
+            (
+                "Capability, ",
+                ",\n    capabilities: Capability[] = []",
+                "\n * @param capabilities The capabilities that are granted to the plugin.",
+                ", capabilities",
+            )
+        } else {
+            ("", "", "", "")
+        };
+    let (capability_data, capability_worker_param, capability_worker_arg) = if has_capabilities {
+        (
+            ", capabilities",
+            ", capabilities: Capability[]",
+            ", workerData.fpRuntime.capabilities",
+        )
+    } else {
+        ("", "", "")
+    };
+
+    let contents = format!(
+        "// ============================================= //
+// Node.js worker runtime for TypeScript         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+// deno-lint-ignore-file no-explicit-any
+
+import {{ isMainThread, parentPort, Worker, workerData }} from \"node:worker_threads\";
+
+import {{ createRuntime, FPRuntimeError }} from \"./index{extension}\";
+import type {{ {capability_import}Exports, Imports, MemoryStats }} from \"./index{extension}\";
+import type * as types from \"./types{extension}\";
+
+export type WorkerExports = {{
+{}}};
+
+export type WorkerRuntime = WorkerExports & {{
+    /**
+     * Stops the worker. Calls that are still pending are rejected.
+     */
+    terminate: () => Promise<void>;
+}};
+
+type WorkerRequest = {{ id: number; name: string; args: unknown[] }};
+
+type WorkerResponse = {{ id: number; result?: unknown; error?: string }};
+
+type PendingCall = {{ resolve: (result: unknown) => void; reject: (error: Error) => void }};
+
+const EXPORT_NAMES = [
+{}];
+
+/**
+ * Creates a runtime that executes the given plugin inside a `worker_threads`
+ * Worker, so that calls into the plugin don't block the event loop.
+ *
+ * The import functions are called from inside the worker as well, so they are
+ * loaded from a module that provides the `Imports` as its default export.
+ *
+ * Arguments are copied into the worker using the structured clone algorithm,
+ * while any `ArrayBuffer`s in return values are transferred back without
+ * copying them.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importsModule The URL of the module that provides the import functions.{capability_param_doc}
+ * @returns Asynchronous proxies for the functions exported by the plugin.
+ */
+export async function createWorkerRuntime(
+    plugin: ArrayBuffer,
+    importsModule: URL | string{capability_param}
+): Promise<WorkerRuntime> {{
+    const worker = new Worker(new URL(import.meta.url), {{
+        workerData: {{ fpRuntime: {{ plugin, importsModule: importsModule.toString(){capability_data} }} }},
+    }});
+
+    const availableExports = await new Promise((resolve, reject) => {{
+        worker.once(\"error\", reject);
+        worker.once(\"message\", (names: string[]) => {{
+            worker.off(\"error\", reject);
+            resolve(names);
+        }});
+    }}) as string[];
+
+    const pendingCalls = new Map<number, PendingCall>();
+    let nextCallId = 0;
+
+    function rejectPendingCalls(error: Error) {{
+        for (const call of pendingCalls.values()) {{
+            call.reject(error);
+        }}
+        pendingCalls.clear();
+    }}
+
+    worker.on(\"message\", (response: WorkerResponse) => {{
+        const call = pendingCalls.get(response.id);
+        if (!call) {{
+            return;
+        }}
+
+        pendingCalls.delete(response.id);
+        if (response.error !== undefined) {{
+            call.reject(new FPRuntimeError(response.error));
+        }} else {{
+            call.resolve(response.result);
+        }}
+    }});
+    worker.on(\"error\", rejectPendingCalls);
+    worker.on(\"exit\", (code: number) => {{
+        rejectPendingCalls(new FPRuntimeError(`Worker exited with code ${{code}}`));
+    }});
+
+    const runtime: Record<string, unknown> = {{
+        terminate: async () => {{
+            await worker.terminate();
+        }},
+    }};
+    for (const name of availableExports) {{
+        runtime[name] = (...args: unknown[]) => new Promise((resolve, reject) => {{
+            const id = nextCallId++;
+            pendingCalls.set(id, {{ resolve, reject }});
+            const request: WorkerRequest = {{ id, name, args }};
+            worker.postMessage(request);
+        }});
+    }}
+    return runtime as WorkerRuntime;
+}}
+
+async function runWorker(port: any, plugin: ArrayBuffer, importsModule: string{capability_worker_param}) {{
+    const module = await import(importsModule);
+    const runtime: Exports = await createRuntime(plugin, module.default as Imports{capability_arg});
+    const exports = runtime as Record<string, any>;
+
+    port.on(\"message\", async ({{ id, name, args }}: WorkerRequest) => {{
+        try {{
+            const result = await exports[name](...args);
+            const response: WorkerResponse = {{ id, result }};
+            port.postMessage(response, collectTransferables(result));
+        }} catch (error) {{
+            const response: WorkerResponse = {{
+                id,
+                error: error instanceof Error ? error.message : String(error),
+            }};
+            port.postMessage(response);
+        }}
+    }});
+
+    port.postMessage(EXPORT_NAMES.filter((name) => typeof exports[name] === \"function\"));
+}}
+
+{COLLECT_TRANSFERABLES_FUNCTION}
+if (!isMainThread && workerData?.fpRuntime) {{
+    runWorker(parentPort, workerData.fpRuntime.plugin, workerData.fpRuntime.importsModule{capability_worker_arg});
+}}
+",
+        join_lines(&export_decls, |line| format!("    {line};")),
+        join_lines(&export_names, |name| format!("    \"{name}\",")),
+    );
+
+    if config.generate_js_output {
+        write_bindings_file(format!("{path}/worker.js"), strip_types(&contents), headers);
+        write_bindings_file(
+            format!("{path}/worker.d.ts"),
+            format_worker_declarations(&export_decls, has_capabilities),
+            headers,
+        );
+    } else {
+        write_bindings_file(format!("{path}/worker.ts"), contents, headers);
+    }
+}
+
+/// Generates a wrapper that runs the plugin inside a dedicated Web Worker in
+/// the browser. The worker side is generated as `worker-runtime.ts`, while
+/// `worker-client.ts` proxies the calls to its exports from the main thread.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn generate_browser_worker_wrapper(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &TsExtendedRuntimeConfig,
+    has_capabilities: bool,
+    has_feature_imports: bool,
+    headers: &FileHeaders,
+    path: &str,
+) {
+    let export_decls = format_worker_export_decls(export_functions, types, config);
+    let export_names = format_worker_export_names(export_functions, config);
+    let extension = get_import_extension(config);
+    let async_imports = config
+        .generate_async_imports
+        .then(|| AsyncImports::new(import_functions, types, config));
+
+    let (
+        capability_import,
+        capability_param,
+        capability_param_doc,
+        capability_field,
+        capability_data,
+        capability_arg,
+    ) = if has_capabilities {
+        (
+            "Capability, ",
+            ",\n    capabilities: Capability[] = []",
+            "\n * @param capabilities The capabilities that are granted to the plugin.",
+            "; capabilities: Capability[]",
+            ", capabilities",
+            ",\n                    init.capabilities",
+        )
+    } else {
+        ("", "", "", "", "", "")
+    };
+
+    let client = format!(
+        "{header}
+// deno-lint-ignore-file no-explicit-any
+{msgpack_import}
+import {{ FPRuntimeError }} from \"./index{extension}\";
+import type {{ {capability_import}MemoryStats{msgpack_options_import} }} from \"./index{extension}\";
+import type * as types from \"./types{extension}\";
+
+export type WorkerExports = {{
+{export_decls}}};
+{async_imports_types}
+export type WorkerClient = WorkerExports & {{
+    /**
+     * Stops the worker. Calls that are still pending are rejected.
+     */
+    terminate: () => void;
+}};
+
+/**
+ * The first message to the worker, with which the plugin is instantiated.
+ */
+export type WorkerInit = {{ plugin: ArrayBuffer{capability_field}{async_imports_field} }};
+
+/**
+ * The response to `WorkerInit`, with the names of the exports that the plugin
+ * implements, or the reason why it could not be instantiated.
+ */
+export type WorkerReady = {{ exports?: string[]; error?: string }};
+
+export type WorkerRequest = {{ id: number; name: string; args: unknown[] }};
+
+export type WorkerResponse = {{ id: number; result?: unknown; error?: string }};
+{import_request_type}
+type PendingCall = {{ resolve: (result: unknown) => void; reject: (error: Error) => void }};
+
+/**
+ * Runs the given plugin inside a dedicated Web Worker, so that calls into the
+ * plugin don't block the main thread.
+ *
+ * The script of the worker needs to call `exposeRuntime()` from
+ * `worker-runtime{extension}` with the functions that may be imported by the
+ * plugin, because functions cannot be passed to the worker.
+ *
+ * The plugin is transferred to the worker, so the given buffer can no longer be
+ * used afterwards. Arguments are copied into the worker using the structured
+ * clone algorithm, while any `ArrayBuffer`s in return values are transferred
+ * back without copying them.{async_imports_doc}
+ *
+ * @param worker The worker, which calls `exposeRuntime()`.
+ * @param plugin The raw WASM plugin.{capability_param_doc}{async_imports_param_doc}
+ * @returns Asynchronous proxies for the functions exported by the plugin.
+ */
+export async function createWorkerClient(
+    worker: Worker,
+    plugin: ArrayBuffer{capability_param}{async_imports_param}
+): Promise<WorkerClient> {{{async_imports_setup}
+    const availableExports = await new Promise((resolve, reject) => {{
+        const onMessage = (event: MessageEvent) => {{
+            removeListeners();
+            const ready = event.data as WorkerReady;
+            if (ready.error !== undefined) {{
+                reject(new FPRuntimeError(ready.error));
+            }} else {{
+                resolve(ready.exports);
+            }}
+        }};
+        const onError = (event: ErrorEvent) => {{
+            removeListeners();
+            reject(new FPRuntimeError(event.message));
+        }};
+        const removeListeners = () => {{
+            worker.removeEventListener(\"message\", onMessage);
+            worker.removeEventListener(\"error\", onError);
+        }};
+        worker.addEventListener(\"message\", onMessage);
+        worker.addEventListener(\"error\", onError);
+
+        const init: WorkerInit = {{ plugin{capability_data}{async_imports_data} }};
+        worker.postMessage(init, [plugin]);
+    }}) as string[];
+
+    const pendingCalls = new Map<number, PendingCall>();
+    let nextCallId = 0;
+
+    function rejectPendingCalls(error: Error) {{
+        for (const call of pendingCalls.values()) {{
+            call.reject(error);
+        }}
+        pendingCalls.clear();
+    }}
+{async_imports_handler}
+    worker.addEventListener(\"message\", (event: MessageEvent) => {{{async_imports_dispatch}
+        const response = event.data as WorkerResponse;
+        const call = pendingCalls.get(response.id);
+        if (!call) {{
+            return;
+        }}
+
+        pendingCalls.delete(response.id);
+        if (response.error !== undefined) {{
+            call.reject(new FPRuntimeError(response.error));
+        }} else {{
+            call.resolve(response.result);
+        }}
+    }});
+    worker.addEventListener(\"error\", (event: ErrorEvent) => {{
+        rejectPendingCalls(new FPRuntimeError(event.message));
+    }});
+
+    const client: Record<string, unknown> = {{
+        terminate: () => {{
+            worker.terminate();
+            rejectPendingCalls(new FPRuntimeError(\"Worker was terminated\"));
+        }},
+    }};
+    for (const name of availableExports) {{
+        client[name] = (...args: unknown[]) => new Promise((resolve, reject) => {{
+            const id = nextCallId++;
+            pendingCalls.set(id, {{ resolve, reject }});
+            const request: WorkerRequest = {{ id, name, args }};
+            worker.postMessage(request);
+        }});
+    }}
+    return client as WorkerClient;
+}}
+{async_imports_writer}",
+        header = format_header("Web Worker client for TypeScript"),
+        export_decls = join_lines(&export_decls, |line| format!("    {line};")),
+        msgpack_import = async_imports
+            .as_ref()
+            .map(|async_imports| {
+                let conversions_import = if async_imports.has_conversions {
+                    format!("\nimport {{ toEncodable }} from \"./conversions{extension}\";")
+                } else {
+                    String::new()
+                };
+                format!(
+                    "\nimport {{ encode }} from \"{}\";{conversions_import}",
+                    config.msgpack_module
+                )
+            })
+            .unwrap_or_default(),
+        async_imports_types = async_imports
+            .as_ref()
+            .map(|async_imports| async_imports.format_types())
+            .unwrap_or_default(),
+        async_imports_field = if async_imports.is_some() {
+            "; asyncImports?: AsyncImportsInit"
+        } else {
+            ""
+        },
+        import_request_type = if async_imports.is_some() {
+            IMPORT_REQUEST_TYPE
+        } else {
+            ""
+        },
+        async_imports_doc = if async_imports.is_some() {
+            ASYNC_IMPORTS_DOC
+        } else {
+            ""
+        },
+        async_imports_param_doc = if async_imports.is_some() {
+            ASYNC_IMPORTS_PARAM_DOC
+        } else {
+            ""
+        },
+        async_imports_param = if async_imports.is_some() {
+            ",\n    asyncImports: AsyncImports = {},\n    msgpackOptions: MsgpackOptions = {}"
+        } else {
+            ""
+        },
+        msgpack_options_import = if async_imports.is_some() {
+            ", MsgpackOptions"
+        } else {
+            ""
+        },
+        async_imports_setup = if async_imports.is_some() {
+            ASYNC_IMPORTS_CLIENT_SETUP
+        } else {
+            ""
+        },
+        async_imports_data = if async_imports.is_some() {
+            ", asyncImports: asyncImportsInit"
+        } else {
+            ""
+        },
+        async_imports_handler = async_imports
+            .as_ref()
+            .map(|async_imports| async_imports.format_client_handler())
+            .unwrap_or_default(),
+        async_imports_dispatch = if async_imports.is_some() {
+            ASYNC_IMPORTS_CLIENT_DISPATCH
+        } else {
+            ""
+        },
+        async_imports_writer = if async_imports.is_some() {
+            ASYNC_IMPORTS_CLIENT_WRITER
+        } else {
+            ""
+        },
+    );
+
+    let runtime = format!(
+        "{header}
+// deno-lint-ignore-file no-explicit-any
+{runtime_imports}
+/**
+ * The parts of the global scope of a dedicated worker that are used, so that
+ * this module doesn't require the `webworker` library of TypeScript.
+ */
+type WorkerScope = {{
+    addEventListener: (type: \"message\", listener: (event: MessageEvent) => void) => void;
+    postMessage: (message: unknown, transfer?: Transferable[]) => void;
+}};
+
+const EXPORT_NAMES = [
+{export_names}];
+
+/**
+ * Instantiates the plugin that is sent by `createWorkerClient()`, and executes
+ * the calls to its exports. This needs to be called from the script of the
+ * worker.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @param options Options for the runtime, such as the options of the MessagePack
+ *                encoder and decoder.
+ */
+export function exposeRuntime(importFunctions: {imports_type}, options: RuntimeOptions = {{}}): void {{
+    const scope = globalThis as unknown as WorkerScope;
+    let exports: Record<string, any> | undefined;
+
+    scope.addEventListener(\"message\", async (event: MessageEvent) => {{
+        if (!exports) {{
+            const init = event.data as WorkerInit;
+            let ready: WorkerReady;
+            try {{
+                {import_functions}const runtime: Exports = await createRuntime(
+                    init.plugin,
+                    {imports_arg}{capability_arg}{feature_arg},
+                    options
+                );
+                exports = runtime as Record<string, any>;
+                ready = {{ exports: EXPORT_NAMES.filter((name) => typeof exports?.[name] === \"function\") }};
+            }} catch (error) {{
+                ready = {{ error: error instanceof Error ? error.message : String(error) }};
+            }}
+            scope.postMessage(ready);
+            return;
+        }}
+
+        const {{ id, name, args }} = event.data as WorkerRequest;
+        try {{
+            const result = await exports[name](...args);
+            const response: WorkerResponse = {{ id, result }};
+            scope.postMessage(response, collectTransferables(result));
+        }} catch (error) {{
+            const response: WorkerResponse = {{
+                id,
+                error: error instanceof Error ? error.message : String(error),
+            }};
+            scope.postMessage(response);
+        }}
+    }});
+}}
+
+{COLLECT_TRANSFERABLES_FUNCTION}{async_imports_runtime}",
+        header = format_header("Web Worker runtime for TypeScript"),
+        export_names = join_lines(&export_names, |name| format!("    \"{name}\",")),
+        runtime_imports = if async_imports.is_some() {
+            format!(
+                "
+import {{ decode }} from \"{msgpack_module}\";
+import {{ createRuntime, FPRuntimeError }} from \"./index{extension}\";
+import type {{ Exports, Imports, MsgpackOptions, RuntimeOptions }} from \"./index{extension}\";
+import type {{
+    AsyncImports,
+    AsyncImportsInit,
+    WorkerImportRequest,
+    WorkerInit,
+    WorkerReady,
+    WorkerRequest,
+    WorkerResponse,
+}} from \"./worker-client{extension}\";
+
+{WORKER_IMPORTS_TYPE}",
+                msgpack_module = config.msgpack_module,
+            )
+        } else {
+            format!(
+                "
+import {{ createRuntime }} from \"./index{extension}\";
+import type {{ Exports, Imports, RuntimeOptions }} from \"./index{extension}\";
+import type {{
+    WorkerInit,
+    WorkerReady,
+    WorkerRequest,
+    WorkerResponse,
+}} from \"./worker-client{extension}\";
+"
+            )
+        },
+        imports_type = if async_imports.is_some() {
+            "WorkerImports"
+        } else {
+            "Imports"
+        },
+        import_functions = if async_imports.is_some() {
+            "const imports = withAsyncImports(\n                    scope,\n                    importFunctions,\n                    init.asyncImports,\n                    options.msgpack\n                );\n                "
+        } else {
+            ""
+        },
+        imports_arg = if async_imports.is_some() {
+            "imports"
+        } else {
+            "importFunctions"
+        },
+        // The worker doesn't pass the imports of features:
+        feature_arg = if has_feature_imports {
+            ",\n                    []"
+        } else {
+            ""
+        },
+        async_imports_runtime = if async_imports.is_some() {
+            ASYNC_IMPORTS_RUNTIME
+        } else {
+            ""
+        },
+    );
+
+    if config.generate_js_output {
+        write_bindings_file(
+            format!("{path}/worker-client.js"),
+            strip_types(&client),
+            headers,
+        );
+        write_bindings_file(
+            format!("{path}/worker-client.d.ts"),
+            format_worker_client_declarations(
+                &export_decls,
+                has_capabilities,
+                async_imports.as_ref(),
+            ),
+            headers,
+        );
+        write_bindings_file(
+            format!("{path}/worker-runtime.js"),
+            strip_types(&runtime),
+            headers,
+        );
+        write_bindings_file(
+            format!("{path}/worker-runtime.d.ts"),
+            format_worker_runtime_declarations(async_imports.is_some()),
+            headers,
+        );
+    } else {
+        write_bindings_file(format!("{path}/worker-client.ts"), client, headers);
+        write_bindings_file(format!("{path}/worker-runtime.ts"), runtime, headers);
+    }
+}
+
+/// The synchronous imports that may be implemented asynchronously on the main
+/// thread when `generate_async_imports` is enabled.
+struct AsyncImports {
+    decls: Vec<String>,
+    /// Whether sets and dates need to be converted before they are encoded.
+    has_conversions: bool,
+}
+
+impl AsyncImports {
+    fn new(
+        import_functions: &FunctionList,
+        types: &TypeMap,
+        config: &TsExtendedRuntimeConfig,
+    ) -> Self {
+        Self {
+            decls: format_function_declarations(
+                import_functions,
+                types,
+                FunctionType::AsyncImport,
+                &config.identifier_casing,
+                config.int64_encoding,
+            ),
+            has_conversions: config.generate_set_types
+                || super::conversions::has_date_fields(types),
+        }
+    }
+
+    fn format_types(&self) -> String {
+        format!(
+            "
+/**
+ * Asynchronous implementations of imports that the protocol declares as
+ * synchronous. They are called on the main thread, while the worker waits for
+ * them to settle.
+ */
+export type AsyncImports = {{
+{}}};
+
+/**
+ * The names of the imports that are implemented on the main thread, and the
+ * buffer through which the worker is notified when a call has settled.
+ */
+export type AsyncImportsInit = {{ names: string[]; signal: SharedArrayBuffer }};
+",
+            join_lines(&self.decls, |line| format!("    {line};")),
+        )
+    }
+
+    /// Formats the function of the client that calls the async imports on
+    /// behalf of the worker.
+    fn format_client_handler(&self) -> String {
+        let result = if self.has_conversions {
+            "toEncodable(await implementation(...args))"
+        } else {
+            "await implementation(...args)"
+        };
+        format!(
+            r#"
+    // The result of an import call that didn't fit in the buffer of the
+    // worker, which is written once the worker sends a larger one:
+    let oversizedImportResult: ImportResult | undefined;
+
+    async function handleImportRequest(request: WorkerImportRequest, signal: Int32Array) {{
+        if ("importResult" in request) {{
+            if (oversizedImportResult) {{
+                writeImportResult(signal, request.importResult.buffer, oversizedImportResult);
+                oversizedImportResult = undefined;
+            }}
+            return;
+        }}
+
+        const {{ name, args, buffer }} = request.importCall;
+        let result: ImportResult;
+        try {{
+            const implementation = (asyncImports as Record<string, (...args: unknown[]) => Promise<unknown>>)[name];
+            result = {{ status: IMPORT_READY, data: encode({result}, msgpackOptions) }};
+        }} catch (error) {{
+            const message = error instanceof Error ? error.message : String(error);
+            result = {{ status: IMPORT_ERROR, data: encode(message, msgpackOptions) }};
+        }}
+
+        if (result.data.byteLength > buffer.byteLength) {{
+            oversizedImportResult = result;
+            Atomics.store(signal, 1, result.data.byteLength);
+            Atomics.store(signal, 0, IMPORT_TOO_LARGE);
+            Atomics.notify(signal, 0);
+        }} else {{
+            writeImportResult(signal, buffer, result);
+        }}
+    }}
+"#
+        )
+    }
+}
+
+/// Formats the declarations of the exports as they are proxied by a worker,
+/// which makes every function asynchronous.
+fn format_worker_export_decls(
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &TsExtendedRuntimeConfig,
+) -> Vec<String> {
+    let casing = &config.identifier_casing;
+    let mut export_decls = format_function_declarations(
+        export_functions,
+        types,
+        FunctionType::WorkerExport,
+        casing,
+        config.int64_encoding,
+    );
+    export_decls.push("memoryStats?: () => Promise<MemoryStats>".to_owned());
+    export_decls.push("dispose: () => Promise<void>".to_owned());
+    export_decls
+}
+
+/// Returns the names of the exports that are proxied by a worker.
+///
+/// Dynamic import registrations, event subscriptions and raw exports are not
+/// proxied, because callbacks cannot be passed to the worker and raw exports
+/// would require an extra copy.
+fn format_worker_export_names(
+    export_functions: &FunctionList,
+    config: &TsExtendedRuntimeConfig,
+) -> Vec<String> {
+    let mut export_names = export_functions
+        .iter()
+        .map(|function| config.identifier_casing.format_name(&function.name))
+        .collect::<Vec<_>>();
+    export_names.push("memoryStats".to_owned());
+    export_names.push("dispose".to_owned());
+    export_names
+}
+
+fn format_worker_client_declarations(
+    export_decls: &[String],
+    has_capabilities: bool,
+    async_imports: Option<&AsyncImports>,
+) -> String {
+    let (capability_import, capability_param, capability_param_doc, capability_field) =
+        if has_capabilities {
+            (
+                "Capability, ",
+                ",\n    capabilities?: Capability[]",
+                "\n * @param capabilities The capabilities that are granted to the plugin.",
+                "; capabilities: Capability[]",
+            )
+        } else {
+            ("", "", "", "")
+        };
+
+    format!(
+        "{header}
+
+import type {{ {capability_import}MemoryStats{msgpack_options_import} }} from \"./index.js\";
+import type * as types from \"./types.js\";
+
+export type WorkerExports = {{
+{export_decls}}};
+{async_imports_types}
+export type WorkerClient = WorkerExports & {{
+    /**
+     * Stops the worker. Calls that are still pending are rejected.
+     */
+    terminate: () => void;
+}};
+
+/**
+ * The first message to the worker, with which the plugin is instantiated.
+ */
+export type WorkerInit = {{ plugin: ArrayBuffer{capability_field}{async_imports_field} }};
+
+/**
+ * The response to `WorkerInit`, with the names of the exports that the plugin
+ * implements, or the reason why it could not be instantiated.
+ */
+export type WorkerReady = {{ exports?: string[]; error?: string }};
+
+export type WorkerRequest = {{ id: number; name: string; args: unknown[] }};
+
+export type WorkerResponse = {{ id: number; result?: unknown; error?: string }};
+{import_request_type}
+/**
+ * Runs the given plugin inside a dedicated Web Worker, so that calls into the
+ * plugin don't block the main thread.
+ *
+ * The script of the worker needs to call `exposeRuntime()` from
+ * `worker-runtime.js` with the functions that may be imported by the plugin,
+ * because functions cannot be passed to the worker.
+ *
+ * The plugin is transferred to the worker, so the given buffer can no longer be
+ * used afterwards. Arguments are copied into the worker using the structured
+ * clone algorithm, while any `ArrayBuffer`s in return values are transferred
+ * back without copying them.{async_imports_doc}
+ *
+ * @param worker The worker, which calls `exposeRuntime()`.
+ * @param plugin The raw WASM plugin.{capability_param_doc}{async_imports_param_doc}
+ * @returns Asynchronous proxies for the functions exported by the plugin.
+ */
+export declare function createWorkerClient(
+    worker: Worker,
+    plugin: ArrayBuffer{capability_param}{async_imports_param}
+): Promise<WorkerClient>;
+",
+        header = format_header("Web Worker client for TypeScript"),
+        export_decls = join_lines(export_decls, |line| format!("    {line};")),
+        async_imports_types = async_imports
+            .map(|async_imports| async_imports.format_types())
+            .unwrap_or_default(),
+        async_imports_field = if async_imports.is_some() {
+            "; asyncImports?: AsyncImportsInit"
+        } else {
+            ""
+        },
+        import_request_type = if async_imports.is_some() {
+            IMPORT_REQUEST_TYPE
+        } else {
+            ""
+        },
+        async_imports_doc = if async_imports.is_some() {
+            ASYNC_IMPORTS_DOC
+        } else {
+            ""
+        },
+        async_imports_param_doc = if async_imports.is_some() {
+            ASYNC_IMPORTS_PARAM_DOC
+        } else {
+            ""
+        },
+        async_imports_param = if async_imports.is_some() {
+            ",\n    asyncImports?: AsyncImports,\n    msgpackOptions?: MsgpackOptions"
+        } else {
+            ""
+        },
+        msgpack_options_import = if async_imports.is_some() {
+            ", MsgpackOptions"
+        } else {
+            ""
+        },
+    )
+}
+
+fn format_worker_runtime_declarations(has_async_imports: bool) -> String {
+    let (imports, imports_type) = if has_async_imports {
+        (
+            format!(
+                "import type {{ Imports, RuntimeOptions }} from \"./index.js\";
+import type {{ AsyncImports }} from \"./worker-client.js\";
+
+{WORKER_IMPORTS_TYPE}"
+            ),
+            "WorkerImports",
+        )
+    } else {
+        (
+            "import type { Imports, RuntimeOptions } from \"./index.js\";\n".to_owned(),
+            "Imports",
+        )
+    };
+
+    format!(
+        "{header}
+
+{imports}
+/**
+ * Instantiates the plugin that is sent by `createWorkerClient()`, and executes
+ * the calls to its exports. This needs to be called from the script of the
+ * worker.
+ *
+ * @param importFunctions The host functions that may be imported by the plugin.
+ * @param options Options for the runtime, such as the options of the MessagePack
+ *                encoder and decoder.
+ */
+export declare function exposeRuntime(importFunctions: {imports_type}, options?: RuntimeOptions): void;
+",
+        header = format_header("Web Worker runtime for TypeScript"),
+    )
+}
+
+fn format_worker_declarations(export_decls: &[String], has_capabilities: bool) -> String {
+    let (capability_import, capability_param, capability_param_doc) = if has_capabilities {
+        (
+            "Capability, ",
+            ",\n    capabilities?: Capability[]",
+            "\n * @param capabilities The capabilities that are granted to the plugin.",
+        )
+    } else {
+        ("", "", "")
+    };
+
+    format!(
+        "// ============================================= //
+// Node.js worker runtime for TypeScript         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import type {{ {capability_import}MemoryStats }} from \"./index.js\";
+import type * as types from \"./types.js\";
+
+export type WorkerExports = {{
+{}}};
+
+export type WorkerRuntime = WorkerExports & {{
+    /**
+     * Stops the worker. Calls that are still pending are rejected.
+     */
+    terminate: () => Promise<void>;
+}};
+
+/**
+ * Creates a runtime that executes the given plugin inside a `worker_threads`
+ * Worker, so that calls into the plugin don't block the event loop.
+ *
+ * The import functions are called from inside the worker as well, so they are
+ * loaded from a module that provides the `Imports` as its default export.
+ *
+ * Arguments are copied into the worker using the structured clone algorithm,
+ * while any `ArrayBuffer`s in return values are transferred back without
+ * copying them.
+ *
+ * @param plugin The raw WASM plugin.
+ * @param importsModule The URL of the module that provides the import functions.{capability_param_doc}
+ * @returns Asynchronous proxies for the functions exported by the plugin.
+ */
+export declare function createWorkerRuntime(
+    plugin: ArrayBuffer,
+    importsModule: URL | string{capability_param}
+): Promise<WorkerRuntime>;
+",
+        join_lines(export_decls, |line| format!("    {line};")),
+    )
+}
@@ -0,0 +1,132 @@
+//! Handling of functions that return multiple values as a tuple, such as
+//! `fn list_rows() -> (Stats, Vec<Row>)`.
+//!
+//! Serde serializes both tuples and tuple structs as arrays, so on the wire,
+//! such tuples are exchanged as an implicit tuple struct that is named after
+//! the function, such as `ListRowsReturn`. The Rust generators keep the tuple
+//! itself, so callers can destructure it, while the generators for other
+//! languages replace it with the implicit struct before any bindings are
+//! generated, so that they only need to know about tuple structs.
+
+use crate::{
+    functions::{Function, FunctionList},
+    types::{Field, Struct, StructOptions, Type, TypeIdent, TypeMap},
+};
+use inflector::Inflector;
+
+/// Replaces the tuples that functions return with an implicit tuple struct
+/// for every function.
+pub(super) fn with_tuple_return_structs(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    mut types: TypeMap,
+) -> (FunctionList, FunctionList, TypeMap) {
+    let mut replace_function = |mut function: Function| {
+        let items = match function.return_type.as_ref().map(|ty| (ty, types.get(ty))) {
+            Some((ty, Some(Type::Tuple(items)))) if !ty.is_packed_pair() => items.clone(),
+            _ => return function,
+        };
+
+        let ty = format_return_struct(&function, items);
+        match types.get(&ty.ident) {
+            Some(Type::Struct(existing)) if existing.fields == ty.fields => {}
+            Some(_) => panic!(
+                "Type `{}` conflicts with the struct that is generated for the tuple returned \
+                    by `{}`",
+                ty.ident, function.name
+            ),
+            None => {
+                types.insert(ty.ident.clone(), Type::Struct(ty.clone()));
+            }
+        }
+        function.return_type = Some(ty.ident);
+        function
+    };
+
+    let import_functions = import_functions
+        .into_iter()
+        .map(&mut replace_function)
+        .collect();
+    let export_functions = export_functions
+        .into_iter()
+        .map(&mut replace_function)
+        .collect();
+    (import_functions, export_functions, types)
+}
+
+/// Formats the implicit tuple struct for the values that are returned by the
+/// given function.
+fn format_return_struct(function: &Function, items: Vec<TypeIdent>) -> Struct {
+    Struct {
+        ident: TypeIdent::from(format!("{}Return", function.name.to_pascal_case()).as_str()),
+        fields: items
+            .into_iter()
+            .map(|ty| Field {
+                name: None,
+                ty,
+                doc_lines: Vec::new(),
+                attrs: Default::default(),
+            })
+            .collect(),
+        doc_lines: vec![format!(
+            " The values that are returned by `{}()`.",
+            function.name
+        )],
+        options: StructOptions::default(),
+        location: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::Primitive;
+
+    #[test]
+    fn replaces_tuples_returned_by_functions() {
+        let types = TypeMap::from([
+            (
+                TypeIdent::from("(String, Vec<u32>)".to_owned()),
+                Type::Tuple(vec![TypeIdent::from("String"), TypeIdent::from("Vec<u32>")]),
+            ),
+            (
+                TypeIdent::from("(u32, i32)"),
+                Type::Tuple(vec![TypeIdent::from("u32"), TypeIdent::from("i32")]),
+            ),
+            (TypeIdent::from("String"), Type::String),
+            (TypeIdent::from("i32"), Type::Primitive(Primitive::I32)),
+            (TypeIdent::from("u32"), Type::Primitive(Primitive::U32)),
+            (
+                TypeIdent::from("Vec<T>"),
+                Type::List("Vec".to_owned(), TypeIdent::from("T")),
+            ),
+        ]);
+
+        let mut export_functions = FunctionList::new();
+        export_functions.add_function("fn export_rows() -> (String, Vec<u32>);");
+        export_functions.add_function("fn export_pair() -> (u32, i32);");
+        let (_, export_functions, types) =
+            with_tuple_return_structs(FunctionList::new(), export_functions, types);
+
+        let return_types = export_functions
+            .iter()
+            .map(|function| function.return_type.as_ref().unwrap().to_string())
+            .collect::<Vec<_>>();
+        // Pairs of primitives are still packed:
+        assert_eq!(return_types, vec!["(u32, i32)", "ExportRowsReturn"]);
+
+        let ty = match types.get(&TypeIdent::from("ExportRowsReturn")) {
+            Some(Type::Struct(ty)) => ty,
+            other => panic!("Unexpected return type: {:?}", other),
+        };
+        let fields = ty
+            .fields
+            .iter()
+            .map(|field| (field.name.as_deref(), field.ty.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![(None, "String".to_owned()), (None, "Vec<u32>".to_owned())]
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{
+    primitives::Primitive,
+    types::{Field, FieldValidation, Struct, Type, TypeIdent, TypeMap},
+};
+use std::collections::BTreeSet;
+
+/// The kind of value held by a field with `#[fp(validate(...))]`, which
+/// determines the rules that can be applied to it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ValidatedKind {
+    Number(Primitive),
+    String,
+    List,
+    Map,
+}
+
+pub(crate) struct ValidatedField<'a> {
+    /// Name of the field that is used in error messages, such as `Foo.bar`.
+    pub name: String,
+    pub kind: ValidatedKind,
+    /// Whether the field is an `Option`, in which case only values that are
+    /// present get validated.
+    pub is_optional: bool,
+    pub validation: &'a FieldValidation,
+}
+
+/// Returns the validation for the given field of a struct, if it has any.
+///
+/// Panics if the validation rules cannot be applied to the type of the field.
+pub(crate) fn get_validated_field<'a>(
+    owner: &TypeIdent,
+    field: &'a Field,
+    types: &TypeMap,
+) -> Option<ValidatedField<'a>> {
+    let validation = field.attrs.validate.as_ref()?;
+
+    let name = format!(
+        "{}.{}",
+        owner.name,
+        field
+            .name
+            .as_deref()
+            .unwrap_or_else(|| panic!("Fields of tuple struct `{}` cannot be validated", owner))
+    );
+    if field.attrs.has_custom_serializer() {
+        panic!(
+            "Field `{}` cannot be validated, because it uses a custom deserializer",
+            name
+        );
+    }
+    if field.attrs.flatten {
+        panic!(
+            "Field `{}` cannot be validated, because it is flattened",
+            name
+        );
+    }
+    if mentions_generic_arg(&field.ty, owner) {
+        panic!(
+            "Field `{}` cannot be validated, because its type depends on a generic argument",
+            name
+        );
+    }
+
+    let (kind, is_optional) = match types.get(&field.ty) {
+        Some(Type::Container(container, _)) if container == "Option" => {
+            let (arg, _) = field
+                .ty
+                .generic_args
+                .first()
+                .expect("Option was expected to contain a generic argument");
+            (get_validated_kind(arg, types), true)
+        }
+        _ => (get_validated_kind(&field.ty, types), false),
+    };
+    let kind = kind.unwrap_or_else(|| {
+        panic!(
+            "Field `{}` cannot be validated, only numbers, strings, lists and maps can be",
+            name
+        )
+    });
+
+    if let Some(range) = &validation.range {
+        let primitive = match kind {
+            ValidatedKind::Number(primitive) => primitive,
+            _ => panic!(
+                "Field `{}` cannot be validated using `range`, it is not a number",
+                name
+            ),
+        };
+        let is_negative = |bound: &Option<String>| {
+            bound
+                .as_deref()
+                .map(|bound| bound.starts_with('-'))
+                .unwrap_or_default()
+        };
+        if is_unsigned(primitive) && (is_negative(&range.start) || is_negative(&range.end)) {
+            panic!(
+                "Field `{}` cannot be validated using range `{}`, because it is unsigned",
+                name, range
+            );
+        }
+    }
+    if validation.non_empty && matches!(kind, ValidatedKind::Number(_)) {
+        panic!(
+            "Field `{}` cannot be validated using `non_empty`, it is a number",
+            name
+        );
+    }
+    if validation.regex.is_some() && kind != ValidatedKind::String {
+        panic!(
+            "Field `{}` cannot be validated using `regex`, it is not a string",
+            name
+        );
+    }
+
+    Some(ValidatedField {
+        name,
+        kind,
+        is_optional,
+        validation,
+    })
+}
+
+fn get_validated_kind(ident: &TypeIdent, types: &TypeMap) -> Option<ValidatedKind> {
+    match types.get(ident)? {
+        Type::Alias(_, ident) => get_validated_kind(ident, types),
+        Type::List(_, _) => Some(ValidatedKind::List),
+        Type::Map(_, _, _) => Some(ValidatedKind::Map),
+        Type::Primitive(Primitive::Bool) => None,
+        Type::Primitive(primitive) => Some(ValidatedKind::Number(*primitive)),
+        Type::String => Some(ValidatedKind::String),
+        _ => None,
+    }
+}
+
+fn mentions_generic_arg(ident: &TypeIdent, owner: &TypeIdent) -> bool {
+    owner
+        .generic_args
+        .iter()
+        .any(|(arg, _)| arg.name == ident.name)
+        || ident
+            .generic_args
+            .iter()
+            .any(|(arg, _)| mentions_generic_arg(arg, owner))
+}
+
+fn is_unsigned(primitive: Primitive) -> bool {
+    matches!(
+        primitive,
+        Primitive::U8 | Primitive::U16 | Primitive::U32 | Primitive::U64
+    )
+}
+
+/// Formats a range bound as a literal of the given primitive, so that floats
+/// may be validated using integer bounds.
+pub(crate) fn format_bound(bound: &str, primitive: Primitive) -> String {
+    let is_float = matches!(primitive, Primitive::F32 | Primitive::F64);
+    if is_float && !bound.contains(['.', 'e', 'E']) {
+        format!("{bound}.0")
+    } else {
+        bound.to_owned()
+    }
+}
+
+/// Checks the validation rules of all fields, so that invalid rules are
+/// reported regardless of which bindings are generated.
+pub(crate) fn check_validations(types: &TypeMap) {
+    for ty in types.values() {
+        match ty {
+            Type::Struct(ty) => {
+                for field in &ty.fields {
+                    get_validated_field(&ty.ident, field, types);
+                }
+            }
+            Type::Enum(ty) => {
+                for variant in &ty.variants {
+                    if let Type::Struct(variant_struct) = &variant.ty {
+                        if variant_struct
+                            .fields
+                            .iter()
+                            .any(|field| field.attrs.validate.is_some())
+                        {
+                            panic!(
+                                "Fields of enum variant `{}::{}` cannot be validated, only \
+                                    fields of structs can be",
+                                ty.ident, variant.name
+                            );
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns whether any field in the protocol is validated using a regular
+/// expression, which requires the `validation` feature of `fp-bindgen-support`.
+pub(crate) fn uses_regex_validation(types: &TypeMap) -> bool {
+    types.values().any(|ty| match ty {
+        Type::Struct(ty) => ty.fields.iter().any(|field| {
+            field
+                .attrs
+                .validate
+                .as_ref()
+                .map(|validation| validation.regex.is_some())
+                .unwrap_or_default()
+        }),
+        _ => false,
+    })
+}
+
+/// Returns the structs that need to be validated, either because they have
+/// validated fields or deny unknown fields themselves, or because they contain
+/// other structs that need to be validated.
+pub(crate) fn collect_validated_structs(types: &TypeMap) -> BTreeSet<TypeIdent> {
+    collect_structs_containing(types, |ty| {
+        ty.options.deny_unknown_fields
+            || ty.fields.iter().any(|field| field.attrs.validate.is_some())
+    })
+}
+
+/// Returns the structs that match the given predicate, or that contain other
+/// structs that do.
+pub(crate) fn collect_structs_containing<F>(types: &TypeMap, predicate: F) -> BTreeSet<TypeIdent>
+where
+    F: Fn(&Struct) -> bool,
+{
+    let mut matching = BTreeSet::new();
+    loop {
+        let mut changed = false;
+        for ty in types.values() {
+            if let Type::Struct(ty) = ty {
+                if !matching.contains(&ty.ident)
+                    && ty.ident.generic_args.is_empty()
+                    && (predicate(ty)
+                        || ty
+                            .fields
+                            .iter()
+                            .any(|field| contains_validated_struct(&field.ty, types, &matching)))
+                {
+                    matching.insert(ty.ident.clone());
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            return matching;
+        }
+    }
+}
+
+/// Returns whether the given type is, or contains, a struct that needs to be
+/// validated. Only `Option`s, lists and the values of maps are searched.
+pub(crate) fn contains_validated_struct(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    validated: &BTreeSet<TypeIdent>,
+) -> bool {
+    match types.get(ident) {
+        Some(Type::Alias(_, ident)) => contains_validated_struct(ident, types, validated),
+        Some(Type::Container(name, _)) if name == "Option" => ident
+            .generic_args
+            .first()
+            .map(|(arg, _)| contains_validated_struct(arg, types, validated))
+            .unwrap_or_default(),
+        Some(Type::List(_, _)) => ident
+            .generic_args
+            .first()
+            .map(|(arg, _)| contains_validated_struct(arg, types, validated))
+            .unwrap_or_default(),
+        Some(Type::Map(_, _, _)) => ident
+            .generic_args
+            .get(1)
+            .map(|(arg, _)| contains_validated_struct(arg, types, validated))
+            .unwrap_or_default(),
+        Some(Type::Struct(_)) => validated.contains(ident),
+        _ => false,
+    }
+}
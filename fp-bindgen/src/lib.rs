@@ -23,13 +23,13 @@ enabling tight integration with existing crates from the Rust ecosystem.
 
 The following table is intended to highlight the major differences between the different tools:
 
-| Feature                                                   |         `fp-bindgen`        | `wasm-bindgen` |         `wit-bindgen`           |
-| --------------------------------------------------------- | :-------------------------: | :------------: | :-----------------------------: |
-| Host environments                                         | Rust (Wasmer), TypeScript\* |     JS/TS      | Rust/Python (Wasmtime), JS/TS\* |
-| Guest languages                                           |            Rust\*           |      Rust      |           Rust, C\*             |
-| Protocol format                                           |     Rust (using macros)     |      N/A       |              .wit               |
-| Serialization format                                      |         MessagePack         |      JSON      |             Custom              |
-| [Can use existing Rust types](#using-existing-rust-types) |           &#9989;           |    &#10060;    |            &#10060;             |
+| Feature                                                   |             `fp-bindgen`           | `wasm-bindgen` |         `wit-bindgen`           |
+| --------------------------------------------------------- | :--------------------------------: | :------------: | :-----------------------------: |
+| Host environments                                         | Rust (Wasmer, Wasmi), TypeScript\* |     JS/TS      | Rust/Python (Wasmtime), JS/TS\* |
+| Guest languages                                           |               Rust\*               |      Rust      |           Rust, C\*             |
+| Protocol format                                           |        Rust (using macros)         |      N/A       |              .wit               |
+| Serialization format                                      |            MessagePack             |      JSON      |             Custom              |
+| [Can use existing Rust types](#using-existing-rust-types) |              &#9989;               |    &#10060;    |            &#10060;             |
 
 \*) These are only the _currently supported_ options. More may be added in the future.
 
@@ -77,170 +77,1825 @@ fp_bindgen::prelude::fp_export! {
 same module as where you invoke `fp_bindgen!()`. If you only have imports, or only have exports, you
 should create an empty block for the other.
 
+### Declaring the protocol as a trait
+
+Instead of two blocks, the protocol can also be declared as a single trait with the
+`#[fp_protocol_trait]` attribute, which expands into the same declarations and therefore replaces
+both blocks. Every method is marked with `#[import]` or `#[export]`, and is documented like any
+other trait method. `#[fp(...)]` attributes on the trait apply to all of its methods, unless a
+method overrides them, and the type parameters of the trait are shared by the methods that use them.
+Associated types with a default are declared as aliases:
+
+```ignore
+#[fp_bindgen::prelude::fp_protocol_trait]
+#[fp(capability = "storage")]
+trait Store<T> {
+    type Key = String;
+
+    /// Stores a value under the given key.
+    #[import]
+    fn set_value(key: Key, value: T);
+
+    /// Retrieves the value stored under the given key.
+    #[import]
+    async fn get_value(key: Key) -> Option<T>;
+
+    /// Called when a value has changed.
+    #[export]
+    fn value_changed(key: Key);
+}
+```
+
+The trait itself is not emitted, and neither are its methods implemented, so it only serves as the
+declaration of the protocol.
+
 ### Data structures
 
-Besides primitives, functions can pass Rust `struct`s and `enum`s as their arguments and return
-value, but only by value (passing a reference across the Wasm bridge is currently not supported) and
-only for types that implement `Serializable`.
+Besides primitives, functions can pass Rust `struct`s and `enum`s as their arguments and return
+value, but only by value (passing a reference across the Wasm bridge is currently not supported) and
+only for types that implement `Serializable`.
+
+**Example:**
+
+```ignore
+#[derive(fp_bindgen::prelude::Serializable)]
+pub struct MyStruct {
+    pub foo: i32,
+    pub bar: String,
+}
+
+fp_bindgen::prelude::fp_import! {
+    fn my_function(data: MyStruct) -> MyStruct;
+}
+```
+
+Note that `Serializable` is implemented by default for some common standard types, such as
+`Option`, `Vec`, and other container types.
+
+Pairs of primitives that fit in 32 bits, such as `(u32, i32)` or `(f32, bool)`, are supported as
+well. When they are returned from a synchronous function, they are packed into a single `u64`
+instead of being serialized, which makes them cheap to return from functions that are called often.
+
+Functions may return multiple values as a tuple of any size, such as `(Stats, Vec<Row>)`. The Rust
+bindings keep the tuple, so callers can destructure it. Languages without tuples of their own
+receive an implicit tuple struct that is named after the function instead, such as `ListRowsReturn`
+for `list_rows()`. Both are serialized as arrays, so the wire format is the same.
+
+Because `usize` and `isize` are 32 bits wide on `wasm32`, they are treated as `u32` and `i32`, and
+the generated bindings use those types instead. Runtimes on 64-bit hosts can therefore not exchange
+values that don't fit in 32 bits. The `NonZero` integer types, such as `NonZeroU32`, are supported
+as well. They are represented as regular numbers in TypeScript, but the Rust bindings refuse to
+deserialize them if they are zero.
+
+`char` is exchanged as a string containing a single character. MessagePack has no 128-bit integers,
+so `i128` and `u128` are exchanged as decimal strings, and typed as the branded string types
+`I128String` and `U128String` in TypeScript. Because the string encoding is applied through a field
+annotation, 128-bit integers can only be used inside structs and enums, not directly as function
+arguments. `f32` and `f64`
+values keep NaN and infinities intact in all runtimes, but the TypeScript runtime doesn't preserve
+NaN payloads or the sign of a negative zero.
+
+Fields may use a custom (de)serializer through Serde's `with`, `serialize_with` and
+`deserialize_with` annotations, which are preserved on the generated Rust types. Because the
+serialized representation of such fields cannot be derived from their Rust type, they require an
+explicit TypeScript type as well:
+
+```ignore
+#[derive(fp_bindgen::prelude::Serializable)]
+pub struct MyEvent {
+    #[fp(with = "time::serde::timestamp", ts_type = "number")]
+    pub timestamp: time::OffsetDateTime,
+}
+```
+
+Generating TypeScript bindings fails if the `ts_type` annotation is missing for such a field.
+
+Timestamps such as `OffsetDateTime` are serialized as RFC3339 strings, so they show up as `string`
+in TypeScript. Fields that are annotated with `#[fp(ts_as = "Date")]` show up as `Date` instead:
+the TypeScript runtime converts the strings into `Date` objects when values are received from the
+plugin, and back into strings when values are sent to it:
+
+```ignore
+#[derive(fp_bindgen::prelude::Serializable)]
+pub struct Document {
+    #[fp(ts_as = "Date")]
+    pub created_at: time::OffsetDateTime,
+    #[fp(ts_as = "Date")]
+    pub deleted_at: Option<time::OffsetDateTime>,
+}
+```
+
+The annotation can be used on (optional) timestamp fields of structs without type parameters.
+
+Fields of structs can be validated using `#[fp(validate(...))]` annotations, so that malformed
+data is rejected right where it enters the plugin or the runtime, with an error that names the
+offending field:
+
+```ignore
+#[derive(fp_bindgen::prelude::Serializable)]
+pub struct MyConfig {
+    #[fp(validate(range = "0..=100"))]
+    pub percentage: u8,
+    #[fp(validate(non_empty, regex = "^[a-z][a-z0-9-]*$"))]
+    pub slug: String,
+}
+```
+
+`range` applies to numbers, `non_empty` to strings, lists and maps, and `regex` to strings. Fields
+that are an `Option` are only validated if they have a value. The generated Rust types validate
+these fields while they are being deserialized, while the TypeScript runtime validates the values
+it receives from the plugin and throws a `ValidationError` if they are invalid. Regular expressions
+should stick to the syntax that is shared by Rust's `regex` crate and JavaScript. In Rust, they
+require the `validation` feature of `fp-bindgen-support`, which the generated plugin crate enables
+automatically. Types that are imported through `rust_module` are not validated.
+
+By default, fields that are not part of a struct are silently ignored when it is deserialized. To
+detect protocol drift instead, structs can be annotated with `#[serde(deny_unknown_fields)]`,
+which the generated Rust types keep. The TypeScript runtime then throws a `ValidationError` for
+values from the plugin that contain unknown fields, unless the struct is generic, and the OpenAPI
+schema disallows additional properties. As with Serde, such structs cannot have flattened fields.
+Adding fields to such a struct is a breaking change, even if they are optional.
+
+Enums, on the other hand, reject variants they don't know about. Enums that may grow new variants
+can be marked `#[non_exhaustive]`, in which case the generated Rust types get an additional
+`Unknown` variant. Any variant that was added after the bindings were generated is deserialized
+into it, together with its serialized value, so older plugins and runtimes can still pass it on.
+The TypeScript types get a matching catch-all, such as `{ type: string }` for an enum that is
+tagged with `type`:
+
+```ignore
+#[derive(fp_bindgen::prelude::Serializable)]
+#[fp(tag = "type")]
+#[non_exhaustive]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { size: f64 },
+}
+```
+
+The `Unknown` variant holds an `rmpv::Value`, which requires the `non-exhaustive` feature of
+`fp-bindgen-support` and Serde 1.0.181 or later, so it is not available to `no_std` plugins.
+Untagged enums and enums with an `other` variant cannot be non-exhaustive. Structs may be marked
+`#[non_exhaustive]` too, but since they already ignore unknown fields, this only ensures they are
+not combined with `deny_unknown_fields`.
+
+Fields and variants behind a `#[cfg(...)]` attribute only end up in the bindings if the condition
+holds while the protocol is compiled, so you can keep experimental parts of a protocol behind a
+feature flag. If a field or variant should only be part of the bindings of some generators, you can
+list them in an `#[fp(generators = "...")]` annotation. Generators are named the same way as
+`BindingsType` is displayed, such as `rust-plugin`, `rust-wasmer-runtime` or `ts-runtime`:
+
+```ignore
+#[derive(fp_bindgen::prelude::Serializable)]
+pub struct MyEvent {
+    pub message: String,
+    #[cfg(feature = "experimental")]
+    pub details: ExperimentalDetails,
+    #[fp(generators = "rust-plugin, rust-wasmer-runtime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backtrace: Option<String>,
+}
+```
+
+Keep in mind that the bindings on the other side of the plugin boundary may not know about such a
+field, so it should be optional on both sides.
+
+Generic types may use `PhantomData<T>` fields to mark type parameters that don't appear in any of
+their other fields. Such fields are not serialized: the Rust bindings keep them with a
+`#[serde(skip)]` annotation, while the other bindings leave them out and only preserve the type
+parameter in the signature of the type.
+
+### Async functions
+
+Functions can also be `async`, which works as you would expect:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_import! {
+    async fn my_async_function(data: MyStruct) -> Result<MyStruct, MyError>;
+}
+```
+
+Async imports without a return value are _fire-and-forget_: the runtime starts the call and
+returns to the plugin right away, so the plugin's future completes without waiting for the host,
+and no result is passed back. Calls reach the runtime in the order in which the plugin makes them,
+but they may complete in any order, because the Rust runtimes spawn them onto the Tokio runtime
+and the TypeScript and C# runtimes don't await them. Errors can only be logged by the runtime. If a
+plugin needs to know when an import has finished, the import should return a value, such as
+`Result<(), MyError>`.
+
+### Dynamic imports
+
+Sometimes a runtime exposes functionality to plugins that is not known when the bindings are
+generated, such as user-configurable commands. For such cases, an import can be marked as
+_dynamic_. Dynamic imports take exactly two arguments: a `String` with the name of the handler to
+invoke, and a serialized payload:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_import! {
+    #[fp(dynamic)]
+    fn invoke_command(name: String, payload: CommandPayload) -> CommandResult;
+}
+```
+
+The runtime implements the import like any other, but may also register handlers for it after the
+runtime has been created. Calls from the plugin are dispatched to the handler registered under the
+given name, and fall back to the regular implementation if there is none. The Rust Wasmer runtime
+exposes `register_invoke_command()` and `unregister_invoke_command()` methods for this, while the
+TypeScript runtime exposes `registerInvokeCommand()` and `unregisterInvokeCommand()`.
+
+### Events
+
+Plugins sometimes need to notify the runtime of something without waiting for a response, such as
+the progress of a long-running task. Rather than declaring an async import that never resolves,
+such notifications can be declared as _events_, using the `#[fp_event]` attribute in the
+`fp_import!` block. Events take a single, non-primitive payload and don't return anything:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_import! {
+    #[fp_event]
+    fn report_progress(progress: Progress);
+}
+```
+
+The plugin emits an event by calling the import, at any time. The runtime doesn't implement it,
+but passes every event on to its subscribers instead. The Rust runtimes expose a
+`subscribe_report_progress()` method that returns a `std::sync::mpsc::Receiver`, which receives
+the events until it is dropped. The TypeScript runtime exposes `on("reportProgress", handler)` and
+`off("reportProgress", handler)`, and the C# runtime raises a `ReportProgress` event.
+
+### Batching imports
+
+Every call to an import crosses the boundary between the plugin and the runtime, which can add up
+for plugins that make many small calls, such as for logging or metrics. Imports that take arguments
+but don't return anything can be marked as _batchable_:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_import! {
+    #[fp(batchable)]
+    fn record_metric(name: String, value: f64);
+}
+```
+
+Batchable imports are called immediately by default. But when the plugin calls them from inside
+the `batch()` function that is exported by its bindings, the calls are queued instead, and the
+runtime receives them together once the closure returns. The runtime then invokes its
+implementations in the same order the calls were made:
+
+```ignore
+batch(|| {
+    for (name, value) in metrics {
+        record_metric(name, value);
+    }
+});
+```
+
+### Capabilities
+
+Imports that give plugins access to sensitive resources, such as the network or the file system,
+can declare the _capability_ they require:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_import! {
+    #[fp(capability = "network")]
+    async fn make_http_request(request: Request) -> HttpResult;
+}
+```
+
+Runtimes don't grant any capabilities unless they are created with an allowlist. The Rust runtimes
+generate a `Capability` enum for this purpose, while the TypeScript runtime accepts the names of the
+capabilities:
+
+```ignore
+let runtime = Runtime::with_capabilities(wasm_module, &[Capability::Network])?;
+```
+
+```ignore
+const runtime = await createRuntime(plugin, imports, ["network"]);
+```
+
+If a plugin calls an import that requires a capability that wasn't granted, the call fails with
+`InvocationError::PermissionDenied` in the Rust runtimes, or a `PermissionDeniedError` in the
+TypeScript runtime. Only imports can require capabilities.
+
+### Rate limits
+
+Imports that are expensive for the host, such as outbound HTTP requests, can be protected against
+plugins that call them too often using `#[fp(rate_limit)]`. Every rate-limited import has a token
+bucket that holds up to `calls` tokens, and that is refilled with `calls` tokens every `per`. Calls
+for which no token is available don't reach the host, but return a `RateLimited` error to the
+plugin, which tells it after how many milliseconds it may try again. Rate-limited imports must
+therefore return `Result<T, RateLimited>`:
+
+```ignore
+fp_bindgen::prelude::fp_import! {
+    #[fp(rate_limit(calls = 10, per = "1s"))]
+    async fn make_http_request(request: Request) -> Result<HttpResult, RateLimited>;
+}
+```
+
+Limits are enforced by the Rust Wasmer runtime, whose clones and pooled instances share the same
+buckets, and by the TypeScript runtime, which keeps buckets per plugin instance. Other runtimes call
+the host for every call. Dynamic and batchable imports cannot be rate-limited, and only imports can
+be.
+
+### HTTP endpoints
+
+Functions can be annotated with the HTTP endpoint through which they should be exposed, in which
+case they are included in the OpenAPI document that is generated by `BindingsType::OpenApi`. This
+allows publishing a REST facade that matches the protocol exactly:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_export! {
+    #[fp(http(method = "GET", path = "/series/{id}"))]
+    fn get_series(id: String, limit: Option<u32>) -> Result<Series, SeriesError>;
+}
+```
+
+Parameters in the path are bound to the argument with the same name. The remaining arguments are
+passed as query parameters for methods without a request body, such as `GET`, or as a JSON request
+body otherwise. Functions that return a `Result` respond with the `Ok` value on success, and with
+the `Err` value otherwise. The protocol's structs and enums are listed as schemas in the document's
+`components` section, following their Serde representation in JSON.
+
+### GraphQL queries
+
+Exports that only read data can be annotated as `read_only`, in which case they are included as
+queries in the GraphQL schema that is generated by `BindingsType::GraphQl`. This allows an API
+gateway to expose them over GraphQL without mirroring the protocol's types by hand:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_export! {
+    /// Returns the series that match the query.
+    #[fp(read_only)]
+    fn search_series(query: SeriesQuery) -> Result<Vec<Series>, SeriesError>;
+}
+```
+
+Queries and their arguments are named in camelCase, such as
+`searchSeries(query: SeriesQueryInput!)`. Structs are defined as object types when they are
+returned, and as input types with an `Input` suffix when they are passed as an argument. Their
+fields use the names by which they are serialized. Enums that are serialized as plain strings become GraphQL enums, while types that
+GraphQL cannot describe, such as maps, tuples and enums with data, use a `JSON` scalar. Integers
+that don't fit in 32 bits use a `BigInt` scalar. Exports that return a `Result` resolve to the `Ok`
+value, so errors should be reported through the `errors` of the GraphQL response.
+
+### Feature-gated functions
+
+Functions that are not ready to be used by everyone, such as experimental additions to a protocol,
+can be placed behind a Cargo feature:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_import! {
+    #[fp(cfg_feature = "experimental")]
+    fn preview_chart(query: ChartQuery) -> Chart;
+}
+```
+
+The Rust bindings wrap such functions in `#[cfg(feature = "experimental")]`, so plugins and
+runtimes only see them if they enable the feature. The generated plugin crate declares the feature
+in its `Cargo.toml`, while runtimes need to declare it themselves.
+
+The TypeScript runtime moves these functions to a module named after the feature, such as
+`experimental.ts`, which is not re-exported from `index.ts`. Its imports are passed to
+`createRuntime()` or `instantiatePlugin()` separately, while its exports are called on the plugin
+instance:
+
+```ignore
+import { createExperimentalImports, exportExperimental } from "./bindings/experimental.ts";
+
+const plugin = await instantiatePlugin(wasm, imports, [
+    createExperimentalImports({ previewChart: (query) => ... }),
+]);
+```
+
+Feature-gated functions cannot be combined with the `dynamic`, `batchable` or `capability`
+attributes, nor can they be events. Other generators include them as usual.
+
+### Renaming functions
+
+Functions are exchanged between runtime and plugin under their own name. To rename a function
+without breaking plugins that were built against the old name, the old name can be kept on the
+wire:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_import! {
+    #[fp(rename = "fetch_data")]
+    async fn fetch_series(query: SeriesQuery) -> Vec<Series>;
+}
+```
+
+The generated bindings use the new name, such as `fetch_series()` in Rust and `fetchSeries()` in
+TypeScript, while the Wasm symbol is still `__fp_gen_fetch_data`. Plugins that implement a renamed
+export need to pass the name on the wire to the `fp_export_impl` macro as well:
+
+```ignore
+#[fp_export_impl(example_bindings, rename = "fetch_data")]
+async fn fetch_series(query: SeriesQuery) -> Vec<Series> {
+    ...
+}
+```
+
+### Generic imports
+
+Imports may be generic over one or more type parameters, whose types are picked by the plugin for
+every call. This is useful for providers that store or retrieve values of arbitrary shape, such as
+a key-value store:
+
+**Example:**
+
+```ignore
+fp_bindgen::prelude::fp_import! {
+    async fn fetch<T>(query: Query) -> T;
+    fn store<T>(key: String, value: T);
+}
+```
+
+The Rust plugin bindings require the type parameters to implement `Serialize` and
+`DeserializeOwned`, so plugins can call `fetch::<Config>(query)` with any protocol or Serde type.
+The TypeScript runtime declares them as generic functions, such as
+`fetch: <T>(query: types.Query) => Promise<T>`, and passes the values on as they are decoded from
+MessagePack. The Rust runtimes infer the types from the implementation, so runtimes implement these
+imports for the concrete type they expect, such as `serde_json::Value`. The C# runtime represents
+them as `object`.
+
+Only imports can be generic, and they cannot be combined with the `dynamic`, `batchable` or `http`
+attributes, nor can they be events. The benchmarks skip generic imports, and the fuzz targets leave
+out the values of generic types.
+
+### Using existing Rust types
+
+Sometimes you may wish to use Rust types for your protocol that you also want to use directly in the
+generated runtime or plugin implementation. In such a case, generation of the data types might force
+you to perform unnecessary copies, so we allow explicit annotations to import the existing
+definition instead of generating a new one:
+
+**Example:**
+
+```
+use fp_bindgen::prelude::Serializable;
+
+#[derive(Serializable)]
+#[fp(rust_module = "my_crate::prelude")]
+pub struct MyStruct {
+    pub foo: i32,
+    pub bar_qux: String,
+}
+```
+
+In this example, `MyStruct` has a double function: it acts both as a type definition for the
+protocol (through `fp-bindgen`'s `Serializable` trait), which can still be used for generating a
+TypeScript type definition, for instance. _And_ it acts as a type that can be directly used by the
+Rust Wasmer runtime, under the assumption the runtime can import it from `my_crate::prelude`.
+
+Please note that in this case, you do have a bigger responsibility to make sure the definition
+fulfills the requirements of the code generator, hence why Serde's trait derives and annotations
+have to be added manually here, in accordance with how the generator would otherwise generate them.
+
+For now, this feature is limited to the Rust generators through the
+`rust_module` annotation. For us, this makes sense given the
+protocol itself is specified using Rust syntax as well. If desired, we could extend this to the
+TypeScript generator as well, though that would imply an even bigger responsibility for the user to
+keep their TypeScript types in sync with the protocol.
+
+#### Types from other crates
+
+Types from crates you cannot modify cannot derive `Serializable`. Instead, you can declare their
+serialized fields with the `serializable_remote!` macro, much like Serde's remote derive. The
+declaration uses the same syntax and annotations as a type that derives `Serializable`, with an
+`fp(remote)` annotation that gives the path of the remote type:
+
+```ignore
+use fp_bindgen::prelude::*;
+
+serializable_remote! {
+    #[fp(remote = "geo_crate::GeoPoint")]
+    #[serde(rename_all = "camelCase")]
+    pub struct GeoPoint {
+        pub latitude: f64,
+        pub longitude: f64,
+    }
+}
+```
+
+The declared type can be used in the protocol under the same name as the remote type. The Rust
+generators import the remote type from its module, just as they would for a `rust_module`
+annotation, while the other generators use the declared fields. Make sure the declaration matches
+the Serde representation of the remote type, and add the crate that defines it as a dependency of
+the generated bindings.
+
+### Cargo features
+
+The `fp-bindgen` crate supports optional Cargo features for compatibility with some common types
+from the crate ecosystem:
+
+- `bytes-compat`: Enables compatibility with the `bytes::Bytes` and `bytes::BytesMut` types.
+- `http-compat`: Enables compatibility with various types from the `http` crate.
+- `num-bigint-compat`: Enables compatibility with the `num_bigint::BigInt` type.
+- `rmpv-compat`: Enables compatibility with the `rmpv::Value` type.
+- `rust-decimal-compat`: Enables compatibility with the `rust_decimal::Decimal` type.
+- `serde-bytes-compat`: Enables compatibility with the `serde_bytes::ByteBuf` type (the `Bytes` type
+  is a reference type, which `fp-bindgen` doesn't support in general).
+- `serde-json-compat`: Enables compatibility with `serde_json::Map` and `serde_json::Value` types.
+- `time-compat`: Enables compatibility with `time`'s `PrimitiveDateTime` and `OffsetDateTime` types.
+
+`Decimal` and `BigInt` are exchanged as strings, so they don't lose precision the way an `f64`
+would. In TypeScript, they are typed as the branded string types `DecimalString` and
+`BigIntString`, so they can't be mixed up with other strings by accident. Use `i64` or `u64` instead
+if your values fit, since those are exchanged losslessly as `bigint` in TypeScript.
+
+### Dynamic values
+
+Sometimes the shape of a value isn't known at the time the protocol is defined, for instance for
+settings that are only interpreted by a specific plugin. With the `serde-json-compat` or
+`rmpv-compat` feature enabled, `serde_json::Value` and `rmpv::Value` can be used anywhere in the
+protocol for such values:
+
+```ignore
+use serde_json::Value;
+
+fp_export! {
+    fn configure(settings: Value) -> Value;
+}
+```
+
+Both are encoded as plain MessagePack and show up as `unknown` in TypeScript. Note that
+`serde_json::Value` cannot represent MessagePack binary data or non-string map keys, so prefer
+`rmpv::Value` if you need to pass arbitrary MessagePack. Because both types are named `Value`, a
+protocol can only use one of them.
+
+### Sets and queues
+
+Besides `Vec`, the `BTreeSet`, `HashSet` and `VecDeque` collections can be used in protocol types.
+All of them are encoded as MessagePack arrays and show up as `Array<T>` in TypeScript. With
+`TsExtendedRuntimeConfig::with_set_types()`, sets show up as `Set<T>` instead:
+
+```ignore
+#[derive(Serializable)]
+pub struct Collections {
+    pub tags: BTreeSet<String>,
+    pub queue: VecDeque<u32>,
+}
+```
+
+Set semantics are only enforced by the Rust side: if TypeScript sends an array with duplicate
+items, the plugin silently drops them while deserializing the set. Keep in mind that JavaScript
+sets compare objects by reference, so `Set<T>` works best for primitives and strings. Sets inside
+enum variants, tuples or generic arguments cannot be converted to `Set<T>`, and the TypeScript
+generator panics on them if `with_set_types()` is used. As always, `HashSet` is not available
+to `no_std` plugins.
+
+### Stricter TypeScript types
+
+Hosts that prefer stricter types can use `TsExtendedRuntimeConfig::with_readonly_types()`, which
+marks all fields of the generated types as `readonly`, and types lists, sets and maps as
+`ReadonlyArray<T>`, `ReadonlySet<T>` and `Readonly<Record<K, V>>`.
+
+Newtype structs show up as plain aliases of their inner type, so a `UserId(u32)` can be mixed up
+with any other number. With `TsExtendedRuntimeConfig::with_branded_types()`, newtypes that are
+annotated with `#[fp(brand)]` become branded types instead:
+
+```ignore
+#[derive(Serializable)]
+#[fp(brand)]
+pub struct UserId(u32);
+```
+
+This generates `type UserId = number & { readonly __brand: "UserId" }`, so hosts have to cast
+numbers explicitly using `value as UserId`. Other generators ignore the annotation.
+
+### Results inside data types
+
+`Result` is not limited to return types. It can be used for fields and aliases as well, for
+instance to report the outcome of every operation in a batch:
+
+```ignore
+#[derive(Serializable)]
+pub struct BatchResponse {
+    pub results: Vec<Result<u64, String>>,
+}
+```
+
+Rust bindings use the standard `Result` type with its regular Serde representation, which shows
+up as `{ Ok: T } | { Err: E }` in TypeScript.
+
+### Columnar lists
+
+Large lists of small structs, such as the samples of a time series, are expensive to encode as
+MessagePack, since every item is a map of its own. Structs that only have numeric fields can be
+annotated with `#[fp(columnar)]` instead:
+
+```ignore
+#[derive(Serializable)]
+#[fp(columnar, rename_all = "camelCase")]
+pub struct Sample {
+    pub timestamp_ms: u64,
+    pub value: f64,
+}
+```
+
+Every `Vec<Sample>` in the protocol is then replaced with a generated `SampleColumns` struct,
+which has a `Vec` with the values of every field. The columns are encoded as contiguous bytes by
+`fp_bindgen_support::common::columnar`, which the TypeScript runtime decodes into typed arrays,
+such as `BigUint64Array` and `Float64Array` here. Generators that do not support the encoding,
+such as the AssemblyScript generator, skip the functions that use the columns.
+
+### Interned strings
+
+Protocols that report telemetry tend to send the same few strings, such as metric names or column
+identifiers, over and over. String fields that are annotated with `#[fp(interned)]` are sent only
+once by the plugin:
+
+```ignore
+#[derive(Serializable)]
+pub struct Metric {
+    #[fp(interned)]
+    pub name: String,
+    pub value: f64,
+}
+```
+
+The first time a plugin sends a value of such a field, it registers the value with the runtime
+through the reserved `__fp_intern_string()` import, and the runtime returns a `u32` handle for it.
+From then on, the plugin sends the handle instead of the string, and the runtime resolves it back
+into the string when deserializing the struct. Values that are sent to the plugin are always sent
+as strings. The Rust runtimes keep their intern table in `fp_bindgen_support::common::interning`,
+which is shared by all runtimes in the process, while the TypeScript runtime keeps one in
+`conversions.ts`. Strings are never removed from the tables, so only intern fields that have a
+limited set of values.
+
+Only the named `String` fields of structs without type parameters can be interned. Imports whose
+arguments or return types contain interned fields cannot be routed to other plugins, and the C#
+runtime does not provide the import, so it cannot load plugins that intern strings.
+
+### Plugin lifecycle
+
+Every protocol has an `init` and a `shutdown` export, which the generators add if the protocol
+doesn't declare them. Plugins may implement or omit either of them. The runtimes call `init` after
+instantiating the plugin if you ask them to, and `shutdown` when the runtime is discarded: when the
+last clone of a Rust `Runtime` is dropped, when `dispose()` is called on the exports of the
+TypeScript runtime, or when the C# `Runtime` is disposed. Protocols can declare `init` themselves to
+have it take a config:
+
+```ignore
+fp_export! {
+    fn init(config: PluginConfig);
+}
+```
+
+In Rust, the config is passed to `RuntimeBuilder::with_init()`, while `createRuntime()` in
+TypeScript accepts it as the `init` option. If `init` doesn't take a config, `with_init()` takes no
+argument and the `init` option is a boolean. An `init` export that fails makes building the runtime
+fail with `RuntimeError::InitFailed`. `shutdown` can never take arguments, and neither export may
+return a value or be async.
+
+Protocols whose `init` takes a config also get an `update_config` export, through which the host
+passes a new config later on, such as `runtime.update_config(config)` in Rust. The Rust plugin
+bindings implement it themselves, and cache the config that is passed to either export, so plugins
+don't need imports to fetch their config. It is returned by `fp_config()`, and listeners that are
+registered with `on_config_change()` are called with every update:
+
+```ignore
+#[fp_export_impl(example_bindings)]
+fn init(config: PluginConfig) {
+    on_config_change(|config: &PluginConfig| set_log_level(&config.log_level));
+}
+
+fn current_log_level() -> String {
+    fp_config::<PluginConfig>()
+        .map(|config| config.log_level.clone())
+        .unwrap_or_default()
+}
+```
+
+Since `init` passes a clone of its config to the cache, the config type must implement `Clone`.
+
+Besides calling `shutdown`, `dispose()` releases what the TypeScript runtime holds on to for the
+plugin: promises of async exports that are still pending are rejected with a `PluginDisposedError`,
+results that were never awaited are freed, and event and dynamic import handlers are dropped.
+Calling exports with serialized values afterwards fails with a `PluginError` of kind `"disposed"`.
+
+## Generating bindings
+
+To generate bindings based on your protocol, you first need to create a function that will generate
+them for you. Creating this function is easy, because its implementation can be created for you
+using the `fp_bindgen` macro:
+
+```ignore
+let bindings_type = fp_bindgen::BindingsType::RustWasmerRuntime;
+
+fp_bindgen::prelude::fp_bindgen!(fp_bindgen::BindingConfig {
+    bindings_type,
+    path: &format!("bindings/{}", bindings_type),
+});
+```
+
+Only the `bindings_type` and `path` need to be given. The other options described below have
+defaults, and can be set by adding them as fields. Outside of `fp_bindgen!()`, the config is
+constructed using `BindingConfig::new()`, and the options are set using its `with_*()` methods.
+
+The `aliases` option determines how the aliases in your protocol are represented (see
+[Can I use aliases?](#can-i-use-aliases)).
+
+The `headers` option lets you prepend a header to every generated file with a given extension, for
+instance to add a license notice, or to exclude the files from linting:
+
+```ignore
+fp_bindgen::FileHeaders::new()
+    .with_header("rs", "// @generated\n#![allow(clippy::all)]")
+    .with_header("ts", "// @generated\n/* eslint-disable */")
+```
+
+The `unused_types` option determines whether types that are not used by any function are generated
+(see [I added a `Serializable` derive to my type, why don't I see it included in the
+bindings?](#i-added-a-serializable-derive-to-my-type-why-dont-i-see-it-included-in-the-bindings)).
+
+The `formatting` option determines how the generated files are formatted. Rust files are formatted
+using `rustfmt` for the 2018 edition by default, and are written as they are if `rustfmt` cannot be
+found, unless `MissingFormatter::Fail` is set. TypeScript and JavaScript files can be formatted with
+an external command, which is run once with the paths of all generated files:
+
+```ignore
+fp_bindgen::Formatting::new()
+    .with_rustfmt_edition("2021")
+    .with_missing_formatter(fp_bindgen::MissingFormatter::Fail)
+    .with_ts_formatter(&["npx", "prettier", "--write"])
+```
+
+Currently, we support the following binding types:
+
+- `BindingsType::RustPlugin`: Generates bindings for a Rust plugin.
+- `BindingsType::RustWasmerRuntime`: Generates runtime bindings for use with Wasmer.
+- `BindingsType::RustWasmerRuntimeWithExtendedConfig`: Generates runtime bindings for use with
+  Wasmer, with additional options.
+- `BindingsType::RustWasmiRuntime`: Generates runtime bindings for use with the Wasmi interpreter.
+- `BindingsType::TsRuntimeWithExtendedConfig`: Generates bindings for a TypeScript runtime.
+- `BindingsType::CSharpRuntime`: Generates a C# project with bindings for a .NET runtime.
+- `BindingsType::AssemblyScriptPlugin`: Generates bindings for a plugin written in AssemblyScript.
+- `BindingsType::OpenApi`: Generates an OpenAPI document for functions with an HTTP endpoint.
+- `BindingsType::GraphQl`: Generates a GraphQL schema with a query for every read-only export.
+- `BindingsType::Docs`: Generates markdown documentation of the protocol, with example payloads.
+- `BindingsType::Benches`: Generates benchmarks for the Rust Wasmer and TypeScript runtimes.
+- `BindingsType::Fuzz`: Generates fuzz targets for the deserialization in the Rust and TypeScript
+  runtimes.
+- `BindingsType::Fixtures`: Generates serialized fixtures for the types of the protocol, with tests
+  that decode them in Rust and TypeScript.
+- `BindingsType::Cli`: Generates a command-line harness for calling the exports of a plugin.
+- `BindingsType::Scaffold`: Generates the skeleton of a plugin crate, with stubs for all exports.
+- `BindingsType::RustTypes`: Generates a crate that only contains the types of the protocol.
+
+Note that some binding types take an additional config argument.
+
+Before any bindings are written, the protocol is checked for declarations that are valid Rust, but
+that not every binding type can represent faithfully. These are printed as warnings, which point at
+the file and line of the type involved:
+
+- Names that collide once they are converted to camelCase, such as `my_value` and `myValue`.
+- Function and argument names that are reserved words in TypeScript, and types that shadow
+  built-in TypeScript types.
+- Unsupported usages, such as `Option<Option<T>>`, or maps with keys that are not strings, numbers
+  or unit-only enums.
+- Types that are registered, but not used by any function.
+- Enums that cannot be serialized unambiguously, such as untagged enums with multiple unit
+  variants, or internally tagged enums with newtype variants that do not contain a map.
+
+### Composing protocols
+
+A base protocol, such as one for logging or configuration, can be shared by multiple protocols. The
+crate of the base protocol captures it using the `fp_protocol!()` macro in the module that holds its
+`fp_import!` and `fp_export!` blocks:
+
+```ignore
+pub fn protocol() -> fp_bindgen::Protocol {
+    fp_protocol!()
+}
+```
+
+Other protocols then add its functions and types to their own using `Protocol::extend()`, and
+generate bindings for the combined protocol:
+
+```ignore
+let mut protocol = fp_protocol!();
+protocol.extend(logging_protocol::protocol());
+protocol.generate_bindings(config);
+```
+
+Functions and types that are declared by both protocols are only included once, regardless of the
+order in which the protocols are combined. If both protocols declare a function or type with the
+same name, but a different definition, `extend()` panics with the name of the conflict, since the
+bindings cannot contain both.
+
+## Using the bindings
+
+How to use the generated bindings differs between the various types.
+
+### Using the Rust plugin bindings
+
+The generator for our Rust plugin bindings generates a complete crate that allows to be linked
+against by plugins. The plugin can import all the functions from the `fp_import!` block from it,
+and call them like any other functions.
+
+In order to export the functions that are defined in the `fp_export!` block, it can use the exported
+`fp_export_impl` macro, like so:
+
+```ignore
+#[fp_bindgen_macros::fp_export_impl(bindings_crate_path)]
+fn my_exported_function(a: u32, b: u32) -> u32 {
+    /* ... */
+}
+```
+
+`bindings_crate_path` is expected to match with the module path from which the bindings crate
+itself is imported. The function signature must match exactly with one of the `fp_export!`
+functions, with one exception: synchronous functions that return a non-primitive type may return
+anything that implements `Borrow` for that type instead. This allows returning a `&'static T` or
+`Cow<'static, T>`, which is serialized directly without cloning it first:
+
+```ignore
+#[fp_bindgen_macros::fp_export_impl(bindings_crate_path)]
+fn get_config() -> &'static Config {
+    &CONFIG
+}
+```
+
+When compiling a plugin, don't forget to compile against the "wasm32-unknown-unknown" target, or you
+will receive linker errors.
+
+Imported functions take `String` and `Vec<T>` arguments as `&str` and `&[T]`, since they only need
+to be borrowed in order to be passed to the runtime. If you'd rather keep passing owned values, for
+instance to avoid updating existing call sites, you can call `with_owned_import_args()` on the
+`RustPluginConfig`.
+
+For plugins that need to be as small as possible, you can call `with_no_std()` on the
+`RustPluginConfig`. The generated crate is then marked `#![no_std]` and only uses `core` and
+`alloc`, so it can be used by `#![no_std]` plugins. It also drops the dependencies that plugins
+don't strictly need, and uses `serde` without its `std` feature. `HashMap` and `HashSet` are not available in this mode, so use
+`BTreeMap` and `BTreeSet` instead. Note that `fp-bindgen-support` still relies on the standard
+library for MessagePack serialization. To get a plugin below 100KB, you will also want to build it
+with `opt-level = "z"`, `lto = true`, `codegen-units = 1` and `panic = "abort"` in its release
+profile.
+
+Plugins that are compiled with `panic = "abort"` cannot unwind, so a failure in the generated glue
+code, such as a value that cannot be deserialized, would normally end in an opaque trap. If you call
+`with_panic_abort()` on the `RustPluginConfig`, the glue code reports such failures to the runtime
+before it aborts instead. The runtimes surface them as an `InvocationError::GuestError` in Rust, a
+`GuestError` in TypeScript and a `GuestErrorException` in C#, with a `kind` that tells you what went
+wrong, and the original message. To report the plugin's own panics the same way, install the panic
+hook from its `init()` export:
+
+```ignore
+#[fp_export_impl(example_bindings)]
+fn init() {
+    fp_bindgen_support::guest::errors::set_panic_hook();
+}
+```
+
+Async exports that fail this way would leave the runtime waiting for a result that never comes, so
+plugins and runtimes that support version 2 of the async value protocol pass the error through the
+async value instead: the future or promise of the export then fails with the `GuestError`. Both
+sides agree on the version through the `__fp_guest_async_protocol()` export when the plugin is
+instantiated, so older plugins and runtimes keep using version 1. The Wasmer and TypeScript runtimes
+support version 2, while the wasmi runtime still uses version 1.
+
+You can also let the generated crate install the plugin's global allocator, by passing
+`PluginAllocator::Dlmalloc`, `PluginAllocator::WeeAlloc` or `PluginAllocator::Talc` to
+`RustPluginConfig::with_allocator()`. Whichever allocator is used, `__fp_malloc` returns a null
+pointer when the runtime asks the plugin for more memory than it can allocate, so that the runtime
+can fail the call with an `InvocationError::AllocationFailed` in Rust, an `AllocationFailedError` in
+TypeScript or an `AllocationFailedException` in C#, instead of trapping.
+
+See the `example-plugin/` directory for an example of a plugin that uses bindings generated from
+our `example-protocol/` (do note this plugin only builds after you've run `cargo run` inside the
+`example-protocol/` directory).
+
+### Using the Rust Wasmer runtime bindings
+
+The generator for our Rust Wasmer runtime works a bit differently. Instead of generating a crate,
+it generates two files: `bindings.rs` and `types.rs`. These can be placed in a module of your
+choosing (we chose a module named `spec` in the `example-rust-runtime/`).
+
+As the implementor of the runtime, it is then your responsibility to implement the `fp_import!`
+functions within the same module as you've placed the generated files. You can see an example of
+this in `example-rust-runtime/spec/mod.rs` (do note the example runtime only builds after you've run
+`cargo run` inside the `example-protocol/` directory).
+
+Finally, the `bindings.rs` file contains a constructor (`Runtime::new()`) that you can use to
+instantiate Wasmer runtimes with the Wasm module provided as a blob. The `fp_export!` functions are
+provided on the `Runtime` instance as methods. Please be aware that implementation of the
+`fp_export!` functions is always at the discretion of the plugin, and an attempt to invoke a missing
+implementation fails with a `PluginError::MissingExport` error.
+
+The `PluginError` is generated for your protocol, and tells you which export failed through a
+`PluginExport` enum with a variant for every export. Besides missing exports, it distinguishes
+exports with a signature that doesn't match the protocol, traps and reported failures (such as
+panics) of the plugin, and return values that cannot be deserialized, which include the name of the
+expected type. Other errors of the runtime, such as an `InvocationError::PermissionDenied`, are
+wrapped in `PluginError::Transport`. The `_raw` variants of the exports still return an
+`InvocationError`, into which a `PluginError` can be converted with `?`. The TypeScript runtime
+throws a `PluginError` with a `kind` and an `exportName` that are typed for your protocol as well.
+
+Exports that are marked with `#[fp(lazy)]` return a `Lazy<T>` instead, which holds the serialized
+return value and only deserializes it when `Lazy::get()` is first called. Hosts that forward most
+responses unchanged can pass on the MessagePack-encoded bytes from `Lazy::raw_bytes()` without
+paying for deserialization:
+
+```ignore
+fp_bindgen::prelude::fp_export! {
+    #[fp(lazy)]
+    fn render_report(query: ReportQuery) -> Report;
+}
+```
+
+Lazy exports must return a non-primitive type. Plugins and the other runtimes are not affected by
+the attribute.
+
+Exports that are called repeatedly with the same arguments can have their results memoized by the
+host, using `#[fp(cache)]`. The `ttl` determines how long a result is reused, while the `key`
+determines which arguments it is keyed by: `"args"` keys results by all arguments, or you can list
+the names of the relevant arguments, separated by commas:
+
+```ignore
+fp_bindgen::prelude::fp_export! {
+    #[fp(cache(ttl = "30s", key = "args"))]
+    fn search_series(query: SeriesQuery) -> Vec<Series>;
+}
+```
+
+The Rust Wasmer runtime and the TypeScript runtime only call the plugin if there is no result for
+the same serialized arguments yet, or if it has expired. Only successful results are memoized. The
+Rust Wasmer runtime stores the serialized results, which are shared with clones of the runtime, and
+discards them when `Runtime::clear_export_cache()` is called. The TypeScript runtime stores the
+results themselves, so callers should not modify them, and discards them when `clearExportCache()`
+is called. Durations are given in `ms`, `s`, `m` or `h`, and cached exports must return a
+non-primitive type.
+
+If instantiation is too costly to perform on the hot path, you can use
+`BindingsType::RustWasmerRuntimeWithExtendedConfig` together with
+`RustWasmerExtendedRuntimeConfig::new().with_runtime_pool()`. This additionally generates a
+`RuntimePool`, which compiles the module once and pre-instantiates a number of runtimes using
+`RuntimePool::with_capacity(wasm_module, n)`. Runtimes are checked out using `RuntimePool::get()`
+and are returned to the pool when they are dropped:
+
+```ignore
+let pool = RuntimePool::with_capacity(wasm_module, 4)?;
+let result = pool.get()?.my_exported_function(a, b)?;
+```
+
+The pool is a list of ready-to-use runtimes behind a mutex. It does not change how Wasmer allocates
+instances, since Wasmer 2 has no pooling instance allocator, so it only moves instantiation off the
+hot path. If all runtimes are checked out, `get()` instantiates a new one on demand, which is
+discarded when it is dropped if the pool is already full.
+
+A plugin instance can only run one call at a time, so a `Runtime` should not be called from
+multiple threads at once. For hosts that do, `with_shared_runtime()` generates a `SharedRuntime`,
+which is `Send + Sync` and holds a fixed number of instances. It has the same export methods as
+the `Runtime`, each of which runs on an instance that is not in use. If all instances are busy,
+regular exports block the calling thread until one is available, while async exports wait for one
+without blocking the thread:
+
+```ignore
+let runtime = SharedRuntime::new(wasm_module, 4)?;
+std::thread::spawn({
+    let runtime = runtime.clone();
+    move || runtime.my_exported_function(a, b)
+});
+```
+
+Keep in mind that the instances don't share their memory, so state that a plugin keeps between
+calls is kept per instance.
+
+`Runtime::new()` compiles the module using Singlepass (or Cranelift on ARM). If you want to use
+another compiler, or want to avoid compiling the module on startup, you can use the
+`RuntimeBuilder` instead. It accepts a custom `Store`, and can instantiate runtimes from an
+already compiled `Module` or from a module that was precompiled using `Module::serialize()`:
+
+```ignore
+let engine = wasmer::Universal::headless().engine();
+let runtime = unsafe {
+    RuntimeBuilder::new()
+        .with_store(wasmer::Store::new(&engine))
+        .build_from_serialized(&precompiled_module)?
+};
+```
+
+The WebAssembly features that plugins may use can be controlled using
+`RuntimeBuilder::with_wasm_features()`, which takes the `WasmFeatures` from
+`fp_bindgen_support::common::wasm_features`. By default, SIMD, bulk memory and reference types are
+enabled, while threads are not. Plugins that use a disabled feature are rejected with
+`RuntimeError::DisabledWasmFeatures` before they are compiled, which lists the features that the
+plugin uses:
+
+```ignore
+let runtime = RuntimeBuilder::new()
+    .with_wasm_features(WasmFeatures { simd: false, ..Default::default() })
+    .build(wasm_module)?;
+```
+
+Plugins should be built with the matching target features. Pass the features that your runtimes
+enable to `RustPluginConfig::with_wasm_features()`, and the generated crate lists the flags to pass
+as `-C target-feature` in its documentation, and refuses to compile plugins that are built with the
+target feature of a disabled WebAssembly feature. The same builder method is available for the Wasmi
+runtime, although Wasmi supports neither SIMD nor threads.
+
+Large plugins can take seconds to compile. To only pay this cost the first time a plugin is loaded,
+you can enable `RustWasmerExtendedRuntimeConfig::new().with_module_cache()`, which requires the
+`module-cache` feature of `fp-bindgen-support`. A `ModuleCache` stores compiled modules in a
+directory of your choice, keyed by a hash of the module, the Wasmer version and the target, so
+stale artifacts are never loaded. Runtimes are created from the cache using
+`Runtime::with_module_cache()`, or `RuntimeBuilder::build_with_cache()` for custom settings:
+
+```ignore
+let cache = ModuleCache::new(cache_dir)?;
+let runtime = Runtime::with_module_cache(wasm_module, &cache)?;
+```
+
+Async exports are executed by a single-threaded executor inside the plugin, which degrades badly
+when the host fires hundreds of concurrent calls. With
+`RustWasmerExtendedRuntimeConfig::new().with_async_concurrency_limit()`, you can limit the number of
+async invocations that run on a runtime at the same time. Invocations that exceed the limit wait for
+their turn in the order in which they were made, and once the queue is full as well, they fail with
+`InvocationError::Overloaded`:
+
+```ignore
+// At most 8 running invocations, and at most 64 waiting for their turn:
+let runtime = Runtime::new(wasm_module)?.with_async_concurrency_limit(8, 64);
+```
+
+### Using the Rust Wasmi runtime bindings
+
+For hosts that cannot use Wasmer, for instance because they run on a platform that doesn't allow
+JIT compilation or because they want to avoid native dependencies, there is also a runtime that is
+based on the [Wasmi](https://github.com/wasmi-labs/wasmi) interpreter. It generates a `bindings.rs`
+and a `types.rs` just like the Wasmer runtime, and the `Runtime` it generates provides the same
+methods. It requires the `wasmi-host` and `async` features of `fp-bindgen-support`, instead of the
+`host` feature.
+
+The Wasmi runtime doesn't depend on Tokio or threads. Instead of spawning the futures returned by
+async imports, the runtime drives them while you await an async export, so a runtime can only be used
+from a single thread. Async imports that don't return a value are not awaited by the plugin, so they
+may outlive the export that called them. `Runtime::run_pending_imports()` drives those to
+completion.
+
+There is no `RuntimePool` for the Wasmi runtime, since Wasmi instances are cheap to create. The
+`RuntimeBuilder` accepts a custom `wasmi::Engine` instead of a store, which you can use to change
+the configuration of the interpreter, such as the WebAssembly proposals that are enabled:
+
+```ignore
+let mut config = wasmi::Config::default();
+config.wasm_tail_call(true);
+let runtime = RuntimeBuilder::new()
+    .with_engine(wasmi::Engine::new(&config))
+    .build(wasm_module)?;
+```
+
+### Using the TypeScript runtime bindings
+
+The TypeScript runtime generator can work with browsers, Node.js and Deno.
+
+It works similarly to that for the Wasmer runtime, but it generates an `index.ts` and a `types.ts`.
+`types.ts` contains the type definitions for all the data structures, while the `index.ts` exports a
+`createRuntime()` function that you can use for instantiating the runtime. Upon instantiation, you
+are expected to provide implementations for all the `fp_import!` functions, while the returned
+`Promise` will give you an object with all the `fp_export!` functions the provided plugin has
+implemented.
+
+The runtime itself is split across a few more modules that are re-exported by `index.ts`:
+`memory.ts` contains the helpers for exchanging values with the plugin, `imports.ts` wraps the
+`fp_import!` functions, and `exports.ts` contains a standalone function for every `fp_export!`
+function. None of these modules have side effects when they are loaded, so if you instantiate the
+plugin using `instantiatePlugin()` and only call the export functions you need, bundlers can drop
+the rest:
+
+```ignore
+import { instantiatePlugin, myExportedFunction } from "./bindings/index.js";
+
+const plugin = await instantiatePlugin(wasm, importFunctions);
+const result = myExportedFunction(plugin, arg);
+```
+
+Before the plugin is instantiated, its import section is compared against the imports that the
+runtime provides. If the plugin imports functions that are missing, such as the imports of a
+feature that were not passed to `instantiatePlugin()`, or whose signature doesn't match the
+protocol, an `InstantiationError` is thrown instead of an opaque `LinkError`. Its `diagnostics`
+list the expected and provided signature of every such import.
+
+If you want to use the runtime without a TypeScript build step, for instance from a plain Node.js
+script or from a browser, you can enable `TsExtendedRuntimeConfig::with_js_output()`. Instead of
+the `.ts` files, this generates a `.js` file for every module, together with `index.d.ts` and
+`types.d.ts` declaration files.
+
+The generated modules are ES modules that import each other without extensions, which works with
+bundlers. Use `TsExtendedRuntimeConfig::with_module_format()` to target other setups:
+`TsModuleFormat::Esm` imports modules with `.js` extensions, as Node.js and TypeScript's `nodenext`
+module resolution require. `TsModuleFormat::CommonJs` generates `.cjs` modules that use
+`require()`, with `.d.cts` declarations. `TsModuleFormat::Dual` generates both the ES and the
+CommonJS modules, together with a `package.json` of which the `exports` map points `import` and
+`require()` to them, so the bindings can be published as a package for both. The latter two imply
+JavaScript output, and the worker wrappers are only available as ES modules.
+
+By default, enums are represented as a union of string literals in TypeScript. If you prefer
+TypeScript string enums, for instance because you want to iterate over their values at runtime, you
+can enable `TsExtendedRuntimeConfig::with_string_enums()`. This only applies to enums that consist
+solely of unit variants and that are not tagged or untagged.
+
+For CPU-heavy plugins running in Node.js, you can enable
+`TsExtendedRuntimeConfig::with_worker_wrapper()`. This generates a `worker.ts` with a
+`createWorkerRuntime()` function, which runs the plugin inside a `worker_threads` Worker and
+returns asynchronous proxies for its exports, so that plugin calls don't block the event loop.
+Because the import functions are called from inside the worker, they are passed as the URL of a
+module that provides them as its default export:
+
+```ignore
+const runtime = await createWorkerRuntime(plugin, new URL("./imports.js", import.meta.url));
+const result = await runtime.myExportedFunction?.(arg);
+```
+
+To keep heavy plugin calls off the UI thread in browsers, you can enable
+`TsExtendedRuntimeConfig::with_browser_worker_wrapper()` instead. This generates a
+`worker-runtime.ts`, whose `exposeRuntime()` function is called from the script of a dedicated Web
+Worker with the import functions, and a `worker-client.ts`, whose `createWorkerClient()` function
+instantiates the plugin inside that worker and returns asynchronous proxies for its exports:
+
+```ignore
+// worker.ts
+exposeRuntime(importFunctions);
+
+// main.ts
+const worker = new Worker(new URL("./worker.ts", import.meta.url), { type: "module" });
+const client = await createWorkerClient(worker, plugin);
+const result = await client.myExportedFunction?.(arg);
+client.terminate();
+```
+
+Many browser APIs, such as `fetch()` and IndexedDB, are asynchronous, while the protocol may declare
+the imports that use them as synchronous. With `TsExtendedRuntimeConfig::with_async_imports()`,
+which also enables the browser worker wrapper, such imports can be passed to `createWorkerClient()`
+as async functions instead. They run on the main thread, while the worker blocks on
+`Atomics.wait()` until they settle, so the plugin can call them as usual. This relies on
+`SharedArrayBuffer`, which requires the page to be
+[cross-origin isolated](https://developer.mozilla.org/en-US/docs/Web/API/Window/crossOriginIsolated):
+
+```ignore
+const client = await createWorkerClient(worker, plugin, [], {
+    loadSettings: async (key) => await settingsStore.get(key),
+});
+```
+
+Plugins that are compiled with Wasm threads, for instance to process data in parallel, import their
+memory rather than exporting it. With `TsExtendedRuntimeConfig::with_shared_memory()`, the runtime
+creates a `WebAssembly.Memory` with `shared: true` and the limits that the plugin declares, and
+passes it to the plugin as `env.memory`. A memory of your own can be passed as the `memory` option
+of `createRuntime()` or `instantiatePlugin()` instead, such as one that is shared with the workers
+that run the threads of the plugin. Whenever the runtime resolves an async value, it calls
+`Atomics.notify()` on it, so plugin threads that are built with the `threads` feature of
+`fp-bindgen-support` can block on the value using `wait_for_async_value()`, without an executor.
+This too requires `SharedArrayBuffer` to be available.
+
+If your plugin uses custom MessagePack extension types, or exchanges values that exceed the default
+limits of the decoder, you can pass the options of the encoder and decoder as the last argument of
+`createRuntime()` or `instantiatePlugin()`, after the capabilities and feature imports if the
+protocol has any. The same options can be passed to `exposeRuntime()`,
+while `createWorkerClient()` accepts them after the async imports, for encoding their results:
+
+```ignore
+const extensionCodec = new ExtensionCodec();
+extensionCodec.register({ type: 0, encode: encodeDecimal, decode: decodeDecimal });
+
+const runtime = await createRuntime(plugin, imports, { msgpack: { extensionCodec } });
+```
 
-**Example:**
+By default, the names of functions and their arguments are converted to camelCase. If this mangles
+some of your names, you can use `TsExtendedRuntimeConfig::with_identifier_casing()` to either
+preserve names as they are declared in the protocol using `TsIdentifierCasing::Preserve`, or to
+provide your own identifiers for specific names using `TsIdentifierCasing::Custom`:
 
 ```ignore
-#[derive(fp_bindgen::prelude::Serializable)]
-pub struct MyStruct {
-    pub foo: i32,
-    pub bar: String,
+let casing = TsIdentifierCasing::Custom(BTreeMap::from([(
+    "get_ipv4_addr".to_owned(),
+    "getIPv4Addr".to_owned(),
+)]));
+let config = TsExtendedRuntimeConfig::new().with_identifier_casing(casing);
+```
+
+64-bit integers that are passed as plain arguments or return values are typed as `bigint` by
+default, which `JSON.stringify()` refuses to serialize. If your host passes such values on to JSON
+APIs, you can use `TsExtendedRuntimeConfig::with_int64_encoding()` to type them as `number` using
+`TsInt64Encoding::Number`, or as decimal strings using `TsInt64Encoding::String`. Either way, the
+runtime throws a `RangeError` rather than truncating values: numbers must be safe integers, strings
+must contain an integer within the range of the type, and values that the plugin returns must fit
+the chosen representation. This also enables a check on all the data that is received from the
+plugin, which reports 64-bit integers beyond `Number.MAX_SAFE_INTEGER` inside data types as a
+`DeserializationError`, rather than letting MessagePack silently round them.
+
+### Using the C# runtime bindings
+
+The C# runtime generator emits a project file together with a `Types.cs` and a `Runtime.cs`, which
+depend on the [Wasmtime](https://www.nuget.org/packages/Wasmtime) and
+[MessagePack](https://www.nuget.org/packages/MessagePack) NuGet packages. The namespace, which is
+also the name of the project, and the target framework can be set through `CSharpRuntimeConfig`.
+
+`Types.cs` contains a record for every struct. Enums that only consist of unit variants become C#
+enums, while other enums become an abstract record with a nested record for every variant. All
+types come with MessagePack formatters that follow the Serde representation of the Rust types.
+
+`Runtime.cs` contains an `IImports` interface that you are expected to implement with all the
+`fp_import!` functions, and a `Runtime` class that exposes the `fp_export!` functions. Async
+functions return a `Task`:
+
+```ignore
+using var runtime = new Runtime(File.ReadAllBytes("plugin.wasm"), new MyImports());
+var result = await runtime.MyAsyncExportedFunction(arg);
+```
+
+Calls into the plugin are serialized, so a runtime may be shared between threads. Exports that the
+plugin doesn't implement throw an `FpRuntimeException` when they are called.
+
+### Writing plugins in AssemblyScript
+
+Plugins can also be written in [AssemblyScript](https://www.assemblyscript.org), using the bindings
+that are generated by `BindingsType::AssemblyScriptPlugin`. These depend on
+[`@wapc/as-msgpack`](https://www.npmjs.com/package/@wapc/as-msgpack) for MessagePack, which can be
+replaced by a module with the same API through `AssemblyScriptPluginConfig::with_msgpack_module()`.
+
+The bindings consist of a `types.ts` with a class for every struct and enum, where every variant of
+an enum is a subclass of it, an `imports.ts` with a function for every import, and an `exports.ts`
+with an `Exports` class that you extend to implement the exports. The entry file of the plugin
+registers the implementation and re-exports `index.ts`, which contains the functions that the
+runtime calls, including `__fp_malloc()` and `__fp_free()`:
+
+```ignore
+import { Exports, registerExports } from "./bindings/exports";
+import { importString } from "./bindings/imports";
+
+class Plugin extends Exports {
+    exportString(arg: string): string {
+        return importString(arg);
+    }
 }
 
-fp_bindgen::prelude::fp_import! {
-    fn my_function(data: MyStruct) -> MyStruct;
+registerExports(new Plugin());
+
+export * from "./bindings";
+```
+
+Plugins are compiled with `--use abort=<path to the bindings>/support/abort`, so that aborts are
+reported to the runtime as panics, rather than imported from an `env` module that the runtimes
+don't provide.
+
+AssemblyScript has no tuples and no unions other than nullable references, so functions that use
+tuples, untagged enums, custom types or generic imports are left out of the bindings, and listed in
+a comment instead. Optional primitives are wrapped in a `Some` class, since they cannot be `null`.
+Async exports are implemented synchronously, while async imports are only supported if they don't
+return a value.
+
+### Memory statistics
+
+Plugins that are built with the `memory-stats` feature of `fp-bindgen-support` keep track of the
+memory they allocate. Runtimes can retrieve these statistics (the number of bytes currently
+allocated, the peak number of allocated bytes, and the total number of allocations) using
+`Runtime::memory_stats()` in the Rust Wasmer runtime, or `memoryStats()` in the TypeScript runtime.
+
+Note that this feature installs a global allocator in the plugin, so it cannot be combined with a
+custom global allocator.
+
+### Guest metrics
+
+To find out where a plugin spends its time, call `with_guest_metrics()` on the `RustPluginConfig`.
+The generated import functions then count and time every call the plugin makes to them, including
+the time spent (de)serializing arguments and return values. Calls that are queued in a batch are not
+counted. Runtimes can retrieve the metrics, by import name, using `Runtime::guest_metrics()` in the
+Rust runtimes, or `guestMetrics()` in the TypeScript runtime. Plugins can read them directly using
+`fp_bindgen_support::guest::metrics::guest_metrics()`, and clear them using `reset_guest_metrics()`.
+
+Calls are timed using the monotonic clock of the [std imports](#std-imports), so the runtime needs
+to provide these imports and grant the `clock` capability.
+
+### Protocol reflection
+
+Both the Rust Wasmer runtimes and the TypeScript runtime embed a description of the protocol they
+were generated for, with the signatures of all imported and exported functions and the types they
+use. This allows generic tooling, such as debug UIs, request builders or fuzzers, to enumerate what
+a plugin supports without being recompiled. `Runtime::protocol()` returns the description as JSON,
+in the same format as the snapshots of `fp_bindgen::Protocol`, so it can be parsed using
+`Protocol::from_json()`. The TypeScript runtime exports a `protocol()` function that returns the
+parsed description.
+
+Plugins may omit exports, so the description lists the exports a plugin _may_ implement.
+
+### Trace context propagation
+
+If your runtime uses OpenTelemetry through `tracing-opentelemetry`, you can generate Rust Wasmer
+runtime bindings using `RustWasmerExtendedRuntimeConfig::new().with_opentelemetry()`, which requires
+the `opentelemetry` feature of `fp-bindgen-support`. Every call to an export is then wrapped in an
+`fp_bindgen.export` span, and calls from the plugin to imports are handled in a child
+`fp_bindgen.import` span, so the trace continues into your import implementations as well as any
+tasks spawned for async imports.
+
+Rather than adding an argument to every function, the runtime provides the trace context through
+a reserved import, so the ABI of the protocol remains unchanged. Plugins that are built with the
+`trace-context` feature of `fp-bindgen-support` can retrieve it using
+`fp_bindgen_support::guest::trace_context::current_trace_context()`, which returns the headers
+injected by the globally configured propagator (such as `traceparent`). Such plugins can only be
+loaded by runtimes that were generated with OpenTelemetry integration.
+
+### Std imports
+
+Rather than having every protocol define imports for common host facilities, runtimes can provide
+a small standard library of them. Plugins that are built with the `std-imports` feature of
+`fp-bindgen-support` can use `read_file()` and `write_file()`, `now()` and `fp_now()`, and
+`random_bytes()` from `fp_bindgen_support::guest::std_imports`, which require the `fs`, `clock` and
+`random` capabilities respectively. These capabilities are added to the `Capability` type of the
+runtime, next to the ones of the protocol.
+
+For the Rust Wasmer runtime, enable the imports using
+`RustWasmerExtendedRuntimeConfig::new().with_std_imports()`, which requires the `std-imports-host`
+feature of `fp-bindgen-support`. The files, clock and random number generator that are exposed to
+the plugin can be virtualized by passing a `StdImports` to `RuntimeBuilder::with_std_imports()`:
+
+```ignore
+let std_imports = StdImports::new()
+    .with_root("/var/lib/my-app/plugin-data")
+    .with_clock(|| SystemTime::UNIX_EPOCH);
+let runtime = RuntimeBuilder::new()
+    .with_capabilities(&[Capability::Clock, Capability::Fs])
+    .with_std_imports(std_imports)
+    .build(wasm_module)?;
+```
+
+Plugins cannot access any files unless a root directory is given, and paths are always relative to
+it. For the TypeScript runtime, enable the imports using
+`TsExtendedRuntimeConfig::with_std_imports()`, after which the files (as a `Map`), clock and random
+number generator can be passed through the optional `std` member of the imports.
+
+Since `std::time::Instant` is not available on `wasm32-unknown-unknown`, the std imports also
+include a monotonic clock. Plugins can call `fp_now()` to get an `Instant` of the runtime's
+monotonic clock, which supports the same arithmetic as the one in `std`, such as `elapsed()` and
+`duration_since()`. Like `now()`, it requires the `clock` capability. Hosts can virtualize the
+monotonic clock using `StdImports::with_monotonic_clock()` in the Rust Wasmer runtime, or the
+`monotonicNow` member of the `std` imports in the TypeScript runtime:
+
+```ignore
+use fp_bindgen_support::guest::std_imports::fp_now;
+
+let start = fp_now();
+expensive_computation();
+let elapsed = start.elapsed();
+```
+
+### HTTP imports
+
+Many protocols need to let plugins make HTTP requests, and would otherwise each define their own
+request and response types for it. Instead, runtimes can provide a built-in `http_request()`
+import. Plugins that are built with the `http-imports` feature of `fp-bindgen-support` can call it
+from `fp_bindgen_support::guest::http_imports`, which requires the `http` capability:
+
+```ignore
+use fp_bindgen_support::guest::http_imports::{http_request, HttpRequest};
+
+let request = HttpRequest::get("https://example.com/api/status")
+    .with_header("Accept", "application/json");
+let response = http_request(&request).await?;
+if response.is_success() {
+    let status: Status = serde_json::from_slice(&response.body)?;
 }
 ```
 
-Note that `Serializable` is implemented by default for some common standard types, such as
-`Option`, `Vec`, and other container types.
+Responses with an error status are returned as an `HttpResponse` nonetheless, while an `HttpError`
+is returned if no response was received at all.
 
-### Async functions
+For the Rust Wasmer runtime, enable the import using
+`RustWasmerExtendedRuntimeConfig::new().with_http_imports()`, which requires the
+`http-imports-host` feature of `fp-bindgen-support`. Requests are sent using a shared
+`reqwest::Client` on the current Tokio runtime. Hosts can pass their own client, or a handler that
+answers the requests itself, through `RuntimeBuilder::with_http_imports()`:
 
-Functions can also be `async`, which works as you would expect:
+```ignore
+let http_imports = HttpImports::new().with_handler(|request: HttpRequest| async move {
+    if !request.url.starts_with("https://api.example.com/") {
+        return Err(HttpError::InvalidRequest("URL is not allowed".to_owned()));
+    }
+    send_request(request).await
+});
+let runtime = RuntimeBuilder::new()
+    .with_capabilities(&[Capability::Http])
+    .with_http_imports(http_imports)
+    .build(wasm_module)?;
+```
 
-**Example:**
+For the TypeScript runtime, enable the import using `TsExtendedRuntimeConfig::with_http_imports()`.
+Requests are sent using the global `fetch()`, unless another implementation is passed through the
+optional `http` member of the imports.
+
+### Routing imports between plugins
+
+Hosts that load multiple plugins implementing the same protocol can let one plugin call the
+exports of another, without the host handling the calls itself. Generate the Rust Wasmer runtime
+using `RustWasmerExtendedRuntimeConfig::new().with_import_routes()`, after which an import can be
+routed to the export of the same name of another runtime:
 
 ```ignore
-fp_bindgen::prelude::fp_import! {
-    async fn my_async_function(data: MyStruct) -> Result<MyStruct, MyError>;
+let formatter = Runtime::new(formatter_module)?;
+let plugin = Runtime::new(plugin_module)?;
+plugin.route_import("format", formatter)?;
+```
+
+Routing fails if the other runtime has no such export, or if its signature differs from that of
+the import. Arguments and results are passed between the plugins in their serialized form, so they
+are never deserialized by the host. Only synchronous imports that are not dynamic, batchable or
+generic can be routed. If the export fails, the call to the import fails with
+`InvocationError::RoutedImportFailed`. Calls are handled by the host again after
+`unroute_import()`. The target can be any `RawExports` implementation, which generated runtimes
+implement for their synchronous exports.
+
+### Sharing state between imports
+
+Imports that need access to host state, such as a database pool, would otherwise have to reach for
+global variables. Instead, the Rust Wasmer runtime can be generated with a context type using
+`RustWasmerExtendedRuntimeConfig::new().with_host_context("crate::HostContext")`, where the path is
+resolved from the module of the generated bindings. The host's implementations of the imports then
+receive a reference to the context as their first argument:
+
+```ignore
+pub struct HostContext {
+    pub db: DatabasePool,
+}
+
+fn get_user(context: &HostContext, id: u32) -> User {
+    context.db.get_user(id)
 }
 ```
 
-### Using existing Rust types
+The context is attached when the runtime is built, using `RuntimeBuilder::with_context()`, which
+accepts either the context or an `Arc` of it, so that it can be shared with other runtimes. The
+context type needs to be `Send + Sync`. Async imports receive an `Arc<HostContext>` instead of a
+reference, since their futures may outlive the call. Calls to imports panic if no context was
+attached, so such runtimes always need to be created through the `RuntimeBuilder`.
 
-Sometimes you may wish to use Rust types for your protocol that you also want to use directly in the
-generated runtime or plugin implementation. In such a case, generation of the data types might force
-you to perform unnecessary copies, so we allow explicit annotations to import the existing
-definition instead of generating a new one:
+### Overriding imports per runtime
 
-**Example:**
+Hosts that run the plugins of multiple tenants may need the imports to behave differently for each
+of them, for instance to use the credentials of the tenant. If the Rust Wasmer runtime is generated
+using `RustWasmerExtendedRuntimeConfig::new().with_import_overrides()`, the `RuntimeBuilder` gets
+an `override_*()` method for every import, which replaces the host's implementation of the import
+with a closure for the runtime that is built:
 
+```ignore
+let runtime = RuntimeBuilder::new()
+    .override_fetch_data(move |url| fetch_with_token(&tenant.token, url))
+    .build(wasm_module)?;
 ```
-use fp_bindgen::prelude::Serializable;
 
+Imports that are not overridden are still handled by the host's implementations. Overrides take
+the same arguments as the host's implementations, including the context if the runtime has one,
+and overrides of async imports return a future. Dynamic imports, events and generic imports cannot
+be overridden, and routed imports are routed regardless of their overrides.
+
+### Verifying plugin signatures
+
+Hosts that load plugins from untrusted locations can require them to be signed. Build pipelines
+sign a plugin using `sign_module()` from the `signatures` feature of `fp-bindgen-support`, which
+appends a custom `fp-signature` section with the Ed25519 signature of the module:
+
+```ignore
+use fp_bindgen_support::common::signatures::{sign_module, SigningKey};
+
+let signed_module = sign_module(&wasm_module, &SigningKey::from_bytes(&secret_key))?;
+```
+
+Runtimes that don't verify signatures ignore the section. If the Rust Wasmer runtime is generated
+using `RustWasmerExtendedRuntimeConfig::new().with_signature_verification()`, runtimes can be
+created using `Runtime::with_signature_verifier()` or `RuntimeBuilder::with_signature_verifier()`,
+which reject plugins that are unsigned or whose signature is invalid with
+`RuntimeError::InvalidSignature` before they are compiled. A `SignatureVerifier` is created from
+an Ed25519 public key, or from a callback for other signature schemes:
+
+```ignore
+let verifier = SignatureVerifier::ed25519(&public_key)?;
+let runtime = Runtime::with_signature_verifier(wasm_module, &verifier)?;
+```
+
+The TypeScript runtime does the same if it is generated using
+`TsExtendedRuntimeConfig::new().with_signature_verification()`, in which case the options of
+`createRuntime()` accept a `signatureVerifier`, which is either the raw public key as a
+`Uint8Array` or a (possibly async) callback. Plugins are rejected with an `InvalidSignatureError`.
+Public keys are verified using the Web Crypto API, which needs to support Ed25519.
+
+### Tracing payloads
+
+Protocol mismatches between a runtime and a plugin that were built at different times are easiest
+to debug by looking at the payloads they exchange. If the Rust Wasmer runtime is generated using
+`RustWasmerExtendedRuntimeConfig::new().with_payload_tracing()`, every serialized argument and
+result is passed to the `PayloadTracer` of the runtime, which logs the function, the direction and
+the payload in hexadecimal, Base64 or as a decoded MessagePack value. Tracers require the
+`payload-tracing` feature of `fp-bindgen-support`, and may redact payloads that should not end up
+in logs:
+
+```ignore
+let runtime = RuntimeBuilder::new()
+    .with_payload_tracer(
+        PayloadTracer::new(PayloadFormat::Decoded)
+            .with_redacted_function("login")
+            .with_logger(|line| log::debug!("{line}")),
+    )
+    .build(wasm_module)?;
+```
+
+Runtimes without a tracer of their own log payloads to stderr if the `FP_BINDGEN_TRACE_PAYLOADS`
+environment variable is set to `hex`, `base64` or `decoded`, so tracing can be enabled in the field
+without rebuilding the host. Payloads of dynamic imports, events and batched imports are not
+traced.
+
+The TypeScript runtime does the same if it is generated using
+`TsExtendedRuntimeConfig::new().with_payload_tracing()`, in which case the options of
+`createRuntime()` accept `tracePayloads`, which is either `true` or an object with the `format`, a
+`redact` callback and a `log` callback. If the option is omitted, the environment variable is used
+where it is available, such as in Node.js.
+
+### Resolving exports at load time
+
+By default, the runtimes look up the exports of a plugin when they are called, so a plugin that was
+built for another version of the protocol only fails once the export is called. If the Rust Wasmer
+runtime is generated using `RustWasmerExtendedRuntimeConfig::new().with_eager_export_resolution()`,
+all exports are resolved and checked against the protocol when the runtime is created, which fails
+with `RuntimeError::ExportSignatureMismatch` if the plugin exports a function with another
+signature. Calls use the resolved exports, and the exports that the plugin omits can be logged at
+load time:
+
+```ignore
+let runtime = Runtime::new(wasm_module)?;
+for export in runtime.missing_exports() {
+    log::info!("Plugin does not implement `{export}`");
+}
+```
+
+The TypeScript runtime does the same if it is generated using
+`TsExtendedRuntimeConfig::new().with_eager_export_resolution()`, in which case `createRuntime()`
+rejects plugins with mismatched exports with a `SignatureMismatchError`, and the returned exports
+have a `missingExports()` function.
+
+### Capturing plugin output
+
+Plugins that are compiled for `wasm32-unknown-unknown` have no stdout or stderr, so anything they
+print using `std::println!()` silently disappears. Plugins that are built with the `stdio` feature
+of `fp-bindgen-support` can import its `println!()` and `eprintln!()` macros instead, which pass
+every line to the runtime:
+
+```ignore
+use fp_bindgen_support::{eprintln, println};
+```
+
+For the Rust Wasmer runtime, generate the import that receives the lines using
+`RustWasmerExtendedRuntimeConfig::new().with_stdio_capture()`. The lines are printed to the stdout
+and stderr of the host, unless a handler is passed to `RuntimeBuilder::with_output_handler()`,
+which lets hosts route them to their logs:
+
+```ignore
+let runtime = RuntimeBuilder::new()
+    .with_output_handler(|stream, line| match stream {
+        OutputStream::Stdout => log::info!("plugin: {line}"),
+        OutputStream::Stderr => log::warn!("plugin: {line}"),
+    })
+    .build(wasm_module)?;
+```
+
+For the TypeScript runtime, use `TsExtendedRuntimeConfig::with_stdio_capture()`, after which the
+lines are passed to the optional `onPrint` member of the imports, or are logged to the console if
+there is none. Plugins that are loaded by the Rust Wasmer WASI runtime don't need any of this,
+since their stdout and stderr are inherited from the host already.
+
+### Propagating deadlines
+
+Hosts that stop waiting for a call after some time can let the plugin know, so that it doesn't
+keep burning resources on a result that nobody is waiting for anymore. Generate the Rust Wasmer
+runtime using `RustWasmerExtendedRuntimeConfig::new().with_deadlines()`, after which a deadline can
+be attached to the future of a call using the `WithDeadline` trait of
+`fp_bindgen_support::host::deadline`:
+
+```ignore
+let deadline = SystemTime::now() + Duration::from_secs(5);
+let result = runtime.fetch_data("sushi".to_owned()).with_deadline(deadline).await;
+```
+
+Plugins that are built with the `deadline` feature of `fp-bindgen-support` retrieve the deadline
+of the call that they are handling using `fp_bindgen_support::guest::deadline::fp_deadline()`:
+
+```ignore
+if fp_deadline().map_or(false, |deadline| deadline.is_exceeded()) {
+    return Err(Error::Cancelled);
+}
+```
+
+Async exports keep the deadline of the call that started them, even while other calls are being
+handled. Calls from the plugin to imports inherit the deadline as well, so the host's
+implementations of the imports can retrieve it using `current_deadline()`, and it is passed on to
+any other plugin they call. Note that deadlines are purely informational: the host still needs to
+stop waiting for the call itself, for instance using `tokio::time::timeout()`. Plugins can be built
+with the `deadline` feature regardless of the runtime, but `Deadline::remaining()` and
+`Deadline::is_exceeded()` need the clock of a runtime that propagates deadlines.
+
+### Migrating payloads of old plugins
+
+Renaming a field or variant breaks plugins that were built against the previous version of the
+protocol, because they still serialize the old name. To keep loading such plugins, list the
+previous names using `#[fp(renamed_from = "...")]`:
+
+```ignore
 #[derive(Serializable)]
-#[fp(rust_module = "my_crate::prelude")]
-pub struct MyStruct {
-    pub foo: i32,
-    pub bar_qux: String,
+#[fp(rename_all = "camelCase")]
+pub struct User {
+    #[fp(renamed_from = "userName")]
+    pub display_name: String,
 }
 ```
 
-In this example, `MyStruct` has a double function: it acts both as a type definition for the
-protocol (through `fp-bindgen`'s `Serializable` trait), which can still be used for generating a
-TypeScript type definition, for instance. _And_ it acts as a type that can be directly used by the
-Rust Wasmer runtime, under the assumption the runtime can import it from `my_crate::prelude`.
+Generating the Rust Wasmer runtime using `RustWasmerExtendedRuntimeConfig::new().with_migrations()`
+then adds a `migrations.rs` file next to the bindings, which requires the `migrations` feature of
+`fp-bindgen-support`. For every type with renamed fields or variants, or that contains such a
+type, it contains a `migrate_*()` function that deserializes a MessagePack payload in which the old
+names are replaced by the current ones. Hosts can use these on the results of the raw versions of
+the exports:
 
-Please note that in this case, you do have a bigger responsibility to make sure the definition
-fulfills the requirements of the code generator, hence why Serde's trait derives and annotations
-have to be added manually here, in accordance with how the generator would otherwise generate them.
+```ignore
+let user = migrate_user(&runtime.get_user_raw(serialize_to_vec(&user_id))?)?;
+```
 
-For now, this feature is limited to the Rust generators through the
-`rust_module` annotation. For us, this makes sense given the
-protocol itself is specified using Rust syntax as well. If desired, we could extend this to the
-TypeScript generator as well, though that would imply an even bigger responsibility for the user to
-keep their TypeScript types in sync with the protocol.
+Fields that use their current name are left untouched, so the migrations can be applied to payloads
+of current plugins as well. Variants of untagged enums cannot be renamed this way, because their
+names are not serialized. Note that migrations only upgrade the payloads that plugins return;
+arguments that are passed to old plugins still use the current names.
 
-### Cargo features
+### Compressing payloads
 
-The `fp-bindgen` crate supports optional Cargo features for compatibility with some common types
-from the crate ecosystem:
+If your protocol passes large, compressible payloads, you can enable the `compression` feature of
+`fp-bindgen-support` in both your runtime and your plugin. Payloads are then compressed using LZ4
+once their size meets the threshold configured through
+`fp_bindgen_support::common::compression::set_compression_threshold()`. Compression is disabled
+until a threshold is set, and either side is able to decompress payloads regardless of its own
+threshold. The TypeScript runtime only supports decompressing payloads, which you can enable using
+`TsExtendedRuntimeConfig::with_compression()`.
 
-- `bytes-compat`: Enables compatibility with the `bytes::Bytes` type.
-- `http-compat`: Enables compatibility with various types from the `http` crate.
-- `rmpv-compat`: Enables compatibility with the `rmpv::Value` type.
-- `serde-bytes-compat`: Enables compatibility with the `serde_bytes::ByteBuf` type (the `Bytes` type
-  is a reference type, which `fp-bindgen` doesn't support in general).
-- `serde-json-compat`: Enables compatibility with `serde_json::Map` and `serde_json::Value` types.
-- `time-compat`: Enables compatibility with `time`'s `PrimitiveDateTime` and `OffsetDateTime` types.
+Compressed payloads declare their uncompressed size, so payloads that declare more than 64 MiB are
+rejected before anything is allocated for them. The Rust runtimes and plugins can change this limit
+using `fp_bindgen_support::common::compression::set_max_decompressed_size()`. If a plugin returns
+an invalid compressed payload, the call fails with `InvocationError::DecompressionFailed`. The
+Wasmer runtime also fails the call this way if the plugin passes such a payload to an import.
 
-## Generating bindings
+### Benchmarking the bindings
 
-To generate bindings based on your protocol, you first need to create a function that will generate
-them for you. Creating this function is easy, because its implementation can be created for you
-using the `fp_bindgen` macro:
+`BindingsType::Benches` generates benchmarks that measure the cost of serializing arguments,
+calling the plugin and deserializing return values for every function in the protocol. Strings,
+lists and maps in the sample payloads are sized according to
+`BenchesConfig::with_payload_sizes()`. Functions with types for which no sample can be generated,
+such as custom types, are skipped.
+
+It generates a `benches.rs`, which should be placed next to the `bindings.rs` of the Rust Wasmer
+runtime and which uses `criterion` (and `tokio` for async functions), and a `benches.ts`, which
+should be placed next to the `index.ts` of the TypeScript runtime and which uses `tinybench`:
 
 ```ignore
-let bindings_type = fp_bindgen::BindingsType::RustWasmerRuntime;
+fn bench(c: &mut Criterion) {
+    spec::benches::bench_functions(c, include_bytes!("plugin.wasm"));
+}
+```
 
-fp_bindgen::prelude::fp_bindgen!(fp_bindgen::BindingConfig {
-    bindings_type,
-    path: &format!("bindings/{}", bindings_type)
-});
+```ignore
+const bench = await createBenches(plugin, importFunctions);
+await bench.run();
+console.table(bench.table());
 ```
 
-Currently, we support the following binding types:
+### Fuzzing the bindings
 
-- `BindingsType::RustPlugin`: Generates bindings for a Rust plugin.
-- `BindingsType::RustWasmerRuntime`: Generates runtime bindings for use with Wasmer.
-- `BindingsType::TsRuntimeWithExtendedConfig`: Generates bindings for a TypeScript runtime.
+Runtimes deserialize whatever a plugin hands them, so the deserialization of the arguments of
+imported functions and the return values of exported functions is worth fuzzing.
+`BindingsType::Fuzz` generates a `cargo-fuzz` crate with a target for each of those types. The crate
+contains its own copy of the types of the Rust runtimes, so it doesn't depend on your host crate.
+Dependencies of custom types can be added using `FuzzConfig::with_dependency()`:
 
-Note that some binding types take an additional config argument.
+```sh
+cargo fuzz run validated_struct
+```
 
-## Using the bindings
+It also generates a `fuzz.ts`, which should be placed next to the `index.ts` of the TypeScript
+runtime. It runs every target with mutations of sample values and reports the inputs for which
+deserializing or validating them threw anything other than a decoding or validation error:
 
-How to use the generated bindings differs between the various types.
+```ignore
+const failures = fuzz(10_000);
+```
 
-### Using the Rust plugin bindings
+Note that compressed payloads are not fuzzed.
 
-The generator for our Rust plugin bindings generates a complete crate that allows to be linked
-against by plugins. The plugin can import all the functions from the `fp_import!` block from it,
-and call them like any other functions.
+### Checking the wire format
 
-In order to export the functions that are defined in the `fp_export!` block, it can use the exported
-`fp_export_impl` macro, like so:
+Every runtime and plugin needs to agree on how the types of the protocol are serialized.
+`BindingsType::Fixtures` writes the canonical MessagePack encoding of a representative value of
+every type to a `fixtures` directory, together with a `manifest.json` that lists the fixtures, as
+well as the types that were skipped and why. Generic types are covered by the fixtures of the types
+that use them, while types with custom serializers, custom types and validated types are skipped.
+
+It also generates a crate with a test for every fixture, which checks that the fixture deserializes
+and that serializing the result reproduces the exact same bytes. Like the fuzz crate, it contains
+its own copy of the types, and dependencies of custom types can be added using
+`FixturesConfig::with_dependency()`:
+
+```sh
+cargo test
+```
+
+Finally, it generates a `fixtures.ts`, which decodes the fixtures the same way the TypeScript runtime
+does and compares them with the values described by the TypeScript types. It doesn't read the files
+itself, so it works with both Node.js and Deno:
 
 ```ignore
-#[fp_bindgen_macros::fp_export_impl(bindings_crate_path)]
-fn my_exported_function(a: u32, b: u32) -> u32 {
-    /* ... */
+const failures = checkFixtures((file) => Deno.readFileSync(`fixtures/${file}`));
+```
+
+Running both in CI catches changes to the types or to either serializer that would break
+compatibility between the languages.
+
+### Detecting incompatible serialized values
+
+Every struct, enum and alias gets a hash of its wire format, which is the same in all generated
+bindings. The hash covers the serialized names of the fields and variants, whether fields are
+optional or flattened, the representation of enums and the wire format of the types they contain,
+but not the names of the types or their docs. In Rust, it is available as an associated
+`SCHEMA_HASH` constant, while TypeScript bindings export a `SCHEMA_HASHES` object with the hashes by
+type name.
+
+Hosts that store serialized values, for instance in a cache, can store the hash next to the value,
+and discard values with a different hash instead of failing to deserialize them:
+
+```ignore
+let hash = redis.get(format!("{key}:schema"))?;
+if hash.as_deref() == Some(Point::<f64>::SCHEMA_HASH) {
+    let point: Point<f64> = rmp_serde::from_slice(&redis.get(&key)?)?;
 }
 ```
 
-`bindings_crate_path` is expected to match with the module path from which the bindings crate
-itself is imported. The function signature must match exactly with one of the `fp_export!`
-functions.
+Hashes are calculated before members are excluded from specific generators, and changes that keep
+values compatible may still change the hash, so a different hash only means values *might* be
+incompatible.
 
-When compiling a plugin, don't forget to compile against the "wasm32-unknown-unknown" target, or you
-will receive linker errors.
+### Smoke-testing plugins from the command line
 
-See the `example-plugin/` directory for an example of a plugin that uses bindings generated from
-our `example-protocol/` (do note this plugin only builds after you've run `cargo run` inside the
-`example-protocol/` directory).
+`BindingsType::Cli` generates a crate with a binary that loads a plugin using the Rust Wasmer
+runtime, and that exposes every export as a subcommand. The arguments of the export are passed as
+JSON, and its result is printed as JSON. Imports are stubbed out, so exports that call them fail,
+but this lets plugin authors try their exports without writing a host. Dependencies of custom types
+can be added using `CliConfig::with_dependency()`:
 
-### Using the Rust Wasmer runtime bindings
+```sh
+cargo run -- plugin.wasm export_primitive_u32 42
+```
 
-The generator for our Rust Wasmer runtime works a bit differently. Instead of generating a crate,
-it generates two files: `bindings.rs` and `types.rs`. These can be placed in a module of your
-choosing (we chose a module named `spec` in the `example-rust-runtime/`).
+### Scaffolding a new plugin
 
-As the implementor of the runtime, it is then your responsibility to implement the `fp_import!`
-functions within the same module as you've placed the generated files. You can see an example of
-this in `example-rust-runtime/spec/mod.rs` (do note the example runtime only builds after you've run
-`cargo run` inside the `example-protocol/` directory).
+`BindingsType::Scaffold` generates a plugin crate that depends on the Rust plugin bindings, as a
+starting point for new plugins. Its `src/lib.rs` contains a stub for every export, which panics
+with `todo!()` until it is implemented, except for the `init` and `shutdown` lifecycle exports,
+which do nothing. The crate is configured to be built for WebAssembly with the target features of
+`ScaffoldConfig::with_wasm_features()`, and comes with a README that explains how to build it. The
+bindings crate is expected at `../bindings/rust-plugin` unless another location is set through
+`ScaffoldConfig::with_bindings()`:
 
-Finally, the `bindings.rs` file contains a constructor (`Runtime::new()`) that you can use to
-instantiate Wasmer runtimes with the Wasm module provided as a blob. The `fp_export!` functions are
-provided on the `Runtime` instance as methods. Please be aware that implementation of the
-`fp_export!` functions is always at the discretion of the plugin, and an attempt to invoke a missing
-implementation can fail with an `InvocationError::FunctionNotExported` error.
+```ignore
+let config = ScaffoldConfig::new()
+    .with_name("my-plugin")
+    .with_bindings("my-bindings", CargoDependency::with_path("../my-bindings"));
+```
 
-### Using the TypeScript runtime bindings
+Since the scaffold is meant to be edited, files that already exist are never overwritten.
 
-The TypeScript runtime generator can work with browsers, Node.js and Deno.
+### Sharing types with other services
 
-It works similarly to that for the Wasmer runtime, but it generates an `index.ts` and a `types.ts`.
-`types.ts` contains the type definitions for all the data structures, while the `index.ts` exports a
-`createRuntime()` function that you can use for instantiating the runtime. Upon instantiation, you
-are expected to provide implementations for all the `fp_import!` functions, while the returned
-`Promise` will give you an object with all the `fp_export!` functions the provided plugin has
-implemented.
+`BindingsType::RustTypes` generates a crate with the same type definitions as the Rust plugin
+bindings, but without any of the WebAssembly glue. Services that exchange the protocol's types with
+a host or plugin, for instance over HTTP, can depend on this crate so that their serialization
+stays compatible. The crate only depends on `fp-bindgen-support` when a type refers to one of its
+helpers, and then without the features for plugins or runtimes. Like the other generated crates,
+its name and dependencies can be set through `RustTypesConfig`:
+
+```ignore
+let config = RustTypesConfig::new().with_name("my-protocol-types");
+```
 
 ## Examples
 
@@ -263,8 +1918,9 @@ If that is you, please have a look at [`docs/SPEC.md`](docs/SPEC.md).
 - TypeScript bindings handle 64-bit integers somewhat inconsistently. When passed as primitives (as
   plain function arguments or return values) they will be encoded using the `BigInt` type. But when
   they're part of a MessagePack-encoded data type, they will be encoded using `number`, which
-  effectively limits them to a maximum size of `2^53 - 1`. For more information, see:
-  <https://github.com/msgpack/msgpack-javascript/issues/115>
+  effectively limits them to a maximum size of `2^53 - 1`. The `int64_encoding` setting of the
+  TypeScript runtime can make these representations consistent, and detect values that exceed the
+  limit. For more information, see: <https://github.com/msgpack/msgpack-javascript/issues/115>
 
 ## FAQ
 
@@ -277,8 +1933,8 @@ directly by one of the `fp_import!` or `fp_export!` functions, or indirectly by
 already in use.
 
 If a type is not referenced either directly or indirectly by any of the functions that are part of
-your protocol, you can force inclusion by adding a `use` statement referencing the type to either
-the `fp_import!` or `fp_export!` section:
+your protocol, you can register it by adding a `use` statement referencing the type to either the
+`fp_import!` or `fp_export!` section:
 
 ```ignore
 fp_bindgen::prelude::fp_import! {
@@ -286,6 +1942,12 @@ fp_bindgen::prelude::fp_import! {
 }
 ```
 
+By default, registered types that are not used by any function are still left out of the bindings,
+which keeps the generated `types.rs` and `types.ts` small for protocols that register many types. To
+generate them anyway, set the `unused_types` option of the `BindingConfig` to `UnusedTypes::Keep`.
+Types that are only used by fields that are excluded from a generator using `#[fp(generators)]`
+are left out of that generator's bindings as well.
+
 Are you referencing the type and it is still not included in your bindings? Please
 [file an issue](https://github.com/fiberplane/fp-bindgen/issues).
 
@@ -300,6 +1962,17 @@ fp_bindgen::prelude::fp_import! {
 }
 ```
 
+Generating bindings for a protocol that uses an alias that isn't repeated like this fails with an
+error that tells you which alias to add.
+
+By default, registered aliases are preserved in the generated bindings: Rust and TypeScript declare
+them as named types, and the OpenAPI specification lists them as schemas of their own. If you'd
+rather have every use of an alias replaced by the type it refers to, set the `aliases` option of the
+`BindingConfig` to `AliasMode::Expand`. This also gives the TypeScript bindings more precise types
+for aliases of 64-bit integers, which can only be typed as `number | bigint` when they are declared
+separately. C# has no aliases that can be shared between files, so the C# runtime always expands
+them.
+
 ### What about versioning?
 
 Generally, versioning is considered out-of-scope for this project. This means it is your own
@@ -318,12 +1991,41 @@ As for what constitutes a breaking change, we offer the following guidelines:
 - Adding fields to `struct`s is always safe, unless your runtime mandates the existence of such
   fields in arguments or return values coming from the plugin.
 - Adding new types is always safe.
+- Adding variants to enums is safe if they were marked `#[non_exhaustive]` before.
+- Renaming functions is safe, as long as they keep their old name on the wire using
+  `#[fp(rename = "...")]`.
+- Renaming fields and variants is safe for runtimes that migrate the payloads of old plugins, as
+  long as the old names are listed using `#[fp(renamed_from = "...")]`.
 - **Anything else should be considered a breaking change.**
 
 Note that, because of the above guidelines, you should never need to define a versioning function in
 your first iteration. Because plugin exports are optional, the absense of a versioning function can
 simply be interpreted as meaning the plugin is at version 1.
 
+#### Checking for breaking changes
+
+To verify these guidelines automatically, the protocol can be captured using the `fp_protocol!()`
+macro and compared against a previous version using `fp_bindgen::diff_protocols()`. Every change is
+classified as either breaking, additive or internal (such as documentation updates).
+
+The easiest way to use this is through `fp_bindgen::handle_protocol_cli()`, which adds `snapshot`
+and `diff` subcommands to your protocol binary:
+
+```ignore
+fn main() {
+    if fp_bindgen::handle_protocol_cli(&fp_protocol!()) {
+        return;
+    }
+
+    // Generate bindings as usual...
+}
+```
+
+Running `cargo run -- snapshot protocol.json` writes a JSON snapshot of the protocol, which you can
+commit to your repository. Afterwards, `cargo run -- diff protocol.json` prints a report of all
+changes since the snapshot was taken and exits with a non-zero status code if any of them are
+breaking, so it can be used to gate releases.
+
 ## Getting Help
 
 Please see
@@ -354,6 +2056,8 @@ mod docs;
 mod functions;
 #[cfg(feature = "generators")]
 mod generators;
+mod protocol;
+mod rate_limited;
 mod serializable;
 
 pub mod prelude;
@@ -366,7 +2070,16 @@ use prelude::*;
 
 primitive_impls!();
 
+pub use protocol::{diff_protocols, ChangeKind, Protocol, ProtocolChange, ProtocolDiff};
+
+#[cfg(feature = "generators")]
+pub use protocol::handle_protocol_cli;
+
 #[cfg(feature = "generators")]
 pub use generators::{
-    generate_bindings, BindingConfig, BindingsType, RustPluginConfig, TsExtendedRuntimeConfig,
+    generate_bindings, AliasMode, AssemblyScriptPluginConfig, BenchesConfig, BindingConfig,
+    BindingsType, CSharpRuntimeConfig, CliConfig, DocsConfig, FileHeaders, FixturesConfig,
+    Formatting, FuzzConfig, MissingFormatter, OpenApiConfig, PluginAllocator, RustPluginConfig,
+    RustTypesConfig, RustWasmerExtendedRuntimeConfig, ScaffoldConfig, TsExtendedRuntimeConfig,
+    TsIdentifierCasing, TsInt64Encoding, TsModuleFormat, UnusedTypes, WasmFeatures,
 };
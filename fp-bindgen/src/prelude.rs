@@ -1,7 +1,17 @@
-pub use crate::functions::{Function, FunctionList};
+pub use crate::functions::{
+    CacheOptions, Function, FunctionAttrs, FunctionList, HttpEndpoint, RateLimitOptions,
+};
 pub use crate::primitives::Primitive;
+pub use crate::protocol::{diff_protocols, Protocol, ProtocolDiff};
+pub use crate::rate_limited::RateLimited;
 pub use crate::serializable::Serializable;
 pub use crate::types::{CustomType, Type, TypeIdent, TypeMap};
 #[cfg(feature = "generators")]
-pub use crate::{BindingConfig, BindingsType, RustPluginConfig, TsExtendedRuntimeConfig};
+pub use crate::{
+    AliasMode, AssemblyScriptPluginConfig, BenchesConfig, BindingConfig, BindingsType,
+    CSharpRuntimeConfig, CliConfig, DocsConfig, FileHeaders, FixturesConfig, Formatting,
+    FuzzConfig, MissingFormatter, OpenApiConfig, PluginAllocator, RustPluginConfig,
+    RustTypesConfig, RustWasmerExtendedRuntimeConfig, ScaffoldConfig, TsExtendedRuntimeConfig,
+    TsIdentifierCasing, TsInt64Encoding, TsModuleFormat, UnusedTypes, WasmFeatures,
+};
 pub use fp_bindgen_macros::*;
@@ -1,8 +1,9 @@
 use quote::{quote, ToTokens};
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 /// Type of primitive that is supported out-of-the-box.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Primitive {
     Bool,
     F32,
@@ -51,6 +52,12 @@ impl Primitive {
         }
         .map(|s| s.to_owned())
     }
+
+    /// Whether the primitive fits in 32 bits, so that a pair of them can be
+    /// packed into a single `u64`.
+    pub fn is_packable(&self) -> bool {
+        !matches!(self, Primitive::F64 | Primitive::I64 | Primitive::U64)
+    }
 }
 
 impl FromStr for Primitive {
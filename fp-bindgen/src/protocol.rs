@@ -0,0 +1,1363 @@
+use crate::{
+    casing::Casing,
+    functions::{register_tuple_return_types, Function, FunctionList},
+    types::{CustomType, Enum, Field, FieldAttrs, Struct, Type, TypeIdent, TypeMap, Variant},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt::Display};
+
+/// A complete protocol, as declared through the `fp_import!` and `fp_export!`
+/// macros.
+///
+/// Use the `fp_protocol!()` macro to capture the protocol of the current
+/// crate, so that it can be snapshotted and compared against other versions
+/// using [diff_protocols()].
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Protocol {
+    pub import_functions: FunctionList,
+    pub export_functions: FunctionList,
+    #[serde(with = "type_map_entries")]
+    pub types: TypeMap,
+}
+
+impl Protocol {
+    pub fn new(
+        import_functions: FunctionList,
+        export_functions: FunctionList,
+        mut types: TypeMap,
+    ) -> Self {
+        register_tuple_return_types(&import_functions, &export_functions, &mut types);
+        Self {
+            import_functions,
+            export_functions,
+            types,
+        }
+    }
+
+    /// Adds the functions and types of another protocol to this one.
+    ///
+    /// This allows a base protocol, such as one for logging or configuration,
+    /// to be shared by multiple protocols without declaring its functions and
+    /// types again. Functions and types that are declared by both protocols
+    /// are only included once, so the result doesn't depend on the order in
+    /// which protocols are combined.
+    ///
+    /// Panics if both protocols declare a function or type with the same name,
+    /// but a different definition, because the bindings cannot contain both.
+    pub fn extend(&mut self, other: Protocol) {
+        self.import_functions.merge(other.import_functions);
+        self.export_functions.merge(other.export_functions);
+
+        for (ident, ty) in other.types {
+            match self.types.get(&ident) {
+                Some(existing) if existing != &ty => {
+                    panic!("Type `{}` is declared with different definitions", ident)
+                }
+                Some(_) => {}
+                None => {
+                    self.types.insert(ident, ty);
+                }
+            }
+        }
+    }
+
+    /// Generates bindings for the protocol, which is useful for protocols that
+    /// are combined using [Protocol::extend()], since the `fp_bindgen!()` macro
+    /// only sees the functions that are declared in its own module.
+    #[cfg(feature = "generators")]
+    pub fn generate_bindings(&self, config: crate::BindingConfig) {
+        crate::generate_bindings(
+            self.import_functions.clone(),
+            self.export_functions.clone(),
+            self.types.clone(),
+            config,
+        );
+    }
+
+    /// Parses a protocol snapshot, as created by [Protocol::to_json()].
+    #[cfg(feature = "generators")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serializes the protocol to a JSON snapshot that can be committed
+    /// alongside the protocol, so future versions can be checked against it.
+    #[cfg(feature = "generators")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Could not serialize protocol")
+    }
+}
+
+/// Borrowed counterpart of [Protocol], which lets generators embed the protocol
+/// in the bindings without taking ownership of its functions and types.
+#[cfg(feature = "generators")]
+#[derive(Serialize)]
+struct ProtocolRef<'a> {
+    import_functions: &'a FunctionList,
+    export_functions: &'a FunctionList,
+    #[serde(serialize_with = "serialize_type_map_ref")]
+    types: &'a TypeMap,
+}
+
+#[cfg(feature = "generators")]
+fn serialize_type_map_ref<S: serde::Serializer>(
+    types: &&TypeMap,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    type_map_entries::serialize(types, serializer)
+}
+
+/// Serializes the protocol to compact JSON, in the same format as
+/// [Protocol::to_json()], so runtimes can describe it through `protocol()`.
+#[cfg(feature = "generators")]
+pub(crate) fn protocol_to_json(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) -> String {
+    serde_json::to_string(&ProtocolRef {
+        import_functions,
+        export_functions,
+        types,
+    })
+    .expect("Could not serialize protocol")
+}
+
+/// `TypeIdent`s cannot be used as JSON keys, so the type map is serialized as
+/// a list of entries instead.
+mod type_map_entries {
+    use super::{Type, TypeIdent, TypeMap};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(types: &TypeMap, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(types.iter())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TypeMap, D::Error> {
+        Vec::<(TypeIdent, Type)>::deserialize(deserializer)
+            .map(|entries| entries.into_iter().collect())
+    }
+}
+
+/// Classification of a single protocol change.
+///
+/// Variants are ordered by severity, so the most severe kind in a diff can be
+/// determined using `max()`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ChangeKind {
+    /// Changes that don't affect the wire format or signatures, such as
+    /// documentation updates or renamed arguments.
+    Internal,
+    /// Changes that existing plugins and runtimes remain compatible with, such
+    /// as new functions or optional fields.
+    Additive,
+    /// Changes that require plugins and runtimes to be updated in lockstep.
+    Breaking,
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Internal => "internal",
+            Self::Additive => "additive",
+            Self::Breaking => "breaking",
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolChange {
+    pub kind: ChangeKind,
+    /// The function, type, field or variant affected by the change.
+    pub subject: String,
+    pub description: String,
+}
+
+/// The result of comparing two versions of a protocol.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProtocolDiff {
+    pub changes: Vec<ProtocolChange>,
+}
+
+impl ProtocolDiff {
+    pub fn is_breaking(&self) -> bool {
+        self.kind() == Some(ChangeKind::Breaking)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the most severe kind of change in the diff, if there are any
+    /// changes at all.
+    pub fn kind(&self) -> Option<ChangeKind> {
+        self.changes.iter().map(|change| change.kind).max()
+    }
+
+    fn push(&mut self, kind: ChangeKind, subject: impl Into<String>, description: String) {
+        self.changes.push(ProtocolChange {
+            kind,
+            subject: subject.into(),
+            description,
+        });
+    }
+}
+
+impl Display for ProtocolDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No protocol changes.");
+        }
+
+        let sections = [
+            (ChangeKind::Breaking, "Breaking changes"),
+            (ChangeKind::Additive, "Additive changes"),
+            (ChangeKind::Internal, "Internal changes"),
+        ];
+        let mut first = true;
+        for (kind, title) in sections {
+            let changes: Vec<_> = self.changes.iter().filter(|c| c.kind == kind).collect();
+            if changes.is_empty() {
+                continue;
+            }
+
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+
+            writeln!(f, "{} ({}):", title, changes.len())?;
+            for change in changes {
+                writeln!(f, "  - {}: {}", change.subject, change.description)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compares two versions of a protocol and classifies every difference
+/// between them.
+///
+/// Only the functions and the named types (structs, enums, aliases and custom
+/// types) are compared directly. Changes to the types used by a function
+/// signature or field surface as changes to that signature or field.
+pub fn diff_protocols(old: &Protocol, new: &Protocol) -> ProtocolDiff {
+    let mut diff = ProtocolDiff::default();
+    diff_functions(
+        &mut diff,
+        "import",
+        &old.import_functions,
+        &new.import_functions,
+    );
+    diff_functions(
+        &mut diff,
+        "export",
+        &old.export_functions,
+        &new.export_functions,
+    );
+    diff_types(&mut diff, &old.types, &new.types);
+    diff
+}
+
+fn diff_functions(
+    diff: &mut ProtocolDiff,
+    direction: &str,
+    old: &FunctionList,
+    new: &FunctionList,
+) {
+    // Functions are matched by their name on the wire, so renaming a function
+    // while keeping its old name on the wire is not a breaking change:
+    let old: BTreeMap<_, _> = old.iter().map(|f| (f.wire_name(), f)).collect();
+    let new: BTreeMap<_, _> = new.iter().map(|f| (f.wire_name(), f)).collect();
+
+    for (name, old_function) in &old {
+        let subject = format!("{direction} `{name}`");
+        match new.get(name) {
+            Some(new_function) => diff_function(diff, &subject, old_function, new_function),
+            None => diff.push(ChangeKind::Breaking, subject, "removed".to_owned()),
+        }
+    }
+
+    for name in new.keys().filter(|name| !old.contains_key(*name)) {
+        diff.push(
+            ChangeKind::Additive,
+            format!("{direction} `{name}`"),
+            "added".to_owned(),
+        );
+    }
+}
+
+fn diff_function(diff: &mut ProtocolDiff, subject: &str, old: &Function, new: &Function) {
+    if old.name != new.name {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            format!("renamed from `{}` to `{}`", old.name, new.name),
+        );
+    }
+
+    let old_args: Vec<_> = old.args.iter().map(|arg| &arg.ty).collect();
+    let new_args: Vec<_> = new.args.iter().map(|arg| &arg.ty).collect();
+    if old_args != new_args {
+        diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!(
+                "argument types changed from `({})` to `({})`",
+                format_idents(old_args),
+                format_idents(new_args)
+            ),
+        );
+    } else if old.args != new.args {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "arguments renamed".to_owned(),
+        );
+    }
+
+    if old.return_type != new.return_type {
+        diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!(
+                "return type changed from `{}` to `{}`",
+                format_return_type(old.return_type.as_ref()),
+                format_return_type(new.return_type.as_ref())
+            ),
+        );
+    }
+
+    if old.is_async != new.is_async {
+        diff.push(
+            ChangeKind::Breaking,
+            subject,
+            if new.is_async {
+                "became async".to_owned()
+            } else {
+                "is no longer async".to_owned()
+            },
+        );
+    }
+
+    if old.attrs.dynamic != new.attrs.dynamic {
+        diff.push(
+            ChangeKind::Breaking,
+            subject,
+            if new.attrs.dynamic {
+                "became dynamic".to_owned()
+            } else {
+                "is no longer dynamic".to_owned()
+            },
+        );
+    }
+
+    if old.attrs.batchable != new.attrs.batchable {
+        diff.push(
+            ChangeKind::Breaking,
+            subject,
+            if new.attrs.batchable {
+                "became batchable".to_owned()
+            } else {
+                "is no longer batchable".to_owned()
+            },
+        );
+    }
+
+    if old.attrs.event != new.attrs.event {
+        diff.push(
+            ChangeKind::Breaking,
+            subject,
+            if new.attrs.event {
+                "became an event".to_owned()
+            } else {
+                "is no longer an event".to_owned()
+            },
+        );
+    }
+
+    match (&old.attrs.capability, &new.attrs.capability) {
+        (old, new) if old == new => {}
+        (None, Some(new)) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!("started requiring capability `{new}`"),
+        ),
+        (Some(old), None) => diff.push(
+            ChangeKind::Additive,
+            subject,
+            format!("no longer requires capability `{old}`"),
+        ),
+        (old, new) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!(
+                "changed required capability from `{}` to `{}`",
+                old.as_deref().unwrap_or_default(),
+                new.as_deref().unwrap_or_default()
+            ),
+        ),
+    }
+
+    match (&old.attrs.cfg_feature, &new.attrs.cfg_feature) {
+        (old, new) if old == new => {}
+        (None, Some(new)) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!("moved behind feature `{new}`"),
+        ),
+        (Some(old), None) => diff.push(
+            ChangeKind::Additive,
+            subject,
+            format!("is no longer behind feature `{old}`"),
+        ),
+        (old, new) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!(
+                "moved from feature `{}` to `{}`",
+                old.as_deref().unwrap_or_default(),
+                new.as_deref().unwrap_or_default()
+            ),
+        ),
+    }
+
+    match (&old.attrs.http, &new.attrs.http) {
+        (None, None) => {}
+        (None, Some(new)) => diff.push(
+            ChangeKind::Additive,
+            subject,
+            format!("became available at HTTP endpoint `{new}`"),
+        ),
+        (Some(old), None) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!("is no longer available at HTTP endpoint `{old}`"),
+        ),
+        (Some(old), Some(new)) if old != new => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!("moved from HTTP endpoint `{old}` to `{new}`"),
+        ),
+        (Some(_), Some(_)) => {}
+    }
+
+    match (old.attrs.read_only, new.attrs.read_only) {
+        (false, true) => diff.push(
+            ChangeKind::Additive,
+            subject,
+            "became available as a GraphQL query".to_owned(),
+        ),
+        (true, false) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            "is no longer available as a GraphQL query".to_owned(),
+        ),
+        _ => {}
+    }
+
+    if old.doc_lines != new.doc_lines {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "documentation changed".to_owned(),
+        );
+    }
+}
+
+fn diff_types(diff: &mut ProtocolDiff, old: &TypeMap, new: &TypeMap) {
+    let old = named_types(old);
+    let new = named_types(new);
+
+    for (name, old_ty) in &old {
+        let subject = format!("type `{name}`");
+        match new.get(name) {
+            Some(new_ty) => diff_type(diff, &subject, old_ty, new_ty),
+            None => diff.push(ChangeKind::Breaking, subject, "removed".to_owned()),
+        }
+    }
+
+    for name in new.keys().filter(|name| !old.contains_key(*name)) {
+        diff.push(
+            ChangeKind::Additive,
+            format!("type `{name}`"),
+            "added".to_owned(),
+        );
+    }
+}
+
+/// Returns the types that are declared by name, keyed by that name. Other
+/// types, such as lists and primitives, are fully described by their
+/// identifier and can only change along with whatever refers to them.
+fn named_types(types: &TypeMap) -> BTreeMap<String, &Type> {
+    types
+        .iter()
+        .filter(|(_, ty)| {
+            matches!(
+                ty,
+                Type::Alias(_, _) | Type::Custom(_) | Type::Enum(_) | Type::Struct(_)
+            )
+        })
+        .map(|(ident, ty)| (ident.to_string(), ty))
+        .collect()
+}
+
+fn diff_type(diff: &mut ProtocolDiff, subject: &str, old: &Type, new: &Type) {
+    match (old, new) {
+        (Type::Alias(_, old_target), Type::Alias(_, new_target)) => {
+            if old_target != new_target {
+                diff.push(
+                    ChangeKind::Breaking,
+                    subject,
+                    format!("alias target changed from `{old_target}` to `{new_target}`"),
+                );
+            }
+        }
+        (Type::Custom(old), Type::Custom(new)) => diff_custom_type(diff, subject, old, new),
+        (Type::Enum(old), Type::Enum(new)) => diff_enum(diff, subject, old, new),
+        (Type::Struct(old), Type::Struct(new)) => diff_struct(diff, subject, old, new),
+        (old, new) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!("changed from {} to {}", type_kind(old), type_kind(new)),
+        ),
+    }
+}
+
+fn diff_custom_type(diff: &mut ProtocolDiff, subject: &str, old: &CustomType, new: &CustomType) {
+    if old.serde_attrs != new.serde_attrs {
+        diff.push(
+            ChangeKind::Breaking,
+            subject,
+            "serde attributes changed".to_owned(),
+        );
+    }
+
+    if old.rs_ty != new.rs_ty || old.ts_ty != new.ts_ty || old.ts_declaration != new.ts_declaration
+    {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "type mapping changed".to_owned(),
+        );
+    }
+}
+
+fn diff_struct(diff: &mut ProtocolDiff, subject: &str, old: &Struct, new: &Struct) {
+    diff_fields(
+        diff,
+        subject,
+        (&old.fields, old.options.field_casing),
+        (&new.fields, new.options.field_casing),
+        old.options.deny_unknown_fields,
+    );
+
+    match (
+        old.options.deny_unknown_fields,
+        new.options.deny_unknown_fields,
+    ) {
+        (false, true) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            "unknown fields are rejected".to_owned(),
+        ),
+        (true, false) => diff.push(
+            ChangeKind::Additive,
+            subject,
+            "unknown fields are ignored".to_owned(),
+        ),
+        _ => {}
+    }
+
+    if old.options.rust_module != new.options.rust_module {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "Rust module changed".to_owned(),
+        );
+    }
+
+    if old.doc_lines != new.doc_lines {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "documentation changed".to_owned(),
+        );
+    }
+}
+
+fn diff_enum(diff: &mut ProtocolDiff, subject: &str, old: &Enum, new: &Enum) {
+    let (old_options, new_options) = (&old.options, &new.options);
+    if old_options.tag_prop_name != new_options.tag_prop_name
+        || old_options.content_prop_name != new_options.content_prop_name
+        || old_options.untagged != new_options.untagged
+    {
+        diff.push(ChangeKind::Breaking, subject, "tagging changed".to_owned());
+    }
+
+    let old_variants = serialized_variants(old);
+    let new_variants = serialized_variants(new);
+    for (serialized_name, old_variant) in &old_variants {
+        let variant_subject = format!("{subject} variant `{}`", old_variant.name);
+        match new_variants.get(serialized_name) {
+            Some(new_variant) => diff_variant(diff, &variant_subject, old_variant, new_variant),
+            None => diff.push(ChangeKind::Breaking, variant_subject, "removed".to_owned()),
+        }
+    }
+
+    // Existing plugins and runtimes are unable to deserialize variants they
+    // don't know about, so even new variants are considered breaking, unless
+    // the enum was non-exhaustive already:
+    let kind = if old_options.non_exhaustive {
+        ChangeKind::Additive
+    } else {
+        ChangeKind::Breaking
+    };
+    for (serialized_name, new_variant) in &new_variants {
+        if !old_variants.contains_key(serialized_name) {
+            diff.push(
+                kind,
+                format!("{subject} variant `{}`", new_variant.name),
+                "added".to_owned(),
+            );
+        }
+    }
+
+    match (old_options.non_exhaustive, new_options.non_exhaustive) {
+        (false, true) => diff.push(
+            ChangeKind::Additive,
+            subject,
+            "unknown variants are accepted".to_owned(),
+        ),
+        (true, false) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            "unknown variants are rejected".to_owned(),
+        ),
+        _ => {}
+    }
+
+    if old_options.rust_module != new_options.rust_module {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "Rust module changed".to_owned(),
+        );
+    }
+
+    if old.doc_lines != new.doc_lines {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "documentation changed".to_owned(),
+        );
+    }
+}
+
+fn serialized_variants(ty: &Enum) -> BTreeMap<String, &Variant> {
+    ty.variants
+        .iter()
+        .map(|variant| {
+            let name = variant
+                .attrs
+                .rename
+                .clone()
+                .unwrap_or_else(|| ty.options.variant_casing.format_string(&variant.name));
+            (name, variant)
+        })
+        .collect()
+}
+
+fn diff_variant(diff: &mut ProtocolDiff, subject: &str, old: &Variant, new: &Variant) {
+    match (&old.ty, &new.ty) {
+        (Type::Struct(old_struct), Type::Struct(new_struct)) => diff_fields(
+            diff,
+            subject,
+            (&old_struct.fields, old.attrs.field_casing),
+            (&new_struct.fields, new.attrs.field_casing),
+            false,
+        ),
+        (old_ty, new_ty) if variant_kind(old_ty) != variant_kind(new_ty) => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!(
+                "changed from {} to {}",
+                variant_kind(old_ty),
+                variant_kind(new_ty)
+            ),
+        ),
+        (old_ty, new_ty) if old_ty != new_ty => diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!(
+                "type changed from `{}` to `{}`",
+                old_ty.name(),
+                new_ty.name()
+            ),
+        ),
+        _ => {}
+    }
+
+    if old.name != new.name {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            format!("renamed to `{}`", new.name),
+        );
+    }
+
+    if old.doc_lines != new.doc_lines {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "documentation changed".to_owned(),
+        );
+    }
+}
+
+/// Compares the fields of a struct or struct variant. If the old fields deny
+/// unknown fields, adding any field is a breaking change, since the other side
+/// would reject it.
+fn diff_fields(
+    diff: &mut ProtocolDiff,
+    subject: &str,
+    (old_fields, old_casing): (&[Field], Casing),
+    (new_fields, new_casing): (&[Field], Casing),
+    old_denies_unknown_fields: bool,
+) {
+    let old_fields = serialized_fields(old_fields, old_casing);
+    let new_fields = serialized_fields(new_fields, new_casing);
+    let old: BTreeMap<_, _> = old_fields.iter().cloned().collect();
+    let new: BTreeMap<_, _> = new_fields.iter().cloned().collect();
+
+    for (serialized_name, old_field) in &old {
+        let field_subject = format!("{subject} field `{serialized_name}`");
+        match new.get(serialized_name) {
+            Some(new_field) => diff_field(diff, &field_subject, old_field, new_field),
+            None => diff.push(ChangeKind::Breaking, field_subject, "removed".to_owned()),
+        }
+    }
+
+    for (serialized_name, new_field) in &new {
+        if !old.contains_key(serialized_name) {
+            let field_subject = format!("{subject} field `{serialized_name}`");
+            if old_denies_unknown_fields {
+                diff.push(
+                    ChangeKind::Breaking,
+                    field_subject,
+                    "added while unknown fields are rejected".to_owned(),
+                );
+            } else if is_optional_field(new_field) {
+                diff.push(
+                    ChangeKind::Additive,
+                    field_subject,
+                    "added as optional field".to_owned(),
+                );
+            } else {
+                diff.push(
+                    ChangeKind::Breaking,
+                    field_subject,
+                    "added as required field".to_owned(),
+                );
+            }
+        }
+    }
+
+    // Fields are serialized by name, so their order only matters to the
+    // generated code.
+    let old_order: Vec<_> = old_fields
+        .iter()
+        .map(|(name, _)| name)
+        .filter(|name| new.contains_key(*name))
+        .collect();
+    let new_order: Vec<_> = new_fields
+        .iter()
+        .map(|(name, _)| name)
+        .filter(|name| old.contains_key(*name))
+        .collect();
+    if old_order != new_order {
+        diff.push(ChangeKind::Internal, subject, "fields reordered".to_owned());
+    }
+}
+
+/// Pairs fields with the name under which they are serialized. Fields of tuple
+/// structs are serialized by position instead, so their index is used.
+fn serialized_fields(fields: &[Field], casing: Casing) -> Vec<(String, &Field)> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let name = match (&field.attrs.rename, &field.name) {
+                (Some(rename), _) => rename.clone(),
+                (None, Some(name)) => casing.format_string(name),
+                (None, None) => index.to_string(),
+            };
+            (name, field)
+        })
+        .collect()
+}
+
+fn diff_field(diff: &mut ProtocolDiff, subject: &str, old: &Field, new: &Field) {
+    if old.ty != new.ty {
+        diff.push(
+            ChangeKind::Breaking,
+            subject,
+            format!("type changed from `{}` to `{}`", old.ty, new.ty),
+        );
+    }
+
+    let (old_attrs, new_attrs) = (&old.attrs, &new.attrs);
+    let without_validation = |attrs: &FieldAttrs| FieldAttrs {
+        validate: None,
+        ..attrs.clone()
+    };
+    if old_attrs.flatten != new_attrs.flatten
+        || old_attrs.serialize_with != new_attrs.serialize_with
+        || old_attrs.deserialize_with != new_attrs.deserialize_with
+    {
+        diff.push(
+            ChangeKind::Breaking,
+            subject,
+            "serialization changed".to_owned(),
+        );
+    } else if without_validation(old_attrs) != without_validation(new_attrs) {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "attributes changed".to_owned(),
+        );
+    }
+
+    // Values that passed the old validation may not pass the new one, unless
+    // the validation is dropped altogether:
+    match (&old_attrs.validate, &new_attrs.validate) {
+        (Some(_), None) => diff.push(
+            ChangeKind::Additive,
+            subject,
+            "validation removed".to_owned(),
+        ),
+        (old_validation, Some(new_validation))
+            if old_validation.as_ref() != Some(new_validation) =>
+        {
+            diff.push(
+                ChangeKind::Breaking,
+                subject,
+                format!("validation changed to `{new_validation}`"),
+            )
+        }
+        _ => {}
+    }
+
+    if old.name != new.name {
+        diff.push(ChangeKind::Internal, subject, "renamed in Rust".to_owned());
+    }
+
+    if old.doc_lines != new.doc_lines {
+        diff.push(
+            ChangeKind::Internal,
+            subject,
+            "documentation changed".to_owned(),
+        );
+    }
+}
+
+/// Fields that may be omitted by the sender can be added without breaking
+/// existing plugins and runtimes.
+fn is_optional_field(field: &Field) -> bool {
+    field.ty.name == "Option" || field.attrs.default.is_some()
+}
+
+fn format_idents(idents: Vec<&TypeIdent>) -> String {
+    idents
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_return_type(return_type: Option<&TypeIdent>) -> String {
+    return_type
+        .map(ToString::to_string)
+        .unwrap_or_else(|| "()".to_owned())
+}
+
+fn type_kind(ty: &Type) -> &'static str {
+    match ty {
+        Type::Alias(_, _) => "an alias",
+        Type::Custom(_) => "a custom type",
+        Type::Enum(_) => "an enum",
+        Type::Struct(_) => "a struct",
+        _ => "a built-in type",
+    }
+}
+
+fn variant_kind(ty: &Type) -> &'static str {
+    match ty {
+        Type::Struct(_) => "a struct variant",
+        Type::Tuple(_) => "a tuple variant",
+        _ => "a unit variant",
+    }
+}
+
+/// Handles the protocol subcommands for a protocol crate's binary:
+///
+/// - `snapshot <path>` writes a JSON snapshot of the protocol to `path`.
+/// - `diff <path>` compares the protocol against the snapshot at `path`,
+///   prints a report and exits with a non-zero status code if there are
+///   breaking changes.
+///
+/// Returns `false` if no subcommand was given, so the caller can proceed
+/// with generating bindings.
+#[cfg(feature = "generators")]
+pub fn handle_protocol_cli(protocol: &Protocol) -> bool {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [command, path] if command == "snapshot" => {
+            std::fs::write(path, protocol.to_json())
+                .unwrap_or_else(|err| panic!("Could not write snapshot to {}: {}", path, err));
+            println!("Protocol snapshot written to `{path}`.");
+            true
+        }
+        [command, path] if command == "diff" => {
+            let json = std::fs::read_to_string(path)
+                .unwrap_or_else(|err| panic!("Could not read snapshot from {}: {}", path, err));
+            let snapshot = Protocol::from_json(&json)
+                .unwrap_or_else(|err| panic!("Invalid protocol snapshot {}: {}", path, err));
+
+            let diff = diff_protocols(&snapshot, protocol);
+            print!("{diff}");
+            if diff.is_breaking() {
+                std::process::exit(1);
+            }
+            true
+        }
+        [command, ..] if command == "snapshot" || command == "diff" => {
+            eprintln!("Usage: {command} <path>");
+            std::process::exit(2);
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protocol(functions: &[&str], items: &[&str]) -> Protocol {
+        let mut export_functions = FunctionList::new();
+        for decl in functions {
+            export_functions.add_function(decl);
+        }
+
+        let types = items
+            .iter()
+            .map(|item| {
+                let ty = Type::from_item(item);
+                let ident = match &ty {
+                    Type::Enum(ty) => ty.ident.clone(),
+                    Type::Struct(ty) => ty.ident.clone(),
+                    _ => unreachable!(),
+                };
+                (ident, ty)
+            })
+            .collect();
+
+        Protocol::new(FunctionList::new(), export_functions, types)
+    }
+
+    fn kinds(diff: &ProtocolDiff) -> Vec<(ChangeKind, &str)> {
+        diff.changes
+            .iter()
+            .map(|change| (change.kind, change.description.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_function_changes() {
+        let old = protocol(&["fn a(x: u32) -> u32;", "fn b(x: u32);"], &[]);
+        let new = protocol(
+            &["/// Docs.\nfn a(y: u32) -> u32;", "fn c(x: u64) -> u64;"],
+            &[],
+        );
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Internal, "arguments renamed"),
+                (ChangeKind::Internal, "documentation changed"),
+                (ChangeKind::Breaking, "removed"),
+                (ChangeKind::Additive, "added"),
+            ]
+        );
+        assert!(diff.is_breaking());
+    }
+
+    #[test]
+    fn test_rename_changes() {
+        let old = protocol(&["fn a(x: u32);", "fn b(x: u32);"], &[]);
+        let new = protocol(
+            &[
+                "#[fp(rename = \"a\")] fn c(x: u32);",
+                "#[fp(rename = \"d\")] fn b(x: u32);",
+            ],
+            &[],
+        );
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Internal, "renamed from `a` to `c`"),
+                (ChangeKind::Breaking, "removed"),
+                (ChangeKind::Additive, "added"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_capability_changes() {
+        let old = protocol(&["fn a();", "#[fp(capability = \"network\")] fn b();"], &[]);
+        let new = protocol(&["#[fp(capability = \"network\")] fn a();", "fn b();"], &[]);
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (
+                    ChangeKind::Breaking,
+                    "started requiring capability `network`"
+                ),
+                (
+                    ChangeKind::Additive,
+                    "no longer requires capability `network`"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_changes() {
+        let old = protocol(&["fn a(x: String);", "#[fp_event] fn b(x: String);"], &[]);
+        let new = protocol(&["#[fp_event] fn a(x: String);", "fn b(x: String);"], &[]);
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Breaking, "became an event"),
+                (ChangeKind::Breaking, "is no longer an event"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cfg_feature_changes() {
+        let old = protocol(
+            &[
+                "fn a();",
+                "#[fp(cfg_feature = \"experimental\")] fn b();",
+                "#[fp(cfg_feature = \"experimental\")] fn c();",
+            ],
+            &[],
+        );
+        let new = protocol(
+            &[
+                "#[fp(cfg_feature = \"experimental\")] fn a();",
+                "fn b();",
+                "#[fp(cfg_feature = \"preview\")] fn c();",
+            ],
+            &[],
+        );
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Breaking, "moved behind feature `experimental`"),
+                (
+                    ChangeKind::Additive,
+                    "is no longer behind feature `experimental`"
+                ),
+                (
+                    ChangeKind::Breaking,
+                    "moved from feature `experimental` to `preview`"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_http_endpoint_changes() {
+        let old = protocol(
+            &[
+                "fn a();",
+                "#[fp(http(method = \"GET\", path = \"/b\"))] fn b();",
+                "#[fp(http(method = \"GET\", path = \"/c\"))] fn c();",
+            ],
+            &[],
+        );
+        let new = protocol(
+            &[
+                "#[fp(http(method = \"post\", path = \"/a\"))] fn a();",
+                "fn b();",
+                "#[fp(http(method = \"PUT\", path = \"/c\"))] fn c();",
+            ],
+            &[],
+        );
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (
+                    ChangeKind::Additive,
+                    "became available at HTTP endpoint `POST /a`"
+                ),
+                (
+                    ChangeKind::Breaking,
+                    "is no longer available at HTTP endpoint `GET /b`"
+                ),
+                (
+                    ChangeKind::Breaking,
+                    "moved from HTTP endpoint `GET /c` to `PUT /c`"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_only_changes() {
+        let old = protocol(&["fn a() -> u32;", "#[fp(read_only)] fn b() -> u32;"], &[]);
+        let new = protocol(&["#[fp(read_only)] fn a() -> u32;", "fn b() -> u32;"], &[]);
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Additive, "became available as a GraphQL query"),
+                (
+                    ChangeKind::Breaking,
+                    "is no longer available as a GraphQL query"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_struct_field_changes() {
+        let old = protocol(
+            &[],
+            &["#[fp(rename_all = \"camelCase\")] struct Foo { some_field: u32, other: String }"],
+        );
+        let new = protocol(
+            &[],
+            &["#[fp(rename_all = \"camelCase\")] struct Foo { other: String, #[fp(rename = \"someField\")] renamed: u32, extra: Option<u8> }"],
+        );
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Internal, "attributes changed"),
+                (ChangeKind::Internal, "renamed in Rust"),
+                (ChangeKind::Additive, "added as optional field"),
+                (ChangeKind::Internal, "fields reordered"),
+            ]
+        );
+        assert_eq!(diff.kind(), Some(ChangeKind::Additive));
+    }
+
+    #[test]
+    fn test_deny_unknown_fields_changes() {
+        let old = protocol(&[], &["struct Foo { a: u32 }", "struct Bar { a: u32 }"]);
+        let new = protocol(
+            &[],
+            &[
+                "#[serde(deny_unknown_fields)] struct Foo { a: u32 }",
+                "struct Bar { a: u32, b: Option<u32> }",
+            ],
+        );
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Additive, "added as optional field"),
+                (ChangeKind::Breaking, "unknown fields are rejected"),
+            ]
+        );
+
+        let diff = diff_protocols(&new, &old);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Breaking, "removed"),
+                (ChangeKind::Additive, "unknown fields are ignored"),
+            ]
+        );
+
+        let newer = protocol(
+            &[],
+            &[
+                "#[serde(deny_unknown_fields)] struct Foo { a: u32, b: Option<u32> }",
+                "struct Bar { a: u32, b: Option<u32> }",
+            ],
+        );
+        let diff = diff_protocols(&new, &newer);
+        assert_eq!(
+            kinds(&diff),
+            vec![(
+                ChangeKind::Breaking,
+                "added while unknown fields are rejected"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_field_validation_changes() {
+        let old = protocol(
+            &[],
+            &["struct Foo { #[fp(validate(range = \"0..=100\"))] a: u8, #[fp(validate(non_empty))] b: String, c: String }"],
+        );
+        let new = protocol(
+            &[],
+            &["struct Foo { a: u8, #[fp(validate(non_empty))] b: String, #[fp(validate(regex = \"^[a-z]+$\"))] c: String }"],
+        );
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Additive, "validation removed"),
+                (
+                    ChangeKind::Breaking,
+                    "validation changed to `validate(regex = \"^[a-z]+$\")`"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_changes() {
+        let old = protocol(&[], &["enum Foo { A, B(u32) }"]);
+        let new = protocol(
+            &[],
+            &["#[fp(tag = \"type\", content = \"payload\")] enum Foo { A, B(u64), C }"],
+        );
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Breaking, "tagging changed"),
+                (ChangeKind::Breaking, "type changed from `(u32)` to `(u64)`"),
+                (ChangeKind::Breaking, "added"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_non_exhaustive_enum_changes() {
+        let old = protocol(&[], &["#[non_exhaustive] enum Foo { A }", "enum Bar { A }"]);
+        let new = protocol(
+            &[],
+            &[
+                "#[non_exhaustive] enum Foo { A, B }",
+                "#[non_exhaustive] enum Bar { A, B }",
+            ],
+        );
+
+        let diff = diff_protocols(&old, &new);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Breaking, "added"),
+                (ChangeKind::Additive, "unknown variants are accepted"),
+                (ChangeKind::Additive, "added"),
+            ]
+        );
+
+        let diff = diff_protocols(&new, &old);
+        assert_eq!(
+            kinds(&diff),
+            vec![
+                (ChangeKind::Breaking, "removed"),
+                (ChangeKind::Breaking, "unknown variants are rejected"),
+                (ChangeKind::Breaking, "removed"),
+            ]
+        );
+    }
+
+    #[cfg(feature = "generators")]
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let old = protocol(
+            &["fn a(foo: Foo) -> Option<u32>;"],
+            &["struct Foo { a: Vec<u8> }"],
+        );
+        let snapshot = Protocol::from_json(&old.to_json()).unwrap();
+
+        assert!(diff_protocols(&old, &snapshot).is_empty());
+    }
+
+    #[cfg(feature = "generators")]
+    #[test]
+    fn test_embedded_protocol_roundtrip() {
+        let old = protocol(
+            &["async fn a(foo: Foo) -> Result<u32, String>;"],
+            &["struct Foo { a: Vec<u8> }"],
+        );
+        let json = protocol_to_json(&old.import_functions, &old.export_functions, &old.types);
+        let embedded = Protocol::from_json(&json).unwrap();
+
+        assert!(diff_protocols(&old, &embedded).is_empty());
+    }
+
+    #[test]
+    fn test_extend() {
+        let base = || {
+            protocol(
+                &["fn a(foo: Foo);", "fn b() -> u32;"],
+                &["struct Foo { a: Vec<u8> }"],
+            )
+        };
+        let other = || {
+            protocol(
+                &["/// Docs.\nfn a(foo: Foo);", "fn c(bar: Bar);"],
+                &["struct Foo { a: Vec<u8> }", "struct Bar { b: String }"],
+            )
+        };
+        let names = |protocol: &Protocol| {
+            protocol
+                .export_functions
+                .iter()
+                .map(|function| function.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut extended_base = base();
+        extended_base.extend(other());
+        let mut extended_other = other();
+        extended_other.extend(base());
+
+        assert_eq!(names(&extended_base), vec!["a", "b", "c"]);
+        assert_eq!(extended_base.types.len(), 2);
+        assert_eq!(names(&extended_other), names(&extended_base));
+        assert_eq!(extended_other.types, extended_base.types);
+    }
+
+    #[test]
+    #[should_panic(expected = "Function `a` is declared with different signatures")]
+    fn test_extend_with_conflicting_function() {
+        let mut base = protocol(&["fn a(x: u32);"], &[]);
+        base.extend(protocol(&["fn a(x: u64);"], &[]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Type `Foo` is declared with different definitions")]
+    fn test_extend_with_conflicting_type() {
+        let mut base = protocol(&[], &["struct Foo { a: u32 }"]);
+        base.extend(protocol(&[], &["struct Foo { a: u64 }"]));
+    }
+}
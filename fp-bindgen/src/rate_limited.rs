@@ -0,0 +1,66 @@
+use crate::{
+    casing::Casing,
+    prelude::*,
+    types::{Field, Struct, StructOptions},
+};
+use serde::{Deserialize, Serialize};
+
+/// Error that is returned to the plugin when it calls an import that is
+/// annotated with `#[fp(rate_limit(...))]` more often than its limit allows.
+///
+/// Rate-limited imports must use this as the error type of their return type,
+/// for instance `Result<HttpResult, RateLimited>`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimited {
+    /// The name of the import that was called.
+    pub function: String,
+
+    /// The number of milliseconds after which the import may be called again.
+    pub retry_after_ms: u32,
+}
+
+impl Serializable for RateLimited {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("RateLimited")
+    }
+
+    fn ty() -> Type {
+        Type::Struct(Struct {
+            ident: Self::ident(),
+            fields: vec![
+                Field {
+                    name: Some("function".to_owned()),
+                    ty: TypeIdent::from("String"),
+                    doc_lines: vec![" The name of the import that was called.".to_owned()],
+                    attrs: Default::default(),
+                },
+                Field {
+                    name: Some("retry_after_ms".to_owned()),
+                    ty: TypeIdent::from("u32"),
+                    doc_lines: vec![
+                        " The number of milliseconds after which the import may be called again."
+                            .to_owned(),
+                    ],
+                    attrs: Default::default(),
+                },
+            ],
+            doc_lines: vec![
+                " Error that is returned to the plugin when it calls a rate-limited import"
+                    .to_owned(),
+                " more often than its limit allows.".to_owned(),
+            ],
+            options: StructOptions {
+                field_casing: Casing::CamelCase,
+                ..Default::default()
+            },
+            location: None,
+        })
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        types.entry(Self::ident()).or_insert_with(Self::ty);
+        String::collect_types(types);
+        u32::collect_types(types);
+    }
+}
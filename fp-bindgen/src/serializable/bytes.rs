@@ -21,3 +21,23 @@ impl Serializable for bytes::Bytes {
         })
     }
 }
+
+impl Serializable for bytes::BytesMut {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("BytesMut")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            rs_ty: "bytes::BytesMut".to_owned(),
+            rs_dependencies: BTreeMap::from([(
+                "bytes",
+                CargoDependency::with_version_and_features("1", BTreeSet::from(["serde"])),
+            )]),
+            serde_attrs: vec![],
+            ts_ty: "Uint8Array".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
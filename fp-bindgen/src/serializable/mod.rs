@@ -1,9 +1,9 @@
 use crate::{
-    types::{Enum, EnumOptions, TypeIdent, TypeMap, Variant, VariantAttrs},
+    types::{CustomType, Enum, EnumOptions, TypeIdent, TypeMap, Variant, VariantAttrs},
     Type,
 };
 use std::{
-    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     rc::Rc,
 };
 
@@ -11,8 +11,13 @@ use std::{
 mod bytes;
 #[cfg(feature = "http-compat")]
 mod http;
+mod num;
+#[cfg(feature = "num-bigint-compat")]
+mod num_bigint;
 #[cfg(feature = "rmpv-compat")]
 mod rmpv;
+#[cfg(feature = "rust-decimal-compat")]
+mod rust_decimal;
 #[cfg(feature = "serde-bytes-compat")]
 mod serde_bytes;
 #[cfg(feature = "serde-json-compat")]
@@ -251,6 +256,7 @@ where
                     .to_owned(),
             ],
             options: EnumOptions::default(),
+            location: None,
         })
     }
 
@@ -261,6 +267,51 @@ where
     }
 }
 
+/// Implements `Serializable` for tuples. Only pairs of primitives can be used
+/// as arguments and fields, while functions may return tuples of any types.
+///
+/// Only packed pairs register themselves, because the identifiers of other
+/// items may be generic, such as `Vec<T>`. The tuples that functions return
+/// are registered with their concrete items instead.
+macro_rules! tuple_impls {
+    ($(($($item:ident),+)),*) => {
+        $(
+            impl<$($item),+> Serializable for ($($item,)+)
+            where
+                $($item: Serializable,)+
+            {
+                fn ident() -> TypeIdent {
+                    TypeIdent::from(format!(
+                        "({})",
+                        [$($item::ident().to_string()),+].join(", ")
+                    ))
+                }
+
+                fn ty() -> Type {
+                    Type::Tuple(vec![$($item::ident()),+])
+                }
+
+                fn collect_types(types: &mut TypeMap) {
+                    if Self::ident().is_packed_pair() {
+                        types.entry(Self::ident()).or_insert_with(Self::ty);
+                    }
+                    $($item::collect_types(types);)+
+                }
+            }
+        )*
+    };
+}
+
+tuple_impls!(
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H)
+);
+
 impl Serializable for () {
     fn ident() -> TypeIdent {
         TypeIdent::from("()")
@@ -281,6 +332,25 @@ impl Serializable for String {
     }
 }
 
+// Serde serializes a `char` as a string containing only that character, so
+// that is how it's exchanged with the runtimes as well.
+impl Serializable for char {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("char")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            rs_ty: "char".to_owned(),
+            rs_dependencies: BTreeMap::new(),
+            serde_attrs: vec![],
+            ts_ty: "string".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
+
 impl<T> Serializable for Vec<T>
 where
     T: Serializable,
@@ -302,3 +372,25 @@ where
         T::collect_types(types);
     }
 }
+
+impl<T> Serializable for VecDeque<T>
+where
+    T: Serializable,
+{
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "VecDeque".to_owned(),
+            generic_args: vec![(TypeIdent::from("T"), vec![])],
+            ..Default::default()
+        }
+    }
+
+    fn ty() -> Type {
+        Type::List("VecDeque".to_owned(), TypeIdent::from("T"))
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        types.entry(Self::ident()).or_insert_with(Self::ty);
+        T::collect_types(types);
+    }
+}
@@ -0,0 +1,116 @@
+use super::Serializable;
+use crate::types::{CustomType, Type, TypeIdent};
+use std::{collections::BTreeMap, num::*};
+
+// `usize` and `isize` are 32 bits wide on `wasm32`, so they are exchanged as
+// `u32` and `i32`. The generated bindings use the latter types as well, so that
+// runtimes on 64-bit hosts agree with the plugin on their size.
+impl Serializable for usize {
+    fn ident() -> TypeIdent {
+        u32::ident()
+    }
+
+    fn ty() -> Type {
+        u32::ty()
+    }
+
+    fn is_primitive() -> bool {
+        true
+    }
+}
+
+impl Serializable for isize {
+    fn ident() -> TypeIdent {
+        i32::ident()
+    }
+
+    fn ty() -> Type {
+        i32::ty()
+    }
+
+    fn is_primitive() -> bool {
+        true
+    }
+}
+
+/// Implements `Serializable` for `NonZero` integer types. These are passed as
+/// regular numbers, but Serde refuses to deserialize them if they are zero.
+macro_rules! non_zero_impls {
+    ($($ty:ident),*) => {
+        $(
+            impl Serializable for $ty {
+                fn ident() -> TypeIdent {
+                    TypeIdent::from(stringify!($ty))
+                }
+
+                fn ty() -> Type {
+                    Type::Custom(CustomType {
+                        ident: Self::ident(),
+                        rs_ty: concat!("std::num::", stringify!($ty)).to_owned(),
+                        rs_dependencies: BTreeMap::new(),
+                        serde_attrs: vec![],
+                        ts_ty: "number".to_owned(),
+                        ts_declaration: None,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+non_zero_impls!(
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64
+);
+
+impl Serializable for NonZeroUsize {
+    fn ident() -> TypeIdent {
+        NonZeroU32::ident()
+    }
+
+    fn ty() -> Type {
+        NonZeroU32::ty()
+    }
+}
+
+impl Serializable for NonZeroIsize {
+    fn ident() -> TypeIdent {
+        NonZeroI32::ident()
+    }
+
+    fn ty() -> Type {
+        NonZeroI32::ty()
+    }
+}
+
+/// Implements `Serializable` for 128-bit integers. MessagePack has no integers
+/// this wide, and JavaScript numbers would lose precision, so these are
+/// exchanged as decimal strings, and typed as branded strings in TypeScript.
+macro_rules! int128_impls {
+    ($($ty:ident => $ts_ty:literal),*) => {
+        $(
+            impl Serializable for $ty {
+                fn ident() -> TypeIdent {
+                    TypeIdent::from(stringify!($ty))
+                }
+
+                fn ty() -> Type {
+                    Type::Custom(CustomType {
+                        ident: Self::ident(),
+                        rs_ty: stringify!($ty).to_owned(),
+                        rs_dependencies: BTreeMap::new(),
+                        serde_attrs: vec![
+                            r#"with = "fp_bindgen_support::common::serde_str""#.to_owned(),
+                        ],
+                        ts_ty: $ts_ty.to_owned(),
+                        ts_declaration: Some(format!(
+                            r#"string & {{ readonly __brand: "{}" }}"#,
+                            stringify!($ty)
+                        )),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+int128_impls!(i128 => "I128String", u128 => "U128String");
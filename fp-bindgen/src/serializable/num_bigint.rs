@@ -0,0 +1,22 @@
+use super::Serializable;
+use crate::types::{CargoDependency, CustomType, Type, TypeIdent};
+use std::collections::BTreeMap;
+
+impl Serializable for num_bigint::BigInt {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("BigInt")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            rs_ty: "num_bigint::BigInt".to_owned(),
+            rs_dependencies: BTreeMap::from([("num-bigint", CargoDependency::with_version("0.4"))]),
+            serde_attrs: vec![r#"with = "fp_bindgen_support::common::serde_str""#.to_owned()],
+            // Named so that it doesn't shadow the global `BigInt` of
+            // JavaScript:
+            ts_ty: "BigIntString".to_owned(),
+            ts_declaration: Some(r#"string & { readonly __brand: "BigInt" }"#.to_owned()),
+        })
+    }
+}
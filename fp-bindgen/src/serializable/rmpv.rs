@@ -20,7 +20,7 @@ impl Serializable for rmpv::Value {
                 },
             )]),
             serde_attrs: Vec::new(),
-            ts_ty: "any".to_owned(),
+            ts_ty: "unknown".to_owned(),
             ts_declaration: None,
         })
     }
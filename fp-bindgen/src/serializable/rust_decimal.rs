@@ -0,0 +1,20 @@
+use super::Serializable;
+use crate::types::{CargoDependency, CustomType, Type, TypeIdent};
+use std::collections::BTreeMap;
+
+impl Serializable for rust_decimal::Decimal {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("Decimal")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            rs_ty: "rust_decimal::Decimal".to_owned(),
+            rs_dependencies: BTreeMap::from([("rust_decimal", CargoDependency::with_version("1"))]),
+            serde_attrs: vec![r#"with = "fp_bindgen_support::common::serde_str""#.to_owned()],
+            ts_ty: "DecimalString".to_owned(),
+            ts_declaration: Some(r#"string & { readonly __brand: "Decimal" }"#.to_owned()),
+        })
+    }
+}
@@ -14,7 +14,7 @@ impl Serializable for serde_json::Value {
             rs_ty: "serde_json::Value".to_owned(),
             rs_dependencies: BTreeMap::from([("serde_json", CargoDependency::with_version("1.0"))]),
             serde_attrs: Vec::new(),
-            ts_ty: "any".to_owned(),
+            ts_ty: "unknown".to_owned(),
             ts_declaration: None,
         })
     }
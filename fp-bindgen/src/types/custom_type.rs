@@ -1,9 +1,10 @@
 use super::{CargoDependency, TypeIdent};
+use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, hash::Hash};
 
 /// Used for defining type information for types that are defined externally,
 /// or that otherwise require custom treatment.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct CustomType {
     pub ident: TypeIdent,
 
@@ -16,6 +17,10 @@ pub struct CustomType {
     /// Keys in the map are dependency names as they appear on the left-hand
     /// side of the `=` in the `Cargo.toml` `[dependencies]` section, while the
     /// value describes what comes on the right-hand side.
+    ///
+    /// Dependencies are not part of the protocol itself, so they are left out
+    /// of protocol snapshots.
+    #[serde(skip)]
     pub rs_dependencies: BTreeMap<&'static str, CargoDependency>,
 
     /// Serde attributes to add to fields of this type.
@@ -1,22 +1,28 @@
 use super::{
     structs::{Field, Struct, StructOptions},
-    Type, TypeIdent,
+    SourceLocation, Type, TypeIdent,
 };
-use crate::types::format_bounds;
+use crate::types::{format_bounds, parse_generator_names};
 use crate::{casing::Casing, docs::get_doc_lines, primitives::Primitive, types::FieldAttrs};
 use quote::ToTokens;
+use serde::{Deserialize, Serialize};
 use std::{convert::TryFrom, str::FromStr};
 use syn::{
     ext::IdentExt, parenthesized, parse::Parse, parse::ParseStream, Attribute, Error, GenericParam,
     Ident, ItemEnum, LitStr, Result, Token, TypePath,
 };
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Enum {
     pub ident: TypeIdent,
     pub variants: Vec<Variant>,
     pub doc_lines: Vec<String>,
     pub options: EnumOptions,
+
+    /// Where the enum was declared, if known. This is not part of the
+    /// serialized protocol.
+    #[serde(default, skip)]
+    pub location: Option<SourceLocation>,
 }
 
 pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
@@ -36,6 +42,28 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
         ..Default::default()
     };
     let options = EnumOptions::from_attrs(&item.attrs);
+    if options.content_prop_name.is_some() && options.tag_prop_name.is_none() {
+        panic!(
+            "Enum {} cannot be serialized, because it has a `content` attribute without a \
+                `tag` attribute",
+            ident
+        );
+    }
+    if options.untagged && options.tag_prop_name.is_some() {
+        panic!(
+            "Enum {} cannot be serialized, because it has both an `untagged` and a `tag` \
+                attribute",
+            ident
+        );
+    }
+    if options.non_exhaustive && options.untagged {
+        panic!(
+            "Enum {} cannot be non-exhaustive, because unknown variants of untagged enums \
+                cannot be told apart from invalid values",
+            ident
+        );
+    }
+
     let variants = item
         .variants
         .iter()
@@ -86,6 +114,7 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
                     fields,
                     doc_lines: Vec::new(),
                     options: StructOptions::default(),
+                    location: None,
                 })
             } else {
                 let item_types: Vec<_> = variant
@@ -120,6 +149,29 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
             };
             let doc_lines = get_doc_lines(&variant.attrs);
             let attrs = VariantAttrs::from_attrs(&variant.attrs);
+            if attrs.other && (options.tag_prop_name.is_none() || ty != Type::Unit) {
+                panic!(
+                    "Enum {} cannot be serialized, because the variant `{}` has an `other` \
+                        attribute, which is only allowed on unit variants of tagged enums",
+                    ident, variant.ident
+                );
+            }
+
+            if options.non_exhaustive && attrs.other {
+                panic!(
+                    "Enum {} cannot be serialized, because the variant `{}` has an `other` \
+                        attribute, while unknown variants of non-exhaustive enums are kept in \
+                        the generated `Unknown` variant",
+                    ident, variant.ident
+                );
+            }
+            if options.non_exhaustive && name == "Unknown" {
+                panic!(
+                    "Enum {} cannot be non-exhaustive, because it already has a variant named \
+                        `Unknown`",
+                    ident
+                );
+            }
 
             Variant {
                 name,
@@ -135,10 +187,11 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
         variants,
         doc_lines: get_doc_lines(&item.attrs),
         options,
+        location: None,
     }
 }
 
-#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct EnumOptions {
     pub variant_casing: Casing,
     pub content_prop_name: Option<String>,
@@ -169,6 +222,22 @@ pub struct EnumOptions {
     ///
     /// Instead of generating the enum definition itself.
     pub rust_module: Option<String>,
+
+    /// Whether the enum is open for extension, because it is marked as
+    /// `#[non_exhaustive]`. Variants that are added later can then be
+    /// deserialized by older plugins and runtimes: the Rust bindings get an
+    /// `Unknown` variant that holds the serialized value of such variants,
+    /// while the TypeScript type includes a catch-all for them.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[non_exhaustive]
+    /// #[fp(tag = "type")]
+    /// enum MyEnum { /* ... */ }
+    /// ```
+    #[serde(default)]
+    pub non_exhaustive: bool,
 }
 
 impl EnumOptions {
@@ -179,6 +248,8 @@ impl EnumOptions {
                 opts.merge_with(
                     &syn::parse2::<Self>(attr.tokens.clone()).expect("Could not parse attributes"),
                 );
+            } else if attr.path.is_ident("non_exhaustive") {
+                opts.non_exhaustive = true;
             }
         }
         opts
@@ -268,7 +339,7 @@ impl Parse for EnumOptions {
     }
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Variant {
     pub name: String,
     pub ty: Type,
@@ -276,15 +347,38 @@ pub struct Variant {
     pub attrs: VariantAttrs,
 }
 
-#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct VariantAttrs {
+    /// Alternative names from which the variant may be deserialized.
+    ///
+    /// See also: <https://serde.rs/variant-attrs.html#alias>
+    pub aliases: Vec<String>,
+
     pub field_casing: Casing,
 
+    /// Names of the generators whose bindings should include the variant. If
+    /// empty, the variant is included in the bindings of all generators.
+    #[serde(default)]
+    pub generators: Vec<String>,
+
+    /// If `true`, this variant is deserialized when the tag of an internally
+    /// or adjacently tagged enum does not match any of the other variants.
+    /// Only allowed on unit variants.
+    ///
+    /// See also: <https://serde.rs/variant-attrs.html#other>
+    pub other: bool,
+
     /// Optional name to use in the serialized format
     /// (only used if different than the variant name itself).
     ///
     /// See also: <https://serde.rs/variant-attrs.html#rename>
     pub rename: Option<String>,
+
+    /// Names under which the variant was serialized by previous versions of
+    /// the protocol, from which the Rust Wasmer runtime can generate
+    /// migrations. Not supported for variants of untagged enums.
+    #[serde(default)]
+    pub renamed_from: Vec<String>,
 }
 
 impl VariantAttrs {
@@ -302,16 +396,38 @@ impl VariantAttrs {
     }
 
     fn merge_with(&mut self, other: &Self) {
+        for alias in &other.aliases {
+            if !self.aliases.contains(alias) {
+                self.aliases.push(alias.clone());
+            }
+        }
         if other.field_casing != Casing::default() {
             self.field_casing = other.field_casing;
         }
+        if !other.generators.is_empty() {
+            self.generators = other.generators.clone();
+        }
+        if other.other {
+            self.other = true;
+        }
         if other.rename.is_some() {
             self.rename = other.rename.clone();
         }
+        for name in &other.renamed_from {
+            if !self.renamed_from.contains(name) {
+                self.renamed_from.push(name.clone());
+            }
+        }
     }
 
     pub fn to_serde_attrs(&self) -> Vec<String> {
         let mut serde_attrs = vec![];
+        for alias in &self.aliases {
+            serde_attrs.push(format!("alias = \"{alias}\""));
+        }
+        if self.other {
+            serde_attrs.push("other".to_owned());
+        }
         if let Some(rename) = self.rename.as_ref() {
             serde_attrs.push(format!("rename = \"{rename}\""));
         }
@@ -320,6 +436,12 @@ impl VariantAttrs {
         }
         serde_attrs
     }
+
+    /// Returns whether the variant should be included in the bindings of the
+    /// generator with the given name.
+    pub fn is_included_in(&self, generator: &str) -> bool {
+        self.generators.is_empty() || self.generators.iter().any(|name| name == generator)
+    }
 }
 
 impl Parse for VariantAttrs {
@@ -341,7 +463,11 @@ impl Parse for VariantAttrs {
         loop {
             let key: Ident = content.call(IdentExt::parse_any)?;
             match key.to_string().as_ref() {
+                "alias" => result.aliases.push(parse_value()?),
+                "generators" => result.generators = parse_generator_names(&parse_value()?),
+                "other" => result.other = true,
                 "rename" => result.rename = Some(parse_value()?),
+                "renamed_from" => result.renamed_from.push(parse_value()?),
                 "rename_all" => {
                     result.field_casing = Casing::try_from(parse_value()?.as_ref())
                         .map_err(|err| Error::new(content.span(), err))?
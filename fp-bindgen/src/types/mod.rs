@@ -1,5 +1,10 @@
 use crate::primitives::Primitive;
-use std::{collections::BTreeMap, hash::Hash};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
 use syn::{Item, TypeParam, TypeParamBound};
 
 mod cargo_dependency;
@@ -11,12 +16,12 @@ mod type_ident;
 pub use cargo_dependency::CargoDependency;
 pub use custom_type::CustomType;
 pub use enums::{Enum, EnumOptions, Variant, VariantAttrs};
-pub use structs::{Field, FieldAttrs, Struct, StructOptions};
+pub use structs::{Field, FieldAttrs, FieldValidation, Struct, StructOptions, ValidationRange};
 pub use type_ident::TypeIdent;
 
 pub type TypeMap = BTreeMap<TypeIdent, Type>;
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Type {
     Alias(String, TypeIdent),
     Array(Primitive, usize),
@@ -45,6 +50,32 @@ impl Type {
         }
     }
 
+    /// Sets the location where the type was declared, for structs and enums.
+    ///
+    /// The `Serializable` derive macro uses this to point diagnostics at the
+    /// declaration of the type.
+    pub fn with_location(mut self, file: &str, line: u32) -> Self {
+        let location = Some(SourceLocation {
+            file: file.to_owned(),
+            line,
+        });
+        match &mut self {
+            Self::Enum(ty) => ty.location = location,
+            Self::Struct(ty) => ty.location = location,
+            _ => {}
+        }
+        self
+    }
+
+    /// Returns the location where the type was declared, if it is known.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            Self::Enum(ty) => ty.location.as_ref(),
+            Self::Struct(ty) => ty.location.as_ref(),
+            _ => None,
+        }
+    }
+
     pub fn name(&self) -> String {
         match self {
             Self::Alias(name, _) => name.clone(),
@@ -70,6 +101,34 @@ impl Type {
     }
 }
 
+/// The location in the source code where a type was declared.
+///
+/// Locations are ignored when types are compared, since moving a declaration
+/// around doesn't change the protocol.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: u32,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+impl PartialEq for SourceLocation {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SourceLocation {}
+
+impl Hash for SourceLocation {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
 pub(crate) fn format_bounds(ty: &TypeParam) -> Vec<String> {
     ty.bounds
         .iter()
@@ -88,6 +147,17 @@ fn path_to_string(path: &syn::Path) -> String {
         .join("::")
 }
 
+/// Parses the comma-separated list of generator names that is given to an
+/// `#[fp(generators = "...")]` attribute.
+pub(crate) fn parse_generator_names(names: &str) -> Vec<String> {
+    names
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
 // Used to remove the 'Serializable' bound from generated types, since this trait only exists in fp-bindgen
 // and doesn't exist at runtime.
 pub(crate) fn is_runtime_bound(bound: &str) -> bool {
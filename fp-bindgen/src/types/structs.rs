@@ -1,19 +1,25 @@
-use super::TypeIdent;
-use crate::types::format_bounds;
+use super::{SourceLocation, TypeIdent};
+use crate::types::{format_bounds, parse_generator_names};
 use crate::{casing::Casing, docs::get_doc_lines};
 use quote::ToTokens;
-use std::convert::TryFrom;
+use serde::{Deserialize, Serialize};
+use std::{convert::TryFrom, fmt::Display, str::FromStr};
 use syn::{
     ext::IdentExt, parenthesized, parse::Parse, parse::ParseStream, Attribute, Error, GenericParam,
     Ident, ItemStruct, LitStr, Result, Token,
 };
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Struct {
     pub ident: TypeIdent,
     pub fields: Vec<Field>,
     pub doc_lines: Vec<String>,
     pub options: StructOptions,
+
+    /// Where the struct was declared, if known. This is not part of the
+    /// serialized protocol.
+    #[serde(default, skip)]
+    pub location: Option<SourceLocation>,
 }
 
 pub(crate) fn parse_struct_item(item: ItemStruct) -> Struct {
@@ -32,7 +38,7 @@ pub(crate) fn parse_struct_item(item: ItemStruct) -> Struct {
             .collect(),
         ..Default::default()
     };
-    let fields = item
+    let fields: Vec<_> = item
         .fields
         .iter()
         .map(|field| Field {
@@ -44,15 +50,38 @@ pub(crate) fn parse_struct_item(item: ItemStruct) -> Struct {
         })
         .collect();
 
+    let options = StructOptions::from_attrs(&item.attrs);
+    // Unknown fields are ignored by default, so non-exhaustive structs only
+    // need to make sure they are not rejected:
+    if options.deny_unknown_fields
+        && item
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("non_exhaustive"))
+    {
+        panic!(
+            "Struct `{}` cannot deny unknown fields, because it is marked as non-exhaustive",
+            ident
+        );
+    }
+    // Serde doesn't support this combination either:
+    if options.deny_unknown_fields && fields.iter().any(|field| field.attrs.flatten) {
+        panic!(
+            "Struct `{}` cannot deny unknown fields, because it has flattened fields",
+            ident
+        );
+    }
+
     Struct {
         ident,
         fields,
         doc_lines: get_doc_lines(&item.attrs),
-        options: StructOptions::from_attrs(&item.attrs),
+        options,
+        location: None,
     }
 }
 
-#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct StructOptions {
     pub field_casing: Casing,
 
@@ -76,6 +105,48 @@ pub struct StructOptions {
     ///
     /// Instead of generating the struct definition itself.
     pub rust_module: Option<String>,
+
+    /// Whether fields that are not part of the struct are rejected when it is
+    /// deserialized, rather than silently ignored.
+    ///
+    /// The TypeScript runtime rejects such fields in values that it receives
+    /// from the plugin, unless the struct is generic.
+    ///
+    /// See also: <https://serde.rs/container-attrs.html#deny_unknown_fields>
+    #[serde(default)]
+    pub deny_unknown_fields: bool,
+
+    /// Whether the TypeScript runtime should generate a branded type for the
+    /// struct, such as `number & { readonly __brand: "UserId" }`, so that it
+    /// cannot be mixed up with other values of its inner type.
+    ///
+    /// Only newtype structs can be branded, and the brand is only generated
+    /// if `generate_branded_types` is enabled for the runtime.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(brand)]
+    /// struct UserId(u32);
+    /// ```
+    #[serde(default)]
+    pub brand: bool,
+
+    /// Whether lists of the struct are encoded as columns, with a contiguous
+    /// array for every field, rather than as a list of separate maps. Every
+    /// use of `Vec<T>` is replaced with a generated `TColumns` struct, whose
+    /// columns are typed arrays in TypeScript and `Vec`s in Rust.
+    ///
+    /// Only structs with named fields of numeric types can be columnar.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(columnar)]
+    /// struct Sample { timestamp: u64, value: f64 }
+    /// ```
+    #[serde(default)]
+    pub columnar: bool,
 }
 
 impl StructOptions {
@@ -98,6 +169,15 @@ impl StructOptions {
         if let Some(other_rust_module) = &other.rust_module {
             self.rust_module = Some(other_rust_module.clone());
         }
+        if other.deny_unknown_fields {
+            self.deny_unknown_fields = true;
+        }
+        if other.brand {
+            self.brand = true;
+        }
+        if other.columnar {
+            self.columnar = true;
+        }
     }
 
     pub fn to_serde_attrs(&self) -> Vec<String> {
@@ -105,6 +185,9 @@ impl StructOptions {
         if let Some(casing) = &self.field_casing.as_maybe_str() {
             serde_attrs.push(format!("rename_all = \"{casing}\""));
         }
+        if self.deny_unknown_fields {
+            serde_attrs.push("deny_unknown_fields".to_owned());
+        }
         serde_attrs
     }
 }
@@ -135,6 +218,15 @@ impl Parse for StructOptions {
                 "rust_module" => {
                     result.rust_module = Some(parse_value()?);
                 }
+                "deny_unknown_fields" => {
+                    result.deny_unknown_fields = true;
+                }
+                "brand" => {
+                    result.brand = true;
+                }
+                "columnar" => {
+                    result.columnar = true;
+                }
                 other => {
                     return Err(Error::new(
                         content.span(),
@@ -154,7 +246,7 @@ impl Parse for StructOptions {
     }
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Field {
     pub name: Option<String>,
     pub ty: TypeIdent,
@@ -162,7 +254,7 @@ pub struct Field {
     pub attrs: FieldAttrs,
 }
 
-#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct FieldAttrs {
     /// Optional path to a function that will produce the default value in case
     /// the field is omitted from the serialized representation.
@@ -183,12 +275,44 @@ pub struct FieldAttrs {
     /// See: <https://serde.rs/attr-flatten.html>
     pub flatten: bool,
 
+    /// Determines whether the field's string is interned.
+    ///
+    /// Plugins register every distinct value of an interned field with the
+    /// runtime once, after which they send a `u32` handle in its place. This
+    /// reduces the size of payloads in which the same strings, such as metric
+    /// names, occur over and over.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(interned)]
+    /// pub metric_name: String,
+    /// ```
+    #[serde(default)]
+    pub interned: bool,
+
     /// Optional name to use in the serialized format
     /// (only used if different than the field name itself).
     ///
     /// See also: <https://serde.rs/field-attrs.html#rename>
     pub rename: Option<String>,
 
+    /// Names under which the field was serialized by previous versions of the
+    /// protocol.
+    ///
+    /// These are not used by the bindings themselves, but the Rust Wasmer
+    /// runtime can generate migrations from them, which upgrade payloads of
+    /// old plugins to the current types.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(renamed_from = "userName")]
+    /// pub display_name: String,
+    /// ```
+    #[serde(default)]
+    pub renamed_from: Vec<String>,
+
     /// Optional Serde dependency used for serialization.
     ///
     /// See also: <https://serde.rs/field-attrs.html#serialize_with>
@@ -202,6 +326,195 @@ pub struct FieldAttrs {
     ///
     /// See also: <https://serde.rs/field-attrs.html#skip_serializing_if>
     pub skip_serializing_if: Option<String>,
+
+    /// Optional TypeScript class that the TypeScript runtime converts the
+    /// field's value into, while it is still serialized as usual.
+    ///
+    /// The only supported value is `"Date"`, which can be used for (optional)
+    /// timestamps that are serialized as RFC3339 strings, such as
+    /// `OffsetDateTime`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(ts_as = "Date")]
+    /// pub created_at: OffsetDateTime,
+    /// ```
+    pub ts_as: Option<String>,
+
+    /// Optional TypeScript type to use for the field, instead of the type
+    /// derived from the Rust type.
+    ///
+    /// This is required for fields with a custom (de)serializer, because the
+    /// wire shape of such fields cannot be derived from their Rust type.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(with = "time::serde::timestamp", ts_type = "number")]
+    /// pub timestamp: OffsetDateTime,
+    /// ```
+    pub ts_type: Option<String>,
+
+    /// Optional rules that the field's value must satisfy, which are enforced
+    /// by the generated bindings when the field is deserialized.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(validate(range = "0..=100"))]
+    /// pub percentage: u8,
+    /// ```
+    #[serde(default)]
+    pub validate: Option<FieldValidation>,
+
+    /// Names of the generators whose bindings should include the field. If
+    /// empty, the field is included in the bindings of all generators.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(generators = "rust-plugin, rust-wasmer-runtime")]
+    /// pub diagnostics: Option<String>,
+    /// ```
+    #[serde(default)]
+    pub generators: Vec<String>,
+}
+
+/// Rules that a field's value must satisfy, as declared through
+/// `#[fp(validate(...))]`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct FieldValidation {
+    /// Range in which a number must lie, such as `0..=100` or `1..`.
+    pub range: Option<ValidationRange>,
+
+    /// Whether a string, list or map must not be empty.
+    pub non_empty: bool,
+
+    /// Regular expression that a string must match.
+    ///
+    /// Both the Rust `regex` crate and JavaScript must be able to parse the
+    /// expression, so it should stick to the syntax they have in common.
+    pub regex: Option<String>,
+}
+
+impl Display for FieldValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut rules = Vec::new();
+        if let Some(range) = &self.range {
+            rules.push(format!("range = \"{range}\""));
+        }
+        if self.non_empty {
+            rules.push("non_empty".to_owned());
+        }
+        if let Some(regex) = &self.regex {
+            rules.push(format!("regex = {regex:?}"));
+        }
+        write!(f, "validate({})", rules.join(", "))
+    }
+}
+
+impl Parse for FieldValidation {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+
+        let mut result = Self::default();
+        loop {
+            let key: Ident = content.call(IdentExt::parse_any)?;
+            match key.to_string().as_ref() {
+                "non_empty" => result.non_empty = true,
+                "range" => {
+                    content.parse::<Token![=]>()?;
+                    let value = content.parse::<LitStr>()?;
+                    result.range = Some(
+                        value
+                            .value()
+                            .parse()
+                            .map_err(|message| Error::new(value.span(), message))?,
+                    );
+                }
+                "regex" => {
+                    content.parse::<Token![=]>()?;
+                    result.regex = Some(content.parse::<LitStr>()?.value());
+                }
+                other => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!("Unexpected validation rule: {other}"),
+                    ))
+                }
+            }
+
+            if content.is_empty() {
+                break;
+            }
+
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// A range of numbers, written using Rust's range syntax. Either bound may be
+/// omitted, and the upper bound is exclusive unless written as `..=`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ValidationRange {
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub inclusive: bool,
+}
+
+impl Display for ValidationRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.start.as_deref().unwrap_or_default(),
+            if self.inclusive { "..=" } else { ".." },
+            self.end.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+impl FromStr for ValidationRange {
+    type Err = String;
+
+    fn from_str(range: &str) -> std::result::Result<Self, Self::Err> {
+        let (start, end, inclusive) = match range.split_once("..=") {
+            Some((start, end)) => (start, end, true),
+            None => match range.split_once("..") {
+                Some((start, end)) => (start, end, false),
+                None => return Err(format!("Invalid range: {range}")),
+            },
+        };
+
+        let parse_bound = |bound: &str| -> std::result::Result<Option<String>, String> {
+            let bound = bound.trim();
+            if bound.is_empty() {
+                Ok(None)
+            } else if bound.parse::<f64>().is_ok() {
+                Ok(Some(bound.to_owned()))
+            } else {
+                Err(format!("Invalid bound in range `{range}`: {bound}"))
+            }
+        };
+
+        let (start, end) = (parse_bound(start)?, parse_bound(end)?);
+        if inclusive && end.is_none() {
+            return Err(format!("Inclusive range `{range}` requires an upper bound"));
+        }
+        if start.is_none() && end.is_none() {
+            return Err(format!("Range `{range}` requires at least one bound"));
+        }
+
+        Ok(Self {
+            start,
+            end,
+            inclusive,
+        })
+    }
 }
 
 impl FieldAttrs {
@@ -228,15 +541,46 @@ impl FieldAttrs {
         if other.flatten {
             self.flatten = other.flatten;
         }
+        if other.interned {
+            self.interned = other.interned;
+        }
         if other.rename.is_some() {
             self.rename = other.rename.clone();
         }
+        for name in &other.renamed_from {
+            if !self.renamed_from.contains(name) {
+                self.renamed_from.push(name.clone());
+            }
+        }
         if other.serialize_with.is_some() {
             self.serialize_with = other.serialize_with.clone();
         }
         if other.skip_serializing_if.is_some() {
             self.skip_serializing_if = other.skip_serializing_if.clone();
         }
+        if other.ts_as.is_some() {
+            self.ts_as = other.ts_as.clone();
+        }
+        if other.ts_type.is_some() {
+            self.ts_type = other.ts_type.clone();
+        }
+        if other.validate.is_some() {
+            self.validate = other.validate.clone();
+        }
+        if !other.generators.is_empty() {
+            self.generators = other.generators.clone();
+        }
+    }
+
+    /// Returns whether the field should be included in the bindings of the
+    /// generator with the given name.
+    pub fn is_included_in(&self, generator: &str) -> bool {
+        self.generators.is_empty() || self.generators.iter().any(|name| name == generator)
+    }
+
+    /// Returns whether the field uses a custom (de)serializer.
+    pub fn has_custom_serializer(&self) -> bool {
+        self.deserialize_with.is_some() || self.serialize_with.is_some()
     }
 
     pub fn to_serde_attrs(&self) -> Vec<String> {
@@ -264,6 +608,9 @@ impl FieldAttrs {
             (None, Some(serialize_with)) => {
                 serde_attrs.push(format!("serialize_with = \"{serialize_with}\""));
             }
+            (None, None) if self.interned => {
+                serde_attrs.push("with = \"fp_bindgen_support::common::interning\"".to_owned());
+            }
             (None, None) => {}
         }
         if self.flatten {
@@ -309,9 +656,15 @@ impl Parse for FieldAttrs {
                 "default" => result.default = Some(parse_optional_value()?),
                 "deserialize_with" => result.deserialize_with = Some(parse_value()?),
                 "flatten" => result.flatten = true,
+                "generators" => result.generators = parse_generator_names(&parse_value()?),
+                "interned" => result.interned = true,
                 "rename" => result.rename = Some(parse_value()?),
+                "renamed_from" => result.renamed_from.push(parse_value()?),
                 "serialize_with" => result.serialize_with = Some(parse_value()?),
                 "skip_serializing_if" => result.skip_serializing_if = Some(parse_value()?),
+                "ts_as" => result.ts_as = Some(parse_value()?),
+                "ts_type" => result.ts_type = Some(parse_value()?),
+                "validate" => result.validate = Some(content.parse()?),
                 "with" => {
                     let value = parse_value()?;
                     result.deserialize_with = Some(value.clone());
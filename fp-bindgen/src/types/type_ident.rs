@@ -1,10 +1,15 @@
 use super::is_runtime_bound;
 use crate::primitives::Primitive;
+use serde::{Deserialize, Serialize};
 use std::num::NonZeroUsize;
 use std::{convert::TryFrom, fmt::Display, str::FromStr};
 use syn::{PathArguments, TypeParamBound, TypePath, TypeTuple};
 
-#[derive(Clone, Default, Debug, Eq, Hash, PartialEq)]
+/// The path to which all `PhantomData` identifiers are normalized, so that the
+/// Rust bindings don't need to import it.
+const PHANTOM_DATA: &str = "std::marker::PhantomData";
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
 pub struct TypeIdent {
     pub name: String,
@@ -38,6 +43,90 @@ impl TypeIdent {
         }
     }
 
+    /// Returns the element types if this is a pair of primitives that fit in
+    /// 32 bits. Such pairs are returned packed into a single `u64`.
+    pub fn as_packed_pair(&self) -> Option<(Primitive, Primitive)> {
+        if self.array.is_some() {
+            return None;
+        }
+
+        let (first, second) = self
+            .name
+            .strip_prefix('(')?
+            .strip_suffix(')')?
+            .split_once(',')?;
+        let first = Primitive::from_str(first.trim()).ok()?;
+        let second = Primitive::from_str(second.trim()).ok()?;
+        if first.is_packable() && second.is_packable() {
+            Some((first, second))
+        } else {
+            None
+        }
+    }
+
+    pub fn is_packed_pair(&self) -> bool {
+        self.as_packed_pair().is_some()
+    }
+
+    /// Returns the item types if this is a tuple, such as `(String, Vec<u32>)`.
+    pub fn tuple_items(&self) -> Option<Vec<TypeIdent>> {
+        if self.array.is_some() || self.name == "()" {
+            return None;
+        }
+
+        let inner = self.name.strip_prefix('(')?.strip_suffix(')')?;
+        let mut items = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+        for (i, c) in inner.char_indices() {
+            match c {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    items.push(&inner[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        items.push(&inner[start..]);
+        items
+            .into_iter()
+            .map(|item| Self::from_str(item.trim()).ok())
+            .collect()
+    }
+
+    /// Returns whether this is a `PhantomData` marker. Fields of this type are
+    /// not part of the serialized representation, so they are only included
+    /// in the Rust bindings, where they keep their type parameter in use.
+    pub fn is_phantom_data(&self) -> bool {
+        self.name == PHANTOM_DATA && self.array.is_none()
+    }
+
+    /// Returns whether this identifier, or any of its generic arguments, is
+    /// one of the given names.
+    pub fn contains_any_of(&self, names: &[String]) -> bool {
+        (self.generic_args.is_empty() && self.array.is_none() && names.contains(&self.name))
+            || self
+                .generic_args
+                .iter()
+                .any(|(arg, _)| arg.contains_any_of(names))
+    }
+
+    /// Returns the identifier with every occurrence of the given names
+    /// replaced by `replacement`.
+    pub fn replace_any_of(&self, names: &[String], replacement: &TypeIdent) -> Self {
+        if self.generic_args.is_empty() && self.array.is_none() && names.contains(&self.name) {
+            return replacement.clone();
+        }
+
+        let mut ident = self.clone();
+        for (arg, _) in ident.generic_args.iter_mut() {
+            *arg = arg.replace_any_of(names, replacement);
+        }
+        ident
+    }
+
     pub fn format(&self, include_bounds: bool) -> String {
         let ty = if self.generic_args.is_empty() {
             self.name.clone()
@@ -102,7 +191,7 @@ impl FromStr for TypeIdent {
                 .split(';')
                 .collect::<Vec<_>>();
 
-            let element = split[0].trim();
+            let element = normalize_name(split[0].trim());
             let len = usize::from_str(split[1].trim())
                 .map_err(|_| format!("Invalid array length in: {string}"))?;
 
@@ -118,15 +207,38 @@ impl FromStr for TypeIdent {
             (string, None)
         };
 
+        if string.starts_with('(') && string != "()" {
+            // Normalize the whitespace, so tuples from stringified tokens
+            // match those from `Serializable` implementations.
+            let ident = Self::from(format!(
+                "({})",
+                string
+                    .trim_start_matches('(')
+                    .trim_end_matches(')')
+                    .split(',')
+                    .map(|item| normalize_name(item.trim()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            return if ident.is_packed_pair() {
+                Ok(ident)
+            } else {
+                Err(format!(
+                    "Only pairs of primitives up to 32 bits are supported as tuples, found: {string}"
+                ))
+            };
+        }
+
         if let Some(start_index) = string.find('<') {
             let end_index = string.rfind('>').unwrap_or(string.len());
             Ok(Self {
-                name: string[0..start_index]
-                    .trim_end_matches(|c: char| c.is_whitespace() || c == ':')
-                    .to_owned(),
+                name: normalize_name(
+                    string[0..start_index]
+                        .trim_end_matches(|c: char| c.is_whitespace() || c == ':'),
+                )
+                .to_owned(),
                 generic_args: string[start_index + 1..end_index]
                     .split(',')
-                    .into_iter()
                     .map(|arg| {
                         let (arg, bounds) = arg.split_once(':').unwrap_or((arg, ""));
                         let ident = Self::from_str(arg.trim());
@@ -142,7 +254,7 @@ impl FromStr for TypeIdent {
             })
         } else {
             Ok(Self {
-                name: string.into(),
+                name: normalize_name(string).into(),
                 generic_args: vec![],
                 array,
             })
@@ -171,10 +283,7 @@ impl Ord for TypeIdent {
 
 impl PartialOrd for TypeIdent {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        // We only compare the name and array so that any type is only included once in
-        // a map, regardless of how many concrete instances are used with
-        // different generic arguments.
-        (&self.name, self.array).partial_cmp(&(&other.name, other.array))
+        Some(self.cmp(other))
     }
 }
 
@@ -244,7 +353,7 @@ impl TryFrom<&syn::Type> for TypeIdent {
                 }
 
                 Ok(Self {
-                    name: path_to_string(path),
+                    name: normalize_name(&path_to_string(path)).to_owned(),
                     generic_args,
                     ..Default::default()
                 })
@@ -253,11 +362,38 @@ impl TryFrom<&syn::Type> for TypeIdent {
                 elems,
                 paren_token: _,
             }) if elems.is_empty() => Ok(TypeIdent::from("()")),
+            syn::Type::Tuple(TypeTuple { elems, .. }) => {
+                let elems = elems
+                    .iter()
+                    .map(TypeIdent::try_from)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Self::from_str(&format!(
+                    "({})",
+                    elems
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            }
             ty => Err(format!("Unsupported type: {ty:?}")),
         }
     }
 }
 
+/// Maps the names of types that differ in size between `wasm32` and 64-bit
+/// hosts to the names of the types they are exchanged as.
+fn normalize_name(name: &str) -> &str {
+    match name {
+        "usize" => "u32",
+        "isize" => "i32",
+        "NonZeroUsize" => "NonZeroU32",
+        "NonZeroIsize" => "NonZeroI32",
+        "PhantomData" | "marker::PhantomData" | "core::marker::PhantomData" => PHANTOM_DATA,
+        name => name,
+    }
+}
+
 fn path_to_string(path: &syn::Path) -> String {
     path.segments
         .iter()
@@ -342,6 +478,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn type_ident_normalizes_pointer_sized_integers() {
+        let ty = syn::parse_str::<syn::Type>("Vec<usize>").unwrap();
+        let t = TypeIdent::try_from(&ty).unwrap();
+        assert_eq!(
+            t.generic_args,
+            vec![(TypeIdent::new("u32", vec![]), vec![])]
+        );
+
+        let ty = syn::parse_str::<syn::Type>("NonZeroIsize").unwrap();
+        assert_eq!(TypeIdent::try_from(&ty).unwrap().name, "NonZeroI32");
+
+        assert_eq!(TypeIdent::from_str("isize").unwrap().name, "i32");
+        assert_eq!(TypeIdent::from_str("[usize; 4]").unwrap().name, "u32");
+        assert_eq!(
+            TypeIdent::from_str("(usize, isize)").unwrap().name,
+            "(u32, i32)"
+        );
+    }
+
     #[test]
     fn type_ident_from_str_array() {
         let t = TypeIdent::from_str("[u32; 8]").unwrap();
@@ -358,4 +514,61 @@ mod tests {
         // Unsupported primitive array types
         assert!(TypeIdent::from_str("[u64; 8]").is_err());
     }
+
+    #[test]
+    fn type_ident_packed_pair() {
+        let ty = syn::parse_str::<syn::Type>("(u32, i32)").unwrap();
+        let t = TypeIdent::try_from(&ty).unwrap();
+        assert_eq!(t.name, "(u32, i32)");
+        assert_eq!(t.as_packed_pair(), Some((Primitive::U32, Primitive::I32)));
+
+        let t = TypeIdent::from_str("(bool , f32)").unwrap();
+        assert_eq!(t.name, "(bool, f32)");
+        assert_eq!(t.as_packed_pair(), Some((Primitive::Bool, Primitive::F32)));
+
+        assert!(!TypeIdent::from_str("()").unwrap().is_packed_pair());
+        assert!(!TypeIdent::from_str("u32").unwrap().is_packed_pair());
+
+        // Only pairs of primitives up to 32 bits can be packed
+        assert!(TypeIdent::from_str("(u32, u64)").is_err());
+        assert!(TypeIdent::from_str("(u32, i32, u8)").is_err());
+        assert!(TypeIdent::from_str("(String, u32)").is_err());
+    }
+
+    #[test]
+    fn type_ident_replace_generic_params() {
+        let params = vec!["T".to_owned()];
+        let unknown = TypeIdent::from("unknown");
+
+        let t = TypeIdent::from_str("HashMap<String, Vec<T>>").unwrap();
+        assert!(t.contains_any_of(&params));
+        assert_eq!(
+            t.replace_any_of(&params, &unknown).to_string(),
+            "HashMap<String, Vec<unknown>>"
+        );
+
+        let t = TypeIdent::from_str("T").unwrap();
+        assert_eq!(t.replace_any_of(&params, &unknown), unknown);
+
+        // Only exact matches are replaced:
+        let t = TypeIdent::from_str("Option<Tag>").unwrap();
+        assert!(!t.contains_any_of(&params));
+        assert_eq!(t.replace_any_of(&params, &unknown), t);
+    }
+
+    #[test]
+    fn type_ident_normalizes_phantom_data() {
+        for path in [
+            "PhantomData<T>",
+            "std::marker::PhantomData<T>",
+            "core::marker::PhantomData<T>",
+        ] {
+            let ty = syn::parse_str::<syn::Type>(path).unwrap();
+            let t = TypeIdent::try_from(&ty).unwrap();
+            assert!(t.is_phantom_data());
+            assert_eq!(t.to_string(), "std::marker::PhantomData<T>");
+        }
+
+        assert!(!TypeIdent::from("Data").is_phantom_data());
+    }
 }
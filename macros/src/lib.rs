@@ -1,5 +1,6 @@
 use crate::{primitives::Primitive, utils::extract_path_from_type};
 use proc_macro::{TokenStream, TokenTree};
+use proc_macro2::{Ident, Span};
 use proc_macro_error::{abort, proc_macro_error, ResultExt};
 use quote::{format_ident, quote, ToTokens};
 use std::{
@@ -7,10 +8,10 @@ use std::{
     iter::once,
 };
 use syn::{
-    AttributeArgs, FnArg, ForeignItemFn, GenericParam, ItemFn, ItemType, ItemUse, Pat, PatPath,
-    Path, PathArguments, PathSegment, ReturnType,
+    AttributeArgs, FnArg, ForeignItemFn, GenericParam, ItemFn, ItemTrait, ItemType, ItemUse, Pat,
+    PatPath, Path, PathArguments, PathSegment, ReturnType, TraitItem, TraitItemType, Visibility,
 };
-use utils::{flatten_using_statement, normalize_return_type};
+use utils::{erase_generic_params, flatten_using_statement, mentions_ident, normalize_return_type};
 
 mod primitives;
 mod serializable;
@@ -23,66 +24,203 @@ pub fn derive_serializable(item: TokenStream) -> TokenStream {
     crate::serializable::impl_derive_serializable(item)
 }
 
+/// Registers a type from another crate, which cannot derive `Serializable`
+/// itself, by declaring its serialized fields.
+///
+/// The declaration is written like a type that derives `Serializable`, with
+/// an `#[fp(remote = "...")]` attribute that gives the path of the remote
+/// type. Its name must match that of the remote type. The Rust generators
+/// import the remote type from its module, while the other generators use the
+/// declared fields:
+///
+/// ```ignore
+/// serializable_remote! {
+///     #[fp(remote = "geo_crate::GeoPoint")]
+///     #[serde(rename_all = "camelCase")]
+///     pub struct GeoPoint {
+///         pub latitude: f64,
+///         pub longitude: f64,
+///     }
+/// }
+/// ```
+#[proc_macro]
+#[proc_macro_error]
+pub fn serializable_remote(item: TokenStream) -> TokenStream {
+    crate::serializable::impl_serializable_remote(item)
+}
+
 /// Declares functions the plugin can import from the host runtime.
 #[proc_macro]
 pub fn fp_import(token_stream: TokenStream) -> TokenStream {
-    let ParsedStatements {
-        functions,
-        collectable_types,
-        aliases,
-    } = parse_statements(token_stream);
-    let collectable_types = collectable_types.iter();
-    let alias_keys = aliases.keys();
-    let alias_paths = aliases
-        .values()
-        .map(|path| path.to_token_stream().to_string());
+    declare_functions(
+        format_ident!("__fp_declare_import_fns"),
+        parse_statements(token_stream),
+    )
+    .into()
+}
 
-    let replacement = quote! {
-        fn __fp_declare_import_fns() -> (fp_bindgen::prelude::FunctionList, fp_bindgen::prelude::TypeMap) {
-            let mut import_types = fp_bindgen::prelude::TypeMap::new();
-            #( #collectable_types::collect_types(&mut import_types); )*
-            #( import_types.insert(TypeIdent::from(#alias_keys), Type::Alias(#alias_keys.to_owned(), std::str::FromStr::from_str(#alias_paths).unwrap())); )*
+/// Declares functions the plugin may export to the host runtime.
+#[proc_macro]
+pub fn fp_export(token_stream: TokenStream) -> TokenStream {
+    declare_functions(
+        format_ident!("__fp_declare_export_fns"),
+        parse_statements(token_stream),
+    )
+    .into()
+}
 
-            let mut list = fp_bindgen::prelude::FunctionList::new();
-            #( list.add_function(#functions); )*
+/// Declares the whole protocol as a trait, instead of using separate
+/// `fp_import!{}` and `fp_export!{}` blocks, which it replaces.
+///
+/// Every method is marked with either `#[import]` or `#[export]`, from the
+/// perspective of the plugin. `#[fp(...)]` attributes on the trait apply to
+/// all of its methods, unless a method overrides them, and the type parameters
+/// of the trait are shared by the methods that use them. Associated types with
+/// a default are declared as aliases, just like `type` items in the blocks.
+///
+/// ```no_compile
+/// #[fp_protocol_trait]
+/// trait Provider<T> {
+///     /// Fetches the value for the given key.
+///     #[export]
+///     async fn fetch(key: String) -> Option<T>;
+///
+///     #[import]
+///     fn log(message: String);
+/// }
+/// ```
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn fp_protocol_trait(attributes: TokenStream, input: TokenStream) -> TokenStream {
+    if let Some(token) = attributes.into_iter().next() {
+        abort!(
+            proc_macro2::TokenStream::from(TokenStream::from(token)),
+            "`fp_protocol_trait` does not take any arguments"
+        );
+    }
+
+    let item = syn::parse::<ItemTrait>(input).unwrap_or_abort();
 
-            (list, import_types)
+    // Attributes on the trait are merged into those of every method, so that
+    // the ones on the method take precedence:
+    let default_attrs = item
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("fp"))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut imports = ParsedStatements::default();
+    let mut exports = ParsedStatements::default();
+    for trait_item in item.items {
+        match trait_item {
+            TraitItem::Method(method) => {
+                if let Some(default) = &method.default {
+                    abort!(
+                        default,
+                        "Protocol methods cannot have a default implementation"
+                    );
+                }
+                if let Some(receiver) = method.sig.receiver() {
+                    abort!(receiver, "Protocol methods cannot take `self`");
+                }
+
+                let is_import = method.attrs.iter().any(|attr| attr.path.is_ident("import"));
+                let is_export = method.attrs.iter().any(|attr| attr.path.is_ident("export"));
+                let statements = match (is_import, is_export) {
+                    (true, false) => &mut imports,
+                    (false, true) => &mut exports,
+                    _ => abort!(
+                        method.sig.ident,
+                        "Protocol method `{}` must be marked with either `#[import]` or `#[export]`",
+                        method.sig.ident
+                    ),
+                };
+
+                let mut sig = method.sig;
+                let shared_params = item
+                    .generics
+                    .params
+                    .iter()
+                    .filter(|param| match param {
+                        GenericParam::Type(param) => {
+                            mentions_ident(sig.inputs.to_token_stream(), &param.ident)
+                                || mentions_ident(sig.output.to_token_stream(), &param.ident)
+                        }
+                        other => abort!(other, "Only type parameters are supported"),
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                sig.generics.params = shared_params
+                    .into_iter()
+                    .chain(sig.generics.params)
+                    .collect();
+
+                statements.add_function(ForeignItemFn {
+                    attrs: default_attrs
+                        .iter()
+                        .cloned()
+                        .chain(method.attrs.into_iter().filter(|attr| {
+                            !attr.path.is_ident("import") && !attr.path.is_ident("export")
+                        }))
+                        .collect(),
+                    vis: Visibility::Inherited,
+                    sig,
+                    semi_token: method.semi_token.unwrap_or_default(),
+                });
+            }
+            TraitItem::Type(TraitItemType {
+                ident,
+                default: Some((_, ty)),
+                ..
+            }) => imports.add_alias(&ident, &ty),
+            other => abort!(
+                other,
+                "Only methods and associated types with a default are supported in protocol traits"
+            ),
         }
-    };
-    replacement.into()
+    }
+
+    let import_fns = declare_functions(format_ident!("__fp_declare_import_fns"), imports);
+    let export_fns = declare_functions(format_ident!("__fp_declare_export_fns"), exports);
+    (quote! {
+        #import_fns
+        #export_fns
+    })
+    .into()
 }
 
-/// Declares functions the plugin may export to the host runtime.
-#[proc_macro]
-pub fn fp_export(token_stream: TokenStream) -> TokenStream {
+/// Generates the function with the given name, which returns the declared
+/// functions together with the types they use.
+fn declare_functions(name: Ident, statements: ParsedStatements) -> proc_macro2::TokenStream {
     let ParsedStatements {
         functions,
         collectable_types,
         aliases,
-    } = parse_statements(token_stream);
+    } = statements;
     let collectable_types = collectable_types.iter();
     let alias_keys = aliases.keys();
     let alias_paths = aliases
         .values()
         .map(|path| path.to_token_stream().to_string());
 
-    let replacement = quote! {
-        fn __fp_declare_export_fns() -> (fp_bindgen::prelude::FunctionList, fp_bindgen::prelude::TypeMap) {
-            let mut export_types = fp_bindgen::prelude::TypeMap::new();
-            #( #collectable_types::collect_types(&mut export_types); )*
-            #( export_types.insert(TypeIdent::from(#alias_keys), Type::Alias(#alias_keys.to_owned(), std::str::FromStr::from_str(#alias_paths).unwrap())); )*
+    quote! {
+        fn #name() -> (fp_bindgen::prelude::FunctionList, fp_bindgen::prelude::TypeMap) {
+            let mut types = fp_bindgen::prelude::TypeMap::new();
+            #( #collectable_types::collect_types(&mut types); )*
+            #( types.insert(TypeIdent::from(#alias_keys), Type::Alias(#alias_keys.to_owned(), std::str::FromStr::from_str(#alias_paths).unwrap())); )*
 
             let mut list = fp_bindgen::prelude::FunctionList::new();
             #( list.add_function(#functions); )*
 
-            (list, export_types)
+            (list, types)
         }
-    };
-    replacement.into()
+    }
 }
 
 /// Contains all the relevant information extracted from inside the `fp_import!` and `fp_export!`
 /// macros.
+#[derive(Default)]
 struct ParsedStatements {
     pub functions: Vec<String>,
     pub collectable_types: HashSet<CollectableTypeDefinition>,
@@ -91,19 +229,19 @@ struct ParsedStatements {
 
 /// A type definition on which we can call ::collect_types()
 #[derive(Debug, Eq, PartialEq, Hash)]
-struct CollectableTypeDefinition {
-    pub path: Path,
-    pub array_len: usize,
+enum CollectableTypeDefinition {
+    Path { path: Path, array_len: usize },
+    Tuple(Vec<CollectableTypeDefinition>),
 }
 
 impl ToTokens for CollectableTypeDefinition {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let path = &self.path;
-        if self.array_len > 0 {
-            let len = self.array_len;
-            tokens.extend(quote! { <[#path; #len]> })
-        } else {
-            tokens.extend(quote! { #path })
+        match self {
+            Self::Path { path, array_len } if *array_len > 0 => {
+                tokens.extend(quote! { <[#path; #array_len]> })
+            }
+            Self::Path { path, .. } => tokens.extend(quote! { #path }),
+            Self::Tuple(elems) => tokens.extend(quote! { <(#(#elems),*)> }),
         }
     }
 }
@@ -114,9 +252,7 @@ impl ToTokens for CollectableTypeDefinition {
 /// to call the functions, and one with all the paths for types that may need deserialization to
 /// call the functions.
 fn parse_statements(token_stream: TokenStream) -> ParsedStatements {
-    let mut functions = Vec::new();
-    let mut collectable_types = HashSet::new();
-    let mut aliases = HashMap::new();
+    let mut statements = ParsedStatements::default();
 
     let mut current_item_tokens = Vec::<TokenTree>::new();
     for token in token_stream.into_iter() {
@@ -127,52 +263,15 @@ fn parse_statements(token_stream: TokenStream) -> ParsedStatements {
                 let stream = current_item_tokens.into_iter().collect::<TokenStream>();
 
                 if let Ok(function) = syn::parse::<ForeignItemFn>(stream.clone()) {
-                    for input in &function.sig.inputs {
-                        match input {
-                            FnArg::Receiver(_) => panic!(
-                                "Methods are not supported. Found `self` in function declaration: {:?}",
-                                function.sig
-                            ),
-                            FnArg::Typed(arg) => {
-                                collectable_types.insert(
-                                    extract_path_from_type(arg.ty.as_ref()).unwrap_or_else(|| {
-                                        panic!(
-                                            "Only value types are supported. \
-                                                Incompatible argument type in function declaration: {:?}",
-                                            function.sig
-                                        )
-                                    }),
-                                );
-                            }
-                        }
-                    }
-
-                    if let Some(ty) = normalize_return_type(&function.sig.output) {
-                        collectable_types.insert(extract_path_from_type(ty).unwrap_or_else(|| {
-                            panic!(
-                                "Only value types are supported. \
-                                            Incompatible return type in function declaration: {:?}",
-                                function.sig
-                            )
-                        }));
-                    }
-
-                    functions.push(function.into_token_stream().to_string());
+                    statements.add_function(function);
                 } else if let Ok(using) = syn::parse::<ItemUse>(stream.clone()) {
                     for path in flatten_using_statement(using) {
-                        collectable_types.insert(CollectableTypeDefinition { path, array_len: 0 });
+                        statements
+                            .collectable_types
+                            .insert(CollectableTypeDefinition::Path { path, array_len: 0 });
                     }
                 } else if let Ok(type_alias) = syn::parse::<ItemType>(stream) {
-                    aliases.insert(
-                        type_alias.ident.to_string(),
-                        extract_path_from_type(type_alias.ty.as_ref()).unwrap_or_else(|| {
-                            panic!(
-                                "Only value types are supported. \
-                                    Incompatible type in alias: {:?}",
-                                type_alias
-                            )
-                        }),
-                    );
+                    statements.add_alias(&type_alias.ident, type_alias.ty.as_ref());
                 }
 
                 current_item_tokens = Vec::new();
@@ -181,17 +280,79 @@ fn parse_statements(token_stream: TokenStream) -> ParsedStatements {
         }
     }
 
-    ParsedStatements {
-        functions,
-        collectable_types,
-        aliases,
+    statements
+}
+
+impl ParsedStatements {
+    /// Adds a function declaration, together with the types of its arguments
+    /// and return value.
+    fn add_function(&mut self, function: ForeignItemFn) {
+        // Type parameters are picked by the plugin, so only the types around
+        // them are collected:
+        let generic_params = function
+            .sig
+            .generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect::<Vec<_>>();
+
+        for input in &function.sig.inputs {
+            match input {
+                FnArg::Receiver(_) => panic!(
+                    "Methods are not supported. Found `self` in function declaration: {:?}",
+                    function.sig
+                ),
+                FnArg::Typed(arg) => {
+                    if let Some(ty) = erase_generic_params(&arg.ty, &generic_params) {
+                        self.collectable_types
+                            .insert(extract_path_from_type(&ty).unwrap_or_else(|| {
+                                panic!(
+                                    "Only value types are supported. \
+                                        Incompatible argument type in function declaration: {:?}",
+                                    function.sig
+                                )
+                            }));
+                    }
+                }
+            }
+        }
+
+        if let Some(ty) = normalize_return_type(&function.sig.output)
+            .and_then(|ty| erase_generic_params(ty, &generic_params))
+        {
+            self.collectable_types
+                .insert(extract_path_from_type(&ty).unwrap_or_else(|| {
+                    panic!(
+                        "Only value types are supported. \
+                            Incompatible return type in function declaration: {:?}",
+                        function.sig
+                    )
+                }));
+        }
+
+        self.functions
+            .push(function.into_token_stream().to_string());
+    }
+
+    fn add_alias(&mut self, ident: &Ident, ty: &syn::Type) {
+        self.aliases.insert(
+            ident.to_string(),
+            extract_path_from_type(ty).unwrap_or_else(|| {
+                panic!(
+                    "Only value types are supported. \
+                        Incompatible type in alias: {}",
+                    ty.to_token_stream()
+                )
+            }),
+        );
     }
 }
 
 /// Generates bindings for the functions declared in the `fp_import!{}` and `fp_export!{}` blocks.
 #[proc_macro]
+#[proc_macro_error]
 pub fn fp_bindgen(args: TokenStream) -> TokenStream {
-    let args: proc_macro2::TokenStream = args.into();
+    let args = construct_binding_config(args.into());
     let replacement = quote! {
         let (import_functions, import_types) = __fp_declare_import_fns();
         let (export_functions, mut export_types) = __fp_declare_export_fns();
@@ -209,6 +370,90 @@ pub fn fp_bindgen(args: TokenStream) -> TokenStream {
     replacement.into()
 }
 
+/// `BindingConfig` is `#[non_exhaustive]`, so it cannot be constructed using a
+/// struct expression outside of `fp-bindgen`. Such expressions are turned into
+/// a call to `BindingConfig::new()`, after which the other fields that are
+/// given are assigned, so that the remaining fields keep their defaults.
+///
+/// Any other expression is passed through as it is.
+fn construct_binding_config(args: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let config = match syn::parse2::<syn::Expr>(args.clone()) {
+        Ok(syn::Expr::Struct(config))
+            if config.rest.is_none()
+                && config
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident == "BindingConfig")
+                    .unwrap_or(false) =>
+        {
+            config
+        }
+        _ => return args,
+    };
+
+    // The fields are evaluated in source order before the config is created,
+    // and bound to `mixed_site()` identifiers, so the field expressions cannot
+    // observe or shadow the bindings that are introduced here.
+    let temporaries: Vec<_> = (0..config.fields.len())
+        .map(|index| Ident::new(&format!("field_{}", index), Span::mixed_site()))
+        .collect();
+    let field_temporary = |name: &str| {
+        config
+            .fields
+            .iter()
+            .position(|field| matches!(&field.member, syn::Member::Named(ident) if ident == name))
+            .map(|index| &temporaries[index])
+            .unwrap_or_else(|| abort!(config, "BindingConfig requires a `{}` field", name))
+    };
+    let path = &config.path;
+    let bindings_type = field_temporary("bindings_type");
+    let output_path = field_temporary("path");
+    let evaluations = config
+        .fields
+        .iter()
+        .zip(&temporaries)
+        .map(|(field, temporary)| {
+            let expr = &field.expr;
+            quote! { let #temporary = #expr; }
+        });
+    let config_ident = Ident::new("config", Span::mixed_site());
+    let assignments = config
+        .fields
+        .iter()
+        .zip(&temporaries)
+        .filter_map(|(field, temporary)| match &field.member {
+            syn::Member::Named(ident) if ident == "bindings_type" || ident == "path" => None,
+            member => Some(quote! { #config_ident.#member = #temporary; }),
+        });
+    quote! {
+        {
+            #(#evaluations)*
+            let mut #config_ident = #path::new(#bindings_type, #output_path);
+            #(#assignments)*
+            #config_ident
+        }
+    }
+}
+
+/// Captures the protocol declared in the `fp_import!{}` and `fp_export!{}`
+/// blocks, so it can be snapshotted and checked for breaking changes.
+#[proc_macro]
+pub fn fp_protocol(_: TokenStream) -> TokenStream {
+    let replacement = quote! {
+        {
+            let (import_functions, import_types) = __fp_declare_import_fns();
+            let (export_functions, mut export_types) = __fp_declare_export_fns();
+
+            let mut types = import_types;
+            types.append(&mut export_types);
+
+            fp_bindgen::Protocol::new(import_functions, export_functions, types)
+        }
+    };
+    replacement.into()
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn primitive_impls(_: TokenStream) -> TokenStream {
@@ -228,7 +473,7 @@ pub fn primitive_impls(_: TokenStream) -> TokenStream {
 
     let mut token_stream = TokenStream::new();
     for primitive in primitives {
-        token_stream.extend(primitive.gen_impl().into_iter());
+        token_stream.extend(primitive.gen_impl());
     }
     token_stream
 }
@@ -237,12 +482,37 @@ pub fn primitive_impls(_: TokenStream) -> TokenStream {
 /// This is not meant to be used directly.
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> TokenStream {
+pub fn fp_export_signature(attributes: TokenStream, input: TokenStream) -> TokenStream {
     proc_macro_error::set_dummy(input.clone().into());
 
     let func = syn::parse_macro_input::parse::<ForeignItemFn>(input.clone()).unwrap_or_abort();
     let args = typing::extract_args(&func.sig).collect::<Vec<_>>();
 
+    let attributes = syn::parse_macro_input::parse::<AttributeArgs>(attributes).unwrap_or_abort();
+    let mut stores_config = false;
+    for attr in attributes {
+        match attr {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("config") => {
+                stores_config = true
+            }
+            // The wire name is only used by `fp_export_impl`:
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { path, .. }))
+                if path.is_ident("rename") => {}
+            other => abort!(
+                other,
+                "unsupported attribute, only `config` and `rename` are supported"
+            ),
+        }
+    }
+
+    // Complex return values are only serialized, so the implementation may
+    // return anything that borrows as the declared type, such as `&'static T`
+    // or `Cow<'static, T>`, instead of cloning into an owned value.
+    let returns_packed_pair = typing::is_ret_type_packed_pair(&func.sig);
+    let borrows_return_value = func.sig.asyncness.is_none()
+        && !returns_packed_pair
+        && typing::is_ret_type_complex(&func.sig.output);
+
     let mut sig = func.sig.clone();
     //Massage the signature into what we wish to export
     {
@@ -255,6 +525,8 @@ pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> Toke
                 let input_types = args.iter().map(|(_, pt, _)| pt.ty.as_ref());
                 let output = if func.sig.asyncness.is_some() {
                     syn::parse::<ReturnType>((quote! {-> FUT}).into()).unwrap_or_abort()
+                } else if borrows_return_value {
+                    syn::parse::<ReturnType>((quote! {-> RET}).into()).unwrap_or_abort()
                 } else {
                     func.sig.output.clone()
                 };
@@ -269,10 +541,16 @@ pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> Toke
             sig.generics.params.push(
                 syn::parse::<GenericParam>(
                     //the 'static life time is ok since we give it a box::pin
-                    (quote! {FUT: std::future::Future<Output=#output> + 'static}).into(),
+                    (quote! {FUT: core::future::Future<Output=#output> + 'static}).into(),
                 )
                 .unwrap_or_abort(),
             )
+        } else if borrows_return_value {
+            let output = typing::get_output_type(&func.sig.output);
+            sig.generics.params.push(
+                syn::parse::<GenericParam>((quote! {RET: core::borrow::Borrow<#output>}).into())
+                    .unwrap_or_abort(),
+            )
         }
     }
 
@@ -290,14 +568,36 @@ pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> Toke
     let names = args.iter().map(|(_, pt, _)| pt.pat.as_ref());
     let func_call = quote! {(fptr)(#(#names),*)};
 
+    // The config that is passed to `init` is cached for `fp_config()`:
+    let config_store = if stores_config {
+        let config = args
+            .first()
+            .map(|(_, pt, _)| pt.pat.as_ref())
+            .unwrap_or_else(|| abort!(func, "the `config` attribute requires a config argument"));
+        quote! {
+            fp_bindgen_support::guest::config::set_config(core::clone::Clone::clone(&#config));
+        }
+    } else {
+        Default::default()
+    };
+
     let func_wrapper = if func.sig.asyncness.is_some() {
         quote! {
             let ret = fp_bindgen_support::guest::r#async::task::Task::alloc_and_spawn(#func_call);
         }
     } else {
         // Check the output type and replace complex ones with FatPtr
-        let return_wrapper = if typing::is_ret_type_complex(&func.sig.output) {
-            quote! {let ret = fp_bindgen_support::guest::io::export_value_to_host(&ret);}
+        let return_wrapper = if borrows_return_value {
+            let output = typing::get_output_type(&func.sig.output);
+            quote! {
+                let ret = fp_bindgen_support::guest::io::export_value_to_host(
+                    core::borrow::Borrow::<#output>::borrow(&ret)
+                );
+            }
+        } else if returns_packed_pair {
+            quote! {
+                let ret = fp_bindgen_support::common::abi::WasmAbi::to_abi(ret);
+            }
         } else {
             Default::default()
         };
@@ -313,6 +613,7 @@ pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> Toke
         #[inline(always)]
         pub #sig {
             #(let #complex_names = unsafe { fp_bindgen_support::guest::io::import_value_from_host::<#complex_types>(#complex_names) };)*
+            #config_store
             #func_wrapper
             ret
         }
@@ -330,6 +631,9 @@ pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> Toke
 ///     format!("{} + {} => {0}{1}", msg, foo)
 /// }
 /// ```
+///
+/// Exports that are renamed in the protocol pass their name on the wire as
+/// well, such as `#[fp_export_impl(logger, rename = "write_log")]`.
 #[proc_macro_attribute]
 #[proc_macro_error]
 pub fn fp_export_impl(attributes: TokenStream, input: TokenStream) -> TokenStream {
@@ -340,7 +644,7 @@ pub fn fp_export_impl(attributes: TokenStream, input: TokenStream) -> TokenStrea
         syn::parse_macro_input::parse::<AttributeArgs>(attributes.clone()).unwrap_or_abort();
 
     let protocol_path = attrs
-        .get(0)
+        .first()
         .map(|om| match om {
             syn::NestedMeta::Meta(meta) => match meta {
                 syn::Meta::Path(path) => path,
@@ -349,6 +653,14 @@ pub fn fp_export_impl(attributes: TokenStream, input: TokenStream) -> TokenStrea
             _ => abort!(om, "unsupported attribute, must name a path"),
         })
         .unwrap_or_else(|| abort!(func, "missing attribute. Must name which provider is being implemented eg: #[fp_export_impl(foobar)]"));
+    let rename = attrs.iter().skip(1).find_map(|attr| match attr {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+            path,
+            lit: syn::Lit::Str(name),
+            ..
+        })) if path.is_ident("rename") => Some(name.value()),
+        other => abort!(other, "unsupported attribute, only `rename` is supported"),
+    });
 
     let args = typing::extract_args(&func.sig).collect::<Vec<_>>();
 
@@ -379,10 +691,19 @@ pub fn fp_export_impl(attributes: TokenStream, input: TokenStream) -> TokenStrea
         .chain(once(&impl_fn_pat))
         .collect::<Vec<_>>();
 
+    // Renamed exports are exported under their name on the wire:
+    let export_attr = match rename {
+        Some(name) => {
+            let symbol = format!("__fp_gen_{}", name);
+            quote! { #[export_name = #symbol] }
+        }
+        None => quote! { #[no_mangle] },
+    };
+
     let ts: proc_macro2::TokenStream = input.clone().into();
     //build the actual exported wrapper function
     (quote! {
-        #[no_mangle]
+        #export_attr
         pub #sig {
             #protocol_path::#fn_name(#(#call_args),*)
         }
@@ -395,17 +716,48 @@ pub fn fp_export_impl(attributes: TokenStream, input: TokenStream) -> TokenStrea
 /// This is not meant to be used directly.
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn fp_import_signature(_attributes: TokenStream, input: TokenStream) -> TokenStream {
+pub fn fp_import_signature(attributes: TokenStream, input: TokenStream) -> TokenStream {
     proc_macro_error::set_dummy(input.clone().into());
 
     let func = syn::parse_macro_input::parse::<ForeignItemFn>(input.clone()).unwrap_or_abort();
     let args = typing::extract_args(&func.sig).collect::<Vec<_>>();
 
+    let attributes = syn::parse_macro_input::parse::<AttributeArgs>(attributes).unwrap_or_abort();
+    let mut is_batchable = false;
+    let mut records_metrics = false;
+    let mut rename = None;
+    for attr in attributes {
+        match attr {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("batchable") => {
+                is_batchable = true
+            }
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("metrics") => {
+                records_metrics = true
+            }
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                lit: syn::Lit::Str(name),
+                ..
+            })) if path.is_ident("rename") => rename = Some(name.value()),
+            other => abort!(
+                other,
+                "unsupported attribute, only `batchable`, `metrics` and `rename` are supported"
+            ),
+        }
+    }
+    // The name under which the import is exchanged with the host:
+    let wire_name = rename.unwrap_or_else(|| func.sig.ident.to_string());
+
     let wrapper_sig = func.sig.clone();
     let mut extern_sig = wrapper_sig.clone();
     //Massage the signature into what we wish to export
     {
         extern_sig.ident = format_ident!("__fp_gen_{}", extern_sig.ident);
+        // Values of generic types are passed as serialized pointers:
+        extern_sig.generics = Default::default();
+        if typing::is_fire_and_forget(&extern_sig) {
+            extern_sig.output = ReturnType::Default;
+        }
         typing::morph_signature(&mut extern_sig, "fp_bindgen_support");
     }
 
@@ -424,7 +776,10 @@ pub fn fp_import_signature(_attributes: TokenStream, input: TokenStream) -> Toke
     let extern_ident = &extern_sig.ident;
     let func_call = quote! {#extern_ident(#(#names),*)};
 
-    let ret_wrapper = if func.sig.asyncness.is_some() {
+    let ret_wrapper = if typing::is_fire_and_forget(&func.sig) {
+        // The host doesn't pass back an async value for these:
+        Default::default()
+    } else if func.sig.asyncness.is_some() {
         quote! {
             let ret = unsafe {
                 fp_bindgen_support::guest::io::import_value_from_host(fp_bindgen_support::guest::r#async::HostFuture::new(ret).await)
@@ -432,7 +787,11 @@ pub fn fp_import_signature(_attributes: TokenStream, input: TokenStream) -> Toke
         }
     } else {
         // Check the output type and replace complex ones with FatPtr
-        if typing::is_ret_type_complex(&func.sig.output) {
+        if typing::is_ret_type_packed_pair(&func.sig) {
+            quote! {
+                let ret = fp_bindgen_support::common::abi::WasmAbi::from_abi(ret);
+            }
+        } else if typing::is_ret_type_complex(&func.sig.output) {
             quote! {
                 let ret = unsafe { fp_bindgen_support::guest::io::import_value_from_host(ret) };
             }
@@ -441,16 +800,45 @@ pub fn fp_import_signature(_attributes: TokenStream, input: TokenStream) -> Toke
         }
     };
 
+    // Calls to batchable imports are queued while a batch is active:
+    let batch_wrapper = if is_batchable {
+        let names = args.iter().map(|(_, pt, _)| pt.pat.as_ref());
+        quote! {
+            if fp_bindgen_support::guest::batch::is_batching() {
+                fp_bindgen_support::guest::batch::queue_call(#wire_name, &(#(#names,)*));
+                return;
+            }
+        }
+    } else {
+        Default::default()
+    };
+
+    // The guard records the duration of the call once it is dropped:
+    let metrics_guard = if records_metrics {
+        let fn_name = func.sig.ident.to_string();
+        quote! {
+            let _metrics_guard = fp_bindgen_support::guest::metrics::ImportCallGuard::start(#fn_name);
+        }
+    } else {
+        Default::default()
+    };
+
     let attrs = &func.attrs;
+    let link_name = format!("__fp_gen_{}", wire_name);
 
     //build the actual imported wrapper function
     (quote! {
         #[link(wasm_import_module = "fp")]
-        extern "C" { #extern_sig; }
+        extern "C" {
+            #[link_name = #link_name]
+            #extern_sig;
+        }
 
         #[inline(always)]
         #(#attrs)*
         pub #wrapper_sig {
+            #batch_wrapper
+            #metrics_guard
             #(let #complex_names = fp_bindgen_support::guest::io::export_value_to_host(&#complex_names);)*
             let ret = unsafe { #func_call };
             #ret_wrapper
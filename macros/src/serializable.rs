@@ -1,10 +1,11 @@
-use crate::utils::{extract_path_from_type, parse_type_item};
+use crate::utils::{extract_path_from_type, is_phantom_data, parse_type_item};
 use crate::CollectableTypeDefinition;
 use proc_macro::TokenStream;
-use quote::quote;
+use proc_macro_error::{abort, abort_call_site};
+use quote::{quote, quote_spanned, ToTokens};
 use std::collections::{BTreeMap, HashSet};
 use syn::punctuated::Punctuated;
-use syn::TypeParamBound;
+use syn::{parse_quote, Attribute, Item, Lit, Meta, NestedMeta, Token, TypeParamBound};
 
 pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
     let item_str = item.to_string();
@@ -15,6 +16,7 @@ pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
             .variants
             .into_iter()
             .flat_map(|variant| variant.fields)
+            .filter(|field| !is_phantom_data(&field.ty))
             .map(|field| {
                 extract_path_from_type(&field.ty).unwrap_or_else(|| {
                     panic!(
@@ -27,6 +29,7 @@ pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
         syn::Item::Struct(ty) => ty
             .fields
             .into_iter()
+            .filter(|field| !is_phantom_data(&field.ty))
             .map(|field| {
                 extract_path_from_type(&field.ty).unwrap_or_else(|| {
                     panic!(
@@ -130,6 +133,10 @@ pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
         }
     };
 
+    // `file!()` and `line!()` resolve to the declaration of the type, because
+    // they are invoked with the span of its name:
+    let location = quote_spanned! {item_name.span()=> (file!(), line!()) };
+
     let implementation = quote! {
         impl#generics fp_bindgen::prelude::Serializable for #item_name#generics#where_clause {
             fn ident() -> fp_bindgen::prelude::TypeIdent {
@@ -137,7 +144,8 @@ pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
             }
 
             fn ty() -> fp_bindgen::prelude::Type {
-                fp_bindgen::prelude::Type::from_item(#item_str)
+                let (file, line) = #location;
+                fp_bindgen::prelude::Type::from_item(#item_str).with_location(file, line)
             }
 
             fn collect_types(types: &mut fp_bindgen::prelude::TypeMap) {
@@ -149,6 +157,127 @@ pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
     implementation.into()
 }
 
+pub(crate) fn impl_serializable_remote(item: TokenStream) -> TokenStream {
+    let mut item = syn::parse::<Item>(item).unwrap_or_else(|error| abort!(error.span(), error));
+    let (ident, attrs) = match &mut item {
+        Item::Enum(item) => (item.ident.clone(), &mut item.attrs),
+        Item::Struct(item) => (item.ident.clone(), &mut item.attrs),
+        item => abort!(
+            item,
+            "only structs and enums can be registered as remote types"
+        ),
+    };
+
+    let remote_path = take_remote_path(attrs).unwrap_or_else(|| {
+        abort_call_site!(
+            "missing `#[fp(remote = \"...\")]` attribute with the path of the remote type"
+        )
+    });
+    let (module, name) = remote_path.rsplit_once("::").unwrap_or_else(|| {
+        abort!(
+            ident,
+            "the path of the remote type needs to include its module, such as `my_crate::{}`",
+            ident
+        )
+    });
+    if ident != name {
+        abort!(
+            ident,
+            "the name of the remote type is `{}`, but it is declared as `{}`",
+            name,
+            ident
+        );
+    }
+
+    // The remote type is imported from its module by the Rust generators,
+    // just like types that are annotated with `rust_module`:
+    attrs.push(parse_quote! { #[fp(rust_module = #module)] });
+    let implementation: proc_macro2::TokenStream =
+        impl_derive_serializable(item.to_token_stream().into()).into();
+
+    // The declaration only describes the remote type, so it doesn't derive
+    // anything that would process its attributes:
+    strip_helper_attrs(&mut item);
+    (quote! {
+        #[allow(dead_code)]
+        #item
+
+        #implementation
+    })
+    .into()
+}
+
+/// Removes the `remote` option from the `#[fp(...)]` attributes, and returns
+/// its value.
+fn take_remote_path(attrs: &mut Vec<Attribute>) -> Option<String> {
+    let mut remote_path = None;
+    let mut remaining_attrs = Vec::new();
+    for attr in attrs.drain(..) {
+        if !attr.path.is_ident("fp") {
+            remaining_attrs.push(attr);
+            continue;
+        }
+
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list,
+            _ => abort!(attr, "expected `#[fp(...)]`"),
+        };
+        let mut options = Punctuated::<NestedMeta, Token![,]>::new();
+        for option in list.nested {
+            match option {
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("remote") =>
+                {
+                    match name_value.lit {
+                        Lit::Str(path) => remote_path = Some(path.value()),
+                        other => abort!(other, "expected the path of the remote type as a string"),
+                    }
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("rust_module") =>
+                {
+                    abort!(name_value, "remote types cannot specify a `rust_module`")
+                }
+                option => options.push(option),
+            }
+        }
+        if !options.is_empty() {
+            remaining_attrs.push(parse_quote! { #[fp(#options)] });
+        }
+    }
+    *attrs = remaining_attrs;
+    remote_path
+}
+
+/// Removes the `#[fp(...)]` and `#[serde(...)]` attributes from the item, its
+/// fields and its variants.
+fn strip_helper_attrs(item: &mut Item) {
+    fn retain_other_attrs(attrs: &mut Vec<Attribute>) {
+        attrs.retain(|attr| !attr.path.is_ident("fp") && !attr.path.is_ident("serde"));
+    }
+
+    fn strip_fields(fields: &mut syn::Fields) {
+        for field in fields.iter_mut() {
+            retain_other_attrs(&mut field.attrs);
+        }
+    }
+
+    match item {
+        Item::Enum(item) => {
+            retain_other_attrs(&mut item.attrs);
+            for variant in item.variants.iter_mut() {
+                retain_other_attrs(&mut variant.attrs);
+                strip_fields(&mut variant.fields);
+            }
+        }
+        Item::Struct(item) => {
+            retain_other_attrs(&mut item.attrs);
+            strip_fields(&mut item.fields);
+        }
+        _ => {}
+    }
+}
+
 fn path_to_string(path: &syn::Path) -> String {
     path.segments
         .iter()
@@ -43,11 +43,47 @@ pub(crate) fn is_type_complex(ty: &Type) -> bool {
                     | "usize"
             )
         }
+        Type::Reference(_) => true,
         Type::Tuple(_) => true,
         t => abort!(t, "unsupported type"),
     }
 }
 
+/// Checks whether the type is a pair of primitives that fit in 32 bits, which
+/// is returned packed into a `u64` instead of being serialized.
+pub(crate) fn is_packed_pair(ty: &Type) -> bool {
+    match ty {
+        Type::Tuple(tuple) if tuple.elems.len() == 2 => tuple.elems.iter().all(|elem| {
+            matches!(elem, Type::Path(tp) if tp.qself.is_none() && matches!(
+                tp.path.to_token_stream().to_string().as_str(),
+                "bool" | "f32" | "i8" | "i16" | "i32" | "u8" | "u16" | "u32"
+            ))
+        }),
+        _ => false,
+    }
+}
+
+/// Checks whether the return value is packed, which only happens for
+/// synchronous functions.
+pub(crate) fn is_ret_type_packed_pair(sig: &Signature) -> bool {
+    match &sig.output {
+        ReturnType::Type(_, ty) => sig.asyncness.is_none() && is_packed_pair(ty.as_ref()),
+        ReturnType::Default => false,
+    }
+}
+
+/// Checks whether the function is async without returning a value, in which
+/// case calls to it are fire-and-forget.
+pub(crate) fn is_fire_and_forget(sig: &Signature) -> bool {
+    sig.asyncness.is_some()
+        && match &sig.output {
+            ReturnType::Default => true,
+            ReturnType::Type(_, ty) => {
+                matches!(ty.as_ref(), Type::Tuple(tuple) if tuple.elems.is_empty())
+            }
+        }
+}
+
 pub(crate) fn get_output_type(output: &ReturnType) -> &Type {
     match output {
         ReturnType::Default => abort!(output, "FIXME"),
@@ -62,8 +98,10 @@ pub(crate) fn replace_complex_type(ty: &mut Type, crate_path: &str) {
     }
 }
 
-/// Replaces complex types in the input and output of a function signature and makes it non-async
+/// Replaces complex types in the input and output of a function signature and makes it non-async.
+/// Packed pairs are returned as `u64`.
 pub(crate) fn morph_signature(sig: &mut Signature, crate_path: &str) {
+    let returns_packed_pair = is_ret_type_packed_pair(sig);
     sig.asyncness = None;
     sig.inputs = sig
         .inputs
@@ -77,7 +115,11 @@ pub(crate) fn morph_signature(sig: &mut Signature, crate_path: &str) {
         .collect();
 
     if let ReturnType::Type(_, ref mut ty) = sig.output {
-        replace_complex_type(ty.as_mut(), crate_path);
+        if returns_packed_pair {
+            *ty.as_mut() = syn::parse_quote!(u64);
+        } else {
+            replace_complex_type(ty.as_mut(), crate_path);
+        }
     }
 }
 
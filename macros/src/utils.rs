@@ -5,10 +5,24 @@ use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use std::str::FromStr;
 use syn::{
-    punctuated::Punctuated, Expr, ExprLit, Generics, Item, ItemUse, Lit, Path, PathArguments,
-    PathSegment, ReturnType, Type, TypeArray,
+    punctuated::Punctuated, Expr, ExprLit, GenericArgument, Generics, Item, ItemUse, Lit, Path,
+    PathArguments, PathSegment, ReturnType, Type, TypeArray,
 };
 
+/// Returns whether the given type is a `PhantomData` marker, which is not part
+/// of the serialized representation and doesn't need to be collected.
+pub(crate) fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "PhantomData")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
 pub(crate) fn extract_path_from_type(ty: &Type) -> Option<CollectableTypeDefinition> {
     match ty {
         Type::Array(TypeArray {
@@ -18,9 +32,12 @@ pub(crate) fn extract_path_from_type(ty: &Type) -> Option<CollectableTypeDefinit
             }),
             ..
         }) => {
-            let def = extract_path_from_type(elem).unwrap();
-            Some(CollectableTypeDefinition {
-                path: def.path,
+            let path = match extract_path_from_type(elem).unwrap() {
+                CollectableTypeDefinition::Path { path, .. } => path,
+                CollectableTypeDefinition::Tuple(_) => return None,
+            };
+            Some(CollectableTypeDefinition::Path {
+                path,
                 array_len: usize::from_str(len.base10_digits()).unwrap(),
             })
         }
@@ -34,12 +51,66 @@ pub(crate) fn extract_path_from_type(ty: &Type) -> Option<CollectableTypeDefinit
                     args.colon2_token = Some(syn::parse_quote!(::));
                 }
             }
-            Some(CollectableTypeDefinition { path, array_len: 0 })
+            Some(CollectableTypeDefinition::Path { path, array_len: 0 })
         }
+        Type::Tuple(tuple) if !tuple.elems.is_empty() => tuple
+            .elems
+            .iter()
+            .map(extract_path_from_type)
+            .collect::<Option<Vec<_>>>()
+            .map(CollectableTypeDefinition::Tuple),
         _ => None,
     }
 }
 
+/// Replaces the type parameters of a generic function with `()` inside the
+/// given type, so the types around them can still be collected.
+///
+/// Returns `None` if the type is one of the type parameters itself, since there
+/// is nothing to collect for it.
+pub(crate) fn erase_generic_params(ty: &Type, params: &[Ident]) -> Option<Type> {
+    if is_generic_param(ty, params) {
+        return None;
+    }
+
+    let mut ty = ty.clone();
+    replace_generic_params(&mut ty, params);
+    Some(ty)
+}
+
+fn is_generic_param(ty: &Type, params: &[Ident]) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none()
+        && params.iter().any(|param| path.path.is_ident(param)))
+}
+
+fn replace_generic_params(ty: &mut Type, params: &[Ident]) {
+    if is_generic_param(ty, params) {
+        *ty = syn::parse_quote!(());
+        return;
+    }
+
+    match ty {
+        Type::Array(array) => replace_generic_params(&mut array.elem, params),
+        Type::Path(path) => {
+            for segment in &mut path.path.segments {
+                if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    for arg in &mut args.args {
+                        if let GenericArgument::Type(ty) = arg {
+                            replace_generic_params(ty, params);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Tuple(tuple) => {
+            for elem in &mut tuple.elems {
+                replace_generic_params(elem, params);
+            }
+        }
+        _ => {}
+    }
+}
+
 pub(crate) fn parse_type_item(item: TokenStream) -> (Ident, Item, Generics) {
     let item = syn::parse::<Item>(item).unwrap();
     match item {
@@ -111,3 +182,13 @@ pub(crate) fn normalize_return_type(ty: &ReturnType) -> Option<&Type> {
         }
     }
 }
+
+/// Returns whether the given tokens contain the given identifier, such as a
+/// type parameter, at any depth.
+pub(crate) fn mentions_ident(tokens: proc_macro2::TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(other) => other == *ident,
+        proc_macro2::TokenTree::Group(group) => mentions_ident(group.stream(), ident),
+        _ => false,
+    })
+}